@@ -0,0 +1,185 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration types for the Neo4j bootstrap provider.
+
+use serde::{Deserialize, Serialize};
+
+fn default_user() -> String {
+    "neo4j".to_string()
+}
+
+fn default_fetch_size() -> usize {
+    500
+}
+
+/// URI schemes accepted by the Neo4j driver.
+pub(crate) const URI_SCHEMES: &[&str] = &[
+    "neo4j://",
+    "neo4j+s://",
+    "neo4j+ssc://",
+    "bolt://",
+    "bolt+s://",
+    "bolt+ssc://",
+];
+
+/// Neo4j bootstrap provider configuration
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Neo4jBootstrapConfig {
+    /// Connection URI, for example `neo4j://localhost:7687`
+    pub uri: String,
+
+    /// Database user
+    #[serde(default = "default_user")]
+    pub user: String,
+
+    /// Database password
+    #[serde(default)]
+    pub password: String,
+
+    /// Database name. `None` uses the server's default database.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub database: Option<String>,
+
+    /// Cypher queries returning the elements to bootstrap. Node rows return
+    /// `id`, `labels` and `properties`; relation rows return `id`, `type`,
+    /// `start`, `end` and `properties`. Empty means all nodes and relations
+    /// with the labels requested by the query.
+    #[serde(default)]
+    pub queries: Vec<String>,
+
+    /// Number of rows fetched from the server at a time
+    #[serde(default = "default_fetch_size")]
+    pub fetch_size: usize,
+}
+
+impl Default for Neo4jBootstrapConfig {
+    fn default() -> Self {
+        Self {
+            uri: String::new(),
+            user: default_user(),
+            password: String::new(),
+            database: None,
+            queries: Vec::new(),
+            fetch_size: default_fetch_size(),
+        }
+    }
+}
+
+impl Neo4jBootstrapConfig {
+    /// Validate the configuration and return an error if invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The URI does not use a `neo4j` or `bolt` scheme
+    /// - User is empty
+    /// - Database or a query is empty
+    /// - Fetch size is 0
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if !URI_SCHEMES
+            .iter()
+            .any(|scheme| self.uri.starts_with(scheme))
+        {
+            return Err(anyhow::anyhow!(
+                "Validation error: uri '{}' must use one of the schemes {}",
+                self.uri,
+                URI_SCHEMES.join(", ")
+            ));
+        }
+
+        if self.user.is_empty() {
+            return Err(anyhow::anyhow!("Validation error: user cannot be empty"));
+        }
+
+        if self.database.as_deref() == Some("") {
+            return Err(anyhow::anyhow!(
+                "Validation error: database cannot be empty. \
+                 Omit it to use the server's default database"
+            ));
+        }
+
+        if self.queries.iter().any(|query| query.trim().is_empty()) {
+            return Err(anyhow::anyhow!("Validation error: queries cannot be empty"));
+        }
+
+        if self.fetch_size == 0 {
+            return Err(anyhow::anyhow!(
+                "Validation error: fetch_size must be greater than 0"
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> Neo4jBootstrapConfig {
+        Neo4jBootstrapConfig {
+            uri: "neo4j://localhost:7687".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(config().validate().is_ok());
+        assert!(Neo4jBootstrapConfig {
+            uri: "bolt+s://graph.example.com".to_string(),
+            database: Some("movies".to_string()),
+            ..config()
+        }
+        .validate()
+        .is_ok());
+
+        assert!(Neo4jBootstrapConfig {
+            uri: "http://localhost:7474".to_string(),
+            ..config()
+        }
+        .validate()
+        .is_err());
+        assert!(Neo4jBootstrapConfig {
+            database: Some(String::new()),
+            ..config()
+        }
+        .validate()
+        .is_err());
+        assert!(Neo4jBootstrapConfig {
+            queries: vec!["  ".to_string()],
+            ..config()
+        }
+        .validate()
+        .is_err());
+        assert!(Neo4jBootstrapConfig {
+            fetch_size: 0,
+            ..config()
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn test_deserialization_defaults() {
+        let config: Neo4jBootstrapConfig =
+            serde_json::from_str(r#"{"uri": "neo4j://localhost:7687"}"#).unwrap();
+
+        assert_eq!(config.user, "neo4j");
+        assert_eq!(config.database, None);
+        assert!(config.queries.is_empty());
+        assert_eq!(config.fetch_size, 500);
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Plugin descriptor for the Neo4j bootstrap provider.
+
+use drasi_lib::bootstrap::BootstrapProvider;
+use drasi_plugin_sdk::prelude::*;
+use utoipa::OpenApi;
+
+use crate::{Neo4jBootstrapConfig, Neo4jBootstrapProvider};
+
+fn default_user() -> ConfigValue<String> {
+    ConfigValue::Static("neo4j".to_string())
+}
+
+fn default_password() -> ConfigValue<String> {
+    ConfigValue::Static(String::new())
+}
+
+fn default_fetch_size() -> ConfigValue<usize> {
+    ConfigValue::Static(500)
+}
+
+/// Configuration DTO for the Neo4j bootstrap provider.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = bootstrap::neo4j::Neo4jBootstrapConfig)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Neo4jBootstrapConfigDto {
+    #[schema(value_type = ConfigValueString)]
+    pub uri: ConfigValue<String>,
+
+    #[serde(default = "default_user")]
+    #[schema(value_type = ConfigValueString)]
+    pub user: ConfigValue<String>,
+
+    #[serde(default = "default_password")]
+    #[schema(value_type = ConfigValueString)]
+    pub password: ConfigValue<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub database: Option<ConfigValue<String>>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub queries: Vec<String>,
+
+    #[serde(default = "default_fetch_size")]
+    #[schema(value_type = ConfigValueUsize)]
+    pub fetch_size: ConfigValue<usize>,
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(Neo4jBootstrapConfigDto)))]
+struct Neo4jBootstrapSchemas;
+
+/// Plugin descriptor for the Neo4j bootstrap provider.
+pub struct Neo4jBootstrapDescriptor;
+
+#[async_trait]
+impl BootstrapPluginDescriptor for Neo4jBootstrapDescriptor {
+    fn kind(&self) -> &str {
+        "neo4j"
+    }
+
+    fn config_version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn config_schema_name(&self) -> &str {
+        "bootstrap.neo4j.Neo4jBootstrapConfig"
+    }
+
+    fn config_schema_json(&self) -> String {
+        let api = Neo4jBootstrapSchemas::openapi();
+        serde_json::to_string(
+            &api.components
+                .as_ref()
+                .expect("OpenAPI components missing")
+                .schemas,
+        )
+        .expect("Failed to serialize config schema")
+    }
+
+    async fn create_bootstrap_provider(
+        &self,
+        config_json: &serde_json::Value,
+        _source_config_json: &serde_json::Value,
+    ) -> anyhow::Result<Box<dyn BootstrapProvider>> {
+        let dto: Neo4jBootstrapConfigDto = serde_json::from_value(config_json.clone())?;
+        let mapper = DtoMapper::new();
+
+        let config = Neo4jBootstrapConfig {
+            uri: mapper.resolve_string(&dto.uri)?,
+            user: mapper.resolve_string(&dto.user)?,
+            password: mapper.resolve_string(&dto.password)?,
+            database: mapper.resolve_optional_string(&dto.database)?,
+            queries: dto.queries,
+            fetch_size: mapper.resolve_typed(&dto.fetch_size)?,
+        };
+        config.validate()?;
+
+        Ok(Box::new(Neo4jBootstrapProvider::new(config)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_descriptor_creates_provider() {
+        let descriptor = Neo4jBootstrapDescriptor;
+        let config = json!({
+            "uri": "neo4j://localhost:7687",
+            "password": "secret",
+            "database": "movies",
+            "fetchSize": 100
+        });
+
+        assert!(descriptor
+            .create_bootstrap_provider(&config, &json!({}))
+            .await
+            .is_ok());
+        assert!(descriptor
+            .config_schema_json()
+            .contains("Neo4jBootstrapConfig"));
+    }
+
+    #[tokio::test]
+    async fn test_descriptor_rejects_unknown_fields() {
+        let config = json!({"uri": "neo4j://localhost:7687", "host": "localhost"});
+
+        assert!(Neo4jBootstrapDescriptor
+            .create_bootstrap_provider(&config, &json!({}))
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,53 @@
+#![allow(unexpected_cfgs)]
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Neo4j bootstrap plugin for Drasi
+//!
+//! This plugin loads the initial element set of a continuous query from a
+//! Neo4j database over Bolt. By default every node with one of the requested
+//! labels and every relationship with one of the requested types is
+//! returned; configured Cypher queries replace the defaults.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use drasi_bootstrap_neo4j::Neo4jBootstrapProvider;
+//!
+//! let provider = Neo4jBootstrapProvider::builder()
+//!     .with_uri("neo4j://localhost:7687")
+//!     .with_user("neo4j")
+//!     .with_password("secret")
+//!     .build()
+//!     .unwrap();
+//! ```
+
+pub mod config;
+pub mod descriptor;
+pub mod neo4j;
+
+pub use config::Neo4jBootstrapConfig;
+pub use neo4j::{Neo4jBootstrapProvider, Neo4jBootstrapProviderBuilder};
+
+/// Dynamic plugin entry point.
+#[cfg(feature = "dynamic-plugin")]
+drasi_plugin_sdk::export_plugin!(
+    plugin_id = "neo4j-bootstrap",
+    core_version = env!("CARGO_PKG_VERSION"),
+    lib_version = env!("CARGO_PKG_VERSION"),
+    plugin_version = env!("CARGO_PKG_VERSION"),
+    source_descriptors = [],
+    reaction_descriptors = [],
+    bootstrap_descriptors = [descriptor::Neo4jBootstrapDescriptor],
+);
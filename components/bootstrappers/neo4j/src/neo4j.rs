@@ -0,0 +1,471 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Neo4j bootstrap provider for reading initial graph data over Bolt
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use drasi_core::models::{Element, ElementMetadata, ElementReference, SourceChange};
+use drasi_lib::bootstrap::{
+    BootstrapContext, BootstrapProvider, BootstrapRequest, BootstrapResult,
+};
+use drasi_lib::channels::{BootstrapEvent, BootstrapEventSender};
+use drasi_lib::sources::convert_json_to_element_properties;
+use log::{debug, info};
+use neo4rs::{query, ConfigBuilder, Graph, Query};
+use serde::Deserialize;
+use std::sync::Arc;
+
+pub use crate::config::Neo4jBootstrapConfig;
+
+/// Default query returning every node carrying one of the requested labels.
+/// An empty `$labels` list returns all nodes.
+const NODE_QUERY: &str = "MATCH (n) \
+     WHERE $labels = [] OR any(label IN labels(n) WHERE label IN $labels) \
+     RETURN elementId(n) AS id, labels(n) AS labels, properties(n) AS properties";
+
+/// Default query returning every relationship of one of the requested types.
+/// An empty `$types` list returns all relationships.
+const RELATION_QUERY: &str = "MATCH (s)-[r]->(e) \
+     WHERE $types = [] OR type(r) IN $types \
+     RETURN elementId(r) AS id, type(r) AS type, elementId(s) AS start, \
+     elementId(e) AS end, properties(r) AS properties";
+
+/// Bootstrap provider for Neo4j
+///
+/// Runs Cypher queries against the database and emits the returned nodes and
+/// relationships as insert events.
+pub struct Neo4jBootstrapProvider {
+    config: Neo4jBootstrapConfig,
+}
+
+impl Neo4jBootstrapProvider {
+    /// Create a new Neo4j bootstrap provider with the given configuration
+    pub fn new(config: Neo4jBootstrapConfig) -> Self {
+        Self { config }
+    }
+
+    /// Create a builder for Neo4jBootstrapProvider
+    pub fn builder() -> Neo4jBootstrapProviderBuilder {
+        Neo4jBootstrapProviderBuilder::new()
+    }
+
+    async fn connect(&self) -> Result<Graph> {
+        let mut builder = ConfigBuilder::default()
+            .uri(self.config.uri.as_str())
+            .user(self.config.user.as_str())
+            .password(self.config.password.as_str())
+            .fetch_size(self.config.fetch_size);
+        if let Some(database) = &self.config.database {
+            builder = builder.db(database.as_str());
+        }
+
+        let graph = Graph::connect(builder.build()?)
+            .await
+            .map_err(|e| anyhow!("Failed to connect to Neo4j at {}: {e}", self.config.uri))?;
+        Ok(graph)
+    }
+
+    /// Queries to run for a request. Configured queries run unchanged and are
+    /// filtered client-side; the defaults push the label filter to the server.
+    fn queries(&self, request: &BootstrapRequest) -> Vec<Query> {
+        if self.config.queries.is_empty() {
+            vec![
+                query(NODE_QUERY).param("labels", request.node_labels.clone()),
+                query(RELATION_QUERY).param("types", request.relation_labels.clone()),
+            ]
+        } else {
+            self.config.queries.iter().map(|q| query(q)).collect()
+        }
+    }
+}
+
+#[async_trait]
+impl BootstrapProvider for Neo4jBootstrapProvider {
+    async fn bootstrap(
+        &self,
+        request: BootstrapRequest,
+        context: &BootstrapContext,
+        event_tx: BootstrapEventSender,
+        _settings: Option<&drasi_lib::config::SourceSubscriptionSettings>,
+    ) -> Result<BootstrapResult> {
+        info!(
+            "Starting Neo4j bootstrap for query '{}' with {} node labels and {} relation labels",
+            request.query_id,
+            request.node_labels.len(),
+            request.relation_labels.len()
+        );
+
+        self.config.validate()?;
+        let graph = self.connect().await?;
+
+        let mut count = 0;
+        for query in self.queries(&request) {
+            let mut rows = graph
+                .execute(query)
+                .await
+                .map_err(|e| anyhow!("Failed to run Neo4j bootstrap query: {e}"))?;
+
+            while let Some(row) = rows
+                .next()
+                .await
+                .map_err(|e| anyhow!("Failed to read Neo4j bootstrap row: {e}"))?
+            {
+                let record: RowRecord = row
+                    .to()
+                    .map_err(|e| anyhow!("Unexpected Neo4j bootstrap row shape: {e}"))?;
+                let graph_row = GraphRow::try_from(record)?;
+                if !graph_row.matches(&request) {
+                    continue;
+                }
+
+                let change = graph_row.into_source_change(
+                    &context.source_id,
+                    chrono::Utc::now().timestamp_millis() as u64,
+                );
+                let bootstrap_event = BootstrapEvent {
+                    source_id: context.source_id.clone(),
+                    change,
+                    timestamp: chrono::Utc::now(),
+                    sequence: context.next_sequence(),
+                };
+                event_tx.send(bootstrap_event).await.map_err(|e| {
+                    anyhow!(
+                        "Failed to send bootstrap event to channel (channel may be closed): {e}"
+                    )
+                })?;
+                count += 1;
+            }
+            debug!("Neo4j bootstrap query completed, {count} elements sent so far");
+        }
+
+        info!(
+            "Completed Neo4j bootstrap for query {}: sent {count} elements",
+            request.query_id
+        );
+
+        Ok(BootstrapResult {
+            event_count: count,
+            last_sequence: None,
+            sequences_aligned: false,
+        })
+    }
+}
+
+/// Raw columns of a bootstrap query row
+#[derive(Debug, Deserialize)]
+struct RowRecord {
+    id: String,
+    #[serde(default)]
+    labels: Vec<String>,
+    #[serde(default, rename = "type")]
+    rel_type: Option<String>,
+    #[serde(default)]
+    start: Option<String>,
+    #[serde(default)]
+    end: Option<String>,
+    #[serde(default)]
+    properties: serde_json::Map<String, serde_json::Value>,
+}
+
+/// A node or relationship returned by a bootstrap query
+#[derive(Debug, PartialEq)]
+enum GraphRow {
+    Node {
+        id: String,
+        labels: Vec<String>,
+        properties: serde_json::Map<String, serde_json::Value>,
+    },
+    Relation {
+        id: String,
+        rel_type: String,
+        start: String,
+        end: String,
+        properties: serde_json::Map<String, serde_json::Value>,
+    },
+}
+
+impl TryFrom<RowRecord> for GraphRow {
+    type Error = anyhow::Error;
+
+    fn try_from(record: RowRecord) -> Result<Self> {
+        match record.rel_type {
+            Some(rel_type) => {
+                let (Some(start), Some(end)) = (record.start, record.end) else {
+                    return Err(anyhow!(
+                        "Relationship row '{}' must return 'start' and 'end' columns",
+                        record.id
+                    ));
+                };
+                Ok(GraphRow::Relation {
+                    id: record.id,
+                    rel_type,
+                    start,
+                    end,
+                    properties: record.properties,
+                })
+            }
+            None => Ok(GraphRow::Node {
+                id: record.id,
+                labels: record.labels,
+                properties: record.properties,
+            }),
+        }
+    }
+}
+
+impl GraphRow {
+    /// Whether the row carries one of the labels requested by the query.
+    /// An empty request list matches every element of that kind.
+    fn matches(&self, request: &BootstrapRequest) -> bool {
+        match self {
+            GraphRow::Node { labels, .. } => {
+                request.node_labels.is_empty()
+                    || labels.iter().any(|l| request.node_labels.contains(l))
+            }
+            GraphRow::Relation { rel_type, .. } => {
+                request.relation_labels.is_empty() || request.relation_labels.contains(rel_type)
+            }
+        }
+    }
+
+    fn into_source_change(self, source_id: &str, effective_from: u64) -> SourceChange {
+        let metadata = |id: &str, labels: Vec<String>| ElementMetadata {
+            reference: ElementReference::new(source_id, id),
+            labels: Arc::from(labels.into_iter().map(Arc::from).collect::<Vec<_>>()),
+            effective_from,
+        };
+
+        let element = match self {
+            GraphRow::Node {
+                id,
+                labels,
+                properties,
+            } => Element::Node {
+                metadata: metadata(&id, labels),
+                properties: convert_json_to_element_properties(&properties),
+            },
+            GraphRow::Relation {
+                id,
+                rel_type,
+                start,
+                end,
+                properties,
+            } => Element::Relation {
+                metadata: metadata(&id, vec![rel_type]),
+                properties: convert_json_to_element_properties(&properties),
+                in_node: ElementReference::new(source_id, &start),
+                out_node: ElementReference::new(source_id, &end),
+            },
+        };
+
+        SourceChange::Insert { element }
+    }
+}
+
+/// Builder for Neo4jBootstrapProvider
+///
+/// # Example
+///
+/// ```no_run
+/// use drasi_bootstrap_neo4j::Neo4jBootstrapProvider;
+///
+/// let provider = Neo4jBootstrapProvider::builder()
+///     .with_uri("neo4j://localhost:7687")
+///     .with_user("neo4j")
+///     .with_password("secret")
+///     .with_database("movies")
+///     .build()
+///     .unwrap();
+/// ```
+pub struct Neo4jBootstrapProviderBuilder {
+    config: Neo4jBootstrapConfig,
+}
+
+impl Neo4jBootstrapProviderBuilder {
+    /// Create a new builder with default values
+    pub fn new() -> Self {
+        Self {
+            config: Neo4jBootstrapConfig::default(),
+        }
+    }
+
+    /// Set the connection URI
+    pub fn with_uri(mut self, uri: impl Into<String>) -> Self {
+        self.config.uri = uri.into();
+        self
+    }
+
+    /// Set the username
+    pub fn with_user(mut self, user: impl Into<String>) -> Self {
+        self.config.user = user.into();
+        self
+    }
+
+    /// Set the password
+    pub fn with_password(mut self, password: impl Into<String>) -> Self {
+        self.config.password = password.into();
+        self
+    }
+
+    /// Set the database name
+    pub fn with_database(mut self, database: impl Into<String>) -> Self {
+        self.config.database = Some(database.into());
+        self
+    }
+
+    /// Set the Cypher queries to bootstrap from
+    pub fn with_queries(mut self, queries: Vec<String>) -> Self {
+        self.config.queries = queries;
+        self
+    }
+
+    /// Add a Cypher query to bootstrap from
+    pub fn with_query(mut self, query: impl Into<String>) -> Self {
+        self.config.queries.push(query.into());
+        self
+    }
+
+    /// Set the number of rows fetched per round trip
+    pub fn with_fetch_size(mut self, fetch_size: usize) -> Self {
+        self.config.fetch_size = fetch_size;
+        self
+    }
+
+    /// Build the Neo4jBootstrapProvider
+    pub fn build(self) -> Result<Neo4jBootstrapProvider> {
+        self.config.validate()?;
+        Ok(Neo4jBootstrapProvider::new(self.config))
+    }
+}
+
+impl Default for Neo4jBootstrapProviderBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use drasi_core::models::ElementValue;
+    use serde_json::json;
+
+    fn request(node_labels: &[&str], relation_labels: &[&str]) -> BootstrapRequest {
+        BootstrapRequest {
+            query_id: "q1".to_string(),
+            node_labels: node_labels.iter().map(|l| l.to_string()).collect(),
+            relation_labels: relation_labels.iter().map(|l| l.to_string()).collect(),
+            request_id: "r1".to_string(),
+        }
+    }
+
+    fn row(value: serde_json::Value) -> Result<GraphRow> {
+        GraphRow::try_from(serde_json::from_value::<RowRecord>(value).unwrap())
+    }
+
+    #[test]
+    fn test_node_row() {
+        let node = row(json!({
+            "id": "4:abc:1",
+            "labels": ["Person", "Actor"],
+            "properties": {"name": "Keanu", "born": 1964}
+        }))
+        .unwrap();
+
+        assert!(node.matches(&request(&[], &[])));
+        assert!(node.matches(&request(&["Actor"], &[])));
+        assert!(!node.matches(&request(&["Movie"], &[])));
+
+        let SourceChange::Insert {
+            element:
+                Element::Node {
+                    metadata,
+                    properties,
+                },
+        } = node.into_source_change("graph", 1_700_000_000_000)
+        else {
+            panic!("expected a node insert");
+        };
+        assert_eq!(metadata.reference.element_id.as_ref(), "4:abc:1");
+        assert_eq!(metadata.reference.source_id.as_ref(), "graph");
+        assert_eq!(metadata.labels.len(), 2);
+        assert_eq!(
+            properties.get("name"),
+            Some(&ElementValue::String(Arc::from("Keanu")))
+        );
+    }
+
+    #[test]
+    fn test_relation_row() {
+        let relation = row(json!({
+            "id": "5:abc:7",
+            "type": "ACTED_IN",
+            "start": "4:abc:1",
+            "end": "4:abc:2",
+            "properties": {"roles": ["Neo"]}
+        }))
+        .unwrap();
+
+        assert!(relation.matches(&request(&["Person"], &[])));
+        assert!(relation.matches(&request(&[], &["ACTED_IN"])));
+        assert!(!relation.matches(&request(&[], &["DIRECTED"])));
+
+        let SourceChange::Insert {
+            element:
+                Element::Relation {
+                    metadata,
+                    in_node,
+                    out_node,
+                    ..
+                },
+        } = relation.into_source_change("graph", 1_700_000_000_000)
+        else {
+            panic!("expected a relation insert");
+        };
+        assert_eq!(metadata.labels[0].as_ref(), "ACTED_IN");
+        assert_eq!(in_node.element_id.as_ref(), "4:abc:1");
+        assert_eq!(out_node.element_id.as_ref(), "4:abc:2");
+    }
+
+    #[test]
+    fn test_relation_row_requires_endpoints() {
+        assert!(row(json!({"id": "5:abc:7", "type": "ACTED_IN", "start": "4:abc:1"})).is_err());
+    }
+
+    #[test]
+    fn test_builder() {
+        let provider = Neo4jBootstrapProvider::builder()
+            .with_uri("bolt://localhost:7687")
+            .with_password("secret")
+            .with_query("MATCH (n:Person) RETURN elementId(n) AS id, labels(n) AS labels, properties(n) AS properties")
+            .build()
+            .unwrap();
+
+        assert_eq!(provider.config.user, "neo4j");
+        assert_eq!(provider.config.queries.len(), 1);
+        assert_eq!(provider.queries(&request(&[], &[])).len(), 1);
+
+        assert!(Neo4jBootstrapProvider::builder().build().is_err());
+        assert_eq!(
+            Neo4jBootstrapProvider::builder()
+                .with_uri("neo4j://localhost")
+                .build()
+                .unwrap()
+                .queries(&request(&["Person"], &[]))
+                .len(),
+            2
+        );
+    }
+}
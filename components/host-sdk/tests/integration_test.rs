@@ -1696,6 +1696,8 @@ async fn test_source_with_null_identity_provider() {
         update_tx,
         state_store: None,
         identity_provider: None,
+        checkpoint_store: None,
+        metrics: None,
     };
 
     // This should not crash — identity_provider is None
@@ -1743,6 +1745,8 @@ async fn test_source_with_identity_provider_injection() {
         update_tx,
         state_store: None,
         identity_provider: Some(provider),
+        checkpoint_store: None,
+        metrics: None,
     };
 
     // This should not crash — identity_provider is passed through FFI
@@ -1791,6 +1795,8 @@ async fn test_reaction_enqueue_query_result() {
         update_tx,
         state_store: None,
         identity_provider: None,
+        checkpoint_store: None,
+        metrics: None,
     };
     reaction.initialize(context).await;
 
@@ -1852,6 +1858,8 @@ async fn test_reaction_enqueue_multiple_query_results() {
         update_tx,
         state_store: None,
         identity_provider: None,
+        checkpoint_store: None,
+        metrics: None,
     };
     reaction.initialize(context).await;
     reaction.start().await.expect("Reaction should start");
@@ -1908,6 +1916,8 @@ async fn test_reaction_enqueue_query_result_with_data() {
         update_tx,
         state_store: None,
         identity_provider: None,
+        checkpoint_store: None,
+        metrics: None,
     };
     reaction.initialize(context).await;
     reaction.start().await.expect("Reaction should start");
@@ -1987,6 +1997,7 @@ async fn test_reaction_start_stop_stress() {
             update_tx,
             state_store: None,
             identity_provider: None,
+            metrics: None,
         };
         reaction.initialize(context).await;
         reaction.start().await.expect("reaction start");
@@ -914,6 +914,33 @@ impl ElementIndex for GarnetElementIndex {
             .await
     }
 
+    #[tracing::instrument(skip_all, err)]
+    async fn get_all_elements(&self) -> Result<ElementStream, IndexError> {
+        let prefix = format!("ei:{{{}}}:", self.query_id);
+        let keys = crate::scan_keys(&self.connection, &format!("{prefix}*")).await?;
+
+        let mut results: Vec<Result<Arc<Element>, IndexError>> = Vec::new();
+        for key in keys {
+            // Inbound, outbound and partial join keys share the prefix but start with '$'
+            if key
+                .strip_prefix(&prefix)
+                .is_some_and(|rest| rest.starts_with('$'))
+            {
+                continue;
+            }
+            match self.get_element_from_committed(&key).await {
+                Ok(Some(stored)) => {
+                    let element: Element = stored.into();
+                    results.push(Ok(Arc::new(element)));
+                }
+                Ok(None) => continue,
+                Err(e) => results.push(Err(e)),
+            }
+        }
+
+        Ok(Box::pin(futures::stream::iter(results)))
+    }
+
     async fn set_joins(&self, match_path: &MatchPath, joins: &Vec<Arc<QueryJoin>>) {
         let joins_by_label = extract_join_spec_by_label(match_path, joins);
         let mut join_spec_by_label = self.join_spec_by_label.write().await;
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
 use drasi_core::{
@@ -36,6 +37,7 @@ pub struct GarnetFutureQueue {
     query_id: Arc<str>,
     connection: MultiplexedConnection,
     session_state: Arc<GarnetSessionState>,
+    state_ttl: Option<Duration>,
 }
 
 impl GarnetFutureQueue {
@@ -49,9 +51,22 @@ impl GarnetFutureQueue {
             query_id: Arc::from(query_id),
             connection,
             session_state,
+            state_ttl: None,
         }
     }
 
+    /// Expire the per-group index of a scheduled future `ttl` after its due time.
+    ///
+    /// An index holding several futures expires `ttl` after the latest due time
+    /// pushed to it, whichever session pushed it.
+    ///
+    /// Acts as a safety net for state orphaned by a query that is never restarted;
+    /// the due-time queue itself never expires.
+    pub fn with_state_ttl(mut self, ttl: Duration) -> Self {
+        self.state_ttl = Some(ttl);
+        self
+    }
+
     fn get_queue_key(&self) -> String {
         format!("fqi:{{{}}}", self.query_id)
     }
@@ -159,6 +174,9 @@ impl FutureQueue for GarnetFutureQueue {
                 .into_iter()
                 .next()
                 .ok_or_else(|| IndexError::other(std::io::Error::other("empty redis args")))?;
+            if let Some(ttl) = self.state_ttl {
+                buffer.expire_at(index_key.clone(), due_time / 1000 + ttl.as_secs());
+            }
             buffer.set_add(index_key, ref_bytes_flat);
             buffer.zset_add(queue_key, ctx_bytes_flat, due_time as f64);
         }
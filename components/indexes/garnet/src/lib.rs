@@ -107,6 +107,61 @@ impl ClearByPattern for MultiplexedConnection {
     }
 }
 
+/// Collect all keys matching `pattern` using `SCAN`.
+async fn scan_keys(con: &MultiplexedConnection, pattern: &str) -> Result<Vec<String>, IndexError> {
+    let mut con = con.clone();
+    let mut keys = Vec::new();
+
+    let mut cursor = "0".to_string();
+    loop {
+        let mut cmd = cmd("SCAN");
+        let cmd = cmd.arg(remove_surrounding_quotes(&cursor));
+        let cmd = cmd.arg("MATCH");
+        let cmd = cmd.arg(pattern);
+        let cmd = cmd.arg("COUNT");
+        let cmd = cmd.arg(100);
+
+        let result = match cmd
+            .query_async::<MultiplexedConnection, Vec<redis::Value>>(&mut con)
+            .await
+        {
+            Ok(v) => v,
+            Err(e) => return Err(IndexError::other(e)),
+        };
+
+        if result.len() < 2 {
+            break;
+        }
+
+        match &result[0] {
+            redis::Value::Status(s) => {
+                cursor = s.clone();
+            }
+            redis::Value::Data(d) => {
+                if let Ok(s) = String::from_utf8(d.to_vec()) {
+                    cursor = s;
+                }
+            }
+            _ => (),
+        }
+
+        if let redis::Value::Bulk(b) = &result[1] {
+            for k in b {
+                if let redis::Value::Data(d) = k {
+                    if let Ok(k) = String::from_utf8(d.to_vec()) {
+                        keys.push(remove_surrounding_quotes(&k).to_string());
+                    }
+                }
+            }
+        }
+
+        if cursor == "0" {
+            break;
+        }
+    }
+    Ok(keys)
+}
+
 fn remove_surrounding_quotes(s: &str) -> &str {
     if s.len() >= 2
         && (s.starts_with('"') && s.ends_with('"') || s.starts_with('\'') && s.ends_with('\''))
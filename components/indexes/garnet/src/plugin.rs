@@ -33,6 +33,7 @@
 use async_trait::async_trait;
 use drasi_core::interface::{IndexBackendPlugin, IndexError, IndexSet};
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::element_index::GarnetElementIndex;
 use crate::future_queue::GarnetFutureQueue;
@@ -66,10 +67,25 @@ use crate::session_state::{GarnetSessionControl, GarnetSessionState};
 /// When `cache_size` is specified, a local LRU cache is added in front of Redis
 /// for frequently accessed elements and results. This improves read performance
 /// but adds memory overhead.
+///
+/// # Sharing and failover
+///
+/// All state lives in Redis and every session is committed as a single
+/// MULTI/EXEC pipeline, so a second DrasiLib process pointed at the same
+/// server and query ID can take over a query after the first one stops.
+/// Only one process may run a given query at a time.
+///
+/// # Future state TTL
+///
+/// With [`with_future_state_ttl`](Self::with_future_state_ttl), the index
+/// entries of scheduled futures (e.g. from `drasi.trueLater()`) expire that
+/// long after their due time, so state of queries that are never restarted
+/// does not accumulate.
 pub struct GarnetIndexProvider {
     connection_string: String,
     cache_size: Option<usize>,
     enable_archive: bool,
+    future_state_ttl: Option<Duration>,
 }
 
 impl GarnetIndexProvider {
@@ -99,9 +115,21 @@ impl GarnetIndexProvider {
             connection_string: connection_string.into(),
             cache_size,
             enable_archive,
+            future_state_ttl: None,
         }
     }
 
+    /// Expire future-scheduled index state `ttl` after the future's due time.
+    pub fn with_future_state_ttl(mut self, ttl: Duration) -> Self {
+        self.future_state_ttl = Some(ttl);
+        self
+    }
+
+    /// Get the configured future state TTL.
+    pub fn future_state_ttl(&self) -> Option<Duration> {
+        self.future_state_ttl
+    }
+
     /// Get the configured connection string.
     pub fn connection_string(&self) -> &str {
         &self.connection_string
@@ -142,7 +170,11 @@ impl IndexBackendPlugin for GarnetIndexProvider {
             connection.clone(),
             session_state.clone(),
         ));
-        let future_queue = Arc::new(GarnetFutureQueue::new(query_id, connection, session_state));
+        let mut future_queue = GarnetFutureQueue::new(query_id, connection, session_state);
+        if let Some(ttl) = self.future_state_ttl {
+            future_queue = future_queue.with_state_ttl(ttl);
+        }
+        let future_queue = Arc::new(future_queue);
 
         Ok(IndexSet {
             element_index: element_index.clone(),
@@ -184,6 +216,14 @@ mod tests {
         assert_eq!(provider.connection_string(), "redis://myhost:1234"); // DevSkim: ignore DS162092
     }
 
+    #[test]
+    fn test_garnet_index_provider_future_state_ttl() {
+        let provider = GarnetIndexProvider::new("redis://localhost:6379", None, false); // DevSkim: ignore DS162092
+        assert!(provider.future_state_ttl().is_none());
+        let provider = provider.with_future_state_ttl(Duration::from_secs(3600));
+        assert_eq!(provider.future_state_ttl(), Some(Duration::from_secs(3600)));
+    }
+
     #[test]
     fn test_garnet_index_provider_is_volatile() {
         let provider = GarnetIndexProvider::new("redis://localhost:6379", None, false); // DevSkim: ignore DS162092
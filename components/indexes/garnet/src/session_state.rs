@@ -88,6 +88,8 @@ pub struct WriteBuffer {
     /// but we still need to emit `DEL key` on commit so stale Redis members
     /// are removed before the new state is applied.
     cleared_keys: HashSet<String>,
+    /// Absolute expiry (unix seconds) to apply to keys after all writes.
+    expirations: HashMap<String, u64>,
 }
 
 impl WriteBuffer {
@@ -95,9 +97,19 @@ impl WriteBuffer {
         Self {
             keys: HashMap::new(),
             cleared_keys: HashSet::new(),
+            expirations: HashMap::new(),
         }
     }
 
+    // --- Expiry ---
+
+    /// Expire `key` at `unix_secs`, unless it already expires later. When called
+    /// repeatedly for the same key within a session, the latest expiry wins.
+    pub fn expire_at(&mut self, key: String, unix_secs: u64) {
+        let entry = self.expirations.entry(key).or_insert(unix_secs);
+        *entry = (*entry).max(unix_secs);
+    }
+
     // --- String operations ---
 
     pub fn string_set(&mut self, key: String, value: Vec<u8>) {
@@ -323,6 +335,7 @@ impl WriteBuffer {
     /// state, ensuring stale Redis members/fields are removed.
     pub fn drain_into_pipeline(&mut self, pipeline: &mut Pipeline) {
         let cleared = std::mem::take(&mut self.cleared_keys);
+        let expirations = std::mem::take(&mut self.expirations);
 
         for (key, state) in self.keys.drain() {
             match state {
@@ -413,6 +426,25 @@ impl WriteBuffer {
                 }
             }
         }
+
+        // Expiry goes last so it applies to the state written above. EXPIREAT on
+        // a key that no longer exists is a no-op. NX sets the expiry of a key
+        // that has none yet and GT only ever extends an existing one, so a later
+        // session cannot cut short the expiry set for members it did not write.
+        for (key, unix_secs) in expirations {
+            pipeline
+                .cmd("EXPIREAT")
+                .arg(&key)
+                .arg(unix_secs)
+                .arg("NX")
+                .ignore();
+            pipeline
+                .cmd("EXPIREAT")
+                .arg(&key)
+                .arg(unix_secs)
+                .arg("GT")
+                .ignore();
+        }
     }
 }
 
@@ -985,6 +1017,17 @@ mod tests {
         assert!(buf.cleared_keys.is_empty());
     }
 
+    #[test]
+    fn expire_at_keeps_latest_expiry() {
+        let mut buf = WriteBuffer::new();
+        buf.expire_at("s1".into(), 200);
+        buf.expire_at("s1".into(), 100);
+        assert_eq!(buf.expirations.get("s1"), Some(&200));
+        let mut pipeline = redis::pipe();
+        buf.drain_into_pipeline(&mut pipeline);
+        assert!(buf.expirations.is_empty());
+    }
+
     #[test]
     fn drain_del_then_zset_add_clears_stale_members() {
         let mut buf = WriteBuffer::new();
@@ -364,6 +364,45 @@ mod index {
         fqi.clear().await.unwrap();
         shared_tests::index::future_queue::push_overwrite(&fqi, &sc).await;
     }
+
+    #[tokio::test]
+    async fn future_state_ttl_is_not_shortened_by_earlier_future() {
+        use drasi_core::interface::PushType;
+        use drasi_core::models::ElementReference;
+        use std::time::Duration;
+
+        let test_config = GarnetQueryConfig::new(false).await;
+        let query_id = format!("test-{}", Uuid::new_v4());
+        let (fqi, sc) = test_config.build_future_queue(&query_id).await;
+        let fqi = fqi.with_state_ttl(Duration::from_secs(60));
+        fqi.clear().await.unwrap();
+
+        let now_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        let late = now_ms + 2 * 3600 * 1000;
+        let early = now_ms + 3600 * 1000;
+        let element = ElementReference::new("source1", "element1");
+
+        // Late future first, then an earlier one for the same group in a later session
+        for due_time in [late, early] {
+            sc.begin().await.unwrap();
+            fqi.push(PushType::Always, 1, 1, &element, now_ms, due_time)
+                .await
+                .unwrap();
+            sc.commit().await.unwrap();
+        }
+
+        let client = redis::Client::open(test_config.url.as_str()).unwrap();
+        let mut connection = client.get_multiplexed_async_connection().await.unwrap();
+        let expire_time: i64 = redis::cmd("EXPIRETIME")
+            .arg(format!("fqi:{{{query_id}}}:1:1"))
+            .query_async(&mut connection)
+            .await
+            .unwrap();
+        assert_eq!(expire_time, (late / 1000 + 60) as i64);
+    }
 }
 
 mod before {
@@ -195,6 +195,45 @@ impl ElementIndex for RocksDbElementIndex {
         }
     }
 
+    #[tracing::instrument(skip_all, err)]
+    async fn get_all_elements(&self) -> Result<ElementStream, IndexError> {
+        let context = self.context.clone();
+        let task = task::spawn_blocking(move || {
+            let element_cf = context
+                .db
+                .cf_handle(ELEMENTS_CF)
+                .expect("Element CF not found");
+
+            let mut results: Vec<Result<Arc<Element>, IndexError>> = Vec::new();
+            for item in context
+                .db
+                .iterator_cf(&element_cf, rocksdb::IteratorMode::Start)
+            {
+                match item {
+                    Ok((_, value)) => match StoredElementContainer::decode(value.as_ref()) {
+                        Ok(StoredElementContainer {
+                            element: Some(stored),
+                        }) => {
+                            let element: Element = stored.into();
+                            results.push(Ok(Arc::new(element)));
+                        }
+                        Ok(_) => results.push(Err(IndexError::CorruptedData)),
+                        Err(e) => results.push(Err(IndexError::other(e))),
+                    },
+                    Err(e) => results.push(Err(IndexError::other(e))),
+                }
+            }
+            results
+        });
+
+        let results = match task.await {
+            Ok(results) => results,
+            Err(e) => return Err(IndexError::other(e)),
+        };
+
+        Ok(Box::pin(futures::stream::iter(results)))
+    }
+
     #[tracing::instrument(skip_all, err)]
     async fn get_slot_elements_by_inbound(
         &self,
@@ -388,20 +427,18 @@ pub(crate) fn get_elements_cf_options() -> Options {
     elements_opts
 }
 
-/// Collect all column family descriptors needed by the element index.
-pub(crate) fn element_cf_descriptors(
-    options: &RocksIndexOptions,
-) -> Vec<rocksdb::ColumnFamilyDescriptor> {
+/// Collect all column families (name and options) needed by the element index.
+pub(crate) fn element_column_families(options: &RocksIndexOptions) -> Vec<(&'static str, Options)> {
     let mut cfs = vec![
-        rocksdb::ColumnFamilyDescriptor::new(ELEMENTS_CF, get_elements_cf_options()),
-        rocksdb::ColumnFamilyDescriptor::new(SLOT_CF, get_elements_cf_options()),
-        rocksdb::ColumnFamilyDescriptor::new(INBOUND_CF, get_inout_index_cf_options()),
-        rocksdb::ColumnFamilyDescriptor::new(OUTBOUND_CF, get_inout_index_cf_options()),
-        rocksdb::ColumnFamilyDescriptor::new(PARTIAL_CF, get_partial_cf_options()),
+        (ELEMENTS_CF, get_elements_cf_options()),
+        (SLOT_CF, get_elements_cf_options()),
+        (INBOUND_CF, get_inout_index_cf_options()),
+        (OUTBOUND_CF, get_inout_index_cf_options()),
+        (PARTIAL_CF, get_partial_cf_options()),
     ];
 
     if options.archive_enabled {
-        cfs.push(rocksdb::ColumnFamilyDescriptor::new(
+        cfs.push((
             archive_index::ARCHIVE_CF,
             archive_index::get_archive_cf_options(),
         ));
@@ -354,10 +354,10 @@ pub(crate) fn get_findex_cf_options() -> Options {
     opts
 }
 
-/// Collect all column family descriptors needed by the future queue.
-pub(crate) fn future_queue_cf_descriptors() -> Vec<rocksdb::ColumnFamilyDescriptor> {
+/// Collect all column families (name and options) needed by the future queue.
+pub(crate) fn future_queue_column_families() -> Vec<(&'static str, Options)> {
     vec![
-        rocksdb::ColumnFamilyDescriptor::new(QUEUE_CF, get_fqueue_cf_options()),
-        rocksdb::ColumnFamilyDescriptor::new(INDEX_CF, get_findex_cf_options()),
+        (QUEUE_CF, get_fqueue_cf_options()),
+        (INDEX_CF, get_findex_cf_options()),
     ]
 }
@@ -36,10 +36,12 @@ mod plugin;
 pub mod result_index;
 mod session_state;
 mod storage_models;
+mod tuning;
 
 // Re-export the plugin provider and unified DB opener for easy access
 pub use plugin::open_unified_db;
-pub use plugin::RocksDbIndexProvider;
+pub use plugin::{open_db_at, RocksDbIndexProvider};
+pub use tuning::{RocksDbCompactionStyle, RocksDbTuning, DEFAULT_DB_WRITE_BUFFER_SIZE};
 
 // Re-export session types
 pub use session_state::RocksDbSessionControl;
@@ -29,16 +29,31 @@
 //!     .with_index_provider(Arc::new(provider))
 //!     .build()?;
 //! ```
+//!
+//! Database paths can be overridden per query and compaction tuned with
+//! [`RocksDbTuning`]:
+//!
+//! ```ignore
+//! use drasi_index_rocksdb::{RocksDbCompactionStyle, RocksDbIndexProvider, RocksDbTuning};
+//!
+//! let provider = RocksDbIndexProvider::new("/data/drasi", false, false)
+//!     .with_query_path("hot-query", "/fast-ssd/drasi/hot-query")
+//!     .with_tuning(
+//!         RocksDbTuning::default().with_compaction_style(RocksDbCompactionStyle::Universal),
+//!     );
+//! ```
 
 use async_trait::async_trait;
 use drasi_core::interface::{IndexBackendPlugin, IndexError, IndexSet};
-use rocksdb::{OptimisticTransactionDB, Options};
-use std::path::PathBuf;
+use rocksdb::{ColumnFamilyDescriptor, OptimisticTransactionDB, Options, DB};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use crate::element_index::{self, RocksDbElementIndex, RocksIndexOptions};
 use crate::future_queue::{self, RocksDbFutureQueue};
 use crate::result_index::{self, RocksDbResultIndex};
+use crate::tuning::RocksDbTuning;
 use crate::{RocksDbSessionControl, RocksDbSessionState};
 
 /// Open a unified RocksDB database with all column families needed for a query.
@@ -59,23 +74,42 @@ pub fn open_unified_db(
     path: &str,
     query_id: &str,
     options: &RocksIndexOptions,
+) -> Result<Arc<OptimisticTransactionDB>, IndexError> {
+    open_db_at(
+        &PathBuf::from(path).join(query_id),
+        options,
+        &RocksDbTuning::default(),
+    )
+}
+
+/// Open a unified RocksDB database for a query at an explicit directory.
+///
+/// Same as [`open_unified_db`], but takes the full database directory and
+/// applies the given [`RocksDbTuning`] to the database and every column family.
+pub fn open_db_at(
+    db_path: &Path,
+    options: &RocksIndexOptions,
+    tuning: &RocksDbTuning,
 ) -> Result<Arc<OptimisticTransactionDB>, IndexError> {
     let mut db_opts = Options::default();
     db_opts.create_if_missing(true);
     db_opts.create_missing_column_families(true);
-    db_opts.set_db_write_buffer_size(128 * 1024 * 1024);
     db_opts.set_use_direct_reads(options.direct_io);
     db_opts.set_use_direct_io_for_flush_and_compaction(options.direct_io);
+    tuning.apply_to_db(&mut db_opts);
 
-    let db_path = PathBuf::from(path).join(query_id);
     let db_path = match db_path.to_str() {
         Some(p) => p.to_string(),
         None => return Err(IndexError::NotSupported),
     };
 
-    let mut cfs = element_index::element_cf_descriptors(options);
-    cfs.extend(result_index::result_cf_descriptors());
-    cfs.extend(future_queue::future_queue_cf_descriptors());
+    let mut cfs = element_index::element_column_families(options);
+    cfs.extend(result_index::result_column_families());
+    cfs.extend(future_queue::future_queue_column_families());
+    let cfs = cfs.into_iter().map(|(name, mut cf_opts)| {
+        tuning.apply_to_cf(&mut cf_opts);
+        ColumnFamilyDescriptor::new(name, cf_opts)
+    });
 
     let db = OptimisticTransactionDB::open_cf_descriptors(&db_opts, db_path, cfs)
         .map_err(IndexError::other)?;
@@ -93,6 +127,8 @@ pub fn open_unified_db(
 /// - `path`: Base directory for RocksDB data files
 /// - `enable_archive`: Enable archive index for `past()` function support
 /// - `direct_io`: Use direct I/O for better performance on SSDs
+/// - `tuning`: Write buffer and compaction settings (see [`RocksDbTuning`])
+/// - per-query paths: Override the directory of individual queries
 ///
 /// # Directory Structure
 ///
@@ -101,10 +137,20 @@ pub fn open_unified_db(
 /// {path}/
 ///   {query_id}/   - Single unified database with all column families
 /// ```
+///
+/// # Migrating from the in-memory store
+///
+/// The in-memory store keeps nothing on disk, so there is no data to convert.
+/// Assign the RocksDB backend to the query; on its next start the query
+/// bootstraps from its sources into the new database, and later restarts
+/// reopen the same directory. Use [`delete_query_state`](Self::delete_query_state)
+/// to discard persisted state and start over.
 pub struct RocksDbIndexProvider {
     path: PathBuf,
     enable_archive: bool,
     direct_io: bool,
+    tuning: RocksDbTuning,
+    query_paths: HashMap<String, PathBuf>,
 }
 
 impl RocksDbIndexProvider {
@@ -126,9 +172,27 @@ impl RocksDbIndexProvider {
             path: path.into(),
             enable_archive,
             direct_io,
+            tuning: RocksDbTuning::default(),
+            query_paths: HashMap::new(),
         }
     }
 
+    /// Apply write buffer and compaction tuning to every query database.
+    pub fn with_tuning(mut self, tuning: RocksDbTuning) -> Self {
+        self.tuning = tuning;
+        self
+    }
+
+    /// Store the database of `query_id` at `path` instead of `{path}/{query_id}`.
+    pub fn with_query_path(
+        mut self,
+        query_id: impl Into<String>,
+        path: impl Into<PathBuf>,
+    ) -> Self {
+        self.query_paths.insert(query_id.into(), path.into());
+        self
+    }
+
     /// Get the configured path.
     pub fn path(&self) -> &PathBuf {
         &self.path
@@ -143,20 +207,58 @@ impl RocksDbIndexProvider {
     pub fn is_direct_io_enabled(&self) -> bool {
         self.direct_io
     }
+
+    /// Get the tuning applied to query databases.
+    pub fn tuning(&self) -> &RocksDbTuning {
+        &self.tuning
+    }
+
+    /// Directory holding the database of `query_id`.
+    pub fn db_path(&self, query_id: &str) -> PathBuf {
+        self.query_paths
+            .get(query_id)
+            .cloned()
+            .unwrap_or_else(|| self.path.join(query_id))
+    }
+
+    /// Whether a database for `query_id` already exists on disk.
+    pub fn has_persisted_state(&self, query_id: &str) -> bool {
+        self.db_path(query_id).join("CURRENT").is_file()
+    }
+
+    /// Permanently delete the persisted state of `query_id`.
+    ///
+    /// The query must not be running, since its database would still be open.
+    pub fn delete_query_state(&self, query_id: &str) -> Result<(), IndexError> {
+        let db_path = self.db_path(query_id);
+        if !db_path.exists() {
+            return Ok(());
+        }
+        DB::destroy(&Options::default(), &db_path).map_err(IndexError::other)?;
+        // destroy() leaves the (now empty) directory behind
+        if let Err(e) = std::fs::remove_dir(&db_path) {
+            log::debug!(
+                "Could not remove RocksDB directory '{}': {e}",
+                db_path.display()
+            );
+        }
+        Ok(())
+    }
 }
 
 #[async_trait]
 impl IndexBackendPlugin for RocksDbIndexProvider {
     async fn create_index_set(&self, query_id: &str) -> Result<IndexSet, IndexError> {
-        let path = self.path.to_string_lossy().to_string();
+        let db_path = self.db_path(query_id);
         let options = RocksIndexOptions {
             archive_enabled: self.enable_archive,
             direct_io: self.direct_io,
         };
 
-        let db = open_unified_db(&path, query_id, &options).map_err(|e| {
+        let db = open_db_at(&db_path, &options, &self.tuning).map_err(|e| {
             log::error!(
-                "Failed to open unified RocksDB for query '{query_id}' at path '{path}': {e}"
+                "Failed to open unified RocksDB for query '{query_id}' at path '{}': {e}",
+                db_path.display()
             );
             e
         })?;
@@ -263,4 +365,54 @@ mod tests {
         let result = open_unified_db(&path, "test_query", &options);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_db_path_uses_query_override() {
+        let provider = RocksDbIndexProvider::new("/data/drasi", false, false)
+            .with_query_path("hot", "/fast/hot");
+        assert_eq!(provider.db_path("hot"), PathBuf::from("/fast/hot"));
+        assert_eq!(provider.db_path("cold"), PathBuf::from("/data/drasi/cold"));
+    }
+
+    #[tokio::test]
+    async fn test_create_index_set_with_tuning_and_query_path() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let custom = temp_dir.path().join("custom").join("q1");
+        let provider = RocksDbIndexProvider::new(temp_dir.path().join("base"), false, false)
+            .with_query_path("q1", &custom)
+            .with_tuning(
+                RocksDbTuning::default()
+                    .with_max_background_jobs(2)
+                    .with_compaction_style(crate::RocksDbCompactionStyle::Universal)
+                    .with_level_zero_file_num_compaction_trigger(8),
+            );
+
+        assert!(!provider.has_persisted_state("q1"));
+        let index_set = provider.create_index_set("q1").await;
+        assert!(index_set.is_ok());
+        assert!(custom.join("CURRENT").is_file());
+        assert!(provider.has_persisted_state("q1"));
+        assert!(!temp_dir.path().join("base").join("q1").exists());
+    }
+
+    #[tokio::test]
+    async fn test_delete_query_state() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let provider = RocksDbIndexProvider::new(temp_dir.path(), false, false);
+
+        let index_set = provider
+            .create_index_set("q1")
+            .await
+            .expect("create index set");
+        drop(index_set);
+        assert!(provider.has_persisted_state("q1"));
+
+        provider.delete_query_state("q1").expect("delete state");
+        assert!(!provider.has_persisted_state("q1"));
+
+        // Deleting state that does not exist is a no-op
+        provider
+            .delete_query_state("missing")
+            .expect("delete missing");
+    }
 }
@@ -387,12 +387,12 @@ pub(crate) fn get_metadata_cf_options() -> Options {
     values_opts
 }
 
-/// Collect all column family descriptors needed by the result index.
-pub(crate) fn result_cf_descriptors() -> Vec<rocksdb::ColumnFamilyDescriptor> {
+/// Collect all column families (name and options) needed by the result index.
+pub(crate) fn result_column_families() -> Vec<(&'static str, Options)> {
     vec![
-        rocksdb::ColumnFamilyDescriptor::new(VALUES_CF, get_value_cf_options()),
-        rocksdb::ColumnFamilyDescriptor::new(SETS_CF, get_lss_cf_options()),
-        rocksdb::ColumnFamilyDescriptor::new(METADATA_CF, get_metadata_cf_options()),
+        (VALUES_CF, get_value_cf_options()),
+        (SETS_CF, get_lss_cf_options()),
+        (METADATA_CF, get_metadata_cf_options()),
     ]
 }
 
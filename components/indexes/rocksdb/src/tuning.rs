@@ -0,0 +1,200 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Write-buffer and compaction tuning for RocksDB query databases.
+
+use rocksdb::{DBCompactionStyle, Options};
+
+/// Default total memtable budget per query database (128 MiB).
+pub const DEFAULT_DB_WRITE_BUFFER_SIZE: usize = 128 * 1024 * 1024;
+
+/// Compaction strategy applied to every column family of a query database.
+///
+/// FIFO compaction is deliberately not offered: it drops the oldest files once
+/// a size limit is reached, which would silently discard live element, result
+/// and archive state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RocksDbCompactionStyle {
+    /// Leveled compaction (RocksDB default). Lowest space amplification.
+    Level,
+    /// Universal compaction. Lower write amplification for write-heavy workloads.
+    Universal,
+}
+
+impl From<RocksDbCompactionStyle> for DBCompactionStyle {
+    fn from(style: RocksDbCompactionStyle) -> Self {
+        match style {
+            RocksDbCompactionStyle::Level => DBCompactionStyle::Level,
+            RocksDbCompactionStyle::Universal => DBCompactionStyle::Universal,
+        }
+    }
+}
+
+/// Tuning knobs for the RocksDB database backing each query.
+///
+/// Fields left as `None` keep the RocksDB defaults. Database-wide settings are
+/// applied to the DB options; compaction settings are applied to every column
+/// family, since RocksDB configures compaction per column family.
+///
+/// # Example
+///
+/// ```ignore
+/// use drasi_index_rocksdb::{RocksDbCompactionStyle, RocksDbIndexProvider, RocksDbTuning};
+///
+/// let provider = RocksDbIndexProvider::new("/data/drasi", false, false).with_tuning(
+///     RocksDbTuning::default()
+///         .with_max_background_jobs(4)
+///         .with_compaction_style(RocksDbCompactionStyle::Universal),
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RocksDbTuning {
+    /// Total memtable size across all column families before flushing.
+    pub db_write_buffer_size: usize,
+    /// Maximum number of concurrent background flush and compaction jobs.
+    pub max_background_jobs: Option<i32>,
+    /// Compaction strategy.
+    pub compaction_style: Option<RocksDbCompactionStyle>,
+    /// Number of level-0 files that triggers a compaction.
+    pub level_zero_file_num_compaction_trigger: Option<i32>,
+    /// Target size of level-1 SST files.
+    pub target_file_size_base: Option<u64>,
+    /// Files older than this are compacted even if no other trigger fires.
+    pub periodic_compaction_seconds: Option<u64>,
+    /// Disable automatic compactions (e.g. during a bulk bootstrap).
+    pub disable_auto_compactions: bool,
+}
+
+impl Default for RocksDbTuning {
+    fn default() -> Self {
+        Self {
+            db_write_buffer_size: DEFAULT_DB_WRITE_BUFFER_SIZE,
+            max_background_jobs: None,
+            compaction_style: None,
+            level_zero_file_num_compaction_trigger: None,
+            target_file_size_base: None,
+            periodic_compaction_seconds: None,
+            disable_auto_compactions: false,
+        }
+    }
+}
+
+impl RocksDbTuning {
+    /// Set the total memtable budget in bytes.
+    pub fn with_db_write_buffer_size(mut self, bytes: usize) -> Self {
+        self.db_write_buffer_size = bytes;
+        self
+    }
+
+    /// Set the maximum number of background jobs.
+    pub fn with_max_background_jobs(mut self, jobs: i32) -> Self {
+        self.max_background_jobs = Some(jobs);
+        self
+    }
+
+    /// Set the compaction strategy.
+    pub fn with_compaction_style(mut self, style: RocksDbCompactionStyle) -> Self {
+        self.compaction_style = Some(style);
+        self
+    }
+
+    /// Set the number of level-0 files that triggers a compaction.
+    pub fn with_level_zero_file_num_compaction_trigger(mut self, files: i32) -> Self {
+        self.level_zero_file_num_compaction_trigger = Some(files);
+        self
+    }
+
+    /// Set the target size of level-1 SST files in bytes.
+    pub fn with_target_file_size_base(mut self, bytes: u64) -> Self {
+        self.target_file_size_base = Some(bytes);
+        self
+    }
+
+    /// Force files older than `seconds` through compaction.
+    pub fn with_periodic_compaction_seconds(mut self, seconds: u64) -> Self {
+        self.periodic_compaction_seconds = Some(seconds);
+        self
+    }
+
+    /// Enable or disable automatic compactions.
+    pub fn with_auto_compactions(mut self, enabled: bool) -> Self {
+        self.disable_auto_compactions = !enabled;
+        self
+    }
+
+    /// Apply the database-wide settings.
+    pub(crate) fn apply_to_db(&self, opts: &mut Options) {
+        opts.set_db_write_buffer_size(self.db_write_buffer_size);
+        if let Some(jobs) = self.max_background_jobs {
+            opts.set_max_background_jobs(jobs);
+        }
+        self.apply_to_cf(opts);
+    }
+
+    /// Apply the per column family compaction settings.
+    pub(crate) fn apply_to_cf(&self, opts: &mut Options) {
+        if let Some(style) = self.compaction_style {
+            opts.set_compaction_style(style.into());
+        }
+        if let Some(files) = self.level_zero_file_num_compaction_trigger {
+            opts.set_level_zero_file_num_compaction_trigger(files);
+        }
+        if let Some(bytes) = self.target_file_size_base {
+            opts.set_target_file_size_base(bytes);
+        }
+        if let Some(seconds) = self.periodic_compaction_seconds {
+            opts.set_periodic_compaction_seconds(seconds);
+        }
+        if self.disable_auto_compactions {
+            opts.set_disable_auto_compactions(true);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_matches_previous_hardcoded_settings() {
+        let tuning = RocksDbTuning::default();
+        assert_eq!(tuning.db_write_buffer_size, 128 * 1024 * 1024);
+        assert!(tuning.max_background_jobs.is_none());
+        assert!(tuning.compaction_style.is_none());
+        assert!(!tuning.disable_auto_compactions);
+    }
+
+    #[test]
+    fn test_builder_methods() {
+        let tuning = RocksDbTuning::default()
+            .with_db_write_buffer_size(64)
+            .with_max_background_jobs(4)
+            .with_compaction_style(RocksDbCompactionStyle::Universal)
+            .with_level_zero_file_num_compaction_trigger(8)
+            .with_target_file_size_base(1024)
+            .with_periodic_compaction_seconds(3600)
+            .with_auto_compactions(false);
+
+        assert_eq!(tuning.db_write_buffer_size, 64);
+        assert_eq!(tuning.max_background_jobs, Some(4));
+        assert_eq!(
+            tuning.compaction_style,
+            Some(RocksDbCompactionStyle::Universal)
+        );
+        assert_eq!(tuning.level_zero_file_num_compaction_trigger, Some(8));
+        assert_eq!(tuning.target_file_size_base, Some(1024));
+        assert_eq!(tuning.periodic_compaction_seconds, Some(3600));
+        assert!(tuning.disable_auto_compactions);
+    }
+}
@@ -0,0 +1,188 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Adapters that register plugin descriptors in a drasi-lib
+//! [`ComponentRegistry`].
+//!
+//! Statically linked plugin crates expose their descriptors (for example
+//! `drasi_reaction_log::descriptor::LogReactionDescriptor`). Registering them
+//! lets [`DrasiLib`](drasi_lib::DrasiLib) and the configuration file loader
+//! create those components by kind from JSON configuration:
+//!
+//! ```rust,ignore
+//! use drasi_lib::ComponentRegistry;
+//! use drasi_plugin_sdk::ComponentRegistryExt;
+//!
+//! let mut registry = ComponentRegistry::new();
+//! registry
+//!     .register_source_descriptor(MockSourceDescriptor)
+//!     .register_reaction_descriptor(LogReactionDescriptor);
+//! ```
+
+use async_trait::async_trait;
+use drasi_lib::reactions::Reaction;
+use drasi_lib::sources::Source;
+use drasi_lib::{ComponentRegistry, ReactionFactory, SourceFactory};
+
+use crate::descriptor::{ReactionPluginDescriptor, SourcePluginDescriptor};
+use crate::registration::PluginRegistration;
+
+/// [`SourceFactory`] backed by a [`SourcePluginDescriptor`].
+pub struct SourceDescriptorFactory(Box<dyn SourcePluginDescriptor>);
+
+impl SourceDescriptorFactory {
+    pub fn new(descriptor: Box<dyn SourcePluginDescriptor>) -> Self {
+        Self(descriptor)
+    }
+}
+
+#[async_trait]
+impl SourceFactory for SourceDescriptorFactory {
+    async fn create_source(
+        &self,
+        id: &str,
+        config: &serde_json::Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn Source>> {
+        self.0.create_source(id, config, auto_start).await
+    }
+}
+
+/// [`ReactionFactory`] backed by a [`ReactionPluginDescriptor`].
+pub struct ReactionDescriptorFactory(Box<dyn ReactionPluginDescriptor>);
+
+impl ReactionDescriptorFactory {
+    pub fn new(descriptor: Box<dyn ReactionPluginDescriptor>) -> Self {
+        Self(descriptor)
+    }
+}
+
+#[async_trait]
+impl ReactionFactory for ReactionDescriptorFactory {
+    async fn create_reaction(
+        &self,
+        id: &str,
+        query_ids: Vec<String>,
+        config: &serde_json::Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn Reaction>> {
+        self.0
+            .create_reaction(id, query_ids, config, auto_start)
+            .await
+    }
+}
+
+/// Registers plugin descriptors in a [`ComponentRegistry`] under their kind.
+pub trait ComponentRegistryExt {
+    /// Register a source descriptor under [`SourcePluginDescriptor::kind`].
+    fn register_source_descriptor(
+        &mut self,
+        descriptor: impl SourcePluginDescriptor + 'static,
+    ) -> &mut Self;
+
+    /// Register a reaction descriptor under [`ReactionPluginDescriptor::kind`].
+    fn register_reaction_descriptor(
+        &mut self,
+        descriptor: impl ReactionPluginDescriptor + 'static,
+    ) -> &mut Self;
+
+    /// Register every source and reaction descriptor of a plugin registration.
+    ///
+    /// Bootstrap and identity provider descriptors are not components and are ignored.
+    fn register_plugin(&mut self, registration: PluginRegistration) -> &mut Self;
+}
+
+impl ComponentRegistryExt for ComponentRegistry {
+    fn register_source_descriptor(
+        &mut self,
+        descriptor: impl SourcePluginDescriptor + 'static,
+    ) -> &mut Self {
+        let kind = descriptor.kind().to_string();
+        self.register_source(kind, SourceDescriptorFactory::new(Box::new(descriptor)))
+    }
+
+    fn register_reaction_descriptor(
+        &mut self,
+        descriptor: impl ReactionPluginDescriptor + 'static,
+    ) -> &mut Self {
+        let kind = descriptor.kind().to_string();
+        self.register_reaction(kind, ReactionDescriptorFactory::new(Box::new(descriptor)))
+    }
+
+    fn register_plugin(&mut self, registration: PluginRegistration) -> &mut Self {
+        for descriptor in registration.sources {
+            let kind = descriptor.kind().to_string();
+            self.register_source(kind, SourceDescriptorFactory::new(descriptor));
+        }
+        for descriptor in registration.reactions {
+            let kind = descriptor.kind().to_string();
+            self.register_reaction(kind, ReactionDescriptorFactory::new(descriptor));
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FailingSource;
+
+    #[async_trait]
+    impl SourcePluginDescriptor for FailingSource {
+        fn kind(&self) -> &str {
+            "failing"
+        }
+        fn config_version(&self) -> &str {
+            "1.0.0"
+        }
+        fn config_schema_json(&self) -> String {
+            "{}".to_string()
+        }
+        fn config_schema_name(&self) -> &str {
+            "FailingSourceConfig"
+        }
+        async fn create_source(
+            &self,
+            id: &str,
+            config_json: &serde_json::Value,
+            _auto_start: bool,
+        ) -> anyhow::Result<Box<dyn Source>> {
+            anyhow::bail!("cannot create {id} from {config_json}")
+        }
+    }
+
+    #[tokio::test]
+    async fn test_register_descriptor_by_kind() {
+        let mut registry = ComponentRegistry::new();
+        registry.register_source_descriptor(FailingSource);
+        assert_eq!(registry.source_kinds(), vec!["failing"]);
+
+        let err = registry
+            .create_source("failing", "s1", &serde_json::json!({"a": 1}), true)
+            .await
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains(r#"cannot create s1 from {"a":1}"#), "{err}");
+    }
+
+    #[test]
+    fn test_register_plugin() {
+        let mut registry = ComponentRegistry::new();
+        registry.register_plugin(PluginRegistration::new().with_source(Box::new(FailingSource)));
+        assert!(registry.has_source_kind("failing"));
+        assert!(registry.reaction_kinds().is_empty());
+    }
+}
@@ -51,6 +51,9 @@ pub enum FfiChangeOp {
 }
 
 /// Dispatch mode, FFI-safe.
+///
+/// There is no batched variant: sources in `DispatchMode::Batched` report
+/// `Channel`, and their changes cross the boundary one event at a time.
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FfiDispatchMode {
@@ -128,10 +128,24 @@ fn component_status_to_ffi(s: ComponentStatus) -> FfiComponentStatus {
     }
 }
 
+/// Report a plugin source's dispatch mode to the host.
+///
+/// [`FfiDispatchMode`] has no batched variant, so a source in
+/// [`DispatchMode::Batched`] is reported as `Channel`. Its changes are still
+/// batched by the dispatcher inside the plugin, but the push forwarder hands
+/// them to the host one event at a time, so host-side subscribers never see
+/// the batches. The host only uses the mode to decide whether to apply
+/// backpressure, which both modes do.
 fn dispatch_mode_to_ffi(m: DispatchMode) -> FfiDispatchMode {
     match m {
         DispatchMode::Broadcast => FfiDispatchMode::Broadcast,
         DispatchMode::Channel => FfiDispatchMode::Channel,
+        DispatchMode::Batched { .. } => {
+            log::debug!(
+                "Reporting batched dispatch mode as channel; batches are not kept across the plugin boundary"
+            );
+            FfiDispatchMode::Channel
+        }
     }
 }
 
@@ -2073,13 +2087,13 @@ fn build_source_runtime_context(
     // In the plugin-side context, status updates flow through the FFI lifecycle callback,
     // not through this channel. The receiver is returned so it stays alive.
     let (update_tx, status_rx) = tokio::sync::mpsc::channel(16);
-    let ctx = SourceRuntimeContext {
+    let ctx = SourceRuntimeContext::new(
         instance_id,
-        source_id: component_id,
-        update_tx,
+        component_id,
         state_store,
+        update_tx,
         identity_provider,
-    };
+    );
     (ctx, status_rx)
 }
 
@@ -2111,6 +2125,7 @@ fn build_reaction_runtime_context(
         update_tx,
         state_store,
         identity_provider,
+        metrics: None,
     };
     (ctx, status_rx)
 }
@@ -206,6 +206,7 @@
 
 pub mod config_value;
 pub mod descriptor;
+pub mod factory;
 pub mod ffi;
 pub mod mapper;
 pub mod prelude;
@@ -218,6 +219,7 @@ pub use descriptor::{
     BootstrapPluginDescriptor, IdentityProviderPluginDescriptor, ReactionPluginDescriptor,
     SourcePluginDescriptor,
 };
+pub use factory::ComponentRegistryExt;
 pub use mapper::{ConfigMapper, DtoMapper, MappingError};
 pub use registration::{PluginRegistration, SDK_VERSION};
 pub use resolver::{register_secret_resolver, ResolverError};
@@ -54,6 +54,7 @@ pub use crate::descriptor::{
 };
 
 // Registration
+pub use crate::factory::ComponentRegistryExt;
 pub use crate::registration::PluginRegistration;
 
 // Common re-exports that plugin authors need
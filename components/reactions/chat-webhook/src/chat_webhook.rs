@@ -0,0 +1,617 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use log::{debug, error, info, warn};
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Client, StatusCode};
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::time::Instant;
+
+use drasi_lib::channels::{ComponentStatus, QueryResult, ResultDiff};
+use drasi_lib::managers::log_component_start;
+use drasi_lib::reactions::common::base::{ReactionBase, ReactionBaseParams};
+use drasi_lib::templating::{change_context, TemplateEngine};
+use drasi_lib::Reaction;
+
+use super::config::{ChatPlatform, ChatWebhookReactionConfig, QueryConfig};
+use super::ChatWebhookReactionBuilder;
+
+/// Delay before the first retry of a failed post. Doubles on every retry.
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Upper bound of the delay between retries, also applied to `Retry-After` headers.
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// A rendered message for a single result change.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ChatMessage {
+    pub webhook_url: String,
+    pub text: String,
+}
+
+/// A post to a webhook, combining the messages of one batch window.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ChatPost {
+    pub webhook_url: String,
+    pub text: String,
+}
+
+/// Spaces posts to the same webhook at least `interval` apart.
+pub(crate) struct RateLimiter {
+    interval: Duration,
+    next_allowed: HashMap<String, Instant>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(rate_limit_per_minute: u32) -> Self {
+        let interval = if rate_limit_per_minute == 0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs(60) / rate_limit_per_minute
+        };
+        Self {
+            interval,
+            next_allowed: HashMap::new(),
+        }
+    }
+
+    /// Wait until a post to the webhook is allowed and reserve the slot.
+    pub(crate) async fn acquire(&mut self, webhook_url: &str) {
+        let now = Instant::now();
+        let slot = self
+            .next_allowed
+            .get(webhook_url)
+            .copied()
+            .filter(|next| *next > now)
+            .unwrap_or(now);
+        self.next_allowed
+            .insert(webhook_url.to_string(), slot + self.interval);
+        tokio::time::sleep_until(slot).await;
+    }
+}
+
+/// Slack / Microsoft Teams reaction posts query result changes to incoming webhooks.
+pub struct ChatWebhookReaction {
+    base: ReactionBase,
+    config: ChatWebhookReactionConfig,
+}
+
+impl std::fmt::Debug for ChatWebhookReaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChatWebhookReaction")
+            .field("id", &self.base.id)
+            .field("platform", &self.config.platform)
+            .finish()
+    }
+}
+
+impl ChatWebhookReaction {
+    /// Create a builder for ChatWebhookReaction
+    pub fn builder(id: impl Into<String>) -> ChatWebhookReactionBuilder {
+        ChatWebhookReactionBuilder::new(id)
+    }
+
+    /// Create a new Slack / Microsoft Teams webhook reaction
+    ///
+    /// The event channel is automatically injected when the reaction is added
+    /// to DrasiLib via `add_reaction()`.
+    ///
+    /// # Errors
+    ///
+    /// - Returns error if a webhook URL is missing or not an HTTP(S) URL
+    /// - Returns error if any template has invalid Handlebars syntax
+    /// - Returns error if a route query ID doesn't match any subscribed query
+    pub fn new(
+        id: impl Into<String>,
+        queries: Vec<String>,
+        config: ChatWebhookReactionConfig,
+    ) -> anyhow::Result<Self> {
+        Self::validate_config(&queries, &config)?;
+        Ok(Self::from_builder(id.into(), queries, config, None, true))
+    }
+
+    /// Create from builder (internal method)
+    pub(crate) fn from_builder(
+        id: String,
+        queries: Vec<String>,
+        config: ChatWebhookReactionConfig,
+        priority_queue_capacity: Option<usize>,
+        auto_start: bool,
+    ) -> Self {
+        let mut params = ReactionBaseParams::new(id, queries).with_auto_start(auto_start);
+        if let Some(capacity) = priority_queue_capacity {
+            params = params.with_priority_queue_capacity(capacity);
+        }
+
+        Self {
+            base: ReactionBase::new(params),
+            config,
+        }
+    }
+
+    /// Validate a template by attempting to compile it with Handlebars
+    fn validate_template(template: &str) -> anyhow::Result<()> {
+        if template.is_empty() {
+            return Ok(());
+        }
+        TemplateEngine::validate(template)
+    }
+
+    /// Validate that a webhook URL is an HTTP(S) URL
+    fn validate_webhook_url(url: &str) -> anyhow::Result<()> {
+        if !url.starts_with("https://") && !url.starts_with("http://") {
+            return Err(anyhow::anyhow!(
+                "Validation error: webhook URL must start with http:// or https://"
+            ));
+        }
+        Ok(())
+    }
+
+    /// Validate message templates and webhook overrides in a QueryConfig
+    fn validate_query_config(config: &QueryConfig) -> anyhow::Result<()> {
+        for spec in [&config.added, &config.updated, &config.deleted]
+            .into_iter()
+            .flatten()
+        {
+            Self::validate_template(&spec.template)?;
+            if let Some(url) = &spec.extension.webhook_url {
+                Self::validate_webhook_url(url)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate configuration: webhook URL, batching, templates and route-query matching
+    pub(crate) fn validate_config(
+        queries: &[String],
+        config: &ChatWebhookReactionConfig,
+    ) -> anyhow::Result<()> {
+        if config.webhook_url.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Validation error: webhook_url cannot be empty"
+            ));
+        }
+        Self::validate_webhook_url(&config.webhook_url)?;
+
+        if config.max_batch_size == 0 {
+            return Err(anyhow::anyhow!(
+                "Validation error: max_batch_size must be greater than 0"
+            ));
+        }
+
+        for (query_id, route_config) in &config.routes {
+            Self::validate_query_config(route_config)
+                .map_err(|e| anyhow::anyhow!("Invalid template in route '{query_id}': {e}"))?;
+        }
+
+        if let Some(default_template) = &config.default_template {
+            Self::validate_query_config(default_template)
+                .map_err(|e| anyhow::anyhow!("Invalid default template: {e}"))?;
+        }
+
+        if !config.routes.is_empty() && !queries.is_empty() {
+            for route_query in config.routes.keys() {
+                let dotted_route = format!(".{route_query}");
+                let matches = queries
+                    .iter()
+                    .any(|q| q == route_query || q.ends_with(&dotted_route));
+                if !matches {
+                    return Err(anyhow::anyhow!(
+                        "Route '{route_query}' does not match any subscribed query. Subscribed queries: {queries:?}"
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Find the route for a query, falling back to the last dotted segment and then the default template
+    fn query_config<'a>(
+        config: &'a ChatWebhookReactionConfig,
+        query_name: &str,
+    ) -> Option<&'a QueryConfig> {
+        config
+            .routes
+            .get(query_name)
+            .or_else(|| {
+                if query_name.contains('.') {
+                    query_name
+                        .rsplit('.')
+                        .next()
+                        .and_then(|name| config.routes.get(name))
+                } else {
+                    None
+                }
+            })
+            .or(config.default_template.as_ref())
+    }
+
+    /// Default message text for a change
+    fn default_text(query_name: &str, operation: &str, context: &Map<String, Value>) -> String {
+        let row = |key: &str| {
+            context
+                .get(key)
+                .map(|value| value.to_string())
+                .unwrap_or_default()
+        };
+        match operation {
+            "ADD" => format!("Added to `{query_name}`: {}", row("after")),
+            "DELETE" => format!("Removed from `{query_name}`: {}", row("before")),
+            _ => format!(
+                "Updated in `{query_name}`: {} → {}",
+                row("before"),
+                row("after")
+            ),
+        }
+    }
+
+    /// Convert a query result into one message per change.
+    ///
+    /// Without a matching route or default template every change is posted
+    /// with the default text. With one, only operations that have a template
+    /// are posted; an empty template uses the default text.
+    pub(crate) fn render_messages(
+        engine: &TemplateEngine,
+        config: &ChatWebhookReactionConfig,
+        query_result: &QueryResult,
+        reaction_id: &str,
+    ) -> Vec<ChatMessage> {
+        let query_name = &query_result.query_id;
+        let query_config = Self::query_config(config, query_name);
+        let mut messages = Vec::new();
+
+        for result in &query_result.results {
+            let (spec, operation) = match result {
+                ResultDiff::Add { .. } => (query_config.map(|qc| qc.added.as_ref()), "ADD"),
+                ResultDiff::Update { .. } => (query_config.map(|qc| qc.updated.as_ref()), "UPDATE"),
+                ResultDiff::Delete { .. } => (query_config.map(|qc| qc.deleted.as_ref()), "DELETE"),
+                ResultDiff::Aggregation { .. } => {
+                    (query_config.map(|qc| qc.updated.as_ref()), "AGGREGATION")
+                }
+                ResultDiff::Noop => continue,
+            };
+
+            // A configured route without a template for this operation posts nothing
+            let spec = match spec {
+                Some(None) => continue,
+                Some(Some(spec)) => Some(spec),
+                None => None,
+            };
+
+            let Some(Value::Object(context)) = change_context(query_result, result) else {
+                continue;
+            };
+
+            let text = match spec.map(|s| s.template.as_str()) {
+                Some(template) if !template.is_empty() => match engine.render(template, &context) {
+                    Ok(rendered) => rendered,
+                    Err(e) => {
+                        error!(
+                                "[{reaction_id}] Failed to render message for query '{query_name}': {e}. Falling back to default text."
+                            );
+                        Self::default_text(query_name, operation, &context)
+                    }
+                },
+                _ => Self::default_text(query_name, operation, &context),
+            };
+            if text.trim().is_empty() {
+                debug!("[{reaction_id}] Skipping empty message for query '{query_name}'");
+                continue;
+            }
+
+            messages.push(ChatMessage {
+                webhook_url: spec
+                    .and_then(|s| s.extension.webhook_url.clone())
+                    .unwrap_or_else(|| config.webhook_url.clone()),
+                text,
+            });
+        }
+
+        messages
+    }
+
+    /// Combine the messages of a batch window into one post per webhook.
+    ///
+    /// Each post lists at most `max_batch_size` messages; the remaining
+    /// messages for the webhook are summarized as a count.
+    pub(crate) fn batch_posts(messages: Vec<ChatMessage>, max_batch_size: usize) -> Vec<ChatPost> {
+        let mut grouped: Vec<(String, Vec<String>)> = Vec::new();
+        for message in messages {
+            match grouped
+                .iter_mut()
+                .find(|(url, _)| *url == message.webhook_url)
+            {
+                Some((_, texts)) => texts.push(message.text),
+                None => grouped.push((message.webhook_url, vec![message.text])),
+            }
+        }
+
+        grouped
+            .into_iter()
+            .map(|(webhook_url, texts)| {
+                let omitted = texts.len().saturating_sub(max_batch_size);
+                let mut lines: Vec<String> = texts.into_iter().take(max_batch_size).collect();
+                match omitted {
+                    0 => {}
+                    1 => lines.push("…and 1 more change".to_string()),
+                    _ => lines.push(format!("…and {omitted} more changes")),
+                }
+                ChatPost {
+                    webhook_url,
+                    text: lines.join("\n"),
+                }
+            })
+            .collect()
+    }
+
+    /// Request body for a post on the given platform
+    pub(crate) fn request_body(platform: ChatPlatform, text: &str) -> Value {
+        match platform {
+            ChatPlatform::Slack => json!({ "text": text }),
+            ChatPlatform::Teams => json!({
+                "type": "message",
+                "attachments": [{
+                    "contentType": "application/vnd.microsoft.card.adaptive",
+                    "content": {
+                        "$schema": "http://adaptivecards.io/schemas/adaptive-card.json",
+                        "type": "AdaptiveCard",
+                        "version": "1.4",
+                        "body": [{ "type": "TextBlock", "text": text, "wrap": true }]
+                    }
+                }]
+            }),
+        }
+    }
+
+    /// Post to a webhook, retrying connection errors, `429` and `5xx`
+    /// responses. `Retry-After` headers are honoured.
+    pub(crate) async fn post_with_retry(
+        client: &Client,
+        webhook_url: &str,
+        body: &Value,
+        max_retries: u32,
+        reaction_id: &str,
+    ) -> Result<()> {
+        let mut attempt = 0;
+        loop {
+            let retry_after = match client.post(webhook_url).json(body).send().await {
+                Ok(response) if response.status().is_success() => return Ok(()),
+                Ok(response) => {
+                    let status = response.status();
+                    let retry_after = response
+                        .headers()
+                        .get(RETRY_AFTER)
+                        .and_then(|value| value.to_str().ok())
+                        .and_then(|value| value.trim().parse::<u64>().ok())
+                        .map(Duration::from_secs);
+                    let error_body = response.text().await.unwrap_or_default();
+                    let retryable =
+                        status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error();
+                    if !retryable || attempt >= max_retries {
+                        return Err(anyhow!(
+                            "Webhook post failed with status {}: {error_body}",
+                            status.as_u16()
+                        ));
+                    }
+                    warn!(
+                        "[{reaction_id}] Webhook post failed with status {}, retrying: {error_body}",
+                        status.as_u16()
+                    );
+                    retry_after
+                }
+                Err(e) => {
+                    if e.is_builder() || attempt >= max_retries {
+                        return Err(anyhow!("Webhook post failed: {}", e.without_url()));
+                    }
+                    warn!(
+                        "[{reaction_id}] Webhook post failed, retrying: {}",
+                        e.without_url()
+                    );
+                    None
+                }
+            };
+
+            let backoff = INITIAL_BACKOFF.saturating_mul(2u32.saturating_pow(attempt));
+            let delay = retry_after.unwrap_or(backoff).min(MAX_BACKOFF);
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+}
+
+#[async_trait]
+impl Reaction for ChatWebhookReaction {
+    fn id(&self) -> &str {
+        &self.base.id
+    }
+
+    fn type_name(&self) -> &str {
+        "chat-webhook"
+    }
+
+    fn properties(&self) -> HashMap<String, serde_json::Value> {
+        // Webhook URLs embed the credentials, so none of them are exposed
+        let mut config = self.config.clone();
+        config.webhook_url = "***".to_string();
+        for query_config in config
+            .routes
+            .values_mut()
+            .chain(config.default_template.as_mut())
+        {
+            for spec in [
+                &mut query_config.added,
+                &mut query_config.updated,
+                &mut query_config.deleted,
+            ]
+            .into_iter()
+            .flatten()
+            {
+                if spec.extension.webhook_url.is_some() {
+                    spec.extension.webhook_url = Some("***".to_string());
+                }
+            }
+        }
+        match serde_json::to_value(&config) {
+            Ok(serde_json::Value::Object(map)) => map.into_iter().collect(),
+            _ => HashMap::new(),
+        }
+    }
+
+    fn query_ids(&self) -> Vec<String> {
+        self.base.queries.clone()
+    }
+
+    fn auto_start(&self) -> bool {
+        self.base.get_auto_start()
+    }
+
+    async fn initialize(&self, context: drasi_lib::context::ReactionRuntimeContext) {
+        self.base.initialize(context).await;
+    }
+
+    async fn start(&self) -> Result<()> {
+        log_component_start("Chat Webhook Reaction", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Starting,
+                Some("Starting chat webhook reaction".to_string()),
+            )
+            .await;
+
+        let client = match Client::builder()
+            .timeout(Duration::from_millis(self.config.timeout_ms))
+            .build()
+        {
+            Ok(client) => client,
+            Err(e) => {
+                self.base
+                    .set_status(
+                        ComponentStatus::Error,
+                        Some(format!("Failed to create HTTP client: {e}")),
+                    )
+                    .await;
+                return Err(anyhow!("Failed to create HTTP client: {e}"));
+            }
+        };
+
+        self.base
+            .set_status(
+                ComponentStatus::Running,
+                Some("Chat webhook reaction started".to_string()),
+            )
+            .await;
+
+        let priority_queue = self.base.priority_queue.clone();
+        let reaction_id = self.base.id.clone();
+        let config = self.config.clone();
+        let mut shutdown_rx = self.base.create_shutdown_channel().await;
+
+        let processing_task = tokio::spawn(async move {
+            info!("[{reaction_id}] Chat webhook processing task started");
+
+            let engine = TemplateEngine::new();
+            let mut rate_limiter = RateLimiter::new(config.rate_limit_per_minute);
+            let batch_window = Duration::from_millis(config.batch_window_ms);
+
+            loop {
+                let first = tokio::select! {
+                    biased;
+
+                    _ = &mut shutdown_rx => {
+                        debug!("[{reaction_id}] Received shutdown signal, exiting processing loop");
+                        break;
+                    }
+
+                    result = priority_queue.dequeue() => result,
+                };
+
+                // Collect the results arriving within the batch window, so that
+                // bursts such as bootstrap are posted as a few combined messages
+                let mut results = vec![first];
+                let deadline = Instant::now() + batch_window;
+                while let Ok(result) =
+                    tokio::time::timeout_at(deadline, priority_queue.dequeue()).await
+                {
+                    results.push(result);
+                }
+
+                let messages: Vec<ChatMessage> = results
+                    .iter()
+                    .flat_map(|result| {
+                        ChatWebhookReaction::render_messages(&engine, &config, result, &reaction_id)
+                    })
+                    .collect();
+                if messages.is_empty() {
+                    continue;
+                }
+
+                let total = messages.len();
+                let posts = ChatWebhookReaction::batch_posts(messages, config.max_batch_size);
+                debug!(
+                    "[{reaction_id}] Posting {total} changes in {} messages",
+                    posts.len()
+                );
+
+                for post in posts {
+                    rate_limiter.acquire(&post.webhook_url).await;
+                    let body = ChatWebhookReaction::request_body(config.platform, &post.text);
+                    if let Err(e) = ChatWebhookReaction::post_with_retry(
+                        &client,
+                        &post.webhook_url,
+                        &body,
+                        config.max_retries,
+                        &reaction_id,
+                    )
+                    .await
+                    {
+                        error!("[{reaction_id}] Failed to post chat message: {e}");
+                    }
+                }
+            }
+
+            info!("[{reaction_id}] Chat webhook processing task ended");
+        });
+
+        self.base.set_processing_task(processing_task).await;
+
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        self.base.stop_common().await?;
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopped,
+                Some("Chat webhook reaction stopped".to_string()),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn status(&self) -> ComponentStatus {
+        self.base.get_status().await
+    }
+
+    async fn enqueue_query_result(&self, result: QueryResult) -> anyhow::Result<()> {
+        self.base.enqueue_query_result(result).await
+    }
+}
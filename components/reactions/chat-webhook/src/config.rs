@@ -0,0 +1,136 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration types for Slack and Microsoft Teams webhook reactions.
+
+use drasi_lib::reactions::common::TemplateRouting;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+fn default_rate_limit_per_minute() -> u32 {
+    30
+}
+
+fn default_batch_window_ms() -> u64 {
+    2000
+}
+
+fn default_max_batch_size() -> usize {
+    20
+}
+
+fn default_max_retries() -> u32 {
+    3
+}
+
+fn default_timeout_ms() -> u64 {
+    10000
+}
+
+/// Chat platform the webhook belongs to. Determines the request body format.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ChatPlatform {
+    /// Slack incoming webhook, posted as `{"text": ...}` (default).
+    #[default]
+    Slack,
+    /// Microsoft Teams incoming webhook, posted as an Adaptive Card.
+    Teams,
+}
+
+/// Chat-specific extension for template specifications.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct ChatExtension {
+    /// Webhook to post this operation's messages to, e.g. a different channel.
+    /// Defaults to the reaction's `webhook_url`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_url: Option<String>,
+}
+
+/// Type alias for chat template specification using the common generic type.
+///
+/// The template string renders the message text (Slack mrkdwn or Teams
+/// markdown). If it is empty, a default one-line summary of the change is used.
+pub type TemplateSpec = drasi_lib::reactions::common::TemplateSpec<ChatExtension>;
+
+/// Type alias for chat query configuration using the common generic type.
+pub type QueryConfig = drasi_lib::reactions::common::QueryConfig<ChatExtension>;
+
+/// Slack / Microsoft Teams webhook reaction configuration
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ChatWebhookReactionConfig {
+    /// Chat platform of the webhooks
+    #[serde(default)]
+    pub platform: ChatPlatform,
+
+    /// Incoming webhook URL
+    #[serde(default)]
+    pub webhook_url: String,
+
+    /// Maximum number of posts per minute and webhook. 0 disables rate limiting.
+    #[serde(default = "default_rate_limit_per_minute")]
+    pub rate_limit_per_minute: u32,
+
+    /// Time in milliseconds to collect changes before posting them together
+    #[serde(default = "default_batch_window_ms")]
+    pub batch_window_ms: u64,
+
+    /// Maximum number of changes listed in one post. Further changes collected
+    /// in the same batch window are summarized as a count.
+    #[serde(default = "default_max_batch_size")]
+    pub max_batch_size: usize,
+
+    /// Number of retries for posts failing with `429`, `5xx` or a connection error
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+
+    /// Request timeout in milliseconds
+    #[serde(default = "default_timeout_ms")]
+    pub timeout_ms: u64,
+
+    /// Query-specific template configurations
+    #[serde(default)]
+    pub routes: HashMap<String, QueryConfig>,
+
+    /// Default template configuration used when no query-specific route is defined.
+    /// If not set, every change is posted with the default message text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_template: Option<QueryConfig>,
+}
+
+impl Default for ChatWebhookReactionConfig {
+    fn default() -> Self {
+        Self {
+            platform: ChatPlatform::default(),
+            webhook_url: String::new(),
+            rate_limit_per_minute: default_rate_limit_per_minute(),
+            batch_window_ms: default_batch_window_ms(),
+            max_batch_size: default_max_batch_size(),
+            max_retries: default_max_retries(),
+            timeout_ms: default_timeout_ms(),
+            routes: HashMap::new(),
+            default_template: None,
+        }
+    }
+}
+
+impl TemplateRouting<ChatExtension> for ChatWebhookReactionConfig {
+    fn routes(&self) -> &HashMap<String, QueryConfig> {
+        &self.routes
+    }
+
+    fn default_template(&self) -> Option<&QueryConfig> {
+        self.default_template.as_ref()
+    }
+}
@@ -0,0 +1,240 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Descriptor for the Slack / Microsoft Teams webhook reaction plugin.
+
+use drasi_lib::reactions::Reaction;
+use drasi_plugin_sdk::prelude::*;
+use std::collections::HashMap;
+use utoipa::OpenApi;
+
+use crate::config::{ChatExtension, ChatPlatform};
+use crate::ChatWebhookReactionBuilder;
+
+/// DTO for the chat platform.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::chat_webhook::ChatPlatform)]
+#[serde(rename_all = "snake_case")]
+pub enum ChatPlatformDto {
+    Slack,
+    Teams,
+}
+
+impl From<ChatPlatformDto> for ChatPlatform {
+    fn from(dto: ChatPlatformDto) -> Self {
+        match dto {
+            ChatPlatformDto::Slack => ChatPlatform::Slack,
+            ChatPlatformDto::Teams => ChatPlatform::Teams,
+        }
+    }
+}
+
+/// DTO for a chat message template specification.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::chat_webhook::ChatTemplateSpec)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct ChatTemplateSpecDto {
+    /// Handlebars template for the message text.
+    #[serde(default)]
+    pub template: String,
+
+    /// Optional webhook URL override for this operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub webhook_url: Option<ConfigValue<String>>,
+}
+
+/// DTO for per-query chat template configuration.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::chat_webhook::ChatQueryConfig)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct ChatQueryConfigDto {
+    /// Template for ADD operations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub added: Option<ChatTemplateSpecDto>,
+
+    /// Template for UPDATE operations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated: Option<ChatTemplateSpecDto>,
+
+    /// Template for DELETE operations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted: Option<ChatTemplateSpecDto>,
+}
+
+/// Configuration DTO for the Slack / Microsoft Teams webhook reaction plugin.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::chat_webhook::ChatWebhookReactionConfig)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatWebhookReactionConfigDto {
+    /// Chat platform of the webhooks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub platform: Option<ChatPlatformDto>,
+
+    /// Incoming webhook URL.
+    #[schema(value_type = ConfigValueString)]
+    pub webhook_url: ConfigValue<String>,
+
+    /// Maximum number of posts per minute and webhook.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueU32>)]
+    pub rate_limit_per_minute: Option<ConfigValue<u32>>,
+
+    /// Time in milliseconds to collect changes before posting them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueU64>)]
+    pub batch_window_ms: Option<ConfigValue<u64>>,
+
+    /// Maximum number of changes listed in one post.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueUsize>)]
+    pub max_batch_size: Option<ConfigValue<usize>>,
+
+    /// Number of retries for failed posts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueU32>)]
+    pub max_retries: Option<ConfigValue<u32>>,
+
+    /// Request timeout in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueU64>)]
+    pub timeout_ms: Option<ConfigValue<u64>>,
+
+    /// Query-specific template configurations.
+    #[serde(default)]
+    pub routes: HashMap<String, ChatQueryConfigDto>,
+
+    /// Default template configuration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_template: Option<ChatQueryConfigDto>,
+}
+
+fn map_template_spec(
+    mapper: &DtoMapper,
+    dto: &ChatTemplateSpecDto,
+) -> anyhow::Result<crate::TemplateSpec> {
+    Ok(crate::TemplateSpec {
+        template: dto.template.clone(),
+        extension: ChatExtension {
+            webhook_url: mapper.resolve_optional_string(&dto.webhook_url)?,
+        },
+    })
+}
+
+fn map_query_config(
+    mapper: &DtoMapper,
+    dto: &ChatQueryConfigDto,
+) -> anyhow::Result<crate::QueryConfig> {
+    Ok(crate::QueryConfig {
+        added: dto
+            .added
+            .as_ref()
+            .map(|spec| map_template_spec(mapper, spec))
+            .transpose()?,
+        updated: dto
+            .updated
+            .as_ref()
+            .map(|spec| map_template_spec(mapper, spec))
+            .transpose()?,
+        deleted: dto
+            .deleted
+            .as_ref()
+            .map(|spec| map_template_spec(mapper, spec))
+            .transpose()?,
+    })
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(
+    ChatWebhookReactionConfigDto,
+    ChatQueryConfigDto,
+    ChatTemplateSpecDto,
+    ChatPlatformDto,
+)))]
+struct ChatWebhookReactionSchemas;
+
+/// Descriptor for the Slack / Microsoft Teams webhook reaction plugin.
+pub struct ChatWebhookReactionDescriptor;
+
+#[async_trait]
+impl ReactionPluginDescriptor for ChatWebhookReactionDescriptor {
+    fn kind(&self) -> &str {
+        "chat-webhook"
+    }
+
+    fn config_version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn config_schema_name(&self) -> &str {
+        "reaction.chat_webhook.ChatWebhookReactionConfig"
+    }
+
+    fn config_schema_json(&self) -> String {
+        let api = ChatWebhookReactionSchemas::openapi();
+        serde_json::to_string(
+            &api.components
+                .as_ref()
+                .expect("OpenAPI components missing")
+                .schemas,
+        )
+        .expect("Failed to serialize config schema")
+    }
+
+    async fn create_reaction(
+        &self,
+        id: &str,
+        query_ids: Vec<String>,
+        config_json: &serde_json::Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn Reaction>> {
+        let dto: ChatWebhookReactionConfigDto = serde_json::from_value(config_json.clone())?;
+        let mapper = DtoMapper::new();
+
+        let mut builder = ChatWebhookReactionBuilder::new(id)
+            .with_queries(query_ids)
+            .with_auto_start(auto_start)
+            .with_webhook_url(mapper.resolve_string(&dto.webhook_url)?);
+
+        if let Some(platform) = dto.platform {
+            builder = builder.with_platform(platform.into());
+        }
+        if let Some(ref limit) = dto.rate_limit_per_minute {
+            builder = builder.with_rate_limit_per_minute(mapper.resolve_typed(limit)?);
+        }
+        if let Some(ref window) = dto.batch_window_ms {
+            builder = builder.with_batch_window_ms(mapper.resolve_typed(window)?);
+        }
+        if let Some(ref size) = dto.max_batch_size {
+            builder = builder.with_max_batch_size(mapper.resolve_typed(size)?);
+        }
+        if let Some(ref retries) = dto.max_retries {
+            builder = builder.with_max_retries(mapper.resolve_typed(retries)?);
+        }
+        if let Some(ref timeout) = dto.timeout_ms {
+            builder = builder.with_timeout_ms(mapper.resolve_typed(timeout)?);
+        }
+
+        if let Some(ref default_template) = dto.default_template {
+            builder = builder.with_default_template(map_query_config(&mapper, default_template)?);
+        }
+
+        for (query_id, config) in &dto.routes {
+            builder = builder.with_route(query_id, map_query_config(&mapper, config)?);
+        }
+
+        let reaction = builder.build()?;
+        Ok(Box::new(reaction))
+    }
+}
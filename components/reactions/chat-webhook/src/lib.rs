@@ -0,0 +1,176 @@
+#![allow(unexpected_cfgs)]
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Slack / Microsoft Teams webhook reaction plugin for Drasi
+//!
+//! This plugin posts messages to Slack or Microsoft Teams incoming webhooks
+//! when query results change. Messages are rendered from per-operation
+//! Handlebars templates, collected over a short batch window and combined into
+//! a few posts, so bursts such as a query bootstrap do not flood the channel.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use drasi_reaction_chat_webhook::{ChatPlatform, ChatWebhookReaction};
+//!
+//! let reaction = ChatWebhookReaction::builder("my-chat-reaction")
+//!     .with_queries(vec!["query1".to_string()])
+//!     .with_platform(ChatPlatform::Slack)
+//!     .with_webhook_url("https://hooks.slack.com/services/T000/B000/XXXX")
+//!     .build()?;
+//! ```
+
+pub mod chat_webhook;
+pub mod config;
+pub mod descriptor;
+
+pub use chat_webhook::ChatWebhookReaction;
+pub use config::{
+    ChatExtension, ChatPlatform, ChatWebhookReactionConfig, QueryConfig, TemplateSpec,
+};
+
+/// Builder for Slack / Microsoft Teams webhook reaction
+pub struct ChatWebhookReactionBuilder {
+    id: String,
+    queries: Vec<String>,
+    config: ChatWebhookReactionConfig,
+    priority_queue_capacity: Option<usize>,
+    auto_start: bool,
+}
+
+impl ChatWebhookReactionBuilder {
+    /// Create a new chat webhook reaction builder with the given ID
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            queries: Vec::new(),
+            config: ChatWebhookReactionConfig::default(),
+            priority_queue_capacity: None,
+            auto_start: true,
+        }
+    }
+
+    /// Set the query IDs to subscribe to
+    pub fn with_queries(mut self, queries: Vec<String>) -> Self {
+        self.queries = queries;
+        self
+    }
+
+    /// Add a query ID to subscribe to
+    pub fn with_query(mut self, query_id: impl Into<String>) -> Self {
+        self.queries.push(query_id.into());
+        self
+    }
+
+    /// Set the chat platform of the webhooks
+    pub fn with_platform(mut self, platform: ChatPlatform) -> Self {
+        self.config.platform = platform;
+        self
+    }
+
+    /// Set the incoming webhook URL
+    pub fn with_webhook_url(mut self, url: impl Into<String>) -> Self {
+        self.config.webhook_url = url.into();
+        self
+    }
+
+    /// Set the maximum number of posts per minute and webhook (0 disables rate limiting)
+    pub fn with_rate_limit_per_minute(mut self, limit: u32) -> Self {
+        self.config.rate_limit_per_minute = limit;
+        self
+    }
+
+    /// Set the time in milliseconds to collect changes before posting them
+    pub fn with_batch_window_ms(mut self, window_ms: u64) -> Self {
+        self.config.batch_window_ms = window_ms;
+        self
+    }
+
+    /// Set the maximum number of changes listed in one post
+    pub fn with_max_batch_size(mut self, size: usize) -> Self {
+        self.config.max_batch_size = size;
+        self
+    }
+
+    /// Set the number of retries for failed posts
+    pub fn with_max_retries(mut self, retries: u32) -> Self {
+        self.config.max_retries = retries;
+        self
+    }
+
+    /// Set the request timeout in milliseconds
+    pub fn with_timeout_ms(mut self, timeout_ms: u64) -> Self {
+        self.config.timeout_ms = timeout_ms;
+        self
+    }
+
+    /// Set the priority queue capacity
+    pub fn with_priority_queue_capacity(mut self, capacity: usize) -> Self {
+        self.priority_queue_capacity = Some(capacity);
+        self
+    }
+
+    /// Set whether the reaction should auto-start
+    pub fn with_auto_start(mut self, auto_start: bool) -> Self {
+        self.auto_start = auto_start;
+        self
+    }
+
+    /// Add a route configuration for a specific query
+    pub fn with_route(mut self, query_id: impl Into<String>, config: QueryConfig) -> Self {
+        self.config.routes.insert(query_id.into(), config);
+        self
+    }
+
+    /// Set the default template configuration used when no query-specific route is defined
+    pub fn with_default_template(mut self, config: QueryConfig) -> Self {
+        self.config.default_template = Some(config);
+        self
+    }
+
+    /// Set the full configuration at once
+    pub fn with_config(mut self, config: ChatWebhookReactionConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Build the chat webhook reaction
+    pub fn build(self) -> anyhow::Result<ChatWebhookReaction> {
+        ChatWebhookReaction::validate_config(&self.queries, &self.config)?;
+
+        Ok(ChatWebhookReaction::from_builder(
+            self.id,
+            self.queries,
+            self.config,
+            self.priority_queue_capacity,
+            self.auto_start,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests;
+
+/// Dynamic plugin entry point.
+#[cfg(feature = "dynamic-plugin")]
+drasi_plugin_sdk::export_plugin!(
+    plugin_id = "chat-webhook-reaction",
+    core_version = env!("CARGO_PKG_VERSION"),
+    lib_version = env!("CARGO_PKG_VERSION"),
+    plugin_version = env!("CARGO_PKG_VERSION"),
+    source_descriptors = [],
+    reaction_descriptors = [descriptor::ChatWebhookReactionDescriptor],
+    bootstrap_descriptors = [],
+);
@@ -0,0 +1,457 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::chat_webhook::{ChatMessage, ChatPost, RateLimiter};
+use crate::descriptor::ChatWebhookReactionDescriptor;
+use drasi_lib::channels::{QueryResult, ResultDiff};
+use drasi_lib::templating::TemplateEngine;
+use drasi_lib::Reaction;
+use drasi_plugin_sdk::prelude::ReactionPluginDescriptor;
+use serde_json::json;
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+const WEBHOOK: &str = "https://hooks.slack.com/services/T000/B000/XXXX";
+
+fn query_result(query_id: &str, results: Vec<ResultDiff>) -> QueryResult {
+    QueryResult::new(
+        query_id.to_string(),
+        chrono::Utc::now(),
+        results,
+        HashMap::new(),
+    )
+}
+
+fn config() -> ChatWebhookReactionConfig {
+    ChatWebhookReactionConfig {
+        webhook_url: WEBHOOK.to_string(),
+        ..Default::default()
+    }
+}
+
+fn render(config: &ChatWebhookReactionConfig, result: &QueryResult) -> Vec<ChatMessage> {
+    ChatWebhookReaction::render_messages(&TemplateEngine::new(), config, result, "test-reaction")
+}
+
+fn message(url: &str, text: &str) -> ChatMessage {
+    ChatMessage {
+        webhook_url: url.to_string(),
+        text: text.to_string(),
+    }
+}
+
+/// Answer one request per status, returning the raw requests.
+async fn serve(listener: TcpListener, responses: Vec<&'static str>) -> Vec<String> {
+    let mut requests = Vec::new();
+    for response in responses {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut request = Vec::new();
+        let mut buffer = [0u8; 4096];
+        while !request.ends_with(b"}") {
+            match stream.read(&mut buffer).await {
+                Ok(0) | Err(_) => break,
+                Ok(read) => request.extend_from_slice(&buffer[..read]),
+            }
+        }
+        requests.push(String::from_utf8_lossy(&request).to_string());
+
+        let response =
+            format!("HTTP/1.1 {response}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n");
+        let _ = stream.write_all(response.as_bytes()).await;
+    }
+    requests
+}
+
+#[test]
+fn test_chat_builder_defaults() {
+    let reaction = ChatWebhookReactionBuilder::new("test-reaction")
+        .with_webhook_url(WEBHOOK)
+        .build()
+        .unwrap();
+    assert_eq!(reaction.id(), "test-reaction");
+    assert_eq!(reaction.type_name(), "chat-webhook");
+    let props = reaction.properties();
+    assert_eq!(props.get("platform"), Some(&json!("slack")));
+    assert_eq!(props.get("rate_limit_per_minute"), Some(&json!(30)));
+    assert_eq!(props.get("batch_window_ms"), Some(&json!(2000)));
+    assert_eq!(props.get("max_batch_size"), Some(&json!(20)));
+}
+
+#[test]
+fn test_chat_properties_redact_webhook_urls() {
+    let reaction = ChatWebhookReaction::builder("test-reaction")
+        .with_webhook_url(WEBHOOK)
+        .with_query("query1")
+        .with_route(
+            "query1",
+            QueryConfig {
+                added: Some(TemplateSpec::with_extension(
+                    "{{after.id}}",
+                    ChatExtension {
+                        webhook_url: Some(
+                            "https://hooks.slack.com/services/T000/B000/YYYY".to_string(),
+                        ),
+                    },
+                )),
+                updated: None,
+                deleted: None,
+            },
+        )
+        .build()
+        .unwrap();
+
+    let props = reaction.properties();
+    assert_eq!(props.get("webhook_url"), Some(&json!("***")));
+    assert_eq!(
+        props["routes"]["query1"]["added"]["webhook_url"],
+        json!("***")
+    );
+}
+
+#[test]
+fn test_chat_builder_validation() {
+    assert!(ChatWebhookReaction::builder("test-reaction")
+        .build()
+        .is_err());
+    assert!(ChatWebhookReaction::builder("test-reaction")
+        .with_webhook_url("hooks.slack.com/services/T000")
+        .build()
+        .is_err());
+    assert!(ChatWebhookReaction::builder("test-reaction")
+        .with_webhook_url(WEBHOOK)
+        .with_max_batch_size(0)
+        .build()
+        .is_err());
+
+    let invalid_template = QueryConfig {
+        added: Some(TemplateSpec::new("{{#if}}")),
+        updated: None,
+        deleted: None,
+    };
+    assert!(ChatWebhookReaction::builder("test-reaction")
+        .with_webhook_url(WEBHOOK)
+        .with_query("query1")
+        .with_route("query1", invalid_template)
+        .build()
+        .is_err());
+
+    let route = QueryConfig {
+        added: Some(TemplateSpec::default()),
+        updated: None,
+        deleted: None,
+    };
+    assert!(ChatWebhookReaction::builder("test-reaction")
+        .with_webhook_url(WEBHOOK)
+        .with_query("source.query1")
+        .with_route("query1", route.clone())
+        .build()
+        .is_ok());
+    assert!(ChatWebhookReaction::builder("test-reaction")
+        .with_webhook_url(WEBHOOK)
+        .with_query("query1")
+        .with_route("other", route)
+        .build()
+        .is_err());
+}
+
+#[test]
+fn test_render_default_text() {
+    let result = query_result(
+        "orders",
+        vec![
+            ResultDiff::Add {
+                data: json!({"id": 1}),
+            },
+            ResultDiff::Update {
+                data: json!({}),
+                before: json!({"id": 1, "status": "open"}),
+                after: json!({"id": 1, "status": "paid"}),
+                grouping_keys: None,
+            },
+            ResultDiff::Delete {
+                data: json!({"id": 2}),
+            },
+            ResultDiff::Noop,
+        ],
+    );
+
+    let messages = render(&config(), &result);
+    assert_eq!(
+        messages,
+        vec![
+            message(WEBHOOK, r#"Added to `orders`: {"id":1}"#),
+            message(
+                WEBHOOK,
+                r#"Updated in `orders`: {"id":1,"status":"open"} → {"id":1,"status":"paid"}"#
+            ),
+            message(WEBHOOK, r#"Removed from `orders`: {"id":2}"#),
+        ]
+    );
+}
+
+#[test]
+fn test_render_route_templates_and_webhook_override() {
+    let mut config = config();
+    config.routes.insert(
+        "orders".to_string(),
+        QueryConfig {
+            added: Some(TemplateSpec::new(
+                ":package: New order *{{after.id}}* for {{after.total}}",
+            )),
+            updated: None,
+            deleted: Some(TemplateSpec::with_extension(
+                "Order {{before.id}} cancelled",
+                ChatExtension {
+                    webhook_url: Some("https://example.com/cancellations".to_string()),
+                },
+            )),
+        },
+    );
+
+    let result = query_result(
+        "source.orders",
+        vec![
+            ResultDiff::Add {
+                data: json!({"id": 7, "total": 12.5}),
+            },
+            ResultDiff::Update {
+                data: json!({}),
+                before: json!({"id": 7}),
+                after: json!({"id": 7}),
+                grouping_keys: None,
+            },
+            ResultDiff::Delete {
+                data: json!({"id": 7}),
+            },
+        ],
+    );
+
+    // Updates have no template in the route and are not posted
+    let messages = render(&config, &result);
+    assert_eq!(
+        messages,
+        vec![
+            message(WEBHOOK, ":package: New order *7* for 12.5"),
+            message("https://example.com/cancellations", "Order 7 cancelled"),
+        ]
+    );
+}
+
+#[test]
+fn test_render_with_shared_template_helpers() {
+    let mut config = config();
+    config.routes.insert(
+        "orders".to_string(),
+        QueryConfig {
+            added: Some(TemplateSpec::new(
+                "{{upper after.status}}: {{format_number after.total}}{{#if after.tags}} [{{join after.tags}}]{{/if}}",
+            )),
+            updated: None,
+            deleted: None,
+        },
+    );
+
+    let result = query_result(
+        "orders",
+        vec![ResultDiff::Add {
+            data: json!({"status": "new", "total": 12.5, "tags": ["rush", "gift"]}),
+        }],
+    );
+    assert_eq!(
+        render(&config, &result),
+        vec![message(WEBHOOK, "NEW: 12.50 [rush, gift]")]
+    );
+}
+
+#[test]
+fn test_batch_posts_groups_and_summarizes() {
+    let other = "https://example.com/other";
+    let messages = vec![
+        message(WEBHOOK, "a"),
+        message(other, "x"),
+        message(WEBHOOK, "b"),
+        message(WEBHOOK, "c"),
+        message(WEBHOOK, "d"),
+    ];
+
+    let posts = ChatWebhookReaction::batch_posts(messages, 2);
+    assert_eq!(
+        posts,
+        vec![
+            ChatPost {
+                webhook_url: WEBHOOK.to_string(),
+                text: "a\nb\n…and 2 more changes".to_string(),
+            },
+            ChatPost {
+                webhook_url: other.to_string(),
+                text: "x".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_request_body_per_platform() {
+    let slack = ChatWebhookReaction::request_body(ChatPlatform::Slack, "hello");
+    assert_eq!(slack, json!({"text": "hello"}));
+
+    let teams = ChatWebhookReaction::request_body(ChatPlatform::Teams, "hello");
+    assert_eq!(teams["type"], "message");
+    let card = &teams["attachments"][0];
+    assert_eq!(
+        card["contentType"],
+        "application/vnd.microsoft.card.adaptive"
+    );
+    assert_eq!(card["content"]["body"][0]["text"], "hello");
+}
+
+#[tokio::test]
+async fn test_rate_limiter_spaces_posts_per_webhook() {
+    // 600 posts per minute is one post every 100ms
+    let mut limiter = RateLimiter::new(600);
+    let start = tokio::time::Instant::now();
+
+    limiter.acquire("a").await;
+    limiter.acquire("b").await;
+    assert!(start.elapsed() < Duration::from_millis(100));
+
+    limiter.acquire("a").await;
+    limiter.acquire("a").await;
+    assert!(start.elapsed() >= Duration::from_millis(200));
+
+    let mut unlimited = RateLimiter::new(0);
+    let start = tokio::time::Instant::now();
+    unlimited.acquire("a").await;
+    unlimited.acquire("a").await;
+    assert!(start.elapsed() < Duration::from_millis(100));
+}
+
+#[tokio::test]
+async fn test_post_retries_rate_limited_requests() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/hook", listener.local_addr().unwrap());
+    let server = tokio::spawn(serve(
+        listener,
+        vec!["429 Too Many Requests\r\nretry-after: 0", "200 OK"],
+    ));
+
+    let body = ChatWebhookReaction::request_body(ChatPlatform::Slack, "hello");
+    ChatWebhookReaction::post_with_retry(&reqwest::Client::new(), &url, &body, 1, "test")
+        .await
+        .unwrap();
+
+    let requests = server.await.unwrap();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[1].starts_with("POST /hook"));
+    assert!(requests[1].ends_with(r#"{"text":"hello"}"#));
+}
+
+#[tokio::test]
+async fn test_post_fails_on_client_error() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/hook", listener.local_addr().unwrap());
+    let server = tokio::spawn(serve(listener, vec!["404 Not Found"]));
+
+    let body = ChatWebhookReaction::request_body(ChatPlatform::Slack, "hello");
+    let result =
+        ChatWebhookReaction::post_with_retry(&reqwest::Client::new(), &url, &body, 3, "test").await;
+    assert!(result.is_err());
+    assert_eq!(server.await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_reaction_posts_batched_changes() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/hook", listener.local_addr().unwrap());
+    let server = tokio::spawn(serve(listener, vec!["200 OK"]));
+
+    let reaction = ChatWebhookReaction::builder("test-reaction")
+        .with_webhook_url(&url)
+        .with_query("orders")
+        .with_batch_window_ms(200)
+        .with_max_batch_size(2)
+        .with_auto_start(false)
+        .build()
+        .unwrap();
+    reaction.start().await.unwrap();
+
+    for id in 1..=3 {
+        reaction
+            .enqueue_query_result(query_result(
+                "orders",
+                vec![ResultDiff::Add {
+                    data: json!({ "id": id }),
+                }],
+            ))
+            .await
+            .unwrap();
+    }
+
+    let requests = tokio::time::timeout(Duration::from_secs(5), server)
+        .await
+        .unwrap()
+        .unwrap();
+    let body = requests[0].split("\r\n\r\n").nth(1).unwrap();
+    let body: serde_json::Value = serde_json::from_str(body).unwrap();
+    assert_eq!(
+        body["text"],
+        "Added to `orders`: {\"id\":1}\nAdded to `orders`: {\"id\":2}\n…and 1 more change"
+    );
+
+    reaction.stop().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_descriptor_creates_reaction() {
+    let descriptor = ChatWebhookReactionDescriptor;
+    assert_eq!(descriptor.kind(), "chat-webhook");
+
+    let config = json!({
+        "platform": "teams",
+        "webhookUrl": "https://example.webhook.office.com/webhookb2/abc",
+        "rateLimitPerMinute": 10,
+        "batchWindowMs": 500,
+        "maxBatchSize": 5,
+        "routes": {
+            "query1": {
+                "added": { "template": "New: {{after.name}}" }
+            }
+        }
+    });
+
+    let reaction = descriptor
+        .create_reaction("chat-1", vec!["query1".to_string()], &config, false)
+        .await
+        .unwrap();
+
+    assert_eq!(reaction.id(), "chat-1");
+    assert!(!reaction.auto_start());
+    let props = reaction.properties();
+    assert_eq!(props.get("platform"), Some(&json!("teams")));
+    assert_eq!(props.get("rate_limit_per_minute"), Some(&json!(10)));
+    assert_eq!(props.get("max_batch_size"), Some(&json!(5)));
+    assert_eq!(props.get("webhook_url"), Some(&json!("***")));
+
+    let invalid = json!({
+        "webhookUrl": "https://example.com/hook",
+        "routes": { "query1": { "added": { "template": "x", "channel": "y" } } }
+    });
+    assert!(descriptor
+        .create_reaction("chat-2", vec!["query1".to_string()], &invalid, false)
+        .await
+        .is_err());
+}
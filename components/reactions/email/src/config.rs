@@ -0,0 +1,216 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration types for email (SMTP) reactions.
+
+use drasi_lib::reactions::common::TemplateRouting;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+fn default_smtp_host() -> String {
+    "localhost".to_string()
+}
+
+fn default_timeout_ms() -> u64 {
+    30000
+}
+
+fn default_subject() -> String {
+    "[Drasi] {{operation}} in {{query_name}}".to_string()
+}
+
+fn default_digest_window_secs() -> u64 {
+    300
+}
+
+fn default_digest_subject() -> String {
+    "[Drasi] {{count}} query result changes".to_string()
+}
+
+/// Transport security of the SMTP connection.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SmtpTls {
+    /// Plain connection without encryption (default port 25).
+    None,
+    /// Plain connection upgraded with STARTTLS (default, default port 587).
+    #[default]
+    StartTls,
+    /// Implicit TLS from the start of the connection (default port 465).
+    Tls,
+}
+
+impl SmtpTls {
+    /// Default SMTP port for this mode
+    pub fn default_port(&self) -> u16 {
+        match self {
+            SmtpTls::None => 25,
+            SmtpTls::StartTls => 587,
+            SmtpTls::Tls => 465,
+        }
+    }
+}
+
+/// When emails are sent.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryMode {
+    /// One email per result change (default).
+    #[default]
+    Immediate,
+    /// One email per digest window, listing all changes of the window.
+    Digest,
+}
+
+/// Email-specific extension for template specifications.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct EmailExtension {
+    /// Subject template for this operation. Defaults to the reaction's `subject`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+}
+
+/// Type alias for email template specification using the common generic type.
+///
+/// The template string renders the message body. If it is empty, the body
+/// lists the changed row as JSON.
+pub type TemplateSpec = drasi_lib::reactions::common::TemplateSpec<EmailExtension>;
+
+/// Type alias for email query configuration using the common generic type.
+pub type QueryConfig = drasi_lib::reactions::common::QueryConfig<EmailExtension>;
+
+/// Email (SMTP) reaction configuration
+#[derive(Clone, Serialize, Deserialize, PartialEq)]
+pub struct EmailReactionConfig {
+    /// SMTP server hostname
+    #[serde(default = "default_smtp_host")]
+    pub smtp_host: String,
+
+    /// SMTP server port. Defaults to the standard port of the `tls` mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub smtp_port: Option<u16>,
+
+    /// Transport security of the SMTP connection
+    #[serde(default)]
+    pub tls: SmtpTls,
+
+    /// Optional username for SMTP authentication
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+
+    /// Optional password for SMTP authentication
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+
+    /// SMTP command timeout in milliseconds
+    #[serde(default = "default_timeout_ms")]
+    pub timeout_ms: u64,
+
+    /// Sender address, e.g. `Drasi <drasi@example.com>`
+    #[serde(default)]
+    pub from: String,
+
+    /// Recipient addresses
+    #[serde(default)]
+    pub to: Vec<String>,
+
+    /// Default subject template
+    #[serde(default = "default_subject")]
+    pub subject: String,
+
+    /// Send bodies as HTML instead of plain text
+    #[serde(default)]
+    pub html: bool,
+
+    /// Immediate or digest delivery
+    #[serde(default)]
+    pub mode: DeliveryMode,
+
+    /// Length of a digest window in seconds
+    #[serde(default = "default_digest_window_secs")]
+    pub digest_window_secs: u64,
+
+    /// Subject template of digest emails
+    #[serde(default = "default_digest_subject")]
+    pub digest_subject: String,
+
+    /// Body template of digest emails. If not set, the rendered changes are
+    /// listed one after another.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub digest_template: Option<String>,
+
+    /// Query-specific template configurations
+    #[serde(default)]
+    pub routes: HashMap<String, QueryConfig>,
+
+    /// Default template configuration used when no query-specific route is defined.
+    /// If not set, every change is sent with the default subject and body.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_template: Option<QueryConfig>,
+}
+
+impl std::fmt::Debug for EmailReactionConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EmailReactionConfig")
+            .field("smtp_host", &self.smtp_host)
+            .field("smtp_port", &self.smtp_port)
+            .field("tls", &self.tls)
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| "***"))
+            .field("from", &self.from)
+            .field("to", &self.to)
+            .field("mode", &self.mode)
+            .finish()
+    }
+}
+
+impl Default for EmailReactionConfig {
+    fn default() -> Self {
+        Self {
+            smtp_host: default_smtp_host(),
+            smtp_port: None,
+            tls: SmtpTls::default(),
+            username: None,
+            password: None,
+            timeout_ms: default_timeout_ms(),
+            from: String::new(),
+            to: Vec::new(),
+            subject: default_subject(),
+            html: false,
+            mode: DeliveryMode::default(),
+            digest_window_secs: default_digest_window_secs(),
+            digest_subject: default_digest_subject(),
+            digest_template: None,
+            routes: HashMap::new(),
+            default_template: None,
+        }
+    }
+}
+
+impl EmailReactionConfig {
+    /// Get the SMTP port, using the default of the TLS mode if not specified
+    pub fn get_port(&self) -> u16 {
+        self.smtp_port.unwrap_or_else(|| self.tls.default_port())
+    }
+}
+
+impl TemplateRouting<EmailExtension> for EmailReactionConfig {
+    fn routes(&self) -> &HashMap<String, QueryConfig> {
+        &self.routes
+    }
+
+    fn default_template(&self) -> Option<&QueryConfig> {
+        self.default_template.as_ref()
+    }
+}
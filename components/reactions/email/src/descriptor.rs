@@ -0,0 +1,312 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Descriptor for the email (SMTP) reaction plugin.
+
+use drasi_lib::reactions::Reaction;
+use drasi_plugin_sdk::prelude::*;
+use std::collections::HashMap;
+use utoipa::OpenApi;
+
+use crate::config::{DeliveryMode, EmailExtension, SmtpTls};
+use crate::EmailReactionBuilder;
+
+/// DTO for the SMTP transport security.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::email::SmtpTls)]
+#[serde(rename_all = "snake_case")]
+pub enum SmtpTlsDto {
+    None,
+    StartTls,
+    Tls,
+}
+
+impl From<SmtpTlsDto> for SmtpTls {
+    fn from(dto: SmtpTlsDto) -> Self {
+        match dto {
+            SmtpTlsDto::None => SmtpTls::None,
+            SmtpTlsDto::StartTls => SmtpTls::StartTls,
+            SmtpTlsDto::Tls => SmtpTls::Tls,
+        }
+    }
+}
+
+/// DTO for the email delivery mode.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::email::DeliveryMode)]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryModeDto {
+    Immediate,
+    Digest,
+}
+
+impl From<DeliveryModeDto> for DeliveryMode {
+    fn from(dto: DeliveryModeDto) -> Self {
+        match dto {
+            DeliveryModeDto::Immediate => DeliveryMode::Immediate,
+            DeliveryModeDto::Digest => DeliveryMode::Digest,
+        }
+    }
+}
+
+/// DTO for an email template specification.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::email::EmailTemplateSpec)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct EmailTemplateSpecDto {
+    /// Handlebars template for the email body.
+    #[serde(default)]
+    pub template: String,
+
+    /// Optional subject template for this operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub subject: Option<ConfigValue<String>>,
+}
+
+/// DTO for per-query email template configuration.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::email::EmailQueryConfig)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct EmailQueryConfigDto {
+    /// Template for ADD operations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub added: Option<EmailTemplateSpecDto>,
+
+    /// Template for UPDATE operations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated: Option<EmailTemplateSpecDto>,
+
+    /// Template for DELETE operations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted: Option<EmailTemplateSpecDto>,
+}
+
+/// Configuration DTO for the email (SMTP) reaction plugin.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::email::EmailReactionConfig)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailReactionConfigDto {
+    /// SMTP server hostname.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub smtp_host: Option<ConfigValue<String>>,
+
+    /// SMTP server port.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueU16>)]
+    pub smtp_port: Option<ConfigValue<u16>>,
+
+    /// Transport security of the SMTP connection.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls: Option<SmtpTlsDto>,
+
+    /// SMTP username.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub username: Option<ConfigValue<String>>,
+
+    /// SMTP password.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub password: Option<ConfigValue<String>>,
+
+    /// SMTP timeout in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueU64>)]
+    pub timeout_ms: Option<ConfigValue<u64>>,
+
+    /// Sender address.
+    #[schema(value_type = ConfigValueString)]
+    pub from: ConfigValue<String>,
+
+    /// Recipient addresses.
+    pub to: Vec<String>,
+
+    /// Default subject template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub subject: Option<ConfigValue<String>>,
+
+    /// Whether email bodies are HTML.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueBool>)]
+    pub html: Option<ConfigValue<bool>>,
+
+    /// Delivery mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<DeliveryModeDto>,
+
+    /// Digest window in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueU64>)]
+    pub digest_window_secs: Option<ConfigValue<u64>>,
+
+    /// Subject template of digest emails.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub digest_subject: Option<ConfigValue<String>>,
+
+    /// Body template of digest emails.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub digest_template: Option<String>,
+
+    /// Query-specific template configurations.
+    #[serde(default)]
+    pub routes: HashMap<String, EmailQueryConfigDto>,
+
+    /// Default template configuration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_template: Option<EmailQueryConfigDto>,
+}
+
+fn map_template_spec(
+    mapper: &DtoMapper,
+    dto: &EmailTemplateSpecDto,
+) -> anyhow::Result<crate::TemplateSpec> {
+    Ok(crate::TemplateSpec {
+        template: dto.template.clone(),
+        extension: EmailExtension {
+            subject: mapper.resolve_optional_string(&dto.subject)?,
+        },
+    })
+}
+
+fn map_query_config(
+    mapper: &DtoMapper,
+    dto: &EmailQueryConfigDto,
+) -> anyhow::Result<crate::QueryConfig> {
+    Ok(crate::QueryConfig {
+        added: dto
+            .added
+            .as_ref()
+            .map(|spec| map_template_spec(mapper, spec))
+            .transpose()?,
+        updated: dto
+            .updated
+            .as_ref()
+            .map(|spec| map_template_spec(mapper, spec))
+            .transpose()?,
+        deleted: dto
+            .deleted
+            .as_ref()
+            .map(|spec| map_template_spec(mapper, spec))
+            .transpose()?,
+    })
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(
+    EmailReactionConfigDto,
+    EmailQueryConfigDto,
+    EmailTemplateSpecDto,
+    SmtpTlsDto,
+    DeliveryModeDto,
+)))]
+struct EmailReactionSchemas;
+
+/// Descriptor for the email (SMTP) reaction plugin.
+pub struct EmailReactionDescriptor;
+
+#[async_trait]
+impl ReactionPluginDescriptor for EmailReactionDescriptor {
+    fn kind(&self) -> &str {
+        "email"
+    }
+
+    fn config_version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn config_schema_name(&self) -> &str {
+        "reaction.email.EmailReactionConfig"
+    }
+
+    fn config_schema_json(&self) -> String {
+        let api = EmailReactionSchemas::openapi();
+        serde_json::to_string(
+            &api.components
+                .as_ref()
+                .expect("OpenAPI components missing")
+                .schemas,
+        )
+        .expect("Failed to serialize config schema")
+    }
+
+    async fn create_reaction(
+        &self,
+        id: &str,
+        query_ids: Vec<String>,
+        config_json: &serde_json::Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn Reaction>> {
+        let dto: EmailReactionConfigDto = serde_json::from_value(config_json.clone())?;
+        let mapper = DtoMapper::new();
+
+        let mut builder = EmailReactionBuilder::new(id)
+            .with_queries(query_ids)
+            .with_auto_start(auto_start)
+            .with_from(mapper.resolve_string(&dto.from)?)
+            .with_to(dto.to.clone());
+
+        if let Some(ref host) = dto.smtp_host {
+            builder = builder.with_smtp_host(mapper.resolve_string(host)?);
+        }
+        if let Some(ref port) = dto.smtp_port {
+            builder = builder.with_smtp_port(mapper.resolve_typed(port)?);
+        }
+        if let Some(tls) = dto.tls {
+            builder = builder.with_tls(tls.into());
+        }
+        if let Some(ref username) = dto.username {
+            let password = mapper.resolve_optional_string(&dto.password)?;
+            builder = builder.with_credentials(
+                mapper.resolve_string(username)?,
+                password.unwrap_or_default(),
+            );
+        }
+        if let Some(ref timeout) = dto.timeout_ms {
+            builder = builder.with_timeout_ms(mapper.resolve_typed(timeout)?);
+        }
+        if let Some(ref subject) = dto.subject {
+            builder = builder.with_subject(mapper.resolve_string(subject)?);
+        }
+        if let Some(ref html) = dto.html {
+            builder = builder.with_html(mapper.resolve_typed(html)?);
+        }
+        if let Some(mode) = dto.mode {
+            builder = builder.with_mode(mode.into());
+        }
+        if let Some(ref window) = dto.digest_window_secs {
+            builder = builder.with_digest_window_secs(mapper.resolve_typed(window)?);
+        }
+        if let Some(ref subject) = dto.digest_subject {
+            builder = builder.with_digest_subject(mapper.resolve_string(subject)?);
+        }
+        if let Some(ref template) = dto.digest_template {
+            builder = builder.with_digest_template(template.clone());
+        }
+
+        if let Some(ref default_template) = dto.default_template {
+            builder = builder.with_default_template(map_query_config(&mapper, default_template)?);
+        }
+
+        for (query_id, config) in &dto.routes {
+            builder = builder.with_route(query_id, map_query_config(&mapper, config)?);
+        }
+
+        let reaction = builder.build()?;
+        Ok(Box::new(reaction))
+    }
+}
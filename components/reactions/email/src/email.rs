@@ -0,0 +1,670 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use lettre::message::header::ContentType;
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use log::{debug, error, info};
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::time::MissedTickBehavior;
+
+use drasi_lib::channels::{ComponentStatus, QueryResult, ResultDiff};
+use drasi_lib::managers::log_component_start;
+use drasi_lib::reactions::common::base::{ReactionBase, ReactionBaseParams};
+use drasi_lib::templating::{change_context, html_escape, TemplateEngine};
+use drasi_lib::Reaction;
+
+use super::config::{DeliveryMode, EmailReactionConfig, QueryConfig, SmtpTls};
+use super::EmailReactionBuilder;
+
+type SmtpTransport = AsyncSmtpTransport<Tokio1Executor>;
+
+/// A result change rendered into an email subject and body.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct RenderedChange {
+    pub query_name: String,
+    pub operation: String,
+    pub subject: String,
+    pub body: String,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+    pub timestamp: i64,
+}
+
+/// Template engines used to render emails.
+///
+/// Subjects are never escaped. Bodies are HTML-escaped only when HTML
+/// bodies are configured.
+pub(crate) struct Renderer {
+    subject: TemplateEngine,
+    body: TemplateEngine,
+}
+
+impl Renderer {
+    pub(crate) fn new(html: bool) -> Self {
+        let body = if html {
+            TemplateEngine::new()
+        } else {
+            TemplateEngine::without_escaping()
+        };
+        Self {
+            subject: TemplateEngine::without_escaping(),
+            body,
+        }
+    }
+}
+
+/// Email reaction sends query result changes as emails over SMTP.
+pub struct EmailReaction {
+    base: ReactionBase,
+    config: EmailReactionConfig,
+}
+
+impl std::fmt::Debug for EmailReaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EmailReaction")
+            .field("id", &self.base.id)
+            .field("smtp_host", &self.config.smtp_host)
+            .field("smtp_port", &self.config.get_port())
+            .field("mode", &self.config.mode)
+            .finish()
+    }
+}
+
+impl EmailReaction {
+    /// Create a builder for EmailReaction
+    pub fn builder(id: impl Into<String>) -> EmailReactionBuilder {
+        EmailReactionBuilder::new(id)
+    }
+
+    /// Create a new email reaction
+    ///
+    /// The event channel is automatically injected when the reaction is added
+    /// to DrasiLib via `add_reaction()`.
+    ///
+    /// # Errors
+    ///
+    /// - Returns error if the sender or a recipient is not a valid address
+    /// - Returns error if any template has invalid Handlebars syntax
+    /// - Returns error if a route query ID doesn't match any subscribed query
+    pub fn new(
+        id: impl Into<String>,
+        queries: Vec<String>,
+        config: EmailReactionConfig,
+    ) -> anyhow::Result<Self> {
+        Self::validate_config(&queries, &config)?;
+        Ok(Self::from_builder(id.into(), queries, config, None, true))
+    }
+
+    /// Create from builder (internal method)
+    pub(crate) fn from_builder(
+        id: String,
+        queries: Vec<String>,
+        config: EmailReactionConfig,
+        priority_queue_capacity: Option<usize>,
+        auto_start: bool,
+    ) -> Self {
+        let mut params = ReactionBaseParams::new(id, queries).with_auto_start(auto_start);
+        if let Some(capacity) = priority_queue_capacity {
+            params = params.with_priority_queue_capacity(capacity);
+        }
+
+        Self {
+            base: ReactionBase::new(params),
+            config,
+        }
+    }
+
+    /// Validate a template by attempting to compile it with Handlebars
+    fn validate_template(template: &str) -> anyhow::Result<()> {
+        if template.is_empty() {
+            return Ok(());
+        }
+        TemplateEngine::validate(template)
+    }
+
+    /// Validate body and subject templates in a QueryConfig
+    fn validate_query_config(config: &QueryConfig) -> anyhow::Result<()> {
+        for spec in [&config.added, &config.updated, &config.deleted]
+            .into_iter()
+            .flatten()
+        {
+            Self::validate_template(&spec.template)?;
+            if let Some(subject) = &spec.extension.subject {
+                Self::validate_template(subject)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate configuration: SMTP settings, addresses, templates and route-query matching
+    pub(crate) fn validate_config(
+        queries: &[String],
+        config: &EmailReactionConfig,
+    ) -> anyhow::Result<()> {
+        if config.smtp_host.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Validation error: smtp_host cannot be empty"
+            ));
+        }
+
+        config
+            .from
+            .parse::<Mailbox>()
+            .map_err(|e| anyhow::anyhow!("Validation error: invalid from address: {e}"))?;
+        if config.to.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Validation error: at least one recipient is required"
+            ));
+        }
+        for recipient in &config.to {
+            recipient.parse::<Mailbox>().map_err(|e| {
+                anyhow::anyhow!("Validation error: invalid recipient '{recipient}': {e}")
+            })?;
+        }
+
+        if config.mode == DeliveryMode::Digest && config.digest_window_secs == 0 {
+            return Err(anyhow::anyhow!(
+                "Validation error: digest_window_secs must be greater than 0"
+            ));
+        }
+
+        Self::validate_template(&config.subject)
+            .map_err(|e| anyhow::anyhow!("Invalid subject: {e}"))?;
+        Self::validate_template(&config.digest_subject)
+            .map_err(|e| anyhow::anyhow!("Invalid digest subject: {e}"))?;
+        if let Some(digest_template) = &config.digest_template {
+            Self::validate_template(digest_template)
+                .map_err(|e| anyhow::anyhow!("Invalid digest template: {e}"))?;
+        }
+
+        for (query_id, route_config) in &config.routes {
+            Self::validate_query_config(route_config)
+                .map_err(|e| anyhow::anyhow!("Invalid template in route '{query_id}': {e}"))?;
+        }
+
+        if let Some(default_template) = &config.default_template {
+            Self::validate_query_config(default_template)
+                .map_err(|e| anyhow::anyhow!("Invalid default template: {e}"))?;
+        }
+
+        if !config.routes.is_empty() && !queries.is_empty() {
+            for route_query in config.routes.keys() {
+                let dotted_route = format!(".{route_query}");
+                let matches = queries
+                    .iter()
+                    .any(|q| q == route_query || q.ends_with(&dotted_route));
+                if !matches {
+                    return Err(anyhow::anyhow!(
+                        "Route '{route_query}' does not match any subscribed query. Subscribed queries: {queries:?}"
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Build the SMTP transport from configuration
+    pub(crate) fn smtp_transport(config: &EmailReactionConfig) -> Result<SmtpTransport> {
+        let builder = match config.tls {
+            SmtpTls::None => SmtpTransport::builder_dangerous(&config.smtp_host),
+            SmtpTls::StartTls => SmtpTransport::starttls_relay(&config.smtp_host)?,
+            SmtpTls::Tls => SmtpTransport::relay(&config.smtp_host)?,
+        };
+        let mut builder = builder
+            .port(config.get_port())
+            .timeout(Some(Duration::from_millis(config.timeout_ms)));
+        if let Some(username) = &config.username {
+            builder = builder.credentials(Credentials::new(
+                username.clone(),
+                config.password.clone().unwrap_or_default(),
+            ));
+        }
+        Ok(builder.build())
+    }
+
+    /// Find the route for a query, falling back to the last dotted segment and then the default template
+    fn query_config<'a>(
+        config: &'a EmailReactionConfig,
+        query_name: &str,
+    ) -> Option<&'a QueryConfig> {
+        config
+            .routes
+            .get(query_name)
+            .or_else(|| {
+                if query_name.contains('.') {
+                    query_name
+                        .rsplit('.')
+                        .next()
+                        .and_then(|name| config.routes.get(name))
+                } else {
+                    None
+                }
+            })
+            .or(config.default_template.as_ref())
+    }
+
+    /// Default body for a change, listing the changed rows as JSON
+    fn default_body(
+        query_name: &str,
+        operation: &str,
+        context: &Map<String, Value>,
+        html: bool,
+    ) -> String {
+        let row = |key: &str| {
+            context
+                .get(key)
+                .and_then(|value| serde_json::to_string_pretty(value).ok())
+                .unwrap_or_default()
+        };
+        let text = match operation {
+            "ADD" => format!("Added to {query_name}:\n{}", row("after")),
+            "DELETE" => format!("Removed from {query_name}:\n{}", row("before")),
+            _ => format!(
+                "Updated in {query_name}:\nBefore:\n{}\nAfter:\n{}",
+                row("before"),
+                row("after")
+            ),
+        };
+        if html {
+            format!("<pre>{}</pre>", html_escape(&text))
+        } else {
+            text
+        }
+    }
+
+    /// Render the subject and body of every change in a query result.
+    ///
+    /// Without a matching route or default template every change is rendered
+    /// with the default subject and body. With one, only operations that have
+    /// a template are rendered; an empty template uses the default body.
+    pub(crate) fn render_changes(
+        renderer: &Renderer,
+        config: &EmailReactionConfig,
+        query_result: &QueryResult,
+        reaction_id: &str,
+    ) -> Vec<RenderedChange> {
+        let query_name = &query_result.query_id;
+        let timestamp = query_result.timestamp.timestamp_millis();
+        let query_config = Self::query_config(config, query_name);
+        let mut changes = Vec::new();
+
+        for result in &query_result.results {
+            let (spec, operation) = match result {
+                ResultDiff::Add { .. } => (query_config.map(|qc| qc.added.as_ref()), "ADD"),
+                ResultDiff::Update { .. } => (query_config.map(|qc| qc.updated.as_ref()), "UPDATE"),
+                ResultDiff::Delete { .. } => (query_config.map(|qc| qc.deleted.as_ref()), "DELETE"),
+                ResultDiff::Aggregation { .. } => {
+                    (query_config.map(|qc| qc.updated.as_ref()), "AGGREGATION")
+                }
+                ResultDiff::Noop => continue,
+            };
+
+            // A configured route without a template for this operation sends nothing
+            let spec = match spec {
+                Some(None) => continue,
+                Some(Some(spec)) => Some(spec),
+                None => None,
+            };
+
+            let Some(Value::Object(context)) = change_context(query_result, result) else {
+                continue;
+            };
+
+            let subject_template = spec
+                .and_then(|s| s.extension.subject.as_deref())
+                .unwrap_or(&config.subject);
+            let subject = match renderer.subject.render(subject_template, &context) {
+                Ok(subject) => subject,
+                Err(e) => {
+                    error!(
+                        "[{reaction_id}] Failed to render subject for query '{query_name}': {e}. Using default subject."
+                    );
+                    format!("[Drasi] {operation} in {query_name}")
+                }
+            };
+
+            let body = match spec.map(|s| s.template.as_str()) {
+                Some(template) if !template.is_empty() => {
+                    match renderer.body.render(template, &context) {
+                        Ok(rendered) => rendered,
+                        Err(e) => {
+                            error!(
+                                "[{reaction_id}] Failed to render body for query '{query_name}': {e}. Falling back to default body."
+                            );
+                            Self::default_body(query_name, operation, &context, config.html)
+                        }
+                    }
+                }
+                _ => Self::default_body(query_name, operation, &context, config.html),
+            };
+
+            changes.push(RenderedChange {
+                query_name: query_name.to_string(),
+                operation: operation.to_string(),
+                // Subjects are single header lines
+                subject: subject.replace(['\r', '\n'], " "),
+                body,
+                before: context.get("before").cloned(),
+                after: context.get("after").cloned(),
+                timestamp,
+            });
+        }
+
+        changes
+    }
+
+    /// Render the subject and body of a digest email.
+    ///
+    /// The templates see `count`, `window_start`, `window_end` (milliseconds)
+    /// and `changes`, a list of the rendered changes with their rows.
+    pub(crate) fn render_digest(
+        renderer: &Renderer,
+        config: &EmailReactionConfig,
+        changes: &[RenderedChange],
+        window_start: i64,
+        window_end: i64,
+        reaction_id: &str,
+    ) -> (String, String) {
+        let context = json!({
+            "count": changes.len(),
+            "window_start": window_start,
+            "window_end": window_end,
+            "changes": changes
+                .iter()
+                .map(|change| json!({
+                    "query_name": change.query_name,
+                    "operation": change.operation,
+                    "subject": change.subject,
+                    "body": change.body,
+                    "before": change.before,
+                    "after": change.after,
+                    "timestamp": change.timestamp,
+                }))
+                .collect::<Vec<_>>(),
+        });
+
+        let subject = renderer
+            .subject
+            .render(&config.digest_subject, &context)
+            .unwrap_or_else(|e| {
+                error!(
+                    "[{reaction_id}] Failed to render digest subject: {e}. Using default subject."
+                );
+                format!("[Drasi] {} query result changes", changes.len())
+            })
+            .replace(['\r', '\n'], " ");
+
+        let default_body = || {
+            let separator = if config.html { "\n<hr>\n" } else { "\n\n" };
+            changes
+                .iter()
+                .map(|change| {
+                    if config.html {
+                        format!("<h3>{}</h3>\n{}", html_escape(&change.subject), change.body)
+                    } else {
+                        format!("{}\n{}", change.subject, change.body)
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(separator)
+        };
+        let body = match &config.digest_template {
+            Some(template) => renderer
+                .body
+                .render(template, &context)
+                .unwrap_or_else(|e| {
+                    error!(
+                        "[{reaction_id}] Failed to render digest template: {e}. Falling back to default body."
+                    );
+                    default_body()
+                }),
+            None => default_body(),
+        };
+
+        (subject, body)
+    }
+
+    /// Build an email message to all configured recipients
+    pub(crate) fn build_message(
+        config: &EmailReactionConfig,
+        subject: &str,
+        body: String,
+    ) -> Result<Message> {
+        let content_type = if config.html {
+            ContentType::TEXT_HTML
+        } else {
+            ContentType::TEXT_PLAIN
+        };
+        let mut builder = Message::builder()
+            .from(config.from.parse::<Mailbox>()?)
+            .subject(subject)
+            .header(content_type);
+        for recipient in &config.to {
+            builder = builder.to(recipient.parse::<Mailbox>()?);
+        }
+        Ok(builder.body(body)?)
+    }
+
+    /// Build and send an email, logging failures
+    async fn send(
+        transport: &SmtpTransport,
+        config: &EmailReactionConfig,
+        subject: &str,
+        body: String,
+        reaction_id: &str,
+    ) {
+        let message = match Self::build_message(config, subject, body) {
+            Ok(message) => message,
+            Err(e) => {
+                error!("[{reaction_id}] Failed to build email '{subject}': {e}");
+                return;
+            }
+        };
+        match transport.send(message).await {
+            Ok(_) => debug!("[{reaction_id}] Sent email '{subject}'"),
+            Err(e) => error!("[{reaction_id}] Failed to send email '{subject}': {e}"),
+        }
+    }
+}
+
+#[async_trait]
+impl Reaction for EmailReaction {
+    fn id(&self) -> &str {
+        &self.base.id
+    }
+
+    fn type_name(&self) -> &str {
+        "email"
+    }
+
+    fn properties(&self) -> HashMap<String, serde_json::Value> {
+        let mut config = self.config.clone();
+        if config.password.is_some() {
+            config.password = Some("***".to_string());
+        }
+        match serde_json::to_value(&config) {
+            Ok(serde_json::Value::Object(map)) => map.into_iter().collect(),
+            _ => HashMap::new(),
+        }
+    }
+
+    fn query_ids(&self) -> Vec<String> {
+        self.base.queries.clone()
+    }
+
+    fn auto_start(&self) -> bool {
+        self.base.get_auto_start()
+    }
+
+    async fn initialize(&self, context: drasi_lib::context::ReactionRuntimeContext) {
+        self.base.initialize(context).await;
+    }
+
+    async fn start(&self) -> Result<()> {
+        log_component_start("Email Reaction", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Starting,
+                Some("Starting email reaction".to_string()),
+            )
+            .await;
+
+        let transport = match Self::smtp_transport(&self.config) {
+            Ok(transport) => transport,
+            Err(e) => {
+                self.base
+                    .set_status(
+                        ComponentStatus::Error,
+                        Some(format!("Failed to create SMTP transport: {e}")),
+                    )
+                    .await;
+                return Err(anyhow!("Failed to create SMTP transport: {e}"));
+            }
+        };
+
+        self.base
+            .set_status(
+                ComponentStatus::Running,
+                Some("Email reaction started".to_string()),
+            )
+            .await;
+
+        let priority_queue = self.base.priority_queue.clone();
+        let reaction_id = self.base.id.clone();
+        let config = self.config.clone();
+        let mut shutdown_rx = self.base.create_shutdown_channel().await;
+
+        let processing_task = tokio::spawn(async move {
+            info!("[{reaction_id}] Email processing task started");
+
+            let renderer = Renderer::new(config.html);
+            let digest = config.mode == DeliveryMode::Digest;
+            let mut pending: Vec<RenderedChange> = Vec::new();
+            let mut window_start = chrono::Utc::now().timestamp_millis();
+
+            // The first tick completes immediately, so the first digest is sent
+            // one full window after start
+            let mut digest_timer =
+                tokio::time::interval(Duration::from_secs(config.digest_window_secs.max(1)));
+            digest_timer.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            digest_timer.tick().await;
+
+            loop {
+                tokio::select! {
+                    biased;
+
+                    _ = &mut shutdown_rx => {
+                        debug!("[{reaction_id}] Received shutdown signal, exiting processing loop");
+                        break;
+                    }
+
+                    _ = digest_timer.tick(), if digest => {
+                        let window_end = chrono::Utc::now().timestamp_millis();
+                        if !pending.is_empty() {
+                            let (subject, body) = EmailReaction::render_digest(
+                                &renderer,
+                                &config,
+                                &pending,
+                                window_start,
+                                window_end,
+                                &reaction_id,
+                            );
+                            info!("[{reaction_id}] Sending digest with {} changes", pending.len());
+                            EmailReaction::send(&transport, &config, &subject, body, &reaction_id)
+                                .await;
+                            pending.clear();
+                        }
+                        window_start = window_end;
+                    }
+
+                    query_result = priority_queue.dequeue() => {
+                        let changes = EmailReaction::render_changes(
+                            &renderer,
+                            &config,
+                            &query_result,
+                            &reaction_id,
+                        );
+                        if digest {
+                            pending.extend(changes);
+                        } else {
+                            for change in changes {
+                                EmailReaction::send(
+                                    &transport,
+                                    &config,
+                                    &change.subject,
+                                    change.body,
+                                    &reaction_id,
+                                )
+                                .await;
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Send what has been collected so far instead of dropping it
+            if !pending.is_empty() {
+                let window_end = chrono::Utc::now().timestamp_millis();
+                let (subject, body) = EmailReaction::render_digest(
+                    &renderer,
+                    &config,
+                    &pending,
+                    window_start,
+                    window_end,
+                    &reaction_id,
+                );
+                info!(
+                    "[{reaction_id}] Sending final digest with {} changes",
+                    pending.len()
+                );
+                EmailReaction::send(&transport, &config, &subject, body, &reaction_id).await;
+            }
+
+            info!("[{reaction_id}] Email processing task ended");
+        });
+
+        self.base.set_processing_task(processing_task).await;
+
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        self.base.stop_common().await?;
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopped,
+                Some("Email reaction stopped".to_string()),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn status(&self) -> ComponentStatus {
+        self.base.get_status().await
+    }
+
+    async fn enqueue_query_result(&self, result: QueryResult) -> anyhow::Result<()> {
+        self.base.enqueue_query_result(result).await
+    }
+}
@@ -0,0 +1,240 @@
+#![allow(unexpected_cfgs)]
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Email (SMTP) reaction plugin for Drasi
+//!
+//! This plugin sends templated emails when query results change. In immediate
+//! mode every change is sent as its own email; in digest mode changes are
+//! collected over a time window and sent together as a single email.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use drasi_reaction_email::{EmailReaction, SmtpTls};
+//!
+//! let reaction = EmailReaction::builder("my-email-reaction")
+//!     .with_queries(vec!["query1".to_string()])
+//!     .with_smtp_server("smtp.example.com", 587)
+//!     .with_tls(SmtpTls::StartTls)
+//!     .with_credentials("drasi", "secret")
+//!     .with_from("Drasi <drasi@example.com>")
+//!     .with_recipient("ops@example.com")
+//!     .with_digest(600)
+//!     .build()?;
+//! ```
+
+pub mod config;
+pub mod descriptor;
+pub mod email;
+
+pub use config::{
+    DeliveryMode, EmailExtension, EmailReactionConfig, QueryConfig, SmtpTls, TemplateSpec,
+};
+pub use email::EmailReaction;
+
+/// Builder for email reaction
+pub struct EmailReactionBuilder {
+    id: String,
+    queries: Vec<String>,
+    config: EmailReactionConfig,
+    priority_queue_capacity: Option<usize>,
+    auto_start: bool,
+}
+
+impl EmailReactionBuilder {
+    /// Create a new email reaction builder with the given ID
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            queries: Vec::new(),
+            config: EmailReactionConfig::default(),
+            priority_queue_capacity: None,
+            auto_start: true,
+        }
+    }
+
+    /// Set the query IDs to subscribe to
+    pub fn with_queries(mut self, queries: Vec<String>) -> Self {
+        self.queries = queries;
+        self
+    }
+
+    /// Add a query ID to subscribe to
+    pub fn with_query(mut self, query_id: impl Into<String>) -> Self {
+        self.queries.push(query_id.into());
+        self
+    }
+
+    /// Set the SMTP server host and port
+    pub fn with_smtp_server(mut self, host: impl Into<String>, port: u16) -> Self {
+        self.config.smtp_host = host.into();
+        self.config.smtp_port = Some(port);
+        self
+    }
+
+    /// Set the SMTP server host
+    pub fn with_smtp_host(mut self, host: impl Into<String>) -> Self {
+        self.config.smtp_host = host.into();
+        self
+    }
+
+    /// Set the SMTP server port (defaults to the standard port of the TLS mode)
+    pub fn with_smtp_port(mut self, port: u16) -> Self {
+        self.config.smtp_port = Some(port);
+        self
+    }
+
+    /// Set the TLS mode of the SMTP connection
+    pub fn with_tls(mut self, tls: SmtpTls) -> Self {
+        self.config.tls = tls;
+        self
+    }
+
+    /// Set the SMTP authentication credentials
+    pub fn with_credentials(
+        mut self,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        self.config.username = Some(username.into());
+        self.config.password = Some(password.into());
+        self
+    }
+
+    /// Set the SMTP timeout in milliseconds
+    pub fn with_timeout_ms(mut self, timeout_ms: u64) -> Self {
+        self.config.timeout_ms = timeout_ms;
+        self
+    }
+
+    /// Set the sender address
+    pub fn with_from(mut self, from: impl Into<String>) -> Self {
+        self.config.from = from.into();
+        self
+    }
+
+    /// Set the recipient addresses
+    pub fn with_to(mut self, to: Vec<String>) -> Self {
+        self.config.to = to;
+        self
+    }
+
+    /// Add a recipient address
+    pub fn with_recipient(mut self, recipient: impl Into<String>) -> Self {
+        self.config.to.push(recipient.into());
+        self
+    }
+
+    /// Set the default subject template
+    pub fn with_subject(mut self, subject: impl Into<String>) -> Self {
+        self.config.subject = subject.into();
+        self
+    }
+
+    /// Set whether email bodies are HTML
+    pub fn with_html(mut self, html: bool) -> Self {
+        self.config.html = html;
+        self
+    }
+
+    /// Set the delivery mode
+    pub fn with_mode(mut self, mode: DeliveryMode) -> Self {
+        self.config.mode = mode;
+        self
+    }
+
+    /// Send changes as a digest every `window_secs` seconds
+    pub fn with_digest(mut self, window_secs: u64) -> Self {
+        self.config.mode = DeliveryMode::Digest;
+        self.config.digest_window_secs = window_secs;
+        self
+    }
+
+    /// Set the digest window in seconds
+    pub fn with_digest_window_secs(mut self, window_secs: u64) -> Self {
+        self.config.digest_window_secs = window_secs;
+        self
+    }
+
+    /// Set the subject template of digest emails
+    pub fn with_digest_subject(mut self, subject: impl Into<String>) -> Self {
+        self.config.digest_subject = subject.into();
+        self
+    }
+
+    /// Set the body template of digest emails
+    pub fn with_digest_template(mut self, template: impl Into<String>) -> Self {
+        self.config.digest_template = Some(template.into());
+        self
+    }
+
+    /// Set the priority queue capacity
+    pub fn with_priority_queue_capacity(mut self, capacity: usize) -> Self {
+        self.priority_queue_capacity = Some(capacity);
+        self
+    }
+
+    /// Set whether the reaction should auto-start
+    pub fn with_auto_start(mut self, auto_start: bool) -> Self {
+        self.auto_start = auto_start;
+        self
+    }
+
+    /// Add a route configuration for a specific query
+    pub fn with_route(mut self, query_id: impl Into<String>, config: QueryConfig) -> Self {
+        self.config.routes.insert(query_id.into(), config);
+        self
+    }
+
+    /// Set the default template configuration used when no query-specific route is defined
+    pub fn with_default_template(mut self, config: QueryConfig) -> Self {
+        self.config.default_template = Some(config);
+        self
+    }
+
+    /// Set the full configuration at once
+    pub fn with_config(mut self, config: EmailReactionConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Build the email reaction
+    pub fn build(self) -> anyhow::Result<EmailReaction> {
+        EmailReaction::validate_config(&self.queries, &self.config)?;
+
+        Ok(EmailReaction::from_builder(
+            self.id,
+            self.queries,
+            self.config,
+            self.priority_queue_capacity,
+            self.auto_start,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests;
+
+/// Dynamic plugin entry point.
+#[cfg(feature = "dynamic-plugin")]
+drasi_plugin_sdk::export_plugin!(
+    plugin_id = "email-reaction",
+    core_version = env!("CARGO_PKG_VERSION"),
+    lib_version = env!("CARGO_PKG_VERSION"),
+    plugin_version = env!("CARGO_PKG_VERSION"),
+    source_descriptors = [],
+    reaction_descriptors = [descriptor::EmailReactionDescriptor],
+    bootstrap_descriptors = [],
+);
@@ -0,0 +1,468 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::descriptor::EmailReactionDescriptor;
+use crate::email::{RenderedChange, Renderer};
+use drasi_lib::channels::{QueryResult, ResultDiff};
+use drasi_lib::Reaction;
+use drasi_plugin_sdk::prelude::ReactionPluginDescriptor;
+use serde_json::json;
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+
+fn query_result(query_id: &str, results: Vec<ResultDiff>) -> QueryResult {
+    QueryResult::new(
+        query_id.to_string(),
+        chrono::Utc::now(),
+        results,
+        HashMap::new(),
+    )
+}
+
+fn config() -> EmailReactionConfig {
+    EmailReactionConfig {
+        from: "drasi@example.com".to_string(),
+        to: vec!["ops@example.com".to_string()],
+        ..Default::default()
+    }
+}
+
+fn render(config: &EmailReactionConfig, result: &QueryResult) -> Vec<RenderedChange> {
+    EmailReaction::render_changes(&Renderer::new(config.html), config, result, "test-reaction")
+}
+
+/// Accept one SMTP session per expected email, returning the DATA of each.
+async fn serve(listener: TcpListener, emails: usize) -> Vec<String> {
+    let mut received = Vec::new();
+    while received.len() < emails {
+        let (stream, _) = listener.accept().await.unwrap();
+        let (reader, mut writer) = stream.into_split();
+        let mut lines = BufReader::new(reader).lines();
+        writer.write_all(b"220 localhost ESMTP\r\n").await.unwrap();
+
+        while let Ok(Some(line)) = lines.next_line().await {
+            let command = line.to_ascii_uppercase();
+            let reply: &[u8] = if command.starts_with("EHLO") {
+                b"250-localhost\r\n250 8BITMIME\r\n"
+            } else if command.starts_with("DATA") {
+                writer.write_all(b"354 Start mail input\r\n").await.unwrap();
+                let mut data = Vec::new();
+                while let Ok(Some(line)) = lines.next_line().await {
+                    if line == "." {
+                        break;
+                    }
+                    data.push(line);
+                }
+                received.push(data.join("\n"));
+                b"250 OK\r\n"
+            } else if command.starts_with("QUIT") {
+                let _ = writer.write_all(b"221 Bye\r\n").await;
+                break;
+            } else {
+                b"250 OK\r\n"
+            };
+            writer.write_all(reply).await.unwrap();
+        }
+    }
+    received
+}
+
+#[test]
+fn test_email_builder_defaults() {
+    let reaction = EmailReactionBuilder::new("test-reaction")
+        .with_from("drasi@example.com")
+        .with_recipient("ops@example.com")
+        .build()
+        .unwrap();
+    assert_eq!(reaction.id(), "test-reaction");
+    assert_eq!(reaction.type_name(), "email");
+    let props = reaction.properties();
+    assert_eq!(props.get("smtp_host"), Some(&json!("localhost")));
+    assert_eq!(props.get("tls"), Some(&json!("start_tls")));
+    assert_eq!(props.get("mode"), Some(&json!("immediate")));
+    assert_eq!(props.get("digest_window_secs"), Some(&json!(300)));
+    assert_eq!(config().get_port(), 587);
+}
+
+#[test]
+fn test_email_properties_redact_password() {
+    let reaction = EmailReaction::builder("test-reaction")
+        .with_smtp_server("smtp.example.com", 2525)
+        .with_tls(SmtpTls::Tls)
+        .with_credentials("drasi", "secret")
+        .with_from("Drasi <drasi@example.com>")
+        .with_recipient("ops@example.com")
+        .with_digest(60)
+        .build()
+        .unwrap();
+    let props = reaction.properties();
+    assert_eq!(props.get("username"), Some(&json!("drasi")));
+    assert_eq!(props.get("password"), Some(&json!("***")));
+    assert_eq!(props.get("smtp_port"), Some(&json!(2525)));
+    assert_eq!(props.get("mode"), Some(&json!("digest")));
+    assert!(!format!("{:?}", reaction.properties()).contains("secret"));
+}
+
+#[test]
+fn test_email_builder_validation() {
+    let valid = || {
+        EmailReaction::builder("test-reaction")
+            .with_from("drasi@example.com")
+            .with_recipient("ops@example.com")
+    };
+
+    assert!(EmailReaction::builder("r")
+        .with_recipient("ops@example.com")
+        .build()
+        .is_err());
+    assert!(EmailReaction::builder("r")
+        .with_from("drasi@example.com")
+        .build()
+        .is_err());
+    assert!(valid().with_recipient("not an address").build().is_err());
+    assert!(valid().with_smtp_host("").build().is_err());
+    assert!(valid().with_digest(0).build().is_err());
+    assert!(valid().with_subject("{{#if}}").build().is_err());
+    assert!(valid()
+        .with_digest_template("{{#each changes}}")
+        .build()
+        .is_err());
+
+    let route = QueryConfig {
+        added: Some(TemplateSpec::with_extension(
+            "{{after.id}}",
+            EmailExtension {
+                subject: Some("{{#if".to_string()),
+            },
+        )),
+        updated: None,
+        deleted: None,
+    };
+    assert!(valid()
+        .with_query("query1")
+        .with_route("query1", route)
+        .build()
+        .is_err());
+
+    let route = QueryConfig {
+        added: Some(TemplateSpec::new("{{after.id}}")),
+        updated: None,
+        deleted: None,
+    };
+    assert!(valid()
+        .with_query("query1")
+        .with_route("other", route.clone())
+        .build()
+        .is_err());
+    assert!(valid()
+        .with_query("source.query1")
+        .with_route("query1", route)
+        .build()
+        .is_ok());
+}
+
+#[test]
+fn test_render_default_subject_and_body() {
+    let result = query_result(
+        "orders",
+        vec![
+            ResultDiff::Add {
+                data: json!({ "id": 1 }),
+            },
+            ResultDiff::Delete {
+                data: json!({ "id": 2 }),
+            },
+        ],
+    );
+    let changes = render(&config(), &result);
+    assert_eq!(changes.len(), 2);
+    assert_eq!(changes[0].subject, "[Drasi] ADD in orders");
+    assert_eq!(changes[0].body, "Added to orders:\n{\n  \"id\": 1\n}");
+    assert_eq!(changes[1].subject, "[Drasi] DELETE in orders");
+    assert_eq!(changes[1].body, "Removed from orders:\n{\n  \"id\": 2\n}");
+    assert_eq!(changes[1].before, Some(json!({ "id": 2 })));
+
+    let html = EmailReactionConfig {
+        html: true,
+        ..config()
+    };
+    let result = query_result(
+        "orders",
+        vec![ResultDiff::Add {
+            data: json!({ "name": "<b>" }),
+        }],
+    );
+    assert_eq!(
+        render(&html, &result)[0].body,
+        "<pre>Added to orders:\n{\n  &quot;name&quot;: &quot;&lt;b&gt;&quot;\n}</pre>"
+    );
+}
+
+#[test]
+fn test_render_route_templates() {
+    let mut config = config();
+    config.routes.insert(
+        "orders".to_string(),
+        QueryConfig {
+            added: Some(TemplateSpec::with_extension(
+                "Order {{after.id}} for {{after.customer}}",
+                EmailExtension {
+                    subject: Some("New order {{after.id}}\n".to_string()),
+                },
+            )),
+            updated: Some(TemplateSpec::new("")),
+            deleted: None,
+        },
+    );
+
+    let result = query_result(
+        "source.orders",
+        vec![
+            ResultDiff::Add {
+                data: json!({ "id": 7, "customer": "A&B" }),
+            },
+            ResultDiff::Update {
+                data: json!({ "id": 7 }),
+                before: json!({ "id": 7, "total": 1 }),
+                after: json!({ "id": 7, "total": 2 }),
+                grouping_keys: None,
+            },
+            ResultDiff::Delete {
+                data: json!({ "id": 7 }),
+            },
+        ],
+    );
+    let changes = render(&config, &result);
+    assert_eq!(changes.len(), 2);
+    assert_eq!(changes[0].subject, "New order 7 ");
+    assert_eq!(changes[0].body, "Order 7 for A&B");
+    assert_eq!(changes[1].subject, "[Drasi] UPDATE in source.orders");
+    assert!(changes[1]
+        .body
+        .starts_with("Updated in source.orders:\nBefore:\n"));
+
+    config.html = true;
+    assert_eq!(render(&config, &result)[0].body, "Order 7 for A&amp;B");
+}
+
+#[test]
+fn test_render_with_shared_template_helpers() {
+    let mut config = config();
+    config.default_template = Some(QueryConfig {
+        added: Some(TemplateSpec::with_extension(
+            "{{#each after.items}}{{upper this}} {{/each}}= {{format_number after.total}}",
+            EmailExtension {
+                subject: Some("{{query_name}}: {{default after.customer \"unknown\"}}".to_string()),
+            },
+        )),
+        updated: None,
+        deleted: None,
+    });
+
+    let result = query_result(
+        "orders",
+        vec![ResultDiff::Add {
+            data: json!({ "items": ["tea", "cake"], "total": 9 }),
+        }],
+    );
+    let changes = render(&config, &result);
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].subject, "orders: unknown");
+    assert_eq!(changes[0].body, "TEA CAKE = 9.00");
+}
+
+#[test]
+fn test_render_digest() {
+    let config = config();
+    let result = query_result(
+        "orders",
+        vec![
+            ResultDiff::Add {
+                data: json!({ "id": 1 }),
+            },
+            ResultDiff::Add {
+                data: json!({ "id": 2 }),
+            },
+        ],
+    );
+    let changes = render(&config, &result);
+    let renderer = Renderer::new(false);
+
+    let (subject, body) =
+        EmailReaction::render_digest(&renderer, &config, &changes, 1000, 2000, "test-reaction");
+    assert_eq!(subject, "[Drasi] 2 query result changes");
+    assert_eq!(
+        body,
+        "[Drasi] ADD in orders\nAdded to orders:\n{\n  \"id\": 1\n}\n\n[Drasi] ADD in orders\nAdded to orders:\n{\n  \"id\": 2\n}"
+    );
+
+    let config = EmailReactionConfig {
+        digest_subject: "{{count}} changes since {{window_start}}".to_string(),
+        digest_template: Some(
+            "{{#each changes}}{{operation}} {{after.id}};{{/each}} until {{window_end}}"
+                .to_string(),
+        ),
+        ..config
+    };
+    let (subject, body) =
+        EmailReaction::render_digest(&renderer, &config, &changes, 1000, 2000, "test-reaction");
+    assert_eq!(subject, "2 changes since 1000");
+    assert_eq!(body, "ADD 1;ADD 2; until 2000");
+}
+
+#[test]
+fn test_build_message_headers() {
+    let config = EmailReactionConfig {
+        to: vec![
+            "ops@example.com".to_string(),
+            "Dev <dev@example.com>".to_string(),
+        ],
+        html: true,
+        ..config()
+    };
+    let message =
+        EmailReaction::build_message(&config, "Subject line", "<p>body</p>".to_string()).unwrap();
+    let formatted = String::from_utf8(message.formatted()).unwrap();
+    assert!(formatted.contains("From: drasi@example.com"));
+    assert!(formatted.contains("To: ops@example.com, \"Dev\" <dev@example.com>"));
+    assert!(formatted.contains("Subject: Subject line"));
+    assert!(formatted.contains("Content-Type: text/html; charset=utf-8"));
+}
+
+fn mock_reaction(port: u16) -> EmailReactionBuilder {
+    EmailReaction::builder("test-reaction")
+        .with_smtp_server("127.0.0.1", port)
+        .with_tls(SmtpTls::None)
+        .with_from("drasi@example.com")
+        .with_recipient("ops@example.com")
+        .with_query("orders")
+        .with_auto_start(false)
+}
+
+#[tokio::test]
+async fn test_reaction_sends_immediate_emails() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let server = tokio::spawn(serve(listener, 2));
+
+    let reaction = mock_reaction(port).build().unwrap();
+    reaction.start().await.unwrap();
+    reaction
+        .enqueue_query_result(query_result(
+            "orders",
+            vec![
+                ResultDiff::Add {
+                    data: json!({ "id": 1 }),
+                },
+                ResultDiff::Add {
+                    data: json!({ "id": 2 }),
+                },
+            ],
+        ))
+        .await
+        .unwrap();
+
+    let emails = tokio::time::timeout(Duration::from_secs(10), server)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(emails.len(), 2);
+    assert!(emails[0].contains("Subject: [Drasi] ADD in orders"));
+    assert!(emails[0].contains("\"id\": 1"));
+    assert!(emails[1].contains("\"id\": 2"));
+
+    reaction.stop().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_reaction_sends_digest_email() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let server = tokio::spawn(serve(listener, 1));
+
+    let reaction = mock_reaction(port).with_digest(1).build().unwrap();
+    reaction.start().await.unwrap();
+    for id in 1..=3 {
+        reaction
+            .enqueue_query_result(query_result(
+                "orders",
+                vec![ResultDiff::Add {
+                    data: json!({ "id": id }),
+                }],
+            ))
+            .await
+            .unwrap();
+    }
+
+    let emails = tokio::time::timeout(Duration::from_secs(10), server)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(emails[0].contains("Subject: [Drasi] 3 query result changes"));
+    for id in 1..=3 {
+        assert!(emails[0].contains(&format!("\"id\": {id}")));
+    }
+
+    reaction.stop().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_descriptor_creates_reaction() {
+    let descriptor = EmailReactionDescriptor;
+    assert_eq!(descriptor.kind(), "email");
+
+    let config = json!({
+        "smtpHost": "smtp.example.com",
+        "smtpPort": 2525,
+        "tls": "tls",
+        "username": "drasi",
+        "password": "secret",
+        "from": "drasi@example.com",
+        "to": ["ops@example.com"],
+        "mode": "digest",
+        "digestWindowSecs": 60,
+        "routes": {
+            "query1": {
+                "added": { "template": "New: {{after.name}}", "subject": "New {{after.id}}" }
+            }
+        }
+    });
+
+    let reaction = descriptor
+        .create_reaction("email-1", vec!["query1".to_string()], &config, false)
+        .await
+        .unwrap();
+
+    assert_eq!(reaction.id(), "email-1");
+    assert!(!reaction.auto_start());
+    let props = reaction.properties();
+    assert_eq!(props.get("smtp_host"), Some(&json!("smtp.example.com")));
+    assert_eq!(props.get("tls"), Some(&json!("tls")));
+    assert_eq!(props.get("mode"), Some(&json!("digest")));
+    assert_eq!(props.get("digest_window_secs"), Some(&json!(60)));
+    assert_eq!(props.get("password"), Some(&json!("***")));
+
+    let invalid = json!({
+        "from": "drasi@example.com",
+        "to": ["ops@example.com"],
+        "routes": { "query1": { "added": { "template": "x", "channel": "y" } } }
+    });
+    assert!(descriptor
+        .create_reaction("email-2", vec!["query1".to_string()], &invalid, false)
+        .await
+        .is_err());
+}
@@ -0,0 +1,344 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Building command invocations from query results and running them.
+
+use anyhow::{anyhow, Context, Result};
+use log::{debug, warn};
+use serde_json::{json, Map, Value};
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+use drasi_lib::channels::{QueryResult, ResultDiff};
+
+use crate::config::{ExecReactionConfig, FailurePolicy, InvocationMode};
+
+/// One run of the command.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Invocation {
+    /// JSON document written to the command's stdin
+    pub payload: Value,
+    /// Environment variables describing the invocation
+    pub env: Vec<(String, String)>,
+}
+
+/// JSON description of one result change.
+fn change(diff: &ResultDiff) -> Option<(&'static str, Map<String, Value>)> {
+    let (operation, before, after) = match diff {
+        ResultDiff::Add { data } => ("ADD", None, Some(data)),
+        ResultDiff::Update { before, after, .. } => ("UPDATE", Some(before), Some(after)),
+        ResultDiff::Aggregation { before, after } => ("AGGREGATION", before.as_ref(), Some(after)),
+        ResultDiff::Delete { data } => ("DELETE", Some(data), None),
+        ResultDiff::Noop => return None,
+    };
+    let mut change = Map::new();
+    change.insert("operation".to_string(), json!(operation));
+    if let Some(before) = before {
+        change.insert("before".to_string(), before.clone());
+    }
+    if let Some(after) = after {
+        change.insert("after".to_string(), after.clone());
+    }
+    Some((operation, change))
+}
+
+/// Invocations for a query result according to the invocation mode.
+pub(crate) fn invocations(
+    config: &ExecReactionConfig,
+    query_result: &QueryResult,
+    reaction_id: &str,
+) -> Vec<Invocation> {
+    let query_id = &query_result.query_id;
+    let timestamp = query_result.timestamp.timestamp_millis();
+    let base_env = || {
+        vec![
+            ("DRASI_REACTION_ID".to_string(), reaction_id.to_string()),
+            ("DRASI_QUERY_ID".to_string(), query_id.to_string()),
+        ]
+    };
+
+    let changes = query_result.results.iter().filter_map(change);
+    match config.mode {
+        InvocationMode::PerChange => changes
+            .map(|(operation, mut change)| {
+                change.insert("queryId".to_string(), json!(query_id));
+                change.insert("timestamp".to_string(), json!(timestamp));
+                let mut env = base_env();
+                env.push(("DRASI_OPERATION".to_string(), operation.to_string()));
+                Invocation {
+                    payload: Value::Object(change),
+                    env,
+                }
+            })
+            .collect(),
+        InvocationMode::PerResult => {
+            let changes: Vec<Value> = changes.map(|(_, change)| Value::Object(change)).collect();
+            if changes.is_empty() {
+                return Vec::new();
+            }
+            let mut env = base_env();
+            env.push(("DRASI_CHANGE_COUNT".to_string(), changes.len().to_string()));
+            vec![Invocation {
+                payload: json!({
+                    "queryId": query_id,
+                    "timestamp": timestamp,
+                    "changes": changes,
+                }),
+                env,
+            }]
+        }
+    }
+}
+
+/// Run the command once, writing the payload to its stdin.
+///
+/// Fails if the command cannot be started, exits with a non-zero status or
+/// runs longer than the timeout, in which case it is killed.
+pub(crate) async fn run(config: &ExecReactionConfig, invocation: &Invocation) -> Result<()> {
+    let mut command = Command::new(&config.command);
+    command
+        .args(&config.args)
+        .envs(&config.env)
+        .envs(invocation.env.iter().cloned())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+    if let Some(dir) = &config.working_dir {
+        command.current_dir(dir);
+    }
+
+    let mut child = command
+        .spawn()
+        .with_context(|| format!("Failed to start '{}'", config.command))?;
+
+    // Write stdin concurrently so a command producing output before reading
+    // its input cannot block on full pipes
+    let mut payload = invocation.payload.to_string().into_bytes();
+    payload.push(b'\n');
+    let stdin = child.stdin.take();
+    let writer = tokio::spawn(async move {
+        if let Some(mut stdin) = stdin {
+            // Commands that exit without reading their input close the pipe
+            let _ = stdin.write_all(&payload).await;
+        }
+    });
+
+    let output = tokio::time::timeout(
+        Duration::from_millis(config.timeout_ms),
+        child.wait_with_output(),
+    )
+    .await
+    .map_err(|_| {
+        anyhow!(
+            "'{}' timed out after {}ms",
+            config.command,
+            config.timeout_ms
+        )
+    })?
+    .with_context(|| format!("Failed to wait for '{}'", config.command))?;
+    let _ = writer.await;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    for line in stdout.lines() {
+        debug!("[{}] stdout: {line}", config.command);
+    }
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(anyhow!(
+            "'{}' failed with {}: {}",
+            config.command,
+            output.status,
+            stderr.trim()
+        ))
+    }
+}
+
+/// Run the command, retrying failures with the `retry` failure policy.
+pub(crate) async fn run_with_policy(
+    config: &ExecReactionConfig,
+    invocation: &Invocation,
+    reaction_id: &str,
+) -> Result<()> {
+    let retries = match config.on_failure {
+        FailurePolicy::Retry => config.max_retries,
+        FailurePolicy::Continue | FailurePolicy::Stop => 0,
+    };
+    let mut attempt = 0;
+    loop {
+        match run(config, invocation).await {
+            Ok(()) => return Ok(()),
+            Err(e) if attempt < retries => {
+                attempt += 1;
+                warn!(
+                    "[{reaction_id}] Command failed (attempt {attempt}/{retries}): {e:#}. Retrying"
+                );
+                tokio::time::sleep(Duration::from_millis(config.retry_delay_ms)).await;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn result() -> QueryResult {
+        QueryResult::new(
+            "sensors".to_string(),
+            chrono::Utc::now(),
+            vec![
+                ResultDiff::Add {
+                    data: json!({ "id": 1 }),
+                },
+                ResultDiff::Noop,
+                ResultDiff::Delete {
+                    data: json!({ "id": 2 }),
+                },
+            ],
+            HashMap::new(),
+        )
+    }
+
+    fn sh(script: &str) -> ExecReactionConfig {
+        ExecReactionConfig {
+            command: "sh".to_string(),
+            args: vec!["-c".to_string(), script.to_string()],
+            timeout_ms: 5000,
+            retry_delay_ms: 10,
+            ..Default::default()
+        }
+    }
+
+    fn invocation() -> Invocation {
+        Invocation {
+            payload: json!({ "operation": "ADD" }),
+            env: vec![("DRASI_OPERATION".to_string(), "ADD".to_string())],
+        }
+    }
+
+    #[test]
+    fn test_per_change_invocations() {
+        let config = ExecReactionConfig::default();
+        let invocations = invocations(&config, &result(), "r1");
+        assert_eq!(invocations.len(), 2);
+        assert_eq!(invocations[0].payload["queryId"], "sensors");
+        assert_eq!(invocations[0].payload["operation"], "ADD");
+        assert_eq!(invocations[0].payload["after"], json!({ "id": 1 }));
+        assert!(invocations[0].payload.get("before").is_none());
+        assert_eq!(invocations[1].payload["before"], json!({ "id": 2 }));
+        assert!(invocations[1]
+            .env
+            .contains(&("DRASI_OPERATION".to_string(), "DELETE".to_string())));
+        assert!(invocations[1]
+            .env
+            .contains(&("DRASI_REACTION_ID".to_string(), "r1".to_string())));
+    }
+
+    #[test]
+    fn test_per_result_invocations() {
+        let config = ExecReactionConfig {
+            mode: InvocationMode::PerResult,
+            ..Default::default()
+        };
+        let invocations = invocations(&config, &result(), "r1");
+        assert_eq!(invocations.len(), 1);
+        let payload = &invocations[0].payload;
+        assert_eq!(payload["queryId"], "sensors");
+        assert_eq!(payload["changes"].as_array().unwrap().len(), 2);
+        assert_eq!(payload["changes"][1]["operation"], "DELETE");
+        assert!(invocations[0]
+            .env
+            .contains(&("DRASI_CHANGE_COUNT".to_string(), "2".to_string())));
+
+        let empty = QueryResult::new(
+            "sensors".to_string(),
+            chrono::Utc::now(),
+            vec![ResultDiff::Noop],
+            HashMap::new(),
+        );
+        assert!(super::invocations(&config, &empty, "r1").is_empty());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_run_passes_stdin_and_env() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("out");
+        let mut config = sh("cat > \"$OUT\"; echo \"$DRASI_OPERATION $GREETING\" >> \"$OUT\"");
+        config
+            .env
+            .insert("OUT".to_string(), out.display().to_string());
+        config
+            .env
+            .insert("GREETING".to_string(), "hello".to_string());
+
+        run(&config, &invocation()).await.unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&out).unwrap(),
+            "{\"operation\":\"ADD\"}\nADD hello\n"
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_run_failures() {
+        let error = run(&sh("echo broken >&2; exit 3"), &invocation())
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("broken"), "{error}");
+
+        let mut slow = sh("sleep 5");
+        slow.timeout_ms = 100;
+        let error = run(&slow, &invocation()).await.unwrap_err().to_string();
+        assert!(error.contains("timed out"), "{error}");
+
+        let missing = ExecReactionConfig {
+            command: "/nonexistent/drasi-command".to_string(),
+            ..Default::default()
+        };
+        assert!(run(&missing, &invocation()).await.is_err());
+
+        // Commands that ignore their input still succeed
+        run(&sh("exit 0"), &invocation()).await.unwrap();
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_retry_policy() {
+        let dir = tempfile::tempdir().unwrap();
+        let count = dir.path().join("count");
+        let script = format!(
+            "echo x >> '{}'; [ $(wc -l < '{}') -ge 3 ]",
+            count.display(),
+            count.display()
+        );
+
+        let mut config = sh(&script);
+        assert!(run_with_policy(&config, &invocation(), "r1").await.is_err());
+        assert_eq!(std::fs::read_to_string(&count).unwrap().lines().count(), 1);
+
+        config.on_failure = FailurePolicy::Retry;
+        run_with_policy(&config, &invocation(), "r1").await.unwrap();
+        assert_eq!(std::fs::read_to_string(&count).unwrap().lines().count(), 3);
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration types for the command execution reaction.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+fn default_max_concurrency() -> usize {
+    1
+}
+
+fn default_timeout_ms() -> u64 {
+    30000
+}
+
+fn default_max_retries() -> u32 {
+    3
+}
+
+fn default_retry_delay_ms() -> u64 {
+    1000
+}
+
+/// How often the command is run.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum InvocationMode {
+    /// Once per added, updated or deleted row (default).
+    #[default]
+    PerChange,
+    /// Once per query result, with all of its changes.
+    PerResult,
+}
+
+/// What happens when the command fails, times out or cannot be started.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum FailurePolicy {
+    /// Log the failure and continue with the next invocation (default).
+    #[default]
+    Continue,
+    /// Retry up to `max_retries` times, then log the failure and continue.
+    Retry,
+    /// Put the reaction into the `Error` state and stop running commands.
+    Stop,
+}
+
+/// Command execution reaction configuration
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExecReactionConfig {
+    /// Program to run, either a path or a name looked up in `PATH`
+    pub command: String,
+
+    /// Arguments passed to the program
+    #[serde(default)]
+    pub args: Vec<String>,
+
+    /// Working directory of the program. Defaults to the current directory.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub working_dir: Option<String>,
+
+    /// Additional environment variables of the program
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
+
+    /// How often the command is run
+    #[serde(default)]
+    pub mode: InvocationMode,
+
+    /// Maximum number of commands running at the same time. With 1, commands
+    /// run one after another in result order.
+    #[serde(default = "default_max_concurrency")]
+    pub max_concurrency: usize,
+
+    /// Time in milliseconds after which a running command is killed
+    #[serde(default = "default_timeout_ms")]
+    pub timeout_ms: u64,
+
+    /// What happens when a command fails
+    #[serde(default)]
+    pub on_failure: FailurePolicy,
+
+    /// Number of retries with the `retry` failure policy
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+
+    /// Delay in milliseconds between retries
+    #[serde(default = "default_retry_delay_ms")]
+    pub retry_delay_ms: u64,
+}
+
+impl Default for ExecReactionConfig {
+    fn default() -> Self {
+        Self {
+            command: String::new(),
+            args: Vec::new(),
+            working_dir: None,
+            env: BTreeMap::new(),
+            mode: InvocationMode::default(),
+            max_concurrency: default_max_concurrency(),
+            timeout_ms: default_timeout_ms(),
+            on_failure: FailurePolicy::default(),
+            max_retries: default_max_retries(),
+            retry_delay_ms: default_retry_delay_ms(),
+        }
+    }
+}
@@ -0,0 +1,190 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Descriptor for the exec reaction plugin.
+
+use drasi_lib::reactions::Reaction;
+use drasi_plugin_sdk::prelude::*;
+use std::collections::HashMap;
+use utoipa::OpenApi;
+
+use crate::config::{FailurePolicy, InvocationMode};
+use crate::ExecReactionBuilder;
+
+/// DTO for how often the command is run.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::exec::InvocationMode)]
+#[serde(rename_all = "snake_case")]
+pub enum InvocationModeDto {
+    PerChange,
+    PerResult,
+}
+
+impl From<InvocationModeDto> for InvocationMode {
+    fn from(dto: InvocationModeDto) -> Self {
+        match dto {
+            InvocationModeDto::PerChange => InvocationMode::PerChange,
+            InvocationModeDto::PerResult => InvocationMode::PerResult,
+        }
+    }
+}
+
+/// DTO for what happens when the command fails.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::exec::FailurePolicy)]
+#[serde(rename_all = "snake_case")]
+pub enum FailurePolicyDto {
+    Continue,
+    Retry,
+    Stop,
+}
+
+impl From<FailurePolicyDto> for FailurePolicy {
+    fn from(dto: FailurePolicyDto) -> Self {
+        match dto {
+            FailurePolicyDto::Continue => FailurePolicy::Continue,
+            FailurePolicyDto::Retry => FailurePolicy::Retry,
+            FailurePolicyDto::Stop => FailurePolicy::Stop,
+        }
+    }
+}
+
+/// Configuration DTO for the exec reaction plugin.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::exec::ExecReactionConfig)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecReactionConfigDto {
+    /// Program to run.
+    #[schema(value_type = ConfigValueString)]
+    pub command: ConfigValue<String>,
+
+    /// Arguments passed to the program.
+    #[serde(default)]
+    pub args: Vec<String>,
+
+    /// Working directory of the program.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub working_dir: Option<ConfigValue<String>>,
+
+    /// Additional environment variables of the program.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub env: HashMap<String, ConfigValue<String>>,
+
+    /// How often the command is run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<InvocationModeDto>,
+
+    /// Maximum number of commands running at the same time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueUsize>)]
+    pub max_concurrency: Option<ConfigValue<usize>>,
+
+    /// Time in milliseconds after which a running command is killed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueU64>)]
+    pub timeout_ms: Option<ConfigValue<u64>>,
+
+    /// What happens when a command fails.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_failure: Option<FailurePolicyDto>,
+
+    /// Number of retries with the `retry` failure policy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueU32>)]
+    pub max_retries: Option<ConfigValue<u32>>,
+
+    /// Delay in milliseconds between retries.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueU64>)]
+    pub retry_delay_ms: Option<ConfigValue<u64>>,
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(ExecReactionConfigDto, InvocationModeDto, FailurePolicyDto,)))]
+struct ExecReactionSchemas;
+
+/// Descriptor for the exec reaction plugin.
+pub struct ExecReactionDescriptor;
+
+#[async_trait]
+impl ReactionPluginDescriptor for ExecReactionDescriptor {
+    fn kind(&self) -> &str {
+        "exec"
+    }
+
+    fn config_version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn config_schema_name(&self) -> &str {
+        "reaction.exec.ExecReactionConfig"
+    }
+
+    fn config_schema_json(&self) -> String {
+        let api = ExecReactionSchemas::openapi();
+        serde_json::to_string(
+            &api.components
+                .as_ref()
+                .expect("OpenAPI components missing")
+                .schemas,
+        )
+        .expect("Failed to serialize config schema")
+    }
+
+    async fn create_reaction(
+        &self,
+        id: &str,
+        query_ids: Vec<String>,
+        config_json: &serde_json::Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn Reaction>> {
+        let dto: ExecReactionConfigDto = serde_json::from_value(config_json.clone())?;
+        let mapper = DtoMapper::new();
+
+        let mut builder = ExecReactionBuilder::new(id)
+            .with_queries(query_ids)
+            .with_auto_start(auto_start)
+            .with_command(mapper.resolve_string(&dto.command)?)
+            .with_args(dto.args.clone());
+
+        if let Some(dir) = mapper.resolve_optional_string(&dto.working_dir)? {
+            builder = builder.with_working_dir(dir);
+        }
+        for (name, value) in &dto.env {
+            builder = builder.with_env(name.clone(), mapper.resolve_string(value)?);
+        }
+        if let Some(mode) = dto.mode {
+            builder = builder.with_mode(mode.into());
+        }
+        if let Some(ref concurrency) = dto.max_concurrency {
+            builder = builder.with_max_concurrency(mapper.resolve_typed(concurrency)?);
+        }
+        if let Some(ref timeout) = dto.timeout_ms {
+            builder = builder.with_timeout_ms(mapper.resolve_typed(timeout)?);
+        }
+        if let Some(policy) = dto.on_failure {
+            builder = builder.with_failure_policy(policy.into());
+        }
+        if let Some(ref retries) = dto.max_retries {
+            builder = builder.with_max_retries(mapper.resolve_typed(retries)?);
+        }
+        if let Some(ref delay) = dto.retry_delay_ms {
+            builder = builder.with_retry_delay_ms(mapper.resolve_typed(delay)?);
+        }
+
+        let reaction = builder.build()?;
+        Ok(Box::new(reaction))
+    }
+}
@@ -0,0 +1,240 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use log::{debug, error, info};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use tokio::task::JoinSet;
+
+use drasi_lib::channels::{ComponentStatus, QueryResult};
+use drasi_lib::managers::log_component_start;
+use drasi_lib::reactions::common::base::{ReactionBase, ReactionBaseParams};
+use drasi_lib::Reaction;
+
+use super::command::{invocations, run_with_policy, Invocation};
+use super::config::{ExecReactionConfig, FailurePolicy};
+use super::ExecReactionBuilder;
+
+/// Exec reaction runs a local command for query result changes.
+pub struct ExecReaction {
+    base: ReactionBase,
+    config: ExecReactionConfig,
+}
+
+impl std::fmt::Debug for ExecReaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExecReaction")
+            .field("id", &self.base.id)
+            .field("command", &self.config.command)
+            .field("mode", &self.config.mode)
+            .field("max_concurrency", &self.config.max_concurrency)
+            .finish()
+    }
+}
+
+impl ExecReaction {
+    /// Create a builder for ExecReaction
+    pub fn builder(id: impl Into<String>) -> ExecReactionBuilder {
+        ExecReactionBuilder::new(id)
+    }
+
+    /// Create a new exec reaction
+    ///
+    /// The event channel is automatically injected when the reaction is added
+    /// to DrasiLib via `add_reaction()`.
+    ///
+    /// # Errors
+    ///
+    /// - Returns error if the command is empty
+    /// - Returns error if max_concurrency or timeout_ms is 0
+    pub fn new(
+        id: impl Into<String>,
+        queries: Vec<String>,
+        config: ExecReactionConfig,
+    ) -> anyhow::Result<Self> {
+        Self::validate_config(&config)?;
+        Ok(Self::from_builder(id.into(), queries, config, None, true))
+    }
+
+    /// Create from builder (internal method)
+    pub(crate) fn from_builder(
+        id: String,
+        queries: Vec<String>,
+        config: ExecReactionConfig,
+        priority_queue_capacity: Option<usize>,
+        auto_start: bool,
+    ) -> Self {
+        let mut params = ReactionBaseParams::new(id, queries).with_auto_start(auto_start);
+        if let Some(capacity) = priority_queue_capacity {
+            params = params.with_priority_queue_capacity(capacity);
+        }
+
+        Self {
+            base: ReactionBase::new(params),
+            config,
+        }
+    }
+
+    /// Validate configuration: command, concurrency and timeout
+    pub(crate) fn validate_config(config: &ExecReactionConfig) -> anyhow::Result<()> {
+        if config.command.trim().is_empty() {
+            return Err(anyhow::anyhow!("Validation error: command cannot be empty"));
+        }
+        if config.max_concurrency == 0 {
+            return Err(anyhow::anyhow!(
+                "Validation error: max_concurrency must be greater than 0"
+            ));
+        }
+        if config.timeout_ms == 0 {
+            return Err(anyhow::anyhow!(
+                "Validation error: timeout_ms must be greater than 0"
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Reaction for ExecReaction {
+    fn id(&self) -> &str {
+        &self.base.id
+    }
+
+    fn type_name(&self) -> &str {
+        "exec"
+    }
+
+    fn properties(&self) -> HashMap<String, serde_json::Value> {
+        // Environment variables commonly carry credentials
+        let mut config = self.config.clone();
+        for value in config.env.values_mut() {
+            *value = "***".to_string();
+        }
+        match serde_json::to_value(&config) {
+            Ok(serde_json::Value::Object(map)) => map.into_iter().collect(),
+            _ => HashMap::new(),
+        }
+    }
+
+    fn query_ids(&self) -> Vec<String> {
+        self.base.queries.clone()
+    }
+
+    fn auto_start(&self) -> bool {
+        self.base.get_auto_start()
+    }
+
+    async fn initialize(&self, context: drasi_lib::context::ReactionRuntimeContext) {
+        self.base.initialize(context).await;
+    }
+
+    async fn start(&self) -> Result<()> {
+        log_component_start("Exec Reaction", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Running,
+                Some("Exec reaction started".to_string()),
+            )
+            .await;
+
+        let priority_queue = self.base.priority_queue.clone();
+        let status_handle = self.base.status_handle();
+        let reaction_id = self.base.id.clone();
+        let config = Arc::new(self.config.clone());
+        let mut shutdown_rx = self.base.create_shutdown_channel().await;
+
+        let processing_task = tokio::spawn(async move {
+            info!("[{reaction_id}] Exec processing task started");
+
+            let mut pending: VecDeque<Invocation> = VecDeque::new();
+            let mut running: JoinSet<Result<()>> = JoinSet::new();
+
+            loop {
+                while running.len() < config.max_concurrency {
+                    let Some(invocation) = pending.pop_front() else {
+                        break;
+                    };
+                    let config = config.clone();
+                    let reaction_id = reaction_id.clone();
+                    running.spawn(async move {
+                        run_with_policy(&config, &invocation, &reaction_id).await
+                    });
+                }
+
+                tokio::select! {
+                    biased;
+
+                    _ = &mut shutdown_rx => {
+                        debug!("[{reaction_id}] Received shutdown signal, exiting processing loop");
+                        break;
+                    }
+
+                    Some(joined) = running.join_next(), if !running.is_empty() => {
+                        let outcome = joined
+                            .map_err(|e| anyhow::anyhow!("Command task failed: {e}"))
+                            .and_then(|result| result);
+                        if let Err(e) = outcome {
+                            error!("[{reaction_id}] {e:#}");
+                            if config.on_failure == FailurePolicy::Stop {
+                                status_handle
+                                    .set_status(
+                                        ComponentStatus::Error,
+                                        Some(format!("Command failed: {e:#}")),
+                                    )
+                                    .await;
+                                break;
+                            }
+                        }
+                    }
+
+                    query_result = priority_queue.dequeue(), if pending.is_empty() => {
+                        pending.extend(invocations(&config, &query_result, &reaction_id));
+                    }
+                }
+            }
+
+            // Aborting running commands kills their processes
+            running.shutdown().await;
+            info!("[{reaction_id}] Exec processing task ended");
+        });
+
+        self.base.set_processing_task(processing_task).await;
+
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        self.base.stop_common().await?;
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopped,
+                Some("Exec reaction stopped".to_string()),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn status(&self) -> ComponentStatus {
+        self.base.get_status().await
+    }
+
+    async fn enqueue_query_result(&self, result: QueryResult) -> anyhow::Result<()> {
+        self.base.enqueue_query_result(result).await
+    }
+}
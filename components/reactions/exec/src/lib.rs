@@ -0,0 +1,191 @@
+#![allow(unexpected_cfgs)]
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Exec reaction plugin for Drasi
+//!
+//! This plugin runs a local command or script whenever continuous query
+//! results change. Each change, or each query result in `per_result` mode, is
+//! written to the command's stdin as a single line of JSON. Commands run with
+//! a concurrency limit and a timeout, and failures are handled according to a
+//! failure policy. This makes it easy to actuate GPIO pins or poke local
+//! services on edge devices without writing a custom reaction.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use drasi_reaction_exec::{ExecReaction, FailurePolicy};
+//!
+//! let reaction = ExecReaction::builder("fan-control")
+//!     .with_query("hot-rooms")
+//!     .with_command("/usr/local/bin/fan.sh")
+//!     .with_env("GPIO_PIN", "17")
+//!     .with_timeout_ms(5000)
+//!     .with_failure_policy(FailurePolicy::Retry)
+//!     .build()?;
+//! ```
+
+mod command;
+pub mod config;
+pub mod descriptor;
+pub mod exec;
+
+pub use config::{ExecReactionConfig, FailurePolicy, InvocationMode};
+pub use exec::ExecReaction;
+
+/// Builder for exec reaction
+pub struct ExecReactionBuilder {
+    id: String,
+    queries: Vec<String>,
+    config: ExecReactionConfig,
+    priority_queue_capacity: Option<usize>,
+    auto_start: bool,
+}
+
+impl ExecReactionBuilder {
+    /// Create a new exec reaction builder with the given ID
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            queries: Vec::new(),
+            config: ExecReactionConfig::default(),
+            priority_queue_capacity: None,
+            auto_start: true,
+        }
+    }
+
+    /// Set the query IDs to subscribe to
+    pub fn with_queries(mut self, queries: Vec<String>) -> Self {
+        self.queries = queries;
+        self
+    }
+
+    /// Add a query ID to subscribe to
+    pub fn with_query(mut self, query_id: impl Into<String>) -> Self {
+        self.queries.push(query_id.into());
+        self
+    }
+
+    /// Set the program to run
+    pub fn with_command(mut self, command: impl Into<String>) -> Self {
+        self.config.command = command.into();
+        self
+    }
+
+    /// Set the arguments passed to the program
+    pub fn with_args(mut self, args: Vec<String>) -> Self {
+        self.config.args = args;
+        self
+    }
+
+    /// Add an argument passed to the program
+    pub fn with_arg(mut self, arg: impl Into<String>) -> Self {
+        self.config.args.push(arg.into());
+        self
+    }
+
+    /// Set the working directory of the program
+    pub fn with_working_dir(mut self, dir: impl Into<String>) -> Self {
+        self.config.working_dir = Some(dir.into());
+        self
+    }
+
+    /// Add an environment variable of the program
+    pub fn with_env(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.config.env.insert(name.into(), value.into());
+        self
+    }
+
+    /// Set how often the command is run
+    pub fn with_mode(mut self, mode: InvocationMode) -> Self {
+        self.config.mode = mode;
+        self
+    }
+
+    /// Set the maximum number of commands running at the same time
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.config.max_concurrency = max_concurrency;
+        self
+    }
+
+    /// Set the time in milliseconds after which a running command is killed
+    pub fn with_timeout_ms(mut self, timeout_ms: u64) -> Self {
+        self.config.timeout_ms = timeout_ms;
+        self
+    }
+
+    /// Set what happens when a command fails
+    pub fn with_failure_policy(mut self, policy: FailurePolicy) -> Self {
+        self.config.on_failure = policy;
+        self
+    }
+
+    /// Set the number of retries with the `retry` failure policy
+    pub fn with_max_retries(mut self, retries: u32) -> Self {
+        self.config.max_retries = retries;
+        self
+    }
+
+    /// Set the delay in milliseconds between retries
+    pub fn with_retry_delay_ms(mut self, delay_ms: u64) -> Self {
+        self.config.retry_delay_ms = delay_ms;
+        self
+    }
+
+    /// Set the priority queue capacity
+    pub fn with_priority_queue_capacity(mut self, capacity: usize) -> Self {
+        self.priority_queue_capacity = Some(capacity);
+        self
+    }
+
+    /// Set whether the reaction should auto-start
+    pub fn with_auto_start(mut self, auto_start: bool) -> Self {
+        self.auto_start = auto_start;
+        self
+    }
+
+    /// Set the full configuration at once
+    pub fn with_config(mut self, config: ExecReactionConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Build the exec reaction
+    pub fn build(self) -> anyhow::Result<ExecReaction> {
+        ExecReaction::validate_config(&self.config)?;
+
+        Ok(ExecReaction::from_builder(
+            self.id,
+            self.queries,
+            self.config,
+            self.priority_queue_capacity,
+            self.auto_start,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests;
+
+/// Dynamic plugin entry point.
+#[cfg(feature = "dynamic-plugin")]
+drasi_plugin_sdk::export_plugin!(
+    plugin_id = "exec-reaction",
+    core_version = env!("CARGO_PKG_VERSION"),
+    lib_version = env!("CARGO_PKG_VERSION"),
+    plugin_version = env!("CARGO_PKG_VERSION"),
+    source_descriptors = [],
+    reaction_descriptors = [descriptor::ExecReactionDescriptor],
+    bootstrap_descriptors = [],
+);
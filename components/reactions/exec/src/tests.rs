@@ -0,0 +1,251 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::descriptor::ExecReactionDescriptor;
+use drasi_lib::channels::{ComponentStatus, QueryResult, ResultDiff};
+use drasi_lib::Reaction;
+use drasi_plugin_sdk::prelude::ReactionPluginDescriptor;
+use serde_json::json;
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::Duration;
+
+fn query_result(query_id: &str, results: Vec<ResultDiff>) -> QueryResult {
+    QueryResult::new(
+        query_id.to_string(),
+        chrono::Utc::now(),
+        results,
+        HashMap::new(),
+    )
+}
+
+fn add(id: i64) -> ResultDiff {
+    ResultDiff::Add {
+        data: json!({ "id": id }),
+    }
+}
+
+/// Reaction appending the stdin of each invocation to `out`
+fn recording_reaction(out: &Path) -> ExecReactionBuilder {
+    ExecReaction::builder("test-reaction")
+        .with_query("sensors")
+        .with_command("sh")
+        .with_arg("-c")
+        .with_arg("cat >> \"$OUT\"")
+        .with_env("OUT", out.display().to_string())
+        .with_auto_start(false)
+}
+
+async fn wait_for_lines(path: &Path, count: usize) -> Vec<serde_json::Value> {
+    tokio::time::timeout(Duration::from_secs(10), async {
+        loop {
+            let content = std::fs::read_to_string(path).unwrap_or_default();
+            if content.lines().count() >= count {
+                return content
+                    .lines()
+                    .map(|line| serde_json::from_str(line).unwrap())
+                    .collect();
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+    })
+    .await
+    .unwrap()
+}
+
+#[test]
+fn test_exec_builder_defaults() {
+    let reaction = ExecReactionBuilder::new("test-reaction")
+        .with_command("/usr/bin/true")
+        .build()
+        .unwrap();
+    assert_eq!(reaction.id(), "test-reaction");
+    assert_eq!(reaction.type_name(), "exec");
+    let props = reaction.properties();
+    assert_eq!(props.get("command"), Some(&json!("/usr/bin/true")));
+    assert_eq!(props.get("args"), Some(&json!([])));
+    assert_eq!(props.get("mode"), Some(&json!("per_change")));
+    assert_eq!(props.get("max_concurrency"), Some(&json!(1)));
+    assert_eq!(props.get("timeout_ms"), Some(&json!(30000)));
+    assert_eq!(props.get("on_failure"), Some(&json!("continue")));
+    assert!(props.get("working_dir").is_none());
+}
+
+#[test]
+fn test_exec_builder_validation() {
+    let valid = || ExecReaction::builder("r").with_command("fan.sh");
+
+    assert!(ExecReaction::builder("r").build().is_err());
+    assert!(ExecReaction::builder("r")
+        .with_command("  ")
+        .build()
+        .is_err());
+    assert!(valid().with_max_concurrency(0).build().is_err());
+    assert!(valid().with_timeout_ms(0).build().is_err());
+    assert!(valid()
+        .with_args(vec!["--pin".to_string(), "17".to_string()])
+        .with_working_dir("/tmp")
+        .with_mode(InvocationMode::PerResult)
+        .with_max_concurrency(4)
+        .with_failure_policy(FailurePolicy::Retry)
+        .with_max_retries(5)
+        .with_retry_delay_ms(10)
+        .build()
+        .is_ok());
+}
+
+#[test]
+fn test_exec_properties_redact_env() {
+    let reaction = ExecReaction::builder("r")
+        .with_command("notify.sh")
+        .with_env("API_TOKEN", "secret")
+        .build()
+        .unwrap();
+    assert_eq!(
+        reaction.properties().get("env"),
+        Some(&json!({ "API_TOKEN": "***" }))
+    );
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_reaction_runs_command_per_change() {
+    let dir = tempfile::tempdir().unwrap();
+    let out = dir.path().join("out");
+
+    let reaction = recording_reaction(&out).build().unwrap();
+    reaction.start().await.unwrap();
+    reaction
+        .enqueue_query_result(query_result("sensors", vec![add(1), add(2)]))
+        .await
+        .unwrap();
+    reaction
+        .enqueue_query_result(query_result(
+            "sensors",
+            vec![ResultDiff::Delete {
+                data: json!({ "id": 1 }),
+            }],
+        ))
+        .await
+        .unwrap();
+
+    let lines = wait_for_lines(&out, 3).await;
+    assert_eq!(lines[0]["operation"], "ADD");
+    assert_eq!(lines[0]["after"], json!({ "id": 1 }));
+    assert_eq!(lines[1]["after"], json!({ "id": 2 }));
+    assert_eq!(lines[2]["operation"], "DELETE");
+    assert_eq!(lines[2]["queryId"], "sensors");
+
+    reaction.stop().await.unwrap();
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_reaction_runs_command_per_result() {
+    let dir = tempfile::tempdir().unwrap();
+    let out = dir.path().join("out");
+
+    let reaction = recording_reaction(&out)
+        .with_mode(InvocationMode::PerResult)
+        .with_max_concurrency(2)
+        .build()
+        .unwrap();
+    reaction.start().await.unwrap();
+    reaction
+        .enqueue_query_result(query_result("sensors", vec![add(1), add(2)]))
+        .await
+        .unwrap();
+
+    let lines = wait_for_lines(&out, 1).await;
+    assert_eq!(lines[0]["changes"].as_array().unwrap().len(), 2);
+
+    reaction.stop().await.unwrap();
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_stop_policy_sets_error_status() {
+    let reaction = ExecReaction::builder("test-reaction")
+        .with_query("sensors")
+        .with_command("sh")
+        .with_args(vec!["-c".to_string(), "exit 1".to_string()])
+        .with_failure_policy(FailurePolicy::Stop)
+        .with_auto_start(false)
+        .build()
+        .unwrap();
+    reaction.start().await.unwrap();
+    reaction
+        .enqueue_query_result(query_result("sensors", vec![add(1)]))
+        .await
+        .unwrap();
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while reaction.status().await != ComponentStatus::Error {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+    })
+    .await
+    .unwrap();
+
+    reaction.stop().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_descriptor_creates_reaction() {
+    let descriptor = ExecReactionDescriptor;
+    assert_eq!(descriptor.kind(), "exec");
+
+    let config = json!({
+        "command": "/opt/gpio/actuate.sh",
+        "args": ["--pin", "17"],
+        "workingDir": "/opt/gpio",
+        "env": { "LEVEL": "high" },
+        "mode": "per_result",
+        "maxConcurrency": 2,
+        "timeoutMs": 5000,
+        "onFailure": "retry",
+        "maxRetries": 2,
+        "retryDelayMs": 250
+    });
+
+    let reaction = descriptor
+        .create_reaction("exec-1", vec!["query1".to_string()], &config, false)
+        .await
+        .unwrap();
+
+    assert_eq!(reaction.id(), "exec-1");
+    assert!(!reaction.auto_start());
+    let props = reaction.properties();
+    assert_eq!(props.get("command"), Some(&json!("/opt/gpio/actuate.sh")));
+    assert_eq!(props.get("args"), Some(&json!(["--pin", "17"])));
+    assert_eq!(props.get("working_dir"), Some(&json!("/opt/gpio")));
+    assert_eq!(props.get("mode"), Some(&json!("per_result")));
+    assert_eq!(props.get("max_concurrency"), Some(&json!(2)));
+    assert_eq!(props.get("timeout_ms"), Some(&json!(5000)));
+    assert_eq!(props.get("on_failure"), Some(&json!("retry")));
+    assert_eq!(props.get("max_retries"), Some(&json!(2)));
+    assert_eq!(props.get("retry_delay_ms"), Some(&json!(250)));
+
+    let missing_command = json!({ "args": ["x"] });
+    assert!(descriptor
+        .create_reaction(
+            "exec-2",
+            vec!["query1".to_string()],
+            &missing_command,
+            false
+        )
+        .await
+        .is_err());
+}
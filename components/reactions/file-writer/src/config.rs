@@ -0,0 +1,148 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration types for the file writer reaction.
+
+use serde::{Deserialize, Serialize};
+
+fn default_directory() -> String {
+    "drasi-output".to_string()
+}
+
+fn default_file_prefix() -> String {
+    "results".to_string()
+}
+
+fn default_max_file_size_bytes() -> u64 {
+    100 * 1024 * 1024
+}
+
+fn default_rotation_interval_secs() -> u64 {
+    3600
+}
+
+fn default_flush_interval_ms() -> u64 {
+    1000
+}
+
+/// Format of the written files.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum FileFormat {
+    /// One JSON object per line (default).
+    #[default]
+    Ndjson,
+    /// Comma-separated values with a header row; rows are JSON encoded.
+    Csv,
+    /// Apache Parquet; rows are stored as JSON strings.
+    Parquet,
+}
+
+impl FileFormat {
+    /// File extension of this format, without compression suffix
+    pub fn extension(&self) -> &'static str {
+        match self {
+            FileFormat::Ndjson => "ndjson",
+            FileFormat::Csv => "csv",
+            FileFormat::Parquet => "parquet",
+        }
+    }
+}
+
+/// Compression of the written files.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum FileCompression {
+    /// Uncompressed files (default).
+    #[default]
+    None,
+    /// Gzip. NDJSON and CSV files are gzip streams with a `.gz` suffix;
+    /// Parquet files use gzip-compressed pages.
+    Gzip,
+}
+
+/// File writer reaction configuration.
+///
+/// # Example
+///
+/// ```rust
+/// use drasi_reaction_file_writer::{FileCompression, FileFormat, FileWriterReactionConfig};
+///
+/// let config = FileWriterReactionConfig {
+///     directory: "/var/lib/drasi/audit".to_string(),
+///     format: FileFormat::Csv,
+///     compression: FileCompression::Gzip,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FileWriterReactionConfig {
+    /// Directory the files are written to. Created if it does not exist.
+    ///
+    /// **Default**: `drasi-output`
+    #[serde(default = "default_directory")]
+    pub directory: String,
+
+    /// Prefix of the file names.
+    ///
+    /// **Default**: `results`
+    #[serde(default = "default_file_prefix")]
+    pub file_prefix: String,
+
+    /// File format.
+    ///
+    /// **Default**: `ndjson`
+    #[serde(default)]
+    pub format: FileFormat,
+
+    /// File compression.
+    ///
+    /// **Default**: `none`
+    #[serde(default)]
+    pub compression: FileCompression,
+
+    /// Rotate to a new file once the current one reaches this size in bytes.
+    /// 0 disables size-based rotation.
+    ///
+    /// **Default**: `104857600` (100 MiB)
+    #[serde(default = "default_max_file_size_bytes")]
+    pub max_file_size_bytes: u64,
+
+    /// Rotate to a new file once the current one is this many seconds old.
+    /// 0 disables time-based rotation.
+    ///
+    /// **Default**: `3600`
+    #[serde(default = "default_rotation_interval_secs")]
+    pub rotation_interval_secs: u64,
+
+    /// Milliseconds between flushes of buffered NDJSON and CSV output.
+    ///
+    /// **Default**: `1000`
+    #[serde(default = "default_flush_interval_ms")]
+    pub flush_interval_ms: u64,
+}
+
+impl Default for FileWriterReactionConfig {
+    fn default() -> Self {
+        Self {
+            directory: default_directory(),
+            file_prefix: default_file_prefix(),
+            format: FileFormat::default(),
+            compression: FileCompression::default(),
+            max_file_size_bytes: default_max_file_size_bytes(),
+            rotation_interval_secs: default_rotation_interval_secs(),
+            flush_interval_ms: default_flush_interval_ms(),
+        }
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Descriptor for the file writer reaction plugin.
+
+use drasi_lib::reactions::Reaction;
+use drasi_plugin_sdk::prelude::*;
+use utoipa::OpenApi;
+
+use crate::config::{FileCompression, FileFormat};
+use crate::FileWriterReactionBuilder;
+
+/// DTO for the file format.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::file_writer::FileFormat)]
+#[serde(rename_all = "lowercase")]
+pub enum FileFormatDto {
+    Ndjson,
+    Csv,
+    Parquet,
+}
+
+impl From<FileFormatDto> for FileFormat {
+    fn from(dto: FileFormatDto) -> Self {
+        match dto {
+            FileFormatDto::Ndjson => FileFormat::Ndjson,
+            FileFormatDto::Csv => FileFormat::Csv,
+            FileFormatDto::Parquet => FileFormat::Parquet,
+        }
+    }
+}
+
+/// DTO for the file compression.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::file_writer::FileCompression)]
+#[serde(rename_all = "lowercase")]
+pub enum FileCompressionDto {
+    None,
+    Gzip,
+}
+
+impl From<FileCompressionDto> for FileCompression {
+    fn from(dto: FileCompressionDto) -> Self {
+        match dto {
+            FileCompressionDto::None => FileCompression::None,
+            FileCompressionDto::Gzip => FileCompression::Gzip,
+        }
+    }
+}
+
+/// Configuration DTO for the file writer reaction plugin.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::file_writer::FileWriterReactionConfig)]
+#[serde(rename_all = "camelCase")]
+pub struct FileWriterReactionConfigDto {
+    /// Directory the files are written to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub directory: Option<ConfigValue<String>>,
+
+    /// Prefix of the file names.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub file_prefix: Option<ConfigValue<String>>,
+
+    /// File format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<FileFormatDto>,
+
+    /// File compression.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compression: Option<FileCompressionDto>,
+
+    /// File size in bytes at which files are rotated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueU64>)]
+    pub max_file_size_bytes: Option<ConfigValue<u64>>,
+
+    /// File age in seconds at which files are rotated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueU64>)]
+    pub rotation_interval_secs: Option<ConfigValue<u64>>,
+
+    /// Interval in milliseconds between flushes of buffered output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueU64>)]
+    pub flush_interval_ms: Option<ConfigValue<u64>>,
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(FileWriterReactionConfigDto, FileFormatDto, FileCompressionDto,)))]
+struct FileWriterReactionSchemas;
+
+/// Descriptor for the file writer reaction plugin.
+pub struct FileWriterReactionDescriptor;
+
+#[async_trait]
+impl ReactionPluginDescriptor for FileWriterReactionDescriptor {
+    fn kind(&self) -> &str {
+        "file-writer"
+    }
+
+    fn config_version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn config_schema_name(&self) -> &str {
+        "reaction.file_writer.FileWriterReactionConfig"
+    }
+
+    fn config_schema_json(&self) -> String {
+        let api = FileWriterReactionSchemas::openapi();
+        serde_json::to_string(
+            &api.components
+                .as_ref()
+                .expect("OpenAPI components missing")
+                .schemas,
+        )
+        .expect("Failed to serialize config schema")
+    }
+
+    async fn create_reaction(
+        &self,
+        id: &str,
+        query_ids: Vec<String>,
+        config_json: &serde_json::Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn Reaction>> {
+        let dto: FileWriterReactionConfigDto = serde_json::from_value(config_json.clone())?;
+        let mapper = DtoMapper::new();
+
+        let mut builder = FileWriterReactionBuilder::new(id)
+            .with_queries(query_ids)
+            .with_auto_start(auto_start);
+
+        if let Some(ref directory) = dto.directory {
+            builder = builder.with_directory(mapper.resolve_string(directory)?);
+        }
+        if let Some(ref prefix) = dto.file_prefix {
+            builder = builder.with_file_prefix(mapper.resolve_string(prefix)?);
+        }
+        if let Some(format) = dto.format {
+            builder = builder.with_format(format.into());
+        }
+        if let Some(compression) = dto.compression {
+            builder = builder.with_compression(compression.into());
+        }
+        if let Some(ref size) = dto.max_file_size_bytes {
+            builder = builder.with_max_file_size_bytes(mapper.resolve_typed(size)?);
+        }
+        if let Some(ref interval) = dto.rotation_interval_secs {
+            builder = builder.with_rotation_interval_secs(mapper.resolve_typed(interval)?);
+        }
+        if let Some(ref interval) = dto.flush_interval_ms {
+            builder = builder.with_flush_interval_ms(mapper.resolve_typed(interval)?);
+        }
+
+        let reaction = builder.build()?;
+        Ok(Box::new(reaction))
+    }
+}
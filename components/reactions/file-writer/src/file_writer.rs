@@ -0,0 +1,256 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use log::{debug, error, info};
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::time::MissedTickBehavior;
+
+use drasi_lib::channels::ComponentStatus;
+use drasi_lib::managers::log_component_start;
+use drasi_lib::reactions::common::base::{ReactionBase, ReactionBaseParams};
+use drasi_lib::Reaction;
+
+use super::config::FileWriterReactionConfig;
+use super::writer::{ChangeRecord, RollingWriter};
+use super::FileWriterReactionBuilder;
+
+/// File writer reaction appends query result changes to rotating files.
+pub struct FileWriterReaction {
+    base: ReactionBase,
+    config: FileWriterReactionConfig,
+}
+
+impl std::fmt::Debug for FileWriterReaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileWriterReaction")
+            .field("id", &self.base.id)
+            .field("directory", &self.config.directory)
+            .field("format", &self.config.format)
+            .finish()
+    }
+}
+
+impl FileWriterReaction {
+    /// Create a builder for FileWriterReaction
+    pub fn builder(id: impl Into<String>) -> FileWriterReactionBuilder {
+        FileWriterReactionBuilder::new(id)
+    }
+
+    /// Create a new file writer reaction
+    ///
+    /// The event channel is automatically injected when the reaction is added
+    /// to DrasiLib via `add_reaction()`.
+    ///
+    /// # Errors
+    ///
+    /// - Returns error if the directory or file prefix is empty
+    /// - Returns error if the file prefix contains a path separator
+    /// - Returns error if the flush interval is 0
+    pub fn new(
+        id: impl Into<String>,
+        queries: Vec<String>,
+        config: FileWriterReactionConfig,
+    ) -> anyhow::Result<Self> {
+        Self::validate_config(&config)?;
+        Ok(Self::from_builder(id.into(), queries, config, None, true))
+    }
+
+    /// Create from builder (internal method)
+    pub(crate) fn from_builder(
+        id: String,
+        queries: Vec<String>,
+        config: FileWriterReactionConfig,
+        priority_queue_capacity: Option<usize>,
+        auto_start: bool,
+    ) -> Self {
+        let mut params = ReactionBaseParams::new(id, queries).with_auto_start(auto_start);
+        if let Some(capacity) = priority_queue_capacity {
+            params = params.with_priority_queue_capacity(capacity);
+        }
+
+        Self {
+            base: ReactionBase::new(params),
+            config,
+        }
+    }
+
+    /// Validate configuration: output location and flush interval
+    pub(crate) fn validate_config(config: &FileWriterReactionConfig) -> anyhow::Result<()> {
+        if config.directory.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Validation error: directory cannot be empty"
+            ));
+        }
+        if config.file_prefix.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Validation error: file_prefix cannot be empty"
+            ));
+        }
+        if config.file_prefix.contains(['/', '\\']) {
+            return Err(anyhow::anyhow!(
+                "Validation error: file_prefix cannot contain path separators, got '{}'",
+                config.file_prefix
+            ));
+        }
+        if config.flush_interval_ms == 0 {
+            return Err(anyhow::anyhow!(
+                "Validation error: flush_interval_ms must be greater than 0"
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Reaction for FileWriterReaction {
+    fn id(&self) -> &str {
+        &self.base.id
+    }
+
+    fn type_name(&self) -> &str {
+        "file-writer"
+    }
+
+    fn properties(&self) -> HashMap<String, serde_json::Value> {
+        match serde_json::to_value(&self.config) {
+            Ok(serde_json::Value::Object(map)) => map.into_iter().collect(),
+            _ => HashMap::new(),
+        }
+    }
+
+    fn query_ids(&self) -> Vec<String> {
+        self.base.queries.clone()
+    }
+
+    fn auto_start(&self) -> bool {
+        self.base.get_auto_start()
+    }
+
+    async fn initialize(&self, context: drasi_lib::context::ReactionRuntimeContext) {
+        self.base.initialize(context).await;
+    }
+
+    async fn start(&self) -> Result<()> {
+        log_component_start("File Writer Reaction", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Starting,
+                Some("Starting file writer reaction".to_string()),
+            )
+            .await;
+
+        if let Err(e) = std::fs::create_dir_all(&self.config.directory) {
+            let message = format!(
+                "Failed to create directory '{}': {e}",
+                self.config.directory
+            );
+            self.base
+                .set_status(ComponentStatus::Error, Some(message.clone()))
+                .await;
+            return Err(anyhow::anyhow!(message));
+        }
+
+        self.base
+            .set_status(
+                ComponentStatus::Running,
+                Some("File writer reaction started".to_string()),
+            )
+            .await;
+
+        let priority_queue = self.base.priority_queue.clone();
+        let reaction_id = self.base.id.clone();
+        let config = self.config.clone();
+        let mut shutdown_rx = self.base.create_shutdown_channel().await;
+
+        let processing_task = tokio::spawn(async move {
+            info!(
+                "[{reaction_id}] File writer processing task started, writing {:?} files to {}",
+                config.format, config.directory
+            );
+
+            let mut writer = RollingWriter::new(config.clone());
+            let mut flush_timer =
+                tokio::time::interval(Duration::from_millis(config.flush_interval_ms));
+            flush_timer.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+            loop {
+                tokio::select! {
+                    biased;
+
+                    _ = &mut shutdown_rx => {
+                        debug!("[{reaction_id}] Received shutdown signal, exiting processing loop");
+                        break;
+                    }
+
+                    _ = flush_timer.tick() => {
+                        if let Err(e) = writer.rotate_if_expired().and_then(|_| writer.flush()) {
+                            error!("[{reaction_id}] Failed to flush output file: {e:#}");
+                        }
+                    }
+
+                    query_result = priority_queue.dequeue() => {
+                        let records = ChangeRecord::from_query_result(&query_result);
+                        if let Err(e) = writer.write(&records) {
+                            error!(
+                                "[{reaction_id}] Failed to write {} changes of query '{}': {e:#}",
+                                records.len(),
+                                query_result.query_id
+                            );
+                        }
+                    }
+                }
+            }
+
+            match writer.close() {
+                Ok(Some(path)) => info!("[{reaction_id}] Completed {}", path.display()),
+                Ok(None) => {}
+                Err(e) => error!("[{reaction_id}] Failed to complete output file: {e:#}"),
+            }
+
+            info!("[{reaction_id}] File writer processing task ended");
+        });
+
+        self.base.set_processing_task(processing_task).await;
+
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        self.base.stop_common().await?;
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopped,
+                Some("File writer reaction stopped".to_string()),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn status(&self) -> ComponentStatus {
+        self.base.get_status().await
+    }
+
+    async fn enqueue_query_result(
+        &self,
+        result: drasi_lib::channels::QueryResult,
+    ) -> anyhow::Result<()> {
+        self.base.enqueue_query_result(result).await
+    }
+}
@@ -0,0 +1,165 @@
+#![allow(unexpected_cfgs)]
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! File writer reaction plugin for Drasi
+//!
+//! This plugin appends query result changes to files in NDJSON, CSV or
+//! Parquet format. Files are rotated by size and age and can be gzip
+//! compressed, providing an audit trail of everything the queries produced
+//! that can be analysed offline.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use drasi_reaction_file_writer::{FileCompression, FileFormat, FileWriterReaction};
+//!
+//! let reaction = FileWriterReaction::builder("audit-trail")
+//!     .with_query("orders")
+//!     .with_directory("/var/lib/drasi/audit")
+//!     .with_format(FileFormat::Parquet)
+//!     .with_compression(FileCompression::Gzip)
+//!     .with_rotation_interval_secs(3600)
+//!     .build()?;
+//! ```
+
+pub mod config;
+pub mod descriptor;
+pub mod file_writer;
+mod writer;
+
+pub use config::{FileCompression, FileFormat, FileWriterReactionConfig};
+pub use file_writer::FileWriterReaction;
+
+/// Builder for file writer reaction
+pub struct FileWriterReactionBuilder {
+    id: String,
+    queries: Vec<String>,
+    config: FileWriterReactionConfig,
+    priority_queue_capacity: Option<usize>,
+    auto_start: bool,
+}
+
+impl FileWriterReactionBuilder {
+    /// Create a new file writer reaction builder with the given ID
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            queries: Vec::new(),
+            config: FileWriterReactionConfig::default(),
+            priority_queue_capacity: None,
+            auto_start: true,
+        }
+    }
+
+    /// Set the query IDs to subscribe to
+    pub fn with_queries(mut self, queries: Vec<String>) -> Self {
+        self.queries = queries;
+        self
+    }
+
+    /// Add a query ID to subscribe to
+    pub fn with_query(mut self, query_id: impl Into<String>) -> Self {
+        self.queries.push(query_id.into());
+        self
+    }
+
+    /// Set the directory the files are written to
+    pub fn with_directory(mut self, directory: impl Into<String>) -> Self {
+        self.config.directory = directory.into();
+        self
+    }
+
+    /// Set the prefix of the file names
+    pub fn with_file_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.config.file_prefix = prefix.into();
+        self
+    }
+
+    /// Set the file format
+    pub fn with_format(mut self, format: FileFormat) -> Self {
+        self.config.format = format;
+        self
+    }
+
+    /// Set the file compression
+    pub fn with_compression(mut self, compression: FileCompression) -> Self {
+        self.config.compression = compression;
+        self
+    }
+
+    /// Set the file size in bytes at which files are rotated (0 disables size-based rotation)
+    pub fn with_max_file_size_bytes(mut self, bytes: u64) -> Self {
+        self.config.max_file_size_bytes = bytes;
+        self
+    }
+
+    /// Set the file age in seconds at which files are rotated (0 disables time-based rotation)
+    pub fn with_rotation_interval_secs(mut self, secs: u64) -> Self {
+        self.config.rotation_interval_secs = secs;
+        self
+    }
+
+    /// Set the interval in milliseconds between flushes of buffered output
+    pub fn with_flush_interval_ms(mut self, interval_ms: u64) -> Self {
+        self.config.flush_interval_ms = interval_ms;
+        self
+    }
+
+    /// Set the priority queue capacity
+    pub fn with_priority_queue_capacity(mut self, capacity: usize) -> Self {
+        self.priority_queue_capacity = Some(capacity);
+        self
+    }
+
+    /// Set whether the reaction should auto-start
+    pub fn with_auto_start(mut self, auto_start: bool) -> Self {
+        self.auto_start = auto_start;
+        self
+    }
+
+    /// Set the full configuration at once
+    pub fn with_config(mut self, config: FileWriterReactionConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Build the file writer reaction
+    pub fn build(self) -> anyhow::Result<FileWriterReaction> {
+        FileWriterReaction::validate_config(&self.config)?;
+
+        Ok(FileWriterReaction::from_builder(
+            self.id,
+            self.queries,
+            self.config,
+            self.priority_queue_capacity,
+            self.auto_start,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests;
+
+/// Dynamic plugin entry point.
+#[cfg(feature = "dynamic-plugin")]
+drasi_plugin_sdk::export_plugin!(
+    plugin_id = "file-writer-reaction",
+    core_version = env!("CARGO_PKG_VERSION"),
+    lib_version = env!("CARGO_PKG_VERSION"),
+    plugin_version = env!("CARGO_PKG_VERSION"),
+    source_descriptors = [],
+    reaction_descriptors = [descriptor::FileWriterReactionDescriptor],
+    bootstrap_descriptors = [],
+);
@@ -0,0 +1,184 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::descriptor::FileWriterReactionDescriptor;
+use drasi_lib::channels::{ComponentStatus, QueryResult, ResultDiff};
+use drasi_lib::Reaction;
+use drasi_plugin_sdk::prelude::ReactionPluginDescriptor;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::time::Duration;
+
+fn query_result(query_id: &str, results: Vec<ResultDiff>) -> QueryResult {
+    QueryResult::new(
+        query_id.to_string(),
+        chrono::Utc::now(),
+        results,
+        HashMap::new(),
+    )
+}
+
+#[test]
+fn test_file_writer_builder_defaults() {
+    let reaction = FileWriterReactionBuilder::new("test-reaction")
+        .build()
+        .unwrap();
+    assert_eq!(reaction.id(), "test-reaction");
+    assert_eq!(reaction.type_name(), "file-writer");
+    let props = reaction.properties();
+    assert_eq!(props.get("directory"), Some(&json!("drasi-output")));
+    assert_eq!(props.get("file_prefix"), Some(&json!("results")));
+    assert_eq!(props.get("format"), Some(&json!("ndjson")));
+    assert_eq!(props.get("compression"), Some(&json!("none")));
+    assert_eq!(props.get("max_file_size_bytes"), Some(&json!(104857600)));
+    assert_eq!(props.get("rotation_interval_secs"), Some(&json!(3600)));
+}
+
+#[test]
+fn test_file_writer_builder_validation() {
+    assert!(FileWriterReaction::builder("r")
+        .with_directory("")
+        .build()
+        .is_err());
+    assert!(FileWriterReaction::builder("r")
+        .with_file_prefix("")
+        .build()
+        .is_err());
+    assert!(FileWriterReaction::builder("r")
+        .with_file_prefix("a/b")
+        .build()
+        .is_err());
+    assert!(FileWriterReaction::builder("r")
+        .with_flush_interval_ms(0)
+        .build()
+        .is_err());
+    assert!(FileWriterReaction::builder("r")
+        .with_format(FileFormat::Parquet)
+        .with_compression(FileCompression::Gzip)
+        .with_max_file_size_bytes(0)
+        .with_rotation_interval_secs(0)
+        .build()
+        .is_ok());
+}
+
+#[tokio::test]
+async fn test_reaction_writes_and_completes_file_on_stop() {
+    let dir = tempfile::tempdir().unwrap();
+    let reaction = FileWriterReaction::builder("test-reaction")
+        .with_query("orders")
+        .with_directory(dir.path().join("out").display().to_string())
+        .with_file_prefix("orders")
+        .with_flush_interval_ms(50)
+        .with_auto_start(false)
+        .build()
+        .unwrap();
+    reaction.start().await.unwrap();
+    assert_eq!(reaction.status().await, ComponentStatus::Running);
+
+    reaction
+        .enqueue_query_result(query_result(
+            "orders",
+            vec![
+                ResultDiff::Add {
+                    data: json!({ "id": 1 }),
+                },
+                ResultDiff::Delete {
+                    data: json!({ "id": 1 }),
+                },
+            ],
+        ))
+        .await
+        .unwrap();
+
+    // The flushed lines are visible in the in-progress file
+    let out = dir.path().join("out");
+    let mut part = None;
+    for _ in 0..50 {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let found = std::fs::read_dir(&out).ok().and_then(|mut entries| {
+            entries.find_map(|entry| {
+                let path = entry.ok()?.path();
+                let content = std::fs::read_to_string(&path).ok()?;
+                (content.lines().count() == 2).then_some(path)
+            })
+        });
+        if found.is_some() {
+            part = found;
+            break;
+        }
+    }
+    let part = part.expect("no flushed output file");
+    assert!(part.to_string_lossy().ends_with(".ndjson.part"));
+
+    reaction.stop().await.unwrap();
+    assert_eq!(reaction.status().await, ComponentStatus::Stopped);
+
+    let files: Vec<_> = std::fs::read_dir(&out)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    assert_eq!(files.len(), 1);
+    let name = files[0].file_name().unwrap().to_string_lossy().to_string();
+    assert!(name.starts_with("orders-"));
+    assert!(name.ends_with(".ndjson"));
+
+    let lines: Vec<Value> = std::fs::read_to_string(&files[0])
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines[0]["query_id"], "orders");
+    assert_eq!(lines[0]["operation"], "ADD");
+    assert_eq!(lines[0]["after"], json!({ "id": 1 }));
+    assert_eq!(lines[1]["operation"], "DELETE");
+    assert_eq!(lines[1]["before"], json!({ "id": 1 }));
+}
+
+#[tokio::test]
+async fn test_descriptor_creates_reaction() {
+    let descriptor = FileWriterReactionDescriptor;
+    assert_eq!(descriptor.kind(), "file-writer");
+
+    let config = json!({
+        "directory": "/tmp/drasi-audit",
+        "filePrefix": "audit",
+        "format": "csv",
+        "compression": "gzip",
+        "maxFileSizeBytes": 1048576,
+        "rotationIntervalSecs": 600,
+        "flushIntervalMs": 500
+    });
+
+    let reaction = descriptor
+        .create_reaction("file-1", vec!["query1".to_string()], &config, false)
+        .await
+        .unwrap();
+
+    assert_eq!(reaction.id(), "file-1");
+    assert!(!reaction.auto_start());
+    let props = reaction.properties();
+    assert_eq!(props.get("directory"), Some(&json!("/tmp/drasi-audit")));
+    assert_eq!(props.get("format"), Some(&json!("csv")));
+    assert_eq!(props.get("compression"), Some(&json!("gzip")));
+    assert_eq!(props.get("max_file_size_bytes"), Some(&json!(1048576)));
+    assert_eq!(props.get("rotation_interval_secs"), Some(&json!(600)));
+    assert_eq!(props.get("flush_interval_ms"), Some(&json!(500)));
+
+    let invalid = json!({ "format": "avro" });
+    assert!(descriptor
+        .create_reaction("file-2", vec!["query1".to_string()], &invalid, false)
+        .await
+        .is_err());
+}
@@ -0,0 +1,591 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Rolling files of result changes in NDJSON, CSV or Parquet format.
+
+use anyhow::{Context, Result};
+use arrow_array::{ArrayRef, Int64Array, RecordBatch, StringArray};
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use flate2::write::GzEncoder;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::{Compression, GzipLevel};
+use parquet::file::properties::WriterProperties;
+use serde_json::{json, Value};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use drasi_lib::channels::{QueryResult, ResultDiff};
+
+use crate::config::{FileCompression, FileFormat, FileWriterReactionConfig};
+
+/// Column names shared by all formats.
+pub(crate) const COLUMNS: [&str; 5] = ["timestamp", "query_id", "operation", "before", "after"];
+
+/// One result change as written to a file.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ChangeRecord {
+    /// Query result timestamp in milliseconds
+    pub timestamp: i64,
+    pub query_id: String,
+    pub operation: &'static str,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+impl ChangeRecord {
+    /// Records for every change of a query result, skipping no-ops.
+    pub(crate) fn from_query_result(result: &QueryResult) -> Vec<ChangeRecord> {
+        let timestamp = result.timestamp.timestamp_millis();
+        result
+            .results
+            .iter()
+            .filter_map(|diff| {
+                let (operation, before, after) = match diff {
+                    ResultDiff::Add { data } => ("ADD", None, Some(data.clone())),
+                    ResultDiff::Update { before, after, .. } => {
+                        ("UPDATE", Some(before.clone()), Some(after.clone()))
+                    }
+                    ResultDiff::Aggregation { before, after } => {
+                        ("AGGREGATION", before.clone(), Some(after.clone()))
+                    }
+                    ResultDiff::Delete { data } => ("DELETE", Some(data.clone()), None),
+                    ResultDiff::Noop => return None,
+                };
+                Some(ChangeRecord {
+                    timestamp,
+                    query_id: result.query_id.clone(),
+                    operation,
+                    before,
+                    after,
+                })
+            })
+            .collect()
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "timestamp": self.timestamp,
+            "query_id": self.query_id,
+            "operation": self.operation,
+            "before": self.before,
+            "after": self.after,
+        })
+    }
+}
+
+fn json_text(value: &Option<Value>) -> Option<String> {
+    value.as_ref().map(Value::to_string)
+}
+
+/// Writer that counts the bytes passed to the underlying file.
+struct CountingWriter<W> {
+    inner: W,
+    count: Arc<AtomicU64>,
+}
+
+impl<W: Write> Write for CountingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.count.fetch_add(written as u64, Ordering::Relaxed);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+type FileOutput = CountingWriter<BufWriter<File>>;
+
+/// Byte stream of NDJSON and CSV files, optionally gzip compressed.
+enum Output {
+    Plain(FileOutput),
+    Gzip(GzEncoder<FileOutput>),
+}
+
+impl Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Output::Plain(writer) => writer.write(buf),
+            Output::Gzip(writer) => writer.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Output::Plain(writer) => writer.flush(),
+            Output::Gzip(writer) => writer.flush(),
+        }
+    }
+}
+
+impl Output {
+    fn new(file: FileOutput, compression: FileCompression) -> Self {
+        match compression {
+            FileCompression::None => Output::Plain(file),
+            FileCompression::Gzip => {
+                Output::Gzip(GzEncoder::new(file, flate2::Compression::default()))
+            }
+        }
+    }
+
+    fn finish(self) -> io::Result<()> {
+        match self {
+            Output::Plain(mut writer) => writer.flush(),
+            Output::Gzip(writer) => writer.finish()?.flush(),
+        }
+    }
+}
+
+enum FormatWriter {
+    Ndjson(Output),
+    Csv(csv::Writer<Output>),
+    Parquet(ArrowWriter<FileOutput>),
+}
+
+/// Arrow schema of Parquet files.
+pub(crate) fn parquet_schema() -> SchemaRef {
+    Arc::new(Schema::new(vec![
+        Field::new("timestamp", DataType::Int64, false),
+        Field::new("query_id", DataType::Utf8, false),
+        Field::new("operation", DataType::Utf8, false),
+        Field::new("before", DataType::Utf8, true),
+        Field::new("after", DataType::Utf8, true),
+    ]))
+}
+
+fn record_batch(records: &[ChangeRecord]) -> Result<RecordBatch> {
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(Int64Array::from_iter_values(
+            records.iter().map(|r| r.timestamp),
+        )),
+        Arc::new(StringArray::from_iter_values(
+            records.iter().map(|r| r.query_id.as_str()),
+        )),
+        Arc::new(StringArray::from_iter_values(
+            records.iter().map(|r| r.operation),
+        )),
+        Arc::new(StringArray::from_iter(
+            records.iter().map(|r| json_text(&r.before)),
+        )),
+        Arc::new(StringArray::from_iter(
+            records.iter().map(|r| json_text(&r.after)),
+        )),
+    ];
+    Ok(RecordBatch::try_new(parquet_schema(), columns)?)
+}
+
+impl FormatWriter {
+    fn open(file: File, config: &FileWriterReactionConfig, count: Arc<AtomicU64>) -> Result<Self> {
+        let file = CountingWriter {
+            inner: BufWriter::new(file),
+            count,
+        };
+        Ok(match config.format {
+            FileFormat::Ndjson => FormatWriter::Ndjson(Output::new(file, config.compression)),
+            FileFormat::Csv => {
+                let mut writer = csv::Writer::from_writer(Output::new(file, config.compression));
+                writer.write_record(COLUMNS)?;
+                FormatWriter::Csv(writer)
+            }
+            FileFormat::Parquet => {
+                let compression = match config.compression {
+                    FileCompression::None => Compression::UNCOMPRESSED,
+                    FileCompression::Gzip => Compression::GZIP(GzipLevel::default()),
+                };
+                let properties = WriterProperties::builder()
+                    .set_compression(compression)
+                    .build();
+                FormatWriter::Parquet(ArrowWriter::try_new(
+                    file,
+                    parquet_schema(),
+                    Some(properties),
+                )?)
+            }
+        })
+    }
+
+    fn write(&mut self, records: &[ChangeRecord]) -> Result<()> {
+        match self {
+            FormatWriter::Ndjson(output) => {
+                for record in records {
+                    serde_json::to_writer(&mut *output, &record.to_json())?;
+                    output.write_all(b"\n")?;
+                }
+            }
+            FormatWriter::Csv(writer) => {
+                for record in records {
+                    writer.write_record([
+                        record.timestamp.to_string(),
+                        record.query_id.clone(),
+                        record.operation.to_string(),
+                        json_text(&record.before).unwrap_or_default(),
+                        json_text(&record.after).unwrap_or_default(),
+                    ])?;
+                }
+            }
+            FormatWriter::Parquet(writer) => writer.write(&record_batch(records)?)?,
+        }
+        Ok(())
+    }
+
+    /// Bytes buffered in memory that are not yet passed to the file.
+    fn buffered_bytes(&self) -> u64 {
+        match self {
+            FormatWriter::Parquet(writer) => writer.in_progress_size() as u64,
+            _ => 0,
+        }
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        match self {
+            FormatWriter::Ndjson(output) => output.flush()?,
+            FormatWriter::Csv(writer) => writer.flush()?,
+            // Parquet files are only readable once closed, row groups are
+            // written when full
+            FormatWriter::Parquet(_) => {}
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Result<()> {
+        match self {
+            FormatWriter::Ndjson(output) => output.finish()?,
+            FormatWriter::Csv(writer) => writer
+                .into_inner()
+                .map_err(|e| anyhow::anyhow!("Failed to flush CSV writer: {}", e.error()))?
+                .finish()?,
+            FormatWriter::Parquet(writer) => {
+                writer.close()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+struct OpenFile {
+    writer: FormatWriter,
+    part_path: PathBuf,
+    path: PathBuf,
+    opened_at: Instant,
+    bytes: Arc<AtomicU64>,
+}
+
+/// Writes change records to a sequence of files, rotating by size and age.
+///
+/// Files are written with a `.part` suffix that is removed when the file is
+/// complete, so readers never see partially written files.
+pub(crate) struct RollingWriter {
+    config: FileWriterReactionConfig,
+    sequence: u64,
+    current: Option<OpenFile>,
+}
+
+impl RollingWriter {
+    pub(crate) fn new(config: FileWriterReactionConfig) -> Self {
+        Self {
+            config,
+            sequence: 0,
+            current: None,
+        }
+    }
+
+    fn file_name(&self) -> String {
+        let mut name = format!(
+            "{}-{}-{:06}.{}",
+            self.config.file_prefix,
+            chrono::Utc::now().format("%Y%m%dT%H%M%S%3fZ"),
+            self.sequence,
+            self.config.format.extension()
+        );
+        if self.config.compression == FileCompression::Gzip
+            && self.config.format != FileFormat::Parquet
+        {
+            name.push_str(".gz");
+        }
+        name
+    }
+
+    fn open(&mut self) -> Result<&mut OpenFile> {
+        let directory = Path::new(&self.config.directory);
+        std::fs::create_dir_all(directory)
+            .with_context(|| format!("Failed to create directory {}", directory.display()))?;
+
+        self.sequence += 1;
+        let path = directory.join(self.file_name());
+        let part_path = PathBuf::from(format!("{}.part", path.display()));
+        let file = File::create(&part_path)
+            .with_context(|| format!("Failed to create {}", part_path.display()))?;
+        let bytes = Arc::new(AtomicU64::new(0));
+        let writer = FormatWriter::open(file, &self.config, bytes.clone())?;
+
+        Ok(self.current.insert(OpenFile {
+            writer,
+            part_path,
+            path,
+            opened_at: Instant::now(),
+            bytes,
+        }))
+    }
+
+    /// Write records, opening and rotating files as needed.
+    pub(crate) fn write(&mut self, records: &[ChangeRecord]) -> Result<()> {
+        if records.is_empty() {
+            return Ok(());
+        }
+        self.rotate_if_expired()?;
+
+        let file = match self.current {
+            Some(ref mut file) => file,
+            None => self.open()?,
+        };
+        file.writer.write(records)?;
+
+        let size = file.bytes.load(Ordering::Relaxed) + file.writer.buffered_bytes();
+        if self.config.max_file_size_bytes > 0 && size >= self.config.max_file_size_bytes {
+            self.close()?;
+        }
+        Ok(())
+    }
+
+    /// Close the current file if it is older than the rotation interval.
+    pub(crate) fn rotate_if_expired(&mut self) -> Result<()> {
+        let interval = Duration::from_secs(self.config.rotation_interval_secs);
+        let expired = self
+            .current
+            .as_ref()
+            .is_some_and(|file| !interval.is_zero() && file.opened_at.elapsed() >= interval);
+        if expired {
+            self.close()?;
+        }
+        Ok(())
+    }
+
+    /// Flush buffered NDJSON and CSV output to the current file.
+    pub(crate) fn flush(&mut self) -> Result<()> {
+        if let Some(file) = self.current.as_mut() {
+            file.writer.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Complete the current file, returning its path.
+    pub(crate) fn close(&mut self) -> Result<Option<PathBuf>> {
+        let Some(file) = self.current.take() else {
+            return Ok(None);
+        };
+        file.writer.finish()?;
+        std::fs::rename(&file.part_path, &file.path)
+            .with_context(|| format!("Failed to rename {}", file.part_path.display()))?;
+        Ok(Some(file.path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::io::Read;
+
+    fn records() -> Vec<ChangeRecord> {
+        let result = QueryResult::new(
+            "orders".to_string(),
+            chrono::Utc::now(),
+            vec![
+                ResultDiff::Add {
+                    data: json!({ "id": 1, "note": "a,\"b\"" }),
+                },
+                ResultDiff::Noop,
+                ResultDiff::Update {
+                    data: json!({}),
+                    before: json!({ "id": 1 }),
+                    after: json!({ "id": 1, "total": 2 }),
+                    grouping_keys: None,
+                },
+                ResultDiff::Delete {
+                    data: json!({ "id": 1 }),
+                },
+            ],
+            HashMap::new(),
+        );
+        ChangeRecord::from_query_result(&result)
+    }
+
+    fn config(
+        dir: &Path,
+        format: FileFormat,
+        compression: FileCompression,
+    ) -> FileWriterReactionConfig {
+        FileWriterReactionConfig {
+            directory: dir.display().to_string(),
+            format,
+            compression,
+            ..Default::default()
+        }
+    }
+
+    fn files(dir: &Path) -> Vec<PathBuf> {
+        let mut files: Vec<PathBuf> = std::fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        files.sort();
+        files
+    }
+
+    #[test]
+    fn test_records_from_query_result() {
+        let records = records();
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0].operation, "ADD");
+        assert_eq!(records[0].before, None);
+        assert_eq!(records[1].operation, "UPDATE");
+        assert_eq!(records[1].before, Some(json!({ "id": 1 })));
+        assert_eq!(records[2].operation, "DELETE");
+        assert_eq!(records[2].after, None);
+    }
+
+    #[test]
+    fn test_ndjson_file_is_renamed_on_close() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut writer = RollingWriter::new(config(
+            dir.path(),
+            FileFormat::Ndjson,
+            FileCompression::None,
+        ));
+        writer.write(&records()).unwrap();
+        writer.flush().unwrap();
+
+        let part = files(dir.path());
+        assert_eq!(part.len(), 1);
+        assert!(part[0].to_string_lossy().ends_with(".ndjson.part"));
+
+        let path = writer.close().unwrap().unwrap();
+        assert_eq!(files(dir.path()), vec![path.clone()]);
+        let name = path.file_name().unwrap().to_string_lossy().to_string();
+        assert!(name.starts_with("results-"));
+        assert!(name.ends_with("-000001.ndjson"));
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<Value> = content
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0]["operation"], "ADD");
+        assert_eq!(lines[0]["after"]["note"], "a,\"b\"");
+        assert_eq!(lines[0]["before"], Value::Null);
+        assert_eq!(lines[2]["before"], json!({ "id": 1 }));
+    }
+
+    #[test]
+    fn test_gzip_csv() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut writer =
+            RollingWriter::new(config(dir.path(), FileFormat::Csv, FileCompression::Gzip));
+        writer.write(&records()).unwrap();
+        let path = writer.close().unwrap().unwrap();
+        assert!(path.to_string_lossy().ends_with(".csv.gz"));
+
+        let mut content = String::new();
+        flate2::read::GzDecoder::new(File::open(&path).unwrap())
+            .read_to_string(&mut content)
+            .unwrap();
+        let mut reader = csv::Reader::from_reader(content.as_bytes());
+        assert_eq!(
+            reader.headers().unwrap().iter().collect::<Vec<_>>(),
+            COLUMNS
+        );
+        let rows: Vec<csv::StringRecord> = reader.records().map(|r| r.unwrap()).collect();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(&rows[0][1], "orders");
+        assert_eq!(&rows[0][3], "");
+        let after: Value = serde_json::from_str(&rows[0][4]).unwrap();
+        assert_eq!(after["note"], "a,\"b\"");
+    }
+
+    #[test]
+    fn test_parquet() {
+        use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+        let dir = tempfile::tempdir().unwrap();
+        let mut writer = RollingWriter::new(config(
+            dir.path(),
+            FileFormat::Parquet,
+            FileCompression::Gzip,
+        ));
+        writer.write(&records()).unwrap();
+        writer.write(&records()).unwrap();
+        let path = writer.close().unwrap().unwrap();
+        assert!(path.to_string_lossy().ends_with(".parquet"));
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(&path).unwrap())
+            .unwrap()
+            .build()
+            .unwrap();
+        let batches: Vec<RecordBatch> = reader.map(|batch| batch.unwrap()).collect();
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 6);
+        assert_eq!(batches[0].schema(), parquet_schema());
+        let operations = batches[0]
+            .column(2)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(operations.value(2), "DELETE");
+        let after = batches[0]
+            .column(4)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert!(after.is_null(2));
+    }
+
+    #[test]
+    fn test_size_rotation() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut writer = RollingWriter::new(FileWriterReactionConfig {
+            max_file_size_bytes: 1,
+            ..config(dir.path(), FileFormat::Ndjson, FileCompression::None)
+        });
+        writer.write(&records()).unwrap();
+        writer.write(&records()).unwrap();
+        assert!(writer.close().unwrap().is_none());
+
+        let files = files(dir.path());
+        assert_eq!(files.len(), 2);
+        assert!(files[0].to_string_lossy().ends_with("-000001.ndjson"));
+        assert!(files[1].to_string_lossy().ends_with("-000002.ndjson"));
+    }
+
+    #[test]
+    fn test_time_rotation() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut writer = RollingWriter::new(FileWriterReactionConfig {
+            rotation_interval_secs: 1,
+            ..config(dir.path(), FileFormat::Ndjson, FileCompression::None)
+        });
+        writer.write(&records()).unwrap();
+        writer.rotate_if_expired().unwrap();
+        assert_eq!(files(dir.path()).len(), 1);
+        assert!(files(dir.path())[0].to_string_lossy().ends_with(".part"));
+
+        std::thread::sleep(Duration::from_millis(1100));
+        writer.rotate_if_expired().unwrap();
+        assert!(files(dir.path())[0].to_string_lossy().ends_with(".ndjson"));
+    }
+}
@@ -1,6 +1,5 @@
 use anyhow::Result;
 use async_trait::async_trait;
-use handlebars::Handlebars;
 use log::{debug, error, info, warn};
 use reqwest::{
     header::{HeaderMap, HeaderName, HeaderValue},
@@ -16,6 +15,7 @@ use tokio::sync::mpsc;
 
 use drasi_lib::channels::{ComponentStatus, ResultDiff};
 use drasi_lib::reactions::common::base::{ReactionBase, ReactionBaseParams};
+use drasi_lib::templating::TemplateEngine;
 use drasi_lib::Reaction;
 
 use crate::adaptive_batcher::{AdaptiveBatchConfig, AdaptiveBatcher};
@@ -51,6 +51,8 @@ pub struct AdaptiveHttpReaction {
     client: Client,
     // Support batch endpoints
     batch_endpoints_enabled: bool,
+    // Renders URL, body and header templates
+    engine: TemplateEngine,
 }
 
 impl AdaptiveHttpReaction {
@@ -128,6 +130,7 @@ impl AdaptiveHttpReaction {
             adaptive_config: utils_adaptive_config,
             client,
             batch_endpoints_enabled,
+            engine: TemplateEngine::new(),
             config,
         }
     }
@@ -260,13 +263,13 @@ impl AdaptiveHttpReaction {
             );
 
             // Render URL
-            let handlebars = Handlebars::new();
-            let full_url = handlebars
-                .render_template(&format!("{}{}", self.base_url, call_spec.url), &context)?;
+            let full_url = self
+                .engine
+                .render(&format!("{}{}", self.base_url, call_spec.url), &context)?;
 
             // Render body
             let body = if !call_spec.body.is_empty() {
-                handlebars.render_template(&call_spec.body, &context)?
+                self.engine.render(&call_spec.body, &context)?
             } else {
                 serde_json::to_string(&data)?
             };
@@ -284,8 +287,7 @@ impl AdaptiveHttpReaction {
 
             for (key, value) in &call_spec.headers {
                 let header_name = HeaderName::from_bytes(key.as_bytes())?;
-                let header_value =
-                    HeaderValue::from_str(&handlebars.render_template(value, &context)?)?;
+                let header_value = HeaderValue::from_str(&self.engine.render(value, &context)?)?;
                 headers.insert(header_name, header_value);
             }
 
@@ -550,6 +552,7 @@ impl Reaction for AdaptiveHttpReaction {
             adaptive_config: self.adaptive_config.clone(),
             client: self.client.clone(),
             batch_endpoints_enabled: self.batch_endpoints_enabled,
+            engine: self.engine.clone(),
         });
 
         // Create shutdown channel for graceful termination
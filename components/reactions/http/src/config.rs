@@ -19,6 +19,7 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::time::Duration;
 
 fn default_base_url() -> String {
     "http://localhost".to_string()
@@ -28,6 +29,26 @@ fn default_timeout_ms() -> u64 {
     5000
 }
 
+fn default_max_retries() -> u32 {
+    3
+}
+
+fn default_initial_backoff_ms() -> u64 {
+    500
+}
+
+fn default_max_backoff_ms() -> u64 {
+    30000
+}
+
+fn default_signature_header() -> String {
+    "X-Drasi-Signature".to_string()
+}
+
+fn default_signature_prefix() -> String {
+    "sha256=".to_string()
+}
+
 /// Specification for an HTTP call, including URL, method, headers, and body template.
 ///
 /// This type is used to configure HTTP requests for different operation types (added, updated, deleted).
@@ -71,6 +92,76 @@ pub struct QueryConfig {
     pub deleted: Option<CallSpec>,
 }
 
+/// Retry policy for failed HTTP calls.
+///
+/// Connection errors, timeouts, `429 Too Many Requests` and `5xx` responses are
+/// retried with exponential backoff. Other responses are not retried.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RetryConfig {
+    /// Number of retries after the first attempt. 0 disables retries.
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+
+    /// Delay before the first retry in milliseconds. Doubles on every retry.
+    #[serde(default = "default_initial_backoff_ms")]
+    pub initial_backoff_ms: u64,
+
+    /// Upper bound of the delay between retries in milliseconds, also applied
+    /// to `Retry-After` headers.
+    #[serde(default = "default_max_backoff_ms")]
+    pub max_backoff_ms: u64,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: default_max_retries(),
+            initial_backoff_ms: default_initial_backoff_ms(),
+            max_backoff_ms: default_max_backoff_ms(),
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Delay before retry number `retry` (0 for the first retry).
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let delay = self
+            .initial_backoff_ms
+            .saturating_mul(2u64.saturating_pow(retry));
+        Duration::from_millis(delay.min(self.max_backoff_ms))
+    }
+}
+
+/// HMAC-SHA256 request signing.
+///
+/// The signature is computed over the request body and sent as
+/// `<header>: <prefix><hex digest>`, matching the signature verification of
+/// the HTTP source.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SigningConfig {
+    /// Shared secret used as the HMAC key.
+    pub secret: String,
+
+    /// Header carrying the signature.
+    #[serde(default = "default_signature_header")]
+    pub header: String,
+
+    /// Prefix of the header value.
+    #[serde(default = "default_signature_prefix")]
+    pub prefix: String,
+}
+
+impl SigningConfig {
+    /// Create a signing configuration with the default header and prefix.
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self {
+            secret: secret.into(),
+            header: default_signature_header(),
+            prefix: default_signature_prefix(),
+        }
+    }
+}
+
 /// HTTP reaction configuration
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct HttpReactionConfig {
@@ -89,6 +180,14 @@ pub struct HttpReactionConfig {
     /// Query-specific call configurations
     #[serde(default)]
     pub routes: HashMap<String, QueryConfig>,
+
+    /// Retry policy for failed calls
+    #[serde(default)]
+    pub retry: RetryConfig,
+
+    /// Optional HMAC request signing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signing: Option<SigningConfig>,
 }
 
 impl Default for HttpReactionConfig {
@@ -98,6 +197,8 @@ impl Default for HttpReactionConfig {
             token: None,
             timeout_ms: default_timeout_ms(),
             routes: HashMap::new(),
+            retry: RetryConfig::default(),
+            signing: None,
         }
     }
 }
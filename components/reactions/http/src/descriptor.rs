@@ -59,6 +59,62 @@ pub struct HttpQueryConfigDto {
     pub deleted: Option<CallSpecDto>,
 }
 
+fn default_max_retries() -> u32 {
+    3
+}
+
+fn default_initial_backoff_ms() -> u64 {
+    500
+}
+
+fn default_max_backoff_ms() -> u64 {
+    30000
+}
+
+fn default_signature_header() -> String {
+    "X-Drasi-Signature".to_string()
+}
+
+fn default_signature_prefix() -> String {
+    "sha256=".to_string()
+}
+
+/// DTO for the retry policy.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::http::RetryConfig)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct RetryConfigDto {
+    /// Number of retries after the first attempt.
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+
+    /// Delay before the first retry in milliseconds.
+    #[serde(default = "default_initial_backoff_ms")]
+    pub initial_backoff_ms: u64,
+
+    /// Upper bound of the delay between retries in milliseconds.
+    #[serde(default = "default_max_backoff_ms")]
+    pub max_backoff_ms: u64,
+}
+
+/// DTO for HMAC request signing.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::http::SigningConfig)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct SigningConfigDto {
+    /// Shared secret used as the HMAC key.
+    #[schema(value_type = ConfigValueString)]
+    pub secret: ConfigValue<String>,
+
+    /// Header carrying the signature.
+    #[serde(default = "default_signature_header")]
+    pub header: String,
+
+    /// Prefix of the header value.
+    #[serde(default = "default_signature_prefix")]
+    pub prefix: String,
+}
+
 /// Configuration DTO for the HTTP reaction plugin.
 #[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
 #[schema(as = reaction::http::HttpReactionConfig)]
@@ -81,6 +137,16 @@ pub struct HttpReactionConfigDto {
     /// Query-specific call configurations.
     #[serde(default)]
     pub routes: HashMap<String, HttpQueryConfigDto>,
+
+    /// Retry policy for failed calls.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<reaction::http::RetryConfig>)]
+    pub retry: Option<RetryConfigDto>,
+
+    /// HMAC request signing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<reaction::http::SigningConfig>)]
+    pub signing: Option<SigningConfigDto>,
 }
 
 fn map_call_spec(dto: &CallSpecDto) -> crate::CallSpec {
@@ -101,7 +167,13 @@ fn map_query_config(dto: &HttpQueryConfigDto) -> crate::QueryConfig {
 }
 
 #[derive(OpenApi)]
-#[openapi(components(schemas(HttpReactionConfigDto, HttpQueryConfigDto, CallSpecDto,)))]
+#[openapi(components(schemas(
+    HttpReactionConfigDto,
+    HttpQueryConfigDto,
+    CallSpecDto,
+    RetryConfigDto,
+    SigningConfigDto,
+)))]
 struct HttpReactionSchemas;
 
 /// Descriptor for the HTTP reaction plugin.
@@ -159,6 +231,22 @@ impl ReactionPluginDescriptor for HttpReactionDescriptor {
             builder = builder.with_route(query_id, map_query_config(config));
         }
 
+        if let Some(retry) = &dto.retry {
+            builder = builder.with_retry(crate::RetryConfig {
+                max_retries: retry.max_retries,
+                initial_backoff_ms: retry.initial_backoff_ms,
+                max_backoff_ms: retry.max_backoff_ms,
+            });
+        }
+
+        if let Some(signing) = &dto.signing {
+            builder = builder.with_signing(crate::SigningConfig {
+                secret: mapper.resolve_string(&signing.secret)?,
+                header: signing.header.clone(),
+                prefix: signing.prefix.clone(),
+            });
+        }
+
         let reaction = builder.build()?;
         Ok(Box::new(reaction))
     }
@@ -14,22 +14,24 @@
 
 pub use super::config::{CallSpec, HttpReactionConfig, QueryConfig};
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use async_trait::async_trait;
-use handlebars::Handlebars;
 use log::{debug, error, info, warn};
 use reqwest::{
-    header::{HeaderMap, HeaderName, HeaderValue},
-    Client, Method,
+    header::{HeaderMap, HeaderName, HeaderValue, RETRY_AFTER},
+    Client, Method, StatusCode,
 };
-use serde_json::{Map, Value};
 use std::collections::HashMap;
+use std::time::Duration;
 
-use drasi_lib::channels::{ComponentStatus, ResultDiff};
+use drasi_lib::channels::{ComponentStatus, QueryResult, ResultDiff};
 use drasi_lib::managers::log_component_start;
 use drasi_lib::reactions::common::base::{ReactionBase, ReactionBaseParams};
+use drasi_lib::templating::{change_context, TemplateEngine};
 use drasi_lib::Reaction;
 
+use super::config::RetryConfig;
+use super::signing::signature;
 use super::HttpReactionBuilder;
 
 pub struct HttpReaction {
@@ -93,65 +95,26 @@ impl HttpReaction {
         }
     }
 
-    #[allow(clippy::too_many_arguments)]
-    async fn process_result(
+    /// Render and send the call for one result diff.
+    pub(crate) async fn process_result(
         client: &Client,
-        handlebars: &Handlebars<'static>,
-        base_url: &str,
-        token: &Option<String>,
+        engine: &TemplateEngine,
+        config: &HttpReactionConfig,
         call_spec: &CallSpec,
-        result_type: &str,
-        data: &Value,
-        query_name: &str,
+        query_result: &QueryResult,
+        result: &ResultDiff,
         reaction_name: &str,
     ) -> Result<()> {
-        // Prepare context for Handlebars templates
-        let mut context = Map::new();
-
-        match result_type {
-            "ADD" => {
-                context.insert("after".to_string(), data.clone());
-            }
-            "UPDATE" => {
-                // For updates, we receive the full result object with before/after/data fields
-                if let Some(obj) = data.as_object() {
-                    if let Some(before) = obj.get("before") {
-                        context.insert("before".to_string(), before.clone());
-                    }
-                    if let Some(after) = obj.get("after") {
-                        context.insert("after".to_string(), after.clone());
-                    }
-                    if let Some(data_field) = obj.get("data") {
-                        context.insert("data".to_string(), data_field.clone());
-                    }
-                } else {
-                    context.insert("after".to_string(), data.clone());
-                }
-            }
-            "DELETE" => {
-                context.insert("before".to_string(), data.clone());
-            }
-            _ => {
-                context.insert("data".to_string(), data.clone());
-            }
-        }
-
-        // Add query metadata
-        context.insert(
-            "query_name".to_string(),
-            Value::String(query_name.to_string()),
-        );
-        context.insert(
-            "operation".to_string(),
-            Value::String(result_type.to_string()),
-        );
+        let Some(context) = change_context(query_result, result) else {
+            return Ok(());
+        };
 
         // Render URL
-        let url = handlebars.render_template(&call_spec.url, &context)?;
+        let url = engine.render(&call_spec.url, &context)?;
         let full_url = if url.starts_with("http://") || url.starts_with("https://") {
             url
         } else {
-            format!("{base_url}{url}")
+            format!("{}{url}", config.base_url)
         };
 
         // Render body
@@ -160,18 +123,23 @@ impl HttpReaction {
                 "[{}] Rendering template: {} with context: {:?}",
                 reaction_name, call_spec.body, context
             );
-            let rendered = handlebars.render_template(&call_spec.body, &context)?;
+            let rendered = engine.render(&call_spec.body, &context)?;
             debug!("[{reaction_name}] Rendered body: {rendered}");
             rendered
         } else {
-            serde_json::to_string(&data)?
+            match result {
+                ResultDiff::Add { data } | ResultDiff::Delete { data } => {
+                    serde_json::to_string(data)?
+                }
+                other => serde_json::to_string(other)?,
+            }
         };
 
         // Build headers
         let mut headers = HeaderMap::new();
         headers.insert("Content-Type", HeaderValue::from_static("application/json"));
 
-        if let Some(token) = token {
+        if let Some(token) = &config.token {
             headers.insert(
                 "Authorization",
                 HeaderValue::from_str(&format!("Bearer {token}"))?,
@@ -180,8 +148,7 @@ impl HttpReaction {
 
         for (key, value) in &call_spec.headers {
             let header_name = HeaderName::from_bytes(key.as_bytes())?;
-            let header_value =
-                HeaderValue::from_str(&handlebars.render_template(value, &context)?)?;
+            let header_value = HeaderValue::from_str(&engine.render(value, &context)?)?;
             headers.insert(header_name, header_value);
         }
 
@@ -195,39 +162,99 @@ impl HttpReaction {
             _ => Method::POST,
         };
 
+        if let Some(signing) = &config.signing {
+            headers.insert(
+                HeaderName::from_bytes(signing.header.as_bytes())?,
+                HeaderValue::from_str(&signature(signing, body.as_bytes())?)?,
+            );
+        }
+
         // Make HTTP request
         debug!("[{reaction_name}] Sending {method} request to {full_url} with body: {body}");
 
-        let response = client
-            .request(method.clone(), &full_url)
-            .headers(headers)
-            .body(body.clone())
-            .send()
-            .await?;
-
-        let status = response.status();
-        debug!(
-            "[{}] HTTP {} {} - Status: {}",
+        Self::send_with_retry(
+            client,
+            method,
+            &full_url,
+            headers,
+            body,
+            &config.retry,
             reaction_name,
-            call_spec.method.to_uppercase(),
-            full_url,
-            status.as_u16()
-        );
+        )
+        .await
+    }
 
-        if !status.is_success() {
-            let error_body = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unable to read response body".to_string());
-            warn!(
-                "[{}] HTTP request failed with status {}: {}",
-                reaction_name,
-                status.as_u16(),
-                error_body
-            );
-        }
+    /// Send a request, retrying connection errors, timeouts, `429` and `5xx`
+    /// responses as configured. Retries block the results that follow, so
+    /// calls are made in result order.
+    pub(crate) async fn send_with_retry(
+        client: &Client,
+        method: Method,
+        url: &str,
+        headers: HeaderMap,
+        body: String,
+        retry: &RetryConfig,
+        reaction_name: &str,
+    ) -> Result<()> {
+        let max_backoff = Duration::from_millis(retry.max_backoff_ms);
+        let mut attempt = 0;
+        loop {
+            let outcome = client
+                .request(method.clone(), url)
+                .headers(headers.clone())
+                .body(body.clone())
+                .send()
+                .await;
 
-        Ok(())
+            let retry_after = match outcome {
+                Ok(response) if response.status().is_success() => {
+                    debug!(
+                        "[{reaction_name}] HTTP {method} {url} - Status: {}",
+                        response.status().as_u16()
+                    );
+                    return Ok(());
+                }
+                Ok(response) => {
+                    let status = response.status();
+                    let retry_after = response
+                        .headers()
+                        .get(RETRY_AFTER)
+                        .and_then(|value| value.to_str().ok())
+                        .and_then(|value| value.trim().parse::<u64>().ok())
+                        .map(Duration::from_secs);
+                    let error_body = response
+                        .text()
+                        .await
+                        .unwrap_or_else(|_| "Unable to read response body".to_string());
+                    let retryable =
+                        status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error();
+                    if !retryable || attempt >= retry.max_retries {
+                        return Err(anyhow!(
+                            "HTTP {method} {url} failed with status {}: {error_body}",
+                            status.as_u16()
+                        ));
+                    }
+                    warn!(
+                        "[{reaction_name}] HTTP {method} {url} failed with status {}, retrying: {error_body}",
+                        status.as_u16()
+                    );
+                    retry_after
+                }
+                Err(e) => {
+                    if e.is_builder() || attempt >= retry.max_retries {
+                        return Err(anyhow!("HTTP {method} {url} failed: {e}"));
+                    }
+                    warn!("[{reaction_name}] HTTP {method} {url} failed, retrying: {e}");
+                    None
+                }
+            };
+
+            let delay = retry_after
+                .map(|delay| delay.min(max_backoff))
+                .unwrap_or_else(|| retry.backoff(attempt));
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
     }
 }
 
@@ -242,7 +269,10 @@ impl Reaction for HttpReaction {
     }
 
     fn properties(&self) -> HashMap<String, serde_json::Value> {
-        use crate::descriptor::{CallSpecDto, HttpQueryConfigDto, HttpReactionConfigDto};
+        use crate::descriptor::{
+            CallSpecDto, HttpQueryConfigDto, HttpReactionConfigDto, RetryConfigDto,
+            SigningConfigDto,
+        };
         use drasi_plugin_sdk::ConfigValue;
 
         fn map_call_to_dto(cs: &crate::CallSpec) -> CallSpecDto {
@@ -276,6 +306,16 @@ impl Reaction for HttpReaction {
                 .iter()
                 .map(|(k, v)| (k.clone(), map_qc_to_dto(v)))
                 .collect(),
+            retry: Some(RetryConfigDto {
+                max_retries: self.config.retry.max_retries,
+                initial_backoff_ms: self.config.retry.initial_backoff_ms,
+                max_backoff_ms: self.config.retry.max_backoff_ms,
+            }),
+            signing: self.config.signing.as_ref().map(|s| SigningConfigDto {
+                secret: ConfigValue::Static("***".to_string()),
+                header: s.header.clone(),
+                prefix: s.prefix.clone(),
+            }),
         };
 
         match serde_json::to_value(&dto) {
@@ -326,9 +366,8 @@ impl Reaction for HttpReaction {
         // Spawn the main processing task
         let reaction_name = self.base.id.clone();
         let status_handle = self.base.status_handle();
+        let config = self.config.clone();
         let query_configs = self.config.routes.clone();
-        let base_url = self.config.base_url.clone();
-        let token = self.config.token.clone();
         let timeout_ms = self.config.timeout_ms;
         let priority_queue = self.base.priority_queue.clone();
 
@@ -344,27 +383,7 @@ impl Reaction for HttpReaction {
                 }
             };
 
-            let mut handlebars = Handlebars::new();
-
-            // Register the json helper to serialize values as JSON
-            handlebars.register_helper(
-                "json",
-                Box::new(
-                    |h: &handlebars::Helper,
-                     _: &Handlebars,
-                     _: &handlebars::Context,
-                     _: &mut handlebars::RenderContext,
-                     out: &mut dyn handlebars::Output|
-                     -> handlebars::HelperResult {
-                        if let Some(value) = h.param(0) {
-                            let json_str = serde_json::to_string(&value.value())
-                                .unwrap_or_else(|_| "null".to_string());
-                            out.write(&json_str)?;
-                        }
-                        Ok(())
-                    },
-                ),
-            );
+            let engine = TemplateEngine::new();
 
             loop {
                 // Use select to wait for either a result OR shutdown signal
@@ -441,73 +460,28 @@ impl Reaction for HttpReaction {
 
                 // Process each result
                 for result in &query_result.results {
-                    match result {
-                        ResultDiff::Add { data } => {
-                            if let Some(spec) = query_config.added.as_ref() {
-                                if let Err(e) = Self::process_result(
-                                    &client,
-                                    &handlebars,
-                                    &base_url,
-                                    &token,
-                                    spec,
-                                    "ADD",
-                                    data,
-                                    query_name,
-                                    &reaction_name,
-                                )
-                                .await
-                                {
-                                    error!("[{reaction_name}] Failed to process result: {e}");
-                                }
-                            }
-                        }
-                        ResultDiff::Delete { data } => {
-                            if let Some(spec) = query_config.deleted.as_ref() {
-                                if let Err(e) = Self::process_result(
-                                    &client,
-                                    &handlebars,
-                                    &base_url,
-                                    &token,
-                                    spec,
-                                    "DELETE",
-                                    data,
-                                    query_name,
-                                    &reaction_name,
-                                )
-                                .await
-                                {
-                                    error!("[{reaction_name}] Failed to process result: {e}");
-                                }
-                            }
-                        }
+                    let spec = match result {
+                        ResultDiff::Add { .. } => query_config.added.as_ref(),
+                        ResultDiff::Delete { .. } => query_config.deleted.as_ref(),
                         ResultDiff::Update { .. } | ResultDiff::Aggregation { .. } => {
-                            if let Some(spec) = query_config.updated.as_ref() {
-                                let operation = if matches!(result, ResultDiff::Aggregation { .. })
-                                {
-                                    "AGGREGATION"
-                                } else {
-                                    "UPDATE"
-                                };
-                                let data_to_process = serde_json::to_value(result)
-                                    .expect("ResultDiff serialization should succeed");
-                                if let Err(e) = Self::process_result(
-                                    &client,
-                                    &handlebars,
-                                    &base_url,
-                                    &token,
-                                    spec,
-                                    operation,
-                                    &data_to_process,
-                                    query_name,
-                                    &reaction_name,
-                                )
-                                .await
-                                {
-                                    error!("[{reaction_name}] Failed to process result: {e}");
-                                }
-                            }
+                            query_config.updated.as_ref()
+                        }
+                        ResultDiff::Noop => None,
+                    };
+                    if let Some(spec) = spec {
+                        if let Err(e) = Self::process_result(
+                            &client,
+                            &engine,
+                            &config,
+                            spec,
+                            query_result,
+                            result,
+                            &reaction_name,
+                        )
+                        .await
+                        {
+                            error!("[{reaction_name}] Failed to process result: {e}");
                         }
-                        ResultDiff::Noop => {}
                     }
                 }
             }
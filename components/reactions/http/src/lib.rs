@@ -30,6 +30,8 @@
 //!     token: Some("secret-token".to_string()),
 //!     timeout_ms: 5000,
 //!     routes: Default::default(),
+//!     retry: Default::default(),
+//!     signing: None,
 //! };
 //!
 //! // Create instance and add to DrasiLib
@@ -44,8 +46,9 @@
 pub mod config;
 pub mod descriptor;
 pub mod http;
+mod signing;
 
-pub use config::{CallSpec, HttpReactionConfig, QueryConfig};
+pub use config::{CallSpec, HttpReactionConfig, QueryConfig, RetryConfig, SigningConfig};
 pub use http::HttpReaction;
 
 use std::collections::HashMap;
@@ -63,6 +66,8 @@ use std::collections::HashMap;
 ///     .with_base_url("http://api.example.com")
 ///     .with_token("secret-token")
 ///     .with_timeout_ms(10000)
+///     .with_max_retries(5)
+///     .with_signing_secret("webhook-secret")
 ///     .build()?;
 /// ```
 pub struct HttpReactionBuilder {
@@ -72,6 +77,8 @@ pub struct HttpReactionBuilder {
     token: Option<String>,
     timeout_ms: u64,
     routes: HashMap<String, QueryConfig>,
+    retry: RetryConfig,
+    signing: Option<SigningConfig>,
     priority_queue_capacity: Option<usize>,
     auto_start: bool,
 }
@@ -86,6 +93,8 @@ impl HttpReactionBuilder {
             token: None,
             timeout_ms: 5000,
             routes: HashMap::new(),
+            retry: RetryConfig::default(),
+            signing: None,
             priority_queue_capacity: None,
             auto_start: true,
         }
@@ -127,6 +136,30 @@ impl HttpReactionBuilder {
         self
     }
 
+    /// Set the retry policy for failed calls
+    pub fn with_retry(mut self, retry: RetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Set the number of retries after the first attempt, 0 to disable retries
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.retry.max_retries = max_retries;
+        self
+    }
+
+    /// Set the HMAC request signing configuration
+    pub fn with_signing(mut self, signing: SigningConfig) -> Self {
+        self.signing = Some(signing);
+        self
+    }
+
+    /// Sign requests with HMAC-SHA256 using the default `X-Drasi-Signature` header
+    pub fn with_signing_secret(mut self, secret: impl Into<String>) -> Self {
+        self.signing = Some(SigningConfig::new(secret));
+        self
+    }
+
     /// Set the priority queue capacity
     pub fn with_priority_queue_capacity(mut self, capacity: usize) -> Self {
         self.priority_queue_capacity = Some(capacity);
@@ -145,6 +178,8 @@ impl HttpReactionBuilder {
         self.token = config.token;
         self.timeout_ms = config.timeout_ms;
         self.routes = config.routes;
+        self.retry = config.retry;
+        self.signing = config.signing;
         self
     }
 
@@ -155,6 +190,8 @@ impl HttpReactionBuilder {
             token: self.token,
             timeout_ms: self.timeout_ms,
             routes: self.routes,
+            retry: self.retry,
+            signing: self.signing,
         };
 
         Ok(HttpReaction::from_builder(
@@ -230,6 +267,8 @@ mod tests {
             token: Some("test-token".to_string()),
             timeout_ms: 3000,
             routes: Default::default(),
+            retry: Default::default(),
+            signing: None,
         };
 
         let reaction = HttpReaction::new("test-reaction", vec!["query1".to_string()], config);
@@ -237,6 +276,176 @@ mod tests {
         assert_eq!(reaction.id(), "test-reaction");
         assert_eq!(reaction.query_ids(), vec!["query1".to_string()]);
     }
+
+    #[test]
+    fn test_http_builder_retry_and_signing() {
+        let reaction = HttpReaction::builder("test-reaction")
+            .with_max_retries(5)
+            .with_signing_secret("webhook-secret")
+            .build()
+            .unwrap();
+
+        let props = reaction.properties();
+        assert_eq!(
+            props.get("retry"),
+            Some(&serde_json::json!({
+                "maxRetries": 5,
+                "initialBackoffMs": 500,
+                "maxBackoffMs": 30000
+            }))
+        );
+        assert_eq!(
+            props.get("signing"),
+            Some(&serde_json::json!({
+                "secret": "***",
+                "header": "X-Drasi-Signature",
+                "prefix": "sha256="
+            }))
+        );
+    }
+
+    #[test]
+    fn test_retry_backoff() {
+        let retry = RetryConfig {
+            max_retries: 10,
+            initial_backoff_ms: 100,
+            max_backoff_ms: 1000,
+        };
+
+        assert_eq!(retry.backoff(0).as_millis(), 100);
+        assert_eq!(retry.backoff(1).as_millis(), 200);
+        assert_eq!(retry.backoff(3).as_millis(), 800);
+        assert_eq!(retry.backoff(4).as_millis(), 1000);
+        assert_eq!(retry.backoff(64).as_millis(), 1000);
+    }
+
+    mod retry {
+        use super::*;
+        use reqwest::header::HeaderMap;
+        use reqwest::{Client, Method};
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        use tokio::net::TcpListener;
+
+        /// Answer one request per status, returning the raw requests.
+        async fn serve(listener: TcpListener, statuses: Vec<&'static str>) -> Vec<String> {
+            let mut requests = Vec::new();
+            for status in statuses {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut request = Vec::new();
+                let mut buffer = [0u8; 1024];
+                while !request.ends_with(b"}") {
+                    match stream.read(&mut buffer).await {
+                        Ok(0) | Err(_) => break,
+                        Ok(read) => request.extend_from_slice(&buffer[..read]),
+                    }
+                }
+                requests.push(String::from_utf8_lossy(&request).to_lowercase());
+
+                let response =
+                    format!("HTTP/1.1 {status}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n");
+                let _ = stream.write_all(response.as_bytes()).await;
+            }
+            requests
+        }
+
+        async fn send(url: &str, max_retries: u32) -> anyhow::Result<()> {
+            let retry = RetryConfig {
+                max_retries,
+                initial_backoff_ms: 10,
+                max_backoff_ms: 10,
+            };
+            HttpReaction::send_with_retry(
+                &Client::new(),
+                Method::POST,
+                url,
+                HeaderMap::new(),
+                r#"{"id":1}"#.to_string(),
+                &retry,
+                "test-reaction",
+            )
+            .await
+        }
+
+        #[tokio::test]
+        async fn test_server_errors_are_retried() {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let url = format!("http://{}/hook", listener.local_addr().unwrap());
+            let server = tokio::spawn(serve(
+                listener,
+                vec!["503 Service Unavailable", "429 Too Many Requests", "200 OK"],
+            ));
+
+            send(&url, 3).await.unwrap();
+
+            let requests = server.await.unwrap();
+            assert_eq!(requests.len(), 3);
+            assert!(requests.iter().all(|r| r.ends_with(r#"{"id":1}"#)));
+        }
+
+        #[tokio::test]
+        async fn test_client_errors_and_exhausted_retries_fail() {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let url = format!("http://{}/hook", listener.local_addr().unwrap());
+            let server = tokio::spawn(serve(
+                listener,
+                vec![
+                    "400 Bad Request",
+                    "500 Internal Server Error",
+                    "502 Bad Gateway",
+                ],
+            ));
+
+            assert!(send(&url, 3).await.is_err());
+            assert!(send(&url, 1).await.is_err());
+            assert_eq!(server.await.unwrap().len(), 3);
+        }
+
+        #[tokio::test]
+        async fn test_calls_are_rendered_with_shared_template_helpers() {
+            use drasi_lib::channels::{QueryResult, ResultDiff};
+            use drasi_lib::templating::TemplateEngine;
+
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let config = HttpReactionConfig {
+                base_url: format!("http://{}", listener.local_addr().unwrap()),
+                ..Default::default()
+            };
+            let server = tokio::spawn(serve(listener, vec!["200 OK"]));
+
+            let call_spec = CallSpec {
+                url: "/rooms/{{lower after.room}}".to_string(),
+                method: "POST".to_string(),
+                body: r#"{"total":"{{format_number after.total 1}}","unit":"{{default after.unit "c"}}"}"#
+                    .to_string(),
+                headers: std::collections::HashMap::new(),
+            };
+            let query_result = QueryResult::new(
+                "rooms".to_string(),
+                chrono::Utc::now(),
+                vec![],
+                std::collections::HashMap::new(),
+            );
+            let result = ResultDiff::Add {
+                data: serde_json::json!({"room": "Lab", "total": 3}),
+            };
+
+            HttpReaction::process_result(
+                &Client::new(),
+                &TemplateEngine::new(),
+                &config,
+                &call_spec,
+                &query_result,
+                &result,
+                "test-reaction",
+            )
+            .await
+            .unwrap();
+
+            let requests = server.await.unwrap();
+            assert!(requests[0].starts_with("post /rooms/lab "));
+            assert!(requests[0].ends_with(r#"{"total":"3.0","unit":"c"}"#));
+        }
+    }
 }
 
 /// Dynamic plugin entry point.
@@ -0,0 +1,48 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! HMAC request signing for HTTP reactions.
+
+use anyhow::{anyhow, Result};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::config::SigningConfig;
+
+/// Compute the signature header value for a request body.
+pub(crate) fn signature(config: &SigningConfig, body: &[u8]) -> Result<String> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(config.secret.as_bytes())
+        .map_err(|e| anyhow!("HMAC-SHA256 key error: {e}"))?;
+    mac.update(body);
+    Ok(format!(
+        "{}{}",
+        config.prefix,
+        hex::encode(mac.finalize().into_bytes())
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signature() {
+        // Reference value from `echo -n '{"id":1}' | openssl dgst -sha256 -hmac secret`
+        let config = SigningConfig::new("secret");
+        assert_eq!(
+            signature(&config, br#"{"id":1}"#).unwrap(),
+            "sha256=03def589620c813f198fd03d7967e292b163ef0435ebf43071ce0e9519763cb7"
+        );
+    }
+}
@@ -0,0 +1,254 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Avro encoding in the Confluent wire format.
+//!
+//! Each value is written as a zero magic byte, the 4-byte big-endian schema id
+//! assigned by the registry, and the Avro binary datum. Schemas are registered
+//! under the `<topic>-value` subject (the registry's default topic name
+//! strategy) the first time a topic is used, and the returned ids are cached.
+
+use anyhow::{anyhow, Context, Result};
+use apache_avro::types::Value as AvroValue;
+use apache_avro::Schema;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::config::SchemaRegistryConfig;
+
+/// Magic byte that prefixes every Confluent-framed message.
+const MAGIC_BYTE: u8 = 0;
+
+/// Content type expected by the schema registry API.
+const REGISTRY_CONTENT_TYPE: &str = "application/vnd.schemaregistry.v1+json";
+
+#[derive(Deserialize)]
+struct RegisterResponse {
+    id: u32,
+}
+
+/// Parse an Avro schema, reporting which schema failed.
+pub(crate) fn parse_schema(schema: &str) -> Result<Schema> {
+    Schema::parse_str(schema).map_err(|e| anyhow!("Invalid Avro schema: {e}"))
+}
+
+/// Encode a JSON value as an Avro datum framed with the given schema id.
+pub(crate) fn encode(schema: &Schema, schema_id: u32, value: serde_json::Value) -> Result<Vec<u8>> {
+    let value = AvroValue::from(value)
+        .resolve(schema)
+        .map_err(|e| anyhow!("Value does not match Avro schema: {e}"))?;
+    let datum = apache_avro::to_avro_datum(schema, value)
+        .map_err(|e| anyhow!("Avro encoding failed: {e}"))?;
+
+    let mut framed = Vec::with_capacity(datum.len() + 5);
+    framed.push(MAGIC_BYTE);
+    framed.extend_from_slice(&schema_id.to_be_bytes());
+    framed.extend_from_slice(&datum);
+    Ok(framed)
+}
+
+/// Encodes message values against registered Avro schemas.
+pub(crate) struct AvroEncoder {
+    client: reqwest::Client,
+    registry: SchemaRegistryConfig,
+    schemas: HashMap<String, Schema>,
+    ids: HashMap<(String, String), u32>,
+}
+
+impl AvroEncoder {
+    pub(crate) fn new(registry: SchemaRegistryConfig) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            registry,
+            schemas: HashMap::new(),
+            ids: HashMap::new(),
+        }
+    }
+
+    /// Encode a rendered payload for a topic.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload is not JSON, does not match the schema,
+    /// or the schema cannot be registered.
+    pub(crate) async fn encode(
+        &mut self,
+        topic: &str,
+        schema: &str,
+        payload: &str,
+    ) -> Result<Vec<u8>> {
+        let value: serde_json::Value =
+            serde_json::from_str(payload).context("Avro payload is not valid JSON")?;
+        if !self.schemas.contains_key(schema) {
+            let parsed = parse_schema(schema)?;
+            self.schemas.insert(schema.to_string(), parsed);
+        }
+        let schema_id = self.schema_id(topic, schema).await?;
+        encode(&self.schemas[schema], schema_id, value)
+    }
+
+    /// Look up the registry id of a schema, registering it on first use.
+    async fn schema_id(&mut self, topic: &str, schema: &str) -> Result<u32> {
+        let subject = format!("{topic}-value");
+        let cache_key = (subject, schema.to_string());
+        if let Some(id) = self.ids.get(&cache_key) {
+            return Ok(*id);
+        }
+
+        let url = format!(
+            "{}/subjects/{}/versions",
+            self.registry.url.trim_end_matches('/'),
+            cache_key.0
+        );
+        let mut request = self
+            .client
+            .post(&url)
+            .header(reqwest::header::CONTENT_TYPE, REGISTRY_CONTENT_TYPE)
+            .json(&serde_json::json!({ "schema": schema }));
+        if let Some(username) = &self.registry.username {
+            request = request.basic_auth(username, self.registry.password.as_ref());
+        }
+
+        let response = request
+            .send()
+            .await
+            .with_context(|| format!("Failed to reach schema registry at {url}"))?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(anyhow!(
+                "Schema registration for subject '{}' failed with status {status}: {body}",
+                cache_key.0
+            ));
+        }
+        let registered: RegisterResponse = response
+            .json()
+            .await
+            .context("Invalid schema registry response")?;
+
+        self.ids.insert(cache_key, registered.id);
+        Ok(registered.id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    const SCHEMA: &str = r#"{
+        "type": "record",
+        "name": "Trade",
+        "fields": [
+            {"name": "symbol", "type": "string"},
+            {"name": "price", "type": "double"},
+            {"name": "volume", "type": "int"},
+            {"name": "venue", "type": ["null", "string"], "default": null}
+        ]
+    }"#;
+
+    /// Answer each request with the given body, returning the raw requests.
+    async fn serve(listener: TcpListener, bodies: Vec<&'static str>) -> Vec<String> {
+        let mut requests = Vec::new();
+        for body in bodies {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0u8; 4096];
+            while !request.ends_with(b"}") {
+                match stream.read(&mut buffer).await {
+                    Ok(0) | Err(_) => break,
+                    Ok(read) => request.extend_from_slice(&buffer[..read]),
+                }
+            }
+            requests.push(String::from_utf8_lossy(&request).to_string());
+
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            let _ = stream.write_all(response.as_bytes()).await;
+        }
+        requests
+    }
+
+    #[test]
+    fn test_encode_wire_format() {
+        let schema = parse_schema(SCHEMA).unwrap();
+        let framed = encode(
+            &schema,
+            42,
+            json!({"symbol": "MSFT", "price": 410.5, "volume": 100, "venue": null}),
+        )
+        .unwrap();
+
+        assert_eq!(framed[0], MAGIC_BYTE);
+        assert_eq!(&framed[1..5], &42u32.to_be_bytes());
+
+        let decoded = apache_avro::from_avro_datum(&schema, &mut &framed[5..], None).unwrap();
+        assert_eq!(
+            decoded,
+            AvroValue::Record(vec![
+                ("symbol".to_string(), AvroValue::String("MSFT".to_string())),
+                ("price".to_string(), AvroValue::Double(410.5)),
+                ("volume".to_string(), AvroValue::Int(100)),
+                (
+                    "venue".to_string(),
+                    AvroValue::Union(0, Box::new(AvroValue::Null))
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_encode_rejects_mismatched_value() {
+        let schema = parse_schema(SCHEMA).unwrap();
+        assert!(encode(&schema, 1, json!({"symbol": "MSFT"})).is_err());
+        assert!(parse_schema(r#"{"type": "record"}"#).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_encoder_registers_schema_once_per_subject() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let server = tokio::spawn(serve(listener, vec![r#"{"id":7}"#, r#"{"id":8}"#]));
+
+        let mut encoder = AvroEncoder::new(SchemaRegistryConfig {
+            url,
+            username: Some("user".to_string()),
+            password: Some("secret".to_string()),
+        });
+        let payload = r#"{"symbol": "MSFT", "price": 1.0, "volume": 1, "venue": "X"}"#;
+
+        let first = encoder.encode("trades", SCHEMA, payload).await.unwrap();
+        let second = encoder.encode("trades", SCHEMA, payload).await.unwrap();
+        let other = encoder.encode("quotes", SCHEMA, payload).await.unwrap();
+        assert_eq!(&first[1..5], &7u32.to_be_bytes());
+        assert_eq!(first, second);
+        assert_eq!(&other[1..5], &8u32.to_be_bytes());
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].starts_with("POST /subjects/trades-value/versions "));
+        assert!(requests[0].to_lowercase().contains("authorization: basic"));
+        assert!(requests[1].starts_with("POST /subjects/quotes-value/versions "));
+    }
+
+    #[tokio::test]
+    async fn test_encoder_rejects_invalid_json() {
+        let mut encoder = AvroEncoder::new(SchemaRegistryConfig::new("http://127.0.0.1:9"));
+        assert!(encoder.encode("trades", SCHEMA, "not json").await.is_err());
+    }
+}
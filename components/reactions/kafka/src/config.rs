@@ -0,0 +1,242 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration types for Kafka reactions.
+
+use drasi_lib::reactions::common::TemplateRouting;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+fn default_brokers() -> String {
+    "localhost:9092".to_string()
+}
+
+fn default_topic() -> String {
+    "drasi-{{query_name}}".to_string()
+}
+
+fn default_message_timeout_ms() -> u64 {
+    30000
+}
+
+/// Acknowledgement level required from the brokers before a message counts as delivered.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum KafkaAcks {
+    /// Wait for all in-sync replicas (default).
+    #[default]
+    All,
+    /// Wait for the partition leader only.
+    Leader,
+    /// Do not wait for any acknowledgement.
+    None,
+}
+
+impl KafkaAcks {
+    /// Value of the librdkafka `acks` property.
+    pub fn as_property(&self) -> &'static str {
+        match self {
+            KafkaAcks::All => "all",
+            KafkaAcks::Leader => "1",
+            KafkaAcks::None => "0",
+        }
+    }
+}
+
+/// How messages are assigned to partitions when no explicit partition is set.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum PartitionStrategy {
+    /// CRC32 hash of the key; messages without a key are spread randomly (default).
+    #[default]
+    KeyHash,
+    /// Murmur2 hash of the key, compatible with the Java producer.
+    Murmur2,
+    /// Random partition regardless of the key.
+    Random,
+}
+
+impl PartitionStrategy {
+    /// Value of the librdkafka `partitioner` property.
+    pub fn as_property(&self) -> &'static str {
+        match self {
+            PartitionStrategy::KeyHash => "consistent_random",
+            PartitionStrategy::Murmur2 => "murmur2_random",
+            PartitionStrategy::Random => "random",
+        }
+    }
+}
+
+/// Encoding of message values.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum KafkaSerialization {
+    /// UTF-8 JSON, or whatever text the payload template renders (default).
+    #[default]
+    Json,
+    /// Avro binary in the Confluent wire format, with schemas registered in a schema registry.
+    Avro,
+}
+
+/// Confluent-compatible schema registry connection.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SchemaRegistryConfig {
+    /// Registry base URL, e.g. `http://localhost:8081`
+    pub url: String,
+
+    /// Optional username for HTTP basic authentication
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+
+    /// Optional password for HTTP basic authentication
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+}
+
+impl SchemaRegistryConfig {
+    /// Create a registry configuration without authentication.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            username: None,
+            password: None,
+        }
+    }
+}
+
+/// Kafka-specific extension for template specifications.
+///
+/// Lets each operation template publish to its own topic with its own key and
+/// partition. Unset fields fall back to the reaction-level defaults.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct KafkaExtension {
+    /// Topic to publish to. Supports Handlebars templates, e.g. `alerts-{{query_name}}`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic: Option<String>,
+
+    /// Dotted path of the message key in the template context, e.g. `after.symbol`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+
+    /// Explicit partition, bypassing the partitioner.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partition: Option<i32>,
+
+    /// Avro schema (JSON) override for this template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avro_schema: Option<String>,
+}
+
+/// Type alias for Kafka template specification using the common generic type.
+///
+/// The template string renders the message value. If it is empty, the value
+/// is a JSON object with `queryId`, `operation`, `result`, and `timestamp`.
+pub type TemplateSpec = drasi_lib::reactions::common::TemplateSpec<KafkaExtension>;
+
+/// Type alias for Kafka query configuration using the common generic type.
+pub type QueryConfig = drasi_lib::reactions::common::QueryConfig<KafkaExtension>;
+
+/// Kafka reaction configuration
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct KafkaReactionConfig {
+    /// Comma-separated list of bootstrap brokers
+    #[serde(default = "default_brokers")]
+    pub brokers: String,
+
+    /// Kafka client id. Defaults to the reaction id when not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+
+    /// Default topic template used when a template spec does not set its own topic
+    #[serde(default = "default_topic")]
+    pub default_topic: String,
+
+    /// Default key path, e.g. `after.id`. Messages have no key when not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+
+    /// Partitioner used for messages without an explicit partition
+    #[serde(default)]
+    pub partitioner: PartitionStrategy,
+
+    /// Acknowledgement level required for delivery
+    #[serde(default)]
+    pub acks: KafkaAcks,
+
+    /// Enable the idempotent producer (requires `acks = all`)
+    #[serde(default)]
+    pub idempotent: bool,
+
+    /// Time in milliseconds librdkafka may spend delivering a message before
+    /// reporting it as failed
+    #[serde(default = "default_message_timeout_ms")]
+    pub message_timeout_ms: u64,
+
+    /// Encoding of message values
+    #[serde(default)]
+    pub serialization: KafkaSerialization,
+
+    /// Default Avro schema (JSON), required when `serialization` is `avro`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avro_schema: Option<String>,
+
+    /// Schema registry, required when `serialization` is `avro`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_registry: Option<SchemaRegistryConfig>,
+
+    /// Additional librdkafka producer properties, e.g. `security.protocol` or
+    /// `compression.type`. These override the settings derived above.
+    #[serde(default)]
+    pub properties: HashMap<String, String>,
+
+    /// Query-specific template configurations
+    #[serde(default)]
+    pub routes: HashMap<String, QueryConfig>,
+
+    /// Default template configuration used when no query-specific route is defined.
+    /// If not set, every result is published as raw JSON to `default_topic`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_template: Option<QueryConfig>,
+}
+
+impl Default for KafkaReactionConfig {
+    fn default() -> Self {
+        Self {
+            brokers: default_brokers(),
+            client_id: None,
+            default_topic: default_topic(),
+            key: None,
+            partitioner: PartitionStrategy::default(),
+            acks: KafkaAcks::default(),
+            idempotent: false,
+            message_timeout_ms: default_message_timeout_ms(),
+            serialization: KafkaSerialization::default(),
+            avro_schema: None,
+            schema_registry: None,
+            properties: HashMap::new(),
+            routes: HashMap::new(),
+            default_template: None,
+        }
+    }
+}
+
+impl TemplateRouting<KafkaExtension> for KafkaReactionConfig {
+    fn routes(&self) -> &HashMap<String, QueryConfig> {
+        &self.routes
+    }
+
+    fn default_template(&self) -> Option<&QueryConfig> {
+        self.default_template.as_ref()
+    }
+}
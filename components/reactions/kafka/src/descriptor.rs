@@ -0,0 +1,343 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Descriptor for the Kafka reaction plugin.
+
+use drasi_lib::reactions::Reaction;
+use drasi_plugin_sdk::prelude::*;
+use std::collections::HashMap;
+use utoipa::OpenApi;
+
+use crate::config::{
+    KafkaAcks, KafkaExtension, KafkaSerialization, PartitionStrategy, SchemaRegistryConfig,
+};
+use crate::KafkaReactionBuilder;
+
+/// DTO for the broker acknowledgement level.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::kafka::KafkaAcks)]
+#[serde(rename_all = "snake_case")]
+pub enum KafkaAcksDto {
+    All,
+    Leader,
+    None,
+}
+
+impl From<KafkaAcksDto> for KafkaAcks {
+    fn from(dto: KafkaAcksDto) -> Self {
+        match dto {
+            KafkaAcksDto::All => KafkaAcks::All,
+            KafkaAcksDto::Leader => KafkaAcks::Leader,
+            KafkaAcksDto::None => KafkaAcks::None,
+        }
+    }
+}
+
+/// DTO for the partitioning strategy.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::kafka::PartitionStrategy)]
+#[serde(rename_all = "snake_case")]
+pub enum PartitionStrategyDto {
+    KeyHash,
+    Murmur2,
+    Random,
+}
+
+impl From<PartitionStrategyDto> for PartitionStrategy {
+    fn from(dto: PartitionStrategyDto) -> Self {
+        match dto {
+            PartitionStrategyDto::KeyHash => PartitionStrategy::KeyHash,
+            PartitionStrategyDto::Murmur2 => PartitionStrategy::Murmur2,
+            PartitionStrategyDto::Random => PartitionStrategy::Random,
+        }
+    }
+}
+
+/// DTO for the message value encoding.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::kafka::KafkaSerialization)]
+#[serde(rename_all = "snake_case")]
+pub enum KafkaSerializationDto {
+    Json,
+    Avro,
+}
+
+impl From<KafkaSerializationDto> for KafkaSerialization {
+    fn from(dto: KafkaSerializationDto) -> Self {
+        match dto {
+            KafkaSerializationDto::Json => KafkaSerialization::Json,
+            KafkaSerializationDto::Avro => KafkaSerialization::Avro,
+        }
+    }
+}
+
+/// DTO for the schema registry connection.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::kafka::SchemaRegistryConfig)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct SchemaRegistryConfigDto {
+    /// Registry base URL.
+    #[schema(value_type = ConfigValueString)]
+    pub url: ConfigValue<String>,
+
+    /// Username for HTTP basic authentication.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub username: Option<ConfigValue<String>>,
+
+    /// Password for HTTP basic authentication.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub password: Option<ConfigValue<String>>,
+}
+
+/// DTO for a Kafka template specification.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::kafka::KafkaTemplateSpec)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct KafkaTemplateSpecDto {
+    /// Handlebars template for the message value.
+    #[serde(default)]
+    pub template: String,
+
+    /// Optional topic template for this operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic: Option<String>,
+
+    /// Optional key path for this operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+
+    /// Optional explicit partition for this operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partition: Option<i32>,
+
+    /// Optional Avro schema override for this operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avro_schema: Option<String>,
+}
+
+/// DTO for per-query Kafka template configuration.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::kafka::KafkaQueryConfig)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct KafkaQueryConfigDto {
+    /// Template for ADD operations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub added: Option<KafkaTemplateSpecDto>,
+
+    /// Template for UPDATE operations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated: Option<KafkaTemplateSpecDto>,
+
+    /// Template for DELETE operations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted: Option<KafkaTemplateSpecDto>,
+}
+
+/// Configuration DTO for the Kafka reaction plugin.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::kafka::KafkaReactionConfig)]
+#[serde(rename_all = "camelCase")]
+pub struct KafkaReactionConfigDto {
+    /// Comma-separated bootstrap brokers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub brokers: Option<ConfigValue<String>>,
+
+    /// Kafka client id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub client_id: Option<ConfigValue<String>>,
+
+    /// Default topic template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub default_topic: Option<ConfigValue<String>>,
+
+    /// Default key path.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub key: Option<ConfigValue<String>>,
+
+    /// Partitioning strategy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partitioner: Option<PartitionStrategyDto>,
+
+    /// Broker acknowledgement level.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acks: Option<KafkaAcksDto>,
+
+    /// Enable the idempotent producer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueBool>)]
+    pub idempotent: Option<ConfigValue<bool>>,
+
+    /// Delivery timeout in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueU64>)]
+    pub message_timeout_ms: Option<ConfigValue<u64>>,
+
+    /// Message value encoding.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serialization: Option<KafkaSerializationDto>,
+
+    /// Default Avro schema (JSON).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avro_schema: Option<String>,
+
+    /// Schema registry connection.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_registry: Option<SchemaRegistryConfigDto>,
+
+    /// Additional librdkafka producer properties.
+    #[serde(default)]
+    pub properties: HashMap<String, String>,
+
+    /// Query-specific template configurations.
+    #[serde(default)]
+    pub routes: HashMap<String, KafkaQueryConfigDto>,
+
+    /// Default template configuration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_template: Option<KafkaQueryConfigDto>,
+}
+
+fn map_template_spec(dto: &KafkaTemplateSpecDto) -> crate::TemplateSpec {
+    crate::TemplateSpec {
+        template: dto.template.clone(),
+        extension: KafkaExtension {
+            topic: dto.topic.clone(),
+            key: dto.key.clone(),
+            partition: dto.partition,
+            avro_schema: dto.avro_schema.clone(),
+        },
+    }
+}
+
+fn map_query_config(dto: &KafkaQueryConfigDto) -> crate::QueryConfig {
+    crate::QueryConfig {
+        added: dto.added.as_ref().map(map_template_spec),
+        updated: dto.updated.as_ref().map(map_template_spec),
+        deleted: dto.deleted.as_ref().map(map_template_spec),
+    }
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(
+    KafkaReactionConfigDto,
+    KafkaQueryConfigDto,
+    KafkaTemplateSpecDto,
+    SchemaRegistryConfigDto,
+    KafkaAcksDto,
+    PartitionStrategyDto,
+    KafkaSerializationDto,
+)))]
+struct KafkaReactionSchemas;
+
+/// Descriptor for the Kafka reaction plugin.
+pub struct KafkaReactionDescriptor;
+
+#[async_trait]
+impl ReactionPluginDescriptor for KafkaReactionDescriptor {
+    fn kind(&self) -> &str {
+        "kafka"
+    }
+
+    fn config_version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn config_schema_name(&self) -> &str {
+        "reaction.kafka.KafkaReactionConfig"
+    }
+
+    fn config_schema_json(&self) -> String {
+        let api = KafkaReactionSchemas::openapi();
+        serde_json::to_string(
+            &api.components
+                .as_ref()
+                .expect("OpenAPI components missing")
+                .schemas,
+        )
+        .expect("Failed to serialize config schema")
+    }
+
+    async fn create_reaction(
+        &self,
+        id: &str,
+        query_ids: Vec<String>,
+        config_json: &serde_json::Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn Reaction>> {
+        let dto: KafkaReactionConfigDto = serde_json::from_value(config_json.clone())?;
+        let mapper = DtoMapper::new();
+
+        let mut builder = KafkaReactionBuilder::new(id)
+            .with_queries(query_ids)
+            .with_auto_start(auto_start);
+
+        if let Some(ref brokers) = dto.brokers {
+            builder = builder.with_brokers(mapper.resolve_string(brokers)?);
+        }
+        if let Some(ref client_id) = dto.client_id {
+            builder = builder.with_client_id(mapper.resolve_string(client_id)?);
+        }
+        if let Some(ref topic) = dto.default_topic {
+            builder = builder.with_default_topic(mapper.resolve_string(topic)?);
+        }
+        if let Some(ref key) = dto.key {
+            builder = builder.with_key(mapper.resolve_string(key)?);
+        }
+        if let Some(partitioner) = dto.partitioner {
+            builder = builder.with_partitioner(partitioner.into());
+        }
+        if let Some(acks) = dto.acks {
+            builder = builder.with_acks(acks.into());
+        }
+        if let Some(ref idempotent) = dto.idempotent {
+            builder = builder.with_idempotent(mapper.resolve_typed(idempotent)?);
+        }
+        if let Some(ref timeout) = dto.message_timeout_ms {
+            builder = builder.with_message_timeout_ms(mapper.resolve_typed(timeout)?);
+        }
+        if let Some(serialization) = dto.serialization {
+            builder = builder.with_serialization(serialization.into());
+        }
+        if let Some(ref schema) = dto.avro_schema {
+            builder = builder.with_avro_schema(schema.clone());
+        }
+        if let Some(ref registry) = dto.schema_registry {
+            builder = builder.with_schema_registry(SchemaRegistryConfig {
+                url: mapper.resolve_string(&registry.url)?,
+                username: mapper.resolve_optional_string(&registry.username)?,
+                password: mapper.resolve_optional_string(&registry.password)?,
+            });
+        }
+        for (key, value) in &dto.properties {
+            builder = builder.with_property(key, value);
+        }
+
+        if let Some(ref default_template) = dto.default_template {
+            builder = builder.with_default_template(map_query_config(default_template));
+        }
+
+        for (query_id, config) in &dto.routes {
+            builder = builder.with_route(query_id, map_query_config(config));
+        }
+
+        let reaction = builder.build()?;
+        Ok(Box::new(reaction))
+    }
+}
@@ -0,0 +1,605 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use futures::future::join_all;
+use log::{debug, error, info};
+use rdkafka::config::ClientConfig;
+use rdkafka::producer::{FutureProducer, FutureRecord, Producer};
+use rdkafka::util::Timeout;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+use drasi_lib::channels::{ComponentStatus, QueryResult, ResultDiff};
+use drasi_lib::managers::log_component_start;
+use drasi_lib::reactions::common::base::{ReactionBase, ReactionBaseParams};
+use drasi_lib::templating::{change_context, TemplateEngine};
+use drasi_lib::Reaction;
+
+use super::avro::{parse_schema, AvroEncoder};
+use super::config::{KafkaAcks, KafkaReactionConfig, KafkaSerialization, QueryConfig};
+use super::KafkaReactionBuilder;
+
+/// Maximum length of a Kafka topic name
+const MAX_TOPIC_LENGTH: usize = 249;
+
+/// A single message ready to be produced.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct KafkaRecord {
+    pub topic: String,
+    pub key: Option<String>,
+    pub partition: Option<i32>,
+    pub payload: String,
+    /// Avro schema for the value; only set when the reaction serializes to Avro.
+    pub avro_schema: Option<String>,
+}
+
+/// Kafka reaction produces query result changes to Kafka topics.
+pub struct KafkaReaction {
+    base: ReactionBase,
+    config: KafkaReactionConfig,
+    producer: Arc<RwLock<Option<FutureProducer>>>,
+}
+
+impl std::fmt::Debug for KafkaReaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KafkaReaction")
+            .field("id", &self.base.id)
+            .field("brokers", &self.config.brokers)
+            .finish()
+    }
+}
+
+impl KafkaReaction {
+    /// Create a builder for KafkaReaction
+    pub fn builder(id: impl Into<String>) -> KafkaReactionBuilder {
+        KafkaReactionBuilder::new(id)
+    }
+
+    /// Create a new Kafka reaction
+    ///
+    /// The event channel is automatically injected when the reaction is added
+    /// to DrasiLib via `add_reaction()`.
+    ///
+    /// # Errors
+    ///
+    /// - Returns error if any topic or payload template has invalid Handlebars syntax
+    /// - Returns error if Avro serialization is selected without a valid schema and registry
+    /// - Returns error if a route query ID doesn't match any subscribed query
+    pub fn new(
+        id: impl Into<String>,
+        queries: Vec<String>,
+        config: KafkaReactionConfig,
+    ) -> anyhow::Result<Self> {
+        Self::validate_config(&queries, &config)?;
+        Ok(Self::from_builder(id.into(), queries, config, None, true))
+    }
+
+    /// Create from builder (internal method)
+    pub(crate) fn from_builder(
+        id: String,
+        queries: Vec<String>,
+        config: KafkaReactionConfig,
+        priority_queue_capacity: Option<usize>,
+        auto_start: bool,
+    ) -> Self {
+        let mut params = ReactionBaseParams::new(id, queries).with_auto_start(auto_start);
+        if let Some(capacity) = priority_queue_capacity {
+            params = params.with_priority_queue_capacity(capacity);
+        }
+
+        Self {
+            base: ReactionBase::new(params),
+            config,
+            producer: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// Validate a template by attempting to compile it with Handlebars
+    fn validate_template(template: &str) -> anyhow::Result<()> {
+        if template.is_empty() {
+            return Ok(());
+        }
+        TemplateEngine::validate(template)
+    }
+
+    /// Validate templates, key paths, partitions and schemas in a QueryConfig
+    fn validate_query_config(config: &QueryConfig) -> anyhow::Result<()> {
+        for spec in [&config.added, &config.updated, &config.deleted]
+            .into_iter()
+            .flatten()
+        {
+            Self::validate_template(&spec.template)?;
+            if let Some(topic) = &spec.extension.topic {
+                Self::validate_template(topic)?;
+            }
+            if spec.extension.key.as_deref() == Some("") {
+                return Err(anyhow::anyhow!("key path cannot be empty"));
+            }
+            if let Some(partition) = spec.extension.partition {
+                if partition < 0 {
+                    return Err(anyhow::anyhow!(
+                        "partition must be non-negative, got {partition}"
+                    ));
+                }
+            }
+            if let Some(schema) = &spec.extension.avro_schema {
+                parse_schema(schema)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate configuration: templates, producer settings and route-query matching
+    pub(crate) fn validate_config(
+        queries: &[String],
+        config: &KafkaReactionConfig,
+    ) -> anyhow::Result<()> {
+        if config.brokers.trim().is_empty() {
+            return Err(anyhow::anyhow!("Validation error: brokers cannot be empty"));
+        }
+
+        if config.default_topic.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Validation error: default_topic cannot be empty"
+            ));
+        }
+        Self::validate_template(&config.default_topic)
+            .map_err(|e| anyhow::anyhow!("Invalid default topic: {e}"))?;
+
+        if config.key.as_deref() == Some("") {
+            return Err(anyhow::anyhow!("Validation error: key cannot be empty"));
+        }
+
+        if config.message_timeout_ms == 0 {
+            return Err(anyhow::anyhow!(
+                "Validation error: message_timeout_ms must be greater than 0"
+            ));
+        }
+
+        if config.idempotent && config.acks != KafkaAcks::All {
+            return Err(anyhow::anyhow!(
+                "Validation error: idempotent producer requires acks = all"
+            ));
+        }
+
+        if config.serialization == KafkaSerialization::Avro {
+            match &config.schema_registry {
+                Some(registry) if !registry.url.is_empty() => {}
+                _ => {
+                    return Err(anyhow::anyhow!(
+                        "Validation error: Avro serialization requires schema_registry.url"
+                    ))
+                }
+            }
+            let schema = config.avro_schema.as_ref().ok_or_else(|| {
+                anyhow::anyhow!("Validation error: Avro serialization requires avro_schema")
+            })?;
+            parse_schema(schema)?;
+        }
+
+        for (query_id, route_config) in &config.routes {
+            Self::validate_query_config(route_config)
+                .map_err(|e| anyhow::anyhow!("Invalid template in route '{query_id}': {e}"))?;
+        }
+
+        if let Some(default_template) = &config.default_template {
+            Self::validate_query_config(default_template)
+                .map_err(|e| anyhow::anyhow!("Invalid default template: {e}"))?;
+        }
+
+        if !config.routes.is_empty() && !queries.is_empty() {
+            for route_query in config.routes.keys() {
+                let dotted_route = format!(".{route_query}");
+                let matches = queries
+                    .iter()
+                    .any(|q| q == route_query || q.ends_with(&dotted_route));
+                if !matches {
+                    return Err(anyhow::anyhow!(
+                        "Route '{route_query}' does not match any subscribed query. Subscribed queries: {queries:?}"
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Build the librdkafka producer configuration.
+    ///
+    /// Entries in `properties` are applied last so they can override any derived setting.
+    pub(crate) fn client_config(id: &str, config: &KafkaReactionConfig) -> ClientConfig {
+        let mut client = ClientConfig::new();
+        client
+            .set("bootstrap.servers", &config.brokers)
+            .set("client.id", config.client_id.as_deref().unwrap_or(id))
+            .set("acks", config.acks.as_property())
+            .set("partitioner", config.partitioner.as_property())
+            .set("enable.idempotence", config.idempotent.to_string())
+            .set("message.timeout.ms", config.message_timeout_ms.to_string());
+        for (key, value) in &config.properties {
+            client.set(key, value);
+        }
+        client
+    }
+
+    /// Find the route for a query, falling back to the last dotted segment and then the default template
+    fn query_config<'a>(
+        config: &'a KafkaReactionConfig,
+        query_name: &str,
+    ) -> Option<&'a QueryConfig> {
+        config
+            .routes
+            .get(query_name)
+            .or_else(|| {
+                if query_name.contains('.') {
+                    query_name
+                        .rsplit('.')
+                        .next()
+                        .and_then(|name| config.routes.get(name))
+                } else {
+                    None
+                }
+            })
+            .or(config.default_template.as_ref())
+    }
+
+    /// Resolve a dotted path such as `after.symbol` against the template context.
+    ///
+    /// Strings are used as-is and other values as their JSON text. Missing and
+    /// null values produce no key.
+    pub(crate) fn extract_key(context: &Map<String, Value>, path: &str) -> Option<String> {
+        let mut segments = path.split('.');
+        let mut value = context.get(segments.next()?)?;
+        for segment in segments {
+            value = match value {
+                Value::Object(map) => map.get(segment)?,
+                Value::Array(items) => items.get(segment.parse::<usize>().ok()?)?,
+                _ => return None,
+            };
+        }
+        match value {
+            Value::Null => None,
+            Value::String(s) => Some(s.clone()),
+            other => Some(other.to_string()),
+        }
+    }
+
+    /// Kafka topic names are 1-249 characters of ASCII alphanumerics, `.`, `_` and `-`.
+    fn is_valid_topic(topic: &str) -> bool {
+        !topic.is_empty()
+            && topic.len() <= MAX_TOPIC_LENGTH
+            && topic != "."
+            && topic != ".."
+            && topic
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'))
+    }
+
+    /// Convert a query result into the records that should be produced.
+    ///
+    /// Results with no matching template are published as JSON to the default topic.
+    /// Results whose topic cannot be rendered, or renders to an invalid topic name,
+    /// are skipped.
+    pub(crate) fn render_records(
+        engine: &TemplateEngine,
+        config: &KafkaReactionConfig,
+        query_result: &QueryResult,
+        reaction_id: &str,
+    ) -> Vec<KafkaRecord> {
+        let query_name = &query_result.query_id;
+        let timestamp = query_result.timestamp.timestamp_millis();
+        let query_config = Self::query_config(config, query_name);
+        let mut records = Vec::new();
+
+        for result in &query_result.results {
+            let (spec, operation) = match result {
+                ResultDiff::Add { .. } => (query_config.and_then(|qc| qc.added.as_ref()), "ADD"),
+                ResultDiff::Update { .. } => {
+                    (query_config.and_then(|qc| qc.updated.as_ref()), "UPDATE")
+                }
+                ResultDiff::Delete { .. } => {
+                    (query_config.and_then(|qc| qc.deleted.as_ref()), "DELETE")
+                }
+                ResultDiff::Aggregation { .. } => (
+                    query_config.and_then(|qc| qc.updated.as_ref()),
+                    "AGGREGATION",
+                ),
+                ResultDiff::Noop => continue,
+            };
+            let Some(Value::Object(context)) = change_context(query_result, result) else {
+                continue;
+            };
+
+            let topic_template = spec
+                .and_then(|s| s.extension.topic.as_deref())
+                .unwrap_or(&config.default_topic);
+            let topic = match engine.render(topic_template, &context) {
+                Ok(topic) => topic,
+                Err(e) => {
+                    error!(
+                        "[{reaction_id}] Failed to render topic '{topic_template}' for query '{query_name}': {e}"
+                    );
+                    continue;
+                }
+            };
+            if !Self::is_valid_topic(&topic) {
+                error!(
+                    "[{reaction_id}] Rendered topic '{topic}' for query '{query_name}' is not a valid Kafka topic name"
+                );
+                continue;
+            }
+
+            let default_payload = || {
+                json!({
+                    "queryId": query_name,
+                    "operation": operation,
+                    "result": result,
+                    "timestamp": timestamp
+                })
+                .to_string()
+            };
+            let payload = match spec.map(|s| s.template.as_str()) {
+                Some(template) if !template.is_empty() => match engine.render(template, &context) {
+                    Ok(rendered) => rendered,
+                    Err(e) => {
+                        error!(
+                                "[{reaction_id}] Failed to render payload for query '{query_name}': {e}. Falling back to default format."
+                            );
+                        default_payload()
+                    }
+                },
+                _ => default_payload(),
+            };
+
+            let key = spec
+                .and_then(|s| s.extension.key.as_deref())
+                .or(config.key.as_deref())
+                .and_then(|path| Self::extract_key(&context, path));
+
+            let avro_schema = match config.serialization {
+                KafkaSerialization::Json => None,
+                KafkaSerialization::Avro => spec
+                    .and_then(|s| s.extension.avro_schema.clone())
+                    .or_else(|| config.avro_schema.clone()),
+            };
+
+            records.push(KafkaRecord {
+                topic,
+                key,
+                partition: spec.and_then(|s| s.extension.partition),
+                payload,
+                avro_schema,
+            });
+        }
+
+        records
+    }
+
+    /// Produce a batch of records and wait for their delivery reports.
+    ///
+    /// All records are enqueued before any report is awaited, so librdkafka can
+    /// batch them while keeping per-partition order. Failed deliveries are logged
+    /// and do not stop the batch.
+    async fn produce(
+        producer: &FutureProducer,
+        avro: Option<&mut AvroEncoder>,
+        records: Vec<KafkaRecord>,
+        reaction_id: &str,
+    ) {
+        let mut encoded = Vec::with_capacity(records.len());
+        match avro {
+            Some(encoder) => {
+                for record in records {
+                    let Some(schema) = record.avro_schema.as_deref() else {
+                        continue;
+                    };
+                    match encoder.encode(&record.topic, schema, &record.payload).await {
+                        Ok(value) => encoded.push((value, record)),
+                        Err(e) => error!(
+                            "[{reaction_id}] Failed to encode Avro value for topic '{}': {e:#}",
+                            record.topic
+                        ),
+                    }
+                }
+            }
+            None => {
+                for record in records {
+                    encoded.push((record.payload.clone().into_bytes(), record));
+                }
+            }
+        }
+
+        let deliveries = encoded.iter().map(|(value, record)| {
+            let mut future_record =
+                FutureRecord::<str, [u8]>::to(&record.topic).payload(value.as_slice());
+            if let Some(key) = &record.key {
+                future_record = future_record.key(key.as_str());
+            }
+            if let Some(partition) = record.partition {
+                future_record = future_record.partition(partition);
+            }
+            producer.send(future_record, Timeout::Never)
+        });
+
+        for ((_, record), report) in encoded.iter().zip(join_all(deliveries).await) {
+            match report {
+                Ok((partition, offset)) => debug!(
+                    "[{reaction_id}] Delivered to '{}' partition {partition} offset {offset}",
+                    record.topic
+                ),
+                Err((e, _)) => error!(
+                    "[{reaction_id}] Failed to deliver message to '{}' (key={:?}): {e}",
+                    record.topic, record.key
+                ),
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Reaction for KafkaReaction {
+    fn id(&self) -> &str {
+        &self.base.id
+    }
+
+    fn type_name(&self) -> &str {
+        "kafka"
+    }
+
+    fn properties(&self) -> HashMap<String, serde_json::Value> {
+        let mut config = self.config.clone();
+        if let Some(registry) = config.schema_registry.as_mut() {
+            if registry.password.is_some() {
+                registry.password = Some("***".to_string());
+            }
+        }
+        for (key, value) in config.properties.iter_mut() {
+            if key.contains("password") || key.contains("secret") {
+                *value = "***".to_string();
+            }
+        }
+        match serde_json::to_value(&config) {
+            Ok(serde_json::Value::Object(map)) => map.into_iter().collect(),
+            _ => HashMap::new(),
+        }
+    }
+
+    fn query_ids(&self) -> Vec<String> {
+        self.base.queries.clone()
+    }
+
+    fn auto_start(&self) -> bool {
+        self.base.get_auto_start()
+    }
+
+    async fn initialize(&self, context: drasi_lib::context::ReactionRuntimeContext) {
+        self.base.initialize(context).await;
+    }
+
+    async fn start(&self) -> Result<()> {
+        log_component_start("Kafka Reaction", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Starting,
+                Some("Starting Kafka reaction".to_string()),
+            )
+            .await;
+
+        let producer: FutureProducer =
+            match Self::client_config(&self.base.id, &self.config).create() {
+                Ok(producer) => producer,
+                Err(e) => {
+                    self.base
+                        .set_status(
+                            ComponentStatus::Error,
+                            Some(format!("Failed to create Kafka producer: {e}")),
+                        )
+                        .await;
+                    return Err(anyhow::anyhow!("Failed to create Kafka producer: {e}"));
+                }
+            };
+        *self.producer.write().await = Some(producer.clone());
+
+        self.base
+            .set_status(
+                ComponentStatus::Running,
+                Some("Kafka reaction started".to_string()),
+            )
+            .await;
+
+        let priority_queue = self.base.priority_queue.clone();
+        let reaction_id = self.base.id.clone();
+        let config = self.config.clone();
+        let mut shutdown_rx = self.base.create_shutdown_channel().await;
+
+        let processing_task = tokio::spawn(async move {
+            info!("[{reaction_id}] Kafka result processing task started");
+
+            let engine = TemplateEngine::new();
+
+            let mut avro = match config.serialization {
+                KafkaSerialization::Json => None,
+                KafkaSerialization::Avro => config.schema_registry.clone().map(AvroEncoder::new),
+            };
+
+            loop {
+                let query_result = tokio::select! {
+                    biased;
+
+                    _ = &mut shutdown_rx => {
+                        debug!("[{reaction_id}] Received shutdown signal, exiting processing loop");
+                        break;
+                    }
+
+                    result = priority_queue.dequeue() => result,
+                };
+
+                if query_result.results.is_empty() {
+                    debug!("[{reaction_id}] Received empty result set from query");
+                    continue;
+                }
+
+                let records =
+                    KafkaReaction::render_records(&engine, &config, &query_result, &reaction_id);
+
+                KafkaReaction::produce(&producer, avro.as_mut(), records, &reaction_id).await;
+            }
+
+            info!("[{reaction_id}] Kafka result processing task ended");
+        });
+
+        self.base.set_processing_task(processing_task).await;
+
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        self.base.stop_common().await?;
+
+        if let Some(producer) = self.producer.write().await.take() {
+            // Give queued messages a chance to be delivered before dropping the producer.
+            let timeout = Duration::from_millis(self.config.message_timeout_ms);
+            let reaction_id = self.base.id.clone();
+            let flushed = tokio::task::spawn_blocking(move || producer.flush(timeout)).await;
+            match flushed {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) => error!("[{reaction_id}] Failed to flush Kafka producer: {e}"),
+                Err(e) => error!("[{reaction_id}] Kafka producer flush task failed: {e}"),
+            }
+        }
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopped,
+                Some("Kafka reaction stopped".to_string()),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn status(&self) -> ComponentStatus {
+        self.base.get_status().await
+    }
+
+    async fn enqueue_query_result(&self, result: QueryResult) -> anyhow::Result<()> {
+        self.base.enqueue_query_result(result).await
+    }
+}
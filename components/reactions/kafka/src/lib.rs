@@ -0,0 +1,219 @@
+#![allow(unexpected_cfgs)]
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Kafka reaction plugin for Drasi
+//!
+//! This plugin produces query result changes to Kafka topics. Each added,
+//! updated or deleted row becomes one message on a topic rendered from a
+//! Handlebars template such as `alerts-{{query_name}}`, keyed by a field of
+//! the result (for example `after.symbol`). Values are JSON by default, or
+//! Avro registered in a Confluent-compatible schema registry.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use drasi_reaction_kafka::{KafkaAcks, KafkaReaction};
+//!
+//! let reaction = KafkaReaction::builder("my-kafka-reaction")
+//!     .with_queries(vec!["query1".to_string()])
+//!     .with_brokers("kafka-1:9092,kafka-2:9092")
+//!     .with_default_topic("drasi-{{query_name}}")
+//!     .with_key("after.id")
+//!     .with_acks(KafkaAcks::All)
+//!     .build()?;
+//! ```
+
+mod avro;
+pub mod config;
+pub mod descriptor;
+pub mod kafka;
+
+pub use config::{
+    KafkaAcks, KafkaExtension, KafkaReactionConfig, KafkaSerialization, PartitionStrategy,
+    QueryConfig, SchemaRegistryConfig, TemplateSpec,
+};
+pub use kafka::KafkaReaction;
+
+/// Builder for Kafka reaction
+pub struct KafkaReactionBuilder {
+    id: String,
+    queries: Vec<String>,
+    config: KafkaReactionConfig,
+    priority_queue_capacity: Option<usize>,
+    auto_start: bool,
+}
+
+impl KafkaReactionBuilder {
+    /// Create a new Kafka reaction builder with the given ID
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            queries: Vec::new(),
+            config: KafkaReactionConfig::default(),
+            priority_queue_capacity: None,
+            auto_start: true,
+        }
+    }
+
+    /// Set the query IDs to subscribe to
+    pub fn with_queries(mut self, queries: Vec<String>) -> Self {
+        self.queries = queries;
+        self
+    }
+
+    /// Add a query ID to subscribe to
+    pub fn with_query(mut self, query_id: impl Into<String>) -> Self {
+        self.queries.push(query_id.into());
+        self
+    }
+
+    /// Set the comma-separated bootstrap brokers
+    pub fn with_brokers(mut self, brokers: impl Into<String>) -> Self {
+        self.config.brokers = brokers.into();
+        self
+    }
+
+    /// Set the Kafka client id (defaults to the reaction id)
+    pub fn with_client_id(mut self, client_id: impl Into<String>) -> Self {
+        self.config.client_id = Some(client_id.into());
+        self
+    }
+
+    /// Set the default topic template
+    pub fn with_default_topic(mut self, topic: impl Into<String>) -> Self {
+        self.config.default_topic = topic.into();
+        self
+    }
+
+    /// Set the default key path, e.g. `after.symbol`
+    pub fn with_key(mut self, path: impl Into<String>) -> Self {
+        self.config.key = Some(path.into());
+        self
+    }
+
+    /// Set the partitioning strategy
+    pub fn with_partitioner(mut self, partitioner: PartitionStrategy) -> Self {
+        self.config.partitioner = partitioner;
+        self
+    }
+
+    /// Set the broker acknowledgement level
+    pub fn with_acks(mut self, acks: KafkaAcks) -> Self {
+        self.config.acks = acks;
+        self
+    }
+
+    /// Enable or disable the idempotent producer
+    pub fn with_idempotent(mut self, idempotent: bool) -> Self {
+        self.config.idempotent = idempotent;
+        self
+    }
+
+    /// Set the delivery timeout in milliseconds
+    pub fn with_message_timeout_ms(mut self, timeout_ms: u64) -> Self {
+        self.config.message_timeout_ms = timeout_ms;
+        self
+    }
+
+    /// Set the message value encoding
+    pub fn with_serialization(mut self, serialization: KafkaSerialization) -> Self {
+        self.config.serialization = serialization;
+        self
+    }
+
+    /// Set the default Avro schema (JSON)
+    pub fn with_avro_schema(mut self, schema: impl Into<String>) -> Self {
+        self.config.avro_schema = Some(schema.into());
+        self
+    }
+
+    /// Set the schema registry used for Avro serialization
+    pub fn with_schema_registry(mut self, registry: SchemaRegistryConfig) -> Self {
+        self.config.schema_registry = Some(registry);
+        self
+    }
+
+    /// Use Avro serialization with the given schema and registry URL
+    pub fn with_avro(mut self, schema: impl Into<String>, registry_url: impl Into<String>) -> Self {
+        self.config.serialization = KafkaSerialization::Avro;
+        self.config.avro_schema = Some(schema.into());
+        self.config.schema_registry = Some(SchemaRegistryConfig::new(registry_url));
+        self
+    }
+
+    /// Set an additional librdkafka producer property
+    pub fn with_property(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.config.properties.insert(key.into(), value.into());
+        self
+    }
+
+    /// Set the priority queue capacity
+    pub fn with_priority_queue_capacity(mut self, capacity: usize) -> Self {
+        self.priority_queue_capacity = Some(capacity);
+        self
+    }
+
+    /// Set whether the reaction should auto-start
+    pub fn with_auto_start(mut self, auto_start: bool) -> Self {
+        self.auto_start = auto_start;
+        self
+    }
+
+    /// Add a route configuration for a specific query
+    pub fn with_route(mut self, query_id: impl Into<String>, config: QueryConfig) -> Self {
+        self.config.routes.insert(query_id.into(), config);
+        self
+    }
+
+    /// Set the default template configuration used when no query-specific route is defined
+    pub fn with_default_template(mut self, config: QueryConfig) -> Self {
+        self.config.default_template = Some(config);
+        self
+    }
+
+    /// Set the full configuration at once
+    pub fn with_config(mut self, config: KafkaReactionConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Build the Kafka reaction
+    pub fn build(self) -> anyhow::Result<KafkaReaction> {
+        KafkaReaction::validate_config(&self.queries, &self.config)?;
+
+        Ok(KafkaReaction::from_builder(
+            self.id,
+            self.queries,
+            self.config,
+            self.priority_queue_capacity,
+            self.auto_start,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests;
+
+/// Dynamic plugin entry point.
+#[cfg(feature = "dynamic-plugin")]
+drasi_plugin_sdk::export_plugin!(
+    plugin_id = "kafka-reaction",
+    core_version = env!("CARGO_PKG_VERSION"),
+    lib_version = env!("CARGO_PKG_VERSION"),
+    plugin_version = env!("CARGO_PKG_VERSION"),
+    source_descriptors = [],
+    reaction_descriptors = [descriptor::KafkaReactionDescriptor],
+    bootstrap_descriptors = [],
+);
@@ -0,0 +1,456 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::descriptor::KafkaReactionDescriptor;
+use crate::kafka::KafkaRecord;
+use drasi_lib::channels::{QueryResult, ResultDiff};
+use drasi_lib::templating::TemplateEngine;
+use drasi_lib::Reaction;
+use drasi_plugin_sdk::prelude::ReactionPluginDescriptor;
+use serde_json::json;
+use std::collections::HashMap;
+
+const TRADE_SCHEMA: &str =
+    r#"{"type": "record", "name": "Trade", "fields": [{"name": "symbol", "type": "string"}]}"#;
+
+fn query_result(query_id: &str, results: Vec<ResultDiff>) -> QueryResult {
+    QueryResult::new(
+        query_id.to_string(),
+        chrono::Utc::now(),
+        results,
+        HashMap::new(),
+    )
+}
+
+fn render(config: &KafkaReactionConfig, result: &QueryResult) -> Vec<KafkaRecord> {
+    KafkaReaction::render_records(&TemplateEngine::new(), config, result, "test-reaction")
+}
+
+#[test]
+fn test_kafka_builder_defaults() {
+    let reaction = KafkaReactionBuilder::new("test-reaction").build().unwrap();
+    assert_eq!(reaction.id(), "test-reaction");
+    assert_eq!(reaction.type_name(), "kafka");
+    let props = reaction.properties();
+    assert_eq!(props.get("brokers"), Some(&json!("localhost:9092")));
+    assert_eq!(props.get("acks"), Some(&json!("all")));
+    assert_eq!(props.get("partitioner"), Some(&json!("key_hash")));
+    assert_eq!(props.get("serialization"), Some(&json!("json")));
+}
+
+#[test]
+fn test_kafka_properties_redact_secrets() {
+    let reaction = KafkaReaction::builder("test-reaction")
+        .with_property("sasl.password", "secret")
+        .with_property("security.protocol", "SASL_SSL")
+        .with_avro(TRADE_SCHEMA, "http://registry:8081")
+        .with_schema_registry(SchemaRegistryConfig {
+            url: "http://registry:8081".to_string(),
+            username: Some("user".to_string()),
+            password: Some("secret".to_string()),
+        })
+        .build()
+        .unwrap();
+    let props = reaction.properties();
+    assert_eq!(props["properties"]["sasl.password"], json!("***"));
+    assert_eq!(props["properties"]["security.protocol"], json!("SASL_SSL"));
+    assert_eq!(props["schema_registry"]["username"], json!("user"));
+    assert_eq!(props["schema_registry"]["password"], json!("***"));
+}
+
+#[test]
+fn test_kafka_builder_validation() {
+    assert!(KafkaReaction::builder("r")
+        .with_brokers("")
+        .build()
+        .is_err());
+    assert!(KafkaReaction::builder("r")
+        .with_default_topic("")
+        .build()
+        .is_err());
+    assert!(KafkaReaction::builder("r").with_key("").build().is_err());
+    assert!(KafkaReaction::builder("r")
+        .with_idempotent(true)
+        .with_acks(KafkaAcks::Leader)
+        .build()
+        .is_err());
+    assert!(KafkaReaction::builder("r")
+        .with_idempotent(true)
+        .build()
+        .is_ok());
+
+    // Avro needs both a valid schema and a registry
+    assert!(KafkaReaction::builder("r")
+        .with_serialization(KafkaSerialization::Avro)
+        .with_avro_schema(TRADE_SCHEMA)
+        .build()
+        .is_err());
+    assert!(KafkaReaction::builder("r")
+        .with_avro(r#"{"type": "record"}"#, "http://registry:8081")
+        .build()
+        .is_err());
+    assert!(KafkaReaction::builder("r")
+        .with_avro(TRADE_SCHEMA, "http://registry:8081")
+        .build()
+        .is_ok());
+}
+
+#[test]
+fn test_kafka_builder_invalid_route_fails() {
+    let route = |extension: KafkaExtension| QueryConfig {
+        added: Some(TemplateSpec::with_extension("{{json after}}", extension)),
+        updated: None,
+        deleted: None,
+    };
+
+    let invalid_topic = KafkaReaction::builder("r")
+        .with_query("query1")
+        .with_route(
+            "query1",
+            route(KafkaExtension {
+                topic: Some("alerts-{{#if}}".to_string()),
+                ..Default::default()
+            }),
+        )
+        .build();
+    assert!(invalid_topic.is_err());
+
+    let negative_partition = KafkaReaction::builder("r")
+        .with_query("query1")
+        .with_route(
+            "query1",
+            route(KafkaExtension {
+                partition: Some(-1),
+                ..Default::default()
+            }),
+        )
+        .build();
+    assert!(negative_partition.is_err());
+
+    let unmatched = KafkaReaction::builder("r")
+        .with_query("query1")
+        .with_route("other", route(KafkaExtension::default()))
+        .build();
+    assert!(unmatched.is_err());
+
+    let dotted = KafkaReaction::builder("r")
+        .with_query("source.query1")
+        .with_route("query1", route(KafkaExtension::default()))
+        .build();
+    assert!(dotted.is_ok());
+}
+
+#[test]
+fn test_client_config() {
+    let config = KafkaReactionConfig {
+        brokers: "k1:9092,k2:9092".to_string(),
+        acks: KafkaAcks::Leader,
+        partitioner: PartitionStrategy::Murmur2,
+        properties: HashMap::from([
+            ("compression.type".to_string(), "lz4".to_string()),
+            ("acks".to_string(), "0".to_string()),
+        ]),
+        ..Default::default()
+    };
+
+    let client = KafkaReaction::client_config("reaction-1", &config);
+    assert_eq!(client.get("bootstrap.servers"), Some("k1:9092,k2:9092"));
+    assert_eq!(client.get("client.id"), Some("reaction-1"));
+    assert_eq!(client.get("partitioner"), Some("murmur2_random"));
+    assert_eq!(client.get("enable.idempotence"), Some("false"));
+    assert_eq!(client.get("message.timeout.ms"), Some("30000"));
+    assert_eq!(client.get("compression.type"), Some("lz4"));
+    // Explicit properties override derived settings
+    assert_eq!(client.get("acks"), Some("0"));
+}
+
+#[test]
+fn test_extract_key() {
+    let mut context = serde_json::Map::new();
+    context.insert(
+        "after".to_string(),
+        json!({"symbol": "MSFT", "id": 7, "tags": ["a", "b"], "venue": null}),
+    );
+
+    let key = |path| KafkaReaction::extract_key(&context, path);
+    assert_eq!(key("after.symbol"), Some("MSFT".to_string()));
+    assert_eq!(key("after.id"), Some("7".to_string()));
+    assert_eq!(key("after.tags.1"), Some("b".to_string()));
+    assert_eq!(key("after.venue"), None);
+    assert_eq!(key("after.missing"), None);
+    assert_eq!(key("before.symbol"), None);
+}
+
+#[test]
+fn test_render_default_payload_and_topic() {
+    let config = KafkaReactionConfig::default();
+    let result = query_result(
+        "trades",
+        vec![
+            ResultDiff::Add {
+                data: json!({"symbol": "MSFT"}),
+            },
+            ResultDiff::Noop,
+        ],
+    );
+
+    let records = render(&config, &result);
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].topic, "drasi-trades");
+    assert_eq!(records[0].key, None);
+    assert_eq!(records[0].partition, None);
+    assert_eq!(records[0].avro_schema, None);
+
+    let payload: serde_json::Value = serde_json::from_str(&records[0].payload).unwrap();
+    assert_eq!(payload["queryId"], "trades");
+    assert_eq!(payload["operation"], "ADD");
+    assert_eq!(payload["result"]["data"]["symbol"], "MSFT");
+}
+
+#[test]
+fn test_render_route_topic_key_and_partition() {
+    let mut config = KafkaReactionConfig {
+        key: Some("after.symbol".to_string()),
+        ..Default::default()
+    };
+    config.routes.insert(
+        "trades".to_string(),
+        QueryConfig {
+            added: None,
+            updated: Some(TemplateSpec::with_extension(
+                "{{before.price}}->{{after.price}}",
+                KafkaExtension {
+                    topic: Some("prices-{{after.venue}}".to_string()),
+                    key: Some("after.id".to_string()),
+                    partition: Some(3),
+                    ..Default::default()
+                },
+            )),
+            deleted: None,
+        },
+    );
+
+    let result = query_result(
+        "source.trades",
+        vec![ResultDiff::Update {
+            data: json!({"id": 1, "symbol": "MSFT", "venue": "nyse", "price": 11}),
+            before: json!({"id": 1, "symbol": "MSFT", "venue": "nyse", "price": 10}),
+            after: json!({"id": 1, "symbol": "MSFT", "venue": "nyse", "price": 11}),
+            grouping_keys: None,
+        }],
+    );
+
+    let records = render(&config, &result);
+    assert_eq!(
+        records,
+        vec![KafkaRecord {
+            topic: "prices-nyse".to_string(),
+            key: Some("1".to_string()),
+            partition: Some(3),
+            payload: "10->11".to_string(),
+            avro_schema: None,
+        }]
+    );
+
+    // Operations without a template use the reaction-level topic and key
+    let result = query_result(
+        "trades",
+        vec![ResultDiff::Add {
+            data: json!({"symbol": "AAPL"}),
+        }],
+    );
+    let records = render(&config, &result);
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].topic, "drasi-trades");
+    assert_eq!(records[0].key, Some("AAPL".to_string()));
+    assert_eq!(records[0].partition, None);
+}
+
+#[test]
+fn test_render_with_shared_template_helpers() {
+    let mut config = KafkaReactionConfig::default();
+    config.routes.insert(
+        "trades".to_string(),
+        QueryConfig {
+            added: Some(TemplateSpec::with_extension(
+                "{{upper after.symbol}} {{format_number after.price 1}}",
+                KafkaExtension {
+                    topic: Some("trades-{{lower after.venue}}".to_string()),
+                    ..Default::default()
+                },
+            )),
+            updated: None,
+            deleted: None,
+        },
+    );
+
+    let result = query_result(
+        "trades",
+        vec![ResultDiff::Add {
+            data: json!({"symbol": "msft", "venue": "NYSE", "price": 410.5}),
+        }],
+    );
+    let records = render(&config, &result);
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].topic, "trades-nyse");
+    assert_eq!(records[0].payload, "MSFT 410.5");
+}
+
+#[test]
+fn test_render_skips_invalid_topics() {
+    let config = KafkaReactionConfig {
+        default_topic: "alerts-{{after.id}}".to_string(),
+        ..Default::default()
+    };
+    let result = query_result(
+        "trades",
+        vec![
+            ResultDiff::Add {
+                data: json!({"id": "a/b"}),
+            },
+            ResultDiff::Add {
+                data: json!({"id": ""}),
+            },
+            ResultDiff::Add {
+                data: json!({"id": "ok_1.x"}),
+            },
+        ],
+    );
+
+    let records = render(&config, &result);
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].topic, "alerts-ok_1.x");
+}
+
+#[test]
+fn test_render_avro_schema_selection() {
+    let override_schema =
+        r#"{"type": "record", "name": "Removed", "fields": [{"name": "id", "type": "long"}]}"#;
+    let mut config = KafkaReactionConfig {
+        serialization: KafkaSerialization::Avro,
+        avro_schema: Some(TRADE_SCHEMA.to_string()),
+        schema_registry: Some(SchemaRegistryConfig::new("http://registry:8081")),
+        ..Default::default()
+    };
+    config.routes.insert(
+        "trades".to_string(),
+        QueryConfig {
+            added: None,
+            updated: None,
+            deleted: Some(TemplateSpec::with_extension(
+                r#"{"id": {{before.id}}}"#,
+                KafkaExtension {
+                    avro_schema: Some(override_schema.to_string()),
+                    ..Default::default()
+                },
+            )),
+        },
+    );
+
+    let result = query_result(
+        "trades",
+        vec![
+            ResultDiff::Add {
+                data: json!({"id": 1, "symbol": "MSFT"}),
+            },
+            ResultDiff::Delete {
+                data: json!({"id": 1, "symbol": "MSFT"}),
+            },
+        ],
+    );
+
+    let records = render(&config, &result);
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].avro_schema.as_deref(), Some(TRADE_SCHEMA));
+    assert_eq!(records[1].avro_schema.as_deref(), Some(override_schema));
+    assert_eq!(records[1].payload, r#"{"id": 1}"#);
+}
+
+#[test]
+fn test_config_deserialization_defaults() {
+    let config: KafkaReactionConfig = serde_json::from_value(json!({
+        "brokers": "kafka:9092",
+        "key": "after.symbol",
+        "partitioner": "murmur2",
+        "acks": "leader"
+    }))
+    .unwrap();
+    assert_eq!(config.brokers, "kafka:9092");
+    assert_eq!(config.key.as_deref(), Some("after.symbol"));
+    assert_eq!(config.partitioner, PartitionStrategy::Murmur2);
+    assert_eq!(config.acks, KafkaAcks::Leader);
+    assert_eq!(config.default_topic, "drasi-{{query_name}}");
+    assert_eq!(config.message_timeout_ms, 30000);
+    assert_eq!(config.serialization, KafkaSerialization::Json);
+    assert!(!config.idempotent);
+    assert!(config.routes.is_empty());
+}
+
+#[tokio::test]
+async fn test_descriptor_creates_reaction() {
+    let descriptor = KafkaReactionDescriptor;
+    assert_eq!(descriptor.kind(), "kafka");
+
+    let config = json!({
+        "brokers": "kafka:9092",
+        "key": "after.symbol",
+        "partitioner": "random",
+        "acks": "all",
+        "idempotent": true,
+        "messageTimeoutMs": 5000,
+        "serialization": "avro",
+        "avroSchema": TRADE_SCHEMA,
+        "schemaRegistry": {
+            "url": "http://registry:8081",
+            "username": "user",
+            "password": "secret"
+        },
+        "properties": { "compression.type": "zstd" },
+        "routes": {
+            "query1": {
+                "added": { "template": "{{json after}}", "topic": "trades-{{after.venue}}", "partition": 0 }
+            }
+        }
+    });
+
+    let reaction = descriptor
+        .create_reaction("kafka-1", vec!["query1".to_string()], &config, false)
+        .await
+        .unwrap();
+
+    assert_eq!(reaction.id(), "kafka-1");
+    assert!(!reaction.auto_start());
+    let props = reaction.properties();
+    assert_eq!(props.get("brokers"), Some(&json!("kafka:9092")));
+    assert_eq!(props.get("partitioner"), Some(&json!("random")));
+    assert_eq!(props.get("idempotent"), Some(&json!(true)));
+    assert_eq!(props.get("message_timeout_ms"), Some(&json!(5000)));
+    assert_eq!(props.get("serialization"), Some(&json!("avro")));
+    assert_eq!(props["schema_registry"]["password"], json!("***"));
+    assert_eq!(props["properties"]["compression.type"], json!("zstd"));
+}
+
+#[tokio::test]
+async fn test_descriptor_rejects_unknown_template_fields() {
+    let config = json!({
+        "routes": {
+            "query1": { "added": { "template": "", "qos": "at_least_once" } }
+        }
+    });
+    let result = KafkaReactionDescriptor
+        .create_reaction("kafka-1", vec!["query1".to_string()], &config, true)
+        .await;
+    assert!(result.is_err());
+}
@@ -38,6 +38,10 @@ pub use common::{QueryConfig, TemplateSpec};
 /// - `data` - The raw data field (available for UPDATE)
 /// - `query_name` - The name of the query that produced the result
 /// - `operation` - The operation type ("ADD", "UPDATE", or "DELETE")
+/// - `timestamp` - The query result timestamp (RFC 3339)
+/// - `metadata` - The query result metadata map
+///
+/// See [`drasi_lib::templating`] for the available helpers.
 ///
 /// ## Example with Default Template
 ///
@@ -15,14 +15,13 @@
 use super::config::LogReactionConfig;
 use anyhow::Result;
 use async_trait::async_trait;
-use handlebars::Handlebars;
 use log::debug;
-use serde_json::{Map, Value};
 use std::collections::HashMap;
 
 use drasi_lib::channels::{ComponentStatus, ResultDiff};
 use drasi_lib::managers::log_component_start;
 use drasi_lib::reactions::common::base::{ReactionBase, ReactionBaseParams};
+use drasi_lib::templating::{change_context, TemplateEngine};
 use drasi_lib::Reaction;
 
 pub struct LogReaction {
@@ -114,9 +113,7 @@ impl LogReaction {
             return Ok(());
         }
         // Compile the template to validate syntax without requiring data
-        handlebars::Template::compile(template)
-            .map_err(|e| anyhow::anyhow!("Invalid template: {e}"))?;
-        Ok(())
+        TemplateEngine::validate(template)
     }
 
     /// Validate all templates in a QueryConfig
@@ -405,26 +402,7 @@ impl Reaction for LogReaction {
         let mut shutdown_rx = self.base.create_shutdown_channel().await;
 
         let processing_task = tokio::spawn(async move {
-            // Set up Handlebars with json helper
-            let mut handlebars = Handlebars::new();
-            handlebars.register_helper(
-                "json",
-                Box::new(
-                    |h: &handlebars::Helper,
-                     _: &Handlebars,
-                     _: &handlebars::Context,
-                     _: &mut handlebars::RenderContext,
-                     out: &mut dyn handlebars::Output|
-                     -> handlebars::HelperResult {
-                        if let Some(value) = h.param(0) {
-                            let json_str = serde_json::to_string(&value.value())
-                                .unwrap_or_else(|_| "null".to_string());
-                            out.write(&json_str)?;
-                        }
-                        Ok(())
-                    },
-                ),
-            );
+            let engine = TemplateEngine::new();
 
             loop {
                 // Use select to wait for either a result OR shutdown signal
@@ -443,6 +421,10 @@ impl Reaction for LogReaction {
                 // Note: We need to clone and modify since Arc doesn't allow mutation
                 let mut query_result = (*query_result_arc).clone();
 
+                // Attach the output below to the change's trace
+                let span = drasi_lib::telemetry::result_span(&query_result);
+                let _entered = span.enter();
+
                 // Capture reaction_receive_ns timestamp
                 if let Some(ref mut profiling) = query_result.profiling {
                     profiling.reaction_receive_ns = Some(drasi_lib::profiling::timestamp_ns());
@@ -463,123 +445,26 @@ impl Reaction for LogReaction {
                     );
                 }
 
-                for result in &query_result.results {
-                    // Build context for template rendering
-                    let mut context = Map::new();
-                    context.insert(
-                        "query_name".to_string(),
-                        Value::String(query_result.query_id.clone()),
-                    );
+                // Get query-specific config if available, otherwise use default
+                let query_config = config
+                    .routes
+                    .get(&query_result.query_id)
+                    .or(config.default_template.as_ref());
 
-                    // Get query-specific config if available, otherwise use default
-                    let query_config = config
-                        .routes
-                        .get(&query_result.query_id)
-                        .or(config.default_template.as_ref());
-
-                    match result {
-                        ResultDiff::Add { data } => {
-                            context.insert("operation".to_string(), Value::String("ADD".into()));
-                            context.insert("after".to_string(), data.clone());
-
-                            let template = query_config
-                                .and_then(|qc| qc.added.as_ref())
-                                .map(|ts| ts.template.as_str());
-
-                            if let Some(template_str) = template {
-                                match handlebars.render_template(template_str, &context) {
-                                    Ok(rendered) => {
-                                        #[allow(clippy::print_stdout)]
-                                        {
-                                            println!("[{reaction_name}]   {rendered}");
-                                        }
-                                    }
-                                    Err(e) => {
-                                        debug!("[{reaction_name}] Template render error: {e}");
-                                        #[allow(clippy::print_stdout)]
-                                        {
-                                            println!("[{reaction_name}]   [ADD] {data}");
-                                        }
-                                    }
-                                }
-                            } else {
-                                #[allow(clippy::print_stdout)]
-                                {
-                                    println!("[{reaction_name}]   [ADD] {data}");
-                                }
-                            }
-                        }
-                        ResultDiff::Delete { data } => {
-                            context.insert("operation".to_string(), Value::String("DELETE".into()));
-                            context.insert("before".to_string(), data.clone());
-
-                            let template = query_config
-                                .and_then(|qc| qc.deleted.as_ref())
-                                .map(|ts| ts.template.as_str());
-
-                            if let Some(template_str) = template {
-                                match handlebars.render_template(template_str, &context) {
-                                    Ok(rendered) => {
-                                        #[allow(clippy::print_stdout)]
-                                        {
-                                            println!("[{reaction_name}]   {rendered}");
-                                        }
-                                    }
-                                    Err(e) => {
-                                        debug!("[{reaction_name}] Template render error: {e}");
-                                        #[allow(clippy::print_stdout)]
-                                        {
-                                            println!("[{reaction_name}]   [DELETE] {data}");
-                                        }
-                                    }
-                                }
-                            } else {
-                                #[allow(clippy::print_stdout)]
-                                {
-                                    println!("[{reaction_name}]   [DELETE] {data}");
-                                }
-                            }
-                        }
-                        ResultDiff::Update {
-                            before,
-                            after,
-                            data,
-                            ..
-                        } => {
-                            context.insert("operation".to_string(), Value::String("UPDATE".into()));
-                            context.insert("before".to_string(), before.clone());
-                            context.insert("after".to_string(), after.clone());
-                            context.insert("data".to_string(), data.clone());
-
-                            let template = query_config
-                                .and_then(|qc| qc.updated.as_ref())
-                                .map(|ts| ts.template.as_str());
-
-                            if let Some(template_str) = template {
-                                match handlebars.render_template(template_str, &context) {
-                                    Ok(rendered) => {
-                                        #[allow(clippy::print_stdout)]
-                                        {
-                                            println!("[{reaction_name}]   {rendered}");
-                                        }
-                                    }
-                                    Err(e) => {
-                                        debug!("[{reaction_name}] Template render error: {e}");
-                                        #[allow(clippy::print_stdout)]
-                                        {
-                                            println!(
-                                                "[{reaction_name}]   [UPDATE] {before} -> {after}"
-                                            );
-                                        }
-                                    }
-                                }
-                            } else {
-                                #[allow(clippy::print_stdout)]
-                                {
-                                    println!("[{reaction_name}]   [UPDATE] {before} -> {after}");
-                                }
-                            }
-                        }
+                for result in &query_result.results {
+                    let (template, fallback) = match result {
+                        ResultDiff::Add { data } => (
+                            query_config.and_then(|qc| qc.added.as_ref()),
+                            format!("[ADD] {data}"),
+                        ),
+                        ResultDiff::Delete { data } => (
+                            query_config.and_then(|qc| qc.deleted.as_ref()),
+                            format!("[DELETE] {data}"),
+                        ),
+                        ResultDiff::Update { before, after, .. } => (
+                            query_config.and_then(|qc| qc.updated.as_ref()),
+                            format!("[UPDATE] {before} -> {after}"),
+                        ),
                         ResultDiff::Aggregation { .. } | ResultDiff::Noop => {
                             let result_json = serde_json::to_string(result)
                                 .expect("ResultDiff serialization should succeed");
@@ -588,11 +473,26 @@ impl Reaction for LogReaction {
                                 ResultDiff::Noop => "NOOP",
                                 _ => "UNKNOWN",
                             };
-                            #[allow(clippy::print_stdout)]
-                            {
-                                println!("[{reaction_name}]   [{operation}] {result_json}");
+                            (None, format!("[{operation}] {result_json}"))
+                        }
+                    };
+
+                    let rendered = match (template, change_context(&query_result, result)) {
+                        (Some(spec), Some(context)) => {
+                            match engine.render(&spec.template, &context) {
+                                Ok(rendered) => rendered,
+                                Err(e) => {
+                                    debug!("[{reaction_name}] {e}");
+                                    fallback
+                                }
                             }
                         }
+                        _ => fallback,
+                    };
+
+                    #[allow(clippy::print_stdout)]
+                    {
+                        println!("[{reaction_name}]   {rendered}");
                     }
                 }
 
@@ -0,0 +1,163 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration types for MQTT reactions.
+
+use drasi_lib::reactions::common::TemplateRouting;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+fn default_broker_host() -> String {
+    "localhost".to_string()
+}
+
+fn default_broker_port() -> u16 {
+    1883
+}
+
+fn default_keep_alive_secs() -> u64 {
+    30
+}
+
+fn default_topic() -> String {
+    "drasi/{{query_name}}".to_string()
+}
+
+/// MQTT quality-of-service level used when publishing.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum MqttQos {
+    /// QoS 0 - fire and forget.
+    AtMostOnce,
+    /// QoS 1 - acknowledged delivery, duplicates possible (default).
+    #[default]
+    AtLeastOnce,
+    /// QoS 2 - assured single delivery.
+    ExactlyOnce,
+}
+
+impl From<MqttQos> for rumqttc::QoS {
+    fn from(qos: MqttQos) -> Self {
+        match qos {
+            MqttQos::AtMostOnce => rumqttc::QoS::AtMostOnce,
+            MqttQos::AtLeastOnce => rumqttc::QoS::AtLeastOnce,
+            MqttQos::ExactlyOnce => rumqttc::QoS::ExactlyOnce,
+        }
+    }
+}
+
+/// MQTT-specific extension for template specifications.
+///
+/// Lets each operation template publish to its own topic with its own
+/// delivery settings. Unset fields fall back to the reaction-level defaults.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct MqttExtension {
+    /// Topic to publish to. Supports Handlebars templates, e.g. `alerts/{{query_name}}`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic: Option<String>,
+
+    /// QoS override for this template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qos: Option<MqttQos>,
+
+    /// Retain flag override for this template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retain: Option<bool>,
+}
+
+/// Type alias for MQTT template specification using the common generic type.
+///
+/// The template string renders the message payload. If it is empty, the payload
+/// is a JSON object with `queryId`, `operation`, `result`, and `timestamp`.
+pub type TemplateSpec = drasi_lib::reactions::common::TemplateSpec<MqttExtension>;
+
+/// Type alias for MQTT query configuration using the common generic type.
+pub type QueryConfig = drasi_lib::reactions::common::QueryConfig<MqttExtension>;
+
+/// MQTT reaction configuration
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MqttReactionConfig {
+    /// Broker hostname
+    #[serde(default = "default_broker_host")]
+    pub broker_host: String,
+
+    /// Broker port
+    #[serde(default = "default_broker_port")]
+    pub broker_port: u16,
+
+    /// MQTT client id. Defaults to the reaction id when not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+
+    /// Optional username for broker authentication
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+
+    /// Optional password for broker authentication
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+
+    /// Keep-alive interval in seconds
+    #[serde(default = "default_keep_alive_secs")]
+    pub keep_alive_secs: u64,
+
+    /// Default topic template used when a template spec does not set its own topic
+    #[serde(default = "default_topic")]
+    pub default_topic: String,
+
+    /// Default QoS for published messages
+    #[serde(default)]
+    pub qos: MqttQos,
+
+    /// Default retain flag for published messages
+    #[serde(default)]
+    pub retain: bool,
+
+    /// Query-specific template configurations
+    #[serde(default)]
+    pub routes: HashMap<String, QueryConfig>,
+
+    /// Default template configuration used when no query-specific route is defined.
+    /// If not set, every result is published as raw JSON to `default_topic`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_template: Option<QueryConfig>,
+}
+
+impl Default for MqttReactionConfig {
+    fn default() -> Self {
+        Self {
+            broker_host: default_broker_host(),
+            broker_port: default_broker_port(),
+            client_id: None,
+            username: None,
+            password: None,
+            keep_alive_secs: default_keep_alive_secs(),
+            default_topic: default_topic(),
+            qos: MqttQos::default(),
+            retain: false,
+            routes: HashMap::new(),
+            default_template: None,
+        }
+    }
+}
+
+impl TemplateRouting<MqttExtension> for MqttReactionConfig {
+    fn routes(&self) -> &HashMap<String, QueryConfig> {
+        &self.routes
+    }
+
+    fn default_template(&self) -> Option<&QueryConfig> {
+        self.default_template.as_ref()
+    }
+}
@@ -0,0 +1,252 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Descriptor for the MQTT reaction plugin.
+
+use drasi_lib::reactions::Reaction;
+use drasi_plugin_sdk::prelude::*;
+use std::collections::HashMap;
+use utoipa::OpenApi;
+
+use crate::config::{MqttExtension, MqttQos};
+use crate::MqttReactionBuilder;
+
+/// DTO for MQTT quality-of-service level.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::mqtt::MqttQos)]
+#[serde(rename_all = "snake_case")]
+pub enum MqttQosDto {
+    AtMostOnce,
+    AtLeastOnce,
+    ExactlyOnce,
+}
+
+impl From<MqttQosDto> for MqttQos {
+    fn from(dto: MqttQosDto) -> Self {
+        match dto {
+            MqttQosDto::AtMostOnce => MqttQos::AtMostOnce,
+            MqttQosDto::AtLeastOnce => MqttQos::AtLeastOnce,
+            MqttQosDto::ExactlyOnce => MqttQos::ExactlyOnce,
+        }
+    }
+}
+
+/// DTO for an MQTT template specification.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::mqtt::MqttTemplateSpec)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct MqttTemplateSpecDto {
+    /// Handlebars template for the message payload.
+    #[serde(default)]
+    pub template: String,
+
+    /// Optional topic template for this operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic: Option<String>,
+
+    /// Optional QoS override for this operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qos: Option<MqttQosDto>,
+
+    /// Optional retain flag override for this operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retain: Option<bool>,
+}
+
+/// DTO for per-query MQTT template configuration.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::mqtt::MqttQueryConfig)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct MqttQueryConfigDto {
+    /// Template for ADD operations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub added: Option<MqttTemplateSpecDto>,
+
+    /// Template for UPDATE operations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated: Option<MqttTemplateSpecDto>,
+
+    /// Template for DELETE operations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted: Option<MqttTemplateSpecDto>,
+}
+
+/// Configuration DTO for the MQTT reaction plugin.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::mqtt::MqttReactionConfig)]
+#[serde(rename_all = "camelCase")]
+pub struct MqttReactionConfigDto {
+    /// Broker hostname.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub broker_host: Option<ConfigValue<String>>,
+
+    /// Broker port.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueU16>)]
+    pub broker_port: Option<ConfigValue<u16>>,
+
+    /// MQTT client id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub client_id: Option<ConfigValue<String>>,
+
+    /// Username for broker authentication.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub username: Option<ConfigValue<String>>,
+
+    /// Password for broker authentication.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub password: Option<ConfigValue<String>>,
+
+    /// Keep-alive interval in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueU64>)]
+    pub keep_alive_secs: Option<ConfigValue<u64>>,
+
+    /// Default topic template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub default_topic: Option<ConfigValue<String>>,
+
+    /// Default QoS for published messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qos: Option<MqttQosDto>,
+
+    /// Default retain flag for published messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueBool>)]
+    pub retain: Option<ConfigValue<bool>>,
+
+    /// Query-specific template configurations.
+    #[serde(default)]
+    pub routes: HashMap<String, MqttQueryConfigDto>,
+
+    /// Default template configuration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_template: Option<MqttQueryConfigDto>,
+}
+
+fn map_template_spec(dto: &MqttTemplateSpecDto) -> crate::TemplateSpec {
+    crate::TemplateSpec {
+        template: dto.template.clone(),
+        extension: MqttExtension {
+            topic: dto.topic.clone(),
+            qos: dto.qos.map(Into::into),
+            retain: dto.retain,
+        },
+    }
+}
+
+fn map_query_config(dto: &MqttQueryConfigDto) -> crate::QueryConfig {
+    crate::QueryConfig {
+        added: dto.added.as_ref().map(map_template_spec),
+        updated: dto.updated.as_ref().map(map_template_spec),
+        deleted: dto.deleted.as_ref().map(map_template_spec),
+    }
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(
+    MqttReactionConfigDto,
+    MqttQueryConfigDto,
+    MqttTemplateSpecDto,
+    MqttQosDto,
+)))]
+struct MqttReactionSchemas;
+
+/// Descriptor for the MQTT reaction plugin.
+pub struct MqttReactionDescriptor;
+
+#[async_trait]
+impl ReactionPluginDescriptor for MqttReactionDescriptor {
+    fn kind(&self) -> &str {
+        "mqtt"
+    }
+
+    fn config_version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn config_schema_name(&self) -> &str {
+        "reaction.mqtt.MqttReactionConfig"
+    }
+
+    fn config_schema_json(&self) -> String {
+        let api = MqttReactionSchemas::openapi();
+        serde_json::to_string(
+            &api.components
+                .as_ref()
+                .expect("OpenAPI components missing")
+                .schemas,
+        )
+        .expect("Failed to serialize config schema")
+    }
+
+    async fn create_reaction(
+        &self,
+        id: &str,
+        query_ids: Vec<String>,
+        config_json: &serde_json::Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn Reaction>> {
+        let dto: MqttReactionConfigDto = serde_json::from_value(config_json.clone())?;
+        let mapper = DtoMapper::new();
+
+        let mut builder = MqttReactionBuilder::new(id)
+            .with_queries(query_ids)
+            .with_auto_start(auto_start);
+
+        if let Some(ref host) = dto.broker_host {
+            builder = builder.with_broker_host(mapper.resolve_string(host)?);
+        }
+        if let Some(ref port) = dto.broker_port {
+            builder = builder.with_broker_port(mapper.resolve_typed(port)?);
+        }
+        if let Some(ref client_id) = dto.client_id {
+            builder = builder.with_client_id(mapper.resolve_string(client_id)?);
+        }
+        if let Some(ref username) = dto.username {
+            let password = mapper
+                .resolve_optional_string(&dto.password)?
+                .unwrap_or_default();
+            builder = builder.with_credentials(mapper.resolve_string(username)?, password);
+        }
+        if let Some(ref keep_alive) = dto.keep_alive_secs {
+            builder = builder.with_keep_alive_secs(mapper.resolve_typed(keep_alive)?);
+        }
+        if let Some(ref topic) = dto.default_topic {
+            builder = builder.with_default_topic(mapper.resolve_string(topic)?);
+        }
+        if let Some(qos) = dto.qos {
+            builder = builder.with_qos(qos.into());
+        }
+        if let Some(ref retain) = dto.retain {
+            builder = builder.with_retain(mapper.resolve_typed(retain)?);
+        }
+
+        if let Some(ref default_template) = dto.default_template {
+            builder = builder.with_default_template(map_query_config(default_template));
+        }
+
+        for (query_id, config) in &dto.routes {
+            builder = builder.with_route(query_id, map_query_config(config));
+        }
+
+        let reaction = builder.build()?;
+        Ok(Box::new(reaction))
+    }
+}
@@ -0,0 +1,192 @@
+#![allow(unexpected_cfgs)]
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! MQTT reaction plugin for Drasi
+//!
+//! This plugin publishes query result changes to an MQTT broker. Each added,
+//! updated or deleted row becomes one message on a topic rendered from a
+//! Handlebars template such as `alerts/{{query_name}}`.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use drasi_reaction_mqtt::{MqttQos, MqttReaction};
+//!
+//! let reaction = MqttReaction::builder("my-mqtt-reaction")
+//!     .with_queries(vec!["query1".to_string()])
+//!     .with_broker("broker.local", 1883)
+//!     .with_default_topic("alerts/{{query_name}}")
+//!     .with_qos(MqttQos::AtLeastOnce)
+//!     .build()?;
+//! ```
+
+pub mod config;
+pub mod descriptor;
+pub mod mqtt;
+
+pub use config::{MqttExtension, MqttQos, MqttReactionConfig, QueryConfig, TemplateSpec};
+pub use mqtt::MqttReaction;
+
+/// Builder for MQTT reaction
+pub struct MqttReactionBuilder {
+    id: String,
+    queries: Vec<String>,
+    config: MqttReactionConfig,
+    priority_queue_capacity: Option<usize>,
+    auto_start: bool,
+}
+
+impl MqttReactionBuilder {
+    /// Create a new MQTT reaction builder with the given ID
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            queries: Vec::new(),
+            config: MqttReactionConfig::default(),
+            priority_queue_capacity: None,
+            auto_start: true,
+        }
+    }
+
+    /// Set the query IDs to subscribe to
+    pub fn with_queries(mut self, queries: Vec<String>) -> Self {
+        self.queries = queries;
+        self
+    }
+
+    /// Add a query ID to subscribe to
+    pub fn with_query(mut self, query_id: impl Into<String>) -> Self {
+        self.queries.push(query_id.into());
+        self
+    }
+
+    /// Set the broker host and port
+    pub fn with_broker(mut self, host: impl Into<String>, port: u16) -> Self {
+        self.config.broker_host = host.into();
+        self.config.broker_port = port;
+        self
+    }
+
+    /// Set the broker host
+    pub fn with_broker_host(mut self, host: impl Into<String>) -> Self {
+        self.config.broker_host = host.into();
+        self
+    }
+
+    /// Set the broker port
+    pub fn with_broker_port(mut self, port: u16) -> Self {
+        self.config.broker_port = port;
+        self
+    }
+
+    /// Set the MQTT client id (defaults to the reaction id)
+    pub fn with_client_id(mut self, client_id: impl Into<String>) -> Self {
+        self.config.client_id = Some(client_id.into());
+        self
+    }
+
+    /// Set username and password for broker authentication
+    pub fn with_credentials(
+        mut self,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        self.config.username = Some(username.into());
+        self.config.password = Some(password.into());
+        self
+    }
+
+    /// Set the keep-alive interval in seconds
+    pub fn with_keep_alive_secs(mut self, secs: u64) -> Self {
+        self.config.keep_alive_secs = secs;
+        self
+    }
+
+    /// Set the default topic template
+    pub fn with_default_topic(mut self, topic: impl Into<String>) -> Self {
+        self.config.default_topic = topic.into();
+        self
+    }
+
+    /// Set the default QoS for published messages
+    pub fn with_qos(mut self, qos: MqttQos) -> Self {
+        self.config.qos = qos;
+        self
+    }
+
+    /// Set the default retain flag for published messages
+    pub fn with_retain(mut self, retain: bool) -> Self {
+        self.config.retain = retain;
+        self
+    }
+
+    /// Set the priority queue capacity
+    pub fn with_priority_queue_capacity(mut self, capacity: usize) -> Self {
+        self.priority_queue_capacity = Some(capacity);
+        self
+    }
+
+    /// Set whether the reaction should auto-start
+    pub fn with_auto_start(mut self, auto_start: bool) -> Self {
+        self.auto_start = auto_start;
+        self
+    }
+
+    /// Add a route configuration for a specific query
+    pub fn with_route(mut self, query_id: impl Into<String>, config: QueryConfig) -> Self {
+        self.config.routes.insert(query_id.into(), config);
+        self
+    }
+
+    /// Set the default template configuration used when no query-specific route is defined
+    pub fn with_default_template(mut self, config: QueryConfig) -> Self {
+        self.config.default_template = Some(config);
+        self
+    }
+
+    /// Set the full configuration at once
+    pub fn with_config(mut self, config: MqttReactionConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Build the MQTT reaction
+    pub fn build(self) -> anyhow::Result<MqttReaction> {
+        MqttReaction::validate_config(&self.queries, &self.config)?;
+
+        Ok(MqttReaction::from_builder(
+            self.id,
+            self.queries,
+            self.config,
+            self.priority_queue_capacity,
+            self.auto_start,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests;
+
+/// Dynamic plugin entry point.
+#[cfg(feature = "dynamic-plugin")]
+drasi_plugin_sdk::export_plugin!(
+    plugin_id = "mqtt-reaction",
+    core_version = env!("CARGO_PKG_VERSION"),
+    lib_version = env!("CARGO_PKG_VERSION"),
+    plugin_version = env!("CARGO_PKG_VERSION"),
+    source_descriptors = [],
+    reaction_descriptors = [descriptor::MqttReactionDescriptor],
+    bootstrap_descriptors = [],
+);
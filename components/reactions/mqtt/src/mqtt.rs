@@ -0,0 +1,464 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use log::{debug, error, info, warn};
+use rumqttc::{AsyncClient, MqttOptions};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+use drasi_lib::channels::{ComponentStatus, QueryResult, ResultDiff};
+use drasi_lib::managers::log_component_start;
+use drasi_lib::reactions::common::base::{ReactionBase, ReactionBaseParams};
+use drasi_lib::templating::{change_context, TemplateEngine};
+use drasi_lib::Reaction;
+
+use super::config::{MqttQos, MqttReactionConfig, QueryConfig};
+use super::MqttReactionBuilder;
+
+/// Capacity of the request channel between the client handle and the rumqttc event loop
+const CLIENT_CHANNEL_CAPACITY: usize = 100;
+
+/// A single message ready to be published to the broker.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Publication {
+    pub topic: String,
+    pub payload: String,
+    pub qos: MqttQos,
+    pub retain: bool,
+}
+
+/// MQTT reaction publishes query result changes to an MQTT broker.
+pub struct MqttReaction {
+    base: ReactionBase,
+    config: MqttReactionConfig,
+    client: Arc<RwLock<Option<AsyncClient>>>,
+    event_loop_handle: Arc<RwLock<Option<tokio::task::JoinHandle<()>>>>,
+}
+
+impl std::fmt::Debug for MqttReaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MqttReaction")
+            .field("id", &self.base.id)
+            .field("broker_host", &self.config.broker_host)
+            .field("broker_port", &self.config.broker_port)
+            .finish()
+    }
+}
+
+impl MqttReaction {
+    /// Create a builder for MqttReaction
+    pub fn builder(id: impl Into<String>) -> MqttReactionBuilder {
+        MqttReactionBuilder::new(id)
+    }
+
+    /// Create a new MQTT reaction
+    ///
+    /// The event channel is automatically injected when the reaction is added
+    /// to DrasiLib via `add_reaction()`.
+    ///
+    /// # Errors
+    ///
+    /// - Returns error if any topic or payload template has invalid Handlebars syntax
+    /// - Returns error if a route query ID doesn't match any subscribed query
+    pub fn new(
+        id: impl Into<String>,
+        queries: Vec<String>,
+        config: MqttReactionConfig,
+    ) -> anyhow::Result<Self> {
+        Self::validate_config(&queries, &config)?;
+        Ok(Self::from_builder(id.into(), queries, config, None, true))
+    }
+
+    /// Create from builder (internal method)
+    pub(crate) fn from_builder(
+        id: String,
+        queries: Vec<String>,
+        config: MqttReactionConfig,
+        priority_queue_capacity: Option<usize>,
+        auto_start: bool,
+    ) -> Self {
+        let mut params = ReactionBaseParams::new(id, queries).with_auto_start(auto_start);
+        if let Some(capacity) = priority_queue_capacity {
+            params = params.with_priority_queue_capacity(capacity);
+        }
+
+        Self {
+            base: ReactionBase::new(params),
+            config,
+            client: Arc::new(RwLock::new(None)),
+            event_loop_handle: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// Validate a template by attempting to compile it with Handlebars
+    fn validate_template(template: &str) -> anyhow::Result<()> {
+        if template.is_empty() {
+            return Ok(());
+        }
+        TemplateEngine::validate(template)
+    }
+
+    /// Validate payload and topic templates in a QueryConfig
+    fn validate_query_config(config: &QueryConfig) -> anyhow::Result<()> {
+        for spec in [&config.added, &config.updated, &config.deleted]
+            .into_iter()
+            .flatten()
+        {
+            Self::validate_template(&spec.template)?;
+            if let Some(topic) = &spec.extension.topic {
+                Self::validate_template(topic)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate configuration: templates, broker settings and route-query matching
+    pub(crate) fn validate_config(
+        queries: &[String],
+        config: &MqttReactionConfig,
+    ) -> anyhow::Result<()> {
+        if config.broker_host.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Validation error: broker_host cannot be empty"
+            ));
+        }
+
+        if config.default_topic.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Validation error: default_topic cannot be empty"
+            ));
+        }
+        Self::validate_template(&config.default_topic)
+            .map_err(|e| anyhow::anyhow!("Invalid default topic: {e}"))?;
+
+        for (query_id, route_config) in &config.routes {
+            Self::validate_query_config(route_config)
+                .map_err(|e| anyhow::anyhow!("Invalid template in route '{query_id}': {e}"))?;
+        }
+
+        if let Some(default_template) = &config.default_template {
+            Self::validate_query_config(default_template)
+                .map_err(|e| anyhow::anyhow!("Invalid default template: {e}"))?;
+        }
+
+        if !config.routes.is_empty() && !queries.is_empty() {
+            for route_query in config.routes.keys() {
+                let dotted_route = format!(".{route_query}");
+                let matches = queries
+                    .iter()
+                    .any(|q| q == route_query || q.ends_with(&dotted_route));
+                if !matches {
+                    return Err(anyhow::anyhow!(
+                        "Route '{route_query}' does not match any subscribed query. Subscribed queries: {queries:?}"
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Build the MQTT connection options from configuration
+    fn mqtt_options(id: &str, config: &MqttReactionConfig) -> MqttOptions {
+        let client_id = config.client_id.clone().unwrap_or_else(|| id.to_string());
+        let mut options =
+            MqttOptions::new(client_id, config.broker_host.clone(), config.broker_port);
+        options.set_keep_alive(Duration::from_secs(config.keep_alive_secs));
+        if let Some(username) = &config.username {
+            options.set_credentials(
+                username.clone(),
+                config.password.clone().unwrap_or_default(),
+            );
+        }
+        options
+    }
+
+    /// Find the route for a query, falling back to the last dotted segment and then the default template
+    fn query_config<'a>(
+        config: &'a MqttReactionConfig,
+        query_name: &str,
+    ) -> Option<&'a QueryConfig> {
+        config
+            .routes
+            .get(query_name)
+            .or_else(|| {
+                if query_name.contains('.') {
+                    query_name
+                        .rsplit('.')
+                        .next()
+                        .and_then(|name| config.routes.get(name))
+                } else {
+                    None
+                }
+            })
+            .or(config.default_template.as_ref())
+    }
+
+    /// Convert a query result into the messages that should be published.
+    ///
+    /// Results with no matching template are published as JSON to the default topic.
+    /// Results whose topic cannot be rendered, or renders to an invalid publish
+    /// topic (empty or containing `+`/`#` wildcards), are skipped.
+    pub(crate) fn render_publications(
+        engine: &TemplateEngine,
+        config: &MqttReactionConfig,
+        query_result: &QueryResult,
+        reaction_id: &str,
+    ) -> Vec<Publication> {
+        let query_name = &query_result.query_id;
+        let timestamp = query_result.timestamp.timestamp_millis();
+        let query_config = Self::query_config(config, query_name);
+        let mut publications = Vec::new();
+
+        for result in &query_result.results {
+            let (spec, operation) = match result {
+                ResultDiff::Add { .. } => (query_config.and_then(|qc| qc.added.as_ref()), "ADD"),
+                ResultDiff::Update { .. } => {
+                    (query_config.and_then(|qc| qc.updated.as_ref()), "UPDATE")
+                }
+                ResultDiff::Delete { .. } => {
+                    (query_config.and_then(|qc| qc.deleted.as_ref()), "DELETE")
+                }
+                ResultDiff::Aggregation { .. } => (
+                    query_config.and_then(|qc| qc.updated.as_ref()),
+                    "AGGREGATION",
+                ),
+                ResultDiff::Noop => continue,
+            };
+            let Some(Value::Object(context)) = change_context(query_result, result) else {
+                continue;
+            };
+
+            let topic_template = spec
+                .and_then(|s| s.extension.topic.as_deref())
+                .unwrap_or(&config.default_topic);
+            let topic = match engine.render(topic_template, &context) {
+                Ok(topic) => topic,
+                Err(e) => {
+                    error!(
+                        "[{reaction_id}] Failed to render topic '{topic_template}' for query '{query_name}': {e}"
+                    );
+                    continue;
+                }
+            };
+            if topic.is_empty() || topic.contains('+') || topic.contains('#') {
+                error!(
+                    "[{reaction_id}] Rendered topic '{topic}' for query '{query_name}' is not a valid publish topic"
+                );
+                continue;
+            }
+
+            let default_payload = || {
+                json!({
+                    "queryId": query_name,
+                    "operation": operation,
+                    "result": result,
+                    "timestamp": timestamp
+                })
+                .to_string()
+            };
+            let payload = match spec.map(|s| s.template.as_str()) {
+                Some(template) if !template.is_empty() => match engine.render(template, &context) {
+                    Ok(rendered) => rendered,
+                    Err(e) => {
+                        error!(
+                                "[{reaction_id}] Failed to render payload for query '{query_name}': {e}. Falling back to default format."
+                            );
+                        default_payload()
+                    }
+                },
+                _ => default_payload(),
+            };
+
+            publications.push(Publication {
+                topic,
+                payload,
+                qos: spec.and_then(|s| s.extension.qos).unwrap_or(config.qos),
+                retain: spec
+                    .and_then(|s| s.extension.retain)
+                    .unwrap_or(config.retain),
+            });
+        }
+
+        publications
+    }
+}
+
+#[async_trait]
+impl Reaction for MqttReaction {
+    fn id(&self) -> &str {
+        &self.base.id
+    }
+
+    fn type_name(&self) -> &str {
+        "mqtt"
+    }
+
+    fn properties(&self) -> HashMap<String, serde_json::Value> {
+        let mut config = self.config.clone();
+        if config.password.is_some() {
+            config.password = Some("***".to_string());
+        }
+        match serde_json::to_value(&config) {
+            Ok(serde_json::Value::Object(map)) => map.into_iter().collect(),
+            _ => HashMap::new(),
+        }
+    }
+
+    fn query_ids(&self) -> Vec<String> {
+        self.base.queries.clone()
+    }
+
+    fn auto_start(&self) -> bool {
+        self.base.get_auto_start()
+    }
+
+    async fn initialize(&self, context: drasi_lib::context::ReactionRuntimeContext) {
+        self.base.initialize(context).await;
+    }
+
+    async fn start(&self) -> Result<()> {
+        log_component_start("MQTT Reaction", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Starting,
+                Some("Starting MQTT reaction".to_string()),
+            )
+            .await;
+
+        let options = Self::mqtt_options(&self.base.id, &self.config);
+        let (client, mut event_loop) = AsyncClient::new(options, CLIENT_CHANNEL_CAPACITY);
+
+        // rumqttc only makes progress (connect, publish, acks, reconnect) while the
+        // event loop is polled, so it runs in its own task for the reaction lifetime.
+        let reaction_id = self.base.id.clone();
+        let event_loop_task = tokio::spawn(async move {
+            loop {
+                if let Err(e) = event_loop.poll().await {
+                    warn!("[{reaction_id}] MQTT connection error: {e}. Retrying in 1s");
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                }
+            }
+        });
+        *self.event_loop_handle.write().await = Some(event_loop_task);
+        *self.client.write().await = Some(client.clone());
+
+        self.base
+            .set_status(
+                ComponentStatus::Running,
+                Some("MQTT reaction started".to_string()),
+            )
+            .await;
+
+        let priority_queue = self.base.priority_queue.clone();
+        let reaction_id = self.base.id.clone();
+        let config = self.config.clone();
+        let mut shutdown_rx = self.base.create_shutdown_channel().await;
+
+        let processing_task = tokio::spawn(async move {
+            info!("[{reaction_id}] MQTT result processing task started");
+
+            let engine = TemplateEngine::new();
+
+            loop {
+                let query_result = tokio::select! {
+                    biased;
+
+                    _ = &mut shutdown_rx => {
+                        debug!("[{reaction_id}] Received shutdown signal, exiting processing loop");
+                        break;
+                    }
+
+                    result = priority_queue.dequeue() => result,
+                };
+
+                if query_result.results.is_empty() {
+                    debug!("[{reaction_id}] Received empty result set from query");
+                    continue;
+                }
+
+                let publications = MqttReaction::render_publications(
+                    &engine,
+                    &config,
+                    &query_result,
+                    &reaction_id,
+                );
+
+                for publication in publications {
+                    debug!(
+                        "[{reaction_id}] Publishing to '{}' (qos={:?}, retain={})",
+                        publication.topic, publication.qos, publication.retain
+                    );
+                    if let Err(e) = client
+                        .publish(
+                            publication.topic.clone(),
+                            publication.qos.into(),
+                            publication.retain,
+                            publication.payload.into_bytes(),
+                        )
+                        .await
+                    {
+                        error!(
+                            "[{reaction_id}] Failed to publish to '{}': {e}",
+                            publication.topic
+                        );
+                    }
+                }
+            }
+
+            info!("[{reaction_id}] MQTT result processing task ended");
+        });
+
+        self.base.set_processing_task(processing_task).await;
+
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        self.base.stop_common().await?;
+
+        if let Some(client) = self.client.write().await.take() {
+            if let Err(e) = client.disconnect().await {
+                debug!("[{}] MQTT disconnect failed: {e}", self.base.id);
+            }
+        }
+
+        if let Some(handle) = self.event_loop_handle.write().await.take() {
+            handle.abort();
+        }
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopped,
+                Some("MQTT reaction stopped".to_string()),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn status(&self) -> ComponentStatus {
+        self.base.get_status().await
+    }
+
+    async fn enqueue_query_result(&self, result: QueryResult) -> anyhow::Result<()> {
+        self.base.enqueue_query_result(result).await
+    }
+}
@@ -0,0 +1,290 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::descriptor::MqttReactionDescriptor;
+use crate::mqtt::Publication;
+use drasi_lib::channels::{QueryResult, ResultDiff};
+use drasi_lib::templating::TemplateEngine;
+use drasi_lib::Reaction;
+use drasi_plugin_sdk::prelude::ReactionPluginDescriptor;
+use serde_json::json;
+use std::collections::HashMap;
+
+fn query_result(query_id: &str, results: Vec<ResultDiff>) -> QueryResult {
+    QueryResult::new(
+        query_id.to_string(),
+        chrono::Utc::now(),
+        results,
+        HashMap::new(),
+    )
+}
+
+fn render(config: &MqttReactionConfig, result: &QueryResult) -> Vec<Publication> {
+    MqttReaction::render_publications(&TemplateEngine::new(), config, result, "test-reaction")
+}
+
+#[test]
+fn test_mqtt_builder_defaults() {
+    let reaction = MqttReactionBuilder::new("test-reaction").build().unwrap();
+    assert_eq!(reaction.id(), "test-reaction");
+    assert_eq!(reaction.type_name(), "mqtt");
+    let props = reaction.properties();
+    assert_eq!(props.get("broker_host"), Some(&json!("localhost")));
+    assert_eq!(props.get("broker_port"), Some(&json!(1883)));
+    assert_eq!(props.get("qos"), Some(&json!("at_least_once")));
+}
+
+#[test]
+fn test_mqtt_properties_redact_password() {
+    let reaction = MqttReaction::builder("test-reaction")
+        .with_credentials("user", "secret")
+        .build()
+        .unwrap();
+    let props = reaction.properties();
+    assert_eq!(props.get("username"), Some(&json!("user")));
+    assert_eq!(props.get("password"), Some(&json!("***")));
+}
+
+#[test]
+fn test_mqtt_builder_invalid_template_fails() {
+    let result = MqttReaction::builder("test-reaction")
+        .with_query("query1")
+        .with_route(
+            "query1",
+            QueryConfig {
+                added: Some(TemplateSpec::with_extension(
+                    "{{after.id}}",
+                    MqttExtension {
+                        topic: Some("alerts/{{#if}}".to_string()),
+                        ..Default::default()
+                    },
+                )),
+                updated: None,
+                deleted: None,
+            },
+        )
+        .build();
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_mqtt_builder_empty_default_topic_fails() {
+    let result = MqttReaction::builder("test-reaction")
+        .with_default_topic("")
+        .build();
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_mqtt_builder_route_validation() {
+    let route = QueryConfig {
+        added: Some(TemplateSpec::default()),
+        updated: None,
+        deleted: None,
+    };
+
+    let ok = MqttReaction::builder("test-reaction")
+        .with_query("source.query1")
+        .with_route("query1", route.clone())
+        .build();
+    assert!(ok.is_ok());
+
+    let err = MqttReaction::builder("test-reaction")
+        .with_query("query1")
+        .with_route("other", route)
+        .build();
+    assert!(err.is_err());
+}
+
+#[test]
+fn test_render_default_payload_and_topic() {
+    let config = MqttReactionConfig::default();
+    let result = query_result(
+        "sensors",
+        vec![
+            ResultDiff::Add {
+                data: json!({"id": "1"}),
+            },
+            ResultDiff::Noop,
+        ],
+    );
+
+    let publications = render(&config, &result);
+    assert_eq!(publications.len(), 1);
+    assert_eq!(publications[0].topic, "drasi/sensors");
+    assert_eq!(publications[0].qos, MqttQos::AtLeastOnce);
+    assert!(!publications[0].retain);
+
+    let payload: serde_json::Value = serde_json::from_str(&publications[0].payload).unwrap();
+    assert_eq!(payload["queryId"], "sensors");
+    assert_eq!(payload["operation"], "ADD");
+    assert_eq!(payload["result"]["data"]["id"], "1");
+}
+
+#[test]
+fn test_render_route_topic_payload_and_overrides() {
+    let mut config = MqttReactionConfig {
+        qos: MqttQos::AtMostOnce,
+        ..Default::default()
+    };
+    config.routes.insert(
+        "sensors".to_string(),
+        QueryConfig {
+            added: None,
+            updated: Some(TemplateSpec::with_extension(
+                "{{before.temp}}->{{after.temp}}",
+                MqttExtension {
+                    topic: Some("alerts/{{query_name}}/{{after.id}}".to_string()),
+                    qos: Some(MqttQos::ExactlyOnce),
+                    retain: Some(true),
+                },
+            )),
+            deleted: None,
+        },
+    );
+
+    let result = query_result(
+        "source.sensors",
+        vec![ResultDiff::Update {
+            data: json!({"id": "7", "temp": 30}),
+            before: json!({"id": "7", "temp": 20}),
+            after: json!({"id": "7", "temp": 30}),
+            grouping_keys: None,
+        }],
+    );
+
+    let publications = render(&config, &result);
+    assert_eq!(
+        publications,
+        vec![Publication {
+            topic: "alerts/source.sensors/7".to_string(),
+            payload: "20->30".to_string(),
+            qos: MqttQos::ExactlyOnce,
+            retain: true,
+        }]
+    );
+
+    // Operations without a template use the reaction defaults
+    let result = query_result(
+        "sensors",
+        vec![ResultDiff::Delete {
+            data: json!({"id": "7"}),
+        }],
+    );
+    let publications = render(&config, &result);
+    assert_eq!(publications.len(), 1);
+    assert_eq!(publications[0].topic, "drasi/sensors");
+    assert_eq!(publications[0].qos, MqttQos::AtMostOnce);
+}
+
+#[test]
+fn test_render_with_shared_template_helpers() {
+    let mut config = MqttReactionConfig::default();
+    config.routes.insert(
+        "sensors".to_string(),
+        QueryConfig {
+            added: Some(TemplateSpec::with_extension(
+                "{{#if (gt after.temp 25)}}HOT {{/if}}{{format_number after.temp 1}}",
+                MqttExtension {
+                    topic: Some("sensors/{{lower after.room}}".to_string()),
+                    ..Default::default()
+                },
+            )),
+            updated: None,
+            deleted: None,
+        },
+    );
+
+    let result = query_result(
+        "sensors",
+        vec![ResultDiff::Add {
+            data: json!({"room": "Lab", "temp": 30}),
+        }],
+    );
+    let publications = render(&config, &result);
+    assert_eq!(publications.len(), 1);
+    assert_eq!(publications[0].topic, "sensors/lab");
+    assert_eq!(publications[0].payload, "HOT 30.0");
+}
+
+#[test]
+fn test_render_skips_wildcard_topics() {
+    let config = MqttReactionConfig {
+        default_topic: "alerts/{{after.id}}".to_string(),
+        ..Default::default()
+    };
+    let result = query_result(
+        "sensors",
+        vec![
+            ResultDiff::Add {
+                data: json!({"id": "#"}),
+            },
+            ResultDiff::Add {
+                data: json!({"id": "ok"}),
+            },
+        ],
+    );
+
+    let publications = render(&config, &result);
+    assert_eq!(publications.len(), 1);
+    assert_eq!(publications[0].topic, "alerts/ok");
+}
+
+#[test]
+fn test_config_deserialization_defaults() {
+    let config: MqttReactionConfig = serde_json::from_value(json!({
+        "broker_host": "broker.local",
+        "qos": "exactly_once"
+    }))
+    .unwrap();
+    assert_eq!(config.broker_host, "broker.local");
+    assert_eq!(config.broker_port, 1883);
+    assert_eq!(config.qos, MqttQos::ExactlyOnce);
+    assert_eq!(config.default_topic, "drasi/{{query_name}}");
+    assert!(config.routes.is_empty());
+}
+
+#[tokio::test]
+async fn test_descriptor_creates_reaction() {
+    let descriptor = MqttReactionDescriptor;
+    assert_eq!(descriptor.kind(), "mqtt");
+
+    let config = json!({
+        "brokerHost": "broker.local",
+        "brokerPort": 8883,
+        "username": "user",
+        "password": "secret",
+        "qos": "at_most_once",
+        "retain": true,
+        "routes": {
+            "query1": {
+                "added": { "template": "{{json after}}", "topic": "alerts/{{after.id}}" }
+            }
+        }
+    });
+
+    let reaction = descriptor
+        .create_reaction("mqtt-1", vec!["query1".to_string()], &config, false)
+        .await
+        .unwrap();
+
+    assert_eq!(reaction.id(), "mqtt-1");
+    assert!(!reaction.auto_start());
+    let props = reaction.properties();
+    assert_eq!(props.get("broker_port"), Some(&json!(8883)));
+    assert_eq!(props.get("qos"), Some(&json!("at_most_once")));
+    assert_eq!(props.get("retain"), Some(&json!(true)));
+    assert_eq!(props.get("password"), Some(&json!("***")));
+}
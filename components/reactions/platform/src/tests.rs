@@ -484,6 +484,8 @@ mod profiling_metadata_tests {
             query_send_ns: Some(1744055178510900000),
             reaction_receive_ns: Some(1744055178510950000),
             reaction_complete_ns: None,
+            change_id: None,
+            span: None,
         };
 
         let query_result = QueryResult {
@@ -545,6 +545,8 @@ mod tests {
             query_send_ns: Some(7000),
             reaction_receive_ns: Some(8000),
             reaction_complete_ns: Some(9000),
+            change_id: None,
+            span: None,
         };
 
         let result = build_tracking_metadata(&profiling, 42);
@@ -36,6 +36,8 @@ fn create_profiling_metadata(
         query_send_ns,
         reaction_receive_ns,
         reaction_complete_ns,
+        change_id: None,
+        span: None,
     }
 }
 
@@ -0,0 +1,181 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration types for the Prometheus metrics reaction.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+fn default_host() -> String {
+    "0.0.0.0".to_string()
+}
+
+fn default_port() -> u16 {
+    9464
+}
+
+fn default_path() -> String {
+    "/metrics".to_string()
+}
+
+fn default_operations() -> Vec<MetricOperation> {
+    vec![MetricOperation::Add]
+}
+
+/// Prometheus metric type.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum MetricType {
+    /// One series per distinct label set, set to the value of the current row (default).
+    /// Series are removed when their row is deleted.
+    #[default]
+    Gauge,
+    /// One series per distinct label set, incremented for every matching change.
+    Counter,
+}
+
+/// Result operation that increments a counter.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MetricOperation {
+    Add,
+    Update,
+    Delete,
+}
+
+/// A metric derived from query results.
+///
+/// `value` and the `labels` values are dotted paths into the result row,
+/// e.g. `val` or `price.bid`. For deleted rows the row before the change is
+/// used, otherwise the row after it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MetricConfig {
+    /// Metric name, e.g. `stock_price`
+    pub name: String,
+
+    /// Help text of the metric
+    #[serde(default)]
+    pub help: String,
+
+    /// Metric type
+    #[serde(rename = "type", default)]
+    pub metric_type: MetricType,
+
+    /// Query whose results feed the metric (exact match or dotted suffix).
+    /// If not set, results of all subscribed queries are used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+
+    /// Path of the numeric value. Required for gauges. For counters the value
+    /// is added to the counter; without it the counter is incremented by 1.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+
+    /// Label names mapped to paths of the label values
+    #[serde(default)]
+    pub labels: BTreeMap<String, String>,
+
+    /// Operations that increment a counter. Ignored for gauges.
+    #[serde(default = "default_operations")]
+    pub operations: Vec<MetricOperation>,
+}
+
+impl MetricConfig {
+    /// Create a gauge set to the value at `value_path`
+    pub fn gauge(name: impl Into<String>, value_path: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            help: String::new(),
+            metric_type: MetricType::Gauge,
+            query: None,
+            value: Some(value_path.into()),
+            labels: BTreeMap::new(),
+            operations: default_operations(),
+        }
+    }
+
+    /// Create a counter incremented by 1 for every added row
+    pub fn counter(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            help: String::new(),
+            metric_type: MetricType::Counter,
+            query: None,
+            value: None,
+            labels: BTreeMap::new(),
+            operations: default_operations(),
+        }
+    }
+
+    /// Set the help text
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help = help.into();
+        self
+    }
+
+    /// Restrict the metric to results of one query
+    pub fn with_query(mut self, query_id: impl Into<String>) -> Self {
+        self.query = Some(query_id.into());
+        self
+    }
+
+    /// Set the path of the numeric value
+    pub fn with_value(mut self, value_path: impl Into<String>) -> Self {
+        self.value = Some(value_path.into());
+        self
+    }
+
+    /// Add a label whose value is read from `value_path`
+    pub fn with_label(mut self, name: impl Into<String>, value_path: impl Into<String>) -> Self {
+        self.labels.insert(name.into(), value_path.into());
+        self
+    }
+
+    /// Set the operations that increment a counter
+    pub fn with_operations(mut self, operations: Vec<MetricOperation>) -> Self {
+        self.operations = operations;
+        self
+    }
+}
+
+/// Prometheus metrics reaction configuration
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PrometheusReactionConfig {
+    /// Host to bind the metrics server to
+    #[serde(default = "default_host")]
+    pub host: String,
+
+    /// Port to bind the metrics server to
+    #[serde(default = "default_port")]
+    pub port: u16,
+
+    /// HTTP path of the metrics endpoint
+    #[serde(default = "default_path")]
+    pub path: String,
+
+    /// Metrics derived from query results
+    #[serde(default)]
+    pub metrics: Vec<MetricConfig>,
+}
+
+impl Default for PrometheusReactionConfig {
+    fn default() -> Self {
+        Self {
+            host: default_host(),
+            port: default_port(),
+            path: default_path(),
+            metrics: Vec::new(),
+        }
+    }
+}
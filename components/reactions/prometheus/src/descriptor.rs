@@ -0,0 +1,202 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Descriptor for the Prometheus metrics reaction plugin.
+
+use drasi_lib::reactions::Reaction;
+use drasi_plugin_sdk::prelude::*;
+use std::collections::BTreeMap;
+use utoipa::OpenApi;
+
+use crate::config::{MetricConfig, MetricOperation, MetricType};
+use crate::PrometheusReactionBuilder;
+
+/// DTO for the Prometheus metric type.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::prometheus::MetricType)]
+#[serde(rename_all = "snake_case")]
+pub enum MetricTypeDto {
+    Gauge,
+    Counter,
+}
+
+impl From<MetricTypeDto> for MetricType {
+    fn from(dto: MetricTypeDto) -> Self {
+        match dto {
+            MetricTypeDto::Gauge => MetricType::Gauge,
+            MetricTypeDto::Counter => MetricType::Counter,
+        }
+    }
+}
+
+/// DTO for a result operation that increments a counter.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::prometheus::MetricOperation)]
+#[serde(rename_all = "snake_case")]
+pub enum MetricOperationDto {
+    Add,
+    Update,
+    Delete,
+}
+
+impl From<MetricOperationDto> for MetricOperation {
+    fn from(dto: MetricOperationDto) -> Self {
+        match dto {
+            MetricOperationDto::Add => MetricOperation::Add,
+            MetricOperationDto::Update => MetricOperation::Update,
+            MetricOperationDto::Delete => MetricOperation::Delete,
+        }
+    }
+}
+
+/// DTO for a metric derived from query results.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::prometheus::MetricConfig)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct MetricConfigDto {
+    /// Metric name.
+    pub name: String,
+
+    /// Help text of the metric.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub help: Option<String>,
+
+    /// Metric type.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub metric_type: Option<MetricTypeDto>,
+
+    /// Query whose results feed the metric.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+
+    /// Path of the numeric value in the result row.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+
+    /// Label names mapped to paths of the label values.
+    #[serde(default)]
+    pub labels: BTreeMap<String, String>,
+
+    /// Operations that increment a counter.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operations: Option<Vec<MetricOperationDto>>,
+}
+
+impl From<&MetricConfigDto> for MetricConfig {
+    fn from(dto: &MetricConfigDto) -> Self {
+        let mut metric = match dto.metric_type.map(MetricType::from).unwrap_or_default() {
+            MetricType::Gauge => MetricConfig::gauge(&dto.name, ""),
+            MetricType::Counter => MetricConfig::counter(&dto.name),
+        };
+        metric.help = dto.help.clone().unwrap_or_default();
+        metric.query = dto.query.clone();
+        metric.value = dto.value.clone();
+        metric.labels = dto.labels.clone();
+        if let Some(operations) = &dto.operations {
+            metric.operations = operations.iter().copied().map(Into::into).collect();
+        }
+        metric
+    }
+}
+
+/// Configuration DTO for the Prometheus metrics reaction plugin.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::prometheus::PrometheusReactionConfig)]
+#[serde(rename_all = "camelCase")]
+pub struct PrometheusReactionConfigDto {
+    /// Host to bind the metrics server to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub host: Option<ConfigValue<String>>,
+
+    /// Port to bind the metrics server to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueU16>)]
+    pub port: Option<ConfigValue<u16>>,
+
+    /// HTTP path of the metrics endpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub path: Option<ConfigValue<String>>,
+
+    /// Metrics derived from query results.
+    pub metrics: Vec<MetricConfigDto>,
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(
+    PrometheusReactionConfigDto,
+    MetricConfigDto,
+    MetricTypeDto,
+    MetricOperationDto,
+)))]
+struct PrometheusReactionSchemas;
+
+/// Descriptor for the Prometheus metrics reaction plugin.
+pub struct PrometheusReactionDescriptor;
+
+#[async_trait]
+impl ReactionPluginDescriptor for PrometheusReactionDescriptor {
+    fn kind(&self) -> &str {
+        "prometheus"
+    }
+
+    fn config_version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn config_schema_name(&self) -> &str {
+        "reaction.prometheus.PrometheusReactionConfig"
+    }
+
+    fn config_schema_json(&self) -> String {
+        let api = PrometheusReactionSchemas::openapi();
+        serde_json::to_string(
+            &api.components
+                .as_ref()
+                .expect("OpenAPI components missing")
+                .schemas,
+        )
+        .expect("Failed to serialize config schema")
+    }
+
+    async fn create_reaction(
+        &self,
+        id: &str,
+        query_ids: Vec<String>,
+        config_json: &serde_json::Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn Reaction>> {
+        let dto: PrometheusReactionConfigDto = serde_json::from_value(config_json.clone())?;
+        let mapper = DtoMapper::new();
+
+        let mut builder = PrometheusReactionBuilder::new(id)
+            .with_queries(query_ids)
+            .with_auto_start(auto_start)
+            .with_metrics(dto.metrics.iter().map(MetricConfig::from).collect());
+
+        if let Some(ref host) = dto.host {
+            builder = builder.with_host(mapper.resolve_string(host)?);
+        }
+        if let Some(ref port) = dto.port {
+            builder = builder.with_port(mapper.resolve_typed(port)?);
+        }
+        if let Some(ref path) = dto.path {
+            builder = builder.with_path(mapper.resolve_string(path)?);
+        }
+
+        let reaction = builder.build()?;
+        Ok(Box::new(reaction))
+    }
+}
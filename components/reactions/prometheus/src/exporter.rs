@@ -0,0 +1,311 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use axum::extract::State;
+use axum::http::{header, StatusCode};
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::Router;
+use log::{debug, error, info};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+
+use drasi_lib::channels::ComponentStatus;
+use drasi_lib::managers::log_component_start;
+use drasi_lib::reactions::common::base::{ReactionBase, ReactionBaseParams};
+use drasi_lib::Reaction;
+
+use super::config::{MetricType, PrometheusReactionConfig};
+use super::metrics::{matches_query, MetricSet};
+use super::PrometheusReactionBuilder;
+
+/// Prometheus reaction exposes query result values as metrics on an HTTP endpoint.
+pub struct PrometheusReaction {
+    base: ReactionBase,
+    config: PrometheusReactionConfig,
+    server_task: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl std::fmt::Debug for PrometheusReaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PrometheusReaction")
+            .field("id", &self.base.id)
+            .field("host", &self.config.host)
+            .field("port", &self.config.port)
+            .field("path", &self.config.path)
+            .finish()
+    }
+}
+
+/// Serve the current metrics in the Prometheus text format
+async fn metrics_handler(State(metrics): State<Arc<MetricSet>>) -> impl IntoResponse {
+    match metrics.encode() {
+        Ok(body) => (
+            StatusCode::OK,
+            [(header::CONTENT_TYPE, prometheus::TEXT_FORMAT)],
+            body,
+        )
+            .into_response(),
+        Err(e) => {
+            error!("Failed to encode metrics: {e}");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+impl PrometheusReaction {
+    /// Create a builder for PrometheusReaction
+    pub fn builder(id: impl Into<String>) -> PrometheusReactionBuilder {
+        PrometheusReactionBuilder::new(id)
+    }
+
+    /// Create a new Prometheus reaction
+    ///
+    /// The event channel is automatically injected when the reaction is added
+    /// to DrasiLib via `add_reaction()`.
+    ///
+    /// # Errors
+    ///
+    /// - Returns error if no metrics are configured
+    /// - Returns error if a metric or label name is invalid or a metric name is used twice
+    /// - Returns error if a metric's query doesn't match any subscribed query
+    pub fn new(
+        id: impl Into<String>,
+        queries: Vec<String>,
+        config: PrometheusReactionConfig,
+    ) -> anyhow::Result<Self> {
+        Self::validate_config(&queries, &config)?;
+        Ok(Self::from_builder(id.into(), queries, config, None, true))
+    }
+
+    /// Create from builder (internal method)
+    pub(crate) fn from_builder(
+        id: String,
+        queries: Vec<String>,
+        config: PrometheusReactionConfig,
+        priority_queue_capacity: Option<usize>,
+        auto_start: bool,
+    ) -> Self {
+        let mut params = ReactionBaseParams::new(id, queries).with_auto_start(auto_start);
+        if let Some(capacity) = priority_queue_capacity {
+            params = params.with_priority_queue_capacity(capacity);
+        }
+
+        Self {
+            base: ReactionBase::new(params),
+            config,
+            server_task: Mutex::new(None),
+        }
+    }
+
+    /// Validate configuration: endpoint, metric definitions and metric-query matching
+    pub(crate) fn validate_config(
+        queries: &[String],
+        config: &PrometheusReactionConfig,
+    ) -> anyhow::Result<()> {
+        if !config.path.starts_with('/') || config.path.contains([':', '*', '{', '}']) {
+            return Err(anyhow::anyhow!(
+                "Validation error: path must be a literal path starting with '/', got '{}'",
+                config.path
+            ));
+        }
+        if config.metrics.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Validation error: at least one metric is required"
+            ));
+        }
+
+        for metric in &config.metrics {
+            match metric.metric_type {
+                MetricType::Gauge if metric.value.as_deref().unwrap_or_default().is_empty() => {
+                    return Err(anyhow::anyhow!(
+                        "Validation error: gauge '{}' requires a value path",
+                        metric.name
+                    ));
+                }
+                MetricType::Counter if metric.operations.is_empty() => {
+                    return Err(anyhow::anyhow!(
+                        "Validation error: counter '{}' requires at least one operation",
+                        metric.name
+                    ));
+                }
+                _ => {}
+            }
+
+            if let Some(query) = &metric.query {
+                if !queries.is_empty() && !queries.iter().any(|q| matches_query(Some(query), q)) {
+                    return Err(anyhow::anyhow!(
+                        "Metric '{}' query '{query}' does not match any subscribed query. Subscribed queries: {queries:?}",
+                        metric.name
+                    ));
+                }
+            }
+        }
+
+        MetricSet::new(&config.metrics).map_err(|e| anyhow::anyhow!("Validation error: {e}"))?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Reaction for PrometheusReaction {
+    fn id(&self) -> &str {
+        &self.base.id
+    }
+
+    fn type_name(&self) -> &str {
+        "prometheus"
+    }
+
+    fn properties(&self) -> HashMap<String, serde_json::Value> {
+        match serde_json::to_value(&self.config) {
+            Ok(serde_json::Value::Object(map)) => map.into_iter().collect(),
+            _ => HashMap::new(),
+        }
+    }
+
+    fn query_ids(&self) -> Vec<String> {
+        self.base.queries.clone()
+    }
+
+    fn auto_start(&self) -> bool {
+        self.base.get_auto_start()
+    }
+
+    async fn initialize(&self, context: drasi_lib::context::ReactionRuntimeContext) {
+        self.base.initialize(context).await;
+    }
+
+    async fn start(&self) -> Result<()> {
+        log_component_start("Prometheus Reaction", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Starting,
+                Some("Starting Prometheus reaction".to_string()),
+            )
+            .await;
+
+        // Metrics start empty on every start and are rebuilt from the query results
+        let metrics = match MetricSet::new(&self.config.metrics) {
+            Ok(metrics) => Arc::new(metrics),
+            Err(e) => {
+                self.base
+                    .set_status(ComponentStatus::Error, Some(e.to_string()))
+                    .await;
+                return Err(e);
+            }
+        };
+
+        let address = format!("{}:{}", self.config.host, self.config.port);
+        let listener = match tokio::net::TcpListener::bind(&address).await {
+            Ok(listener) => listener,
+            Err(e) => {
+                self.base
+                    .set_status(
+                        ComponentStatus::Error,
+                        Some(format!("Failed to bind metrics server to {address}: {e}")),
+                    )
+                    .await;
+                return Err(anyhow!("Failed to bind metrics server to {address}: {e}"));
+            }
+        };
+
+        let app = Router::new()
+            .route(&self.config.path, get(metrics_handler))
+            .with_state(metrics.clone());
+        let reaction_id = self.base.id.clone();
+        info!(
+            "[{reaction_id}] Serving Prometheus metrics on http://{address}{}",
+            self.config.path
+        );
+        let server_task = tokio::spawn(async move {
+            if let Err(e) = axum::serve(listener, app).await {
+                error!("[{reaction_id}] Metrics server error: {e}");
+            }
+        });
+        *self.server_task.lock().await = Some(server_task);
+
+        self.base
+            .set_status(
+                ComponentStatus::Running,
+                Some("Prometheus reaction started".to_string()),
+            )
+            .await;
+
+        let priority_queue = self.base.priority_queue.clone();
+        let reaction_id = self.base.id.clone();
+        let mut shutdown_rx = self.base.create_shutdown_channel().await;
+
+        let processing_task = tokio::spawn(async move {
+            info!("[{reaction_id}] Prometheus processing task started");
+
+            loop {
+                tokio::select! {
+                    biased;
+
+                    _ = &mut shutdown_rx => {
+                        debug!("[{reaction_id}] Received shutdown signal, exiting processing loop");
+                        break;
+                    }
+
+                    query_result = priority_queue.dequeue() => {
+                        for diff in &query_result.results {
+                            metrics.apply(&query_result.query_id, diff);
+                        }
+                    }
+                }
+            }
+
+            info!("[{reaction_id}] Prometheus processing task ended");
+        });
+
+        self.base.set_processing_task(processing_task).await;
+
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        self.base.stop_common().await?;
+
+        if let Some(server_task) = self.server_task.lock().await.take() {
+            server_task.abort();
+        }
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopped,
+                Some("Prometheus reaction stopped".to_string()),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn status(&self) -> ComponentStatus {
+        self.base.get_status().await
+    }
+
+    async fn enqueue_query_result(
+        &self,
+        result: drasi_lib::channels::QueryResult,
+    ) -> anyhow::Result<()> {
+        self.base.enqueue_query_result(result).await
+    }
+}
@@ -0,0 +1,154 @@
+#![allow(unexpected_cfgs)]
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Prometheus metrics reaction plugin for Drasi
+//!
+//! This plugin exposes values of continuous query results as Prometheus
+//! gauges and counters on an HTTP `/metrics` endpoint, so query outputs can
+//! drive dashboards and alerting in existing monitoring stacks.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use drasi_reaction_prometheus::{MetricConfig, PrometheusReaction};
+//!
+//! let reaction = PrometheusReaction::builder("my-metrics-reaction")
+//!     .with_query("stock-prices")
+//!     .with_port(9464)
+//!     .with_metric(
+//!         MetricConfig::gauge("stock_price", "val")
+//!             .with_help("Latest stock price")
+//!             .with_label("symbol", "symbol"),
+//!     )
+//!     .build()?;
+//! ```
+
+pub mod config;
+pub mod descriptor;
+pub mod exporter;
+mod metrics;
+
+pub use config::{MetricConfig, MetricOperation, MetricType, PrometheusReactionConfig};
+pub use exporter::PrometheusReaction;
+
+/// Builder for Prometheus metrics reaction
+pub struct PrometheusReactionBuilder {
+    id: String,
+    queries: Vec<String>,
+    config: PrometheusReactionConfig,
+    priority_queue_capacity: Option<usize>,
+    auto_start: bool,
+}
+
+impl PrometheusReactionBuilder {
+    /// Create a new Prometheus reaction builder with the given ID
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            queries: Vec::new(),
+            config: PrometheusReactionConfig::default(),
+            priority_queue_capacity: None,
+            auto_start: true,
+        }
+    }
+
+    /// Set the query IDs to subscribe to
+    pub fn with_queries(mut self, queries: Vec<String>) -> Self {
+        self.queries = queries;
+        self
+    }
+
+    /// Add a query ID to subscribe to
+    pub fn with_query(mut self, query_id: impl Into<String>) -> Self {
+        self.queries.push(query_id.into());
+        self
+    }
+
+    /// Set the host to bind the metrics server to
+    pub fn with_host(mut self, host: impl Into<String>) -> Self {
+        self.config.host = host.into();
+        self
+    }
+
+    /// Set the port to bind the metrics server to
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.config.port = port;
+        self
+    }
+
+    /// Set the HTTP path of the metrics endpoint
+    pub fn with_path(mut self, path: impl Into<String>) -> Self {
+        self.config.path = path.into();
+        self
+    }
+
+    /// Add a metric
+    pub fn with_metric(mut self, metric: MetricConfig) -> Self {
+        self.config.metrics.push(metric);
+        self
+    }
+
+    /// Set all metrics
+    pub fn with_metrics(mut self, metrics: Vec<MetricConfig>) -> Self {
+        self.config.metrics = metrics;
+        self
+    }
+
+    /// Set the priority queue capacity
+    pub fn with_priority_queue_capacity(mut self, capacity: usize) -> Self {
+        self.priority_queue_capacity = Some(capacity);
+        self
+    }
+
+    /// Set whether the reaction should auto-start
+    pub fn with_auto_start(mut self, auto_start: bool) -> Self {
+        self.auto_start = auto_start;
+        self
+    }
+
+    /// Set the full configuration at once
+    pub fn with_config(mut self, config: PrometheusReactionConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Build the Prometheus reaction
+    pub fn build(self) -> anyhow::Result<PrometheusReaction> {
+        PrometheusReaction::validate_config(&self.queries, &self.config)?;
+
+        Ok(PrometheusReaction::from_builder(
+            self.id,
+            self.queries,
+            self.config,
+            self.priority_queue_capacity,
+            self.auto_start,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests;
+
+/// Dynamic plugin entry point.
+#[cfg(feature = "dynamic-plugin")]
+drasi_plugin_sdk::export_plugin!(
+    plugin_id = "prometheus-reaction",
+    core_version = env!("CARGO_PKG_VERSION"),
+    lib_version = env!("CARGO_PKG_VERSION"),
+    plugin_version = env!("CARGO_PKG_VERSION"),
+    source_descriptors = [],
+    reaction_descriptors = [descriptor::PrometheusReactionDescriptor],
+    bootstrap_descriptors = [],
+);
@@ -0,0 +1,351 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Mapping of query result changes to Prometheus metrics.
+
+use anyhow::Result;
+use log::debug;
+use prometheus::{CounterVec, Encoder, GaugeVec, Opts, Registry, TextEncoder};
+use serde_json::Value;
+
+use drasi_lib::channels::ResultDiff;
+
+use crate::config::{MetricConfig, MetricOperation, MetricType};
+
+enum MetricVec {
+    Gauge(GaugeVec),
+    Counter(CounterVec),
+}
+
+struct Metric {
+    config: MetricConfig,
+    vec: MetricVec,
+}
+
+/// Registry of the metrics configured for one reaction.
+pub(crate) struct MetricSet {
+    registry: Registry,
+    metrics: Vec<Metric>,
+}
+
+/// Whether a metric's `query` selects results of `query_id`.
+pub(crate) fn matches_query(metric_query: Option<&str>, query_id: &str) -> bool {
+    match metric_query {
+        None => true,
+        Some(query) => query == query_id || query_id.ends_with(&format!(".{query}")),
+    }
+}
+
+/// Look up a dotted path in a row.
+fn lookup<'a>(row: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.').try_fold(row, |value, segment| match value {
+        Value::Object(map) => map.get(segment),
+        Value::Array(items) => segment.parse::<usize>().ok().and_then(|i| items.get(i)),
+        _ => None,
+    })
+}
+
+/// Read a numeric value; numeric strings and booleans are accepted.
+pub(crate) fn numeric_value(row: &Value, path: &str) -> Option<f64> {
+    match lookup(row, path)? {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse().ok(),
+        Value::Bool(b) => Some(if *b { 1.0 } else { 0.0 }),
+        _ => None,
+    }
+}
+
+/// Read a label value. Missing values and nulls become an empty label.
+pub(crate) fn label_value(row: &Value, path: &str) -> String {
+    match lookup(row, path) {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(s)) => s.clone(),
+        Some(other) => other.to_string(),
+    }
+}
+
+impl Metric {
+    fn label_values(&self, row: &Value) -> Vec<String> {
+        self.config
+            .labels
+            .values()
+            .map(|path| label_value(row, path))
+            .collect()
+    }
+
+    fn apply(&self, diff: &ResultDiff) {
+        let (operation, before, after) = match diff {
+            ResultDiff::Add { data } => (MetricOperation::Add, None, Some(data)),
+            ResultDiff::Update { before, after, .. } => {
+                (MetricOperation::Update, Some(before), Some(after))
+            }
+            ResultDiff::Aggregation { before, after } => {
+                (MetricOperation::Update, before.as_ref(), Some(after))
+            }
+            ResultDiff::Delete { data } => (MetricOperation::Delete, Some(data), None),
+            ResultDiff::Noop => return,
+        };
+
+        match &self.vec {
+            MetricVec::Gauge(gauge) => {
+                let new_labels = after.map(|row| self.label_values(row));
+                if let Some(before) = before {
+                    let old_labels = self.label_values(before);
+                    if new_labels.as_ref() != Some(&old_labels) {
+                        let old_labels: Vec<&str> = old_labels.iter().map(String::as_str).collect();
+                        let _ = gauge.remove_label_values(&old_labels);
+                    }
+                }
+                if let (Some(row), Some(labels)) = (after, new_labels) {
+                    let path = self.config.value.as_deref().unwrap_or_default();
+                    match numeric_value(row, path) {
+                        Some(value) => {
+                            let labels: Vec<&str> = labels.iter().map(String::as_str).collect();
+                            gauge.with_label_values(&labels).set(value);
+                        }
+                        None => debug!(
+                            "No numeric value at '{path}' for metric '{}'",
+                            self.config.name
+                        ),
+                    }
+                }
+            }
+            MetricVec::Counter(counter) => {
+                if !self.config.operations.contains(&operation) {
+                    return;
+                }
+                let Some(row) = after.or(before) else {
+                    return;
+                };
+                let increment = match &self.config.value {
+                    Some(path) => match numeric_value(row, path) {
+                        Some(value) if value >= 0.0 => value,
+                        _ => {
+                            debug!(
+                                "No non-negative value at '{path}' for counter '{}'",
+                                self.config.name
+                            );
+                            return;
+                        }
+                    },
+                    None => 1.0,
+                };
+                let labels = self.label_values(row);
+                let labels: Vec<&str> = labels.iter().map(String::as_str).collect();
+                counter.with_label_values(&labels).inc_by(increment);
+            }
+        }
+    }
+}
+
+impl MetricSet {
+    /// Create and register the configured metrics.
+    ///
+    /// Fails for invalid metric or label names and duplicate metric names.
+    pub(crate) fn new(configs: &[MetricConfig]) -> Result<Self> {
+        let registry = Registry::new();
+        let mut metrics = Vec::with_capacity(configs.len());
+
+        for config in configs {
+            // Prometheus requires a help text
+            let help = if config.help.is_empty() {
+                config.name.clone()
+            } else {
+                config.help.clone()
+            };
+            let opts = Opts::new(config.name.clone(), help);
+            let label_names: Vec<&str> = config.labels.keys().map(String::as_str).collect();
+
+            let vec = match config.metric_type {
+                MetricType::Gauge => {
+                    let gauge = GaugeVec::new(opts, &label_names)
+                        .map_err(|e| anyhow::anyhow!("Invalid metric '{}': {e}", config.name))?;
+                    registry
+                        .register(Box::new(gauge.clone()))
+                        .map_err(|e| anyhow::anyhow!("Invalid metric '{}': {e}", config.name))?;
+                    MetricVec::Gauge(gauge)
+                }
+                MetricType::Counter => {
+                    let counter = CounterVec::new(opts, &label_names)
+                        .map_err(|e| anyhow::anyhow!("Invalid metric '{}': {e}", config.name))?;
+                    registry
+                        .register(Box::new(counter.clone()))
+                        .map_err(|e| anyhow::anyhow!("Invalid metric '{}': {e}", config.name))?;
+                    MetricVec::Counter(counter)
+                }
+            };
+
+            metrics.push(Metric {
+                config: config.clone(),
+                vec,
+            });
+        }
+
+        Ok(Self { registry, metrics })
+    }
+
+    /// Update all metrics selecting `query_id` with one result change.
+    pub(crate) fn apply(&self, query_id: &str, diff: &ResultDiff) {
+        for metric in &self.metrics {
+            if matches_query(metric.config.query.as_deref(), query_id) {
+                metric.apply(diff);
+            }
+        }
+    }
+
+    /// Encode all metrics in the Prometheus text exposition format.
+    pub(crate) fn encode(&self) -> Result<String> {
+        let mut buffer = Vec::new();
+        TextEncoder::new().encode(&self.registry.gather(), &mut buffer)?;
+        Ok(String::from_utf8(buffer)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn stock_metrics() -> MetricSet {
+        MetricSet::new(&[
+            MetricConfig::gauge("stock_price", "val")
+                .with_help("Latest stock price")
+                .with_label("symbol", "symbol"),
+            MetricConfig::counter("stock_updates_total")
+                .with_label("symbol", "symbol")
+                .with_operations(vec![MetricOperation::Add, MetricOperation::Update]),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_lookup_values() {
+        let row = json!({ "a": { "b": "2.5" }, "list": [1, true], "n": null });
+        assert_eq!(numeric_value(&row, "a.b"), Some(2.5));
+        assert_eq!(numeric_value(&row, "list.0"), Some(1.0));
+        assert_eq!(numeric_value(&row, "list.1"), Some(1.0));
+        assert_eq!(numeric_value(&row, "missing"), None);
+        assert_eq!(label_value(&row, "list.0"), "1");
+        assert_eq!(label_value(&row, "n"), "");
+        assert_eq!(label_value(&row, "a.b"), "2.5");
+    }
+
+    #[test]
+    fn test_gauge_follows_rows() {
+        let metrics = stock_metrics();
+        metrics.apply(
+            "stocks",
+            &ResultDiff::Add {
+                data: json!({ "symbol": "MSFT", "val": 410.5 }),
+            },
+        );
+        metrics.apply(
+            "stocks",
+            &ResultDiff::Add {
+                data: json!({ "symbol": "AAPL", "val": 190 }),
+            },
+        );
+        metrics.apply(
+            "stocks",
+            &ResultDiff::Update {
+                data: json!({}),
+                before: json!({ "symbol": "MSFT", "val": 410.5 }),
+                after: json!({ "symbol": "MSFT", "val": 412 }),
+                grouping_keys: None,
+            },
+        );
+        metrics.apply(
+            "stocks",
+            &ResultDiff::Delete {
+                data: json!({ "symbol": "AAPL", "val": 190 }),
+            },
+        );
+
+        let text = metrics.encode().unwrap();
+        assert!(text.contains("# HELP stock_price Latest stock price"));
+        assert!(text.contains("# TYPE stock_price gauge"));
+        assert!(text.contains("stock_price{symbol=\"MSFT\"} 412"));
+        assert!(!text.contains("stock_price{symbol=\"AAPL\"}"));
+        assert!(text.contains("stock_updates_total{symbol=\"MSFT\"} 2"));
+        assert!(text.contains("stock_updates_total{symbol=\"AAPL\"} 1"));
+    }
+
+    #[test]
+    fn test_gauge_moves_series_when_labels_change() {
+        let metrics = stock_metrics();
+        metrics.apply(
+            "stocks",
+            &ResultDiff::Add {
+                data: json!({ "symbol": "MSFT", "val": 1 }),
+            },
+        );
+        metrics.apply(
+            "stocks",
+            &ResultDiff::Update {
+                data: json!({}),
+                before: json!({ "symbol": "MSFT", "val": 1 }),
+                after: json!({ "symbol": "MSFT.O", "val": 2 }),
+                grouping_keys: None,
+            },
+        );
+        let text = metrics.encode().unwrap();
+        assert!(!text.contains("stock_price{symbol=\"MSFT\"}"));
+        assert!(text.contains("stock_price{symbol=\"MSFT.O\"} 2"));
+    }
+
+    #[test]
+    fn test_counter_values_and_query_filter() {
+        let metrics = MetricSet::new(&[MetricConfig::counter("order_amount_total")
+            .with_query("orders")
+            .with_value("amount")])
+        .unwrap();
+        for (query, amount) in [("source.orders", json!(5)), ("refunds", json!(7))] {
+            metrics.apply(
+                query,
+                &ResultDiff::Add {
+                    data: json!({ "amount": amount }),
+                },
+            );
+        }
+        metrics.apply(
+            "orders",
+            &ResultDiff::Add {
+                data: json!({ "amount": -3 }),
+            },
+        );
+        metrics.apply(
+            "orders",
+            &ResultDiff::Delete {
+                data: json!({ "amount": 100 }),
+            },
+        );
+        assert!(metrics.encode().unwrap().contains("order_amount_total 5"));
+    }
+
+    #[test]
+    fn test_invalid_metrics() {
+        assert!(MetricSet::new(&[MetricConfig::gauge("bad name", "val")]).is_err());
+        assert!(
+            MetricSet::new(&[MetricConfig::gauge("ok", "val").with_label("bad-label", "x")])
+                .is_err()
+        );
+        assert!(MetricSet::new(&[
+            MetricConfig::gauge("dup", "val"),
+            MetricConfig::counter("dup"),
+        ])
+        .is_err());
+        assert!(matches_query(None, "anything"));
+        assert!(!matches_query(Some("orders"), "preorders"));
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::descriptor::PrometheusReactionDescriptor;
+use drasi_lib::channels::{ComponentStatus, QueryResult, ResultDiff};
+use drasi_lib::Reaction;
+use drasi_plugin_sdk::prelude::ReactionPluginDescriptor;
+use serde_json::json;
+use std::collections::HashMap;
+use std::time::Duration;
+
+fn price_gauge() -> MetricConfig {
+    MetricConfig::gauge("stock_price", "val").with_label("symbol", "symbol")
+}
+
+fn query_result(query_id: &str, results: Vec<ResultDiff>) -> QueryResult {
+    QueryResult::new(
+        query_id.to_string(),
+        chrono::Utc::now(),
+        results,
+        HashMap::new(),
+    )
+}
+
+#[test]
+fn test_prometheus_builder_defaults() {
+    let reaction = PrometheusReactionBuilder::new("test-reaction")
+        .with_metric(price_gauge())
+        .build()
+        .unwrap();
+    assert_eq!(reaction.id(), "test-reaction");
+    assert_eq!(reaction.type_name(), "prometheus");
+    let props = reaction.properties();
+    assert_eq!(props.get("host"), Some(&json!("0.0.0.0")));
+    assert_eq!(props.get("port"), Some(&json!(9464)));
+    assert_eq!(props.get("path"), Some(&json!("/metrics")));
+    assert_eq!(props["metrics"][0]["type"], json!("gauge"));
+    assert_eq!(props["metrics"][0]["labels"], json!({ "symbol": "symbol" }));
+}
+
+#[test]
+fn test_prometheus_builder_validation() {
+    let builder = || PrometheusReaction::builder("test-reaction").with_query("stocks");
+
+    assert!(builder().build().is_err());
+    assert!(builder()
+        .with_metric(price_gauge())
+        .with_path("metrics")
+        .build()
+        .is_err());
+    assert!(builder()
+        .with_metric(price_gauge())
+        .with_path("/metrics/:id")
+        .build()
+        .is_err());
+    assert!(builder()
+        .with_metric(MetricConfig::counter("changes_total").with_operations(vec![]))
+        .build()
+        .is_err());
+
+    let mut gauge = price_gauge();
+    gauge.value = None;
+    assert!(builder().with_metric(gauge).build().is_err());
+    assert!(builder()
+        .with_metric(MetricConfig::gauge("stock-price", "val"))
+        .build()
+        .is_err());
+    assert!(builder()
+        .with_metric(price_gauge())
+        .with_metric(MetricConfig::counter("stock_price"))
+        .build()
+        .is_err());
+    assert!(builder()
+        .with_metric(price_gauge().with_query("other"))
+        .build()
+        .is_err());
+    assert!(PrometheusReaction::builder("test-reaction")
+        .with_query("source.stocks")
+        .with_metric(price_gauge().with_query("stocks"))
+        .build()
+        .is_ok());
+}
+
+#[tokio::test]
+async fn test_reaction_serves_metrics() {
+    let reaction = PrometheusReaction::builder("test-reaction")
+        .with_query("stocks")
+        .with_host("127.0.0.1")
+        .with_port(19464)
+        .with_metric(price_gauge().with_help("Latest stock price"))
+        .with_metric(MetricConfig::counter("stock_rows_added_total"))
+        .with_auto_start(false)
+        .build()
+        .unwrap();
+    reaction.start().await.unwrap();
+    assert_eq!(reaction.status().await, ComponentStatus::Running);
+
+    reaction
+        .enqueue_query_result(query_result(
+            "stocks",
+            vec![
+                ResultDiff::Add {
+                    data: json!({ "symbol": "MSFT", "val": 410.5 }),
+                },
+                ResultDiff::Add {
+                    data: json!({ "symbol": "AAPL", "val": "190.25" }),
+                },
+            ],
+        ))
+        .await
+        .unwrap();
+
+    let client = reqwest::Client::new();
+    let mut body = String::new();
+    for _ in 0..50 {
+        let response = client
+            .get("http://127.0.0.1:19464/metrics")
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        assert!(response.headers()["content-type"]
+            .to_str()
+            .unwrap()
+            .starts_with("text/plain; version=0.0.4"));
+        body = response.text().await.unwrap();
+        if body.contains("stock_rows_added_total 2") {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    assert!(body.contains("# HELP stock_price Latest stock price"));
+    assert!(body.contains("stock_price{symbol=\"MSFT\"} 410.5"));
+    assert!(body.contains("stock_price{symbol=\"AAPL\"} 190.25"));
+    assert!(body.contains("stock_rows_added_total 2"));
+
+    let not_found = client
+        .get("http://127.0.0.1:19464/other")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(not_found.status(), 404);
+
+    reaction.stop().await.unwrap();
+    assert_eq!(reaction.status().await, ComponentStatus::Stopped);
+}
+
+#[tokio::test]
+async fn test_descriptor_creates_reaction() {
+    let descriptor = PrometheusReactionDescriptor;
+    assert_eq!(descriptor.kind(), "prometheus");
+
+    let config = json!({
+        "port": 19465,
+        "path": "/prom",
+        "metrics": [
+            {
+                "name": "stock_price",
+                "help": "Latest stock price",
+                "query": "stocks",
+                "value": "val",
+                "labels": { "symbol": "symbol" }
+            },
+            {
+                "name": "stock_changes_total",
+                "type": "counter",
+                "operations": ["add", "update", "delete"]
+            }
+        ]
+    });
+
+    let reaction = descriptor
+        .create_reaction("prom-1", vec!["stocks".to_string()], &config, false)
+        .await
+        .unwrap();
+
+    assert_eq!(reaction.id(), "prom-1");
+    assert!(!reaction.auto_start());
+    let props = reaction.properties();
+    assert_eq!(props.get("port"), Some(&json!(19465)));
+    assert_eq!(props.get("path"), Some(&json!("/prom")));
+    assert_eq!(props["metrics"][0]["query"], json!("stocks"));
+    assert_eq!(props["metrics"][1]["type"], json!("counter"));
+    assert_eq!(
+        props["metrics"][1]["operations"],
+        json!(["add", "update", "delete"])
+    );
+
+    let missing_value = json!({ "metrics": [{ "name": "stock_price" }] });
+    assert!(descriptor
+        .create_reaction("prom-2", vec!["stocks".to_string()], &missing_value, false)
+        .await
+        .is_err());
+
+    let unknown_field = json!({ "metrics": [{ "name": "x", "value": "v", "unit": "s" }] });
+    assert!(descriptor
+        .create_reaction("prom-3", vec!["stocks".to_string()], &unknown_field, false)
+        .await
+        .is_err());
+}
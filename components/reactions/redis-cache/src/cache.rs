@@ -0,0 +1,331 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Translation of query result changes into Redis commands.
+
+use log::warn;
+use serde_json::{json, Map, Value};
+
+use drasi_lib::channels::{QueryResult, ResultDiff};
+
+use crate::config::{RedisCacheReactionConfig, RedisStorage};
+
+/// A change to the cached state of one row.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum CacheOp {
+    /// Store `row` at `key`, replacing the previous row
+    Write { key: String, row: Value },
+    /// Remove the row at `key`
+    Delete { key: String },
+    /// Publish a change notification
+    Notify { message: Value },
+}
+
+/// Key of the set listing the row keys cached for a query.
+pub(crate) fn index_key(config: &RedisCacheReactionConfig, query_id: &str) -> String {
+    format!("{}:{query_id}", config.key_prefix)
+}
+
+/// Key of a row, or `None` if a key field is missing or null.
+pub(crate) fn row_key(
+    config: &RedisCacheReactionConfig,
+    query_id: &str,
+    row: &Value,
+) -> Option<String> {
+    let mut key = index_key(config, query_id);
+    for field in &config.key_fields {
+        let part = match row.get(field)? {
+            Value::Null => return None,
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        };
+        key.push(':');
+        key.push_str(&part);
+    }
+    Some(key)
+}
+
+/// Hash fields of a row. Non-object rows are stored in a `value` field.
+pub(crate) fn hash_fields(row: &Value) -> Vec<(String, String)> {
+    let text = |value: &Value| match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    };
+    match row {
+        Value::Object(map) => map.iter().map(|(k, v)| (k.clone(), text(v))).collect(),
+        other => vec![("value".to_string(), text(other))],
+    }
+}
+
+/// Cache operations for all changes of a query result.
+pub(crate) fn cache_ops(
+    config: &RedisCacheReactionConfig,
+    query_result: &QueryResult,
+    reaction_id: &str,
+) -> Vec<CacheOp> {
+    let query_id = &query_result.query_id;
+    let key_of = |row: &Value| {
+        let key = row_key(config, query_id, row);
+        if key.is_none() {
+            warn!(
+                "[{reaction_id}] Skipping row of query '{query_id}' without key fields {:?}: {row}",
+                config.key_fields
+            );
+        }
+        key
+    };
+
+    let mut ops = Vec::new();
+    for diff in &query_result.results {
+        let (operation, before, after) = match diff {
+            ResultDiff::Add { data } => ("ADD", None, Some(data)),
+            ResultDiff::Update { before, after, .. } => ("UPDATE", Some(before), Some(after)),
+            ResultDiff::Aggregation { before, after } => ("UPDATE", before.as_ref(), Some(after)),
+            ResultDiff::Delete { data } => ("DELETE", Some(data), None),
+            ResultDiff::Noop => continue,
+        };
+
+        let old_key = match before {
+            Some(row) => match key_of(row) {
+                Some(key) => Some(key),
+                None => continue,
+            },
+            None => None,
+        };
+        let new_key = match after {
+            Some(row) => match key_of(row) {
+                Some(key) => Some(key),
+                None => continue,
+            },
+            None => None,
+        };
+
+        if let Some(old_key) = &old_key {
+            if new_key.as_ref() != Some(old_key) {
+                ops.push(CacheOp::Delete {
+                    key: old_key.clone(),
+                });
+            }
+        }
+        if let (Some(key), Some(row)) = (&new_key, after) {
+            ops.push(CacheOp::Write {
+                key: key.clone(),
+                row: row.clone(),
+            });
+        }
+
+        if config.channel.is_some() {
+            let mut message = Map::new();
+            message.insert("queryId".to_string(), json!(query_id));
+            message.insert("op".to_string(), json!(operation));
+            message.insert(
+                "key".to_string(),
+                json!(new_key.as_ref().or(old_key.as_ref())),
+            );
+            if let Some(before) = before {
+                message.insert("before".to_string(), before.clone());
+            }
+            if let Some(after) = after {
+                message.insert("after".to_string(), after.clone());
+            }
+            message.insert(
+                "timestamp".to_string(),
+                json!(query_result.timestamp.timestamp_millis()),
+            );
+            ops.push(CacheOp::Notify {
+                message: Value::Object(message),
+            });
+        }
+    }
+    ops
+}
+
+/// Atomic pipeline applying cache operations for one query.
+pub(crate) fn pipeline(
+    config: &RedisCacheReactionConfig,
+    query_id: &str,
+    ops: &[CacheOp],
+) -> redis::Pipeline {
+    let index = index_key(config, query_id);
+    let mut pipe = redis::pipe();
+    pipe.atomic();
+
+    for op in ops {
+        match op {
+            CacheOp::Write { key, row } => {
+                // Replace the whole row so fields removed from it do not linger
+                pipe.del(key).ignore();
+                match config.storage {
+                    RedisStorage::Hash => {
+                        let fields = hash_fields(row);
+                        if !fields.is_empty() {
+                            pipe.hset_multiple(key, &fields).ignore();
+                        }
+                    }
+                    RedisStorage::Json => {
+                        pipe.set(key, row.to_string()).ignore();
+                    }
+                }
+                if let Some(ttl) = config.ttl_secs {
+                    pipe.expire(key, ttl as i64).ignore();
+                }
+                pipe.sadd(&index, key).ignore();
+            }
+            CacheOp::Delete { key } => {
+                pipe.del(key).ignore();
+                pipe.srem(&index, key).ignore();
+            }
+            CacheOp::Notify { message } => {
+                if let Some(channel) = &config.channel {
+                    pipe.publish(channel, message.to_string()).ignore();
+                }
+            }
+        }
+    }
+    pipe
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn config() -> RedisCacheReactionConfig {
+        RedisCacheReactionConfig {
+            key_fields: vec!["region".to_string(), "id".to_string()],
+            ..Default::default()
+        }
+    }
+
+    fn result(results: Vec<ResultDiff>) -> QueryResult {
+        QueryResult::new(
+            "orders".to_string(),
+            chrono::Utc::now(),
+            results,
+            HashMap::new(),
+        )
+    }
+
+    #[test]
+    fn test_row_key() {
+        let config = config();
+        assert_eq!(
+            row_key(&config, "orders", &json!({ "region": "eu", "id": 7 })),
+            Some("drasi:orders:eu:7".to_string())
+        );
+        assert_eq!(row_key(&config, "orders", &json!({ "id": 7 })), None);
+        assert_eq!(
+            row_key(&config, "orders", &json!({ "region": null, "id": 7 })),
+            None
+        );
+        assert_eq!(index_key(&config, "orders"), "drasi:orders");
+    }
+
+    #[test]
+    fn test_hash_fields() {
+        let mut fields = hash_fields(&json!({ "name": "a", "n": 1, "tags": ["x"], "none": null }));
+        fields.sort();
+        assert_eq!(
+            fields,
+            vec![
+                ("n".to_string(), "1".to_string()),
+                ("name".to_string(), "a".to_string()),
+                ("none".to_string(), "null".to_string()),
+                ("tags".to_string(), "[\"x\"]".to_string()),
+            ]
+        );
+        assert_eq!(
+            hash_fields(&json!(5)),
+            vec![("value".to_string(), "5".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_cache_ops() {
+        let ops = cache_ops(
+            &config(),
+            &result(vec![
+                ResultDiff::Add {
+                    data: json!({ "region": "eu", "id": 1, "total": 5 }),
+                },
+                ResultDiff::Update {
+                    data: json!({}),
+                    before: json!({ "region": "eu", "id": 1, "total": 5 }),
+                    after: json!({ "region": "eu", "id": 1, "total": 6 }),
+                    grouping_keys: None,
+                },
+                ResultDiff::Update {
+                    data: json!({}),
+                    before: json!({ "region": "eu", "id": 1 }),
+                    after: json!({ "region": "us", "id": 1 }),
+                    grouping_keys: None,
+                },
+                ResultDiff::Delete {
+                    data: json!({ "region": "us", "id": 1 }),
+                },
+                ResultDiff::Add {
+                    data: json!({ "id": 2 }),
+                },
+            ]),
+            "test",
+        );
+        assert_eq!(
+            ops,
+            vec![
+                CacheOp::Write {
+                    key: "drasi:orders:eu:1".to_string(),
+                    row: json!({ "region": "eu", "id": 1, "total": 5 }),
+                },
+                CacheOp::Write {
+                    key: "drasi:orders:eu:1".to_string(),
+                    row: json!({ "region": "eu", "id": 1, "total": 6 }),
+                },
+                CacheOp::Delete {
+                    key: "drasi:orders:eu:1".to_string(),
+                },
+                CacheOp::Write {
+                    key: "drasi:orders:us:1".to_string(),
+                    row: json!({ "region": "us", "id": 1 }),
+                },
+                CacheOp::Delete {
+                    key: "drasi:orders:us:1".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cache_ops_notifications() {
+        let config = RedisCacheReactionConfig {
+            channel: Some("changes".to_string()),
+            ..config()
+        };
+        let ops = cache_ops(
+            &config,
+            &result(vec![ResultDiff::Delete {
+                data: json!({ "region": "eu", "id": 1 }),
+            }]),
+            "test",
+        );
+        assert_eq!(ops.len(), 2);
+        let CacheOp::Notify { message } = &ops[1] else {
+            panic!("expected notification, got {:?}", ops[1]);
+        };
+        assert_eq!(message["queryId"], "orders");
+        assert_eq!(message["op"], "DELETE");
+        assert_eq!(message["key"], "drasi:orders:eu:1");
+        assert_eq!(message["before"], json!({ "region": "eu", "id": 1 }));
+        assert!(message.get("after").is_none());
+    }
+}
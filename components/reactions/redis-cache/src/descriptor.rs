@@ -0,0 +1,157 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Descriptor for the Redis cache reaction plugin.
+
+use drasi_lib::reactions::Reaction;
+use drasi_plugin_sdk::prelude::*;
+use utoipa::OpenApi;
+
+use crate::config::RedisStorage;
+use crate::RedisCacheReactionBuilder;
+
+/// DTO for the row storage.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::redis_cache::RedisStorage)]
+#[serde(rename_all = "lowercase")]
+pub enum RedisStorageDto {
+    Hash,
+    Json,
+}
+
+impl From<RedisStorageDto> for RedisStorage {
+    fn from(dto: RedisStorageDto) -> Self {
+        match dto {
+            RedisStorageDto::Hash => RedisStorage::Hash,
+            RedisStorageDto::Json => RedisStorage::Json,
+        }
+    }
+}
+
+/// Configuration DTO for the Redis cache reaction plugin.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::redis_cache::RedisCacheReactionConfig)]
+#[serde(rename_all = "camelCase")]
+pub struct RedisCacheReactionConfigDto {
+    /// Redis connection URL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub redis_url: Option<ConfigValue<String>>,
+
+    /// Prefix of all keys.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub key_prefix: Option<ConfigValue<String>>,
+
+    /// Row fields forming the key of a row.
+    pub key_fields: Vec<String>,
+
+    /// How rows are stored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage: Option<RedisStorageDto>,
+
+    /// Expiry of row keys in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueU64>)]
+    pub ttl_secs: Option<ConfigValue<u64>>,
+
+    /// Pub/sub channel for change notifications.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub channel: Option<ConfigValue<String>>,
+
+    /// Delete rows cached by a previous run on start.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueBool>)]
+    pub clear_on_start: Option<ConfigValue<bool>>,
+
+    /// Number of retries when applying a query result fails.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueU32>)]
+    pub max_retries: Option<ConfigValue<u32>>,
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(RedisCacheReactionConfigDto, RedisStorageDto,)))]
+struct RedisCacheReactionSchemas;
+
+/// Descriptor for the Redis cache reaction plugin.
+pub struct RedisCacheReactionDescriptor;
+
+#[async_trait]
+impl ReactionPluginDescriptor for RedisCacheReactionDescriptor {
+    fn kind(&self) -> &str {
+        "redis-cache"
+    }
+
+    fn config_version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn config_schema_name(&self) -> &str {
+        "reaction.redis_cache.RedisCacheReactionConfig"
+    }
+
+    fn config_schema_json(&self) -> String {
+        let api = RedisCacheReactionSchemas::openapi();
+        serde_json::to_string(
+            &api.components
+                .as_ref()
+                .expect("OpenAPI components missing")
+                .schemas,
+        )
+        .expect("Failed to serialize config schema")
+    }
+
+    async fn create_reaction(
+        &self,
+        id: &str,
+        query_ids: Vec<String>,
+        config_json: &serde_json::Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn Reaction>> {
+        let dto: RedisCacheReactionConfigDto = serde_json::from_value(config_json.clone())?;
+        let mapper = DtoMapper::new();
+
+        let mut builder = RedisCacheReactionBuilder::new(id)
+            .with_queries(query_ids)
+            .with_auto_start(auto_start)
+            .with_key_fields(dto.key_fields.clone());
+
+        if let Some(ref url) = dto.redis_url {
+            builder = builder.with_redis_url(mapper.resolve_string(url)?);
+        }
+        if let Some(ref prefix) = dto.key_prefix {
+            builder = builder.with_key_prefix(mapper.resolve_string(prefix)?);
+        }
+        if let Some(storage) = dto.storage {
+            builder = builder.with_storage(storage.into());
+        }
+        if let Some(ref ttl) = dto.ttl_secs {
+            builder = builder.with_ttl_secs(mapper.resolve_typed(ttl)?);
+        }
+        if let Some(channel) = mapper.resolve_optional_string(&dto.channel)? {
+            builder = builder.with_channel(channel);
+        }
+        if let Some(ref clear) = dto.clear_on_start {
+            builder = builder.with_clear_on_start(mapper.resolve_typed(clear)?);
+        }
+        if let Some(ref retries) = dto.max_retries {
+            builder = builder.with_max_retries(mapper.resolve_typed(retries)?);
+        }
+
+        let reaction = builder.build()?;
+        Ok(Box::new(reaction))
+    }
+}
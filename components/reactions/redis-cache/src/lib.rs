@@ -0,0 +1,177 @@
+#![allow(unexpected_cfgs)]
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Redis cache reaction plugin for Drasi
+//!
+//! This plugin keeps the current results of continuous queries in Redis, one
+//! key per result row, so services can read query state with O(1) lookups.
+//! Rows are stored as hashes or JSON documents, removed results delete their
+//! keys, and changes can optionally be announced on a pub/sub channel.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use drasi_reaction_redis_cache::{RedisCacheReaction, RedisStorage};
+//!
+//! let reaction = RedisCacheReaction::builder("order-cache")
+//!     .with_query("orders")
+//!     .with_redis_url("redis://localhost:6379")
+//!     .with_key_fields(vec!["id".to_string()])
+//!     .with_storage(RedisStorage::Json)
+//!     .with_channel("order-changes")
+//!     .build()?;
+//! ```
+
+mod cache;
+pub mod config;
+pub mod descriptor;
+pub mod redis_cache;
+
+pub use config::{RedisCacheReactionConfig, RedisStorage};
+pub use redis_cache::RedisCacheReaction;
+
+/// Builder for Redis cache reaction
+pub struct RedisCacheReactionBuilder {
+    id: String,
+    queries: Vec<String>,
+    config: RedisCacheReactionConfig,
+    priority_queue_capacity: Option<usize>,
+    auto_start: bool,
+}
+
+impl RedisCacheReactionBuilder {
+    /// Create a new Redis cache reaction builder with the given ID
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            queries: Vec::new(),
+            config: RedisCacheReactionConfig::default(),
+            priority_queue_capacity: None,
+            auto_start: true,
+        }
+    }
+
+    /// Set the query IDs to subscribe to
+    pub fn with_queries(mut self, queries: Vec<String>) -> Self {
+        self.queries = queries;
+        self
+    }
+
+    /// Add a query ID to subscribe to
+    pub fn with_query(mut self, query_id: impl Into<String>) -> Self {
+        self.queries.push(query_id.into());
+        self
+    }
+
+    /// Set the Redis connection URL
+    pub fn with_redis_url(mut self, url: impl Into<String>) -> Self {
+        self.config.redis_url = url.into();
+        self
+    }
+
+    /// Set the prefix of all keys
+    pub fn with_key_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.config.key_prefix = prefix.into();
+        self
+    }
+
+    /// Set the row fields forming the key of a row
+    pub fn with_key_fields(mut self, fields: Vec<String>) -> Self {
+        self.config.key_fields = fields;
+        self
+    }
+
+    /// Add a row field to the key of a row
+    pub fn with_key_field(mut self, field: impl Into<String>) -> Self {
+        self.config.key_fields.push(field.into());
+        self
+    }
+
+    /// Set how rows are stored
+    pub fn with_storage(mut self, storage: RedisStorage) -> Self {
+        self.config.storage = storage;
+        self
+    }
+
+    /// Set the expiry of row keys in seconds
+    pub fn with_ttl_secs(mut self, ttl_secs: u64) -> Self {
+        self.config.ttl_secs = Some(ttl_secs);
+        self
+    }
+
+    /// Set the pub/sub channel for change notifications
+    pub fn with_channel(mut self, channel: impl Into<String>) -> Self {
+        self.config.channel = Some(channel.into());
+        self
+    }
+
+    /// Set whether rows cached by a previous run are deleted on start
+    pub fn with_clear_on_start(mut self, clear: bool) -> Self {
+        self.config.clear_on_start = clear;
+        self
+    }
+
+    /// Set the number of retries when applying a query result fails
+    pub fn with_max_retries(mut self, retries: u32) -> Self {
+        self.config.max_retries = retries;
+        self
+    }
+
+    /// Set the priority queue capacity
+    pub fn with_priority_queue_capacity(mut self, capacity: usize) -> Self {
+        self.priority_queue_capacity = Some(capacity);
+        self
+    }
+
+    /// Set whether the reaction should auto-start
+    pub fn with_auto_start(mut self, auto_start: bool) -> Self {
+        self.auto_start = auto_start;
+        self
+    }
+
+    /// Set the full configuration at once
+    pub fn with_config(mut self, config: RedisCacheReactionConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Build the Redis cache reaction
+    pub fn build(self) -> anyhow::Result<RedisCacheReaction> {
+        RedisCacheReaction::validate_config(&self.config)?;
+
+        Ok(RedisCacheReaction::from_builder(
+            self.id,
+            self.queries,
+            self.config,
+            self.priority_queue_capacity,
+            self.auto_start,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests;
+
+/// Dynamic plugin entry point.
+#[cfg(feature = "dynamic-plugin")]
+drasi_plugin_sdk::export_plugin!(
+    plugin_id = "redis-cache-reaction",
+    core_version = env!("CARGO_PKG_VERSION"),
+    lib_version = env!("CARGO_PKG_VERSION"),
+    plugin_version = env!("CARGO_PKG_VERSION"),
+    source_descriptors = [],
+    reaction_descriptors = [descriptor::RedisCacheReactionDescriptor],
+    bootstrap_descriptors = [],
+);
@@ -0,0 +1,325 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use log::{debug, error, info, warn};
+use redis::aio::ConnectionManager;
+use redis::AsyncCommands;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use drasi_lib::channels::{ComponentStatus, QueryResult};
+use drasi_lib::managers::log_component_start;
+use drasi_lib::reactions::common::base::{ReactionBase, ReactionBaseParams};
+use drasi_lib::Reaction;
+
+use super::cache::{cache_ops, index_key, pipeline};
+use super::config::RedisCacheReactionConfig;
+use super::RedisCacheReactionBuilder;
+
+/// Redis cache reaction keeps query results in Redis.
+pub struct RedisCacheReaction {
+    base: ReactionBase,
+    config: RedisCacheReactionConfig,
+}
+
+impl std::fmt::Debug for RedisCacheReaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RedisCacheReaction")
+            .field("id", &self.base.id)
+            .field("redis_url", &redact_url(&self.config.redis_url))
+            .field("key_prefix", &self.config.key_prefix)
+            .field("storage", &self.config.storage)
+            .finish()
+    }
+}
+
+/// Hide the credentials of a Redis URL
+pub(crate) fn redact_url(url: &str) -> String {
+    match (url.find("://"), url.rfind('@')) {
+        (Some(scheme_end), Some(at)) if at > scheme_end => {
+            format!("{}***{}", &url[..scheme_end + 3], &url[at..])
+        }
+        _ => url.to_string(),
+    }
+}
+
+impl RedisCacheReaction {
+    /// Create a builder for RedisCacheReaction
+    pub fn builder(id: impl Into<String>) -> RedisCacheReactionBuilder {
+        RedisCacheReactionBuilder::new(id)
+    }
+
+    /// Create a new Redis cache reaction
+    ///
+    /// The event channel is automatically injected when the reaction is added
+    /// to DrasiLib via `add_reaction()`.
+    ///
+    /// # Errors
+    ///
+    /// - Returns error if the Redis URL is invalid
+    /// - Returns error if no key fields are configured
+    /// - Returns error if the key prefix or channel is empty
+    pub fn new(
+        id: impl Into<String>,
+        queries: Vec<String>,
+        config: RedisCacheReactionConfig,
+    ) -> anyhow::Result<Self> {
+        Self::validate_config(&config)?;
+        Ok(Self::from_builder(id.into(), queries, config, None, true))
+    }
+
+    /// Create from builder (internal method)
+    pub(crate) fn from_builder(
+        id: String,
+        queries: Vec<String>,
+        config: RedisCacheReactionConfig,
+        priority_queue_capacity: Option<usize>,
+        auto_start: bool,
+    ) -> Self {
+        let mut params = ReactionBaseParams::new(id, queries).with_auto_start(auto_start);
+        if let Some(capacity) = priority_queue_capacity {
+            params = params.with_priority_queue_capacity(capacity);
+        }
+
+        Self {
+            base: ReactionBase::new(params),
+            config,
+        }
+    }
+
+    /// Validate configuration: connection URL, keys and channel
+    pub(crate) fn validate_config(config: &RedisCacheReactionConfig) -> anyhow::Result<()> {
+        redis::Client::open(config.redis_url.as_str()).map_err(|e| {
+            anyhow::anyhow!(
+                "Validation error: invalid redis_url '{}': {e}",
+                redact_url(&config.redis_url)
+            )
+        })?;
+        if config.key_prefix.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Validation error: key_prefix cannot be empty"
+            ));
+        }
+        if config.key_fields.is_empty() || config.key_fields.iter().any(String::is_empty) {
+            return Err(anyhow::anyhow!(
+                "Validation error: key_fields must contain at least one non-empty field"
+            ));
+        }
+        if config.channel.as_deref() == Some("") {
+            return Err(anyhow::anyhow!("Validation error: channel cannot be empty"));
+        }
+        if config.ttl_secs == Some(0) {
+            return Err(anyhow::anyhow!(
+                "Validation error: ttl_secs must be greater than 0"
+            ));
+        }
+        Ok(())
+    }
+
+    /// Delete the rows and index sets cached for the given queries
+    async fn clear(
+        connection: &mut ConnectionManager,
+        config: &RedisCacheReactionConfig,
+        queries: &[String],
+    ) -> Result<()> {
+        for query_id in queries {
+            let index = index_key(config, query_id);
+            let keys: Vec<String> = connection.smembers(&index).await?;
+            if !keys.is_empty() {
+                let _: () = connection.del(&keys).await?;
+            }
+            let _: () = connection.del(&index).await?;
+            info!("Cleared {} cached rows of query '{query_id}'", keys.len());
+        }
+        Ok(())
+    }
+
+    /// Apply all changes of a query result in one transaction, retrying on failure
+    async fn apply(
+        connection: &mut ConnectionManager,
+        config: &RedisCacheReactionConfig,
+        query_result: &QueryResult,
+        reaction_id: &str,
+    ) -> Result<()> {
+        let ops = cache_ops(config, query_result, reaction_id);
+        if ops.is_empty() {
+            return Ok(());
+        }
+        let pipe = pipeline(config, &query_result.query_id, &ops);
+
+        let mut retry_delay = Duration::from_millis(100);
+        let mut attempt = 0;
+        loop {
+            let result: redis::RedisResult<()> = pipe.query_async(connection).await;
+            match result {
+                Ok(()) => {
+                    debug!(
+                        "[{reaction_id}] Applied {} cache operations for query '{}'",
+                        ops.len(),
+                        query_result.query_id
+                    );
+                    return Ok(());
+                }
+                Err(e) if attempt < config.max_retries => {
+                    attempt += 1;
+                    warn!(
+                        "[{reaction_id}] Failed to update cache (attempt {attempt}/{}): {e}. Retrying in {retry_delay:?}",
+                        config.max_retries
+                    );
+                    tokio::time::sleep(retry_delay).await;
+                    retry_delay *= 2;
+                }
+                Err(e) => return Err(anyhow!("Failed to update cache: {e}")),
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Reaction for RedisCacheReaction {
+    fn id(&self) -> &str {
+        &self.base.id
+    }
+
+    fn type_name(&self) -> &str {
+        "redis-cache"
+    }
+
+    fn properties(&self) -> HashMap<String, serde_json::Value> {
+        let mut config = self.config.clone();
+        config.redis_url = redact_url(&config.redis_url);
+        match serde_json::to_value(&config) {
+            Ok(serde_json::Value::Object(map)) => map.into_iter().collect(),
+            _ => HashMap::new(),
+        }
+    }
+
+    fn query_ids(&self) -> Vec<String> {
+        self.base.queries.clone()
+    }
+
+    fn auto_start(&self) -> bool {
+        self.base.get_auto_start()
+    }
+
+    async fn initialize(&self, context: drasi_lib::context::ReactionRuntimeContext) {
+        self.base.initialize(context).await;
+    }
+
+    async fn start(&self) -> Result<()> {
+        log_component_start("Redis Cache Reaction", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Starting,
+                Some("Starting Redis cache reaction".to_string()),
+            )
+            .await;
+
+        let connect = async {
+            let client = redis::Client::open(self.config.redis_url.as_str())?;
+            let mut connection = ConnectionManager::new(client).await?;
+            if self.config.clear_on_start {
+                Self::clear(&mut connection, &self.config, &self.base.queries).await?;
+            }
+            anyhow::Ok(connection)
+        };
+        let mut connection = match connect.await {
+            Ok(connection) => connection,
+            Err(e) => {
+                let message = format!(
+                    "Failed to connect to Redis at {}: {e}",
+                    redact_url(&self.config.redis_url)
+                );
+                self.base
+                    .set_status(ComponentStatus::Error, Some(message.clone()))
+                    .await;
+                return Err(anyhow!(message));
+            }
+        };
+
+        self.base
+            .set_status(
+                ComponentStatus::Running,
+                Some("Redis cache reaction started".to_string()),
+            )
+            .await;
+
+        let priority_queue = self.base.priority_queue.clone();
+        let reaction_id = self.base.id.clone();
+        let config = self.config.clone();
+        let mut shutdown_rx = self.base.create_shutdown_channel().await;
+
+        let processing_task = tokio::spawn(async move {
+            info!("[{reaction_id}] Redis cache processing task started");
+
+            loop {
+                tokio::select! {
+                    biased;
+
+                    _ = &mut shutdown_rx => {
+                        debug!("[{reaction_id}] Received shutdown signal, exiting processing loop");
+                        break;
+                    }
+
+                    query_result = priority_queue.dequeue() => {
+                        if let Err(e) = RedisCacheReaction::apply(
+                            &mut connection,
+                            &config,
+                            &query_result,
+                            &reaction_id,
+                        )
+                        .await
+                        {
+                            error!(
+                                "[{reaction_id}] Dropping {} changes of query '{}': {e}",
+                                query_result.results.len(),
+                                query_result.query_id
+                            );
+                        }
+                    }
+                }
+            }
+
+            info!("[{reaction_id}] Redis cache processing task ended");
+        });
+
+        self.base.set_processing_task(processing_task).await;
+
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        self.base.stop_common().await?;
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopped,
+                Some("Redis cache reaction stopped".to_string()),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn status(&self) -> ComponentStatus {
+        self.base.get_status().await
+    }
+
+    async fn enqueue_query_result(&self, result: QueryResult) -> anyhow::Result<()> {
+        self.base.enqueue_query_result(result).await
+    }
+}
@@ -0,0 +1,186 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the Redis cache reaction
+//!
+//! These tests run the reaction against a real Redis server provided by
+//! testcontainers and check the cached keys.
+
+use drasi_lib::channels::{ComponentStatus, QueryResult, ResultDiff};
+use drasi_lib::Reaction;
+use drasi_reaction_redis_cache::{RedisCacheReaction, RedisStorage};
+use redis::AsyncCommands;
+use serde_json::{json, Value};
+use shared_tests::redis_helpers::setup_redis;
+use std::collections::HashMap;
+use std::time::Duration;
+
+fn init_logging() {
+    env_logger::builder()
+        .is_test(true)
+        .filter_level(log::LevelFilter::Debug)
+        .try_init()
+        .ok();
+}
+
+fn result(results: Vec<ResultDiff>) -> QueryResult {
+    QueryResult::new(
+        "orders".to_string(),
+        chrono::Utc::now(),
+        results,
+        HashMap::new(),
+    )
+}
+
+async fn connection(url: &str) -> redis::aio::MultiplexedConnection {
+    redis::Client::open(url)
+        .unwrap()
+        .get_multiplexed_async_connection()
+        .await
+        .unwrap()
+}
+
+/// Wait until the index set of the orders query has `count` members.
+async fn wait_for_rows(connection: &mut redis::aio::MultiplexedConnection, count: usize) {
+    for _ in 0..100 {
+        let members: Vec<String> = connection.smembers("drasi:orders").await.unwrap();
+        if members.len() == count {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    panic!("timed out waiting for {count} cached rows");
+}
+
+#[tokio::test]
+async fn test_hash_storage_follows_results() {
+    init_logging();
+    let redis = setup_redis().await;
+    let mut connection = connection(redis.url()).await;
+
+    let reaction = RedisCacheReaction::builder("cache-reaction")
+        .with_redis_url(redis.url())
+        .with_query("orders")
+        .with_key_field("id")
+        .with_auto_start(false)
+        .build()
+        .unwrap();
+    reaction.start().await.unwrap();
+    assert_eq!(reaction.status().await, ComponentStatus::Running);
+
+    reaction
+        .enqueue_query_result(result(vec![
+            ResultDiff::Add {
+                data: json!({ "id": 1, "customer": "ada", "total": 5, "note": "rush" }),
+            },
+            ResultDiff::Add {
+                data: json!({ "id": 2, "customer": "bob", "total": 7 }),
+            },
+        ]))
+        .await
+        .unwrap();
+    wait_for_rows(&mut connection, 2).await;
+
+    let row: HashMap<String, String> = connection.hgetall("drasi:orders:1").await.unwrap();
+    assert_eq!(row.get("customer").map(String::as_str), Some("ada"));
+    assert_eq!(row.get("total").map(String::as_str), Some("5"));
+
+    reaction
+        .enqueue_query_result(result(vec![
+            ResultDiff::Update {
+                data: json!({}),
+                before: json!({ "id": 1, "customer": "ada", "total": 5, "note": "rush" }),
+                after: json!({ "id": 1, "customer": "ada", "total": 6 }),
+                grouping_keys: None,
+            },
+            ResultDiff::Delete {
+                data: json!({ "id": 2, "customer": "bob", "total": 7 }),
+            },
+        ]))
+        .await
+        .unwrap();
+    wait_for_rows(&mut connection, 1).await;
+
+    let row: HashMap<String, String> = connection.hgetall("drasi:orders:1").await.unwrap();
+    assert_eq!(row.get("total").map(String::as_str), Some("6"));
+    assert!(!row.contains_key("note"));
+    let exists: bool = connection.exists("drasi:orders:2").await.unwrap();
+    assert!(!exists);
+
+    reaction.stop().await.unwrap();
+    redis.cleanup().await;
+}
+
+#[tokio::test]
+async fn test_json_storage_ttl_and_notifications() {
+    init_logging();
+    let redis = setup_redis().await;
+    let mut connection = connection(redis.url()).await;
+
+    let client = redis::Client::open(redis.url()).unwrap();
+    let mut pubsub = client.get_async_pubsub().await.unwrap();
+    pubsub.subscribe("order-changes").await.unwrap();
+
+    // A row left over from a previous run
+    let _: () = connection.set("drasi:orders:9", "{}").await.unwrap();
+    let _: () = connection
+        .sadd("drasi:orders", "drasi:orders:9")
+        .await
+        .unwrap();
+
+    let reaction = RedisCacheReaction::builder("cache-reaction")
+        .with_redis_url(redis.url())
+        .with_query("orders")
+        .with_key_field("id")
+        .with_storage(RedisStorage::Json)
+        .with_ttl_secs(600)
+        .with_channel("order-changes")
+        .with_clear_on_start(true)
+        .with_auto_start(false)
+        .build()
+        .unwrap();
+    reaction.start().await.unwrap();
+    let exists: bool = connection.exists("drasi:orders:9").await.unwrap();
+    assert!(!exists);
+
+    reaction
+        .enqueue_query_result(result(vec![ResultDiff::Add {
+            data: json!({ "id": 1, "customer": "ada" }),
+        }]))
+        .await
+        .unwrap();
+    wait_for_rows(&mut connection, 1).await;
+
+    let document: String = connection.get("drasi:orders:1").await.unwrap();
+    let document: Value = serde_json::from_str(&document).unwrap();
+    assert_eq!(document, json!({ "id": 1, "customer": "ada" }));
+    let ttl: i64 = connection.ttl("drasi:orders:1").await.unwrap();
+    assert!(ttl > 0 && ttl <= 600);
+
+    let message = tokio::time::timeout(Duration::from_secs(5), async {
+        use futures_util::StreamExt;
+        pubsub.on_message().next().await
+    })
+    .await
+    .unwrap()
+    .unwrap();
+    let payload: Value = serde_json::from_str(&message.get_payload::<String>().unwrap()).unwrap();
+    assert_eq!(payload["queryId"], "orders");
+    assert_eq!(payload["op"], "ADD");
+    assert_eq!(payload["key"], "drasi:orders:1");
+    assert_eq!(payload["after"], json!({ "id": 1, "customer": "ada" }));
+
+    reaction.stop().await.unwrap();
+    redis.cleanup().await;
+}
@@ -0,0 +1,166 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Azure SignalR Service REST API client.
+
+use anyhow::{anyhow, Result};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use reqwest::{Client, Url};
+use serde_json::{json, Value};
+use sha2::Sha256;
+use std::time::Duration;
+
+/// Lifetime of the access tokens signed for each request.
+const TOKEN_LIFETIME: Duration = Duration::from_secs(3600);
+
+/// Parsed Azure SignalR Service connection string.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ConnectionString {
+    pub endpoint: String,
+    pub access_key: String,
+}
+
+impl ConnectionString {
+    /// Parse `Endpoint=...;AccessKey=...;Version=1.0;[Port=...;]`.
+    pub(crate) fn parse(connection_string: &str) -> Result<Self> {
+        let mut endpoint = None;
+        let mut access_key = None;
+        let mut port = None;
+        for part in connection_string
+            .split(';')
+            .filter(|p| !p.trim().is_empty())
+        {
+            let (key, value) = part.split_once('=').ok_or_else(|| {
+                anyhow!("connection string segments must have the form Key=Value")
+            })?;
+            match key.trim().to_ascii_lowercase().as_str() {
+                "endpoint" => endpoint = Some(value.trim().trim_end_matches('/').to_string()),
+                "accesskey" => access_key = Some(value.trim().to_string()),
+                "port" => port = Some(value.trim().to_string()),
+                _ => {}
+            }
+        }
+
+        let mut endpoint = endpoint
+            .filter(|e| !e.is_empty())
+            .ok_or_else(|| anyhow!("connection string has no Endpoint"))?;
+        let access_key = access_key
+            .filter(|k| !k.is_empty())
+            .ok_or_else(|| anyhow!("connection string has no AccessKey"))?;
+        if !endpoint.starts_with("https://") && !endpoint.starts_with("http://") {
+            return Err(anyhow!("Endpoint must start with http:// or https://"));
+        }
+        if let Some(port) = port {
+            port.parse::<u16>()
+                .map_err(|_| anyhow!("invalid Port '{port}'"))?;
+            endpoint = format!("{endpoint}:{port}");
+        }
+        Url::parse(&endpoint).map_err(|e| anyhow!("invalid Endpoint: {e}"))?;
+
+        Ok(Self {
+            endpoint,
+            access_key,
+        })
+    }
+}
+
+/// Hide the access key of a connection string
+pub(crate) fn redact_connection_string(connection_string: &str) -> String {
+    connection_string
+        .split(';')
+        .map(|part| match part.split_once('=') {
+            Some((key, _)) if key.trim().eq_ignore_ascii_case("accesskey") => {
+                format!("{key}=***")
+            }
+            _ => part.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+/// Sign an HS256 JWT for the given audience with the access key.
+pub(crate) fn access_token(audience: &str, access_key: &str, now: i64) -> Result<String> {
+    let header = URL_SAFE_NO_PAD.encode(json!({ "alg": "HS256", "typ": "JWT" }).to_string());
+    let claims = URL_SAFE_NO_PAD.encode(
+        json!({
+            "aud": audience,
+            "iat": now,
+            "exp": now + TOKEN_LIFETIME.as_secs() as i64,
+        })
+        .to_string(),
+    );
+    let signing_input = format!("{header}.{claims}");
+
+    let mut mac = Hmac::<Sha256>::new_from_slice(access_key.as_bytes())
+        .map_err(|e| anyhow!("Invalid access key: {e}"))?;
+    mac.update(signing_input.as_bytes());
+    let signature = URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes());
+
+    Ok(format!("{signing_input}.{signature}"))
+}
+
+/// Client sending messages to groups of an Azure SignalR hub.
+pub(crate) struct AzureSignalRClient {
+    http: Client,
+    connection: ConnectionString,
+    hub: String,
+}
+
+impl AzureSignalRClient {
+    pub(crate) fn new(connection: ConnectionString, hub: &str, timeout: Duration) -> Result<Self> {
+        let http = Client::builder().timeout(timeout).build()?;
+        Ok(Self {
+            http,
+            connection,
+            hub: hub.to_string(),
+        })
+    }
+
+    /// REST API URL of a group of the hub
+    pub(crate) fn group_url(&self, group: &str) -> Result<Url> {
+        let mut url = Url::parse(&self.connection.endpoint)?;
+        url.path_segments_mut()
+            .map_err(|_| anyhow!("Endpoint cannot be a base URL"))?
+            .pop_if_empty()
+            .extend(["api", "v1", "hubs", self.hub.as_str(), "groups", group]);
+        Ok(url)
+    }
+
+    /// Invoke `target` with `message` on all clients of a group.
+    ///
+    /// Fails with a `reqwest::Error` carrying the status for non-success responses.
+    pub(crate) async fn send_to_group(
+        &self,
+        group: &str,
+        target: &str,
+        message: &Value,
+    ) -> Result<()> {
+        let url = self.group_url(group)?;
+        let token = access_token(
+            url.as_str(),
+            &self.connection.access_key,
+            chrono::Utc::now().timestamp(),
+        )?;
+        self.http
+            .post(url)
+            .bearer_auth(token)
+            .json(&json!({ "target": target, "arguments": [message] }))
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+}
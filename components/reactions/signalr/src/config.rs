@@ -0,0 +1,105 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration types for the SignalR reaction.
+
+use serde::{Deserialize, Serialize};
+
+fn default_hub() -> String {
+    "drasi".to_string()
+}
+
+fn default_namespace() -> String {
+    "/".to_string()
+}
+
+fn default_target() -> String {
+    "change".to_string()
+}
+
+fn default_timeout_ms() -> u64 {
+    10000
+}
+
+fn default_max_retries() -> u32 {
+    3
+}
+
+/// Service the changes are pushed to.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SignalRBackend {
+    /// Azure SignalR Service REST API (default).
+    #[default]
+    Azure,
+    /// Socket.IO server, relaying messages to its rooms.
+    SocketIo,
+}
+
+/// SignalR reaction configuration
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SignalRReactionConfig {
+    /// Service the changes are pushed to
+    #[serde(default)]
+    pub backend: SignalRBackend,
+
+    /// Azure SignalR Service connection string,
+    /// e.g. `Endpoint=https://example.service.signalr.net;AccessKey=...;Version=1.0;`
+    #[serde(default)]
+    pub connection_string: String,
+
+    /// Azure SignalR hub receiving the messages
+    #[serde(default = "default_hub")]
+    pub hub: String,
+
+    /// Socket.IO server URL
+    #[serde(default)]
+    pub url: String,
+
+    /// Socket.IO namespace
+    #[serde(default = "default_namespace")]
+    pub namespace: String,
+
+    /// Hub method (Azure) or event name (Socket.IO) invoked on clients
+    #[serde(default = "default_target")]
+    pub target: String,
+
+    /// Result column selecting the group of a change. Defaults to the query ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_field: Option<String>,
+
+    /// Request timeout in milliseconds
+    #[serde(default = "default_timeout_ms")]
+    pub timeout_ms: u64,
+
+    /// Number of retries when sending a message fails
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+}
+
+impl Default for SignalRReactionConfig {
+    fn default() -> Self {
+        Self {
+            backend: SignalRBackend::default(),
+            connection_string: String::new(),
+            hub: default_hub(),
+            url: String::new(),
+            namespace: default_namespace(),
+            target: default_target(),
+            group_field: None,
+            timeout_ms: default_timeout_ms(),
+            max_retries: default_max_retries(),
+        }
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Descriptor for the SignalR reaction plugin.
+
+use drasi_lib::reactions::Reaction;
+use drasi_plugin_sdk::prelude::*;
+use utoipa::OpenApi;
+
+use crate::config::SignalRBackend;
+use crate::SignalRReactionBuilder;
+
+/// DTO for the service the changes are pushed to.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::signalr::SignalRBackend)]
+#[serde(rename_all = "snake_case")]
+pub enum SignalRBackendDto {
+    Azure,
+    SocketIo,
+}
+
+impl From<SignalRBackendDto> for SignalRBackend {
+    fn from(dto: SignalRBackendDto) -> Self {
+        match dto {
+            SignalRBackendDto::Azure => SignalRBackend::Azure,
+            SignalRBackendDto::SocketIo => SignalRBackend::SocketIo,
+        }
+    }
+}
+
+/// Configuration DTO for the SignalR reaction plugin.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::signalr::SignalRReactionConfig)]
+#[serde(rename_all = "camelCase")]
+pub struct SignalRReactionConfigDto {
+    /// Service the changes are pushed to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backend: Option<SignalRBackendDto>,
+
+    /// Azure SignalR Service connection string.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub connection_string: Option<ConfigValue<String>>,
+
+    /// Azure SignalR hub receiving the messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub hub: Option<ConfigValue<String>>,
+
+    /// Socket.IO server URL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub url: Option<ConfigValue<String>>,
+
+    /// Socket.IO namespace.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub namespace: Option<ConfigValue<String>>,
+
+    /// Hub method or event name invoked on clients.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub target: Option<ConfigValue<String>>,
+
+    /// Result column selecting the group of a change.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub group_field: Option<ConfigValue<String>>,
+
+    /// Request timeout in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueU64>)]
+    pub timeout_ms: Option<ConfigValue<u64>>,
+
+    /// Number of retries when sending a message fails.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueU32>)]
+    pub max_retries: Option<ConfigValue<u32>>,
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(SignalRReactionConfigDto, SignalRBackendDto,)))]
+struct SignalRReactionSchemas;
+
+/// Descriptor for the SignalR reaction plugin.
+pub struct SignalRReactionDescriptor;
+
+#[async_trait]
+impl ReactionPluginDescriptor for SignalRReactionDescriptor {
+    fn kind(&self) -> &str {
+        "signalr"
+    }
+
+    fn config_version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn config_schema_name(&self) -> &str {
+        "reaction.signalr.SignalRReactionConfig"
+    }
+
+    fn config_schema_json(&self) -> String {
+        let api = SignalRReactionSchemas::openapi();
+        serde_json::to_string(
+            &api.components
+                .as_ref()
+                .expect("OpenAPI components missing")
+                .schemas,
+        )
+        .expect("Failed to serialize config schema")
+    }
+
+    async fn create_reaction(
+        &self,
+        id: &str,
+        query_ids: Vec<String>,
+        config_json: &serde_json::Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn Reaction>> {
+        let dto: SignalRReactionConfigDto = serde_json::from_value(config_json.clone())?;
+        let mapper = DtoMapper::new();
+
+        let mut builder = SignalRReactionBuilder::new(id)
+            .with_queries(query_ids)
+            .with_auto_start(auto_start);
+
+        if let Some(ref connection_string) = dto.connection_string {
+            builder = builder.with_connection_string(mapper.resolve_string(connection_string)?);
+        }
+        if let Some(ref hub) = dto.hub {
+            builder = builder.with_hub(mapper.resolve_string(hub)?);
+        }
+        if let Some(ref url) = dto.url {
+            builder = builder.with_socketio_url(mapper.resolve_string(url)?);
+        }
+        if let Some(ref namespace) = dto.namespace {
+            builder = builder.with_namespace(mapper.resolve_string(namespace)?);
+        }
+        // An explicit backend wins over the one implied by the connection settings
+        if let Some(backend) = dto.backend {
+            builder = builder.with_backend(backend.into());
+        }
+        if let Some(ref target) = dto.target {
+            builder = builder.with_target(mapper.resolve_string(target)?);
+        }
+        if let Some(field) = mapper.resolve_optional_string(&dto.group_field)? {
+            builder = builder.with_group_field(field);
+        }
+        if let Some(ref timeout) = dto.timeout_ms {
+            builder = builder.with_timeout_ms(mapper.resolve_typed(timeout)?);
+        }
+        if let Some(ref retries) = dto.max_retries {
+            builder = builder.with_max_retries(mapper.resolve_typed(retries)?);
+        }
+
+        let reaction = builder.build()?;
+        Ok(Box::new(reaction))
+    }
+}
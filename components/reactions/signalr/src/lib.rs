@@ -0,0 +1,182 @@
+#![allow(unexpected_cfgs)]
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! SignalR reaction plugin for Drasi
+//!
+//! This plugin pushes continuous query result changes to Azure SignalR
+//! Service hubs or to a Socket.IO server, so browser and mobile clients
+//! receive live updates. Changes are sent to a group named after the query,
+//! or after the value of a result column, using the message format of the
+//! Drasi platform SignalR reaction.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use drasi_reaction_signalr::SignalRReaction;
+//!
+//! let reaction = SignalRReaction::builder("live-orders")
+//!     .with_query("orders")
+//!     .with_connection_string(
+//!         "Endpoint=https://example.service.signalr.net;AccessKey=...;Version=1.0;",
+//!     )
+//!     .with_hub("orders")
+//!     .with_group_field("customerId")
+//!     .build()?;
+//! ```
+
+mod azure;
+pub mod config;
+pub mod descriptor;
+mod message;
+pub mod signalr;
+
+pub use config::{SignalRBackend, SignalRReactionConfig};
+pub use signalr::SignalRReaction;
+
+/// Builder for SignalR reaction
+pub struct SignalRReactionBuilder {
+    id: String,
+    queries: Vec<String>,
+    config: SignalRReactionConfig,
+    priority_queue_capacity: Option<usize>,
+    auto_start: bool,
+}
+
+impl SignalRReactionBuilder {
+    /// Create a new SignalR reaction builder with the given ID
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            queries: Vec::new(),
+            config: SignalRReactionConfig::default(),
+            priority_queue_capacity: None,
+            auto_start: true,
+        }
+    }
+
+    /// Set the query IDs to subscribe to
+    pub fn with_queries(mut self, queries: Vec<String>) -> Self {
+        self.queries = queries;
+        self
+    }
+
+    /// Add a query ID to subscribe to
+    pub fn with_query(mut self, query_id: impl Into<String>) -> Self {
+        self.queries.push(query_id.into());
+        self
+    }
+
+    /// Set the service the changes are pushed to
+    pub fn with_backend(mut self, backend: SignalRBackend) -> Self {
+        self.config.backend = backend;
+        self
+    }
+
+    /// Push to Azure SignalR Service using the given connection string
+    pub fn with_connection_string(mut self, connection_string: impl Into<String>) -> Self {
+        self.config.backend = SignalRBackend::Azure;
+        self.config.connection_string = connection_string.into();
+        self
+    }
+
+    /// Set the Azure SignalR hub receiving the messages
+    pub fn with_hub(mut self, hub: impl Into<String>) -> Self {
+        self.config.hub = hub.into();
+        self
+    }
+
+    /// Push to the Socket.IO server at the given URL
+    pub fn with_socketio_url(mut self, url: impl Into<String>) -> Self {
+        self.config.backend = SignalRBackend::SocketIo;
+        self.config.url = url.into();
+        self
+    }
+
+    /// Set the Socket.IO namespace
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.config.namespace = namespace.into();
+        self
+    }
+
+    /// Set the hub method or event name invoked on clients
+    pub fn with_target(mut self, target: impl Into<String>) -> Self {
+        self.config.target = target.into();
+        self
+    }
+
+    /// Set the result column selecting the group of a change
+    pub fn with_group_field(mut self, field: impl Into<String>) -> Self {
+        self.config.group_field = Some(field.into());
+        self
+    }
+
+    /// Set the request timeout in milliseconds
+    pub fn with_timeout_ms(mut self, timeout_ms: u64) -> Self {
+        self.config.timeout_ms = timeout_ms;
+        self
+    }
+
+    /// Set the number of retries when sending a message fails
+    pub fn with_max_retries(mut self, retries: u32) -> Self {
+        self.config.max_retries = retries;
+        self
+    }
+
+    /// Set the priority queue capacity
+    pub fn with_priority_queue_capacity(mut self, capacity: usize) -> Self {
+        self.priority_queue_capacity = Some(capacity);
+        self
+    }
+
+    /// Set whether the reaction should auto-start
+    pub fn with_auto_start(mut self, auto_start: bool) -> Self {
+        self.auto_start = auto_start;
+        self
+    }
+
+    /// Set the full configuration at once
+    pub fn with_config(mut self, config: SignalRReactionConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Build the SignalR reaction
+    pub fn build(self) -> anyhow::Result<SignalRReaction> {
+        SignalRReaction::validate_config(&self.config)?;
+
+        Ok(SignalRReaction::from_builder(
+            self.id,
+            self.queries,
+            self.config,
+            self.priority_queue_capacity,
+            self.auto_start,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests;
+
+/// Dynamic plugin entry point.
+#[cfg(feature = "dynamic-plugin")]
+drasi_plugin_sdk::export_plugin!(
+    plugin_id = "signalr-reaction",
+    core_version = env!("CARGO_PKG_VERSION"),
+    lib_version = env!("CARGO_PKG_VERSION"),
+    plugin_version = env!("CARGO_PKG_VERSION"),
+    source_descriptors = [],
+    reaction_descriptors = [descriptor::SignalRReactionDescriptor],
+    bootstrap_descriptors = [],
+);
@@ -0,0 +1,107 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion of query results into change notifications.
+//!
+//! Notifications use the message format of the Drasi platform SignalR
+//! reaction, so existing clients work unchanged:
+//!
+//! ```json
+//! {
+//!   "op": "u",
+//!   "ts_ms": 1735689600000,
+//!   "payload": {
+//!     "source": { "queryId": "orders", "ts_ms": 1735689600000 },
+//!     "before": { "id": 1, "status": "open" },
+//!     "after": { "id": 1, "status": "shipped" }
+//!   }
+//! }
+//! ```
+
+use log::warn;
+use serde_json::{json, Value};
+
+use drasi_lib::channels::{QueryResult, ResultDiff};
+
+use crate::config::SignalRReactionConfig;
+
+/// A message for one group.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Notification {
+    pub group: String,
+    pub message: Value,
+}
+
+/// Group name from a result column. Objects, arrays and nulls have none.
+fn group_value(row: Option<&Value>, field: &str) -> Option<String> {
+    match row?.get(field)? {
+        Value::String(s) => Some(s.clone()),
+        value @ (Value::Number(_) | Value::Bool(_)) => Some(value.to_string()),
+        _ => None,
+    }
+}
+
+/// Build one notification per change of a query result.
+///
+/// Changes are sent to the group named by `group_field`, taken from the row
+/// after the change or, for deletions, before it. Without `group_field`
+/// changes go to the group named after the query. Changes without a usable
+/// group value are skipped.
+pub(crate) fn notifications(
+    config: &SignalRReactionConfig,
+    query_result: &QueryResult,
+    reaction_id: &str,
+) -> Vec<Notification> {
+    let query_id = &query_result.query_id;
+    let ts_ms = query_result.timestamp.timestamp_millis();
+    let mut notifications = Vec::new();
+
+    for diff in &query_result.results {
+        let (op, before, after) = match diff {
+            ResultDiff::Add { data } => ("i", None, Some(data)),
+            ResultDiff::Update { before, after, .. } => ("u", Some(before), Some(after)),
+            ResultDiff::Aggregation { before, after } => ("u", before.as_ref(), Some(after)),
+            ResultDiff::Delete { data } => ("d", Some(data), None),
+            ResultDiff::Noop => continue,
+        };
+
+        let group = match &config.group_field {
+            Some(field) => match group_value(after.or(before), field) {
+                Some(group) => group,
+                None => {
+                    warn!(
+                        "[{reaction_id}] Skipping change of query '{query_id}' without a value for group field '{field}'"
+                    );
+                    continue;
+                }
+            },
+            None => query_id.clone(),
+        };
+
+        notifications.push(Notification {
+            group,
+            message: json!({
+                "op": op,
+                "ts_ms": ts_ms,
+                "payload": {
+                    "source": { "queryId": query_id, "ts_ms": ts_ms },
+                    "before": before,
+                    "after": after,
+                },
+            }),
+        });
+    }
+
+    notifications
+}
@@ -0,0 +1,383 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use log::{debug, error, info, warn};
+use reqwest::StatusCode;
+use rust_socketio::asynchronous::{Client as SocketIoClient, ClientBuilder};
+use serde_json::json;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use drasi_lib::channels::{ComponentStatus, QueryResult};
+use drasi_lib::managers::log_component_start;
+use drasi_lib::reactions::common::base::{ReactionBase, ReactionBaseParams};
+use drasi_lib::Reaction;
+
+use super::azure::{redact_connection_string, AzureSignalRClient, ConnectionString};
+use super::config::{SignalRBackend, SignalRReactionConfig};
+use super::message::{notifications, Notification};
+use super::SignalRReactionBuilder;
+
+/// Delay before the first retry of a failed send. Doubles on every retry.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(100);
+
+/// Connection to the service messages are pushed to.
+pub(crate) enum Publisher {
+    Azure(AzureSignalRClient),
+    SocketIo(SocketIoClient),
+}
+
+impl Publisher {
+    /// Connect to the configured backend
+    pub(crate) async fn connect(config: &SignalRReactionConfig) -> Result<Self> {
+        let timeout = Duration::from_millis(config.timeout_ms);
+        match config.backend {
+            SignalRBackend::Azure => {
+                let connection = ConnectionString::parse(&config.connection_string)?;
+                Ok(Self::Azure(AzureSignalRClient::new(
+                    connection,
+                    &config.hub,
+                    timeout,
+                )?))
+            }
+            SignalRBackend::SocketIo => {
+                let connect = ClientBuilder::new(config.url.as_str())
+                    .namespace(config.namespace.as_str())
+                    .connect();
+                let client = tokio::time::timeout(timeout, connect)
+                    .await
+                    .map_err(|_| anyhow!("Timed out connecting to {}", config.url))??;
+                Ok(Self::SocketIo(client))
+            }
+        }
+    }
+
+    /// Send a notification to its group.
+    ///
+    /// Socket.IO clients cannot address rooms, so the server receives the
+    /// group with the message and is expected to relay it to that room.
+    pub(crate) async fn send(
+        &self,
+        config: &SignalRReactionConfig,
+        notification: &Notification,
+    ) -> Result<()> {
+        match self {
+            Self::Azure(client) => {
+                client
+                    .send_to_group(&notification.group, &config.target, &notification.message)
+                    .await
+            }
+            Self::SocketIo(client) => {
+                let payload = json!({
+                    "group": notification.group,
+                    "message": notification.message,
+                });
+                tokio::time::timeout(
+                    Duration::from_millis(config.timeout_ms),
+                    client.emit(config.target.as_str(), payload),
+                )
+                .await
+                .map_err(|_| anyhow!("Timed out sending to {}", config.url))??;
+                Ok(())
+            }
+        }
+    }
+
+    /// Close the connection
+    pub(crate) async fn close(self) {
+        if let Self::SocketIo(client) = self {
+            if let Err(e) = client.disconnect().await {
+                debug!("Failed to disconnect from Socket.IO server: {e}");
+            }
+        }
+    }
+}
+
+/// Whether a failed send may succeed when retried
+fn is_retryable(error: &anyhow::Error) -> bool {
+    match error
+        .downcast_ref::<reqwest::Error>()
+        .and_then(reqwest::Error::status)
+    {
+        Some(status) => status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error(),
+        None => true,
+    }
+}
+
+/// SignalR reaction pushes query result changes to Azure SignalR Service or Socket.IO.
+pub struct SignalRReaction {
+    base: ReactionBase,
+    config: SignalRReactionConfig,
+}
+
+impl std::fmt::Debug for SignalRReaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SignalRReaction")
+            .field("id", &self.base.id)
+            .field("backend", &self.config.backend)
+            .field("hub", &self.config.hub)
+            .field("target", &self.config.target)
+            .finish()
+    }
+}
+
+impl SignalRReaction {
+    /// Create a builder for SignalRReaction
+    pub fn builder(id: impl Into<String>) -> SignalRReactionBuilder {
+        SignalRReactionBuilder::new(id)
+    }
+
+    /// Create a new SignalR reaction
+    ///
+    /// The event channel is automatically injected when the reaction is added
+    /// to DrasiLib via `add_reaction()`.
+    ///
+    /// # Errors
+    ///
+    /// - Returns error if the Azure connection string or hub name is invalid
+    /// - Returns error if the Socket.IO URL or namespace is invalid
+    /// - Returns error if the target, group field or timeout is empty
+    pub fn new(
+        id: impl Into<String>,
+        queries: Vec<String>,
+        config: SignalRReactionConfig,
+    ) -> anyhow::Result<Self> {
+        Self::validate_config(&config)?;
+        Ok(Self::from_builder(id.into(), queries, config, None, true))
+    }
+
+    /// Create from builder (internal method)
+    pub(crate) fn from_builder(
+        id: String,
+        queries: Vec<String>,
+        config: SignalRReactionConfig,
+        priority_queue_capacity: Option<usize>,
+        auto_start: bool,
+    ) -> Self {
+        let mut params = ReactionBaseParams::new(id, queries).with_auto_start(auto_start);
+        if let Some(capacity) = priority_queue_capacity {
+            params = params.with_priority_queue_capacity(capacity);
+        }
+
+        Self {
+            base: ReactionBase::new(params),
+            config,
+        }
+    }
+
+    /// Validate configuration: backend connection, target and grouping
+    pub(crate) fn validate_config(config: &SignalRReactionConfig) -> anyhow::Result<()> {
+        match config.backend {
+            SignalRBackend::Azure => {
+                ConnectionString::parse(&config.connection_string).map_err(|e| {
+                    anyhow::anyhow!("Validation error: invalid connection_string: {e}")
+                })?;
+                if config.hub.is_empty()
+                    || !config
+                        .hub
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '_')
+                {
+                    return Err(anyhow::anyhow!(
+                        "Validation error: hub must be non-empty and contain only letters, digits and underscores"
+                    ));
+                }
+            }
+            SignalRBackend::SocketIo => {
+                if !config.url.starts_with("https://") && !config.url.starts_with("http://") {
+                    return Err(anyhow::anyhow!(
+                        "Validation error: url must start with http:// or https://"
+                    ));
+                }
+                if !config.namespace.starts_with('/') {
+                    return Err(anyhow::anyhow!(
+                        "Validation error: namespace must start with '/'"
+                    ));
+                }
+            }
+        }
+        if config.target.is_empty() {
+            return Err(anyhow::anyhow!("Validation error: target cannot be empty"));
+        }
+        if config.group_field.as_deref() == Some("") {
+            return Err(anyhow::anyhow!(
+                "Validation error: group_field cannot be empty"
+            ));
+        }
+        if config.timeout_ms == 0 {
+            return Err(anyhow::anyhow!(
+                "Validation error: timeout_ms must be greater than 0"
+            ));
+        }
+        Ok(())
+    }
+
+    /// Send a notification, retrying transient failures with exponential backoff
+    pub(crate) async fn send_with_retry(
+        publisher: &Publisher,
+        config: &SignalRReactionConfig,
+        notification: &Notification,
+        reaction_id: &str,
+    ) -> Result<()> {
+        let mut retry_delay = INITIAL_BACKOFF;
+        let mut attempt = 0;
+        loop {
+            match publisher.send(config, notification).await {
+                Ok(()) => return Ok(()),
+                Err(e) if attempt < config.max_retries && is_retryable(&e) => {
+                    attempt += 1;
+                    warn!(
+                        "[{reaction_id}] Failed to send to group '{}' (attempt {attempt}/{}): {e}. Retrying in {retry_delay:?}",
+                        notification.group, config.max_retries
+                    );
+                    tokio::time::sleep(retry_delay).await;
+                    retry_delay *= 2;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Reaction for SignalRReaction {
+    fn id(&self) -> &str {
+        &self.base.id
+    }
+
+    fn type_name(&self) -> &str {
+        "signalr"
+    }
+
+    fn properties(&self) -> HashMap<String, serde_json::Value> {
+        let mut config = self.config.clone();
+        config.connection_string = redact_connection_string(&config.connection_string);
+        match serde_json::to_value(&config) {
+            Ok(serde_json::Value::Object(map)) => map.into_iter().collect(),
+            _ => HashMap::new(),
+        }
+    }
+
+    fn query_ids(&self) -> Vec<String> {
+        self.base.queries.clone()
+    }
+
+    fn auto_start(&self) -> bool {
+        self.base.get_auto_start()
+    }
+
+    async fn initialize(&self, context: drasi_lib::context::ReactionRuntimeContext) {
+        self.base.initialize(context).await;
+    }
+
+    async fn start(&self) -> Result<()> {
+        log_component_start("SignalR Reaction", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Starting,
+                Some("Starting SignalR reaction".to_string()),
+            )
+            .await;
+
+        let publisher = match Publisher::connect(&self.config).await {
+            Ok(publisher) => publisher,
+            Err(e) => {
+                let message = format!(
+                    "Failed to connect to {:?} backend: {e}",
+                    self.config.backend
+                );
+                self.base
+                    .set_status(ComponentStatus::Error, Some(message.clone()))
+                    .await;
+                return Err(anyhow!(message));
+            }
+        };
+
+        self.base
+            .set_status(
+                ComponentStatus::Running,
+                Some("SignalR reaction started".to_string()),
+            )
+            .await;
+
+        let priority_queue = self.base.priority_queue.clone();
+        let reaction_id = self.base.id.clone();
+        let config = self.config.clone();
+        let mut shutdown_rx = self.base.create_shutdown_channel().await;
+
+        let processing_task = tokio::spawn(async move {
+            info!("[{reaction_id}] SignalR processing task started");
+
+            loop {
+                tokio::select! {
+                    biased;
+
+                    _ = &mut shutdown_rx => {
+                        debug!("[{reaction_id}] Received shutdown signal, exiting processing loop");
+                        break;
+                    }
+
+                    query_result = priority_queue.dequeue() => {
+                        for notification in notifications(&config, &query_result, &reaction_id) {
+                            if let Err(e) = SignalRReaction::send_with_retry(
+                                &publisher,
+                                &config,
+                                &notification,
+                                &reaction_id,
+                            )
+                            .await
+                            {
+                                error!(
+                                    "[{reaction_id}] Dropping change of query '{}' for group '{}': {e}",
+                                    query_result.query_id, notification.group
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+
+            publisher.close().await;
+            info!("[{reaction_id}] SignalR processing task ended");
+        });
+
+        self.base.set_processing_task(processing_task).await;
+
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        self.base.stop_common().await?;
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopped,
+                Some("SignalR reaction stopped".to_string()),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn status(&self) -> ComponentStatus {
+        self.base.get_status().await
+    }
+
+    async fn enqueue_query_result(&self, result: QueryResult) -> anyhow::Result<()> {
+        self.base.enqueue_query_result(result).await
+    }
+}
@@ -0,0 +1,431 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::azure::{access_token, redact_connection_string, AzureSignalRClient, ConnectionString};
+use crate::descriptor::SignalRReactionDescriptor;
+use crate::message::notifications;
+use crate::signalr::Publisher;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use drasi_lib::channels::{QueryResult, ResultDiff};
+use drasi_lib::Reaction;
+use drasi_plugin_sdk::prelude::ReactionPluginDescriptor;
+use hmac::{Hmac, Mac};
+use serde_json::json;
+use sha2::Sha256;
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+const CONNECTION_STRING: &str =
+    "Endpoint=https://example.service.signalr.net;AccessKey=c2VjcmV0;Version=1.0;";
+
+fn query_result(query_id: &str, results: Vec<ResultDiff>) -> QueryResult {
+    QueryResult::new(
+        query_id.to_string(),
+        chrono::Utc::now(),
+        results,
+        HashMap::new(),
+    )
+}
+
+fn mock_connection_string(listener: &TcpListener) -> String {
+    let port = listener.local_addr().unwrap().port();
+    format!("Endpoint=http://127.0.0.1;Port={port};AccessKey=key;Version=1.0;")
+}
+
+/// Answer one request per status, returning the raw requests.
+async fn serve(listener: TcpListener, responses: Vec<&'static str>) -> Vec<String> {
+    let mut requests = Vec::new();
+    for response in responses {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut request = Vec::new();
+        let mut buffer = [0u8; 4096];
+        while !request.ends_with(b"}") {
+            match stream.read(&mut buffer).await {
+                Ok(0) | Err(_) => break,
+                Ok(read) => request.extend_from_slice(&buffer[..read]),
+            }
+        }
+        requests.push(String::from_utf8_lossy(&request).to_string());
+
+        let response =
+            format!("HTTP/1.1 {response}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n");
+        let _ = stream.write_all(response.as_bytes()).await;
+    }
+    requests
+}
+
+fn body(request: &str) -> serde_json::Value {
+    serde_json::from_str(request.split("\r\n\r\n").nth(1).unwrap()).unwrap()
+}
+
+#[test]
+fn test_signalr_builder_defaults() {
+    let reaction = SignalRReactionBuilder::new("test-reaction")
+        .with_connection_string(CONNECTION_STRING)
+        .build()
+        .unwrap();
+    assert_eq!(reaction.id(), "test-reaction");
+    assert_eq!(reaction.type_name(), "signalr");
+    let props = reaction.properties();
+    assert_eq!(props.get("backend"), Some(&json!("azure")));
+    assert_eq!(props.get("hub"), Some(&json!("drasi")));
+    assert_eq!(props.get("namespace"), Some(&json!("/")));
+    assert_eq!(props.get("target"), Some(&json!("change")));
+    assert_eq!(props.get("timeout_ms"), Some(&json!(10000)));
+    assert!(props.get("group_field").is_none());
+}
+
+#[test]
+fn test_signalr_builder_validation() {
+    let azure = || SignalRReaction::builder("r").with_connection_string(CONNECTION_STRING);
+    let socketio = || SignalRReaction::builder("r").with_socketio_url("http://localhost:3000");
+
+    assert!(SignalRReaction::builder("r").build().is_err());
+    assert!(SignalRReaction::builder("r")
+        .with_connection_string("Endpoint=https://example.service.signalr.net;Version=1.0;")
+        .build()
+        .is_err());
+    assert!(SignalRReaction::builder("r")
+        .with_connection_string("Endpoint=example.service.signalr.net;AccessKey=k;")
+        .build()
+        .is_err());
+    assert!(azure().with_hub("").build().is_err());
+    assert!(azure().with_hub("my-hub").build().is_err());
+    assert!(azure().with_target("").build().is_err());
+    assert!(azure().with_group_field("").build().is_err());
+    assert!(azure().with_timeout_ms(0).build().is_err());
+    assert!(azure()
+        .with_hub("orders_hub")
+        .with_group_field("customerId")
+        .build()
+        .is_ok());
+
+    assert!(SignalRReaction::builder("r")
+        .with_socketio_url("ws://localhost:3000")
+        .build()
+        .is_err());
+    assert!(socketio().with_namespace("admin").build().is_err());
+    assert!(socketio().with_namespace("/admin").build().is_ok());
+}
+
+#[test]
+fn test_connection_string_is_parsed_and_redacted() {
+    let connection = ConnectionString::parse(CONNECTION_STRING).unwrap();
+    assert_eq!(connection.endpoint, "https://example.service.signalr.net");
+    assert_eq!(connection.access_key, "c2VjcmV0");
+
+    let local =
+        ConnectionString::parse("Endpoint=http://localhost/;Port=8888;AccessKey=k;Version=1.0")
+            .unwrap();
+    assert_eq!(local.endpoint, "http://localhost:8888");
+    assert!(ConnectionString::parse("Endpoint=http://localhost;Port=x;AccessKey=k").is_err());
+
+    assert_eq!(
+        redact_connection_string(CONNECTION_STRING),
+        "Endpoint=https://example.service.signalr.net;AccessKey=***;Version=1.0;"
+    );
+    let reaction = SignalRReaction::builder("r")
+        .with_connection_string(CONNECTION_STRING)
+        .build()
+        .unwrap();
+    assert_eq!(
+        reaction.properties().get("connection_string"),
+        Some(&json!(
+            "Endpoint=https://example.service.signalr.net;AccessKey=***;Version=1.0;"
+        ))
+    );
+    assert!(!format!("{reaction:?}").contains("c2VjcmV0"));
+}
+
+#[test]
+fn test_access_token_is_signed_jwt() {
+    let token = access_token("https://example/api/v1/hubs/drasi", "key", 1_000).unwrap();
+    let parts: Vec<&str> = token.split('.').collect();
+    assert_eq!(parts.len(), 3);
+
+    let header: serde_json::Value =
+        serde_json::from_slice(&URL_SAFE_NO_PAD.decode(parts[0]).unwrap()).unwrap();
+    assert_eq!(header, json!({ "alg": "HS256", "typ": "JWT" }));
+    let claims: serde_json::Value =
+        serde_json::from_slice(&URL_SAFE_NO_PAD.decode(parts[1]).unwrap()).unwrap();
+    assert_eq!(claims["aud"], "https://example/api/v1/hubs/drasi");
+    assert_eq!(claims["exp"], 4_600);
+
+    let mut mac = Hmac::<Sha256>::new_from_slice(b"key").unwrap();
+    mac.update(format!("{}.{}", parts[0], parts[1]).as_bytes());
+    mac.verify_slice(&URL_SAFE_NO_PAD.decode(parts[2]).unwrap())
+        .unwrap();
+}
+
+#[test]
+fn test_group_url_encodes_group() {
+    let client = AzureSignalRClient::new(
+        ConnectionString::parse(CONNECTION_STRING).unwrap(),
+        "orders",
+        Duration::from_secs(1),
+    )
+    .unwrap();
+    assert_eq!(
+        client.group_url("eu west/1").unwrap().as_str(),
+        "https://example.service.signalr.net/api/v1/hubs/orders/groups/eu%20west%2F1"
+    );
+}
+
+#[test]
+fn test_notifications_use_platform_format() {
+    let config = SignalRReactionConfig::default();
+    let result = query_result(
+        "orders",
+        vec![
+            ResultDiff::Add {
+                data: json!({ "id": 1 }),
+            },
+            ResultDiff::Update {
+                data: json!({ "id": 1, "status": "shipped" }),
+                before: json!({ "id": 1, "status": "open" }),
+                after: json!({ "id": 1, "status": "shipped" }),
+                grouping_keys: None,
+            },
+            ResultDiff::Noop,
+            ResultDiff::Delete {
+                data: json!({ "id": 1 }),
+            },
+        ],
+    );
+    let ts_ms = result.timestamp.timestamp_millis();
+
+    let notifications = notifications(&config, &result, "r");
+    assert_eq!(notifications.len(), 3);
+    assert!(notifications.iter().all(|n| n.group == "orders"));
+    assert_eq!(
+        notifications[0].message,
+        json!({
+            "op": "i",
+            "ts_ms": ts_ms,
+            "payload": {
+                "source": { "queryId": "orders", "ts_ms": ts_ms },
+                "before": null,
+                "after": { "id": 1 },
+            },
+        })
+    );
+    assert_eq!(notifications[1].message["op"], "u");
+    assert_eq!(
+        notifications[1].message["payload"]["before"]["status"],
+        "open"
+    );
+    assert_eq!(notifications[2].message["op"], "d");
+    assert_eq!(notifications[2].message["payload"]["after"], json!(null));
+}
+
+#[test]
+fn test_notifications_grouped_by_field() {
+    let config = SignalRReactionConfig {
+        group_field: Some("customer".to_string()),
+        ..Default::default()
+    };
+    let result = query_result(
+        "orders",
+        vec![
+            ResultDiff::Add {
+                data: json!({ "id": 1, "customer": "alice" }),
+            },
+            ResultDiff::Delete {
+                data: json!({ "id": 2, "customer": 42 }),
+            },
+            ResultDiff::Add {
+                data: json!({ "id": 3 }),
+            },
+            ResultDiff::Add {
+                data: json!({ "id": 4, "customer": { "name": "bob" } }),
+            },
+        ],
+    );
+
+    let groups: Vec<String> = notifications(&config, &result, "r")
+        .into_iter()
+        .map(|n| n.group)
+        .collect();
+    assert_eq!(groups, vec!["alice", "42"]);
+}
+
+#[tokio::test]
+async fn test_send_retries_server_errors() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let config = SignalRReactionConfig {
+        connection_string: mock_connection_string(&listener),
+        hub: "orders".to_string(),
+        ..Default::default()
+    };
+    let server = tokio::spawn(serve(
+        listener,
+        vec!["503 Service Unavailable", "202 Accepted"],
+    ));
+
+    let publisher = Publisher::connect(&config).await.unwrap();
+    let notification = notifications(
+        &config,
+        &query_result(
+            "orders",
+            vec![ResultDiff::Add {
+                data: json!({ "id": 1 }),
+            }],
+        ),
+        "r",
+    )
+    .remove(0);
+    SignalRReaction::send_with_retry(&publisher, &config, &notification, "r")
+        .await
+        .unwrap();
+
+    let requests = server.await.unwrap();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[1].starts_with("POST /api/v1/hubs/orders/groups/orders HTTP/1.1"));
+    assert!(requests[1]
+        .to_ascii_lowercase()
+        .contains("authorization: bearer "));
+    let body = body(&requests[1]);
+    assert_eq!(body["target"], "change");
+    assert_eq!(body["arguments"][0]["payload"]["after"], json!({ "id": 1 }));
+}
+
+#[tokio::test]
+async fn test_send_fails_on_client_error() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let config = SignalRReactionConfig {
+        connection_string: mock_connection_string(&listener),
+        ..Default::default()
+    };
+    let server = tokio::spawn(serve(listener, vec!["401 Unauthorized"]));
+
+    let publisher = Publisher::connect(&config).await.unwrap();
+    let notification = notifications(
+        &config,
+        &query_result(
+            "orders",
+            vec![ResultDiff::Add {
+                data: json!({ "id": 1 }),
+            }],
+        ),
+        "r",
+    )
+    .remove(0);
+    assert!(
+        SignalRReaction::send_with_retry(&publisher, &config, &notification, "r")
+            .await
+            .is_err()
+    );
+    assert_eq!(server.await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_reaction_sends_changes_to_groups() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let connection_string = mock_connection_string(&listener);
+    let server = tokio::spawn(serve(listener, vec!["202 Accepted", "202 Accepted"]));
+
+    let reaction = SignalRReaction::builder("test-reaction")
+        .with_connection_string(connection_string)
+        .with_hub("orders")
+        .with_target("orderChanged")
+        .with_group_field("region")
+        .with_query("orders")
+        .with_auto_start(false)
+        .build()
+        .unwrap();
+    reaction.start().await.unwrap();
+    reaction
+        .enqueue_query_result(query_result(
+            "orders",
+            vec![
+                ResultDiff::Add {
+                    data: json!({ "id": 1, "region": "eu" }),
+                },
+                ResultDiff::Add {
+                    data: json!({ "id": 2, "region": "us" }),
+                },
+            ],
+        ))
+        .await
+        .unwrap();
+
+    let requests = tokio::time::timeout(Duration::from_secs(5), server)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(requests[0].starts_with("POST /api/v1/hubs/orders/groups/eu "));
+    assert!(requests[1].starts_with("POST /api/v1/hubs/orders/groups/us "));
+    assert_eq!(body(&requests[0])["target"], "orderChanged");
+
+    reaction.stop().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_descriptor_creates_reaction() {
+    let descriptor = SignalRReactionDescriptor;
+    assert_eq!(descriptor.kind(), "signalr");
+
+    let config = json!({
+        "connectionString": CONNECTION_STRING,
+        "hub": "orders",
+        "target": "orderChanged",
+        "groupField": "customerId",
+        "timeoutMs": 5000,
+        "maxRetries": 1
+    });
+
+    let reaction = descriptor
+        .create_reaction("signalr-1", vec!["query1".to_string()], &config, false)
+        .await
+        .unwrap();
+
+    assert_eq!(reaction.id(), "signalr-1");
+    assert!(!reaction.auto_start());
+    let props = reaction.properties();
+    assert_eq!(props.get("backend"), Some(&json!("azure")));
+    assert_eq!(props.get("hub"), Some(&json!("orders")));
+    assert_eq!(props.get("target"), Some(&json!("orderChanged")));
+    assert_eq!(props.get("group_field"), Some(&json!("customerId")));
+    assert_eq!(props.get("timeout_ms"), Some(&json!(5000)));
+    assert_eq!(props.get("max_retries"), Some(&json!(1)));
+
+    let socketio = json!({
+        "backend": "socket_io",
+        "url": "http://localhost:3000",
+        "namespace": "/drasi"
+    });
+    let reaction = descriptor
+        .create_reaction("signalr-2", vec!["query1".to_string()], &socketio, false)
+        .await
+        .unwrap();
+    assert_eq!(
+        reaction.properties().get("backend"),
+        Some(&json!("socket_io"))
+    );
+
+    let missing_connection = json!({ "hub": "orders" });
+    assert!(descriptor
+        .create_reaction(
+            "signalr-3",
+            vec!["query1".to_string()],
+            &missing_connection,
+            false
+        )
+        .await
+        .is_err());
+}
@@ -0,0 +1,213 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Coalescing of result changes into per-row table writes.
+//!
+//! A batch can touch the same row several times (added then updated, updated
+//! twice, ...). Only the net effect per key is written, which keeps the number
+//! of statements bounded and avoids `ON CONFLICT` affecting a row twice in one
+//! statement.
+
+use anyhow::{anyhow, Result};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+
+/// A result row as a JSON object.
+pub(crate) type Row = Map<String, Value>;
+
+/// Net change to a single row.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum RowChange {
+    /// The row was added.
+    Insert(Row),
+    /// The row was updated in place.
+    Update(Row),
+    /// The row was deleted; the last known contents are kept for the key.
+    Delete(Row),
+    /// The row was deleted and added again, or its key changed into an existing key.
+    Replace(Row),
+}
+
+/// Net changes for one table, keyed by the row key.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TableChanges {
+    key_columns: Vec<String>,
+    rows: BTreeMap<String, RowChange>,
+}
+
+impl TableChanges {
+    pub(crate) fn new(key_columns: Vec<String>) -> Self {
+        Self {
+            key_columns,
+            rows: BTreeMap::new(),
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Net changes in key order.
+    pub(crate) fn changes(&self) -> impl Iterator<Item = &RowChange> {
+        self.rows.values()
+    }
+
+    /// Serialized key values of a row.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is not an object or misses a key column.
+    fn key(&self, row: &Row) -> Result<String> {
+        let values = self
+            .key_columns
+            .iter()
+            .map(|column| {
+                row.get(column)
+                    .cloned()
+                    .ok_or_else(|| anyhow!("Row is missing key column '{column}'"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Value::Array(values).to_string())
+    }
+
+    /// Record an added row.
+    pub(crate) fn add(&mut self, row: &Value) -> Result<()> {
+        let row = as_row(row)?;
+        let key = self.key(&row)?;
+        let change = match self.rows.remove(&key) {
+            None | Some(RowChange::Insert(_)) => RowChange::Insert(row),
+            Some(_) => RowChange::Replace(row),
+        };
+        self.rows.insert(key, change);
+        Ok(())
+    }
+
+    /// Record an updated row. A key change is a delete of the old key and an
+    /// add of the new one.
+    pub(crate) fn update(&mut self, before: &Value, after: &Value) -> Result<()> {
+        let before_row = as_row(before)?;
+        let after_row = as_row(after)?;
+        let before_key = self.key(&before_row)?;
+        let after_key = self.key(&after_row)?;
+        if before_key != after_key {
+            self.delete(before)?;
+            return self.add(after);
+        }
+
+        let change = match self.rows.remove(&after_key) {
+            None | Some(RowChange::Update(_)) => RowChange::Update(after_row),
+            Some(RowChange::Insert(_)) => RowChange::Insert(after_row),
+            Some(RowChange::Delete(_)) | Some(RowChange::Replace(_)) => {
+                RowChange::Replace(after_row)
+            }
+        };
+        self.rows.insert(after_key, change);
+        Ok(())
+    }
+
+    /// Record a deleted row.
+    ///
+    /// A row added earlier in the batch still becomes a delete, since the key
+    /// may have existed in the table before the batch.
+    pub(crate) fn delete(&mut self, row: &Value) -> Result<()> {
+        let row = as_row(row)?;
+        let key = self.key(&row)?;
+        self.rows.insert(key, RowChange::Delete(row));
+        Ok(())
+    }
+}
+
+fn as_row(value: &Value) -> Result<Row> {
+    match value {
+        Value::Object(map) => Ok(map.clone()),
+        other => Err(anyhow!("Result row must be a JSON object, got {other}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn row(value: Value) -> Row {
+        value.as_object().unwrap().clone()
+    }
+
+    fn changes(table: &TableChanges) -> Vec<RowChange> {
+        table.changes().cloned().collect()
+    }
+
+    #[test]
+    fn test_changes_are_coalesced_per_key() {
+        let mut table = TableChanges::new(vec!["id".to_string()]);
+        table.add(&json!({"id": 1, "v": "a"})).unwrap();
+        table
+            .update(&json!({"id": 1, "v": "a"}), &json!({"id": 1, "v": "b"}))
+            .unwrap();
+        table.add(&json!({"id": 2, "v": "x"})).unwrap();
+        table.delete(&json!({"id": 2, "v": "x"})).unwrap();
+        table
+            .update(&json!({"id": 3, "v": "c"}), &json!({"id": 3, "v": "d"}))
+            .unwrap();
+        table
+            .update(&json!({"id": 3, "v": "d"}), &json!({"id": 3, "v": "e"}))
+            .unwrap();
+
+        assert_eq!(
+            changes(&table),
+            vec![
+                RowChange::Insert(row(json!({"id": 1, "v": "b"}))),
+                RowChange::Delete(row(json!({"id": 2, "v": "x"}))),
+                RowChange::Update(row(json!({"id": 3, "v": "e"}))),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_delete_then_add_replaces() {
+        let mut table = TableChanges::new(vec!["id".to_string()]);
+        table.delete(&json!({"id": 1, "v": "a"})).unwrap();
+        table.add(&json!({"id": 1, "v": "b"})).unwrap();
+        assert_eq!(
+            changes(&table),
+            vec![RowChange::Replace(row(json!({"id": 1, "v": "b"})))]
+        );
+    }
+
+    #[test]
+    fn test_key_change_deletes_old_key() {
+        let mut table = TableChanges::new(vec!["region".to_string(), "id".to_string()]);
+        table
+            .update(
+                &json!({"region": "eu", "id": 1}),
+                &json!({"region": "us", "id": 1}),
+            )
+            .unwrap();
+        assert_eq!(
+            changes(&table),
+            vec![
+                RowChange::Delete(row(json!({"region": "eu", "id": 1}))),
+                RowChange::Insert(row(json!({"region": "us", "id": 1}))),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_rows_are_rejected() {
+        let mut table = TableChanges::new(vec!["id".to_string()]);
+        assert!(table.add(&json!({"name": "no key"})).is_err());
+        assert!(table.add(&json!([1, 2])).is_err());
+        assert!(table.is_empty());
+    }
+}
@@ -0,0 +1,313 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration for the PostgreSQL sink reaction.
+
+use drasi_lib::identity::IdentityProvider;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+fn default_hostname() -> String {
+    // DevSkim: ignore DS137138
+    "localhost".to_string()
+}
+
+fn default_schema() -> String {
+    "public".to_string()
+}
+
+fn default_create_table() -> bool {
+    true
+}
+
+fn default_batch_size() -> usize {
+    500
+}
+
+fn default_batch_timeout_ms() -> u64 {
+    100
+}
+
+fn default_timeout_ms() -> u64 {
+    30000
+}
+
+fn default_retry_attempts() -> u32 {
+    3
+}
+
+/// How writes that collide with the current table contents are handled.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictStrategy {
+    /// Added and updated rows are written with `INSERT ... ON CONFLICT DO UPDATE`,
+    /// so the table converges on the query result even if it drifted (default).
+    #[default]
+    Upsert,
+    /// Added rows whose key already exists are skipped, as are updates of missing rows.
+    Ignore,
+    /// Duplicate inserts and updates of missing rows fail the batch.
+    Error,
+}
+
+/// Target table for one query.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TableMapping {
+    /// Table name. Defaults to the query id with characters other than ASCII
+    /// letters, digits and `_` replaced by `_`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table: Option<String>,
+
+    /// Columns that identify a row. Defaults to the reaction-level `key_columns`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub key_columns: Vec<String>,
+}
+
+/// Configuration for the PostgreSQL sink reaction
+///
+/// Each subscribed query is mirrored into one table: added rows are inserted,
+/// updated rows are updated in place and deleted rows are deleted, matched by
+/// the key columns.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// let config = PostgresSinkReactionConfig {
+///     hostname: "localhost".to_string(),
+///     database: "mydb".to_string(),
+///     user: "postgres".to_string(),
+///     password: "password".to_string(),
+///     key_columns: vec!["id".to_string()],
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PostgresSinkReactionConfig {
+    /// Database hostname or IP address
+    #[serde(default = "default_hostname")]
+    pub hostname: String,
+
+    /// Database port (default: 5432)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+
+    /// Identity provider for authentication (takes precedence over user/password)
+    #[serde(skip)]
+    pub identity_provider: Option<Box<dyn IdentityProvider>>,
+
+    /// Database user
+    #[serde(default)]
+    pub user: String,
+
+    /// Database password
+    #[serde(default)]
+    pub password: String,
+
+    /// Database name
+    pub database: String,
+
+    /// Enable SSL/TLS
+    #[serde(default)]
+    pub ssl: bool,
+
+    /// Schema containing the target tables
+    #[serde(default = "default_schema")]
+    pub schema: String,
+
+    /// Default key columns for queries without their own `key_columns`
+    #[serde(default)]
+    pub key_columns: Vec<String>,
+
+    /// Per-query table mappings
+    #[serde(default)]
+    pub tables: HashMap<String, TableMapping>,
+
+    /// How conflicting writes are handled
+    #[serde(default)]
+    pub conflict: ConflictStrategy,
+
+    /// Create missing tables, and add missing columns, from the result rows
+    #[serde(default = "default_create_table")]
+    pub create_table: bool,
+
+    /// Maximum number of result changes written in one transaction
+    #[serde(default = "default_batch_size")]
+    pub batch_size: usize,
+
+    /// Time in milliseconds to wait for more results before writing a partial batch
+    #[serde(default = "default_batch_timeout_ms")]
+    pub batch_timeout_ms: u64,
+
+    /// Command timeout in milliseconds
+    #[serde(default = "default_timeout_ms")]
+    pub command_timeout_ms: u64,
+
+    /// Number of retry attempts for a failed batch
+    #[serde(default = "default_retry_attempts")]
+    pub retry_attempts: u32,
+}
+
+// Manual Debug implementation to avoid issues with trait objects
+impl std::fmt::Debug for PostgresSinkReactionConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PostgresSinkReactionConfig")
+            .field("hostname", &self.hostname)
+            .field("port", &self.port)
+            .field("identity_provider", &self.identity_provider.is_some())
+            .field("user", &self.user)
+            .field("password", &"***")
+            .field("database", &self.database)
+            .field("ssl", &self.ssl)
+            .field("schema", &self.schema)
+            .field("key_columns", &self.key_columns)
+            .field("tables", &self.tables)
+            .field("conflict", &self.conflict)
+            .field("create_table", &self.create_table)
+            .field("batch_size", &self.batch_size)
+            .field("batch_timeout_ms", &self.batch_timeout_ms)
+            .field("command_timeout_ms", &self.command_timeout_ms)
+            .field("retry_attempts", &self.retry_attempts)
+            .finish()
+    }
+}
+
+impl Default for PostgresSinkReactionConfig {
+    fn default() -> Self {
+        Self {
+            hostname: default_hostname(),
+            port: None,
+            identity_provider: None,
+            user: String::new(),
+            password: String::new(),
+            database: String::new(),
+            ssl: false,
+            schema: default_schema(),
+            key_columns: Vec::new(),
+            tables: HashMap::new(),
+            conflict: ConflictStrategy::default(),
+            create_table: default_create_table(),
+            batch_size: default_batch_size(),
+            batch_timeout_ms: default_batch_timeout_ms(),
+            command_timeout_ms: default_timeout_ms(),
+            retry_attempts: default_retry_attempts(),
+        }
+    }
+}
+
+/// Resolved target of one query.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TableTarget {
+    pub table: String,
+    pub key_columns: Vec<String>,
+}
+
+impl PostgresSinkReactionConfig {
+    /// Get the port for the database, using the default if not specified
+    pub fn get_port(&self) -> u16 {
+        self.port.unwrap_or(5432)
+    }
+
+    /// Find the table mapping for a query, falling back to the last dotted segment
+    fn mapping(&self, query_id: &str) -> Option<&TableMapping> {
+        self.tables.get(query_id).or_else(|| {
+            query_id
+                .rsplit_once('.')
+                .and_then(|(_, name)| self.tables.get(name))
+        })
+    }
+
+    /// Resolve the table and key columns for a query
+    pub fn target(&self, query_id: &str) -> TableTarget {
+        let mapping = self.mapping(query_id);
+        let table = mapping
+            .and_then(|m| m.table.clone())
+            .unwrap_or_else(|| default_table_name(query_id));
+        let key_columns = match mapping {
+            Some(m) if !m.key_columns.is_empty() => m.key_columns.clone(),
+            _ => self.key_columns.clone(),
+        };
+        TableTarget { table, key_columns }
+    }
+
+    /// Validate the configuration
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if self.identity_provider.is_none() && self.user.is_empty() {
+            anyhow::bail!("Either identity_provider or user/password must be provided");
+        }
+
+        if self.database.is_empty() {
+            anyhow::bail!("Database name is required");
+        }
+
+        if self.schema.is_empty() {
+            anyhow::bail!("Schema name cannot be empty");
+        }
+
+        if self.batch_size == 0 {
+            anyhow::bail!("batch_size must be greater than 0");
+        }
+
+        for (query_id, mapping) in &self.tables {
+            if mapping.table.as_deref() == Some("") {
+                anyhow::bail!("Table name for query '{query_id}' cannot be empty");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validate that every subscribed query has key columns and every mapping
+    /// matches a subscribed query
+    pub fn validate_queries(&self, queries: &[String]) -> anyhow::Result<()> {
+        for query_id in queries {
+            let target = self.target(query_id);
+            if target.key_columns.is_empty() {
+                anyhow::bail!(
+                    "No key columns configured for query '{query_id}'. Set key_columns on the reaction or in its table mapping"
+                );
+            }
+            if target.key_columns.iter().any(String::is_empty) {
+                anyhow::bail!("Key column names for query '{query_id}' cannot be empty");
+            }
+        }
+
+        for mapped_query in self.tables.keys() {
+            let dotted = format!(".{mapped_query}");
+            if !queries
+                .iter()
+                .any(|q| q == mapped_query || q.ends_with(&dotted))
+            {
+                anyhow::bail!(
+                    "Table mapping '{mapped_query}' does not match any subscribed query. Subscribed queries: {queries:?}"
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Derive a table name from a query id
+pub(crate) fn default_table_name(query_id: &str) -> String {
+    query_id
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
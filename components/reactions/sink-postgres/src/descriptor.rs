@@ -0,0 +1,300 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Descriptor for the PostgreSQL sink reaction plugin.
+
+use drasi_lib::reactions::Reaction;
+use drasi_plugin_sdk::prelude::*;
+use std::collections::HashMap;
+use utoipa::OpenApi;
+
+use crate::config::{ConflictStrategy, TableMapping};
+use crate::PostgresSinkReaction;
+
+/// DTO for the conflict strategy.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::sink_postgres::ConflictStrategy)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictStrategyDto {
+    Upsert,
+    Ignore,
+    Error,
+}
+
+impl From<ConflictStrategyDto> for ConflictStrategy {
+    fn from(dto: ConflictStrategyDto) -> Self {
+        match dto {
+            ConflictStrategyDto::Upsert => ConflictStrategy::Upsert,
+            ConflictStrategyDto::Ignore => ConflictStrategy::Ignore,
+            ConflictStrategyDto::Error => ConflictStrategy::Error,
+        }
+    }
+}
+
+/// DTO for a per-query table mapping.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::sink_postgres::TableMapping)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct TableMappingDto {
+    /// Target table name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table: Option<String>,
+
+    /// Columns that identify a row.
+    #[serde(default)]
+    pub key_columns: Vec<String>,
+}
+
+/// Configuration DTO for the PostgreSQL sink reaction plugin.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::sink_postgres::PostgresSinkReactionConfig)]
+#[serde(rename_all = "camelCase")]
+pub struct PostgresSinkReactionConfigDto {
+    /// Database hostname or IP address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub hostname: Option<ConfigValue<String>>,
+
+    /// Database port.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueU16>)]
+    pub port: Option<ConfigValue<u16>>,
+
+    /// Database user.
+    #[schema(value_type = ConfigValueString)]
+    pub user: ConfigValue<String>,
+
+    /// Database password.
+    #[schema(value_type = ConfigValueString)]
+    pub password: ConfigValue<String>,
+
+    /// Database name.
+    #[schema(value_type = ConfigValueString)]
+    pub database: ConfigValue<String>,
+
+    /// Enable SSL/TLS.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueBool>)]
+    pub ssl: Option<ConfigValue<bool>>,
+
+    /// Schema containing the target tables.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub schema: Option<ConfigValue<String>>,
+
+    /// Default key columns.
+    #[serde(default)]
+    pub key_columns: Vec<String>,
+
+    /// Per-query table mappings.
+    #[serde(default)]
+    pub tables: HashMap<String, TableMappingDto>,
+
+    /// Conflict handling strategy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conflict: Option<ConflictStrategyDto>,
+
+    /// Create missing tables and columns.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueBool>)]
+    pub create_table: Option<ConfigValue<bool>>,
+
+    /// Maximum number of result changes per transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueUsize>)]
+    pub batch_size: Option<ConfigValue<usize>>,
+
+    /// Time to wait for more results before writing a partial batch, in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueU64>)]
+    pub batch_timeout_ms: Option<ConfigValue<u64>>,
+
+    /// Command timeout in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueU64>)]
+    pub command_timeout_ms: Option<ConfigValue<u64>>,
+
+    /// Number of retry attempts for a failed batch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueU32>)]
+    pub retry_attempts: Option<ConfigValue<u32>>,
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(
+    PostgresSinkReactionConfigDto,
+    TableMappingDto,
+    ConflictStrategyDto,
+)))]
+struct PostgresSinkReactionSchemas;
+
+/// Descriptor for the PostgreSQL sink reaction plugin.
+pub struct PostgresSinkReactionDescriptor;
+
+#[async_trait]
+impl ReactionPluginDescriptor for PostgresSinkReactionDescriptor {
+    fn kind(&self) -> &str {
+        "sink-postgres"
+    }
+
+    fn config_version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn config_schema_name(&self) -> &str {
+        "reaction.sink_postgres.PostgresSinkReactionConfig"
+    }
+
+    fn config_schema_json(&self) -> String {
+        let api = PostgresSinkReactionSchemas::openapi();
+        serde_json::to_string(
+            &api.components
+                .as_ref()
+                .expect("OpenAPI components missing")
+                .schemas,
+        )
+        .expect("Failed to serialize config schema")
+    }
+
+    async fn create_reaction(
+        &self,
+        id: &str,
+        query_ids: Vec<String>,
+        config_json: &serde_json::Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn Reaction>> {
+        let dto: PostgresSinkReactionConfigDto = serde_json::from_value(config_json.clone())?;
+        let mapper = DtoMapper::new();
+
+        let mut builder = PostgresSinkReaction::builder(id)
+            .with_queries(query_ids)
+            .with_auto_start(auto_start)
+            .with_user(mapper.resolve_string(&dto.user)?)
+            .with_password(mapper.resolve_string(&dto.password)?)
+            .with_database(mapper.resolve_string(&dto.database)?)
+            .with_key_columns(dto.key_columns.clone());
+
+        if let Some(ref v) = dto.hostname {
+            builder = builder.with_hostname(mapper.resolve_string(v)?);
+        }
+        if let Some(ref v) = dto.port {
+            builder = builder.with_port(mapper.resolve_typed(v)?);
+        }
+        if let Some(ref v) = dto.ssl {
+            builder = builder.with_ssl(mapper.resolve_typed(v)?);
+        }
+        if let Some(ref v) = dto.schema {
+            builder = builder.with_schema(mapper.resolve_string(v)?);
+        }
+        if let Some(conflict) = dto.conflict {
+            builder = builder.with_conflict_strategy(conflict.into());
+        }
+        if let Some(ref v) = dto.create_table {
+            builder = builder.with_create_table(mapper.resolve_typed(v)?);
+        }
+        if let Some(ref v) = dto.batch_size {
+            builder = builder.with_batch_size(mapper.resolve_typed(v)?);
+        }
+        if let Some(ref v) = dto.batch_timeout_ms {
+            builder = builder.with_batch_timeout_ms(mapper.resolve_typed(v)?);
+        }
+        if let Some(ref v) = dto.command_timeout_ms {
+            builder = builder.with_command_timeout_ms(mapper.resolve_typed(v)?);
+        }
+        if let Some(ref v) = dto.retry_attempts {
+            builder = builder.with_retry_attempts(mapper.resolve_typed(v)?);
+        }
+
+        for (query_id, mapping) in &dto.tables {
+            builder = builder.with_table_mapping(
+                query_id,
+                TableMapping {
+                    table: mapping.table.clone(),
+                    key_columns: mapping.key_columns.clone(),
+                },
+            );
+        }
+
+        let reaction = builder.build()?;
+        Ok(Box::new(reaction))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_descriptor_creates_reaction() {
+        let descriptor = PostgresSinkReactionDescriptor;
+        assert_eq!(descriptor.kind(), "sink-postgres");
+        assert!(descriptor
+            .config_schema_json()
+            .contains("reaction.sink_postgres.PostgresSinkReactionConfig"));
+
+        let config = json!({
+            "hostname": "db",
+            "user": "postgres",
+            "password": "secret",
+            "database": "app",
+            "schema": "mirror",
+            "keyColumns": ["id"],
+            "tables": {
+                "customers": { "table": "customer_view", "keyColumns": ["customer_id"] }
+            },
+            "conflict": "error",
+            "createTable": false,
+            "batchSize": 50
+        });
+
+        let reaction = descriptor
+            .create_reaction(
+                "sink-1",
+                vec!["orders".to_string(), "customers".to_string()],
+                &config,
+                false,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(reaction.id(), "sink-1");
+        assert!(!reaction.auto_start());
+        let props = reaction.properties();
+        assert_eq!(props.get("schema"), Some(&json!("mirror")));
+        assert_eq!(props.get("conflict"), Some(&json!("error")));
+        assert_eq!(props.get("create_table"), Some(&json!(false)));
+        assert_eq!(props.get("batch_size"), Some(&json!(50)));
+        assert_eq!(
+            props["tables"]["customers"],
+            json!({"table": "customer_view", "key_columns": ["customer_id"]})
+        );
+        assert!(!props.contains_key("password"));
+    }
+
+    #[tokio::test]
+    async fn test_descriptor_rejects_unknown_mapping_fields() {
+        let config = json!({
+            "user": "postgres",
+            "password": "secret",
+            "database": "app",
+            "keyColumns": ["id"],
+            "tables": { "orders": { "tableName": "x" } }
+        });
+        let result = PostgresSinkReactionDescriptor
+            .create_reaction("sink-1", vec!["orders".to_string()], &config, true)
+            .await;
+        assert!(result.is_err());
+    }
+}
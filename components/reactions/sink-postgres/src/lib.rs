@@ -0,0 +1,57 @@
+#![allow(unexpected_cfgs)]
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! PostgreSQL sink reaction plugin for Drasi
+//!
+//! This plugin keeps a PostgreSQL table in step with a continuous query's
+//! result set: added rows are inserted, updated rows are updated and deleted
+//! rows are deleted, matched by key columns. Changes are written in batched
+//! transactions, and missing tables and columns are created from the result
+//! rows.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use drasi_reaction_sink_postgres::{ConflictStrategy, PostgresSinkReaction};
+//!
+//! let reaction = PostgresSinkReaction::builder("order-mirror")
+//!     .with_connection("localhost", 5432, "mydb", "postgres", "password")
+//!     .with_query("open-orders")
+//!     .with_table("open-orders", "open_orders", vec!["order_id".to_string()])
+//!     .with_conflict_strategy(ConflictStrategy::Upsert)
+//!     .build()?;
+//! ```
+
+mod changes;
+pub mod config;
+pub mod descriptor;
+pub mod reaction;
+mod sql;
+mod writer;
+
+pub use config::{ConflictStrategy, PostgresSinkReactionConfig, TableMapping};
+pub use reaction::{PostgresSinkReaction, PostgresSinkReactionBuilder};
+
+/// Dynamic plugin entry point.
+#[cfg(feature = "dynamic-plugin")]
+drasi_plugin_sdk::export_plugin!(
+    plugin_id = "sink-postgres-reaction",
+    core_version = env!("CARGO_PKG_VERSION"),
+    lib_version = env!("CARGO_PKG_VERSION"),
+    plugin_version = env!("CARGO_PKG_VERSION"),
+    source_descriptors = [],
+    reaction_descriptors = [descriptor::PostgresSinkReactionDescriptor],
+    bootstrap_descriptors = [],
+);
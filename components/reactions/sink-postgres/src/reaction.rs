@@ -0,0 +1,631 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! PostgreSQL sink reaction implementation.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use log::{debug, error, info};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+use drasi_lib::channels::{ComponentStatus, QueryResult, ResultDiff};
+use drasi_lib::managers::log_component_start;
+use drasi_lib::reactions::common::base::{ReactionBase, ReactionBaseParams};
+use drasi_lib::Reaction;
+
+use crate::changes::TableChanges;
+use crate::config::{ConflictStrategy, PostgresSinkReactionConfig, TableMapping};
+use crate::writer::{PostgresWriter, TableBatch};
+
+/// PostgreSQL sink reaction
+///
+/// Mirrors each subscribed query's result set into a table. Changes are
+/// collected into batches and written in one transaction per batch.
+pub struct PostgresSinkReaction {
+    base: ReactionBase,
+    config: PostgresSinkReactionConfig,
+    writer: RwLock<Option<Arc<PostgresWriter>>>,
+}
+
+impl std::fmt::Debug for PostgresSinkReaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PostgresSinkReaction")
+            .field("id", &self.base.id)
+            .field("config", &self.config)
+            .finish()
+    }
+}
+
+impl PostgresSinkReaction {
+    /// Create a builder for PostgresSinkReaction
+    pub fn builder(id: impl Into<String>) -> PostgresSinkReactionBuilder {
+        PostgresSinkReactionBuilder::new(id)
+    }
+
+    /// Create a new PostgreSQL sink reaction
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid or a subscribed query
+    /// has no key columns.
+    pub fn new(
+        id: impl Into<String>,
+        queries: Vec<String>,
+        config: PostgresSinkReactionConfig,
+    ) -> Result<Self> {
+        Self::from_builder(id.into(), queries, config, None, true)
+    }
+
+    /// Create from builder (internal method)
+    pub(crate) fn from_builder(
+        id: String,
+        queries: Vec<String>,
+        config: PostgresSinkReactionConfig,
+        priority_queue_capacity: Option<usize>,
+        auto_start: bool,
+    ) -> Result<Self> {
+        config.validate()?;
+        config.validate_queries(&queries)?;
+
+        let mut params = ReactionBaseParams::new(id, queries).with_auto_start(auto_start);
+        if let Some(capacity) = priority_queue_capacity {
+            params = params.with_priority_queue_capacity(capacity);
+        }
+
+        Ok(Self {
+            base: ReactionBase::new(params),
+            config,
+            writer: RwLock::new(None),
+        })
+    }
+
+    /// Test the database connection
+    pub async fn test_connection(&self) -> Result<()> {
+        let guard = self.writer.read().await;
+        match guard.as_ref() {
+            Some(writer) => writer.test_connection().await,
+            None => Err(anyhow::anyhow!(
+                "Writer not initialized — call start() first"
+            )),
+        }
+    }
+
+    /// Group the changes of several query results by target table.
+    ///
+    /// Rows that are not objects or miss a key column are logged and skipped.
+    pub(crate) fn build_batches(
+        config: &PostgresSinkReactionConfig,
+        results: &[Arc<QueryResult>],
+        reaction_id: &str,
+    ) -> Vec<TableBatch> {
+        let mut batches: Vec<TableBatch> = Vec::new();
+
+        for query_result in results {
+            let target = config.target(&query_result.query_id);
+            let index = match batches.iter().position(|b| b.target == target) {
+                Some(index) => index,
+                None => {
+                    batches.push(TableBatch {
+                        changes: TableChanges::new(target.key_columns.clone()),
+                        target,
+                    });
+                    batches.len() - 1
+                }
+            };
+            let changes = &mut batches[index].changes;
+
+            for diff in &query_result.results {
+                let applied = match diff {
+                    ResultDiff::Add { data } => changes.add(data),
+                    ResultDiff::Update { before, after, .. } => changes.update(before, after),
+                    ResultDiff::Delete { data } => changes.delete(data),
+                    ResultDiff::Aggregation {
+                        before: Some(before),
+                        after,
+                    } => changes.update(before, after),
+                    ResultDiff::Aggregation {
+                        before: None,
+                        after,
+                    } => changes.add(after),
+                    ResultDiff::Noop => Ok(()),
+                };
+                if let Err(e) = applied {
+                    error!(
+                        "[{reaction_id}] Skipping result from query '{}': {e}",
+                        query_result.query_id
+                    );
+                }
+            }
+        }
+
+        batches.retain(|batch| !batch.changes.is_empty());
+        batches
+    }
+}
+
+#[async_trait]
+impl Reaction for PostgresSinkReaction {
+    fn id(&self) -> &str {
+        &self.base.id
+    }
+
+    fn type_name(&self) -> &str {
+        "sink-postgres"
+    }
+
+    fn properties(&self) -> HashMap<String, serde_json::Value> {
+        match serde_json::to_value(&self.config) {
+            Ok(serde_json::Value::Object(mut map)) => {
+                // Don't expose password
+                map.remove("password");
+                map.into_iter().collect()
+            }
+            _ => HashMap::new(),
+        }
+    }
+
+    fn query_ids(&self) -> Vec<String> {
+        self.base.queries.clone()
+    }
+
+    fn auto_start(&self) -> bool {
+        self.base.get_auto_start()
+    }
+
+    async fn initialize(&self, context: drasi_lib::context::ReactionRuntimeContext) {
+        self.base.initialize(context).await;
+    }
+
+    async fn start(&self) -> Result<()> {
+        log_component_start("PostgreSQL Sink Reaction", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Starting,
+                Some("Starting PostgreSQL sink reaction".to_string()),
+            )
+            .await;
+
+        // Connect in start so an identity provider from the runtime context is available
+        let identity_provider = self.base.identity_provider().await;
+        let connected = async {
+            let writer = PostgresWriter::new(&self.config, identity_provider).await?;
+            writer.test_connection().await?;
+            anyhow::Ok(Arc::new(writer))
+        };
+        let writer = match connected.await {
+            Ok(writer) => writer,
+            Err(e) => {
+                self.base
+                    .set_status(
+                        ComponentStatus::Error,
+                        Some(format!("Failed to connect to PostgreSQL: {e}")),
+                    )
+                    .await;
+                return Err(e);
+            }
+        };
+        *self.writer.write().await = Some(writer.clone());
+
+        self.base
+            .set_status(
+                ComponentStatus::Running,
+                Some("PostgreSQL sink reaction started".to_string()),
+            )
+            .await;
+
+        let priority_queue = self.base.priority_queue.clone();
+        let reaction_id = self.base.id.clone();
+        let config = self.config.clone();
+        let batch_timeout = Duration::from_millis(config.batch_timeout_ms);
+        let mut shutdown_rx = self.base.create_shutdown_channel().await;
+
+        let processing_task = tokio::spawn(async move {
+            info!("[{reaction_id}] PostgreSQL sink processing task started");
+
+            loop {
+                let first = tokio::select! {
+                    biased;
+
+                    _ = &mut shutdown_rx => {
+                        debug!("[{reaction_id}] Received shutdown signal, exiting processing loop");
+                        break;
+                    }
+
+                    result = priority_queue.dequeue() => result,
+                };
+
+                // Collect more results until the batch is full or the batch timeout expires
+                let mut size = first.results.len();
+                let mut results = vec![first];
+                let deadline = tokio::time::Instant::now() + batch_timeout;
+                while size < config.batch_size {
+                    match tokio::time::timeout_at(deadline, priority_queue.dequeue()).await {
+                        Ok(result) => {
+                            size += result.results.len();
+                            results.push(result);
+                        }
+                        Err(_) => break,
+                    }
+                }
+
+                let batches = PostgresSinkReaction::build_batches(&config, &results, &reaction_id);
+                if batches.is_empty() {
+                    continue;
+                }
+
+                match writer.write(&batches).await {
+                    Ok(()) => debug!(
+                        "[{reaction_id}] Wrote {} results to {} tables",
+                        results.len(),
+                        batches.len()
+                    ),
+                    Err(e) => error!(
+                        "[{reaction_id}] Failed to write batch of {} results: {e}",
+                        results.len()
+                    ),
+                }
+            }
+
+            info!("[{reaction_id}] PostgreSQL sink processing task ended");
+        });
+
+        self.base.set_processing_task(processing_task).await;
+
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        self.base.stop_common().await?;
+        *self.writer.write().await = None;
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopped,
+                Some("PostgreSQL sink reaction stopped".to_string()),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn status(&self) -> ComponentStatus {
+        self.base.get_status().await
+    }
+
+    async fn enqueue_query_result(&self, result: QueryResult) -> anyhow::Result<()> {
+        self.base.enqueue_query_result(result).await
+    }
+}
+
+/// Builder for PostgresSinkReaction
+pub struct PostgresSinkReactionBuilder {
+    id: String,
+    queries: Vec<String>,
+    config: PostgresSinkReactionConfig,
+    priority_queue_capacity: Option<usize>,
+    auto_start: bool,
+}
+
+impl PostgresSinkReactionBuilder {
+    /// Create a new builder with the given reaction ID
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            queries: Vec::new(),
+            config: PostgresSinkReactionConfig::default(),
+            priority_queue_capacity: None,
+            auto_start: true,
+        }
+    }
+
+    /// Set the database connection parameters
+    pub fn with_connection(
+        mut self,
+        hostname: impl Into<String>,
+        port: u16,
+        database: impl Into<String>,
+        user: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        self.config.hostname = hostname.into();
+        self.config.port = Some(port);
+        self.config.database = database.into();
+        self.config.user = user.into();
+        self.config.password = password.into();
+        self
+    }
+
+    /// Set the database hostname
+    pub fn with_hostname(mut self, hostname: impl Into<String>) -> Self {
+        self.config.hostname = hostname.into();
+        self
+    }
+
+    /// Set the database port
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.config.port = Some(port);
+        self
+    }
+
+    /// Set the database name
+    pub fn with_database(mut self, database: impl Into<String>) -> Self {
+        self.config.database = database.into();
+        self
+    }
+
+    /// Set the database user
+    pub fn with_user(mut self, user: impl Into<String>) -> Self {
+        self.config.user = user.into();
+        self
+    }
+
+    /// Set the database password
+    pub fn with_password(mut self, password: impl Into<String>) -> Self {
+        self.config.password = password.into();
+        self
+    }
+
+    /// Set the identity provider for authentication
+    ///
+    /// This takes precedence over `with_user` and `with_password`.
+    pub fn with_identity_provider(
+        mut self,
+        provider: impl drasi_lib::identity::IdentityProvider + 'static,
+    ) -> Self {
+        self.config.identity_provider = Some(Box::new(provider));
+        self
+    }
+
+    /// Enable or disable SSL/TLS
+    pub fn with_ssl(mut self, enable: bool) -> Self {
+        self.config.ssl = enable;
+        self
+    }
+
+    /// Set the schema containing the target tables
+    pub fn with_schema(mut self, schema: impl Into<String>) -> Self {
+        self.config.schema = schema.into();
+        self
+    }
+
+    /// Set the default key columns
+    pub fn with_key_columns(mut self, columns: Vec<String>) -> Self {
+        self.config.key_columns = columns;
+        self
+    }
+
+    /// Map a query to a table with its own key columns
+    pub fn with_table(
+        mut self,
+        query_id: impl Into<String>,
+        table: impl Into<String>,
+        key_columns: Vec<String>,
+    ) -> Self {
+        self.config.tables.insert(
+            query_id.into(),
+            TableMapping {
+                table: Some(table.into()),
+                key_columns,
+            },
+        );
+        self
+    }
+
+    /// Set the table mapping for a query
+    pub fn with_table_mapping(
+        mut self,
+        query_id: impl Into<String>,
+        mapping: TableMapping,
+    ) -> Self {
+        self.config.tables.insert(query_id.into(), mapping);
+        self
+    }
+
+    /// Set how conflicting writes are handled
+    pub fn with_conflict_strategy(mut self, conflict: ConflictStrategy) -> Self {
+        self.config.conflict = conflict;
+        self
+    }
+
+    /// Enable or disable automatic table and column creation
+    pub fn with_create_table(mut self, create_table: bool) -> Self {
+        self.config.create_table = create_table;
+        self
+    }
+
+    /// Set the maximum number of result changes per transaction
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.config.batch_size = batch_size;
+        self
+    }
+
+    /// Set how long to wait for more results before writing a partial batch
+    pub fn with_batch_timeout_ms(mut self, timeout_ms: u64) -> Self {
+        self.config.batch_timeout_ms = timeout_ms;
+        self
+    }
+
+    /// Add a query to subscribe to
+    pub fn with_query(mut self, query_id: impl Into<String>) -> Self {
+        self.queries.push(query_id.into());
+        self
+    }
+
+    /// Set all queries to subscribe to
+    pub fn with_queries(mut self, queries: Vec<String>) -> Self {
+        self.queries = queries;
+        self
+    }
+
+    /// Set the command timeout in milliseconds
+    pub fn with_command_timeout_ms(mut self, timeout_ms: u64) -> Self {
+        self.config.command_timeout_ms = timeout_ms;
+        self
+    }
+
+    /// Set the number of retry attempts for a failed batch
+    pub fn with_retry_attempts(mut self, attempts: u32) -> Self {
+        self.config.retry_attempts = attempts;
+        self
+    }
+
+    /// Set the priority queue capacity
+    pub fn with_priority_queue_capacity(mut self, capacity: usize) -> Self {
+        self.priority_queue_capacity = Some(capacity);
+        self
+    }
+
+    /// Set whether the reaction should auto-start
+    pub fn with_auto_start(mut self, auto_start: bool) -> Self {
+        self.auto_start = auto_start;
+        self
+    }
+
+    /// Set the full configuration at once
+    pub fn with_config(mut self, config: PostgresSinkReactionConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Build the PostgresSinkReaction
+    pub fn build(self) -> Result<PostgresSinkReaction> {
+        PostgresSinkReaction::from_builder(
+            self.id,
+            self.queries,
+            self.config,
+            self.priority_queue_capacity,
+            self.auto_start,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::changes::RowChange;
+    use serde_json::json;
+
+    fn result(query_id: &str, results: Vec<ResultDiff>) -> Arc<QueryResult> {
+        Arc::new(QueryResult::new(
+            query_id.to_string(),
+            chrono::Utc::now(),
+            results,
+            HashMap::new(),
+        ))
+    }
+
+    fn builder() -> PostgresSinkReactionBuilder {
+        PostgresSinkReaction::builder("sink")
+            .with_connection("localhost", 5432, "db", "user", "secret")
+            .with_key_columns(vec!["id".to_string()])
+    }
+
+    #[test]
+    fn test_builder_validation() {
+        assert!(builder().with_query("orders").build().is_ok());
+        assert!(builder().with_batch_size(0).build().is_err());
+        assert!(builder().with_database("").build().is_err());
+
+        // Every query needs key columns
+        let no_keys = PostgresSinkReaction::builder("sink")
+            .with_connection("localhost", 5432, "db", "user", "secret")
+            .with_query("orders")
+            .build();
+        assert!(no_keys.is_err());
+
+        // Table mappings must match a subscribed query
+        let unmatched = builder()
+            .with_query("orders")
+            .with_table("other", "other_table", vec![])
+            .build();
+        assert!(unmatched.is_err());
+    }
+
+    #[test]
+    fn test_properties_hide_password() {
+        let reaction = builder().with_query("orders").build().unwrap();
+        assert_eq!(reaction.type_name(), "sink-postgres");
+        let props = reaction.properties();
+        assert!(!props.contains_key("password"));
+        assert_eq!(props.get("user"), Some(&json!("user")));
+        assert_eq!(props.get("conflict"), Some(&json!("upsert")));
+        assert_eq!(props.get("key_columns"), Some(&json!(["id"])));
+    }
+
+    #[test]
+    fn test_build_batches_groups_by_table() {
+        let config = PostgresSinkReactionConfig {
+            key_columns: vec!["id".to_string()],
+            tables: HashMap::from([(
+                "customers".to_string(),
+                TableMapping {
+                    table: Some("customer_view".to_string()),
+                    key_columns: vec!["customer_id".to_string()],
+                },
+            )]),
+            ..Default::default()
+        };
+
+        let results = vec![
+            result(
+                "orders",
+                vec![
+                    ResultDiff::Add {
+                        data: json!({"id": 1, "total": 10}),
+                    },
+                    ResultDiff::Add {
+                        data: json!({"total": 5}),
+                    },
+                    ResultDiff::Noop,
+                ],
+            ),
+            result(
+                "source.customers",
+                vec![ResultDiff::Aggregation {
+                    before: None,
+                    after: json!({"customer_id": "c1", "orders": 1}),
+                }],
+            ),
+            result(
+                "orders",
+                vec![ResultDiff::Update {
+                    data: json!({}),
+                    before: json!({"id": 1, "total": 10}),
+                    after: json!({"id": 1, "total": 12}),
+                    grouping_keys: None,
+                }],
+            ),
+            result("empty", vec![ResultDiff::Noop]),
+        ];
+
+        let batches = PostgresSinkReaction::build_batches(&config, &results, "sink");
+        assert_eq!(batches.len(), 2);
+
+        assert_eq!(batches[0].target.table, "orders");
+        let orders: Vec<_> = batches[0].changes.changes().cloned().collect();
+        assert_eq!(
+            orders,
+            vec![RowChange::Insert(
+                json!({"id": 1, "total": 12}).as_object().unwrap().clone()
+            )]
+        );
+
+        assert_eq!(batches[1].target.table, "customer_view");
+        assert_eq!(batches[1].target.key_columns, vec!["customer_id"]);
+    }
+}
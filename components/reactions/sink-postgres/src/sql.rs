@@ -0,0 +1,280 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! SQL generation for the PostgreSQL sink.
+//!
+//! Rows are passed as a single JSONB array parameter and expanded with
+//! `jsonb_populate_recordset`, so PostgreSQL converts each value to the type
+//! of its target column and a whole batch is written by one statement.
+
+use serde_json::Value;
+
+use crate::changes::Row;
+use crate::config::ConflictStrategy;
+
+/// Quote an identifier, doubling embedded quotes.
+pub(crate) fn quote_ident(ident: &str) -> String {
+    format!("\"{}\"", ident.replace('"', "\"\""))
+}
+
+/// Schema-qualified, quoted table name.
+pub(crate) fn qualified_table(schema: &str, table: &str) -> String {
+    format!("{}.{}", quote_ident(schema), quote_ident(table))
+}
+
+/// PostgreSQL column type for a JSON value.
+pub(crate) fn column_type(value: &Value) -> &'static str {
+    match value {
+        Value::Bool(_) => "BOOLEAN",
+        Value::Number(n) if n.is_i64() || n.is_u64() => "BIGINT",
+        Value::Number(_) => "DOUBLE PRECISION",
+        Value::Object(_) | Value::Array(_) => "JSONB",
+        Value::String(_) | Value::Null => "TEXT",
+    }
+}
+
+/// Infer column types from rows, in first-seen column order.
+///
+/// The type of a column comes from its first non-null value; columns that are
+/// null in every row are `TEXT`.
+pub(crate) fn infer_columns<'a>(
+    rows: impl IntoIterator<Item = &'a Row>,
+) -> Vec<(String, &'static str)> {
+    let mut columns: Vec<(String, &'static str, bool)> = Vec::new();
+    for row in rows {
+        for (name, value) in row {
+            match columns.iter_mut().find(|(column, _, _)| column == name) {
+                Some((_, ty, seen)) => {
+                    if !*seen && !value.is_null() {
+                        *ty = column_type(value);
+                        *seen = true;
+                    }
+                }
+                None => columns.push((name.clone(), column_type(value), !value.is_null())),
+            }
+        }
+    }
+    columns
+        .into_iter()
+        .map(|(name, ty, _)| (name, ty))
+        .collect()
+}
+
+/// `CREATE TABLE IF NOT EXISTS` with the key columns as primary key.
+pub(crate) fn create_table(
+    table: &str,
+    columns: &[(String, &'static str)],
+    key_columns: &[String],
+) -> String {
+    let definitions: Vec<String> = columns
+        .iter()
+        .map(|(name, ty)| format!("{} {ty}", quote_ident(name)))
+        .collect();
+    let keys: Vec<String> = key_columns.iter().map(|k| quote_ident(k)).collect();
+    format!(
+        "CREATE TABLE IF NOT EXISTS {table} ({}, PRIMARY KEY ({}))",
+        definitions.join(", "),
+        keys.join(", ")
+    )
+}
+
+/// `ALTER TABLE ... ADD COLUMN IF NOT EXISTS` for a new column.
+pub(crate) fn add_column(table: &str, column: &str, ty: &str) -> String {
+    format!(
+        "ALTER TABLE {table} ADD COLUMN IF NOT EXISTS {} {ty}",
+        quote_ident(column)
+    )
+}
+
+/// Join condition matching table rows (`t`) to batch rows (`d`) by key.
+fn key_condition(key_columns: &[String]) -> String {
+    key_columns
+        .iter()
+        .map(|k| {
+            let k = quote_ident(k);
+            format!("t.{k} = d.{k}")
+        })
+        .collect::<Vec<_>>()
+        .join(" AND ")
+}
+
+/// Delete every row whose key appears in the `$1` array.
+pub(crate) fn delete_rows(table: &str, key_columns: &[String]) -> String {
+    format!(
+        "DELETE FROM {table} AS t USING jsonb_populate_recordset(NULL::{table}, $1) AS d WHERE {}",
+        key_condition(key_columns)
+    )
+}
+
+/// Insert the rows of the `$1` array, resolving key conflicts per strategy.
+pub(crate) fn insert_rows(
+    table: &str,
+    columns: &[String],
+    key_columns: &[String],
+    conflict: ConflictStrategy,
+) -> String {
+    let column_list = columns
+        .iter()
+        .map(|c| quote_ident(c))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let keys = key_columns
+        .iter()
+        .map(|k| quote_ident(k))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let assignments: Vec<String> = columns
+        .iter()
+        .filter(|c| !key_columns.contains(c))
+        .map(|c| {
+            let c = quote_ident(c);
+            format!("{c} = EXCLUDED.{c}")
+        })
+        .collect();
+
+    let on_conflict = match conflict {
+        ConflictStrategy::Upsert if !assignments.is_empty() => format!(
+            " ON CONFLICT ({keys}) DO UPDATE SET {}",
+            assignments.join(", ")
+        ),
+        ConflictStrategy::Upsert | ConflictStrategy::Ignore => {
+            format!(" ON CONFLICT ({keys}) DO NOTHING")
+        }
+        ConflictStrategy::Error => String::new(),
+    };
+
+    format!(
+        "INSERT INTO {table} ({column_list}) SELECT {column_list} FROM jsonb_populate_recordset(NULL::{table}, $1){on_conflict}"
+    )
+}
+
+/// Update existing rows from the `$1` array, matched by key.
+pub(crate) fn update_rows(table: &str, columns: &[String], key_columns: &[String]) -> String {
+    let mut assignments: Vec<String> = columns
+        .iter()
+        .filter(|c| !key_columns.contains(c))
+        .map(|c| {
+            let c = quote_ident(c);
+            format!("{c} = d.{c}")
+        })
+        .collect();
+    if assignments.is_empty() {
+        // Key-only tables: a no-op assignment still reports matched rows.
+        let k = quote_ident(&key_columns[0]);
+        assignments.push(format!("{k} = d.{k}"));
+    }
+    format!(
+        "UPDATE {table} AS t SET {} FROM jsonb_populate_recordset(NULL::{table}, $1) AS d WHERE {}",
+        assignments.join(", "),
+        key_condition(key_columns)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn keys() -> Vec<String> {
+        vec!["id".to_string()]
+    }
+
+    fn columns() -> Vec<String> {
+        vec!["id".to_string(), "name".to_string()]
+    }
+
+    #[test]
+    fn test_quote_ident() {
+        assert_eq!(quote_ident("name"), "\"name\"");
+        assert_eq!(quote_ident("we\"ird"), "\"we\"\"ird\"");
+        assert_eq!(qualified_table("public", "Orders"), "\"public\".\"Orders\"");
+    }
+
+    #[test]
+    fn test_infer_columns() {
+        let rows = [
+            json!({"id": 1, "name": null, "price": 1.5}),
+            json!({"id": 2, "name": "b", "active": true, "tags": ["x"]}),
+        ];
+        let rows: Vec<Row> = rows
+            .iter()
+            .map(|r| r.as_object().unwrap().clone())
+            .collect();
+
+        assert_eq!(
+            infer_columns(&rows),
+            vec![
+                ("id".to_string(), "BIGINT"),
+                ("name".to_string(), "TEXT"),
+                ("price".to_string(), "DOUBLE PRECISION"),
+                ("active".to_string(), "BOOLEAN"),
+                ("tags".to_string(), "JSONB"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_create_table() {
+        let sql = create_table(
+            "\"public\".\"t\"",
+            &[("id".to_string(), "BIGINT"), ("name".to_string(), "TEXT")],
+            &keys(),
+        );
+        assert_eq!(
+            sql,
+            "CREATE TABLE IF NOT EXISTS \"public\".\"t\" (\"id\" BIGINT, \"name\" TEXT, PRIMARY KEY (\"id\"))"
+        );
+        assert_eq!(
+            add_column("\"public\".\"t\"", "price", "DOUBLE PRECISION"),
+            "ALTER TABLE \"public\".\"t\" ADD COLUMN IF NOT EXISTS \"price\" DOUBLE PRECISION"
+        );
+    }
+
+    #[test]
+    fn test_insert_rows_conflict_clauses() {
+        let table = "\"public\".\"t\"";
+        let base = "INSERT INTO \"public\".\"t\" (\"id\", \"name\") SELECT \"id\", \"name\" FROM jsonb_populate_recordset(NULL::\"public\".\"t\", $1)";
+
+        assert_eq!(
+            insert_rows(table, &columns(), &keys(), ConflictStrategy::Upsert),
+            format!("{base} ON CONFLICT (\"id\") DO UPDATE SET \"name\" = EXCLUDED.\"name\"")
+        );
+        assert_eq!(
+            insert_rows(table, &columns(), &keys(), ConflictStrategy::Ignore),
+            format!("{base} ON CONFLICT (\"id\") DO NOTHING")
+        );
+        assert_eq!(
+            insert_rows(table, &columns(), &keys(), ConflictStrategy::Error),
+            base
+        );
+        // Nothing to update on key-only tables
+        assert!(
+            insert_rows(table, &keys(), &keys(), ConflictStrategy::Upsert)
+                .ends_with("ON CONFLICT (\"id\") DO NOTHING")
+        );
+    }
+
+    #[test]
+    fn test_update_and_delete_rows() {
+        let table = "\"public\".\"t\"";
+        assert_eq!(
+            update_rows(table, &columns(), &keys()),
+            "UPDATE \"public\".\"t\" AS t SET \"name\" = d.\"name\" FROM jsonb_populate_recordset(NULL::\"public\".\"t\", $1) AS d WHERE t.\"id\" = d.\"id\""
+        );
+        assert_eq!(
+            delete_rows(table, &["a".to_string(), "b".to_string()]),
+            "DELETE FROM \"public\".\"t\" AS t USING jsonb_populate_recordset(NULL::\"public\".\"t\", $1) AS d WHERE t.\"a\" = d.\"a\" AND t.\"b\" = d.\"b\""
+        );
+    }
+}
@@ -0,0 +1,469 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! PostgreSQL writer that applies coalesced changes to the target tables.
+
+use anyhow::{anyhow, Result};
+use log::{debug, info, warn};
+use postgres_native_tls::MakeTlsConnector;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::timeout;
+use tokio_postgres::{Client, GenericClient, NoTls};
+
+use crate::changes::{Row, RowChange, TableChanges};
+use crate::config::{ConflictStrategy, PostgresSinkReactionConfig, TableTarget};
+use crate::sql;
+
+/// Changes for one table within a batch.
+#[derive(Debug)]
+pub(crate) struct TableBatch {
+    pub target: TableTarget,
+    pub changes: TableChanges,
+}
+
+/// Writes batches of row changes to PostgreSQL.
+pub(crate) struct PostgresWriter {
+    client: Mutex<Client>,
+    schema: String,
+    conflict: ConflictStrategy,
+    create_table: bool,
+    command_timeout: Duration,
+    retry_attempts: u32,
+    /// Known columns per qualified table name.
+    columns: Mutex<HashMap<String, HashSet<String>>>,
+}
+
+impl PostgresWriter {
+    /// Connect to the database
+    ///
+    /// The `identity_provider` parameter allows injecting a credential provider
+    /// from the runtime context. If provided, it takes precedence over the
+    /// config's identity_provider. Falls back to config's user/password if neither is set.
+    pub(crate) async fn new(
+        config: &PostgresSinkReactionConfig,
+        identity_provider: Option<Arc<dyn drasi_lib::identity::IdentityProvider>>,
+    ) -> Result<Self> {
+        let port = config.get_port();
+
+        // Resolve credentials: injected provider > config provider > user/password
+        let effective_provider = identity_provider.as_ref().map(|p| p.as_ref());
+        let config_provider = config.identity_provider.as_deref();
+        let provider = effective_provider.or(config_provider);
+
+        let credentials = if let Some(provider) = provider {
+            debug!("Using identity provider for authentication");
+            let context = drasi_lib::identity::CredentialContext::new()
+                .with_property("hostname", &config.hostname)
+                .with_property("port", port.to_string());
+            Some(provider.get_credentials(&context).await?)
+        } else {
+            None
+        };
+
+        let is_cert_auth = credentials.as_ref().is_some_and(|c| c.is_certificate());
+
+        // For username/password and token auth, extract the auth pair
+        let (username, password) = if let Some(creds) = &credentials {
+            if !creds.is_certificate() {
+                creds
+                    .clone()
+                    .try_into_auth_pair()
+                    .map_err(|_| anyhow!("Unexpected credential type"))?
+            } else {
+                // Certificate auth: username is optional, password is not used
+                let (_, _, cert_username) = creds
+                    .clone()
+                    .try_into_certificate()
+                    .map_err(|_| anyhow!("Expected certificate credentials"))?;
+                (cert_username.unwrap_or_default(), String::new())
+            }
+        } else {
+            debug!("Using username/password for authentication");
+            (config.user.clone(), config.password.clone())
+        };
+
+        // Build connection string
+        let ssl_mode = if config.ssl || is_cert_auth {
+            "require"
+        } else {
+            "disable"
+        };
+
+        // Log connection attempt (without password)
+        debug!(
+            "Connection details - host: {}, port: {}, user: {}, database: {}, ssl: {}, cert_auth: {}",
+            config.hostname, port, username, config.database, ssl_mode, is_cert_auth
+        );
+
+        let connection_string = format!(
+            "host={} port={} user={} password={} dbname={} sslmode={}",
+            config.hostname, port, username, password, config.database, ssl_mode
+        );
+
+        info!(
+            "Connecting to PostgreSQL: {}:{}/{} (SSL: {}, cert_auth: {})",
+            config.hostname,
+            port,
+            config.database,
+            config.ssl || is_cert_auth,
+            is_cert_auth
+        );
+
+        // Connect to database with appropriate TLS configuration
+        let client = if is_cert_auth {
+            // Client certificate authentication (mTLS)
+            let (cert_pem, key_pem, _) = credentials
+                .expect("credentials must exist when is_cert_auth is true")
+                .try_into_certificate()
+                .map_err(|_| anyhow!("Expected certificate credentials"))?;
+
+            let identity = native_tls::Identity::from_pkcs8(
+                cert_pem.as_bytes(),
+                key_pem.as_bytes(),
+            )
+            .map_err(|e| anyhow!("Failed to load client certificate: {e}. Ensure cert_pem and key_pem are valid PEM-encoded data."))?;
+
+            let tls_connector = native_tls::TlsConnector::builder()
+                .identity(identity)
+                .danger_accept_invalid_hostnames(false)
+                .danger_accept_invalid_certs(false)
+                .build()
+                .map_err(|e| {
+                    anyhow!("Failed to create TLS connector with client certificate: {e}")
+                })?;
+            let connector = MakeTlsConnector::new(tls_connector);
+
+            debug!("Attempting mTLS connection to PostgreSQL with client certificate...");
+            let (client, connection) = tokio_postgres::connect(&connection_string, connector)
+                .await
+                .map_err(|e| {
+                    log::error!("mTLS connection error: {e:?}");
+                    anyhow!("Failed to connect to database with client certificate: {e}")
+                })?;
+
+            // Spawn connection handler
+            tokio::spawn(async move {
+                if let Err(e) = connection.await {
+                    log::error!("PostgreSQL connection error: {e}");
+                }
+            });
+
+            client
+        } else if config.ssl {
+            // Server-only TLS (no client certificate)
+            let tls_connector = native_tls::TlsConnector::builder()
+                .danger_accept_invalid_hostnames(false)
+                .danger_accept_invalid_certs(false)
+                .build()
+                .map_err(|e| anyhow!("Failed to create TLS connector: {e}"))?;
+            let connector = MakeTlsConnector::new(tls_connector);
+
+            debug!("Attempting SSL connection to PostgreSQL with system trust store...");
+            let (client, connection) = tokio_postgres::connect(&connection_string, connector)
+                .await
+                .map_err(|e| {
+                    log::error!("SSL connection error: {e:?}");
+                    anyhow!("Failed to connect to database with SSL: {e}. Ensure SSL CA certificates are installed in system trust store.")
+                })?;
+
+            // Spawn connection handler
+            tokio::spawn(async move {
+                if let Err(e) = connection.await {
+                    log::error!("PostgreSQL connection error: {e}");
+                }
+            });
+
+            client
+        } else {
+            let (client, connection) = tokio_postgres::connect(&connection_string, NoTls)
+                .await
+                .map_err(|e| anyhow!("Failed to connect to database: {e}"))?;
+
+            // Spawn connection handler
+            tokio::spawn(async move {
+                if let Err(e) = connection.await {
+                    log::error!("PostgreSQL connection error: {e}");
+                }
+            });
+
+            client
+        };
+
+        info!(
+            "Connected to PostgreSQL: {}:{}/{}",
+            config.hostname, port, config.database
+        );
+
+        Ok(Self {
+            client: Mutex::new(client),
+            schema: config.schema.clone(),
+            conflict: config.conflict,
+            create_table: config.create_table,
+            command_timeout: Duration::from_millis(config.command_timeout_ms),
+            retry_attempts: config.retry_attempts,
+            columns: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Test the database connection
+    pub(crate) async fn test_connection(&self) -> Result<()> {
+        let client = self.client.lock().await;
+
+        timeout(self.command_timeout, client.simple_query("SELECT 1"))
+            .await
+            .map_err(|_| anyhow!("Connection test timed out"))?
+            .map_err(|e| anyhow!("Connection test failed: {e}"))?;
+
+        info!("Database connection test successful");
+        Ok(())
+    }
+
+    /// Apply a batch in a single transaction, retrying on failure.
+    pub(crate) async fn write(&self, batches: &[TableBatch]) -> Result<()> {
+        let mut last_error = None;
+
+        for attempt in 0..=self.retry_attempts {
+            if attempt > 0 {
+                // Use saturating_pow and saturating_mul to prevent overflow, and cap the backoff to 30 seconds.
+                let max_backoff = Duration::from_secs(30);
+                let exp = attempt - 1;
+                let backoff_millis = 100u64.saturating_mul(2u64.saturating_pow(exp));
+                let backoff = Duration::from_millis(backoff_millis).min(max_backoff);
+                debug!("Retrying after {backoff:?} (attempt {attempt})");
+                tokio::time::sleep(backoff).await;
+            }
+
+            match self.write_once(batches).await {
+                Ok(()) => return Ok(()),
+                Err(e) => {
+                    // The table may have changed underneath us; reload its columns.
+                    self.columns.lock().await.clear();
+                    last_error = Some(e);
+                    if attempt < self.retry_attempts {
+                        debug!("Attempt {} failed, retrying...", attempt + 1);
+                    }
+                }
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| anyhow!("Operation failed with no error")))
+    }
+
+    async fn write_once(&self, batches: &[TableBatch]) -> Result<()> {
+        let mut client = self.client.lock().await;
+
+        // DDL runs before the transaction so a rolled-back batch never leaves
+        // the column cache out of step with the database.
+        let mut table_columns = Vec::with_capacity(batches.len());
+        for batch in batches {
+            let columns = self.ensure_table(&*client, batch).await?;
+            table_columns.push(columns);
+        }
+
+        let transaction = client.transaction().await?;
+        for (batch, columns) in batches.iter().zip(&table_columns) {
+            self.apply(&transaction, batch, columns).await?;
+        }
+        timeout(self.command_timeout, transaction.commit())
+            .await
+            .map_err(|_| anyhow!("Commit timed out after {:?}", self.command_timeout))??;
+        Ok(())
+    }
+
+    /// Make sure the table exists with every column present in the batch.
+    ///
+    /// Returns the table columns. Columns the table does not have are dropped
+    /// from writes unless `create_table` is enabled, in which case they are added.
+    async fn ensure_table(
+        &self,
+        client: &impl GenericClient,
+        batch: &TableBatch,
+    ) -> Result<HashSet<String>> {
+        let table = sql::qualified_table(&self.schema, &batch.target.table);
+        let mut cache = self.columns.lock().await;
+
+        let mut existing = match cache.get(&table) {
+            Some(columns) => columns.clone(),
+            None => self.load_columns(client, &batch.target.table).await?,
+        };
+
+        let rows: Vec<&Row> = batch.changes.changes().map(change_row).collect();
+        let inferred = sql::infer_columns(rows.iter().copied());
+
+        if existing.is_empty() {
+            if !self.create_table {
+                return Err(anyhow!(
+                    "Table {table} does not exist and create_table is disabled"
+                ));
+            }
+            for key in &batch.target.key_columns {
+                if !inferred.iter().any(|(column, _)| column == key) {
+                    return Err(anyhow!(
+                        "Cannot create table {table}: key column '{key}' is not in the results"
+                    ));
+                }
+            }
+            let statement = sql::create_table(&table, &inferred, &batch.target.key_columns);
+            info!("Creating table {table}");
+            self.execute(client, &statement).await?;
+            existing = inferred.iter().map(|(column, _)| column.clone()).collect();
+        } else {
+            for (column, ty) in &inferred {
+                if existing.contains(column) {
+                    continue;
+                }
+                if self.create_table {
+                    info!("Adding column {column} {ty} to {table}");
+                    self.execute(client, &sql::add_column(&table, column, ty))
+                        .await?;
+                    existing.insert(column.clone());
+                } else {
+                    warn!("Column '{column}' does not exist in {table} and will not be written");
+                }
+            }
+        }
+
+        cache.insert(table, existing.clone());
+        Ok(existing)
+    }
+
+    async fn load_columns(
+        &self,
+        client: &impl GenericClient,
+        table: &str,
+    ) -> Result<HashSet<String>> {
+        let rows = timeout(
+            self.command_timeout,
+            client.query(
+                "SELECT column_name FROM information_schema.columns WHERE table_schema = $1 AND table_name = $2",
+                &[&self.schema, &table],
+            ),
+        )
+        .await
+        .map_err(|_| anyhow!("Loading columns of {table} timed out"))??;
+        Ok(rows.iter().map(|row| row.get::<_, String>(0)).collect())
+    }
+
+    /// Write one table's changes: deletes first, then inserts, then updates.
+    async fn apply(
+        &self,
+        client: &impl GenericClient,
+        batch: &TableBatch,
+        columns: &HashSet<String>,
+    ) -> Result<()> {
+        let table = sql::qualified_table(&self.schema, &batch.target.table);
+        let keys = &batch.target.key_columns;
+
+        let mut deletes = Vec::new();
+        let mut inserts = Vec::new();
+        let mut updates = Vec::new();
+        for change in batch.changes.changes() {
+            match change {
+                RowChange::Insert(row) => inserts.push(row),
+                RowChange::Update(row) if self.conflict == ConflictStrategy::Upsert => {
+                    inserts.push(row)
+                }
+                RowChange::Update(row) => updates.push(row),
+                RowChange::Delete(row) => deletes.push(row),
+                RowChange::Replace(row) => {
+                    deletes.push(row);
+                    inserts.push(row);
+                }
+            }
+        }
+
+        if !deletes.is_empty() {
+            let removed = self
+                .execute_rows(client, &sql::delete_rows(&table, keys), &deletes)
+                .await?;
+            debug!("Deleted {removed} rows from {table}");
+        }
+
+        if !inserts.is_empty() {
+            let written = written_columns(&inserts, columns);
+            let statement = sql::insert_rows(&table, &written, keys, self.conflict);
+            let inserted = self.execute_rows(client, &statement, &inserts).await?;
+            debug!("Inserted {inserted} of {} rows into {table}", inserts.len());
+        }
+
+        if !updates.is_empty() {
+            let written = written_columns(&updates, columns);
+            let statement = sql::update_rows(&table, &written, keys);
+            let updated = self.execute_rows(client, &statement, &updates).await?;
+            if self.conflict == ConflictStrategy::Error && updated != updates.len() as u64 {
+                return Err(anyhow!(
+                    "Expected to update {} rows in {table} but {updated} matched",
+                    updates.len()
+                ));
+            }
+            debug!("Updated {updated} of {} rows in {table}", updates.len());
+        }
+
+        Ok(())
+    }
+
+    /// Execute a DDL statement.
+    async fn execute(&self, client: &impl GenericClient, statement: &str) -> Result<()> {
+        timeout(self.command_timeout, client.execute(statement, &[]))
+            .await
+            .map_err(|_| anyhow!("Statement timed out after {:?}", self.command_timeout))?
+            .map_err(|e| anyhow!("Failed to execute '{statement}': {e}"))?;
+        Ok(())
+    }
+
+    /// Execute a row statement with `rows` bound to `$1` as a JSONB array.
+    async fn execute_rows(
+        &self,
+        client: &impl GenericClient,
+        statement: &str,
+        rows: &[&Row],
+    ) -> Result<u64> {
+        let rows = Value::Array(
+            rows.iter()
+                .map(|row| Value::Object((*row).clone()))
+                .collect(),
+        );
+        timeout(self.command_timeout, client.execute(statement, &[&rows]))
+            .await
+            .map_err(|_| anyhow!("Statement timed out after {:?}", self.command_timeout))?
+            .map_err(|e| anyhow!("Failed to execute '{statement}': {e}"))
+    }
+}
+
+fn change_row(change: &RowChange) -> &Row {
+    match change {
+        RowChange::Insert(row)
+        | RowChange::Update(row)
+        | RowChange::Delete(row)
+        | RowChange::Replace(row) => row,
+    }
+}
+
+/// Columns present in any of the rows that also exist in the table, sorted for stable SQL.
+fn written_columns(rows: &[&Row], table_columns: &HashSet<String>) -> Vec<String> {
+    let mut columns: Vec<String> = rows
+        .iter()
+        .flat_map(|row| row.keys())
+        .filter(|column| table_columns.contains(*column))
+        .cloned()
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    columns.sort();
+    columns
+}
@@ -0,0 +1,300 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the PostgreSQL sink reaction
+//!
+//! These tests run the reaction against a real PostgreSQL database provided
+//! by testcontainers and check the mirrored table contents.
+
+mod postgres_helpers;
+
+use drasi_lib::channels::{QueryResult, ResultDiff};
+use drasi_lib::Reaction;
+use drasi_reaction_sink_postgres::{ConflictStrategy, PostgresSinkReaction};
+use postgres_helpers::{setup_postgres, PostgresConfig};
+use serde_json::{json, Value};
+use serial_test::serial;
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::time::sleep;
+
+// ============================================================================
+// Test Helper Functions
+// ============================================================================
+
+fn init_logging() {
+    env_logger::builder()
+        .is_test(true)
+        .filter_level(log::LevelFilter::Debug)
+        .try_init()
+        .ok();
+}
+
+fn builder(
+    pg_config: &PostgresConfig,
+) -> drasi_reaction_sink_postgres::PostgresSinkReactionBuilder {
+    PostgresSinkReaction::builder("sink-reaction")
+        .with_connection(
+            &pg_config.host,
+            pg_config.port,
+            &pg_config.database,
+            &pg_config.user,
+            &pg_config.password,
+        )
+        .with_query("orders")
+        .with_key_columns(vec!["id".to_string()])
+        .with_batch_timeout_ms(20)
+        .with_auto_start(false)
+}
+
+fn result(results: Vec<ResultDiff>) -> QueryResult {
+    QueryResult::new(
+        "orders".to_string(),
+        chrono::Utc::now(),
+        results,
+        HashMap::new(),
+    )
+}
+
+async fn execute(pg_config: &PostgresConfig, statement: &str) {
+    let (client, connection) =
+        tokio_postgres::connect(&pg_config.connection_string(), tokio_postgres::NoTls)
+            .await
+            .expect("Failed to connect to database");
+    tokio::spawn(async move {
+        if let Err(e) = connection.await {
+            eprintln!("Connection error: {e}");
+        }
+    });
+    client
+        .batch_execute(statement)
+        .await
+        .expect("Failed to execute statement");
+}
+
+/// Rows of a table as JSON, ordered by id.
+async fn table_rows(pg_config: &PostgresConfig, table: &str) -> Vec<Value> {
+    let (client, connection) =
+        tokio_postgres::connect(&pg_config.connection_string(), tokio_postgres::NoTls)
+            .await
+            .expect("Failed to connect to database");
+    tokio::spawn(async move {
+        if let Err(e) = connection.await {
+            eprintln!("Connection error: {e}");
+        }
+    });
+
+    let query = format!("SELECT to_jsonb(t) FROM {table} t ORDER BY id");
+    match client.query(query.as_str(), &[]).await {
+        Ok(rows) => rows.iter().map(|row| row.get::<_, Value>(0)).collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Poll a table until it has the expected rows or the timeout expires.
+async fn wait_for_rows(pg_config: &PostgresConfig, table: &str, expected: Vec<Value>) {
+    let mut rows = Vec::new();
+    for _ in 0..50 {
+        rows = table_rows(pg_config, table).await;
+        if rows == expected {
+            return;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+    assert_eq!(
+        rows, expected,
+        "Table {table} did not reach the expected rows"
+    );
+}
+
+// ============================================================================
+// Integration Tests
+// ============================================================================
+
+#[tokio::test]
+#[serial]
+async fn test_sink_creates_table_and_mirrors_changes() {
+    init_logging();
+
+    let pg = setup_postgres().await;
+    let pg_config = pg.config();
+
+    let reaction = builder(pg_config).build().expect("Should create reaction");
+    reaction.start().await.expect("Should start");
+    reaction.test_connection().await.expect("Should connect");
+
+    reaction
+        .enqueue_query_result(result(vec![
+            ResultDiff::Add {
+                data: json!({"id": 1, "customer": "alice", "total": 10.5}),
+            },
+            ResultDiff::Add {
+                data: json!({"id": 2, "customer": "bob", "total": 20.5}),
+            },
+        ]))
+        .await
+        .unwrap();
+
+    wait_for_rows(
+        pg_config,
+        "orders",
+        vec![
+            json!({"id": 1, "customer": "alice", "total": 10.5}),
+            json!({"id": 2, "customer": "bob", "total": 20.5}),
+        ],
+    )
+    .await;
+
+    reaction
+        .enqueue_query_result(result(vec![
+            ResultDiff::Update {
+                data: json!({}),
+                before: json!({"id": 1, "customer": "alice", "total": 10.5}),
+                after: json!({"id": 1, "customer": "alice", "total": 15.5}),
+                grouping_keys: None,
+            },
+            ResultDiff::Delete {
+                data: json!({"id": 2, "customer": "bob", "total": 20.5}),
+            },
+            ResultDiff::Add {
+                data: json!({"id": 3, "customer": "carol", "total": 5.5, "priority": true}),
+            },
+        ]))
+        .await
+        .unwrap();
+
+    // The new column is added to the table; existing rows get NULL
+    wait_for_rows(
+        pg_config,
+        "orders",
+        vec![
+            json!({"id": 1, "customer": "alice", "total": 15.5, "priority": null}),
+            json!({"id": 3, "customer": "carol", "total": 5.5, "priority": true}),
+        ],
+    )
+    .await;
+
+    reaction.stop().await.expect("Should stop");
+    pg.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_sink_writes_to_existing_table() {
+    init_logging();
+
+    let pg = setup_postgres().await;
+    let pg_config = pg.config();
+    execute(
+        pg_config,
+        "CREATE SCHEMA mirror;
+         CREATE TABLE mirror.order_view (id INTEGER PRIMARY KEY, total NUMERIC(10, 2));
+         INSERT INTO mirror.order_view VALUES (1, 1.00);",
+    )
+    .await;
+
+    let reaction = builder(pg_config)
+        .with_schema("mirror")
+        .with_table("orders", "order_view", vec![])
+        .with_create_table(false)
+        .build()
+        .expect("Should create reaction");
+    reaction.start().await.expect("Should start");
+
+    // Upsert overwrites the existing row; columns missing from the table are ignored
+    reaction
+        .enqueue_query_result(result(vec![
+            ResultDiff::Add {
+                data: json!({"id": 1, "total": 12.5, "note": "ignored"}),
+            },
+            ResultDiff::Add {
+                data: json!({"id": 2, "total": 3}),
+            },
+        ]))
+        .await
+        .unwrap();
+
+    wait_for_rows(
+        pg_config,
+        "mirror.order_view",
+        vec![
+            json!({"id": 1, "total": 12.50}),
+            json!({"id": 2, "total": 3.00}),
+        ],
+    )
+    .await;
+
+    reaction.stop().await.expect("Should stop");
+    pg.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_sink_conflict_error_rolls_back_batch() {
+    init_logging();
+
+    let pg = setup_postgres().await;
+    let pg_config = pg.config();
+    execute(
+        pg_config,
+        "CREATE TABLE orders (id BIGINT PRIMARY KEY, total DOUBLE PRECISION);
+         INSERT INTO orders VALUES (1, 1.5);",
+    )
+    .await;
+
+    let reaction = builder(pg_config)
+        .with_conflict_strategy(ConflictStrategy::Error)
+        .with_retry_attempts(0)
+        .build()
+        .expect("Should create reaction");
+    reaction.start().await.expect("Should start");
+
+    // The duplicate key fails the whole transaction, including the new row
+    reaction
+        .enqueue_query_result(result(vec![
+            ResultDiff::Add {
+                data: json!({"id": 2, "total": 2.5}),
+            },
+            ResultDiff::Add {
+                data: json!({"id": 1, "total": 9.5}),
+            },
+        ]))
+        .await
+        .unwrap();
+    sleep(Duration::from_millis(500)).await;
+    assert_eq!(
+        table_rows(pg_config, "orders").await,
+        vec![json!({"id": 1, "total": 1.5})]
+    );
+
+    // Later batches are still written
+    reaction
+        .enqueue_query_result(result(vec![ResultDiff::Add {
+            data: json!({"id": 3, "total": 3.5}),
+        }]))
+        .await
+        .unwrap();
+    wait_for_rows(
+        pg_config,
+        "orders",
+        vec![
+            json!({"id": 1, "total": 1.5}),
+            json!({"id": 3, "total": 3.5}),
+        ],
+    )
+    .await;
+
+    reaction.stop().await.expect("Should stop");
+    pg.cleanup().await;
+}
@@ -0,0 +1,110 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration types for the SNS/SQS reaction.
+
+use serde::{Deserialize, Serialize};
+
+/// Maximum number of entries of a `SendMessageBatch` or `PublishBatch` call.
+pub const MAX_BATCH_SIZE: usize = 10;
+
+fn default_batch_size() -> usize {
+    MAX_BATCH_SIZE
+}
+
+fn default_max_retries() -> u32 {
+    3
+}
+
+/// Where result changes are published.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SnsSqsDestination {
+    /// An SQS queue, identified by `queue_url` (default).
+    #[default]
+    Sqs,
+    /// An SNS topic, identified by `topic_arn`.
+    Sns,
+}
+
+/// SNS/SQS reaction configuration
+///
+/// Credentials and, unless `region` is set, the region come from the default
+/// AWS provider chain (environment, profile, instance or task role).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SnsSqsReactionConfig {
+    /// Where result changes are published
+    #[serde(default)]
+    pub destination: SnsSqsDestination,
+
+    /// URL of the SQS queue
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub queue_url: Option<String>,
+
+    /// ARN of the SNS topic
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub topic_arn: Option<String>,
+
+    /// AWS region. Falls back to the provider chain.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+
+    /// Endpoint override, e.g. for LocalStack
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub endpoint_url: Option<String>,
+
+    /// Result columns copied into message attributes
+    #[serde(default)]
+    pub attribute_fields: Vec<String>,
+
+    /// Result column used as message group ID of FIFO queues and topics.
+    /// Defaults to the query ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message_group_field: Option<String>,
+
+    /// Maximum number of messages per batch call, at most 10
+    #[serde(default = "default_batch_size")]
+    pub batch_size: usize,
+
+    /// Number of retries of failed batch calls and entries
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+}
+
+impl Default for SnsSqsReactionConfig {
+    fn default() -> Self {
+        Self {
+            destination: SnsSqsDestination::default(),
+            queue_url: None,
+            topic_arn: None,
+            region: None,
+            endpoint_url: None,
+            attribute_fields: Vec::new(),
+            message_group_field: None,
+            batch_size: default_batch_size(),
+            max_retries: default_max_retries(),
+        }
+    }
+}
+
+impl SnsSqsReactionConfig {
+    /// Whether the destination is a FIFO queue or topic
+    pub fn is_fifo(&self) -> bool {
+        let name = match self.destination {
+            SnsSqsDestination::Sqs => self.queue_url.as_deref(),
+            SnsSqsDestination::Sns => self.topic_arn.as_deref(),
+        };
+        name.is_some_and(|name| name.ends_with(".fifo"))
+    }
+}
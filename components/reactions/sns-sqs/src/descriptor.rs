@@ -0,0 +1,167 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Descriptor for the SNS/SQS reaction plugin.
+
+use drasi_lib::reactions::Reaction;
+use drasi_plugin_sdk::prelude::*;
+use utoipa::OpenApi;
+
+use crate::config::SnsSqsDestination;
+use crate::SnsSqsReactionBuilder;
+
+/// DTO for where result changes are published.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::sns_sqs::SnsSqsDestination)]
+#[serde(rename_all = "lowercase")]
+pub enum SnsSqsDestinationDto {
+    Sqs,
+    Sns,
+}
+
+impl From<SnsSqsDestinationDto> for SnsSqsDestination {
+    fn from(dto: SnsSqsDestinationDto) -> Self {
+        match dto {
+            SnsSqsDestinationDto::Sqs => SnsSqsDestination::Sqs,
+            SnsSqsDestinationDto::Sns => SnsSqsDestination::Sns,
+        }
+    }
+}
+
+/// Configuration DTO for the SNS/SQS reaction plugin.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = reaction::sns_sqs::SnsSqsReactionConfig)]
+#[serde(rename_all = "camelCase")]
+pub struct SnsSqsReactionConfigDto {
+    /// Where result changes are published.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination: Option<SnsSqsDestinationDto>,
+
+    /// URL of the SQS queue.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub queue_url: Option<ConfigValue<String>>,
+
+    /// ARN of the SNS topic.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub topic_arn: Option<ConfigValue<String>>,
+
+    /// AWS region.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub region: Option<ConfigValue<String>>,
+
+    /// Endpoint override, e.g. for LocalStack.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub endpoint_url: Option<ConfigValue<String>>,
+
+    /// Result columns copied into message attributes.
+    #[serde(default)]
+    pub attribute_fields: Vec<String>,
+
+    /// Result column used as message group ID of FIFO destinations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub message_group_field: Option<ConfigValue<String>>,
+
+    /// Maximum number of messages per batch call.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueUsize>)]
+    pub batch_size: Option<ConfigValue<usize>>,
+
+    /// Number of retries of failed batch calls and entries.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueU32>)]
+    pub max_retries: Option<ConfigValue<u32>>,
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(SnsSqsReactionConfigDto, SnsSqsDestinationDto,)))]
+struct SnsSqsReactionSchemas;
+
+/// Descriptor for the SNS/SQS reaction plugin.
+pub struct SnsSqsReactionDescriptor;
+
+#[async_trait]
+impl ReactionPluginDescriptor for SnsSqsReactionDescriptor {
+    fn kind(&self) -> &str {
+        "sns-sqs"
+    }
+
+    fn config_version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn config_schema_name(&self) -> &str {
+        "reaction.sns_sqs.SnsSqsReactionConfig"
+    }
+
+    fn config_schema_json(&self) -> String {
+        let api = SnsSqsReactionSchemas::openapi();
+        serde_json::to_string(
+            &api.components
+                .as_ref()
+                .expect("OpenAPI components missing")
+                .schemas,
+        )
+        .expect("Failed to serialize config schema")
+    }
+
+    async fn create_reaction(
+        &self,
+        id: &str,
+        query_ids: Vec<String>,
+        config_json: &serde_json::Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn Reaction>> {
+        let dto: SnsSqsReactionConfigDto = serde_json::from_value(config_json.clone())?;
+        let mapper = DtoMapper::new();
+
+        let mut builder = SnsSqsReactionBuilder::new(id)
+            .with_queries(query_ids)
+            .with_auto_start(auto_start)
+            .with_attribute_fields(dto.attribute_fields.clone());
+
+        if let Some(queue_url) = mapper.resolve_optional_string(&dto.queue_url)? {
+            builder = builder.with_queue_url(queue_url);
+        }
+        if let Some(topic_arn) = mapper.resolve_optional_string(&dto.topic_arn)? {
+            builder = builder.with_topic_arn(topic_arn);
+        }
+        // An explicit destination wins over the one implied by the queue URL or topic ARN
+        if let Some(destination) = dto.destination {
+            builder = builder.with_destination(destination.into());
+        }
+        if let Some(region) = mapper.resolve_optional_string(&dto.region)? {
+            builder = builder.with_region(region);
+        }
+        if let Some(endpoint_url) = mapper.resolve_optional_string(&dto.endpoint_url)? {
+            builder = builder.with_endpoint_url(endpoint_url);
+        }
+        if let Some(field) = mapper.resolve_optional_string(&dto.message_group_field)? {
+            builder = builder.with_message_group_field(field);
+        }
+        if let Some(ref batch_size) = dto.batch_size {
+            builder = builder.with_batch_size(mapper.resolve_typed(batch_size)?);
+        }
+        if let Some(ref retries) = dto.max_retries {
+            builder = builder.with_max_retries(mapper.resolve_typed(retries)?);
+        }
+
+        let reaction = builder.build()?;
+        Ok(Box::new(reaction))
+    }
+}
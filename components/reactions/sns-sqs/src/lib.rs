@@ -0,0 +1,187 @@
+#![allow(unexpected_cfgs)]
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! SNS/SQS reaction plugin for Drasi
+//!
+//! This plugin publishes continuous query result changes to Amazon SQS queues
+//! or Amazon SNS topics. Each change becomes one JSON message with message
+//! attributes taken from result columns, so subscribers can filter without
+//! parsing the body. Messages are sent with `SendMessageBatch` and
+//! `PublishBatch` for throughput, and FIFO queues and topics get a message
+//! group ID from a result column.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use drasi_reaction_sns_sqs::SnsSqsReaction;
+//!
+//! let reaction = SnsSqsReaction::builder("order-events")
+//!     .with_query("orders")
+//!     .with_queue_url("https://sqs.eu-west-1.amazonaws.com/123456789012/orders.fifo")
+//!     .with_attribute_field("status")
+//!     .with_message_group_field("customerId")
+//!     .build()?;
+//! ```
+
+pub mod config;
+pub mod descriptor;
+mod message;
+mod publisher;
+pub mod sns_sqs;
+
+pub use config::{SnsSqsDestination, SnsSqsReactionConfig};
+pub use sns_sqs::SnsSqsReaction;
+
+/// Builder for SNS/SQS reaction
+pub struct SnsSqsReactionBuilder {
+    id: String,
+    queries: Vec<String>,
+    config: SnsSqsReactionConfig,
+    priority_queue_capacity: Option<usize>,
+    auto_start: bool,
+}
+
+impl SnsSqsReactionBuilder {
+    /// Create a new SNS/SQS reaction builder with the given ID
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            queries: Vec::new(),
+            config: SnsSqsReactionConfig::default(),
+            priority_queue_capacity: None,
+            auto_start: true,
+        }
+    }
+
+    /// Set the query IDs to subscribe to
+    pub fn with_queries(mut self, queries: Vec<String>) -> Self {
+        self.queries = queries;
+        self
+    }
+
+    /// Add a query ID to subscribe to
+    pub fn with_query(mut self, query_id: impl Into<String>) -> Self {
+        self.queries.push(query_id.into());
+        self
+    }
+
+    /// Set where result changes are published
+    pub fn with_destination(mut self, destination: SnsSqsDestination) -> Self {
+        self.config.destination = destination;
+        self
+    }
+
+    /// Publish to the SQS queue with the given URL
+    pub fn with_queue_url(mut self, queue_url: impl Into<String>) -> Self {
+        self.config.destination = SnsSqsDestination::Sqs;
+        self.config.queue_url = Some(queue_url.into());
+        self
+    }
+
+    /// Publish to the SNS topic with the given ARN
+    pub fn with_topic_arn(mut self, topic_arn: impl Into<String>) -> Self {
+        self.config.destination = SnsSqsDestination::Sns;
+        self.config.topic_arn = Some(topic_arn.into());
+        self
+    }
+
+    /// Set the AWS region
+    pub fn with_region(mut self, region: impl Into<String>) -> Self {
+        self.config.region = Some(region.into());
+        self
+    }
+
+    /// Set an endpoint override, e.g. for LocalStack
+    pub fn with_endpoint_url(mut self, endpoint_url: impl Into<String>) -> Self {
+        self.config.endpoint_url = Some(endpoint_url.into());
+        self
+    }
+
+    /// Set the result columns copied into message attributes
+    pub fn with_attribute_fields(mut self, fields: Vec<String>) -> Self {
+        self.config.attribute_fields = fields;
+        self
+    }
+
+    /// Add a result column copied into message attributes
+    pub fn with_attribute_field(mut self, field: impl Into<String>) -> Self {
+        self.config.attribute_fields.push(field.into());
+        self
+    }
+
+    /// Set the result column used as message group ID of FIFO destinations
+    pub fn with_message_group_field(mut self, field: impl Into<String>) -> Self {
+        self.config.message_group_field = Some(field.into());
+        self
+    }
+
+    /// Set the maximum number of messages per batch call
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.config.batch_size = batch_size;
+        self
+    }
+
+    /// Set the number of retries of failed batch calls and entries
+    pub fn with_max_retries(mut self, retries: u32) -> Self {
+        self.config.max_retries = retries;
+        self
+    }
+
+    /// Set the priority queue capacity
+    pub fn with_priority_queue_capacity(mut self, capacity: usize) -> Self {
+        self.priority_queue_capacity = Some(capacity);
+        self
+    }
+
+    /// Set whether the reaction should auto-start
+    pub fn with_auto_start(mut self, auto_start: bool) -> Self {
+        self.auto_start = auto_start;
+        self
+    }
+
+    /// Set the full configuration at once
+    pub fn with_config(mut self, config: SnsSqsReactionConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Build the SNS/SQS reaction
+    pub fn build(self) -> anyhow::Result<SnsSqsReaction> {
+        SnsSqsReaction::validate_config(&self.config)?;
+
+        Ok(SnsSqsReaction::from_builder(
+            self.id,
+            self.queries,
+            self.config,
+            self.priority_queue_capacity,
+            self.auto_start,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests;
+
+/// Dynamic plugin entry point.
+#[cfg(feature = "dynamic-plugin")]
+drasi_plugin_sdk::export_plugin!(
+    plugin_id = "sns-sqs-reaction",
+    core_version = env!("CARGO_PKG_VERSION"),
+    lib_version = env!("CARGO_PKG_VERSION"),
+    plugin_version = env!("CARGO_PKG_VERSION"),
+    source_descriptors = [],
+    reaction_descriptors = [descriptor::SnsSqsReactionDescriptor],
+    bootstrap_descriptors = [],
+);
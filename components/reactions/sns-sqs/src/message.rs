@@ -0,0 +1,197 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion of query results into SNS/SQS messages and batches.
+
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+
+use drasi_lib::channels::{QueryResult, ResultDiff};
+
+use crate::config::SnsSqsReactionConfig;
+
+/// Maximum total payload of a batch call, shared by SQS and SNS.
+pub(crate) const MAX_BATCH_BYTES: usize = 256 * 1024;
+
+/// Attributes set on every message.
+pub(crate) const RESERVED_ATTRIBUTES: [&str; 2] = ["queryId", "operation"];
+
+/// A typed message attribute.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct MessageAttribute {
+    /// `String` or `Number`
+    pub data_type: &'static str,
+    pub value: String,
+}
+
+impl MessageAttribute {
+    fn string(value: impl Into<String>) -> Self {
+        Self {
+            data_type: "String",
+            value: value.into(),
+        }
+    }
+
+    /// Attribute for a result value. Objects, arrays and nulls have none.
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::String(s) => Some(Self::string(s.clone())),
+            Value::Number(n) => Some(Self {
+                data_type: "Number",
+                value: n.to_string(),
+            }),
+            Value::Bool(b) => Some(Self::string(b.to_string())),
+            _ => None,
+        }
+    }
+}
+
+/// A message ready to be sent.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct OutgoingMessage {
+    pub body: String,
+    pub attributes: BTreeMap<String, MessageAttribute>,
+    /// Message group ID for FIFO destinations
+    pub group_id: Option<String>,
+    /// Deduplication ID for FIFO destinations
+    pub deduplication_id: Option<String>,
+}
+
+impl OutgoingMessage {
+    /// Size counted against the batch payload limit
+    pub(crate) fn size(&self) -> usize {
+        self.body.len()
+            + self
+                .attributes
+                .iter()
+                .map(|(name, attribute)| {
+                    name.len() + attribute.data_type.len() + attribute.value.len()
+                })
+                .sum::<usize>()
+    }
+}
+
+/// Unique deduplication IDs for FIFO messages.
+///
+/// IDs combine the time the reaction started with a counter, so messages are
+/// never deduplicated against each other, while retries of a message reuse
+/// its ID.
+pub(crate) struct DeduplicationIds {
+    run_id: i64,
+    next: u64,
+}
+
+impl DeduplicationIds {
+    pub(crate) fn new() -> Self {
+        Self {
+            run_id: chrono::Utc::now().timestamp_millis(),
+            next: 0,
+        }
+    }
+
+    fn next_id(&mut self) -> String {
+        self.next += 1;
+        format!("{}-{}", self.run_id, self.next)
+    }
+}
+
+/// Build one message per change of a query result.
+pub(crate) fn messages(
+    config: &SnsSqsReactionConfig,
+    query_result: &QueryResult,
+    deduplication_ids: &mut DeduplicationIds,
+) -> Vec<OutgoingMessage> {
+    let query_id = &query_result.query_id;
+    let timestamp = query_result.timestamp.timestamp_millis();
+    let fifo = config.is_fifo();
+    let mut messages = Vec::new();
+
+    for diff in &query_result.results {
+        let (op, before, after) = match diff {
+            ResultDiff::Add { data } => ("ADD", None, Some(data)),
+            ResultDiff::Update { before, after, .. } => ("UPDATE", Some(before), Some(after)),
+            ResultDiff::Aggregation { before, after } => ("UPDATE", before.as_ref(), Some(after)),
+            ResultDiff::Delete { data } => ("DELETE", Some(data), None),
+            ResultDiff::Noop => continue,
+        };
+        let row = after.or(before);
+        let field = |name: &str| row.and_then(|row| row.get(name));
+
+        let mut body = json!({ "queryId": query_id, "op": op, "timestamp": timestamp });
+        if let Some(before) = before {
+            body["before"] = before.clone();
+        }
+        if let Some(after) = after {
+            body["after"] = after.clone();
+        }
+
+        let mut attributes = BTreeMap::new();
+        attributes.insert("queryId".to_string(), MessageAttribute::string(query_id));
+        attributes.insert("operation".to_string(), MessageAttribute::string(op));
+        for name in &config.attribute_fields {
+            if let Some(attribute) = field(name).and_then(MessageAttribute::from_value) {
+                attributes.insert(name.clone(), attribute);
+            }
+        }
+
+        let (group_id, deduplication_id) = if fifo {
+            let group_id = config
+                .message_group_field
+                .as_deref()
+                .and_then(field)
+                .and_then(MessageAttribute::from_value)
+                .map(|attribute| attribute.value)
+                .unwrap_or_else(|| query_id.clone());
+            (Some(group_id), Some(deduplication_ids.next_id()))
+        } else {
+            (None, None)
+        };
+
+        messages.push(OutgoingMessage {
+            body: body.to_string(),
+            attributes,
+            group_id,
+            deduplication_id,
+        });
+    }
+
+    messages
+}
+
+/// Split messages into batches of at most `batch_size` messages and
+/// [`MAX_BATCH_BYTES`]. A message larger than the limit gets a batch of its
+/// own and is rejected by AWS.
+pub(crate) fn batches(
+    messages: Vec<OutgoingMessage>,
+    batch_size: usize,
+) -> Vec<Vec<OutgoingMessage>> {
+    let mut batches = Vec::new();
+    let mut batch: Vec<OutgoingMessage> = Vec::new();
+    let mut batch_bytes = 0;
+
+    for message in messages {
+        let size = message.size();
+        if !batch.is_empty() && (batch.len() >= batch_size || batch_bytes + size > MAX_BATCH_BYTES)
+        {
+            batches.push(std::mem::take(&mut batch));
+            batch_bytes = 0;
+        }
+        batch_bytes += size;
+        batch.push(message);
+    }
+    if !batch.is_empty() {
+        batches.push(batch);
+    }
+    batches
+}
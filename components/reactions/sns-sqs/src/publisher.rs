@@ -0,0 +1,208 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Batch publishing to SQS queues and SNS topics.
+
+use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+
+use crate::config::{SnsSqsDestination, SnsSqsReactionConfig};
+use crate::message::OutgoingMessage;
+
+/// An entry of a batch that was not accepted.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FailedEntry {
+    /// Index of the message in the batch
+    pub index: usize,
+    /// Whether the failure was on the AWS side and may succeed when retried
+    pub retryable: bool,
+    pub reason: String,
+}
+
+fn failed_entry(id: &str, sender_fault: bool, code: &str, message: Option<&str>) -> FailedEntry {
+    FailedEntry {
+        index: id.parse().unwrap_or(usize::MAX),
+        retryable: !sender_fault,
+        reason: match message {
+            Some(message) => format!("{code}: {message}"),
+            None => code.to_string(),
+        },
+    }
+}
+
+/// Client of the configured destination.
+pub(crate) enum Publisher {
+    Sqs {
+        client: aws_sdk_sqs::Client,
+        queue_url: String,
+    },
+    Sns {
+        client: aws_sdk_sns::Client,
+        topic_arn: String,
+    },
+}
+
+impl Publisher {
+    /// Create a client from the default provider chain, overriding the
+    /// region and endpoint when configured.
+    pub(crate) async fn new(config: &SnsSqsReactionConfig) -> Result<Self> {
+        let mut loader = aws_config::defaults(aws_config::BehaviorVersion::latest());
+        if let Some(region) = &config.region {
+            loader = loader.region(aws_config::Region::new(region.clone()));
+        }
+        if let Some(endpoint_url) = &config.endpoint_url {
+            loader = loader.endpoint_url(endpoint_url);
+        }
+        let sdk_config = loader.load().await;
+
+        match config.destination {
+            SnsSqsDestination::Sqs => Ok(Self::Sqs {
+                client: aws_sdk_sqs::Client::new(&sdk_config),
+                queue_url: config
+                    .queue_url
+                    .clone()
+                    .ok_or_else(|| anyhow!("queue_url is required for SQS"))?,
+            }),
+            SnsSqsDestination::Sns => Ok(Self::Sns {
+                client: aws_sdk_sns::Client::new(&sdk_config),
+                topic_arn: config
+                    .topic_arn
+                    .clone()
+                    .ok_or_else(|| anyhow!("topic_arn is required for SNS"))?,
+            }),
+        }
+    }
+
+    /// Send a batch of up to 10 messages, returning the entries that failed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the whole call fails.
+    pub(crate) async fn send_batch(&self, batch: &[OutgoingMessage]) -> Result<Vec<FailedEntry>> {
+        match self {
+            Self::Sqs { client, queue_url } => send_sqs_batch(client, queue_url, batch).await,
+            Self::Sns { client, topic_arn } => send_sns_batch(client, topic_arn, batch).await,
+        }
+    }
+}
+
+async fn send_sqs_batch(
+    client: &aws_sdk_sqs::Client,
+    queue_url: &str,
+    batch: &[OutgoingMessage],
+) -> Result<Vec<FailedEntry>> {
+    use aws_sdk_sqs::error::DisplayErrorContext;
+    use aws_sdk_sqs::types::{MessageAttributeValue, SendMessageBatchRequestEntry};
+
+    let entries = batch
+        .iter()
+        .enumerate()
+        .map(|(index, message)| {
+            let attributes = message
+                .attributes
+                .iter()
+                .map(|(name, attribute)| {
+                    let value = MessageAttributeValue::builder()
+                        .data_type(attribute.data_type)
+                        .string_value(&attribute.value)
+                        .build()?;
+                    Ok((name.clone(), value))
+                })
+                .collect::<Result<HashMap<_, _>>>()?;
+            Ok(SendMessageBatchRequestEntry::builder()
+                .id(index.to_string())
+                .message_body(&message.body)
+                .set_message_attributes(Some(attributes))
+                .set_message_group_id(message.group_id.clone())
+                .set_message_deduplication_id(message.deduplication_id.clone())
+                .build()?)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let output = client
+        .send_message_batch()
+        .queue_url(queue_url)
+        .set_entries(Some(entries))
+        .send()
+        .await
+        .map_err(|e| anyhow!("SendMessageBatch failed: {}", DisplayErrorContext(e)))?;
+
+    Ok(output
+        .failed()
+        .iter()
+        .map(|entry| {
+            failed_entry(
+                entry.id(),
+                entry.sender_fault(),
+                entry.code(),
+                entry.message(),
+            )
+        })
+        .collect())
+}
+
+async fn send_sns_batch(
+    client: &aws_sdk_sns::Client,
+    topic_arn: &str,
+    batch: &[OutgoingMessage],
+) -> Result<Vec<FailedEntry>> {
+    use aws_sdk_sns::error::DisplayErrorContext;
+    use aws_sdk_sns::types::{MessageAttributeValue, PublishBatchRequestEntry};
+
+    let entries = batch
+        .iter()
+        .enumerate()
+        .map(|(index, message)| {
+            let attributes = message
+                .attributes
+                .iter()
+                .map(|(name, attribute)| {
+                    let value = MessageAttributeValue::builder()
+                        .data_type(attribute.data_type)
+                        .string_value(&attribute.value)
+                        .build()?;
+                    Ok((name.clone(), value))
+                })
+                .collect::<Result<HashMap<_, _>>>()?;
+            Ok(PublishBatchRequestEntry::builder()
+                .id(index.to_string())
+                .message(&message.body)
+                .set_message_attributes(Some(attributes))
+                .set_message_group_id(message.group_id.clone())
+                .set_message_deduplication_id(message.deduplication_id.clone())
+                .build()?)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let output = client
+        .publish_batch()
+        .topic_arn(topic_arn)
+        .set_publish_batch_request_entries(Some(entries))
+        .send()
+        .await
+        .map_err(|e| anyhow!("PublishBatch failed: {}", DisplayErrorContext(e)))?;
+
+    Ok(output
+        .failed()
+        .iter()
+        .map(|entry| {
+            failed_entry(
+                entry.id(),
+                entry.sender_fault(),
+                entry.code(),
+                entry.message(),
+            )
+        })
+        .collect())
+}
@@ -0,0 +1,364 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use log::{debug, error, info, warn};
+use std::collections::HashMap;
+use std::time::Duration;
+
+use drasi_lib::channels::{ComponentStatus, QueryResult};
+use drasi_lib::managers::log_component_start;
+use drasi_lib::reactions::common::base::{ReactionBase, ReactionBaseParams};
+use drasi_lib::Reaction;
+
+use super::config::{SnsSqsDestination, SnsSqsReactionConfig, MAX_BATCH_SIZE};
+use super::message::{batches, messages, DeduplicationIds, OutgoingMessage, RESERVED_ATTRIBUTES};
+use super::publisher::Publisher;
+use super::SnsSqsReactionBuilder;
+
+/// Maximum number of message attributes allowed by SQS and SNS.
+const MAX_ATTRIBUTES: usize = 10;
+
+/// Delay before the first retry of a failed batch. Doubles on every retry.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(100);
+
+/// SNS/SQS reaction publishes query result changes to SNS topics or SQS queues.
+pub struct SnsSqsReaction {
+    base: ReactionBase,
+    config: SnsSqsReactionConfig,
+}
+
+impl std::fmt::Debug for SnsSqsReaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SnsSqsReaction")
+            .field("id", &self.base.id)
+            .field("destination", &self.config.destination)
+            .field("queue_url", &self.config.queue_url)
+            .field("topic_arn", &self.config.topic_arn)
+            .finish()
+    }
+}
+
+impl SnsSqsReaction {
+    /// Create a builder for SnsSqsReaction
+    pub fn builder(id: impl Into<String>) -> SnsSqsReactionBuilder {
+        SnsSqsReactionBuilder::new(id)
+    }
+
+    /// Create a new SNS/SQS reaction
+    ///
+    /// The event channel is automatically injected when the reaction is added
+    /// to DrasiLib via `add_reaction()`.
+    ///
+    /// # Errors
+    ///
+    /// - Returns error if the queue URL or topic ARN of the destination is missing
+    /// - Returns error if `batch_size` is not between 1 and 10
+    /// - Returns error if an attribute field is invalid or there are too many
+    /// - Returns error if `message_group_field` is set for a non-FIFO destination
+    pub fn new(
+        id: impl Into<String>,
+        queries: Vec<String>,
+        config: SnsSqsReactionConfig,
+    ) -> anyhow::Result<Self> {
+        Self::validate_config(&config)?;
+        Ok(Self::from_builder(id.into(), queries, config, None, true))
+    }
+
+    /// Create from builder (internal method)
+    pub(crate) fn from_builder(
+        id: String,
+        queries: Vec<String>,
+        config: SnsSqsReactionConfig,
+        priority_queue_capacity: Option<usize>,
+        auto_start: bool,
+    ) -> Self {
+        let mut params = ReactionBaseParams::new(id, queries).with_auto_start(auto_start);
+        if let Some(capacity) = priority_queue_capacity {
+            params = params.with_priority_queue_capacity(capacity);
+        }
+
+        Self {
+            base: ReactionBase::new(params),
+            config,
+        }
+    }
+
+    /// Validate a message attribute name taken from a result column
+    fn validate_attribute_name(name: &str) -> anyhow::Result<()> {
+        let lower = name.to_ascii_lowercase();
+        if name.is_empty()
+            || name.len() > 256
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+            || name.starts_with('.')
+            || name.ends_with('.')
+            || name.contains("..")
+            || lower.starts_with("aws.")
+            || lower.starts_with("amazon.")
+        {
+            return Err(anyhow::anyhow!(
+                "Validation error: '{name}' is not a valid message attribute name"
+            ));
+        }
+        if RESERVED_ATTRIBUTES.contains(&name) {
+            return Err(anyhow::anyhow!(
+                "Validation error: attribute '{name}' is set by the reaction"
+            ));
+        }
+        Ok(())
+    }
+
+    /// Validate configuration: destination, batching, attributes and FIFO grouping
+    pub(crate) fn validate_config(config: &SnsSqsReactionConfig) -> anyhow::Result<()> {
+        match config.destination {
+            SnsSqsDestination::Sqs => {
+                let queue_url = config.queue_url.as_deref().unwrap_or_default();
+                if !queue_url.starts_with("https://") && !queue_url.starts_with("http://") {
+                    return Err(anyhow::anyhow!(
+                        "Validation error: queue_url must be an http:// or https:// URL"
+                    ));
+                }
+            }
+            SnsSqsDestination::Sns => {
+                let topic_arn = config.topic_arn.as_deref().unwrap_or_default();
+                if !topic_arn.starts_with("arn:") {
+                    return Err(anyhow::anyhow!(
+                        "Validation error: topic_arn must be an SNS topic ARN"
+                    ));
+                }
+            }
+        }
+        for (name, value) in [
+            ("region", &config.region),
+            ("endpoint_url", &config.endpoint_url),
+            ("message_group_field", &config.message_group_field),
+        ] {
+            if value.as_deref() == Some("") {
+                return Err(anyhow::anyhow!("Validation error: {name} cannot be empty"));
+            }
+        }
+        if config.batch_size == 0 || config.batch_size > MAX_BATCH_SIZE {
+            return Err(anyhow::anyhow!(
+                "Validation error: batch_size must be between 1 and {MAX_BATCH_SIZE}"
+            ));
+        }
+
+        if config.attribute_fields.len() + RESERVED_ATTRIBUTES.len() > MAX_ATTRIBUTES {
+            return Err(anyhow::anyhow!(
+                "Validation error: at most {} attribute_fields are allowed",
+                MAX_ATTRIBUTES - RESERVED_ATTRIBUTES.len()
+            ));
+        }
+        for (index, name) in config.attribute_fields.iter().enumerate() {
+            Self::validate_attribute_name(name)?;
+            if config.attribute_fields[..index].contains(name) {
+                return Err(anyhow::anyhow!(
+                    "Validation error: duplicate attribute field '{name}'"
+                ));
+            }
+        }
+
+        if config.message_group_field.is_some() && !config.is_fifo() {
+            return Err(anyhow::anyhow!(
+                "Validation error: message_group_field requires a FIFO queue or topic (name ending in .fifo)"
+            ));
+        }
+        Ok(())
+    }
+
+    /// Send a batch, retrying failed calls and entries that failed on the AWS side
+    async fn send_with_retry(
+        publisher: &Publisher,
+        mut batch: Vec<OutgoingMessage>,
+        max_retries: u32,
+        reaction_id: &str,
+    ) -> Result<()> {
+        let mut retry_delay = INITIAL_BACKOFF;
+        let mut attempt = 0;
+        loop {
+            match publisher.send_batch(&batch).await {
+                Ok(failed) if failed.is_empty() => return Ok(()),
+                Ok(failed) => {
+                    let mut retry = Vec::new();
+                    for entry in failed {
+                        match batch.get(entry.index) {
+                            Some(message) if entry.retryable && attempt < max_retries => {
+                                retry.push(message.clone());
+                            }
+                            _ => error!(
+                                "[{reaction_id}] Dropping message rejected by AWS: {}",
+                                entry.reason
+                            ),
+                        }
+                    }
+                    if retry.is_empty() {
+                        return Ok(());
+                    }
+                    warn!(
+                        "[{reaction_id}] {} messages failed (attempt {}/{max_retries}). Retrying in {retry_delay:?}",
+                        retry.len(),
+                        attempt + 1
+                    );
+                    batch = retry;
+                }
+                Err(e) if attempt < max_retries => {
+                    warn!(
+                        "[{reaction_id}] Failed to send batch (attempt {}/{max_retries}): {e}. Retrying in {retry_delay:?}",
+                        attempt + 1
+                    );
+                }
+                Err(e) => {
+                    return Err(anyhow!("Dropping batch of {} messages: {e}", batch.len()));
+                }
+            }
+            attempt += 1;
+            tokio::time::sleep(retry_delay).await;
+            retry_delay *= 2;
+        }
+    }
+}
+
+#[async_trait]
+impl Reaction for SnsSqsReaction {
+    fn id(&self) -> &str {
+        &self.base.id
+    }
+
+    fn type_name(&self) -> &str {
+        "sns-sqs"
+    }
+
+    fn properties(&self) -> HashMap<String, serde_json::Value> {
+        match serde_json::to_value(&self.config) {
+            Ok(serde_json::Value::Object(map)) => map.into_iter().collect(),
+            _ => HashMap::new(),
+        }
+    }
+
+    fn query_ids(&self) -> Vec<String> {
+        self.base.queries.clone()
+    }
+
+    fn auto_start(&self) -> bool {
+        self.base.get_auto_start()
+    }
+
+    async fn initialize(&self, context: drasi_lib::context::ReactionRuntimeContext) {
+        self.base.initialize(context).await;
+    }
+
+    async fn start(&self) -> Result<()> {
+        log_component_start("SNS/SQS Reaction", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Starting,
+                Some("Starting SNS/SQS reaction".to_string()),
+            )
+            .await;
+
+        let publisher = match Publisher::new(&self.config).await {
+            Ok(publisher) => publisher,
+            Err(e) => {
+                let message = format!("Failed to create AWS client: {e}");
+                self.base
+                    .set_status(ComponentStatus::Error, Some(message.clone()))
+                    .await;
+                return Err(anyhow!(message));
+            }
+        };
+
+        self.base
+            .set_status(
+                ComponentStatus::Running,
+                Some("SNS/SQS reaction started".to_string()),
+            )
+            .await;
+
+        let priority_queue = self.base.priority_queue.clone();
+        let reaction_id = self.base.id.clone();
+        let config = self.config.clone();
+        let mut shutdown_rx = self.base.create_shutdown_channel().await;
+
+        let processing_task = tokio::spawn(async move {
+            info!("[{reaction_id}] SNS/SQS processing task started");
+            let mut deduplication_ids = DeduplicationIds::new();
+
+            loop {
+                tokio::select! {
+                    biased;
+
+                    _ = &mut shutdown_rx => {
+                        debug!("[{reaction_id}] Received shutdown signal, exiting processing loop");
+                        break;
+                    }
+
+                    query_result = priority_queue.dequeue() => {
+                        let mut outgoing = messages(&config, &query_result, &mut deduplication_ids);
+                        // Fill the batch with results that are already waiting
+                        while outgoing.len() < config.batch_size {
+                            match priority_queue.try_dequeue().await {
+                                Some(next) => outgoing.extend(messages(&config, &next, &mut deduplication_ids)),
+                                None => break,
+                            }
+                        }
+
+                        for batch in batches(outgoing, config.batch_size) {
+                            if let Err(e) = SnsSqsReaction::send_with_retry(
+                                &publisher,
+                                batch,
+                                config.max_retries,
+                                &reaction_id,
+                            )
+                            .await
+                            {
+                                error!("[{reaction_id}] {e}");
+                            }
+                        }
+                    }
+                }
+            }
+
+            info!("[{reaction_id}] SNS/SQS processing task ended");
+        });
+
+        self.base.set_processing_task(processing_task).await;
+
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        self.base.stop_common().await?;
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopped,
+                Some("SNS/SQS reaction stopped".to_string()),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn status(&self) -> ComponentStatus {
+        self.base.get_status().await
+    }
+
+    async fn enqueue_query_result(&self, result: QueryResult) -> anyhow::Result<()> {
+        self.base.enqueue_query_result(result).await
+    }
+}
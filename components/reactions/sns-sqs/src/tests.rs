@@ -0,0 +1,262 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::descriptor::SnsSqsReactionDescriptor;
+use crate::message::{batches, messages, DeduplicationIds, MessageAttribute, MAX_BATCH_BYTES};
+use drasi_lib::channels::{QueryResult, ResultDiff};
+use drasi_lib::Reaction;
+use drasi_plugin_sdk::prelude::ReactionPluginDescriptor;
+use serde_json::json;
+use std::collections::HashMap;
+
+const QUEUE_URL: &str = "https://sqs.eu-west-1.amazonaws.com/123456789012/orders";
+const FIFO_QUEUE_URL: &str = "https://sqs.eu-west-1.amazonaws.com/123456789012/orders.fifo";
+const TOPIC_ARN: &str = "arn:aws:sns:eu-west-1:123456789012:orders";
+
+fn query_result(query_id: &str, results: Vec<ResultDiff>) -> QueryResult {
+    QueryResult::new(
+        query_id.to_string(),
+        chrono::Utc::now(),
+        results,
+        HashMap::new(),
+    )
+}
+
+fn order(id: i64, customer: &str, total: f64) -> serde_json::Value {
+    json!({ "id": id, "customer": customer, "total": total, "express": true })
+}
+
+#[test]
+fn test_sns_sqs_builder_defaults() {
+    let reaction = SnsSqsReactionBuilder::new("test-reaction")
+        .with_queue_url(QUEUE_URL)
+        .build()
+        .unwrap();
+    assert_eq!(reaction.id(), "test-reaction");
+    assert_eq!(reaction.type_name(), "sns-sqs");
+    let props = reaction.properties();
+    assert_eq!(props.get("destination"), Some(&json!("sqs")));
+    assert_eq!(props.get("queue_url"), Some(&json!(QUEUE_URL)));
+    assert_eq!(props.get("batch_size"), Some(&json!(10)));
+    assert_eq!(props.get("max_retries"), Some(&json!(3)));
+    assert!(props.get("topic_arn").is_none());
+}
+
+#[test]
+fn test_sns_sqs_builder_validation() {
+    let sqs = || SnsSqsReaction::builder("r").with_queue_url(QUEUE_URL);
+
+    assert!(SnsSqsReaction::builder("r").build().is_err());
+    assert!(SnsSqsReaction::builder("r")
+        .with_queue_url("orders")
+        .build()
+        .is_err());
+    assert!(SnsSqsReaction::builder("r")
+        .with_topic_arn("orders")
+        .build()
+        .is_err());
+    assert!(SnsSqsReaction::builder("r")
+        .with_topic_arn(TOPIC_ARN)
+        .build()
+        .is_ok());
+    assert!(sqs().with_region("").build().is_err());
+    assert!(sqs().with_batch_size(0).build().is_err());
+    assert!(sqs().with_batch_size(11).build().is_err());
+    assert!(sqs().with_attribute_field("").build().is_err());
+    assert!(sqs().with_attribute_field("AWS.trace").build().is_err());
+    assert!(sqs().with_attribute_field("has space").build().is_err());
+    assert!(sqs().with_attribute_field("operation").build().is_err());
+    assert!(sqs()
+        .with_attribute_field("status")
+        .with_attribute_field("status")
+        .build()
+        .is_err());
+    assert!(sqs()
+        .with_attribute_fields((0..9).map(|i| format!("field{i}")).collect())
+        .build()
+        .is_err());
+    assert!(sqs().with_message_group_field("customer").build().is_err());
+    assert!(SnsSqsReaction::builder("r")
+        .with_queue_url(FIFO_QUEUE_URL)
+        .with_message_group_field("customer")
+        .with_attribute_fields(vec!["customer".to_string(), "order.total".to_string()])
+        .with_region("eu-west-1")
+        .with_endpoint_url("http://localhost:4566")
+        .build()
+        .is_ok());
+}
+
+#[test]
+fn test_messages_for_changes() {
+    let config = SnsSqsReactionConfig {
+        queue_url: Some(QUEUE_URL.to_string()),
+        attribute_fields: vec![
+            "customer".to_string(),
+            "total".to_string(),
+            "express".to_string(),
+            "missing".to_string(),
+        ],
+        ..Default::default()
+    };
+    let result = query_result(
+        "orders",
+        vec![
+            ResultDiff::Add {
+                data: order(1, "alice", 12.5),
+            },
+            ResultDiff::Noop,
+            ResultDiff::Delete {
+                data: order(2, "bob", 3.0),
+            },
+        ],
+    );
+
+    let messages = messages(&config, &result, &mut DeduplicationIds::new());
+    assert_eq!(messages.len(), 2);
+
+    let body: serde_json::Value = serde_json::from_str(&messages[0].body).unwrap();
+    assert_eq!(body["queryId"], "orders");
+    assert_eq!(body["op"], "ADD");
+    assert_eq!(body["after"], order(1, "alice", 12.5));
+    assert!(body.get("before").is_none());
+
+    let attributes = &messages[0].attributes;
+    assert_eq!(attributes.len(), 5);
+    assert_eq!(
+        attributes["operation"],
+        MessageAttribute {
+            data_type: "String",
+            value: "ADD".to_string()
+        }
+    );
+    assert_eq!(attributes["customer"].value, "alice");
+    assert_eq!(attributes["total"].data_type, "Number");
+    assert_eq!(attributes["total"].value, "12.5");
+    assert_eq!(attributes["express"].value, "true");
+    assert!(messages[0].group_id.is_none());
+    assert!(messages[0].deduplication_id.is_none());
+
+    // Deleted rows take attributes from the row before the change
+    assert_eq!(messages[1].attributes["operation"].value, "DELETE");
+    assert_eq!(messages[1].attributes["customer"].value, "bob");
+}
+
+#[test]
+fn test_fifo_messages_have_group_and_deduplication_ids() {
+    let config = SnsSqsReactionConfig {
+        queue_url: Some(FIFO_QUEUE_URL.to_string()),
+        message_group_field: Some("customer".to_string()),
+        ..Default::default()
+    };
+    assert!(config.is_fifo());
+    let result = query_result(
+        "orders",
+        vec![
+            ResultDiff::Add {
+                data: order(1, "alice", 1.0),
+            },
+            ResultDiff::Add {
+                data: json!({ "id": 2 }),
+            },
+        ],
+    );
+
+    let mut deduplication_ids = DeduplicationIds::new();
+    let first = messages(&config, &result, &mut deduplication_ids);
+    let second = messages(&config, &result, &mut deduplication_ids);
+    assert_eq!(first[0].group_id.as_deref(), Some("alice"));
+    assert_eq!(first[1].group_id.as_deref(), Some("orders"));
+
+    let ids: std::collections::HashSet<_> = first
+        .iter()
+        .chain(&second)
+        .map(|m| m.deduplication_id.clone().unwrap())
+        .collect();
+    assert_eq!(ids.len(), 4);
+}
+
+#[test]
+fn test_batches_respect_count_and_size() {
+    let config = SnsSqsReactionConfig {
+        queue_url: Some(QUEUE_URL.to_string()),
+        ..Default::default()
+    };
+    let changes = (0..25)
+        .map(|id| ResultDiff::Add {
+            data: json!({ "id": id }),
+        })
+        .collect();
+    let outgoing = messages(
+        &config,
+        &query_result("orders", changes),
+        &mut DeduplicationIds::new(),
+    );
+    let sizes: Vec<usize> = batches(outgoing, 10).iter().map(Vec::len).collect();
+    assert_eq!(sizes, vec![10, 10, 5]);
+
+    let large = "x".repeat(100 * 1024);
+    let changes = (0..3)
+        .map(|id| ResultDiff::Add {
+            data: json!({ "id": id, "blob": large }),
+        })
+        .collect();
+    let outgoing = messages(
+        &config,
+        &query_result("orders", changes),
+        &mut DeduplicationIds::new(),
+    );
+    let batches = batches(outgoing, 10);
+    assert_eq!(batches.len(), 2);
+    for batch in &batches {
+        assert!(batch.iter().map(|m| m.size()).sum::<usize>() <= MAX_BATCH_BYTES);
+    }
+}
+
+#[tokio::test]
+async fn test_descriptor_creates_reaction() {
+    let descriptor = SnsSqsReactionDescriptor;
+    assert_eq!(descriptor.kind(), "sns-sqs");
+
+    let config = json!({
+        "topicArn": "arn:aws:sns:eu-west-1:123456789012:orders.fifo",
+        "region": "eu-west-1",
+        "endpointUrl": "http://localhost:4566",
+        "attributeFields": ["status"],
+        "messageGroupField": "customerId",
+        "batchSize": 5,
+        "maxRetries": 1
+    });
+
+    let reaction = descriptor
+        .create_reaction("sns-1", vec!["query1".to_string()], &config, false)
+        .await
+        .unwrap();
+
+    assert_eq!(reaction.id(), "sns-1");
+    assert!(!reaction.auto_start());
+    let props = reaction.properties();
+    assert_eq!(props.get("destination"), Some(&json!("sns")));
+    assert_eq!(props.get("region"), Some(&json!("eu-west-1")));
+    assert_eq!(props.get("attribute_fields"), Some(&json!(["status"])));
+    assert_eq!(props.get("message_group_field"), Some(&json!("customerId")));
+    assert_eq!(props.get("batch_size"), Some(&json!(5)));
+    assert_eq!(props.get("max_retries"), Some(&json!(1)));
+
+    let missing_queue = json!({ "destination": "sqs" });
+    assert!(descriptor
+        .create_reaction("sqs-1", vec!["query1".to_string()], &missing_queue, false)
+        .await
+        .is_err());
+}
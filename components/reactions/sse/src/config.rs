@@ -33,6 +33,14 @@ fn default_sse_host() -> String {
     "0.0.0.0".to_string()
 }
 
+fn default_query_path() -> String {
+    "/queries".to_string()
+}
+
+fn default_true() -> bool {
+    true
+}
+
 /// SSE-specific extension for template specifications.
 ///
 /// This extension adds path routing capabilities specific to SSE reactions.
@@ -83,6 +91,19 @@ pub struct SseReactionConfig {
     #[serde(default = "default_heartbeat_interval_ms")]
     pub heartbeat_interval_ms: u64,
 
+    /// Base path of the per-query endpoints. Each subscribed query is served at
+    /// `{query_path}/{query_id}`.
+    #[serde(default = "default_query_path")]
+    pub query_path: String,
+
+    /// Accept WebSocket upgrades on all endpoints in addition to SSE
+    #[serde(default = "default_true")]
+    pub enable_websocket: bool,
+
+    /// Send the current result set to clients connecting to a per-query endpoint
+    #[serde(default = "default_true")]
+    pub snapshot_on_connect: bool,
+
     /// Query-specific template configurations
     #[serde(default)]
     pub routes: HashMap<String, QueryConfig>,
@@ -100,6 +121,9 @@ impl Default for SseReactionConfig {
             port: default_sse_port(),
             sse_path: default_sse_path(),
             heartbeat_interval_ms: default_heartbeat_interval_ms(),
+            query_path: default_query_path(),
+            enable_websocket: true,
+            snapshot_on_connect: true,
             routes: HashMap::new(),
             default_template: None,
         }
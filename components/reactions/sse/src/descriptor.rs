@@ -79,6 +79,21 @@ pub struct SseReactionConfigDto {
     #[schema(value_type = Option<ConfigValueU64>)]
     pub heartbeat_interval_ms: Option<ConfigValue<u64>>,
 
+    /// Base path of the per-query endpoints.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub query_path: Option<ConfigValue<String>>,
+
+    /// Accept WebSocket connections in addition to SSE.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueBool>)]
+    pub enable_websocket: Option<ConfigValue<bool>>,
+
+    /// Send the current result set when a client connects to a per-query endpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueBool>)]
+    pub snapshot_on_connect: Option<ConfigValue<bool>>,
+
     /// Query-specific template configurations.
     #[serde(default)]
     pub routes: HashMap<String, SseQueryConfigDto>,
@@ -163,6 +178,15 @@ impl ReactionPluginDescriptor for SseReactionDescriptor {
         if let Some(ref heartbeat) = dto.heartbeat_interval_ms {
             builder = builder.with_heartbeat_interval_ms(mapper.resolve_typed(heartbeat)?);
         }
+        if let Some(ref query_path) = dto.query_path {
+            builder = builder.with_query_path(mapper.resolve_string(query_path)?);
+        }
+        if let Some(ref enable_websocket) = dto.enable_websocket {
+            builder = builder.with_websocket(mapper.resolve_typed(enable_websocket)?);
+        }
+        if let Some(ref snapshot) = dto.snapshot_on_connect {
+            builder = builder.with_snapshot_on_connect(mapper.resolve_typed(snapshot)?);
+        }
 
         if let Some(ref default_template) = dto.default_template {
             builder = builder.with_default_template(map_query_config(default_template));
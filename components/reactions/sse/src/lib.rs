@@ -31,43 +31,16 @@
 
 use std::collections::HashMap;
 
+use drasi_lib::templating::TemplateEngine;
+
 pub mod config;
 pub mod descriptor;
 pub mod sse;
+mod stream;
 
 pub use config::{QueryConfig, SseExtension, SseReactionConfig, TemplateSpec};
 pub use sse::SseReaction;
 
-/// Helper function to register the json helper in a Handlebars instance
-/// This helper serializes values to JSON format in templates
-fn register_json_helper(handlebars: &mut handlebars::Handlebars) {
-    handlebars.register_helper(
-        "json",
-        Box::new(
-            |h: &handlebars::Helper,
-             _: &handlebars::Handlebars,
-             _: &handlebars::Context,
-             _: &mut handlebars::RenderContext,
-             out: &mut dyn handlebars::Output|
-             -> handlebars::HelperResult {
-                if let Some(value) = h.param(0) {
-                    match serde_json::to_string(&value.value()) {
-                        Ok(json_str) => out.write(&json_str)?,
-                        Err(_) => {
-                            // On serialization error, output null
-                            out.write("null")?;
-                        }
-                    }
-                } else {
-                    // No parameter provided to json helper
-                    out.write("null")?;
-                }
-                Ok(())
-            },
-        ),
-    );
-}
-
 /// Builder for SSE reaction
 pub struct SseReactionBuilder {
     id: String,
@@ -76,6 +49,9 @@ pub struct SseReactionBuilder {
     port: u16,
     sse_path: String,
     heartbeat_interval_ms: u64,
+    query_path: String,
+    enable_websocket: bool,
+    snapshot_on_connect: bool,
     priority_queue_capacity: Option<usize>,
     auto_start: bool,
     routes: HashMap<String, QueryConfig>,
@@ -92,6 +68,9 @@ impl SseReactionBuilder {
             port: 8080,
             sse_path: "/events".to_string(),
             heartbeat_interval_ms: 30000,
+            query_path: "/queries".to_string(),
+            enable_websocket: true,
+            snapshot_on_connect: true,
             priority_queue_capacity: None,
             auto_start: true,
             routes: HashMap::new(),
@@ -135,6 +114,24 @@ impl SseReactionBuilder {
         self
     }
 
+    /// Set the base path of the per-query endpoints
+    pub fn with_query_path(mut self, path: impl Into<String>) -> Self {
+        self.query_path = path.into();
+        self
+    }
+
+    /// Set whether endpoints accept WebSocket connections
+    pub fn with_websocket(mut self, enabled: bool) -> Self {
+        self.enable_websocket = enabled;
+        self
+    }
+
+    /// Set whether per-query endpoints send the current result set on connect
+    pub fn with_snapshot_on_connect(mut self, enabled: bool) -> Self {
+        self.snapshot_on_connect = enabled;
+        self
+    }
+
     /// Set the priority queue capacity
     pub fn with_priority_queue_capacity(mut self, capacity: usize) -> Self {
         self.priority_queue_capacity = Some(capacity);
@@ -165,53 +162,33 @@ impl SseReactionBuilder {
         self.port = config.port;
         self.sse_path = config.sse_path;
         self.heartbeat_interval_ms = config.heartbeat_interval_ms;
+        self.query_path = config.query_path;
+        self.enable_websocket = config.enable_websocket;
+        self.snapshot_on_connect = config.snapshot_on_connect;
         self.routes = config.routes;
         self.default_template = config.default_template;
         self
     }
 
     /// Validate a template by attempting to compile it with Handlebars
-    fn validate_template(
-        handlebars: &handlebars::Handlebars,
-        template: &str,
-    ) -> anyhow::Result<()> {
+    fn validate_template(template: &str) -> anyhow::Result<()> {
         if template.is_empty() {
             return Ok(());
         }
-        // Validate the template by attempting to render it with empty data
-        handlebars
-            .render_template(template, &serde_json::json!({}))
-            .map_err(|e| anyhow::anyhow!("Invalid template: {e}"))?;
-        Ok(())
+        TemplateEngine::validate(template)
     }
 
     /// Validate all templates in a QueryConfig
-    fn validate_query_config(
-        handlebars: &handlebars::Handlebars,
-        config: &QueryConfig,
-    ) -> anyhow::Result<()> {
-        if let Some(added) = &config.added {
+    fn validate_query_config(config: &QueryConfig) -> anyhow::Result<()> {
+        for spec in [&config.added, &config.updated, &config.deleted]
+            .into_iter()
+            .flatten()
+        {
             // Validate body template
-            Self::validate_template(handlebars, &added.template)?;
+            Self::validate_template(&spec.template)?;
             // Validate path template (if present)
-            if let Some(path) = &added.extension.path {
-                Self::validate_template(handlebars, path)?;
-            }
-        }
-        if let Some(updated) = &config.updated {
-            // Validate body template
-            Self::validate_template(handlebars, &updated.template)?;
-            // Validate path template (if present)
-            if let Some(path) = &updated.extension.path {
-                Self::validate_template(handlebars, path)?;
-            }
-        }
-        if let Some(deleted) = &config.deleted {
-            // Validate body template
-            Self::validate_template(handlebars, &deleted.template)?;
-            // Validate path template (if present)
-            if let Some(path) = &deleted.extension.path {
-                Self::validate_template(handlebars, path)?;
+            if let Some(path) = &spec.extension.path {
+                Self::validate_template(path)?;
             }
         }
         Ok(())
@@ -219,24 +196,25 @@ impl SseReactionBuilder {
 
     /// Build the SSE reaction
     pub fn build(self) -> anyhow::Result<SseReaction> {
-        // Create a single Handlebars instance for all validation with json helper
-        let mut handlebars = handlebars::Handlebars::new();
-
-        // Register the json helper for template validation
-        register_json_helper(&mut handlebars);
-
         // Validate all templates in routes
         for (query_id, config) in &self.routes {
-            Self::validate_query_config(&handlebars, config)
+            Self::validate_query_config(config)
                 .map_err(|e| anyhow::anyhow!("Invalid template in route '{query_id}': {e}"))?;
         }
 
         // Validate default template if provided
         if let Some(default_template) = &self.default_template {
-            Self::validate_query_config(&handlebars, default_template)
+            Self::validate_query_config(default_template)
                 .map_err(|e| anyhow::anyhow!("Invalid default template: {e}"))?;
         }
 
+        if !self.query_path.starts_with('/') || self.query_path.trim_end_matches('/').is_empty() {
+            return Err(anyhow::anyhow!(
+                "Query path '{}' must start with '/' and cannot be the root path",
+                self.query_path
+            ));
+        }
+
         // Validate that all routes correspond to subscribed queries
         if !self.routes.is_empty() && !self.queries.is_empty() {
             for route_query in self.routes.keys() {
@@ -260,6 +238,9 @@ impl SseReactionBuilder {
             port: self.port,
             sse_path: self.sse_path,
             heartbeat_interval_ms: self.heartbeat_interval_ms,
+            query_path: self.query_path,
+            enable_websocket: self.enable_websocket,
+            snapshot_on_connect: self.snapshot_on_connect,
             routes: self.routes,
             default_template: self.default_template,
         };
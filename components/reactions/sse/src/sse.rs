@@ -13,26 +13,31 @@
 // limitations under the License.
 
 use async_trait::async_trait;
-use axum::http::Method;
+use axum::extract::ws::WebSocketUpgrade;
+use axum::extract::{FromRequestParts, Query};
+use axum::http::{Method, StatusCode};
 use axum::response::sse::{Event, KeepAlive, Sse};
 use axum::response::IntoResponse;
 use axum::{routing::get, Router};
-use handlebars::Handlebars;
+use futures::stream::BoxStream;
 use log::{debug, error, info};
 use serde_json::{json, Map, Value};
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::broadcast;
+use tokio_stream::wrappers::BroadcastStream;
 use tokio_stream::StreamExt;
 use tower_http::cors::{Any, CorsLayer};
 
 use drasi_lib::channels::{ComponentStatus, ResultDiff};
 use drasi_lib::managers::log_component_start;
 use drasi_lib::reactions::common::base::{ReactionBase, ReactionBaseParams};
+use drasi_lib::templating::{change_context, TemplateEngine};
 use drasi_lib::Reaction;
 
 pub use super::config::SseReactionConfig;
+use super::stream::{forward_to_socket, ConnectionFilter, QueryStreams};
 use super::SseReactionBuilder;
 
 const BROADCAST_CHANNEL_CAPACITY: usize = 1024;
@@ -81,6 +86,7 @@ pub struct SseReaction {
     base: ReactionBase,
     config: SseReactionConfig,
     broadcasters: Arc<tokio::sync::RwLock<HashMap<String, broadcast::Sender<String>>>>,
+    query_streams: QueryStreams,
     task_handles: Arc<tokio::sync::Mutex<Vec<tokio::task::JoinHandle<()>>>>,
 }
 
@@ -90,6 +96,7 @@ impl std::fmt::Debug for SseReaction {
             .field("id", &self.base.id)
             .field("config", &self.config)
             .field("broadcasters", &"<broadcasters>")
+            .field("query_streams", &"<query_streams>")
             .field("task_handles", &"<task_handles>")
             .finish()
     }
@@ -147,6 +154,7 @@ impl SseReaction {
         priority_queue_capacity: Option<usize>,
         auto_start: bool,
     ) -> Self {
+        let query_streams = QueryStreams::new(&queries);
         let mut params = ReactionBaseParams::new(id, queries).with_auto_start(auto_start);
         if let Some(capacity) = priority_queue_capacity {
             params = params.with_priority_queue_capacity(capacity);
@@ -181,23 +189,24 @@ impl SseReaction {
             base: ReactionBase::new(params),
             config,
             broadcasters: Arc::new(tokio::sync::RwLock::new(broadcasters)),
+            query_streams,
             task_handles: Arc::new(tokio::sync::Mutex::new(Vec::new())),
         }
     }
 
     /// Resolve the SSE path for an event based on the template spec and base path
-    fn resolve_sse_path(
+    pub(crate) fn resolve_sse_path(
         custom_path: Option<&String>,
         base_sse_path: &str,
-        handlebars: &Handlebars,
+        engine: &TemplateEngine,
         context: &Map<String, Value>,
         reaction_id: &str,
     ) -> String {
         if let Some(custom_path) = custom_path {
             // Render the path template if it contains variables
             let rendered_path = if custom_path.contains("{{") {
-                handlebars
-                    .render_template(custom_path, context)
+                engine
+                    .render(custom_path, context)
                     .unwrap_or_else(|e| {
                         error!("[{reaction_id}] Failed to render path template '{custom_path}': {e}. Using template as-is.");
                         custom_path.clone()
@@ -276,13 +285,11 @@ impl Reaction for SseReaction {
         let query_configs = self.config.routes.clone();
         let default_template = self.config.default_template.clone();
         let base_sse_path = self.config.sse_path.clone();
+        let query_streams = self.query_streams.clone();
         let processing_handle = tokio::spawn(async move {
             info!("[{reaction_id}] SSE result processing task started");
 
-            let mut handlebars = Handlebars::new();
-
-            // Register the json helper to serialize values as JSON
-            super::register_json_helper(&mut handlebars);
+            let engine = TemplateEngine::new();
 
             loop {
                 if !matches!(status_handle.get_status().await, ComponentStatus::Running) {
@@ -312,6 +319,11 @@ impl Reaction for SseReaction {
                 let query_name = &query_result.query_id;
                 let timestamp = chrono::Utc::now().timestamp_millis();
 
+                // Keep the per-query result set current and notify its connections
+                query_streams
+                    .publish(query_name, &query_result.results, timestamp)
+                    .await;
+
                 // Check if we have configuration for this query
                 // First, try exact match on full query ID
                 // If the query ID is in dotted format (e.g., "source.query" or "namespace.source.query"),
@@ -346,52 +358,24 @@ impl Reaction for SseReaction {
                         };
 
                         if let Some(spec) = template_spec {
-                            // Prepare context for template
-                            let mut context = Map::new();
-
-                            match result {
-                                ResultDiff::Add { data } => {
-                                    context.insert("after".to_string(), data.clone());
-                                }
-                                ResultDiff::Update { before, after, .. } => {
-                                    context.insert("before".to_string(), before.clone());
-                                    context.insert("after".to_string(), after.clone());
-                                }
-                                ResultDiff::Delete { data } => {
-                                    context.insert("before".to_string(), data.clone());
-                                }
-                                ResultDiff::Aggregation { before, after } => {
-                                    if let Some(before) = before {
-                                        context.insert("before".to_string(), before.clone());
-                                    }
-                                    context.insert("after".to_string(), after.clone());
-                                }
-                                ResultDiff::Noop => {}
-                            }
-
-                            context.insert(
-                                "query_name".to_string(),
-                                Value::String(query_name.to_string()),
-                            );
-                            context.insert(
-                                "operation".to_string(),
-                                Value::String(operation.to_string()),
-                            );
-                            context
-                                .insert("timestamp".to_string(), Value::Number(timestamp.into()));
+                            let Some(Value::Object(context)) =
+                                change_context(&query_result, result)
+                            else {
+                                continue;
+                            };
 
                             // Determine the SSE path for this event
                             let sse_path = SseReaction::resolve_sse_path(
                                 spec.extension.path.as_ref(),
                                 &base_sse_path,
-                                &handlebars,
+                                &engine,
                                 &context,
                                 &reaction_id,
                             );
 
                             // Render template if provided
                             let payload = if !spec.template.is_empty() {
-                                match handlebars.render_template(&spec.template, &context) {
+                                match engine.render(&spec.template, &context) {
                                     Ok(rendered) => rendered,
                                     Err(e) => {
                                         error!(
@@ -497,6 +481,7 @@ impl Reaction for SseReaction {
 
         // Heartbeat task - sends to all paths
         let broadcasters_hb = self.broadcasters.clone();
+        let query_streams_hb = self.query_streams.clone();
         let interval = self.config.heartbeat_interval_ms;
         let hb_handle = tokio::spawn(async move {
             let mut ticker = tokio::time::interval(Duration::from_millis(interval));
@@ -505,10 +490,13 @@ impl Reaction for SseReaction {
                 let beat = json!({"type":"heartbeat","ts": chrono::Utc::now().timestamp_millis()})
                     .to_string();
                 // Send heartbeat to all broadcasters
-                let broadcasters_read = broadcasters_hb.read().await;
-                for broadcaster in broadcasters_read.values() {
-                    let _ = broadcaster.send(beat.clone());
+                {
+                    let broadcasters_read = broadcasters_hb.read().await;
+                    for broadcaster in broadcasters_read.values() {
+                        let _ = broadcaster.send(beat.clone());
+                    }
                 }
+                query_streams_hb.heartbeat(&beat).await;
             }
         });
         self.task_handles.lock().await.push(hb_handle);
@@ -517,6 +505,10 @@ impl Reaction for SseReaction {
         let host = self.config.host.clone();
         let port = self.config.port;
         let broadcasters_server = self.broadcasters.clone();
+        let query_streams_server = self.query_streams.clone();
+        let query_prefix = format!("{}/", self.config.query_path.trim_end_matches('/'));
+        let enable_websocket = self.config.enable_websocket;
+        let snapshot_on_connect = self.config.snapshot_on_connect;
         let server_handle = tokio::spawn(async move {
             // Configure CORS to allow all origins
             let cors = CorsLayer::new()
@@ -528,8 +520,20 @@ impl Reaction for SseReaction {
             let broadcasters_clone = broadcasters_server.clone();
             let handler = get(move |req: axum::http::Request<axum::body::Body>| {
                 let broadcasters = broadcasters_clone.clone();
+                let query_streams = query_streams_server.clone();
+                let query_prefix = query_prefix.clone();
                 async move {
-                    let path = req.uri().path().to_string();
+                    let (mut parts, _body) = req.into_parts();
+                    let path = parts.uri.path().to_string();
+
+                    // Serve the stream over WebSocket when the client asks for an upgrade
+                    let websocket = if enable_websocket {
+                        WebSocketUpgrade::from_request_parts(&mut parts, &())
+                            .await
+                            .ok()
+                    } else {
+                        None
+                    };
 
                     // Try to find a broadcaster for this path
                     let broadcaster = {
@@ -537,23 +541,75 @@ impl Reaction for SseReaction {
                         broadcasters_read.get(&path).cloned()
                     };
 
-                    if let Some(broadcaster) = broadcaster {
-                        let rx = broadcaster.subscribe();
-                        let stream = tokio_stream::wrappers::BroadcastStream::new(rx)
-                            .filter_map(|res| res.ok())
-                            .map(|msg| {
-                                Ok::<Event, std::convert::Infallible>(Event::default().data(msg))
-                            });
-                        Sse::new(stream)
-                            .keep_alive(
-                                KeepAlive::new()
-                                    .interval(Duration::from_secs(30))
-                                    .text("keep-alive"),
-                            )
-                            .into_response()
+                    let messages: BoxStream<'static, String> = if let Some(broadcaster) =
+                        broadcaster
+                    {
+                        Box::pin(
+                            BroadcastStream::new(broadcaster.subscribe())
+                                .filter_map(|res| res.ok()),
+                        )
+                    } else if let Some(query_id) = path.strip_prefix(&query_prefix) {
+                        let params = match Query::<Vec<(String, String)>>::try_from_uri(&parts.uri)
+                        {
+                            Ok(Query(params)) => params,
+                            Err(e) => {
+                                return (StatusCode::BAD_REQUEST, e.to_string()).into_response()
+                            }
+                        };
+                        let filter = match ConnectionFilter::parse(
+                            params
+                                .iter()
+                                .filter(|(name, _)| name == "filter")
+                                .map(|(_, value)| value.as_str()),
+                        ) {
+                            Ok(filter) => filter,
+                            Err(e) => {
+                                return (StatusCode::BAD_REQUEST, e.to_string()).into_response()
+                            }
+                        };
+                        let snapshot = match params.iter().find(|(name, _)| name == "snapshot") {
+                            Some((_, value)) => match value.parse::<bool>() {
+                                Ok(snapshot) => snapshot,
+                                Err(_) => {
+                                    return (
+                                        StatusCode::BAD_REQUEST,
+                                        format!("Invalid snapshot parameter '{value}'"),
+                                    )
+                                        .into_response()
+                                }
+                            },
+                            None => snapshot_on_connect,
+                        };
+
+                        let timestamp = chrono::Utc::now().timestamp_millis();
+                        match query_streams
+                            .connect(query_id, filter, snapshot, timestamp)
+                            .await
+                        {
+                            Some(messages) => messages,
+                            None => return StatusCode::NOT_FOUND.into_response(),
+                        }
                     } else {
                         // Return 404 for unknown paths
-                        axum::http::StatusCode::NOT_FOUND.into_response()
+                        return StatusCode::NOT_FOUND.into_response();
+                    };
+
+                    match websocket {
+                        Some(websocket) => websocket
+                            .on_upgrade(move |socket| forward_to_socket(socket, messages))
+                            .into_response(),
+                        None => {
+                            let stream = messages.map(|msg| {
+                                Ok::<Event, std::convert::Infallible>(Event::default().data(msg))
+                            });
+                            Sse::new(stream)
+                                .keep_alive(
+                                    KeepAlive::new()
+                                        .interval(Duration::from_secs(30))
+                                        .text("keep-alive"),
+                                )
+                                .into_response()
+                        }
                     }
                 }
             });
@@ -0,0 +1,437 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-query result streams with connect-time snapshots and filtering.
+//!
+//! Each subscribed query has a [`QueryStream`] holding the query's current
+//! result set, as observed from the result diffs delivered to the reaction,
+//! and a broadcast channel of change events. Clients connecting to the
+//! query's endpoint receive a snapshot of the result set followed by the live
+//! changes, optionally narrowed by a [`ConnectionFilter`].
+
+use futures::stream::BoxStream;
+use futures::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use tokio::sync::{broadcast, RwLock};
+use tokio_stream::wrappers::BroadcastStream;
+
+use axum::extract::ws::{Message, WebSocket};
+use drasi_lib::channels::ResultDiff;
+
+const QUERY_STREAM_CHANNEL_CAPACITY: usize = 1024;
+
+/// Event broadcast to the connections of a query stream.
+#[derive(Debug, Clone)]
+pub(crate) enum StreamEvent {
+    /// A result change. `before` and `after` are the row values used for filtering.
+    Change {
+        before: Option<Value>,
+        after: Option<Value>,
+        payload: String,
+    },
+    /// A heartbeat, delivered to every connection regardless of its filter.
+    Heartbeat(String),
+}
+
+/// Current result set of a query, kept as a multiset of rows.
+#[derive(Debug, Default)]
+pub(crate) struct ResultSet {
+    rows: BTreeMap<String, (Value, usize)>,
+}
+
+impl ResultSet {
+    /// Apply a result diff to the set.
+    pub(crate) fn apply(&mut self, diff: &ResultDiff) {
+        match diff {
+            ResultDiff::Add { data } => self.insert(data),
+            ResultDiff::Delete { data } => self.remove(data),
+            ResultDiff::Update { before, after, .. } => {
+                self.remove(before);
+                self.insert(after);
+            }
+            ResultDiff::Aggregation { before, after } => {
+                if let Some(before) = before {
+                    self.remove(before);
+                }
+                self.insert(after);
+            }
+            ResultDiff::Noop => {}
+        }
+    }
+
+    /// Rows of the set, with duplicates repeated.
+    pub(crate) fn rows(&self) -> Vec<Value> {
+        self.rows
+            .values()
+            .flat_map(|(row, count)| std::iter::repeat_n(row.clone(), *count))
+            .collect()
+    }
+
+    fn insert(&mut self, row: &Value) {
+        self.rows
+            .entry(row.to_string())
+            .or_insert_with(|| (row.clone(), 0))
+            .1 += 1;
+    }
+
+    fn remove(&mut self, row: &Value) {
+        let key = row.to_string();
+        if let Some((_, count)) = self.rows.get_mut(&key) {
+            *count -= 1;
+            if *count == 0 {
+                self.rows.remove(&key);
+            }
+        }
+    }
+}
+
+/// Result set and change channel of a single query.
+pub(crate) struct QueryStream {
+    results: ResultSet,
+    sender: broadcast::Sender<Arc<StreamEvent>>,
+}
+
+impl QueryStream {
+    fn new() -> Self {
+        let (sender, _rx) = broadcast::channel(QUERY_STREAM_CHANNEL_CAPACITY);
+        Self {
+            results: ResultSet::default(),
+            sender,
+        }
+    }
+}
+
+/// Streams for all subscribed queries, keyed by query ID.
+#[derive(Clone)]
+pub(crate) struct QueryStreams {
+    streams: Arc<RwLock<HashMap<String, QueryStream>>>,
+}
+
+impl QueryStreams {
+    pub(crate) fn new(query_ids: &[String]) -> Self {
+        let streams = query_ids
+            .iter()
+            .map(|id| (id.clone(), QueryStream::new()))
+            .collect();
+        Self {
+            streams: Arc::new(RwLock::new(streams)),
+        }
+    }
+
+    /// Apply a query's result diffs to its result set and broadcast them.
+    ///
+    /// The write lock is held while broadcasting so that a connecting client
+    /// never misses or double counts a change between its snapshot and its
+    /// subscription.
+    pub(crate) async fn publish(&self, query_id: &str, results: &[ResultDiff], timestamp: i64) {
+        let mut streams = self.streams.write().await;
+        let stream = streams
+            .entry(query_id.to_string())
+            .or_insert_with(QueryStream::new);
+
+        for diff in results {
+            if matches!(diff, ResultDiff::Noop) {
+                continue;
+            }
+            stream.results.apply(diff);
+
+            let (before, after) = match diff {
+                ResultDiff::Add { data } => (None, Some(data.clone())),
+                ResultDiff::Delete { data } => (Some(data.clone()), None),
+                ResultDiff::Update { before, after, .. } => {
+                    (Some(before.clone()), Some(after.clone()))
+                }
+                ResultDiff::Aggregation { before, after } => (before.clone(), Some(after.clone())),
+                ResultDiff::Noop => (None, None),
+            };
+            let payload = json!({
+                "type": "change",
+                "queryId": query_id,
+                "result": diff,
+                "timestamp": timestamp
+            })
+            .to_string();
+
+            // Sending only fails when no client is connected
+            let _ = stream.sender.send(Arc::new(StreamEvent::Change {
+                before,
+                after,
+                payload,
+            }));
+        }
+    }
+
+    /// Send a heartbeat to every query stream.
+    pub(crate) async fn heartbeat(&self, beat: &str) {
+        let streams = self.streams.read().await;
+        for stream in streams.values() {
+            let _ = stream
+                .sender
+                .send(Arc::new(StreamEvent::Heartbeat(beat.to_string())));
+        }
+    }
+
+    /// Open a connection to a query stream.
+    ///
+    /// Returns `None` if the reaction is not subscribed to the query. The
+    /// returned stream starts with a snapshot message when `snapshot` is set.
+    pub(crate) async fn connect(
+        &self,
+        query_id: &str,
+        filter: ConnectionFilter,
+        snapshot: bool,
+        timestamp: i64,
+    ) -> Option<BoxStream<'static, String>> {
+        let streams = self.streams.read().await;
+        let stream = streams.get(query_id)?;
+        let receiver = stream.sender.subscribe();
+
+        let initial = if snapshot {
+            let rows: Vec<Value> = stream
+                .results
+                .rows()
+                .into_iter()
+                .filter(|row| filter.matches(row))
+                .collect();
+            vec![json!({
+                "type": "snapshot",
+                "queryId": query_id,
+                "results": rows,
+                "timestamp": timestamp
+            })
+            .to_string()]
+        } else {
+            Vec::new()
+        };
+        drop(streams);
+
+        let changes = BroadcastStream::new(receiver).filter_map(move |event| {
+            let message = match event.as_deref() {
+                Ok(StreamEvent::Change {
+                    before,
+                    after,
+                    payload,
+                }) => {
+                    let visible = before.iter().chain(after.iter()).any(|r| filter.matches(r));
+                    visible.then(|| payload.clone())
+                }
+                Ok(StreamEvent::Heartbeat(beat)) => Some(beat.clone()),
+                // The connection lagged behind; the skipped changes are lost
+                Err(_) => None,
+            };
+            futures::future::ready(message)
+        });
+
+        Some(futures::stream::iter(initial).chain(changes).boxed())
+    }
+}
+
+/// Row filter requested by a client when connecting to a query stream.
+///
+/// Each condition is written as `path:value` and compares the row field at the
+/// dotted `path` with `value`. A row matches when every condition holds.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct ConnectionFilter {
+    conditions: Vec<(Vec<String>, String)>,
+}
+
+impl ConnectionFilter {
+    /// Parse filter conditions of the form `path:value`.
+    pub(crate) fn parse<'a>(conditions: impl IntoIterator<Item = &'a str>) -> anyhow::Result<Self> {
+        let conditions = conditions
+            .into_iter()
+            .map(|condition| {
+                let (path, value) = condition.split_once(':').ok_or_else(|| {
+                    anyhow::anyhow!("Invalid filter '{condition}', expected 'field:value'")
+                })?;
+                let path: Vec<String> = path.split('.').map(str::to_string).collect();
+                if path.iter().any(String::is_empty) {
+                    anyhow::bail!("Invalid filter '{condition}', field name cannot be empty");
+                }
+                Ok((path, value.to_string()))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        Ok(Self { conditions })
+    }
+
+    /// Check whether a row satisfies all conditions.
+    pub(crate) fn matches(&self, row: &Value) -> bool {
+        self.conditions.iter().all(|(path, expected)| {
+            let field = path
+                .iter()
+                .try_fold(row, |value, segment| value.get(segment));
+            match field {
+                Some(Value::String(s)) => s == expected,
+                Some(value) => value.to_string() == *expected,
+                None => false,
+            }
+        })
+    }
+}
+
+/// Forward a message stream to a WebSocket until either side closes.
+pub(crate) async fn forward_to_socket(socket: WebSocket, mut messages: BoxStream<'static, String>) {
+    let (mut sender, mut receiver) = socket.split();
+    loop {
+        tokio::select! {
+            message = messages.next() => match message {
+                Some(text) => {
+                    if sender.send(Message::Text(text)).await.is_err() {
+                        break;
+                    }
+                }
+                None => break,
+            },
+            incoming = receiver.next() => match incoming {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                // Messages from the client are ignored
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+    let _ = sender.close().await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_result_set_tracks_diffs() {
+        let mut set = ResultSet::default();
+        set.apply(&ResultDiff::Add {
+            data: json!({"id": 1, "status": "open"}),
+        });
+        set.apply(&ResultDiff::Add {
+            data: json!({"id": 2, "status": "open"}),
+        });
+        set.apply(&ResultDiff::Update {
+            data: json!({}),
+            before: json!({"id": 1, "status": "open"}),
+            after: json!({"id": 1, "status": "closed"}),
+            grouping_keys: None,
+        });
+        set.apply(&ResultDiff::Delete {
+            data: json!({"id": 2, "status": "open"}),
+        });
+
+        assert_eq!(set.rows(), vec![json!({"id": 1, "status": "closed"})]);
+    }
+
+    #[test]
+    fn test_result_set_keeps_duplicate_rows() {
+        let mut set = ResultSet::default();
+        let row = json!({"count": 1});
+        set.apply(&ResultDiff::Add { data: row.clone() });
+        set.apply(&ResultDiff::Add { data: row.clone() });
+        assert_eq!(set.rows().len(), 2);
+
+        set.apply(&ResultDiff::Delete { data: row.clone() });
+        assert_eq!(set.rows(), vec![row]);
+    }
+
+    #[test]
+    fn test_result_set_aggregation_replaces_previous_value() {
+        let mut set = ResultSet::default();
+        set.apply(&ResultDiff::Aggregation {
+            before: None,
+            after: json!({"total": 1}),
+        });
+        set.apply(&ResultDiff::Aggregation {
+            before: Some(json!({"total": 1})),
+            after: json!({"total": 2}),
+        });
+        assert_eq!(set.rows(), vec![json!({"total": 2})]);
+    }
+
+    #[test]
+    fn test_connection_filter_matches() {
+        let filter = ConnectionFilter::parse(["status:open", "location.floor:3"]).unwrap();
+
+        assert!(filter.matches(&json!({"status": "open", "location": {"floor": 3}})));
+        assert!(!filter.matches(&json!({"status": "closed", "location": {"floor": 3}})));
+        assert!(!filter.matches(&json!({"status": "open"})));
+        assert!(ConnectionFilter::default().matches(&json!({"any": "row"})));
+    }
+
+    #[test]
+    fn test_connection_filter_rejects_invalid_conditions() {
+        assert!(ConnectionFilter::parse(["status"]).is_err());
+        assert!(ConnectionFilter::parse([":open"]).is_err());
+        assert!(ConnectionFilter::parse(["a..b:1"]).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_connect_sends_filtered_snapshot_then_changes() {
+        let streams = QueryStreams::new(&["q1".to_string()]);
+        streams
+            .publish(
+                "q1",
+                &[
+                    ResultDiff::Add {
+                        data: json!({"id": 1, "status": "open"}),
+                    },
+                    ResultDiff::Add {
+                        data: json!({"id": 2, "status": "closed"}),
+                    },
+                ],
+                1,
+            )
+            .await;
+
+        let filter = ConnectionFilter::parse(["status:open"]).unwrap();
+        let mut messages = streams.connect("q1", filter, true, 2).await.unwrap();
+
+        let snapshot: Value = serde_json::from_str(&messages.next().await.unwrap()).unwrap();
+        assert_eq!(snapshot["type"], "snapshot");
+        assert_eq!(snapshot["results"], json!([{"id": 1, "status": "open"}]));
+
+        streams
+            .publish(
+                "q1",
+                &[
+                    ResultDiff::Add {
+                        data: json!({"id": 3, "status": "closed"}),
+                    },
+                    ResultDiff::Update {
+                        data: json!({}),
+                        before: json!({"id": 1, "status": "open"}),
+                        after: json!({"id": 1, "status": "closed"}),
+                        grouping_keys: None,
+                    },
+                ],
+                3,
+            )
+            .await;
+
+        // The closed row is filtered out; the update leaving the filter is delivered
+        let change: Value = serde_json::from_str(&messages.next().await.unwrap()).unwrap();
+        assert_eq!(change["type"], "change");
+        assert_eq!(
+            change["result"]["after"],
+            json!({"id": 1, "status": "closed"})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_connect_unknown_query() {
+        let streams = QueryStreams::new(&["q1".to_string()]);
+        assert!(streams
+            .connect("q2", ConnectionFilter::default(), true, 0)
+            .await
+            .is_none());
+    }
+}
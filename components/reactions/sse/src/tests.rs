@@ -167,6 +167,9 @@ fn test_sse_config_with_routes_serialization() {
         port: 8080,
         sse_path: "/events".to_string(),
         heartbeat_interval_ms: 30000,
+        query_path: "/queries".to_string(),
+        enable_websocket: true,
+        snapshot_on_connect: true,
         routes,
         default_template: None,
     };
@@ -246,6 +249,23 @@ fn test_sse_builder_with_default_template() {
     assert_eq!(reaction.query_ids().len(), 2);
 }
 
+#[test]
+fn test_resolve_sse_path_uses_shared_template_helpers() {
+    let engine = drasi_lib::templating::TemplateEngine::new();
+    let context = serde_json::json!({ "after": { "room": "Lab-1" } });
+    let context = context.as_object().unwrap();
+    let path = Some("rooms/{{lower after.room}}".to_string());
+
+    assert_eq!(
+        SseReaction::resolve_sse_path(path.as_ref(), "/events", &engine, context, "test"),
+        "/events/rooms/lab-1"
+    );
+    assert_eq!(
+        SseReaction::resolve_sse_path(None, "/events", &engine, context, "test"),
+        "/events"
+    );
+}
+
 #[test]
 fn test_sse_builder_invalid_template_fails() {
     let invalid_template = QueryConfig {
@@ -344,6 +364,9 @@ fn test_config_with_default_template_serialization() {
         port: 8080,
         sse_path: "/events".to_string(),
         heartbeat_interval_ms: 30000,
+        query_path: "/queries".to_string(),
+        enable_websocket: true,
+        snapshot_on_connect: true,
         routes: std::collections::HashMap::new(),
         default_template: Some(default_template),
     };
@@ -353,3 +376,42 @@ fn test_config_with_default_template_serialization() {
 
     assert_eq!(config, deserialized);
 }
+
+#[test]
+fn test_sse_builder_query_endpoint_options() {
+    let reaction = SseReaction::builder("test-reaction")
+        .with_query_path("/live")
+        .with_websocket(false)
+        .with_snapshot_on_connect(false)
+        .build()
+        .unwrap();
+
+    let props = reaction.properties();
+    assert_eq!(props.get("query_path"), Some(&serde_json::json!("/live")));
+    assert_eq!(
+        props.get("enable_websocket"),
+        Some(&serde_json::json!(false))
+    );
+    assert_eq!(
+        props.get("snapshot_on_connect"),
+        Some(&serde_json::json!(false))
+    );
+}
+
+#[test]
+fn test_sse_builder_invalid_query_path_fails() {
+    for path in ["queries", "/", ""] {
+        let result = SseReaction::builder("test-reaction")
+            .with_query_path(path)
+            .build();
+        assert!(result.is_err(), "Query path '{path}' should be rejected");
+    }
+}
+
+#[test]
+fn test_config_defaults_for_query_endpoints() {
+    let config: SseReactionConfig = serde_json::from_str("{}").unwrap();
+    assert_eq!(config.query_path, "/queries");
+    assert!(config.enable_websocket);
+    assert!(config.snapshot_on_connect);
+}
@@ -703,3 +703,207 @@ async fn test_sse_multi_path_integration() -> Result<()> {
     core.stop().await?;
     Ok(())
 }
+
+/// Read the next non-heartbeat SSE event as JSON
+async fn next_data_event<S>(events: &mut S) -> Option<serde_json::Value>
+where
+    S: futures_util::Stream<
+            Item = Result<
+                eventsource_stream::Event,
+                eventsource_stream::EventStreamError<reqwest::Error>,
+            >,
+        > + Unpin,
+{
+    while let Some(event_result) = events.next().await {
+        let data = event_result.ok()?.data;
+        log::info!("Received SSE event: {data}");
+        let value: serde_json::Value = serde_json::from_str(&data).ok()?;
+        if value["type"] != "heartbeat" {
+            return Some(value);
+        }
+    }
+    None
+}
+
+/// Test per-query endpoint with snapshot on connect and a connection filter
+#[tokio::test]
+async fn test_sse_query_endpoint_snapshot_and_filter() -> Result<()> {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info"))
+        .is_test(true)
+        .try_init();
+
+    let (mock_source, handle) = MockSource::new("test-source")?;
+
+    let query = Query::cypher("person-query")
+        .query(
+            r#"
+            MATCH (p:Person)
+            RETURN p.name AS name, p.city AS city
+        "#,
+        )
+        .from_source("test-source")
+        .auto_start(true)
+        .build();
+
+    let sse_reaction = SseReaction::builder("test-sse")
+        .with_port(18083)
+        .with_query("person-query")
+        .build()?;
+
+    let core = Arc::new(
+        DrasiLib::builder()
+            .with_id("test-core")
+            .with_source(mock_source)
+            .with_query(query)
+            .with_reaction(sse_reaction)
+            .build()
+            .await?,
+    );
+
+    core.start().await?;
+    sleep(Duration::from_millis(500)).await;
+
+    // Results produced before the client connects end up in its snapshot
+    for (id, name, city) in [("p1", "Alice", "Seattle"), ("p2", "Bob", "Portland")] {
+        let props = PropertyMapBuilder::new()
+            .with_string("name", name)
+            .with_string("city", city)
+            .build();
+        handle.send_node_insert(id, vec!["Person"], props).await?;
+    }
+    sleep(Duration::from_millis(500)).await;
+
+    let client = reqwest::Client::new();
+    let response = client
+        .get("http://localhost:18083/queries/person-query?filter=city:Seattle")
+        .send()
+        .await?;
+    assert_eq!(response.status(), 200);
+    let mut events = eventsource_stream::EventStream::new(response.bytes_stream()).take(10);
+
+    let snapshot = next_data_event(&mut events)
+        .await
+        .expect("Should receive a snapshot");
+    assert_eq!(snapshot["type"], "snapshot");
+    assert_eq!(snapshot["queryId"], "person-query");
+    assert_eq!(
+        snapshot["results"],
+        serde_json::json!([{"name": "Alice", "city": "Seattle"}])
+    );
+
+    // Only changes matching the filter are delivered
+    for (id, name, city) in [("p3", "Carol", "Portland"), ("p4", "Dave", "Seattle")] {
+        let props = PropertyMapBuilder::new()
+            .with_string("name", name)
+            .with_string("city", city)
+            .build();
+        handle.send_node_insert(id, vec!["Person"], props).await?;
+        sleep(Duration::from_millis(100)).await;
+    }
+
+    let change = next_data_event(&mut events)
+        .await
+        .expect("Should receive a change");
+    assert_eq!(change["type"], "change");
+    assert_eq!(change["result"]["data"]["name"], "Dave");
+
+    // Unknown queries and invalid filters are rejected
+    let response = client
+        .get("http://localhost:18083/queries/other-query")
+        .send()
+        .await?;
+    assert_eq!(response.status(), 404);
+    let response = client
+        .get("http://localhost:18083/queries/person-query?filter=city")
+        .send()
+        .await?;
+    assert_eq!(response.status(), 400);
+
+    core.stop().await?;
+    Ok(())
+}
+
+/// Read the next non-heartbeat WebSocket message as JSON
+async fn next_ws_event<S>(socket: &mut S) -> Option<serde_json::Value>
+where
+    S: futures_util::Stream<
+            Item = Result<
+                tokio_tungstenite::tungstenite::Message,
+                tokio_tungstenite::tungstenite::Error,
+            >,
+        > + Unpin,
+{
+    while let Some(message) = socket.next().await {
+        if let tokio_tungstenite::tungstenite::Message::Text(text) = message.ok()? {
+            let value: serde_json::Value = serde_json::from_str(&text).ok()?;
+            if value["type"] != "heartbeat" {
+                return Some(value);
+            }
+        }
+    }
+    None
+}
+
+/// Test streaming a per-query endpoint over WebSocket
+#[tokio::test]
+async fn test_websocket_query_endpoint() -> Result<()> {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info"))
+        .is_test(true)
+        .try_init();
+
+    let (mock_source, handle) = MockSource::new("test-source")?;
+
+    let query = Query::cypher("person-query")
+        .query(
+            r#"
+            MATCH (p:Person)
+            RETURN p.name AS name
+        "#,
+        )
+        .from_source("test-source")
+        .auto_start(true)
+        .build();
+
+    let sse_reaction = SseReaction::builder("test-sse")
+        .with_port(18084)
+        .with_query("person-query")
+        .build()?;
+
+    let core = Arc::new(
+        DrasiLib::builder()
+            .with_id("test-core")
+            .with_source(mock_source)
+            .with_query(query)
+            .with_reaction(sse_reaction)
+            .build()
+            .await?,
+    );
+
+    core.start().await?;
+    sleep(Duration::from_millis(500)).await;
+
+    let (mut socket, _) =
+        tokio_tungstenite::connect_async("ws://localhost:18084/queries/person-query").await?;
+
+    let snapshot = next_ws_event(&mut socket)
+        .await
+        .expect("Should receive a snapshot");
+    assert_eq!(snapshot["type"], "snapshot");
+    assert_eq!(snapshot["results"], serde_json::json!([]));
+
+    let props = PropertyMapBuilder::new()
+        .with_string("name", "Alice")
+        .build();
+    handle
+        .send_node_insert("person-1", vec!["Person"], props)
+        .await?;
+
+    let change = tokio::time::timeout(Duration::from_secs(5), next_ws_event(&mut socket))
+        .await?
+        .expect("Should receive a change");
+    assert_eq!(change["type"], "change");
+    assert_eq!(change["result"]["data"]["name"], "Alice");
+
+    core.stop().await?;
+    Ok(())
+}
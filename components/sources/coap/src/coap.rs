@@ -0,0 +1,619 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! CoAP source implementation and builder.
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use coap_lite::{MessageType, Packet};
+use log::{debug, error, info, warn};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::UdpSocket;
+use tokio::sync::RwLock;
+use tokio::task::JoinSet;
+
+use drasi_core::models::SourceChange;
+use drasi_lib::channels::*;
+use drasi_lib::managers::{log_component_start, log_component_stop};
+use drasi_lib::sources::base::{SourceBase, SourceBaseParams};
+use drasi_lib::Source;
+use tracing::Instrument;
+
+use crate::config::{CoapResource, CoapSourceConfig, CoapUri, PayloadFormat};
+use crate::conversion::{
+    decode_payload, element_id_for, label_for_resource, observation_to_source_change,
+    removal_to_source_change,
+};
+use crate::protocol::{classify, empty_reply, encode, is_fresher, observe_request, Notification};
+
+type Dispatchers = Arc<RwLock<Vec<Box<dyn ChangeDispatcher<SourceEventWrapper> + Send + Sync>>>>;
+
+/// Largest datagram the source accepts. CoAP messages must fit in one datagram.
+const MAX_DATAGRAM_SIZE: usize = 1500;
+
+/// Source that observes CoAP resources and upserts one node per observed element.
+///
+/// Each resource gets its own UDP socket and observe relationship. Resources
+/// that cannot be reached are retried without affecting the others.
+pub struct CoapSource {
+    /// Base source implementation providing dispatchers, status tracking, and lifecycle management.
+    base: SourceBase,
+    /// CoAP configuration.
+    config: CoapSourceConfig,
+}
+
+/// State of one observe relationship.
+struct Observation {
+    resource: CoapResource,
+    uri: CoapUri,
+    label: String,
+    token: Vec<u8>,
+    message_id: u16,
+    last_sequence: Option<u32>,
+    /// Elements inserted from this resource, so later observations become updates.
+    elements: HashSet<String>,
+}
+
+impl CoapSource {
+    /// Create a builder for a CoAP source.
+    pub fn builder(id: impl Into<String>) -> CoapSourceBuilder {
+        CoapSourceBuilder::new(id)
+    }
+
+    /// Create a new CoAP source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid.
+    pub fn new(id: impl Into<String>, config: CoapSourceConfig) -> Result<Self> {
+        CoapSourceBuilder::new(id).with_config(config).build()
+    }
+
+    async fn dispatch(
+        source_id: &str,
+        dispatchers: &Dispatchers,
+        change: SourceChange,
+    ) -> Result<()> {
+        let mut profiling = drasi_lib::profiling::ProfilingMetadata::new();
+        profiling.source_send_ns = Some(drasi_lib::profiling::timestamp_ns());
+
+        let wrapper = SourceEventWrapper::with_profiling(
+            source_id.to_string(),
+            SourceEvent::Change(change),
+            chrono::Utc::now(),
+            profiling,
+        );
+
+        SourceBase::dispatch_from_task(dispatchers.clone(), wrapper, source_id).await
+    }
+
+    /// Token identifying the observation of the resource at `index`.
+    fn token_for(index: usize) -> Vec<u8> {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.subsec_nanos())
+            .unwrap_or_default();
+        (nanos ^ index as u32).to_be_bytes().to_vec()
+    }
+
+    async fn connect(uri: &CoapUri) -> Result<UdpSocket> {
+        let addr = tokio::net::lookup_host((uri.host.as_str(), uri.port))
+            .await?
+            .next()
+            .ok_or_else(|| anyhow!("No address found for {}", uri.host))?;
+        let bind_addr = if addr.is_ipv4() {
+            "0.0.0.0:0"
+        } else {
+            "[::]:0"
+        };
+        let socket = UdpSocket::bind(bind_addr).await?;
+        socket.connect(addr).await?;
+        Ok(socket)
+    }
+
+    async fn send(socket: &UdpSocket, packet: &Packet) -> Result<()> {
+        socket.send(&encode(packet)?).await?;
+        Ok(())
+    }
+
+    /// Handle a representation of the resource.
+    async fn handle_value(
+        source_id: &str,
+        config: &CoapSourceConfig,
+        dispatchers: &Dispatchers,
+        observation: &mut Observation,
+        payload: &[u8],
+        cbor: bool,
+    ) {
+        if payload.is_empty() {
+            return;
+        }
+
+        let value = match decode_payload(payload, cbor) {
+            Ok(value) => value,
+            Err(e) => {
+                warn!(
+                    "[{source_id}] Skipping observation of {}: {e}",
+                    observation.resource.uri
+                );
+                return;
+            }
+        };
+
+        let element_id = element_id_for(&value, &observation.resource, config);
+        let exists = observation.elements.contains(&element_id);
+        let change =
+            observation_to_source_change(source_id, &element_id, &observation.label, value, exists);
+
+        match Self::dispatch(source_id, dispatchers, change).await {
+            Ok(()) => {
+                observation.elements.insert(element_id);
+            }
+            Err(e) => warn!("[{source_id}] Failed to dispatch observation: {e}"),
+        }
+    }
+
+    /// Delete every element seen from a resource that no longer exists.
+    async fn handle_removed(
+        source_id: &str,
+        dispatchers: &Dispatchers,
+        observation: &mut Observation,
+    ) {
+        for element_id in observation.elements.drain() {
+            let change = removal_to_source_change(source_id, &element_id, &observation.label);
+            if let Err(e) = Self::dispatch(source_id, dispatchers, change).await {
+                warn!("[{source_id}] Failed to dispatch removal of '{element_id}': {e}");
+            }
+        }
+    }
+
+    /// Register as an observer and process notifications until nothing has
+    /// been received for `reregister_interval_ms`.
+    async fn observe_once(
+        source_id: &str,
+        config: &CoapSourceConfig,
+        dispatchers: &Dispatchers,
+        socket: &UdpSocket,
+        observation: &mut Observation,
+    ) -> Result<()> {
+        observation.message_id = observation.message_id.wrapping_add(1);
+        let request = observe_request(&observation.uri, observation.message_id, &observation.token);
+        Self::send(socket, &request).await?;
+        debug!(
+            "[{source_id}] Registered observer on {}",
+            observation.resource.uri
+        );
+
+        let request_timeout = Duration::from_millis(config.request_timeout_ms);
+        let reregister_interval = Duration::from_millis(config.reregister_interval_ms);
+        let mut wait = request_timeout;
+        let mut buf = [0u8; MAX_DATAGRAM_SIZE];
+
+        loop {
+            let len = match tokio::time::timeout(wait, socket.recv(&mut buf)).await {
+                Ok(result) => result?,
+                Err(_) => return Ok(()),
+            };
+
+            let packet = match Packet::from_bytes(&buf[..len]) {
+                Ok(packet) => packet,
+                Err(e) => {
+                    debug!("[{source_id}] Ignoring malformed CoAP message: {e:?}");
+                    continue;
+                }
+            };
+
+            let confirmable = packet.header.get_type() == MessageType::Confirmable;
+            if packet.get_token() != observation.token.as_slice() {
+                // Stale observation from an earlier registration; tell the server to drop it
+                if confirmable {
+                    Self::send(socket, &empty_reply(packet.header.message_id, false)).await?;
+                }
+                continue;
+            }
+            if confirmable {
+                Self::send(socket, &empty_reply(packet.header.message_id, true)).await?;
+            }
+
+            match classify(&packet, config.payload_format) {
+                Notification::Value {
+                    payload,
+                    cbor,
+                    observe,
+                } => {
+                    if let Some(sequence) = observe {
+                        if !is_fresher(observation.last_sequence, sequence) {
+                            continue;
+                        }
+                        observation.last_sequence = Some(sequence);
+                    }
+                    Self::handle_value(source_id, config, dispatchers, observation, &payload, cbor)
+                        .await;
+                    if observe.is_none() {
+                        // The server does not support observe; poll again after the interval
+                        tokio::time::sleep(reregister_interval).await;
+                        return Ok(());
+                    }
+                    wait = reregister_interval;
+                }
+                Notification::Removed => {
+                    info!(
+                        "[{source_id}] Resource {} not found",
+                        observation.resource.uri
+                    );
+                    Self::handle_removed(source_id, dispatchers, observation).await;
+                    tokio::time::sleep(reregister_interval).await;
+                    return Ok(());
+                }
+                Notification::Rejected(code) => {
+                    return Err(anyhow!("server responded with {code}"));
+                }
+                Notification::Empty => {}
+            }
+        }
+    }
+
+    async fn observe_resource(
+        source_id: String,
+        config: Arc<CoapSourceConfig>,
+        dispatchers: Dispatchers,
+        index: usize,
+    ) {
+        let resource = config.resources[index].clone();
+        let uri = match CoapUri::parse(&resource.uri) {
+            Ok(uri) => uri,
+            Err(e) => {
+                error!("[{source_id}] {e}");
+                return;
+            }
+        };
+
+        let mut observation = Observation {
+            label: label_for_resource(&resource, &uri, &config),
+            token: Self::token_for(index),
+            message_id: (std::process::id() as u16).wrapping_add((index as u16).wrapping_mul(1000)),
+            last_sequence: None,
+            elements: HashSet::new(),
+            resource,
+            uri,
+        };
+        let retry_interval = Duration::from_millis(config.request_timeout_ms);
+
+        loop {
+            let result = match Self::connect(&observation.uri).await {
+                Ok(socket) => {
+                    Self::observe_once(&source_id, &config, &dispatchers, &socket, &mut observation)
+                        .await
+                }
+                Err(e) => Err(e),
+            };
+
+            if let Err(e) = result {
+                warn!(
+                    "[{source_id}] Observation of {} failed, retrying in {}ms: {e}",
+                    observation.resource.uri, config.request_timeout_ms
+                );
+                tokio::time::sleep(retry_interval).await;
+            }
+            // Sequence numbers restart with each registration
+            observation.last_sequence = None;
+        }
+    }
+
+    async fn run(source_id: String, config: CoapSourceConfig, dispatchers: Dispatchers) {
+        let config = Arc::new(config);
+        let mut observers = JoinSet::new();
+        for index in 0..config.resources.len() {
+            observers.spawn(
+                Self::observe_resource(
+                    source_id.clone(),
+                    config.clone(),
+                    dispatchers.clone(),
+                    index,
+                )
+                .in_current_span(),
+            );
+        }
+
+        // Dropping the set when this task is aborted aborts every observer
+        while observers.join_next().await.is_some() {}
+    }
+}
+
+#[async_trait]
+impl Source for CoapSource {
+    fn id(&self) -> &str {
+        &self.base.id
+    }
+
+    fn type_name(&self) -> &str {
+        "coap"
+    }
+
+    fn properties(&self) -> HashMap<String, serde_json::Value> {
+        use crate::descriptor::{CoapResourceDto, CoapSourceConfigDto, PayloadFormatDto};
+        use drasi_plugin_sdk::ConfigValue;
+
+        let dto = CoapSourceConfigDto {
+            resources: self
+                .config
+                .resources
+                .iter()
+                .map(|r| CoapResourceDto {
+                    uri: ConfigValue::Static(r.uri.clone()),
+                    element_id: r.element_id.clone(),
+                    label: r.label.clone(),
+                })
+                .collect(),
+            payload_format: match self.config.payload_format {
+                PayloadFormat::Auto => PayloadFormatDto::Auto,
+                PayloadFormat::Json => PayloadFormatDto::Json,
+                PayloadFormat::Cbor => PayloadFormatDto::Cbor,
+            },
+            reregister_interval_ms: ConfigValue::Static(self.config.reregister_interval_ms),
+            request_timeout_ms: ConfigValue::Static(self.config.request_timeout_ms),
+            id_field: self.config.id_field.clone(),
+            label: self.config.label.clone(),
+        };
+
+        match serde_json::to_value(&dto) {
+            Ok(serde_json::Value::Object(map)) => map.into_iter().collect(),
+            _ => HashMap::new(),
+        }
+    }
+
+    fn auto_start(&self) -> bool {
+        self.base.get_auto_start()
+    }
+
+    async fn start(&self) -> Result<()> {
+        log_component_start("CoAP Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Starting,
+                Some("Starting CoAP source".to_string()),
+            )
+            .await;
+
+        let instance_id = self
+            .base
+            .context()
+            .await
+            .map(|c| c.instance_id)
+            .unwrap_or_default();
+
+        let span = tracing::info_span!(
+            "coap_source_observer",
+            instance_id = %instance_id,
+            component_id = %self.base.id,
+            component_type = "source"
+        );
+        let task = tokio::spawn(
+            Self::run(
+                self.base.id.clone(),
+                self.config.clone(),
+                self.base.dispatchers.clone(),
+            )
+            .instrument(span),
+        );
+
+        *self.base.task_handle.write().await = Some(task);
+
+        // CoAP is connectionless; unreachable resources are retried in the background
+        self.base
+            .set_status(
+                ComponentStatus::Running,
+                Some(format!(
+                    "Observing {} CoAP resource(s)",
+                    self.config.resources.len()
+                )),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        log_component_stop("CoAP Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopping,
+                Some("Stopping CoAP source".to_string()),
+            )
+            .await;
+
+        // Servers drop the observers once their next confirmable notification goes unanswered
+        if let Some(handle) = self.base.task_handle.write().await.take() {
+            handle.abort();
+            let _ = handle.await;
+        }
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopped,
+                Some("CoAP source stopped".to_string()),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn status(&self) -> ComponentStatus {
+        self.base.get_status().await
+    }
+
+    async fn subscribe(
+        &self,
+        settings: drasi_lib::config::SourceSubscriptionSettings,
+    ) -> Result<SubscriptionResponse> {
+        self.base.subscribe_with_bootstrap(&settings, "CoAP").await
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    async fn initialize(&self, context: drasi_lib::context::SourceRuntimeContext) {
+        self.base.initialize(context).await;
+    }
+
+    async fn set_bootstrap_provider(
+        &self,
+        provider: Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>,
+    ) {
+        self.base.set_bootstrap_provider(provider).await;
+    }
+}
+
+/// Builder for [`CoapSource`] instances.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use drasi_source_coap::{CoapSource, PayloadFormat};
+///
+/// let source = CoapSource::builder("field-sensors")
+///     .with_resource("coap://10.0.0.12/sensors/temp")
+///     .with_resource("coap://10.0.0.13/sensors/temp")
+///     .with_payload_format(PayloadFormat::Cbor)
+///     .with_label("Sensor")
+///     .build()?;
+/// ```
+pub struct CoapSourceBuilder {
+    id: String,
+    config: CoapSourceConfig,
+    dispatch_mode: Option<DispatchMode>,
+    dispatch_buffer_capacity: Option<usize>,
+    bootstrap_provider: Option<Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>>,
+    auto_start: bool,
+}
+
+impl CoapSourceBuilder {
+    /// Create a new builder with the given ID and default values.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            config: CoapSourceConfig::default(),
+            dispatch_mode: None,
+            dispatch_buffer_capacity: None,
+            bootstrap_provider: None,
+            auto_start: true,
+        }
+    }
+
+    /// Observe the resource at `uri`.
+    pub fn with_resource(mut self, uri: impl Into<String>) -> Self {
+        self.config.resources.push(CoapResource::new(uri));
+        self
+    }
+
+    /// Observe a resource with ID or label overrides.
+    pub fn with_resource_config(mut self, resource: CoapResource) -> Self {
+        self.config.resources.push(resource);
+        self
+    }
+
+    /// Set how payloads are decoded.
+    pub fn with_payload_format(mut self, format: PayloadFormat) -> Self {
+        self.config.payload_format = format;
+        self
+    }
+
+    /// Set the silence after which observations are registered again, in milliseconds.
+    pub fn with_reregister_interval_ms(mut self, interval_ms: u64) -> Self {
+        self.config.reregister_interval_ms = interval_ms;
+        self
+    }
+
+    /// Set the registration response timeout in milliseconds.
+    pub fn with_request_timeout_ms(mut self, timeout_ms: u64) -> Self {
+        self.config.request_timeout_ms = timeout_ms;
+        self
+    }
+
+    /// Set the payload field holding the element ID.
+    pub fn with_id_field(mut self, field: impl Into<String>) -> Self {
+        self.config.id_field = field.into();
+        self
+    }
+
+    /// Set the label for nodes from resources without their own label.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.config.label = Some(label.into());
+        self
+    }
+
+    /// Set the dispatch mode for this source.
+    pub fn with_dispatch_mode(mut self, mode: DispatchMode) -> Self {
+        self.dispatch_mode = Some(mode);
+        self
+    }
+
+    /// Set the dispatch buffer capacity.
+    pub fn with_dispatch_buffer_capacity(mut self, capacity: usize) -> Self {
+        self.dispatch_buffer_capacity = Some(capacity);
+        self
+    }
+
+    /// Set the bootstrap provider for initial data delivery.
+    pub fn with_bootstrap_provider(
+        mut self,
+        provider: impl drasi_lib::bootstrap::BootstrapProvider + 'static,
+    ) -> Self {
+        self.bootstrap_provider = Some(Box::new(provider));
+        self
+    }
+
+    /// Set whether this source should auto-start when DrasiLib starts.
+    pub fn with_auto_start(mut self, auto_start: bool) -> Self {
+        self.auto_start = auto_start;
+        self
+    }
+
+    /// Set the full configuration at once.
+    pub fn with_config(mut self, config: CoapSourceConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Build the CoAP source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid or the base source
+    /// cannot be initialized.
+    pub fn build(self) -> Result<CoapSource> {
+        self.config.validate()?;
+
+        let mut params = SourceBaseParams::new(&self.id).with_auto_start(self.auto_start);
+        if let Some(mode) = self.dispatch_mode {
+            params = params.with_dispatch_mode(mode);
+        }
+        if let Some(capacity) = self.dispatch_buffer_capacity {
+            params = params.with_dispatch_buffer_capacity(capacity);
+        }
+        if let Some(provider) = self.bootstrap_provider {
+            params = params.with_bootstrap_provider(provider);
+        }
+
+        Ok(CoapSource {
+            base: SourceBase::new(params)?,
+            config: self.config,
+        })
+    }
+}
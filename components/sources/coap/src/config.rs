@@ -0,0 +1,274 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration types for the CoAP source plugin.
+//!
+//! This module defines which resources the source observes, how payloads are
+//! decoded, and how observations are mapped onto graph nodes.
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+/// Default CoAP UDP port.
+pub const DEFAULT_COAP_PORT: u16 = 5683;
+
+fn default_reregister_interval_ms() -> u64 {
+    120000
+}
+
+fn default_request_timeout_ms() -> u64 {
+    5000
+}
+
+fn default_id_field() -> String {
+    "id".to_string()
+}
+
+/// How observation payloads are decoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum PayloadFormat {
+    /// Use the Content-Format option of each notification, treating payloads
+    /// without one as JSON.
+    #[default]
+    Auto,
+    /// Always decode as JSON.
+    Json,
+    /// Always decode as CBOR.
+    Cbor,
+}
+
+/// A resource to observe.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CoapResource {
+    /// Resource URI, e.g. `coap://10.0.0.12/sensors/temp`. The port defaults to 5683.
+    pub uri: String,
+
+    /// Element ID for observations of this resource whose payload has no
+    /// `id_field`. Defaults to the resource URI.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub element_id: Option<String>,
+
+    /// Label for nodes from this resource. Overrides the source-wide `label`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+impl CoapResource {
+    /// Create a resource entry for `uri` with no ID or label override.
+    pub fn new(uri: impl Into<String>) -> Self {
+        Self {
+            uri: uri.into(),
+            element_id: None,
+            label: None,
+        }
+    }
+}
+
+/// CoAP source configuration.
+///
+/// The source registers as an observer (RFC 7641) on every resource and turns
+/// each notification into an upsert of one node.
+///
+/// # Example
+///
+/// ```rust
+/// use drasi_source_coap::{CoapResource, CoapSourceConfig};
+///
+/// let config = CoapSourceConfig {
+///     resources: vec![CoapResource::new("coap://10.0.0.12/sensors/temp")],
+///     label: Some("Sensor".to_string()),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CoapSourceConfig {
+    /// Resources to observe.
+    pub resources: Vec<CoapResource>,
+
+    /// How payloads are decoded.
+    ///
+    /// **Default**: `auto`
+    #[serde(default)]
+    pub payload_format: PayloadFormat,
+
+    /// Milliseconds without a notification after which the observation is
+    /// registered again. Servers may drop observers silently, for example after
+    /// a reboot.
+    ///
+    /// **Default**: `120000`
+    #[serde(default = "default_reregister_interval_ms")]
+    pub reregister_interval_ms: u64,
+
+    /// Milliseconds to wait for the response to a registration before
+    /// retrying it.
+    ///
+    /// **Default**: `5000`
+    #[serde(default = "default_request_timeout_ms")]
+    pub request_timeout_ms: u64,
+
+    /// Payload field holding the element ID.
+    ///
+    /// **Default**: `"id"`
+    #[serde(default = "default_id_field")]
+    pub id_field: String,
+
+    /// Label for nodes from resources without their own label. Falls back to
+    /// the last segment of the resource path.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+impl Default for CoapSourceConfig {
+    fn default() -> Self {
+        Self {
+            resources: Vec::new(),
+            payload_format: PayloadFormat::default(),
+            reregister_interval_ms: default_reregister_interval_ms(),
+            request_timeout_ms: default_request_timeout_ms(),
+            id_field: default_id_field(),
+            label: None,
+        }
+    }
+}
+
+impl CoapSourceConfig {
+    /// Validate the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `resources` is empty, or a resource URI is invalid or duplicated
+    /// - `reregister_interval_ms` or `request_timeout_ms` is zero, or the
+    ///   request timeout is not shorter than the re-register interval
+    /// - `id_field` is empty
+    pub fn validate(&self) -> Result<()> {
+        if self.resources.is_empty() {
+            return Err(anyhow!(
+                "Validation error: at least one resource is required"
+            ));
+        }
+
+        let mut seen = std::collections::HashSet::new();
+        for resource in &self.resources {
+            CoapUri::parse(&resource.uri)?;
+            if !seen.insert(resource.uri.as_str()) {
+                return Err(anyhow!(
+                    "Validation error: resource '{}' is listed more than once",
+                    resource.uri
+                ));
+            }
+        }
+
+        if self.reregister_interval_ms == 0 || self.request_timeout_ms == 0 {
+            return Err(anyhow!(
+                "Validation error: reregister_interval_ms and request_timeout_ms must be greater than 0"
+            ));
+        }
+
+        if self.request_timeout_ms >= self.reregister_interval_ms {
+            return Err(anyhow!(
+                "Validation error: request_timeout_ms must be less than reregister_interval_ms"
+            ));
+        }
+
+        if self.id_field.is_empty() {
+            return Err(anyhow!("Validation error: id_field cannot be empty"));
+        }
+
+        Ok(())
+    }
+}
+
+/// Parsed `coap://` URI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoapUri {
+    /// Host name or IP address, without IPv6 brackets.
+    pub host: String,
+    /// UDP port.
+    pub port: u16,
+    /// Percent-encoded path segments.
+    pub path: Vec<String>,
+    /// Query parameters, one `key=value` string each.
+    pub query: Vec<String>,
+}
+
+impl CoapUri {
+    /// Parse a `coap://host[:port]/path[?query]` URI.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scheme is not `coap`, or the host or port is invalid.
+    pub fn parse(uri: &str) -> Result<Self> {
+        let rest = uri.strip_prefix("coap://").ok_or_else(|| {
+            anyhow!("Validation error: resource URI '{uri}' must start with coap://")
+        })?;
+
+        let (authority, path_and_query) = match rest.find('/') {
+            Some(index) => rest.split_at(index),
+            None => (rest, ""),
+        };
+
+        let (host, port) = if let Some(bracketed) = authority.strip_prefix('[') {
+            // IPv6 literal, e.g. [fe80::1]:5683
+            let (host, after) = bracketed.split_once(']').ok_or_else(|| {
+                anyhow!("Validation error: resource URI '{uri}' has an unterminated IPv6 address")
+            })?;
+            (host, after.strip_prefix(':'))
+        } else {
+            match authority.split_once(':') {
+                Some((host, port)) => (host, Some(port)),
+                None => (authority, None),
+            }
+        };
+
+        if host.is_empty() {
+            return Err(anyhow!(
+                "Validation error: resource URI '{uri}' has no host"
+            ));
+        }
+
+        let port = match port {
+            Some(port) => port.parse::<u16>().map_err(|_| {
+                anyhow!("Validation error: resource URI '{uri}' has an invalid port")
+            })?,
+            None => DEFAULT_COAP_PORT,
+        };
+
+        let (path, query) = match path_and_query.split_once('?') {
+            Some((path, query)) => (path, query),
+            None => (path_and_query, ""),
+        };
+
+        Ok(Self {
+            host: host.to_string(),
+            port,
+            path: path
+                .split('/')
+                .filter(|s| !s.is_empty())
+                .map(str::to_string)
+                .collect(),
+            query: query
+                .split('&')
+                .filter(|s| !s.is_empty())
+                .map(str::to_string)
+                .collect(),
+        })
+    }
+
+    /// Last path segment, used as the default label.
+    pub fn last_segment(&self) -> Option<&str> {
+        self.path.last().map(String::as_str)
+    }
+}
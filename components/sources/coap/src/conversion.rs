@@ -0,0 +1,131 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion of CoAP observations into Drasi source changes.
+
+use anyhow::{anyhow, Result};
+use drasi_core::models::{Element, ElementMetadata, ElementReference, SourceChange};
+use drasi_lib::sources::convert_json_to_element_properties;
+use serde_json::{Map, Value};
+use std::sync::Arc;
+
+use crate::config::{CoapResource, CoapSourceConfig, CoapUri};
+
+/// Label used when neither the resource nor the source sets one and the
+/// resource path is empty.
+const FALLBACK_LABEL: &str = "CoapResource";
+
+fn field_as_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// Decode an observation payload as JSON or CBOR.
+///
+/// # Errors
+///
+/// Returns an error if the payload is not valid in the chosen encoding.
+pub(crate) fn decode_payload(payload: &[u8], cbor: bool) -> Result<Value> {
+    if cbor {
+        ciborium::de::from_reader(payload).map_err(|e| anyhow!("Payload is not valid CBOR: {e}"))
+    } else {
+        serde_json::from_slice(payload).map_err(|e| anyhow!("Payload is not valid JSON: {e}"))
+    }
+}
+
+/// Resolve the label for nodes from a resource: the resource label, then the
+/// source label, then the last path segment.
+pub(crate) fn label_for_resource(
+    resource: &CoapResource,
+    uri: &CoapUri,
+    config: &CoapSourceConfig,
+) -> String {
+    resource
+        .label
+        .clone()
+        .or_else(|| config.label.clone())
+        .or_else(|| uri.last_segment().map(str::to_string))
+        .unwrap_or_else(|| FALLBACK_LABEL.to_string())
+}
+
+/// Resolve the element ID of an observation: the payload's `id_field`, then the
+/// resource's `element_id`, then the resource URI.
+pub(crate) fn element_id_for(
+    value: &Value,
+    resource: &CoapResource,
+    config: &CoapSourceConfig,
+) -> String {
+    value
+        .get(&config.id_field)
+        .and_then(field_as_string)
+        .filter(|id| !id.is_empty())
+        .or_else(|| resource.element_id.clone())
+        .unwrap_or_else(|| resource.uri.clone())
+}
+
+/// Convert a decoded observation into an upsert.
+///
+/// Object payloads become the node's properties. Any other payload, such as
+/// a bare temperature reading, is stored in a `value` property. The change is
+/// an insert the first time the element is seen and an update afterwards.
+pub(crate) fn observation_to_source_change(
+    source_id: &str,
+    element_id: &str,
+    label: &str,
+    value: Value,
+    exists: bool,
+) -> SourceChange {
+    let object = match value {
+        Value::Object(object) => object,
+        other => {
+            let mut object = Map::new();
+            object.insert("value".to_string(), other);
+            object
+        }
+    };
+
+    let element = Element::Node {
+        metadata: ElementMetadata {
+            reference: ElementReference::new(source_id, element_id),
+            labels: Arc::from(vec![Arc::from(label)]),
+            effective_from: chrono::Utc::now().timestamp_millis() as u64,
+        },
+        properties: convert_json_to_element_properties(&object),
+    };
+
+    if exists {
+        SourceChange::Update { element }
+    } else {
+        SourceChange::Insert { element }
+    }
+}
+
+/// Delete for an element whose resource has gone away.
+pub(crate) fn removal_to_source_change(
+    source_id: &str,
+    element_id: &str,
+    label: &str,
+) -> SourceChange {
+    SourceChange::Delete {
+        metadata: ElementMetadata {
+            reference: ElementReference::new(source_id, element_id),
+            labels: Arc::from(vec![Arc::from(label)]),
+            effective_from: chrono::Utc::now().timestamp_millis() as u64,
+        },
+    }
+}
@@ -0,0 +1,149 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! CoAP source plugin descriptor and configuration DTOs.
+
+use crate::{CoapResource, CoapSourceBuilder, CoapSourceConfig, PayloadFormat};
+use drasi_plugin_sdk::prelude::*;
+use utoipa::OpenApi;
+
+/// Payload format DTO.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::coap::PayloadFormat)]
+#[serde(rename_all = "snake_case")]
+pub enum PayloadFormatDto {
+    #[default]
+    Auto,
+    Json,
+    Cbor,
+}
+
+/// Observed resource DTO.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::coap::CoapResource)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CoapResourceDto {
+    pub uri: ConfigValue<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub element_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+/// CoAP source configuration DTO.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::coap::CoapSourceConfig)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CoapSourceConfigDto {
+    #[schema(value_type = Vec<source::coap::CoapResource>)]
+    pub resources: Vec<CoapResourceDto>,
+    #[serde(default)]
+    #[schema(value_type = source::coap::PayloadFormat)]
+    pub payload_format: PayloadFormatDto,
+    #[serde(default = "default_reregister_interval_ms")]
+    pub reregister_interval_ms: ConfigValue<u64>,
+    #[serde(default = "default_request_timeout_ms")]
+    pub request_timeout_ms: ConfigValue<u64>,
+    #[serde(default = "default_id_field")]
+    pub id_field: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+fn default_reregister_interval_ms() -> ConfigValue<u64> {
+    ConfigValue::Static(120000)
+}
+
+fn default_request_timeout_ms() -> ConfigValue<u64> {
+    ConfigValue::Static(5000)
+}
+
+fn default_id_field() -> String {
+    "id".to_string()
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(CoapSourceConfigDto, CoapResourceDto, PayloadFormatDto)))]
+struct CoapSourceSchemas;
+
+/// Descriptor for the CoAP source plugin.
+pub struct CoapSourceDescriptor;
+
+#[async_trait]
+impl SourcePluginDescriptor for CoapSourceDescriptor {
+    fn kind(&self) -> &str {
+        "coap"
+    }
+
+    fn config_version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn config_schema_name(&self) -> &str {
+        "source.coap.CoapSourceConfig"
+    }
+
+    fn config_schema_json(&self) -> String {
+        let api = CoapSourceSchemas::openapi();
+        serde_json::to_string(
+            &api.components
+                .as_ref()
+                .expect("OpenAPI components missing")
+                .schemas,
+        )
+        .expect("Failed to serialize config schema")
+    }
+
+    async fn create_source(
+        &self,
+        id: &str,
+        config_json: &serde_json::Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn drasi_lib::sources::Source>> {
+        let dto: CoapSourceConfigDto = serde_json::from_value(config_json.clone())?;
+        let mapper = DtoMapper::new();
+
+        let resources = dto
+            .resources
+            .iter()
+            .map(|r| {
+                Ok(CoapResource {
+                    uri: mapper.resolve_string(&r.uri)?,
+                    element_id: r.element_id.clone(),
+                    label: r.label.clone(),
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let config = CoapSourceConfig {
+            resources,
+            payload_format: match dto.payload_format {
+                PayloadFormatDto::Auto => PayloadFormat::Auto,
+                PayloadFormatDto::Json => PayloadFormat::Json,
+                PayloadFormatDto::Cbor => PayloadFormat::Cbor,
+            },
+            reregister_interval_ms: mapper.resolve_typed(&dto.reregister_interval_ms)?,
+            request_timeout_ms: mapper.resolve_typed(&dto.request_timeout_ms)?,
+            id_field: dto.id_field,
+            label: dto.label,
+        };
+
+        let source = CoapSourceBuilder::new(id)
+            .with_config(config)
+            .with_auto_start(auto_start)
+            .build()?;
+
+        Ok(Box::new(source))
+    }
+}
@@ -0,0 +1,85 @@
+#![allow(unexpected_cfgs)]
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! CoAP Source Plugin for drasi-lib.
+//!
+//! This plugin observes resources on constrained devices over CoAP
+//! (RFC 7252) using the observe extension (RFC 7641), so devices that cannot
+//! run an MQTT client can still feed continuous queries.
+//!
+//! # Observation Mapping
+//!
+//! Each notification becomes an upsert of one node: an insert the first time
+//! the element is seen, an update afterwards.
+//!
+//! | Source | Maps to |
+//! |--------|---------|
+//! | payload `id_field` (default `id`), resource `element_id`, resource URI | Element ID, first match wins |
+//! | resource `label`, source `label`, last URI path segment | Node label, first match wins |
+//! | object payload fields | Node properties |
+//! | non-object payload (e.g. `21.5`) | `value` property |
+//!
+//! Payloads are decoded as JSON or CBOR according to `payload_format`. In
+//! `auto` mode the notification's Content-Format option decides, and payloads
+//! without one are treated as JSON.
+//!
+//! When a resource answers `4.04 Not Found`, the nodes seen from it are deleted.
+//!
+//! # Observe Handling
+//!
+//! Each resource gets its own UDP socket and token. Confirmable notifications
+//! are acknowledged, reordered notifications are discarded by their observe
+//! sequence number, and the observation is registered again when nothing has
+//! arrived for `reregister_interval_ms`. Servers that do not support observe
+//! are polled at that interval instead.
+//!
+//! # Usage Example
+//!
+//! ```rust,ignore
+//! use drasi_source_coap::{CoapSource, PayloadFormat};
+//!
+//! let source = CoapSource::builder("field-sensors")
+//!     .with_resource("coap://10.0.0.12/sensors/temp")
+//!     .with_resource("coap://10.0.0.13/sensors/temp")
+//!     .with_payload_format(PayloadFormat::Cbor)
+//!     .with_label("Sensor")
+//!     .build()?;
+//!
+//! drasi.add_source(source).await?;
+//! ```
+
+mod coap;
+mod config;
+mod conversion;
+pub mod descriptor;
+mod protocol;
+
+#[cfg(test)]
+mod tests;
+
+pub use coap::{CoapSource, CoapSourceBuilder};
+pub use config::{CoapResource, CoapSourceConfig, CoapUri, PayloadFormat, DEFAULT_COAP_PORT};
+
+/// Dynamic plugin entry point.
+#[cfg(feature = "dynamic-plugin")]
+drasi_plugin_sdk::export_plugin!(
+    plugin_id = "coap-source",
+    core_version = env!("CARGO_PKG_VERSION"),
+    lib_version = env!("CARGO_PKG_VERSION"),
+    plugin_version = env!("CARGO_PKG_VERSION"),
+    source_descriptors = [descriptor::CoapSourceDescriptor],
+    reaction_descriptors = [],
+    bootstrap_descriptors = [],
+);
@@ -0,0 +1,121 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! CoAP message handling for observe relationships (RFC 7252, RFC 7641).
+
+use anyhow::{anyhow, Result};
+use coap_lite::{
+    CoapOption, ContentFormat, MessageClass, MessageType, Packet, RequestType, ResponseType,
+};
+
+use crate::config::{CoapUri, PayloadFormat};
+
+/// Observe option value that registers an observer.
+pub(crate) const OBSERVE_REGISTER: u32 = 0;
+
+/// Half of the 24-bit observe sequence space.
+const OBSERVE_HALF_RANGE: u32 = 1 << 23;
+
+/// What a received message means for the observation.
+#[derive(Debug, PartialEq)]
+pub(crate) enum Notification {
+    /// A representation of the resource. `observe` is `None` when the server
+    /// answered without establishing an observation.
+    Value {
+        payload: Vec<u8>,
+        cbor: bool,
+        observe: Option<u32>,
+    },
+    /// The resource no longer exists.
+    Removed,
+    /// The server rejected the request with the given response code.
+    Rejected(String),
+    /// The message carries no representation, e.g. an empty ACK.
+    Empty,
+}
+
+/// Build a confirmable GET with an Observe option.
+pub(crate) fn observe_request(uri: &CoapUri, message_id: u16, token: &[u8]) -> Packet {
+    let mut packet = Packet::new();
+    packet.header.set_type(MessageType::Confirmable);
+    packet.header.code = MessageClass::Request(RequestType::Get);
+    packet.header.message_id = message_id;
+    packet.set_token(token.to_vec());
+    packet.set_observe_value(OBSERVE_REGISTER);
+    for segment in &uri.path {
+        packet.add_option(CoapOption::UriPath, segment.as_bytes().to_vec());
+    }
+    for query in &uri.query {
+        packet.add_option(CoapOption::UriQuery, query.as_bytes().to_vec());
+    }
+    packet
+}
+
+/// Build an empty ACK or RST for a received confirmable message.
+pub(crate) fn empty_reply(message_id: u16, accept: bool) -> Packet {
+    let mut packet = Packet::new();
+    packet.header.set_type(if accept {
+        MessageType::Acknowledgement
+    } else {
+        MessageType::Reset
+    });
+    packet.header.code = MessageClass::Empty;
+    packet.header.message_id = message_id;
+    packet
+}
+
+/// Encode a packet for sending.
+pub(crate) fn encode(packet: &Packet) -> Result<Vec<u8>> {
+    packet
+        .to_bytes()
+        .map_err(|e| anyhow!("Failed to encode CoAP message: {e:?}"))
+}
+
+/// Whether a notification with sequence number `new` is newer than `last`.
+///
+/// Implements the 24-bit wrap-around comparison of RFC 7641 section 3.4, so
+/// reordered notifications do not overwrite newer state.
+pub(crate) fn is_fresher(last: Option<u32>, new: u32) -> bool {
+    match last {
+        None => true,
+        Some(last) => {
+            (last < new && new - last < OBSERVE_HALF_RANGE)
+                || (last > new && last - new > OBSERVE_HALF_RANGE)
+        }
+    }
+}
+
+fn is_cbor(format: PayloadFormat, content_format: Option<ContentFormat>) -> bool {
+    match format {
+        PayloadFormat::Json => false,
+        PayloadFormat::Cbor => true,
+        PayloadFormat::Auto => matches!(content_format, Some(ContentFormat::ApplicationCBOR)),
+    }
+}
+
+/// Interpret a received message that matched the observation's token.
+pub(crate) fn classify(packet: &Packet, format: PayloadFormat) -> Notification {
+    match packet.header.code {
+        MessageClass::Response(ResponseType::Content | ResponseType::Valid) => {
+            Notification::Value {
+                payload: packet.payload.clone(),
+                cbor: is_cbor(format, packet.get_content_format()),
+                observe: packet.get_observe_value().and_then(|v| v.ok()),
+            }
+        }
+        MessageClass::Response(ResponseType::NotFound) => Notification::Removed,
+        MessageClass::Response(code) => Notification::Rejected(format!("{code:?}")),
+        _ => Notification::Empty,
+    }
+}
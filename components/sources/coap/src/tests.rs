@@ -0,0 +1,328 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for the CoAP source plugin.
+
+use super::*;
+use crate::conversion::{
+    decode_payload, element_id_for, label_for_resource, observation_to_source_change,
+};
+use crate::protocol::{classify, empty_reply, is_fresher, observe_request, Notification};
+use coap_lite::{
+    CoapOption, ContentFormat, MessageClass, MessageType, Packet, RequestType, ResponseType,
+};
+use drasi_core::models::{Element, ElementValue, SourceChange};
+use drasi_lib::Source;
+use drasi_plugin_sdk::prelude::SourcePluginDescriptor;
+use serde_json::json;
+
+fn config() -> CoapSourceConfig {
+    CoapSourceConfig {
+        resources: vec![CoapResource::new("coap://10.0.0.12/sensors/temp")],
+        ..Default::default()
+    }
+}
+
+mod builder {
+    use super::*;
+
+    #[test]
+    fn test_builder_with_valid_config() {
+        let source = CoapSource::builder("test-source")
+            .with_resource("coap://10.0.0.12/sensors/temp")
+            .with_payload_format(PayloadFormat::Cbor)
+            .with_label("Sensor")
+            .build()
+            .unwrap();
+
+        assert_eq!(source.id(), "test-source");
+        assert_eq!(source.type_name(), "coap");
+        let props = source.properties();
+        assert_eq!(
+            props.get("resources"),
+            Some(&json!([{"uri": "coap://10.0.0.12/sensors/temp"}]))
+        );
+        assert_eq!(props.get("payloadFormat"), Some(&json!("cbor")));
+    }
+
+    #[test]
+    fn test_builder_requires_resources() {
+        assert!(CoapSource::builder("test-source").build().is_err());
+    }
+
+    #[test]
+    fn test_config_rejects_invalid_values() {
+        let bad_scheme = CoapSourceConfig {
+            resources: vec![CoapResource::new("http://10.0.0.12/temp")],
+            ..config()
+        };
+        assert!(bad_scheme.validate().is_err());
+
+        let duplicate = CoapSourceConfig {
+            resources: vec![
+                CoapResource::new("coap://a/temp"),
+                CoapResource::new("coap://a/temp"),
+            ],
+            ..config()
+        };
+        assert!(duplicate.validate().is_err());
+
+        let slow_requests = CoapSourceConfig {
+            request_timeout_ms: 120000,
+            ..config()
+        };
+        assert!(slow_requests.validate().is_err());
+    }
+
+    #[test]
+    fn test_config_deserialization_defaults() {
+        let config: CoapSourceConfig =
+            serde_json::from_value(json!({"resources": [{"uri": "coap://dev/temp"}]})).unwrap();
+        assert_eq!(config.payload_format, PayloadFormat::Auto);
+        assert_eq!(config.reregister_interval_ms, 120000);
+        assert_eq!(config.request_timeout_ms, 5000);
+        assert_eq!(config.id_field, "id");
+    }
+
+    #[test]
+    fn test_parse_uri() {
+        let uri = CoapUri::parse("coap://sensor.local:5684/a/b?unit=c&x=1").unwrap();
+        assert_eq!(uri.host, "sensor.local");
+        assert_eq!(uri.port, 5684);
+        assert_eq!(uri.path, vec!["a", "b"]);
+        assert_eq!(uri.query, vec!["unit=c", "x=1"]);
+        assert_eq!(uri.last_segment(), Some("b"));
+
+        let ipv6 = CoapUri::parse("coap://[fe80::1]/temp").unwrap();
+        assert_eq!(ipv6.host, "fe80::1");
+        assert_eq!(ipv6.port, DEFAULT_COAP_PORT);
+
+        let root = CoapUri::parse("coap://dev").unwrap();
+        assert!(root.path.is_empty());
+
+        assert!(CoapUri::parse("coap://dev:port/x").is_err());
+        assert!(CoapUri::parse("coap:///x").is_err());
+        assert!(CoapUri::parse("coaps://dev/x").is_err());
+    }
+}
+
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn test_decode_json_and_cbor() {
+        assert_eq!(
+            decode_payload(br#"{"temp": 21.5}"#, false).unwrap(),
+            json!({"temp": 21.5})
+        );
+
+        let mut cbor = Vec::new();
+        ciborium::ser::into_writer(&json!({"temp": 21.5, "ok": true}), &mut cbor).unwrap();
+        assert_eq!(
+            decode_payload(&cbor, true).unwrap(),
+            json!({"temp": 21.5, "ok": true})
+        );
+
+        assert!(decode_payload(b"{", false).is_err());
+        assert!(decode_payload(&[0xff], true).is_err());
+    }
+
+    #[test]
+    fn test_observations_become_upserts() {
+        let change = observation_to_source_change("src", "dev-1", "temp", json!(21.5), false);
+        let SourceChange::Insert {
+            element: Element::Node { properties, .. },
+        } = change
+        else {
+            panic!("expected node insert");
+        };
+        assert_eq!(
+            properties.get("value"),
+            Some(&ElementValue::Float(21.5.into()))
+        );
+
+        let change =
+            observation_to_source_change("src", "dev-1", "temp", json!({"temp": 22}), true);
+        assert!(matches!(change, SourceChange::Update { .. }));
+    }
+
+    #[test]
+    fn test_id_and_label_fallbacks() {
+        let config = config();
+        let resource = CoapResource::new("coap://10.0.0.12/sensors/temp");
+        let uri = CoapUri::parse(&resource.uri).unwrap();
+
+        assert_eq!(element_id_for(&json!({"id": 7}), &resource, &config), "7");
+        assert_eq!(
+            element_id_for(&json!(21.5), &resource, &config),
+            "coap://10.0.0.12/sensors/temp"
+        );
+        let named = CoapResource {
+            element_id: Some("dev-1".to_string()),
+            label: Some("Thermometer".to_string()),
+            ..resource.clone()
+        };
+        assert_eq!(element_id_for(&json!(21.5), &named, &config), "dev-1");
+
+        assert_eq!(label_for_resource(&resource, &uri, &config), "temp");
+        assert_eq!(label_for_resource(&named, &uri, &config), "Thermometer");
+        let labelled = CoapSourceConfig {
+            label: Some("Sensor".to_string()),
+            ..config
+        };
+        assert_eq!(label_for_resource(&resource, &uri, &labelled), "Sensor");
+    }
+}
+
+mod protocol {
+    use super::*;
+
+    fn response(code: ResponseType, payload: &[u8]) -> Packet {
+        let mut packet = Packet::new();
+        packet.header.set_type(MessageType::Confirmable);
+        packet.header.code = MessageClass::Response(code);
+        packet.payload = payload.to_vec();
+        packet
+    }
+
+    #[test]
+    fn test_observe_request() {
+        let uri = CoapUri::parse("coap://dev/sensors/temp?unit=c").unwrap();
+        let packet = observe_request(&uri, 42, &[1, 2, 3, 4]);
+
+        assert_eq!(packet.header.get_type(), MessageType::Confirmable);
+        assert_eq!(packet.header.code, MessageClass::Request(RequestType::Get));
+        assert_eq!(packet.header.message_id, 42);
+        assert_eq!(packet.get_token(), &[1, 2, 3, 4]);
+        assert_eq!(packet.get_observe_value().unwrap().unwrap(), 0);
+        let path: Vec<_> = packet
+            .get_option(CoapOption::UriPath)
+            .unwrap()
+            .iter()
+            .cloned()
+            .collect();
+        assert_eq!(path, vec![b"sensors".to_vec(), b"temp".to_vec()]);
+
+        let bytes = packet.to_bytes().unwrap();
+        assert_eq!(Packet::from_bytes(&bytes).unwrap().header.message_id, 42);
+    }
+
+    #[test]
+    fn test_empty_replies() {
+        let ack = empty_reply(7, true);
+        assert_eq!(ack.header.get_type(), MessageType::Acknowledgement);
+        assert_eq!(ack.header.message_id, 7);
+        assert_eq!(empty_reply(7, false).header.get_type(), MessageType::Reset);
+    }
+
+    #[test]
+    fn test_classify_notifications() {
+        let mut packet = response(ResponseType::Content, b"21.5");
+        packet.set_observe_value(5);
+        packet.set_content_format(ContentFormat::ApplicationCBOR);
+        assert_eq!(
+            classify(&packet, PayloadFormat::Auto),
+            Notification::Value {
+                payload: b"21.5".to_vec(),
+                cbor: true,
+                observe: Some(5),
+            }
+        );
+        assert!(matches!(
+            classify(&packet, PayloadFormat::Json),
+            Notification::Value { cbor: false, .. }
+        ));
+
+        let plain = response(ResponseType::Content, b"{}");
+        assert!(matches!(
+            classify(&plain, PayloadFormat::Auto),
+            Notification::Value {
+                cbor: false,
+                observe: None,
+                ..
+            }
+        ));
+
+        assert_eq!(
+            classify(&response(ResponseType::NotFound, b""), PayloadFormat::Auto),
+            Notification::Removed
+        );
+        assert!(matches!(
+            classify(
+                &response(ResponseType::Unauthorized, b""),
+                PayloadFormat::Auto
+            ),
+            Notification::Rejected(_)
+        ));
+    }
+
+    #[test]
+    fn test_sequence_freshness() {
+        assert!(is_fresher(None, 0));
+        assert!(is_fresher(Some(5), 6));
+        assert!(!is_fresher(Some(6), 5));
+        assert!(!is_fresher(Some(6), 6));
+        // Wrap-around of the 24-bit sequence number
+        assert!(is_fresher(Some((1 << 24) - 1), 1));
+        assert!(!is_fresher(Some(1), (1 << 24) - 1));
+    }
+}
+
+mod descriptor {
+    use super::*;
+    use crate::descriptor::CoapSourceDescriptor;
+
+    #[tokio::test]
+    async fn test_descriptor_creates_source() {
+        let descriptor = CoapSourceDescriptor;
+        assert_eq!(descriptor.kind(), "coap");
+
+        let source = descriptor
+            .create_source(
+                "coap-1",
+                &json!({
+                    "resources": [
+                        {"uri": "coap://dev/temp", "elementId": "dev-1", "label": "Sensor"}
+                    ],
+                    "payloadFormat": "json",
+                    "reregisterIntervalMs": 60000
+                }),
+                false,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(source.id(), "coap-1");
+        assert!(!source.auto_start());
+        let props = source.properties();
+        assert_eq!(props.get("payloadFormat"), Some(&json!("json")));
+        assert_eq!(props.get("reregisterIntervalMs"), Some(&json!(60000)));
+        assert_eq!(
+            props.get("resources"),
+            Some(&json!([{"uri": "coap://dev/temp", "elementId": "dev-1", "label": "Sensor"}]))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_descriptor_rejects_unknown_fields() {
+        let result = CoapSourceDescriptor
+            .create_source(
+                "coap-1",
+                &json!({"resources": [{"uri": "coap://dev/temp"}], "bogus": 1}),
+                true,
+            )
+            .await;
+        assert!(result.is_err());
+    }
+}
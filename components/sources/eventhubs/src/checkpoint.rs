@@ -0,0 +1,107 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Partition checkpoints for the Azure Event Hubs source.
+//!
+//! Checkpoints record the last dispatched offset of each partition. They are
+//! saved as the source's checkpoint and also committed to the consumer group,
+//! so a restarted source never resumes behind its saved offsets.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// Last dispatched offsets of the partitions of an Event Hub, as saved by the source.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Checkpoint {
+    /// Event Hub the offsets belong to.
+    pub event_hub: String,
+    /// Consumer group the offsets were read with.
+    pub consumer_group: String,
+    /// Offset of the last event dispatched from each partition.
+    pub offsets: BTreeMap<i32, i64>,
+}
+
+impl Checkpoint {
+    /// An empty checkpoint for `event_hub` read by `consumer_group`.
+    pub fn new(event_hub: impl Into<String>, consumer_group: impl Into<String>) -> Self {
+        Self {
+            event_hub: event_hub.into(),
+            consumer_group: consumer_group.into(),
+            offsets: BTreeMap::new(),
+        }
+    }
+
+    /// Whether the checkpoint was saved for `event_hub` and `consumer_group`.
+    pub fn is_for(&self, event_hub: &str, consumer_group: &str) -> bool {
+        self.event_hub == event_hub && self.consumer_group == consumer_group
+    }
+}
+
+/// Per-partition progress of the partitions this source currently owns.
+///
+/// `loaded` holds the stored checkpoint of each partition, read once when the
+/// partition is first seen after an assignment. Events at or before it were
+/// already dispatched by a previous owner and are skipped. `pending` holds
+/// dispatched offsets that have not been flushed yet.
+#[derive(Debug, Default)]
+pub(crate) struct PartitionTracker {
+    loaded: HashMap<i32, Option<i64>>,
+    pending: HashMap<i32, i64>,
+}
+
+impl PartitionTracker {
+    pub(crate) fn is_loaded(&self, partition: i32) -> bool {
+        self.loaded.contains_key(&partition)
+    }
+
+    pub(crate) fn set_loaded(&mut self, partition: i32, offset: Option<i64>) {
+        self.loaded.insert(partition, offset);
+    }
+
+    /// Whether an event was already dispatched according to the stored checkpoint.
+    pub(crate) fn is_behind_checkpoint(&self, partition: i32, offset: i64) -> bool {
+        matches!(self.loaded.get(&partition), Some(Some(checkpoint)) if offset <= *checkpoint)
+    }
+
+    /// Record that the event at `offset` has been dispatched.
+    pub(crate) fn record(&mut self, partition: i32, offset: i64) {
+        let pending = self.pending.entry(partition).or_insert(offset);
+        *pending = (*pending).max(offset);
+    }
+
+    /// Forget a partition after losing ownership of it.
+    ///
+    /// Unflushed progress is dropped rather than written, so a stale owner
+    /// never overwrites the checkpoint of the partition's new owner. The new
+    /// owner re-reads those events, keeping delivery at-least-once.
+    pub(crate) fn revoke(&mut self, partition: i32) {
+        self.loaded.remove(&partition);
+        self.pending.remove(&partition);
+    }
+
+    /// Take all unflushed offsets, ordered by partition.
+    pub(crate) fn take_pending(&mut self) -> Vec<(i32, i64)> {
+        let mut pending: Vec<_> = self.pending.drain().collect();
+        pending.sort_unstable();
+        pending
+    }
+
+    /// Mark an offset as flushed so it becomes the skip threshold.
+    pub(crate) fn mark_flushed(&mut self, partition: i32, offset: i64) {
+        if self.is_loaded(partition) {
+            self.loaded.insert(partition, Some(offset));
+        }
+    }
+}
@@ -0,0 +1,329 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration types for the Azure Event Hubs source plugin.
+//!
+//! This module defines how the source connects to an Event Hub through its
+//! Kafka-compatible endpoint, how checkpoints are kept, and how event payloads
+//! are mapped onto graph nodes.
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+fn default_consumer_group() -> String {
+    "$Default".to_string()
+}
+
+fn default_checkpoint_interval_ms() -> u64 {
+    5000
+}
+
+fn default_session_timeout_ms() -> u64 {
+    30000
+}
+
+fn default_id_field() -> String {
+    "id".to_string()
+}
+
+fn default_operation_field() -> String {
+    "op".to_string()
+}
+
+/// Where to start reading a partition that has no checkpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum StartPosition {
+    /// Read from the oldest retained event.
+    Earliest,
+    /// Read only events enqueued after the consumer joins.
+    #[default]
+    Latest,
+}
+
+/// Azure Event Hubs source configuration.
+///
+/// The source reads from the Kafka-compatible endpoint of an Event Hubs
+/// namespace (port 9093). Partitions are balanced across every source that
+/// uses the same `consumer_group`.
+///
+/// # Example
+///
+/// ```rust
+/// use drasi_source_eventhubs::{EventHubsSourceConfig, StartPosition};
+///
+/// let config = EventHubsSourceConfig {
+///     connection_string: "Endpoint=sb://my-ns.servicebus.windows.net/;SharedAccessKeyName=listen;SharedAccessKey=abc=;EntityPath=telemetry".to_string(),
+///     consumer_group: "drasi".to_string(),
+///     start_position: StartPosition::Earliest,
+///     label: Some("Telemetry".to_string()),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EventHubsSourceConfig {
+    /// Event Hubs connection string, as shown under "Shared access policies"
+    /// in the Azure portal. Use a policy with the `Listen` claim.
+    pub connection_string: String,
+
+    /// Event Hub to read from. Required when the connection string is scoped
+    /// to the namespace and has no `EntityPath`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event_hub: Option<String>,
+
+    /// Consumer group to read with.
+    ///
+    /// **Default**: `"$Default"`
+    #[serde(default = "default_consumer_group")]
+    pub consumer_group: String,
+
+    /// Where to start reading partitions without a checkpoint.
+    ///
+    /// **Default**: `latest`
+    #[serde(default)]
+    pub start_position: StartPosition,
+
+    /// Milliseconds between checkpoint flushes.
+    ///
+    /// **Default**: `5000`
+    #[serde(default = "default_checkpoint_interval_ms")]
+    pub checkpoint_interval_ms: u64,
+
+    /// Milliseconds without a heartbeat after which the consumer loses
+    /// ownership of its partitions.
+    ///
+    /// **Default**: `30000`
+    #[serde(default = "default_session_timeout_ms")]
+    pub session_timeout_ms: u64,
+
+    /// Payload field holding the element ID.
+    ///
+    /// **Default**: `"id"`
+    #[serde(default = "default_id_field")]
+    pub id_field: String,
+
+    /// Event header holding the element ID for events whose body has no
+    /// `id_field`. Set to `iothub-connection-device-id` to key IoT Hub
+    /// telemetry by device.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id_header: Option<String>,
+
+    /// Payload field holding the change operation (`insert`, `update`, `delete`
+    /// or `i`/`u`/`d`). Events without it are treated as inserts.
+    ///
+    /// **Default**: `"op"`
+    #[serde(default = "default_operation_field")]
+    pub operation_field: String,
+
+    /// Optional payload field holding the node label. Takes precedence over `label`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_field: Option<String>,
+
+    /// Label for nodes from this source. Falls back to the Event Hub name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+impl Default for EventHubsSourceConfig {
+    fn default() -> Self {
+        Self {
+            connection_string: String::new(),
+            event_hub: None,
+            consumer_group: default_consumer_group(),
+            start_position: StartPosition::default(),
+            checkpoint_interval_ms: default_checkpoint_interval_ms(),
+            session_timeout_ms: default_session_timeout_ms(),
+            id_field: default_id_field(),
+            id_header: None,
+            operation_field: default_operation_field(),
+            label_field: None,
+            label: None,
+        }
+    }
+}
+
+impl EventHubsSourceConfig {
+    /// Parse the connection string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection string is malformed.
+    pub fn connection(&self) -> Result<EventHubsConnection> {
+        EventHubsConnection::parse(&self.connection_string)
+    }
+
+    /// Name of the Event Hub to read from: `event_hub` when set, otherwise the
+    /// connection string's `EntityPath`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection string is malformed or neither names
+    /// an Event Hub.
+    pub fn event_hub_name(&self) -> Result<String> {
+        if let Some(event_hub) = &self.event_hub {
+            return Ok(event_hub.clone());
+        }
+
+        self.connection()?.entity_path.ok_or_else(|| {
+            anyhow!("Validation error: event_hub is required when the connection string has no EntityPath")
+        })
+    }
+
+    /// Validate the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `connection_string` is empty or malformed
+    /// - no Event Hub is named, or `event_hub` conflicts with `EntityPath`
+    /// - `consumer_group`, `id_field` or `id_header` is empty
+    /// - `checkpoint_interval_ms` or `session_timeout_ms` is zero
+    pub fn validate(&self) -> Result<()> {
+        if self.connection_string.is_empty() {
+            return Err(anyhow!(
+                "Validation error: connection_string cannot be empty"
+            ));
+        }
+
+        let connection = self.connection()?;
+
+        if self.event_hub.as_deref() == Some("") {
+            return Err(anyhow!(
+                "Validation error: event_hub cannot be empty; omit it to use the connection string's EntityPath"
+            ));
+        }
+
+        if let (Some(event_hub), Some(entity_path)) = (&self.event_hub, &connection.entity_path) {
+            if event_hub != entity_path {
+                return Err(anyhow!(
+                    "Validation error: event_hub '{event_hub}' does not match the connection string's EntityPath '{entity_path}'"
+                ));
+            }
+        }
+
+        self.event_hub_name()?;
+
+        if self.consumer_group.is_empty() {
+            return Err(anyhow!("Validation error: consumer_group cannot be empty"));
+        }
+
+        if self.checkpoint_interval_ms == 0 {
+            return Err(anyhow!(
+                "Validation error: checkpoint_interval_ms must be greater than 0"
+            ));
+        }
+
+        if self.session_timeout_ms == 0 {
+            return Err(anyhow!(
+                "Validation error: session_timeout_ms must be greater than 0"
+            ));
+        }
+
+        if self.id_field.is_empty() {
+            return Err(anyhow!("Validation error: id_field cannot be empty"));
+        }
+
+        if self.id_header.as_deref() == Some("") {
+            return Err(anyhow!("Validation error: id_header cannot be empty"));
+        }
+
+        Ok(())
+    }
+}
+
+/// Parts of an Event Hubs connection string.
+///
+/// Connection strings have the form
+/// `Endpoint=sb://<namespace>.servicebus.windows.net/;SharedAccessKeyName=<policy>;SharedAccessKey=<key>[;EntityPath=<hub>]`.
+/// Keys are matched case-insensitively and unknown keys are ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventHubsConnection {
+    /// Fully qualified namespace host, e.g. `my-ns.servicebus.windows.net`.
+    pub host: String,
+    /// Shared access policy name.
+    pub shared_access_key_name: String,
+    /// Shared access policy key.
+    pub shared_access_key: String,
+    /// Event Hub the connection string is scoped to, if any.
+    pub entity_path: Option<String>,
+}
+
+impl EventHubsConnection {
+    /// Parse an Event Hubs connection string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a segment is not `key=value` or `Endpoint`,
+    /// `SharedAccessKeyName` or `SharedAccessKey` is missing.
+    pub fn parse(connection_string: &str) -> Result<Self> {
+        let mut endpoint = None;
+        let mut key_name = None;
+        let mut key = None;
+        let mut entity_path = None;
+
+        for segment in connection_string.split(';').map(str::trim) {
+            if segment.is_empty() {
+                continue;
+            }
+
+            // Keys are base64 and may end in '=', so split on the first one only
+            let (name, value) = segment.split_once('=').ok_or_else(|| {
+                anyhow!("Validation error: connection string segment is not key=value")
+            })?;
+
+            match name.trim().to_ascii_lowercase().as_str() {
+                "endpoint" => endpoint = Some(value.trim().to_string()),
+                "sharedaccesskeyname" => key_name = Some(value.trim().to_string()),
+                "sharedaccesskey" => key = Some(value.trim().to_string()),
+                "entitypath" => {
+                    entity_path = Some(value.trim().to_string()).filter(|p| !p.is_empty())
+                }
+                _ => {}
+            }
+        }
+
+        let endpoint = endpoint
+            .filter(|e| !e.is_empty())
+            .ok_or_else(|| anyhow!("Validation error: connection string has no Endpoint"))?;
+        let host = endpoint
+            .split_once("://")
+            .map_or(endpoint.as_str(), |(_, rest)| rest)
+            .trim_end_matches('/')
+            .split([':', '/'])
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        if host.is_empty() {
+            return Err(anyhow!(
+                "Validation error: connection string Endpoint has no host"
+            ));
+        }
+
+        Ok(Self {
+            host,
+            shared_access_key_name: key_name.filter(|k| !k.is_empty()).ok_or_else(|| {
+                anyhow!("Validation error: connection string has no SharedAccessKeyName")
+            })?,
+            shared_access_key: key.filter(|k| !k.is_empty()).ok_or_else(|| {
+                anyhow!("Validation error: connection string has no SharedAccessKey")
+            })?,
+            entity_path,
+        })
+    }
+
+    /// Bootstrap server of the namespace's Kafka endpoint.
+    pub fn kafka_bootstrap_server(&self) -> String {
+        format!("{}:9093", self.host)
+    }
+}
@@ -0,0 +1,141 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Kafka consumer setup for the Event Hubs Kafka-compatible endpoint.
+
+use anyhow::Result;
+use log::{info, warn};
+use rdkafka::config::ClientConfig;
+use rdkafka::consumer::{ConsumerContext, Rebalance, StreamConsumer};
+use rdkafka::{ClientContext, TopicPartitionList};
+use std::sync::Mutex;
+
+use crate::config::{EventHubsSourceConfig, StartPosition};
+
+/// Consumer context that records partitions revoked by group rebalances.
+///
+/// The consumer group protocol is what assigns partition ownership: each
+/// partition belongs to one member at a time, and every rebalance starts a
+/// new group generation. Revoked partitions are queued here so the consume
+/// loop can drop their unflushed progress before handling more events.
+pub(crate) struct RebalanceContext {
+    source_id: String,
+    revoked: Mutex<Vec<i32>>,
+}
+
+impl RebalanceContext {
+    pub(crate) fn new(source_id: impl Into<String>) -> Self {
+        Self {
+            source_id: source_id.into(),
+            revoked: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Take the partitions revoked since the last call.
+    pub(crate) fn take_revoked(&self) -> Vec<i32> {
+        match self.revoked.lock() {
+            Ok(mut revoked) => std::mem::take(&mut *revoked),
+            Err(_) => Vec::new(),
+        }
+    }
+}
+
+fn partitions(list: &TopicPartitionList) -> Vec<i32> {
+    list.elements().iter().map(|e| e.partition()).collect()
+}
+
+impl ClientContext for RebalanceContext {}
+
+impl ConsumerContext for RebalanceContext {
+    fn post_rebalance(&self, rebalance: &Rebalance<'_>) {
+        match rebalance {
+            Rebalance::Assign(list) => {
+                info!(
+                    "[{}] Assigned Event Hubs partitions {:?}",
+                    self.source_id,
+                    partitions(list)
+                );
+            }
+            Rebalance::Revoke(list) => {
+                let revoked = partitions(list);
+                info!(
+                    "[{}] Event Hubs partitions {revoked:?} revoked",
+                    self.source_id
+                );
+                if let Ok(mut pending) = self.revoked.lock() {
+                    pending.extend(revoked);
+                }
+            }
+            Rebalance::Error(e) => {
+                warn!("[{}] Event Hubs rebalance failed: {e}", self.source_id);
+            }
+        }
+    }
+}
+
+/// Build the Kafka client configuration for an Event Hubs namespace.
+///
+/// Event Hubs accepts SASL PLAIN over TLS with the literal user name
+/// `$ConnectionString` and the connection string as the password. Offsets are
+/// committed by the source after each checkpoint flush, never automatically.
+///
+/// # Errors
+///
+/// Returns an error if the connection string is malformed.
+pub(crate) fn client_config(
+    source_id: &str,
+    config: &EventHubsSourceConfig,
+) -> Result<ClientConfig> {
+    let connection = config.connection()?;
+
+    let mut client = ClientConfig::new();
+    client
+        .set("bootstrap.servers", connection.kafka_bootstrap_server())
+        .set("security.protocol", "SASL_SSL")
+        .set("sasl.mechanism", "PLAIN")
+        .set("sasl.username", "$ConnectionString")
+        .set("sasl.password", &config.connection_string)
+        .set("group.id", &config.consumer_group)
+        .set("client.id", source_id)
+        .set("enable.auto.commit", "false")
+        .set(
+            "auto.offset.reset",
+            match config.start_position {
+                StartPosition::Earliest => "earliest",
+                StartPosition::Latest => "latest",
+            },
+        )
+        .set("session.timeout.ms", config.session_timeout_ms.to_string());
+
+    Ok(client)
+}
+
+/// Create a consumer subscribed to the configured Event Hub.
+///
+/// # Errors
+///
+/// Returns an error if the configuration is invalid or the subscription fails.
+pub(crate) fn create_consumer(
+    source_id: &str,
+    config: &EventHubsSourceConfig,
+) -> Result<StreamConsumer<RebalanceContext>> {
+    use rdkafka::consumer::Consumer;
+
+    let event_hub = config.event_hub_name()?;
+    let consumer: StreamConsumer<RebalanceContext> =
+        client_config(source_id, config)?.create_with_context(RebalanceContext::new(source_id))?;
+    consumer.subscribe(&[&event_hub])?;
+
+    Ok(consumer)
+}
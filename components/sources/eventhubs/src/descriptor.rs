@@ -0,0 +1,145 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Azure Event Hubs source plugin descriptor and configuration DTOs.
+
+use crate::{EventHubsSourceBuilder, EventHubsSourceConfig, StartPosition};
+use drasi_plugin_sdk::prelude::*;
+use utoipa::OpenApi;
+
+/// Start position DTO.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::eventhubs::StartPosition)]
+#[serde(rename_all = "snake_case")]
+pub enum StartPositionDto {
+    Earliest,
+    #[default]
+    Latest,
+}
+
+/// Azure Event Hubs source configuration DTO.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::eventhubs::EventHubsSourceConfig)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EventHubsSourceConfigDto {
+    pub connection_string: ConfigValue<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event_hub: Option<ConfigValue<String>>,
+    #[serde(default = "default_consumer_group")]
+    pub consumer_group: ConfigValue<String>,
+    #[serde(default)]
+    #[schema(value_type = source::eventhubs::StartPosition)]
+    pub start_position: StartPositionDto,
+    #[serde(default = "default_checkpoint_interval_ms")]
+    pub checkpoint_interval_ms: ConfigValue<u64>,
+    #[serde(default = "default_session_timeout_ms")]
+    pub session_timeout_ms: ConfigValue<u64>,
+    #[serde(default = "default_id_field")]
+    pub id_field: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id_header: Option<String>,
+    #[serde(default = "default_operation_field")]
+    pub operation_field: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_field: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+fn default_consumer_group() -> ConfigValue<String> {
+    ConfigValue::Static("$Default".to_string())
+}
+
+fn default_checkpoint_interval_ms() -> ConfigValue<u64> {
+    ConfigValue::Static(5000)
+}
+
+fn default_session_timeout_ms() -> ConfigValue<u64> {
+    ConfigValue::Static(30000)
+}
+
+fn default_id_field() -> String {
+    "id".to_string()
+}
+
+fn default_operation_field() -> String {
+    "op".to_string()
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(EventHubsSourceConfigDto, StartPositionDto)))]
+struct EventHubsSourceSchemas;
+
+/// Descriptor for the Azure Event Hubs source plugin.
+pub struct EventHubsSourceDescriptor;
+
+#[async_trait]
+impl SourcePluginDescriptor for EventHubsSourceDescriptor {
+    fn kind(&self) -> &str {
+        "eventhubs"
+    }
+
+    fn config_version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn config_schema_name(&self) -> &str {
+        "source.eventhubs.EventHubsSourceConfig"
+    }
+
+    fn config_schema_json(&self) -> String {
+        let api = EventHubsSourceSchemas::openapi();
+        serde_json::to_string(
+            &api.components
+                .as_ref()
+                .expect("OpenAPI components missing")
+                .schemas,
+        )
+        .expect("Failed to serialize config schema")
+    }
+
+    async fn create_source(
+        &self,
+        id: &str,
+        config_json: &serde_json::Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn drasi_lib::sources::Source>> {
+        let dto: EventHubsSourceConfigDto = serde_json::from_value(config_json.clone())?;
+        let mapper = DtoMapper::new();
+
+        let config = EventHubsSourceConfig {
+            connection_string: mapper.resolve_string(&dto.connection_string)?,
+            event_hub: mapper.resolve_optional(&dto.event_hub)?,
+            consumer_group: mapper.resolve_string(&dto.consumer_group)?,
+            start_position: match dto.start_position {
+                StartPositionDto::Earliest => StartPosition::Earliest,
+                StartPositionDto::Latest => StartPosition::Latest,
+            },
+            checkpoint_interval_ms: mapper.resolve_typed(&dto.checkpoint_interval_ms)?,
+            session_timeout_ms: mapper.resolve_typed(&dto.session_timeout_ms)?,
+            id_field: dto.id_field,
+            id_header: dto.id_header,
+            operation_field: dto.operation_field,
+            label_field: dto.label_field,
+            label: dto.label,
+        };
+
+        let source = EventHubsSourceBuilder::new(id)
+            .with_config(config)
+            .with_auto_start(auto_start)
+            .build()?;
+
+        Ok(Box::new(source))
+    }
+}
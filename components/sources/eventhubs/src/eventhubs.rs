@@ -0,0 +1,643 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Azure Event Hubs source implementation and builder.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use log::{debug, error, info, warn};
+use rdkafka::consumer::{CommitMode, Consumer, StreamConsumer};
+use rdkafka::message::{BorrowedMessage, Headers, Message};
+use rdkafka::{Offset, TopicPartitionList};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+use drasi_core::models::SourceChange;
+use drasi_lib::channels::*;
+use drasi_lib::component_graph::ComponentStatusHandle;
+use drasi_lib::managers::{log_component_start, log_component_stop};
+use drasi_lib::sources::base::{SourceBase, SourceBaseParams};
+use drasi_lib::Source;
+use tracing::Instrument;
+
+use crate::checkpoint::{Checkpoint, PartitionTracker};
+use crate::config::{EventHubsSourceConfig, StartPosition};
+use crate::consumer::{create_consumer, RebalanceContext};
+use crate::conversion::event_to_source_change;
+
+type Dispatchers = Arc<RwLock<Vec<Box<dyn ChangeDispatcher<SourceEventWrapper> + Send + Sync>>>>;
+
+/// Delay before re-reading an event whose dispatch failed.
+const RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// Source that consumes JSON change events from an Azure Event Hub.
+///
+/// The source connects to the Kafka-compatible endpoint of the Event Hubs
+/// namespace and joins `consumer_group`, which balances partitions across all
+/// sources in the group. Dispatched offsets are periodically saved as the
+/// source's checkpoint and committed to the consumer group.
+pub struct EventHubsSource {
+    /// Base source implementation providing dispatchers, status tracking, and lifecycle management.
+    base: SourceBase,
+    /// Event Hubs configuration.
+    config: EventHubsSourceConfig,
+}
+
+/// Connection details shared by the consume loop.
+struct ConsumeContext {
+    source_id: String,
+    event_hub: String,
+    config: EventHubsSourceConfig,
+    dispatchers: Dispatchers,
+    base: SourceBase,
+}
+
+impl EventHubsSource {
+    /// Create a builder for an Event Hubs source.
+    pub fn builder(id: impl Into<String>) -> EventHubsSourceBuilder {
+        EventHubsSourceBuilder::new(id)
+    }
+
+    /// Create a new Event Hubs source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid.
+    pub fn new(id: impl Into<String>, config: EventHubsSourceConfig) -> Result<Self> {
+        EventHubsSourceBuilder::new(id).with_config(config).build()
+    }
+
+    async fn dispatch(
+        source_id: &str,
+        dispatchers: &Dispatchers,
+        change: SourceChange,
+    ) -> Result<()> {
+        let mut profiling = drasi_lib::profiling::ProfilingMetadata::new();
+        profiling.source_send_ns = Some(drasi_lib::profiling::timestamp_ns());
+
+        let wrapper = SourceEventWrapper::with_profiling(
+            source_id.to_string(),
+            SourceEvent::Change(change),
+            chrono::Utc::now(),
+            profiling,
+        );
+
+        SourceBase::dispatch_from_task(dispatchers.clone(), wrapper, source_id).await
+    }
+
+    fn header_value(message: &BorrowedMessage<'_>, name: &str) -> Option<String> {
+        message
+            .headers()?
+            .iter()
+            .find(|header| header.key == name)
+            .and_then(|header| header.value)
+            .map(|value| String::from_utf8_lossy(value).into_owned())
+    }
+
+    /// Load the saved offset of a partition the first time it is seen after
+    /// an assignment.
+    fn ensure_loaded(
+        ctx: &ConsumeContext,
+        tracker: &mut PartitionTracker,
+        checkpoint: &Checkpoint,
+        partition: i32,
+    ) {
+        if tracker.is_loaded(partition) {
+            return;
+        }
+
+        let offset = checkpoint.offsets.get(&partition).copied();
+        if let Some(offset) = offset {
+            debug!(
+                "[{}] Partition {partition} resumes after offset {offset}",
+                ctx.source_id
+            );
+        }
+        tracker.set_loaded(partition, offset);
+    }
+
+    /// Handle one event. Returns `false` if the event must be read again.
+    async fn handle_message(
+        ctx: &ConsumeContext,
+        tracker: &mut PartitionTracker,
+        checkpoint: &Checkpoint,
+        message: &BorrowedMessage<'_>,
+    ) -> bool {
+        let partition = message.partition();
+        let offset = message.offset();
+
+        Self::ensure_loaded(ctx, tracker, checkpoint, partition);
+        if tracker.is_behind_checkpoint(partition, offset) {
+            // Already dispatched before the consumer group offset was committed
+            return true;
+        }
+
+        let header_id = ctx
+            .config
+            .id_header
+            .as_deref()
+            .and_then(|name| Self::header_value(message, name));
+
+        match event_to_source_change(
+            &ctx.source_id,
+            &ctx.event_hub,
+            message.payload().unwrap_or_default(),
+            header_id.as_deref(),
+            &ctx.config,
+        ) {
+            Ok(change) => {
+                if let Err(e) = Self::dispatch(&ctx.source_id, &ctx.dispatchers, change).await {
+                    warn!(
+                        "[{}] Failed to dispatch event {partition}/{offset}, retrying: {e}",
+                        ctx.source_id
+                    );
+                    return false;
+                }
+            }
+            Err(e) => {
+                // Retrying a malformed event would fail the same way
+                warn!(
+                    "[{}] Skipping unprocessable event {partition}/{offset}: {e}",
+                    ctx.source_id
+                );
+            }
+        }
+
+        tracker.record(partition, offset);
+        true
+    }
+
+    /// Save pending offsets as the source's checkpoint and commit them to the
+    /// consumer group.
+    ///
+    /// The checkpoint is saved first: the committed group offset is where a
+    /// new owner starts reading, so it must never be ahead of the checkpoint.
+    async fn flush(
+        ctx: &ConsumeContext,
+        consumer: &StreamConsumer<RebalanceContext>,
+        tracker: &mut PartitionTracker,
+        checkpoint: &mut Checkpoint,
+    ) {
+        let pending = tracker.take_pending();
+        if pending.is_empty() {
+            return;
+        }
+
+        checkpoint.offsets.extend(pending.iter().copied());
+        if let Err(e) = ctx.base.save_checkpoint(checkpoint).await {
+            warn!("[{}] Failed to save checkpoint: {e}", ctx.source_id);
+            for (partition, offset) in pending {
+                tracker.record(partition, offset);
+            }
+            return;
+        }
+
+        let mut commits = TopicPartitionList::new();
+        for (partition, offset) in pending {
+            tracker.mark_flushed(partition, offset);
+            // The committed offset is the next event to read
+            if let Err(e) =
+                commits.add_partition_offset(&ctx.event_hub, partition, Offset::Offset(offset + 1))
+            {
+                warn!(
+                    "[{}] Failed to prepare commit for partition {partition}: {e}",
+                    ctx.source_id
+                );
+            }
+        }
+
+        if commits.count() > 0 {
+            if let Err(e) = consumer.commit(&commits, CommitMode::Async) {
+                warn!("[{}] Failed to commit offsets: {e}", ctx.source_id);
+            }
+        }
+    }
+
+    async fn run(ctx: ConsumeContext, status_handle: ComponentStatusHandle) {
+        let consumer = match create_consumer(&ctx.source_id, &ctx.config) {
+            Ok(consumer) => consumer,
+            Err(e) => {
+                error!(
+                    "[{}] Failed to create Event Hubs consumer: {e}",
+                    ctx.source_id
+                );
+                status_handle
+                    .set_status(
+                        ComponentStatus::Error,
+                        Some(format!("Failed to create Event Hubs consumer: {e}")),
+                    )
+                    .await;
+                return;
+            }
+        };
+
+        info!(
+            "[{}] Consuming Event Hub '{}' as consumer group '{}'",
+            ctx.source_id, ctx.event_hub, ctx.config.consumer_group
+        );
+        status_handle
+            .set_status(
+                ComponentStatus::Running,
+                Some("Event Hubs source running".to_string()),
+            )
+            .await;
+
+        let mut tracker = PartitionTracker::default();
+        let mut checkpoint = match ctx.base.load_checkpoint::<Checkpoint>().await {
+            Ok(Some(checkpoint))
+                if checkpoint.is_for(&ctx.event_hub, &ctx.config.consumer_group) =>
+            {
+                checkpoint
+            }
+            Ok(_) => Checkpoint::new(&ctx.event_hub, &ctx.config.consumer_group),
+            Err(e) => {
+                warn!(
+                    "[{}] Failed to load checkpoint, relying on consumer group offsets: {e}",
+                    ctx.source_id
+                );
+                Checkpoint::new(&ctx.event_hub, &ctx.config.consumer_group)
+            }
+        };
+        let mut checkpoint_interval =
+            tokio::time::interval(Duration::from_millis(ctx.config.checkpoint_interval_ms));
+
+        loop {
+            for partition in consumer.context().take_revoked() {
+                tracker.revoke(partition);
+            }
+
+            tokio::select! {
+                _ = checkpoint_interval.tick() => {
+                    Self::flush(&ctx, &consumer, &mut tracker, &mut checkpoint).await;
+                }
+                message = consumer.recv() => {
+                    let message = match message {
+                        Ok(message) => message,
+                        Err(e) => {
+                            // The client reconnects by itself; errors here are transient
+                            warn!("[{}] Event Hubs consumer error: {e}", ctx.source_id);
+                            continue;
+                        }
+                    };
+
+                    if !Self::handle_message(&ctx, &mut tracker, &checkpoint, &message).await {
+                        let (partition, offset) = (message.partition(), message.offset());
+                        drop(message);
+                        if let Err(e) = consumer.seek(
+                            &ctx.event_hub,
+                            partition,
+                            Offset::Offset(offset),
+                            Duration::from_secs(5),
+                        ) {
+                            warn!(
+                                "[{}] Failed to rewind partition {partition} to offset {offset}: {e}",
+                                ctx.source_id
+                            );
+                        }
+                        tokio::time::sleep(RETRY_DELAY).await;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Source for EventHubsSource {
+    fn id(&self) -> &str {
+        &self.base.id
+    }
+
+    fn type_name(&self) -> &str {
+        "eventhubs"
+    }
+
+    fn properties(&self) -> HashMap<String, serde_json::Value> {
+        use crate::descriptor::{EventHubsSourceConfigDto, StartPositionDto};
+        use drasi_plugin_sdk::ConfigValue;
+
+        let dto = EventHubsSourceConfigDto {
+            // The connection string carries the access key, so only the
+            // namespace and Event Hub are exposed
+            connection_string: ConfigValue::Static(redact_connection_string(
+                &self.config.connection_string,
+            )),
+            event_hub: self.config.event_hub.clone().map(ConfigValue::Static),
+            consumer_group: ConfigValue::Static(self.config.consumer_group.clone()),
+            start_position: match self.config.start_position {
+                StartPosition::Earliest => StartPositionDto::Earliest,
+                StartPosition::Latest => StartPositionDto::Latest,
+            },
+            checkpoint_interval_ms: ConfigValue::Static(self.config.checkpoint_interval_ms),
+            session_timeout_ms: ConfigValue::Static(self.config.session_timeout_ms),
+            id_field: self.config.id_field.clone(),
+            id_header: self.config.id_header.clone(),
+            operation_field: self.config.operation_field.clone(),
+            label_field: self.config.label_field.clone(),
+            label: self.config.label.clone(),
+        };
+
+        match serde_json::to_value(&dto) {
+            Ok(serde_json::Value::Object(map)) => map.into_iter().collect(),
+            _ => HashMap::new(),
+        }
+    }
+
+    fn auto_start(&self) -> bool {
+        self.base.get_auto_start()
+    }
+
+    async fn start(&self) -> Result<()> {
+        log_component_start("Event Hubs Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Starting,
+                Some("Starting Event Hubs source".to_string()),
+            )
+            .await;
+
+        let has_checkpoint_store = self
+            .base
+            .context()
+            .await
+            .is_some_and(|c| c.checkpoint_store().is_some());
+        if !has_checkpoint_store {
+            info!(
+                "[{}] No checkpoint store configured, relying on consumer group offsets only",
+                self.base.id
+            );
+        }
+
+        let ctx = ConsumeContext {
+            source_id: self.base.id.clone(),
+            event_hub: self.config.event_hub_name()?,
+            config: self.config.clone(),
+            dispatchers: self.base.dispatchers.clone(),
+            base: self.base.clone_shared(),
+        };
+
+        let instance_id = self
+            .base
+            .context()
+            .await
+            .map(|c| c.instance_id)
+            .unwrap_or_default();
+
+        let span = tracing::info_span!(
+            "eventhubs_source_consumer",
+            instance_id = %instance_id,
+            component_id = %self.base.id,
+            component_type = "source"
+        );
+        let task = tokio::spawn(Self::run(ctx, self.base.status_handle()).instrument(span));
+
+        *self.base.task_handle.write().await = Some(task);
+
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        log_component_stop("Event Hubs Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopping,
+                Some("Stopping Event Hubs source".to_string()),
+            )
+            .await;
+
+        // Progress since the last flush is re-read on the next start
+        if let Some(handle) = self.base.task_handle.write().await.take() {
+            handle.abort();
+            let _ = handle.await;
+        }
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopped,
+                Some("Event Hubs source stopped".to_string()),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn status(&self) -> ComponentStatus {
+        self.base.get_status().await
+    }
+
+    async fn subscribe(
+        &self,
+        settings: drasi_lib::config::SourceSubscriptionSettings,
+    ) -> Result<SubscriptionResponse> {
+        self.base
+            .subscribe_with_bootstrap(&settings, "Event Hubs")
+            .await
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    async fn initialize(&self, context: drasi_lib::context::SourceRuntimeContext) {
+        self.base.initialize(context).await;
+    }
+
+    async fn set_bootstrap_provider(
+        &self,
+        provider: Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>,
+    ) {
+        self.base.set_bootstrap_provider(provider).await;
+    }
+}
+
+/// Mask the `SharedAccessKey` of a connection string.
+pub(crate) fn redact_connection_string(connection_string: &str) -> String {
+    connection_string
+        .split(';')
+        .map(|segment| match segment.split_once('=') {
+            Some((name, _)) if name.trim().eq_ignore_ascii_case("SharedAccessKey") => {
+                format!("{name}=***")
+            }
+            _ => segment.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+/// Builder for [`EventHubsSource`] instances.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use drasi_source_eventhubs::EventHubsSource;
+///
+/// let source = EventHubsSource::builder("telemetry")
+///     .with_connection_string(std::env::var("EVENTHUBS_CONNECTION_STRING")?)
+///     .with_event_hub("telemetry")
+///     .with_consumer_group("drasi")
+///     .with_id_header("iothub-connection-device-id")
+///     .with_label("Device")
+///     .build()?;
+/// ```
+pub struct EventHubsSourceBuilder {
+    id: String,
+    config: EventHubsSourceConfig,
+    dispatch_mode: Option<DispatchMode>,
+    dispatch_buffer_capacity: Option<usize>,
+    bootstrap_provider: Option<Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>>,
+    auto_start: bool,
+}
+
+impl EventHubsSourceBuilder {
+    /// Create a new builder with the given ID and default values.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            config: EventHubsSourceConfig::default(),
+            dispatch_mode: None,
+            dispatch_buffer_capacity: None,
+            bootstrap_provider: None,
+            auto_start: true,
+        }
+    }
+
+    /// Set the Event Hubs connection string.
+    pub fn with_connection_string(mut self, connection_string: impl Into<String>) -> Self {
+        self.config.connection_string = connection_string.into();
+        self
+    }
+
+    /// Set the Event Hub to read from.
+    pub fn with_event_hub(mut self, event_hub: impl Into<String>) -> Self {
+        self.config.event_hub = Some(event_hub.into());
+        self
+    }
+
+    /// Set the consumer group.
+    pub fn with_consumer_group(mut self, consumer_group: impl Into<String>) -> Self {
+        self.config.consumer_group = consumer_group.into();
+        self
+    }
+
+    /// Set where to start reading partitions without a checkpoint.
+    pub fn with_start_position(mut self, start_position: StartPosition) -> Self {
+        self.config.start_position = start_position;
+        self
+    }
+
+    /// Set the interval between checkpoint flushes in milliseconds.
+    pub fn with_checkpoint_interval_ms(mut self, interval_ms: u64) -> Self {
+        self.config.checkpoint_interval_ms = interval_ms;
+        self
+    }
+
+    /// Set the consumer group session timeout in milliseconds.
+    pub fn with_session_timeout_ms(mut self, timeout_ms: u64) -> Self {
+        self.config.session_timeout_ms = timeout_ms;
+        self
+    }
+
+    /// Set the payload field holding the element ID.
+    pub fn with_id_field(mut self, field: impl Into<String>) -> Self {
+        self.config.id_field = field.into();
+        self
+    }
+
+    /// Set the event header holding the element ID when the body has none.
+    pub fn with_id_header(mut self, header: impl Into<String>) -> Self {
+        self.config.id_header = Some(header.into());
+        self
+    }
+
+    /// Set the payload field holding the change operation.
+    pub fn with_operation_field(mut self, field: impl Into<String>) -> Self {
+        self.config.operation_field = field.into();
+        self
+    }
+
+    /// Set the payload field holding the node label.
+    pub fn with_label_field(mut self, field: impl Into<String>) -> Self {
+        self.config.label_field = Some(field.into());
+        self
+    }
+
+    /// Set the label for nodes from this source.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.config.label = Some(label.into());
+        self
+    }
+
+    /// Set the dispatch mode for this source.
+    pub fn with_dispatch_mode(mut self, mode: DispatchMode) -> Self {
+        self.dispatch_mode = Some(mode);
+        self
+    }
+
+    /// Set the dispatch buffer capacity.
+    pub fn with_dispatch_buffer_capacity(mut self, capacity: usize) -> Self {
+        self.dispatch_buffer_capacity = Some(capacity);
+        self
+    }
+
+    /// Set the bootstrap provider for initial data delivery.
+    pub fn with_bootstrap_provider(
+        mut self,
+        provider: impl drasi_lib::bootstrap::BootstrapProvider + 'static,
+    ) -> Self {
+        self.bootstrap_provider = Some(Box::new(provider));
+        self
+    }
+
+    /// Set whether this source should auto-start when DrasiLib starts.
+    pub fn with_auto_start(mut self, auto_start: bool) -> Self {
+        self.auto_start = auto_start;
+        self
+    }
+
+    /// Set the full configuration at once.
+    pub fn with_config(mut self, config: EventHubsSourceConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Build the Event Hubs source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid or the base source
+    /// cannot be initialized.
+    pub fn build(self) -> Result<EventHubsSource> {
+        self.config.validate()?;
+
+        let mut params = SourceBaseParams::new(&self.id).with_auto_start(self.auto_start);
+        if let Some(mode) = self.dispatch_mode {
+            params = params.with_dispatch_mode(mode);
+        }
+        if let Some(capacity) = self.dispatch_buffer_capacity {
+            params = params.with_dispatch_buffer_capacity(capacity);
+        }
+        if let Some(provider) = self.bootstrap_provider {
+            params = params.with_bootstrap_provider(provider);
+        }
+
+        Ok(EventHubsSource {
+            base: SourceBase::new(params)?,
+            config: self.config,
+        })
+    }
+}
@@ -0,0 +1,90 @@
+#![allow(unexpected_cfgs)]
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Azure Event Hubs Source Plugin for drasi-lib.
+//!
+//! This plugin consumes JSON change events from an Azure Event Hub through the
+//! namespace's Kafka-compatible endpoint. Because Azure IoT Hub exposes an
+//! Event Hub-compatible endpoint, it can also feed device telemetry straight
+//! into continuous queries.
+//!
+//! # Event Mapping
+//!
+//! | Body field | Maps to |
+//! |------------|---------|
+//! | `id_field` (default `id`) | Element ID; falls back to the `id_header` event header |
+//! | `operation_field` (default `op`) | `insert`/`i`, `update`/`u` or `delete`/`d`; missing means insert |
+//! | `label_field` (optional) | Node label, overriding `label` |
+//! | all other fields | Node properties |
+//!
+//! Without `label_field` or `label`, the Event Hub name is used as the label.
+//!
+//! # Partitions and Checkpoints
+//!
+//! Sources sharing a `consumer_group` split the Event Hub's partitions between
+//! them; each partition is owned by one source at a time and ownership moves
+//! on rebalance. Every `checkpoint_interval_ms` the source saves the last
+//! dispatched offset of each partition as its [`Checkpoint`] and then commits
+//! the offsets to the consumer group. A source that gains a partition skips
+//! events at or before its checkpointed offset. Progress on a revoked partition
+//! that was not flushed yet is discarded, so the new owner re-reads those
+//! events and delivery is at-least-once.
+//!
+//! Checkpoints go to the DrasiLib checkpoint store and belong to a single
+//! source; the committed consumer group offsets carry progress from one
+//! source to another. Without a checkpoint store, the consumer group offsets
+//! are the only checkpoints.
+//!
+//! # Usage Example
+//!
+//! ```rust,ignore
+//! use drasi_source_eventhubs::{EventHubsSource, StartPosition};
+//!
+//! let source = EventHubsSource::builder("iot-telemetry")
+//!     .with_connection_string(std::env::var("IOTHUB_EVENTHUB_CONNECTION_STRING")?)
+//!     .with_consumer_group("drasi")
+//!     .with_start_position(StartPosition::Earliest)
+//!     .with_id_header("iothub-connection-device-id")
+//!     .with_label("Device")
+//!     .build()?;
+//!
+//! drasi.add_source(source).await?;
+//! ```
+
+mod checkpoint;
+mod config;
+mod consumer;
+mod conversion;
+pub mod descriptor;
+mod eventhubs;
+
+#[cfg(test)]
+mod tests;
+
+pub use checkpoint::Checkpoint;
+pub use config::{EventHubsConnection, EventHubsSourceConfig, StartPosition};
+pub use eventhubs::{EventHubsSource, EventHubsSourceBuilder};
+
+/// Dynamic plugin entry point.
+#[cfg(feature = "dynamic-plugin")]
+drasi_plugin_sdk::export_plugin!(
+    plugin_id = "eventhubs-source",
+    core_version = env!("CARGO_PKG_VERSION"),
+    lib_version = env!("CARGO_PKG_VERSION"),
+    plugin_version = env!("CARGO_PKG_VERSION"),
+    source_descriptors = [descriptor::EventHubsSourceDescriptor],
+    reaction_descriptors = [],
+    bootstrap_descriptors = [],
+);
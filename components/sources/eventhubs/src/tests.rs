@@ -0,0 +1,380 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for the Azure Event Hubs source plugin.
+
+use super::*;
+use crate::checkpoint::PartitionTracker;
+use crate::conversion::event_to_source_change;
+use crate::eventhubs::redact_connection_string;
+use drasi_core::models::{Element, ElementValue, SourceChange};
+use drasi_lib::Source;
+use drasi_plugin_sdk::prelude::SourcePluginDescriptor;
+use serde_json::json;
+use std::sync::Arc;
+
+const CONNECTION_STRING: &str = "Endpoint=sb://drasi-ns.servicebus.windows.net/;SharedAccessKeyName=listen;SharedAccessKey=c2VjcmV0a2V5=;EntityPath=telemetry";
+
+fn config() -> EventHubsSourceConfig {
+    EventHubsSourceConfig {
+        connection_string: CONNECTION_STRING.to_string(),
+        ..Default::default()
+    }
+}
+
+fn payload(value: serde_json::Value) -> Vec<u8> {
+    serde_json::to_vec(&value).unwrap()
+}
+
+mod builder {
+    use super::*;
+
+    #[test]
+    fn test_builder_with_valid_config() {
+        let source = EventHubsSource::builder("test-source")
+            .with_connection_string(CONNECTION_STRING)
+            .with_consumer_group("drasi")
+            .with_start_position(StartPosition::Earliest)
+            .with_id_header("iothub-connection-device-id")
+            .build()
+            .unwrap();
+
+        assert_eq!(source.id(), "test-source");
+        assert_eq!(source.type_name(), "eventhubs");
+        let props = source.properties();
+        assert_eq!(
+            props.get("connectionString"),
+            Some(&json!("Endpoint=sb://drasi-ns.servicebus.windows.net/;SharedAccessKeyName=listen;SharedAccessKey=***;EntityPath=telemetry"))
+        );
+        assert_eq!(props.get("consumerGroup"), Some(&json!("drasi")));
+        assert_eq!(props.get("startPosition"), Some(&json!("earliest")));
+        assert_eq!(
+            props.get("idHeader"),
+            Some(&json!("iothub-connection-device-id"))
+        );
+    }
+
+    #[test]
+    fn test_builder_requires_connection_string() {
+        assert!(EventHubsSource::builder("test-source").build().is_err());
+    }
+
+    #[test]
+    fn test_config_rejects_invalid_values() {
+        let namespace_only = EventHubsSourceConfig {
+            connection_string:
+                "Endpoint=sb://ns.servicebus.windows.net/;SharedAccessKeyName=k;SharedAccessKey=v"
+                    .to_string(),
+            ..Default::default()
+        };
+        assert!(namespace_only.validate().is_err());
+        assert!(EventHubsSourceConfig {
+            event_hub: Some("telemetry".to_string()),
+            ..namespace_only
+        }
+        .validate()
+        .is_ok());
+
+        let mismatched_hub = EventHubsSourceConfig {
+            event_hub: Some("other".to_string()),
+            ..config()
+        };
+        assert!(mismatched_hub.validate().is_err());
+
+        let empty_group = EventHubsSourceConfig {
+            consumer_group: String::new(),
+            ..config()
+        };
+        assert!(empty_group.validate().is_err());
+
+        let zero_interval = EventHubsSourceConfig {
+            checkpoint_interval_ms: 0,
+            ..config()
+        };
+        assert!(zero_interval.validate().is_err());
+    }
+
+    #[test]
+    fn test_config_deserialization_defaults() {
+        let config: EventHubsSourceConfig =
+            serde_json::from_value(json!({"connection_string": CONNECTION_STRING})).unwrap();
+        assert_eq!(config.consumer_group, "$Default");
+        assert_eq!(config.start_position, StartPosition::Latest);
+        assert_eq!(config.checkpoint_interval_ms, 5000);
+        assert_eq!(config.session_timeout_ms, 30000);
+        assert_eq!(config.event_hub_name().unwrap(), "telemetry");
+    }
+
+    #[test]
+    fn test_parse_connection_string() {
+        let connection = EventHubsConnection::parse(CONNECTION_STRING).unwrap();
+        assert_eq!(connection.host, "drasi-ns.servicebus.windows.net");
+        assert_eq!(connection.shared_access_key_name, "listen");
+        assert_eq!(connection.shared_access_key, "c2VjcmV0a2V5=");
+        assert_eq!(connection.entity_path.as_deref(), Some("telemetry"));
+        assert_eq!(
+            connection.kafka_bootstrap_server(),
+            "drasi-ns.servicebus.windows.net:9093"
+        );
+
+        let lowercase = EventHubsConnection::parse(
+            "endpoint=sb://ns.servicebus.windows.net;sharedaccesskeyname=k;sharedaccesskey=v;",
+        )
+        .unwrap();
+        assert_eq!(lowercase.host, "ns.servicebus.windows.net");
+        assert_eq!(lowercase.entity_path, None);
+
+        assert!(EventHubsConnection::parse("SharedAccessKeyName=k;SharedAccessKey=v").is_err());
+        assert!(EventHubsConnection::parse(
+            "Endpoint=sb://ns.servicebus.windows.net/;SharedAccessKeyName=k"
+        )
+        .is_err());
+        assert!(EventHubsConnection::parse("Endpoint").is_err());
+    }
+
+    #[test]
+    fn test_kafka_client_config() {
+        let client = crate::consumer::client_config("src", &config()).unwrap();
+        assert_eq!(
+            client.get("bootstrap.servers"),
+            Some("drasi-ns.servicebus.windows.net:9093")
+        );
+        assert_eq!(client.get("sasl.username"), Some("$ConnectionString"));
+        assert_eq!(client.get("sasl.password"), Some(CONNECTION_STRING));
+        assert_eq!(client.get("group.id"), Some("$Default"));
+        assert_eq!(client.get("enable.auto.commit"), Some("false"));
+        assert_eq!(client.get("auto.offset.reset"), Some("latest"));
+    }
+
+    #[test]
+    fn test_redact_connection_string() {
+        assert_eq!(
+            redact_connection_string("Endpoint=sb://ns/;sharedaccesskey=abc==;EntityPath=hub"),
+            "Endpoint=sb://ns/;sharedaccesskey=***;EntityPath=hub"
+        );
+    }
+}
+
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn test_event_without_operation_is_insert() {
+        let change = event_to_source_change(
+            "src",
+            "telemetry",
+            &payload(json!({"id": "d-1", "temperature": 21.5})),
+            None,
+            &config(),
+        )
+        .unwrap();
+
+        let SourceChange::Insert {
+            element:
+                Element::Node {
+                    metadata,
+                    properties,
+                },
+        } = change
+        else {
+            panic!("expected node insert");
+        };
+        assert_eq!(metadata.reference.element_id.as_ref(), "d-1");
+        assert_eq!(metadata.labels[0].as_ref(), "telemetry");
+        assert_eq!(
+            properties.get("temperature"),
+            Some(&ElementValue::Float(21.5.into()))
+        );
+    }
+
+    #[test]
+    fn test_id_header_fallback() {
+        let body = payload(json!({"temperature": 21.5}));
+
+        let change =
+            event_to_source_change("src", "telemetry", &body, Some("device-7"), &config()).unwrap();
+        assert_eq!(change.get_reference().element_id.as_ref(), "device-7");
+
+        assert!(event_to_source_change("src", "telemetry", &body, None, &config()).is_err());
+    }
+
+    #[test]
+    fn test_operations_and_labels() {
+        let config = EventHubsSourceConfig {
+            label_field: Some("type".to_string()),
+            label: Some("Device".to_string()),
+            ..config()
+        };
+
+        let change = event_to_source_change(
+            "src",
+            "telemetry",
+            &payload(json!({"id": 1, "op": "u", "type": "Sensor"})),
+            None,
+            &config,
+        )
+        .unwrap();
+        let SourceChange::Update { element } = change else {
+            panic!("expected update");
+        };
+        assert_eq!(element.get_metadata().labels[0].as_ref(), "Sensor");
+        let Element::Node { properties, .. } = element else {
+            panic!("expected node");
+        };
+        assert!(properties.get("type").is_none());
+        assert!(properties.get("op").is_none());
+
+        let change = event_to_source_change(
+            "src",
+            "telemetry",
+            &payload(json!({"id": 1, "op": "delete"})),
+            None,
+            &config,
+        )
+        .unwrap();
+        let SourceChange::Delete { metadata } = change else {
+            panic!("expected delete");
+        };
+        assert_eq!(metadata.labels[0].as_ref(), "Device");
+    }
+
+    #[test]
+    fn test_invalid_events_fail() {
+        let config = config();
+        assert!(event_to_source_change("src", "hub", b"not json", None, &config).is_err());
+        assert!(event_to_source_change("src", "hub", &payload(json!([1])), None, &config).is_err());
+        assert!(event_to_source_change(
+            "src",
+            "hub",
+            &payload(json!({"id": "1", "op": "upsert"})),
+            None,
+            &config
+        )
+        .is_err());
+    }
+}
+
+mod checkpoint {
+    use super::*;
+
+    #[test]
+    fn test_tracker_skips_events_behind_checkpoint() {
+        let mut tracker = PartitionTracker::default();
+        assert!(!tracker.is_loaded(0));
+
+        tracker.set_loaded(0, Some(41));
+        tracker.set_loaded(1, None);
+        assert!(tracker.is_behind_checkpoint(0, 41));
+        assert!(!tracker.is_behind_checkpoint(0, 42));
+        assert!(!tracker.is_behind_checkpoint(1, 0));
+    }
+
+    #[test]
+    fn test_tracker_flush_and_revoke() {
+        let mut tracker = PartitionTracker::default();
+        tracker.set_loaded(0, None);
+        tracker.set_loaded(1, None);
+        tracker.record(1, 7);
+        tracker.record(0, 3);
+        tracker.record(0, 5);
+
+        assert_eq!(tracker.take_pending(), vec![(0, 5), (1, 7)]);
+        assert!(tracker.take_pending().is_empty());
+
+        tracker.mark_flushed(0, 5);
+        assert!(tracker.is_behind_checkpoint(0, 5));
+
+        // Losing ownership drops unflushed progress and the loaded checkpoint
+        tracker.record(0, 9);
+        tracker.revoke(0);
+        assert!(!tracker.is_loaded(0));
+        assert!(tracker.take_pending().is_empty());
+
+        // A revoked partition is not marked until it is loaded again
+        tracker.mark_flushed(0, 9);
+        assert!(!tracker.is_loaded(0));
+    }
+
+    #[tokio::test]
+    async fn test_checkpoint_roundtrip_through_source_base() {
+        use drasi_lib::sources::base::{SourceBase, SourceBaseParams};
+
+        let base = SourceBase::new(SourceBaseParams::new("src")).unwrap();
+        let (update_tx, _update_rx) = tokio::sync::mpsc::channel(16);
+        base.initialize(drasi_lib::context::SourceRuntimeContext::new(
+            "test-instance",
+            "src",
+            Some(Arc::new(drasi_lib::MemoryStateStoreProvider::new())),
+            update_tx,
+            None,
+        ))
+        .await;
+
+        assert_eq!(base.load_checkpoint::<Checkpoint>().await.unwrap(), None);
+
+        let mut checkpoint = Checkpoint::new("telemetry", "$Default");
+        checkpoint.offsets.insert(0, 128);
+        checkpoint.offsets.insert(3, 7);
+        base.save_checkpoint(&checkpoint).await.unwrap();
+
+        let loaded = base.load_checkpoint::<Checkpoint>().await.unwrap().unwrap();
+        assert_eq!(loaded, checkpoint);
+        assert!(loaded.is_for("telemetry", "$Default"));
+        assert!(!loaded.is_for("telemetry", "drasi"));
+        assert!(!loaded.is_for("other", "$Default"));
+    }
+}
+
+mod descriptor {
+    use super::*;
+    use crate::descriptor::EventHubsSourceDescriptor;
+
+    #[tokio::test]
+    async fn test_descriptor_creates_source() {
+        let descriptor = EventHubsSourceDescriptor;
+        assert_eq!(descriptor.kind(), "eventhubs");
+
+        let source = descriptor
+            .create_source(
+                "hub-1",
+                &json!({
+                    "connectionString": CONNECTION_STRING,
+                    "consumerGroup": "drasi",
+                    "startPosition": "earliest",
+                    "checkpointIntervalMs": 1000
+                }),
+                false,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(source.id(), "hub-1");
+        assert!(!source.auto_start());
+        let props = source.properties();
+        assert_eq!(props.get("startPosition"), Some(&json!("earliest")));
+        assert_eq!(props.get("checkpointIntervalMs"), Some(&json!(1000)));
+    }
+
+    #[tokio::test]
+    async fn test_descriptor_rejects_unknown_fields() {
+        let result = EventHubsSourceDescriptor
+            .create_source(
+                "hub-1",
+                &json!({"connectionString": CONNECTION_STRING, "bogus": 1}),
+                true,
+            )
+            .await;
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,168 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration types for the file-tail source plugin.
+//!
+//! This module defines which files the source tails, where it starts reading,
+//! and how each JSON line is mapped onto a graph node.
+
+use serde::{Deserialize, Serialize};
+
+fn default_poll_interval_ms() -> u64 {
+    1000
+}
+
+fn default_batch_size() -> usize {
+    1000
+}
+
+fn default_id_field() -> String {
+    "id".to_string()
+}
+
+fn default_operation_field() -> String {
+    "op".to_string()
+}
+
+/// Where to start reading files found when the source starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum StartPosition {
+    /// Read existing content from the start of the file.
+    Beginning,
+    /// Skip existing content and only read lines appended later.
+    #[default]
+    End,
+}
+
+/// File-tail source configuration.
+///
+/// # Example
+///
+/// ```rust
+/// use drasi_source_file_tail::{FileTailSourceConfig, StartPosition};
+///
+/// let config = FileTailSourceConfig {
+///     paths: vec!["/var/log/app/*.ndjson".to_string()],
+///     start_position: StartPosition::Beginning,
+///     label: Some("Event".to_string()),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FileTailSourceConfig {
+    /// File paths or glob patterns to tail. Patterns are re-evaluated on every
+    /// poll, so files created later are picked up.
+    pub paths: Vec<String>,
+
+    /// Where to start reading files that have no saved offset when the source
+    /// starts. Files that appear while the source is running are always read
+    /// from the beginning.
+    ///
+    /// **Default**: `end`
+    #[serde(default)]
+    pub start_position: StartPosition,
+
+    /// Milliseconds between polls for new lines, new files and rotation.
+    ///
+    /// **Default**: `1000`
+    #[serde(default = "default_poll_interval_ms")]
+    pub poll_interval_ms: u64,
+
+    /// Maximum number of lines read from a single file per poll.
+    ///
+    /// **Default**: `1000`
+    #[serde(default = "default_batch_size")]
+    pub batch_size: usize,
+
+    /// JSON field holding the element ID.
+    ///
+    /// **Default**: `"id"`
+    #[serde(default = "default_id_field")]
+    pub id_field: String,
+
+    /// JSON field holding the change operation (`insert`, `update`, `delete`
+    /// or `i`/`u`/`d`). Lines without it are treated as inserts.
+    ///
+    /// **Default**: `"op"`
+    #[serde(default = "default_operation_field")]
+    pub operation_field: String,
+
+    /// Optional JSON field holding the node label. Takes precedence over `label`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_field: Option<String>,
+
+    /// Label for nodes read by this source. Falls back to the file stem.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+impl Default for FileTailSourceConfig {
+    fn default() -> Self {
+        Self {
+            paths: Vec::new(),
+            start_position: StartPosition::default(),
+            poll_interval_ms: default_poll_interval_ms(),
+            batch_size: default_batch_size(),
+            id_field: default_id_field(),
+            operation_field: default_operation_field(),
+            label_field: None,
+            label: None,
+        }
+    }
+}
+
+impl FileTailSourceConfig {
+    /// Validate the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `paths` is empty or contains an invalid glob pattern
+    /// - `poll_interval_ms` or `batch_size` is zero
+    /// - `id_field` is empty
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if self.paths.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Validation error: paths must contain at least one path or pattern"
+            ));
+        }
+
+        for path in &self.paths {
+            glob::Pattern::new(path).map_err(|e| {
+                anyhow::anyhow!("Validation error: invalid path pattern '{path}': {e}")
+            })?;
+        }
+
+        if self.poll_interval_ms == 0 {
+            return Err(anyhow::anyhow!(
+                "Validation error: poll_interval_ms must be greater than 0"
+            ));
+        }
+
+        if self.batch_size == 0 {
+            return Err(anyhow::anyhow!(
+                "Validation error: batch_size must be greater than 0"
+            ));
+        }
+
+        if self.id_field.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Validation error: id_field cannot be empty"
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! File-tail source plugin descriptor and configuration DTOs.
+
+use crate::{FileTailSourceBuilder, FileTailSourceConfig, StartPosition};
+use drasi_plugin_sdk::prelude::*;
+use utoipa::OpenApi;
+
+/// Start position DTO.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::file_tail::StartPosition)]
+#[serde(rename_all = "lowercase")]
+pub enum StartPositionDto {
+    Beginning,
+    #[default]
+    End,
+}
+
+/// File-tail source configuration DTO.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::file_tail::FileTailSourceConfig)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct FileTailSourceConfigDto {
+    pub paths: Vec<ConfigValue<String>>,
+    #[serde(default)]
+    #[schema(value_type = source::file_tail::StartPosition)]
+    pub start_position: StartPositionDto,
+    #[serde(default = "default_poll_interval_ms")]
+    pub poll_interval_ms: ConfigValue<u64>,
+    #[serde(default = "default_batch_size")]
+    pub batch_size: ConfigValue<usize>,
+    #[serde(default = "default_id_field")]
+    pub id_field: String,
+    #[serde(default = "default_operation_field")]
+    pub operation_field: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_field: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+fn default_poll_interval_ms() -> ConfigValue<u64> {
+    ConfigValue::Static(1000)
+}
+
+fn default_batch_size() -> ConfigValue<usize> {
+    ConfigValue::Static(1000)
+}
+
+fn default_id_field() -> String {
+    "id".to_string()
+}
+
+fn default_operation_field() -> String {
+    "op".to_string()
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(FileTailSourceConfigDto, StartPositionDto)))]
+struct FileTailSourceSchemas;
+
+/// Descriptor for the file-tail source plugin.
+pub struct FileTailSourceDescriptor;
+
+#[async_trait]
+impl SourcePluginDescriptor for FileTailSourceDescriptor {
+    fn kind(&self) -> &str {
+        "file-tail"
+    }
+
+    fn config_version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn config_schema_name(&self) -> &str {
+        "source.file_tail.FileTailSourceConfig"
+    }
+
+    fn config_schema_json(&self) -> String {
+        let api = FileTailSourceSchemas::openapi();
+        serde_json::to_string(
+            &api.components
+                .as_ref()
+                .expect("OpenAPI components missing")
+                .schemas,
+        )
+        .expect("Failed to serialize config schema")
+    }
+
+    async fn create_source(
+        &self,
+        id: &str,
+        config_json: &serde_json::Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn drasi_lib::sources::Source>> {
+        let dto: FileTailSourceConfigDto = serde_json::from_value(config_json.clone())?;
+        let mapper = DtoMapper::new();
+
+        let config = FileTailSourceConfig {
+            paths: mapper.resolve_string_vec(&dto.paths)?,
+            start_position: match dto.start_position {
+                StartPositionDto::Beginning => StartPosition::Beginning,
+                StartPositionDto::End => StartPosition::End,
+            },
+            poll_interval_ms: mapper.resolve_typed(&dto.poll_interval_ms)?,
+            batch_size: mapper.resolve_typed(&dto.batch_size)?,
+            id_field: dto.id_field,
+            operation_field: dto.operation_field,
+            label_field: dto.label_field,
+            label: dto.label,
+        };
+
+        let source = FileTailSourceBuilder::new(id)
+            .with_config(config)
+            .with_auto_start(auto_start)
+            .build()?;
+
+        Ok(Box::new(source))
+    }
+}
@@ -0,0 +1,473 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! File-tail source implementation and builder.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use log::{debug, error, info, warn};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+use drasi_lib::channels::*;
+use drasi_lib::managers::{log_component_start, log_component_stop};
+use drasi_lib::sources::base::{SourceBase, SourceBaseParams};
+use drasi_lib::Source;
+use tracing::Instrument;
+
+use crate::config::{FileTailSourceConfig, StartPosition};
+use crate::conversion::line_to_source_change;
+use crate::tailer::{FileOffset, FileTailer, TailBatch};
+
+type Dispatchers = Arc<RwLock<Vec<Box<dyn ChangeDispatcher<SourceEventWrapper> + Send + Sync>>>>;
+
+/// Saved position of every tailed file, keyed by path.
+type SavedOffsets = HashMap<String, FileOffset>;
+
+/// Source that tails newline-delimited JSON files.
+///
+/// Each complete line becomes one node change. When DrasiLib has a checkpoint
+/// store, the byte offset of every file is saved after its lines have been
+/// dispatched, and reading resumes from there after a restart.
+pub struct FileTailSource {
+    /// Base source implementation providing dispatchers, status tracking, and lifecycle management.
+    base: SourceBase,
+    /// File-tail configuration.
+    config: FileTailSourceConfig,
+}
+
+impl FileTailSource {
+    /// Create a builder for a file-tail source.
+    pub fn builder(id: impl Into<String>) -> FileTailSourceBuilder {
+        FileTailSourceBuilder::new(id)
+    }
+
+    /// Create a new file-tail source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid.
+    pub fn new(id: impl Into<String>, config: FileTailSourceConfig) -> Result<Self> {
+        FileTailSourceBuilder::new(id).with_config(config).build()
+    }
+
+    /// Record the position of a file and save the offsets of every file as
+    /// the source's checkpoint. A `None` position forgets the file.
+    async fn save_offset(
+        base: &SourceBase,
+        offsets: &mut SavedOffsets,
+        path: &Path,
+        position: Option<FileOffset>,
+    ) {
+        let key = path.to_string_lossy().into_owned();
+        match position {
+            Some(position) => offsets.insert(key, position),
+            None => offsets.remove(&key),
+        };
+        if let Err(e) = base.save_checkpoint(offsets).await {
+            warn!(
+                "[{}] Failed to save offset for {}: {e}",
+                base.id,
+                path.display()
+            );
+        }
+    }
+
+    async fn dispatch_batch(
+        source_id: &str,
+        config: &FileTailSourceConfig,
+        dispatchers: &Dispatchers,
+        batch: &TailBatch,
+    ) {
+        for line in &batch.lines {
+            let change = match line_to_source_change(source_id, &batch.path, line, config) {
+                Ok(change) => change,
+                Err(e) => {
+                    warn!(
+                        "[{source_id}] Skipping line in {}: {e}",
+                        batch.path.display()
+                    );
+                    continue;
+                }
+            };
+
+            let mut profiling = drasi_lib::profiling::ProfilingMetadata::new();
+            profiling.source_send_ns = Some(drasi_lib::profiling::timestamp_ns());
+
+            let wrapper = SourceEventWrapper::with_profiling(
+                source_id.to_string(),
+                SourceEvent::Change(change),
+                chrono::Utc::now(),
+                profiling,
+            );
+
+            if let Err(e) =
+                SourceBase::dispatch_from_task(dispatchers.clone(), wrapper, source_id).await
+            {
+                debug!("[{source_id}] Failed to dispatch change: {e}");
+            }
+        }
+    }
+
+    async fn run(base: SourceBase, config: FileTailSourceConfig) {
+        let source_id = base.id.clone();
+        let dispatchers = base.dispatchers.clone();
+        let mut offsets: SavedOffsets = base
+            .load_checkpoint()
+            .await
+            .unwrap_or_else(|e| {
+                warn!("[{source_id}] Failed to load saved offsets: {e}");
+                None
+            })
+            .unwrap_or_default();
+        let mut tailer = FileTailer::new(config.paths.clone(), config.batch_size);
+        let mut from_end = config.start_position == StartPosition::End;
+        let mut interval = tokio::time::interval(Duration::from_millis(config.poll_interval_ms));
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+
+            // Poll known files first so rotated files are drained before
+            // their new names are discovered
+            for path in tailer.tracked_paths() {
+                match tailer.poll(&path).await {
+                    Ok(Some(batch)) => {
+                        Self::dispatch_batch(&source_id, &config, &dispatchers, &batch).await;
+                        Self::save_offset(&base, &mut offsets, &path, batch.position).await;
+                    }
+                    Ok(None) => {}
+                    Err(e) => warn!("[{source_id}] Failed to read {}: {e}", path.display()),
+                }
+            }
+
+            for path in tailer.matching_paths() {
+                if tailer.is_tracked(&path) {
+                    continue;
+                }
+                let saved = offsets.get(path.to_string_lossy().as_ref()).copied();
+                match tailer.track(&path, saved, from_end).await {
+                    Ok(position) => info!(
+                        "[{source_id}] Tailing {} from offset {}",
+                        path.display(),
+                        position.offset
+                    ),
+                    Err(e) => error!("[{source_id}] Failed to open {}: {e}", path.display()),
+                }
+            }
+
+            tailer.clear_retired();
+            from_end = false;
+        }
+    }
+}
+
+#[async_trait]
+impl Source for FileTailSource {
+    fn id(&self) -> &str {
+        &self.base.id
+    }
+
+    fn type_name(&self) -> &str {
+        "file-tail"
+    }
+
+    fn properties(&self) -> HashMap<String, serde_json::Value> {
+        use crate::descriptor::{FileTailSourceConfigDto, StartPositionDto};
+        use drasi_plugin_sdk::ConfigValue;
+
+        let dto = FileTailSourceConfigDto {
+            paths: self
+                .config
+                .paths
+                .iter()
+                .map(|p| ConfigValue::Static(p.clone()))
+                .collect(),
+            start_position: match self.config.start_position {
+                StartPosition::Beginning => StartPositionDto::Beginning,
+                StartPosition::End => StartPositionDto::End,
+            },
+            poll_interval_ms: ConfigValue::Static(self.config.poll_interval_ms),
+            batch_size: ConfigValue::Static(self.config.batch_size),
+            id_field: self.config.id_field.clone(),
+            operation_field: self.config.operation_field.clone(),
+            label_field: self.config.label_field.clone(),
+            label: self.config.label.clone(),
+        };
+
+        match serde_json::to_value(&dto) {
+            Ok(serde_json::Value::Object(map)) => map.into_iter().collect(),
+            _ => HashMap::new(),
+        }
+    }
+
+    fn auto_start(&self) -> bool {
+        self.base.get_auto_start()
+    }
+
+    async fn start(&self) -> Result<()> {
+        log_component_start("File Tail Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Starting,
+                Some("Starting file-tail source".to_string()),
+            )
+            .await;
+
+        let has_checkpoint_store = self
+            .base
+            .context()
+            .await
+            .is_some_and(|c| c.checkpoint_store().is_some());
+        if !has_checkpoint_store {
+            info!(
+                "[{}] No checkpoint store configured, file offsets will not survive restarts",
+                self.base.id
+            );
+        }
+
+        let instance_id = self
+            .base
+            .context()
+            .await
+            .map(|c| c.instance_id)
+            .unwrap_or_default();
+
+        let span = tracing::info_span!(
+            "file_tail_source",
+            instance_id = %instance_id,
+            component_id = %self.base.id,
+            component_type = "source"
+        );
+        let task =
+            tokio::spawn(Self::run(self.base.clone_shared(), self.config.clone()).instrument(span));
+
+        *self.base.task_handle.write().await = Some(task);
+
+        self.base
+            .set_status(
+                ComponentStatus::Running,
+                Some("File-tail source running".to_string()),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        log_component_stop("File Tail Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopping,
+                Some("Stopping file-tail source".to_string()),
+            )
+            .await;
+
+        if let Some(handle) = self.base.task_handle.write().await.take() {
+            handle.abort();
+            let _ = handle.await;
+        }
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopped,
+                Some("File-tail source stopped".to_string()),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn status(&self) -> ComponentStatus {
+        self.base.get_status().await
+    }
+
+    async fn subscribe(
+        &self,
+        settings: drasi_lib::config::SourceSubscriptionSettings,
+    ) -> Result<SubscriptionResponse> {
+        self.base
+            .subscribe_with_bootstrap(&settings, "FileTail")
+            .await
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    async fn initialize(&self, context: drasi_lib::context::SourceRuntimeContext) {
+        self.base.initialize(context).await;
+    }
+
+    async fn set_bootstrap_provider(
+        &self,
+        provider: Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>,
+    ) {
+        self.base.set_bootstrap_provider(provider).await;
+    }
+}
+
+/// Builder for [`FileTailSource`] instances.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use drasi_source_file_tail::{FileTailSource, StartPosition};
+///
+/// let source = FileTailSource::builder("app-events")
+///     .with_path("/var/log/app/*.ndjson")
+///     .with_start_position(StartPosition::Beginning)
+///     .with_label("Event")
+///     .build()?;
+/// ```
+pub struct FileTailSourceBuilder {
+    id: String,
+    config: FileTailSourceConfig,
+    dispatch_mode: Option<DispatchMode>,
+    dispatch_buffer_capacity: Option<usize>,
+    bootstrap_provider: Option<Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>>,
+    auto_start: bool,
+}
+
+impl FileTailSourceBuilder {
+    /// Create a new builder with the given ID and default values.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            config: FileTailSourceConfig::default(),
+            dispatch_mode: None,
+            dispatch_buffer_capacity: None,
+            bootstrap_provider: None,
+            auto_start: true,
+        }
+    }
+
+    /// Set the file paths or glob patterns to tail.
+    pub fn with_paths(mut self, paths: Vec<String>) -> Self {
+        self.config.paths = paths;
+        self
+    }
+
+    /// Add a file path or glob pattern to tail.
+    pub fn with_path(mut self, path: impl Into<String>) -> Self {
+        self.config.paths.push(path.into());
+        self
+    }
+
+    /// Set where to start reading files without a saved offset.
+    pub fn with_start_position(mut self, position: StartPosition) -> Self {
+        self.config.start_position = position;
+        self
+    }
+
+    /// Set the poll interval in milliseconds.
+    pub fn with_poll_interval_ms(mut self, interval_ms: u64) -> Self {
+        self.config.poll_interval_ms = interval_ms;
+        self
+    }
+
+    /// Set the maximum number of lines read from a file per poll.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.config.batch_size = batch_size;
+        self
+    }
+
+    /// Set the JSON field holding the element ID.
+    pub fn with_id_field(mut self, field: impl Into<String>) -> Self {
+        self.config.id_field = field.into();
+        self
+    }
+
+    /// Set the JSON field holding the change operation.
+    pub fn with_operation_field(mut self, field: impl Into<String>) -> Self {
+        self.config.operation_field = field.into();
+        self
+    }
+
+    /// Set the JSON field holding the node label.
+    pub fn with_label_field(mut self, field: impl Into<String>) -> Self {
+        self.config.label_field = Some(field.into());
+        self
+    }
+
+    /// Set the label for nodes read by this source.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.config.label = Some(label.into());
+        self
+    }
+
+    /// Set the dispatch mode for this source.
+    pub fn with_dispatch_mode(mut self, mode: DispatchMode) -> Self {
+        self.dispatch_mode = Some(mode);
+        self
+    }
+
+    /// Set the dispatch buffer capacity.
+    pub fn with_dispatch_buffer_capacity(mut self, capacity: usize) -> Self {
+        self.dispatch_buffer_capacity = Some(capacity);
+        self
+    }
+
+    /// Set the bootstrap provider for initial data delivery.
+    pub fn with_bootstrap_provider(
+        mut self,
+        provider: impl drasi_lib::bootstrap::BootstrapProvider + 'static,
+    ) -> Self {
+        self.bootstrap_provider = Some(Box::new(provider));
+        self
+    }
+
+    /// Set whether this source should auto-start when DrasiLib starts.
+    pub fn with_auto_start(mut self, auto_start: bool) -> Self {
+        self.auto_start = auto_start;
+        self
+    }
+
+    /// Set the full configuration at once.
+    pub fn with_config(mut self, config: FileTailSourceConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Build the file-tail source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid or the base source
+    /// cannot be initialized.
+    pub fn build(self) -> Result<FileTailSource> {
+        self.config.validate()?;
+
+        let mut params = SourceBaseParams::new(&self.id).with_auto_start(self.auto_start);
+        if let Some(mode) = self.dispatch_mode {
+            params = params.with_dispatch_mode(mode);
+        }
+        if let Some(capacity) = self.dispatch_buffer_capacity {
+            params = params.with_dispatch_buffer_capacity(capacity);
+        }
+        if let Some(provider) = self.bootstrap_provider {
+            params = params.with_bootstrap_provider(provider);
+        }
+
+        Ok(FileTailSource {
+            base: SourceBase::new(params)?,
+            config: self.config,
+        })
+    }
+}
@@ -0,0 +1,85 @@
+#![allow(unexpected_cfgs)]
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! File-Tail Source Plugin for drasi-lib.
+//!
+//! This plugin tails newline-delimited JSON (NDJSON) files, such as application
+//! logs or exported CDC files, and turns each line into a node change.
+//!
+//! # Line Mapping
+//!
+//! | JSON field | Maps to |
+//! |------------|---------|
+//! | `id_field` (default `id`) | Element ID |
+//! | `operation_field` (default `op`) | `insert`/`i`, `update`/`u` or `delete`/`d`; missing means insert |
+//! | `label_field` (optional) | Node label, overriding `label` |
+//! | all other fields | Node properties |
+//!
+//! Without `label_field` or `label`, the file stem is used as the label
+//! (`orders.ndjson` becomes `orders`). Lines that are not JSON objects are
+//! logged and skipped.
+//!
+//! # Files, Rotation and Resume
+//!
+//! `paths` accepts glob patterns that are re-evaluated on every poll. Only
+//! complete lines are read; a trailing line without a newline waits for the
+//! next poll. Files renamed away by log rotation are drained before the new
+//! file at the same path is read from its start, and truncated files are read
+//! again from the start. Rotation is detected by inode, so only truncation is
+//! detected on non-Unix platforms.
+//!
+//! When DrasiLib has a checkpoint store, each file's byte offset is saved as
+//! part of the source's checkpoint after its lines have been dispatched and
+//! used to resume after a restart.
+//! Lines dispatched after the last saved offset may be read again, so delivery
+//! is at-least-once.
+//!
+//! # Usage Example
+//!
+//! ```rust,ignore
+//! use drasi_source_file_tail::{FileTailSource, StartPosition};
+//!
+//! let source = FileTailSource::builder("app-events")
+//!     .with_path("/var/log/app/*.ndjson")
+//!     .with_start_position(StartPosition::Beginning)
+//!     .with_label_field("type")
+//!     .build()?;
+//!
+//! drasi.add_source(source).await?;
+//! ```
+
+mod config;
+mod conversion;
+pub mod descriptor;
+mod file_tail;
+mod tailer;
+
+#[cfg(test)]
+mod tests;
+
+pub use config::{FileTailSourceConfig, StartPosition};
+pub use file_tail::{FileTailSource, FileTailSourceBuilder};
+
+/// Dynamic plugin entry point.
+#[cfg(feature = "dynamic-plugin")]
+drasi_plugin_sdk::export_plugin!(
+    plugin_id = "file-tail-source",
+    core_version = env!("CARGO_PKG_VERSION"),
+    lib_version = env!("CARGO_PKG_VERSION"),
+    plugin_version = env!("CARGO_PKG_VERSION"),
+    source_descriptors = [descriptor::FileTailSourceDescriptor],
+    reaction_descriptors = [],
+    bootstrap_descriptors = [],
+);
@@ -0,0 +1,262 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Polling file tailer that follows files across rotation and truncation.
+//!
+//! Each tracked file keeps an open handle and the byte offset just past the
+//! last complete line that was read. On every poll the tailer compares the
+//! file currently at the path with the tracked one:
+//!
+//! - If the path now refers to a different file (rotation by rename), the old
+//!   handle is drained to its end and the new file is read from the start.
+//! - If the file shrank below the tracked offset (truncation), reading restarts
+//!   from the start of the file.
+//! - If the path disappeared, the old handle is drained and tracking stops.
+//!
+//! Files are identified by inode on Unix. Other platforms only detect truncation.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::io::SeekFrom;
+use std::path::{Path, PathBuf};
+use tokio::fs::File;
+use tokio::io::{AsyncBufReadExt, AsyncSeekExt, BufReader};
+
+/// Read position within a specific file, persisted for resume.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct FileOffset {
+    /// Identity of the file the offset belongs to (inode on Unix, `0` elsewhere)
+    pub file_id: u64,
+    /// Byte offset just past the last complete line read
+    pub offset: u64,
+}
+
+/// Complete lines read from one path in a single poll.
+#[derive(Debug)]
+pub(crate) struct TailBatch {
+    pub path: PathBuf,
+    pub lines: Vec<String>,
+    /// Position after the batch, or `None` if the path no longer exists
+    pub position: Option<FileOffset>,
+}
+
+struct TrackedFile {
+    file: File,
+    position: FileOffset,
+}
+
+#[cfg(unix)]
+fn file_id(metadata: &std::fs::Metadata) -> u64 {
+    use std::os::unix::fs::MetadataExt;
+    metadata.ino()
+}
+
+#[cfg(not(unix))]
+fn file_id(_metadata: &std::fs::Metadata) -> u64 {
+    0
+}
+
+/// Tails the files matching a set of glob patterns.
+pub(crate) struct FileTailer {
+    patterns: Vec<String>,
+    batch_size: usize,
+    files: HashMap<PathBuf, TrackedFile>,
+    /// Final offsets of files drained during the current poll, by file ID, so a
+    /// rotated file that still matches a pattern is not read twice.
+    retired: HashMap<u64, u64>,
+}
+
+impl FileTailer {
+    pub fn new(patterns: Vec<String>, batch_size: usize) -> Self {
+        Self {
+            patterns,
+            batch_size,
+            files: HashMap::new(),
+            retired: HashMap::new(),
+        }
+    }
+
+    /// Expand the patterns into the regular files that currently match them.
+    pub fn matching_paths(&self) -> Vec<PathBuf> {
+        let mut paths = BTreeSet::new();
+        for pattern in &self.patterns {
+            match glob::glob(pattern) {
+                Ok(entries) => paths.extend(entries.flatten().filter(|path| path.is_file())),
+                Err(e) => log::warn!("Invalid path pattern '{pattern}': {e}"),
+            }
+        }
+        paths.into_iter().collect()
+    }
+
+    pub fn tracked_paths(&self) -> Vec<PathBuf> {
+        self.files.keys().cloned().collect()
+    }
+
+    pub fn is_tracked(&self, path: &Path) -> bool {
+        self.files.contains_key(path)
+    }
+
+    /// Start tracking a file.
+    ///
+    /// The starting offset is, in order: the `saved` offset if it belongs to
+    /// the same file and still fits in it, the end of a file drained during
+    /// this poll (a rotated file seen under its new name), the end of the file
+    /// if `from_end` is set, and otherwise the start of the file.
+    pub async fn track(
+        &mut self,
+        path: &Path,
+        saved: Option<FileOffset>,
+        from_end: bool,
+    ) -> std::io::Result<FileOffset> {
+        let tracked = self.open(path, saved, from_end).await?;
+        let position = tracked.position;
+        self.files.insert(path.to_path_buf(), tracked);
+        Ok(position)
+    }
+
+    async fn open(
+        &self,
+        path: &Path,
+        saved: Option<FileOffset>,
+        from_end: bool,
+    ) -> std::io::Result<TrackedFile> {
+        let file = File::open(path).await?;
+        let metadata = file.metadata().await?;
+        let id = file_id(&metadata);
+        let len = metadata.len();
+
+        let offset = match saved {
+            Some(saved) if saved.file_id == id && saved.offset <= len => saved.offset,
+            // The file was replaced while the source was stopped
+            Some(_) => 0,
+            None => match self.retired.get(&id).filter(|_| id != 0) {
+                Some(offset) => (*offset).min(len),
+                None if from_end => len,
+                None => 0,
+            },
+        };
+
+        Ok(TrackedFile {
+            file,
+            position: FileOffset {
+                file_id: id,
+                offset,
+            },
+        })
+    }
+
+    /// Forget the files drained during the last poll.
+    pub fn clear_retired(&mut self) {
+        self.retired.clear();
+    }
+
+    /// Read new complete lines from a tracked path, handling rotation,
+    /// truncation and removal.
+    ///
+    /// Returns `None` if the path is not tracked or nothing changed.
+    pub async fn poll(&mut self, path: &Path) -> std::io::Result<Option<TailBatch>> {
+        let Some(tracked) = self.files.get_mut(path) else {
+            return Ok(None);
+        };
+
+        let metadata = match tokio::fs::metadata(path).await {
+            Ok(metadata) => Some(metadata),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+            Err(e) => return Err(e),
+        };
+
+        let rotated = metadata
+            .as_ref()
+            .is_none_or(|m| file_id(m) != tracked.position.file_id);
+
+        if !rotated {
+            if let Some(metadata) = &metadata {
+                if metadata.len() < tracked.position.offset {
+                    log::info!("File {} was truncated, reading from start", path.display());
+                    tracked.position.offset = 0;
+                }
+            }
+
+            let lines = read_lines(tracked, self.batch_size).await?;
+            if lines.is_empty() {
+                return Ok(None);
+            }
+            return Ok(Some(TailBatch {
+                path: path.to_path_buf(),
+                lines,
+                position: Some(tracked.position),
+            }));
+        }
+
+        // Finish the old file before moving on to whatever is at the path now
+        let Some(mut old) = self.files.remove(path) else {
+            return Ok(None);
+        };
+        let mut lines = read_lines(&mut old, usize::MAX).await?;
+        if old.position.file_id != 0 {
+            self.retired
+                .insert(old.position.file_id, old.position.offset);
+        }
+
+        let position = if metadata.is_some() {
+            log::info!("File {} was rotated, reading new file", path.display());
+            let mut new = self.open(path, None, false).await?;
+            lines.extend(read_lines(&mut new, self.batch_size).await?);
+            let position = new.position;
+            self.files.insert(path.to_path_buf(), new);
+            Some(position)
+        } else {
+            log::info!("File {} was removed", path.display());
+            None
+        };
+
+        Ok(Some(TailBatch {
+            path: path.to_path_buf(),
+            lines,
+            position,
+        }))
+    }
+}
+
+/// Read up to `limit` complete lines from the tracked offset, advancing it.
+///
+/// A trailing line without a newline is left for a later poll. Blank lines are
+/// skipped.
+async fn read_lines(tracked: &mut TrackedFile, limit: usize) -> std::io::Result<Vec<String>> {
+    tracked
+        .file
+        .seek(SeekFrom::Start(tracked.position.offset))
+        .await?;
+    let mut reader = BufReader::new(&mut tracked.file);
+    let mut lines = Vec::new();
+    let mut buf = Vec::new();
+
+    while lines.len() < limit {
+        buf.clear();
+        let read = reader.read_until(b'\n', &mut buf).await?;
+        if read == 0 || buf.last() != Some(&b'\n') {
+            break;
+        }
+        tracked.position.offset += read as u64;
+
+        let line = String::from_utf8_lossy(&buf);
+        let line = line.trim();
+        if !line.is_empty() {
+            lines.push(line.to_string());
+        }
+    }
+
+    Ok(lines)
+}
@@ -0,0 +1,341 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for the file-tail source plugin.
+
+use super::*;
+use crate::conversion::{label_for_path, line_to_source_change};
+use crate::tailer::{FileOffset, FileTailer};
+use drasi_core::models::{Element, ElementValue, SourceChange};
+use drasi_lib::Source;
+use drasi_plugin_sdk::prelude::SourcePluginDescriptor;
+use serde_json::json;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+fn config() -> FileTailSourceConfig {
+    FileTailSourceConfig {
+        paths: vec!["/tmp/*.ndjson".to_string()],
+        ..Default::default()
+    }
+}
+
+fn append(path: &Path, content: &str) {
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .unwrap();
+    file.write_all(content.as_bytes()).unwrap();
+}
+
+fn tailer_for(dir: &tempfile::TempDir) -> FileTailer {
+    let pattern = dir.path().join("*.ndjson").to_string_lossy().into_owned();
+    FileTailer::new(vec![pattern], 100)
+}
+
+mod builder {
+    use super::*;
+
+    #[test]
+    fn test_builder_with_valid_config() {
+        let source = FileTailSource::builder("test-source")
+            .with_path("/var/log/app/*.ndjson")
+            .with_start_position(StartPosition::Beginning)
+            .with_label("Event")
+            .build()
+            .unwrap();
+
+        assert_eq!(source.id(), "test-source");
+        assert_eq!(source.type_name(), "file-tail");
+        let props = source.properties();
+        assert_eq!(props.get("paths"), Some(&json!(["/var/log/app/*.ndjson"])));
+        assert_eq!(props.get("startPosition"), Some(&json!("beginning")));
+        assert_eq!(props.get("label"), Some(&json!("Event")));
+    }
+
+    #[test]
+    fn test_builder_requires_paths() {
+        assert!(FileTailSource::builder("test-source").build().is_err());
+    }
+
+    #[test]
+    fn test_config_rejects_invalid_values() {
+        let invalid_pattern = FileTailSourceConfig {
+            paths: vec!["/var/log/[".to_string()],
+            ..config()
+        };
+        assert!(invalid_pattern.validate().is_err());
+
+        let zero_interval = FileTailSourceConfig {
+            poll_interval_ms: 0,
+            ..config()
+        };
+        assert!(zero_interval.validate().is_err());
+    }
+
+    #[test]
+    fn test_config_deserialization_defaults() {
+        let config: FileTailSourceConfig =
+            serde_json::from_value(json!({"paths": ["a.ndjson"]})).unwrap();
+        assert_eq!(config.start_position, StartPosition::End);
+        assert_eq!(config.poll_interval_ms, 1000);
+        assert_eq!(config.batch_size, 1000);
+        assert_eq!(config.id_field, "id");
+        assert_eq!(config.operation_field, "op");
+    }
+}
+
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn test_line_without_operation_is_insert() {
+        let change = line_to_source_change(
+            "src",
+            Path::new("/logs/orders.ndjson"),
+            r#"{"id": "o-1", "total": 42.5}"#,
+            &config(),
+        )
+        .unwrap();
+
+        let SourceChange::Insert {
+            element:
+                Element::Node {
+                    metadata,
+                    properties,
+                },
+        } = change
+        else {
+            panic!("expected node insert");
+        };
+        assert_eq!(metadata.reference.element_id.as_ref(), "o-1");
+        assert_eq!(metadata.labels[0].as_ref(), "orders");
+        assert_eq!(
+            properties.get("total"),
+            Some(&ElementValue::Float(42.5.into()))
+        );
+    }
+
+    #[test]
+    fn test_operations_and_label_field() {
+        let config = FileTailSourceConfig {
+            label_field: Some("type".to_string()),
+            ..config()
+        };
+        let path = Path::new("/logs/events.ndjson");
+
+        let change = line_to_source_change(
+            "src",
+            path,
+            r#"{"id": 1, "op": "u", "type": "Order"}"#,
+            &config,
+        )
+        .unwrap();
+        let SourceChange::Update { element } = change else {
+            panic!("expected update");
+        };
+        assert_eq!(element.get_metadata().labels[0].as_ref(), "Order");
+
+        let change =
+            line_to_source_change("src", path, r#"{"id": 1, "op": "D"}"#, &config).unwrap();
+        assert!(matches!(change, SourceChange::Delete { .. }));
+    }
+
+    #[test]
+    fn test_invalid_lines_fail() {
+        let path = Path::new("/logs/events.ndjson");
+        assert!(line_to_source_change("src", path, "plain text", &config()).is_err());
+        assert!(line_to_source_change("src", path, "[1, 2]", &config()).is_err());
+        assert!(line_to_source_change("src", path, r#"{"name": "x"}"#, &config()).is_err());
+        assert!(
+            line_to_source_change("src", path, r#"{"id": "1", "op": "merge"}"#, &config()).is_err()
+        );
+    }
+
+    #[test]
+    fn test_label_for_path() {
+        let path = Path::new("/logs/orders.ndjson");
+        assert_eq!(label_for_path(path, &config()), "orders");
+
+        let config = FileTailSourceConfig {
+            label: Some("Order".to_string()),
+            ..config()
+        };
+        assert_eq!(label_for_path(path, &config), "Order");
+    }
+}
+
+mod tailer {
+    use super::*;
+
+    async fn poll_lines(tailer: &mut FileTailer, path: &Path) -> Vec<String> {
+        tailer
+            .poll(path)
+            .await
+            .unwrap()
+            .map(|batch| batch.lines)
+            .unwrap_or_default()
+    }
+
+    #[tokio::test]
+    async fn test_reads_complete_lines_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("app.ndjson");
+        append(&path, "{\"id\":1}\n\n{\"id\":2");
+
+        let mut tailer = tailer_for(&dir);
+        assert_eq!(tailer.matching_paths(), vec![path.clone()]);
+        tailer.track(&path, None, false).await.unwrap();
+
+        assert_eq!(poll_lines(&mut tailer, &path).await, vec!["{\"id\":1}"]);
+        assert!(poll_lines(&mut tailer, &path).await.is_empty());
+
+        append(&path, "}\n");
+        assert_eq!(poll_lines(&mut tailer, &path).await, vec!["{\"id\":2}"]);
+    }
+
+    #[tokio::test]
+    async fn test_start_from_end_skips_existing_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("app.ndjson");
+        append(&path, "{\"id\":1}\n");
+
+        let mut tailer = tailer_for(&dir);
+        tailer.track(&path, None, true).await.unwrap();
+        assert!(poll_lines(&mut tailer, &path).await.is_empty());
+
+        append(&path, "{\"id\":2}\n");
+        assert_eq!(poll_lines(&mut tailer, &path).await, vec!["{\"id\":2}"]);
+    }
+
+    #[tokio::test]
+    async fn test_truncation_restarts_from_beginning() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("app.ndjson");
+        append(&path, "{\"id\":1}\n{\"id\":2}\n");
+
+        let mut tailer = tailer_for(&dir);
+        tailer.track(&path, None, false).await.unwrap();
+        assert_eq!(poll_lines(&mut tailer, &path).await.len(), 2);
+
+        std::fs::write(&path, "{\"id\":3}\n").unwrap();
+        assert_eq!(poll_lines(&mut tailer, &path).await, vec!["{\"id\":3}"]);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_rotation_drains_old_file_then_reads_new() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("app.ndjson");
+        append(&path, "{\"id\":1}\n");
+
+        let mut tailer = tailer_for(&dir);
+        tailer.track(&path, None, false).await.unwrap();
+        assert_eq!(poll_lines(&mut tailer, &path).await.len(), 1);
+
+        append(&path, "{\"id\":2}\n");
+        let rotated: PathBuf = dir.path().join("app.ndjson.1");
+        std::fs::rename(&path, &rotated).unwrap();
+        append(&path, "{\"id\":3}\n");
+
+        let batch = tailer.poll(&path).await.unwrap().unwrap();
+        assert_eq!(batch.lines, vec!["{\"id\":2}", "{\"id\":3}"]);
+        assert_eq!(batch.position.map(|p| p.offset), Some(9));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_rotated_file_matching_pattern_is_not_reread() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("app.ndjson");
+        append(&path, "{\"id\":1}\n");
+
+        let mut tailer = tailer_for(&dir);
+        tailer.track(&path, None, false).await.unwrap();
+        assert_eq!(poll_lines(&mut tailer, &path).await.len(), 1);
+
+        let rotated = dir.path().join("app-1.ndjson");
+        std::fs::rename(&path, &rotated).unwrap();
+        let batch = tailer.poll(&path).await.unwrap().unwrap();
+        assert!(batch.position.is_none());
+
+        let position = tailer.track(&rotated, None, false).await.unwrap();
+        assert_eq!(position.offset, 9);
+    }
+
+    #[tokio::test]
+    async fn test_saved_offset_resumes_same_file_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("app.ndjson");
+        append(&path, "{\"id\":1}\n{\"id\":2}\n");
+
+        let mut tailer = tailer_for(&dir);
+        let position = tailer.track(&path, None, false).await.unwrap();
+        let saved = FileOffset {
+            file_id: position.file_id,
+            offset: 9,
+        };
+
+        let mut resumed = tailer_for(&dir);
+        resumed.track(&path, Some(saved), false).await.unwrap();
+        assert_eq!(poll_lines(&mut resumed, &path).await, vec!["{\"id\":2}"]);
+
+        let stale = FileOffset {
+            file_id: position.file_id.wrapping_add(1),
+            offset: 9,
+        };
+        let mut replaced = tailer_for(&dir);
+        let position = replaced.track(&path, Some(stale), true).await.unwrap();
+        assert_eq!(position.offset, 0);
+    }
+}
+
+mod descriptor {
+    use super::*;
+    use crate::descriptor::FileTailSourceDescriptor;
+
+    #[tokio::test]
+    async fn test_descriptor_creates_source() {
+        let descriptor = FileTailSourceDescriptor;
+        assert_eq!(descriptor.kind(), "file-tail");
+
+        let source = descriptor
+            .create_source(
+                "tail-1",
+                &json!({
+                    "paths": ["/logs/*.ndjson"],
+                    "startPosition": "beginning",
+                    "labelField": "type"
+                }),
+                false,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(source.id(), "tail-1");
+        assert!(!source.auto_start());
+        let props = source.properties();
+        assert_eq!(props.get("startPosition"), Some(&json!("beginning")));
+        assert_eq!(props.get("labelField"), Some(&json!("type")));
+    }
+
+    #[tokio::test]
+    async fn test_descriptor_rejects_unknown_fields() {
+        let result = FileTailSourceDescriptor
+            .create_source("tail-1", &json!({"paths": ["a"], "bogus": 1}), true)
+            .await;
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-client bearer token authentication for the gRPC source.
+//!
+//! When client tokens are configured, every call must carry
+//! `authorization: Bearer <token>` metadata matching one of them. The matching
+//! client is attached to the request as an [`AuthenticatedClient`] extension.
+
+use std::sync::Arc;
+use subtle::ConstantTimeEq;
+use tonic::metadata::MetadataMap;
+use tonic::{Request, Status};
+
+use crate::config::GrpcClientToken;
+
+/// Client that sent a request, as identified by its token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthenticatedClient(pub String);
+
+/// Find the client whose token is presented in the request metadata.
+///
+/// Every configured token is compared in constant time so the response time
+/// does not reveal which tokens exist.
+pub(crate) fn authenticate(
+    clients: &[GrpcClientToken],
+    metadata: &MetadataMap,
+) -> Result<AuthenticatedClient, Status> {
+    let header = metadata
+        .get("authorization")
+        .ok_or_else(|| Status::unauthenticated("Missing authorization metadata"))?
+        .to_str()
+        .map_err(|_| Status::unauthenticated("Invalid authorization metadata"))?;
+
+    let token = header
+        .strip_prefix("Bearer ")
+        .or_else(|| header.strip_prefix("bearer "))
+        .ok_or_else(|| Status::unauthenticated("Authorization metadata is not a Bearer token"))?;
+
+    let mut matched = None;
+    for client in clients {
+        let equal: bool = client.token.as_bytes().ct_eq(token.as_bytes()).into();
+        if equal && matched.is_none() {
+            matched = Some(client.client_id.clone());
+        }
+    }
+
+    matched
+        .map(AuthenticatedClient)
+        .ok_or_else(|| Status::unauthenticated("Invalid token"))
+}
+
+/// Interceptor enforcing client tokens. Passes every request when no tokens
+/// are configured.
+#[derive(Clone)]
+pub(crate) struct TokenInterceptor {
+    clients: Arc<Vec<GrpcClientToken>>,
+}
+
+impl TokenInterceptor {
+    pub(crate) fn new(clients: Vec<GrpcClientToken>) -> Self {
+        Self {
+            clients: Arc::new(clients),
+        }
+    }
+}
+
+impl tonic::service::Interceptor for TokenInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        if self.clients.is_empty() {
+            return Ok(request);
+        }
+
+        let client = authenticate(&self.clients, request.metadata())?;
+        request.extensions_mut().insert(client);
+        Ok(request)
+    }
+}
@@ -39,6 +39,23 @@ pub struct GrpcSourceConfig {
     /// Request timeout in milliseconds
     #[serde(default = "default_timeout_ms")]
     pub timeout_ms: u64,
+
+    /// Tokens accepted from clients. When empty, the endpoint is unauthenticated.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub client_tokens: Vec<GrpcClientToken>,
+}
+
+/// Bearer token issued to one client of the gRPC source.
+///
+/// Clients send the token as `authorization: Bearer <token>` metadata. The
+/// client ID identifies the caller in logs and is never sent by the client.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GrpcClientToken {
+    /// Name of the client the token was issued to
+    pub client_id: String,
+
+    /// Token the client must present
+    pub token: String,
 }
 
 fn default_host() -> String {
@@ -56,6 +73,7 @@ impl Default for GrpcSourceConfig {
             port: default_port(),
             endpoint: None,
             timeout_ms: default_timeout_ms(),
+            client_tokens: Vec::new(),
         }
     }
 }
@@ -68,6 +86,8 @@ impl GrpcSourceConfig {
     /// Returns an error if:
     /// - Port is 0 (invalid port)
     /// - Timeout is 0 (would cause immediate timeouts)
+    /// - A client token has an empty client ID or token, or a client ID or
+    ///   token is used more than once
     pub fn validate(&self) -> anyhow::Result<()> {
         if self.port == 0 {
             return Err(anyhow::anyhow!(
@@ -83,6 +103,28 @@ impl GrpcSourceConfig {
             ));
         }
 
+        let mut client_ids = std::collections::HashSet::new();
+        let mut tokens = std::collections::HashSet::new();
+        for client in &self.client_tokens {
+            if client.client_id.is_empty() || client.token.is_empty() {
+                return Err(anyhow::anyhow!(
+                    "Validation error: client tokens require a non-empty client_id and token"
+                ));
+            }
+            if !client_ids.insert(client.client_id.as_str()) {
+                return Err(anyhow::anyhow!(
+                    "Validation error: duplicate client_id '{}' in client_tokens",
+                    client.client_id
+                ));
+            }
+            if !tokens.insert(client.token.as_str()) {
+                return Err(anyhow::anyhow!(
+                    "Validation error: client '{}' reuses a token issued to another client",
+                    client.client_id
+                ));
+            }
+        }
+
         Ok(())
     }
 }
@@ -14,7 +14,7 @@
 
 //! gRPC source plugin descriptor and configuration DTOs.
 
-use crate::{GrpcSourceBuilder, GrpcSourceConfig};
+use crate::{GrpcClientToken, GrpcSourceBuilder, GrpcSourceConfig};
 use drasi_plugin_sdk::prelude::*;
 use utoipa::OpenApi;
 
@@ -31,6 +31,18 @@ pub struct GrpcSourceConfigDto {
     pub endpoint: Option<ConfigValue<String>>,
     #[serde(default = "default_grpc_timeout_ms")]
     pub timeout_ms: ConfigValue<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schema(value_type = Vec<source::grpc::GrpcClientToken>)]
+    pub client_tokens: Vec<GrpcClientTokenDto>,
+}
+
+/// Per-client bearer token DTO.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::grpc::GrpcClientToken)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct GrpcClientTokenDto {
+    pub client_id: String,
+    pub token: ConfigValue<String>,
 }
 
 fn default_grpc_host() -> ConfigValue<String> {
@@ -46,7 +58,7 @@ fn default_grpc_timeout_ms() -> ConfigValue<u64> {
 }
 
 #[derive(OpenApi)]
-#[openapi(components(schemas(GrpcSourceConfigDto)))]
+#[openapi(components(schemas(GrpcSourceConfigDto, GrpcClientTokenDto)))]
 struct GrpcSourceSchemas;
 
 /// Descriptor for the gRPC source plugin.
@@ -91,6 +103,16 @@ impl SourcePluginDescriptor for GrpcSourceDescriptor {
             port: mapper.resolve_typed(&dto.port)?,
             endpoint: mapper.resolve_optional(&dto.endpoint)?,
             timeout_ms: mapper.resolve_typed(&dto.timeout_ms)?,
+            client_tokens: dto
+                .client_tokens
+                .iter()
+                .map(|client| {
+                    Ok(GrpcClientToken {
+                        client_id: client.client_id.clone(),
+                        token: mapper.resolve_string(&client.token)?,
+                    })
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?,
         };
 
         let source = GrpcSourceBuilder::new(id)
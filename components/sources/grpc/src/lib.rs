@@ -67,6 +67,14 @@
 //! | `port` | u16 | `50051` | Port to listen on |
 //! | `endpoint` | string | None | Optional custom endpoint path |
 //! | `timeout_ms` | u64 | `5000` | Request timeout in milliseconds |
+//! | `client_tokens` | list | `[]` | Per-client bearer tokens; empty means unauthenticated |
+//!
+//! # Authentication
+//!
+//! When `client_tokens` is set, every call must send `authorization: Bearer <token>`
+//! metadata matching one of the configured tokens, otherwise it fails with
+//! `UNAUTHENTICATED`. Each token belongs to a named client, which is recorded in
+//! the logs for the events it submits.
 //!
 //! # Example Configuration (YAML)
 //!
@@ -75,6 +83,9 @@
 //! properties:
 //!   host: "0.0.0.0"
 //!   port: 50051
+//!   client_tokens:
+//!     - client_id: billing-service
+//!       token: "${BILLING_TOKEN}"
 //! ```
 //!
 //! # Usage Example
@@ -88,6 +99,7 @@
 //!     port: 50051,
 //!     endpoint: None,
 //!     timeout_ms: 5000,
+//!     client_tokens: vec![],
 //! };
 //!
 //! let source = Arc::new(GrpcSource::new("my-grpc-source", config)?);
@@ -97,15 +109,18 @@
 //! # Client Example (using grpcurl)
 //!
 //! ```bash
-//! grpcurl -plaintext -d '{"event": {...}}' localhost:50051 drasi.v1.SourceService/SubmitEvent
+//! grpcurl -plaintext -H 'authorization: Bearer <token>' -d '{"event": {...}}' \
+//!     localhost:50051 drasi.v1.SourceService/SubmitEvent
 //! ```
 
+mod auth;
 pub mod config;
 pub mod descriptor;
 
 #[cfg(test)]
 mod tests;
-pub use config::GrpcSourceConfig;
+pub use auth::AuthenticatedClient;
+pub use config::{GrpcClientToken, GrpcSourceConfig};
 
 use anyhow::Result;
 use async_trait::async_trait;
@@ -197,6 +212,7 @@ impl GrpcSource {
     ///     port: 50051,
     ///     endpoint: None,
     ///     timeout_ms: 5000,
+    ///     client_tokens: vec![],
     /// };
     ///
     /// let source = GrpcSource::new("my-grpc-source", config)?;
@@ -258,6 +274,8 @@ impl Source for GrpcSource {
                 .as_ref()
                 .map(|e| ConfigValue::Static(e.clone())),
             timeout_ms: ConfigValue::Static(self.config.timeout_ms),
+            // Tokens are credentials and are never exposed through properties
+            client_tokens: Vec::new(),
         };
 
         match serde_json::to_value(&dto) {
@@ -307,7 +325,17 @@ impl Source for GrpcSource {
             dispatchers: self.base.dispatchers.clone(),
         };
 
-        let svc = SourceServiceServer::new(service);
+        let svc = SourceServiceServer::with_interceptor(
+            service,
+            auth::TokenInterceptor::new(self.config.client_tokens.clone()),
+        );
+        if !self.config.client_tokens.is_empty() {
+            info!(
+                "gRPC source '{}' requires bearer tokens from {} client(s)",
+                self.base.id,
+                self.config.client_tokens.len()
+            );
+        }
 
         // Start the gRPC server
         let source_id = self.base.id.clone();
@@ -408,6 +436,7 @@ impl SourceService for GrpcSourceService {
         &self,
         request: Request<SubmitEventRequest>,
     ) -> Result<Response<SubmitEventResponse>, Status> {
+        let client = client_name(&request);
         let event_request = request.into_inner();
 
         if let Some(proto_change) = event_request.event {
@@ -424,7 +453,10 @@ impl SourceService for GrpcSourceService {
                         profiling,
                     );
 
-                    debug!("[{}] Processing gRPC event: {:?}", self.source_id, &wrapper);
+                    debug!(
+                        "[{}] Processing gRPC event from {}: {:?}",
+                        self.source_id, client, &wrapper
+                    );
 
                     // Dispatch via helper
                     if let Err(e) = SourceBase::dispatch_from_task(
@@ -449,7 +481,10 @@ impl SourceService for GrpcSourceService {
                     }))
                 }
                 Err(e) => {
-                    error!("[{}] Invalid event data: {}", self.source_id, e);
+                    error!(
+                        "[{}] Invalid event data from {}: {}",
+                        self.source_id, client, e
+                    );
                     Ok(Response::new(SubmitEventResponse {
                         success: false,
                         message: "Invalid event data".to_string(),
@@ -475,6 +510,7 @@ impl SourceService for GrpcSourceService {
         &self,
         request: Request<tonic::Streaming<ProtoSourceChange>>,
     ) -> Result<Response<Self::StreamEventsStream>, Status> {
+        let client = client_name(&request);
         let mut stream = request.into_inner();
         let source_id = self.source_id.clone();
         let instance_id = self.instance_id.clone();
@@ -533,7 +569,7 @@ impl SourceService for GrpcSourceService {
                             }
                         }
                         Err(e) => {
-                            error!("[{source_id}] Invalid event data: {e}");
+                            error!("[{source_id}] Invalid event data from {client}: {e}");
                             let _ = tx
                                 .send(Ok(StreamEventResponse {
                                     success: false,
@@ -546,6 +582,10 @@ impl SourceService for GrpcSourceService {
                     }
                 }
 
+                debug!(
+                    "[{source_id}] Stream from {client} completed after {events_processed} events"
+                );
+
                 // Send final response
                 let _ = tx
                     .send(Ok(StreamEventResponse {
@@ -612,6 +652,15 @@ impl SourceService for GrpcSourceService {
     }
 }
 
+/// Name of the authenticated client for log messages.
+fn client_name<T>(request: &Request<T>) -> String {
+    request
+        .extensions()
+        .get::<AuthenticatedClient>()
+        .map(|client| client.0.clone())
+        .unwrap_or_else(|| "anonymous client".to_string())
+}
+
 /// Convert protobuf SourceChange to Drasi Core SourceChange.
 ///
 /// # Arguments
@@ -850,6 +899,7 @@ pub struct GrpcSourceBuilder {
     port: u16,
     endpoint: Option<String>,
     timeout_ms: u64,
+    client_tokens: Vec<GrpcClientToken>,
     dispatch_mode: Option<DispatchMode>,
     dispatch_buffer_capacity: Option<usize>,
     bootstrap_provider: Option<Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>>,
@@ -865,6 +915,7 @@ impl GrpcSourceBuilder {
             port: 50051,
             endpoint: None,
             timeout_ms: 5000,
+            client_tokens: Vec::new(),
             dispatch_mode: None,
             dispatch_buffer_capacity: None,
             bootstrap_provider: None,
@@ -896,6 +947,21 @@ impl GrpcSourceBuilder {
         self
     }
 
+    /// Accept a bearer token from a named client.
+    ///
+    /// Once any token is added, calls without a valid token are rejected.
+    pub fn with_client_token(
+        mut self,
+        client_id: impl Into<String>,
+        token: impl Into<String>,
+    ) -> Self {
+        self.client_tokens.push(GrpcClientToken {
+            client_id: client_id.into(),
+            token: token.into(),
+        });
+        self
+    }
+
     /// Set the dispatch mode for this source
     pub fn with_dispatch_mode(mut self, mode: DispatchMode) -> Self {
         self.dispatch_mode = Some(mode);
@@ -932,6 +998,7 @@ impl GrpcSourceBuilder {
         self.port = config.port;
         self.endpoint = config.endpoint;
         self.timeout_ms = config.timeout_ms;
+        self.client_tokens = config.client_tokens;
         self
     }
 
@@ -939,14 +1006,17 @@ impl GrpcSourceBuilder {
     ///
     /// # Errors
     ///
-    /// Returns an error if the source cannot be constructed.
+    /// Returns an error if the configuration is invalid or the source cannot
+    /// be constructed.
     pub fn build(self) -> Result<GrpcSource> {
         let config = GrpcSourceConfig {
             host: self.host,
             port: self.port,
             endpoint: self.endpoint,
             timeout_ms: self.timeout_ms,
+            client_tokens: self.client_tokens,
         };
+        config.validate()?;
 
         let mut params = SourceBaseParams::new(&self.id).with_auto_start(self.auto_start);
         if let Some(mode) = self.dispatch_mode {
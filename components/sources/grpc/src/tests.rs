@@ -33,6 +33,7 @@ mod construction {
             port: 50052,
             endpoint: Some("/custom".to_string()),
             timeout_ms: 10000,
+            client_tokens: vec![],
         };
         let source = GrpcSource::new("custom-source", config).unwrap();
         assert_eq!(source.id(), "custom-source");
@@ -76,6 +77,7 @@ mod properties {
             port: 9000,
             endpoint: None,
             timeout_ms: 5000,
+            client_tokens: vec![],
         };
         let source = GrpcSource::new("test", config).unwrap();
         let props = source.properties();
@@ -97,6 +99,7 @@ mod properties {
             port: 50051,
             endpoint: Some("/api/v1".to_string()),
             timeout_ms: 5000,
+            client_tokens: vec![],
         };
         let source = GrpcSource::new("test", config).unwrap();
         let props = source.properties();
@@ -114,6 +117,7 @@ mod properties {
             port: 50051,
             endpoint: None,
             timeout_ms: 5000,
+            client_tokens: vec![],
         };
         let source = GrpcSource::new("test", config).unwrap();
         let props = source.properties();
@@ -205,6 +209,7 @@ mod config {
             port: 50051,
             endpoint: Some("/api".to_string()),
             timeout_ms: 10000,
+            client_tokens: vec![],
         };
 
         let json = serde_json::to_string(&config).unwrap();
@@ -404,3 +409,95 @@ mod proto_conversion {
         );
     }
 }
+
+mod authentication {
+    use super::*;
+    use crate::auth::authenticate;
+    use tonic::metadata::MetadataMap;
+
+    fn clients() -> Vec<GrpcClientToken> {
+        vec![
+            GrpcClientToken {
+                client_id: "billing".to_string(),
+                token: "billing-secret".to_string(),
+            },
+            GrpcClientToken {
+                client_id: "inventory".to_string(),
+                token: "inventory-secret".to_string(),
+            },
+        ]
+    }
+
+    fn metadata(authorization: &str) -> MetadataMap {
+        let mut metadata = MetadataMap::new();
+        metadata.insert("authorization", authorization.parse().unwrap());
+        metadata
+    }
+
+    #[test]
+    fn test_authenticate_identifies_client() {
+        let client = authenticate(&clients(), &metadata("Bearer inventory-secret")).unwrap();
+        assert_eq!(client, AuthenticatedClient("inventory".to_string()));
+    }
+
+    #[test]
+    fn test_authenticate_rejects_missing_or_wrong_token() {
+        let status = authenticate(&clients(), &MetadataMap::new()).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+
+        let status = authenticate(&clients(), &metadata("Bearer nope")).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+
+        let status = authenticate(&clients(), &metadata("Basic billing-secret")).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+    }
+
+    #[test]
+    fn test_validate_rejects_duplicate_clients_and_tokens() {
+        let mut config = GrpcSourceConfig {
+            client_tokens: clients(),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+
+        config.client_tokens[1].token = "billing-secret".to_string();
+        assert!(config.validate().is_err());
+
+        config.client_tokens[1].token = "other".to_string();
+        config.client_tokens[1].client_id = "billing".to_string();
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_builder_validates_tokens_and_hides_them() {
+        assert!(GrpcSource::builder("test")
+            .with_client_token("billing", "")
+            .build()
+            .is_err());
+
+        let source = GrpcSource::builder("test")
+            .with_client_token("billing", "billing-secret")
+            .build()
+            .unwrap();
+        assert!(!source.properties().contains_key("clientTokens"));
+    }
+
+    #[tokio::test]
+    async fn test_descriptor_resolves_client_tokens() {
+        use crate::descriptor::GrpcSourceDescriptor;
+        use drasi_plugin_sdk::prelude::SourcePluginDescriptor;
+
+        let source = GrpcSourceDescriptor
+            .create_source(
+                "grpc-1",
+                &serde_json::json!({
+                    "clientTokens": [{"clientId": "billing", "token": "billing-secret"}]
+                }),
+                false,
+            )
+            .await
+            .unwrap();
+        let source = source.as_any().downcast_ref::<GrpcSource>().unwrap();
+        assert_eq!(source.config.client_tokens, clients()[..1].to_vec());
+    }
+}
@@ -0,0 +1,177 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration types for the HTTP polling source plugin.
+//!
+//! This module defines which endpoint the source polls, how items are found
+//! in the response, and how each item is identified.
+
+use anyhow::{anyhow, Result};
+use jsonpath_rust::JsonPathInst;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+fn default_items_path() -> String {
+    "$".to_string()
+}
+
+fn default_id_path() -> String {
+    "$.id".to_string()
+}
+
+fn default_poll_interval_ms() -> u64 {
+    5000
+}
+
+fn default_timeout_ms() -> u64 {
+    10000
+}
+
+fn default_emit_initial() -> bool {
+    true
+}
+
+/// HTTP polling source configuration.
+///
+/// # Example
+///
+/// ```rust
+/// use drasi_source_http_poll::HttpPollSourceConfig;
+///
+/// let config = HttpPollSourceConfig {
+///     url: "https://api.example.com/v1/tickets?status=open".to_string(),
+///     items_path: "$.data[*]".to_string(),
+///     id_path: "$.ticket_id".to_string(),
+///     label: Some("Ticket".to_string()),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HttpPollSourceConfig {
+    /// URL requested with `GET` on every poll. Must use `http` or `https`.
+    pub url: String,
+
+    /// Extra request headers, such as `Authorization`.
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+
+    /// JSONPath selecting the items in the response body. Arrays among the
+    /// matches are expanded, so `$.data` and `$.data[*]` select the same items.
+    ///
+    /// **Default**: `"$"` (the body is the collection)
+    #[serde(default = "default_items_path")]
+    pub items_path: String,
+
+    /// JSONPath evaluated against each item to get its ID. The first match is
+    /// used; strings are taken as-is and other values in their JSON form.
+    ///
+    /// **Default**: `"$.id"`
+    #[serde(default = "default_id_path")]
+    pub id_path: String,
+
+    /// Label for item nodes.
+    ///
+    /// **Default**: `"HttpItem"`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+
+    /// Milliseconds between polls.
+    ///
+    /// **Default**: `5000`
+    #[serde(default = "default_poll_interval_ms")]
+    pub poll_interval_ms: u64,
+
+    /// Request timeout in milliseconds.
+    ///
+    /// **Default**: `10000`
+    #[serde(default = "default_timeout_ms")]
+    pub timeout_ms: u64,
+
+    /// Emit every item of the first poll as an insert. When `false`, the first
+    /// poll only records the baseline and later polls report changes to it.
+    ///
+    /// **Default**: `true`
+    #[serde(default = "default_emit_initial")]
+    pub emit_initial: bool,
+}
+
+impl Default for HttpPollSourceConfig {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            headers: HashMap::new(),
+            items_path: default_items_path(),
+            id_path: default_id_path(),
+            label: None,
+            poll_interval_ms: default_poll_interval_ms(),
+            timeout_ms: default_timeout_ms(),
+            emit_initial: default_emit_initial(),
+        }
+    }
+}
+
+impl HttpPollSourceConfig {
+    /// Label for item nodes.
+    pub fn label(&self) -> &str {
+        self.label.as_deref().unwrap_or("HttpItem")
+    }
+
+    /// Validate the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `url` is empty or does not use `http` or `https`
+    /// - a header name is empty
+    /// - `items_path` or `id_path` is not a valid JSONPath expression
+    /// - `poll_interval_ms` or `timeout_ms` is zero
+    pub fn validate(&self) -> Result<()> {
+        if self.url.is_empty() {
+            return Err(anyhow!("Validation error: url cannot be empty"));
+        }
+        if !(self.url.starts_with("http://") || self.url.starts_with("https://")) {
+            return Err(anyhow!(
+                "Validation error: url '{}' must use http or https",
+                self.url
+            ));
+        }
+
+        if self.headers.keys().any(String::is_empty) {
+            return Err(anyhow!("Validation error: header names cannot be empty"));
+        }
+
+        for (name, path) in [("items_path", &self.items_path), ("id_path", &self.id_path)] {
+            if path.is_empty() {
+                return Err(anyhow!("Validation error: {name} cannot be empty"));
+            }
+            JsonPathInst::from_str(path).map_err(|e| {
+                anyhow!("Validation error: {name} '{path}' is not a valid JSONPath: {e}")
+            })?;
+        }
+
+        if self.poll_interval_ms == 0 {
+            return Err(anyhow!(
+                "Validation error: poll_interval_ms must be greater than 0"
+            ));
+        }
+        if self.timeout_ms == 0 {
+            return Err(anyhow!(
+                "Validation error: timeout_ms must be greater than 0"
+            ));
+        }
+
+        Ok(())
+    }
+}
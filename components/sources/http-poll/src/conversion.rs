@@ -0,0 +1,54 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion of item changes into Drasi source changes.
+
+use drasi_core::models::{Element, ElementMetadata, ElementReference, SourceChange};
+use drasi_lib::sources::convert_json_to_element_properties;
+use std::sync::Arc;
+
+use crate::diff::ItemChange;
+
+fn metadata(source_id: &str, label: &str, key: &str) -> ElementMetadata {
+    ElementMetadata {
+        reference: ElementReference::new(source_id, key),
+        labels: Arc::from(vec![Arc::from(label)]),
+        effective_from: chrono::Utc::now().timestamp_millis() as u64,
+    }
+}
+
+/// Convert an item change into a [`SourceChange`] of a node labelled `label`.
+pub(crate) fn item_change_to_source_change(
+    source_id: &str,
+    label: &str,
+    change: &ItemChange,
+) -> SourceChange {
+    match change {
+        ItemChange::Insert(item) => SourceChange::Insert {
+            element: Element::Node {
+                metadata: metadata(source_id, label, &item.key),
+                properties: convert_json_to_element_properties(&item.properties),
+            },
+        },
+        ItemChange::Update(item) => SourceChange::Update {
+            element: Element::Node {
+                metadata: metadata(source_id, label, &item.key),
+                properties: convert_json_to_element_properties(&item.properties),
+            },
+        },
+        ItemChange::Delete(key) => SourceChange::Delete {
+            metadata: metadata(source_id, label, key),
+        },
+    }
+}
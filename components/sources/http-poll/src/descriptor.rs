@@ -0,0 +1,129 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! HTTP polling source plugin descriptor and configuration DTOs.
+
+use crate::{HttpPollSourceBuilder, HttpPollSourceConfig};
+use drasi_plugin_sdk::prelude::*;
+use std::collections::HashMap;
+use utoipa::OpenApi;
+
+/// HTTP polling source configuration DTO.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::http_poll::HttpPollSourceConfig)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct HttpPollSourceConfigDto {
+    pub url: ConfigValue<String>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub headers: HashMap<String, ConfigValue<String>>,
+    #[serde(default = "default_items_path")]
+    pub items_path: String,
+    #[serde(default = "default_id_path")]
+    pub id_path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(default = "default_poll_interval_ms")]
+    pub poll_interval_ms: ConfigValue<u64>,
+    #[serde(default = "default_timeout_ms")]
+    pub timeout_ms: ConfigValue<u64>,
+    #[serde(default = "default_emit_initial")]
+    pub emit_initial: ConfigValue<bool>,
+}
+
+fn default_items_path() -> String {
+    "$".to_string()
+}
+
+fn default_id_path() -> String {
+    "$.id".to_string()
+}
+
+fn default_poll_interval_ms() -> ConfigValue<u64> {
+    ConfigValue::Static(5000)
+}
+
+fn default_timeout_ms() -> ConfigValue<u64> {
+    ConfigValue::Static(10000)
+}
+
+fn default_emit_initial() -> ConfigValue<bool> {
+    ConfigValue::Static(true)
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(HttpPollSourceConfigDto)))]
+struct HttpPollSourceSchemas;
+
+/// Descriptor for the HTTP polling source plugin.
+pub struct HttpPollSourceDescriptor;
+
+#[async_trait]
+impl SourcePluginDescriptor for HttpPollSourceDescriptor {
+    fn kind(&self) -> &str {
+        "http-poll"
+    }
+
+    fn config_version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn config_schema_name(&self) -> &str {
+        "source.http_poll.HttpPollSourceConfig"
+    }
+
+    fn config_schema_json(&self) -> String {
+        let api = HttpPollSourceSchemas::openapi();
+        serde_json::to_string(
+            &api.components
+                .as_ref()
+                .expect("OpenAPI components missing")
+                .schemas,
+        )
+        .expect("Failed to serialize config schema")
+    }
+
+    async fn create_source(
+        &self,
+        id: &str,
+        config_json: &serde_json::Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn drasi_lib::sources::Source>> {
+        let dto: HttpPollSourceConfigDto = serde_json::from_value(config_json.clone())?;
+        let mapper = DtoMapper::new();
+
+        let headers = dto
+            .headers
+            .iter()
+            .map(|(name, value)| Ok((name.clone(), mapper.resolve_string(value)?)))
+            .collect::<anyhow::Result<HashMap<_, _>>>()?;
+
+        let config = HttpPollSourceConfig {
+            url: mapper.resolve_string(&dto.url)?,
+            headers,
+            items_path: dto.items_path,
+            id_path: dto.id_path,
+            label: dto.label,
+            poll_interval_ms: mapper.resolve_typed(&dto.poll_interval_ms)?,
+            timeout_ms: mapper.resolve_typed(&dto.timeout_ms)?,
+            emit_initial: mapper.resolve_typed(&dto.emit_initial)?,
+        };
+
+        let source = HttpPollSourceBuilder::new(id)
+            .with_config(config)
+            .with_auto_start(auto_start)
+            .build()?;
+
+        Ok(Box::new(source))
+    }
+}
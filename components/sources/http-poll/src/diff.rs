@@ -0,0 +1,67 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Snapshot diffing for polled collections.
+
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+use crate::extract::PolledItem;
+
+/// Item objects from the last poll, keyed by item ID.
+pub(crate) type Snapshot = HashMap<String, Map<String, Value>>;
+
+/// Difference of one item between two polls.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum ItemChange {
+    Insert(PolledItem),
+    Update(PolledItem),
+    Delete(String),
+}
+
+/// Compare the items of the current poll against the previous snapshot.
+///
+/// Without a previous snapshot every item is an insert. Inserts and updates
+/// keep the order of `items`; deletes follow, sorted by key. Returns the
+/// changes and the snapshot to compare the next poll against.
+pub(crate) fn diff(
+    previous: Option<&Snapshot>,
+    items: Vec<PolledItem>,
+) -> (Vec<ItemChange>, Snapshot) {
+    let mut snapshot = Snapshot::with_capacity(items.len());
+    let mut changes = Vec::new();
+
+    for item in items {
+        snapshot.insert(item.key.clone(), item.properties.clone());
+        match previous.and_then(|p| p.get(&item.key)) {
+            None => changes.push(ItemChange::Insert(item)),
+            Some(properties) if *properties != item.properties => {
+                changes.push(ItemChange::Update(item))
+            }
+            Some(_) => {}
+        }
+    }
+
+    if let Some(previous) = previous {
+        let mut deleted: Vec<String> = previous
+            .keys()
+            .filter(|key| !snapshot.contains_key(*key))
+            .cloned()
+            .collect();
+        deleted.sort_unstable();
+        changes.extend(deleted.into_iter().map(ItemChange::Delete));
+    }
+
+    (changes, snapshot)
+}
@@ -0,0 +1,122 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Extraction of keyed items from polled response bodies.
+
+use anyhow::{anyhow, Result};
+use jsonpath_rust::{path::config::JsonPathConfig, JsonPathInst};
+use serde_json::{Map, Value};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use crate::config::HttpPollSourceConfig;
+
+/// An item of the polled collection.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PolledItem {
+    /// Item ID from the ID expression.
+    pub key: String,
+    /// The item object.
+    pub properties: Map<String, Value>,
+}
+
+/// Items found in one response body.
+#[derive(Debug, Default)]
+pub(crate) struct Extraction {
+    /// Items in response order.
+    pub items: Vec<PolledItem>,
+    /// Why items were left out, one entry per skipped item.
+    pub skipped: Vec<String>,
+}
+
+/// Compiled items and ID expressions of a source.
+pub(crate) struct ItemExtractor {
+    items_path: JsonPathInst,
+    id_path: JsonPathInst,
+}
+
+impl ItemExtractor {
+    /// Compile the expressions of `config`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an expression is not valid JSONPath.
+    pub(crate) fn new(config: &HttpPollSourceConfig) -> Result<Self> {
+        let compile = |path: &str| {
+            JsonPathInst::from_str(path).map_err(|e| anyhow!("Invalid JSONPath '{path}': {e}"))
+        };
+        Ok(Self {
+            items_path: compile(&config.items_path)?,
+            id_path: compile(&config.id_path)?,
+        })
+    }
+
+    /// Find the items of `body`.
+    ///
+    /// Items that are not objects, have no ID, or repeat an earlier ID are
+    /// skipped and reported in [`Extraction::skipped`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the items expression matches nothing, so that an
+    /// unexpected response is not mistaken for an empty collection.
+    pub(crate) fn extract(&self, body: &Value) -> Result<Extraction> {
+        let matches = self.items_path.find_slice(body, JsonPathConfig::default());
+        if matches.is_empty() {
+            return Err(anyhow!("Items path matched nothing in the response"));
+        }
+
+        let mut extraction = Extraction::default();
+        let mut seen = HashSet::new();
+        for value in matches.iter().flat_map(|m| match &**m {
+            Value::Array(values) => values.as_slice(),
+            value => std::slice::from_ref(value),
+        }) {
+            let Value::Object(object) = value else {
+                extraction
+                    .skipped
+                    .push(format!("item is not a JSON object: {value}"));
+                continue;
+            };
+
+            let key = match self
+                .id_path
+                .find_slice(value, JsonPathConfig::default())
+                .first()
+                .map(|id| (**id).clone())
+            {
+                Some(Value::String(id)) if !id.is_empty() => id,
+                Some(Value::Null) | Some(Value::String(_)) | None => {
+                    extraction.skipped.push(format!("item has no ID: {value}"));
+                    continue;
+                }
+                Some(id) => id.to_string(),
+            };
+
+            if !seen.insert(key.clone()) {
+                extraction
+                    .skipped
+                    .push(format!("duplicate item ID '{key}'"));
+                continue;
+            }
+
+            extraction.items.push(PolledItem {
+                key,
+                properties: object.clone(),
+            });
+        }
+
+        Ok(extraction)
+    }
+}
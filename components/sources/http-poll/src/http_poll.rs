@@ -0,0 +1,485 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! HTTP polling source implementation and builder.
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use log::{debug, error, info, warn};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, ACCEPT};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+use drasi_core::models::SourceChange;
+use drasi_lib::channels::*;
+use drasi_lib::component_graph::ComponentStatusHandle;
+use drasi_lib::managers::{log_component_start, log_component_stop};
+use drasi_lib::sources::base::{SourceBase, SourceBaseParams};
+use drasi_lib::Source;
+use tracing::Instrument;
+
+use crate::config::HttpPollSourceConfig;
+use crate::conversion::item_change_to_source_change;
+use crate::diff::{diff, ItemChange, Snapshot};
+use crate::extract::ItemExtractor;
+
+type Dispatchers = Arc<RwLock<Vec<Box<dyn ChangeDispatcher<SourceEventWrapper> + Send + Sync>>>>;
+
+/// Source that polls a JSON endpoint and emits the items that changed.
+///
+/// Each poll requests the configured URL, selects the items of the response
+/// with a JSONPath expression and compares them, by ID, against the items of
+/// the previous poll. New IDs become inserts, items whose content changed
+/// become updates, and IDs that disappeared become deletes.
+pub struct HttpPollSource {
+    /// Base source implementation providing dispatchers, status tracking, and lifecycle management.
+    base: SourceBase,
+    /// Polling configuration.
+    config: HttpPollSourceConfig,
+}
+
+impl HttpPollSource {
+    /// Create a builder for an HTTP polling source.
+    pub fn builder(id: impl Into<String>) -> HttpPollSourceBuilder {
+        HttpPollSourceBuilder::new(id)
+    }
+
+    /// Create a new HTTP polling source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid.
+    pub fn new(id: impl Into<String>, config: HttpPollSourceConfig) -> Result<Self> {
+        HttpPollSourceBuilder::new(id).with_config(config).build()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn test_subscribe(&self) -> Box<dyn ChangeReceiver<SourceEventWrapper>> {
+        self.base.test_subscribe()
+    }
+
+    /// Request headers sent on every poll.
+    pub(crate) fn header_map(config: &HttpPollSourceConfig) -> Result<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
+        for (name, value) in &config.headers {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|e| anyhow!("Invalid header name '{name}': {e}"))?;
+            let value = HeaderValue::from_str(value)
+                .map_err(|e| anyhow!("Invalid value of header '{name}': {e}"))?;
+            headers.insert(name, value);
+        }
+        Ok(headers)
+    }
+
+    async fn dispatch(
+        source_id: &str,
+        dispatchers: &Dispatchers,
+        change: SourceChange,
+    ) -> Result<()> {
+        let mut profiling = drasi_lib::profiling::ProfilingMetadata::new();
+        profiling.source_send_ns = Some(drasi_lib::profiling::timestamp_ns());
+
+        let wrapper = SourceEventWrapper::with_profiling(
+            source_id.to_string(),
+            SourceEvent::Change(change),
+            chrono::Utc::now(),
+            profiling,
+        );
+
+        SourceBase::dispatch_from_task(dispatchers.clone(), wrapper, source_id).await
+    }
+
+    /// Dispatch the changes of one poll.
+    ///
+    /// Returns `false` if a dispatch failed, in which case the caller keeps the
+    /// previous snapshot so the remaining changes are found again next poll.
+    async fn emit(
+        source_id: &str,
+        dispatchers: &Dispatchers,
+        label: &str,
+        changes: &[ItemChange],
+    ) -> bool {
+        for change in changes {
+            let source_change = item_change_to_source_change(source_id, label, change);
+            if let Err(e) = Self::dispatch(source_id, dispatchers, source_change).await {
+                warn!("[{source_id}] Failed to dispatch change: {e}");
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Request the endpoint and parse the response body as JSON.
+    async fn fetch(client: &reqwest::Client, url: &str) -> Result<serde_json::Value> {
+        let response = client.get(url).send().await?.error_for_status()?;
+        Ok(response.json().await?)
+    }
+
+    async fn run(
+        source_id: String,
+        config: HttpPollSourceConfig,
+        dispatchers: Dispatchers,
+        status_handle: ComponentStatusHandle,
+    ) {
+        let setup = Self::header_map(&config).and_then(|headers| {
+            let client = reqwest::Client::builder()
+                .default_headers(headers)
+                .timeout(Duration::from_millis(config.timeout_ms))
+                .build()?;
+            Ok((client, ItemExtractor::new(&config)?))
+        });
+        let (client, extractor) = match setup {
+            Ok(client) => client,
+            Err(e) => {
+                error!("[{source_id}] Failed to create HTTP client: {e}");
+                status_handle
+                    .set_status(
+                        ComponentStatus::Error,
+                        Some(format!("Failed to create HTTP client: {e}")),
+                    )
+                    .await;
+                return;
+            }
+        };
+
+        info!(
+            "[{source_id}] Polling '{}' every {}ms",
+            config.url, config.poll_interval_ms
+        );
+        status_handle
+            .set_status(
+                ComponentStatus::Running,
+                Some("HTTP polling source running".to_string()),
+            )
+            .await;
+
+        let label = config.label();
+        let mut snapshot: Option<Snapshot> = None;
+        let poll_interval = Duration::from_millis(config.poll_interval_ms);
+
+        loop {
+            let extraction = Self::fetch(&client, &config.url)
+                .await
+                .and_then(|body| extractor.extract(&body));
+
+            match extraction {
+                Ok(extraction) => {
+                    for reason in &extraction.skipped {
+                        warn!("[{source_id}] Skipping {reason}");
+                    }
+
+                    let first_poll = snapshot.is_none();
+                    let (changes, next) = diff(snapshot.as_ref(), extraction.items);
+                    if first_poll && !config.emit_initial {
+                        debug!("[{source_id}] Recorded baseline of {} item(s)", next.len());
+                        snapshot = Some(next);
+                    } else {
+                        if !changes.is_empty() {
+                            debug!("[{source_id}] {} change(s) since last poll", changes.len());
+                        }
+                        if Self::emit(&source_id, &dispatchers, label, &changes).await {
+                            snapshot = Some(next);
+                        }
+                    }
+                }
+                Err(e) => warn!("[{source_id}] Failed to poll '{}': {e}", config.url),
+            }
+
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+}
+
+#[async_trait]
+impl Source for HttpPollSource {
+    fn id(&self) -> &str {
+        &self.base.id
+    }
+
+    fn type_name(&self) -> &str {
+        "http-poll"
+    }
+
+    fn properties(&self) -> HashMap<String, serde_json::Value> {
+        use crate::descriptor::HttpPollSourceConfigDto;
+        use drasi_plugin_sdk::ConfigValue;
+
+        let dto = HttpPollSourceConfigDto {
+            url: ConfigValue::Static(self.config.url.clone()),
+            headers: self
+                .config
+                .headers
+                .keys()
+                .map(|name| (name.clone(), ConfigValue::Static("***".to_string())))
+                .collect(),
+            items_path: self.config.items_path.clone(),
+            id_path: self.config.id_path.clone(),
+            label: self.config.label.clone(),
+            poll_interval_ms: ConfigValue::Static(self.config.poll_interval_ms),
+            timeout_ms: ConfigValue::Static(self.config.timeout_ms),
+            emit_initial: ConfigValue::Static(self.config.emit_initial),
+        };
+
+        match serde_json::to_value(&dto) {
+            Ok(serde_json::Value::Object(map)) => map.into_iter().collect(),
+            _ => HashMap::new(),
+        }
+    }
+
+    fn auto_start(&self) -> bool {
+        self.base.get_auto_start()
+    }
+
+    async fn start(&self) -> Result<()> {
+        log_component_start("HTTP Poll Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Starting,
+                Some("Starting HTTP polling source".to_string()),
+            )
+            .await;
+
+        let instance_id = self
+            .base
+            .context()
+            .await
+            .map(|c| c.instance_id)
+            .unwrap_or_default();
+
+        let span = tracing::info_span!(
+            "http_poll_source_poller",
+            instance_id = %instance_id,
+            component_id = %self.base.id,
+            component_type = "source"
+        );
+        let task = tokio::spawn(
+            Self::run(
+                self.base.id.clone(),
+                self.config.clone(),
+                self.base.dispatchers.clone(),
+                self.base.status_handle(),
+            )
+            .instrument(span),
+        );
+
+        *self.base.task_handle.write().await = Some(task);
+
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        log_component_stop("HTTP Poll Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopping,
+                Some("Stopping HTTP polling source".to_string()),
+            )
+            .await;
+
+        // The snapshot lives in the task, so a restart diffs from scratch
+        if let Some(handle) = self.base.task_handle.write().await.take() {
+            handle.abort();
+            let _ = handle.await;
+        }
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopped,
+                Some("HTTP polling source stopped".to_string()),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn status(&self) -> ComponentStatus {
+        self.base.get_status().await
+    }
+
+    async fn subscribe(
+        &self,
+        settings: drasi_lib::config::SourceSubscriptionSettings,
+    ) -> Result<SubscriptionResponse> {
+        self.base
+            .subscribe_with_bootstrap(&settings, "HTTP Poll")
+            .await
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    async fn initialize(&self, context: drasi_lib::context::SourceRuntimeContext) {
+        self.base.initialize(context).await;
+    }
+
+    async fn set_bootstrap_provider(
+        &self,
+        provider: Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>,
+    ) {
+        self.base.set_bootstrap_provider(provider).await;
+    }
+}
+
+/// Builder for [`HttpPollSource`] instances.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use drasi_source_http_poll::HttpPollSource;
+///
+/// let source = HttpPollSource::builder("tickets")
+///     .with_url("https://api.example.com/v1/tickets?status=open")
+///     .with_header("Authorization", "Bearer <token>")
+///     .with_items_path("$.data[*]")
+///     .with_id_path("$.ticket_id")
+///     .with_label("Ticket")
+///     .with_poll_interval_ms(30000)
+///     .build()?;
+/// ```
+pub struct HttpPollSourceBuilder {
+    id: String,
+    config: HttpPollSourceConfig,
+    dispatch_mode: Option<DispatchMode>,
+    dispatch_buffer_capacity: Option<usize>,
+    bootstrap_provider: Option<Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>>,
+    auto_start: bool,
+}
+
+impl HttpPollSourceBuilder {
+    /// Create a new builder with the given ID and default values.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            config: HttpPollSourceConfig::default(),
+            dispatch_mode: None,
+            dispatch_buffer_capacity: None,
+            bootstrap_provider: None,
+            auto_start: true,
+        }
+    }
+
+    /// Set the URL to poll.
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.config.url = url.into();
+        self
+    }
+
+    /// Add a request header.
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.config.headers.insert(name.into(), value.into());
+        self
+    }
+
+    /// Set the JSONPath selecting the items in the response body.
+    pub fn with_items_path(mut self, path: impl Into<String>) -> Self {
+        self.config.items_path = path.into();
+        self
+    }
+
+    /// Set the JSONPath selecting the ID of an item.
+    pub fn with_id_path(mut self, path: impl Into<String>) -> Self {
+        self.config.id_path = path.into();
+        self
+    }
+
+    /// Set the label for item nodes.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.config.label = Some(label.into());
+        self
+    }
+
+    /// Set the interval between polls in milliseconds.
+    pub fn with_poll_interval_ms(mut self, interval_ms: u64) -> Self {
+        self.config.poll_interval_ms = interval_ms;
+        self
+    }
+
+    /// Set the request timeout in milliseconds.
+    pub fn with_timeout_ms(mut self, timeout_ms: u64) -> Self {
+        self.config.timeout_ms = timeout_ms;
+        self
+    }
+
+    /// Set whether the items of the first poll are emitted as inserts.
+    pub fn with_emit_initial(mut self, emit_initial: bool) -> Self {
+        self.config.emit_initial = emit_initial;
+        self
+    }
+
+    /// Set the dispatch mode for this source.
+    pub fn with_dispatch_mode(mut self, mode: DispatchMode) -> Self {
+        self.dispatch_mode = Some(mode);
+        self
+    }
+
+    /// Set the dispatch buffer capacity.
+    pub fn with_dispatch_buffer_capacity(mut self, capacity: usize) -> Self {
+        self.dispatch_buffer_capacity = Some(capacity);
+        self
+    }
+
+    /// Set the bootstrap provider for initial data delivery.
+    pub fn with_bootstrap_provider(
+        mut self,
+        provider: impl drasi_lib::bootstrap::BootstrapProvider + 'static,
+    ) -> Self {
+        self.bootstrap_provider = Some(Box::new(provider));
+        self
+    }
+
+    /// Set whether this source should auto-start when DrasiLib starts.
+    pub fn with_auto_start(mut self, auto_start: bool) -> Self {
+        self.auto_start = auto_start;
+        self
+    }
+
+    /// Set the full configuration at once.
+    pub fn with_config(mut self, config: HttpPollSourceConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Build the HTTP polling source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid or the base source
+    /// cannot be initialized.
+    pub fn build(self) -> Result<HttpPollSource> {
+        self.config.validate()?;
+        HttpPollSource::header_map(&self.config).map_err(|e| anyhow!("Validation error: {e}"))?;
+
+        let mut params = SourceBaseParams::new(&self.id).with_auto_start(self.auto_start);
+        if let Some(mode) = self.dispatch_mode {
+            params = params.with_dispatch_mode(mode);
+        }
+        if let Some(capacity) = self.dispatch_buffer_capacity {
+            params = params.with_dispatch_buffer_capacity(capacity);
+        }
+        if let Some(provider) = self.bootstrap_provider {
+            params = params.with_bootstrap_provider(provider);
+        }
+
+        Ok(HttpPollSource {
+            base: SourceBase::new(params)?,
+            config: self.config,
+        })
+    }
+}
@@ -0,0 +1,79 @@
+#![allow(unexpected_cfgs)]
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! HTTP Polling Source Plugin for drasi-lib.
+//!
+//! This plugin turns any JSON REST endpoint into a Drasi source. It requests
+//! the endpoint on an interval, selects a collection of items from the
+//! response with JSONPath, and diffs the items against the previous poll.
+//!
+//! # Change Detection
+//!
+//! Items are matched between polls by the ID selected with `id_path`:
+//!
+//! - An ID not seen in the previous poll is an **insert**
+//! - An item whose content differs from the previous poll is an **update**
+//! - An ID missing from the current poll is a **delete**
+//!
+//! Each item becomes a node whose element ID is the item ID and whose
+//! properties are the item's fields. The first poll emits every item as an
+//! insert unless `emit_initial` is disabled.
+//!
+//! Failed requests and responses where `items_path` matches nothing are
+//! logged and skipped, keeping the previous snapshot, so an outage does not
+//! delete every item.
+//!
+//! # Usage Example
+//!
+//! ```rust,ignore
+//! use drasi_source_http_poll::HttpPollSource;
+//!
+//! // {"data": [{"ticket_id": 17, "status": "open", ...}, ...], "next": null}
+//! let source = HttpPollSource::builder("tickets")
+//!     .with_url("https://api.example.com/v1/tickets?status=open")
+//!     .with_header("Authorization", "Bearer <token>")
+//!     .with_items_path("$.data[*]")
+//!     .with_id_path("$.ticket_id")
+//!     .with_label("Ticket")
+//!     .with_poll_interval_ms(30000)
+//!     .build()?;
+//!
+//! drasi.add_source(source).await?;
+//! ```
+
+mod config;
+mod conversion;
+pub mod descriptor;
+mod diff;
+mod extract;
+mod http_poll;
+
+#[cfg(test)]
+mod tests;
+
+pub use config::HttpPollSourceConfig;
+pub use http_poll::{HttpPollSource, HttpPollSourceBuilder};
+
+/// Dynamic plugin entry point.
+#[cfg(feature = "dynamic-plugin")]
+drasi_plugin_sdk::export_plugin!(
+    plugin_id = "http-poll-source",
+    core_version = env!("CARGO_PKG_VERSION"),
+    lib_version = env!("CARGO_PKG_VERSION"),
+    plugin_version = env!("CARGO_PKG_VERSION"),
+    source_descriptors = [descriptor::HttpPollSourceDescriptor],
+    reaction_descriptors = [],
+    bootstrap_descriptors = [],
+);
@@ -0,0 +1,401 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for the HTTP polling source plugin.
+
+use super::*;
+use crate::conversion::item_change_to_source_change;
+use crate::diff::{diff, ItemChange};
+use crate::extract::{ItemExtractor, PolledItem};
+use drasi_core::models::{Element, ElementValue, SourceChange};
+use drasi_lib::channels::SourceEvent;
+use drasi_lib::Source;
+use drasi_plugin_sdk::prelude::SourcePluginDescriptor;
+use serde_json::json;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+fn config() -> HttpPollSourceConfig {
+    HttpPollSourceConfig {
+        url: "http://localhost:8080/api/tickets".to_string(),
+        ..Default::default()
+    }
+}
+
+fn item(key: &str, status: &str) -> PolledItem {
+    PolledItem {
+        key: key.to_string(),
+        properties: json!({"id": key, "status": status})
+            .as_object()
+            .unwrap()
+            .clone(),
+    }
+}
+
+fn keys(items: &[PolledItem]) -> Vec<&str> {
+    items.iter().map(|item| item.key.as_str()).collect()
+}
+
+mod builder {
+    use super::*;
+
+    #[test]
+    fn test_builder_with_valid_config() {
+        let source = HttpPollSource::builder("test-source")
+            .with_url("https://api.example.com/v1/tickets")
+            .with_header("Authorization", "Bearer secret")
+            .with_items_path("$.data[*]")
+            .with_id_path("$.ticket_id")
+            .with_label("Ticket")
+            .with_poll_interval_ms(30000)
+            .with_emit_initial(false)
+            .build()
+            .unwrap();
+
+        assert_eq!(source.id(), "test-source");
+        assert_eq!(source.type_name(), "http-poll");
+        let props = source.properties();
+        assert_eq!(
+            props.get("url"),
+            Some(&json!("https://api.example.com/v1/tickets"))
+        );
+        assert_eq!(props.get("headers"), Some(&json!({"Authorization": "***"})));
+        assert_eq!(props.get("itemsPath"), Some(&json!("$.data[*]")));
+        assert_eq!(props.get("idPath"), Some(&json!("$.ticket_id")));
+        assert_eq!(props.get("label"), Some(&json!("Ticket")));
+        assert_eq!(props.get("pollIntervalMs"), Some(&json!(30000)));
+        assert_eq!(props.get("emitInitial"), Some(&json!(false)));
+    }
+
+    #[test]
+    fn test_builder_rejects_invalid_config() {
+        assert!(HttpPollSource::builder("test-source").build().is_err());
+        assert!(HttpPollSource::builder("test-source")
+            .with_url("ftp://example.com/items")
+            .build()
+            .is_err());
+        assert!(HttpPollSource::builder("test-source")
+            .with_url("https://example.com/items")
+            .with_items_path("$.data[")
+            .build()
+            .is_err());
+        assert!(HttpPollSource::builder("test-source")
+            .with_url("https://example.com/items")
+            .with_header("Bad Header", "value")
+            .build()
+            .is_err());
+        assert!(HttpPollSource::builder("test-source")
+            .with_url("https://example.com/items")
+            .with_poll_interval_ms(0)
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_config_deserialization_defaults() {
+        let config: HttpPollSourceConfig =
+            serde_json::from_value(json!({"url": "http://localhost/items"})).unwrap();
+
+        assert_eq!(config.items_path, "$");
+        assert_eq!(config.id_path, "$.id");
+        assert_eq!(config.label(), "HttpItem");
+        assert_eq!(config.poll_interval_ms, 5000);
+        assert_eq!(config.timeout_ms, 10000);
+        assert!(config.emit_initial);
+    }
+}
+
+mod extract {
+    use super::*;
+
+    fn extractor(items_path: &str, id_path: &str) -> ItemExtractor {
+        ItemExtractor::new(&HttpPollSourceConfig {
+            items_path: items_path.to_string(),
+            id_path: id_path.to_string(),
+            ..config()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_array_matches_are_expanded() {
+        let body = json!({"data": [{"id": "a"}, {"id": "b"}], "next": null});
+
+        for path in ["$.data", "$.data[*]"] {
+            let extraction = extractor(path, "$.id").extract(&body).unwrap();
+            assert_eq!(keys(&extraction.items), vec!["a", "b"], "path {path}");
+        }
+
+        let extraction = extractor("$", "$.id")
+            .extract(&json!([{"id": "x"}]))
+            .unwrap();
+        assert_eq!(keys(&extraction.items), vec!["x"]);
+    }
+
+    #[test]
+    fn test_nested_and_non_string_ids() {
+        let body = json!([
+            {"meta": {"uid": 17}, "name": "first"},
+            {"meta": {"uid": "t-2"}, "name": "second"}
+        ]);
+
+        let extraction = extractor("$", "$.meta.uid").extract(&body).unwrap();
+        assert_eq!(keys(&extraction.items), vec!["17", "t-2"]);
+        assert_eq!(
+            extraction.items[0].properties.get("name"),
+            Some(&json!("first"))
+        );
+    }
+
+    #[test]
+    fn test_invalid_items_are_skipped() {
+        let body = json!([
+            {"id": "a"},
+            42,
+            {"name": "no id"},
+            {"id": null},
+            {"id": "a", "duplicate": true},
+            {"id": "b"}
+        ]);
+
+        let extraction = extractor("$", "$.id").extract(&body).unwrap();
+        assert_eq!(keys(&extraction.items), vec!["a", "b"]);
+        assert_eq!(extraction.skipped.len(), 4);
+        assert!(extraction.items[0].properties.get("duplicate").is_none());
+    }
+
+    #[test]
+    fn test_missing_collection_is_an_error() {
+        let extractor = extractor("$.data", "$.id");
+        assert!(extractor
+            .extract(&json!({"error": "rate limited"}))
+            .is_err());
+
+        let extraction = extractor.extract(&json!({"data": []})).unwrap();
+        assert!(extraction.items.is_empty());
+    }
+}
+
+mod diffing {
+    use super::*;
+
+    #[test]
+    fn test_first_poll_inserts_everything() {
+        let (changes, snapshot) = diff(None, vec![item("a", "open"), item("b", "open")]);
+
+        assert_eq!(
+            changes,
+            vec![
+                ItemChange::Insert(item("a", "open")),
+                ItemChange::Insert(item("b", "open"))
+            ]
+        );
+        assert_eq!(snapshot.len(), 2);
+    }
+
+    #[test]
+    fn test_changes_between_polls() {
+        let (_, snapshot) = diff(
+            None,
+            vec![item("a", "open"), item("b", "open"), item("c", "open")],
+        );
+        let (changes, next) = diff(
+            Some(&snapshot),
+            vec![item("d", "open"), item("b", "closed"), item("a", "open")],
+        );
+
+        assert_eq!(
+            changes,
+            vec![
+                ItemChange::Insert(item("d", "open")),
+                ItemChange::Update(item("b", "closed")),
+                ItemChange::Delete("c".to_string())
+            ]
+        );
+        assert_eq!(next.len(), 3);
+    }
+
+    #[test]
+    fn test_field_order_is_not_a_change() {
+        let first = PolledItem {
+            key: "a".to_string(),
+            properties: serde_json::from_str(r#"{"id": "a", "x": 1, "y": 2}"#).unwrap(),
+        };
+        let second = PolledItem {
+            key: "a".to_string(),
+            properties: serde_json::from_str(r#"{"y": 2, "id": "a", "x": 1}"#).unwrap(),
+        };
+
+        let (_, snapshot) = diff(None, vec![first]);
+        let (changes, _) = diff(Some(&snapshot), vec![second]);
+        assert!(changes.is_empty());
+    }
+
+    #[test]
+    fn test_conversion_to_source_changes() {
+        let insert =
+            item_change_to_source_change("src", "Ticket", &ItemChange::Insert(item("a", "open")));
+        let SourceChange::Insert {
+            element:
+                Element::Node {
+                    metadata,
+                    properties,
+                },
+        } = insert
+        else {
+            panic!("expected node insert");
+        };
+        assert_eq!(metadata.reference.element_id.as_ref(), "a");
+        assert_eq!(metadata.labels[0].as_ref(), "Ticket");
+        assert_eq!(
+            properties.get("status"),
+            Some(&ElementValue::String("open".into()))
+        );
+
+        let delete =
+            item_change_to_source_change("src", "Ticket", &ItemChange::Delete("a".to_string()));
+        let SourceChange::Delete { metadata } = delete else {
+            panic!("expected delete");
+        };
+        assert_eq!(metadata.reference.element_id.as_ref(), "a");
+    }
+}
+
+mod polling {
+    use super::*;
+
+    /// Serve `bodies` as JSON responses in turn, repeating the last one.
+    async fn serve(listener: TcpListener, bodies: Vec<serde_json::Value>) {
+        let mut bodies = bodies.into_iter();
+        let mut current = bodies.next().unwrap();
+        loop {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0u8; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                match stream.read(&mut buffer).await {
+                    Ok(0) | Err(_) => break,
+                    Ok(read) => request.extend_from_slice(&buffer[..read]),
+                }
+            }
+
+            let body = current.to_string();
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            let _ = stream.write_all(response.as_bytes()).await;
+            if let Some(next) = bodies.next() {
+                current = next;
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_polls_emit_diffed_changes() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/tickets", listener.local_addr().unwrap());
+        let server = tokio::spawn(serve(
+            listener,
+            vec![
+                json!({"data": [{"id": 1, "status": "open"}, {"id": 2, "status": "open"}]}),
+                json!({"error": "unavailable"}),
+                json!({"data": [{"id": 1, "status": "closed"}]}),
+            ],
+        ));
+
+        let source = HttpPollSource::builder("poll-test")
+            .with_url(url)
+            .with_items_path("$.data")
+            .with_label("Ticket")
+            .with_poll_interval_ms(50)
+            .build()
+            .unwrap();
+        let mut rx = source.test_subscribe();
+        source.start().await.unwrap();
+
+        let mut changes = Vec::new();
+        for _ in 0..4 {
+            let event = tokio::time::timeout(Duration::from_secs(10), rx.recv())
+                .await
+                .expect("Timeout")
+                .expect("No event");
+            if let SourceEvent::Change(change) = &event.event {
+                changes.push(change.clone());
+            }
+        }
+        source.stop().await.unwrap();
+        server.abort();
+
+        assert!(matches!(changes[0], SourceChange::Insert { .. }));
+        assert!(matches!(changes[1], SourceChange::Insert { .. }));
+        let SourceChange::Update {
+            element: Element::Node { metadata, .. },
+        } = &changes[2]
+        else {
+            panic!("expected node update");
+        };
+        assert_eq!(metadata.reference.element_id.as_ref(), "1");
+        let SourceChange::Delete { metadata } = &changes[3] else {
+            panic!("expected delete");
+        };
+        assert_eq!(metadata.reference.element_id.as_ref(), "2");
+    }
+}
+
+mod descriptor {
+    use super::*;
+    use crate::descriptor::HttpPollSourceDescriptor;
+
+    #[tokio::test]
+    async fn test_descriptor_creates_source() {
+        let descriptor = HttpPollSourceDescriptor;
+        assert_eq!(descriptor.kind(), "http-poll");
+
+        let source = descriptor
+            .create_source(
+                "poll-1",
+                &json!({
+                    "url": "https://api.example.com/v1/tickets",
+                    "headers": {"Authorization": "Bearer abc"},
+                    "itemsPath": "$.data[*]",
+                    "idPath": "$.ticket_id",
+                    "pollIntervalMs": 30000
+                }),
+                false,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(source.id(), "poll-1");
+        assert!(!source.auto_start());
+        let props = source.properties();
+        assert_eq!(props.get("itemsPath"), Some(&json!("$.data[*]")));
+        assert_eq!(props.get("pollIntervalMs"), Some(&json!(30000)));
+        assert_eq!(props["headers"]["Authorization"], json!("***"));
+    }
+
+    #[tokio::test]
+    async fn test_descriptor_rejects_unknown_fields() {
+        let result = HttpPollSourceDescriptor
+            .create_source(
+                "poll-1",
+                &json!({"url": "https://api.example.com/items", "bogus": 1}),
+                true,
+            )
+            .await;
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Shard checkpoints for the Kinesis source.
+//!
+//! Checkpoints record the sequence number of the last dispatched record of
+//! each shard, and whether a closed shard has been read to its end. The
+//! checkpoints of all shards are saved together as the source's checkpoint
+//! after every batch.
+
+use anyhow::Result;
+use drasi_lib::sources::base::SourceBase;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tokio::sync::Mutex;
+
+/// Last dispatched position of a shard.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    /// Sequence number of the last record dispatched from the shard.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sequence_number: Option<String>,
+    /// Whether the shard was closed by resharding and read to its end. Child
+    /// shards are only read once their parents are finished.
+    #[serde(default)]
+    pub finished: bool,
+}
+
+/// Checkpoints of every shard of a stream, as saved by the source.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SavedCheckpoints {
+    stream_name: String,
+    shards: BTreeMap<String, Checkpoint>,
+}
+
+/// Shard checkpoints shared by the shard tasks of a source.
+pub(crate) struct ShardCheckpoints {
+    base: SourceBase,
+    saved: Mutex<SavedCheckpoints>,
+}
+
+impl ShardCheckpoints {
+    /// Load the checkpoints `base` saved for `stream_name`.
+    ///
+    /// Checkpoints saved for another stream are discarded.
+    pub(crate) async fn load(base: SourceBase, stream_name: &str) -> Result<Self> {
+        let saved = match base.load_checkpoint::<SavedCheckpoints>().await? {
+            Some(saved) if saved.stream_name == stream_name => saved,
+            _ => SavedCheckpoints {
+                stream_name: stream_name.to_string(),
+                shards: BTreeMap::new(),
+            },
+        };
+        Ok(Self {
+            base,
+            saved: Mutex::new(saved),
+        })
+    }
+
+    /// The checkpoint of a shard, or `None` if it has none.
+    pub(crate) async fn get(&self, shard_id: &str) -> Option<Checkpoint> {
+        self.saved.lock().await.shards.get(shard_id).cloned()
+    }
+
+    /// Record the checkpoint of a shard and save the checkpoints of all shards.
+    pub(crate) async fn save(&self, shard_id: &str, checkpoint: Checkpoint) -> Result<()> {
+        // Saving under the lock keeps a slower task from overwriting newer checkpoints
+        let mut saved = self.saved.lock().await;
+        saved.shards.insert(shard_id.to_string(), checkpoint);
+        self.base.save_checkpoint(&*saved).await
+    }
+}
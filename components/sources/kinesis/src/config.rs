@@ -0,0 +1,218 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration types for the AWS Kinesis source plugin.
+//!
+//! This module defines which stream the source reads, how shards are consumed,
+//! and how record payloads are mapped onto graph nodes.
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+fn default_poll_interval_ms() -> u64 {
+    1000
+}
+
+fn default_max_records() -> i32 {
+    1000
+}
+
+fn default_shard_discovery_interval_ms() -> u64 {
+    30000
+}
+
+fn default_id_field() -> String {
+    "id".to_string()
+}
+
+fn default_operation_field() -> String {
+    "op".to_string()
+}
+
+/// Largest `max_records` accepted by `GetRecords`.
+pub(crate) const MAX_RECORDS_LIMIT: i32 = 10000;
+
+/// Where to start reading a shard that has no checkpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum StartPosition {
+    /// Read from the oldest record still retained by the shard.
+    TrimHorizon,
+    /// Read only records added after the shard is opened.
+    #[default]
+    Latest,
+}
+
+/// AWS Kinesis Data Streams source configuration.
+///
+/// Credentials and, unless `region` is set, the region come from the default
+/// AWS provider chain (environment, shared config, web identity, or instance
+/// metadata).
+///
+/// # Example
+///
+/// ```rust
+/// use drasi_source_kinesis::{KinesisSourceConfig, StartPosition};
+///
+/// let config = KinesisSourceConfig {
+///     stream_name: "orders".to_string(),
+///     region: Some("eu-west-1".to_string()),
+///     start_position: StartPosition::TrimHorizon,
+///     label: Some("Order".to_string()),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct KinesisSourceConfig {
+    /// Name of the stream to read.
+    pub stream_name: String,
+
+    /// AWS region of the stream. Falls back to the provider chain.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+
+    /// Custom endpoint URL, for example a LocalStack instance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub endpoint_url: Option<String>,
+
+    /// Name of the enhanced fan-out consumer to read through. Setting it
+    /// switches from polling `GetRecords` to `SubscribeToShard`; the consumer is
+    /// registered on the stream if it does not exist.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub consumer_name: Option<String>,
+
+    /// Where to start reading shards without a checkpoint.
+    ///
+    /// **Default**: `latest`
+    #[serde(default)]
+    pub start_position: StartPosition,
+
+    /// Milliseconds to wait after a `GetRecords` call that returned no
+    /// records. Not used with enhanced fan-out.
+    ///
+    /// **Default**: `1000`
+    #[serde(default = "default_poll_interval_ms")]
+    pub poll_interval_ms: u64,
+
+    /// Maximum records per `GetRecords` call, at most 10000. Not used with
+    /// enhanced fan-out.
+    ///
+    /// **Default**: `1000`
+    #[serde(default = "default_max_records")]
+    pub max_records: i32,
+
+    /// Milliseconds between shard listings, which pick up shards created by
+    /// resharding.
+    ///
+    /// **Default**: `30000`
+    #[serde(default = "default_shard_discovery_interval_ms")]
+    pub shard_discovery_interval_ms: u64,
+
+    /// Payload field holding the element ID.
+    ///
+    /// **Default**: `"id"`
+    #[serde(default = "default_id_field")]
+    pub id_field: String,
+
+    /// Payload field holding the change operation (`insert`, `update`, `delete`
+    /// or `i`/`u`/`d`). Records without it are treated as inserts.
+    ///
+    /// **Default**: `"op"`
+    #[serde(default = "default_operation_field")]
+    pub operation_field: String,
+
+    /// Optional payload field holding the node label. Takes precedence over `label`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_field: Option<String>,
+
+    /// Label for nodes from this source. Falls back to the stream name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+impl Default for KinesisSourceConfig {
+    fn default() -> Self {
+        Self {
+            stream_name: String::new(),
+            region: None,
+            endpoint_url: None,
+            consumer_name: None,
+            start_position: StartPosition::default(),
+            poll_interval_ms: default_poll_interval_ms(),
+            max_records: default_max_records(),
+            shard_discovery_interval_ms: default_shard_discovery_interval_ms(),
+            id_field: default_id_field(),
+            operation_field: default_operation_field(),
+            label_field: None,
+            label: None,
+        }
+    }
+}
+
+impl KinesisSourceConfig {
+    /// Validate the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `stream_name`, `id_field` or `operation_field` is empty
+    /// - `region`, `endpoint_url` or `consumer_name` is set but empty
+    /// - `max_records` is not between 1 and 10000
+    /// - `poll_interval_ms` or `shard_discovery_interval_ms` is zero
+    pub fn validate(&self) -> Result<()> {
+        if self.stream_name.is_empty() {
+            return Err(anyhow!("Validation error: stream_name cannot be empty"));
+        }
+
+        for (name, value) in [
+            ("region", &self.region),
+            ("endpoint_url", &self.endpoint_url),
+            ("consumer_name", &self.consumer_name),
+        ] {
+            if value.as_deref() == Some("") {
+                return Err(anyhow!(
+                    "Validation error: {name} cannot be empty; omit it to use the default"
+                ));
+            }
+        }
+
+        if !(1..=MAX_RECORDS_LIMIT).contains(&self.max_records) {
+            return Err(anyhow!(
+                "Validation error: max_records must be between 1 and {MAX_RECORDS_LIMIT}"
+            ));
+        }
+
+        if self.poll_interval_ms == 0 {
+            return Err(anyhow!(
+                "Validation error: poll_interval_ms must be greater than 0"
+            ));
+        }
+
+        if self.shard_discovery_interval_ms == 0 {
+            return Err(anyhow!(
+                "Validation error: shard_discovery_interval_ms must be greater than 0"
+            ));
+        }
+
+        if self.id_field.is_empty() {
+            return Err(anyhow!("Validation error: id_field cannot be empty"));
+        }
+
+        if self.operation_field.is_empty() {
+            return Err(anyhow!("Validation error: operation_field cannot be empty"));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,161 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Kinesis client setup and enhanced fan-out consumer registration.
+
+use anyhow::{anyhow, Result};
+use aws_sdk_kinesis::error::DisplayErrorContext;
+use aws_sdk_kinesis::types::ConsumerStatus;
+use aws_sdk_kinesis::Client;
+use log::info;
+use std::time::Duration;
+
+use crate::config::KinesisSourceConfig;
+
+/// Delay between checks while a new consumer is being created.
+const CONSUMER_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How many times to check before giving up on a consumer becoming active.
+const CONSUMER_POLL_ATTEMPTS: usize = 60;
+
+/// Create a Kinesis client from the default provider chain, overriding the
+/// region and endpoint when configured.
+pub(crate) async fn create_client(config: &KinesisSourceConfig) -> Client {
+    let mut loader = aws_config::defaults(aws_config::BehaviorVersion::latest());
+    if let Some(region) = &config.region {
+        loader = loader.region(aws_config::Region::new(region.clone()));
+    }
+    if let Some(endpoint_url) = &config.endpoint_url {
+        loader = loader.endpoint_url(endpoint_url);
+    }
+    Client::new(&loader.load().await)
+}
+
+/// ARN of the stream.
+///
+/// # Errors
+///
+/// Returns an error if the stream does not exist or the call is denied.
+pub(crate) async fn stream_arn(client: &Client, stream_name: &str) -> Result<String> {
+    let output = client
+        .describe_stream_summary()
+        .stream_name(stream_name)
+        .send()
+        .await
+        .map_err(|e| {
+            anyhow!(
+                "Failed to describe stream '{stream_name}': {}",
+                DisplayErrorContext(e)
+            )
+        })?;
+
+    output
+        .stream_description_summary()
+        .map(|summary| summary.stream_arn().to_string())
+        .ok_or_else(|| anyhow!("Stream '{stream_name}' has no description"))
+}
+
+/// Look up the enhanced fan-out consumer `consumer_name` of a stream,
+/// registering it if it does not exist, and wait until it is active.
+///
+/// Returns the consumer ARN.
+///
+/// # Errors
+///
+/// Returns an error if the consumer cannot be described or registered, or
+/// does not become active in time.
+pub(crate) async fn register_consumer(
+    client: &Client,
+    stream_arn: &str,
+    consumer_name: &str,
+) -> Result<String> {
+    let described = client
+        .describe_stream_consumer()
+        .stream_arn(stream_arn)
+        .consumer_name(consumer_name)
+        .send()
+        .await;
+
+    let (consumer_arn, mut status) = match described {
+        Ok(output) => {
+            let description = output
+                .consumer_description()
+                .ok_or_else(|| anyhow!("Consumer '{consumer_name}' has no description"))?;
+            (
+                description.consumer_arn().to_string(),
+                description.consumer_status().clone(),
+            )
+        }
+        Err(e)
+            if e.as_service_error()
+                .is_some_and(|e| e.is_resource_not_found_exception()) =>
+        {
+            info!("Registering enhanced fan-out consumer '{consumer_name}'");
+            let output = client
+                .register_stream_consumer()
+                .stream_arn(stream_arn)
+                .consumer_name(consumer_name)
+                .send()
+                .await
+                .map_err(|e| {
+                    anyhow!(
+                        "Failed to register consumer '{consumer_name}': {}",
+                        DisplayErrorContext(e)
+                    )
+                })?;
+            let consumer = output.consumer().ok_or_else(|| {
+                anyhow!("Registering consumer '{consumer_name}' returned nothing")
+            })?;
+            (
+                consumer.consumer_arn().to_string(),
+                consumer.consumer_status().clone(),
+            )
+        }
+        Err(e) => {
+            return Err(anyhow!(
+                "Failed to describe consumer '{consumer_name}': {}",
+                DisplayErrorContext(e)
+            ))
+        }
+    };
+
+    for _ in 0..CONSUMER_POLL_ATTEMPTS {
+        match status {
+            ConsumerStatus::Active => return Ok(consumer_arn),
+            ConsumerStatus::Deleting => {
+                return Err(anyhow!("Consumer '{consumer_name}' is being deleted"))
+            }
+            _ => tokio::time::sleep(CONSUMER_POLL_INTERVAL).await,
+        }
+
+        let output = client
+            .describe_stream_consumer()
+            .consumer_arn(&consumer_arn)
+            .send()
+            .await
+            .map_err(|e| {
+                anyhow!(
+                    "Failed to describe consumer '{consumer_name}': {}",
+                    DisplayErrorContext(e)
+                )
+            })?;
+        if let Some(description) = output.consumer_description() {
+            status = description.consumer_status().clone();
+        }
+    }
+
+    Err(anyhow!(
+        "Consumer '{consumer_name}' did not become active in time"
+    ))
+}
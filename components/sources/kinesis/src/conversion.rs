@@ -0,0 +1,96 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion of Kinesis records into Drasi source changes.
+
+use anyhow::{anyhow, Result};
+use drasi_core::models::{Element, ElementMetadata, ElementReference, SourceChange};
+use drasi_lib::sources::convert_json_to_element_properties;
+use serde_json::Value;
+use std::sync::Arc;
+
+use crate::config::KinesisSourceConfig;
+
+fn field_as_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// Convert the data of a record into a [`SourceChange`].
+///
+/// The data must be a JSON object with an `id_field`. Fields other than the
+/// operation and label fields become node properties, and the label falls back
+/// to the stream name.
+///
+/// # Errors
+///
+/// Returns an error if the data is not a JSON object, has no ID, or has an
+/// unrecognized operation.
+pub(crate) fn record_to_source_change(
+    source_id: &str,
+    stream_name: &str,
+    data: &[u8],
+    config: &KinesisSourceConfig,
+) -> Result<SourceChange> {
+    let mut object = match serde_json::from_slice::<Value>(data) {
+        Ok(Value::Object(object)) => object,
+        Ok(_) => return Err(anyhow!("Record data is not a JSON object")),
+        Err(e) => return Err(anyhow!("Record data is not valid JSON: {e}")),
+    };
+
+    let element_id = object
+        .get(&config.id_field)
+        .and_then(field_as_string)
+        .filter(|id| !id.is_empty())
+        .ok_or_else(|| anyhow!("Record has no '{}' field", config.id_field))?;
+
+    let operation = object
+        .remove(&config.operation_field)
+        .and_then(|v| field_as_string(&v))
+        .map(|op| op.to_lowercase())
+        .unwrap_or_else(|| "insert".to_string());
+
+    let label = config
+        .label_field
+        .as_ref()
+        .and_then(|field| object.remove(field))
+        .and_then(|v| field_as_string(&v))
+        .or_else(|| config.label.clone())
+        .unwrap_or_else(|| stream_name.to_string());
+
+    let metadata = ElementMetadata {
+        reference: ElementReference::new(source_id, &element_id),
+        labels: Arc::from(vec![Arc::from(label.as_str())]),
+        effective_from: chrono::Utc::now().timestamp_millis() as u64,
+    };
+
+    if matches!(operation.as_str(), "d" | "delete") {
+        return Ok(SourceChange::Delete { metadata });
+    }
+
+    let element = Element::Node {
+        metadata,
+        properties: convert_json_to_element_properties(&object),
+    };
+
+    match operation.as_str() {
+        "i" | "c" | "insert" | "create" => Ok(SourceChange::Insert { element }),
+        "u" | "update" => Ok(SourceChange::Update { element }),
+        other => Err(anyhow!("Record has unknown operation '{other}'")),
+    }
+}
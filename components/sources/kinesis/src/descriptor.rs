@@ -0,0 +1,148 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! AWS Kinesis source plugin descriptor and configuration DTOs.
+
+use crate::{KinesisSourceBuilder, KinesisSourceConfig, StartPosition};
+use drasi_plugin_sdk::prelude::*;
+use utoipa::OpenApi;
+
+/// Start position DTO.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::kinesis::StartPosition)]
+#[serde(rename_all = "snake_case")]
+pub enum StartPositionDto {
+    TrimHorizon,
+    #[default]
+    Latest,
+}
+
+/// AWS Kinesis source configuration DTO.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::kinesis::KinesisSourceConfig)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct KinesisSourceConfigDto {
+    pub stream_name: ConfigValue<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<ConfigValue<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub endpoint_url: Option<ConfigValue<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub consumer_name: Option<String>,
+    #[serde(default)]
+    #[schema(value_type = source::kinesis::StartPosition)]
+    pub start_position: StartPositionDto,
+    #[serde(default = "default_poll_interval_ms")]
+    pub poll_interval_ms: ConfigValue<u64>,
+    #[serde(default = "default_max_records")]
+    pub max_records: ConfigValue<i32>,
+    #[serde(default = "default_shard_discovery_interval_ms")]
+    pub shard_discovery_interval_ms: ConfigValue<u64>,
+    #[serde(default = "default_id_field")]
+    pub id_field: String,
+    #[serde(default = "default_operation_field")]
+    pub operation_field: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_field: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+fn default_poll_interval_ms() -> ConfigValue<u64> {
+    ConfigValue::Static(1000)
+}
+
+fn default_max_records() -> ConfigValue<i32> {
+    ConfigValue::Static(1000)
+}
+
+fn default_shard_discovery_interval_ms() -> ConfigValue<u64> {
+    ConfigValue::Static(30000)
+}
+
+fn default_id_field() -> String {
+    "id".to_string()
+}
+
+fn default_operation_field() -> String {
+    "op".to_string()
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(KinesisSourceConfigDto, StartPositionDto)))]
+struct KinesisSourceSchemas;
+
+/// Descriptor for the AWS Kinesis source plugin.
+pub struct KinesisSourceDescriptor;
+
+#[async_trait]
+impl SourcePluginDescriptor for KinesisSourceDescriptor {
+    fn kind(&self) -> &str {
+        "kinesis"
+    }
+
+    fn config_version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn config_schema_name(&self) -> &str {
+        "source.kinesis.KinesisSourceConfig"
+    }
+
+    fn config_schema_json(&self) -> String {
+        let api = KinesisSourceSchemas::openapi();
+        serde_json::to_string(
+            &api.components
+                .as_ref()
+                .expect("OpenAPI components missing")
+                .schemas,
+        )
+        .expect("Failed to serialize config schema")
+    }
+
+    async fn create_source(
+        &self,
+        id: &str,
+        config_json: &serde_json::Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn drasi_lib::sources::Source>> {
+        let dto: KinesisSourceConfigDto = serde_json::from_value(config_json.clone())?;
+        let mapper = DtoMapper::new();
+
+        let config = KinesisSourceConfig {
+            stream_name: mapper.resolve_string(&dto.stream_name)?,
+            region: mapper.resolve_optional(&dto.region)?,
+            endpoint_url: mapper.resolve_optional(&dto.endpoint_url)?,
+            consumer_name: dto.consumer_name,
+            start_position: match dto.start_position {
+                StartPositionDto::TrimHorizon => StartPosition::TrimHorizon,
+                StartPositionDto::Latest => StartPosition::Latest,
+            },
+            poll_interval_ms: mapper.resolve_typed(&dto.poll_interval_ms)?,
+            max_records: mapper.resolve_typed(&dto.max_records)?,
+            shard_discovery_interval_ms: mapper.resolve_typed(&dto.shard_discovery_interval_ms)?,
+            id_field: dto.id_field,
+            operation_field: dto.operation_field,
+            label_field: dto.label_field,
+            label: dto.label,
+        };
+
+        let source = KinesisSourceBuilder::new(id)
+            .with_config(config)
+            .with_auto_start(auto_start)
+            .build()?;
+
+        Ok(Box::new(source))
+    }
+}
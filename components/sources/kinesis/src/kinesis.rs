@@ -0,0 +1,763 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! AWS Kinesis source implementation and builder.
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use aws_sdk_kinesis::error::DisplayErrorContext;
+use aws_sdk_kinesis::types::{Record, StartingPosition, SubscribeToShardEventStream};
+use aws_sdk_kinesis::Client;
+use log::{debug, error, info, warn};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tokio::task::JoinSet;
+
+use drasi_core::models::SourceChange;
+use drasi_lib::channels::*;
+use drasi_lib::managers::{log_component_start, log_component_stop};
+use drasi_lib::sources::base::{SourceBase, SourceBaseParams};
+use drasi_lib::Source;
+use tracing::Instrument;
+
+use crate::checkpoint::{Checkpoint, ShardCheckpoints};
+use crate::config::{KinesisSourceConfig, StartPosition};
+use crate::consumer::{create_client, register_consumer, stream_arn};
+use crate::conversion::record_to_source_change;
+use crate::shards::{list_shards, ShardInfo, ShardPosition, ShardTracker};
+
+type Dispatchers = Arc<RwLock<Vec<Box<dyn ChangeDispatcher<SourceEventWrapper> + Send + Sync>>>>;
+
+/// Delay before re-reading a shard after an error.
+const RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// Minimum delay between `GetRecords` calls on a shard, which allows five per second.
+const MIN_GET_RECORDS_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Source that reads JSON change records from an AWS Kinesis data stream.
+///
+/// Every shard of the stream is read by its own task, either by polling
+/// `GetRecords` or, when `consumer_name` is set, through an enhanced fan-out
+/// subscription. The sequence number of the last dispatched record of each
+/// shard is saved as the source's checkpoint after every batch.
+pub struct KinesisSource {
+    /// Base source implementation providing dispatchers, status tracking, and lifecycle management.
+    base: SourceBase,
+    /// Kinesis configuration.
+    config: KinesisSourceConfig,
+}
+
+/// State shared by the shard tasks.
+struct ShardContext {
+    source_id: String,
+    config: KinesisSourceConfig,
+    client: Client,
+    /// ARN of the enhanced fan-out consumer, if one is used.
+    consumer_arn: Option<String>,
+    dispatchers: Dispatchers,
+    checkpoints: ShardCheckpoints,
+}
+
+/// Why reading a shard stopped without an error.
+enum ShardEnd {
+    /// The shard was closed by resharding and read to its end.
+    Closed,
+    /// The fan-out subscription expired and must be renewed.
+    Resubscribe,
+}
+
+impl KinesisSource {
+    /// Create a builder for a Kinesis source.
+    pub fn builder(id: impl Into<String>) -> KinesisSourceBuilder {
+        KinesisSourceBuilder::new(id)
+    }
+
+    /// Create a new Kinesis source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid.
+    pub fn new(id: impl Into<String>, config: KinesisSourceConfig) -> Result<Self> {
+        KinesisSourceBuilder::new(id).with_config(config).build()
+    }
+
+    /// Wrap the change read from a record, keyed by the record's partition key.
+    ///
+    /// Kinesis orders records per partition key, so changes sharing one keep
+    /// their order on an evaluation pool while other keys are dispatched in
+    /// parallel. The key is kept across resharding, unlike the shard id.
+    pub(crate) fn change_event(
+        source_id: &str,
+        change: SourceChange,
+        partition_key: &str,
+    ) -> SourceEventWrapper {
+        let mut profiling = drasi_lib::profiling::ProfilingMetadata::new();
+        profiling.source_send_ns = Some(drasi_lib::profiling::timestamp_ns());
+
+        SourceEventWrapper::with_profiling(
+            source_id.to_string(),
+            SourceEvent::Change(change),
+            chrono::Utc::now(),
+            profiling,
+        )
+        .with_ordering_key(OrderingKey::from(partition_key))
+    }
+
+    async fn dispatch(
+        source_id: &str,
+        dispatchers: &Dispatchers,
+        change: SourceChange,
+        partition_key: &str,
+    ) -> Result<()> {
+        let wrapper = Self::change_event(source_id, change, partition_key);
+        SourceBase::dispatch_from_task(dispatchers.clone(), wrapper, source_id).await
+    }
+
+    async fn save_checkpoint(ctx: &ShardContext, shard_id: &str, checkpoint: Checkpoint) {
+        if let Err(e) = ctx.checkpoints.save(shard_id, checkpoint).await {
+            warn!(
+                "[{}] Failed to save checkpoint of shard {shard_id}: {e}",
+                ctx.source_id
+            );
+        }
+    }
+
+    /// Dispatch a batch of records and checkpoint the last one dispatched.
+    ///
+    /// Records that cannot be converted are logged and skipped. On a dispatch
+    /// failure, `position` stays before the failed record so it is re-read.
+    async fn process_records(
+        ctx: &ShardContext,
+        shard_id: &str,
+        records: &[Record],
+        position: &mut ShardPosition,
+    ) -> Result<()> {
+        let mut result = Ok(());
+        let mut advanced = false;
+
+        for record in records {
+            match record_to_source_change(
+                &ctx.source_id,
+                &ctx.config.stream_name,
+                record.data().as_ref(),
+                &ctx.config,
+            ) {
+                Ok(change) => {
+                    if let Err(e) = Self::dispatch(
+                        &ctx.source_id,
+                        &ctx.dispatchers,
+                        change,
+                        record.partition_key(),
+                    )
+                    .await
+                    {
+                        result = Err(e);
+                        break;
+                    }
+                }
+                Err(e) => warn!(
+                    "[{}] Skipping record {} of shard {shard_id}: {e}",
+                    ctx.source_id,
+                    record.sequence_number()
+                ),
+            }
+            *position = ShardPosition::After(record.sequence_number().to_string());
+            advanced = true;
+        }
+
+        if advanced {
+            let checkpoint = Checkpoint {
+                sequence_number: position.sequence_number().map(str::to_string),
+                finished: false,
+            };
+            Self::save_checkpoint(ctx, shard_id, checkpoint).await;
+        }
+
+        result
+    }
+
+    /// Read a shard by polling `GetRecords` until it is closed.
+    async fn poll_shard(
+        ctx: &ShardContext,
+        shard_id: &str,
+        position: &mut ShardPosition,
+    ) -> Result<ShardEnd> {
+        let mut request = ctx
+            .client
+            .get_shard_iterator()
+            .stream_name(&ctx.config.stream_name)
+            .shard_id(shard_id)
+            .shard_iterator_type(position.iterator_type());
+        if let Some(sequence_number) = position.sequence_number() {
+            request = request.starting_sequence_number(sequence_number);
+        }
+        let mut iterator = request
+            .send()
+            .await
+            .map_err(|e| anyhow!("GetShardIterator failed: {}", DisplayErrorContext(e)))?
+            .shard_iterator()
+            .map(str::to_string);
+
+        // A closed shard returns no next iterator once it has been read to its end
+        while let Some(current) = iterator {
+            let output = ctx
+                .client
+                .get_records()
+                .shard_iterator(current)
+                .limit(ctx.config.max_records)
+                .send()
+                .await
+                .map_err(|e| anyhow!("GetRecords failed: {}", DisplayErrorContext(e)))?;
+
+            Self::process_records(ctx, shard_id, output.records(), position).await?;
+            iterator = output.next_shard_iterator().map(str::to_string);
+
+            if output.records().is_empty() {
+                tokio::time::sleep(Duration::from_millis(ctx.config.poll_interval_ms)).await;
+            } else {
+                tokio::time::sleep(MIN_GET_RECORDS_INTERVAL).await;
+            }
+        }
+
+        Ok(ShardEnd::Closed)
+    }
+
+    /// Read a shard through an enhanced fan-out subscription, which lasts up
+    /// to five minutes.
+    async fn subscribe_shard(
+        ctx: &ShardContext,
+        consumer_arn: &str,
+        shard_id: &str,
+        position: &mut ShardPosition,
+    ) -> Result<ShardEnd> {
+        let mut starting_position = StartingPosition::builder().r#type(position.iterator_type());
+        if let Some(sequence_number) = position.sequence_number() {
+            starting_position = starting_position.sequence_number(sequence_number);
+        }
+        let starting_position = starting_position
+            .build()
+            .map_err(|e| anyhow!("Invalid starting position: {e}"))?;
+
+        let mut output = ctx
+            .client
+            .subscribe_to_shard()
+            .consumer_arn(consumer_arn)
+            .shard_id(shard_id)
+            .starting_position(starting_position)
+            .send()
+            .await
+            .map_err(|e| anyhow!("SubscribeToShard failed: {}", DisplayErrorContext(e)))?;
+
+        loop {
+            match output.event_stream.recv().await {
+                Ok(Some(SubscribeToShardEventStream::SubscribeToShardEvent(event))) => {
+                    Self::process_records(ctx, shard_id, event.records(), position).await?;
+                    // Child shards are only reported once the shard has been read to its end
+                    if !event.child_shards().is_empty() {
+                        return Ok(ShardEnd::Closed);
+                    }
+                }
+                Ok(Some(_)) => {}
+                Ok(None) => return Ok(ShardEnd::Resubscribe),
+                Err(e) => {
+                    return Err(anyhow!(
+                        "Shard subscription failed: {}",
+                        DisplayErrorContext(e)
+                    ))
+                }
+            }
+        }
+    }
+
+    /// Read a shard until it is closed, resuming from its checkpoint.
+    ///
+    /// Returns the shard ID once the shard has been read to its end.
+    async fn run_shard(ctx: Arc<ShardContext>, shard_id: String) -> String {
+        let checkpoint = ctx.checkpoints.get(&shard_id).await;
+        if checkpoint.as_ref().is_some_and(|c| c.finished) {
+            debug!(
+                "[{}] Shard {shard_id} was already read to its end",
+                ctx.source_id
+            );
+            return shard_id;
+        }
+
+        let mut position = ShardPosition::resume(checkpoint.as_ref(), ctx.config.start_position);
+        info!(
+            "[{}] Reading shard {shard_id} from {position:?}",
+            ctx.source_id
+        );
+
+        loop {
+            let result = match &ctx.consumer_arn {
+                Some(consumer_arn) => {
+                    Self::subscribe_shard(&ctx, consumer_arn, &shard_id, &mut position).await
+                }
+                None => Self::poll_shard(&ctx, &shard_id, &mut position).await,
+            };
+
+            match result {
+                Ok(ShardEnd::Closed) => break,
+                Ok(ShardEnd::Resubscribe) => {}
+                Err(e) => {
+                    warn!("[{}] Error reading shard {shard_id}: {e}", ctx.source_id);
+                    tokio::time::sleep(RETRY_DELAY).await;
+                }
+            }
+        }
+
+        info!(
+            "[{}] Shard {shard_id} is closed and fully read",
+            ctx.source_id
+        );
+        let checkpoint = Checkpoint {
+            sequence_number: position.sequence_number().map(str::to_string),
+            finished: true,
+        };
+        Self::save_checkpoint(&ctx, &shard_id, checkpoint).await;
+        shard_id
+    }
+
+    fn start_ready_shards(
+        ctx: &Arc<ShardContext>,
+        tracker: &mut ShardTracker,
+        tasks: &mut JoinSet<String>,
+        shards: &[ShardInfo],
+    ) {
+        let ready: Vec<String> = tracker
+            .ready(shards)
+            .into_iter()
+            .map(|shard| shard.shard_id.clone())
+            .collect();
+
+        for shard_id in ready {
+            tracker.mark_started(&shard_id);
+            tasks.spawn(Self::run_shard(ctx.clone(), shard_id).in_current_span());
+        }
+    }
+
+    async fn connect(client: &Client, config: &KinesisSourceConfig) -> Result<Option<String>> {
+        match &config.consumer_name {
+            Some(consumer_name) => {
+                let stream_arn = stream_arn(client, &config.stream_name).await?;
+                Ok(Some(
+                    register_consumer(client, &stream_arn, consumer_name).await?,
+                ))
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn run(base: SourceBase, config: KinesisSourceConfig) {
+        let source_id = base.id.clone();
+        let dispatchers = base.dispatchers.clone();
+        let status_handle = base.status_handle();
+        let checkpoints = loop {
+            match ShardCheckpoints::load(base.clone_shared(), &config.stream_name).await {
+                Ok(checkpoints) => break checkpoints,
+                Err(e) => {
+                    warn!("[{source_id}] Failed to load shard checkpoints: {e}");
+                    tokio::time::sleep(RETRY_DELAY).await;
+                }
+            }
+        };
+        let client = create_client(&config).await;
+
+        let setup = async {
+            let consumer_arn = Self::connect(&client, &config).await?;
+            let shards = list_shards(&client, &config.stream_name).await?;
+            Ok::<_, anyhow::Error>((consumer_arn, shards))
+        };
+        let (consumer_arn, shards) = match setup.await {
+            Ok(setup) => setup,
+            Err(e) => {
+                error!("[{source_id}] Failed to connect to Kinesis: {e}");
+                status_handle
+                    .set_status(
+                        ComponentStatus::Error,
+                        Some(format!("Failed to connect to Kinesis: {e}")),
+                    )
+                    .await;
+                return;
+            }
+        };
+
+        info!(
+            "[{source_id}] Reading {} shard(s) of stream '{}'{}",
+            shards.len(),
+            config.stream_name,
+            if consumer_arn.is_some() {
+                " with enhanced fan-out"
+            } else {
+                ""
+            }
+        );
+        status_handle
+            .set_status(
+                ComponentStatus::Running,
+                Some("Kinesis source running".to_string()),
+            )
+            .await;
+
+        let discovery_interval = Duration::from_millis(config.shard_discovery_interval_ms);
+        let ctx = Arc::new(ShardContext {
+            source_id,
+            config,
+            client,
+            consumer_arn,
+            dispatchers,
+            checkpoints,
+        });
+
+        // Dropping the join set on abort stops every shard task
+        let mut tracker = ShardTracker::default();
+        let mut tasks = JoinSet::new();
+        Self::start_ready_shards(&ctx, &mut tracker, &mut tasks, &shards);
+
+        let mut discovery = tokio::time::interval(discovery_interval);
+        discovery.tick().await;
+
+        loop {
+            tokio::select! {
+                _ = discovery.tick() => {
+                    match list_shards(&ctx.client, &ctx.config.stream_name).await {
+                        Ok(shards) => Self::start_ready_shards(&ctx, &mut tracker, &mut tasks, &shards),
+                        Err(e) => warn!("[{}] Shard discovery failed: {e}", ctx.source_id),
+                    }
+                }
+                Some(joined) = tasks.join_next() => {
+                    match joined {
+                        Ok(shard_id) => {
+                            // Children of the finished shard may be ready now
+                            tracker.mark_finished(&shard_id);
+                            discovery.reset_immediately();
+                        }
+                        Err(e) => error!("[{}] Shard task failed: {e}", ctx.source_id),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Source for KinesisSource {
+    fn id(&self) -> &str {
+        &self.base.id
+    }
+
+    fn type_name(&self) -> &str {
+        "kinesis"
+    }
+
+    fn properties(&self) -> HashMap<String, serde_json::Value> {
+        use crate::descriptor::{KinesisSourceConfigDto, StartPositionDto};
+        use drasi_plugin_sdk::ConfigValue;
+
+        let dto = KinesisSourceConfigDto {
+            stream_name: ConfigValue::Static(self.config.stream_name.clone()),
+            region: self.config.region.clone().map(ConfigValue::Static),
+            endpoint_url: self.config.endpoint_url.clone().map(ConfigValue::Static),
+            consumer_name: self.config.consumer_name.clone(),
+            start_position: match self.config.start_position {
+                StartPosition::TrimHorizon => StartPositionDto::TrimHorizon,
+                StartPosition::Latest => StartPositionDto::Latest,
+            },
+            poll_interval_ms: ConfigValue::Static(self.config.poll_interval_ms),
+            max_records: ConfigValue::Static(self.config.max_records),
+            shard_discovery_interval_ms: ConfigValue::Static(
+                self.config.shard_discovery_interval_ms,
+            ),
+            id_field: self.config.id_field.clone(),
+            operation_field: self.config.operation_field.clone(),
+            label_field: self.config.label_field.clone(),
+            label: self.config.label.clone(),
+        };
+
+        match serde_json::to_value(&dto) {
+            Ok(serde_json::Value::Object(map)) => map.into_iter().collect(),
+            _ => HashMap::new(),
+        }
+    }
+
+    fn auto_start(&self) -> bool {
+        self.base.get_auto_start()
+    }
+
+    async fn start(&self) -> Result<()> {
+        log_component_start("Kinesis Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Starting,
+                Some("Starting Kinesis source".to_string()),
+            )
+            .await;
+
+        let has_checkpoint_store = self
+            .base
+            .context()
+            .await
+            .is_some_and(|c| c.checkpoint_store().is_some());
+        if !has_checkpoint_store {
+            info!(
+                "[{}] No checkpoint store configured, shards restart at the start position",
+                self.base.id
+            );
+        }
+
+        let instance_id = self
+            .base
+            .context()
+            .await
+            .map(|c| c.instance_id)
+            .unwrap_or_default();
+
+        let span = tracing::info_span!(
+            "kinesis_source_consumer",
+            instance_id = %instance_id,
+            component_id = %self.base.id,
+            component_type = "source"
+        );
+        let task =
+            tokio::spawn(Self::run(self.base.clone_shared(), self.config.clone()).instrument(span));
+
+        *self.base.task_handle.write().await = Some(task);
+
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        log_component_stop("Kinesis Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopping,
+                Some("Stopping Kinesis source".to_string()),
+            )
+            .await;
+
+        if let Some(handle) = self.base.task_handle.write().await.take() {
+            handle.abort();
+            let _ = handle.await;
+        }
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopped,
+                Some("Kinesis source stopped".to_string()),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn status(&self) -> ComponentStatus {
+        self.base.get_status().await
+    }
+
+    async fn subscribe(
+        &self,
+        settings: drasi_lib::config::SourceSubscriptionSettings,
+    ) -> Result<SubscriptionResponse> {
+        self.base
+            .subscribe_with_bootstrap(&settings, "Kinesis")
+            .await
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    async fn initialize(&self, context: drasi_lib::context::SourceRuntimeContext) {
+        self.base.initialize(context).await;
+    }
+
+    async fn set_bootstrap_provider(
+        &self,
+        provider: Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>,
+    ) {
+        self.base.set_bootstrap_provider(provider).await;
+    }
+}
+
+/// Builder for [`KinesisSource`] instances.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use drasi_source_kinesis::{KinesisSource, StartPosition};
+///
+/// let source = KinesisSource::builder("orders")
+///     .with_stream_name("orders")
+///     .with_region("eu-west-1")
+///     .with_start_position(StartPosition::TrimHorizon)
+///     .with_label("Order")
+///     .build()?;
+/// ```
+pub struct KinesisSourceBuilder {
+    id: String,
+    config: KinesisSourceConfig,
+    dispatch_mode: Option<DispatchMode>,
+    dispatch_buffer_capacity: Option<usize>,
+    bootstrap_provider: Option<Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>>,
+    auto_start: bool,
+}
+
+impl KinesisSourceBuilder {
+    /// Create a new builder with the given ID and default values.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            config: KinesisSourceConfig::default(),
+            dispatch_mode: None,
+            dispatch_buffer_capacity: None,
+            bootstrap_provider: None,
+            auto_start: true,
+        }
+    }
+
+    /// Set the stream to read.
+    pub fn with_stream_name(mut self, stream_name: impl Into<String>) -> Self {
+        self.config.stream_name = stream_name.into();
+        self
+    }
+
+    /// Set the AWS region of the stream.
+    pub fn with_region(mut self, region: impl Into<String>) -> Self {
+        self.config.region = Some(region.into());
+        self
+    }
+
+    /// Set a custom endpoint URL.
+    pub fn with_endpoint_url(mut self, endpoint_url: impl Into<String>) -> Self {
+        self.config.endpoint_url = Some(endpoint_url.into());
+        self
+    }
+
+    /// Read through the enhanced fan-out consumer with this name.
+    pub fn with_enhanced_fan_out(mut self, consumer_name: impl Into<String>) -> Self {
+        self.config.consumer_name = Some(consumer_name.into());
+        self
+    }
+
+    /// Set where to start reading shards without a checkpoint.
+    pub fn with_start_position(mut self, start_position: StartPosition) -> Self {
+        self.config.start_position = start_position;
+        self
+    }
+
+    /// Set the delay after an empty `GetRecords` call in milliseconds.
+    pub fn with_poll_interval_ms(mut self, interval_ms: u64) -> Self {
+        self.config.poll_interval_ms = interval_ms;
+        self
+    }
+
+    /// Set the maximum records per `GetRecords` call.
+    pub fn with_max_records(mut self, max_records: i32) -> Self {
+        self.config.max_records = max_records;
+        self
+    }
+
+    /// Set the interval between shard listings in milliseconds.
+    pub fn with_shard_discovery_interval_ms(mut self, interval_ms: u64) -> Self {
+        self.config.shard_discovery_interval_ms = interval_ms;
+        self
+    }
+
+    /// Set the payload field holding the element ID.
+    pub fn with_id_field(mut self, field: impl Into<String>) -> Self {
+        self.config.id_field = field.into();
+        self
+    }
+
+    /// Set the payload field holding the change operation.
+    pub fn with_operation_field(mut self, field: impl Into<String>) -> Self {
+        self.config.operation_field = field.into();
+        self
+    }
+
+    /// Set the payload field holding the node label.
+    pub fn with_label_field(mut self, field: impl Into<String>) -> Self {
+        self.config.label_field = Some(field.into());
+        self
+    }
+
+    /// Set the label for nodes from this source.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.config.label = Some(label.into());
+        self
+    }
+
+    /// Set the dispatch mode for this source.
+    pub fn with_dispatch_mode(mut self, mode: DispatchMode) -> Self {
+        self.dispatch_mode = Some(mode);
+        self
+    }
+
+    /// Set the dispatch buffer capacity.
+    pub fn with_dispatch_buffer_capacity(mut self, capacity: usize) -> Self {
+        self.dispatch_buffer_capacity = Some(capacity);
+        self
+    }
+
+    /// Set the bootstrap provider for initial data delivery.
+    pub fn with_bootstrap_provider(
+        mut self,
+        provider: impl drasi_lib::bootstrap::BootstrapProvider + 'static,
+    ) -> Self {
+        self.bootstrap_provider = Some(Box::new(provider));
+        self
+    }
+
+    /// Set whether this source should auto-start when DrasiLib starts.
+    pub fn with_auto_start(mut self, auto_start: bool) -> Self {
+        self.auto_start = auto_start;
+        self
+    }
+
+    /// Set the full configuration at once.
+    pub fn with_config(mut self, config: KinesisSourceConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Build the Kinesis source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid or the base source
+    /// cannot be initialized.
+    pub fn build(self) -> Result<KinesisSource> {
+        self.config.validate()?;
+
+        let mut params = SourceBaseParams::new(&self.id).with_auto_start(self.auto_start);
+        if let Some(mode) = self.dispatch_mode {
+            params = params.with_dispatch_mode(mode);
+        }
+        if let Some(capacity) = self.dispatch_buffer_capacity {
+            params = params.with_dispatch_buffer_capacity(capacity);
+        }
+        if let Some(provider) = self.bootstrap_provider {
+            params = params.with_bootstrap_provider(provider);
+        }
+
+        Ok(KinesisSource {
+            base: SourceBase::new(params)?,
+            config: self.config,
+        })
+    }
+}
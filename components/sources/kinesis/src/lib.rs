@@ -0,0 +1,100 @@
+#![allow(unexpected_cfgs)]
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! AWS Kinesis Source Plugin for drasi-lib.
+//!
+//! This plugin reads JSON change records from an AWS Kinesis data stream and
+//! turns each record into a node change.
+//!
+//! # Record Mapping
+//!
+//! | Data field | Maps to |
+//! |------------|---------|
+//! | `id_field` (default `id`) | Element ID |
+//! | `operation_field` (default `op`) | `insert`/`i`, `update`/`u` or `delete`/`d`; missing means insert |
+//! | `label_field` (optional) | Node label, overriding `label` |
+//! | all other fields | Node properties |
+//!
+//! Without `label_field` or `label`, the stream name is used as the label.
+//!
+//! # Shards and Checkpoints
+//!
+//! Each shard is read by its own task. Shards are listed every
+//! `shard_discovery_interval_ms`, and a child shard created by resharding is
+//! only read after its parents have been read to their end, so records of a
+//! partition key stay in order.
+//!
+//! Each change is dispatched with the record's partition key as its ordering
+//! key, so on an evaluation pool the results of changes sharing a partition
+//! key are dispatched in stream order, while other keys proceed in parallel.
+//! Producers should write all records of an element with the same partition
+//! key; Kinesis does not order records across keys either.
+//!
+//! After every batch the sequence number of the last dispatched record is
+//! saved as part of the source's checkpoint, and a restarted source resumes
+//! after it. Checkpoints go to the DrasiLib checkpoint store, which keeps them
+//! in the state store unless another one is set with
+//! `DrasiLibBuilder::with_checkpoint_store`. Without one, shards start at
+//! `start_position` on every start.
+//!
+//! # Enhanced Fan-Out
+//!
+//! By default shards are polled with `GetRecords`, sharing the stream's read
+//! throughput with other consumers. Setting `consumer_name` reads through an
+//! enhanced fan-out consumer instead, which has dedicated throughput and
+//! receives records pushed over `SubscribeToShard`.
+//!
+//! # Usage Example
+//!
+//! ```rust,ignore
+//! use drasi_source_kinesis::{KinesisSource, StartPosition};
+//!
+//! let source = KinesisSource::builder("orders")
+//!     .with_stream_name("orders")
+//!     .with_region("eu-west-1")
+//!     .with_enhanced_fan_out("drasi")
+//!     .with_start_position(StartPosition::TrimHorizon)
+//!     .with_label("Order")
+//!     .build()?;
+//!
+//! drasi.add_source(source).await?;
+//! ```
+
+mod checkpoint;
+mod config;
+mod consumer;
+mod conversion;
+pub mod descriptor;
+mod kinesis;
+mod shards;
+
+#[cfg(test)]
+mod tests;
+
+pub use checkpoint::Checkpoint;
+pub use config::{KinesisSourceConfig, StartPosition};
+pub use kinesis::{KinesisSource, KinesisSourceBuilder};
+
+/// Dynamic plugin entry point.
+#[cfg(feature = "dynamic-plugin")]
+drasi_plugin_sdk::export_plugin!(
+    plugin_id = "kinesis-source",
+    core_version = env!("CARGO_PKG_VERSION"),
+    lib_version = env!("CARGO_PKG_VERSION"),
+    plugin_version = env!("CARGO_PKG_VERSION"),
+    source_descriptors = [descriptor::KinesisSourceDescriptor],
+    reaction_descriptors = [],
+    bootstrap_descriptors = [],
+);
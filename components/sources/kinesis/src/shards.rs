@@ -0,0 +1,148 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Shard discovery and lineage tracking.
+//!
+//! Resharding closes a shard and creates one or two child shards. To keep the
+//! records of a key in order, a child is only read once all of its parents
+//! have been read to their end.
+
+use anyhow::{anyhow, Result};
+use aws_sdk_kinesis::error::DisplayErrorContext;
+use aws_sdk_kinesis::types::ShardIteratorType;
+use aws_sdk_kinesis::Client;
+use std::collections::HashSet;
+
+use crate::checkpoint::Checkpoint;
+use crate::config::StartPosition;
+
+/// A shard and the shards it was split from or merged from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ShardInfo {
+    pub shard_id: String,
+    pub parent_shard_id: Option<String>,
+    pub adjacent_parent_shard_id: Option<String>,
+}
+
+impl ShardInfo {
+    fn parents(&self) -> impl Iterator<Item = &str> {
+        self.parent_shard_id
+            .iter()
+            .chain(&self.adjacent_parent_shard_id)
+            .map(String::as_str)
+    }
+}
+
+/// Which shards are being read and which have been read to their end.
+#[derive(Debug, Default)]
+pub(crate) struct ShardTracker {
+    started: HashSet<String>,
+    finished: HashSet<String>,
+}
+
+impl ShardTracker {
+    /// Shards of the current listing that can be started now.
+    ///
+    /// A shard is ready if it has not been started and each of its parents is
+    /// finished or no longer listed, because its records have expired.
+    pub(crate) fn ready<'a>(&self, shards: &'a [ShardInfo]) -> Vec<&'a ShardInfo> {
+        let listed: HashSet<&str> = shards.iter().map(|s| s.shard_id.as_str()).collect();
+        shards
+            .iter()
+            .filter(|shard| !self.started.contains(&shard.shard_id))
+            .filter(|shard| {
+                shard
+                    .parents()
+                    .all(|parent| self.finished.contains(parent) || !listed.contains(parent))
+            })
+            .collect()
+    }
+
+    pub(crate) fn mark_started(&mut self, shard_id: &str) {
+        self.started.insert(shard_id.to_string());
+    }
+
+    pub(crate) fn mark_finished(&mut self, shard_id: &str) {
+        self.finished.insert(shard_id.to_string());
+    }
+}
+
+/// List all shards of a stream, following pagination.
+///
+/// # Errors
+///
+/// Returns an error if the stream does not exist or the call is denied.
+pub(crate) async fn list_shards(client: &Client, stream_name: &str) -> Result<Vec<ShardInfo>> {
+    let mut shards = Vec::new();
+    let mut next_token: Option<String> = None;
+
+    loop {
+        // The stream name must not be sent together with a pagination token
+        let request = match &next_token {
+            Some(token) => client.list_shards().next_token(token),
+            None => client.list_shards().stream_name(stream_name),
+        };
+        let output = request.send().await.map_err(|e| {
+            anyhow!(
+                "Failed to list shards of stream '{stream_name}': {}",
+                DisplayErrorContext(e)
+            )
+        })?;
+
+        shards.extend(output.shards().iter().map(|shard| ShardInfo {
+            shard_id: shard.shard_id().to_string(),
+            parent_shard_id: shard.parent_shard_id().map(str::to_string),
+            adjacent_parent_shard_id: shard.adjacent_parent_shard_id().map(str::to_string),
+        }));
+
+        match output.next_token() {
+            Some(token) => next_token = Some(token.to_string()),
+            None => return Ok(shards),
+        }
+    }
+}
+
+/// Position to resume reading a shard from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ShardPosition {
+    /// No record has been dispatched yet; start at the configured position.
+    Start(StartPosition),
+    /// Continue after the record with this sequence number.
+    After(String),
+}
+
+impl ShardPosition {
+    /// Position of a shard given its stored checkpoint.
+    pub(crate) fn resume(checkpoint: Option<&Checkpoint>, start: StartPosition) -> Self {
+        match checkpoint.and_then(|c| c.sequence_number.clone()) {
+            Some(sequence_number) => Self::After(sequence_number),
+            None => Self::Start(start),
+        }
+    }
+
+    pub(crate) fn iterator_type(&self) -> ShardIteratorType {
+        match self {
+            Self::Start(StartPosition::TrimHorizon) => ShardIteratorType::TrimHorizon,
+            Self::Start(StartPosition::Latest) => ShardIteratorType::Latest,
+            Self::After(_) => ShardIteratorType::AfterSequenceNumber,
+        }
+    }
+
+    pub(crate) fn sequence_number(&self) -> Option<&str> {
+        match self {
+            Self::After(sequence_number) => Some(sequence_number),
+            Self::Start(_) => None,
+        }
+    }
+}
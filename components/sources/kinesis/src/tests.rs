@@ -0,0 +1,349 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for the AWS Kinesis source plugin.
+
+use super::*;
+use crate::conversion::record_to_source_change;
+use crate::shards::{ShardInfo, ShardPosition, ShardTracker};
+use aws_sdk_kinesis::types::ShardIteratorType;
+use drasi_core::models::{Element, ElementValue, SourceChange};
+use drasi_lib::channels::SourceEvent;
+use drasi_lib::Source;
+use drasi_plugin_sdk::prelude::SourcePluginDescriptor;
+use serde_json::json;
+use std::sync::Arc;
+
+fn config() -> KinesisSourceConfig {
+    KinesisSourceConfig {
+        stream_name: "orders".to_string(),
+        ..Default::default()
+    }
+}
+
+fn payload(value: serde_json::Value) -> Vec<u8> {
+    serde_json::to_vec(&value).unwrap()
+}
+
+fn shard(id: &str, parent: Option<&str>, adjacent_parent: Option<&str>) -> ShardInfo {
+    ShardInfo {
+        shard_id: id.to_string(),
+        parent_shard_id: parent.map(str::to_string),
+        adjacent_parent_shard_id: adjacent_parent.map(str::to_string),
+    }
+}
+
+fn ready_ids(tracker: &ShardTracker, shards: &[ShardInfo]) -> Vec<String> {
+    tracker
+        .ready(shards)
+        .into_iter()
+        .map(|s| s.shard_id.clone())
+        .collect()
+}
+
+mod builder {
+    use super::*;
+
+    #[test]
+    fn test_builder_with_valid_config() {
+        let source = KinesisSource::builder("test-source")
+            .with_stream_name("orders")
+            .with_region("eu-west-1")
+            .with_enhanced_fan_out("drasi")
+            .with_start_position(StartPosition::TrimHorizon)
+            .with_max_records(500)
+            .build()
+            .unwrap();
+
+        assert_eq!(source.id(), "test-source");
+        assert_eq!(source.type_name(), "kinesis");
+        let props = source.properties();
+        assert_eq!(props.get("streamName"), Some(&json!("orders")));
+        assert_eq!(props.get("region"), Some(&json!("eu-west-1")));
+        assert_eq!(props.get("consumerName"), Some(&json!("drasi")));
+        assert_eq!(props.get("startPosition"), Some(&json!("trim_horizon")));
+        assert_eq!(props.get("maxRecords"), Some(&json!(500)));
+    }
+
+    #[test]
+    fn test_builder_requires_stream_name() {
+        assert!(KinesisSource::builder("test-source").build().is_err());
+    }
+
+    #[test]
+    fn test_config_rejects_invalid_values() {
+        for max_records in [0, 10001] {
+            let config = KinesisSourceConfig {
+                max_records,
+                ..config()
+            };
+            assert!(config.validate().is_err());
+        }
+
+        let empty_consumer = KinesisSourceConfig {
+            consumer_name: Some(String::new()),
+            ..config()
+        };
+        assert!(empty_consumer.validate().is_err());
+
+        let zero_discovery = KinesisSourceConfig {
+            shard_discovery_interval_ms: 0,
+            ..config()
+        };
+        assert!(zero_discovery.validate().is_err());
+    }
+
+    #[test]
+    fn test_config_deserialization_defaults() {
+        let config: KinesisSourceConfig =
+            serde_json::from_value(json!({"stream_name": "orders"})).unwrap();
+        assert_eq!(config.start_position, StartPosition::Latest);
+        assert_eq!(config.poll_interval_ms, 1000);
+        assert_eq!(config.max_records, 1000);
+        assert_eq!(config.shard_discovery_interval_ms, 30000);
+        assert_eq!(config.consumer_name, None);
+    }
+}
+
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn test_record_without_operation_is_insert() {
+        let change = record_to_source_change(
+            "src",
+            "orders",
+            &payload(json!({"id": "o-1", "total": 12.5})),
+            &config(),
+        )
+        .unwrap();
+
+        let SourceChange::Insert {
+            element:
+                Element::Node {
+                    metadata,
+                    properties,
+                },
+        } = change
+        else {
+            panic!("expected node insert");
+        };
+        assert_eq!(metadata.reference.element_id.as_ref(), "o-1");
+        assert_eq!(metadata.labels[0].as_ref(), "orders");
+        assert_eq!(
+            properties.get("total"),
+            Some(&ElementValue::Float(12.5.into()))
+        );
+    }
+
+    #[test]
+    fn test_operations_and_labels() {
+        let config = KinesisSourceConfig {
+            label_field: Some("type".to_string()),
+            label: Some("Order".to_string()),
+            ..config()
+        };
+
+        let change = record_to_source_change(
+            "src",
+            "orders",
+            &payload(json!({"id": 1, "op": "u", "type": "Refund"})),
+            &config,
+        )
+        .unwrap();
+        let SourceChange::Update { element } = change else {
+            panic!("expected update");
+        };
+        assert_eq!(element.get_metadata().labels[0].as_ref(), "Refund");
+
+        let change = record_to_source_change(
+            "src",
+            "orders",
+            &payload(json!({"id": 1, "op": "d"})),
+            &config,
+        )
+        .unwrap();
+        let SourceChange::Delete { metadata } = change else {
+            panic!("expected delete");
+        };
+        assert_eq!(metadata.labels[0].as_ref(), "Order");
+    }
+
+    #[test]
+    fn test_invalid_records_fail() {
+        let config = config();
+        assert!(record_to_source_change("src", "orders", b"not json", &config).is_err());
+        assert!(
+            record_to_source_change("src", "orders", &payload(json!({"total": 1})), &config)
+                .is_err()
+        );
+        assert!(record_to_source_change(
+            "src",
+            "orders",
+            &payload(json!({"id": "1", "op": "upsert"})),
+            &config
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_changes_are_keyed_by_partition_key() {
+        let change =
+            record_to_source_change("src", "orders", &payload(json!({"id": "o-1"})), &config())
+                .unwrap();
+
+        let event = KinesisSource::change_event("src", change, "customer-7");
+        assert_eq!(event.ordering_key.as_deref(), Some("customer-7"));
+        assert!(matches!(event.event, SourceEvent::Change(_)));
+    }
+}
+
+mod shards {
+    use super::*;
+
+    #[test]
+    fn test_children_wait_for_parents() {
+        let shards = vec![
+            shard("shard-0", None, None),
+            shard("shard-1", None, None),
+            shard("shard-2", Some("shard-0"), None),
+            shard("shard-3", Some("shard-0"), Some("shard-1")),
+        ];
+        let mut tracker = ShardTracker::default();
+
+        assert_eq!(ready_ids(&tracker, &shards), vec!["shard-0", "shard-1"]);
+        tracker.mark_started("shard-0");
+        tracker.mark_started("shard-1");
+        assert!(ready_ids(&tracker, &shards).is_empty());
+
+        tracker.mark_finished("shard-0");
+        assert_eq!(ready_ids(&tracker, &shards), vec!["shard-2"]);
+
+        tracker.mark_finished("shard-1");
+        assert_eq!(ready_ids(&tracker, &shards), vec!["shard-2", "shard-3"]);
+    }
+
+    #[test]
+    fn test_expired_parents_do_not_block() {
+        let shards = vec![shard("shard-5", Some("shard-1"), Some("shard-2"))];
+        assert_eq!(
+            ready_ids(&ShardTracker::default(), &shards),
+            vec!["shard-5"]
+        );
+    }
+
+    #[test]
+    fn test_resume_position() {
+        let position = ShardPosition::resume(None, StartPosition::TrimHorizon);
+        assert_eq!(position.iterator_type(), ShardIteratorType::TrimHorizon);
+        assert_eq!(position.sequence_number(), None);
+
+        let checkpoint = Checkpoint {
+            sequence_number: Some("4959".to_string()),
+            finished: false,
+        };
+        let position = ShardPosition::resume(Some(&checkpoint), StartPosition::Latest);
+        assert_eq!(
+            position.iterator_type(),
+            ShardIteratorType::AfterSequenceNumber
+        );
+        assert_eq!(position.sequence_number(), Some("4959"));
+    }
+
+    #[tokio::test]
+    async fn test_shard_checkpoints_roundtrip() {
+        use crate::checkpoint::ShardCheckpoints;
+        use drasi_lib::sources::base::{SourceBase, SourceBaseParams};
+
+        let base = SourceBase::new(SourceBaseParams::new("src")).unwrap();
+        let (update_tx, _update_rx) = tokio::sync::mpsc::channel(16);
+        base.initialize(drasi_lib::context::SourceRuntimeContext::new(
+            "test-instance",
+            "src",
+            Some(Arc::new(drasi_lib::MemoryStateStoreProvider::new())),
+            update_tx,
+            None,
+        ))
+        .await;
+
+        let checkpoints = ShardCheckpoints::load(base.clone_shared(), "orders")
+            .await
+            .unwrap();
+        assert_eq!(checkpoints.get("shard-0").await, None);
+
+        let checkpoint = Checkpoint {
+            sequence_number: Some("4959".to_string()),
+            finished: true,
+        };
+        checkpoints
+            .save("shard-0", checkpoint.clone())
+            .await
+            .unwrap();
+
+        // A restarted source sees the checkpoint, but only for the same stream
+        let reloaded = ShardCheckpoints::load(base.clone_shared(), "orders")
+            .await
+            .unwrap();
+        assert_eq!(reloaded.get("shard-0").await, Some(checkpoint));
+        assert_eq!(reloaded.get("shard-1").await, None);
+        let other_stream = ShardCheckpoints::load(base, "payments").await.unwrap();
+        assert_eq!(other_stream.get("shard-0").await, None);
+    }
+}
+
+mod descriptor {
+    use super::*;
+    use crate::descriptor::KinesisSourceDescriptor;
+
+    #[tokio::test]
+    async fn test_descriptor_creates_source() {
+        let descriptor = KinesisSourceDescriptor;
+        assert_eq!(descriptor.kind(), "kinesis");
+
+        let source = descriptor
+            .create_source(
+                "kinesis-1",
+                &json!({
+                    "streamName": "orders",
+                    "endpointUrl": "http://localhost:4566",
+                    "startPosition": "trim_horizon",
+                    "maxRecords": 100
+                }),
+                false,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(source.id(), "kinesis-1");
+        assert!(!source.auto_start());
+        let props = source.properties();
+        assert_eq!(
+            props.get("endpointUrl"),
+            Some(&json!("http://localhost:4566"))
+        );
+        assert_eq!(props.get("maxRecords"), Some(&json!(100)));
+    }
+
+    #[tokio::test]
+    async fn test_descriptor_rejects_unknown_fields() {
+        let result = KinesisSourceDescriptor
+            .create_source(
+                "kinesis-1",
+                &json!({"streamName": "orders", "bogus": 1}),
+                true,
+            )
+            .await;
+        assert!(result.is_err());
+    }
+}
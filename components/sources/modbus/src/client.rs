@@ -0,0 +1,92 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Modbus TCP connection and reads.
+
+use anyhow::{anyhow, Result};
+use std::time::Duration;
+use tokio_modbus::client::Context;
+use tokio_modbus::prelude::*;
+
+use crate::config::{ModbusSourceConfig, RegisterType};
+use crate::registers::{BlockData, ReadBlock};
+
+/// Connect to the configured endpoint.
+pub(crate) async fn connect(config: &ModbusSourceConfig) -> Result<Context> {
+    let addr = format!("{}:{}", config.host, config.port);
+    let timeout = Duration::from_millis(config.timeout_ms);
+
+    let socket_addr = tokio::net::lookup_host(&addr)
+        .await
+        .map_err(|e| anyhow!("Failed to resolve {addr}: {e}"))?
+        .next()
+        .ok_or_else(|| anyhow!("Failed to resolve {addr}: no addresses"))?;
+
+    tokio::time::timeout(timeout, tcp::connect(socket_addr))
+        .await
+        .map_err(|_| anyhow!("Timed out connecting to {addr}"))?
+        .map_err(|e| anyhow!("Failed to connect to {addr}: {e}"))
+}
+
+/// Read one block.
+///
+/// The outer error is a transport failure or timeout, after which the
+/// connection should be re-established. The inner error is an exception
+/// returned by the device, such as an illegal data address.
+pub(crate) async fn read_block(
+    context: &mut Context,
+    block: &ReadBlock,
+    timeout: Duration,
+) -> Result<std::result::Result<BlockData, ExceptionCode>> {
+    context.set_slave(Slave(block.unit_id));
+
+    let (start, count) = (block.start, block.count);
+    let request = async {
+        match block.register_type {
+            RegisterType::Coil => context
+                .read_coils(start, count)
+                .await
+                .map(|result| result.map(BlockData::Bits)),
+            RegisterType::DiscreteInput => context
+                .read_discrete_inputs(start, count)
+                .await
+                .map(|result| result.map(BlockData::Bits)),
+            RegisterType::HoldingRegister => context
+                .read_holding_registers(start, count)
+                .await
+                .map(|result| result.map(BlockData::Words)),
+            RegisterType::InputRegister => context
+                .read_input_registers(start, count)
+                .await
+                .map(|result| result.map(BlockData::Words)),
+        }
+    };
+
+    tokio::time::timeout(timeout, request)
+        .await
+        .map_err(|_| {
+            anyhow!(
+                "Timed out reading {count} {:?} from {start} on unit {}",
+                block.register_type,
+                block.unit_id
+            )
+        })?
+        .map_err(|e| {
+            anyhow!(
+                "Failed to read {count} {:?} from {start} on unit {}: {e}",
+                block.register_type,
+                block.unit_id
+            )
+        })
+}
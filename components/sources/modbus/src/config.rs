@@ -0,0 +1,419 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration types for the Modbus source plugin.
+//!
+//! This module defines the Modbus TCP endpoint, the polling timing, and the
+//! register map that turns register values into device node properties.
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+fn default_port() -> u16 {
+    502
+}
+
+fn default_unit_id() -> u8 {
+    1
+}
+
+fn default_scale() -> f64 {
+    1.0
+}
+
+fn default_poll_interval_ms() -> u64 {
+    1000
+}
+
+fn default_timeout_ms() -> u64 {
+    3000
+}
+
+fn default_reconnect_interval_ms() -> u64 {
+    5000
+}
+
+/// Modbus data table a value is read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RegisterType {
+    /// Read/write single bits (function code 1).
+    Coil,
+    /// Read-only single bits (function code 2).
+    DiscreteInput,
+    /// Read/write 16-bit registers (function code 3).
+    HoldingRegister,
+    /// Read-only 16-bit registers (function code 4).
+    InputRegister,
+}
+
+impl RegisterType {
+    /// Whether the table holds single bits rather than 16-bit registers.
+    pub fn is_bit(self) -> bool {
+        matches!(self, Self::Coil | Self::DiscreteInput)
+    }
+}
+
+/// How register contents are interpreted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DataType {
+    /// A bit, or a register that is `true` when non-zero.
+    Bool,
+    U16,
+    I16,
+    U32,
+    I32,
+    U64,
+    I64,
+    F32,
+    F64,
+}
+
+impl DataType {
+    /// Number of 16-bit registers the type spans.
+    pub fn register_count(self) -> u16 {
+        match self {
+            Self::Bool | Self::U16 | Self::I16 => 1,
+            Self::U32 | Self::I32 | Self::F32 => 2,
+            Self::U64 | Self::I64 | Self::F64 => 4,
+        }
+    }
+}
+
+/// Order of the registers of values spanning more than one register. Bytes
+/// within a register are always big-endian, as Modbus specifies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum WordOrder {
+    /// Most significant register first.
+    #[default]
+    BigEndian,
+    /// Least significant register first ("word swapped").
+    LittleEndian,
+}
+
+/// A register, or run of registers, mapped onto a device node property.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RegisterMapping {
+    /// Property that holds the value.
+    pub property: String,
+
+    /// Table the value is read from.
+    pub register_type: RegisterType,
+
+    /// Zero-based address of the (first) register.
+    pub address: u16,
+
+    /// How the value is decoded. Defaults to `bool` for coils and discrete
+    /// inputs and to `u16` for registers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_type: Option<DataType>,
+
+    /// Register order of multi-register values.
+    ///
+    /// **Default**: `big_endian`
+    #[serde(default)]
+    pub word_order: WordOrder,
+
+    /// Factor the raw value is multiplied by.
+    ///
+    /// **Default**: `1.0`
+    #[serde(default = "default_scale")]
+    pub scale: f64,
+
+    /// Amount added after scaling.
+    ///
+    /// **Default**: `0.0`
+    #[serde(default)]
+    pub offset: f64,
+}
+
+impl RegisterMapping {
+    /// Create a mapping with the default data type and no scaling.
+    pub fn new(property: impl Into<String>, register_type: RegisterType, address: u16) -> Self {
+        Self {
+            property: property.into(),
+            register_type,
+            address,
+            data_type: None,
+            word_order: WordOrder::default(),
+            scale: default_scale(),
+            offset: 0.0,
+        }
+    }
+
+    /// Set how the value is decoded.
+    pub fn with_data_type(mut self, data_type: DataType) -> Self {
+        self.data_type = Some(data_type);
+        self
+    }
+
+    /// Set the register order of multi-register values.
+    pub fn with_word_order(mut self, word_order: WordOrder) -> Self {
+        self.word_order = word_order;
+        self
+    }
+
+    /// Convert the raw value to `raw * scale + offset`.
+    pub fn with_scaling(mut self, scale: f64, offset: f64) -> Self {
+        self.scale = scale;
+        self.offset = offset;
+        self
+    }
+
+    /// Data type the value is decoded as.
+    pub fn value_type(&self) -> DataType {
+        self.data_type.unwrap_or(if self.register_type.is_bit() {
+            DataType::Bool
+        } else {
+            DataType::U16
+        })
+    }
+
+    /// Number of coils or registers the value spans.
+    pub fn width(&self) -> u16 {
+        if self.register_type.is_bit() {
+            1
+        } else {
+            self.value_type().register_count()
+        }
+    }
+
+    /// Whether the raw value is scaled or offset.
+    pub fn is_scaled(&self) -> bool {
+        self.scale != 1.0 || self.offset != 0.0
+    }
+}
+
+/// A Modbus device (unit) represented as one graph node.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ModbusDevice {
+    /// ID of the graph node.
+    pub element_id: String,
+
+    /// Modbus unit identifier. Gateways use it to address devices behind
+    /// them; most stand-alone devices accept any value.
+    ///
+    /// **Default**: `1`
+    #[serde(default = "default_unit_id")]
+    pub unit_id: u8,
+
+    /// Label of the graph node. Falls back to the source-wide `label`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+
+    /// Registers mapped onto properties of the node.
+    pub registers: Vec<RegisterMapping>,
+}
+
+impl ModbusDevice {
+    /// Create a device without registers.
+    pub fn new(element_id: impl Into<String>, unit_id: u8) -> Self {
+        Self {
+            element_id: element_id.into(),
+            unit_id,
+            label: None,
+            registers: Vec::new(),
+        }
+    }
+
+    /// Set the label of the device node.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Add a register mapping.
+    pub fn with_register(mut self, register: RegisterMapping) -> Self {
+        self.registers.push(register);
+        self
+    }
+}
+
+/// Modbus source configuration.
+///
+/// The source connects to one Modbus TCP endpoint, a device or a gateway, and
+/// reads the registers of every device each `poll_interval_ms`.
+///
+/// # Example
+///
+/// ```rust
+/// use drasi_source_modbus::{
+///     DataType, ModbusDevice, ModbusSourceConfig, RegisterMapping, RegisterType,
+/// };
+///
+/// let config = ModbusSourceConfig {
+///     host: "192.168.1.50".to_string(),
+///     devices: vec![ModbusDevice::new("meter-1", 1).with_register(
+///         RegisterMapping::new("power_kw", RegisterType::InputRegister, 12)
+///             .with_data_type(DataType::F32),
+///     )],
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ModbusSourceConfig {
+    /// Host name or IP address of the device or gateway.
+    pub host: String,
+
+    /// Modbus TCP port.
+    ///
+    /// **Default**: `502`
+    #[serde(default = "default_port")]
+    pub port: u16,
+
+    /// Devices to poll.
+    pub devices: Vec<ModbusDevice>,
+
+    /// Milliseconds between polls.
+    ///
+    /// **Default**: `1000`
+    #[serde(default = "default_poll_interval_ms")]
+    pub poll_interval_ms: u64,
+
+    /// Milliseconds to wait for a connection or a response.
+    ///
+    /// **Default**: `3000`
+    #[serde(default = "default_timeout_ms")]
+    pub timeout_ms: u64,
+
+    /// Milliseconds to wait before reconnecting after the connection is lost.
+    ///
+    /// **Default**: `5000`
+    #[serde(default = "default_reconnect_interval_ms")]
+    pub reconnect_interval_ms: u64,
+
+    /// Label for device nodes that set no label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+impl Default for ModbusSourceConfig {
+    fn default() -> Self {
+        Self {
+            host: String::new(),
+            port: default_port(),
+            devices: Vec::new(),
+            poll_interval_ms: default_poll_interval_ms(),
+            timeout_ms: default_timeout_ms(),
+            reconnect_interval_ms: default_reconnect_interval_ms(),
+            label: None,
+        }
+    }
+}
+
+impl ModbusSourceConfig {
+    /// Validate the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `host` is empty
+    /// - `devices` is empty, a device has no registers, or an element ID is
+    ///   empty or repeated
+    /// - a property is empty or mapped twice on one device
+    /// - a coil or discrete input is not decoded as `bool`, or a `bool` is scaled
+    /// - a register run extends past address 65535
+    /// - `scale` or `offset` is not finite
+    /// - an interval or `timeout_ms` is zero
+    pub fn validate(&self) -> Result<()> {
+        if self.host.is_empty() {
+            return Err(anyhow!("Validation error: host cannot be empty"));
+        }
+
+        if self.devices.is_empty() {
+            return Err(anyhow!("Validation error: at least one device is required"));
+        }
+
+        let mut element_ids = HashSet::new();
+        for device in &self.devices {
+            if device.element_id.is_empty() {
+                return Err(anyhow!("Validation error: element_id cannot be empty"));
+            }
+            if !element_ids.insert(device.element_id.as_str()) {
+                return Err(anyhow!(
+                    "Validation error: element_id '{}' is used by more than one device",
+                    device.element_id
+                ));
+            }
+            if device.registers.is_empty() {
+                return Err(anyhow!(
+                    "Validation error: device '{}' needs at least one register",
+                    device.element_id
+                ));
+            }
+
+            let mut properties = HashSet::new();
+            for register in &device.registers {
+                Self::validate_register(&device.element_id, register)?;
+                if !properties.insert(register.property.as_str()) {
+                    return Err(anyhow!(
+                        "Validation error: property '{}' of device '{}' is mapped more than once",
+                        register.property,
+                        device.element_id
+                    ));
+                }
+            }
+        }
+
+        if self.poll_interval_ms == 0 || self.timeout_ms == 0 || self.reconnect_interval_ms == 0 {
+            return Err(anyhow!(
+                "Validation error: poll_interval_ms, timeout_ms and reconnect_interval_ms must be greater than 0"
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn validate_register(element_id: &str, register: &RegisterMapping) -> Result<()> {
+        if register.property.is_empty() {
+            return Err(anyhow!(
+                "Validation error: a register of device '{element_id}' has an empty property"
+            ));
+        }
+
+        let data_type = register.value_type();
+        if register.register_type.is_bit() && data_type != DataType::Bool {
+            return Err(anyhow!(
+                "Validation error: property '{}' of device '{element_id}' reads a bit and must use data_type bool",
+                register.property
+            ));
+        }
+
+        if data_type == DataType::Bool && register.is_scaled() {
+            return Err(anyhow!(
+                "Validation error: property '{}' of device '{element_id}' is bool and cannot be scaled",
+                register.property
+            ));
+        }
+
+        if !register.scale.is_finite() || !register.offset.is_finite() {
+            return Err(anyhow!(
+                "Validation error: scale and offset of property '{}' of device '{element_id}' must be finite",
+                register.property
+            ));
+        }
+
+        if u32::from(register.address) + u32::from(register.width()) > 65536 {
+            return Err(anyhow!(
+                "Validation error: property '{}' of device '{element_id}' extends past address 65535",
+                register.property
+            ));
+        }
+
+        Ok(())
+    }
+}
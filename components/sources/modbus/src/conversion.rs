@@ -0,0 +1,93 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion of polled register values into Drasi source changes.
+
+use drasi_core::models::{Element, ElementMetadata, ElementReference, SourceChange};
+use drasi_lib::sources::convert_json_to_element_properties;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::config::{ModbusDevice, ModbusSourceConfig};
+
+/// Label used when neither the device nor the source sets one.
+const FALLBACK_LABEL: &str = "ModbusDevice";
+
+/// Resolve the label of a device node.
+pub(crate) fn label_for_device(device: &ModbusDevice, config: &ModbusSourceConfig) -> String {
+    device
+        .label
+        .clone()
+        .or_else(|| config.label.clone())
+        .unwrap_or_else(|| FALLBACK_LABEL.to_string())
+}
+
+/// Last dispatched properties of every device node.
+#[derive(Debug, Default)]
+pub(crate) struct DeviceCache {
+    devices: HashMap<String, Map<String, Value>>,
+}
+
+impl DeviceCache {
+    /// Compare polled values with the last dispatched properties of a device.
+    ///
+    /// Returns `None` if nothing changed. Otherwise returns an insert the
+    /// first time the device is seen and an update afterwards, together with
+    /// the properties to [`store`](Self::store) once the change is dispatched.
+    pub(crate) fn change(
+        &self,
+        source_id: &str,
+        element_id: &str,
+        label: &str,
+        values: Map<String, Value>,
+    ) -> Option<(SourceChange, Map<String, Value>)> {
+        let previous = self.devices.get(element_id);
+        let properties = match previous {
+            Some(previous) => {
+                if values
+                    .iter()
+                    .all(|(name, value)| previous.get(name) == Some(value))
+                {
+                    return None;
+                }
+                let mut properties = previous.clone();
+                properties.extend(values);
+                properties
+            }
+            None => values,
+        };
+
+        let element = Element::Node {
+            metadata: ElementMetadata {
+                reference: ElementReference::new(source_id, element_id),
+                labels: Arc::from(vec![Arc::from(label)]),
+                effective_from: chrono::Utc::now().timestamp_millis() as u64,
+            },
+            properties: convert_json_to_element_properties(&properties),
+        };
+
+        let change = if previous.is_some() {
+            SourceChange::Update { element }
+        } else {
+            SourceChange::Insert { element }
+        };
+        Some((change, properties))
+    }
+
+    /// Record the properties of a dispatched change.
+    pub(crate) fn store(&mut self, element_id: &str, properties: Map<String, Value>) {
+        self.devices.insert(element_id.to_string(), properties);
+    }
+}
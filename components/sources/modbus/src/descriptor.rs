@@ -0,0 +1,298 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Modbus source plugin descriptor and configuration DTOs.
+
+use crate::{
+    DataType, ModbusDevice, ModbusSourceBuilder, ModbusSourceConfig, RegisterMapping, RegisterType,
+    WordOrder,
+};
+use drasi_plugin_sdk::prelude::*;
+use utoipa::OpenApi;
+
+/// Register type DTO.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, utoipa::ToSchema)]
+#[schema(as = source::modbus::RegisterType)]
+#[serde(rename_all = "snake_case")]
+pub enum RegisterTypeDto {
+    Coil,
+    DiscreteInput,
+    HoldingRegister,
+    InputRegister,
+}
+
+impl From<RegisterTypeDto> for RegisterType {
+    fn from(dto: RegisterTypeDto) -> Self {
+        match dto {
+            RegisterTypeDto::Coil => RegisterType::Coil,
+            RegisterTypeDto::DiscreteInput => RegisterType::DiscreteInput,
+            RegisterTypeDto::HoldingRegister => RegisterType::HoldingRegister,
+            RegisterTypeDto::InputRegister => RegisterType::InputRegister,
+        }
+    }
+}
+
+impl From<RegisterType> for RegisterTypeDto {
+    fn from(register_type: RegisterType) -> Self {
+        match register_type {
+            RegisterType::Coil => RegisterTypeDto::Coil,
+            RegisterType::DiscreteInput => RegisterTypeDto::DiscreteInput,
+            RegisterType::HoldingRegister => RegisterTypeDto::HoldingRegister,
+            RegisterType::InputRegister => RegisterTypeDto::InputRegister,
+        }
+    }
+}
+
+/// Data type DTO.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, utoipa::ToSchema)]
+#[schema(as = source::modbus::DataType)]
+#[serde(rename_all = "snake_case")]
+pub enum DataTypeDto {
+    Bool,
+    U16,
+    I16,
+    U32,
+    I32,
+    U64,
+    I64,
+    F32,
+    F64,
+}
+
+impl From<DataTypeDto> for DataType {
+    fn from(dto: DataTypeDto) -> Self {
+        match dto {
+            DataTypeDto::Bool => DataType::Bool,
+            DataTypeDto::U16 => DataType::U16,
+            DataTypeDto::I16 => DataType::I16,
+            DataTypeDto::U32 => DataType::U32,
+            DataTypeDto::I32 => DataType::I32,
+            DataTypeDto::U64 => DataType::U64,
+            DataTypeDto::I64 => DataType::I64,
+            DataTypeDto::F32 => DataType::F32,
+            DataTypeDto::F64 => DataType::F64,
+        }
+    }
+}
+
+impl From<DataType> for DataTypeDto {
+    fn from(data_type: DataType) -> Self {
+        match data_type {
+            DataType::Bool => DataTypeDto::Bool,
+            DataType::U16 => DataTypeDto::U16,
+            DataType::I16 => DataTypeDto::I16,
+            DataType::U32 => DataTypeDto::U32,
+            DataType::I32 => DataTypeDto::I32,
+            DataType::U64 => DataTypeDto::U64,
+            DataType::I64 => DataTypeDto::I64,
+            DataType::F32 => DataTypeDto::F32,
+            DataType::F64 => DataTypeDto::F64,
+        }
+    }
+}
+
+/// Word order DTO.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, utoipa::ToSchema)]
+#[schema(as = source::modbus::WordOrder)]
+#[serde(rename_all = "snake_case")]
+pub enum WordOrderDto {
+    #[default]
+    BigEndian,
+    LittleEndian,
+}
+
+impl From<WordOrderDto> for WordOrder {
+    fn from(dto: WordOrderDto) -> Self {
+        match dto {
+            WordOrderDto::BigEndian => WordOrder::BigEndian,
+            WordOrderDto::LittleEndian => WordOrder::LittleEndian,
+        }
+    }
+}
+
+impl From<WordOrder> for WordOrderDto {
+    fn from(word_order: WordOrder) -> Self {
+        match word_order {
+            WordOrder::BigEndian => WordOrderDto::BigEndian,
+            WordOrder::LittleEndian => WordOrderDto::LittleEndian,
+        }
+    }
+}
+
+/// Register mapping DTO.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::modbus::RegisterMapping)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RegisterMappingDto {
+    pub property: String,
+    #[schema(value_type = source::modbus::RegisterType)]
+    pub register_type: RegisterTypeDto,
+    pub address: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<source::modbus::DataType>)]
+    pub data_type: Option<DataTypeDto>,
+    #[serde(default)]
+    #[schema(value_type = source::modbus::WordOrder)]
+    pub word_order: WordOrderDto,
+    #[serde(default = "default_scale")]
+    pub scale: f64,
+    #[serde(default)]
+    pub offset: f64,
+}
+
+/// Modbus device DTO.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::modbus::ModbusDevice)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ModbusDeviceDto {
+    pub element_id: String,
+    #[serde(default = "default_unit_id")]
+    pub unit_id: u8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[schema(value_type = Vec<source::modbus::RegisterMapping>)]
+    pub registers: Vec<RegisterMappingDto>,
+}
+
+/// Modbus source configuration DTO.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::modbus::ModbusSourceConfig)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ModbusSourceConfigDto {
+    pub host: ConfigValue<String>,
+    #[serde(default = "default_port")]
+    pub port: ConfigValue<u16>,
+    #[schema(value_type = Vec<source::modbus::ModbusDevice>)]
+    pub devices: Vec<ModbusDeviceDto>,
+    #[serde(default = "default_poll_interval_ms")]
+    pub poll_interval_ms: ConfigValue<u64>,
+    #[serde(default = "default_timeout_ms")]
+    pub timeout_ms: ConfigValue<u64>,
+    #[serde(default = "default_reconnect_interval_ms")]
+    pub reconnect_interval_ms: ConfigValue<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+fn default_scale() -> f64 {
+    1.0
+}
+
+fn default_unit_id() -> u8 {
+    1
+}
+
+fn default_port() -> ConfigValue<u16> {
+    ConfigValue::Static(502)
+}
+
+fn default_poll_interval_ms() -> ConfigValue<u64> {
+    ConfigValue::Static(1000)
+}
+
+fn default_timeout_ms() -> ConfigValue<u64> {
+    ConfigValue::Static(3000)
+}
+
+fn default_reconnect_interval_ms() -> ConfigValue<u64> {
+    ConfigValue::Static(5000)
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(
+    ModbusSourceConfigDto,
+    ModbusDeviceDto,
+    RegisterMappingDto,
+    RegisterTypeDto,
+    DataTypeDto,
+    WordOrderDto
+)))]
+struct ModbusSourceSchemas;
+
+/// Descriptor for the Modbus source plugin.
+pub struct ModbusSourceDescriptor;
+
+#[async_trait]
+impl SourcePluginDescriptor for ModbusSourceDescriptor {
+    fn kind(&self) -> &str {
+        "modbus"
+    }
+
+    fn config_version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn config_schema_name(&self) -> &str {
+        "source.modbus.ModbusSourceConfig"
+    }
+
+    fn config_schema_json(&self) -> String {
+        let api = ModbusSourceSchemas::openapi();
+        serde_json::to_string(
+            &api.components
+                .as_ref()
+                .expect("OpenAPI components missing")
+                .schemas,
+        )
+        .expect("Failed to serialize config schema")
+    }
+
+    async fn create_source(
+        &self,
+        id: &str,
+        config_json: &serde_json::Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn drasi_lib::sources::Source>> {
+        let dto: ModbusSourceConfigDto = serde_json::from_value(config_json.clone())?;
+        let mapper = DtoMapper::new();
+
+        let config = ModbusSourceConfig {
+            host: mapper.resolve_string(&dto.host)?,
+            port: mapper.resolve_typed(&dto.port)?,
+            devices: dto
+                .devices
+                .into_iter()
+                .map(|device| ModbusDevice {
+                    element_id: device.element_id,
+                    unit_id: device.unit_id,
+                    label: device.label,
+                    registers: device
+                        .registers
+                        .into_iter()
+                        .map(|register| RegisterMapping {
+                            property: register.property,
+                            register_type: register.register_type.into(),
+                            address: register.address,
+                            data_type: register.data_type.map(Into::into),
+                            word_order: register.word_order.into(),
+                            scale: register.scale,
+                            offset: register.offset,
+                        })
+                        .collect(),
+                })
+                .collect(),
+            poll_interval_ms: mapper.resolve_typed(&dto.poll_interval_ms)?,
+            timeout_ms: mapper.resolve_typed(&dto.timeout_ms)?,
+            reconnect_interval_ms: mapper.resolve_typed(&dto.reconnect_interval_ms)?,
+            label: dto.label,
+        };
+
+        let source = ModbusSourceBuilder::new(id)
+            .with_config(config)
+            .with_auto_start(auto_start)
+            .build()?;
+
+        Ok(Box::new(source))
+    }
+}
@@ -0,0 +1,94 @@
+#![allow(unexpected_cfgs)]
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Modbus TCP Source Plugin for drasi-lib.
+//!
+//! This plugin polls coils, discrete inputs, holding registers and input
+//! registers of Modbus TCP devices and writes their values to device nodes.
+//!
+//! # Register Map
+//!
+//! Each configured device becomes one graph node. Every register mapping of
+//! the device names a property, the table and address to read, and how to
+//! decode it:
+//!
+//! | Setting | Meaning |
+//! |---------|---------|
+//! | `data_type` | `bool`, `u16`, `i16`, `u32`, `i32`, `u64`, `i64`, `f32` or `f64`; spans 1, 2 or 4 registers |
+//! | `word_order` | Register order of multi-register values, `big_endian` or `little_endian` |
+//! | `scale`, `offset` | The property becomes `raw * scale + offset` |
+//!
+//! Mappings of the same unit and table with adjacent or overlapping
+//! addresses are read with a single request.
+//!
+//! # Changes
+//!
+//! A device node is inserted after its first poll and updated whenever one of
+//! its values changes; unchanged polls produce no changes. Registers the
+//! device answers with an exception are set to `null`.
+//!
+//! # Usage Example
+//!
+//! ```rust,ignore
+//! use drasi_source_modbus::{
+//!     DataType, ModbusDevice, ModbusSource, RegisterMapping, RegisterType, WordOrder,
+//! };
+//!
+//! let source = ModbusSource::builder("plant-floor")
+//!     .with_host("192.168.1.50")
+//!     .with_device(
+//!         ModbusDevice::new("meter-1", 1)
+//!             .with_label("PowerMeter")
+//!             .with_register(
+//!                 RegisterMapping::new("voltage", RegisterType::InputRegister, 0)
+//!                     .with_scaling(0.1, 0.0),
+//!             )
+//!             .with_register(
+//!                 RegisterMapping::new("energy_kwh", RegisterType::InputRegister, 10)
+//!                     .with_data_type(DataType::F32)
+//!                     .with_word_order(WordOrder::LittleEndian),
+//!             ),
+//!     )
+//!     .build()?;
+//!
+//! drasi.add_source(source).await?;
+//! ```
+
+mod client;
+mod config;
+mod conversion;
+pub mod descriptor;
+mod modbus;
+mod registers;
+
+#[cfg(test)]
+mod tests;
+
+pub use config::{
+    DataType, ModbusDevice, ModbusSourceConfig, RegisterMapping, RegisterType, WordOrder,
+};
+pub use modbus::{ModbusSource, ModbusSourceBuilder};
+
+/// Dynamic plugin entry point.
+#[cfg(feature = "dynamic-plugin")]
+drasi_plugin_sdk::export_plugin!(
+    plugin_id = "modbus-source",
+    core_version = env!("CARGO_PKG_VERSION"),
+    lib_version = env!("CARGO_PKG_VERSION"),
+    plugin_version = env!("CARGO_PKG_VERSION"),
+    source_descriptors = [descriptor::ModbusSourceDescriptor],
+    reaction_descriptors = [],
+    bootstrap_descriptors = [],
+);
@@ -0,0 +1,507 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Modbus source implementation and builder.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use log::{error, info, warn};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tokio::time::MissedTickBehavior;
+use tokio_modbus::client::Context;
+
+use drasi_core::models::SourceChange;
+use drasi_lib::channels::*;
+use drasi_lib::component_graph::ComponentStatusHandle;
+use drasi_lib::managers::{log_component_start, log_component_stop};
+use drasi_lib::sources::base::{SourceBase, SourceBaseParams};
+use drasi_lib::Source;
+use tracing::Instrument;
+
+use crate::client::{connect, read_block};
+use crate::config::{ModbusDevice, ModbusSourceConfig};
+use crate::conversion::{label_for_device, DeviceCache};
+use crate::registers::{decode, plan_reads, ReadBlock};
+
+type Dispatchers = Arc<RwLock<Vec<Box<dyn ChangeDispatcher<SourceEventWrapper> + Send + Sync>>>>;
+
+/// Source that polls Modbus TCP devices and writes register values to device
+/// nodes.
+///
+/// Each device becomes one graph node whose properties are its mapped
+/// registers. A device node is inserted after the first poll and updated
+/// whenever one of its values changes.
+pub struct ModbusSource {
+    /// Base source implementation providing dispatchers, status tracking, and lifecycle management.
+    base: SourceBase,
+    /// Modbus configuration.
+    config: ModbusSourceConfig,
+}
+
+impl ModbusSource {
+    /// Create a builder for a Modbus source.
+    pub fn builder(id: impl Into<String>) -> ModbusSourceBuilder {
+        ModbusSourceBuilder::new(id)
+    }
+
+    /// Create a new Modbus source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid.
+    pub fn new(id: impl Into<String>, config: ModbusSourceConfig) -> Result<Self> {
+        ModbusSourceBuilder::new(id).with_config(config).build()
+    }
+
+    async fn dispatch(
+        source_id: &str,
+        dispatchers: &Dispatchers,
+        change: SourceChange,
+    ) -> Result<()> {
+        let mut profiling = drasi_lib::profiling::ProfilingMetadata::new();
+        profiling.source_send_ns = Some(drasi_lib::profiling::timestamp_ns());
+
+        let wrapper = SourceEventWrapper::with_profiling(
+            source_id.to_string(),
+            SourceEvent::Change(change),
+            chrono::Utc::now(),
+            profiling,
+        );
+
+        SourceBase::dispatch_from_task(dispatchers.clone(), wrapper, source_id).await
+    }
+
+    /// Read every block and return the polled values of each device.
+    ///
+    /// Values of registers the device answers with an exception are `null`.
+    /// A transport failure aborts the poll.
+    async fn poll(
+        source_id: &str,
+        context: &mut Context,
+        config: &ModbusSourceConfig,
+        blocks: &[ReadBlock],
+    ) -> Result<Vec<Map<String, Value>>> {
+        let timeout = Duration::from_millis(config.timeout_ms);
+        let mut values = vec![Map::new(); config.devices.len()];
+
+        for block in blocks {
+            match read_block(context, block, timeout).await? {
+                Ok(data) => {
+                    for &(device, register) in &block.mappings {
+                        let mapping = &config.devices[device].registers[register];
+                        values[device].insert(
+                            mapping.property.clone(),
+                            decode(mapping, block.start, &data),
+                        );
+                    }
+                }
+                Err(exception) => {
+                    warn!(
+                        "[{source_id}] Unit {} rejected reading {} {:?} from {}: {exception}",
+                        block.unit_id, block.count, block.register_type, block.start
+                    );
+                    for &(device, register) in &block.mappings {
+                        let mapping = &config.devices[device].registers[register];
+                        values[device].insert(mapping.property.clone(), Value::Null);
+                    }
+                }
+            }
+        }
+
+        Ok(values)
+    }
+
+    /// Reconnect every `reconnect_interval_ms` until it succeeds.
+    async fn reconnect(source_id: &str, config: &ModbusSourceConfig) -> Context {
+        let interval = Duration::from_millis(config.reconnect_interval_ms);
+        loop {
+            tokio::time::sleep(interval).await;
+            match connect(config).await {
+                Ok(context) => {
+                    info!("[{source_id}] Reconnected to Modbus device");
+                    return context;
+                }
+                Err(e) => warn!("[{source_id}] Failed to reconnect to Modbus device: {e}"),
+            }
+        }
+    }
+
+    async fn run(
+        source_id: String,
+        config: ModbusSourceConfig,
+        dispatchers: Dispatchers,
+        status_handle: ComponentStatusHandle,
+    ) {
+        let mut context = match connect(&config).await {
+            Ok(context) => context,
+            Err(e) => {
+                error!("[{source_id}] Failed to connect to Modbus device: {e}");
+                status_handle
+                    .set_status(
+                        ComponentStatus::Error,
+                        Some(format!("Failed to connect to Modbus device: {e}")),
+                    )
+                    .await;
+                return;
+            }
+        };
+
+        info!(
+            "[{source_id}] Connected to Modbus device {}:{}",
+            config.host, config.port
+        );
+        status_handle
+            .set_status(
+                ComponentStatus::Running,
+                Some("Modbus source running".to_string()),
+            )
+            .await;
+
+        let blocks = plan_reads(&config.devices);
+        let labels: Vec<String> = config
+            .devices
+            .iter()
+            .map(|device| label_for_device(device, &config))
+            .collect();
+        let mut cache = DeviceCache::default();
+
+        let mut interval = tokio::time::interval(Duration::from_millis(config.poll_interval_ms));
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+
+            let values = match Self::poll(&source_id, &mut context, &config, &blocks).await {
+                Ok(values) => values,
+                Err(e) => {
+                    warn!("[{source_id}] Modbus poll failed, reconnecting: {e}");
+                    context = Self::reconnect(&source_id, &config).await;
+                    continue;
+                }
+            };
+
+            for ((device, label), values) in config.devices.iter().zip(&labels).zip(values) {
+                let Some((change, properties)) =
+                    cache.change(&source_id, &device.element_id, label, values)
+                else {
+                    continue;
+                };
+
+                // Unstored values are compared and dispatched again next poll
+                match Self::dispatch(&source_id, &dispatchers, change).await {
+                    Ok(()) => cache.store(&device.element_id, properties),
+                    Err(e) => warn!(
+                        "[{source_id}] Failed to dispatch values of device '{}': {e}",
+                        device.element_id
+                    ),
+                }
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Source for ModbusSource {
+    fn id(&self) -> &str {
+        &self.base.id
+    }
+
+    fn type_name(&self) -> &str {
+        "modbus"
+    }
+
+    fn properties(&self) -> HashMap<String, serde_json::Value> {
+        use crate::descriptor::{ModbusDeviceDto, ModbusSourceConfigDto, RegisterMappingDto};
+        use drasi_plugin_sdk::ConfigValue;
+
+        let dto = ModbusSourceConfigDto {
+            host: ConfigValue::Static(self.config.host.clone()),
+            port: ConfigValue::Static(self.config.port),
+            devices: self
+                .config
+                .devices
+                .iter()
+                .map(|device| ModbusDeviceDto {
+                    element_id: device.element_id.clone(),
+                    unit_id: device.unit_id,
+                    label: device.label.clone(),
+                    registers: device
+                        .registers
+                        .iter()
+                        .map(|register| RegisterMappingDto {
+                            property: register.property.clone(),
+                            register_type: register.register_type.into(),
+                            address: register.address,
+                            data_type: register.data_type.map(Into::into),
+                            word_order: register.word_order.into(),
+                            scale: register.scale,
+                            offset: register.offset,
+                        })
+                        .collect(),
+                })
+                .collect(),
+            poll_interval_ms: ConfigValue::Static(self.config.poll_interval_ms),
+            timeout_ms: ConfigValue::Static(self.config.timeout_ms),
+            reconnect_interval_ms: ConfigValue::Static(self.config.reconnect_interval_ms),
+            label: self.config.label.clone(),
+        };
+
+        match serde_json::to_value(&dto) {
+            Ok(serde_json::Value::Object(map)) => map.into_iter().collect(),
+            _ => HashMap::new(),
+        }
+    }
+
+    fn auto_start(&self) -> bool {
+        self.base.get_auto_start()
+    }
+
+    async fn start(&self) -> Result<()> {
+        log_component_start("Modbus Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Starting,
+                Some("Starting Modbus source".to_string()),
+            )
+            .await;
+
+        let instance_id = self
+            .base
+            .context()
+            .await
+            .map(|c| c.instance_id)
+            .unwrap_or_default();
+
+        let span = tracing::info_span!(
+            "modbus_source_poller",
+            instance_id = %instance_id,
+            component_id = %self.base.id,
+            component_type = "source"
+        );
+        let task = tokio::spawn(
+            Self::run(
+                self.base.id.clone(),
+                self.config.clone(),
+                self.base.dispatchers.clone(),
+                self.base.status_handle(),
+            )
+            .instrument(span),
+        );
+
+        *self.base.task_handle.write().await = Some(task);
+
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        log_component_stop("Modbus Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopping,
+                Some("Stopping Modbus source".to_string()),
+            )
+            .await;
+
+        if let Some(handle) = self.base.task_handle.write().await.take() {
+            handle.abort();
+            let _ = handle.await;
+        }
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopped,
+                Some("Modbus source stopped".to_string()),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn status(&self) -> ComponentStatus {
+        self.base.get_status().await
+    }
+
+    async fn subscribe(
+        &self,
+        settings: drasi_lib::config::SourceSubscriptionSettings,
+    ) -> Result<SubscriptionResponse> {
+        self.base
+            .subscribe_with_bootstrap(&settings, "Modbus")
+            .await
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    async fn initialize(&self, context: drasi_lib::context::SourceRuntimeContext) {
+        self.base.initialize(context).await;
+    }
+
+    async fn set_bootstrap_provider(
+        &self,
+        provider: Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>,
+    ) {
+        self.base.set_bootstrap_provider(provider).await;
+    }
+}
+
+/// Builder for [`ModbusSource`] instances.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use drasi_source_modbus::{
+///     DataType, ModbusDevice, ModbusSource, RegisterMapping, RegisterType,
+/// };
+///
+/// let source = ModbusSource::builder("plant-floor")
+///     .with_host("192.168.1.50")
+///     .with_device(
+///         ModbusDevice::new("meter-1", 1).with_register(
+///             RegisterMapping::new("voltage", RegisterType::InputRegister, 0)
+///                 .with_scaling(0.1, 0.0),
+///         ),
+///     )
+///     .build()?;
+/// ```
+pub struct ModbusSourceBuilder {
+    id: String,
+    config: ModbusSourceConfig,
+    dispatch_mode: Option<DispatchMode>,
+    dispatch_buffer_capacity: Option<usize>,
+    bootstrap_provider: Option<Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>>,
+    auto_start: bool,
+}
+
+impl ModbusSourceBuilder {
+    /// Create a new builder with the given ID and default values.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            config: ModbusSourceConfig::default(),
+            dispatch_mode: None,
+            dispatch_buffer_capacity: None,
+            bootstrap_provider: None,
+            auto_start: true,
+        }
+    }
+
+    /// Set the host name or IP address of the device or gateway.
+    pub fn with_host(mut self, host: impl Into<String>) -> Self {
+        self.config.host = host.into();
+        self
+    }
+
+    /// Set the Modbus TCP port.
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.config.port = port;
+        self
+    }
+
+    /// Add a device to poll.
+    pub fn with_device(mut self, device: ModbusDevice) -> Self {
+        self.config.devices.push(device);
+        self
+    }
+
+    /// Set the milliseconds between polls.
+    pub fn with_poll_interval_ms(mut self, interval_ms: u64) -> Self {
+        self.config.poll_interval_ms = interval_ms;
+        self
+    }
+
+    /// Set the milliseconds to wait for a connection or a response.
+    pub fn with_timeout_ms(mut self, timeout_ms: u64) -> Self {
+        self.config.timeout_ms = timeout_ms;
+        self
+    }
+
+    /// Set the milliseconds to wait before reconnecting.
+    pub fn with_reconnect_interval_ms(mut self, interval_ms: u64) -> Self {
+        self.config.reconnect_interval_ms = interval_ms;
+        self
+    }
+
+    /// Set the label for device nodes that set no label.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.config.label = Some(label.into());
+        self
+    }
+
+    /// Set the dispatch mode for this source.
+    pub fn with_dispatch_mode(mut self, mode: DispatchMode) -> Self {
+        self.dispatch_mode = Some(mode);
+        self
+    }
+
+    /// Set the dispatch buffer capacity.
+    pub fn with_dispatch_buffer_capacity(mut self, capacity: usize) -> Self {
+        self.dispatch_buffer_capacity = Some(capacity);
+        self
+    }
+
+    /// Set the bootstrap provider for initial data delivery.
+    pub fn with_bootstrap_provider(
+        mut self,
+        provider: impl drasi_lib::bootstrap::BootstrapProvider + 'static,
+    ) -> Self {
+        self.bootstrap_provider = Some(Box::new(provider));
+        self
+    }
+
+    /// Set whether this source should auto-start when DrasiLib starts.
+    pub fn with_auto_start(mut self, auto_start: bool) -> Self {
+        self.auto_start = auto_start;
+        self
+    }
+
+    /// Set the full configuration at once.
+    pub fn with_config(mut self, config: ModbusSourceConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Build the Modbus source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid or the base source
+    /// cannot be initialized.
+    pub fn build(self) -> Result<ModbusSource> {
+        self.config.validate()?;
+
+        let mut params = SourceBaseParams::new(&self.id).with_auto_start(self.auto_start);
+        if let Some(mode) = self.dispatch_mode {
+            params = params.with_dispatch_mode(mode);
+        }
+        if let Some(capacity) = self.dispatch_buffer_capacity {
+            params = params.with_dispatch_buffer_capacity(capacity);
+        }
+        if let Some(provider) = self.bootstrap_provider {
+            params = params.with_bootstrap_provider(provider);
+        }
+
+        Ok(ModbusSource {
+            base: SourceBase::new(params)?,
+            config: self.config,
+        })
+    }
+}
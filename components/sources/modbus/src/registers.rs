@@ -0,0 +1,155 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Read planning and decoding of register values.
+
+use serde_json::Value;
+
+use crate::config::{DataType, ModbusDevice, RegisterMapping, RegisterType, WordOrder};
+
+/// Most coils or discrete inputs one request may read.
+const MAX_BITS_PER_READ: u16 = 2000;
+
+/// Most registers one request may read.
+const MAX_REGISTERS_PER_READ: u16 = 125;
+
+/// One read request and the mappings it serves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ReadBlock {
+    pub unit_id: u8,
+    pub register_type: RegisterType,
+    pub start: u16,
+    pub count: u16,
+    /// Device and register index of every mapping within the block.
+    pub mappings: Vec<(usize, usize)>,
+}
+
+/// Data returned by a read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum BlockData {
+    Bits(Vec<bool>),
+    Words(Vec<u16>),
+}
+
+/// Group the mappings of all devices into as few reads as possible.
+///
+/// Mappings of the same unit and table share a read when their ranges touch
+/// or overlap and the combined read stays within the protocol limit. Gaps are
+/// never read, since devices often reject reads of unmapped addresses.
+pub(crate) fn plan_reads(devices: &[ModbusDevice]) -> Vec<ReadBlock> {
+    let mut entries: Vec<(u8, RegisterType, u16, u16, usize, usize)> = devices
+        .iter()
+        .enumerate()
+        .flat_map(|(device_index, device)| {
+            device
+                .registers
+                .iter()
+                .enumerate()
+                .map(move |(register_index, register)| {
+                    (
+                        device.unit_id,
+                        register.register_type,
+                        register.address,
+                        register.width(),
+                        device_index,
+                        register_index,
+                    )
+                })
+        })
+        .collect();
+    entries.sort();
+
+    let mut blocks: Vec<ReadBlock> = Vec::new();
+    for (unit_id, register_type, address, width, device_index, register_index) in entries {
+        let limit = if register_type.is_bit() {
+            MAX_BITS_PER_READ
+        } else {
+            MAX_REGISTERS_PER_READ
+        };
+
+        if let Some(block) = blocks.last_mut() {
+            let start = u32::from(block.start);
+            let end = start + u32::from(block.count);
+            let new_end = end.max(u32::from(address) + u32::from(width));
+            if block.unit_id == unit_id
+                && block.register_type == register_type
+                && u32::from(address) <= end
+                && new_end - start <= u32::from(limit)
+            {
+                block.count = (new_end - start) as u16;
+                block.mappings.push((device_index, register_index));
+                continue;
+            }
+        }
+
+        blocks.push(ReadBlock {
+            unit_id,
+            register_type,
+            start: address,
+            count: width,
+            mappings: vec![(device_index, register_index)],
+        });
+    }
+
+    blocks
+}
+
+fn float_to_json(value: f64) -> Value {
+    serde_json::Number::from_f64(value)
+        .map(Value::Number)
+        .unwrap_or(Value::Null)
+}
+
+/// Decode the value of a mapping from the data of the block starting at
+/// `block_start` that read it.
+///
+/// Integers stay integers unless the mapping is scaled, in which case the
+/// result is `raw * scale + offset` as a float. Data too short for the
+/// mapping and non-finite floats decode to `null`.
+pub(crate) fn decode(mapping: &RegisterMapping, block_start: u16, data: &BlockData) -> Value {
+    let offset = usize::from(mapping.address.saturating_sub(block_start));
+    match data {
+        BlockData::Bits(bits) => bits
+            .get(offset)
+            .map_or(Value::Null, |bit| Value::Bool(*bit)),
+        BlockData::Words(words) => words
+            .get(offset..offset + usize::from(mapping.width()))
+            .map_or(Value::Null, |words| decode_words(mapping, words)),
+    }
+}
+
+fn decode_words(mapping: &RegisterMapping, words: &[u16]) -> Value {
+    let combine = |acc: u64, word: &u16| (acc << 16) | u64::from(*word);
+    let raw = match mapping.word_order {
+        WordOrder::BigEndian => words.iter().fold(0, combine),
+        WordOrder::LittleEndian => words.iter().rev().fold(0, combine),
+    };
+
+    let value = match mapping.value_type() {
+        DataType::Bool => return Value::Bool(raw != 0),
+        DataType::U16 | DataType::U32 | DataType::U64 => Value::from(raw),
+        DataType::I16 => Value::from(raw as u16 as i16),
+        DataType::I32 => Value::from(raw as u32 as i32),
+        DataType::I64 => Value::from(raw as i64),
+        DataType::F32 => float_to_json(f64::from(f32::from_bits(raw as u32))),
+        DataType::F64 => float_to_json(f64::from_bits(raw)),
+    };
+
+    if !mapping.is_scaled() {
+        return value;
+    }
+    value.as_f64().map_or(Value::Null, |raw| {
+        float_to_json(raw * mapping.scale + mapping.offset)
+    })
+}
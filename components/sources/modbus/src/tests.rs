@@ -0,0 +1,431 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for the Modbus source plugin.
+
+use super::*;
+use crate::conversion::{label_for_device, DeviceCache};
+use crate::registers::{decode, plan_reads, BlockData, ReadBlock};
+use drasi_core::models::{Element, ElementValue, SourceChange};
+use drasi_lib::Source;
+use drasi_plugin_sdk::prelude::SourcePluginDescriptor;
+use serde_json::{json, Map, Value};
+
+fn meter() -> ModbusDevice {
+    ModbusDevice::new("meter-1", 1)
+        .with_register(RegisterMapping::new(
+            "voltage",
+            RegisterType::InputRegister,
+            0,
+        ))
+        .with_register(
+            RegisterMapping::new("power", RegisterType::InputRegister, 1)
+                .with_data_type(DataType::F32),
+        )
+}
+
+fn config() -> ModbusSourceConfig {
+    ModbusSourceConfig {
+        host: "127.0.0.1".to_string(),
+        devices: vec![meter()],
+        ..Default::default()
+    }
+}
+
+fn values(value: Value) -> Map<String, Value> {
+    match value {
+        Value::Object(map) => map,
+        _ => panic!("expected object"),
+    }
+}
+
+mod builder {
+    use super::*;
+
+    #[test]
+    fn test_builder_with_valid_config() {
+        let source = ModbusSource::builder("test-source")
+            .with_host("plc-1")
+            .with_port(5020)
+            .with_device(meter())
+            .with_poll_interval_ms(250)
+            .with_label("Meter")
+            .build()
+            .unwrap();
+
+        assert_eq!(source.id(), "test-source");
+        assert_eq!(source.type_name(), "modbus");
+        let props = source.properties();
+        assert_eq!(props.get("host"), Some(&json!("plc-1")));
+        assert_eq!(props.get("port"), Some(&json!(5020)));
+        assert_eq!(props.get("pollIntervalMs"), Some(&json!(250)));
+        assert_eq!(
+            props["devices"][0]["registers"][1],
+            json!({
+                "property": "power",
+                "registerType": "input_register",
+                "address": 1,
+                "dataType": "f32",
+                "wordOrder": "big_endian",
+                "scale": 1.0,
+                "offset": 0.0
+            })
+        );
+    }
+
+    #[test]
+    fn test_builder_requires_host_and_devices() {
+        assert!(ModbusSource::builder("test-source")
+            .with_device(meter())
+            .build()
+            .is_err());
+        assert!(ModbusSource::builder("test-source")
+            .with_host("plc-1")
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_config_rejects_invalid_register_maps() {
+        let duplicate_device = ModbusSourceConfig {
+            devices: vec![meter(), meter()],
+            ..config()
+        };
+        assert!(duplicate_device.validate().is_err());
+
+        let duplicate_property = ModbusSourceConfig {
+            devices: vec![meter().with_register(RegisterMapping::new(
+                "voltage",
+                RegisterType::HoldingRegister,
+                100,
+            ))],
+            ..config()
+        };
+        assert!(duplicate_property.validate().is_err());
+
+        let invalid_registers = [
+            RegisterMapping::new("running", RegisterType::Coil, 0).with_data_type(DataType::U16),
+            RegisterMapping::new("alarm", RegisterType::HoldingRegister, 0)
+                .with_data_type(DataType::Bool)
+                .with_scaling(2.0, 0.0),
+            RegisterMapping::new("total", RegisterType::HoldingRegister, 65535)
+                .with_data_type(DataType::U32),
+            RegisterMapping::new("level", RegisterType::HoldingRegister, 0)
+                .with_scaling(f64::NAN, 0.0),
+        ];
+        for register in invalid_registers {
+            let config = ModbusSourceConfig {
+                devices: vec![ModbusDevice::new("tank-1", 2).with_register(register.clone())],
+                ..config()
+            };
+            assert!(config.validate().is_err(), "{register:?}");
+        }
+
+        let zero_interval = ModbusSourceConfig {
+            poll_interval_ms: 0,
+            ..config()
+        };
+        assert!(zero_interval.validate().is_err());
+    }
+
+    #[test]
+    fn test_config_deserialization_defaults() {
+        let config: ModbusSourceConfig = serde_json::from_value(json!({
+            "host": "plc-1",
+            "devices": [{
+                "element_id": "tank-1",
+                "registers": [
+                    {"property": "level", "register_type": "holding_register", "address": 3},
+                    {"property": "open", "register_type": "coil", "address": 0}
+                ]
+            }]
+        }))
+        .unwrap();
+
+        assert_eq!(config.port, 502);
+        assert_eq!(config.poll_interval_ms, 1000);
+        let device = &config.devices[0];
+        assert_eq!(device.unit_id, 1);
+        assert_eq!(device.registers[0].value_type(), DataType::U16);
+        assert_eq!(device.registers[0].scale, 1.0);
+        assert_eq!(device.registers[1].value_type(), DataType::Bool);
+        assert!(config.validate().is_ok());
+    }
+}
+
+mod registers {
+    use super::*;
+
+    #[test]
+    fn test_adjacent_registers_share_a_read() {
+        let devices = vec![
+            meter().with_register(
+                RegisterMapping::new("energy", RegisterType::InputRegister, 3)
+                    .with_data_type(DataType::U64),
+            ),
+            ModbusDevice::new("meter-2", 1).with_register(RegisterMapping::new(
+                "voltage",
+                RegisterType::InputRegister,
+                7,
+            )),
+        ];
+
+        assert_eq!(
+            plan_reads(&devices),
+            vec![ReadBlock {
+                unit_id: 1,
+                register_type: RegisterType::InputRegister,
+                start: 0,
+                count: 8,
+                mappings: vec![(0, 0), (0, 1), (0, 2), (1, 0)],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_reads_split_on_gaps_units_tables_and_limits() {
+        let devices = vec![
+            ModbusDevice::new("plc", 1)
+                .with_register(RegisterMapping::new("a", RegisterType::HoldingRegister, 0))
+                .with_register(RegisterMapping::new("b", RegisterType::HoldingRegister, 5))
+                .with_register(RegisterMapping::new("c", RegisterType::InputRegister, 1))
+                .with_register(RegisterMapping::new("d", RegisterType::Coil, 0))
+                .with_register(RegisterMapping::new("e", RegisterType::Coil, 1)),
+            ModbusDevice::new("drive", 2)
+                .with_register(RegisterMapping::new("a", RegisterType::HoldingRegister, 0))
+                .with_register(
+                    RegisterMapping::new("b", RegisterType::HoldingRegister, 124)
+                        .with_data_type(DataType::U32),
+                ),
+        ];
+
+        let blocks: Vec<_> = plan_reads(&devices)
+            .into_iter()
+            .map(|b| (b.unit_id, b.register_type, b.start, b.count))
+            .collect();
+        assert_eq!(
+            blocks,
+            vec![
+                (1, RegisterType::Coil, 0, 2),
+                (1, RegisterType::HoldingRegister, 0, 1),
+                (1, RegisterType::HoldingRegister, 5, 1),
+                (1, RegisterType::InputRegister, 1, 1),
+                (2, RegisterType::HoldingRegister, 0, 1),
+                (2, RegisterType::HoldingRegister, 124, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reads_respect_protocol_limit() {
+        let device = (0..126).fold(ModbusDevice::new("plc", 1), |device, address| {
+            device.with_register(RegisterMapping::new(
+                format!("r{address}"),
+                RegisterType::HoldingRegister,
+                address,
+            ))
+        });
+
+        let blocks: Vec<_> = plan_reads(&[device])
+            .into_iter()
+            .map(|b| (b.start, b.count, b.mappings.len()))
+            .collect();
+        assert_eq!(blocks, vec![(0, 125, 125), (125, 1, 1)]);
+    }
+
+    #[test]
+    fn test_decode_integers_and_floats() {
+        let words = BlockData::Words(vec![0xFFFE, 0x4148, 0x0000, 0x0000, 0x4148]);
+        let register = |address, data_type| {
+            RegisterMapping::new("v", RegisterType::HoldingRegister, address)
+                .with_data_type(data_type)
+        };
+
+        assert_eq!(
+            decode(&register(10, DataType::U16), 10, &words),
+            json!(65534)
+        );
+        assert_eq!(decode(&register(10, DataType::I16), 10, &words), json!(-2));
+        assert_eq!(
+            decode(&register(11, DataType::F32), 10, &words),
+            json!(12.5)
+        );
+        assert_eq!(
+            decode(
+                &register(13, DataType::F32).with_word_order(WordOrder::LittleEndian),
+                10,
+                &words
+            ),
+            json!(12.5)
+        );
+        assert_eq!(decode(&register(12, DataType::U32), 10, &words), json!(0));
+        assert_eq!(
+            decode(&register(11, DataType::Bool), 10, &words),
+            json!(true)
+        );
+        assert_eq!(
+            decode(&register(13, DataType::U64), 10, &words),
+            Value::Null
+        );
+    }
+
+    #[test]
+    fn test_decode_scaling_and_bits() {
+        let words = BlockData::Words(vec![2305]);
+        let scaled = RegisterMapping::new("temperature", RegisterType::InputRegister, 0)
+            .with_scaling(0.1, -40.0);
+        let value = decode(&scaled, 0, &words).as_f64().unwrap();
+        assert!((value - 190.5).abs() < 1e-9);
+
+        let bits = BlockData::Bits(vec![false, true]);
+        let coil = RegisterMapping::new("running", RegisterType::Coil, 6);
+        assert_eq!(decode(&coil, 5, &bits), json!(true));
+    }
+}
+
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn test_device_labels() {
+        let config = config();
+        assert_eq!(label_for_device(&meter(), &config), "ModbusDevice");
+
+        let config = ModbusSourceConfig {
+            label: Some("Meter".to_string()),
+            ..config
+        };
+        assert_eq!(label_for_device(&meter(), &config), "Meter");
+        assert_eq!(
+            label_for_device(&meter().with_label("PowerMeter"), &config),
+            "PowerMeter"
+        );
+    }
+
+    #[test]
+    fn test_cache_emits_insert_then_updates_on_change() {
+        let mut cache = DeviceCache::default();
+        let first = values(json!({"voltage": 230, "power": 1.5}));
+
+        let (change, properties) = cache
+            .change("src", "meter-1", "Meter", first.clone())
+            .unwrap();
+        assert!(matches!(change, SourceChange::Insert { .. }));
+
+        // Not stored yet, so the next poll inserts again
+        assert!(matches!(
+            cache.change("src", "meter-1", "Meter", first.clone()),
+            Some((SourceChange::Insert { .. }, _))
+        ));
+
+        cache.store("meter-1", properties);
+        assert!(cache.change("src", "meter-1", "Meter", first).is_none());
+
+        let (change, _) = cache
+            .change(
+                "src",
+                "meter-1",
+                "Meter",
+                values(json!({"voltage": 231, "power": 1.5})),
+            )
+            .unwrap();
+        let SourceChange::Update {
+            element:
+                Element::Node {
+                    metadata,
+                    properties,
+                },
+        } = change
+        else {
+            panic!("expected node update");
+        };
+        assert_eq!(metadata.reference.element_id.as_ref(), "meter-1");
+        assert_eq!(metadata.labels[0].as_ref(), "Meter");
+        assert_eq!(properties.get("voltage"), Some(&ElementValue::Integer(231)));
+        assert_eq!(
+            properties.get("power"),
+            Some(&ElementValue::Float(1.5.into()))
+        );
+    }
+}
+
+mod descriptor {
+    use super::*;
+    use crate::descriptor::ModbusSourceDescriptor;
+
+    #[tokio::test]
+    async fn test_descriptor_creates_source() {
+        let descriptor = ModbusSourceDescriptor;
+        assert_eq!(descriptor.kind(), "modbus");
+
+        let source = descriptor
+            .create_source(
+                "modbus-1",
+                &json!({
+                    "host": "plc-1",
+                    "devices": [{
+                        "elementId": "tank-1",
+                        "unitId": 3,
+                        "registers": [{
+                            "property": "level",
+                            "registerType": "holding_register",
+                            "address": 100,
+                            "dataType": "i32",
+                            "wordOrder": "little_endian",
+                            "scale": 0.01
+                        }]
+                    }]
+                }),
+                false,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(source.id(), "modbus-1");
+        assert!(!source.auto_start());
+        let props = source.properties();
+        assert_eq!(props["devices"][0]["unitId"], json!(3));
+        assert_eq!(
+            props["devices"][0]["registers"][0]["dataType"],
+            json!("i32")
+        );
+        assert_eq!(
+            props["devices"][0]["registers"][0]["wordOrder"],
+            json!("little_endian")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_descriptor_rejects_unknown_fields() {
+        let result = ModbusSourceDescriptor
+            .create_source(
+                "modbus-1",
+                &json!({
+                    "host": "plc-1",
+                    "devices": [{
+                        "elementId": "tank-1",
+                        "registers": [{
+                            "property": "level",
+                            "registerType": "holding_register",
+                            "address": 100,
+                            "bogus": 1
+                        }]
+                    }]
+                }),
+                true,
+            )
+            .await;
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,224 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration types for the MongoDB source plugin.
+//!
+//! This module defines which database and collections the source watches,
+//! how change events are filtered, and how documents map onto node
+//! properties.
+
+use anyhow::{anyhow, Result};
+use mongodb::bson::{doc, Bson, Document};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+fn default_flatten_separator() -> String {
+    ".".to_string()
+}
+
+fn default_reconnect_delay_ms() -> u64 {
+    5000
+}
+
+/// Document included with update events.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FullDocumentMode {
+    /// Look up the current version of the document when the event is read.
+    /// The lookup may return a later version than the update, or nothing if
+    /// the document has been deleted since.
+    #[default]
+    UpdateLookup,
+    /// Use the post-image of the update if the collection records one
+    /// (`changeStreamPreAndPostImages`, MongoDB 6.0 and later).
+    WhenAvailable,
+    /// Like `when_available`, but the server fails the change stream if the
+    /// post-image is missing.
+    Required,
+}
+
+/// MongoDB source configuration.
+///
+/// # Example
+///
+/// ```rust
+/// use drasi_source_mongodb::MongoSourceConfig;
+///
+/// let config = MongoSourceConfig {
+///     connection_string: "mongodb://localhost:27017/?replicaSet=rs0".to_string(),
+///     database: "shop".to_string(),
+///     collections: vec!["orders".to_string(), "customers".to_string()],
+///     flatten_nested: true,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MongoSourceConfig {
+    /// MongoDB connection string. Change streams need a replica set or a
+    /// sharded cluster.
+    pub connection_string: String,
+
+    /// Database to watch.
+    pub database: String,
+
+    /// Collections to watch. Empty means every collection of the database.
+    #[serde(default)]
+    pub collections: Vec<String>,
+
+    /// Extra aggregation stages appended to the change stream pipeline, as
+    /// MongoDB Extended JSON, for example
+    /// `{"$match": {"fullDocument.status": {"$ne": "draft"}}}`. Stages must
+    /// not remove or modify `_id`, which holds the resume token.
+    #[serde(default)]
+    pub pipeline: Vec<serde_json::Value>,
+
+    /// Document included with update events.
+    ///
+    /// **Default**: `update_lookup`
+    #[serde(default)]
+    pub full_document: FullDocumentMode,
+
+    /// Flatten nested documents into top-level properties, joining field names
+    /// with `flatten_separator`. Arrays are kept as-is.
+    ///
+    /// **Default**: `false`
+    #[serde(default)]
+    pub flatten_nested: bool,
+
+    /// Separator between the field names of flattened properties.
+    ///
+    /// **Default**: `"."`
+    #[serde(default = "default_flatten_separator")]
+    pub flatten_separator: String,
+
+    /// Number of nesting levels to flatten. Documents below this depth are
+    /// kept as nested values. `None` flattens all levels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flatten_max_depth: Option<usize>,
+
+    /// Milliseconds to wait before reopening a failed change stream.
+    ///
+    /// **Default**: `5000`
+    #[serde(default = "default_reconnect_delay_ms")]
+    pub reconnect_delay_ms: u64,
+}
+
+impl Default for MongoSourceConfig {
+    fn default() -> Self {
+        Self {
+            connection_string: String::new(),
+            database: String::new(),
+            collections: Vec::new(),
+            pipeline: Vec::new(),
+            full_document: FullDocumentMode::default(),
+            flatten_nested: false,
+            flatten_separator: default_flatten_separator(),
+            flatten_max_depth: None,
+            reconnect_delay_ms: default_reconnect_delay_ms(),
+        }
+    }
+}
+
+impl MongoSourceConfig {
+    /// Aggregation pipeline of the change stream: a `$match` on the watched
+    /// collections, followed by the configured stages.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a configured stage is not a document with a single
+    /// `$` operator.
+    pub(crate) fn change_stream_pipeline(&self) -> Result<Vec<Document>> {
+        let mut pipeline = Vec::with_capacity(self.pipeline.len() + 1);
+        if !self.collections.is_empty() {
+            pipeline.push(doc! { "$match": { "ns.coll": { "$in": self.collections.clone() } } });
+        }
+        for (index, stage) in self.pipeline.iter().enumerate() {
+            match Bson::try_from(stage.clone()) {
+                Ok(Bson::Document(stage))
+                    if stage.len() == 1 && stage.keys().all(|key| key.starts_with('$')) =>
+                {
+                    pipeline.push(stage)
+                }
+                Ok(_) => {
+                    return Err(anyhow!(
+                        "pipeline stage {index} must be a document with a single $ operator"
+                    ))
+                }
+                Err(e) => return Err(anyhow!("pipeline stage {index} is invalid: {e}")),
+            }
+        }
+        Ok(pipeline)
+    }
+
+    /// Validate the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `connection_string` does not use the `mongodb` or `mongodb+srv` scheme
+    /// - `database` is empty
+    /// - a collection name is empty or listed twice
+    /// - a pipeline stage is not a document with a single `$` operator
+    /// - `flatten_separator` is empty or `flatten_max_depth` is zero
+    /// - `reconnect_delay_ms` is zero
+    pub fn validate(&self) -> Result<()> {
+        if !(self.connection_string.starts_with("mongodb://")
+            || self.connection_string.starts_with("mongodb+srv://"))
+        {
+            return Err(anyhow!(
+                "Validation error: connection_string must use the mongodb:// or mongodb+srv:// scheme"
+            ));
+        }
+
+        if self.database.is_empty() {
+            return Err(anyhow!("Validation error: database cannot be empty"));
+        }
+
+        let mut seen = HashSet::new();
+        for collection in &self.collections {
+            if collection.is_empty() {
+                return Err(anyhow!(
+                    "Validation error: collection names cannot be empty"
+                ));
+            }
+            if !seen.insert(collection.as_str()) {
+                return Err(anyhow!(
+                    "Validation error: collection '{collection}' is listed more than once"
+                ));
+            }
+        }
+
+        self.change_stream_pipeline()
+            .map_err(|e| anyhow!("Validation error: {e}"))?;
+
+        if self.flatten_separator.is_empty() {
+            return Err(anyhow!(
+                "Validation error: flatten_separator cannot be empty"
+            ));
+        }
+        if self.flatten_max_depth == Some(0) {
+            return Err(anyhow!(
+                "Validation error: flatten_max_depth must be greater than 0"
+            ));
+        }
+
+        if self.reconnect_delay_ms == 0 {
+            return Err(anyhow!(
+                "Validation error: reconnect_delay_ms must be greater than 0"
+            ));
+        }
+
+        Ok(())
+    }
+}
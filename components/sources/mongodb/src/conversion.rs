@@ -0,0 +1,173 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion of change stream events into Drasi source changes.
+
+use anyhow::{anyhow, Result};
+use drasi_core::models::{Element, ElementMetadata, ElementReference, SourceChange};
+use drasi_lib::sources::convert_json_to_element_properties;
+use mongodb::bson::{Bson, Document};
+use mongodb::change_stream::event::{ChangeStreamEvent, OperationType};
+use serde_json::{Map, Value};
+use std::sync::Arc;
+
+use crate::config::MongoSourceConfig;
+
+/// Convert a BSON value to JSON.
+///
+/// Object IDs become hex strings, dates RFC 3339 strings and decimals their
+/// string form. Other types without a JSON equivalent use relaxed Extended
+/// JSON.
+pub(crate) fn bson_to_json(value: &Bson) -> Value {
+    match value {
+        Bson::Null | Bson::Undefined => Value::Null,
+        Bson::Boolean(b) => Value::Bool(*b),
+        Bson::Int32(i) => Value::from(*i),
+        Bson::Int64(i) => Value::from(*i),
+        Bson::Double(f) => serde_json::Number::from_f64(*f)
+            .map(Value::Number)
+            .unwrap_or(Value::Null),
+        Bson::String(s) | Bson::Symbol(s) => Value::String(s.clone()),
+        Bson::ObjectId(oid) => Value::String(oid.to_hex()),
+        Bson::DateTime(dt) => dt
+            .try_to_rfc3339_string()
+            .map(Value::String)
+            .unwrap_or_else(|_| Value::from(dt.timestamp_millis())),
+        Bson::Decimal128(d) => Value::String(d.to_string()),
+        Bson::Array(values) => Value::Array(values.iter().map(bson_to_json).collect()),
+        Bson::Document(document) => Value::Object(
+            document
+                .iter()
+                .map(|(key, value)| (key.clone(), bson_to_json(value)))
+                .collect(),
+        ),
+        other => other.clone().into_relaxed_extjson(),
+    }
+}
+
+/// String form of a document `_id`, used in element IDs.
+pub(crate) fn id_to_string(id: &Bson) -> String {
+    match id {
+        Bson::String(s) => s.clone(),
+        Bson::ObjectId(oid) => oid.to_hex(),
+        Bson::Int32(i) => i.to_string(),
+        Bson::Int64(i) => i.to_string(),
+        other => bson_to_json(other).to_string(),
+    }
+}
+
+fn insert_property(
+    properties: &mut Map<String, Value>,
+    name: String,
+    value: &Bson,
+    config: &MongoSourceConfig,
+    depth: usize,
+) {
+    match value {
+        Bson::Document(nested)
+            if config.flatten_nested
+                && !nested.is_empty()
+                && config.flatten_max_depth.is_none_or(|max| depth < max) =>
+        {
+            for (key, value) in nested {
+                let name = format!("{name}{}{key}", config.flatten_separator);
+                insert_property(properties, name, value, config, depth + 1);
+            }
+        }
+        _ => {
+            properties.insert(name, bson_to_json(value));
+        }
+    }
+}
+
+/// Node properties of a document, flattened as configured.
+pub(crate) fn document_properties(
+    document: &Document,
+    config: &MongoSourceConfig,
+) -> Map<String, Value> {
+    let mut properties = Map::new();
+    for (key, value) in document {
+        insert_property(&mut properties, key.clone(), value, config, 0);
+    }
+    properties
+}
+
+/// Element ID of a document: `<collection>:<_id>`.
+pub(crate) fn element_id(collection: &str, id: &Bson) -> String {
+    format!("{collection}:{}", id_to_string(id))
+}
+
+/// Convert a change stream event into a [`SourceChange`].
+///
+/// Returns `None` for events that do not change a document, such as `drop`
+/// or `rename`.
+///
+/// # Errors
+///
+/// Returns an error if the event has no document key, or an insert, update
+/// or replace event has no full document.
+pub(crate) fn event_to_source_change(
+    source_id: &str,
+    config: &MongoSourceConfig,
+    event: &ChangeStreamEvent<Document>,
+) -> Result<Option<SourceChange>> {
+    if !matches!(
+        event.operation_type,
+        OperationType::Insert
+            | OperationType::Update
+            | OperationType::Replace
+            | OperationType::Delete
+    ) {
+        return Ok(None);
+    }
+
+    let Some(collection) = event.ns.as_ref().and_then(|ns| ns.coll.as_deref()) else {
+        return Err(anyhow!("Change event has no collection"));
+    };
+    let id = event
+        .document_key
+        .as_ref()
+        .and_then(|key| key.get("_id"))
+        .ok_or_else(|| anyhow!("Change event in '{collection}' has no document key"))?;
+
+    let metadata = ElementMetadata {
+        reference: ElementReference::new(source_id, &element_id(collection, id)),
+        labels: Arc::from(vec![Arc::from(collection)]),
+        effective_from: event
+            .cluster_time
+            .map(|time| u64::from(time.time) * 1000)
+            .unwrap_or_else(|| chrono::Utc::now().timestamp_millis() as u64),
+    };
+
+    if event.operation_type == OperationType::Delete {
+        return Ok(Some(SourceChange::Delete { metadata }));
+    }
+
+    let document = event.full_document.as_ref().ok_or_else(|| {
+        anyhow!(
+            "{:?} event for '{}' has no full document",
+            event.operation_type,
+            metadata.reference.element_id
+        )
+    })?;
+    let element = Element::Node {
+        metadata,
+        properties: convert_json_to_element_properties(&document_properties(document, config)),
+    };
+
+    Ok(Some(match event.operation_type {
+        OperationType::Insert => SourceChange::Insert { element },
+        _ => SourceChange::Update { element },
+    }))
+}
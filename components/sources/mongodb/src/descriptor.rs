@@ -0,0 +1,145 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! MongoDB source plugin descriptor and configuration DTOs.
+
+use crate::{FullDocumentMode, MongoSourceBuilder, MongoSourceConfig};
+use drasi_plugin_sdk::prelude::*;
+use utoipa::OpenApi;
+
+/// Full document mode DTO.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::mongodb::FullDocumentMode)]
+#[serde(rename_all = "snake_case")]
+pub enum FullDocumentModeDto {
+    #[default]
+    UpdateLookup,
+    WhenAvailable,
+    Required,
+}
+
+impl From<FullDocumentModeDto> for FullDocumentMode {
+    fn from(dto: FullDocumentModeDto) -> Self {
+        match dto {
+            FullDocumentModeDto::UpdateLookup => FullDocumentMode::UpdateLookup,
+            FullDocumentModeDto::WhenAvailable => FullDocumentMode::WhenAvailable,
+            FullDocumentModeDto::Required => FullDocumentMode::Required,
+        }
+    }
+}
+
+impl From<FullDocumentMode> for FullDocumentModeDto {
+    fn from(mode: FullDocumentMode) -> Self {
+        match mode {
+            FullDocumentMode::UpdateLookup => FullDocumentModeDto::UpdateLookup,
+            FullDocumentMode::WhenAvailable => FullDocumentModeDto::WhenAvailable,
+            FullDocumentMode::Required => FullDocumentModeDto::Required,
+        }
+    }
+}
+
+/// MongoDB source configuration DTO.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::mongodb::MongoSourceConfig)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct MongoSourceConfigDto {
+    pub connection_string: ConfigValue<String>,
+    pub database: ConfigValue<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub collections: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schema(value_type = Vec<Object>)]
+    pub pipeline: Vec<serde_json::Value>,
+    #[serde(default)]
+    #[schema(value_type = source::mongodb::FullDocumentMode)]
+    pub full_document: FullDocumentModeDto,
+    #[serde(default)]
+    pub flatten_nested: bool,
+    #[serde(default = "default_flatten_separator")]
+    pub flatten_separator: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flatten_max_depth: Option<usize>,
+    #[serde(default = "default_reconnect_delay_ms")]
+    pub reconnect_delay_ms: ConfigValue<u64>,
+}
+
+fn default_flatten_separator() -> String {
+    ".".to_string()
+}
+
+fn default_reconnect_delay_ms() -> ConfigValue<u64> {
+    ConfigValue::Static(5000)
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(MongoSourceConfigDto, FullDocumentModeDto)))]
+struct MongoSourceSchemas;
+
+/// Descriptor for the MongoDB source plugin.
+pub struct MongoSourceDescriptor;
+
+#[async_trait]
+impl SourcePluginDescriptor for MongoSourceDescriptor {
+    fn kind(&self) -> &str {
+        "mongodb"
+    }
+
+    fn config_version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn config_schema_name(&self) -> &str {
+        "source.mongodb.MongoSourceConfig"
+    }
+
+    fn config_schema_json(&self) -> String {
+        let api = MongoSourceSchemas::openapi();
+        serde_json::to_string(
+            &api.components
+                .as_ref()
+                .expect("OpenAPI components missing")
+                .schemas,
+        )
+        .expect("Failed to serialize config schema")
+    }
+
+    async fn create_source(
+        &self,
+        id: &str,
+        config_json: &serde_json::Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn drasi_lib::sources::Source>> {
+        let dto: MongoSourceConfigDto = serde_json::from_value(config_json.clone())?;
+        let mapper = DtoMapper::new();
+
+        let config = MongoSourceConfig {
+            connection_string: mapper.resolve_string(&dto.connection_string)?,
+            database: mapper.resolve_string(&dto.database)?,
+            collections: dto.collections,
+            pipeline: dto.pipeline,
+            full_document: dto.full_document.into(),
+            flatten_nested: dto.flatten_nested,
+            flatten_separator: dto.flatten_separator,
+            flatten_max_depth: dto.flatten_max_depth,
+            reconnect_delay_ms: mapper.resolve_typed(&dto.reconnect_delay_ms)?,
+        };
+
+        let source = MongoSourceBuilder::new(id)
+            .with_config(config)
+            .with_auto_start(auto_start)
+            .build()?;
+
+        Ok(Box::new(source))
+    }
+}
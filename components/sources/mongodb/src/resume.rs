@@ -0,0 +1,50 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Resume token persistence for the MongoDB source.
+//!
+//! The token of the last processed event is saved as the source's checkpoint,
+//! so a restarted source continues the change stream where it stopped. The
+//! point is stored as raw BSON, which keeps the token's types intact.
+
+use anyhow::Result;
+use drasi_lib::sources::base::SourceBase;
+use mongodb::bson;
+use mongodb::change_stream::event::ResumeToken;
+use serde::{Deserialize, Serialize};
+
+/// Position to reopen the change stream from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ResumePoint {
+    /// Token of the last processed event.
+    pub token: ResumeToken,
+    /// Whether the token is of an `invalidate` event, which can only be
+    /// resumed with `startAfter`.
+    #[serde(default)]
+    pub start_after: bool,
+}
+
+/// Load the saved resume point, or `None` if there is none.
+pub(crate) async fn load(base: &SourceBase) -> Result<Option<ResumePoint>> {
+    match base.load_checkpoint::<Vec<u8>>().await? {
+        Some(bytes) => Ok(Some(bson::from_slice(&bytes)?)),
+        None => Ok(None),
+    }
+}
+
+/// Save a resume point as the source's checkpoint.
+pub(crate) async fn save(base: &SourceBase, point: &ResumePoint) -> Result<()> {
+    base.save_checkpoint(&bson::to_vec(point)?).await
+}
@@ -93,7 +93,6 @@ use anyhow::Result;
 use async_trait::async_trait;
 use drasi_lib::sources::base::{SourceBase, SourceBaseParams};
 use drasi_lib::sources::Source;
-use drasi_lib::state_store::StateStoreProvider;
 use std::sync::Arc;
 use tokio::sync::watch;
 use tokio::sync::RwLock;
@@ -111,9 +110,6 @@ pub struct MsSqlSource {
     /// Base source implementation (handles dispatching, status, etc.)
     base: SourceBase,
 
-    /// State store for LSN persistence
-    state_store: Arc<RwLock<Option<Arc<dyn StateStoreProvider>>>>,
-
     /// CDC polling task handle
     task_handle: Arc<RwLock<Option<tokio::task::JoinHandle<()>>>>,
 
@@ -143,7 +139,6 @@ impl MsSqlSource {
             source_id,
             config,
             base: SourceBase::new(params)?,
-            state_store: Arc::new(RwLock::new(None)),
             task_handle: Arc::new(RwLock::new(None)),
             shutdown_tx,
             shutdown_rx,
@@ -264,19 +259,14 @@ impl Source for MsSqlSource {
         let config = self.config.clone();
         let source_id = self.base.id.clone();
         let dispatchers = self.base.dispatchers.clone();
-        let state_store = self.state_store.read().await.clone();
+        let base = self.base.clone_shared();
         let shutdown_rx = self.shutdown_rx.clone();
 
         // Spawn CDC polling task
         let task_handle = tokio::spawn(async move {
-            if let Err(e) = stream::run_cdc_stream(
-                source_id.clone(),
-                config,
-                dispatchers,
-                state_store,
-                shutdown_rx,
-            )
-            .await
+            if let Err(e) =
+                stream::run_cdc_stream(source_id.clone(), config, dispatchers, base, shutdown_rx)
+                    .await
             {
                 log::error!("CDC stream task failed for {source_id}: {e}");
             }
@@ -336,13 +326,7 @@ impl Source for MsSqlSource {
     }
 
     async fn initialize(&self, context: drasi_lib::context::SourceRuntimeContext) {
-        self.base.initialize(context.clone()).await;
-
-        // Store state store if provided
-        if let Some(state_store) = context.state_store {
-            *self.state_store.write().await = Some(state_store);
-            log::debug!("State store injected into MS SQL source '{}'", self.base.id);
-        }
+        self.base.initialize(context).await;
     }
 
     async fn set_bootstrap_provider(
@@ -447,7 +431,7 @@ impl MsSqlSourceBuilder {
         self
     }
 
-    /// Set the starting position when no LSN is found in state store
+    /// Set the starting position when no LSN checkpoint has been saved
     pub fn with_start_position(mut self, position: StartPosition) -> Self {
         self.config.start_position = position;
         self
@@ -492,7 +476,6 @@ impl MsSqlSourceBuilder {
             source_id,
             config: self.config,
             base: SourceBase::new(params)?,
-            state_store: Arc::new(RwLock::new(None)),
             task_handle: Arc::new(RwLock::new(None)),
             shutdown_tx,
             shutdown_rx,
@@ -24,6 +24,7 @@ use crate::types::extract_properties_from_cdc_row;
 use anyhow::{anyhow, Result};
 use drasi_core::models::{Element, ElementMetadata, ElementReference, SourceChange};
 use drasi_lib::channels::{ChangeDispatcher, SourceEventWrapper};
+use drasi_lib::sources::base::SourceBase;
 use log::{debug, error, info, warn};
 use std::sync::Arc;
 use std::time::Duration;
@@ -50,7 +51,7 @@ pub async fn run_cdc_stream(
     source_id: String,
     config: MsSqlSourceConfig,
     dispatchers: Arc<RwLock<Vec<Box<dyn ChangeDispatcher<SourceEventWrapper> + Send + Sync>>>>,
-    state_store: Option<Arc<dyn drasi_lib::state_store::StateStoreProvider>>,
+    base: SourceBase,
     mut shutdown_rx: watch::Receiver<bool>,
 ) -> Result<()> {
     info!("Starting CDC stream for source '{source_id}'");
@@ -65,14 +66,7 @@ pub async fn run_cdc_stream(
             return Ok(());
         }
 
-        match run_cdc_polling_loop(
-            &source_id,
-            &config,
-            &dispatchers,
-            &state_store,
-            &mut shutdown_rx,
-        )
-        .await
+        match run_cdc_polling_loop(&source_id, &config, &dispatchers, &base, &mut shutdown_rx).await
         {
             Ok(()) => {
                 // Normal exit (loop was shutdown or exited gracefully)
@@ -136,7 +130,7 @@ async fn run_cdc_polling_loop(
     source_id: &str,
     config: &MsSqlSourceConfig,
     dispatchers: &Arc<RwLock<Vec<Box<dyn ChangeDispatcher<SourceEventWrapper> + Send + Sync>>>>,
-    state_store: &Option<Arc<dyn drasi_lib::state_store::StateStoreProvider>>,
+    base: &SourceBase,
     shutdown_rx: &mut watch::Receiver<bool>,
 ) -> Result<()> {
     // Connect to MS SQL
@@ -149,8 +143,8 @@ async fn run_cdc_polling_loop(
     pk_cache.discover_keys(client, config).await?;
     info!("Discovered primary keys for {} tables", config.tables.len());
 
-    // Load last LSN checkpoint from StateStore
-    let mut current_lsn = load_checkpoint(source_id, state_store).await?;
+    // Load last LSN checkpoint
+    let mut current_lsn = load_checkpoint(base).await?;
     info!(
         "Starting CDC from LSN: {}",
         current_lsn
@@ -200,7 +194,7 @@ async fn run_cdc_polling_loop(
                     }
 
                     if let Some(ref lsn) = current_lsn {
-                        save_checkpoint(source_id, lsn, state_store).await?;
+                        save_checkpoint(base, lsn).await?;
                     }
                 }
             }
@@ -215,7 +209,7 @@ async fn run_cdc_polling_loop(
                     }
                     MsSqlErrorKind::RecoverableLsn => {
                         warn!("LSN error detected, clearing checkpoint and restarting from current position");
-                        clear_checkpoint(source_id, state_store).await?;
+                        clear_checkpoint(base).await?;
                         current_lsn = None;
                         consecutive_errors = 0; // Reset since we handled this
                     }
@@ -585,53 +579,50 @@ fn extract_lsn(row: &tiberius::Row) -> Result<Lsn> {
     Lsn::from_bytes(lsn_bytes)
 }
 
-/// Load LSN checkpoint from StateStore
-async fn load_checkpoint(
-    source_id: &str,
-    state_store: &Option<Arc<dyn drasi_lib::state_store::StateStoreProvider>>,
-) -> Result<Option<Lsn>> {
-    if let Some(store) = state_store {
-        let key = "checkpoint.lsn";
-        if let Some(bytes) = store.get(source_id, key).await? {
-            match Lsn::from_bytes(&bytes) {
-                Ok(lsn) => {
-                    info!("Loaded checkpoint LSN: {}", lsn.to_hex());
-                    return Ok(Some(lsn));
-                }
-                Err(e) => {
-                    warn!("Failed to parse stored LSN: {e}, starting fresh");
-                }
-            }
+/// State store key under which earlier versions kept the LSN checkpoint
+const LEGACY_CHECKPOINT_KEY: &str = "checkpoint.lsn";
+
+/// Load the LSN checkpoint saved through the source's checkpoint store
+///
+/// Falls back to the raw LSN that earlier versions kept in the state store, so
+/// an upgraded source resumes where it stopped.
+async fn load_checkpoint(base: &SourceBase) -> Result<Option<Lsn>> {
+    let stored = match base.load_checkpoint::<String>().await? {
+        Some(hex) => Lsn::from_hex(&hex),
+        None => match base.state_store().await {
+            Some(store) => match store.get(&base.id, LEGACY_CHECKPOINT_KEY).await? {
+                Some(bytes) => Lsn::from_bytes(&bytes),
+                None => return Ok(None),
+            },
+            None => return Ok(None),
+        },
+    };
+    match stored {
+        Ok(lsn) => {
+            info!("Loaded checkpoint LSN: {}", lsn.to_hex());
+            Ok(Some(lsn))
+        }
+        Err(e) => {
+            warn!("Failed to parse stored LSN: {e}, starting fresh");
+            Ok(None)
         }
     }
-    Ok(None)
 }
 
-/// Save LSN checkpoint to StateStore
-async fn save_checkpoint(
-    source_id: &str,
-    lsn: &Lsn,
-    state_store: &Option<Arc<dyn drasi_lib::state_store::StateStoreProvider>>,
-) -> Result<()> {
-    if let Some(store) = state_store {
-        let key = "checkpoint.lsn";
-        let bytes = lsn.to_bytes();
-        store.set(source_id, key, bytes).await?;
-        debug!("Saved checkpoint LSN: {}", lsn.to_hex());
-    }
+/// Save the LSN checkpoint through the source's checkpoint store
+async fn save_checkpoint(base: &SourceBase, lsn: &Lsn) -> Result<()> {
+    base.save_checkpoint(&lsn.to_hex()).await?;
+    debug!("Saved checkpoint LSN: {}", lsn.to_hex());
     Ok(())
 }
 
-/// Clear LSN checkpoint from StateStore
-async fn clear_checkpoint(
-    source_id: &str,
-    state_store: &Option<Arc<dyn drasi_lib::state_store::StateStoreProvider>>,
-) -> Result<()> {
-    if let Some(store) = state_store {
-        let key = "checkpoint.lsn";
-        store.delete(source_id, key).await?;
-        info!("Cleared checkpoint LSN");
+/// Clear the LSN checkpoint, including one left by earlier versions
+async fn clear_checkpoint(base: &SourceBase) -> Result<()> {
+    base.clear_checkpoint().await?;
+    if let Some(store) = base.state_store().await {
+        store.delete(&base.id, LEGACY_CHECKPOINT_KEY).await?;
     }
+    info!("Cleared checkpoint LSN");
     Ok(())
 }
 
@@ -646,10 +637,38 @@ mod tests {
         assert_eq!(capture_instance, "dbo_orders");
     }
 
-    #[test]
-    fn test_checkpoint_key_format() {
-        let key = "checkpoint.lsn";
-        assert_eq!(key, "checkpoint.lsn");
+    #[tokio::test]
+    async fn test_checkpoint_roundtrip_and_legacy_fallback() {
+        use drasi_lib::sources::base::SourceBaseParams;
+        use drasi_lib::state_store::{MemoryStateStoreProvider, StateStoreProvider};
+
+        let base = SourceBase::new(SourceBaseParams::new("mssql-source")).unwrap();
+        let store: Arc<dyn StateStoreProvider> = Arc::new(MemoryStateStoreProvider::new());
+        let (update_tx, _update_rx) = tokio::sync::mpsc::channel(16);
+        base.initialize(drasi_lib::context::SourceRuntimeContext::new(
+            "test-instance",
+            "mssql-source",
+            Some(store.clone()),
+            update_tx,
+            None,
+        ))
+        .await;
+        assert_eq!(load_checkpoint(&base).await.unwrap(), None);
+
+        // A checkpoint written by an earlier version is still picked up
+        let legacy = Lsn::from_hex("0x00000027000001F00003").unwrap();
+        store
+            .set("mssql-source", LEGACY_CHECKPOINT_KEY, legacy.to_bytes())
+            .await
+            .unwrap();
+        assert_eq!(load_checkpoint(&base).await.unwrap(), Some(legacy));
+
+        let lsn = Lsn::from_hex("0x00000028000002A00001").unwrap();
+        save_checkpoint(&base, &lsn).await.unwrap();
+        assert_eq!(load_checkpoint(&base).await.unwrap(), Some(lsn));
+
+        clear_checkpoint(&base).await.unwrap();
+        assert_eq!(load_checkpoint(&base).await.unwrap(), None);
     }
 
     #[test]
@@ -0,0 +1,209 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration types for the NATS source plugin.
+//!
+//! This module defines how the source connects to NATS, which subjects it
+//! consumes, and how message payloads are mapped onto graph nodes.
+
+use serde::{Deserialize, Serialize};
+
+fn default_url() -> String {
+    "nats://localhost:4222".to_string()
+}
+
+fn default_ack_wait_secs() -> u64 {
+    30
+}
+
+fn default_id_field() -> String {
+    "id".to_string()
+}
+
+fn default_operation_field() -> String {
+    "op".to_string()
+}
+
+/// How the source consumes messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum NatsMode {
+    /// Core NATS subscriptions. At-most-once: messages published while the
+    /// source is stopped are not seen.
+    #[default]
+    Core,
+    /// JetStream durable pull consumer. At-least-once: messages are acked after
+    /// dispatch and redelivered if the source stops before acknowledging them.
+    JetStream,
+}
+
+/// Maps a subject pattern to a node label.
+///
+/// Patterns use NATS wildcards: `*` matches one token and `>` matches one or
+/// more trailing tokens (e.g. `orders.*.created`, `inventory.>`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubjectLabelMapping {
+    /// Subject pattern
+    pub subject: String,
+    /// Label applied to nodes from matching subjects
+    pub label: String,
+}
+
+/// NATS source configuration.
+///
+/// # Example
+///
+/// ```rust
+/// use drasi_source_nats::{NatsMode, NatsSourceConfig, SubjectLabelMapping};
+///
+/// let config = NatsSourceConfig {
+///     subjects: vec!["orders.>".to_string()],
+///     mode: NatsMode::JetStream,
+///     stream: Some("ORDERS".to_string()),
+///     label_mappings: vec![SubjectLabelMapping {
+///         subject: "orders.>".to_string(),
+///         label: "Order".to_string(),
+///     }],
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NatsSourceConfig {
+    /// NATS server URL.
+    ///
+    /// **Default**: `"nats://localhost:4222"`
+    #[serde(default = "default_url")]
+    pub url: String,
+
+    /// Subjects to consume. Wildcards are allowed.
+    pub subjects: Vec<String>,
+
+    /// Consumption mode.
+    ///
+    /// **Default**: `core`
+    #[serde(default)]
+    pub mode: NatsMode,
+
+    /// JetStream stream name. Required in JetStream mode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stream: Option<String>,
+
+    /// Durable consumer name for JetStream mode.
+    ///
+    /// **Default**: `drasi-{source_id}`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub durable_name: Option<String>,
+
+    /// Queue group for core subscriptions, to share messages between instances.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub queue_group: Option<String>,
+
+    /// Seconds JetStream waits for an ack before redelivering a message.
+    ///
+    /// **Default**: `30`
+    #[serde(default = "default_ack_wait_secs")]
+    pub ack_wait_secs: u64,
+
+    /// Optional token for authentication.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+
+    /// Optional username for authentication.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+
+    /// Optional password for authentication.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+
+    /// Payload field holding the element ID.
+    ///
+    /// **Default**: `"id"`
+    #[serde(default = "default_id_field")]
+    pub id_field: String,
+
+    /// Payload field holding the change operation (`insert`, `update`, `delete`
+    /// or `i`/`u`/`d`). Messages without it are treated as inserts.
+    ///
+    /// **Default**: `"op"`
+    #[serde(default = "default_operation_field")]
+    pub operation_field: String,
+
+    /// Optional payload field holding the node label. Takes precedence over `label_mappings`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_field: Option<String>,
+
+    /// Subject pattern to label mappings, checked in order. Messages matching
+    /// none of them use the last subject token as label.
+    #[serde(default)]
+    pub label_mappings: Vec<SubjectLabelMapping>,
+}
+
+impl Default for NatsSourceConfig {
+    fn default() -> Self {
+        Self {
+            url: default_url(),
+            subjects: Vec::new(),
+            mode: NatsMode::default(),
+            stream: None,
+            durable_name: None,
+            queue_group: None,
+            ack_wait_secs: default_ack_wait_secs(),
+            token: None,
+            username: None,
+            password: None,
+            id_field: default_id_field(),
+            operation_field: default_operation_field(),
+            label_field: None,
+            label_mappings: Vec::new(),
+        }
+    }
+}
+
+impl NatsSourceConfig {
+    /// Validate the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `url` is empty
+    /// - `subjects` is empty or contains an empty subject
+    /// - `stream` is missing in JetStream mode
+    /// - `id_field` is empty
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if self.url.is_empty() {
+            return Err(anyhow::anyhow!("Validation error: url cannot be empty"));
+        }
+
+        if self.subjects.is_empty() || self.subjects.iter().any(|s| s.is_empty()) {
+            return Err(anyhow::anyhow!(
+                "Validation error: subjects must contain at least one non-empty subject"
+            ));
+        }
+
+        if self.mode == NatsMode::JetStream && self.stream.as_deref().unwrap_or("").is_empty() {
+            return Err(anyhow::anyhow!(
+                "Validation error: stream is required in JetStream mode"
+            ));
+        }
+
+        if self.id_field.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Validation error: id_field cannot be empty"
+            ));
+        }
+
+        Ok(())
+    }
+}
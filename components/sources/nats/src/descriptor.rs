@@ -0,0 +1,167 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! NATS source plugin descriptor and configuration DTOs.
+
+use crate::{NatsMode, NatsSourceBuilder, NatsSourceConfig, SubjectLabelMapping};
+use drasi_plugin_sdk::prelude::*;
+use utoipa::OpenApi;
+
+/// NATS consumption mode DTO.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::nats::NatsMode)]
+#[serde(rename_all = "lowercase")]
+pub enum NatsModeDto {
+    #[default]
+    Core,
+    JetStream,
+}
+
+/// Subject pattern to label mapping DTO.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::nats::SubjectLabelMapping)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SubjectLabelMappingDto {
+    pub subject: String,
+    pub label: String,
+}
+
+/// NATS source configuration DTO.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::nats::NatsSourceConfig)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NatsSourceConfigDto {
+    #[serde(default = "default_url")]
+    pub url: ConfigValue<String>,
+    pub subjects: Vec<ConfigValue<String>>,
+    #[serde(default)]
+    #[schema(value_type = source::nats::NatsMode)]
+    pub mode: NatsModeDto,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stream: Option<ConfigValue<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub durable_name: Option<ConfigValue<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub queue_group: Option<ConfigValue<String>>,
+    #[serde(default = "default_ack_wait_secs")]
+    pub ack_wait_secs: ConfigValue<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<ConfigValue<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<ConfigValue<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<ConfigValue<String>>,
+    #[serde(default = "default_id_field")]
+    pub id_field: String,
+    #[serde(default = "default_operation_field")]
+    pub operation_field: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_field: Option<String>,
+    #[serde(default)]
+    #[schema(value_type = Vec<source::nats::SubjectLabelMapping>)]
+    pub label_mappings: Vec<SubjectLabelMappingDto>,
+}
+
+fn default_url() -> ConfigValue<String> {
+    ConfigValue::Static("nats://localhost:4222".to_string())
+}
+
+fn default_ack_wait_secs() -> ConfigValue<u64> {
+    ConfigValue::Static(30)
+}
+
+fn default_id_field() -> String {
+    "id".to_string()
+}
+
+fn default_operation_field() -> String {
+    "op".to_string()
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(NatsSourceConfigDto, NatsModeDto, SubjectLabelMappingDto)))]
+struct NatsSourceSchemas;
+
+/// Descriptor for the NATS source plugin.
+pub struct NatsSourceDescriptor;
+
+#[async_trait]
+impl SourcePluginDescriptor for NatsSourceDescriptor {
+    fn kind(&self) -> &str {
+        "nats"
+    }
+
+    fn config_version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn config_schema_name(&self) -> &str {
+        "source.nats.NatsSourceConfig"
+    }
+
+    fn config_schema_json(&self) -> String {
+        let api = NatsSourceSchemas::openapi();
+        serde_json::to_string(
+            &api.components
+                .as_ref()
+                .expect("OpenAPI components missing")
+                .schemas,
+        )
+        .expect("Failed to serialize config schema")
+    }
+
+    async fn create_source(
+        &self,
+        id: &str,
+        config_json: &serde_json::Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn drasi_lib::sources::Source>> {
+        let dto: NatsSourceConfigDto = serde_json::from_value(config_json.clone())?;
+        let mapper = DtoMapper::new();
+
+        let config = NatsSourceConfig {
+            url: mapper.resolve_string(&dto.url)?,
+            subjects: mapper.resolve_string_vec(&dto.subjects)?,
+            mode: match dto.mode {
+                NatsModeDto::Core => NatsMode::Core,
+                NatsModeDto::JetStream => NatsMode::JetStream,
+            },
+            stream: mapper.resolve_optional(&dto.stream)?,
+            durable_name: mapper.resolve_optional(&dto.durable_name)?,
+            queue_group: mapper.resolve_optional(&dto.queue_group)?,
+            ack_wait_secs: mapper.resolve_typed(&dto.ack_wait_secs)?,
+            token: mapper.resolve_optional(&dto.token)?,
+            username: mapper.resolve_optional(&dto.username)?,
+            password: mapper.resolve_optional(&dto.password)?,
+            id_field: dto.id_field,
+            operation_field: dto.operation_field,
+            label_field: dto.label_field,
+            label_mappings: dto
+                .label_mappings
+                .into_iter()
+                .map(|m| SubjectLabelMapping {
+                    subject: m.subject,
+                    label: m.label,
+                })
+                .collect(),
+        };
+
+        let source = NatsSourceBuilder::new(id)
+            .with_config(config)
+            .with_auto_start(auto_start)
+            .build()?;
+
+        Ok(Box::new(source))
+    }
+}
@@ -0,0 +1,85 @@
+#![allow(unexpected_cfgs)]
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! NATS Source Plugin for drasi-lib.
+//!
+//! This plugin consumes JSON change messages from NATS subjects and turns each
+//! message into a node change. It supports plain core NATS subscriptions and
+//! JetStream durable consumers.
+//!
+//! # Message Mapping
+//!
+//! | Payload field | Maps to |
+//! |---------------|---------|
+//! | `id_field` (default `id`) | Element ID |
+//! | `operation_field` (default `op`) | `insert`/`i`, `update`/`u` or `delete`/`d`; missing means insert |
+//! | `label_field` (optional) | Node label, overriding the subject mapping |
+//! | all other fields | Node properties |
+//!
+//! The node label is taken from `label_field`, then from the first
+//! `label_mappings` entry whose subject pattern matches the message subject,
+//! and finally falls back to the last subject token (`orders.created` becomes
+//! `created`).
+//!
+//! # Delivery Guarantees
+//!
+//! In `core` mode delivery is at-most-once: messages published while the source
+//! is stopped or disconnected are not seen. Subscriptions can share load
+//! through a queue group.
+//!
+//! In `jetstream` mode the source binds a durable pull consumer with explicit
+//! acks to an existing stream. Messages are acked after they have been
+//! dispatched, negatively acked if dispatch fails, and terminated if they
+//! cannot be converted. Unacked messages are redelivered after
+//! `ack_wait_secs`, so delivery is at-least-once.
+//!
+//! # Usage Example
+//!
+//! ```rust,ignore
+//! use drasi_source_nats::NatsSource;
+//!
+//! let source = NatsSource::builder("orders-source")
+//!     .with_url("nats://localhost:4222")
+//!     .with_subject("orders.>")
+//!     .with_jetstream("ORDERS")
+//!     .with_durable_name("drasi-orders")
+//!     .with_label_mapping("orders.>", "Order")
+//!     .build()?;
+//!
+//! drasi.add_source(source).await?;
+//! ```
+
+mod config;
+mod conversion;
+pub mod descriptor;
+mod nats;
+
+#[cfg(test)]
+mod tests;
+
+pub use config::{NatsMode, NatsSourceConfig, SubjectLabelMapping};
+pub use nats::{NatsSource, NatsSourceBuilder};
+
+/// Dynamic plugin entry point.
+#[cfg(feature = "dynamic-plugin")]
+drasi_plugin_sdk::export_plugin!(
+    plugin_id = "nats-source",
+    core_version = env!("CARGO_PKG_VERSION"),
+    lib_version = env!("CARGO_PKG_VERSION"),
+    plugin_version = env!("CARGO_PKG_VERSION"),
+    source_descriptors = [descriptor::NatsSourceDescriptor],
+    reaction_descriptors = [],
+    bootstrap_descriptors = [],
+);
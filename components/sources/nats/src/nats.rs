@@ -0,0 +1,604 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! NATS source implementation and builder.
+
+use anyhow::Result;
+use async_nats::jetstream::{self, consumer::pull, consumer::AckPolicy, AckKind};
+use async_trait::async_trait;
+use futures::StreamExt;
+use log::{debug, error, info, warn};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+use drasi_core::models::SourceChange;
+use drasi_lib::channels::*;
+use drasi_lib::component_graph::ComponentStatusHandle;
+use drasi_lib::managers::{log_component_start, log_component_stop};
+use drasi_lib::sources::base::{SourceBase, SourceBaseParams};
+use drasi_lib::Source;
+use tracing::Instrument;
+
+use crate::config::{NatsMode, NatsSourceConfig, SubjectLabelMapping};
+use crate::conversion::message_to_source_change;
+
+type Dispatchers = Arc<RwLock<Vec<Box<dyn ChangeDispatcher<SourceEventWrapper> + Send + Sync>>>>;
+
+/// Source that consumes JSON change messages from NATS subjects.
+///
+/// In core mode the source subscribes to each subject (optionally in a queue
+/// group). In JetStream mode it binds a durable pull consumer to the configured
+/// stream and acknowledges each message only after it has been dispatched.
+pub struct NatsSource {
+    /// Base source implementation providing dispatchers, status tracking, and lifecycle management.
+    base: SourceBase,
+    /// NATS configuration.
+    config: NatsSourceConfig,
+}
+
+impl NatsSource {
+    /// Create a builder for a NATS source.
+    pub fn builder(id: impl Into<String>) -> NatsSourceBuilder {
+        NatsSourceBuilder::new(id)
+    }
+
+    /// Create a new NATS source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid.
+    pub fn new(id: impl Into<String>, config: NatsSourceConfig) -> Result<Self> {
+        NatsSourceBuilder::new(id).with_config(config).build()
+    }
+
+    async fn connect(config: &NatsSourceConfig) -> Result<async_nats::Client> {
+        let options = match (&config.token, &config.username) {
+            (Some(token), _) => async_nats::ConnectOptions::with_token(token.clone()),
+            (None, Some(username)) => async_nats::ConnectOptions::with_user_and_password(
+                username.clone(),
+                config.password.clone().unwrap_or_default(),
+            ),
+            (None, None) => async_nats::ConnectOptions::new(),
+        };
+
+        let client = options
+            .connect(config.url.as_str())
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to connect to NATS at {}: {e}", config.url))?;
+        info!("Connected to NATS at {}", config.url);
+        Ok(client)
+    }
+
+    async fn dispatch(
+        source_id: &str,
+        dispatchers: &Dispatchers,
+        change: SourceChange,
+    ) -> Result<()> {
+        let mut profiling = drasi_lib::profiling::ProfilingMetadata::new();
+        profiling.source_send_ns = Some(drasi_lib::profiling::timestamp_ns());
+
+        let wrapper = SourceEventWrapper::with_profiling(
+            source_id.to_string(),
+            SourceEvent::Change(change),
+            chrono::Utc::now(),
+            profiling,
+        );
+
+        SourceBase::dispatch_from_task(dispatchers.clone(), wrapper, source_id).await
+    }
+
+    /// Consume core NATS subscriptions until the connection closes.
+    async fn run_core(
+        source_id: &str,
+        config: &NatsSourceConfig,
+        dispatchers: &Dispatchers,
+        status_handle: &ComponentStatusHandle,
+    ) -> Result<()> {
+        let client = Self::connect(config).await?;
+
+        let mut subscribers = Vec::with_capacity(config.subjects.len());
+        for subject in &config.subjects {
+            let subscriber = match &config.queue_group {
+                Some(group) => {
+                    client
+                        .queue_subscribe(subject.clone(), group.clone())
+                        .await?
+                }
+                None => client.subscribe(subject.clone()).await?,
+            };
+            subscribers.push(subscriber);
+        }
+        info!("[{source_id}] Subscribed to {:?}", config.subjects);
+
+        status_handle
+            .set_status(
+                ComponentStatus::Running,
+                Some("NATS source running".to_string()),
+            )
+            .await;
+
+        let mut messages = futures::stream::select_all(subscribers);
+        while let Some(message) = messages.next().await {
+            match message_to_source_change(
+                source_id,
+                message.subject.as_str(),
+                &message.payload,
+                config,
+            ) {
+                Ok(change) => {
+                    if let Err(e) = Self::dispatch(source_id, dispatchers, change).await {
+                        debug!("[{source_id}] Failed to dispatch change: {e}");
+                    }
+                }
+                Err(e) => warn!("[{source_id}] Skipping message: {e}"),
+            }
+        }
+
+        Err(anyhow::anyhow!("NATS subscriptions closed"))
+    }
+
+    /// Consume a JetStream durable pull consumer, acking after dispatch.
+    async fn run_jetstream(
+        source_id: &str,
+        config: &NatsSourceConfig,
+        dispatchers: &Dispatchers,
+        status_handle: &ComponentStatusHandle,
+    ) -> Result<()> {
+        let client = Self::connect(config).await?;
+        let context = jetstream::new(client);
+
+        let stream_name = config.stream.clone().unwrap_or_default();
+        let stream = context
+            .get_stream(&stream_name)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to get JetStream stream '{stream_name}': {e}"))?;
+
+        let durable_name = config
+            .durable_name
+            .clone()
+            .unwrap_or_else(|| format!("drasi-{source_id}"));
+
+        let mut consumer_config = pull::Config {
+            durable_name: Some(durable_name.clone()),
+            ack_policy: AckPolicy::Explicit,
+            ack_wait: Duration::from_secs(config.ack_wait_secs),
+            ..Default::default()
+        };
+        // `filter_subjects` needs NATS 2.10+, so keep the single-subject form when possible
+        if let [subject] = config.subjects.as_slice() {
+            consumer_config.filter_subject = subject.clone();
+        } else {
+            consumer_config.filter_subjects = config.subjects.clone();
+        }
+
+        let consumer = stream
+            .get_or_create_consumer(&durable_name, consumer_config)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to create consumer '{durable_name}': {e}"))?;
+        let mut messages = consumer
+            .messages()
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to pull messages: {e}"))?;
+        info!("[{source_id}] Consuming JetStream stream '{stream_name}' as '{durable_name}'");
+
+        status_handle
+            .set_status(
+                ComponentStatus::Running,
+                Some("NATS source running".to_string()),
+            )
+            .await;
+
+        while let Some(message) = messages.next().await {
+            let message = match message {
+                Ok(message) => message,
+                Err(e) => {
+                    warn!("[{source_id}] Error receiving JetStream message: {e}");
+                    continue;
+                }
+            };
+
+            let ack = match message_to_source_change(
+                source_id,
+                message.message.subject.as_str(),
+                &message.message.payload,
+                config,
+            ) {
+                Ok(change) => {
+                    match Self::dispatch(source_id, dispatchers, change).await {
+                        Ok(()) => AckKind::Ack,
+                        Err(e) => {
+                            warn!("[{source_id}] Failed to dispatch change, requesting redelivery: {e}");
+                            AckKind::Nak(None)
+                        }
+                    }
+                }
+                Err(e) => {
+                    // Redelivering a malformed message would fail the same way
+                    warn!("[{source_id}] Terminating unprocessable message: {e}");
+                    AckKind::Term
+                }
+            };
+
+            if let Err(e) = message.ack_with(ack).await {
+                error!("[{source_id}] Failed to acknowledge JetStream message: {e}");
+            }
+        }
+
+        Err(anyhow::anyhow!("JetStream message stream closed"))
+    }
+
+    async fn run(
+        source_id: String,
+        config: NatsSourceConfig,
+        dispatchers: Dispatchers,
+        status_handle: ComponentStatusHandle,
+    ) {
+        let result = match config.mode {
+            NatsMode::Core => {
+                Self::run_core(&source_id, &config, &dispatchers, &status_handle).await
+            }
+            NatsMode::JetStream => {
+                Self::run_jetstream(&source_id, &config, &dispatchers, &status_handle).await
+            }
+        };
+
+        if let Err(e) = result {
+            error!("[{source_id}] NATS consumer stopped: {e}");
+            status_handle
+                .set_status(
+                    ComponentStatus::Error,
+                    Some(format!("NATS consumer stopped: {e}")),
+                )
+                .await;
+        }
+    }
+}
+
+#[async_trait]
+impl Source for NatsSource {
+    fn id(&self) -> &str {
+        &self.base.id
+    }
+
+    fn type_name(&self) -> &str {
+        "nats"
+    }
+
+    fn properties(&self) -> HashMap<String, serde_json::Value> {
+        use crate::descriptor::{NatsModeDto, NatsSourceConfigDto, SubjectLabelMappingDto};
+        use drasi_plugin_sdk::ConfigValue;
+
+        let dto = NatsSourceConfigDto {
+            url: ConfigValue::Static(self.config.url.clone()),
+            subjects: self
+                .config
+                .subjects
+                .iter()
+                .map(|s| ConfigValue::Static(s.clone()))
+                .collect(),
+            mode: match self.config.mode {
+                NatsMode::Core => NatsModeDto::Core,
+                NatsMode::JetStream => NatsModeDto::JetStream,
+            },
+            stream: self.config.stream.clone().map(ConfigValue::Static),
+            durable_name: self.config.durable_name.clone().map(ConfigValue::Static),
+            queue_group: self.config.queue_group.clone().map(ConfigValue::Static),
+            ack_wait_secs: ConfigValue::Static(self.config.ack_wait_secs),
+            // Credentials are never exposed through properties
+            token: None,
+            username: self.config.username.clone().map(ConfigValue::Static),
+            password: None,
+            id_field: self.config.id_field.clone(),
+            operation_field: self.config.operation_field.clone(),
+            label_field: self.config.label_field.clone(),
+            label_mappings: self
+                .config
+                .label_mappings
+                .iter()
+                .map(|m| SubjectLabelMappingDto {
+                    subject: m.subject.clone(),
+                    label: m.label.clone(),
+                })
+                .collect(),
+        };
+
+        match serde_json::to_value(&dto) {
+            Ok(serde_json::Value::Object(map)) => map.into_iter().collect(),
+            _ => HashMap::new(),
+        }
+    }
+
+    fn auto_start(&self) -> bool {
+        self.base.get_auto_start()
+    }
+
+    async fn start(&self) -> Result<()> {
+        log_component_start("NATS Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Starting,
+                Some("Starting NATS source".to_string()),
+            )
+            .await;
+
+        let instance_id = self
+            .base
+            .context()
+            .await
+            .map(|c| c.instance_id)
+            .unwrap_or_default();
+
+        let span = tracing::info_span!(
+            "nats_source_consumer",
+            instance_id = %instance_id,
+            component_id = %self.base.id,
+            component_type = "source"
+        );
+        let task = tokio::spawn(
+            Self::run(
+                self.base.id.clone(),
+                self.config.clone(),
+                self.base.dispatchers.clone(),
+                self.base.status_handle(),
+            )
+            .instrument(span),
+        );
+
+        *self.base.task_handle.write().await = Some(task);
+
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        log_component_stop("NATS Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopping,
+                Some("Stopping NATS source".to_string()),
+            )
+            .await;
+
+        // Unacknowledged JetStream messages are redelivered after `ack_wait_secs`
+        if let Some(handle) = self.base.task_handle.write().await.take() {
+            handle.abort();
+            let _ = handle.await;
+        }
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopped,
+                Some("NATS source stopped".to_string()),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn status(&self) -> ComponentStatus {
+        self.base.get_status().await
+    }
+
+    async fn subscribe(
+        &self,
+        settings: drasi_lib::config::SourceSubscriptionSettings,
+    ) -> Result<SubscriptionResponse> {
+        self.base.subscribe_with_bootstrap(&settings, "NATS").await
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    async fn initialize(&self, context: drasi_lib::context::SourceRuntimeContext) {
+        self.base.initialize(context).await;
+    }
+
+    async fn set_bootstrap_provider(
+        &self,
+        provider: Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>,
+    ) {
+        self.base.set_bootstrap_provider(provider).await;
+    }
+}
+
+/// Builder for [`NatsSource`] instances.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use drasi_source_nats::NatsSource;
+///
+/// let source = NatsSource::builder("orders")
+///     .with_url("nats://localhost:4222")
+///     .with_subject("orders.>")
+///     .with_jetstream("ORDERS")
+///     .with_label_mapping("orders.>", "Order")
+///     .build()?;
+/// ```
+pub struct NatsSourceBuilder {
+    id: String,
+    config: NatsSourceConfig,
+    dispatch_mode: Option<DispatchMode>,
+    dispatch_buffer_capacity: Option<usize>,
+    bootstrap_provider: Option<Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>>,
+    auto_start: bool,
+}
+
+impl NatsSourceBuilder {
+    /// Create a new builder with the given ID and default values.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            config: NatsSourceConfig::default(),
+            dispatch_mode: None,
+            dispatch_buffer_capacity: None,
+            bootstrap_provider: None,
+            auto_start: true,
+        }
+    }
+
+    /// Set the NATS server URL.
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.config.url = url.into();
+        self
+    }
+
+    /// Set the subjects to consume.
+    pub fn with_subjects(mut self, subjects: Vec<String>) -> Self {
+        self.config.subjects = subjects;
+        self
+    }
+
+    /// Add a subject to consume.
+    pub fn with_subject(mut self, subject: impl Into<String>) -> Self {
+        self.config.subjects.push(subject.into());
+        self
+    }
+
+    /// Consume through a JetStream durable consumer on the given stream.
+    pub fn with_jetstream(mut self, stream: impl Into<String>) -> Self {
+        self.config.mode = NatsMode::JetStream;
+        self.config.stream = Some(stream.into());
+        self
+    }
+
+    /// Set the JetStream durable consumer name.
+    pub fn with_durable_name(mut self, name: impl Into<String>) -> Self {
+        self.config.durable_name = Some(name.into());
+        self
+    }
+
+    /// Set the queue group for core subscriptions.
+    pub fn with_queue_group(mut self, group: impl Into<String>) -> Self {
+        self.config.queue_group = Some(group.into());
+        self
+    }
+
+    /// Set the JetStream ack wait in seconds.
+    pub fn with_ack_wait_secs(mut self, secs: u64) -> Self {
+        self.config.ack_wait_secs = secs;
+        self
+    }
+
+    /// Authenticate with a token.
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.config.token = Some(token.into());
+        self
+    }
+
+    /// Authenticate with a username and password.
+    pub fn with_credentials(
+        mut self,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        self.config.username = Some(username.into());
+        self.config.password = Some(password.into());
+        self
+    }
+
+    /// Set the payload field holding the element ID.
+    pub fn with_id_field(mut self, field: impl Into<String>) -> Self {
+        self.config.id_field = field.into();
+        self
+    }
+
+    /// Set the payload field holding the change operation.
+    pub fn with_operation_field(mut self, field: impl Into<String>) -> Self {
+        self.config.operation_field = field.into();
+        self
+    }
+
+    /// Set the payload field holding the node label.
+    pub fn with_label_field(mut self, field: impl Into<String>) -> Self {
+        self.config.label_field = Some(field.into());
+        self
+    }
+
+    /// Map a subject pattern to a node label.
+    pub fn with_label_mapping(
+        mut self,
+        subject: impl Into<String>,
+        label: impl Into<String>,
+    ) -> Self {
+        self.config.label_mappings.push(SubjectLabelMapping {
+            subject: subject.into(),
+            label: label.into(),
+        });
+        self
+    }
+
+    /// Set the dispatch mode for this source.
+    pub fn with_dispatch_mode(mut self, mode: DispatchMode) -> Self {
+        self.dispatch_mode = Some(mode);
+        self
+    }
+
+    /// Set the dispatch buffer capacity.
+    pub fn with_dispatch_buffer_capacity(mut self, capacity: usize) -> Self {
+        self.dispatch_buffer_capacity = Some(capacity);
+        self
+    }
+
+    /// Set the bootstrap provider for initial data delivery.
+    pub fn with_bootstrap_provider(
+        mut self,
+        provider: impl drasi_lib::bootstrap::BootstrapProvider + 'static,
+    ) -> Self {
+        self.bootstrap_provider = Some(Box::new(provider));
+        self
+    }
+
+    /// Set whether this source should auto-start when DrasiLib starts.
+    pub fn with_auto_start(mut self, auto_start: bool) -> Self {
+        self.auto_start = auto_start;
+        self
+    }
+
+    /// Set the full configuration at once.
+    pub fn with_config(mut self, config: NatsSourceConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Build the NATS source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid or the base source
+    /// cannot be initialized.
+    pub fn build(self) -> Result<NatsSource> {
+        self.config.validate()?;
+
+        let mut params = SourceBaseParams::new(&self.id).with_auto_start(self.auto_start);
+        if let Some(mode) = self.dispatch_mode {
+            params = params.with_dispatch_mode(mode);
+        }
+        if let Some(capacity) = self.dispatch_buffer_capacity {
+            params = params.with_dispatch_buffer_capacity(capacity);
+        }
+        if let Some(provider) = self.bootstrap_provider {
+            params = params.with_bootstrap_provider(provider);
+        }
+
+        Ok(NatsSource {
+            base: SourceBase::new(params)?,
+            config: self.config,
+        })
+    }
+}
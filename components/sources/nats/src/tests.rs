@@ -0,0 +1,275 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for the NATS source plugin.
+
+use super::*;
+use crate::conversion::{label_for_subject, message_to_source_change, subject_matches};
+use drasi_core::models::{Element, ElementValue, SourceChange};
+use drasi_lib::Source;
+use drasi_plugin_sdk::prelude::SourcePluginDescriptor;
+use serde_json::json;
+use std::sync::Arc;
+
+fn config() -> NatsSourceConfig {
+    NatsSourceConfig {
+        subjects: vec!["orders.>".to_string()],
+        ..Default::default()
+    }
+}
+
+fn payload(value: serde_json::Value) -> Vec<u8> {
+    serde_json::to_vec(&value).unwrap()
+}
+
+mod builder {
+    use super::*;
+
+    #[test]
+    fn test_builder_with_valid_config() {
+        let source = NatsSource::builder("test-source")
+            .with_url("nats://nats:4222")
+            .with_subject("orders.>")
+            .with_credentials("drasi", "secret")
+            .with_label_mapping("orders.>", "Order")
+            .build()
+            .unwrap();
+
+        assert_eq!(source.id(), "test-source");
+        assert_eq!(source.type_name(), "nats");
+        let props = source.properties();
+        assert_eq!(props.get("url"), Some(&json!("nats://nats:4222")));
+        assert_eq!(props.get("subjects"), Some(&json!(["orders.>"])));
+        assert_eq!(props.get("mode"), Some(&json!("core")));
+        assert_eq!(props.get("username"), Some(&json!("drasi")));
+        assert!(props.get("password").is_none());
+    }
+
+    #[test]
+    fn test_builder_requires_subjects() {
+        let result = NatsSource::builder("test-source").build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_jetstream_mode_requires_stream() {
+        let config = NatsSourceConfig {
+            mode: NatsMode::JetStream,
+            ..config()
+        };
+        assert!(config.validate().is_err());
+
+        let source = NatsSource::builder("test-source")
+            .with_subject("orders.>")
+            .with_jetstream("ORDERS")
+            .with_token("t0ken")
+            .build()
+            .unwrap();
+        let props = source.properties();
+        assert_eq!(props.get("mode"), Some(&json!("jetstream")));
+        assert_eq!(props.get("stream"), Some(&json!("ORDERS")));
+        assert!(props.get("token").is_none());
+    }
+
+    #[test]
+    fn test_config_deserialization_defaults() {
+        let config: NatsSourceConfig =
+            serde_json::from_value(json!({"subjects": ["orders.*"]})).unwrap();
+        assert_eq!(config.url, "nats://localhost:4222");
+        assert_eq!(config.mode, NatsMode::Core);
+        assert_eq!(config.ack_wait_secs, 30);
+        assert_eq!(config.id_field, "id");
+        assert_eq!(config.operation_field, "op");
+    }
+}
+
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn test_subject_wildcards() {
+        assert!(subject_matches("orders.created", "orders.created"));
+        assert!(subject_matches("orders.*", "orders.created"));
+        assert!(!subject_matches("orders.*", "orders.eu.created"));
+        assert!(subject_matches("orders.>", "orders.eu.created"));
+        assert!(!subject_matches("orders.>", "orders"));
+        assert!(!subject_matches("orders.created", "orders"));
+    }
+
+    #[test]
+    fn test_label_for_subject() {
+        let config = NatsSourceConfig {
+            label_mappings: vec![SubjectLabelMapping {
+                subject: "orders.*".to_string(),
+                label: "Order".to_string(),
+            }],
+            ..config()
+        };
+        assert_eq!(label_for_subject("orders.created", &config), "Order");
+        assert_eq!(label_for_subject("inventory.item", &config), "item");
+    }
+
+    #[test]
+    fn test_message_without_operation_is_insert() {
+        let change = message_to_source_change(
+            "src",
+            "orders.created",
+            &payload(json!({"id": 7, "total": 42.5, "status": "open"})),
+            &config(),
+        )
+        .unwrap();
+
+        let SourceChange::Insert {
+            element:
+                Element::Node {
+                    metadata,
+                    properties,
+                },
+        } = change
+        else {
+            panic!("expected node insert");
+        };
+        assert_eq!(metadata.reference.element_id.as_ref(), "7");
+        assert_eq!(metadata.labels[0].as_ref(), "created");
+        assert_eq!(
+            properties.get("total"),
+            Some(&ElementValue::Float(42.5.into()))
+        );
+        assert_eq!(
+            properties.get("status"),
+            Some(&ElementValue::String(Arc::from("open")))
+        );
+    }
+
+    #[test]
+    fn test_update_and_delete_operations() {
+        let change = message_to_source_change(
+            "src",
+            "orders.changed",
+            &payload(json!({"id": "o-1", "op": "U"})),
+            &config(),
+        )
+        .unwrap();
+        assert!(matches!(change, SourceChange::Update { .. }));
+
+        let change = message_to_source_change(
+            "src",
+            "orders.changed",
+            &payload(json!({"id": "o-1", "op": "delete"})),
+            &config(),
+        )
+        .unwrap();
+        assert!(matches!(change, SourceChange::Delete { .. }));
+    }
+
+    #[test]
+    fn test_operation_and_label_fields_are_not_properties() {
+        let config = NatsSourceConfig {
+            label_field: Some("type".to_string()),
+            ..config()
+        };
+        let change = message_to_source_change(
+            "src",
+            "orders.created",
+            &payload(json!({"id": "1", "op": "i", "type": "Invoice", "n": 1})),
+            &config,
+        )
+        .unwrap();
+
+        let SourceChange::Insert {
+            element:
+                Element::Node {
+                    metadata,
+                    properties,
+                },
+        } = change
+        else {
+            panic!("expected node insert");
+        };
+        assert_eq!(metadata.labels[0].as_ref(), "Invoice");
+        assert!(properties.get("op").is_none());
+        assert!(properties.get("type").is_none());
+        assert_eq!(properties.get("n"), Some(&ElementValue::Integer(1)));
+    }
+
+    #[test]
+    fn test_invalid_messages_fail() {
+        let config = config();
+        assert!(message_to_source_change("src", "orders.x", b"not json", &config).is_err());
+        assert!(
+            message_to_source_change("src", "orders.x", &payload(json!([1])), &config).is_err()
+        );
+        assert!(message_to_source_change(
+            "src",
+            "orders.x",
+            &payload(json!({"name": "x"})),
+            &config
+        )
+        .is_err());
+        assert!(message_to_source_change(
+            "src",
+            "orders.x",
+            &payload(json!({"id": "1", "op": "upsert"})),
+            &config
+        )
+        .is_err());
+    }
+}
+
+mod descriptor {
+    use super::*;
+    use crate::descriptor::NatsSourceDescriptor;
+
+    #[tokio::test]
+    async fn test_descriptor_creates_source() {
+        let descriptor = NatsSourceDescriptor;
+        assert_eq!(descriptor.kind(), "nats");
+
+        let source = descriptor
+            .create_source(
+                "nats-1",
+                &json!({
+                    "subjects": ["orders.>"],
+                    "mode": "jetstream",
+                    "stream": "ORDERS",
+                    "durableName": "drasi-orders",
+                    "labelMappings": [{"subject": "orders.>", "label": "Order"}]
+                }),
+                false,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(source.id(), "nats-1");
+        assert!(!source.auto_start());
+        let props = source.properties();
+        assert_eq!(props.get("durableName"), Some(&json!("drasi-orders")));
+        assert_eq!(
+            props.get("labelMappings"),
+            Some(&json!([{"subject": "orders.>", "label": "Order"}]))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_descriptor_rejects_jetstream_without_stream() {
+        let result = NatsSourceDescriptor
+            .create_source(
+                "nats-1",
+                &json!({"subjects": ["orders.>"], "mode": "jetstream"}),
+                true,
+            )
+            .await;
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,163 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Neo4j CDC change events and their conversion to source changes.
+//!
+//! Events are read from `db.cdc.query` and require the database to record
+//! full entity states (`txLogEnrichment: FULL`).
+
+use anyhow::{anyhow, Result};
+use drasi_core::models::{Element, ElementMetadata, ElementReference, SourceChange};
+use drasi_lib::sources::convert_json_to_element_properties;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::sync::Arc;
+
+/// Kind of entity a change applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub(crate) enum EventType {
+    #[serde(rename = "n")]
+    Node,
+    #[serde(rename = "r")]
+    Relationship,
+}
+
+/// Change operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub(crate) enum Operation {
+    #[serde(rename = "c")]
+    Create,
+    #[serde(rename = "u")]
+    Update,
+    #[serde(rename = "d")]
+    Delete,
+}
+
+/// Start or end node of a relationship change.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct NodeRef {
+    pub element_id: String,
+}
+
+/// State of an entity before or after a change.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub(crate) struct EntityState {
+    #[serde(default)]
+    pub labels: Vec<String>,
+    #[serde(default)]
+    pub properties: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub(crate) struct States {
+    #[serde(default)]
+    pub before: Option<EntityState>,
+    #[serde(default)]
+    pub after: Option<EntityState>,
+}
+
+/// The `event` field of a CDC change.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CdcEvent {
+    pub element_id: String,
+    pub event_type: EventType,
+    pub operation: Operation,
+    /// Labels of a node at the time of the change.
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// Type of a relationship.
+    #[serde(default, rename = "type")]
+    pub rel_type: Option<String>,
+    #[serde(default)]
+    pub start: Option<NodeRef>,
+    #[serde(default)]
+    pub end: Option<NodeRef>,
+    #[serde(default)]
+    pub state: States,
+}
+
+impl CdcEvent {
+    /// Convert the event into a source change.
+    pub(crate) fn into_source_change(
+        self,
+        source_id: &str,
+        effective_from: u64,
+    ) -> Result<SourceChange> {
+        let labels = match self.event_type {
+            EventType::Node => self.node_labels(),
+            EventType::Relationship => vec![self
+                .rel_type
+                .clone()
+                .ok_or_else(|| anyhow!("Relationship change '{}' has no type", self.element_id))?],
+        };
+        let metadata = ElementMetadata {
+            reference: ElementReference::new(source_id, &self.element_id),
+            labels: Arc::from(labels.into_iter().map(Arc::from).collect::<Vec<_>>()),
+            effective_from,
+        };
+
+        if self.operation == Operation::Delete {
+            return Ok(SourceChange::Delete { metadata });
+        }
+
+        let after = self.state.after.ok_or_else(|| {
+            anyhow!(
+                "Change '{}' has no after state; set txLogEnrichment to FULL",
+                self.element_id
+            )
+        })?;
+        let properties = convert_json_to_element_properties(&after.properties);
+
+        let element = match self.event_type {
+            EventType::Node => Element::Node {
+                metadata,
+                properties,
+            },
+            EventType::Relationship => {
+                let (Some(start), Some(end)) = (self.start, self.end) else {
+                    return Err(anyhow!(
+                        "Relationship change '{}' has no start or end node",
+                        self.element_id
+                    ));
+                };
+                Element::Relation {
+                    metadata,
+                    properties,
+                    in_node: ElementReference::new(source_id, &start.element_id),
+                    out_node: ElementReference::new(source_id, &end.element_id),
+                }
+            }
+        };
+
+        Ok(match self.operation {
+            Operation::Create => SourceChange::Insert { element },
+            _ => SourceChange::Update { element },
+        })
+    }
+
+    /// Node labels after the change, or before it for deletions. Falls back
+    /// to the labels recorded on the event.
+    fn node_labels(&self) -> Vec<String> {
+        let state = match self.operation {
+            Operation::Delete => self.state.before.as_ref(),
+            _ => self.state.after.as_ref(),
+        };
+        match state {
+            Some(state) if !state.labels.is_empty() => state.labels.clone(),
+            _ => self.labels.clone(),
+        }
+    }
+}
@@ -0,0 +1,186 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration types for the Neo4j source plugin.
+//!
+//! This module defines the database the source connects to, which changes
+//! of the CDC feed are selected, and where the feed is first read from.
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+fn default_user() -> String {
+    "neo4j".to_string()
+}
+
+fn default_poll_interval_ms() -> u64 {
+    1000
+}
+
+fn default_batch_size() -> usize {
+    1000
+}
+
+/// URI schemes accepted by the Neo4j driver.
+const URI_SCHEMES: &[&str] = &[
+    "neo4j://",
+    "neo4j+s://",
+    "neo4j+ssc://",
+    "bolt://",
+    "bolt+s://",
+    "bolt+ssc://",
+];
+
+/// Position of the CDC feed the source reads from when no cursor is stored.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StartFrom {
+    /// Only changes committed after the source starts.
+    #[default]
+    Now,
+    /// The oldest change still in the transaction log.
+    Earliest,
+}
+
+/// Neo4j source configuration.
+///
+/// # Example
+///
+/// ```rust
+/// use drasi_source_neo4j::Neo4jSourceConfig;
+///
+/// let config = Neo4jSourceConfig {
+///     uri: "neo4j://localhost:7687".to_string(),
+///     password: "secret".to_string(),
+///     labels: vec!["Person".to_string()],
+///     relation_types: vec!["KNOWS".to_string()],
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Neo4jSourceConfig {
+    /// Connection URI, for example `neo4j://localhost:7687`.
+    pub uri: String,
+
+    /// Database user.
+    ///
+    /// **Default**: `neo4j`
+    #[serde(default = "default_user")]
+    pub user: String,
+
+    /// Database password.
+    #[serde(default)]
+    pub password: String,
+
+    /// Database name. `None` uses the server's default database.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub database: Option<String>,
+
+    /// Node labels to follow. Nodes carrying any of them are selected.
+    #[serde(default)]
+    pub labels: Vec<String>,
+
+    /// Relationship types to follow.
+    ///
+    /// With neither labels nor relationship types, every change is selected.
+    #[serde(default)]
+    pub relation_types: Vec<String>,
+
+    /// Where to start reading when no cursor is stored.
+    ///
+    /// **Default**: `now`
+    #[serde(default)]
+    pub start_from: StartFrom,
+
+    /// Interval between CDC queries in milliseconds, when the previous query
+    /// returned less than a full batch.
+    ///
+    /// **Default**: `1000`
+    #[serde(default = "default_poll_interval_ms")]
+    pub poll_interval_ms: u64,
+
+    /// Maximum number of changes read per query.
+    ///
+    /// **Default**: `1000`
+    #[serde(default = "default_batch_size")]
+    pub batch_size: usize,
+}
+
+impl Default for Neo4jSourceConfig {
+    fn default() -> Self {
+        Self {
+            uri: String::new(),
+            user: default_user(),
+            password: String::new(),
+            database: None,
+            labels: Vec::new(),
+            relation_types: Vec::new(),
+            start_from: StartFrom::default(),
+            poll_interval_ms: default_poll_interval_ms(),
+            batch_size: default_batch_size(),
+        }
+    }
+}
+
+impl Neo4jSourceConfig {
+    /// Validate the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URI does not use a `neo4j` or `bolt` scheme,
+    /// the user, database, a label or a relationship type is empty, or the
+    /// poll interval or batch size is 0.
+    pub fn validate(&self) -> Result<()> {
+        if !URI_SCHEMES
+            .iter()
+            .any(|scheme| self.uri.starts_with(scheme))
+        {
+            return Err(anyhow!(
+                "Validation error: uri '{}' must use one of the schemes {}",
+                self.uri,
+                URI_SCHEMES.join(", ")
+            ));
+        }
+        if self.user.is_empty() {
+            return Err(anyhow!("Validation error: user cannot be empty"));
+        }
+        if self.database.as_deref() == Some("") {
+            return Err(anyhow!(
+                "Validation error: database cannot be empty. \
+                 Omit it to use the server's default database"
+            ));
+        }
+        if self.labels.iter().any(String::is_empty) {
+            return Err(anyhow!(
+                "Validation error: labels cannot contain empty names"
+            ));
+        }
+        if self.relation_types.iter().any(String::is_empty) {
+            return Err(anyhow!(
+                "Validation error: relation_types cannot contain empty names"
+            ));
+        }
+        if self.poll_interval_ms == 0 {
+            return Err(anyhow!(
+                "Validation error: poll_interval_ms must be greater than 0"
+            ));
+        }
+        if self.batch_size == 0 {
+            return Err(anyhow!(
+                "Validation error: batch_size must be greater than 0"
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! CDC cursor persistence for the Neo4j source.
+//!
+//! The change identifier of the last processed change is stored in the
+//! drasi-lib state store, so a restarted source continues the feed where it
+//! stopped.
+
+use anyhow::Result;
+use drasi_lib::StateStoreProvider;
+use std::sync::Arc;
+
+const CURSOR_KEY: &str = "cdc_cursor";
+
+/// Change identifier storage in the `store_id` partition of a state store.
+pub(crate) struct CursorStore {
+    store: Arc<dyn StateStoreProvider>,
+    store_id: String,
+}
+
+impl CursorStore {
+    pub(crate) fn new(store: Arc<dyn StateStoreProvider>, store_id: impl Into<String>) -> Self {
+        Self {
+            store,
+            store_id: store_id.into(),
+        }
+    }
+
+    /// Load the stored change identifier, or `None` if there is none.
+    pub(crate) async fn load(&self) -> Result<Option<String>> {
+        match self.store.get(&self.store_id, CURSOR_KEY).await? {
+            Some(bytes) => Ok(Some(String::from_utf8(bytes)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Persist a change identifier.
+    pub(crate) async fn save(&self, cursor: &str) -> Result<()> {
+        self.store
+            .set(&self.store_id, CURSOR_KEY, cursor.as_bytes().to_vec())
+            .await?;
+        Ok(())
+    }
+}
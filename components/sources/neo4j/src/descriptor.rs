@@ -0,0 +1,151 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Neo4j source plugin descriptor and configuration DTOs.
+
+use crate::{Neo4jSourceBuilder, Neo4jSourceConfig, StartFrom};
+use drasi_plugin_sdk::prelude::*;
+use utoipa::OpenApi;
+
+/// Start position DTO.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::neo4j::StartFrom)]
+#[serde(rename_all = "snake_case")]
+pub enum StartFromDto {
+    #[default]
+    Now,
+    Earliest,
+}
+
+impl From<StartFromDto> for StartFrom {
+    fn from(dto: StartFromDto) -> Self {
+        match dto {
+            StartFromDto::Now => StartFrom::Now,
+            StartFromDto::Earliest => StartFrom::Earliest,
+        }
+    }
+}
+
+impl From<StartFrom> for StartFromDto {
+    fn from(start_from: StartFrom) -> Self {
+        match start_from {
+            StartFrom::Now => StartFromDto::Now,
+            StartFrom::Earliest => StartFromDto::Earliest,
+        }
+    }
+}
+
+/// Neo4j source configuration DTO.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::neo4j::Neo4jSourceConfig)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Neo4jSourceConfigDto {
+    pub uri: ConfigValue<String>,
+    #[serde(default = "default_user")]
+    pub user: ConfigValue<String>,
+    #[serde(default = "default_password")]
+    pub password: ConfigValue<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<ConfigValueString>)]
+    pub database: Option<ConfigValue<String>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub relation_types: Vec<String>,
+    #[serde(default)]
+    #[schema(value_type = source::neo4j::StartFrom)]
+    pub start_from: StartFromDto,
+    #[serde(default = "default_poll_interval_ms")]
+    pub poll_interval_ms: ConfigValue<u64>,
+    #[serde(default = "default_batch_size")]
+    pub batch_size: ConfigValue<usize>,
+}
+
+fn default_user() -> ConfigValue<String> {
+    ConfigValue::Static("neo4j".to_string())
+}
+
+fn default_password() -> ConfigValue<String> {
+    ConfigValue::Static(String::new())
+}
+
+fn default_poll_interval_ms() -> ConfigValue<u64> {
+    ConfigValue::Static(1000)
+}
+
+fn default_batch_size() -> ConfigValue<usize> {
+    ConfigValue::Static(1000)
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(Neo4jSourceConfigDto, StartFromDto)))]
+struct Neo4jSourceSchemas;
+
+/// Descriptor for the Neo4j source plugin.
+pub struct Neo4jSourceDescriptor;
+
+#[async_trait]
+impl SourcePluginDescriptor for Neo4jSourceDescriptor {
+    fn kind(&self) -> &str {
+        "neo4j"
+    }
+
+    fn config_version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn config_schema_name(&self) -> &str {
+        "source.neo4j.Neo4jSourceConfig"
+    }
+
+    fn config_schema_json(&self) -> String {
+        let api = Neo4jSourceSchemas::openapi();
+        serde_json::to_string(
+            &api.components
+                .as_ref()
+                .expect("OpenAPI components missing")
+                .schemas,
+        )
+        .expect("Failed to serialize config schema")
+    }
+
+    async fn create_source(
+        &self,
+        id: &str,
+        config_json: &serde_json::Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn drasi_lib::sources::Source>> {
+        let dto: Neo4jSourceConfigDto = serde_json::from_value(config_json.clone())?;
+        let mapper = DtoMapper::new();
+
+        let config = Neo4jSourceConfig {
+            uri: mapper.resolve_string(&dto.uri)?,
+            user: mapper.resolve_string(&dto.user)?,
+            password: mapper.resolve_string(&dto.password)?,
+            database: mapper.resolve_optional_string(&dto.database)?,
+            labels: dto.labels,
+            relation_types: dto.relation_types,
+            start_from: dto.start_from.into(),
+            poll_interval_ms: mapper.resolve_typed(&dto.poll_interval_ms)?,
+            batch_size: mapper.resolve_typed(&dto.batch_size)?,
+        };
+
+        let source = Neo4jSourceBuilder::new(id)
+            .with_config(config)
+            .with_auto_start(auto_start)
+            .build()?;
+
+        Ok(Box::new(source))
+    }
+}
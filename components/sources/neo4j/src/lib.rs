@@ -0,0 +1,88 @@
+#![allow(unexpected_cfgs)]
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Neo4j Source Plugin for drasi-lib.
+//!
+//! This plugin follows the [change data capture] feed of a Neo4j database
+//! and turns node and relationship changes into source changes. CDC needs
+//! Neo4j 5.13 or later (Enterprise Edition or Aura) with the database's
+//! `txLogEnrichment` option set to `FULL`.
+//!
+//! # Change Mapping
+//!
+//! | CDC change | Source change |
+//! |------------|---------------|
+//! | Node or relationship created | Insert |
+//! | Node or relationship updated | Update with the full state after the change |
+//! | Node or relationship deleted | Delete |
+//!
+//! Element IDs are Neo4j `elementId()` values, as returned by the Neo4j
+//! bootstrap provider. Relations are labelled with their relationship type;
+//! the start node is the relation's `in_node` and the end node its
+//! `out_node`.
+//!
+//! # Resuming
+//!
+//! The identifier of the last processed change is stored in the drasi-lib
+//! state store, when one is configured, and polling continues after it on
+//! restart.
+//!
+//! # Usage Example
+//!
+//! ```rust,ignore
+//! use drasi_bootstrap_neo4j::Neo4jBootstrapProvider;
+//! use drasi_source_neo4j::Neo4jSource;
+//!
+//! let bootstrap = Neo4jBootstrapProvider::builder()
+//!     .with_uri("neo4j://localhost:7687")
+//!     .with_password("secret")
+//!     .build()?;
+//!
+//! let source = Neo4jSource::builder("social")
+//!     .with_uri("neo4j://localhost:7687")
+//!     .with_password("secret")
+//!     .with_label("Person")
+//!     .with_relation_type("KNOWS")
+//!     .with_bootstrap_provider(bootstrap)
+//!     .build()?;
+//!
+//! drasi.add_source(source).await?;
+//! ```
+//!
+//! [change data capture]: https://neo4j.com/docs/cdc/current/
+
+mod cdc;
+mod config;
+mod cursor;
+pub mod descriptor;
+mod neo4j;
+
+#[cfg(test)]
+mod tests;
+
+pub use config::{Neo4jSourceConfig, StartFrom};
+pub use neo4j::{Neo4jSource, Neo4jSourceBuilder};
+
+/// Dynamic plugin entry point.
+#[cfg(feature = "dynamic-plugin")]
+drasi_plugin_sdk::export_plugin!(
+    plugin_id = "neo4j-source",
+    core_version = env!("CARGO_PKG_VERSION"),
+    lib_version = env!("CARGO_PKG_VERSION"),
+    plugin_version = env!("CARGO_PKG_VERSION"),
+    source_descriptors = [descriptor::Neo4jSourceDescriptor],
+    reaction_descriptors = [],
+    bootstrap_descriptors = [],
+);
@@ -0,0 +1,558 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Neo4j CDC source implementation and builder.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use log::{debug, error, info, warn};
+use neo4rs::{query, ConfigBuilder, Graph};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+use drasi_core::models::SourceChange;
+use drasi_lib::channels::*;
+use drasi_lib::component_graph::ComponentStatusHandle;
+use drasi_lib::managers::{log_component_start, log_component_stop};
+use drasi_lib::sources::base::{SourceBase, SourceBaseParams};
+use drasi_lib::Source;
+use tracing::Instrument;
+
+use crate::cdc::CdcEvent;
+use crate::config::{Neo4jSourceConfig, StartFrom};
+use crate::cursor::CursorStore;
+
+type Dispatchers = Arc<RwLock<Vec<Box<dyn ChangeDispatcher<SourceEventWrapper> + Send + Sync>>>>;
+
+/// Reads the changes after `$from`, selected by node label and relationship
+/// type. Without labels or types every entity change is selected.
+const CDC_QUERY: &str = "WITH [label IN $labels | {select: 'n', labels: [label]}] \
+     + [type IN $types | {select: 'r', type: type}] AS selectors \
+     CALL db.cdc.query($from, CASE WHEN selectors = [] THEN [{select: 'e'}] ELSE selectors END) \
+     YIELD id, event, metadata \
+     RETURN id, event, metadata.txCommitTime.epochMillis AS commitTime \
+     LIMIT $limit";
+
+/// Server error code prefixes after which polling cannot succeed: an
+/// unknown or purged cursor, CDC not enabled, or rejected credentials.
+const FATAL_ERROR_PREFIXES: &[&str] = &[
+    "Neo.ClientError.ChangeDataCapture.",
+    "Neo.ClientError.Security.",
+];
+
+/// Source that follows the change data capture feed of a Neo4j database.
+///
+/// The source polls `db.cdc.query` and turns node and relationship changes
+/// into source changes. The identifier of the last processed change is
+/// persisted so the feed continues where it stopped.
+pub struct Neo4jSource {
+    /// Base source implementation providing dispatchers, status tracking, and lifecycle management.
+    base: SourceBase,
+    /// Neo4j configuration.
+    config: Neo4jSourceConfig,
+}
+
+/// State shared by every poll of the CDC feed.
+struct PollContext {
+    source_id: String,
+    config: Neo4jSourceConfig,
+    dispatchers: Dispatchers,
+    cursor_store: Option<CursorStore>,
+}
+
+impl Neo4jSource {
+    /// Create a builder for a Neo4j source.
+    pub fn builder(id: impl Into<String>) -> Neo4jSourceBuilder {
+        Neo4jSourceBuilder::new(id)
+    }
+
+    /// Create a new Neo4j source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid.
+    pub fn new(id: impl Into<String>, config: Neo4jSourceConfig) -> Result<Self> {
+        Neo4jSourceBuilder::new(id).with_config(config).build()
+    }
+
+    async fn dispatch(
+        source_id: &str,
+        dispatchers: &Dispatchers,
+        change: SourceChange,
+    ) -> Result<()> {
+        let mut profiling = drasi_lib::profiling::ProfilingMetadata::new();
+        profiling.source_send_ns = Some(drasi_lib::profiling::timestamp_ns());
+
+        let wrapper = SourceEventWrapper::with_profiling(
+            source_id.to_string(),
+            SourceEvent::Change(change),
+            chrono::Utc::now(),
+            profiling,
+        );
+
+        SourceBase::dispatch_from_task(dispatchers.clone(), wrapper, source_id).await
+    }
+
+    /// Whether polling again cannot help.
+    fn is_fatal(error: &anyhow::Error) -> bool {
+        match error.downcast_ref::<neo4rs::Error>() {
+            Some(neo4rs::Error::Neo4j(e)) => FATAL_ERROR_PREFIXES
+                .iter()
+                .any(|prefix| e.code().starts_with(prefix)),
+            _ => false,
+        }
+    }
+
+    async fn connect(config: &Neo4jSourceConfig) -> Result<Graph> {
+        let mut builder = ConfigBuilder::default()
+            .uri(config.uri.as_str())
+            .user(config.user.as_str())
+            .password(config.password.as_str());
+        if let Some(database) = &config.database {
+            builder = builder.db(database.as_str());
+        }
+        let graph = Graph::connect(builder.build()?)
+            .await
+            .with_context(|| format!("Failed to connect to Neo4j at {}", config.uri))?;
+        Ok(graph)
+    }
+
+    /// The stored cursor, or the current or earliest change identifier.
+    async fn initial_cursor(ctx: &PollContext, graph: &Graph) -> Result<String> {
+        if let Some(store) = &ctx.cursor_store {
+            match store.load().await {
+                Ok(Some(cursor)) => return Ok(cursor),
+                Ok(None) => {}
+                Err(e) => warn!(
+                    "[{}] Failed to load CDC cursor, starting from {:?}: {e}",
+                    ctx.source_id, ctx.config.start_from
+                ),
+            }
+        }
+
+        let procedure = match ctx.config.start_from {
+            StartFrom::Now => "db.cdc.current",
+            StartFrom::Earliest => "db.cdc.earliest",
+        };
+        let mut rows = graph
+            .execute(query(&format!("CALL {procedure}() YIELD id RETURN id")))
+            .await
+            .with_context(|| format!("Failed to call {procedure}"))?;
+        let row = rows
+            .next()
+            .await?
+            .with_context(|| format!("{procedure} returned no change identifier"))?;
+        Ok(row.get::<String>("id")?)
+    }
+
+    /// Dispatch the changes after `cursor` and advance it. Returns the
+    /// number of changes read.
+    async fn poll(ctx: &PollContext, graph: &Graph, cursor: &mut String) -> Result<usize> {
+        let cdc_query = query(CDC_QUERY)
+            .param("from", cursor.clone())
+            .param("labels", ctx.config.labels.clone())
+            .param("types", ctx.config.relation_types.clone())
+            .param("limit", ctx.config.batch_size as i64);
+        let mut rows = graph
+            .execute(cdc_query)
+            .await
+            .context("Failed to query CDC feed")?;
+
+        let mut count = 0;
+        while let Some(row) = rows.next().await.context("Failed to read CDC feed")? {
+            count += 1;
+            let id: String = row.get("id").context("CDC change has no identifier")?;
+
+            let effective_from = row
+                .get::<i64>("commitTime")
+                .map(|millis| millis as u64)
+                .unwrap_or_else(|_| chrono::Utc::now().timestamp_millis() as u64);
+            let change = row
+                .get::<CdcEvent>("event")
+                .map_err(anyhow::Error::from)
+                .and_then(|event| event.into_source_change(&ctx.source_id, effective_from));
+            match change {
+                Ok(change) => {
+                    if let Err(e) = Self::dispatch(&ctx.source_id, &ctx.dispatchers, change).await {
+                        warn!("[{}] Failed to dispatch change: {e}", ctx.source_id);
+                    }
+                }
+                Err(e) => warn!("[{}] Skipping CDC change {id}: {e}", ctx.source_id),
+            }
+            *cursor = id;
+        }
+
+        if count > 0 {
+            if let Some(store) = &ctx.cursor_store {
+                if let Err(e) = store.save(cursor).await {
+                    warn!("[{}] Failed to save CDC cursor: {e}", ctx.source_id);
+                }
+            }
+        }
+        Ok(count)
+    }
+
+    async fn run(ctx: PollContext, status_handle: ComponentStatusHandle) {
+        let setup = async {
+            let graph = Self::connect(&ctx.config).await?;
+            let cursor = Self::initial_cursor(&ctx, &graph).await?;
+            Ok::<_, anyhow::Error>((graph, cursor))
+        };
+        let (graph, mut cursor) = match setup.await {
+            Ok(setup) => setup,
+            Err(e) => {
+                error!("[{}] Failed to open Neo4j CDC feed: {e:#}", ctx.source_id);
+                status_handle
+                    .set_status(
+                        ComponentStatus::Error,
+                        Some(format!("Failed to open Neo4j CDC feed: {e:#}")),
+                    )
+                    .await;
+                return;
+            }
+        };
+
+        info!("[{}] Following Neo4j CDC feed from {cursor}", ctx.source_id);
+        status_handle
+            .set_status(
+                ComponentStatus::Running,
+                Some("Neo4j source running".to_string()),
+            )
+            .await;
+
+        let poll_interval = Duration::from_millis(ctx.config.poll_interval_ms);
+        loop {
+            match Self::poll(&ctx, &graph, &mut cursor).await {
+                // A full batch means more changes are waiting.
+                Ok(count) if count >= ctx.config.batch_size => continue,
+                Ok(count) => {
+                    if count > 0 {
+                        debug!("[{}] Read {count} CDC changes", ctx.source_id);
+                    }
+                }
+                Err(e) if Self::is_fatal(&e) => {
+                    error!("[{}] CDC feed stopped: {e:#}", ctx.source_id);
+                    status_handle
+                        .set_status(
+                            ComponentStatus::Error,
+                            Some(format!("CDC feed stopped: {e:#}")),
+                        )
+                        .await;
+                    return;
+                }
+                Err(e) => warn!(
+                    "[{}] CDC poll failed, retrying in {} ms: {e:#}",
+                    ctx.source_id,
+                    poll_interval.as_millis()
+                ),
+            }
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+}
+
+#[async_trait]
+impl Source for Neo4jSource {
+    fn id(&self) -> &str {
+        &self.base.id
+    }
+
+    fn type_name(&self) -> &str {
+        "neo4j"
+    }
+
+    fn properties(&self) -> HashMap<String, serde_json::Value> {
+        use crate::descriptor::{Neo4jSourceConfigDto, StartFromDto};
+        use drasi_plugin_sdk::ConfigValue;
+
+        let password = if self.config.password.is_empty() {
+            String::new()
+        } else {
+            "***".to_string()
+        };
+        let dto = Neo4jSourceConfigDto {
+            uri: ConfigValue::Static(self.config.uri.clone()),
+            user: ConfigValue::Static(self.config.user.clone()),
+            password: ConfigValue::Static(password),
+            database: self.config.database.clone().map(ConfigValue::Static),
+            labels: self.config.labels.clone(),
+            relation_types: self.config.relation_types.clone(),
+            start_from: StartFromDto::from(self.config.start_from),
+            poll_interval_ms: ConfigValue::Static(self.config.poll_interval_ms),
+            batch_size: ConfigValue::Static(self.config.batch_size),
+        };
+
+        match serde_json::to_value(&dto) {
+            Ok(serde_json::Value::Object(map)) => map.into_iter().collect(),
+            _ => HashMap::new(),
+        }
+    }
+
+    fn auto_start(&self) -> bool {
+        self.base.get_auto_start()
+    }
+
+    async fn start(&self) -> Result<()> {
+        log_component_start("Neo4j Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Starting,
+                Some("Starting Neo4j source".to_string()),
+            )
+            .await;
+
+        let cursor_store = self
+            .base
+            .state_store()
+            .await
+            .map(|store| CursorStore::new(store, self.base.id.clone()));
+        if cursor_store.is_none() {
+            info!(
+                "[{}] No state store configured, the CDC feed restarts from {:?} after a restart",
+                self.base.id, self.config.start_from
+            );
+        }
+
+        let ctx = PollContext {
+            source_id: self.base.id.clone(),
+            config: self.config.clone(),
+            dispatchers: self.base.dispatchers.clone(),
+            cursor_store,
+        };
+
+        let instance_id = self
+            .base
+            .context()
+            .await
+            .map(|c| c.instance_id)
+            .unwrap_or_default();
+
+        let span = tracing::info_span!(
+            "neo4j_source_cdc",
+            instance_id = %instance_id,
+            component_id = %self.base.id,
+            component_type = "source"
+        );
+        let task = tokio::spawn(Self::run(ctx, self.base.status_handle()).instrument(span));
+
+        *self.base.task_handle.write().await = Some(task);
+
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        log_component_stop("Neo4j Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopping,
+                Some("Stopping Neo4j source".to_string()),
+            )
+            .await;
+
+        if let Some(handle) = self.base.task_handle.write().await.take() {
+            handle.abort();
+            let _ = handle.await;
+        }
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopped,
+                Some("Neo4j source stopped".to_string()),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn status(&self) -> ComponentStatus {
+        self.base.get_status().await
+    }
+
+    async fn subscribe(
+        &self,
+        settings: drasi_lib::config::SourceSubscriptionSettings,
+    ) -> Result<SubscriptionResponse> {
+        self.base.subscribe_with_bootstrap(&settings, "Neo4j").await
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    async fn initialize(&self, context: drasi_lib::context::SourceRuntimeContext) {
+        self.base.initialize(context).await;
+    }
+
+    async fn set_bootstrap_provider(
+        &self,
+        provider: Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>,
+    ) {
+        self.base.set_bootstrap_provider(provider).await;
+    }
+}
+
+/// Builder for [`Neo4jSource`] instances.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use drasi_source_neo4j::{Neo4jSource, StartFrom};
+///
+/// let source = Neo4jSource::builder("social")
+///     .with_uri("neo4j://localhost:7687")
+///     .with_password("secret")
+///     .with_label("Person")
+///     .with_relation_type("KNOWS")
+///     .with_start_from(StartFrom::Earliest)
+///     .build()?;
+/// ```
+pub struct Neo4jSourceBuilder {
+    id: String,
+    config: Neo4jSourceConfig,
+    dispatch_mode: Option<DispatchMode>,
+    dispatch_buffer_capacity: Option<usize>,
+    bootstrap_provider: Option<Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>>,
+    auto_start: bool,
+}
+
+impl Neo4jSourceBuilder {
+    /// Create a new builder with the given ID and default values.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            config: Neo4jSourceConfig::default(),
+            dispatch_mode: None,
+            dispatch_buffer_capacity: None,
+            bootstrap_provider: None,
+            auto_start: true,
+        }
+    }
+
+    /// Set the connection URI.
+    pub fn with_uri(mut self, uri: impl Into<String>) -> Self {
+        self.config.uri = uri.into();
+        self
+    }
+
+    /// Set the database user.
+    pub fn with_user(mut self, user: impl Into<String>) -> Self {
+        self.config.user = user.into();
+        self
+    }
+
+    /// Set the database password.
+    pub fn with_password(mut self, password: impl Into<String>) -> Self {
+        self.config.password = password.into();
+        self
+    }
+
+    /// Set the database name.
+    pub fn with_database(mut self, database: impl Into<String>) -> Self {
+        self.config.database = Some(database.into());
+        self
+    }
+
+    /// Follow nodes carrying a label.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.config.labels.push(label.into());
+        self
+    }
+
+    /// Follow relationships of a type.
+    pub fn with_relation_type(mut self, relation_type: impl Into<String>) -> Self {
+        self.config.relation_types.push(relation_type.into());
+        self
+    }
+
+    /// Set where to start reading when no cursor is stored.
+    pub fn with_start_from(mut self, start_from: StartFrom) -> Self {
+        self.config.start_from = start_from;
+        self
+    }
+
+    /// Set the interval between CDC queries in milliseconds.
+    pub fn with_poll_interval_ms(mut self, interval_ms: u64) -> Self {
+        self.config.poll_interval_ms = interval_ms;
+        self
+    }
+
+    /// Set the maximum number of changes read per query.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.config.batch_size = batch_size;
+        self
+    }
+
+    /// Set the dispatch mode for this source.
+    pub fn with_dispatch_mode(mut self, mode: DispatchMode) -> Self {
+        self.dispatch_mode = Some(mode);
+        self
+    }
+
+    /// Set the dispatch buffer capacity.
+    pub fn with_dispatch_buffer_capacity(mut self, capacity: usize) -> Self {
+        self.dispatch_buffer_capacity = Some(capacity);
+        self
+    }
+
+    /// Set the bootstrap provider for initial data delivery.
+    pub fn with_bootstrap_provider(
+        mut self,
+        provider: impl drasi_lib::bootstrap::BootstrapProvider + 'static,
+    ) -> Self {
+        self.bootstrap_provider = Some(Box::new(provider));
+        self
+    }
+
+    /// Set whether this source should auto-start when DrasiLib starts.
+    pub fn with_auto_start(mut self, auto_start: bool) -> Self {
+        self.auto_start = auto_start;
+        self
+    }
+
+    /// Set the full configuration at once.
+    pub fn with_config(mut self, config: Neo4jSourceConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Build the Neo4j source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid or the base source
+    /// cannot be initialized.
+    pub fn build(self) -> Result<Neo4jSource> {
+        self.config.validate()?;
+
+        let mut params = SourceBaseParams::new(&self.id).with_auto_start(self.auto_start);
+        if let Some(mode) = self.dispatch_mode {
+            params = params.with_dispatch_mode(mode);
+        }
+        if let Some(capacity) = self.dispatch_buffer_capacity {
+            params = params.with_dispatch_buffer_capacity(capacity);
+        }
+        if let Some(provider) = self.bootstrap_provider {
+            params = params.with_bootstrap_provider(provider);
+        }
+
+        Ok(Neo4jSource {
+            base: SourceBase::new(params)?,
+            config: self.config,
+        })
+    }
+}
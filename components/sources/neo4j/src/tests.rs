@@ -0,0 +1,306 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for the Neo4j source plugin.
+
+use super::*;
+use crate::cdc::CdcEvent;
+use crate::cursor::CursorStore;
+use drasi_core::models::{Element, ElementValue, SourceChange};
+use drasi_lib::Source;
+use drasi_plugin_sdk::prelude::SourcePluginDescriptor;
+use serde_json::json;
+use std::sync::Arc;
+
+fn config() -> Neo4jSourceConfig {
+    Neo4jSourceConfig {
+        uri: "neo4j://localhost:7687".to_string(),
+        ..Default::default()
+    }
+}
+
+fn change(event: serde_json::Value) -> anyhow::Result<SourceChange> {
+    serde_json::from_value::<CdcEvent>(event)
+        .unwrap()
+        .into_source_change("graph", 1_700_000_000_000)
+}
+
+mod builder {
+    use super::*;
+
+    #[test]
+    fn test_builder_with_valid_config() {
+        let source = Neo4jSource::builder("test-source")
+            .with_uri("neo4j+s://graph.example.com")
+            .with_user("drasi")
+            .with_password("s3cret")
+            .with_database("social")
+            .with_label("Person")
+            .with_relation_type("KNOWS")
+            .with_start_from(StartFrom::Earliest)
+            .with_batch_size(200)
+            .build()
+            .unwrap();
+
+        assert_eq!(source.id(), "test-source");
+        assert_eq!(source.type_name(), "neo4j");
+        let props = source.properties();
+        assert_eq!(props.get("password"), Some(&json!("***")));
+        assert_eq!(props.get("database"), Some(&json!("social")));
+        assert_eq!(props.get("labels"), Some(&json!(["Person"])));
+        assert_eq!(props.get("relationTypes"), Some(&json!(["KNOWS"])));
+        assert_eq!(props.get("startFrom"), Some(&json!("earliest")));
+        assert_eq!(props.get("batchSize"), Some(&json!(200)));
+    }
+
+    #[test]
+    fn test_builder_rejects_invalid_config() {
+        let build = |config: Neo4jSourceConfig| Neo4jSource::new("test-source", config);
+
+        assert!(build(config()).is_ok());
+        assert!(build(Neo4jSourceConfig {
+            uri: "http://localhost:7474".to_string(),
+            ..config()
+        })
+        .is_err());
+        assert!(build(Neo4jSourceConfig {
+            user: String::new(),
+            ..config()
+        })
+        .is_err());
+        assert!(build(Neo4jSourceConfig {
+            database: Some(String::new()),
+            ..config()
+        })
+        .is_err());
+        assert!(build(Neo4jSourceConfig {
+            labels: vec![String::new()],
+            ..config()
+        })
+        .is_err());
+        assert!(build(Neo4jSourceConfig {
+            poll_interval_ms: 0,
+            ..config()
+        })
+        .is_err());
+        assert!(build(Neo4jSourceConfig {
+            batch_size: 0,
+            ..config()
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_config_deserialization_defaults() {
+        let config: Neo4jSourceConfig =
+            serde_json::from_value(json!({"uri": "bolt://localhost:7687"})).unwrap();
+
+        assert_eq!(config.user, "neo4j");
+        assert_eq!(config.start_from, StartFrom::Now);
+        assert_eq!(config.poll_interval_ms, 1000);
+        assert_eq!(config.batch_size, 1000);
+    }
+}
+
+mod cdc {
+    use super::*;
+
+    #[test]
+    fn test_node_changes() {
+        let created = change(json!({
+            "elementId": "4:abc:1",
+            "eventType": "n",
+            "operation": "c",
+            "labels": ["Person"],
+            "state": {
+                "before": null,
+                "after": {"labels": ["Person", "Admin"], "properties": {"name": "Ada"}}
+            }
+        }))
+        .unwrap();
+        let SourceChange::Insert {
+            element:
+                Element::Node {
+                    metadata,
+                    properties,
+                },
+        } = created
+        else {
+            panic!("expected a node insert");
+        };
+        assert_eq!(metadata.reference.element_id.as_ref(), "4:abc:1");
+        assert_eq!(metadata.labels.len(), 2);
+        assert_eq!(metadata.effective_from, 1_700_000_000_000);
+        assert_eq!(
+            properties.get("name"),
+            Some(&ElementValue::String(Arc::from("Ada")))
+        );
+
+        let updated = change(json!({
+            "elementId": "4:abc:1",
+            "eventType": "n",
+            "operation": "u",
+            "labels": ["Person"],
+            "state": {
+                "before": {"labels": ["Person"], "properties": {"name": "Ada"}},
+                "after": {"labels": ["Person"], "properties": {"name": "Ada L."}}
+            }
+        }))
+        .unwrap();
+        assert!(matches!(updated, SourceChange::Update { .. }));
+
+        let deleted = change(json!({
+            "elementId": "4:abc:1",
+            "eventType": "n",
+            "operation": "d",
+            "labels": ["Person"],
+            "state": {
+                "before": {"labels": ["Person"], "properties": {"name": "Ada L."}},
+                "after": null
+            }
+        }))
+        .unwrap();
+        let SourceChange::Delete { metadata } = deleted else {
+            panic!("expected a delete");
+        };
+        assert_eq!(metadata.labels[0].as_ref(), "Person");
+    }
+
+    #[test]
+    fn test_relationship_changes() {
+        let created = change(json!({
+            "elementId": "5:abc:9",
+            "eventType": "r",
+            "operation": "c",
+            "type": "KNOWS",
+            "start": {"elementId": "4:abc:1", "labels": ["Person"]},
+            "end": {"elementId": "4:abc:2", "labels": ["Person"]},
+            "state": {"before": null, "after": {"properties": {"since": 2020}}}
+        }))
+        .unwrap();
+        let SourceChange::Insert {
+            element:
+                Element::Relation {
+                    metadata,
+                    properties,
+                    in_node,
+                    out_node,
+                },
+        } = created
+        else {
+            panic!("expected a relation insert");
+        };
+        assert_eq!(metadata.labels[0].as_ref(), "KNOWS");
+        assert_eq!(in_node.element_id.as_ref(), "4:abc:1");
+        assert_eq!(out_node.element_id.as_ref(), "4:abc:2");
+        assert_eq!(properties.get("since"), Some(&ElementValue::Integer(2020)));
+
+        let deleted = change(json!({
+            "elementId": "5:abc:9",
+            "eventType": "r",
+            "operation": "d",
+            "type": "KNOWS",
+            "start": {"elementId": "4:abc:1"},
+            "end": {"elementId": "4:abc:2"},
+            "state": {"before": {"properties": {"since": 2020}}, "after": null}
+        }))
+        .unwrap();
+        assert!(matches!(deleted, SourceChange::Delete { .. }));
+    }
+
+    #[test]
+    fn test_incomplete_changes() {
+        // Without FULL enrichment there is no after state.
+        assert!(change(json!({
+            "elementId": "4:abc:1",
+            "eventType": "n",
+            "operation": "u",
+            "labels": ["Person"]
+        }))
+        .is_err());
+        assert!(change(json!({
+            "elementId": "5:abc:9",
+            "eventType": "r",
+            "operation": "c",
+            "type": "KNOWS",
+            "state": {"after": {"properties": {}}}
+        }))
+        .is_err());
+    }
+}
+
+mod cursor {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_cursor_roundtrip() {
+        let store = CursorStore::new(Arc::new(drasi_lib::MemoryStateStoreProvider::new()), "src");
+        assert_eq!(store.load().await.unwrap(), None);
+
+        store
+            .save("A3V0mXa4S0CLx7UxqXSrxZ8AAAAAAAAABAAAAAAAAAAAAAAAAAAAAAA=")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            store.load().await.unwrap().as_deref(),
+            Some("A3V0mXa4S0CLx7UxqXSrxZ8AAAAAAAAABAAAAAAAAAAAAAAAAAAAAAA=")
+        );
+    }
+}
+
+mod descriptor {
+    use super::*;
+    use crate::descriptor::Neo4jSourceDescriptor;
+
+    #[tokio::test]
+    async fn test_descriptor_creates_source() {
+        let descriptor = Neo4jSourceDescriptor;
+        assert_eq!(descriptor.kind(), "neo4j");
+
+        let source = descriptor
+            .create_source(
+                "neo4j-1",
+                &json!({
+                    "uri": "neo4j://localhost:7687",
+                    "password": "secret",
+                    "labels": ["Person"],
+                    "startFrom": "earliest",
+                    "pollIntervalMs": 500
+                }),
+                false,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(source.id(), "neo4j-1");
+        assert!(!source.auto_start());
+        let props = source.properties();
+        assert_eq!(props.get("user"), Some(&json!("neo4j")));
+        assert_eq!(props.get("startFrom"), Some(&json!("earliest")));
+        assert_eq!(props.get("pollIntervalMs"), Some(&json!(500)));
+    }
+
+    #[tokio::test]
+    async fn test_descriptor_rejects_unknown_fields() {
+        let result = Neo4jSourceDescriptor
+            .create_source(
+                "neo4j-1",
+                &json!({"uri": "neo4j://localhost:7687", "bogus": 1}),
+                true,
+            )
+            .await;
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,328 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration types for the OPC UA source plugin.
+//!
+//! This module defines the server endpoint and security settings, the
+//! subscription timing, and how monitored items map onto graph nodes.
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+fn default_application_name() -> String {
+    "Drasi OPC UA Source".to_string()
+}
+
+fn default_pki_dir() -> String {
+    "./pki".to_string()
+}
+
+fn default_publishing_interval_ms() -> u64 {
+    1000
+}
+
+fn default_sampling_interval_ms() -> u64 {
+    500
+}
+
+fn default_queue_size() -> u32 {
+    10
+}
+
+fn default_reconnect_interval_ms() -> u64 {
+    5000
+}
+
+/// Security policy of the secure channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SecurityPolicy {
+    #[default]
+    None,
+    Basic256Sha256,
+    Aes128Sha256RsaOaep,
+    Aes256Sha256RsaPss,
+}
+
+/// Message security mode of the secure channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SecurityMode {
+    #[default]
+    None,
+    Sign,
+    SignAndEncrypt,
+}
+
+/// User identity presented when activating the session.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OpcUaAuth {
+    /// No user identity.
+    #[default]
+    Anonymous,
+    /// User name and password.
+    UserName { username: String, password: String },
+    /// X.509 user certificate and its private key, both PEM or DER files.
+    Certificate {
+        certificate_path: String,
+        private_key_path: String,
+    },
+}
+
+/// A node to monitor and the graph property its value is written to.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MonitoredNode {
+    /// OPC UA node ID, e.g. `ns=2;s=Line1.Press.Temperature`.
+    pub node_id: String,
+
+    /// ID of the graph node that receives the value.
+    pub element_id: String,
+
+    /// Property that holds the value.
+    pub property: String,
+
+    /// Label of the graph node. Falls back to the source-wide `label`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+
+    /// Sampling interval for this node, overriding `sampling_interval_ms`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sampling_interval_ms: Option<u64>,
+}
+
+/// OPC UA source configuration.
+///
+/// The source opens a session to `endpoint_url`, creates one subscription and
+/// adds a monitored item for every entry in `nodes`. Monitored items that
+/// share an `element_id` update different properties of the same graph node.
+///
+/// # Example
+///
+/// ```rust
+/// use drasi_source_opcua::{MonitoredNode, OpcUaSourceConfig};
+///
+/// let config = OpcUaSourceConfig {
+///     endpoint_url: "opc.tcp://plc-1:4840".to_string(),
+///     nodes: vec![MonitoredNode {
+///         node_id: "ns=2;s=Press1.Temperature".to_string(),
+///         element_id: "press-1".to_string(),
+///         property: "temperature".to_string(),
+///         label: None,
+///         sampling_interval_ms: None,
+///     }],
+///     label: Some("Machine".to_string()),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OpcUaSourceConfig {
+    /// Server endpoint, e.g. `opc.tcp://plc-1:4840`.
+    pub endpoint_url: String,
+
+    /// Security policy of the secure channel.
+    ///
+    /// **Default**: `none`
+    #[serde(default)]
+    pub security_policy: SecurityPolicy,
+
+    /// Message security mode of the secure channel.
+    ///
+    /// **Default**: `none`
+    #[serde(default)]
+    pub security_mode: SecurityMode,
+
+    /// User identity.
+    ///
+    /// **Default**: `anonymous`
+    #[serde(default)]
+    pub auth: OpcUaAuth,
+
+    /// Application name presented to the server.
+    ///
+    /// **Default**: `"Drasi OPC UA Source"`
+    #[serde(default = "default_application_name")]
+    pub application_name: String,
+
+    /// Directory holding the client's application instance certificate and
+    /// the trusted and rejected server certificates.
+    ///
+    /// **Default**: `"./pki"`
+    #[serde(default = "default_pki_dir")]
+    pub pki_dir: String,
+
+    /// Trust server certificates that are not yet in the trusted folder.
+    /// Only use this on isolated networks.
+    ///
+    /// **Default**: `false`
+    #[serde(default)]
+    pub trust_server_certs: bool,
+
+    /// Nodes to monitor.
+    pub nodes: Vec<MonitoredNode>,
+
+    /// Interval at which the server sends notifications, in milliseconds.
+    ///
+    /// **Default**: `1000`
+    #[serde(default = "default_publishing_interval_ms")]
+    pub publishing_interval_ms: u64,
+
+    /// Interval at which the server samples monitored nodes, in milliseconds.
+    ///
+    /// **Default**: `500`
+    #[serde(default = "default_sampling_interval_ms")]
+    pub sampling_interval_ms: u64,
+
+    /// Number of samples the server queues per monitored item between publishes.
+    ///
+    /// **Default**: `10`
+    #[serde(default = "default_queue_size")]
+    pub queue_size: u32,
+
+    /// Milliseconds to wait before reconnecting after the session is lost.
+    ///
+    /// **Default**: `5000`
+    #[serde(default = "default_reconnect_interval_ms")]
+    pub reconnect_interval_ms: u64,
+
+    /// Label for graph nodes whose monitored nodes set no label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+impl Default for OpcUaSourceConfig {
+    fn default() -> Self {
+        Self {
+            endpoint_url: String::new(),
+            security_policy: SecurityPolicy::default(),
+            security_mode: SecurityMode::default(),
+            auth: OpcUaAuth::default(),
+            application_name: default_application_name(),
+            pki_dir: default_pki_dir(),
+            trust_server_certs: false,
+            nodes: Vec::new(),
+            publishing_interval_ms: default_publishing_interval_ms(),
+            sampling_interval_ms: default_sampling_interval_ms(),
+            queue_size: default_queue_size(),
+            reconnect_interval_ms: default_reconnect_interval_ms(),
+            label: None,
+        }
+    }
+}
+
+impl OpcUaSourceConfig {
+    /// Validate the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `endpoint_url` is not an `opc.tcp://` URL
+    /// - `security_policy` and `security_mode` disagree about using security
+    /// - `nodes` is empty, a node ID is invalid or repeated, or two nodes write
+    ///   the same property of the same element
+    /// - an interval or `queue_size` is zero
+    /// - user name or certificate credentials are empty
+    pub fn validate(&self) -> Result<()> {
+        if !self.endpoint_url.starts_with("opc.tcp://") {
+            return Err(anyhow!(
+                "Validation error: endpoint_url must start with opc.tcp://"
+            ));
+        }
+
+        if (self.security_policy == SecurityPolicy::None)
+            != (self.security_mode == SecurityMode::None)
+        {
+            return Err(anyhow!(
+                "Validation error: security_policy and security_mode must both be none or both be set"
+            ));
+        }
+
+        match &self.auth {
+            OpcUaAuth::Anonymous => {}
+            OpcUaAuth::UserName { username, .. } if username.is_empty() => {
+                return Err(anyhow!("Validation error: auth username cannot be empty"));
+            }
+            OpcUaAuth::UserName { .. } => {}
+            OpcUaAuth::Certificate {
+                certificate_path,
+                private_key_path,
+            } => {
+                if certificate_path.is_empty() || private_key_path.is_empty() {
+                    return Err(anyhow!(
+                        "Validation error: auth certificate_path and private_key_path cannot be empty"
+                    ));
+                }
+            }
+        }
+
+        if self.nodes.is_empty() {
+            return Err(anyhow!("Validation error: at least one node is required"));
+        }
+
+        let mut node_ids = HashSet::new();
+        let mut targets = HashSet::new();
+        for node in &self.nodes {
+            opcua::types::NodeId::from_str(&node.node_id).map_err(|_| {
+                anyhow!(
+                    "Validation error: '{}' is not a valid node ID",
+                    node.node_id
+                )
+            })?;
+            if node.element_id.is_empty() || node.property.is_empty() {
+                return Err(anyhow!(
+                    "Validation error: node '{}' needs an element_id and a property",
+                    node.node_id
+                ));
+            }
+            if !node_ids.insert(node.node_id.as_str()) {
+                return Err(anyhow!(
+                    "Validation error: node '{}' is listed more than once",
+                    node.node_id
+                ));
+            }
+            if !targets.insert((node.element_id.as_str(), node.property.as_str())) {
+                return Err(anyhow!(
+                    "Validation error: property '{}' of element '{}' is written by more than one node",
+                    node.property,
+                    node.element_id
+                ));
+            }
+            if node.sampling_interval_ms == Some(0) {
+                return Err(anyhow!(
+                    "Validation error: sampling_interval_ms of node '{}' must be greater than 0",
+                    node.node_id
+                ));
+            }
+        }
+
+        if self.publishing_interval_ms == 0
+            || self.sampling_interval_ms == 0
+            || self.reconnect_interval_ms == 0
+        {
+            return Err(anyhow!(
+                "Validation error: publishing_interval_ms, sampling_interval_ms and reconnect_interval_ms must be greater than 0"
+            ));
+        }
+
+        if self.queue_size == 0 {
+            return Err(anyhow!(
+                "Validation error: queue_size must be greater than 0"
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion of OPC UA data values into Drasi source changes.
+
+use drasi_core::models::{Element, ElementMetadata, ElementReference, SourceChange};
+use drasi_lib::sources::convert_json_to_element_properties;
+use opcua::types::{DataValue, Variant};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::config::{MonitoredNode, OpcUaSourceConfig};
+
+/// Label used when neither the monitored node nor the source sets one.
+const FALLBACK_LABEL: &str = "OpcUaNode";
+
+fn float_to_json(value: f64) -> Value {
+    serde_json::Number::from_f64(value)
+        .map(Value::Number)
+        .unwrap_or(Value::Null)
+}
+
+/// Convert an OPC UA variant to JSON.
+///
+/// Numbers, booleans, strings and date-times map to their JSON equivalents and
+/// arrays map element-wise. Other types use their text representation.
+pub(crate) fn variant_to_json(variant: &Variant) -> Value {
+    match variant {
+        Variant::Empty => Value::Null,
+        Variant::Boolean(v) => Value::Bool(*v),
+        Variant::SByte(v) => Value::from(*v),
+        Variant::Byte(v) => Value::from(*v),
+        Variant::Int16(v) => Value::from(*v),
+        Variant::UInt16(v) => Value::from(*v),
+        Variant::Int32(v) => Value::from(*v),
+        Variant::UInt32(v) => Value::from(*v),
+        Variant::Int64(v) => Value::from(*v),
+        Variant::UInt64(v) => Value::from(*v),
+        Variant::Float(v) => float_to_json(f64::from(*v)),
+        Variant::Double(v) => float_to_json(*v),
+        Variant::String(v) => v.value().clone().map_or(Value::Null, Value::String),
+        Variant::DateTime(v) => Value::String(v.as_chrono().to_rfc3339()),
+        Variant::LocalizedText(v) => v.text.value().clone().map_or(Value::Null, Value::String),
+        Variant::Array(array) => Value::Array(array.values.iter().map(variant_to_json).collect()),
+        other => Value::String(other.to_string()),
+    }
+}
+
+/// Convert a data value to JSON. Values with a bad status become `null`, so
+/// queries can tell a failed sensor from a stale reading.
+pub(crate) fn data_value_to_json(data_value: &DataValue) -> Value {
+    if data_value.status.is_some_and(|status| status.is_bad()) {
+        return Value::Null;
+    }
+    data_value
+        .value
+        .as_ref()
+        .map_or(Value::Null, variant_to_json)
+}
+
+/// Resolve the label of the graph node written by a monitored node.
+pub(crate) fn label_for_node(node: &MonitoredNode, config: &OpcUaSourceConfig) -> String {
+    node.label
+        .clone()
+        .or_else(|| config.label.clone())
+        .unwrap_or_else(|| FALLBACK_LABEL.to_string())
+}
+
+/// Latest known properties of every graph node written by the source.
+///
+/// Each monitored item sets one property, but a change carries the whole
+/// node, so the other properties are replayed from this cache.
+#[derive(Debug, Default)]
+pub(crate) struct ElementCache {
+    elements: HashMap<String, Map<String, Value>>,
+}
+
+impl ElementCache {
+    /// Record a new value and return the resulting change: an insert the
+    /// first time the element is written, an update afterwards.
+    pub(crate) fn apply(
+        &mut self,
+        source_id: &str,
+        element_id: &str,
+        label: &str,
+        property: &str,
+        value: Value,
+    ) -> SourceChange {
+        let exists = self.elements.contains_key(element_id);
+        let properties = self.elements.entry(element_id.to_string()).or_default();
+        properties.insert(property.to_string(), value);
+
+        let element = Element::Node {
+            metadata: ElementMetadata {
+                reference: ElementReference::new(source_id, element_id),
+                labels: Arc::from(vec![Arc::from(label)]),
+                effective_from: chrono::Utc::now().timestamp_millis() as u64,
+            },
+            properties: convert_json_to_element_properties(properties),
+        };
+
+        if exists {
+            SourceChange::Update { element }
+        } else {
+            SourceChange::Insert { element }
+        }
+    }
+}
@@ -0,0 +1,244 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! OPC UA source plugin descriptor and configuration DTOs.
+
+use crate::{
+    MonitoredNode, OpcUaAuth, OpcUaSourceBuilder, OpcUaSourceConfig, SecurityMode, SecurityPolicy,
+};
+use drasi_plugin_sdk::prelude::*;
+use utoipa::OpenApi;
+
+/// Security policy DTO.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::opcua::SecurityPolicy)]
+#[serde(rename_all = "snake_case")]
+pub enum SecurityPolicyDto {
+    #[default]
+    None,
+    Basic256Sha256,
+    Aes128Sha256RsaOaep,
+    Aes256Sha256RsaPss,
+}
+
+/// Message security mode DTO.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::opcua::SecurityMode)]
+#[serde(rename_all = "snake_case")]
+pub enum SecurityModeDto {
+    #[default]
+    None,
+    Sign,
+    SignAndEncrypt,
+}
+
+/// User identity DTO.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::opcua::OpcUaAuth)]
+#[serde(tag = "type", rename_all = "camelCase", deny_unknown_fields)]
+pub enum OpcUaAuthDto {
+    #[default]
+    Anonymous,
+    UserName {
+        username: ConfigValue<String>,
+        password: ConfigValue<String>,
+    },
+    Certificate {
+        #[serde(rename = "certificatePath")]
+        certificate_path: ConfigValue<String>,
+        #[serde(rename = "privateKeyPath")]
+        private_key_path: ConfigValue<String>,
+    },
+}
+
+/// Monitored node DTO.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::opcua::MonitoredNode)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct MonitoredNodeDto {
+    pub node_id: String,
+    pub element_id: String,
+    pub property: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sampling_interval_ms: Option<u64>,
+}
+
+/// OPC UA source configuration DTO.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::opcua::OpcUaSourceConfig)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct OpcUaSourceConfigDto {
+    pub endpoint_url: ConfigValue<String>,
+    #[serde(default)]
+    #[schema(value_type = source::opcua::SecurityPolicy)]
+    pub security_policy: SecurityPolicyDto,
+    #[serde(default)]
+    #[schema(value_type = source::opcua::SecurityMode)]
+    pub security_mode: SecurityModeDto,
+    #[serde(default)]
+    #[schema(value_type = source::opcua::OpcUaAuth)]
+    pub auth: OpcUaAuthDto,
+    #[serde(default = "default_application_name")]
+    pub application_name: ConfigValue<String>,
+    #[serde(default = "default_pki_dir")]
+    pub pki_dir: ConfigValue<String>,
+    #[serde(default = "default_trust_server_certs")]
+    pub trust_server_certs: ConfigValue<bool>,
+    #[schema(value_type = Vec<source::opcua::MonitoredNode>)]
+    pub nodes: Vec<MonitoredNodeDto>,
+    #[serde(default = "default_publishing_interval_ms")]
+    pub publishing_interval_ms: ConfigValue<u64>,
+    #[serde(default = "default_sampling_interval_ms")]
+    pub sampling_interval_ms: ConfigValue<u64>,
+    #[serde(default = "default_queue_size")]
+    pub queue_size: ConfigValue<u32>,
+    #[serde(default = "default_reconnect_interval_ms")]
+    pub reconnect_interval_ms: ConfigValue<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+fn default_application_name() -> ConfigValue<String> {
+    ConfigValue::Static("Drasi OPC UA Source".to_string())
+}
+
+fn default_pki_dir() -> ConfigValue<String> {
+    ConfigValue::Static("./pki".to_string())
+}
+
+fn default_trust_server_certs() -> ConfigValue<bool> {
+    ConfigValue::Static(false)
+}
+
+fn default_publishing_interval_ms() -> ConfigValue<u64> {
+    ConfigValue::Static(1000)
+}
+
+fn default_sampling_interval_ms() -> ConfigValue<u64> {
+    ConfigValue::Static(500)
+}
+
+fn default_queue_size() -> ConfigValue<u32> {
+    ConfigValue::Static(10)
+}
+
+fn default_reconnect_interval_ms() -> ConfigValue<u64> {
+    ConfigValue::Static(5000)
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(
+    OpcUaSourceConfigDto,
+    MonitoredNodeDto,
+    OpcUaAuthDto,
+    SecurityPolicyDto,
+    SecurityModeDto
+)))]
+struct OpcUaSourceSchemas;
+
+/// Descriptor for the OPC UA source plugin.
+pub struct OpcUaSourceDescriptor;
+
+#[async_trait]
+impl SourcePluginDescriptor for OpcUaSourceDescriptor {
+    fn kind(&self) -> &str {
+        "opcua"
+    }
+
+    fn config_version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn config_schema_name(&self) -> &str {
+        "source.opcua.OpcUaSourceConfig"
+    }
+
+    fn config_schema_json(&self) -> String {
+        let api = OpcUaSourceSchemas::openapi();
+        serde_json::to_string(
+            &api.components
+                .as_ref()
+                .expect("OpenAPI components missing")
+                .schemas,
+        )
+        .expect("Failed to serialize config schema")
+    }
+
+    async fn create_source(
+        &self,
+        id: &str,
+        config_json: &serde_json::Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn drasi_lib::sources::Source>> {
+        let dto: OpcUaSourceConfigDto = serde_json::from_value(config_json.clone())?;
+        let mapper = DtoMapper::new();
+
+        let config = OpcUaSourceConfig {
+            endpoint_url: mapper.resolve_string(&dto.endpoint_url)?,
+            security_policy: match dto.security_policy {
+                SecurityPolicyDto::None => SecurityPolicy::None,
+                SecurityPolicyDto::Basic256Sha256 => SecurityPolicy::Basic256Sha256,
+                SecurityPolicyDto::Aes128Sha256RsaOaep => SecurityPolicy::Aes128Sha256RsaOaep,
+                SecurityPolicyDto::Aes256Sha256RsaPss => SecurityPolicy::Aes256Sha256RsaPss,
+            },
+            security_mode: match dto.security_mode {
+                SecurityModeDto::None => SecurityMode::None,
+                SecurityModeDto::Sign => SecurityMode::Sign,
+                SecurityModeDto::SignAndEncrypt => SecurityMode::SignAndEncrypt,
+            },
+            auth: match &dto.auth {
+                OpcUaAuthDto::Anonymous => OpcUaAuth::Anonymous,
+                OpcUaAuthDto::UserName { username, password } => OpcUaAuth::UserName {
+                    username: mapper.resolve_string(username)?,
+                    password: mapper.resolve_string(password)?,
+                },
+                OpcUaAuthDto::Certificate {
+                    certificate_path,
+                    private_key_path,
+                } => OpcUaAuth::Certificate {
+                    certificate_path: mapper.resolve_string(certificate_path)?,
+                    private_key_path: mapper.resolve_string(private_key_path)?,
+                },
+            },
+            application_name: mapper.resolve_string(&dto.application_name)?,
+            pki_dir: mapper.resolve_string(&dto.pki_dir)?,
+            trust_server_certs: mapper.resolve_typed(&dto.trust_server_certs)?,
+            nodes: dto
+                .nodes
+                .into_iter()
+                .map(|node| MonitoredNode {
+                    node_id: node.node_id,
+                    element_id: node.element_id,
+                    property: node.property,
+                    label: node.label,
+                    sampling_interval_ms: node.sampling_interval_ms,
+                })
+                .collect(),
+            publishing_interval_ms: mapper.resolve_typed(&dto.publishing_interval_ms)?,
+            sampling_interval_ms: mapper.resolve_typed(&dto.sampling_interval_ms)?,
+            queue_size: mapper.resolve_typed(&dto.queue_size)?,
+            reconnect_interval_ms: mapper.resolve_typed(&dto.reconnect_interval_ms)?,
+            label: dto.label,
+        };
+
+        let source = OpcUaSourceBuilder::new(id)
+            .with_config(config)
+            .with_auto_start(auto_start)
+            .build()?;
+
+        Ok(Box::new(source))
+    }
+}
@@ -0,0 +1,86 @@
+#![allow(unexpected_cfgs)]
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! OPC UA Source Plugin for drasi-lib.
+//!
+//! This plugin subscribes to nodes on an OPC UA server and writes their value
+//! changes to properties of graph nodes, so machines can feed continuous
+//! queries without an intermediate broker.
+//!
+//! # Node Mapping
+//!
+//! Each configured [`MonitoredNode`] names an OPC UA node ID, the graph node it
+//! writes to (`element_id`) and the property that holds its value. Several
+//! monitored nodes can write different properties of the same graph node:
+//!
+//! | OPC UA node | Graph node | Property |
+//! |-------------|------------|----------|
+//! | `ns=2;s=Press1.Temperature` | `press-1` | `temperature` |
+//! | `ns=2;s=Press1.Pressure` | `press-1` | `pressure` |
+//!
+//! The first value written to a graph node inserts it; later values update it
+//! with all properties known so far. Values with a bad status code are written
+//! as `null`.
+//!
+//! # Sessions and Security
+//!
+//! The source opens one session with one subscription. Sampling and publishing
+//! intervals are configurable, with per-node sampling overrides. Sessions can
+//! be anonymous or authenticate with a user name and password or an X.509 user
+//! certificate, over any of the supported security policies. After a
+//! connection loss the client reconnects every `reconnect_interval_ms` and
+//! re-creates the subscription.
+//!
+//! # Usage Example
+//!
+//! ```rust,ignore
+//! use drasi_source_opcua::{OpcUaSource, SecurityMode, SecurityPolicy};
+//!
+//! let source = OpcUaSource::builder("press-line")
+//!     .with_endpoint_url("opc.tcp://plc-1:4840")
+//!     .with_security(SecurityPolicy::Basic256Sha256, SecurityMode::SignAndEncrypt)
+//!     .with_user_name("operator", "secret")
+//!     .with_node("ns=2;s=Press1.Temperature", "press-1", "temperature")
+//!     .with_node("ns=2;s=Press1.Pressure", "press-1", "pressure")
+//!     .with_sampling_interval_ms(250)
+//!     .with_label("Machine")
+//!     .build()?;
+//!
+//! drasi.add_source(source).await?;
+//! ```
+
+mod config;
+mod conversion;
+pub mod descriptor;
+mod session;
+mod source;
+
+#[cfg(test)]
+mod tests;
+
+pub use config::{MonitoredNode, OpcUaAuth, OpcUaSourceConfig, SecurityMode, SecurityPolicy};
+pub use source::{OpcUaSource, OpcUaSourceBuilder};
+
+/// Dynamic plugin entry point.
+#[cfg(feature = "dynamic-plugin")]
+drasi_plugin_sdk::export_plugin!(
+    plugin_id = "opcua-source",
+    core_version = env!("CARGO_PKG_VERSION"),
+    lib_version = env!("CARGO_PKG_VERSION"),
+    plugin_version = env!("CARGO_PKG_VERSION"),
+    source_descriptors = [descriptor::OpcUaSourceDescriptor],
+    reaction_descriptors = [],
+    bootstrap_descriptors = [],
+);
@@ -0,0 +1,221 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! OPC UA session and subscription setup.
+//!
+//! The `opcua` client runs its own runtime and blocks while connecting, so the
+//! session is created on a dedicated thread and data changes are forwarded to
+//! the source task over a channel.
+
+use anyhow::{anyhow, Result};
+use log::{info, warn};
+use opcua::client::prelude::{
+    ClientBuilder, DataChangeCallback, EndpointDescription, IdentityToken, MessageSecurityMode,
+    MonitoredItemCreateRequest, MonitoringMode, MonitoringParameters, ReadValueId, Session,
+    SessionCommand, TimestampsToReturn, UserTokenPolicy,
+};
+use opcua::crypto::SecurityPolicy as UaSecurityPolicy;
+use opcua::sync::RwLock;
+use opcua::types::{DataValue, ExtensionObject, NodeId};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::{mpsc, oneshot};
+
+use crate::config::{OpcUaAuth, OpcUaSourceConfig, SecurityMode, SecurityPolicy};
+
+/// A data change of the monitored node at `index` in the configuration.
+pub(crate) type DataChange = (usize, DataValue);
+
+/// Running session. Dropping it stops the session's run loop.
+pub(crate) struct OpcUaSession {
+    _session: Arc<RwLock<Session>>,
+    stop: Option<oneshot::Sender<SessionCommand>>,
+}
+
+impl Drop for OpcUaSession {
+    fn drop(&mut self) {
+        if let Some(stop) = self.stop.take() {
+            let _ = stop.send(SessionCommand::Stop);
+        }
+    }
+}
+
+fn security_policy(policy: SecurityPolicy) -> UaSecurityPolicy {
+    match policy {
+        SecurityPolicy::None => UaSecurityPolicy::None,
+        SecurityPolicy::Basic256Sha256 => UaSecurityPolicy::Basic256Sha256,
+        SecurityPolicy::Aes128Sha256RsaOaep => UaSecurityPolicy::Aes128Sha256RsaOaep,
+        SecurityPolicy::Aes256Sha256RsaPss => UaSecurityPolicy::Aes256Sha256RsaPss,
+    }
+}
+
+fn security_mode(mode: SecurityMode) -> MessageSecurityMode {
+    match mode {
+        SecurityMode::None => MessageSecurityMode::None,
+        SecurityMode::Sign => MessageSecurityMode::Sign,
+        SecurityMode::SignAndEncrypt => MessageSecurityMode::SignAndEncrypt,
+    }
+}
+
+fn identity_token(auth: &OpcUaAuth) -> IdentityToken {
+    match auth {
+        OpcUaAuth::Anonymous => IdentityToken::Anonymous,
+        OpcUaAuth::UserName { username, password } => {
+            IdentityToken::UserName(username.clone(), password.clone())
+        }
+        OpcUaAuth::Certificate {
+            certificate_path,
+            private_key_path,
+        } => IdentityToken::X509(
+            PathBuf::from(certificate_path),
+            PathBuf::from(private_key_path),
+        ),
+    }
+}
+
+/// Connect, create the subscription and its monitored items, and start the
+/// session's run loop. Blocks until the session is established.
+fn connect_blocking(
+    source_id: &str,
+    config: &OpcUaSourceConfig,
+    changes: mpsc::UnboundedSender<DataChange>,
+) -> Result<OpcUaSession> {
+    let node_ids = config
+        .nodes
+        .iter()
+        .map(|node| {
+            NodeId::from_str(&node.node_id)
+                .map_err(|_| anyhow!("'{}' is not a valid node ID", node.node_id))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let indexes: HashMap<NodeId, usize> = node_ids
+        .iter()
+        .cloned()
+        .enumerate()
+        .map(|(index, node_id)| (node_id, index))
+        .collect();
+
+    // Reconnection and subscription re-creation are handled by the client
+    let mut client = ClientBuilder::new()
+        .application_name(config.application_name.as_str())
+        .application_uri(format!("urn:drasi:source:{source_id}"))
+        .product_uri("urn:drasi:source:opcua")
+        .pki_dir(config.pki_dir.as_str())
+        .create_sample_keypair(true)
+        .trust_server_certs(config.trust_server_certs)
+        .session_retry_limit(i32::MAX)
+        .session_retry_interval(u32::try_from(config.reconnect_interval_ms).unwrap_or(u32::MAX))
+        .client()
+        .ok_or_else(|| anyhow!("Invalid OPC UA client configuration"))?;
+
+    let endpoint: EndpointDescription = (
+        config.endpoint_url.as_str(),
+        security_policy(config.security_policy).to_str(),
+        security_mode(config.security_mode),
+        UserTokenPolicy::anonymous(),
+    )
+        .into();
+    let session = client
+        .connect_to_endpoint(endpoint, identity_token(&config.auth))
+        .map_err(|status| anyhow!("Failed to connect to {}: {status}", config.endpoint_url))?;
+
+    {
+        let session = session.read();
+        let subscription_id = session
+            .create_subscription(
+                config.publishing_interval_ms as f64,
+                10,
+                30,
+                0,
+                0,
+                true,
+                DataChangeCallback::new(move |items| {
+                    for item in items {
+                        if let Some(index) = indexes.get(&item.item_to_monitor().node_id) {
+                            let _ = changes.send((*index, item.last_value().clone()));
+                        }
+                    }
+                }),
+            )
+            .map_err(|status| anyhow!("Failed to create subscription: {status}"))?;
+
+        let requests: Vec<MonitoredItemCreateRequest> = node_ids
+            .into_iter()
+            .zip(&config.nodes)
+            .map(|(node_id, node)| {
+                MonitoredItemCreateRequest::new(
+                    ReadValueId::from(node_id),
+                    MonitoringMode::Reporting,
+                    MonitoringParameters {
+                        client_handle: 0,
+                        sampling_interval: node
+                            .sampling_interval_ms
+                            .unwrap_or(config.sampling_interval_ms)
+                            as f64,
+                        filter: ExtensionObject::null(),
+                        queue_size: config.queue_size,
+                        discard_oldest: true,
+                    },
+                )
+            })
+            .collect();
+
+        let results = session
+            .create_monitored_items(subscription_id, TimestampsToReturn::Both, &requests)
+            .map_err(|status| anyhow!("Failed to create monitored items: {status}"))?;
+        for (result, node) in results.iter().zip(&config.nodes) {
+            if result.status_code.is_bad() {
+                warn!(
+                    "[{source_id}] Server rejected monitored item '{}': {}",
+                    node.node_id, result.status_code
+                );
+            }
+        }
+        info!(
+            "[{source_id}] Subscription {subscription_id} monitoring {} node(s)",
+            results.len()
+        );
+    }
+
+    let stop = Session::run_async(session.clone());
+    Ok(OpcUaSession {
+        _session: session,
+        stop: Some(stop),
+    })
+}
+
+/// Open a session on a dedicated thread.
+///
+/// # Errors
+///
+/// Returns an error if the server cannot be reached or rejects the session or
+/// subscription.
+pub(crate) async fn connect(
+    source_id: String,
+    config: OpcUaSourceConfig,
+    changes: mpsc::UnboundedSender<DataChange>,
+) -> Result<OpcUaSession> {
+    let (result_tx, result_rx) = oneshot::channel();
+    std::thread::Builder::new()
+        .name(format!("opcua-{source_id}"))
+        .spawn(move || {
+            let _ = result_tx.send(connect_blocking(&source_id, &config, changes));
+        })?;
+
+    result_rx
+        .await
+        .map_err(|_| anyhow!("OPC UA connection thread exited unexpectedly"))?
+}
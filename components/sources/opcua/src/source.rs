@@ -0,0 +1,527 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! OPC UA source implementation and builder.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use log::{error, info, warn};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::{mpsc, RwLock};
+
+use drasi_core::models::SourceChange;
+use drasi_lib::channels::*;
+use drasi_lib::component_graph::ComponentStatusHandle;
+use drasi_lib::managers::{log_component_start, log_component_stop};
+use drasi_lib::sources::base::{SourceBase, SourceBaseParams};
+use drasi_lib::Source;
+use tracing::Instrument;
+
+use crate::config::{MonitoredNode, OpcUaAuth, OpcUaSourceConfig, SecurityMode, SecurityPolicy};
+use crate::conversion::{data_value_to_json, label_for_node, ElementCache};
+use crate::session::connect;
+
+type Dispatchers = Arc<RwLock<Vec<Box<dyn ChangeDispatcher<SourceEventWrapper> + Send + Sync>>>>;
+
+/// Source that subscribes to OPC UA nodes and writes their values to graph
+/// node properties.
+///
+/// The source keeps one session with one subscription. The `opcua` client
+/// reconnects on its own after a connection loss and re-creates the
+/// subscription, so values keep flowing without restarting the source.
+pub struct OpcUaSource {
+    /// Base source implementation providing dispatchers, status tracking, and lifecycle management.
+    base: SourceBase,
+    /// OPC UA configuration.
+    config: OpcUaSourceConfig,
+}
+
+impl OpcUaSource {
+    /// Create a builder for an OPC UA source.
+    pub fn builder(id: impl Into<String>) -> OpcUaSourceBuilder {
+        OpcUaSourceBuilder::new(id)
+    }
+
+    /// Create a new OPC UA source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid.
+    pub fn new(id: impl Into<String>, config: OpcUaSourceConfig) -> Result<Self> {
+        OpcUaSourceBuilder::new(id).with_config(config).build()
+    }
+
+    async fn dispatch(
+        source_id: &str,
+        dispatchers: &Dispatchers,
+        change: SourceChange,
+    ) -> Result<()> {
+        let mut profiling = drasi_lib::profiling::ProfilingMetadata::new();
+        profiling.source_send_ns = Some(drasi_lib::profiling::timestamp_ns());
+
+        let wrapper = SourceEventWrapper::with_profiling(
+            source_id.to_string(),
+            SourceEvent::Change(change),
+            chrono::Utc::now(),
+            profiling,
+        );
+
+        SourceBase::dispatch_from_task(dispatchers.clone(), wrapper, source_id).await
+    }
+
+    async fn run(
+        source_id: String,
+        config: OpcUaSourceConfig,
+        dispatchers: Dispatchers,
+        status_handle: ComponentStatusHandle,
+    ) {
+        let (changes_tx, mut changes_rx) = mpsc::unbounded_channel();
+
+        // Held until the task ends; dropping it stops the session
+        let _session = match connect(source_id.clone(), config.clone(), changes_tx).await {
+            Ok(session) => session,
+            Err(e) => {
+                error!("[{source_id}] Failed to start OPC UA session: {e}");
+                status_handle
+                    .set_status(
+                        ComponentStatus::Error,
+                        Some(format!("Failed to start OPC UA session: {e}")),
+                    )
+                    .await;
+                return;
+            }
+        };
+
+        info!(
+            "[{source_id}] Connected to OPC UA server {}",
+            config.endpoint_url
+        );
+        status_handle
+            .set_status(
+                ComponentStatus::Running,
+                Some("OPC UA source running".to_string()),
+            )
+            .await;
+
+        let labels: Vec<String> = config
+            .nodes
+            .iter()
+            .map(|node| label_for_node(node, &config))
+            .collect();
+        let mut cache = ElementCache::default();
+
+        while let Some((index, data_value)) = changes_rx.recv().await {
+            let node = &config.nodes[index];
+            let change = cache.apply(
+                &source_id,
+                &node.element_id,
+                &labels[index],
+                &node.property,
+                data_value_to_json(&data_value),
+            );
+
+            if let Err(e) = Self::dispatch(&source_id, &dispatchers, change).await {
+                warn!(
+                    "[{source_id}] Failed to dispatch value of '{}': {e}",
+                    node.node_id
+                );
+            }
+        }
+
+        error!("[{source_id}] OPC UA subscription ended");
+        status_handle
+            .set_status(
+                ComponentStatus::Error,
+                Some("OPC UA subscription ended".to_string()),
+            )
+            .await;
+    }
+}
+
+#[async_trait]
+impl Source for OpcUaSource {
+    fn id(&self) -> &str {
+        &self.base.id
+    }
+
+    fn type_name(&self) -> &str {
+        "opcua"
+    }
+
+    fn properties(&self) -> HashMap<String, serde_json::Value> {
+        use crate::descriptor::{
+            MonitoredNodeDto, OpcUaAuthDto, OpcUaSourceConfigDto, SecurityModeDto,
+            SecurityPolicyDto,
+        };
+        use drasi_plugin_sdk::ConfigValue;
+
+        let dto = OpcUaSourceConfigDto {
+            endpoint_url: ConfigValue::Static(self.config.endpoint_url.clone()),
+            security_policy: match self.config.security_policy {
+                SecurityPolicy::None => SecurityPolicyDto::None,
+                SecurityPolicy::Basic256Sha256 => SecurityPolicyDto::Basic256Sha256,
+                SecurityPolicy::Aes128Sha256RsaOaep => SecurityPolicyDto::Aes128Sha256RsaOaep,
+                SecurityPolicy::Aes256Sha256RsaPss => SecurityPolicyDto::Aes256Sha256RsaPss,
+            },
+            security_mode: match self.config.security_mode {
+                SecurityMode::None => SecurityModeDto::None,
+                SecurityMode::Sign => SecurityModeDto::Sign,
+                SecurityMode::SignAndEncrypt => SecurityModeDto::SignAndEncrypt,
+            },
+            auth: match &self.config.auth {
+                OpcUaAuth::Anonymous => OpcUaAuthDto::Anonymous,
+                // The password is never exposed
+                OpcUaAuth::UserName { username, .. } => OpcUaAuthDto::UserName {
+                    username: ConfigValue::Static(username.clone()),
+                    password: ConfigValue::Static("***".to_string()),
+                },
+                OpcUaAuth::Certificate {
+                    certificate_path,
+                    private_key_path,
+                } => OpcUaAuthDto::Certificate {
+                    certificate_path: ConfigValue::Static(certificate_path.clone()),
+                    private_key_path: ConfigValue::Static(private_key_path.clone()),
+                },
+            },
+            application_name: ConfigValue::Static(self.config.application_name.clone()),
+            pki_dir: ConfigValue::Static(self.config.pki_dir.clone()),
+            trust_server_certs: ConfigValue::Static(self.config.trust_server_certs),
+            nodes: self
+                .config
+                .nodes
+                .iter()
+                .map(|node| MonitoredNodeDto {
+                    node_id: node.node_id.clone(),
+                    element_id: node.element_id.clone(),
+                    property: node.property.clone(),
+                    label: node.label.clone(),
+                    sampling_interval_ms: node.sampling_interval_ms,
+                })
+                .collect(),
+            publishing_interval_ms: ConfigValue::Static(self.config.publishing_interval_ms),
+            sampling_interval_ms: ConfigValue::Static(self.config.sampling_interval_ms),
+            queue_size: ConfigValue::Static(self.config.queue_size),
+            reconnect_interval_ms: ConfigValue::Static(self.config.reconnect_interval_ms),
+            label: self.config.label.clone(),
+        };
+
+        match serde_json::to_value(&dto) {
+            Ok(serde_json::Value::Object(map)) => map.into_iter().collect(),
+            _ => HashMap::new(),
+        }
+    }
+
+    fn auto_start(&self) -> bool {
+        self.base.get_auto_start()
+    }
+
+    async fn start(&self) -> Result<()> {
+        log_component_start("OPC UA Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Starting,
+                Some("Starting OPC UA source".to_string()),
+            )
+            .await;
+
+        let instance_id = self
+            .base
+            .context()
+            .await
+            .map(|c| c.instance_id)
+            .unwrap_or_default();
+
+        let span = tracing::info_span!(
+            "opcua_source_subscription",
+            instance_id = %instance_id,
+            component_id = %self.base.id,
+            component_type = "source"
+        );
+        let task = tokio::spawn(
+            Self::run(
+                self.base.id.clone(),
+                self.config.clone(),
+                self.base.dispatchers.clone(),
+                self.base.status_handle(),
+            )
+            .instrument(span),
+        );
+
+        *self.base.task_handle.write().await = Some(task);
+
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        log_component_stop("OPC UA Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopping,
+                Some("Stopping OPC UA source".to_string()),
+            )
+            .await;
+
+        if let Some(handle) = self.base.task_handle.write().await.take() {
+            handle.abort();
+            let _ = handle.await;
+        }
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopped,
+                Some("OPC UA source stopped".to_string()),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn status(&self) -> ComponentStatus {
+        self.base.get_status().await
+    }
+
+    async fn subscribe(
+        &self,
+        settings: drasi_lib::config::SourceSubscriptionSettings,
+    ) -> Result<SubscriptionResponse> {
+        self.base
+            .subscribe_with_bootstrap(&settings, "OPC UA")
+            .await
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    async fn initialize(&self, context: drasi_lib::context::SourceRuntimeContext) {
+        self.base.initialize(context).await;
+    }
+
+    async fn set_bootstrap_provider(
+        &self,
+        provider: Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>,
+    ) {
+        self.base.set_bootstrap_provider(provider).await;
+    }
+}
+
+/// Builder for [`OpcUaSource`] instances.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use drasi_source_opcua::OpcUaSource;
+///
+/// let source = OpcUaSource::builder("press-line")
+///     .with_endpoint_url("opc.tcp://plc-1:4840")
+///     .with_user_name("operator", "secret")
+///     .with_node("ns=2;s=Press1.Temperature", "press-1", "temperature")
+///     .with_node("ns=2;s=Press1.Pressure", "press-1", "pressure")
+///     .with_label("Machine")
+///     .build()?;
+/// ```
+pub struct OpcUaSourceBuilder {
+    id: String,
+    config: OpcUaSourceConfig,
+    dispatch_mode: Option<DispatchMode>,
+    dispatch_buffer_capacity: Option<usize>,
+    bootstrap_provider: Option<Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>>,
+    auto_start: bool,
+}
+
+impl OpcUaSourceBuilder {
+    /// Create a new builder with the given ID and default values.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            config: OpcUaSourceConfig::default(),
+            dispatch_mode: None,
+            dispatch_buffer_capacity: None,
+            bootstrap_provider: None,
+            auto_start: true,
+        }
+    }
+
+    /// Set the server endpoint URL.
+    pub fn with_endpoint_url(mut self, url: impl Into<String>) -> Self {
+        self.config.endpoint_url = url.into();
+        self
+    }
+
+    /// Set the security policy and message security mode.
+    pub fn with_security(mut self, policy: SecurityPolicy, mode: SecurityMode) -> Self {
+        self.config.security_policy = policy;
+        self.config.security_mode = mode;
+        self
+    }
+
+    /// Authenticate with a user name and password.
+    pub fn with_user_name(
+        mut self,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        self.config.auth = OpcUaAuth::UserName {
+            username: username.into(),
+            password: password.into(),
+        };
+        self
+    }
+
+    /// Authenticate with an X.509 user certificate.
+    pub fn with_certificate(
+        mut self,
+        certificate_path: impl Into<String>,
+        private_key_path: impl Into<String>,
+    ) -> Self {
+        self.config.auth = OpcUaAuth::Certificate {
+            certificate_path: certificate_path.into(),
+            private_key_path: private_key_path.into(),
+        };
+        self
+    }
+
+    /// Set the application name presented to the server.
+    pub fn with_application_name(mut self, name: impl Into<String>) -> Self {
+        self.config.application_name = name.into();
+        self
+    }
+
+    /// Set the PKI directory.
+    pub fn with_pki_dir(mut self, dir: impl Into<String>) -> Self {
+        self.config.pki_dir = dir.into();
+        self
+    }
+
+    /// Set whether untrusted server certificates are accepted.
+    pub fn with_trust_server_certs(mut self, trust: bool) -> Self {
+        self.config.trust_server_certs = trust;
+        self
+    }
+
+    /// Monitor `node_id` and write its value to `property` of `element_id`.
+    pub fn with_node(
+        mut self,
+        node_id: impl Into<String>,
+        element_id: impl Into<String>,
+        property: impl Into<String>,
+    ) -> Self {
+        self.config.nodes.push(MonitoredNode {
+            node_id: node_id.into(),
+            element_id: element_id.into(),
+            property: property.into(),
+            label: None,
+            sampling_interval_ms: None,
+        });
+        self
+    }
+
+    /// Monitor a node with label or sampling overrides.
+    pub fn with_monitored_node(mut self, node: MonitoredNode) -> Self {
+        self.config.nodes.push(node);
+        self
+    }
+
+    /// Set the publishing interval in milliseconds.
+    pub fn with_publishing_interval_ms(mut self, interval_ms: u64) -> Self {
+        self.config.publishing_interval_ms = interval_ms;
+        self
+    }
+
+    /// Set the default sampling interval in milliseconds.
+    pub fn with_sampling_interval_ms(mut self, interval_ms: u64) -> Self {
+        self.config.sampling_interval_ms = interval_ms;
+        self
+    }
+
+    /// Set the server-side queue size per monitored item.
+    pub fn with_queue_size(mut self, queue_size: u32) -> Self {
+        self.config.queue_size = queue_size;
+        self
+    }
+
+    /// Set the delay between reconnection attempts in milliseconds.
+    pub fn with_reconnect_interval_ms(mut self, interval_ms: u64) -> Self {
+        self.config.reconnect_interval_ms = interval_ms;
+        self
+    }
+
+    /// Set the label for graph nodes whose monitored nodes set no label.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.config.label = Some(label.into());
+        self
+    }
+
+    /// Set the dispatch mode for this source.
+    pub fn with_dispatch_mode(mut self, mode: DispatchMode) -> Self {
+        self.dispatch_mode = Some(mode);
+        self
+    }
+
+    /// Set the dispatch buffer capacity.
+    pub fn with_dispatch_buffer_capacity(mut self, capacity: usize) -> Self {
+        self.dispatch_buffer_capacity = Some(capacity);
+        self
+    }
+
+    /// Set the bootstrap provider for initial data delivery.
+    pub fn with_bootstrap_provider(
+        mut self,
+        provider: impl drasi_lib::bootstrap::BootstrapProvider + 'static,
+    ) -> Self {
+        self.bootstrap_provider = Some(Box::new(provider));
+        self
+    }
+
+    /// Set whether this source should auto-start when DrasiLib starts.
+    pub fn with_auto_start(mut self, auto_start: bool) -> Self {
+        self.auto_start = auto_start;
+        self
+    }
+
+    /// Set the full configuration at once.
+    pub fn with_config(mut self, config: OpcUaSourceConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Build the OPC UA source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid or the base source
+    /// cannot be initialized.
+    pub fn build(self) -> Result<OpcUaSource> {
+        self.config.validate()?;
+
+        let mut params = SourceBaseParams::new(&self.id).with_auto_start(self.auto_start);
+        if let Some(mode) = self.dispatch_mode {
+            params = params.with_dispatch_mode(mode);
+        }
+        if let Some(capacity) = self.dispatch_buffer_capacity {
+            params = params.with_dispatch_buffer_capacity(capacity);
+        }
+        if let Some(provider) = self.bootstrap_provider {
+            params = params.with_bootstrap_provider(provider);
+        }
+
+        Ok(OpcUaSource {
+            base: SourceBase::new(params)?,
+            config: self.config,
+        })
+    }
+}
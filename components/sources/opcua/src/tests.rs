@@ -0,0 +1,273 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for the OPC UA source plugin.
+
+use super::*;
+use crate::conversion::{data_value_to_json, label_for_node, variant_to_json, ElementCache};
+use drasi_core::models::{Element, ElementValue, SourceChange};
+use drasi_lib::Source;
+use drasi_plugin_sdk::prelude::SourcePluginDescriptor;
+use opcua::types::{DataValue, StatusCode, UAString, Variant};
+use serde_json::json;
+
+fn node(node_id: &str, element_id: &str, property: &str) -> MonitoredNode {
+    MonitoredNode {
+        node_id: node_id.to_string(),
+        element_id: element_id.to_string(),
+        property: property.to_string(),
+        label: None,
+        sampling_interval_ms: None,
+    }
+}
+
+fn config() -> OpcUaSourceConfig {
+    OpcUaSourceConfig {
+        endpoint_url: "opc.tcp://plc-1:4840".to_string(),
+        nodes: vec![
+            node("ns=2;s=Press1.Temperature", "press-1", "temperature"),
+            node("ns=2;s=Press1.Pressure", "press-1", "pressure"),
+        ],
+        ..Default::default()
+    }
+}
+
+mod builder {
+    use super::*;
+
+    #[test]
+    fn test_builder_with_valid_config() {
+        let source = OpcUaSource::builder("test-source")
+            .with_endpoint_url("opc.tcp://plc-1:4840")
+            .with_security(SecurityPolicy::Basic256Sha256, SecurityMode::SignAndEncrypt)
+            .with_user_name("operator", "secret")
+            .with_node("ns=2;s=Press1.Temperature", "press-1", "temperature")
+            .with_node("ns=2;i=1001", "press-1", "pressure")
+            .build()
+            .unwrap();
+
+        assert_eq!(source.id(), "test-source");
+        assert_eq!(source.type_name(), "opcua");
+        let props = source.properties();
+        assert_eq!(props.get("securityPolicy"), Some(&json!("basic256_sha256")));
+        assert_eq!(
+            props.get("auth"),
+            Some(&json!({"type": "userName", "username": "operator", "password": "***"}))
+        );
+        assert_eq!(props["nodes"].as_array().map(Vec::len), Some(2));
+    }
+
+    #[test]
+    fn test_builder_requires_endpoint_and_nodes() {
+        assert!(OpcUaSource::builder("test-source")
+            .with_node("ns=2;s=X", "x", "value")
+            .build()
+            .is_err());
+        assert!(OpcUaSource::builder("test-source")
+            .with_endpoint_url("opc.tcp://plc-1:4840")
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_config_rejects_invalid_values() {
+        let bad_node_id = OpcUaSourceConfig {
+            nodes: vec![node("not a node id", "x", "value")],
+            ..config()
+        };
+        assert!(bad_node_id.validate().is_err());
+
+        let same_target = OpcUaSourceConfig {
+            nodes: vec![
+                node("ns=2;s=A", "press-1", "temperature"),
+                node("ns=2;s=B", "press-1", "temperature"),
+            ],
+            ..config()
+        };
+        assert!(same_target.validate().is_err());
+
+        let half_security = OpcUaSourceConfig {
+            security_policy: SecurityPolicy::Basic256Sha256,
+            ..config()
+        };
+        assert!(half_security.validate().is_err());
+
+        let empty_user = OpcUaSourceConfig {
+            auth: OpcUaAuth::UserName {
+                username: String::new(),
+                password: "x".to_string(),
+            },
+            ..config()
+        };
+        assert!(empty_user.validate().is_err());
+
+        let zero_queue = OpcUaSourceConfig {
+            queue_size: 0,
+            ..config()
+        };
+        assert!(zero_queue.validate().is_err());
+    }
+
+    #[test]
+    fn test_config_deserialization_defaults() {
+        let config: OpcUaSourceConfig = serde_json::from_value(json!({
+            "endpoint_url": "opc.tcp://plc-1:4840",
+            "auth": {"type": "certificate", "certificate_path": "c.pem", "private_key_path": "k.pem"},
+            "nodes": [{"node_id": "ns=2;s=X", "element_id": "x", "property": "value"}]
+        }))
+        .unwrap();
+        assert_eq!(config.security_policy, SecurityPolicy::None);
+        assert_eq!(config.security_mode, SecurityMode::None);
+        assert!(matches!(config.auth, OpcUaAuth::Certificate { .. }));
+        assert_eq!(config.publishing_interval_ms, 1000);
+        assert_eq!(config.sampling_interval_ms, 500);
+        assert_eq!(config.queue_size, 10);
+        assert!(!config.trust_server_certs);
+        assert!(config.validate().is_ok());
+    }
+}
+
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn test_variant_to_json() {
+        assert_eq!(variant_to_json(&Variant::Boolean(true)), json!(true));
+        assert_eq!(variant_to_json(&Variant::Int32(-4)), json!(-4));
+        assert_eq!(variant_to_json(&Variant::UInt64(7)), json!(7));
+        assert_eq!(variant_to_json(&Variant::Double(21.5)), json!(21.5));
+        assert_eq!(variant_to_json(&Variant::Double(f64::NAN)), json!(null));
+        assert_eq!(
+            variant_to_json(&Variant::String(UAString::from("running"))),
+            json!("running")
+        );
+        assert_eq!(variant_to_json(&Variant::Empty), json!(null));
+        assert_eq!(
+            variant_to_json(&Variant::from(vec![1i32, 2, 3])),
+            json!([1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn test_bad_status_becomes_null() {
+        let good = DataValue::new_now(Variant::Double(1.5));
+        assert_eq!(data_value_to_json(&good), json!(1.5));
+
+        let mut bad = DataValue::new_now(Variant::Double(1.5));
+        bad.status = Some(StatusCode::BadSensorFailure);
+        assert_eq!(data_value_to_json(&bad), json!(null));
+    }
+
+    #[test]
+    fn test_cache_merges_properties() {
+        let mut cache = ElementCache::default();
+
+        let change = cache.apply("src", "press-1", "Machine", "temperature", json!(80.5));
+        assert!(matches!(change, SourceChange::Insert { .. }));
+
+        let change = cache.apply("src", "press-1", "Machine", "pressure", json!(3));
+        let SourceChange::Update {
+            element:
+                Element::Node {
+                    metadata,
+                    properties,
+                },
+        } = change
+        else {
+            panic!("expected node update");
+        };
+        assert_eq!(metadata.reference.element_id.as_ref(), "press-1");
+        assert_eq!(metadata.labels[0].as_ref(), "Machine");
+        assert_eq!(
+            properties.get("temperature"),
+            Some(&ElementValue::Float(80.5.into()))
+        );
+        assert_eq!(properties.get("pressure"), Some(&ElementValue::Integer(3)));
+
+        let change = cache.apply("src", "press-2", "Machine", "temperature", json!(1));
+        assert!(matches!(change, SourceChange::Insert { .. }));
+    }
+
+    #[test]
+    fn test_label_fallbacks() {
+        let mut node = node("ns=2;s=X", "x", "value");
+        assert_eq!(label_for_node(&node, &config()), "OpcUaNode");
+
+        let labelled = OpcUaSourceConfig {
+            label: Some("Machine".to_string()),
+            ..config()
+        };
+        assert_eq!(label_for_node(&node, &labelled), "Machine");
+
+        node.label = Some("Press".to_string());
+        assert_eq!(label_for_node(&node, &labelled), "Press");
+    }
+}
+
+mod descriptor {
+    use super::*;
+    use crate::descriptor::OpcUaSourceDescriptor;
+
+    #[tokio::test]
+    async fn test_descriptor_creates_source() {
+        let descriptor = OpcUaSourceDescriptor;
+        assert_eq!(descriptor.kind(), "opcua");
+
+        let source = descriptor
+            .create_source(
+                "opcua-1",
+                &json!({
+                    "endpointUrl": "opc.tcp://plc-1:4840",
+                    "securityPolicy": "basic256_sha256",
+                    "securityMode": "sign",
+                    "auth": {
+                        "type": "certificate",
+                        "certificatePath": "/pki/user.der",
+                        "privateKeyPath": "/pki/user.pem"
+                    },
+                    "nodes": [
+                        {"nodeId": "ns=2;s=Press1.Temperature", "elementId": "press-1", "property": "temperature", "samplingIntervalMs": 100}
+                    ],
+                    "publishingIntervalMs": 250
+                }),
+                false,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(source.id(), "opcua-1");
+        assert!(!source.auto_start());
+        let props = source.properties();
+        assert_eq!(props.get("securityMode"), Some(&json!("sign")));
+        assert_eq!(props.get("publishingIntervalMs"), Some(&json!(250)));
+        assert_eq!(props["auth"]["certificatePath"], json!("/pki/user.der"));
+        assert_eq!(props["nodes"][0]["samplingIntervalMs"], json!(100));
+    }
+
+    #[tokio::test]
+    async fn test_descriptor_rejects_unknown_fields() {
+        let result = OpcUaSourceDescriptor
+            .create_source(
+                "opcua-1",
+                &json!({
+                    "endpointUrl": "opc.tcp://plc-1:4840",
+                    "nodes": [{"nodeId": "ns=2;s=X", "elementId": "x", "property": "v"}],
+                    "bogus": 1
+                }),
+                true,
+            )
+            .await;
+        assert!(result.is_err());
+    }
+}
@@ -183,11 +183,8 @@ use anyhow::Result;
 use async_trait::async_trait;
 use log::{error, info};
 use std::collections::HashMap;
-use std::sync::Arc;
-use tokio::sync::RwLock;
 
 use drasi_lib::channels::{DispatchMode, *};
-use drasi_lib::component_graph::ComponentStatusHandle;
 use drasi_lib::sources::base::{SourceBase, SourceBaseParams};
 use drasi_lib::Source;
 use tracing::Instrument;
@@ -328,7 +325,7 @@ impl Source for PostgresReplicationSource {
 
         let config = self.config.clone();
         let source_id = self.base.id.clone();
-        let dispatchers = self.base.dispatchers.clone();
+        let base = self.base.clone_shared();
         let reporter = self.base.status_handle();
 
         // Get instance_id from context for log routing isolation
@@ -350,9 +347,7 @@ impl Source for PostgresReplicationSource {
 
         let task = tokio::spawn(
             async move {
-                if let Err(e) =
-                    run_replication(source_id.clone(), config, dispatchers, reporter.clone()).await
-                {
+                if let Err(e) = run_replication(config, base).await {
                     error!("Replication task failed for {source_id}: {e}");
                     reporter
                         .set_status(
@@ -429,19 +424,10 @@ impl Source for PostgresReplicationSource {
     }
 }
 
-async fn run_replication(
-    source_id: String,
-    config: PostgresSourceConfig,
-    dispatchers: Arc<
-        RwLock<
-            Vec<Box<dyn drasi_lib::channels::ChangeDispatcher<SourceEventWrapper> + Send + Sync>>,
-        >,
-    >,
-    status_handle: ComponentStatusHandle,
-) -> Result<()> {
-    info!("Starting replication for source {source_id}");
-
-    let mut stream = stream::ReplicationStream::new(config, source_id, dispatchers, status_handle);
+async fn run_replication(config: PostgresSourceConfig, base: SourceBase) -> Result<()> {
+    info!("Starting replication for source {}", base.id);
+
+    let mut stream = stream::ReplicationStream::new(config, base);
 
     stream.run().await
 }
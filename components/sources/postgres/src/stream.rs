@@ -30,10 +30,24 @@ use drasi_core::models::{Element, ElementMetadata, ElementReference, SourceChang
 use drasi_lib::channels::{ComponentStatus, SourceEvent, SourceEventWrapper};
 use drasi_lib::component_graph::ComponentStatusHandle;
 use drasi_lib::sources::base::SourceBase;
+use serde::{Deserialize, Serialize};
+
+/// Confirmed LSN saved as the source's checkpoint.
+///
+/// It is only used when replication restarts on the same server and slot, so a
+/// checkpoint of a dropped slot or a restored database is ignored.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LsnCheckpoint {
+    system_id: String,
+    slot_name: String,
+    lsn: u64,
+}
 
 pub struct ReplicationStream {
     config: PostgresSourceConfig,
     source_id: String,
+    base: SourceBase,
     connection: Option<ReplicationConnection>,
     decoder: PgOutputDecoder,
     dispatchers: Arc<
@@ -44,6 +58,13 @@ pub struct ReplicationStream {
     #[allow(dead_code)]
     status_handle: ComponentStatusHandle,
     current_lsn: u64,
+    /// Highest LSN whose changes have been dispatched. Reported to the server as
+    /// flushed so the slot only advances past fully delivered transactions.
+    confirmed_lsn: u64,
+    /// Confirmed LSN last saved as the source's checkpoint.
+    saved_lsn: u64,
+    /// Identifier of the server being replicated, reported by `IDENTIFY_SYSTEM`.
+    system_id: String,
     last_feedback_time: std::time::Instant,
     pending_transaction: Option<Vec<SourceChange>>,
     relations: HashMap<u32, RelationMapping>,
@@ -59,28 +80,19 @@ struct RelationMapping {
 }
 
 impl ReplicationStream {
-    pub fn new(
-        config: PostgresSourceConfig,
-        source_id: String,
-        dispatchers: Arc<
-            RwLock<
-                Vec<
-                    Box<
-                        dyn drasi_lib::channels::ChangeDispatcher<SourceEventWrapper> + Send + Sync,
-                    >,
-                >,
-            >,
-        >,
-        status_handle: ComponentStatusHandle,
-    ) -> Self {
+    pub fn new(config: PostgresSourceConfig, base: SourceBase) -> Self {
         Self {
             config,
-            source_id,
+            source_id: base.id.clone(),
             connection: None,
             decoder: PgOutputDecoder::new(),
-            dispatchers,
-            status_handle,
+            dispatchers: base.dispatchers.clone(),
+            status_handle: base.status_handle(),
+            base,
             current_lsn: 0,
+            confirmed_lsn: 0,
+            saved_lsn: 0,
+            system_id: String::new(),
             last_feedback_time: std::time::Instant::now(),
             pending_transaction: None,
             relations: HashMap::new(),
@@ -169,6 +181,7 @@ impl ReplicationStream {
         // Identify system
         let system_info = conn.identify_system().await?;
         info!("Connected to PostgreSQL system: {system_info:?}");
+        self.system_id = system_info.get("systemid").cloned().unwrap_or_default();
 
         // Create or verify replication slot
         let slot_info = conn
@@ -184,6 +197,20 @@ impl ReplicationStream {
             self.current_lsn = 0;
         }
 
+        // Changes dispatched before the last feedback reached the server are
+        // not streamed again
+        if let Some(lsn) = self.load_checkpoint().await {
+            if lsn > self.current_lsn {
+                info!("Resuming after checkpoint LSN {lsn:x}");
+                self.current_lsn = lsn;
+            }
+        }
+        self.confirmed_lsn = self.confirmed_lsn.max(self.current_lsn);
+
+        // Any partially received transaction is replayed by the server from the
+        // confirmed position, so drop it rather than dispatching it twice.
+        self.pending_transaction = None;
+
         // Build replication options
         let mut options = HashMap::new();
         options.insert("proto_version".to_string(), "1".to_string());
@@ -228,6 +255,7 @@ impl ReplicationStream {
                 reply,
             } => {
                 self.current_lsn = wal_end;
+                self.advance_idle_lsn();
                 if reply == 1 {
                     self.send_feedback(true).await?;
                 }
@@ -342,6 +370,7 @@ impl ReplicationStream {
         let reply = data[16];
 
         self.current_lsn = wal_end;
+        self.advance_idle_lsn();
 
         if reply == 1 {
             self.send_feedback(true).await?;
@@ -391,6 +420,7 @@ impl ReplicationStream {
                         tx_info.xid, tx_info.commit_lsn
                     );
                 }
+                self.confirmed_lsn = self.confirmed_lsn.max(tx_info.commit_lsn);
             }
             WalMessage::Relation(relation) => {
                 // Store relation mapping - use table name as-is for label (no uppercase)
@@ -712,18 +742,66 @@ impl ReplicationStream {
         Ok(format!("{}:{}", table_name, uuid::Uuid::new_v4()))
     }
 
+    /// Keepalives report the server's WAL end. When no transaction is in flight
+    /// everything up to that point has been dispatched, so it is safe to confirm.
+    fn advance_idle_lsn(&mut self) {
+        if self.pending_transaction.is_none() {
+            self.confirmed_lsn = self.confirmed_lsn.max(self.current_lsn);
+        }
+    }
+
+    /// Load the saved confirmed LSN if it belongs to the current server and slot.
+    async fn load_checkpoint(&self) -> Option<u64> {
+        match self.base.load_checkpoint::<LsnCheckpoint>().await {
+            Ok(Some(checkpoint))
+                if checkpoint.system_id == self.system_id
+                    && checkpoint.slot_name == self.config.slot_name =>
+            {
+                Some(checkpoint.lsn)
+            }
+            Ok(_) => None,
+            Err(e) => {
+                warn!("Failed to load LSN checkpoint: {e}");
+                None
+            }
+        }
+    }
+
+    /// Save the confirmed LSN as the source's checkpoint if it advanced.
+    async fn save_checkpoint(&mut self) {
+        if self.confirmed_lsn <= self.saved_lsn {
+            return;
+        }
+        let checkpoint = LsnCheckpoint {
+            system_id: self.system_id.clone(),
+            slot_name: self.config.slot_name.clone(),
+            lsn: self.confirmed_lsn,
+        };
+        match self.base.save_checkpoint(&checkpoint).await {
+            Ok(()) => self.saved_lsn = checkpoint.lsn,
+            Err(e) => warn!("Failed to save LSN checkpoint: {e}"),
+        }
+    }
+
     async fn send_feedback(&mut self, reply_requested: bool) -> Result<()> {
+        // The checkpoint is saved first, so it is never behind the server's
+        // flushed position
+        self.save_checkpoint().await;
         if let Some(conn) = &mut self.connection {
             let status = StandbyStatusUpdate {
                 write_lsn: self.current_lsn,
-                flush_lsn: self.current_lsn,
-                apply_lsn: self.current_lsn,
+                flush_lsn: self.confirmed_lsn,
+                apply_lsn: self.confirmed_lsn,
                 reply_requested,
             };
 
             conn.send_standby_status(status).await?;
             self.last_feedback_time = std::time::Instant::now();
-            trace!("Sent feedback with LSN: {:x}", self.current_lsn);
+            trace!(
+                "Sent feedback with write LSN: {:x}, flush LSN: {:x}",
+                self.current_lsn,
+                self.confirmed_lsn
+            );
         }
 
         Ok(())
@@ -805,4 +883,65 @@ mod tests {
             "Nanosecond timestamp ({bad_effective_from}) should be rejected"
         );
     }
+
+    fn test_stream() -> super::ReplicationStream {
+        let config: super::PostgresSourceConfig =
+            serde_json::from_value(serde_json::json!({"database": "db", "user": "user"})).unwrap();
+        super::ReplicationStream::new(
+            config,
+            super::SourceBase::new(drasi_lib::sources::base::SourceBaseParams::new(
+                "test-source",
+            ))
+            .unwrap(),
+        )
+    }
+
+    /// Keepalives only confirm the server's WAL end while no transaction is buffered,
+    /// so a restart never skips changes that were received but not yet dispatched.
+    #[test]
+    fn idle_keepalive_advances_confirmed_lsn_only_between_transactions() {
+        let mut stream = test_stream();
+
+        stream.current_lsn = 100;
+        stream.advance_idle_lsn();
+        assert_eq!(stream.confirmed_lsn, 100);
+
+        stream.pending_transaction = Some(Vec::new());
+        stream.current_lsn = 200;
+        stream.advance_idle_lsn();
+        assert_eq!(stream.confirmed_lsn, 100);
+
+        stream.pending_transaction = None;
+        stream.advance_idle_lsn();
+        assert_eq!(stream.confirmed_lsn, 200);
+    }
+
+    /// A saved LSN is only used again on the server and slot it was saved for.
+    #[tokio::test]
+    async fn checkpoint_is_only_used_on_the_same_server_and_slot() {
+        let mut stream = test_stream();
+        let (update_tx, _update_rx) = tokio::sync::mpsc::channel(16);
+        stream
+            .base
+            .initialize(drasi_lib::context::SourceRuntimeContext::new(
+                "test-instance",
+                "test-source",
+                Some(std::sync::Arc::new(
+                    drasi_lib::MemoryStateStoreProvider::new(),
+                )),
+                update_tx,
+                None,
+            ))
+            .await;
+        stream.system_id = "7001".to_string();
+        assert_eq!(stream.load_checkpoint().await, None);
+
+        stream.confirmed_lsn = 300;
+        stream.save_checkpoint().await;
+        assert_eq!(stream.saved_lsn, 300);
+        assert_eq!(stream.load_checkpoint().await, Some(300));
+
+        stream.system_id = "7002".to_string();
+        assert_eq!(stream.load_checkpoint().await, None);
+    }
 }
@@ -0,0 +1,63 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pub/Sub client setup and authentication.
+
+use anyhow::{anyhow, Result};
+use google_cloud_pubsub::client::google_cloud_auth::credentials::CredentialsFile;
+use google_cloud_pubsub::client::{Client, ClientConfig};
+
+use crate::config::PubSubSourceConfig;
+
+/// Create a Pub/Sub client.
+///
+/// Credentials come from `credentials_file` or `credentials_json` when set,
+/// and from Application Default Credentials otherwise. With
+/// `PUBSUB_EMULATOR_HOST` set, no credentials are used.
+///
+/// # Errors
+///
+/// Returns an error if the credentials cannot be read or no project can be
+/// determined.
+pub(crate) async fn create_client(config: &PubSubSourceConfig) -> Result<Client> {
+    let base = ClientConfig {
+        project_id: config.project_id.clone(),
+        ..ClientConfig::default()
+    };
+
+    let client_config = if let Some(path) = &config.credentials_file {
+        let credentials = CredentialsFile::new_from_file(path.clone())
+            .await
+            .map_err(|e| anyhow!("Failed to read credentials file '{path}': {e}"))?;
+        base.with_credentials(credentials).await
+    } else if let Some(json) = &config.credentials_json {
+        let credentials = CredentialsFile::new_from_str(json)
+            .await
+            .map_err(|e| anyhow!("Failed to parse credentials_json: {e}"))?;
+        base.with_credentials(credentials).await
+    } else {
+        base.with_auth().await
+    }
+    .map_err(|e| anyhow!("Failed to authenticate with Google Cloud: {e}"))?;
+
+    if client_config.project_id.is_none() && !config.subscription.contains('/') {
+        return Err(anyhow!(
+            "No project found in the credentials; set project_id or use a full subscription name"
+        ));
+    }
+
+    Client::new(client_config)
+        .await
+        .map_err(|e| anyhow!("Failed to create Pub/Sub client: {e}"))
+}
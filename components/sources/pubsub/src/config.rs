@@ -0,0 +1,239 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration types for the Google Cloud Pub/Sub source plugin.
+//!
+//! This module defines which subscription the source pulls from, how it
+//! authenticates, how message acknowledgement is paced, and how message
+//! payloads are mapped onto graph nodes.
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+fn default_ack_deadline_secs() -> i32 {
+    60
+}
+
+fn default_max_ack_extension_secs() -> u64 {
+    3600
+}
+
+fn default_max_outstanding_messages() -> i64 {
+    1000
+}
+
+fn default_dispatch_lanes() -> usize {
+    8
+}
+
+fn default_id_field() -> String {
+    "id".to_string()
+}
+
+fn default_operation_field() -> String {
+    "op".to_string()
+}
+
+/// Smallest and largest ack deadline accepted by Pub/Sub, in seconds.
+pub(crate) const ACK_DEADLINE_RANGE: std::ops::RangeInclusive<i32> = 10..=600;
+
+/// Google Cloud Pub/Sub source configuration.
+///
+/// Without `credentials_file` or `credentials_json`, the source uses
+/// Application Default Credentials. Set the `PUBSUB_EMULATOR_HOST`
+/// environment variable to use the Pub/Sub emulator.
+///
+/// # Example
+///
+/// ```rust
+/// use drasi_source_pubsub::PubSubSourceConfig;
+///
+/// let config = PubSubSourceConfig {
+///     subscription: "orders-drasi".to_string(),
+///     project_id: Some("my-project".to_string()),
+///     credentials_file: Some("/var/secrets/drasi-sa.json".to_string()),
+///     label: Some("Order".to_string()),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PubSubSourceConfig {
+    /// Subscription to pull from, either its ID or its full
+    /// `projects/<project>/subscriptions/<id>` name.
+    pub subscription: String,
+
+    /// Project of the subscription. Falls back to the project of the credentials.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+
+    /// Path of a service account key file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credentials_file: Option<String>,
+
+    /// Contents of a service account key file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credentials_json: Option<String>,
+
+    /// Ack deadline requested for the streaming pull and for each extension,
+    /// in seconds (10–600).
+    ///
+    /// **Default**: `60`
+    #[serde(default = "default_ack_deadline_secs")]
+    pub ack_deadline_secs: i32,
+
+    /// Longest a message is kept leased by extending its ack deadline, in
+    /// seconds. After that it is left to expire and be redelivered.
+    ///
+    /// **Default**: `3600`
+    #[serde(default = "default_max_ack_extension_secs")]
+    pub max_ack_extension_secs: u64,
+
+    /// Maximum unacknowledged messages delivered to the source at once.
+    ///
+    /// **Default**: `1000`
+    #[serde(default = "default_max_outstanding_messages")]
+    pub max_outstanding_messages: i64,
+
+    /// Number of lanes dispatching messages concurrently. Messages with the
+    /// same ordering key always use the same lane.
+    ///
+    /// **Default**: `8`
+    #[serde(default = "default_dispatch_lanes")]
+    pub dispatch_lanes: usize,
+
+    /// Payload field holding the element ID.
+    ///
+    /// **Default**: `"id"`
+    #[serde(default = "default_id_field")]
+    pub id_field: String,
+
+    /// Message attribute holding the element ID for messages whose payload
+    /// has no `id_field`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id_attribute: Option<String>,
+
+    /// Payload field holding the change operation (`insert`, `update`, `delete`
+    /// or `i`/`u`/`d`). Messages without it are treated as inserts.
+    ///
+    /// **Default**: `"op"`
+    #[serde(default = "default_operation_field")]
+    pub operation_field: String,
+
+    /// Optional payload field holding the node label. Takes precedence over `label`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_field: Option<String>,
+
+    /// Label for nodes from this source. Falls back to the subscription ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+impl Default for PubSubSourceConfig {
+    fn default() -> Self {
+        Self {
+            subscription: String::new(),
+            project_id: None,
+            credentials_file: None,
+            credentials_json: None,
+            ack_deadline_secs: default_ack_deadline_secs(),
+            max_ack_extension_secs: default_max_ack_extension_secs(),
+            max_outstanding_messages: default_max_outstanding_messages(),
+            dispatch_lanes: default_dispatch_lanes(),
+            id_field: default_id_field(),
+            id_attribute: None,
+            operation_field: default_operation_field(),
+            label_field: None,
+            label: None,
+        }
+    }
+}
+
+impl PubSubSourceConfig {
+    /// ID of the subscription, without the project prefix.
+    pub fn subscription_id(&self) -> &str {
+        self.subscription
+            .rsplit_once('/')
+            .map_or(self.subscription.as_str(), |(_, id)| id)
+    }
+
+    /// Validate the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `subscription`, `id_field` or `operation_field` is empty
+    /// - `subscription` is a malformed full name
+    /// - both `credentials_file` and `credentials_json` are set
+    /// - `ack_deadline_secs` is outside 10–600
+    /// - `max_ack_extension_secs`, `max_outstanding_messages` or `dispatch_lanes` is zero
+    pub fn validate(&self) -> Result<()> {
+        if self.subscription.is_empty() {
+            return Err(anyhow!("Validation error: subscription cannot be empty"));
+        }
+
+        if self.subscription.contains('/') {
+            let parts: Vec<&str> = self.subscription.split('/').collect();
+            if parts.len() != 4
+                || parts[0] != "projects"
+                || parts[2] != "subscriptions"
+                || parts.iter().any(|p| p.is_empty())
+            {
+                return Err(anyhow!(
+                    "Validation error: subscription '{}' must be an ID or 'projects/<project>/subscriptions/<id>'",
+                    self.subscription
+                ));
+            }
+        }
+
+        if self.credentials_file.is_some() && self.credentials_json.is_some() {
+            return Err(anyhow!(
+                "Validation error: set only one of credentials_file and credentials_json"
+            ));
+        }
+
+        if !ACK_DEADLINE_RANGE.contains(&self.ack_deadline_secs) {
+            return Err(anyhow!(
+                "Validation error: ack_deadline_secs must be between 10 and 600"
+            ));
+        }
+
+        if self.max_ack_extension_secs == 0 {
+            return Err(anyhow!(
+                "Validation error: max_ack_extension_secs must be greater than 0"
+            ));
+        }
+
+        if self.max_outstanding_messages <= 0 {
+            return Err(anyhow!(
+                "Validation error: max_outstanding_messages must be greater than 0"
+            ));
+        }
+
+        if self.dispatch_lanes == 0 {
+            return Err(anyhow!(
+                "Validation error: dispatch_lanes must be greater than 0"
+            ));
+        }
+
+        if self.id_field.is_empty() {
+            return Err(anyhow!("Validation error: id_field cannot be empty"));
+        }
+
+        if self.operation_field.is_empty() {
+            return Err(anyhow!("Validation error: operation_field cannot be empty"));
+        }
+
+        Ok(())
+    }
+}
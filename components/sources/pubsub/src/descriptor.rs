@@ -0,0 +1,141 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Google Cloud Pub/Sub source plugin descriptor and configuration DTOs.
+
+use crate::{PubSubSourceBuilder, PubSubSourceConfig};
+use drasi_plugin_sdk::prelude::*;
+use utoipa::OpenApi;
+
+/// Google Cloud Pub/Sub source configuration DTO.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::pubsub::PubSubSourceConfig)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct PubSubSourceConfigDto {
+    pub subscription: ConfigValue<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<ConfigValue<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credentials_file: Option<ConfigValue<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credentials_json: Option<ConfigValue<String>>,
+    #[serde(default = "default_ack_deadline_secs")]
+    pub ack_deadline_secs: ConfigValue<i32>,
+    #[serde(default = "default_max_ack_extension_secs")]
+    pub max_ack_extension_secs: ConfigValue<u64>,
+    #[serde(default = "default_max_outstanding_messages")]
+    pub max_outstanding_messages: ConfigValue<i64>,
+    #[serde(default = "default_dispatch_lanes")]
+    pub dispatch_lanes: ConfigValue<usize>,
+    #[serde(default = "default_id_field")]
+    pub id_field: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id_attribute: Option<String>,
+    #[serde(default = "default_operation_field")]
+    pub operation_field: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_field: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+fn default_ack_deadline_secs() -> ConfigValue<i32> {
+    ConfigValue::Static(60)
+}
+
+fn default_max_ack_extension_secs() -> ConfigValue<u64> {
+    ConfigValue::Static(3600)
+}
+
+fn default_max_outstanding_messages() -> ConfigValue<i64> {
+    ConfigValue::Static(1000)
+}
+
+fn default_dispatch_lanes() -> ConfigValue<usize> {
+    ConfigValue::Static(8)
+}
+
+fn default_id_field() -> String {
+    "id".to_string()
+}
+
+fn default_operation_field() -> String {
+    "op".to_string()
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(PubSubSourceConfigDto)))]
+struct PubSubSourceSchemas;
+
+/// Descriptor for the Google Cloud Pub/Sub source plugin.
+pub struct PubSubSourceDescriptor;
+
+#[async_trait]
+impl SourcePluginDescriptor for PubSubSourceDescriptor {
+    fn kind(&self) -> &str {
+        "pubsub"
+    }
+
+    fn config_version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn config_schema_name(&self) -> &str {
+        "source.pubsub.PubSubSourceConfig"
+    }
+
+    fn config_schema_json(&self) -> String {
+        let api = PubSubSourceSchemas::openapi();
+        serde_json::to_string(
+            &api.components
+                .as_ref()
+                .expect("OpenAPI components missing")
+                .schemas,
+        )
+        .expect("Failed to serialize config schema")
+    }
+
+    async fn create_source(
+        &self,
+        id: &str,
+        config_json: &serde_json::Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn drasi_lib::sources::Source>> {
+        let dto: PubSubSourceConfigDto = serde_json::from_value(config_json.clone())?;
+        let mapper = DtoMapper::new();
+
+        let config = PubSubSourceConfig {
+            subscription: mapper.resolve_string(&dto.subscription)?,
+            project_id: mapper.resolve_optional(&dto.project_id)?,
+            credentials_file: mapper.resolve_optional(&dto.credentials_file)?,
+            credentials_json: mapper.resolve_optional(&dto.credentials_json)?,
+            ack_deadline_secs: mapper.resolve_typed(&dto.ack_deadline_secs)?,
+            max_ack_extension_secs: mapper.resolve_typed(&dto.max_ack_extension_secs)?,
+            max_outstanding_messages: mapper.resolve_typed(&dto.max_outstanding_messages)?,
+            dispatch_lanes: mapper.resolve_typed(&dto.dispatch_lanes)?,
+            id_field: dto.id_field,
+            id_attribute: dto.id_attribute,
+            operation_field: dto.operation_field,
+            label_field: dto.label_field,
+            label: dto.label,
+        };
+
+        let source = PubSubSourceBuilder::new(id)
+            .with_config(config)
+            .with_auto_start(auto_start)
+            .build()?;
+
+        Ok(Box::new(source))
+    }
+}
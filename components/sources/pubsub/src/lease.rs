@@ -0,0 +1,91 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Dispatch lanes and ack deadline leases.
+//!
+//! Messages are spread over a fixed number of lanes, each dispatching one
+//! message at a time. All messages with the same ordering key go to the same
+//! lane, so per-entity order is kept while unrelated keys proceed in parallel.
+//!
+//! Every message is leased from the moment it is received until it is acked
+//! or nacked. A lease keeps the message's ack deadline extended, so messages
+//! queued behind a slow dispatch are not redelivered.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Lane that dispatches a message.
+///
+/// Messages with an ordering key are routed by the key; messages without one
+/// are spread by message ID.
+pub(crate) fn lane_for(ordering_key: &str, message_id: &str, lanes: usize) -> usize {
+    let mut hasher = DefaultHasher::new();
+    if ordering_key.is_empty() {
+        message_id.hash(&mut hasher);
+    } else {
+        ordering_key.hash(&mut hasher);
+    }
+    (hasher.finish() % lanes.max(1) as u64) as usize
+}
+
+struct Lease<T> {
+    message: Arc<T>,
+    received: Instant,
+}
+
+/// Messages whose ack deadline is kept extended, keyed by ack ID.
+pub(crate) struct Leases<T> {
+    leases: HashMap<String, Lease<T>>,
+}
+
+impl<T> Default for Leases<T> {
+    fn default() -> Self {
+        Self {
+            leases: HashMap::new(),
+        }
+    }
+}
+
+impl<T> Leases<T> {
+    pub(crate) fn insert(&mut self, ack_id: impl Into<String>, message: Arc<T>, received: Instant) {
+        self.leases
+            .insert(ack_id.into(), Lease { message, received });
+    }
+
+    pub(crate) fn remove(&mut self, ack_id: &str) {
+        self.leases.remove(ack_id);
+    }
+
+    /// Messages whose deadline should be extended now.
+    ///
+    /// Leases held longer than `max_extension` are dropped instead, leaving
+    /// their messages to expire and be redelivered. Returns the messages to
+    /// extend and the number of dropped leases.
+    pub(crate) fn sweep(&mut self, now: Instant, max_extension: Duration) -> (Vec<Arc<T>>, usize) {
+        let before = self.leases.len();
+        self.leases
+            .retain(|_, lease| now.saturating_duration_since(lease.received) < max_extension);
+        let dropped = before - self.leases.len();
+
+        let extend = self
+            .leases
+            .values()
+            .map(|lease| lease.message.clone())
+            .collect();
+        (extend, dropped)
+    }
+}
@@ -0,0 +1,81 @@
+#![allow(unexpected_cfgs)]
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Google Cloud Pub/Sub Source Plugin for drasi-lib.
+//!
+//! This plugin receives JSON change messages from a Pub/Sub subscription over
+//! a streaming pull and turns each message into a node change.
+//!
+//! # Message Mapping
+//!
+//! | Payload field | Maps to |
+//! |---------------|---------|
+//! | `id_field` (default `id`) | Element ID; falls back to the `id_attribute` message attribute |
+//! | `operation_field` (default `op`) | `insert`/`i`, `update`/`u` or `delete`/`d`; missing means insert |
+//! | `label_field` (optional) | Node label, overriding `label` |
+//! | all other fields | Node properties |
+//!
+//! Without `label_field` or `label`, the subscription ID is used as the label.
+//!
+//! # Ordering and Acknowledgement
+//!
+//! Received messages are spread over `dispatch_lanes` lanes that dispatch
+//! concurrently. Messages with the same ordering key always use the same lane
+//! and are dispatched in the order they were received; enable message
+//! ordering on the subscription for Pub/Sub to deliver them in publish order.
+//!
+//! A message is acked after it has been dispatched. Until then its ack
+//! deadline is extended every `ack_deadline_secs / 2` seconds, for at most
+//! `max_ack_extension_secs`, so messages waiting behind a slow dispatch are not
+//! redelivered.
+//!
+//! # Usage Example
+//!
+//! ```rust,ignore
+//! use drasi_source_pubsub::PubSubSource;
+//!
+//! let source = PubSubSource::builder("orders")
+//!     .with_subscription("projects/my-project/subscriptions/orders-drasi")
+//!     .with_credentials_file("/var/secrets/drasi-sa.json")
+//!     .with_label("Order")
+//!     .build()?;
+//!
+//! drasi.add_source(source).await?;
+//! ```
+
+mod client;
+mod config;
+mod conversion;
+pub mod descriptor;
+mod lease;
+mod pubsub;
+
+#[cfg(test)]
+mod tests;
+
+pub use config::PubSubSourceConfig;
+pub use pubsub::{PubSubSource, PubSubSourceBuilder};
+
+/// Dynamic plugin entry point.
+#[cfg(feature = "dynamic-plugin")]
+drasi_plugin_sdk::export_plugin!(
+    plugin_id = "pubsub-source",
+    core_version = env!("CARGO_PKG_VERSION"),
+    lib_version = env!("CARGO_PKG_VERSION"),
+    plugin_version = env!("CARGO_PKG_VERSION"),
+    source_descriptors = [descriptor::PubSubSourceDescriptor],
+    reaction_descriptors = [],
+    bootstrap_descriptors = [],
+);
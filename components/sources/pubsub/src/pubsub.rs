@@ -0,0 +1,603 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Google Cloud Pub/Sub source implementation and builder.
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use futures::StreamExt;
+use google_cloud_pubsub::subscriber::{ReceivedMessage, SubscriberConfig};
+use google_cloud_pubsub::subscription::{SubscribeConfig, Subscription};
+use log::{debug, error, info, warn};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{mpsc, Mutex, RwLock};
+use tokio::task::JoinSet;
+
+use drasi_core::models::SourceChange;
+use drasi_lib::channels::*;
+use drasi_lib::component_graph::ComponentStatusHandle;
+use drasi_lib::managers::{log_component_start, log_component_stop};
+use drasi_lib::sources::base::{SourceBase, SourceBaseParams};
+use drasi_lib::Source;
+use tracing::Instrument;
+
+use crate::client::create_client;
+use crate::config::PubSubSourceConfig;
+use crate::conversion::message_to_source_change;
+use crate::lease::{lane_for, Leases};
+
+type Dispatchers = Arc<RwLock<Vec<Box<dyn ChangeDispatcher<SourceEventWrapper> + Send + Sync>>>>;
+
+type MessageLeases = Arc<Mutex<Leases<ReceivedMessage>>>;
+
+/// Delay before reconnecting the stream or retrying a dispatch.
+const RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// Messages buffered per lane before the stream is paused.
+const LANE_CAPACITY: usize = 100;
+
+/// Source that receives JSON change messages from a Pub/Sub subscription.
+///
+/// Messages are received over a streaming pull and dispatched by a fixed set
+/// of lanes. Messages sharing an ordering key always go through the same lane
+/// in the order they were received. Each message is acked once it has been
+/// dispatched, and its ack deadline is extended until then.
+pub struct PubSubSource {
+    /// Base source implementation providing dispatchers, status tracking, and lifecycle management.
+    base: SourceBase,
+    /// Pub/Sub configuration.
+    config: PubSubSourceConfig,
+}
+
+/// State shared by the dispatch lanes.
+struct LaneContext {
+    source_id: String,
+    config: PubSubSourceConfig,
+    dispatchers: Dispatchers,
+    leases: MessageLeases,
+}
+
+impl PubSubSource {
+    /// Create a builder for a Pub/Sub source.
+    pub fn builder(id: impl Into<String>) -> PubSubSourceBuilder {
+        PubSubSourceBuilder::new(id)
+    }
+
+    /// Create a new Pub/Sub source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid.
+    pub fn new(id: impl Into<String>, config: PubSubSourceConfig) -> Result<Self> {
+        PubSubSourceBuilder::new(id).with_config(config).build()
+    }
+
+    async fn dispatch(
+        source_id: &str,
+        dispatchers: &Dispatchers,
+        change: SourceChange,
+    ) -> Result<()> {
+        let mut profiling = drasi_lib::profiling::ProfilingMetadata::new();
+        profiling.source_send_ns = Some(drasi_lib::profiling::timestamp_ns());
+
+        let wrapper = SourceEventWrapper::with_profiling(
+            source_id.to_string(),
+            SourceEvent::Change(change),
+            chrono::Utc::now(),
+            profiling,
+        );
+
+        SourceBase::dispatch_from_task(dispatchers.clone(), wrapper, source_id).await
+    }
+
+    /// Dispatch and ack the messages of one lane, one at a time.
+    async fn run_lane(ctx: Arc<LaneContext>, mut messages: mpsc::Receiver<Arc<ReceivedMessage>>) {
+        while let Some(message) = messages.recv().await {
+            let attribute_id = ctx
+                .config
+                .id_attribute
+                .as_ref()
+                .and_then(|attribute| message.message.attributes.get(attribute))
+                .map(String::as_str);
+
+            match message_to_source_change(
+                &ctx.source_id,
+                &message.message.data,
+                attribute_id,
+                &ctx.config,
+            ) {
+                Ok(change) => {
+                    // Retry rather than nack, so later messages with the same
+                    // ordering key are never dispatched ahead of this one
+                    while let Err(e) =
+                        Self::dispatch(&ctx.source_id, &ctx.dispatchers, change.clone()).await
+                    {
+                        warn!(
+                            "[{}] Failed to dispatch message {}: {e}",
+                            ctx.source_id, message.message.message_id
+                        );
+                        tokio::time::sleep(RETRY_DELAY).await;
+                    }
+                }
+                Err(e) => warn!(
+                    "[{}] Skipping message {}: {e}",
+                    ctx.source_id, message.message.message_id
+                ),
+            }
+
+            if let Err(e) = message.ack().await {
+                warn!(
+                    "[{}] Failed to ack message {}: {e}",
+                    ctx.source_id, message.message.message_id
+                );
+            }
+            ctx.leases.lock().await.remove(message.ack_id());
+        }
+    }
+
+    /// Extend the ack deadline of every leased message at half the deadline.
+    async fn extend_leases(ctx: Arc<LaneContext>) {
+        let period = Duration::from_secs((ctx.config.ack_deadline_secs / 2) as u64);
+        let max_extension = Duration::from_secs(ctx.config.max_ack_extension_secs);
+        let mut ticker = tokio::time::interval(period);
+        ticker.tick().await;
+
+        loop {
+            ticker.tick().await;
+
+            let (messages, dropped) = ctx.leases.lock().await.sweep(Instant::now(), max_extension);
+            if dropped > 0 {
+                warn!(
+                    "[{}] {dropped} message(s) exceeded max_ack_extension_secs and will be redelivered",
+                    ctx.source_id
+                );
+            }
+
+            let deadline = ctx.config.ack_deadline_secs;
+            let results = futures::future::join_all(
+                messages
+                    .iter()
+                    .map(|message| message.modify_ack_deadline(deadline)),
+            )
+            .await;
+            let failed = results.iter().filter(|r| r.is_err()).count();
+            if failed > 0 {
+                debug!(
+                    "[{}] Failed to extend the ack deadline of {failed} message(s)",
+                    ctx.source_id
+                );
+            }
+        }
+    }
+
+    /// Receive messages from one streaming pull until it ends.
+    async fn receive(
+        ctx: &LaneContext,
+        subscription: &Subscription,
+        lanes: &[mpsc::Sender<Arc<ReceivedMessage>>],
+    ) -> Result<()> {
+        let subscribe_config =
+            SubscribeConfig::default().with_subscriber_config(SubscriberConfig {
+                stream_ack_deadline_seconds: ctx.config.ack_deadline_secs,
+                max_outstanding_messages: ctx.config.max_outstanding_messages,
+                ..Default::default()
+            });
+        let mut stream = subscription.subscribe(Some(subscribe_config)).await?;
+
+        while let Some(message) = stream.next().await {
+            let message = Arc::new(message);
+            ctx.leases
+                .lock()
+                .await
+                .insert(message.ack_id(), message.clone(), Instant::now());
+
+            let lane = lane_for(
+                &message.message.ordering_key,
+                &message.message.message_id,
+                lanes.len(),
+            );
+            if lanes[lane].send(message).await.is_err() {
+                return Err(anyhow!("Dispatch lane {lane} stopped"));
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn run(
+        source_id: String,
+        config: PubSubSourceConfig,
+        dispatchers: Dispatchers,
+        status_handle: ComponentStatusHandle,
+    ) {
+        let setup = async {
+            let client = create_client(&config).await?;
+            let subscription = client.subscription(&config.subscription);
+            if !subscription.exists(None).await? {
+                return Err(anyhow!(
+                    "Subscription '{}' does not exist",
+                    config.subscription
+                ));
+            }
+            Ok(subscription)
+        };
+        let subscription = match setup.await {
+            Ok(subscription) => subscription,
+            Err(e) => {
+                error!("[{source_id}] Failed to connect to Pub/Sub: {e}");
+                status_handle
+                    .set_status(
+                        ComponentStatus::Error,
+                        Some(format!("Failed to connect to Pub/Sub: {e}")),
+                    )
+                    .await;
+                return;
+            }
+        };
+
+        info!(
+            "[{source_id}] Pulling from subscription '{}' with {} dispatch lane(s)",
+            subscription.fully_qualified_name(),
+            config.dispatch_lanes
+        );
+        status_handle
+            .set_status(
+                ComponentStatus::Running,
+                Some("Pub/Sub source running".to_string()),
+            )
+            .await;
+
+        let ctx = Arc::new(LaneContext {
+            source_id,
+            config,
+            dispatchers,
+            leases: MessageLeases::default(),
+        });
+
+        // Dropping the join set on abort stops the lanes and the lease extender
+        let mut tasks = JoinSet::new();
+        let mut lanes = Vec::with_capacity(ctx.config.dispatch_lanes);
+        for _ in 0..ctx.config.dispatch_lanes {
+            let (tx, rx) = mpsc::channel(LANE_CAPACITY);
+            lanes.push(tx);
+            tasks.spawn(Self::run_lane(ctx.clone(), rx).in_current_span());
+        }
+        tasks.spawn(Self::extend_leases(ctx.clone()).in_current_span());
+
+        loop {
+            match Self::receive(&ctx, &subscription, &lanes).await {
+                Ok(()) => warn!("[{}] Streaming pull ended, reconnecting", ctx.source_id),
+                Err(e) => warn!("[{}] Streaming pull failed: {e}", ctx.source_id),
+            }
+            tokio::time::sleep(RETRY_DELAY).await;
+        }
+    }
+}
+
+#[async_trait]
+impl Source for PubSubSource {
+    fn id(&self) -> &str {
+        &self.base.id
+    }
+
+    fn type_name(&self) -> &str {
+        "pubsub"
+    }
+
+    fn properties(&self) -> HashMap<String, serde_json::Value> {
+        use crate::descriptor::PubSubSourceConfigDto;
+        use drasi_plugin_sdk::ConfigValue;
+
+        let dto = PubSubSourceConfigDto {
+            subscription: ConfigValue::Static(self.config.subscription.clone()),
+            project_id: self.config.project_id.clone().map(ConfigValue::Static),
+            credentials_file: self
+                .config
+                .credentials_file
+                .clone()
+                .map(ConfigValue::Static),
+            // The service account key is a secret
+            credentials_json: self
+                .config
+                .credentials_json
+                .as_ref()
+                .map(|_| ConfigValue::Static("***".to_string())),
+            ack_deadline_secs: ConfigValue::Static(self.config.ack_deadline_secs),
+            max_ack_extension_secs: ConfigValue::Static(self.config.max_ack_extension_secs),
+            max_outstanding_messages: ConfigValue::Static(self.config.max_outstanding_messages),
+            dispatch_lanes: ConfigValue::Static(self.config.dispatch_lanes),
+            id_field: self.config.id_field.clone(),
+            id_attribute: self.config.id_attribute.clone(),
+            operation_field: self.config.operation_field.clone(),
+            label_field: self.config.label_field.clone(),
+            label: self.config.label.clone(),
+        };
+
+        match serde_json::to_value(&dto) {
+            Ok(serde_json::Value::Object(map)) => map.into_iter().collect(),
+            _ => HashMap::new(),
+        }
+    }
+
+    fn auto_start(&self) -> bool {
+        self.base.get_auto_start()
+    }
+
+    async fn start(&self) -> Result<()> {
+        log_component_start("Pub/Sub Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Starting,
+                Some("Starting Pub/Sub source".to_string()),
+            )
+            .await;
+
+        let instance_id = self
+            .base
+            .context()
+            .await
+            .map(|c| c.instance_id)
+            .unwrap_or_default();
+
+        let span = tracing::info_span!(
+            "pubsub_source_subscriber",
+            instance_id = %instance_id,
+            component_id = %self.base.id,
+            component_type = "source"
+        );
+        let task = tokio::spawn(
+            Self::run(
+                self.base.id.clone(),
+                self.config.clone(),
+                self.base.dispatchers.clone(),
+                self.base.status_handle(),
+            )
+            .instrument(span),
+        );
+
+        *self.base.task_handle.write().await = Some(task);
+
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        log_component_stop("Pub/Sub Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopping,
+                Some("Stopping Pub/Sub source".to_string()),
+            )
+            .await;
+
+        // Unacked messages are redelivered once their ack deadline expires
+        if let Some(handle) = self.base.task_handle.write().await.take() {
+            handle.abort();
+            let _ = handle.await;
+        }
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopped,
+                Some("Pub/Sub source stopped".to_string()),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn status(&self) -> ComponentStatus {
+        self.base.get_status().await
+    }
+
+    async fn subscribe(
+        &self,
+        settings: drasi_lib::config::SourceSubscriptionSettings,
+    ) -> Result<SubscriptionResponse> {
+        self.base
+            .subscribe_with_bootstrap(&settings, "Pub/Sub")
+            .await
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    async fn initialize(&self, context: drasi_lib::context::SourceRuntimeContext) {
+        self.base.initialize(context).await;
+    }
+
+    async fn set_bootstrap_provider(
+        &self,
+        provider: Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>,
+    ) {
+        self.base.set_bootstrap_provider(provider).await;
+    }
+}
+
+/// Builder for [`PubSubSource`] instances.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use drasi_source_pubsub::PubSubSource;
+///
+/// let source = PubSubSource::builder("orders")
+///     .with_subscription("projects/my-project/subscriptions/orders-drasi")
+///     .with_credentials_file("/var/secrets/drasi-sa.json")
+///     .with_label("Order")
+///     .build()?;
+/// ```
+pub struct PubSubSourceBuilder {
+    id: String,
+    config: PubSubSourceConfig,
+    dispatch_mode: Option<DispatchMode>,
+    dispatch_buffer_capacity: Option<usize>,
+    bootstrap_provider: Option<Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>>,
+    auto_start: bool,
+}
+
+impl PubSubSourceBuilder {
+    /// Create a new builder with the given ID and default values.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            config: PubSubSourceConfig::default(),
+            dispatch_mode: None,
+            dispatch_buffer_capacity: None,
+            bootstrap_provider: None,
+            auto_start: true,
+        }
+    }
+
+    /// Set the subscription to pull from.
+    pub fn with_subscription(mut self, subscription: impl Into<String>) -> Self {
+        self.config.subscription = subscription.into();
+        self
+    }
+
+    /// Set the project of the subscription.
+    pub fn with_project_id(mut self, project_id: impl Into<String>) -> Self {
+        self.config.project_id = Some(project_id.into());
+        self
+    }
+
+    /// Authenticate with the service account key file at `path`.
+    pub fn with_credentials_file(mut self, path: impl Into<String>) -> Self {
+        self.config.credentials_file = Some(path.into());
+        self
+    }
+
+    /// Authenticate with the contents of a service account key file.
+    pub fn with_credentials_json(mut self, json: impl Into<String>) -> Self {
+        self.config.credentials_json = Some(json.into());
+        self
+    }
+
+    /// Set the ack deadline in seconds.
+    pub fn with_ack_deadline_secs(mut self, seconds: i32) -> Self {
+        self.config.ack_deadline_secs = seconds;
+        self
+    }
+
+    /// Set how long a message's ack deadline is extended at most, in seconds.
+    pub fn with_max_ack_extension_secs(mut self, seconds: u64) -> Self {
+        self.config.max_ack_extension_secs = seconds;
+        self
+    }
+
+    /// Set the maximum number of unacknowledged messages.
+    pub fn with_max_outstanding_messages(mut self, max_messages: i64) -> Self {
+        self.config.max_outstanding_messages = max_messages;
+        self
+    }
+
+    /// Set the number of dispatch lanes.
+    pub fn with_dispatch_lanes(mut self, lanes: usize) -> Self {
+        self.config.dispatch_lanes = lanes;
+        self
+    }
+
+    /// Set the payload field holding the element ID.
+    pub fn with_id_field(mut self, field: impl Into<String>) -> Self {
+        self.config.id_field = field.into();
+        self
+    }
+
+    /// Set the message attribute holding the element ID when the payload has none.
+    pub fn with_id_attribute(mut self, attribute: impl Into<String>) -> Self {
+        self.config.id_attribute = Some(attribute.into());
+        self
+    }
+
+    /// Set the payload field holding the change operation.
+    pub fn with_operation_field(mut self, field: impl Into<String>) -> Self {
+        self.config.operation_field = field.into();
+        self
+    }
+
+    /// Set the payload field holding the node label.
+    pub fn with_label_field(mut self, field: impl Into<String>) -> Self {
+        self.config.label_field = Some(field.into());
+        self
+    }
+
+    /// Set the label for nodes from this source.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.config.label = Some(label.into());
+        self
+    }
+
+    /// Set the dispatch mode for this source.
+    pub fn with_dispatch_mode(mut self, mode: DispatchMode) -> Self {
+        self.dispatch_mode = Some(mode);
+        self
+    }
+
+    /// Set the dispatch buffer capacity.
+    pub fn with_dispatch_buffer_capacity(mut self, capacity: usize) -> Self {
+        self.dispatch_buffer_capacity = Some(capacity);
+        self
+    }
+
+    /// Set the bootstrap provider for initial data delivery.
+    pub fn with_bootstrap_provider(
+        mut self,
+        provider: impl drasi_lib::bootstrap::BootstrapProvider + 'static,
+    ) -> Self {
+        self.bootstrap_provider = Some(Box::new(provider));
+        self
+    }
+
+    /// Set whether this source should auto-start when DrasiLib starts.
+    pub fn with_auto_start(mut self, auto_start: bool) -> Self {
+        self.auto_start = auto_start;
+        self
+    }
+
+    /// Set the full configuration at once.
+    pub fn with_config(mut self, config: PubSubSourceConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Build the Pub/Sub source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid or the base source
+    /// cannot be initialized.
+    pub fn build(self) -> Result<PubSubSource> {
+        self.config.validate()?;
+
+        let mut params = SourceBaseParams::new(&self.id).with_auto_start(self.auto_start);
+        if let Some(mode) = self.dispatch_mode {
+            params = params.with_dispatch_mode(mode);
+        }
+        if let Some(capacity) = self.dispatch_buffer_capacity {
+            params = params.with_dispatch_buffer_capacity(capacity);
+        }
+        if let Some(provider) = self.bootstrap_provider {
+            params = params.with_bootstrap_provider(provider);
+        }
+
+        Ok(PubSubSource {
+            base: SourceBase::new(params)?,
+            config: self.config,
+        })
+    }
+}
@@ -0,0 +1,254 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for the Google Cloud Pub/Sub source plugin.
+
+use super::*;
+use crate::conversion::message_to_source_change;
+use crate::lease::{lane_for, Leases};
+use drasi_core::models::{Element, ElementValue, SourceChange};
+use drasi_lib::Source;
+use drasi_plugin_sdk::prelude::SourcePluginDescriptor;
+use serde_json::json;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+fn config() -> PubSubSourceConfig {
+    PubSubSourceConfig {
+        subscription: "projects/my-project/subscriptions/orders-drasi".to_string(),
+        ..Default::default()
+    }
+}
+
+fn payload(value: serde_json::Value) -> Vec<u8> {
+    serde_json::to_vec(&value).unwrap()
+}
+
+mod builder {
+    use super::*;
+
+    #[test]
+    fn test_builder_with_valid_config() {
+        let source = PubSubSource::builder("test-source")
+            .with_subscription("orders-drasi")
+            .with_project_id("my-project")
+            .with_credentials_json(r#"{"type": "service_account"}"#)
+            .with_dispatch_lanes(4)
+            .with_id_attribute("entity")
+            .build()
+            .unwrap();
+
+        assert_eq!(source.id(), "test-source");
+        assert_eq!(source.type_name(), "pubsub");
+        let props = source.properties();
+        assert_eq!(props.get("subscription"), Some(&json!("orders-drasi")));
+        assert_eq!(props.get("projectId"), Some(&json!("my-project")));
+        assert_eq!(props.get("credentialsJson"), Some(&json!("***")));
+        assert_eq!(props.get("dispatchLanes"), Some(&json!(4)));
+        assert_eq!(props.get("idAttribute"), Some(&json!("entity")));
+    }
+
+    #[test]
+    fn test_builder_requires_subscription() {
+        assert!(PubSubSource::builder("test-source").build().is_err());
+    }
+
+    #[test]
+    fn test_config_rejects_invalid_values() {
+        for subscription in ["projects/p/topics/t", "projects//subscriptions/s", "a/b"] {
+            let config = PubSubSourceConfig {
+                subscription: subscription.to_string(),
+                ..config()
+            };
+            assert!(config.validate().is_err(), "{subscription}");
+        }
+
+        let both_credentials = PubSubSourceConfig {
+            credentials_file: Some("/sa.json".to_string()),
+            credentials_json: Some("{}".to_string()),
+            ..config()
+        };
+        assert!(both_credentials.validate().is_err());
+
+        for ack_deadline_secs in [5, 601] {
+            let config = PubSubSourceConfig {
+                ack_deadline_secs,
+                ..config()
+            };
+            assert!(config.validate().is_err());
+        }
+
+        let no_lanes = PubSubSourceConfig {
+            dispatch_lanes: 0,
+            ..config()
+        };
+        assert!(no_lanes.validate().is_err());
+    }
+
+    #[test]
+    fn test_config_deserialization_defaults() {
+        let config: PubSubSourceConfig =
+            serde_json::from_value(json!({"subscription": "orders-drasi"})).unwrap();
+        assert_eq!(config.ack_deadline_secs, 60);
+        assert_eq!(config.max_ack_extension_secs, 3600);
+        assert_eq!(config.max_outstanding_messages, 1000);
+        assert_eq!(config.dispatch_lanes, 8);
+        assert_eq!(config.subscription_id(), "orders-drasi");
+    }
+}
+
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn test_message_without_operation_is_insert() {
+        let change = message_to_source_change(
+            "src",
+            &payload(json!({"id": "o-1", "total": 12.5})),
+            None,
+            &config(),
+        )
+        .unwrap();
+
+        let SourceChange::Insert {
+            element:
+                Element::Node {
+                    metadata,
+                    properties,
+                },
+        } = change
+        else {
+            panic!("expected node insert");
+        };
+        assert_eq!(metadata.reference.element_id.as_ref(), "o-1");
+        assert_eq!(metadata.labels[0].as_ref(), "orders-drasi");
+        assert_eq!(
+            properties.get("total"),
+            Some(&ElementValue::Float(12.5.into()))
+        );
+    }
+
+    #[test]
+    fn test_id_attribute_fallback() {
+        let data = payload(json!({"total": 12.5}));
+
+        let change = message_to_source_change("src", &data, Some("o-7"), &config()).unwrap();
+        assert_eq!(change.get_reference().element_id.as_ref(), "o-7");
+
+        assert!(message_to_source_change("src", &data, None, &config()).is_err());
+    }
+
+    #[test]
+    fn test_operations_and_labels() {
+        let config = PubSubSourceConfig {
+            label: Some("Order".to_string()),
+            ..config()
+        };
+
+        let change = message_to_source_change(
+            "src",
+            &payload(json!({"id": 1, "op": "delete"})),
+            None,
+            &config,
+        )
+        .unwrap();
+        let SourceChange::Delete { metadata } = change else {
+            panic!("expected delete");
+        };
+        assert_eq!(metadata.labels[0].as_ref(), "Order");
+
+        assert!(message_to_source_change(
+            "src",
+            &payload(json!({"id": 1, "op": "upsert"})),
+            None,
+            &config
+        )
+        .is_err());
+    }
+}
+
+mod lease {
+    use super::*;
+
+    #[test]
+    fn test_ordering_key_picks_lane() {
+        let lane = lane_for("customer-1", "m-1", 8);
+        assert!(lane < 8);
+        for message_id in ["m-2", "m-3", "m-4"] {
+            assert_eq!(lane_for("customer-1", message_id, 8), lane);
+        }
+        assert_eq!(lane_for("", "m-1", 1), 0);
+    }
+
+    #[test]
+    fn test_sweep_extends_and_drops_leases() {
+        let start = Instant::now();
+        let mut leases = Leases::default();
+        leases.insert("ack-1", Arc::new("m-1"), start);
+        leases.insert("ack-2", Arc::new("m-2"), start + Duration::from_secs(50));
+        leases.insert("ack-3", Arc::new("m-3"), start);
+        leases.remove("ack-3");
+
+        let (extend, dropped) =
+            leases.sweep(start + Duration::from_secs(30), Duration::from_secs(60));
+        assert_eq!(extend.len(), 2);
+        assert_eq!(dropped, 0);
+
+        let (extend, dropped) =
+            leases.sweep(start + Duration::from_secs(90), Duration::from_secs(60));
+        assert_eq!(extend.iter().map(|m| **m).collect::<Vec<_>>(), vec!["m-2"]);
+        assert_eq!(dropped, 1);
+    }
+}
+
+mod descriptor {
+    use super::*;
+    use crate::descriptor::PubSubSourceDescriptor;
+
+    #[tokio::test]
+    async fn test_descriptor_creates_source() {
+        let descriptor = PubSubSourceDescriptor;
+        assert_eq!(descriptor.kind(), "pubsub");
+
+        let source = descriptor
+            .create_source(
+                "pubsub-1",
+                &json!({
+                    "subscription": "orders-drasi",
+                    "projectId": "my-project",
+                    "ackDeadlineSecs": 120
+                }),
+                false,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(source.id(), "pubsub-1");
+        assert!(!source.auto_start());
+        let props = source.properties();
+        assert_eq!(props.get("ackDeadlineSecs"), Some(&json!(120)));
+    }
+
+    #[tokio::test]
+    async fn test_descriptor_rejects_unknown_fields() {
+        let result = PubSubSourceDescriptor
+            .create_source(
+                "pubsub-1",
+                &json!({"subscription": "orders-drasi", "bogus": 1}),
+                true,
+            )
+            .await;
+        assert!(result.is_err());
+    }
+}
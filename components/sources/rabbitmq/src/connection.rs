@@ -0,0 +1,126 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Broker connection and topology declaration.
+
+use anyhow::{anyhow, Result};
+use lapin::options::{
+    BasicConsumeOptions, BasicQosOptions, ExchangeDeclareOptions, QueueBindOptions,
+    QueueDeclareOptions,
+};
+use lapin::types::FieldTable;
+use lapin::{Connection, ConnectionProperties, Consumer, ExchangeKind};
+
+use crate::config::{ExchangeType, RabbitMqSourceConfig};
+
+impl From<ExchangeType> for ExchangeKind {
+    fn from(exchange_type: ExchangeType) -> Self {
+        match exchange_type {
+            ExchangeType::Direct => ExchangeKind::Direct,
+            ExchangeType::Fanout => ExchangeKind::Fanout,
+            ExchangeType::Topic => ExchangeKind::Topic,
+            ExchangeType::Headers => ExchangeKind::Headers,
+        }
+    }
+}
+
+/// Open a connection, declare the configured topology and start consuming.
+///
+/// Declarations are idempotent, so this runs on every (re)connect. The returned
+/// connection must be kept alive for as long as the consumer is used.
+pub(crate) async fn connect_and_consume(
+    source_id: &str,
+    config: &RabbitMqSourceConfig,
+) -> Result<(Connection, Consumer)> {
+    let connection = Connection::connect(&config.url, ConnectionProperties::default())
+        .await
+        .map_err(|e| anyhow!("Failed to connect to RabbitMQ: {e}"))?;
+    let channel = connection.create_channel().await?;
+
+    channel
+        .basic_qos(config.prefetch_count, BasicQosOptions::default())
+        .await?;
+
+    if let Some(exchange) = &config.exchange {
+        channel
+            .exchange_declare(
+                exchange,
+                config.exchange_type.into(),
+                ExchangeDeclareOptions {
+                    durable: config.durable,
+                    ..Default::default()
+                },
+                FieldTable::default(),
+            )
+            .await
+            .map_err(|e| anyhow!("Failed to declare exchange '{exchange}': {e}"))?;
+    }
+
+    channel
+        .queue_declare(
+            &config.queue,
+            QueueDeclareOptions {
+                durable: config.durable,
+                ..Default::default()
+            },
+            FieldTable::default(),
+        )
+        .await
+        .map_err(|e| anyhow!("Failed to declare queue '{}': {e}", config.queue))?;
+
+    if let Some(exchange) = &config.exchange {
+        let default_key = [String::new()];
+        let keys = if config.routing_keys.is_empty() {
+            &default_key[..]
+        } else {
+            &config.routing_keys[..]
+        };
+        for key in keys {
+            channel
+                .queue_bind(
+                    &config.queue,
+                    exchange,
+                    key,
+                    QueueBindOptions::default(),
+                    FieldTable::default(),
+                )
+                .await
+                .map_err(|e| {
+                    anyhow!(
+                        "Failed to bind queue '{}' to '{exchange}' with '{key}': {e}",
+                        config.queue
+                    )
+                })?;
+        }
+    }
+
+    let consumer_tag = config.consumer_tag.clone().unwrap_or_default();
+    let consumer = channel
+        .basic_consume(
+            &config.queue,
+            &consumer_tag,
+            BasicConsumeOptions::default(),
+            FieldTable::default(),
+        )
+        .await
+        .map_err(|e| anyhow!("Failed to consume from '{}': {e}", config.queue))?;
+
+    log::info!(
+        "[{source_id}] Consuming from queue '{}' with prefetch {}",
+        config.queue,
+        config.prefetch_count
+    );
+
+    Ok((connection, consumer))
+}
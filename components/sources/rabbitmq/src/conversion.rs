@@ -0,0 +1,108 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion of RabbitMQ deliveries into Drasi source changes.
+
+use anyhow::{anyhow, Result};
+use drasi_core::models::{Element, ElementMetadata, ElementReference, SourceChange};
+use drasi_lib::sources::convert_json_to_element_properties;
+use serde_json::Value;
+use std::sync::Arc;
+
+use crate::config::RabbitMqSourceConfig;
+
+fn field_as_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// Resolve the label for a delivery: the configured label, then the last
+/// token of the routing key (`order.created` becomes `created`), then the
+/// queue name.
+pub(crate) fn label_for_routing_key(routing_key: &str, config: &RabbitMqSourceConfig) -> String {
+    config.label.clone().unwrap_or_else(|| {
+        routing_key
+            .rsplit('.')
+            .next()
+            .filter(|token| !token.is_empty())
+            .unwrap_or(&config.queue)
+            .to_string()
+    })
+}
+
+/// Convert a message payload into a [`SourceChange`].
+///
+/// The payload must be a JSON object. Fields other than the operation and label
+/// fields become node properties.
+///
+/// # Errors
+///
+/// Returns an error if the payload is not a JSON object, has no ID, or has an
+/// unrecognized operation.
+pub(crate) fn delivery_to_source_change(
+    source_id: &str,
+    routing_key: &str,
+    payload: &[u8],
+    config: &RabbitMqSourceConfig,
+) -> Result<SourceChange> {
+    let mut object = match serde_json::from_slice::<Value>(payload) {
+        Ok(Value::Object(object)) => object,
+        Ok(_) => return Err(anyhow!("Message is not a JSON object")),
+        Err(e) => return Err(anyhow!("Message is not valid JSON: {e}")),
+    };
+
+    let element_id = object
+        .get(&config.id_field)
+        .and_then(field_as_string)
+        .filter(|id| !id.is_empty())
+        .ok_or_else(|| anyhow!("Message has no '{}' field", config.id_field))?;
+
+    let operation = object
+        .remove(&config.operation_field)
+        .and_then(|v| field_as_string(&v))
+        .map(|op| op.to_lowercase())
+        .unwrap_or_else(|| "insert".to_string());
+
+    let label = config
+        .label_field
+        .as_ref()
+        .and_then(|field| object.remove(field))
+        .and_then(|v| field_as_string(&v))
+        .unwrap_or_else(|| label_for_routing_key(routing_key, config));
+
+    let metadata = ElementMetadata {
+        reference: ElementReference::new(source_id, &element_id),
+        labels: Arc::from(vec![Arc::from(label.as_str())]),
+        effective_from: chrono::Utc::now().timestamp_millis() as u64,
+    };
+
+    if matches!(operation.as_str(), "d" | "delete") {
+        return Ok(SourceChange::Delete { metadata });
+    }
+
+    let element = Element::Node {
+        metadata,
+        properties: convert_json_to_element_properties(&object),
+    };
+
+    match operation.as_str() {
+        "i" | "c" | "insert" | "create" => Ok(SourceChange::Insert { element }),
+        "u" | "update" => Ok(SourceChange::Update { element }),
+        other => Err(anyhow!("Message has unknown operation '{other}'")),
+    }
+}
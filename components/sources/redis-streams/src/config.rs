@@ -0,0 +1,220 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration types for the Redis Streams source plugin.
+//!
+//! This module defines how the source connects to Redis, which streams it
+//! consumes, and how stream entry fields are mapped onto graph nodes.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+fn default_consumer_group() -> String {
+    "drasi-core".to_string()
+}
+
+fn default_start_id() -> String {
+    "$".to_string()
+}
+
+fn default_batch_size() -> usize {
+    100
+}
+
+fn default_block_ms() -> u64 {
+    5000
+}
+
+fn default_claim_min_idle_ms() -> u64 {
+    60000
+}
+
+fn default_id_field() -> String {
+    "id".to_string()
+}
+
+fn default_operation_field() -> String {
+    "op".to_string()
+}
+
+/// Redis Streams source configuration.
+///
+/// Each stream entry becomes one node change. The entry's fields are mapped to
+/// node properties, except for the configured id, operation and label fields.
+///
+/// # Example
+///
+/// ```rust
+/// use drasi_source_redis_streams::RedisStreamsSourceConfig;
+/// use std::collections::HashMap;
+///
+/// let config = RedisStreamsSourceConfig {
+///     redis_url: "redis://localhost:6379".to_string(),
+///     streams: vec!["orders".to_string(), "customers".to_string()],
+///     labels: HashMap::from([("orders".to_string(), "Order".to_string())]),
+///     ..Default::default()
+/// };
+/// ```
+///
+/// # YAML Configuration
+///
+/// ```yaml
+/// source_type: redis-streams
+/// properties:
+///   redis_url: "redis://localhost:6379"
+///   streams: ["orders", "customers"]
+///   consumer_group: "drasi-core"
+///   labels:
+///     orders: Order
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RedisStreamsSourceConfig {
+    /// Redis connection URL (e.g. `redis://localhost:6379`).
+    pub redis_url: String,
+
+    /// Stream keys to consume from.
+    pub streams: Vec<String>,
+
+    /// Consumer group name. Created on every stream if it does not exist.
+    ///
+    /// **Default**: `"drasi-core"`
+    #[serde(default = "default_consumer_group")]
+    pub consumer_group: String,
+
+    /// Consumer name (unique within group).
+    ///
+    /// The name must be stable across restarts so that entries delivered but not
+    /// acknowledged before a restart are redelivered to this consumer.
+    ///
+    /// **Default**: Auto-generated from source ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub consumer_name: Option<String>,
+
+    /// Position at which a newly created consumer group starts reading.
+    ///
+    /// `"$"` only consumes entries added after the group is created, `"0"`
+    /// consumes the whole stream. Ignored when the group already exists.
+    ///
+    /// **Default**: `"$"`
+    #[serde(default = "default_start_id")]
+    pub start_id: String,
+
+    /// Number of entries to read per XREADGROUP call.
+    ///
+    /// **Default**: `100`
+    #[serde(default = "default_batch_size")]
+    pub batch_size: usize,
+
+    /// Milliseconds to block waiting for new entries.
+    ///
+    /// **Default**: `5000`
+    #[serde(default = "default_block_ms")]
+    pub block_ms: u64,
+
+    /// Minimum idle time before pending entries owned by other consumers are
+    /// claimed (XAUTOCLAIM) on startup. `0` disables claiming.
+    ///
+    /// **Default**: `60000`
+    #[serde(default = "default_claim_min_idle_ms")]
+    pub claim_min_idle_ms: u64,
+
+    /// Entry field holding the element ID.
+    ///
+    /// **Default**: `"id"`
+    #[serde(default = "default_id_field")]
+    pub id_field: String,
+
+    /// Entry field holding the change operation (`insert`, `update`, `delete`
+    /// or `i`/`u`/`d`). Entries without it are treated as inserts.
+    ///
+    /// **Default**: `"op"`
+    #[serde(default = "default_operation_field")]
+    pub operation_field: String,
+
+    /// Optional entry field holding the node label. Takes precedence over `labels`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_field: Option<String>,
+
+    /// Label to use for each stream. Streams without a mapping use the stream key.
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
+}
+
+impl Default for RedisStreamsSourceConfig {
+    fn default() -> Self {
+        Self {
+            redis_url: String::new(),
+            streams: Vec::new(),
+            consumer_group: default_consumer_group(),
+            consumer_name: None,
+            start_id: default_start_id(),
+            batch_size: default_batch_size(),
+            block_ms: default_block_ms(),
+            claim_min_idle_ms: default_claim_min_idle_ms(),
+            id_field: default_id_field(),
+            operation_field: default_operation_field(),
+            label_field: None,
+            labels: HashMap::new(),
+        }
+    }
+}
+
+impl RedisStreamsSourceConfig {
+    /// Validate the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `redis_url` is empty
+    /// - `streams` is empty or contains an empty key
+    /// - `consumer_group` is empty
+    /// - `batch_size` is 0
+    /// - `id_field` is empty
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if self.redis_url.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Validation error: redis_url cannot be empty. \
+                 Please provide a valid Redis connection URL (e.g., redis://localhost:6379)"
+            ));
+        }
+
+        if self.streams.is_empty() || self.streams.iter().any(|s| s.is_empty()) {
+            return Err(anyhow::anyhow!(
+                "Validation error: streams must contain at least one non-empty stream key"
+            ));
+        }
+
+        if self.consumer_group.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Validation error: consumer_group cannot be empty. \
+                 Please specify a consumer group name"
+            ));
+        }
+
+        if self.batch_size == 0 {
+            return Err(anyhow::anyhow!(
+                "Validation error: batch_size cannot be 0. \
+                 Please specify a positive batch size"
+            ));
+        }
+
+        if self.id_field.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Validation error: id_field cannot be empty"
+            ));
+        }
+
+        Ok(())
+    }
+}
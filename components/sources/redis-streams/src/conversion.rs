@@ -0,0 +1,129 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion of Redis stream entries into Drasi source changes.
+
+use anyhow::{anyhow, Result};
+use drasi_core::models::{Element, ElementMetadata, ElementReference, SourceChange};
+use drasi_lib::sources::convert_json_to_element_properties;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::config::RedisStreamsSourceConfig;
+
+/// Convert a raw Redis value to JSON.
+///
+/// String values that parse as JSON (numbers, booleans, objects, arrays) keep
+/// their JSON type; anything else is kept as a string.
+pub(crate) fn redis_value_to_json(value: &redis::Value) -> Value {
+    match value {
+        redis::Value::Nil => Value::Null,
+        redis::Value::Int(i) => Value::Number((*i).into()),
+        redis::Value::Data(bytes) => {
+            let text = String::from_utf8_lossy(bytes);
+            serde_json::from_str(&text).unwrap_or_else(|_| Value::String(text.into_owned()))
+        }
+        redis::Value::Status(s) => Value::String(s.clone()),
+        redis::Value::Okay => Value::String("OK".to_string()),
+        redis::Value::Bulk(items) => Value::Array(items.iter().map(redis_value_to_json).collect()),
+    }
+}
+
+/// Read a field as a plain string, without JSON interpretation.
+fn field_as_string(fields: &HashMap<String, redis::Value>, name: &str) -> Option<String> {
+    match fields.get(name)? {
+        redis::Value::Data(bytes) => Some(String::from_utf8_lossy(bytes).into_owned()),
+        redis::Value::Int(i) => Some(i.to_string()),
+        redis::Value::Status(s) => Some(s.clone()),
+        _ => None,
+    }
+}
+
+/// Derive the effective-from timestamp (milliseconds) from a stream entry ID.
+///
+/// Redis entry IDs have the form `<millis>-<seq>`; the current time is used if
+/// the ID does not follow that form (e.g. explicitly assigned IDs).
+pub(crate) fn entry_timestamp_millis(entry_id: &str) -> u64 {
+    entry_id
+        .split('-')
+        .next()
+        .and_then(|ms| ms.parse::<u64>().ok())
+        .unwrap_or_else(|| chrono::Utc::now().timestamp_millis() as u64)
+}
+
+/// Convert one stream entry into a [`SourceChange`].
+///
+/// # Errors
+///
+/// Returns an error if the entry has no value for the configured `id_field`
+/// or an unrecognized operation.
+pub(crate) fn entry_to_source_change(
+    source_id: &str,
+    stream_key: &str,
+    entry_id: &str,
+    fields: &HashMap<String, redis::Value>,
+    config: &RedisStreamsSourceConfig,
+) -> Result<SourceChange> {
+    let element_id = field_as_string(fields, &config.id_field)
+        .filter(|id| !id.is_empty())
+        .ok_or_else(|| {
+            anyhow!(
+                "Entry {entry_id} on stream '{stream_key}' has no '{}' field",
+                config.id_field
+            )
+        })?;
+
+    let label = config
+        .label_field
+        .as_ref()
+        .and_then(|field| field_as_string(fields, field))
+        .or_else(|| config.labels.get(stream_key).cloned())
+        .unwrap_or_else(|| stream_key.to_string());
+
+    let metadata = ElementMetadata {
+        reference: ElementReference::new(source_id, &element_id),
+        labels: Arc::from(vec![Arc::from(label.as_str())]),
+        effective_from: entry_timestamp_millis(entry_id),
+    };
+
+    let operation = field_as_string(fields, &config.operation_field)
+        .map(|op| op.to_lowercase())
+        .unwrap_or_else(|| "insert".to_string());
+
+    if matches!(operation.as_str(), "d" | "delete") {
+        return Ok(SourceChange::Delete { metadata });
+    }
+
+    let mut properties = Map::new();
+    for (name, value) in fields {
+        if name == &config.operation_field || Some(name) == config.label_field.as_ref() {
+            continue;
+        }
+        properties.insert(name.clone(), redis_value_to_json(value));
+    }
+
+    let element = Element::Node {
+        metadata,
+        properties: convert_json_to_element_properties(&properties),
+    };
+
+    match operation.as_str() {
+        "i" | "c" | "insert" | "create" => Ok(SourceChange::Insert { element }),
+        "u" | "update" => Ok(SourceChange::Update { element }),
+        other => Err(anyhow!(
+            "Entry {entry_id} on stream '{stream_key}' has unknown operation '{other}'"
+        )),
+    }
+}
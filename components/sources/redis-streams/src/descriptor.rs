@@ -0,0 +1,142 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Redis Streams source plugin descriptor and configuration DTOs.
+
+use crate::{RedisStreamsSourceBuilder, RedisStreamsSourceConfig};
+use drasi_plugin_sdk::prelude::*;
+use std::collections::HashMap;
+use utoipa::OpenApi;
+
+/// Redis Streams source configuration DTO.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::redis_streams::RedisStreamsSourceConfig)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RedisStreamsSourceConfigDto {
+    pub redis_url: ConfigValue<String>,
+    pub streams: Vec<ConfigValue<String>>,
+    #[serde(default = "default_consumer_group")]
+    pub consumer_group: ConfigValue<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub consumer_name: Option<ConfigValue<String>>,
+    #[serde(default = "default_start_id")]
+    pub start_id: ConfigValue<String>,
+    #[serde(default = "default_batch_size")]
+    pub batch_size: ConfigValue<usize>,
+    #[serde(default = "default_block_ms")]
+    pub block_ms: ConfigValue<u64>,
+    #[serde(default = "default_claim_min_idle_ms")]
+    pub claim_min_idle_ms: ConfigValue<u64>,
+    #[serde(default = "default_id_field")]
+    pub id_field: String,
+    #[serde(default = "default_operation_field")]
+    pub operation_field: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_field: Option<String>,
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
+}
+
+fn default_consumer_group() -> ConfigValue<String> {
+    ConfigValue::Static("drasi-core".to_string())
+}
+
+fn default_start_id() -> ConfigValue<String> {
+    ConfigValue::Static("$".to_string())
+}
+
+fn default_batch_size() -> ConfigValue<usize> {
+    ConfigValue::Static(100)
+}
+
+fn default_block_ms() -> ConfigValue<u64> {
+    ConfigValue::Static(5000)
+}
+
+fn default_claim_min_idle_ms() -> ConfigValue<u64> {
+    ConfigValue::Static(60000)
+}
+
+fn default_id_field() -> String {
+    "id".to_string()
+}
+
+fn default_operation_field() -> String {
+    "op".to_string()
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(RedisStreamsSourceConfigDto)))]
+struct RedisStreamsSourceSchemas;
+
+/// Descriptor for the Redis Streams source plugin.
+pub struct RedisStreamsSourceDescriptor;
+
+#[async_trait]
+impl SourcePluginDescriptor for RedisStreamsSourceDescriptor {
+    fn kind(&self) -> &str {
+        "redis-streams"
+    }
+
+    fn config_version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn config_schema_name(&self) -> &str {
+        "source.redis_streams.RedisStreamsSourceConfig"
+    }
+
+    fn config_schema_json(&self) -> String {
+        let api = RedisStreamsSourceSchemas::openapi();
+        serde_json::to_string(
+            &api.components
+                .as_ref()
+                .expect("OpenAPI components missing")
+                .schemas,
+        )
+        .expect("Failed to serialize config schema")
+    }
+
+    async fn create_source(
+        &self,
+        id: &str,
+        config_json: &serde_json::Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn drasi_lib::sources::Source>> {
+        let dto: RedisStreamsSourceConfigDto = serde_json::from_value(config_json.clone())?;
+        let mapper = DtoMapper::new();
+
+        let config = RedisStreamsSourceConfig {
+            redis_url: mapper.resolve_string(&dto.redis_url)?,
+            streams: mapper.resolve_string_vec(&dto.streams)?,
+            consumer_group: mapper.resolve_string(&dto.consumer_group)?,
+            consumer_name: mapper.resolve_optional(&dto.consumer_name)?,
+            start_id: mapper.resolve_string(&dto.start_id)?,
+            batch_size: mapper.resolve_typed(&dto.batch_size)?,
+            block_ms: mapper.resolve_typed(&dto.block_ms)?,
+            claim_min_idle_ms: mapper.resolve_typed(&dto.claim_min_idle_ms)?,
+            id_field: dto.id_field,
+            operation_field: dto.operation_field,
+            label_field: dto.label_field,
+            labels: dto.labels,
+        };
+
+        let source = RedisStreamsSourceBuilder::new(id)
+            .with_config(config)
+            .with_auto_start(auto_start)
+            .build()?;
+
+        Ok(Box::new(source))
+    }
+}
@@ -0,0 +1,81 @@
+#![allow(unexpected_cfgs)]
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Redis Streams Source Plugin for drasi-lib.
+//!
+//! This plugin consumes entries from one or more Redis Streams using a consumer
+//! group and turns each entry into a node change.
+//!
+//! # Entry Mapping
+//!
+//! | Entry field | Maps to |
+//! |-------------|---------|
+//! | `id_field` (default `id`) | Element ID |
+//! | `operation_field` (default `op`) | `insert`/`i`, `update`/`u` or `delete`/`d`; missing means insert |
+//! | `label_field` (optional) | Node label, overriding the stream mapping |
+//! | all other fields | Node properties |
+//!
+//! Field values that parse as JSON keep their JSON type (`"42"` becomes an
+//! integer, `"{\"a\":1}"` an object); everything else is a string. The node
+//! label is taken from `label_field`, then from the `labels` map for the
+//! stream, and finally falls back to the stream key. The entry ID's millisecond
+//! part is used as the change's effective-from timestamp.
+//!
+//! # Delivery Guarantees
+//!
+//! Entries are acknowledged with XACK only after they have been dispatched.
+//! When the source starts (or reconnects) it first replays entries that were
+//! delivered to its consumer but not acknowledged, then claims entries that
+//! other consumers left pending longer than `claim_min_idle_ms` (XAUTOCLAIM,
+//! Redis 6.2+). Delivery is therefore at-least-once.
+//!
+//! # Usage Example
+//!
+//! ```rust,ignore
+//! use drasi_source_redis_streams::RedisStreamsSource;
+//!
+//! let source = RedisStreamsSource::builder("orders-source")
+//!     .with_redis_url("redis://localhost:6379")
+//!     .with_streams(vec!["orders".to_string(), "customers".to_string()])
+//!     .with_label("orders", "Order")
+//!     .with_label("customers", "Customer")
+//!     .with_consumer_name("drasi-1")
+//!     .build()?;
+//!
+//! drasi.add_source(source).await?;
+//! ```
+
+mod config;
+mod conversion;
+pub mod descriptor;
+mod redis_streams;
+
+#[cfg(test)]
+mod tests;
+
+pub use config::RedisStreamsSourceConfig;
+pub use redis_streams::{RedisStreamsSource, RedisStreamsSourceBuilder};
+
+/// Dynamic plugin entry point.
+#[cfg(feature = "dynamic-plugin")]
+drasi_plugin_sdk::export_plugin!(
+    plugin_id = "redis-streams-source",
+    core_version = env!("CARGO_PKG_VERSION"),
+    lib_version = env!("CARGO_PKG_VERSION"),
+    plugin_version = env!("CARGO_PKG_VERSION"),
+    source_descriptors = [descriptor::RedisStreamsSourceDescriptor],
+    reaction_descriptors = [],
+    bootstrap_descriptors = [],
+);
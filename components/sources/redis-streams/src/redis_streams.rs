@@ -0,0 +1,783 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Redis Streams source implementation and builder.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use log::{debug, error, info, warn};
+use redis::streams::{StreamId, StreamRangeReply, StreamReadReply};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+use drasi_lib::channels::*;
+use drasi_lib::component_graph::ComponentStatusHandle;
+use drasi_lib::managers::{log_component_start, log_component_stop};
+use drasi_lib::sources::base::{SourceBase, SourceBaseParams};
+use drasi_lib::Source;
+use tracing::Instrument;
+
+use crate::config::RedisStreamsSourceConfig;
+use crate::conversion::entry_to_source_change;
+
+const MAX_CONNECT_RETRIES: usize = 5;
+const RETRY_DELAY_MS: u64 = 1000;
+
+type Dispatchers = Arc<RwLock<Vec<Box<dyn ChangeDispatcher<SourceEventWrapper> + Send + Sync>>>>;
+
+/// Source that consumes one or more Redis Streams through a consumer group.
+///
+/// Entries are acknowledged (XACK) only after their change has been dispatched.
+/// On startup the source first replays entries that were delivered to this
+/// consumer but never acknowledged, then claims entries abandoned by other
+/// consumers, and only then starts reading new entries.
+pub struct RedisStreamsSource {
+    /// Base source implementation providing dispatchers, status tracking, and lifecycle management.
+    base: SourceBase,
+    /// Redis Streams configuration.
+    config: RedisStreamsSourceConfig,
+}
+
+impl RedisStreamsSource {
+    /// Create a builder for a Redis Streams source.
+    pub fn builder(id: impl Into<String>) -> RedisStreamsSourceBuilder {
+        RedisStreamsSourceBuilder::new(id)
+    }
+
+    /// Create a new Redis Streams source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid.
+    pub fn new(id: impl Into<String>, config: RedisStreamsSourceConfig) -> Result<Self> {
+        RedisStreamsSourceBuilder::new(id)
+            .with_config(config)
+            .build()
+    }
+
+    fn consumer_name(&self) -> String {
+        self.config
+            .consumer_name
+            .clone()
+            .unwrap_or_else(|| format!("drasi-consumer-{}", self.base.id))
+    }
+
+    /// Connect to Redis with retry logic
+    async fn connect_with_retry(redis_url: &str) -> Result<redis::aio::MultiplexedConnection> {
+        let client = redis::Client::open(redis_url)?;
+        let mut delay = RETRY_DELAY_MS;
+
+        for attempt in 0..MAX_CONNECT_RETRIES {
+            match client.get_multiplexed_async_connection().await {
+                Ok(conn) => {
+                    info!("Successfully connected to Redis");
+                    return Ok(conn);
+                }
+                Err(e) if attempt < MAX_CONNECT_RETRIES - 1 => {
+                    warn!(
+                        "Redis connection failed (attempt {}/{}): {}",
+                        attempt + 1,
+                        MAX_CONNECT_RETRIES,
+                        e
+                    );
+                    tokio::time::sleep(Duration::from_millis(delay)).await;
+                    delay *= 2;
+                }
+                Err(e) => {
+                    return Err(anyhow::anyhow!(
+                        "Failed to connect to Redis after {MAX_CONNECT_RETRIES} attempts: {e}"
+                    ));
+                }
+            }
+        }
+
+        unreachable!()
+    }
+
+    /// Create the consumer group on a stream if it does not exist yet
+    async fn ensure_consumer_group(
+        conn: &mut redis::aio::MultiplexedConnection,
+        stream_key: &str,
+        consumer_group: &str,
+        start_id: &str,
+    ) -> Result<()> {
+        let result: Result<String, redis::RedisError> = redis::cmd("XGROUP")
+            .arg("CREATE")
+            .arg(stream_key)
+            .arg(consumer_group)
+            .arg(start_id)
+            .arg("MKSTREAM")
+            .query_async(conn)
+            .await;
+
+        match result {
+            Ok(_) => {
+                info!(
+                    "Created consumer group '{consumer_group}' for stream '{stream_key}' at position '{start_id}'"
+                );
+                Ok(())
+            }
+            Err(e) if e.to_string().contains("BUSYGROUP") => {
+                debug!(
+                    "Consumer group '{consumer_group}' already exists for stream '{stream_key}'"
+                );
+                Ok(())
+            }
+            Err(e) => Err(anyhow::anyhow!("Failed to create consumer group: {e}")),
+        }
+    }
+
+    /// Read from all streams with XREADGROUP.
+    ///
+    /// `position` is `">"` for new entries or `"0"` for this consumer's pending entries.
+    async fn read_group(
+        conn: &mut redis::aio::MultiplexedConnection,
+        config: &RedisStreamsSourceConfig,
+        consumer_name: &str,
+        position: &str,
+        block_ms: Option<u64>,
+    ) -> Result<Option<StreamReadReply>, redis::RedisError> {
+        let mut cmd = redis::cmd("XREADGROUP");
+        cmd.arg("GROUP")
+            .arg(&config.consumer_group)
+            .arg(consumer_name)
+            .arg("COUNT")
+            .arg(config.batch_size);
+        if let Some(block_ms) = block_ms {
+            cmd.arg("BLOCK").arg(block_ms);
+        }
+        cmd.arg("STREAMS");
+        for stream in &config.streams {
+            cmd.arg(stream);
+        }
+        for _ in &config.streams {
+            cmd.arg(position);
+        }
+        cmd.query_async(conn).await
+    }
+
+    /// Convert and dispatch entries, returning the IDs that are safe to acknowledge.
+    ///
+    /// Entries that cannot be converted are acknowledged as well, since
+    /// redelivering them would fail the same way.
+    async fn dispatch_entries(
+        source_id: &str,
+        config: &RedisStreamsSourceConfig,
+        dispatchers: &Dispatchers,
+        stream_key: &str,
+        entries: &[StreamId],
+    ) -> Vec<String> {
+        let mut ack_ids = Vec::with_capacity(entries.len());
+
+        for entry in entries {
+            let change = match entry_to_source_change(
+                source_id, stream_key, &entry.id, &entry.map, config,
+            ) {
+                Ok(change) => change,
+                Err(e) => {
+                    warn!("[{source_id}] Skipping entry: {e}");
+                    ack_ids.push(entry.id.clone());
+                    continue;
+                }
+            };
+
+            let mut profiling = drasi_lib::profiling::ProfilingMetadata::new();
+            profiling.source_send_ns = Some(drasi_lib::profiling::timestamp_ns());
+
+            let wrapper = SourceEventWrapper::with_profiling(
+                source_id.to_string(),
+                SourceEvent::Change(change),
+                chrono::Utc::now(),
+                profiling,
+            );
+
+            match SourceBase::dispatch_from_task(dispatchers.clone(), wrapper, source_id).await {
+                Ok(()) => ack_ids.push(entry.id.clone()),
+                Err(e) => {
+                    // Leave the entry pending so it is redelivered after a restart
+                    warn!(
+                        "[{source_id}] Failed to dispatch entry {} from '{stream_key}', leaving it pending: {e}",
+                        entry.id
+                    );
+                }
+            }
+        }
+
+        ack_ids
+    }
+
+    /// Acknowledge entries on a stream
+    async fn ack(
+        conn: &mut redis::aio::MultiplexedConnection,
+        stream_key: &str,
+        consumer_group: &str,
+        ids: &[String],
+    ) {
+        if ids.is_empty() {
+            return;
+        }
+
+        let mut cmd = redis::cmd("XACK");
+        cmd.arg(stream_key).arg(consumer_group);
+        for id in ids {
+            cmd.arg(id);
+        }
+
+        match cmd.query_async::<_, i64>(conn).await {
+            Ok(count) => debug!("Acknowledged {count} entries on '{stream_key}'"),
+            Err(e) => error!("Failed to acknowledge entries on '{stream_key}': {e}"),
+        }
+    }
+
+    /// Dispatch and acknowledge every entry in an XREADGROUP reply.
+    ///
+    /// Returns the number of entries received.
+    async fn process_reply(
+        conn: &mut redis::aio::MultiplexedConnection,
+        source_id: &str,
+        config: &RedisStreamsSourceConfig,
+        dispatchers: &Dispatchers,
+        reply: StreamReadReply,
+    ) -> usize {
+        let mut received = 0;
+        for stream in reply.keys {
+            received += stream.ids.len();
+            let ack_ids =
+                Self::dispatch_entries(source_id, config, dispatchers, &stream.key, &stream.ids)
+                    .await;
+            Self::ack(conn, &stream.key, &config.consumer_group, &ack_ids).await;
+        }
+        received
+    }
+
+    /// Replay entries delivered to this consumer but never acknowledged.
+    async fn replay_pending(
+        conn: &mut redis::aio::MultiplexedConnection,
+        source_id: &str,
+        config: &RedisStreamsSourceConfig,
+        consumer_name: &str,
+        dispatchers: &Dispatchers,
+    ) -> Result<()> {
+        loop {
+            let reply = Self::read_group(conn, config, consumer_name, "0", None).await?;
+            let received = match reply {
+                Some(reply) => {
+                    Self::process_reply(conn, source_id, config, dispatchers, reply).await
+                }
+                None => 0,
+            };
+            if received == 0 {
+                return Ok(());
+            }
+            info!("[{source_id}] Replayed {received} pending entries");
+        }
+    }
+
+    /// Claim entries left pending by other consumers for longer than `claim_min_idle_ms`.
+    async fn claim_abandoned(
+        conn: &mut redis::aio::MultiplexedConnection,
+        source_id: &str,
+        config: &RedisStreamsSourceConfig,
+        consumer_name: &str,
+        dispatchers: &Dispatchers,
+        stream_key: &str,
+    ) -> Result<()> {
+        let mut cursor = "0-0".to_string();
+        loop {
+            let reply: Vec<redis::Value> = redis::cmd("XAUTOCLAIM")
+                .arg(stream_key)
+                .arg(&config.consumer_group)
+                .arg(consumer_name)
+                .arg(config.claim_min_idle_ms)
+                .arg(&cursor)
+                .arg("COUNT")
+                .arg(config.batch_size)
+                .query_async(conn)
+                .await?;
+
+            let (Some(next_cursor), Some(entries)) = (reply.first(), reply.get(1)) else {
+                return Err(anyhow::anyhow!("Unexpected XAUTOCLAIM reply"));
+            };
+            let next_cursor: String = redis::from_redis_value(next_cursor)?;
+            let entries: StreamRangeReply = redis::from_redis_value(entries)?;
+
+            if !entries.ids.is_empty() {
+                info!(
+                    "[{source_id}] Claimed {} abandoned entries from '{stream_key}'",
+                    entries.ids.len()
+                );
+                let ack_ids = Self::dispatch_entries(
+                    source_id,
+                    config,
+                    dispatchers,
+                    stream_key,
+                    &entries.ids,
+                )
+                .await;
+                Self::ack(conn, stream_key, &config.consumer_group, &ack_ids).await;
+            }
+
+            if next_cursor == "0-0" {
+                return Ok(());
+            }
+            cursor = next_cursor;
+        }
+    }
+
+    /// Connect, set up consumer groups and recover pending entries.
+    async fn connect_and_recover(
+        source_id: &str,
+        config: &RedisStreamsSourceConfig,
+        consumer_name: &str,
+        dispatchers: &Dispatchers,
+    ) -> Result<redis::aio::MultiplexedConnection> {
+        let mut conn = Self::connect_with_retry(&config.redis_url).await?;
+
+        for stream in &config.streams {
+            Self::ensure_consumer_group(
+                &mut conn,
+                stream,
+                &config.consumer_group,
+                &config.start_id,
+            )
+            .await?;
+        }
+
+        Self::replay_pending(&mut conn, source_id, config, consumer_name, dispatchers).await?;
+
+        if config.claim_min_idle_ms > 0 {
+            for stream in &config.streams {
+                if let Err(e) = Self::claim_abandoned(
+                    &mut conn,
+                    source_id,
+                    config,
+                    consumer_name,
+                    dispatchers,
+                    stream,
+                )
+                .await
+                {
+                    // XAUTOCLAIM requires Redis 6.2+; older servers still work without claiming
+                    warn!("[{source_id}] Could not claim abandoned entries on '{stream}': {e}");
+                }
+            }
+        }
+
+        Ok(conn)
+    }
+
+    /// Consumer loop: recover, then read new entries until the task is aborted.
+    async fn run_consumer(
+        source_id: String,
+        config: RedisStreamsSourceConfig,
+        consumer_name: String,
+        dispatchers: Dispatchers,
+        status_handle: ComponentStatusHandle,
+    ) {
+        let mut conn = match Self::connect_and_recover(
+            &source_id,
+            &config,
+            &consumer_name,
+            &dispatchers,
+        )
+        .await
+        {
+            Ok(conn) => conn,
+            Err(e) => {
+                error!("[{source_id}] Failed to start Redis Streams consumer: {e}");
+                status_handle
+                    .set_status(
+                        ComponentStatus::Error,
+                        Some(format!("Failed to start Redis Streams consumer: {e}")),
+                    )
+                    .await;
+                return;
+            }
+        };
+
+        status_handle
+            .set_status(
+                ComponentStatus::Running,
+                Some("Redis Streams source running".to_string()),
+            )
+            .await;
+
+        loop {
+            let result = Self::read_group(
+                &mut conn,
+                &config,
+                &consumer_name,
+                ">",
+                Some(config.block_ms),
+            )
+            .await;
+
+            match result {
+                Ok(Some(reply)) => {
+                    Self::process_reply(&mut conn, &source_id, &config, &dispatchers, reply).await;
+                }
+                Ok(None) => {
+                    // Block timeout with no new entries
+                }
+                Err(e) if e.is_connection_dropped() || e.is_io_error() => {
+                    warn!("[{source_id}] Redis connection lost: {e}");
+                    match Self::connect_and_recover(
+                        &source_id,
+                        &config,
+                        &consumer_name,
+                        &dispatchers,
+                    )
+                    .await
+                    {
+                        Ok(new_conn) => {
+                            conn = new_conn;
+                            info!("[{source_id}] Reconnected to Redis");
+                        }
+                        Err(e) => {
+                            error!("[{source_id}] Failed to reconnect to Redis: {e}");
+                            status_handle
+                                .set_status(
+                                    ComponentStatus::Error,
+                                    Some(format!("Failed to reconnect to Redis: {e}")),
+                                )
+                                .await;
+                            return;
+                        }
+                    }
+                }
+                Err(e) => {
+                    error!("[{source_id}] Error reading from streams: {e}");
+                    tokio::time::sleep(Duration::from_millis(RETRY_DELAY_MS)).await;
+                }
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Source for RedisStreamsSource {
+    fn id(&self) -> &str {
+        &self.base.id
+    }
+
+    fn type_name(&self) -> &str {
+        "redis-streams"
+    }
+
+    fn properties(&self) -> HashMap<String, serde_json::Value> {
+        use crate::descriptor::RedisStreamsSourceConfigDto;
+        use drasi_plugin_sdk::ConfigValue;
+
+        let dto = RedisStreamsSourceConfigDto {
+            redis_url: ConfigValue::Static(self.config.redis_url.clone()),
+            streams: self
+                .config
+                .streams
+                .iter()
+                .map(|s| ConfigValue::Static(s.clone()))
+                .collect(),
+            consumer_group: ConfigValue::Static(self.config.consumer_group.clone()),
+            consumer_name: self
+                .config
+                .consumer_name
+                .as_ref()
+                .map(|n| ConfigValue::Static(n.clone())),
+            start_id: ConfigValue::Static(self.config.start_id.clone()),
+            batch_size: ConfigValue::Static(self.config.batch_size),
+            block_ms: ConfigValue::Static(self.config.block_ms),
+            claim_min_idle_ms: ConfigValue::Static(self.config.claim_min_idle_ms),
+            id_field: self.config.id_field.clone(),
+            operation_field: self.config.operation_field.clone(),
+            label_field: self.config.label_field.clone(),
+            labels: self.config.labels.clone(),
+        };
+
+        match serde_json::to_value(&dto) {
+            Ok(serde_json::Value::Object(map)) => map.into_iter().collect(),
+            _ => HashMap::new(),
+        }
+    }
+
+    fn auto_start(&self) -> bool {
+        self.base.get_auto_start()
+    }
+
+    async fn start(&self) -> Result<()> {
+        log_component_start("Redis Streams Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Starting,
+                Some("Starting Redis Streams source".to_string()),
+            )
+            .await;
+
+        let instance_id = self
+            .base
+            .context()
+            .await
+            .map(|c| c.instance_id)
+            .unwrap_or_default();
+
+        let span = tracing::info_span!(
+            "redis_streams_source_consumer",
+            instance_id = %instance_id,
+            component_id = %self.base.id,
+            component_type = "source"
+        );
+        let task = tokio::spawn(
+            Self::run_consumer(
+                self.base.id.clone(),
+                self.config.clone(),
+                self.consumer_name(),
+                self.base.dispatchers.clone(),
+                self.base.status_handle(),
+            )
+            .instrument(span),
+        );
+
+        *self.base.task_handle.write().await = Some(task);
+
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        log_component_stop("Redis Streams Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopping,
+                Some("Stopping Redis Streams source".to_string()),
+            )
+            .await;
+
+        // Entries read but not yet acknowledged stay pending and are replayed on the next start
+        if let Some(handle) = self.base.task_handle.write().await.take() {
+            handle.abort();
+            let _ = handle.await;
+        }
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopped,
+                Some("Redis Streams source stopped".to_string()),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn status(&self) -> ComponentStatus {
+        self.base.get_status().await
+    }
+
+    async fn subscribe(
+        &self,
+        settings: drasi_lib::config::SourceSubscriptionSettings,
+    ) -> Result<SubscriptionResponse> {
+        self.base
+            .subscribe_with_bootstrap(&settings, "RedisStreams")
+            .await
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    async fn initialize(&self, context: drasi_lib::context::SourceRuntimeContext) {
+        self.base.initialize(context).await;
+    }
+
+    async fn set_bootstrap_provider(
+        &self,
+        provider: Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>,
+    ) {
+        self.base.set_bootstrap_provider(provider).await;
+    }
+}
+
+/// Builder for [`RedisStreamsSource`] instances.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use drasi_source_redis_streams::RedisStreamsSource;
+///
+/// let source = RedisStreamsSource::builder("orders")
+///     .with_redis_url("redis://localhost:6379")
+///     .with_stream("orders")
+///     .with_label("orders", "Order")
+///     .build()?;
+/// ```
+pub struct RedisStreamsSourceBuilder {
+    id: String,
+    config: RedisStreamsSourceConfig,
+    dispatch_mode: Option<DispatchMode>,
+    dispatch_buffer_capacity: Option<usize>,
+    bootstrap_provider: Option<Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>>,
+    auto_start: bool,
+}
+
+impl RedisStreamsSourceBuilder {
+    /// Create a new builder with the given ID and default values.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            config: RedisStreamsSourceConfig::default(),
+            dispatch_mode: None,
+            dispatch_buffer_capacity: None,
+            bootstrap_provider: None,
+            auto_start: true,
+        }
+    }
+
+    /// Set the Redis connection URL.
+    pub fn with_redis_url(mut self, url: impl Into<String>) -> Self {
+        self.config.redis_url = url.into();
+        self
+    }
+
+    /// Set the stream keys to consume.
+    pub fn with_streams(mut self, streams: Vec<String>) -> Self {
+        self.config.streams = streams;
+        self
+    }
+
+    /// Add a stream key to consume.
+    pub fn with_stream(mut self, stream: impl Into<String>) -> Self {
+        self.config.streams.push(stream.into());
+        self
+    }
+
+    /// Set the consumer group name.
+    pub fn with_consumer_group(mut self, group: impl Into<String>) -> Self {
+        self.config.consumer_group = group.into();
+        self
+    }
+
+    /// Set the consumer name.
+    pub fn with_consumer_name(mut self, name: impl Into<String>) -> Self {
+        self.config.consumer_name = Some(name.into());
+        self
+    }
+
+    /// Set the position a newly created consumer group starts from (`"$"` or `"0"`).
+    pub fn with_start_id(mut self, start_id: impl Into<String>) -> Self {
+        self.config.start_id = start_id.into();
+        self
+    }
+
+    /// Set the number of entries read per call.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.config.batch_size = batch_size;
+        self
+    }
+
+    /// Set how long to block waiting for new entries.
+    pub fn with_block_ms(mut self, block_ms: u64) -> Self {
+        self.config.block_ms = block_ms;
+        self
+    }
+
+    /// Set the idle time before other consumers' pending entries are claimed (`0` disables).
+    pub fn with_claim_min_idle_ms(mut self, idle_ms: u64) -> Self {
+        self.config.claim_min_idle_ms = idle_ms;
+        self
+    }
+
+    /// Set the entry field holding the element ID.
+    pub fn with_id_field(mut self, field: impl Into<String>) -> Self {
+        self.config.id_field = field.into();
+        self
+    }
+
+    /// Set the entry field holding the change operation.
+    pub fn with_operation_field(mut self, field: impl Into<String>) -> Self {
+        self.config.operation_field = field.into();
+        self
+    }
+
+    /// Set the entry field holding the node label.
+    pub fn with_label_field(mut self, field: impl Into<String>) -> Self {
+        self.config.label_field = Some(field.into());
+        self
+    }
+
+    /// Map a stream to a node label.
+    pub fn with_label(mut self, stream: impl Into<String>, label: impl Into<String>) -> Self {
+        self.config.labels.insert(stream.into(), label.into());
+        self
+    }
+
+    /// Set the dispatch mode for this source.
+    pub fn with_dispatch_mode(mut self, mode: DispatchMode) -> Self {
+        self.dispatch_mode = Some(mode);
+        self
+    }
+
+    /// Set the dispatch buffer capacity.
+    pub fn with_dispatch_buffer_capacity(mut self, capacity: usize) -> Self {
+        self.dispatch_buffer_capacity = Some(capacity);
+        self
+    }
+
+    /// Set the bootstrap provider for initial data delivery.
+    pub fn with_bootstrap_provider(
+        mut self,
+        provider: impl drasi_lib::bootstrap::BootstrapProvider + 'static,
+    ) -> Self {
+        self.bootstrap_provider = Some(Box::new(provider));
+        self
+    }
+
+    /// Set whether this source should auto-start when DrasiLib starts.
+    pub fn with_auto_start(mut self, auto_start: bool) -> Self {
+        self.auto_start = auto_start;
+        self
+    }
+
+    /// Set the full configuration at once.
+    pub fn with_config(mut self, config: RedisStreamsSourceConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Build the Redis Streams source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid or the base source
+    /// cannot be initialized.
+    pub fn build(self) -> Result<RedisStreamsSource> {
+        self.config.validate()?;
+
+        let mut params = SourceBaseParams::new(&self.id).with_auto_start(self.auto_start);
+        if let Some(mode) = self.dispatch_mode {
+            params = params.with_dispatch_mode(mode);
+        }
+        if let Some(capacity) = self.dispatch_buffer_capacity {
+            params = params.with_dispatch_buffer_capacity(capacity);
+        }
+        if let Some(provider) = self.bootstrap_provider {
+            params = params.with_bootstrap_provider(provider);
+        }
+
+        Ok(RedisStreamsSource {
+            base: SourceBase::new(params)?,
+            config: self.config,
+        })
+    }
+}
@@ -0,0 +1,258 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for the Redis Streams source plugin.
+
+use super::*;
+use crate::conversion::{entry_timestamp_millis, entry_to_source_change, redis_value_to_json};
+use drasi_core::models::{Element, ElementValue, SourceChange};
+use drasi_lib::Source;
+use drasi_plugin_sdk::prelude::SourcePluginDescriptor;
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+fn fields(pairs: &[(&str, &str)]) -> HashMap<String, redis::Value> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), redis::Value::Data(v.as_bytes().to_vec())))
+        .collect()
+}
+
+fn config() -> RedisStreamsSourceConfig {
+    RedisStreamsSourceConfig {
+        redis_url: "redis://localhost:6379".to_string(),
+        streams: vec!["orders".to_string()],
+        ..Default::default()
+    }
+}
+
+mod builder {
+    use super::*;
+
+    #[test]
+    fn test_builder_with_valid_config() {
+        let source = RedisStreamsSource::builder("test-source")
+            .with_redis_url("redis://localhost:6379")
+            .with_stream("orders")
+            .with_stream("customers")
+            .with_label("orders", "Order")
+            .build()
+            .unwrap();
+
+        assert_eq!(source.id(), "test-source");
+        assert_eq!(source.type_name(), "redis-streams");
+        let props = source.properties();
+        assert_eq!(props.get("streams"), Some(&json!(["orders", "customers"])));
+        assert_eq!(props.get("consumerGroup"), Some(&json!("drasi-core")));
+        assert_eq!(props.get("labels"), Some(&json!({"orders": "Order"})));
+    }
+
+    #[test]
+    fn test_builder_requires_streams() {
+        let result = RedisStreamsSource::builder("test-source")
+            .with_redis_url("redis://localhost:6379")
+            .build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_builder_requires_redis_url() {
+        let result = RedisStreamsSource::builder("test-source")
+            .with_stream("orders")
+            .build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_config_rejects_zero_batch_size() {
+        let config = RedisStreamsSourceConfig {
+            batch_size: 0,
+            ..config()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_config_deserialization_defaults() {
+        let config: RedisStreamsSourceConfig = serde_json::from_value(json!({
+            "redis_url": "redis://localhost:6379",
+            "streams": ["orders"]
+        }))
+        .unwrap();
+        assert_eq!(config.consumer_group, "drasi-core");
+        assert_eq!(config.start_id, "$");
+        assert_eq!(config.id_field, "id");
+        assert_eq!(config.operation_field, "op");
+        assert_eq!(config.claim_min_idle_ms, 60000);
+    }
+}
+
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn test_entry_without_operation_is_insert() {
+        let entry = fields(&[("id", "o-1"), ("total", "42.5"), ("status", "open")]);
+        let change =
+            entry_to_source_change("src", "orders", "1700000000000-0", &entry, &config()).unwrap();
+
+        let SourceChange::Insert {
+            element:
+                Element::Node {
+                    metadata,
+                    properties,
+                },
+        } = change
+        else {
+            panic!("expected node insert");
+        };
+        assert_eq!(metadata.reference.element_id.as_ref(), "o-1");
+        assert_eq!(metadata.labels[0].as_ref(), "orders");
+        assert_eq!(metadata.effective_from, 1700000000000);
+        assert_eq!(
+            properties.get("total"),
+            Some(&ElementValue::Float(42.5.into()))
+        );
+        assert_eq!(
+            properties.get("status"),
+            Some(&ElementValue::String(Arc::from("open")))
+        );
+    }
+
+    #[test]
+    fn test_update_and_delete_operations() {
+        let update = fields(&[("id", "o-1"), ("op", "U")]);
+        let change = entry_to_source_change("src", "orders", "1-0", &update, &config()).unwrap();
+        assert!(matches!(change, SourceChange::Update { .. }));
+
+        let delete = fields(&[("id", "o-1"), ("op", "delete")]);
+        let change = entry_to_source_change("src", "orders", "1-0", &delete, &config()).unwrap();
+        assert!(matches!(change, SourceChange::Delete { .. }));
+    }
+
+    #[test]
+    fn test_operation_and_label_fields_are_not_properties() {
+        let config = RedisStreamsSourceConfig {
+            label_field: Some("type".to_string()),
+            ..config()
+        };
+        let entry = fields(&[("id", "1"), ("op", "i"), ("type", "Invoice"), ("n", "1")]);
+        let change = entry_to_source_change("src", "orders", "1-0", &entry, &config).unwrap();
+
+        let SourceChange::Insert {
+            element:
+                Element::Node {
+                    metadata,
+                    properties,
+                },
+        } = change
+        else {
+            panic!("expected node insert");
+        };
+        assert_eq!(metadata.labels[0].as_ref(), "Invoice");
+        assert!(properties.get("op").is_none());
+        assert!(properties.get("type").is_none());
+        assert_eq!(properties.get("n"), Some(&ElementValue::Integer(1)));
+    }
+
+    #[test]
+    fn test_stream_label_mapping() {
+        let mut config = config();
+        config
+            .labels
+            .insert("orders".to_string(), "Order".to_string());
+        let entry = fields(&[("id", "1")]);
+        let change = entry_to_source_change("src", "orders", "1-0", &entry, &config).unwrap();
+        let SourceChange::Insert { element } = change else {
+            panic!("expected insert");
+        };
+        assert_eq!(element.get_metadata().labels[0].as_ref(), "Order");
+    }
+
+    #[test]
+    fn test_missing_id_and_unknown_operation_fail() {
+        let entry = fields(&[("name", "x")]);
+        assert!(entry_to_source_change("src", "orders", "1-0", &entry, &config()).is_err());
+
+        let entry = fields(&[("id", "1"), ("op", "upsert")]);
+        assert!(entry_to_source_change("src", "orders", "1-0", &entry, &config()).is_err());
+    }
+
+    #[test]
+    fn test_redis_value_to_json() {
+        assert_eq!(
+            redis_value_to_json(&redis::Value::Data(b"{\"a\":1}".to_vec())),
+            json!({"a": 1})
+        );
+        assert_eq!(
+            redis_value_to_json(&redis::Value::Data(b"true".to_vec())),
+            json!(true)
+        );
+        assert_eq!(
+            redis_value_to_json(&redis::Value::Data(b"hello".to_vec())),
+            json!("hello")
+        );
+        assert_eq!(redis_value_to_json(&redis::Value::Int(7)), json!(7));
+    }
+
+    #[test]
+    fn test_entry_timestamp_millis() {
+        assert_eq!(entry_timestamp_millis("1700000000123-4"), 1700000000123);
+        assert!(entry_timestamp_millis("not-an-id") > 0);
+    }
+}
+
+mod descriptor {
+    use super::*;
+    use crate::descriptor::RedisStreamsSourceDescriptor;
+
+    #[tokio::test]
+    async fn test_descriptor_creates_source() {
+        let descriptor = RedisStreamsSourceDescriptor;
+        assert_eq!(descriptor.kind(), "redis-streams");
+
+        let source = descriptor
+            .create_source(
+                "redis-1",
+                &json!({
+                    "redisUrl": "redis://localhost:6379",
+                    "streams": ["orders"],
+                    "consumerName": "c1",
+                    "labelField": "type"
+                }),
+                false,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(source.id(), "redis-1");
+        assert!(!source.auto_start());
+        let props = source.properties();
+        assert_eq!(props.get("consumerName"), Some(&json!("c1")));
+        assert_eq!(props.get("labelField"), Some(&json!("type")));
+    }
+
+    #[tokio::test]
+    async fn test_descriptor_rejects_unknown_fields() {
+        let result = RedisStreamsSourceDescriptor
+            .create_source(
+                "redis-1",
+                &json!({"redisUrl": "redis://localhost", "streams": ["s"], "bogus": 1}),
+                true,
+            )
+            .await;
+        assert!(result.is_err());
+    }
+}
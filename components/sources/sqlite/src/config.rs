@@ -0,0 +1,171 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration types for the SQLite source plugin.
+//!
+//! This module defines which database and tables the source polls, and which
+//! columns identify rows and detect changes.
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+fn default_poll_interval_ms() -> u64 {
+    5000
+}
+
+fn default_emit_initial() -> bool {
+    true
+}
+
+/// A table to poll.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SqliteTable {
+    /// Table (or view) name.
+    pub table: String,
+
+    /// Columns that identify a row. Empty means the table's primary key, or
+    /// `rowid` for tables without one. Views must set this.
+    #[serde(default)]
+    pub key_columns: Vec<String>,
+
+    /// Columns compared between polls to detect updates. Empty means all
+    /// columns. Changes to other columns are not reported until a compared
+    /// column changes too.
+    #[serde(default)]
+    pub hash_columns: Vec<String>,
+
+    /// Label for nodes from this table.
+    ///
+    /// **Default**: the table name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+impl SqliteTable {
+    /// Create a table entry that uses the primary key and compares all columns.
+    pub fn new(table: impl Into<String>) -> Self {
+        Self {
+            table: table.into(),
+            key_columns: Vec::new(),
+            hash_columns: Vec::new(),
+            label: None,
+        }
+    }
+
+    /// Label for nodes from this table.
+    pub fn label(&self) -> &str {
+        self.label.as_deref().unwrap_or(&self.table)
+    }
+}
+
+/// SQLite source configuration.
+///
+/// # Example
+///
+/// ```rust
+/// use drasi_source_sqlite::{SqliteSourceConfig, SqliteTable};
+///
+/// let config = SqliteSourceConfig {
+///     path: "/var/lib/gateway/devices.db".to_string(),
+///     tables: vec![SqliteTable::new("devices")],
+///     poll_interval_ms: 2000,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SqliteSourceConfig {
+    /// Path of the database file. The database is opened read-only.
+    pub path: String,
+
+    /// Tables to poll.
+    pub tables: Vec<SqliteTable>,
+
+    /// Milliseconds between polls.
+    ///
+    /// **Default**: `5000`
+    #[serde(default = "default_poll_interval_ms")]
+    pub poll_interval_ms: u64,
+
+    /// Emit every row of the first poll as an insert. When `false`, the first
+    /// poll only records the baseline and later polls report changes to it.
+    ///
+    /// **Default**: `true`
+    #[serde(default = "default_emit_initial")]
+    pub emit_initial: bool,
+}
+
+impl Default for SqliteSourceConfig {
+    fn default() -> Self {
+        Self {
+            path: String::new(),
+            tables: Vec::new(),
+            poll_interval_ms: default_poll_interval_ms(),
+            emit_initial: default_emit_initial(),
+        }
+    }
+}
+
+impl SqliteSourceConfig {
+    /// Validate the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `path` is empty
+    /// - `tables` is empty, or a table is empty or listed twice
+    /// - a key or hash column name is empty
+    /// - `poll_interval_ms` is zero
+    pub fn validate(&self) -> Result<()> {
+        if self.path.is_empty() {
+            return Err(anyhow!("Validation error: path cannot be empty"));
+        }
+
+        if self.tables.is_empty() {
+            return Err(anyhow!("Validation error: at least one table is required"));
+        }
+
+        let mut seen = HashSet::new();
+        for table in &self.tables {
+            if table.table.is_empty() {
+                return Err(anyhow!("Validation error: table name cannot be empty"));
+            }
+            if !seen.insert(table.table.as_str()) {
+                return Err(anyhow!(
+                    "Validation error: table '{}' is listed more than once",
+                    table.table
+                ));
+            }
+            if table
+                .key_columns
+                .iter()
+                .chain(&table.hash_columns)
+                .any(String::is_empty)
+            {
+                return Err(anyhow!(
+                    "Validation error: column names of table '{}' cannot be empty",
+                    table.table
+                ));
+            }
+        }
+
+        if self.poll_interval_ms == 0 {
+            return Err(anyhow!(
+                "Validation error: poll_interval_ms must be greater than 0"
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion of row changes into Drasi source changes.
+
+use drasi_core::models::{Element, ElementMetadata, ElementReference, SourceChange};
+use drasi_lib::sources::convert_json_to_element_properties;
+use std::sync::Arc;
+
+use crate::config::SqliteTable;
+use crate::diff::RowChange;
+use crate::reader::TableRow;
+
+/// Element ID of a row: `<table>:<key>`, matching the database CDC sources.
+pub(crate) fn element_id(table: &str, key: &str) -> String {
+    format!("{table}:{key}")
+}
+
+fn metadata(source_id: &str, table: &SqliteTable, key: &str) -> ElementMetadata {
+    ElementMetadata {
+        reference: ElementReference::new(source_id, &element_id(&table.table, key)),
+        labels: Arc::from(vec![Arc::from(table.label())]),
+        effective_from: chrono::Utc::now().timestamp_millis() as u64,
+    }
+}
+
+fn node(source_id: &str, table: &SqliteTable, row: &TableRow) -> Element {
+    Element::Node {
+        metadata: metadata(source_id, table, &row.key),
+        properties: convert_json_to_element_properties(&row.properties),
+    }
+}
+
+/// Convert a row change of `table` into a [`SourceChange`].
+pub(crate) fn row_change_to_source_change(
+    source_id: &str,
+    table: &SqliteTable,
+    change: &RowChange,
+) -> SourceChange {
+    match change {
+        RowChange::Insert(row) => SourceChange::Insert {
+            element: node(source_id, table, row),
+        },
+        RowChange::Update(row) => SourceChange::Update {
+            element: node(source_id, table, row),
+        },
+        RowChange::Delete(key) => SourceChange::Delete {
+            metadata: metadata(source_id, table, key),
+        },
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! SQLite source plugin descriptor and configuration DTOs.
+
+use crate::{SqliteSourceBuilder, SqliteSourceConfig, SqliteTable};
+use drasi_plugin_sdk::prelude::*;
+use utoipa::OpenApi;
+
+/// Polled table DTO.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::sqlite::SqliteTable)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SqliteTableDto {
+    pub table: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub key_columns: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hash_columns: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+/// SQLite source configuration DTO.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::sqlite::SqliteSourceConfig)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SqliteSourceConfigDto {
+    pub path: ConfigValue<String>,
+    #[schema(value_type = Vec<source::sqlite::SqliteTable>)]
+    pub tables: Vec<SqliteTableDto>,
+    #[serde(default = "default_poll_interval_ms")]
+    pub poll_interval_ms: ConfigValue<u64>,
+    #[serde(default = "default_emit_initial")]
+    pub emit_initial: ConfigValue<bool>,
+}
+
+fn default_poll_interval_ms() -> ConfigValue<u64> {
+    ConfigValue::Static(5000)
+}
+
+fn default_emit_initial() -> ConfigValue<bool> {
+    ConfigValue::Static(true)
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(SqliteSourceConfigDto, SqliteTableDto)))]
+struct SqliteSourceSchemas;
+
+/// Descriptor for the SQLite source plugin.
+pub struct SqliteSourceDescriptor;
+
+#[async_trait]
+impl SourcePluginDescriptor for SqliteSourceDescriptor {
+    fn kind(&self) -> &str {
+        "sqlite"
+    }
+
+    fn config_version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn config_schema_name(&self) -> &str {
+        "source.sqlite.SqliteSourceConfig"
+    }
+
+    fn config_schema_json(&self) -> String {
+        let api = SqliteSourceSchemas::openapi();
+        serde_json::to_string(
+            &api.components
+                .as_ref()
+                .expect("OpenAPI components missing")
+                .schemas,
+        )
+        .expect("Failed to serialize config schema")
+    }
+
+    async fn create_source(
+        &self,
+        id: &str,
+        config_json: &serde_json::Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn drasi_lib::sources::Source>> {
+        let dto: SqliteSourceConfigDto = serde_json::from_value(config_json.clone())?;
+        let mapper = DtoMapper::new();
+
+        let config = SqliteSourceConfig {
+            path: mapper.resolve_string(&dto.path)?,
+            tables: dto
+                .tables
+                .into_iter()
+                .map(|table| SqliteTable {
+                    table: table.table,
+                    key_columns: table.key_columns,
+                    hash_columns: table.hash_columns,
+                    label: table.label,
+                })
+                .collect(),
+            poll_interval_ms: mapper.resolve_typed(&dto.poll_interval_ms)?,
+            emit_initial: mapper.resolve_typed(&dto.emit_initial)?,
+        };
+
+        let source = SqliteSourceBuilder::new(id)
+            .with_config(config)
+            .with_auto_start(auto_start)
+            .build()?;
+
+        Ok(Box::new(source))
+    }
+}
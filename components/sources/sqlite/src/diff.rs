@@ -0,0 +1,61 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Snapshot diffing for polled tables.
+
+use std::collections::HashMap;
+
+use crate::reader::TableRow;
+
+/// Row hashes of a table from the last poll, keyed by row key.
+pub(crate) type Snapshot = HashMap<String, u64>;
+
+/// Difference of one row between two polls.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum RowChange {
+    Insert(TableRow),
+    Update(TableRow),
+    Delete(String),
+}
+
+/// Compare the rows of the current poll against the previous snapshot.
+///
+/// Without a previous snapshot every row is an insert. Inserts and updates
+/// keep the order of `rows`; deletes follow, sorted by key. Returns the
+/// changes and the snapshot to compare the next poll against.
+pub(crate) fn diff(previous: Option<&Snapshot>, rows: Vec<TableRow>) -> (Vec<RowChange>, Snapshot) {
+    let mut snapshot = Snapshot::with_capacity(rows.len());
+    let mut changes = Vec::new();
+
+    for row in rows {
+        snapshot.insert(row.key.clone(), row.hash);
+        match previous.and_then(|p| p.get(&row.key)) {
+            None => changes.push(RowChange::Insert(row)),
+            Some(hash) if *hash != row.hash => changes.push(RowChange::Update(row)),
+            Some(_) => {}
+        }
+    }
+
+    if let Some(previous) = previous {
+        let mut deleted: Vec<String> = previous
+            .keys()
+            .filter(|key| !snapshot.contains_key(*key))
+            .cloned()
+            .collect();
+        deleted.sort_unstable();
+        changes.extend(deleted.into_iter().map(RowChange::Delete));
+    }
+
+    (changes, snapshot)
+}
@@ -0,0 +1,82 @@
+#![allow(unexpected_cfgs)]
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! SQLite Source Plugin for drasi-lib.
+//!
+//! This plugin polls tables of a SQLite database file and turns the rows that
+//! changed since the previous poll into graph changes. It suits embedded and
+//! edge applications that keep their state in SQLite and have no change feed.
+//!
+//! # Change Detection
+//!
+//! Every `poll_interval_ms` the source reads all configured tables within one
+//! read transaction. Each row is identified by its key columns (the primary
+//! key by default, or `rowid` for tables without one) and fingerprinted by a
+//! hash of its compared columns (all columns by default). Against the
+//! snapshot of the previous poll:
+//!
+//! - a new key is an insert
+//! - a key whose hash changed is an update
+//! - a missing key is a delete
+//!
+//! Each row becomes a node with ID `<table>:<key values joined by _>`, the
+//! table name (or the configured label) as label, and every column as a
+//! property. Blobs are hex encoded.
+//!
+//! Snapshots are kept in memory. After a restart the first poll emits every
+//! row as an insert again, unless `emit_initial` is `false`.
+//!
+//! # Usage Example
+//!
+//! ```rust,ignore
+//! use drasi_source_sqlite::{SqliteSource, SqliteTable};
+//!
+//! let source = SqliteSource::builder("gateway-db")
+//!     .with_path("/var/lib/gateway/devices.db")
+//!     .with_table("devices")
+//!     .with_table_config(SqliteTable {
+//!         key_columns: vec!["device_id".to_string(), "channel".to_string()],
+//!         ..SqliteTable::new("readings")
+//!     })
+//!     .with_poll_interval_ms(1000)
+//!     .build()?;
+//!
+//! drasi.add_source(source).await?;
+//! ```
+
+mod config;
+mod conversion;
+pub mod descriptor;
+mod diff;
+mod reader;
+mod sqlite;
+
+#[cfg(test)]
+mod tests;
+
+pub use config::{SqliteSourceConfig, SqliteTable};
+pub use sqlite::{SqliteSource, SqliteSourceBuilder};
+
+/// Dynamic plugin entry point.
+#[cfg(feature = "dynamic-plugin")]
+drasi_plugin_sdk::export_plugin!(
+    plugin_id = "sqlite-source",
+    core_version = env!("CARGO_PKG_VERSION"),
+    lib_version = env!("CARGO_PKG_VERSION"),
+    plugin_version = env!("CARGO_PKG_VERSION"),
+    source_descriptors = [descriptor::SqliteSourceDescriptor],
+    reaction_descriptors = [],
+    bootstrap_descriptors = [],
+);
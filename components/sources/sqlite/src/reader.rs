@@ -0,0 +1,269 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reading table snapshots from SQLite.
+
+use anyhow::{anyhow, Result};
+use log::warn;
+use rusqlite::types::ValueRef;
+use rusqlite::{Connection, OpenFlags};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::path::Path;
+use std::time::Duration;
+
+use crate::config::SqliteTable;
+
+/// Key column used for tables without a primary key.
+pub(crate) const ROWID: &str = "rowid";
+
+/// How long a poll waits for a writer to release its lock.
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// One row of a table snapshot.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TableRow {
+    /// Key column values joined with `_`.
+    pub key: String,
+    /// Hash of the compared columns.
+    pub hash: u64,
+    /// All columns of the row.
+    pub properties: Map<String, Value>,
+}
+
+pub(crate) fn quote_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+/// Convert an SQLite value to JSON. Blobs become lowercase hex strings.
+pub(crate) fn value_to_json(value: ValueRef<'_>) -> Value {
+    match value {
+        ValueRef::Null => Value::Null,
+        ValueRef::Integer(i) => Value::from(i),
+        ValueRef::Real(f) => serde_json::Number::from_f64(f)
+            .map(Value::Number)
+            .unwrap_or(Value::Null),
+        ValueRef::Text(bytes) => Value::String(String::from_utf8_lossy(bytes).into_owned()),
+        ValueRef::Blob(bytes) => Value::String(bytes.iter().map(|b| format!("{b:02x}")).collect()),
+    }
+}
+
+fn key_part(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(s) => Some(s.clone()),
+        other => Some(other.to_string()),
+    }
+}
+
+/// Primary key columns of a table in key order. Empty for tables without one.
+///
+/// # Errors
+///
+/// Returns an error if the table does not exist.
+pub(crate) fn primary_key_columns(conn: &Connection, table: &str) -> Result<Vec<String>> {
+    let mut stmt = conn.prepare(&format!("PRAGMA table_info({})", quote_identifier(table)))?;
+    let mut columns = stmt
+        .query_map([], |row| {
+            Ok((row.get::<_, i64>("pk")?, row.get::<_, String>("name")?))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    if columns.is_empty() {
+        return Err(anyhow!("Table '{table}' does not exist"));
+    }
+
+    columns.retain(|(pk, _)| *pk > 0);
+    columns.sort_unstable();
+    Ok(columns.into_iter().map(|(_, name)| name).collect())
+}
+
+/// Read every row of a table, ordered by its key columns.
+///
+/// A `rowid` key that is not a real column is selected alongside the row but
+/// left out of its properties. Rows whose key columns are all `NULL` are skipped.
+///
+/// # Errors
+///
+/// Returns an error if the query fails or a key or hash column does not exist.
+pub(crate) fn read_table(
+    conn: &Connection,
+    table: &SqliteTable,
+    key_columns: &[String],
+) -> Result<Vec<TableRow>> {
+    let quoted_table = quote_identifier(&table.table);
+    let order_by = key_columns
+        .iter()
+        .map(|c| quote_identifier(c))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let synthetic_rowid = key_columns == [ROWID];
+    let sql = if synthetic_rowid {
+        format!("SELECT rowid AS \"__drasi_rowid\", * FROM {quoted_table} ORDER BY rowid")
+    } else {
+        format!("SELECT * FROM {quoted_table} ORDER BY {order_by}")
+    };
+
+    let mut stmt = conn.prepare(&sql)?;
+    let names: Vec<String> = stmt.column_names().into_iter().map(String::from).collect();
+    let first_column = usize::from(synthetic_rowid);
+
+    let position = |column: &str| {
+        names
+            .iter()
+            .skip(first_column)
+            .position(|name| name == column)
+            .map(|i| i + first_column)
+            .ok_or_else(|| anyhow!("Table '{}' has no column '{column}'", table.table))
+    };
+
+    let key_indexes = if synthetic_rowid {
+        vec![0]
+    } else {
+        key_columns
+            .iter()
+            .map(|c| position(c))
+            .collect::<Result<Vec<_>>>()?
+    };
+    let hash_indexes = if table.hash_columns.is_empty() {
+        (first_column..names.len()).collect()
+    } else {
+        table
+            .hash_columns
+            .iter()
+            .map(|c| position(c))
+            .collect::<Result<Vec<_>>>()?
+    };
+
+    let mut snapshot = Vec::new();
+    let mut skipped = 0usize;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        let values = (0..names.len())
+            .map(|i| row.get_ref(i).map(value_to_json))
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+
+        let key_parts: Vec<String> = key_indexes
+            .iter()
+            .filter_map(|&i| key_part(&values[i]))
+            .collect();
+        if key_parts.is_empty() {
+            skipped += 1;
+            continue;
+        }
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        for &i in &hash_indexes {
+            values[i].to_string().hash(&mut hasher);
+        }
+
+        snapshot.push(TableRow {
+            key: key_parts.join("_"),
+            hash: hasher.finish(),
+            properties: names
+                .iter()
+                .cloned()
+                .zip(values)
+                .skip(first_column)
+                .collect(),
+        });
+    }
+
+    if skipped > 0 {
+        warn!(
+            "Skipped {skipped} row(s) of table '{}' with NULL key columns",
+            table.table
+        );
+    }
+
+    Ok(snapshot)
+}
+
+/// Read-only connection that snapshots the configured tables.
+pub(crate) struct TableReader {
+    conn: Connection,
+    /// Resolved key columns per table, looked up on first read.
+    key_columns: HashMap<String, Vec<String>>,
+}
+
+impl TableReader {
+    /// Open the database read-only.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file does not exist or is not a database.
+    pub(crate) fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let conn = Connection::open_with_flags(
+            path,
+            OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+        )?;
+        Self::with_connection(conn)
+    }
+
+    pub(crate) fn with_connection(conn: Connection) -> Result<Self> {
+        conn.busy_timeout(BUSY_TIMEOUT)?;
+        Ok(Self {
+            conn,
+            key_columns: HashMap::new(),
+        })
+    }
+
+    #[cfg(test)]
+    pub(crate) fn connection(&self) -> &Connection {
+        &self.conn
+    }
+
+    fn key_columns_for(&mut self, table: &SqliteTable) -> Result<Vec<String>> {
+        if !table.key_columns.is_empty() {
+            return Ok(table.key_columns.clone());
+        }
+        if let Some(columns) = self.key_columns.get(&table.table) {
+            return Ok(columns.clone());
+        }
+
+        let mut columns = primary_key_columns(&self.conn, &table.table)?;
+        if columns.is_empty() {
+            columns.push(ROWID.to_string());
+        }
+        self.key_columns
+            .insert(table.table.clone(), columns.clone());
+        Ok(columns)
+    }
+
+    /// Snapshot all tables within one read transaction, so they are consistent
+    /// with each other.
+    pub(crate) fn read_all(&mut self, tables: &[SqliteTable]) -> Vec<Result<Vec<TableRow>>> {
+        let keys: Vec<Result<Vec<String>>> =
+            tables.iter().map(|t| self.key_columns_for(t)).collect();
+
+        let transaction = match self.conn.transaction() {
+            Ok(transaction) => transaction,
+            Err(e) => {
+                let message = format!("Failed to begin read transaction: {e}");
+                return tables
+                    .iter()
+                    .map(|_| Err(anyhow!(message.clone())))
+                    .collect();
+            }
+        };
+
+        tables
+            .iter()
+            .zip(keys)
+            .map(|(table, keys)| read_table(&transaction, table, &keys?))
+            .collect()
+    }
+}
@@ -0,0 +1,474 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! SQLite source implementation and builder.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use log::{debug, error, info, warn};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+use drasi_core::models::SourceChange;
+use drasi_lib::channels::*;
+use drasi_lib::component_graph::ComponentStatusHandle;
+use drasi_lib::managers::{log_component_start, log_component_stop};
+use drasi_lib::sources::base::{SourceBase, SourceBaseParams};
+use drasi_lib::Source;
+use tracing::Instrument;
+
+use crate::config::{SqliteSourceConfig, SqliteTable};
+use crate::conversion::row_change_to_source_change;
+use crate::diff::{diff, RowChange, Snapshot};
+use crate::reader::TableReader;
+
+type Dispatchers = Arc<RwLock<Vec<Box<dyn ChangeDispatcher<SourceEventWrapper> + Send + Sync>>>>;
+
+/// Source that polls SQLite tables and emits the rows that changed.
+///
+/// Each poll reads all configured tables within one read transaction and
+/// compares them against the snapshot of the previous poll. New keys become
+/// inserts, keys whose compared columns changed become updates, and keys that
+/// disappeared become deletes.
+pub struct SqliteSource {
+    /// Base source implementation providing dispatchers, status tracking, and lifecycle management.
+    base: SourceBase,
+    /// SQLite configuration.
+    config: SqliteSourceConfig,
+}
+
+impl SqliteSource {
+    /// Create a builder for a SQLite source.
+    pub fn builder(id: impl Into<String>) -> SqliteSourceBuilder {
+        SqliteSourceBuilder::new(id)
+    }
+
+    /// Create a new SQLite source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid.
+    pub fn new(id: impl Into<String>, config: SqliteSourceConfig) -> Result<Self> {
+        SqliteSourceBuilder::new(id).with_config(config).build()
+    }
+
+    async fn dispatch(
+        source_id: &str,
+        dispatchers: &Dispatchers,
+        change: SourceChange,
+    ) -> Result<()> {
+        let mut profiling = drasi_lib::profiling::ProfilingMetadata::new();
+        profiling.source_send_ns = Some(drasi_lib::profiling::timestamp_ns());
+
+        let wrapper = SourceEventWrapper::with_profiling(
+            source_id.to_string(),
+            SourceEvent::Change(change),
+            chrono::Utc::now(),
+            profiling,
+        );
+
+        SourceBase::dispatch_from_task(dispatchers.clone(), wrapper, source_id).await
+    }
+
+    /// Dispatch the changes of one table since `previous`.
+    ///
+    /// Returns `false` if a dispatch failed, in which case the caller keeps the
+    /// previous snapshot so the remaining changes are found again next poll.
+    async fn emit_table(
+        source_id: &str,
+        dispatchers: &Dispatchers,
+        table: &SqliteTable,
+        changes: &[RowChange],
+    ) -> bool {
+        for change in changes {
+            let source_change = row_change_to_source_change(source_id, table, change);
+            if let Err(e) = Self::dispatch(source_id, dispatchers, source_change).await {
+                warn!(
+                    "[{source_id}] Failed to dispatch change of table '{}': {e}",
+                    table.table
+                );
+                return false;
+            }
+        }
+        true
+    }
+
+    async fn run(
+        source_id: String,
+        config: SqliteSourceConfig,
+        dispatchers: Dispatchers,
+        status_handle: ComponentStatusHandle,
+    ) {
+        let path = config.path.clone();
+        let reader = match tokio::task::spawn_blocking(move || TableReader::open(path)).await {
+            Ok(Ok(reader)) => Arc::new(Mutex::new(reader)),
+            Ok(Err(e)) => {
+                error!("[{source_id}] Failed to open '{}': {e}", config.path);
+                status_handle
+                    .set_status(
+                        ComponentStatus::Error,
+                        Some(format!("Failed to open SQLite database: {e}")),
+                    )
+                    .await;
+                return;
+            }
+            Err(e) => {
+                error!("[{source_id}] SQLite open task failed: {e}");
+                status_handle
+                    .set_status(
+                        ComponentStatus::Error,
+                        Some(format!("SQLite open task failed: {e}")),
+                    )
+                    .await;
+                return;
+            }
+        };
+
+        info!(
+            "[{source_id}] Polling {} table(s) of '{}' every {}ms",
+            config.tables.len(),
+            config.path,
+            config.poll_interval_ms
+        );
+        status_handle
+            .set_status(
+                ComponentStatus::Running,
+                Some("SQLite source running".to_string()),
+            )
+            .await;
+
+        let tables = Arc::new(config.tables.clone());
+        let mut snapshots: Vec<Option<Snapshot>> = vec![None; tables.len()];
+        let poll_interval = Duration::from_millis(config.poll_interval_ms);
+
+        loop {
+            let results = {
+                let reader = reader.clone();
+                let tables = tables.clone();
+                tokio::task::spawn_blocking(move || match reader.lock() {
+                    Ok(mut reader) => reader.read_all(&tables),
+                    Err(poisoned) => poisoned.into_inner().read_all(&tables),
+                })
+                .await
+            };
+
+            match results {
+                Ok(results) => {
+                    for ((table, snapshot), result) in
+                        tables.iter().zip(snapshots.iter_mut()).zip(results)
+                    {
+                        let rows = match result {
+                            Ok(rows) => rows,
+                            Err(e) => {
+                                warn!("[{source_id}] Failed to read table '{}': {e}", table.table);
+                                continue;
+                            }
+                        };
+
+                        let first_poll = snapshot.is_none();
+                        let (changes, next) = diff(snapshot.as_ref(), rows);
+                        if first_poll && !config.emit_initial {
+                            debug!(
+                                "[{source_id}] Recorded baseline of {} row(s) for table '{}'",
+                                next.len(),
+                                table.table
+                            );
+                            *snapshot = Some(next);
+                            continue;
+                        }
+
+                        if !changes.is_empty() {
+                            debug!(
+                                "[{source_id}] {} change(s) in table '{}'",
+                                changes.len(),
+                                table.table
+                            );
+                        }
+                        if Self::emit_table(&source_id, &dispatchers, table, &changes).await {
+                            *snapshot = Some(next);
+                        }
+                    }
+                }
+                Err(e) => warn!("[{source_id}] SQLite poll task failed: {e}"),
+            }
+
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+}
+
+#[async_trait]
+impl Source for SqliteSource {
+    fn id(&self) -> &str {
+        &self.base.id
+    }
+
+    fn type_name(&self) -> &str {
+        "sqlite"
+    }
+
+    fn properties(&self) -> HashMap<String, serde_json::Value> {
+        use crate::descriptor::{SqliteSourceConfigDto, SqliteTableDto};
+        use drasi_plugin_sdk::ConfigValue;
+
+        let dto = SqliteSourceConfigDto {
+            path: ConfigValue::Static(self.config.path.clone()),
+            tables: self
+                .config
+                .tables
+                .iter()
+                .map(|table| SqliteTableDto {
+                    table: table.table.clone(),
+                    key_columns: table.key_columns.clone(),
+                    hash_columns: table.hash_columns.clone(),
+                    label: table.label.clone(),
+                })
+                .collect(),
+            poll_interval_ms: ConfigValue::Static(self.config.poll_interval_ms),
+            emit_initial: ConfigValue::Static(self.config.emit_initial),
+        };
+
+        match serde_json::to_value(&dto) {
+            Ok(serde_json::Value::Object(map)) => map.into_iter().collect(),
+            _ => HashMap::new(),
+        }
+    }
+
+    fn auto_start(&self) -> bool {
+        self.base.get_auto_start()
+    }
+
+    async fn start(&self) -> Result<()> {
+        log_component_start("SQLite Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Starting,
+                Some("Starting SQLite source".to_string()),
+            )
+            .await;
+
+        let instance_id = self
+            .base
+            .context()
+            .await
+            .map(|c| c.instance_id)
+            .unwrap_or_default();
+
+        let span = tracing::info_span!(
+            "sqlite_source_poller",
+            instance_id = %instance_id,
+            component_id = %self.base.id,
+            component_type = "source"
+        );
+        let task = tokio::spawn(
+            Self::run(
+                self.base.id.clone(),
+                self.config.clone(),
+                self.base.dispatchers.clone(),
+                self.base.status_handle(),
+            )
+            .instrument(span),
+        );
+
+        *self.base.task_handle.write().await = Some(task);
+
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        log_component_stop("SQLite Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopping,
+                Some("Stopping SQLite source".to_string()),
+            )
+            .await;
+
+        // Snapshots live in the task, so a restart diffs from scratch
+        if let Some(handle) = self.base.task_handle.write().await.take() {
+            handle.abort();
+            let _ = handle.await;
+        }
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopped,
+                Some("SQLite source stopped".to_string()),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn status(&self) -> ComponentStatus {
+        self.base.get_status().await
+    }
+
+    async fn subscribe(
+        &self,
+        settings: drasi_lib::config::SourceSubscriptionSettings,
+    ) -> Result<SubscriptionResponse> {
+        self.base
+            .subscribe_with_bootstrap(&settings, "SQLite")
+            .await
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    async fn initialize(&self, context: drasi_lib::context::SourceRuntimeContext) {
+        self.base.initialize(context).await;
+    }
+
+    async fn set_bootstrap_provider(
+        &self,
+        provider: Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>,
+    ) {
+        self.base.set_bootstrap_provider(provider).await;
+    }
+}
+
+/// Builder for [`SqliteSource`] instances.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use drasi_source_sqlite::{SqliteSource, SqliteTable};
+///
+/// let source = SqliteSource::builder("gateway-db")
+///     .with_path("/var/lib/gateway/devices.db")
+///     .with_table("devices")
+///     .with_table_config(SqliteTable {
+///         hash_columns: vec!["status".to_string()],
+///         ..SqliteTable::new("alarms")
+///     })
+///     .with_poll_interval_ms(2000)
+///     .build()?;
+/// ```
+pub struct SqliteSourceBuilder {
+    id: String,
+    config: SqliteSourceConfig,
+    dispatch_mode: Option<DispatchMode>,
+    dispatch_buffer_capacity: Option<usize>,
+    bootstrap_provider: Option<Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>>,
+    auto_start: bool,
+}
+
+impl SqliteSourceBuilder {
+    /// Create a new builder with the given ID and default values.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            config: SqliteSourceConfig::default(),
+            dispatch_mode: None,
+            dispatch_buffer_capacity: None,
+            bootstrap_provider: None,
+            auto_start: true,
+        }
+    }
+
+    /// Set the path of the database file.
+    pub fn with_path(mut self, path: impl Into<String>) -> Self {
+        self.config.path = path.into();
+        self
+    }
+
+    /// Poll a table by its primary key, comparing all columns.
+    pub fn with_table(mut self, table: impl Into<String>) -> Self {
+        self.config.tables.push(SqliteTable::new(table));
+        self
+    }
+
+    /// Poll a table with explicit key columns, hash columns or label.
+    pub fn with_table_config(mut self, table: SqliteTable) -> Self {
+        self.config.tables.push(table);
+        self
+    }
+
+    /// Set the interval between polls in milliseconds.
+    pub fn with_poll_interval_ms(mut self, interval_ms: u64) -> Self {
+        self.config.poll_interval_ms = interval_ms;
+        self
+    }
+
+    /// Set whether the rows of the first poll are emitted as inserts.
+    pub fn with_emit_initial(mut self, emit_initial: bool) -> Self {
+        self.config.emit_initial = emit_initial;
+        self
+    }
+
+    /// Set the dispatch mode for this source.
+    pub fn with_dispatch_mode(mut self, mode: DispatchMode) -> Self {
+        self.dispatch_mode = Some(mode);
+        self
+    }
+
+    /// Set the dispatch buffer capacity.
+    pub fn with_dispatch_buffer_capacity(mut self, capacity: usize) -> Self {
+        self.dispatch_buffer_capacity = Some(capacity);
+        self
+    }
+
+    /// Set the bootstrap provider for initial data delivery.
+    pub fn with_bootstrap_provider(
+        mut self,
+        provider: impl drasi_lib::bootstrap::BootstrapProvider + 'static,
+    ) -> Self {
+        self.bootstrap_provider = Some(Box::new(provider));
+        self
+    }
+
+    /// Set whether this source should auto-start when DrasiLib starts.
+    pub fn with_auto_start(mut self, auto_start: bool) -> Self {
+        self.auto_start = auto_start;
+        self
+    }
+
+    /// Set the full configuration at once.
+    pub fn with_config(mut self, config: SqliteSourceConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Build the SQLite source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid or the base source
+    /// cannot be initialized.
+    pub fn build(self) -> Result<SqliteSource> {
+        self.config.validate()?;
+
+        let mut params = SourceBaseParams::new(&self.id).with_auto_start(self.auto_start);
+        if let Some(mode) = self.dispatch_mode {
+            params = params.with_dispatch_mode(mode);
+        }
+        if let Some(capacity) = self.dispatch_buffer_capacity {
+            params = params.with_dispatch_buffer_capacity(capacity);
+        }
+        if let Some(provider) = self.bootstrap_provider {
+            params = params.with_bootstrap_provider(provider);
+        }
+
+        Ok(SqliteSource {
+            base: SourceBase::new(params)?,
+            config: self.config,
+        })
+    }
+}
@@ -0,0 +1,351 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for the SQLite source plugin.
+
+use super::*;
+use crate::conversion::row_change_to_source_change;
+use crate::diff::{diff, RowChange};
+use crate::reader::{TableReader, TableRow};
+use drasi_core::models::{Element, ElementValue, SourceChange};
+use drasi_lib::Source;
+use drasi_plugin_sdk::prelude::SourcePluginDescriptor;
+use rusqlite::Connection;
+use serde_json::json;
+
+fn config() -> SqliteSourceConfig {
+    SqliteSourceConfig {
+        path: "/tmp/devices.db".to_string(),
+        tables: vec![SqliteTable::new("devices")],
+        ..Default::default()
+    }
+}
+
+fn reader(sql: &str) -> TableReader {
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute_batch(sql).unwrap();
+    TableReader::with_connection(conn).unwrap()
+}
+
+fn row(key: &str, hash: u64) -> TableRow {
+    TableRow {
+        key: key.to_string(),
+        hash,
+        properties: json!({"id": key}).as_object().unwrap().clone(),
+    }
+}
+
+mod builder {
+    use super::*;
+
+    #[test]
+    fn test_builder_with_valid_config() {
+        let source = SqliteSource::builder("test-source")
+            .with_path("/tmp/devices.db")
+            .with_table("devices")
+            .with_table_config(SqliteTable {
+                hash_columns: vec!["status".to_string()],
+                label: Some("Alarm".to_string()),
+                ..SqliteTable::new("alarms")
+            })
+            .with_poll_interval_ms(1000)
+            .with_emit_initial(false)
+            .build()
+            .unwrap();
+
+        assert_eq!(source.id(), "test-source");
+        assert_eq!(source.type_name(), "sqlite");
+        let props = source.properties();
+        assert_eq!(props.get("path"), Some(&json!("/tmp/devices.db")));
+        assert_eq!(props.get("pollIntervalMs"), Some(&json!(1000)));
+        assert_eq!(props.get("emitInitial"), Some(&json!(false)));
+        assert_eq!(
+            props.get("tables"),
+            Some(&json!([
+                {"table": "devices"},
+                {"table": "alarms", "hashColumns": ["status"], "label": "Alarm"}
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_builder_requires_path_and_tables() {
+        assert!(SqliteSource::builder("test-source")
+            .with_table("devices")
+            .build()
+            .is_err());
+        assert!(SqliteSource::builder("test-source")
+            .with_path("/tmp/devices.db")
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_config_rejects_invalid_values() {
+        let duplicate = SqliteSourceConfig {
+            tables: vec![SqliteTable::new("devices"), SqliteTable::new("devices")],
+            ..config()
+        };
+        assert!(duplicate.validate().is_err());
+
+        let empty_column = SqliteSourceConfig {
+            tables: vec![SqliteTable {
+                key_columns: vec![String::new()],
+                ..SqliteTable::new("devices")
+            }],
+            ..config()
+        };
+        assert!(empty_column.validate().is_err());
+
+        let zero_interval = SqliteSourceConfig {
+            poll_interval_ms: 0,
+            ..config()
+        };
+        assert!(zero_interval.validate().is_err());
+    }
+
+    #[test]
+    fn test_config_deserialization_defaults() {
+        let config: SqliteSourceConfig = serde_json::from_value(json!({
+            "path": "/tmp/devices.db",
+            "tables": [{"table": "devices"}]
+        }))
+        .unwrap();
+        assert_eq!(config.poll_interval_ms, 5000);
+        assert!(config.emit_initial);
+        assert!(config.tables[0].key_columns.is_empty());
+        assert_eq!(config.tables[0].label(), "devices");
+    }
+}
+
+mod reader {
+    use super::*;
+
+    #[test]
+    fn test_reads_rows_by_primary_key() {
+        let mut reader = reader(
+            "CREATE TABLE readings (device TEXT, channel INTEGER, value REAL, raw BLOB,
+                PRIMARY KEY (device, channel));
+             INSERT INTO readings VALUES ('d1', 2, 1.5, x'0aff');
+             INSERT INTO readings VALUES ('d1', 1, NULL, NULL);",
+        );
+
+        let rows = reader
+            .read_all(&[SqliteTable::new("readings")])
+            .pop()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            rows.iter().map(|r| r.key.as_str()).collect::<Vec<_>>(),
+            vec!["d1_1", "d1_2"]
+        );
+        assert_eq!(
+            serde_json::Value::Object(rows[1].properties.clone()),
+            json!({"device": "d1", "channel": 2, "value": 1.5, "raw": "0aff"})
+        );
+    }
+
+    #[test]
+    fn test_falls_back_to_rowid() {
+        let mut reader = reader(
+            "CREATE TABLE events (name TEXT);
+             INSERT INTO events VALUES ('start');
+             INSERT INTO events VALUES ('stop');",
+        );
+
+        let rows = reader
+            .read_all(&[SqliteTable::new("events")])
+            .pop()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(rows[0].key, "1");
+        assert_eq!(rows[1].key, "2");
+        // The synthetic rowid column is not a property
+        assert_eq!(
+            serde_json::Value::Object(rows[1].properties.clone()),
+            json!({"name": "stop"})
+        );
+    }
+
+    #[test]
+    fn test_hash_columns_limit_change_detection() {
+        let mut reader = reader(
+            "CREATE TABLE devices (id TEXT PRIMARY KEY, status TEXT, last_seen INTEGER);
+             INSERT INTO devices VALUES ('d1', 'online', 1);",
+        );
+        let tables = [SqliteTable {
+            hash_columns: vec!["status".to_string()],
+            ..SqliteTable::new("devices")
+        }];
+
+        let before = reader.read_all(&tables).pop().unwrap().unwrap();
+        reader
+            .connection()
+            .execute("UPDATE devices SET last_seen = 2", [])
+            .unwrap();
+        let touched = reader.read_all(&tables).pop().unwrap().unwrap();
+        reader
+            .connection()
+            .execute("UPDATE devices SET status = 'offline'", [])
+            .unwrap();
+        let changed = reader.read_all(&tables).pop().unwrap().unwrap();
+
+        assert_eq!(before[0].hash, touched[0].hash);
+        assert_ne!(before[0].hash, changed[0].hash);
+    }
+
+    #[test]
+    fn test_errors_are_per_table() {
+        let mut reader = reader("CREATE TABLE devices (id TEXT PRIMARY KEY);");
+
+        let results = reader.read_all(&[
+            SqliteTable::new("missing"),
+            SqliteTable {
+                key_columns: vec!["nope".to_string()],
+                ..SqliteTable::new("devices")
+            },
+            SqliteTable::new("devices"),
+        ]);
+
+        assert!(results[0].is_err());
+        assert!(results[1].is_err());
+        assert!(results[2].as_ref().unwrap().is_empty());
+    }
+}
+
+mod diff {
+    use super::*;
+
+    #[test]
+    fn test_first_poll_inserts_everything() {
+        let (changes, snapshot) = diff(None, vec![row("a", 1), row("b", 2)]);
+        assert_eq!(
+            changes,
+            vec![
+                RowChange::Insert(row("a", 1)),
+                RowChange::Insert(row("b", 2))
+            ]
+        );
+        assert_eq!(snapshot.len(), 2);
+    }
+
+    #[test]
+    fn test_detects_inserts_updates_and_deletes() {
+        let (_, previous) = diff(None, vec![row("a", 1), row("b", 2), row("c", 3)]);
+        let (changes, snapshot) = diff(
+            Some(&previous),
+            vec![row("a", 1), row("b", 20), row("d", 4)],
+        );
+
+        assert_eq!(
+            changes,
+            vec![
+                RowChange::Update(row("b", 20)),
+                RowChange::Insert(row("d", 4)),
+                RowChange::Delete("c".to_string()),
+            ]
+        );
+        assert_eq!(snapshot.get("b"), Some(&20));
+        assert!(!snapshot.contains_key("c"));
+    }
+}
+
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn test_insert_and_delete() {
+        let table = SqliteTable {
+            label: Some("Device".to_string()),
+            ..SqliteTable::new("devices")
+        };
+
+        match row_change_to_source_change("src", &table, &RowChange::Insert(row("d1", 1))) {
+            SourceChange::Insert {
+                element:
+                    Element::Node {
+                        metadata,
+                        properties,
+                    },
+            } => {
+                assert_eq!(metadata.reference.element_id.as_ref(), "devices:d1");
+                assert_eq!(metadata.labels[0].as_ref(), "Device");
+                assert_eq!(
+                    properties.get("id"),
+                    Some(&ElementValue::String("d1".into()))
+                );
+            }
+            other => panic!("unexpected change: {other:?}"),
+        }
+
+        match row_change_to_source_change(
+            "src",
+            &SqliteTable::new("devices"),
+            &RowChange::Delete("d1".to_string()),
+        ) {
+            SourceChange::Delete { metadata } => {
+                assert_eq!(metadata.reference.element_id.as_ref(), "devices:d1");
+                assert_eq!(metadata.labels[0].as_ref(), "devices");
+            }
+            other => panic!("unexpected change: {other:?}"),
+        }
+    }
+}
+
+mod descriptor {
+    use super::*;
+    use crate::descriptor::SqliteSourceDescriptor;
+
+    #[tokio::test]
+    async fn test_descriptor_creates_source() {
+        let descriptor = SqliteSourceDescriptor;
+        assert_eq!(descriptor.kind(), "sqlite");
+
+        let source = descriptor
+            .create_source(
+                "sqlite-1",
+                &json!({
+                    "path": "/tmp/devices.db",
+                    "tables": [{"table": "readings", "keyColumns": ["device", "channel"]}],
+                    "pollIntervalMs": 1000
+                }),
+                false,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(source.id(), "sqlite-1");
+        assert!(!source.auto_start());
+        let props = source.properties();
+        assert_eq!(props.get("pollIntervalMs"), Some(&json!(1000)));
+        assert_eq!(
+            props.get("tables"),
+            Some(&json!([{"table": "readings", "keyColumns": ["device", "channel"]}]))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_descriptor_rejects_unknown_fields() {
+        let result = SqliteSourceDescriptor
+            .create_source(
+                "sqlite-1",
+                &json!({"path": "/tmp/devices.db", "tables": [{"table": "t", "bogus": 1}]}),
+                true,
+            )
+            .await;
+        assert!(result.is_err());
+    }
+}
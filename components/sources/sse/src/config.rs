@@ -0,0 +1,209 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration types for the SSE source plugin.
+//!
+//! This module defines the event stream to connect to, how reconnection is
+//! paced, and how events map onto graph changes.
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+fn default_event_operations() -> HashMap<String, SseOperation> {
+    HashMap::from([
+        ("message".to_string(), SseOperation::Upsert),
+        ("insert".to_string(), SseOperation::Insert),
+        ("create".to_string(), SseOperation::Insert),
+        ("update".to_string(), SseOperation::Update),
+        ("upsert".to_string(), SseOperation::Upsert),
+        ("delete".to_string(), SseOperation::Delete),
+    ])
+}
+
+fn default_id_field() -> String {
+    "id".to_string()
+}
+
+fn default_reconnect_delay_ms() -> u64 {
+    1000
+}
+
+fn default_max_reconnect_delay_ms() -> u64 {
+    30000
+}
+
+fn default_resume() -> bool {
+    true
+}
+
+/// Change produced by an event type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SseOperation {
+    Insert,
+    Update,
+    /// Insert the first time an element ID is seen, update afterwards.
+    Upsert,
+    Delete,
+}
+
+/// SSE source configuration.
+///
+/// # Example
+///
+/// ```rust
+/// use drasi_source_sse::SseSourceConfig;
+///
+/// let config = SseSourceConfig {
+///     url: "http://couchdb:5984/orders/_changes?feed=eventsource&include_docs=true".to_string(),
+///     properties_field: Some("doc".to_string()),
+///     deleted_field: Some("deleted".to_string()),
+///     label: Some("Order".to_string()),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SseSourceConfig {
+    /// URL of the event stream.
+    pub url: String,
+
+    /// Headers sent with every request, for example `Authorization`.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub headers: HashMap<String, String>,
+
+    /// Operation of each event type. Events of other types are ignored.
+    ///
+    /// **Default**: `message` and `upsert` → upsert, `insert` and `create` →
+    /// insert, `update` → update, `delete` → delete
+    #[serde(default = "default_event_operations")]
+    pub event_operations: HashMap<String, SseOperation>,
+
+    /// Payload field holding the element ID.
+    ///
+    /// **Default**: `"id"`
+    #[serde(default = "default_id_field")]
+    pub id_field: String,
+
+    /// Payload field holding the node properties. When unset, all payload
+    /// fields except the label field become properties.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties_field: Option<String>,
+
+    /// Payload field that turns an event into a delete when it is `true`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_field: Option<String>,
+
+    /// Payload field holding the node label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_field: Option<String>,
+
+    /// Label for all nodes, used when `label_field` is unset or missing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+
+    /// Milliseconds to wait before the first reconnection attempt. A `retry`
+    /// field sent by the server replaces it.
+    ///
+    /// **Default**: `1000`
+    #[serde(default = "default_reconnect_delay_ms")]
+    pub reconnect_delay_ms: u64,
+
+    /// Upper bound of the reconnection delay, which doubles after every
+    /// failed attempt.
+    ///
+    /// **Default**: `30000`
+    #[serde(default = "default_max_reconnect_delay_ms")]
+    pub max_reconnect_delay_ms: u64,
+
+    /// Reconnect when nothing, not even a comment, is received for this many
+    /// milliseconds. Disabled when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idle_timeout_ms: Option<u64>,
+
+    /// Save the last event ID as the source's checkpoint and resume from it after
+    /// a restart. Reconnections always resume.
+    ///
+    /// **Default**: `true`
+    #[serde(default = "default_resume")]
+    pub resume: bool,
+}
+
+impl Default for SseSourceConfig {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            headers: HashMap::new(),
+            event_operations: default_event_operations(),
+            id_field: default_id_field(),
+            properties_field: None,
+            deleted_field: None,
+            label_field: None,
+            label: None,
+            reconnect_delay_ms: default_reconnect_delay_ms(),
+            max_reconnect_delay_ms: default_max_reconnect_delay_ms(),
+            idle_timeout_ms: None,
+            resume: default_resume(),
+        }
+    }
+}
+
+impl SseSourceConfig {
+    /// Validate the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `url` is not an `http://` or `https://` URL
+    /// - a header name is empty
+    /// - `event_operations` is empty
+    /// - `id_field` is empty
+    /// - `reconnect_delay_ms` is zero or greater than `max_reconnect_delay_ms`
+    /// - `idle_timeout_ms` is zero
+    pub fn validate(&self) -> Result<()> {
+        if !self.url.starts_with("http://") && !self.url.starts_with("https://") {
+            return Err(anyhow!(
+                "Validation error: url must start with http:// or https://"
+            ));
+        }
+
+        if self.headers.keys().any(String::is_empty) {
+            return Err(anyhow!("Validation error: header names cannot be empty"));
+        }
+
+        if self.event_operations.is_empty() {
+            return Err(anyhow!(
+                "Validation error: event_operations must map at least one event type"
+            ));
+        }
+
+        if self.id_field.is_empty() {
+            return Err(anyhow!("Validation error: id_field cannot be empty"));
+        }
+
+        if self.reconnect_delay_ms == 0 || self.reconnect_delay_ms > self.max_reconnect_delay_ms {
+            return Err(anyhow!(
+                "Validation error: reconnect_delay_ms must be greater than 0 and at most max_reconnect_delay_ms"
+            ));
+        }
+
+        if self.idle_timeout_ms == Some(0) {
+            return Err(anyhow!(
+                "Validation error: idle_timeout_ms must be greater than 0"
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion of SSE events into Drasi source changes.
+
+use anyhow::{anyhow, Result};
+use drasi_core::models::{Element, ElementMetadata, ElementReference, SourceChange};
+use drasi_lib::sources::convert_json_to_element_properties;
+use serde_json::{Map, Value};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::config::{SseOperation, SseSourceConfig};
+use crate::parser::SseEvent;
+
+/// Label used when neither `label_field` nor `label` provides one.
+const FALLBACK_LABEL: &str = "SseEvent";
+
+fn field_as_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// Converts events into changes and tracks which element IDs exist, so
+/// upserts can be told apart.
+#[derive(Debug)]
+pub(crate) struct ChangeMapper {
+    config: SseSourceConfig,
+    known: HashSet<String>,
+}
+
+impl ChangeMapper {
+    pub(crate) fn new(config: SseSourceConfig) -> Self {
+        Self {
+            config,
+            known: HashSet::new(),
+        }
+    }
+
+    /// Convert an event into a change, or `None` if its type is not mapped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data is not a JSON object, has no ID, or its
+    /// properties field is not an object.
+    pub(crate) fn convert(
+        &self,
+        source_id: &str,
+        event: &SseEvent,
+    ) -> Result<Option<SourceChange>> {
+        let Some(&operation) = self.config.event_operations.get(&event.event_type) else {
+            return Ok(None);
+        };
+
+        let mut object = match serde_json::from_str::<Value>(&event.data) {
+            Ok(Value::Object(object)) => object,
+            Ok(_) => return Err(anyhow!("Event data is not a JSON object")),
+            Err(e) => return Err(anyhow!("Event data is not valid JSON: {e}")),
+        };
+
+        let element_id = object
+            .get(&self.config.id_field)
+            .and_then(field_as_string)
+            .filter(|id| !id.is_empty())
+            .ok_or_else(|| anyhow!("Event has no '{}' field", self.config.id_field))?;
+
+        let deleted = self
+            .config
+            .deleted_field
+            .as_ref()
+            .and_then(|field| object.get(field))
+            .is_some_and(|value| value == &Value::Bool(true));
+
+        let label = self
+            .config
+            .label_field
+            .as_ref()
+            .and_then(|field| object.remove(field))
+            .and_then(|v| field_as_string(&v))
+            .or_else(|| self.config.label.clone())
+            .unwrap_or_else(|| FALLBACK_LABEL.to_string());
+
+        let metadata = ElementMetadata {
+            reference: ElementReference::new(source_id, &element_id),
+            labels: Arc::from(vec![Arc::from(label.as_str())]),
+            effective_from: chrono::Utc::now().timestamp_millis() as u64,
+        };
+
+        if deleted || operation == SseOperation::Delete {
+            return Ok(Some(SourceChange::Delete { metadata }));
+        }
+
+        let properties = match &self.config.properties_field {
+            Some(field) => match object.remove(field) {
+                Some(Value::Object(properties)) => properties,
+                Some(_) => return Err(anyhow!("Event field '{field}' is not a JSON object")),
+                None => Map::new(),
+            },
+            None => object,
+        };
+
+        let element = Element::Node {
+            metadata,
+            properties: convert_json_to_element_properties(&properties),
+        };
+
+        let change = match operation {
+            SseOperation::Update => SourceChange::Update { element },
+            SseOperation::Upsert if self.known.contains(&element_id) => {
+                SourceChange::Update { element }
+            }
+            _ => SourceChange::Insert { element },
+        };
+        Ok(Some(change))
+    }
+
+    /// Record a dispatched change, so later upserts of the element update it.
+    pub(crate) fn record(&mut self, change: &SourceChange) {
+        match change {
+            SourceChange::Insert { element } | SourceChange::Update { element } => {
+                self.known
+                    .insert(element.get_reference().element_id.to_string());
+            }
+            SourceChange::Delete { metadata } => {
+                self.known.remove(metadata.reference.element_id.as_ref());
+            }
+            _ => {}
+        }
+    }
+}
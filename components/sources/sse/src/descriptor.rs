@@ -0,0 +1,172 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! SSE source plugin descriptor and configuration DTOs.
+
+use crate::{SseOperation, SseSourceBuilder, SseSourceConfig};
+use drasi_plugin_sdk::prelude::*;
+use std::collections::HashMap;
+use utoipa::OpenApi;
+
+/// Event operation DTO.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, utoipa::ToSchema)]
+#[schema(as = source::sse::SseOperation)]
+#[serde(rename_all = "snake_case")]
+pub enum SseOperationDto {
+    Insert,
+    Update,
+    Upsert,
+    Delete,
+}
+
+/// SSE source configuration DTO.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::sse::SseSourceConfig)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SseSourceConfigDto {
+    pub url: ConfigValue<String>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub headers: HashMap<String, ConfigValue<String>>,
+    #[serde(default = "default_event_operations")]
+    #[schema(value_type = HashMap<String, source::sse::SseOperation>)]
+    pub event_operations: HashMap<String, SseOperationDto>,
+    #[serde(default = "default_id_field")]
+    pub id_field: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties_field: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_field: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_field: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(default = "default_reconnect_delay_ms")]
+    pub reconnect_delay_ms: ConfigValue<u64>,
+    #[serde(default = "default_max_reconnect_delay_ms")]
+    pub max_reconnect_delay_ms: ConfigValue<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idle_timeout_ms: Option<ConfigValue<u64>>,
+    #[serde(default = "default_resume")]
+    pub resume: bool,
+}
+
+fn default_event_operations() -> HashMap<String, SseOperationDto> {
+    HashMap::from([
+        ("message".to_string(), SseOperationDto::Upsert),
+        ("insert".to_string(), SseOperationDto::Insert),
+        ("create".to_string(), SseOperationDto::Insert),
+        ("update".to_string(), SseOperationDto::Update),
+        ("upsert".to_string(), SseOperationDto::Upsert),
+        ("delete".to_string(), SseOperationDto::Delete),
+    ])
+}
+
+fn default_id_field() -> String {
+    "id".to_string()
+}
+
+fn default_reconnect_delay_ms() -> ConfigValue<u64> {
+    ConfigValue::Static(1000)
+}
+
+fn default_max_reconnect_delay_ms() -> ConfigValue<u64> {
+    ConfigValue::Static(30000)
+}
+
+fn default_resume() -> bool {
+    true
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(SseSourceConfigDto, SseOperationDto)))]
+struct SseSourceSchemas;
+
+/// Descriptor for the SSE source plugin.
+pub struct SseSourceDescriptor;
+
+#[async_trait]
+impl SourcePluginDescriptor for SseSourceDescriptor {
+    fn kind(&self) -> &str {
+        "sse"
+    }
+
+    fn config_version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn config_schema_name(&self) -> &str {
+        "source.sse.SseSourceConfig"
+    }
+
+    fn config_schema_json(&self) -> String {
+        let api = SseSourceSchemas::openapi();
+        serde_json::to_string(
+            &api.components
+                .as_ref()
+                .expect("OpenAPI components missing")
+                .schemas,
+        )
+        .expect("Failed to serialize config schema")
+    }
+
+    async fn create_source(
+        &self,
+        id: &str,
+        config_json: &serde_json::Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn drasi_lib::sources::Source>> {
+        let dto: SseSourceConfigDto = serde_json::from_value(config_json.clone())?;
+        let mapper = DtoMapper::new();
+
+        let headers = dto
+            .headers
+            .iter()
+            .map(|(name, value)| Ok((name.clone(), mapper.resolve_string(value)?)))
+            .collect::<anyhow::Result<HashMap<_, _>>>()?;
+
+        let config = SseSourceConfig {
+            url: mapper.resolve_string(&dto.url)?,
+            headers,
+            event_operations: dto
+                .event_operations
+                .into_iter()
+                .map(|(event_type, operation)| {
+                    let operation = match operation {
+                        SseOperationDto::Insert => SseOperation::Insert,
+                        SseOperationDto::Update => SseOperation::Update,
+                        SseOperationDto::Upsert => SseOperation::Upsert,
+                        SseOperationDto::Delete => SseOperation::Delete,
+                    };
+                    (event_type, operation)
+                })
+                .collect(),
+            id_field: dto.id_field,
+            properties_field: dto.properties_field,
+            deleted_field: dto.deleted_field,
+            label_field: dto.label_field,
+            label: dto.label,
+            reconnect_delay_ms: mapper.resolve_typed(&dto.reconnect_delay_ms)?,
+            max_reconnect_delay_ms: mapper.resolve_typed(&dto.max_reconnect_delay_ms)?,
+            idle_timeout_ms: mapper.resolve_optional(&dto.idle_timeout_ms)?,
+            resume: dto.resume,
+        };
+
+        let source = SseSourceBuilder::new(id)
+            .with_config(config)
+            .with_auto_start(auto_start)
+            .build()?;
+
+        Ok(Box::new(source))
+    }
+}
@@ -0,0 +1,161 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Incremental parser for `text/event-stream` bodies.
+//!
+//! Follows the WHATWG event stream interpretation: lines end with CRLF, LF or
+//! CR, blank lines dispatch the buffered event, lines starting with `:` are
+//! comments, and the `event`, `data`, `id` and `retry` fields are recognised.
+
+/// A dispatched event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SseEvent {
+    /// Event type; `message` when the event sets none.
+    pub event_type: String,
+    /// Data lines joined with `\n`.
+    pub data: String,
+    /// Last event ID in effect when the event was dispatched.
+    pub id: Option<String>,
+}
+
+/// Splits received bytes into events.
+#[derive(Debug, Default)]
+pub(crate) struct SseParser {
+    buffer: Vec<u8>,
+    /// The previous chunk ended in CR, so a leading LF belongs to it.
+    skip_lf: bool,
+    started: bool,
+    event_type: String,
+    data: String,
+    last_event_id: Option<String>,
+    retry: Option<u64>,
+}
+
+impl SseParser {
+    /// Create a parser that starts from a known last event ID.
+    pub(crate) fn new(last_event_id: Option<String>) -> Self {
+        Self {
+            last_event_id,
+            ..Default::default()
+        }
+    }
+
+    /// Parse received bytes and return the events they complete.
+    pub(crate) fn push(&mut self, bytes: &[u8]) -> Vec<SseEvent> {
+        let mut bytes = bytes;
+        if self.skip_lf {
+            if let Some(rest) = bytes.strip_prefix(b"\n") {
+                bytes = rest;
+            }
+            self.skip_lf = false;
+        }
+        self.buffer.extend_from_slice(bytes);
+
+        let mut events = Vec::new();
+        let mut start = 0;
+        let mut index = 0;
+        while index < self.buffer.len() {
+            let end = index;
+            match self.buffer[index] {
+                b'\n' => index += 1,
+                b'\r' if self.buffer.get(index + 1) == Some(&b'\n') => index += 2,
+                b'\r' if index + 1 == self.buffer.len() => {
+                    self.skip_lf = true;
+                    index += 1;
+                }
+                b'\r' => index += 1,
+                _ => {
+                    index += 1;
+                    continue;
+                }
+            }
+
+            let line = String::from_utf8_lossy(&self.buffer[start..end]).into_owned();
+            if let Some(event) = self.process_line(&line) {
+                events.push(event);
+            }
+            start = index;
+        }
+        self.buffer.drain(..start);
+
+        events
+    }
+
+    /// Last event ID set by the stream, sent as `Last-Event-ID` on reconnect.
+    pub(crate) fn last_event_id(&self) -> Option<&str> {
+        self.last_event_id.as_deref()
+    }
+
+    /// Take the reconnection time last sent by the server, in milliseconds.
+    pub(crate) fn take_retry(&mut self) -> Option<u64> {
+        self.retry.take()
+    }
+
+    fn process_line(&mut self, line: &str) -> Option<SseEvent> {
+        let line = if self.started {
+            line
+        } else {
+            self.started = true;
+            line.strip_prefix('\u{feff}').unwrap_or(line)
+        };
+
+        if line.is_empty() {
+            return self.dispatch();
+        }
+        if line.starts_with(':') {
+            return None;
+        }
+
+        let (field, value) = match line.split_once(':') {
+            Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+            None => (line, ""),
+        };
+
+        match field {
+            "event" => self.event_type = value.to_string(),
+            "data" => {
+                self.data.push_str(value);
+                self.data.push('\n');
+            }
+            "id" if !value.contains('\0') => {
+                self.last_event_id = (!value.is_empty()).then(|| value.to_string());
+            }
+            "retry" if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) => {
+                self.retry = value.parse().ok();
+            }
+            _ => {}
+        }
+
+        None
+    }
+
+    fn dispatch(&mut self) -> Option<SseEvent> {
+        let event_type = std::mem::take(&mut self.event_type);
+        let mut data = std::mem::take(&mut self.data);
+        if data.is_empty() {
+            return None;
+        }
+        data.pop();
+
+        Some(SseEvent {
+            event_type: if event_type.is_empty() {
+                "message".to_string()
+            } else {
+                event_type
+            },
+            data,
+            id: self.last_event_id.clone(),
+        })
+    }
+}
@@ -0,0 +1,688 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! SSE source implementation and builder.
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use log::{debug, error, info, warn};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, ACCEPT, CACHE_CONTROL, CONTENT_TYPE};
+use reqwest::StatusCode;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+use drasi_core::models::SourceChange;
+use drasi_lib::channels::*;
+use drasi_lib::component_graph::ComponentStatusHandle;
+use drasi_lib::managers::{log_component_start, log_component_stop};
+use drasi_lib::sources::base::{SourceBase, SourceBaseParams};
+use drasi_lib::Source;
+use tracing::Instrument;
+
+use crate::config::{SseOperation, SseSourceConfig};
+use crate::conversion::ChangeMapper;
+use crate::parser::SseParser;
+
+type Dispatchers = Arc<RwLock<Vec<Box<dyn ChangeDispatcher<SourceEventWrapper> + Send + Sync>>>>;
+
+/// Source that consumes a Server-Sent Events stream.
+///
+/// Each event is mapped to an insert, update or delete by its event type. The
+/// source reconnects with exponential backoff and resumes from the last event
+/// ID it received.
+pub struct SseSource {
+    /// Base source implementation providing dispatchers, status tracking, and lifecycle management.
+    base: SourceBase,
+    /// SSE configuration.
+    config: SseSourceConfig,
+}
+
+/// Why a connection ended.
+enum Disconnect {
+    /// Reconnect after the backoff delay.
+    Retry(String),
+    /// Stop; reconnecting would not help.
+    Fatal(String),
+}
+
+/// State shared by every connection of a stream.
+struct StreamContext {
+    source_id: String,
+    config: SseSourceConfig,
+    dispatchers: Dispatchers,
+    /// Base to save the last event ID through, when `resume` is enabled.
+    resume: Option<SourceBase>,
+}
+
+impl SseSource {
+    /// Create a builder for an SSE source.
+    pub fn builder(id: impl Into<String>) -> SseSourceBuilder {
+        SseSourceBuilder::new(id)
+    }
+
+    /// Create a new SSE source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid.
+    pub fn new(id: impl Into<String>, config: SseSourceConfig) -> Result<Self> {
+        SseSourceBuilder::new(id).with_config(config).build()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn test_subscribe(&self) -> Box<dyn ChangeReceiver<SourceEventWrapper>> {
+        self.base.test_subscribe()
+    }
+
+    async fn dispatch(
+        source_id: &str,
+        dispatchers: &Dispatchers,
+        change: SourceChange,
+    ) -> Result<()> {
+        let mut profiling = drasi_lib::profiling::ProfilingMetadata::new();
+        profiling.source_send_ns = Some(drasi_lib::profiling::timestamp_ns());
+
+        let wrapper = SourceEventWrapper::with_profiling(
+            source_id.to_string(),
+            SourceEvent::Change(change),
+            chrono::Utc::now(),
+            profiling,
+        );
+
+        SourceBase::dispatch_from_task(dispatchers.clone(), wrapper, source_id).await
+    }
+
+    /// Build the headers sent with every request.
+    pub(crate) fn header_map(config: &SseSourceConfig) -> Result<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT, HeaderValue::from_static("text/event-stream"));
+        headers.insert(CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+        for (name, value) in &config.headers {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|e| anyhow!("Invalid header name '{name}': {e}"))?;
+            let value = HeaderValue::from_str(value)
+                .map_err(|e| anyhow!("Invalid value of header '{name}': {e}"))?;
+            headers.insert(name, value);
+        }
+        Ok(headers)
+    }
+
+    async fn load_last_event_id(ctx: &StreamContext) -> Option<String> {
+        let base = ctx.resume.as_ref()?;
+        base.load_checkpoint().await.unwrap_or_else(|e| {
+            warn!("[{}] Failed to load last event ID: {e}", ctx.source_id);
+            None
+        })
+    }
+
+    async fn save_last_event_id(ctx: &StreamContext, last_event_id: Option<&str>) {
+        let Some(base) = &ctx.resume else {
+            return;
+        };
+        let result = match last_event_id {
+            Some(id) => base.save_checkpoint(&id).await,
+            None => base.clear_checkpoint().await,
+        };
+        if let Err(e) = result {
+            warn!("[{}] Failed to save last event ID: {e}", ctx.source_id);
+        }
+    }
+
+    /// Open the stream, classifying failures by whether a retry can help.
+    async fn open(
+        client: &reqwest::Client,
+        url: &str,
+        last_event_id: Option<&str>,
+    ) -> std::result::Result<reqwest::Response, Disconnect> {
+        let mut request = client.get(url);
+        if let Some(id) = last_event_id {
+            request = request.header("Last-Event-ID", id);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| Disconnect::Retry(format!("Request failed: {e}")))?;
+
+        let status = response.status();
+        if status == StatusCode::NO_CONTENT {
+            return Err(Disconnect::Fatal(
+                "Server responded 204 No Content, ending the stream".to_string(),
+            ));
+        }
+        if !status.is_success() {
+            let reason = format!("Server responded {status}");
+            let retryable = status.is_server_error()
+                || status == StatusCode::REQUEST_TIMEOUT
+                || status == StatusCode::TOO_MANY_REQUESTS;
+            return Err(if retryable {
+                Disconnect::Retry(reason)
+            } else {
+                Disconnect::Fatal(reason)
+            });
+        }
+
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default();
+        if !content_type.starts_with("text/event-stream") {
+            return Err(Disconnect::Fatal(format!(
+                "Server responded with content type '{content_type}' instead of text/event-stream"
+            )));
+        }
+
+        Ok(response)
+    }
+
+    /// Read events from an open stream until it ends.
+    async fn consume(
+        ctx: &StreamContext,
+        mut response: reqwest::Response,
+        parser: &mut SseParser,
+        mapper: &mut ChangeMapper,
+    ) -> Disconnect {
+        let idle_timeout = ctx.config.idle_timeout_ms.map(Duration::from_millis);
+        let mut saved_id = parser.last_event_id().map(str::to_string);
+
+        loop {
+            let chunk = match idle_timeout {
+                Some(timeout) => match tokio::time::timeout(timeout, response.chunk()).await {
+                    Ok(chunk) => chunk,
+                    Err(_) => {
+                        return Disconnect::Retry(format!(
+                            "Nothing received for {} ms",
+                            timeout.as_millis()
+                        ))
+                    }
+                },
+                None => response.chunk().await,
+            };
+
+            let bytes = match chunk {
+                Ok(Some(bytes)) => bytes,
+                Ok(None) => return Disconnect::Retry("Server closed the stream".to_string()),
+                Err(e) => return Disconnect::Retry(format!("Stream failed: {e}")),
+            };
+
+            for event in parser.push(&bytes) {
+                let change = match mapper.convert(&ctx.source_id, &event) {
+                    Ok(Some(change)) => change,
+                    Ok(None) => {
+                        debug!(
+                            "[{}] Ignoring event of unmapped type '{}'",
+                            ctx.source_id, event.event_type
+                        );
+                        continue;
+                    }
+                    Err(e) => {
+                        warn!(
+                            "[{}] Skipping event {}: {e}",
+                            ctx.source_id,
+                            event.id.as_deref().unwrap_or("without ID")
+                        );
+                        continue;
+                    }
+                };
+
+                match Self::dispatch(&ctx.source_id, &ctx.dispatchers, change.clone()).await {
+                    Ok(()) => mapper.record(&change),
+                    Err(e) => warn!("[{}] Failed to dispatch event: {e}", ctx.source_id),
+                }
+            }
+
+            // Persisted once per chunk rather than per event
+            if ctx.config.resume && parser.last_event_id() != saved_id.as_deref() {
+                saved_id = parser.last_event_id().map(str::to_string);
+                Self::save_last_event_id(ctx, saved_id.as_deref()).await;
+            }
+        }
+    }
+
+    async fn run(ctx: StreamContext, status_handle: ComponentStatusHandle) {
+        let client = match Self::header_map(&ctx.config).and_then(|headers| {
+            reqwest::Client::builder()
+                .default_headers(headers)
+                .build()
+                .map_err(|e| anyhow!("Failed to create HTTP client: {e}"))
+        }) {
+            Ok(client) => client,
+            Err(e) => {
+                error!("[{}] Failed to start SSE source: {e}", ctx.source_id);
+                status_handle
+                    .set_status(
+                        ComponentStatus::Error,
+                        Some(format!("Failed to start SSE source: {e}")),
+                    )
+                    .await;
+                return;
+            }
+        };
+
+        let mut last_event_id = if ctx.config.resume {
+            Self::load_last_event_id(&ctx).await
+        } else {
+            None
+        };
+        let mut mapper = ChangeMapper::new(ctx.config.clone());
+        let mut base_delay = Duration::from_millis(ctx.config.reconnect_delay_ms);
+        let max_delay = Duration::from_millis(ctx.config.max_reconnect_delay_ms);
+        let mut delay = base_delay;
+        let mut running = false;
+
+        loop {
+            let disconnect = match Self::open(&client, &ctx.config.url, last_event_id.as_deref())
+                .await
+            {
+                Ok(response) => {
+                    info!(
+                        "[{}] Connected to {} (last event ID: {})",
+                        ctx.source_id,
+                        ctx.config.url,
+                        last_event_id.as_deref().unwrap_or("none")
+                    );
+                    if !running {
+                        status_handle
+                            .set_status(
+                                ComponentStatus::Running,
+                                Some("SSE source running".to_string()),
+                            )
+                            .await;
+                        running = true;
+                    }
+
+                    let mut parser = SseParser::new(last_event_id.clone());
+                    let disconnect = Self::consume(&ctx, response, &mut parser, &mut mapper).await;
+                    last_event_id = parser.last_event_id().map(str::to_string);
+                    if let Some(retry_ms) = parser.take_retry() {
+                        base_delay = Duration::from_millis(retry_ms);
+                    }
+                    delay = base_delay;
+                    disconnect
+                }
+                Err(disconnect) => disconnect,
+            };
+
+            match disconnect {
+                Disconnect::Fatal(reason) => {
+                    error!("[{}] SSE stream stopped: {reason}", ctx.source_id);
+                    status_handle
+                        .set_status(
+                            ComponentStatus::Error,
+                            Some(format!("SSE stream stopped: {reason}")),
+                        )
+                        .await;
+                    return;
+                }
+                Disconnect::Retry(reason) => {
+                    warn!(
+                        "[{}] SSE stream interrupted, reconnecting in {} ms: {reason}",
+                        ctx.source_id,
+                        delay.as_millis()
+                    );
+                    tokio::time::sleep(delay).await;
+                    delay = (delay * 2).min(max_delay.max(base_delay));
+                }
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Source for SseSource {
+    fn id(&self) -> &str {
+        &self.base.id
+    }
+
+    fn type_name(&self) -> &str {
+        "sse"
+    }
+
+    fn properties(&self) -> HashMap<String, serde_json::Value> {
+        use crate::descriptor::{SseOperationDto, SseSourceConfigDto};
+        use drasi_plugin_sdk::ConfigValue;
+
+        let dto = SseSourceConfigDto {
+            url: ConfigValue::Static(self.config.url.clone()),
+            // Header values often carry credentials and are never exposed
+            headers: self
+                .config
+                .headers
+                .keys()
+                .map(|name| (name.clone(), ConfigValue::Static("***".to_string())))
+                .collect(),
+            event_operations: self
+                .config
+                .event_operations
+                .iter()
+                .map(|(event_type, operation)| {
+                    let operation = match operation {
+                        SseOperation::Insert => SseOperationDto::Insert,
+                        SseOperation::Update => SseOperationDto::Update,
+                        SseOperation::Upsert => SseOperationDto::Upsert,
+                        SseOperation::Delete => SseOperationDto::Delete,
+                    };
+                    (event_type.clone(), operation)
+                })
+                .collect(),
+            id_field: self.config.id_field.clone(),
+            properties_field: self.config.properties_field.clone(),
+            deleted_field: self.config.deleted_field.clone(),
+            label_field: self.config.label_field.clone(),
+            label: self.config.label.clone(),
+            reconnect_delay_ms: ConfigValue::Static(self.config.reconnect_delay_ms),
+            max_reconnect_delay_ms: ConfigValue::Static(self.config.max_reconnect_delay_ms),
+            idle_timeout_ms: self.config.idle_timeout_ms.map(ConfigValue::Static),
+            resume: self.config.resume,
+        };
+
+        match serde_json::to_value(&dto) {
+            Ok(serde_json::Value::Object(map)) => map.into_iter().collect(),
+            _ => HashMap::new(),
+        }
+    }
+
+    fn auto_start(&self) -> bool {
+        self.base.get_auto_start()
+    }
+
+    async fn start(&self) -> Result<()> {
+        log_component_start("SSE Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Starting,
+                Some("Starting SSE source".to_string()),
+            )
+            .await;
+
+        let resume = if self.config.resume {
+            let has_checkpoint_store = self
+                .base
+                .context()
+                .await
+                .is_some_and(|c| c.checkpoint_store().is_some());
+            if !has_checkpoint_store {
+                info!(
+                    "[{}] No checkpoint store configured, the last event ID is kept in memory only",
+                    self.base.id
+                );
+            }
+            Some(self.base.clone_shared())
+        } else {
+            None
+        };
+
+        let ctx = StreamContext {
+            source_id: self.base.id.clone(),
+            config: self.config.clone(),
+            dispatchers: self.base.dispatchers.clone(),
+            resume,
+        };
+
+        let instance_id = self
+            .base
+            .context()
+            .await
+            .map(|c| c.instance_id)
+            .unwrap_or_default();
+
+        let span = tracing::info_span!(
+            "sse_source_stream",
+            instance_id = %instance_id,
+            component_id = %self.base.id,
+            component_type = "source"
+        );
+        let task = tokio::spawn(Self::run(ctx, self.base.status_handle()).instrument(span));
+
+        *self.base.task_handle.write().await = Some(task);
+
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        log_component_stop("SSE Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopping,
+                Some("Stopping SSE source".to_string()),
+            )
+            .await;
+
+        if let Some(handle) = self.base.task_handle.write().await.take() {
+            handle.abort();
+            let _ = handle.await;
+        }
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopped,
+                Some("SSE source stopped".to_string()),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn status(&self) -> ComponentStatus {
+        self.base.get_status().await
+    }
+
+    async fn subscribe(
+        &self,
+        settings: drasi_lib::config::SourceSubscriptionSettings,
+    ) -> Result<SubscriptionResponse> {
+        self.base.subscribe_with_bootstrap(&settings, "SSE").await
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    async fn initialize(&self, context: drasi_lib::context::SourceRuntimeContext) {
+        self.base.initialize(context).await;
+    }
+
+    async fn set_bootstrap_provider(
+        &self,
+        provider: Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>,
+    ) {
+        self.base.set_bootstrap_provider(provider).await;
+    }
+}
+
+/// Builder for [`SseSource`] instances.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use drasi_source_sse::{SseOperation, SseSource};
+///
+/// let source = SseSource::builder("orders-feed")
+///     .with_url("https://api.example.com/orders/events")
+///     .with_header("Authorization", "Bearer my-token")
+///     .with_event_operation("order.cancelled", SseOperation::Delete)
+///     .with_label("Order")
+///     .build()?;
+/// ```
+pub struct SseSourceBuilder {
+    id: String,
+    config: SseSourceConfig,
+    dispatch_mode: Option<DispatchMode>,
+    dispatch_buffer_capacity: Option<usize>,
+    bootstrap_provider: Option<Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>>,
+    auto_start: bool,
+}
+
+impl SseSourceBuilder {
+    /// Create a new builder with the given ID and default values.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            config: SseSourceConfig::default(),
+            dispatch_mode: None,
+            dispatch_buffer_capacity: None,
+            bootstrap_provider: None,
+            auto_start: true,
+        }
+    }
+
+    /// Set the URL of the event stream.
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.config.url = url.into();
+        self
+    }
+
+    /// Add a header sent with every request.
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.config.headers.insert(name.into(), value.into());
+        self
+    }
+
+    /// Map an event type to an operation, in addition to the existing mappings.
+    pub fn with_event_operation(
+        mut self,
+        event_type: impl Into<String>,
+        operation: SseOperation,
+    ) -> Self {
+        self.config
+            .event_operations
+            .insert(event_type.into(), operation);
+        self
+    }
+
+    /// Replace all event type mappings.
+    pub fn with_event_operations(mut self, operations: HashMap<String, SseOperation>) -> Self {
+        self.config.event_operations = operations;
+        self
+    }
+
+    /// Set the payload field holding the element ID.
+    pub fn with_id_field(mut self, field: impl Into<String>) -> Self {
+        self.config.id_field = field.into();
+        self
+    }
+
+    /// Set the payload field holding the node properties.
+    pub fn with_properties_field(mut self, field: impl Into<String>) -> Self {
+        self.config.properties_field = Some(field.into());
+        self
+    }
+
+    /// Set the payload field that turns an event into a delete when `true`.
+    pub fn with_deleted_field(mut self, field: impl Into<String>) -> Self {
+        self.config.deleted_field = Some(field.into());
+        self
+    }
+
+    /// Set the payload field holding the node label.
+    pub fn with_label_field(mut self, field: impl Into<String>) -> Self {
+        self.config.label_field = Some(field.into());
+        self
+    }
+
+    /// Set the label for all nodes.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.config.label = Some(label.into());
+        self
+    }
+
+    /// Set the milliseconds to wait before the first reconnection attempt.
+    pub fn with_reconnect_delay_ms(mut self, delay_ms: u64) -> Self {
+        self.config.reconnect_delay_ms = delay_ms;
+        self
+    }
+
+    /// Set the upper bound of the reconnection delay.
+    pub fn with_max_reconnect_delay_ms(mut self, delay_ms: u64) -> Self {
+        self.config.max_reconnect_delay_ms = delay_ms;
+        self
+    }
+
+    /// Reconnect when nothing is received for this many milliseconds.
+    pub fn with_idle_timeout_ms(mut self, timeout_ms: u64) -> Self {
+        self.config.idle_timeout_ms = Some(timeout_ms);
+        self
+    }
+
+    /// Set whether the last event ID is persisted to resume after a restart.
+    pub fn with_resume(mut self, resume: bool) -> Self {
+        self.config.resume = resume;
+        self
+    }
+
+    /// Set the dispatch mode for this source.
+    pub fn with_dispatch_mode(mut self, mode: DispatchMode) -> Self {
+        self.dispatch_mode = Some(mode);
+        self
+    }
+
+    /// Set the dispatch buffer capacity.
+    pub fn with_dispatch_buffer_capacity(mut self, capacity: usize) -> Self {
+        self.dispatch_buffer_capacity = Some(capacity);
+        self
+    }
+
+    /// Set the bootstrap provider for initial data delivery.
+    pub fn with_bootstrap_provider(
+        mut self,
+        provider: impl drasi_lib::bootstrap::BootstrapProvider + 'static,
+    ) -> Self {
+        self.bootstrap_provider = Some(Box::new(provider));
+        self
+    }
+
+    /// Set whether this source should auto-start when DrasiLib starts.
+    pub fn with_auto_start(mut self, auto_start: bool) -> Self {
+        self.auto_start = auto_start;
+        self
+    }
+
+    /// Set the full configuration at once.
+    pub fn with_config(mut self, config: SseSourceConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Build the SSE source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid, a header is not a
+    /// valid HTTP header, or the base source cannot be initialized.
+    pub fn build(self) -> Result<SseSource> {
+        self.config.validate()?;
+        SseSource::header_map(&self.config).map_err(|e| anyhow!("Validation error: {e}"))?;
+
+        let mut params = SourceBaseParams::new(&self.id).with_auto_start(self.auto_start);
+        if let Some(mode) = self.dispatch_mode {
+            params = params.with_dispatch_mode(mode);
+        }
+        if let Some(capacity) = self.dispatch_buffer_capacity {
+            params = params.with_dispatch_buffer_capacity(capacity);
+        }
+        if let Some(provider) = self.bootstrap_provider {
+            params = params.with_bootstrap_provider(provider);
+        }
+
+        Ok(SseSource {
+            base: SourceBase::new(params)?,
+            config: self.config,
+        })
+    }
+}
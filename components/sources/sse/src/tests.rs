@@ -0,0 +1,432 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for the SSE source plugin.
+
+use super::*;
+use crate::conversion::ChangeMapper;
+use crate::parser::{SseEvent, SseParser};
+use drasi_core::models::{Element, ElementValue, SourceChange};
+use drasi_lib::channels::SourceEvent;
+use drasi_lib::Source;
+use drasi_plugin_sdk::prelude::SourcePluginDescriptor;
+use serde_json::json;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+fn config() -> SseSourceConfig {
+    SseSourceConfig {
+        url: "http://localhost:5984/orders/_changes?feed=eventsource".to_string(),
+        ..Default::default()
+    }
+}
+
+fn event(event_type: &str, data: serde_json::Value) -> SseEvent {
+    SseEvent {
+        event_type: event_type.to_string(),
+        data: data.to_string(),
+        id: None,
+    }
+}
+
+mod builder {
+    use super::*;
+
+    #[test]
+    fn test_builder_with_valid_config() {
+        let source = SseSource::builder("test-source")
+            .with_url("https://api.example.com/events")
+            .with_header("Authorization", "Bearer secret")
+            .with_event_operation("order.cancelled", SseOperation::Delete)
+            .with_idle_timeout_ms(45000)
+            .build()
+            .unwrap();
+
+        assert_eq!(source.id(), "test-source");
+        assert_eq!(source.type_name(), "sse");
+        let props = source.properties();
+        assert_eq!(
+            props.get("url"),
+            Some(&json!("https://api.example.com/events"))
+        );
+        assert_eq!(props.get("headers"), Some(&json!({"Authorization": "***"})));
+        assert_eq!(props["eventOperations"]["order.cancelled"], json!("delete"));
+        assert_eq!(props["eventOperations"]["message"], json!("upsert"));
+        assert_eq!(props.get("idleTimeoutMs"), Some(&json!(45000)));
+    }
+
+    #[test]
+    fn test_builder_rejects_invalid_config() {
+        assert!(SseSource::builder("test-source").build().is_err());
+        assert!(SseSource::builder("test-source")
+            .with_url("ws://example.com/events")
+            .build()
+            .is_err());
+        assert!(SseSource::builder("test-source")
+            .with_url("https://example.com/events")
+            .with_header("Bad Header", "value")
+            .build()
+            .is_err());
+        assert!(SseSource::builder("test-source")
+            .with_url("https://example.com/events")
+            .with_reconnect_delay_ms(60000)
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_config_deserialization_defaults() {
+        let config: SseSourceConfig =
+            serde_json::from_value(json!({"url": "http://localhost/events"})).unwrap();
+
+        assert_eq!(config.id_field, "id");
+        assert_eq!(config.reconnect_delay_ms, 1000);
+        assert_eq!(config.max_reconnect_delay_ms, 30000);
+        assert!(config.resume);
+        assert_eq!(
+            config.event_operations.get("message"),
+            Some(&SseOperation::Upsert)
+        );
+        assert_eq!(
+            config.event_operations.get("delete"),
+            Some(&SseOperation::Delete)
+        );
+    }
+}
+
+mod parser {
+    use super::*;
+
+    #[test]
+    fn test_parse_events_and_fields() {
+        let mut parser = SseParser::new(None);
+        let events = parser.push(
+            b": heartbeat\n\nid: 1\nevent: update\ndata: {\"id\": \"a\",\ndata:  \"v\": 1}\n\ndata:plain\nretry: 2500\n\n",
+        );
+
+        assert_eq!(
+            events,
+            vec![
+                SseEvent {
+                    event_type: "update".to_string(),
+                    data: "{\"id\": \"a\",\n \"v\": 1}".to_string(),
+                    id: Some("1".to_string()),
+                },
+                SseEvent {
+                    event_type: "message".to_string(),
+                    data: "plain".to_string(),
+                    id: Some("1".to_string()),
+                },
+            ]
+        );
+        assert_eq!(parser.take_retry(), Some(2500));
+        assert_eq!(parser.take_retry(), None);
+    }
+
+    #[test]
+    fn test_parse_across_chunks_and_line_endings() {
+        let mut parser = SseParser::new(Some("7".to_string()));
+
+        assert!(parser.push("\u{feff}data: fi".as_bytes()).is_empty());
+        assert!(parser.push(b"rst\r").is_empty());
+        let events = parser.push(b"\n\r\ndata: second\r\rid\n");
+        assert_eq!(
+            events.iter().map(|e| e.data.as_str()).collect::<Vec<_>>(),
+            vec!["first", "second"]
+        );
+        assert_eq!(events[0].id.as_deref(), Some("7"));
+        // A bare "id" field clears the last event ID
+        assert_eq!(parser.last_event_id(), None);
+    }
+
+    #[test]
+    fn test_events_without_data_are_not_dispatched() {
+        let mut parser = SseParser::new(None);
+        assert!(parser.push(b"event: ping\nid: 42\n\n").is_empty());
+        assert_eq!(parser.last_event_id(), Some("42"));
+    }
+}
+
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn test_upsert_inserts_then_updates() {
+        let mut mapper = ChangeMapper::new(config());
+        let message = event("message", json!({"id": "o-1", "total": 12.5}));
+
+        let change = mapper.convert("src", &message).unwrap().unwrap();
+        let SourceChange::Insert {
+            element:
+                Element::Node {
+                    metadata,
+                    properties,
+                },
+        } = &change
+        else {
+            panic!("expected node insert");
+        };
+        assert_eq!(metadata.reference.element_id.as_ref(), "o-1");
+        assert_eq!(metadata.labels[0].as_ref(), "SseEvent");
+        assert_eq!(
+            properties.get("total"),
+            Some(&ElementValue::Float(12.5.into()))
+        );
+
+        mapper.record(&change);
+        assert!(matches!(
+            mapper.convert("src", &message).unwrap(),
+            Some(SourceChange::Update { .. })
+        ));
+
+        let delete = mapper
+            .convert("src", &event("delete", json!({"id": "o-1"})))
+            .unwrap()
+            .unwrap();
+        assert!(matches!(delete, SourceChange::Delete { .. }));
+        mapper.record(&delete);
+        assert!(matches!(
+            mapper.convert("src", &message).unwrap(),
+            Some(SourceChange::Insert { .. })
+        ));
+    }
+
+    #[test]
+    fn test_couchdb_change_mapping() {
+        let config = SseSourceConfig {
+            properties_field: Some("doc".to_string()),
+            deleted_field: Some("deleted".to_string()),
+            label: Some("Order".to_string()),
+            ..config()
+        };
+        let mapper = ChangeMapper::new(config);
+
+        let change = mapper
+            .convert(
+                "src",
+                &event(
+                    "message",
+                    json!({"seq": "3-g1", "id": "o-1", "changes": [{"rev": "1-a"}], "doc": {"_id": "o-1", "status": "open"}}),
+                ),
+            )
+            .unwrap()
+            .unwrap();
+        let SourceChange::Insert {
+            element:
+                Element::Node {
+                    metadata,
+                    properties,
+                },
+        } = change
+        else {
+            panic!("expected node insert");
+        };
+        assert_eq!(metadata.labels[0].as_ref(), "Order");
+        assert_eq!(
+            properties.get("status"),
+            Some(&ElementValue::String("open".into()))
+        );
+        assert!(properties.get("seq").is_none());
+
+        let deleted = mapper
+            .convert(
+                "src",
+                &event("message", json!({"id": "o-1", "deleted": true})),
+            )
+            .unwrap();
+        assert!(matches!(deleted, Some(SourceChange::Delete { .. })));
+    }
+
+    #[test]
+    fn test_unmapped_and_invalid_events() {
+        let config = SseSourceConfig {
+            label_field: Some("type".to_string()),
+            ..config()
+        };
+        let mapper = ChangeMapper::new(config);
+
+        assert!(mapper
+            .convert("src", &event("heartbeat", json!({"id": "x"})))
+            .unwrap()
+            .is_none());
+        assert!(mapper
+            .convert("src", &event("insert", json!({"name": "no id"})))
+            .is_err());
+        assert!(mapper
+            .convert("src", &event("insert", json!([1, 2])))
+            .is_err());
+
+        let change = mapper
+            .convert("src", &event("insert", json!({"id": 7, "type": "Sensor"})))
+            .unwrap()
+            .unwrap();
+        let SourceChange::Insert {
+            element: Element::Node { metadata, .. },
+        } = change
+        else {
+            panic!("expected node insert");
+        };
+        assert_eq!(metadata.reference.element_id.as_ref(), "7");
+        assert_eq!(metadata.labels[0].as_ref(), "Sensor");
+    }
+}
+
+mod stream {
+    use super::*;
+
+    /// Accept one connection, return its request head and write `response`.
+    async fn serve(listener: &TcpListener, response: &str) -> (tokio::net::TcpStream, String) {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut request = Vec::new();
+        let mut buffer = [0u8; 1024];
+        while !request.ends_with(b"\r\n\r\n") {
+            let read = stream.read(&mut buffer).await.unwrap();
+            assert!(read > 0, "connection closed before the request ended");
+            request.extend_from_slice(&buffer[..read]);
+        }
+        stream.write_all(response.as_bytes()).await.unwrap();
+        (stream, String::from_utf8_lossy(&request).to_lowercase())
+    }
+
+    #[tokio::test]
+    async fn test_reconnects_with_last_event_id() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/events", listener.local_addr().unwrap());
+        let head =
+            "HTTP/1.1 200 OK\r\ncontent-type: text/event-stream\r\nconnection: close\r\n\r\n";
+
+        let server = tokio::spawn(async move {
+            let (stream, first) = serve(
+                &listener,
+                &format!(
+                    "{head}retry: 10\nid: 1\nevent: insert\ndata: {{\"id\": \"a\", \"v\": 1}}\n\n"
+                ),
+            )
+            .await;
+            drop(stream);
+
+            let (stream, second) = serve(
+                &listener,
+                &format!("{head}id: 2\nevent: delete\ndata: {{\"id\": \"a\"}}\n\n"),
+            )
+            .await;
+            (stream, first, second)
+        });
+
+        let source = SseSource::builder("sse-test")
+            .with_url(url)
+            .with_resume(false)
+            .build()
+            .unwrap();
+        let mut rx = source.test_subscribe();
+        source.start().await.unwrap();
+
+        let mut changes = Vec::new();
+        for _ in 0..2 {
+            let event = tokio::time::timeout(Duration::from_secs(10), rx.recv())
+                .await
+                .expect("Timeout")
+                .expect("No event");
+            if let SourceEvent::Change(change) = &event.event {
+                changes.push(change.clone());
+            }
+        }
+        source.stop().await.unwrap();
+
+        assert!(matches!(changes[0], SourceChange::Insert { .. }));
+        assert!(matches!(changes[1], SourceChange::Delete { .. }));
+
+        let (_stream, first, second) = server.await.unwrap();
+        assert!(first.contains("accept: text/event-stream"));
+        assert!(!first.contains("last-event-id"));
+        assert!(second.contains("last-event-id: 1"));
+    }
+
+    #[tokio::test]
+    async fn test_client_errors_stop_the_source() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/events", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            serve(
+                &listener,
+                "HTTP/1.1 401 Unauthorized\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+            )
+            .await
+        });
+
+        let source = SseSource::builder("sse-test")
+            .with_url(url)
+            .with_resume(false)
+            .build()
+            .unwrap();
+        source.start().await.unwrap();
+        let _ = server.await.unwrap();
+
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while source.status().await != drasi_lib::channels::ComponentStatus::Error {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+        })
+        .await
+        .expect("source did not enter the Error state");
+    }
+}
+
+mod descriptor {
+    use super::*;
+    use crate::descriptor::SseSourceDescriptor;
+
+    #[tokio::test]
+    async fn test_descriptor_creates_source() {
+        let descriptor = SseSourceDescriptor;
+        assert_eq!(descriptor.kind(), "sse");
+
+        let source = descriptor
+            .create_source(
+                "sse-1",
+                &json!({
+                    "url": "http://localhost:5984/orders/_changes?feed=eventsource",
+                    "headers": {"Authorization": "Basic abc"},
+                    "eventOperations": {"change": "upsert"},
+                    "propertiesField": "doc"
+                }),
+                false,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(source.id(), "sse-1");
+        assert!(!source.auto_start());
+        let props = source.properties();
+        assert_eq!(
+            props.get("eventOperations"),
+            Some(&json!({"change": "upsert"}))
+        );
+        assert_eq!(props.get("propertiesField"), Some(&json!("doc")));
+        assert_eq!(props["headers"]["Authorization"], json!("***"));
+    }
+
+    #[tokio::test]
+    async fn test_descriptor_rejects_unknown_fields() {
+        let result = SseSourceDescriptor
+            .create_source(
+                "sse-1",
+                &json!({"url": "http://localhost/events", "bogus": 1}),
+                true,
+            )
+            .await;
+        assert!(result.is_err());
+    }
+}
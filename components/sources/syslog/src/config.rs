@@ -0,0 +1,176 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration types for the Syslog source plugin.
+//!
+//! This module defines where the source listens, which transport it accepts,
+//! and how parsed messages are labeled.
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+fn default_host() -> String {
+    "0.0.0.0".to_string()
+}
+
+fn default_port() -> u16 {
+    514
+}
+
+fn default_max_message_size() -> usize {
+    65536
+}
+
+fn default_label() -> String {
+    "SyslogMessage".to_string()
+}
+
+fn default_facility_labels() -> bool {
+    true
+}
+
+/// Transport the source listens on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SyslogProtocol {
+    /// One message per datagram (RFC 5426).
+    #[default]
+    Udp,
+    /// Octet-counted or newline-delimited messages over TCP (RFC 6587).
+    Tcp,
+    /// TCP framing over TLS (RFC 5425).
+    Tls,
+}
+
+/// Syslog source configuration.
+///
+/// # Example
+///
+/// ```rust
+/// use drasi_source_syslog::{SyslogProtocol, SyslogSourceConfig};
+///
+/// let config = SyslogSourceConfig {
+///     port: 6514,
+///     protocol: SyslogProtocol::Tls,
+///     tls_cert_path: Some("/etc/drasi/syslog.crt".to_string()),
+///     tls_key_path: Some("/etc/drasi/syslog.key".to_string()),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SyslogSourceConfig {
+    /// Address to listen on.
+    ///
+    /// **Default**: `"0.0.0.0"`
+    #[serde(default = "default_host")]
+    pub host: String,
+
+    /// Port to listen on. The standard ports are 514 for UDP and TCP, and
+    /// 6514 for TLS.
+    ///
+    /// **Default**: `514`
+    #[serde(default = "default_port")]
+    pub port: u16,
+
+    /// Transport to accept.
+    ///
+    /// **Default**: `udp`
+    #[serde(default)]
+    pub protocol: SyslogProtocol,
+
+    /// PEM certificate chain presented to TLS clients. Required for `tls`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls_cert_path: Option<String>,
+
+    /// PEM private key of the certificate. Required for `tls`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls_key_path: Option<String>,
+
+    /// Largest accepted message in bytes. Longer datagrams are truncated, and
+    /// stream connections sending longer frames are closed.
+    ///
+    /// **Default**: `65536`
+    #[serde(default = "default_max_message_size")]
+    pub max_message_size: usize,
+
+    /// Label of every message node.
+    ///
+    /// **Default**: `"SyslogMessage"`
+    #[serde(default = "default_label")]
+    pub label: String,
+
+    /// Add the facility name (for example `auth` or `local0`) as a label.
+    ///
+    /// **Default**: `true`
+    #[serde(default = "default_facility_labels")]
+    pub facility_labels: bool,
+
+    /// Add the hostname of the message as a label.
+    ///
+    /// **Default**: `false`
+    #[serde(default)]
+    pub hostname_labels: bool,
+}
+
+impl Default for SyslogSourceConfig {
+    fn default() -> Self {
+        Self {
+            host: default_host(),
+            port: default_port(),
+            protocol: SyslogProtocol::default(),
+            tls_cert_path: None,
+            tls_key_path: None,
+            max_message_size: default_max_message_size(),
+            label: default_label(),
+            facility_labels: default_facility_labels(),
+            hostname_labels: false,
+        }
+    }
+}
+
+impl SyslogSourceConfig {
+    /// Validate the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `host` or `label` is empty
+    /// - `max_message_size` is zero
+    /// - `protocol` is `tls` and the certificate or key path is missing
+    pub fn validate(&self) -> Result<()> {
+        if self.host.is_empty() {
+            return Err(anyhow!("Validation error: host cannot be empty"));
+        }
+
+        if self.label.is_empty() {
+            return Err(anyhow!("Validation error: label cannot be empty"));
+        }
+
+        if self.max_message_size == 0 {
+            return Err(anyhow!(
+                "Validation error: max_message_size must be greater than 0"
+            ));
+        }
+
+        if self.protocol == SyslogProtocol::Tls
+            && (self.tls_cert_path.is_none() || self.tls_key_path.is_none())
+        {
+            return Err(anyhow!(
+                "Validation error: tls_cert_path and tls_key_path are required for the tls protocol"
+            ));
+        }
+
+        Ok(())
+    }
+}
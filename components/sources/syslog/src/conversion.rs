@@ -0,0 +1,109 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion of parsed syslog messages into Drasi source changes.
+
+use drasi_core::models::{Element, ElementMetadata, ElementReference, SourceChange};
+use drasi_lib::sources::convert_json_to_element_properties;
+use serde_json::{Map, Value};
+use std::sync::Arc;
+
+use crate::config::SyslogSourceConfig;
+use crate::parser::SyslogMessage;
+
+/// Labels of a message node: the configured label, then the facility name and
+/// hostname when enabled.
+pub(crate) fn labels_for(message: &SyslogMessage, config: &SyslogSourceConfig) -> Vec<String> {
+    let mut labels = vec![config.label.clone()];
+    if config.facility_labels {
+        labels.push(message.facility_name().to_string());
+    }
+    if config.hostname_labels {
+        if let Some(hostname) = &message.hostname {
+            labels.push(hostname.clone());
+        }
+    }
+    labels
+}
+
+/// Properties of a message node. Structured data becomes a nested map of
+/// element ID to parameters.
+pub(crate) fn message_properties(message: &SyslogMessage, remote_addr: &str) -> Map<String, Value> {
+    let mut properties = Map::new();
+    properties.insert("format".into(), message.format.as_str().into());
+    properties.insert("facility".into(), message.facility_name().into());
+    properties.insert("facility_code".into(), message.facility.into());
+    properties.insert("severity".into(), message.severity_name().into());
+    properties.insert("severity_code".into(), message.severity.into());
+    properties.insert("message".into(), message.message.clone().into());
+    properties.insert("remote_addr".into(), remote_addr.into());
+
+    for (name, value) in [
+        ("timestamp", &message.timestamp),
+        ("hostname", &message.hostname),
+        ("app_name", &message.app_name),
+        ("proc_id", &message.proc_id),
+        ("msg_id", &message.msg_id),
+    ] {
+        if let Some(value) = value {
+            properties.insert(name.into(), value.clone().into());
+        }
+    }
+
+    if !message.structured_data.is_empty() {
+        let structured_data: Map<String, Value> = message
+            .structured_data
+            .iter()
+            .map(|element| {
+                let params: Map<String, Value> = element
+                    .params
+                    .iter()
+                    .map(|(name, value)| (name.clone(), Value::String(value.clone())))
+                    .collect();
+                (element.id.clone(), Value::Object(params))
+            })
+            .collect();
+        properties.insert("structured_data".into(), Value::Object(structured_data));
+    }
+
+    properties
+}
+
+/// Convert a parsed message into an insert of a message node.
+pub(crate) fn message_to_source_change(
+    source_id: &str,
+    element_id: &str,
+    message: &SyslogMessage,
+    remote_addr: &str,
+    config: &SyslogSourceConfig,
+) -> SourceChange {
+    let labels: Vec<Arc<str>> = labels_for(message, config)
+        .into_iter()
+        .map(Arc::from)
+        .collect();
+
+    SourceChange::Insert {
+        element: Element::Node {
+            metadata: ElementMetadata {
+                reference: ElementReference::new(source_id, element_id),
+                labels: Arc::from(labels),
+                effective_from: chrono::Utc::now().timestamp_millis() as u64,
+            },
+            properties: convert_json_to_element_properties(&message_properties(
+                message,
+                remote_addr,
+            )),
+        },
+    }
+}
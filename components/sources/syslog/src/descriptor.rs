@@ -0,0 +1,142 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Syslog source plugin descriptor and configuration DTOs.
+
+use crate::{SyslogProtocol, SyslogSourceBuilder, SyslogSourceConfig};
+use drasi_plugin_sdk::prelude::*;
+use utoipa::OpenApi;
+
+/// Syslog transport DTO.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::syslog::SyslogProtocol)]
+#[serde(rename_all = "snake_case")]
+pub enum SyslogProtocolDto {
+    #[default]
+    Udp,
+    Tcp,
+    Tls,
+}
+
+/// Syslog source configuration DTO.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::syslog::SyslogSourceConfig)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SyslogSourceConfigDto {
+    #[serde(default = "default_host")]
+    pub host: ConfigValue<String>,
+    #[serde(default = "default_port")]
+    pub port: ConfigValue<u16>,
+    #[serde(default)]
+    #[schema(value_type = source::syslog::SyslogProtocol)]
+    pub protocol: SyslogProtocolDto,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls_cert_path: Option<ConfigValue<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls_key_path: Option<ConfigValue<String>>,
+    #[serde(default = "default_max_message_size")]
+    pub max_message_size: ConfigValue<usize>,
+    #[serde(default = "default_label")]
+    pub label: String,
+    #[serde(default = "default_facility_labels")]
+    pub facility_labels: bool,
+    #[serde(default)]
+    pub hostname_labels: bool,
+}
+
+fn default_host() -> ConfigValue<String> {
+    ConfigValue::Static("0.0.0.0".to_string())
+}
+
+fn default_port() -> ConfigValue<u16> {
+    ConfigValue::Static(514)
+}
+
+fn default_max_message_size() -> ConfigValue<usize> {
+    ConfigValue::Static(65536)
+}
+
+fn default_label() -> String {
+    "SyslogMessage".to_string()
+}
+
+fn default_facility_labels() -> bool {
+    true
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(SyslogSourceConfigDto, SyslogProtocolDto)))]
+struct SyslogSourceSchemas;
+
+/// Descriptor for the Syslog source plugin.
+pub struct SyslogSourceDescriptor;
+
+#[async_trait]
+impl SourcePluginDescriptor for SyslogSourceDescriptor {
+    fn kind(&self) -> &str {
+        "syslog"
+    }
+
+    fn config_version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn config_schema_name(&self) -> &str {
+        "source.syslog.SyslogSourceConfig"
+    }
+
+    fn config_schema_json(&self) -> String {
+        let api = SyslogSourceSchemas::openapi();
+        serde_json::to_string(
+            &api.components
+                .as_ref()
+                .expect("OpenAPI components missing")
+                .schemas,
+        )
+        .expect("Failed to serialize config schema")
+    }
+
+    async fn create_source(
+        &self,
+        id: &str,
+        config_json: &serde_json::Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn drasi_lib::sources::Source>> {
+        let dto: SyslogSourceConfigDto = serde_json::from_value(config_json.clone())?;
+        let mapper = DtoMapper::new();
+
+        let config = SyslogSourceConfig {
+            host: mapper.resolve_string(&dto.host)?,
+            port: mapper.resolve_typed(&dto.port)?,
+            protocol: match dto.protocol {
+                SyslogProtocolDto::Udp => SyslogProtocol::Udp,
+                SyslogProtocolDto::Tcp => SyslogProtocol::Tcp,
+                SyslogProtocolDto::Tls => SyslogProtocol::Tls,
+            },
+            tls_cert_path: mapper.resolve_optional(&dto.tls_cert_path)?,
+            tls_key_path: mapper.resolve_optional(&dto.tls_key_path)?,
+            max_message_size: mapper.resolve_typed(&dto.max_message_size)?,
+            label: dto.label,
+            facility_labels: dto.facility_labels,
+            hostname_labels: dto.hostname_labels,
+        };
+
+        let source = SyslogSourceBuilder::new(id)
+            .with_config(config)
+            .with_auto_start(auto_start)
+            .build()?;
+
+        Ok(Box::new(source))
+    }
+}
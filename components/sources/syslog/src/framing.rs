@@ -0,0 +1,136 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Message framing for syslog over TCP and TLS (RFC 6587).
+//!
+//! A frame is either octet-counted (`<length> <message>`) or terminated by a
+//! newline. Syslog messages start with `<`, so a frame starting with a digit
+//! is octet-counted. Both methods may be mixed on one connection.
+
+use anyhow::{anyhow, Result};
+
+/// Longest length prefix accepted for octet-counted frames.
+const MAX_LENGTH_DIGITS: usize = 10;
+
+/// Splits a byte stream into syslog frames.
+pub(crate) struct Framer {
+    buffer: Vec<u8>,
+    max_message_size: usize,
+}
+
+impl Framer {
+    pub(crate) fn new(max_message_size: usize) -> Self {
+        Self {
+            buffer: Vec::new(),
+            max_message_size,
+        }
+    }
+
+    /// Append received bytes.
+    pub(crate) fn push(&mut self, data: &[u8]) {
+        self.buffer.extend_from_slice(data);
+    }
+
+    /// Take the next complete frame, or `None` if more bytes are needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a frame is longer than the maximum message size or
+    /// has a malformed length prefix. The connection should then be closed.
+    pub(crate) fn next_frame(&mut self) -> Result<Option<String>> {
+        loop {
+            let start = self
+                .buffer
+                .iter()
+                .position(|b| !matches!(b, b'\n' | b'\r' | b'\0' | b' '))
+                .unwrap_or(self.buffer.len());
+            self.buffer.drain(..start);
+
+            let Some(first) = self.buffer.first() else {
+                return Ok(None);
+            };
+
+            let frame = if first.is_ascii_digit() {
+                self.octet_counted_frame()?
+            } else {
+                self.delimited_frame()?
+            };
+
+            match frame {
+                Some(frame) if frame.trim().is_empty() => continue,
+                frame => return Ok(frame),
+            }
+        }
+    }
+
+    fn octet_counted_frame(&mut self) -> Result<Option<String>> {
+        let Some(space) = self.buffer.iter().position(|b| *b == b' ') else {
+            if self.buffer.len() > MAX_LENGTH_DIGITS {
+                return Err(anyhow!("Malformed octet count"));
+            }
+            return Ok(None);
+        };
+
+        let length: usize = std::str::from_utf8(&self.buffer[..space])
+            .ok()
+            .and_then(|digits| digits.parse().ok())
+            .ok_or_else(|| anyhow!("Malformed octet count"))?;
+        if length > self.max_message_size {
+            return Err(anyhow!(
+                "Frame of {length} bytes exceeds the maximum of {}",
+                self.max_message_size
+            ));
+        }
+
+        let end = space + 1 + length;
+        if self.buffer.len() < end {
+            return Ok(None);
+        }
+
+        let frame = String::from_utf8_lossy(&self.buffer[space + 1..end]).into_owned();
+        self.buffer.drain(..end);
+        Ok(Some(frame))
+    }
+
+    fn delimited_frame(&mut self) -> Result<Option<String>> {
+        let Some(end) = self.buffer.iter().position(|b| matches!(b, b'\n' | b'\0')) else {
+            if self.buffer.len() > self.max_message_size {
+                return Err(anyhow!(
+                    "Unterminated frame exceeds the maximum of {} bytes",
+                    self.max_message_size
+                ));
+            }
+            return Ok(None);
+        };
+        if end > self.max_message_size {
+            return Err(anyhow!(
+                "Frame of {end} bytes exceeds the maximum of {}",
+                self.max_message_size
+            ));
+        }
+
+        let frame = String::from_utf8_lossy(&self.buffer[..end])
+            .trim_end_matches('\r')
+            .to_string();
+        self.buffer.drain(..=end);
+        Ok(Some(frame))
+    }
+
+    /// Take the unterminated remainder when the connection closes.
+    pub(crate) fn finish(&mut self) -> Option<String> {
+        let rest = String::from_utf8_lossy(&self.buffer).trim().to_string();
+        self.buffer.clear();
+        (!rest.is_empty()).then_some(rest)
+    }
+}
@@ -0,0 +1,80 @@
+#![allow(unexpected_cfgs)]
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Syslog Source Plugin for drasi-lib.
+//!
+//! This plugin listens for syslog messages over UDP, TCP or TLS and inserts
+//! one node per message. Messages are parsed as RFC 5424 and fall back to
+//! RFC 3164 (BSD syslog) when they are not well-formed RFC 5424.
+//!
+//! # Message Mapping
+//!
+//! | Message part | Maps to |
+//! |--------------|---------|
+//! | Priority | `facility`, `facility_code`, `severity`, `severity_code` properties |
+//! | Header | `timestamp`, `hostname`, `app_name`, `proc_id`, `msg_id` properties, when present |
+//! | Structured data | `structured_data` property mapping element IDs to their parameters |
+//! | Message text | `message` property |
+//! | Sender | `remote_addr` property |
+//!
+//! Nodes are labeled with `label` (default `SyslogMessage`), the facility name
+//! unless `facility_labels` is disabled, and the hostname when
+//! `hostname_labels` is enabled.
+//!
+//! # Framing
+//!
+//! Over UDP every datagram is one message. Over TCP and TLS messages are
+//! either octet-counted or newline-delimited (RFC 6587), and a connection may
+//! mix both.
+//!
+//! # Usage Example
+//!
+//! ```rust,ignore
+//! use drasi_source_syslog::{SyslogProtocol, SyslogSource};
+//!
+//! let source = SyslogSource::builder("syslog")
+//!     .with_port(1514)
+//!     .with_protocol(SyslogProtocol::Tcp)
+//!     .with_hostname_labels(true)
+//!     .build()?;
+//!
+//! drasi.add_source(source).await?;
+//! ```
+
+mod config;
+mod conversion;
+pub mod descriptor;
+mod framing;
+mod parser;
+mod syslog;
+mod tls;
+
+#[cfg(test)]
+mod tests;
+
+pub use config::{SyslogProtocol, SyslogSourceConfig};
+pub use syslog::{SyslogSource, SyslogSourceBuilder};
+
+/// Dynamic plugin entry point.
+#[cfg(feature = "dynamic-plugin")]
+drasi_plugin_sdk::export_plugin!(
+    plugin_id = "syslog-source",
+    core_version = env!("CARGO_PKG_VERSION"),
+    lib_version = env!("CARGO_PKG_VERSION"),
+    plugin_version = env!("CARGO_PKG_VERSION"),
+    source_descriptors = [descriptor::SyslogSourceDescriptor],
+    reaction_descriptors = [],
+    bootstrap_descriptors = [],
+);
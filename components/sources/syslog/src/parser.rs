@@ -0,0 +1,286 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Parsing of RFC 5424 and RFC 3164 syslog messages.
+//!
+//! Parsing is lenient: anything that is not a well-formed RFC 5424 message is
+//! read as RFC 3164, and fields that cannot be found are left empty. A message
+//! without a priority gets `user.notice`, as RFC 3164 prescribes.
+
+/// Facility names by code.
+const FACILITIES: [&str; 24] = [
+    "kern",
+    "user",
+    "mail",
+    "daemon",
+    "auth",
+    "syslog",
+    "lpr",
+    "news",
+    "uucp",
+    "cron",
+    "authpriv",
+    "ftp",
+    "ntp",
+    "security",
+    "console",
+    "solaris-cron",
+    "local0",
+    "local1",
+    "local2",
+    "local3",
+    "local4",
+    "local5",
+    "local6",
+    "local7",
+];
+
+/// Severity names by code.
+const SEVERITIES: [&str; 8] = [
+    "emerg", "alert", "crit", "err", "warning", "notice", "info", "debug",
+];
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Priority of messages that have none: facility `user`, severity `notice`.
+const DEFAULT_PRIORITY: u8 = 13;
+
+/// Wire format a message was parsed as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SyslogFormat {
+    Rfc5424,
+    Rfc3164,
+}
+
+impl SyslogFormat {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Rfc5424 => "rfc5424",
+            Self::Rfc3164 => "rfc3164",
+        }
+    }
+}
+
+/// One RFC 5424 structured data element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct StructuredData {
+    pub id: String,
+    pub params: Vec<(String, String)>,
+}
+
+/// A parsed syslog message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SyslogMessage {
+    pub format: SyslogFormat,
+    pub facility: u8,
+    pub severity: u8,
+    pub timestamp: Option<String>,
+    pub hostname: Option<String>,
+    pub app_name: Option<String>,
+    pub proc_id: Option<String>,
+    pub msg_id: Option<String>,
+    pub structured_data: Vec<StructuredData>,
+    pub message: String,
+}
+
+impl SyslogMessage {
+    pub(crate) fn facility_name(&self) -> &'static str {
+        FACILITIES
+            .get(usize::from(self.facility))
+            .copied()
+            .unwrap_or("unknown")
+    }
+
+    pub(crate) fn severity_name(&self) -> &'static str {
+        SEVERITIES
+            .get(usize::from(self.severity))
+            .copied()
+            .unwrap_or("unknown")
+    }
+}
+
+/// Parse a syslog message.
+pub(crate) fn parse(input: &str) -> SyslogMessage {
+    let input = input.trim_end_matches(['\r', '\n', '\0']);
+    let (priority, rest) = parse_priority(input).unwrap_or((DEFAULT_PRIORITY, input));
+    let (facility, severity) = (priority >> 3, priority & 7);
+
+    rest.strip_prefix("1 ")
+        .and_then(|rest| parse_rfc5424(rest, facility, severity))
+        .unwrap_or_else(|| parse_rfc3164(rest, facility, severity))
+}
+
+/// Split `<PRI>` off a message.
+fn parse_priority(input: &str) -> Option<(u8, &str)> {
+    let rest = input.strip_prefix('<')?;
+    let end = rest.find('>')?;
+    let digits = &rest[..end];
+    if digits.is_empty() || digits.len() > 3 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let priority: u8 = digits.parse().ok()?;
+    (priority <= 191).then_some((priority, &rest[end + 1..]))
+}
+
+fn nil(value: &str) -> Option<String> {
+    (value != "-").then(|| value.to_string())
+}
+
+fn parse_rfc5424(rest: &str, facility: u8, severity: u8) -> Option<SyslogMessage> {
+    let mut fields = rest.splitn(6, ' ');
+    let timestamp = fields.next()?;
+    let hostname = fields.next()?;
+    let app_name = fields.next()?;
+    let proc_id = fields.next()?;
+    let msg_id = fields.next()?;
+    let (structured_data, message) = parse_structured_data(fields.next()?)?;
+
+    let message = message.strip_prefix(' ').unwrap_or(message);
+    let message = message.strip_prefix('\u{feff}').unwrap_or(message);
+
+    Some(SyslogMessage {
+        format: SyslogFormat::Rfc5424,
+        facility,
+        severity,
+        timestamp: nil(timestamp),
+        hostname: nil(hostname),
+        app_name: nil(app_name),
+        proc_id: nil(proc_id),
+        msg_id: nil(msg_id),
+        structured_data,
+        message: message.to_string(),
+    })
+}
+
+/// Parse the structured data of an RFC 5424 message. Returns the elements and
+/// the text after them.
+fn parse_structured_data(input: &str) -> Option<(Vec<StructuredData>, &str)> {
+    if let Some(rest) = input.strip_prefix('-') {
+        return Some((Vec::new(), rest));
+    }
+
+    let mut elements = Vec::new();
+    let mut rest = input;
+    while let Some(element) = rest.strip_prefix('[') {
+        let (parsed, after) = parse_sd_element(element)?;
+        elements.push(parsed);
+        rest = after;
+    }
+
+    (!elements.is_empty()).then_some((elements, rest))
+}
+
+/// Parse one `[id name="value" ...]` element, starting after the `[`.
+fn parse_sd_element(input: &str) -> Option<(StructuredData, &str)> {
+    let id_end = input.find([' ', ']'])?;
+    let id = input[..id_end].to_string();
+    let mut rest = &input[id_end..];
+    let mut params = Vec::new();
+
+    loop {
+        rest = rest.trim_start_matches(' ');
+        if let Some(after) = rest.strip_prefix(']') {
+            return Some((StructuredData { id, params }, after));
+        }
+
+        let name_end = rest.find('=')?;
+        let name = rest[..name_end].to_string();
+        rest = rest[name_end + 1..].strip_prefix('"')?;
+
+        // Values escape '"', '\' and ']' with a backslash
+        let mut value = String::new();
+        let mut chars = rest.char_indices();
+        let end = loop {
+            match chars.next()? {
+                (_, '\\') => match chars.next()? {
+                    (_, c @ ('"' | '\\' | ']')) => value.push(c),
+                    (_, c) => {
+                        value.push('\\');
+                        value.push(c);
+                    }
+                },
+                (i, '"') => break i,
+                (_, c) => value.push(c),
+            }
+        };
+        params.push((name, value));
+        rest = &rest[end + 1..];
+    }
+}
+
+/// Whether `input` starts with an RFC 3164 `Mmm dd hh:mm:ss` timestamp.
+fn is_rfc3164_timestamp(input: &str) -> bool {
+    let Some(timestamp) = input.get(..15) else {
+        return false;
+    };
+    let bytes = timestamp.as_bytes();
+    timestamp
+        .get(..3)
+        .is_some_and(|month| MONTHS.contains(&month))
+        && bytes[3] == b' '
+        && bytes[6] == b' '
+        && bytes[9] == b':'
+        && bytes[12] == b':'
+        && [4, 5, 7, 8, 10, 11, 13, 14]
+            .iter()
+            .all(|&i| bytes[i].is_ascii_digit() || (i == 4 && bytes[i] == b' '))
+}
+
+fn parse_rfc3164(rest: &str, facility: u8, severity: u8) -> SyslogMessage {
+    let mut message = SyslogMessage {
+        format: SyslogFormat::Rfc3164,
+        facility,
+        severity,
+        timestamp: None,
+        hostname: None,
+        app_name: None,
+        proc_id: None,
+        msg_id: None,
+        structured_data: Vec::new(),
+        message: String::new(),
+    };
+
+    let mut rest = rest;
+    if is_rfc3164_timestamp(rest) {
+        message.timestamp = Some(rest[..15].to_string());
+        rest = rest[15..].trim_start_matches(' ');
+
+        // The hostname follows the timestamp, unless the next word is the tag
+        if let Some((hostname, after)) = rest.split_once(' ') {
+            if !hostname.ends_with(':') && !hostname.ends_with(']') {
+                message.hostname = Some(hostname.to_string());
+                rest = after;
+            }
+        }
+    }
+
+    // A tag is a single word ending in ':' and may carry a "[pid]"
+    if let Some((tag, after)) = rest.split_once(": ") {
+        if !tag.is_empty() && !tag.contains(' ') {
+            match tag.split_once('[') {
+                Some((app_name, pid)) if pid.ends_with(']') => {
+                    message.app_name = Some(app_name.to_string());
+                    message.proc_id = Some(pid.trim_end_matches(']').to_string());
+                }
+                _ => message.app_name = Some(tag.to_string()),
+            }
+            rest = after;
+        }
+    }
+
+    message.message = rest.to_string();
+    message
+}
@@ -0,0 +1,567 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Syslog source implementation and builder.
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use log::{debug, error, info, warn};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio::net::{TcpListener, UdpSocket};
+use tokio::sync::RwLock;
+use tokio::task::JoinSet;
+use tokio_rustls::TlsAcceptor;
+
+use drasi_core::models::SourceChange;
+use drasi_lib::channels::*;
+use drasi_lib::component_graph::ComponentStatusHandle;
+use drasi_lib::managers::{log_component_start, log_component_stop};
+use drasi_lib::sources::base::{SourceBase, SourceBaseParams};
+use drasi_lib::Source;
+use tracing::Instrument;
+
+use crate::config::{SyslogProtocol, SyslogSourceConfig};
+use crate::conversion::message_to_source_change;
+use crate::framing::Framer;
+use crate::parser::parse;
+use crate::tls::load_acceptor;
+
+type Dispatchers = Arc<RwLock<Vec<Box<dyn ChangeDispatcher<SourceEventWrapper> + Send + Sync>>>>;
+
+/// Size of the read buffer of stream connections.
+const READ_BUFFER_SIZE: usize = 8192;
+
+/// Source that receives syslog messages and inserts one node per message.
+///
+/// The source listens on UDP, TCP or TLS. Each message is parsed as RFC 5424
+/// or RFC 3164 and inserted as a node labeled with the configured label and,
+/// optionally, its facility and hostname.
+pub struct SyslogSource {
+    /// Base source implementation providing dispatchers, status tracking, and lifecycle management.
+    base: SourceBase,
+    /// Syslog configuration.
+    config: SyslogSourceConfig,
+}
+
+/// State shared by the listener and its connections.
+struct ListenContext {
+    source_id: String,
+    config: SyslogSourceConfig,
+    dispatchers: Dispatchers,
+    /// Counter making element IDs unique within a millisecond.
+    sequence: AtomicU64,
+}
+
+/// A bound socket of the configured transport.
+enum Listener {
+    Udp(UdpSocket),
+    Stream(TcpListener, Option<TlsAcceptor>),
+}
+
+impl SyslogSource {
+    /// Create a builder for a syslog source.
+    pub fn builder(id: impl Into<String>) -> SyslogSourceBuilder {
+        SyslogSourceBuilder::new(id)
+    }
+
+    /// Create a new syslog source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid.
+    pub fn new(id: impl Into<String>, config: SyslogSourceConfig) -> Result<Self> {
+        SyslogSourceBuilder::new(id).with_config(config).build()
+    }
+
+    async fn dispatch(
+        source_id: &str,
+        dispatchers: &Dispatchers,
+        change: SourceChange,
+    ) -> Result<()> {
+        let mut profiling = drasi_lib::profiling::ProfilingMetadata::new();
+        profiling.source_send_ns = Some(drasi_lib::profiling::timestamp_ns());
+
+        let wrapper = SourceEventWrapper::with_profiling(
+            source_id.to_string(),
+            SourceEvent::Change(change),
+            chrono::Utc::now(),
+            profiling,
+        );
+
+        SourceBase::dispatch_from_task(dispatchers.clone(), wrapper, source_id).await
+    }
+
+    async fn handle_frame(ctx: &ListenContext, frame: &str, remote_addr: &str) {
+        let message = parse(frame);
+        let element_id = format!(
+            "{}-{}",
+            chrono::Utc::now().timestamp_millis(),
+            ctx.sequence.fetch_add(1, Ordering::Relaxed)
+        );
+        let change = message_to_source_change(
+            &ctx.source_id,
+            &element_id,
+            &message,
+            remote_addr,
+            &ctx.config,
+        );
+
+        if let Err(e) = Self::dispatch(&ctx.source_id, &ctx.dispatchers, change).await {
+            warn!(
+                "[{}] Failed to dispatch syslog message from {remote_addr}: {e}",
+                ctx.source_id
+            );
+        }
+    }
+
+    async fn bind(config: &SyslogSourceConfig) -> Result<Listener> {
+        let addr = format!("{}:{}", config.host, config.port);
+        let bind_error = |e: std::io::Error| anyhow!("Failed to bind {addr}: {e}");
+
+        match config.protocol {
+            SyslogProtocol::Udp => Ok(Listener::Udp(
+                UdpSocket::bind(&addr).await.map_err(bind_error)?,
+            )),
+            SyslogProtocol::Tcp => Ok(Listener::Stream(
+                TcpListener::bind(&addr).await.map_err(bind_error)?,
+                None,
+            )),
+            SyslogProtocol::Tls => {
+                let cert_path = config
+                    .tls_cert_path
+                    .as_deref()
+                    .ok_or_else(|| anyhow!("tls_cert_path is required for TLS"))?;
+                let key_path = config
+                    .tls_key_path
+                    .as_deref()
+                    .ok_or_else(|| anyhow!("tls_key_path is required for TLS"))?;
+                let acceptor = load_acceptor(cert_path, key_path)?;
+                Ok(Listener::Stream(
+                    TcpListener::bind(&addr).await.map_err(bind_error)?,
+                    Some(acceptor),
+                ))
+            }
+        }
+    }
+
+    async fn run_udp(ctx: Arc<ListenContext>, socket: UdpSocket) {
+        // Datagrams longer than the buffer are truncated by the socket
+        let mut buffer = vec![0u8; ctx.config.max_message_size];
+        loop {
+            match socket.recv_from(&mut buffer).await {
+                Ok((len, peer)) => {
+                    let frame = String::from_utf8_lossy(&buffer[..len]);
+                    Self::handle_frame(&ctx, &frame, &peer.to_string()).await;
+                }
+                Err(e) => warn!("[{}] Failed to receive datagram: {e}", ctx.source_id),
+            }
+        }
+    }
+
+    async fn read_stream<S: AsyncRead + Unpin>(
+        ctx: Arc<ListenContext>,
+        mut stream: S,
+        peer: String,
+    ) {
+        debug!("[{}] Syslog connection from {peer}", ctx.source_id);
+        let mut framer = Framer::new(ctx.config.max_message_size);
+        let mut buffer = vec![0u8; READ_BUFFER_SIZE];
+
+        loop {
+            match stream.read(&mut buffer).await {
+                Ok(0) => break,
+                Ok(len) => {
+                    framer.push(&buffer[..len]);
+                    loop {
+                        match framer.next_frame() {
+                            Ok(Some(frame)) => Self::handle_frame(&ctx, &frame, &peer).await,
+                            Ok(None) => break,
+                            Err(e) => {
+                                warn!(
+                                    "[{}] Closing syslog connection from {peer}: {e}",
+                                    ctx.source_id
+                                );
+                                return;
+                            }
+                        }
+                    }
+                }
+                Err(e) => {
+                    debug!(
+                        "[{}] Syslog connection from {peer} failed: {e}",
+                        ctx.source_id
+                    );
+                    break;
+                }
+            }
+        }
+
+        if let Some(frame) = framer.finish() {
+            Self::handle_frame(&ctx, &frame, &peer).await;
+        }
+    }
+
+    async fn run_stream(
+        ctx: Arc<ListenContext>,
+        listener: TcpListener,
+        acceptor: Option<TlsAcceptor>,
+    ) {
+        // Dropping the join set on abort closes every connection
+        let mut connections = JoinSet::new();
+
+        loop {
+            tokio::select! {
+                accepted = listener.accept() => {
+                    let (stream, peer) = match accepted {
+                        Ok(accepted) => accepted,
+                        Err(e) => {
+                            warn!("[{}] Failed to accept connection: {e}", ctx.source_id);
+                            continue;
+                        }
+                    };
+                    let ctx = ctx.clone();
+                    let peer = peer.to_string();
+
+                    match acceptor.clone() {
+                        Some(acceptor) => connections.spawn(
+                            async move {
+                                match acceptor.accept(stream).await {
+                                    Ok(stream) => Self::read_stream(ctx, stream, peer).await,
+                                    Err(e) => warn!(
+                                        "[{}] TLS handshake with {peer} failed: {e}",
+                                        ctx.source_id
+                                    ),
+                                }
+                            }
+                            .in_current_span(),
+                        ),
+                        None => connections.spawn(Self::read_stream(ctx, stream, peer).in_current_span()),
+                    };
+                }
+                Some(_) = connections.join_next() => {}
+            }
+        }
+    }
+
+    async fn run(ctx: Arc<ListenContext>, status_handle: ComponentStatusHandle) {
+        let listener = match Self::bind(&ctx.config).await {
+            Ok(listener) => listener,
+            Err(e) => {
+                error!("[{}] Failed to start syslog listener: {e}", ctx.source_id);
+                status_handle
+                    .set_status(
+                        ComponentStatus::Error,
+                        Some(format!("Failed to start syslog listener: {e}")),
+                    )
+                    .await;
+                return;
+            }
+        };
+
+        info!(
+            "[{}] Listening for syslog over {:?} on {}:{}",
+            ctx.source_id, ctx.config.protocol, ctx.config.host, ctx.config.port
+        );
+        status_handle
+            .set_status(
+                ComponentStatus::Running,
+                Some("Syslog source running".to_string()),
+            )
+            .await;
+
+        match listener {
+            Listener::Udp(socket) => Self::run_udp(ctx, socket).await,
+            Listener::Stream(listener, acceptor) => Self::run_stream(ctx, listener, acceptor).await,
+        }
+    }
+}
+
+#[async_trait]
+impl Source for SyslogSource {
+    fn id(&self) -> &str {
+        &self.base.id
+    }
+
+    fn type_name(&self) -> &str {
+        "syslog"
+    }
+
+    fn properties(&self) -> HashMap<String, serde_json::Value> {
+        use crate::descriptor::{SyslogProtocolDto, SyslogSourceConfigDto};
+        use drasi_plugin_sdk::ConfigValue;
+
+        let dto = SyslogSourceConfigDto {
+            host: ConfigValue::Static(self.config.host.clone()),
+            port: ConfigValue::Static(self.config.port),
+            protocol: match self.config.protocol {
+                SyslogProtocol::Udp => SyslogProtocolDto::Udp,
+                SyslogProtocol::Tcp => SyslogProtocolDto::Tcp,
+                SyslogProtocol::Tls => SyslogProtocolDto::Tls,
+            },
+            tls_cert_path: self.config.tls_cert_path.clone().map(ConfigValue::Static),
+            tls_key_path: self.config.tls_key_path.clone().map(ConfigValue::Static),
+            max_message_size: ConfigValue::Static(self.config.max_message_size),
+            label: self.config.label.clone(),
+            facility_labels: self.config.facility_labels,
+            hostname_labels: self.config.hostname_labels,
+        };
+
+        match serde_json::to_value(&dto) {
+            Ok(serde_json::Value::Object(map)) => map.into_iter().collect(),
+            _ => HashMap::new(),
+        }
+    }
+
+    fn auto_start(&self) -> bool {
+        self.base.get_auto_start()
+    }
+
+    async fn start(&self) -> Result<()> {
+        log_component_start("Syslog Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Starting,
+                Some("Starting syslog source".to_string()),
+            )
+            .await;
+
+        let ctx = Arc::new(ListenContext {
+            source_id: self.base.id.clone(),
+            config: self.config.clone(),
+            dispatchers: self.base.dispatchers.clone(),
+            sequence: AtomicU64::new(0),
+        });
+
+        let instance_id = self
+            .base
+            .context()
+            .await
+            .map(|c| c.instance_id)
+            .unwrap_or_default();
+
+        let span = tracing::info_span!(
+            "syslog_source_listener",
+            instance_id = %instance_id,
+            component_id = %self.base.id,
+            component_type = "source"
+        );
+        let task = tokio::spawn(Self::run(ctx, self.base.status_handle()).instrument(span));
+
+        *self.base.task_handle.write().await = Some(task);
+
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        log_component_stop("Syslog Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopping,
+                Some("Stopping syslog source".to_string()),
+            )
+            .await;
+
+        if let Some(handle) = self.base.task_handle.write().await.take() {
+            handle.abort();
+            let _ = handle.await;
+        }
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopped,
+                Some("Syslog source stopped".to_string()),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn status(&self) -> ComponentStatus {
+        self.base.get_status().await
+    }
+
+    async fn subscribe(
+        &self,
+        settings: drasi_lib::config::SourceSubscriptionSettings,
+    ) -> Result<SubscriptionResponse> {
+        self.base
+            .subscribe_with_bootstrap(&settings, "Syslog")
+            .await
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    async fn initialize(&self, context: drasi_lib::context::SourceRuntimeContext) {
+        self.base.initialize(context).await;
+    }
+
+    async fn set_bootstrap_provider(
+        &self,
+        provider: Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>,
+    ) {
+        self.base.set_bootstrap_provider(provider).await;
+    }
+}
+
+/// Builder for [`SyslogSource`] instances.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use drasi_source_syslog::{SyslogProtocol, SyslogSource};
+///
+/// let source = SyslogSource::builder("syslog")
+///     .with_port(1514)
+///     .with_protocol(SyslogProtocol::Tcp)
+///     .with_hostname_labels(true)
+///     .build()?;
+/// ```
+pub struct SyslogSourceBuilder {
+    id: String,
+    config: SyslogSourceConfig,
+    dispatch_mode: Option<DispatchMode>,
+    dispatch_buffer_capacity: Option<usize>,
+    bootstrap_provider: Option<Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>>,
+    auto_start: bool,
+}
+
+impl SyslogSourceBuilder {
+    /// Create a new builder with the given ID and default values.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            config: SyslogSourceConfig::default(),
+            dispatch_mode: None,
+            dispatch_buffer_capacity: None,
+            bootstrap_provider: None,
+            auto_start: true,
+        }
+    }
+
+    /// Set the address to listen on.
+    pub fn with_host(mut self, host: impl Into<String>) -> Self {
+        self.config.host = host.into();
+        self
+    }
+
+    /// Set the port to listen on.
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.config.port = port;
+        self
+    }
+
+    /// Set the transport to accept.
+    pub fn with_protocol(mut self, protocol: SyslogProtocol) -> Self {
+        self.config.protocol = protocol;
+        self
+    }
+
+    /// Listen over TLS with the given PEM certificate chain and private key.
+    pub fn with_tls(mut self, cert_path: impl Into<String>, key_path: impl Into<String>) -> Self {
+        self.config.protocol = SyslogProtocol::Tls;
+        self.config.tls_cert_path = Some(cert_path.into());
+        self.config.tls_key_path = Some(key_path.into());
+        self
+    }
+
+    /// Set the largest accepted message in bytes.
+    pub fn with_max_message_size(mut self, size: usize) -> Self {
+        self.config.max_message_size = size;
+        self
+    }
+
+    /// Set the label of every message node.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.config.label = label.into();
+        self
+    }
+
+    /// Set whether the facility name is added as a label.
+    pub fn with_facility_labels(mut self, enabled: bool) -> Self {
+        self.config.facility_labels = enabled;
+        self
+    }
+
+    /// Set whether the message hostname is added as a label.
+    pub fn with_hostname_labels(mut self, enabled: bool) -> Self {
+        self.config.hostname_labels = enabled;
+        self
+    }
+
+    /// Set the dispatch mode for this source.
+    pub fn with_dispatch_mode(mut self, mode: DispatchMode) -> Self {
+        self.dispatch_mode = Some(mode);
+        self
+    }
+
+    /// Set the dispatch buffer capacity.
+    pub fn with_dispatch_buffer_capacity(mut self, capacity: usize) -> Self {
+        self.dispatch_buffer_capacity = Some(capacity);
+        self
+    }
+
+    /// Set the bootstrap provider for initial data delivery.
+    pub fn with_bootstrap_provider(
+        mut self,
+        provider: impl drasi_lib::bootstrap::BootstrapProvider + 'static,
+    ) -> Self {
+        self.bootstrap_provider = Some(Box::new(provider));
+        self
+    }
+
+    /// Set whether this source should auto-start when DrasiLib starts.
+    pub fn with_auto_start(mut self, auto_start: bool) -> Self {
+        self.auto_start = auto_start;
+        self
+    }
+
+    /// Set the full configuration at once.
+    pub fn with_config(mut self, config: SyslogSourceConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Build the syslog source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid or the base source
+    /// cannot be initialized.
+    pub fn build(self) -> Result<SyslogSource> {
+        self.config.validate()?;
+
+        let mut params = SourceBaseParams::new(&self.id).with_auto_start(self.auto_start);
+        if let Some(mode) = self.dispatch_mode {
+            params = params.with_dispatch_mode(mode);
+        }
+        if let Some(capacity) = self.dispatch_buffer_capacity {
+            params = params.with_dispatch_buffer_capacity(capacity);
+        }
+        if let Some(provider) = self.bootstrap_provider {
+            params = params.with_bootstrap_provider(provider);
+        }
+
+        Ok(SyslogSource {
+            base: SourceBase::new(params)?,
+            config: self.config,
+        })
+    }
+}
@@ -0,0 +1,361 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for the Syslog source plugin.
+
+use super::*;
+use crate::conversion::{labels_for, message_properties, message_to_source_change};
+use crate::framing::Framer;
+use crate::parser::{parse, StructuredData, SyslogFormat};
+use drasi_core::models::{Element, ElementValue, SourceChange};
+use drasi_lib::Source;
+use drasi_plugin_sdk::prelude::SourcePluginDescriptor;
+use serde_json::json;
+
+const RFC5424_MESSAGE: &str = "<165>1 2003-10-11T22:14:15.003Z mymachine.example.com evntslog - ID47 [exampleSDID@32473 iut=\"3\" eventSource=\"Application\" eventID=\"1011\"][examplePriority@32473 class=\"high\"] \u{feff}An application event log entry...";
+
+const RFC3164_MESSAGE: &str =
+    "<34>Oct 11 22:14:15 mymachine su[1234]: 'su root' failed for lonvick on /dev/pts/8";
+
+mod builder {
+    use super::*;
+
+    #[test]
+    fn test_builder_with_valid_config() {
+        let source = SyslogSource::builder("test-source")
+            .with_host("127.0.0.1")
+            .with_port(1514)
+            .with_protocol(SyslogProtocol::Tcp)
+            .with_label("LogLine")
+            .with_hostname_labels(true)
+            .build()
+            .unwrap();
+
+        assert_eq!(source.id(), "test-source");
+        assert_eq!(source.type_name(), "syslog");
+        let props = source.properties();
+        assert_eq!(props.get("host"), Some(&json!("127.0.0.1")));
+        assert_eq!(props.get("port"), Some(&json!(1514)));
+        assert_eq!(props.get("protocol"), Some(&json!("tcp")));
+        assert_eq!(props.get("label"), Some(&json!("LogLine")));
+        assert_eq!(props.get("hostnameLabels"), Some(&json!(true)));
+    }
+
+    #[test]
+    fn test_builder_with_tls() {
+        let source = SyslogSource::builder("test-source")
+            .with_tls("/etc/drasi/syslog.crt", "/etc/drasi/syslog.key")
+            .build()
+            .unwrap();
+
+        let props = source.properties();
+        assert_eq!(props.get("protocol"), Some(&json!("tls")));
+        assert_eq!(
+            props.get("tlsCertPath"),
+            Some(&json!("/etc/drasi/syslog.crt"))
+        );
+    }
+
+    #[test]
+    fn test_config_rejects_invalid_values() {
+        let tls_without_key = SyslogSourceConfig {
+            protocol: SyslogProtocol::Tls,
+            tls_cert_path: Some("/etc/drasi/syslog.crt".to_string()),
+            ..Default::default()
+        };
+        assert!(tls_without_key.validate().is_err());
+
+        let empty_label = SyslogSourceConfig {
+            label: String::new(),
+            ..Default::default()
+        };
+        assert!(empty_label.validate().is_err());
+
+        let zero_size = SyslogSourceConfig {
+            max_message_size: 0,
+            ..Default::default()
+        };
+        assert!(zero_size.validate().is_err());
+    }
+
+    #[test]
+    fn test_config_deserialization_defaults() {
+        let config: SyslogSourceConfig = serde_json::from_value(json!({})).unwrap();
+        assert_eq!(config, SyslogSourceConfig::default());
+        assert_eq!(config.port, 514);
+        assert_eq!(config.protocol, SyslogProtocol::Udp);
+        assert!(config.facility_labels);
+        assert!(!config.hostname_labels);
+    }
+}
+
+mod parser {
+    use super::*;
+
+    #[test]
+    fn test_parse_rfc5424() {
+        let message = parse(RFC5424_MESSAGE);
+
+        assert_eq!(message.format, SyslogFormat::Rfc5424);
+        assert_eq!(message.facility, 20);
+        assert_eq!(message.facility_name(), "local4");
+        assert_eq!(message.severity, 5);
+        assert_eq!(message.severity_name(), "notice");
+        assert_eq!(
+            message.timestamp.as_deref(),
+            Some("2003-10-11T22:14:15.003Z")
+        );
+        assert_eq!(message.hostname.as_deref(), Some("mymachine.example.com"));
+        assert_eq!(message.app_name.as_deref(), Some("evntslog"));
+        assert_eq!(message.proc_id, None);
+        assert_eq!(message.msg_id.as_deref(), Some("ID47"));
+        assert_eq!(message.message, "An application event log entry...");
+        assert_eq!(message.structured_data.len(), 2);
+        assert_eq!(
+            message.structured_data[1],
+            StructuredData {
+                id: "examplePriority@32473".to_string(),
+                params: vec![("class".to_string(), "high".to_string())],
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_rfc5424_escaped_values_and_nil_fields() {
+        let message =
+            parse(r#"<14>1 - - - - - [meta path="C:\\logs" quote="say \"hi\"" bracket="a\]b"]"#);
+
+        assert_eq!(message.format, SyslogFormat::Rfc5424);
+        assert_eq!(message.timestamp, None);
+        assert_eq!(message.hostname, None);
+        assert_eq!(message.message, "");
+        assert_eq!(
+            message.structured_data[0].params,
+            vec![
+                ("path".to_string(), r"C:\logs".to_string()),
+                ("quote".to_string(), r#"say "hi""#.to_string()),
+                ("bracket".to_string(), "a]b".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_rfc3164() {
+        let message = parse(RFC3164_MESSAGE);
+
+        assert_eq!(message.format, SyslogFormat::Rfc3164);
+        assert_eq!(message.facility_name(), "auth");
+        assert_eq!(message.severity_name(), "crit");
+        assert_eq!(message.timestamp.as_deref(), Some("Oct 11 22:14:15"));
+        assert_eq!(message.hostname.as_deref(), Some("mymachine"));
+        assert_eq!(message.app_name.as_deref(), Some("su"));
+        assert_eq!(message.proc_id.as_deref(), Some("1234"));
+        assert_eq!(
+            message.message,
+            "'su root' failed for lonvick on /dev/pts/8"
+        );
+    }
+
+    #[test]
+    fn test_parse_rfc3164_without_hostname() {
+        let message = parse("<13>Feb  5 17:32:18 cron: job finished\n");
+
+        assert_eq!(message.timestamp.as_deref(), Some("Feb  5 17:32:18"));
+        assert_eq!(message.hostname, None);
+        assert_eq!(message.app_name.as_deref(), Some("cron"));
+        assert_eq!(message.message, "job finished");
+    }
+
+    #[test]
+    fn test_parse_without_priority() {
+        let message = parse("just some text");
+
+        assert_eq!(message.format, SyslogFormat::Rfc3164);
+        assert_eq!(message.facility_name(), "user");
+        assert_eq!(message.severity_name(), "notice");
+        assert_eq!(message.message, "just some text");
+    }
+
+    #[test]
+    fn test_malformed_rfc5424_falls_back_to_rfc3164() {
+        let message = parse("<14>1 2024-01-01T00:00:00Z host app [unterminated");
+
+        assert_eq!(message.format, SyslogFormat::Rfc3164);
+        assert_eq!(message.facility_name(), "user");
+    }
+}
+
+mod framing {
+    use super::*;
+
+    fn frames(framer: &mut Framer) -> Vec<String> {
+        let mut frames = Vec::new();
+        while let Some(frame) = framer.next_frame().unwrap() {
+            frames.push(frame);
+        }
+        frames
+    }
+
+    #[test]
+    fn test_octet_counted_frames_across_reads() {
+        let mut framer = Framer::new(1024);
+        framer.push(b"12 <13>hello w");
+        assert!(frames(&mut framer).is_empty());
+
+        framer.push(b"o15 <13>second\nline");
+        assert_eq!(
+            frames(&mut framer),
+            vec!["<13>hello wo", "<13>second\nline"]
+        );
+    }
+
+    #[test]
+    fn test_delimited_and_mixed_frames() {
+        let mut framer = Framer::new(1024);
+        framer.push(b"<13>first\r\n\n10 <13>second<13>third\0<13>four");
+
+        assert_eq!(
+            frames(&mut framer),
+            vec!["<13>first", "<13>second", "<13>third"]
+        );
+        assert_eq!(framer.finish().as_deref(), Some("<13>four"));
+        assert_eq!(framer.finish(), None);
+    }
+
+    #[test]
+    fn test_oversized_frames_are_rejected() {
+        let mut framer = Framer::new(8);
+        framer.push(b"100 <13>");
+        assert!(framer.next_frame().is_err());
+
+        let mut framer = Framer::new(8);
+        framer.push(b"<13>no newline yet");
+        assert!(framer.next_frame().is_err());
+
+        let mut framer = Framer::new(8);
+        framer.push(b"12x <13>");
+        assert!(framer.next_frame().is_err());
+    }
+}
+
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn test_labels() {
+        let message = parse(RFC3164_MESSAGE);
+
+        let config = SyslogSourceConfig::default();
+        assert_eq!(labels_for(&message, &config), vec!["SyslogMessage", "auth"]);
+
+        let config = SyslogSourceConfig {
+            facility_labels: false,
+            hostname_labels: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            labels_for(&message, &config),
+            vec!["SyslogMessage", "mymachine"]
+        );
+    }
+
+    #[test]
+    fn test_properties_include_structured_data() {
+        let properties = message_properties(&parse(RFC5424_MESSAGE), "10.0.0.5:51514");
+
+        assert_eq!(properties["format"], json!("rfc5424"));
+        assert_eq!(properties["facility"], json!("local4"));
+        assert_eq!(properties["severity_code"], json!(5));
+        assert_eq!(properties["remote_addr"], json!("10.0.0.5:51514"));
+        assert_eq!(properties["msg_id"], json!("ID47"));
+        assert!(!properties.contains_key("proc_id"));
+        assert_eq!(
+            properties["structured_data"]["exampleSDID@32473"]["eventID"],
+            json!("1011")
+        );
+    }
+
+    #[test]
+    fn test_message_becomes_node_insert() {
+        let change = message_to_source_change(
+            "src",
+            "1700000000000-0",
+            &parse(RFC3164_MESSAGE),
+            "10.0.0.5:514",
+            &SyslogSourceConfig::default(),
+        );
+
+        let SourceChange::Insert {
+            element:
+                Element::Node {
+                    metadata,
+                    properties,
+                },
+        } = change
+        else {
+            panic!("expected node insert");
+        };
+        assert_eq!(metadata.reference.source_id.as_ref(), "src");
+        assert_eq!(metadata.reference.element_id.as_ref(), "1700000000000-0");
+        assert_eq!(metadata.labels[1].as_ref(), "auth");
+        assert_eq!(
+            properties.get("app_name"),
+            Some(&ElementValue::String("su".into()))
+        );
+    }
+}
+
+mod descriptor {
+    use super::*;
+    use crate::descriptor::SyslogSourceDescriptor;
+
+    #[tokio::test]
+    async fn test_descriptor_creates_source() {
+        let descriptor = SyslogSourceDescriptor;
+        assert_eq!(descriptor.kind(), "syslog");
+
+        let source = descriptor
+            .create_source(
+                "syslog-1",
+                &json!({
+                    "port": 1514,
+                    "protocol": "tcp",
+                    "facilityLabels": false
+                }),
+                false,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(source.id(), "syslog-1");
+        assert!(!source.auto_start());
+        let props = source.properties();
+        assert_eq!(props.get("port"), Some(&json!(1514)));
+        assert_eq!(props.get("protocol"), Some(&json!("tcp")));
+        assert_eq!(props.get("facilityLabels"), Some(&json!(false)));
+    }
+
+    #[tokio::test]
+    async fn test_descriptor_rejects_unknown_fields() {
+        let result = SyslogSourceDescriptor
+            .create_source("syslog-1", &json!({"port": 1514, "bogus": 1}), true)
+            .await;
+        assert!(result.is_err());
+
+        let tls_without_paths = SyslogSourceDescriptor
+            .create_source("syslog-1", &json!({"protocol": "tls"}), true)
+            .await;
+        assert!(tls_without_paths.is_err());
+    }
+}
@@ -0,0 +1,56 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! TLS acceptor setup for syslog over TLS (RFC 5425).
+
+use anyhow::{anyhow, Result};
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::Arc;
+use tokio_rustls::rustls::crypto::ring::default_provider;
+use tokio_rustls::rustls::ServerConfig;
+use tokio_rustls::TlsAcceptor;
+
+/// Build a TLS acceptor from a PEM certificate chain and private key.
+///
+/// # Errors
+///
+/// Returns an error if either file cannot be read or holds no usable
+/// certificate or key.
+pub(crate) fn load_acceptor(cert_path: &str, key_path: &str) -> Result<TlsAcceptor> {
+    let certs = rustls_pemfile::certs(&mut BufReader::new(
+        File::open(cert_path)
+            .map_err(|e| anyhow!("Failed to open certificate '{cert_path}': {e}"))?,
+    ))
+    .collect::<std::result::Result<Vec<_>, _>>()
+    .map_err(|e| anyhow!("Failed to read certificate '{cert_path}': {e}"))?;
+    if certs.is_empty() {
+        return Err(anyhow!("No certificate found in '{cert_path}'"));
+    }
+
+    let key = rustls_pemfile::private_key(&mut BufReader::new(
+        File::open(key_path).map_err(|e| anyhow!("Failed to open key '{key_path}': {e}"))?,
+    ))
+    .map_err(|e| anyhow!("Failed to read key '{key_path}': {e}"))?
+    .ok_or_else(|| anyhow!("No private key found in '{key_path}'"))?;
+
+    let config = ServerConfig::builder_with_provider(Arc::new(default_provider()))
+        .with_safe_default_protocol_versions()
+        .map_err(|e| anyhow!("Failed to configure TLS: {e}"))?
+        .with_no_client_auth()
+        .with_single_cert(certs, key)
+        .map_err(|e| anyhow!("Invalid certificate or key: {e}"))?;
+
+    Ok(TlsAcceptor::from(Arc::new(config)))
+}
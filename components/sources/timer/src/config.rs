@@ -0,0 +1,183 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration types for the timer source plugin.
+//!
+//! This module defines when the source fires and what the emitted tick
+//! elements look like.
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::schedule::parse_cron;
+
+fn default_interval_ms() -> u64 {
+    1000
+}
+
+fn default_label() -> String {
+    "Tick".to_string()
+}
+
+fn default_element_id() -> String {
+    "tick".to_string()
+}
+
+fn default_emit_on_start() -> bool {
+    true
+}
+
+/// When the source fires.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TimerSchedule {
+    /// Fire every `interval_ms` milliseconds.
+    Interval {
+        #[serde(default = "default_interval_ms")]
+        interval_ms: u64,
+    },
+    /// Fire on a cron expression, evaluated in UTC. Both the five-field form
+    /// (`min hour day month weekday`) and the six or seven-field form with
+    /// seconds (and years) are accepted.
+    Cron { expression: String },
+}
+
+impl Default for TimerSchedule {
+    fn default() -> Self {
+        TimerSchedule::Interval {
+            interval_ms: default_interval_ms(),
+        }
+    }
+}
+
+/// How ticks are represented in the graph.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TickMode {
+    /// A single node, inserted on the first tick and updated on every
+    /// following tick.
+    #[default]
+    Update,
+    /// A new node per tick, with the tick count appended to its ID.
+    Insert,
+}
+
+/// Timer source configuration.
+///
+/// # Example
+///
+/// ```rust
+/// use drasi_source_timer::{TimerSchedule, TimerSourceConfig};
+///
+/// let config = TimerSourceConfig {
+///     schedule: TimerSchedule::Cron {
+///         expression: "*/5 * * * *".to_string(),
+///     },
+///     label: "Heartbeat".to_string(),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TimerSourceConfig {
+    /// When the source fires.
+    ///
+    /// **Default**: an interval of 1000 ms
+    #[serde(default)]
+    pub schedule: TimerSchedule,
+
+    /// Label of the tick nodes.
+    ///
+    /// **Default**: `Tick`
+    #[serde(default = "default_label")]
+    pub label: String,
+
+    /// Element ID of the tick node, or the ID prefix in `insert` mode.
+    ///
+    /// **Default**: `tick`
+    #[serde(default = "default_element_id")]
+    pub element_id: String,
+
+    /// How ticks are represented.
+    ///
+    /// **Default**: `update`
+    #[serde(default)]
+    pub mode: TickMode,
+
+    /// In `insert` mode, the number of tick nodes to keep. Older ticks are
+    /// deleted. `None` keeps every tick.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retain: Option<usize>,
+
+    /// Static properties added to every tick.
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub properties: Map<String, Value>,
+
+    /// Whether to emit a tick as soon as the source starts, in addition to
+    /// the scheduled ones.
+    ///
+    /// **Default**: `true`
+    #[serde(default = "default_emit_on_start")]
+    pub emit_on_start: bool,
+}
+
+impl Default for TimerSourceConfig {
+    fn default() -> Self {
+        Self {
+            schedule: TimerSchedule::default(),
+            label: default_label(),
+            element_id: default_element_id(),
+            mode: TickMode::default(),
+            retain: None,
+            properties: Map::new(),
+            emit_on_start: default_emit_on_start(),
+        }
+    }
+}
+
+impl TimerSourceConfig {
+    /// Validate the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the interval is 0, the cron expression is invalid,
+    /// the label or element ID is empty, or `retain` is 0.
+    pub fn validate(&self) -> Result<()> {
+        match &self.schedule {
+            TimerSchedule::Interval { interval_ms } if *interval_ms == 0 => {
+                return Err(anyhow!(
+                    "Validation error: interval_ms cannot be 0. \
+                     Please specify a positive interval in milliseconds"
+                ));
+            }
+            TimerSchedule::Interval { .. } => {}
+            TimerSchedule::Cron { expression } => {
+                parse_cron(expression).map_err(|e| anyhow!("Validation error: {e}"))?;
+            }
+        }
+        if self.label.is_empty() {
+            return Err(anyhow!("Validation error: label cannot be empty"));
+        }
+        if self.element_id.is_empty() {
+            return Err(anyhow!("Validation error: element_id cannot be empty"));
+        }
+        if self.retain == Some(0) {
+            return Err(anyhow!(
+                "Validation error: retain must be greater than 0. \
+                 Omit it to keep every tick"
+            ));
+        }
+        Ok(())
+    }
+}
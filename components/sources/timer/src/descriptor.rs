@@ -0,0 +1,177 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Timer source plugin descriptor and configuration DTOs.
+
+use crate::{TickMode, TimerSchedule, TimerSourceBuilder, TimerSourceConfig};
+use drasi_plugin_sdk::prelude::*;
+use utoipa::OpenApi;
+
+fn default_interval_ms() -> ConfigValue<u64> {
+    ConfigValue::Static(1000)
+}
+
+/// Schedule DTO.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[schema(as = source::timer::TimerSchedule)]
+#[serde(tag = "type", rename_all = "camelCase", deny_unknown_fields)]
+pub enum TimerScheduleDto {
+    Interval {
+        #[serde(default = "default_interval_ms", rename = "intervalMs")]
+        interval_ms: ConfigValue<u64>,
+    },
+    Cron {
+        expression: ConfigValue<String>,
+    },
+}
+
+impl Default for TimerScheduleDto {
+    fn default() -> Self {
+        TimerScheduleDto::Interval {
+            interval_ms: default_interval_ms(),
+        }
+    }
+}
+
+/// Tick mode DTO.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::timer::TickMode)]
+#[serde(rename_all = "snake_case")]
+pub enum TickModeDto {
+    #[default]
+    Update,
+    Insert,
+}
+
+impl From<TickModeDto> for TickMode {
+    fn from(dto: TickModeDto) -> Self {
+        match dto {
+            TickModeDto::Update => TickMode::Update,
+            TickModeDto::Insert => TickMode::Insert,
+        }
+    }
+}
+
+impl From<TickMode> for TickModeDto {
+    fn from(mode: TickMode) -> Self {
+        match mode {
+            TickMode::Update => TickModeDto::Update,
+            TickMode::Insert => TickModeDto::Insert,
+        }
+    }
+}
+
+/// Timer source configuration DTO.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+#[schema(as = source::timer::TimerSourceConfig)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct TimerSourceConfigDto {
+    #[serde(default)]
+    #[schema(value_type = source::timer::TimerSchedule)]
+    pub schedule: TimerScheduleDto,
+    #[serde(default = "default_label")]
+    pub label: String,
+    #[serde(default = "default_element_id")]
+    pub element_id: String,
+    #[serde(default)]
+    #[schema(value_type = source::timer::TickMode)]
+    pub mode: TickModeDto,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retain: Option<usize>,
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    #[schema(value_type = Object)]
+    pub properties: serde_json::Map<String, serde_json::Value>,
+    #[serde(default = "default_emit_on_start")]
+    pub emit_on_start: bool,
+}
+
+fn default_label() -> String {
+    "Tick".to_string()
+}
+
+fn default_element_id() -> String {
+    "tick".to_string()
+}
+
+fn default_emit_on_start() -> bool {
+    true
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(TimerSourceConfigDto, TimerScheduleDto, TickModeDto)))]
+struct TimerSourceSchemas;
+
+/// Descriptor for the timer source plugin.
+pub struct TimerSourceDescriptor;
+
+#[async_trait]
+impl SourcePluginDescriptor for TimerSourceDescriptor {
+    fn kind(&self) -> &str {
+        "timer"
+    }
+
+    fn config_version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn config_schema_name(&self) -> &str {
+        "source.timer.TimerSourceConfig"
+    }
+
+    fn config_schema_json(&self) -> String {
+        let api = TimerSourceSchemas::openapi();
+        serde_json::to_string(
+            &api.components
+                .as_ref()
+                .expect("OpenAPI components missing")
+                .schemas,
+        )
+        .expect("Failed to serialize config schema")
+    }
+
+    async fn create_source(
+        &self,
+        id: &str,
+        config_json: &serde_json::Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn drasi_lib::sources::Source>> {
+        let dto: TimerSourceConfigDto = serde_json::from_value(config_json.clone())?;
+        let mapper = DtoMapper::new();
+
+        let schedule = match &dto.schedule {
+            TimerScheduleDto::Interval { interval_ms } => TimerSchedule::Interval {
+                interval_ms: mapper.resolve_typed(interval_ms)?,
+            },
+            TimerScheduleDto::Cron { expression } => TimerSchedule::Cron {
+                expression: mapper.resolve_string(expression)?,
+            },
+        };
+        let config = TimerSourceConfig {
+            schedule,
+            label: dto.label,
+            element_id: dto.element_id,
+            mode: dto.mode.into(),
+            retain: dto.retain,
+            properties: dto.properties,
+            emit_on_start: dto.emit_on_start,
+        };
+
+        let source = TimerSourceBuilder::new(id)
+            .with_config(config)
+            .with_auto_start(auto_start)
+            .build()?;
+
+        Ok(Box::new(source))
+    }
+}
@@ -0,0 +1,74 @@
+#![allow(unexpected_cfgs)]
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Timer Source Plugin for drasi-lib.
+//!
+//! This plugin emits synthetic tick nodes on a fixed interval or a cron
+//! schedule, so time-driven queries and reactions can fire without external
+//! infrastructure, for example to re-evaluate a query every minute or to
+//! raise a daily report.
+//!
+//! # Tick Nodes
+//!
+//! Every tick is a node labelled `Tick` (configurable) with the properties:
+//!
+//! | Property | Description |
+//! |----------|-------------|
+//! | `timestamp` | Fire time in milliseconds since the Unix epoch |
+//! | `time` | Fire time as an RFC 3339 string in UTC |
+//! | `count` | Number of ticks since the source started |
+//!
+//! plus any configured static properties. In `update` mode (the default) a
+//! single node `tick` is inserted on the first tick and updated afterwards;
+//! in `insert` mode each tick is a new node `tick:<count>`, and the oldest
+//! ticks beyond `retain` are deleted.
+//!
+//! # Usage Example
+//!
+//! ```rust,ignore
+//! use drasi_source_timer::TimerSource;
+//!
+//! let clock = TimerSource::builder("clock")
+//!     .with_cron("*/5 * * * *")
+//!     .build()?;
+//!
+//! drasi.add_source(clock).await?;
+//!
+//! // MATCH (t:Tick), (o:Order) WHERE o.due < t.timestamp RETURN o.id
+//! ```
+
+mod config;
+pub mod descriptor;
+mod schedule;
+mod tick;
+mod timer;
+
+#[cfg(test)]
+mod tests;
+
+pub use config::{TickMode, TimerSchedule, TimerSourceConfig};
+pub use timer::{TimerSource, TimerSourceBuilder};
+
+/// Dynamic plugin entry point.
+#[cfg(feature = "dynamic-plugin")]
+drasi_plugin_sdk::export_plugin!(
+    plugin_id = "timer-source",
+    core_version = env!("CARGO_PKG_VERSION"),
+    lib_version = env!("CARGO_PKG_VERSION"),
+    plugin_version = env!("CARGO_PKG_VERSION"),
+    source_descriptors = [descriptor::TimerSourceDescriptor],
+    reaction_descriptors = [],
+    bootstrap_descriptors = [],
+);
@@ -0,0 +1,81 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fire times of the timer source.
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::time::{Instant, Interval, MissedTickBehavior};
+
+use crate::config::TimerSchedule;
+
+/// Parse a cron expression. Five-field expressions, without seconds, fire
+/// at second 0.
+pub(crate) fn parse_cron(expression: &str) -> Result<cron::Schedule> {
+    let fields = expression.split_whitespace().count();
+    let normalized = if fields == 5 {
+        format!("0 {expression}")
+    } else {
+        expression.to_string()
+    };
+    cron::Schedule::from_str(&normalized)
+        .map_err(|e| anyhow!("invalid cron expression '{expression}': {e}"))
+}
+
+/// First fire time of a cron schedule strictly after `after`.
+pub(crate) fn next_fire(schedule: &cron::Schedule, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    schedule.after(&after).next()
+}
+
+/// Waits for the fire times of a [`TimerSchedule`].
+pub(crate) enum Ticker {
+    Interval(Interval),
+    Cron(cron::Schedule),
+}
+
+impl Ticker {
+    /// Create a ticker whose first fire time is one period or cron step from
+    /// now.
+    pub(crate) fn new(schedule: &TimerSchedule) -> Result<Self> {
+        match schedule {
+            TimerSchedule::Interval { interval_ms } => {
+                let period = Duration::from_millis(*interval_ms);
+                let mut interval = tokio::time::interval_at(Instant::now() + period, period);
+                // After a stall, fire once and realign rather than bursting.
+                interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+                Ok(Ticker::Interval(interval))
+            }
+            TimerSchedule::Cron { expression } => Ok(Ticker::Cron(parse_cron(expression)?)),
+        }
+    }
+
+    /// Wait for the next fire time and return it, or `None` if the schedule
+    /// never fires again.
+    pub(crate) async fn tick(&mut self) -> Option<DateTime<Utc>> {
+        match self {
+            Ticker::Interval(interval) => {
+                interval.tick().await;
+                Some(Utc::now())
+            }
+            Ticker::Cron(schedule) => {
+                let next = next_fire(schedule, Utc::now())?;
+                let wait = (next - Utc::now()).to_std().unwrap_or_default();
+                tokio::time::sleep(wait).await;
+                Some(next)
+            }
+        }
+    }
+}
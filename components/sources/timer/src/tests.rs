@@ -0,0 +1,274 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unit tests for the timer source plugin.
+
+use super::*;
+use crate::schedule::{next_fire, parse_cron};
+use crate::tick::TickGenerator;
+use chrono::{DateTime, TimeZone, Utc};
+use drasi_core::models::{Element, ElementValue, SourceChange};
+use drasi_lib::channels::SourceEvent;
+use drasi_lib::Source;
+use drasi_plugin_sdk::prelude::SourcePluginDescriptor;
+use serde_json::json;
+use std::time::Duration;
+
+fn time(seconds: i64) -> DateTime<Utc> {
+    Utc.timestamp_opt(1_700_000_000 + seconds, 0).unwrap()
+}
+
+mod builder {
+    use super::*;
+
+    #[test]
+    fn test_builder_with_valid_config() {
+        let source = TimerSource::builder("clock")
+            .with_cron("0 9 * * MON-FRI")
+            .with_label("WorkdayStart")
+            .with_mode(TickMode::Insert)
+            .with_retain(7)
+            .with_property("zone", json!("UTC"))
+            .build()
+            .unwrap();
+
+        assert_eq!(source.id(), "clock");
+        assert_eq!(source.type_name(), "timer");
+        let props = source.properties();
+        assert_eq!(
+            props.get("schedule"),
+            Some(&json!({"type": "cron", "expression": "0 9 * * MON-FRI"}))
+        );
+        assert_eq!(props.get("label"), Some(&json!("WorkdayStart")));
+        assert_eq!(props.get("mode"), Some(&json!("insert")));
+        assert_eq!(props.get("retain"), Some(&json!(7)));
+        assert_eq!(props.get("properties"), Some(&json!({"zone": "UTC"})));
+    }
+
+    #[test]
+    fn test_builder_rejects_invalid_config() {
+        assert!(TimerSource::builder("clock").build().is_ok());
+        assert!(TimerSource::builder("clock")
+            .with_interval_ms(0)
+            .build()
+            .is_err());
+        assert!(TimerSource::builder("clock")
+            .with_cron("every minute")
+            .build()
+            .is_err());
+        assert!(TimerSource::builder("clock")
+            .with_label("")
+            .build()
+            .is_err());
+        assert!(TimerSource::builder("clock")
+            .with_element_id("")
+            .build()
+            .is_err());
+        assert!(TimerSource::builder("clock")
+            .with_retain(0)
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_config_deserialization_defaults() {
+        let config: TimerSourceConfig = serde_json::from_value(json!({})).unwrap();
+        assert_eq!(
+            config.schedule,
+            TimerSchedule::Interval { interval_ms: 1000 }
+        );
+        assert_eq!(config.label, "Tick");
+        assert_eq!(config.element_id, "tick");
+        assert_eq!(config.mode, TickMode::Update);
+        assert!(config.emit_on_start);
+
+        let config: TimerSourceConfig = serde_json::from_value(json!({
+            "schedule": {"type": "cron", "expression": "0 0 * * *"}
+        }))
+        .unwrap();
+        assert!(matches!(config.schedule, TimerSchedule::Cron { .. }));
+    }
+}
+
+mod schedule {
+    use super::*;
+
+    #[test]
+    fn test_cron_fire_times() {
+        // 2023-11-14T22:13:20Z
+        let start = time(0);
+
+        let every_five_minutes = parse_cron("*/5 * * * *").unwrap();
+        assert_eq!(next_fire(&every_five_minutes, start), Some(time(100)));
+
+        let with_seconds = parse_cron("30 * * * * *").unwrap();
+        assert_eq!(next_fire(&with_seconds, start), Some(time(10)));
+
+        assert!(parse_cron("* * *").is_err());
+        assert!(parse_cron("61 * * * *").is_err());
+    }
+}
+
+mod tick {
+    use super::*;
+
+    fn properties(change: &SourceChange) -> &drasi_core::models::ElementPropertyMap {
+        match change {
+            SourceChange::Insert {
+                element: Element::Node { properties, .. },
+            }
+            | SourceChange::Update {
+                element: Element::Node { properties, .. },
+            } => properties,
+            other => panic!("unexpected change {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_update_mode() {
+        let mut generator = TickGenerator::new(
+            "clock",
+            TimerSourceConfig {
+                properties: json!({"zone": "UTC", "count": -1})
+                    .as_object()
+                    .unwrap()
+                    .clone(),
+                ..Default::default()
+            },
+        );
+
+        let first = generator.tick(time(0));
+        assert_eq!(first.len(), 1);
+        assert!(matches!(first[0], SourceChange::Insert { .. }));
+        assert_eq!(first[0].get_reference().element_id.as_ref(), "tick");
+        let props = properties(&first[0]);
+        assert_eq!(
+            props.get("timestamp"),
+            Some(&ElementValue::Integer(1_700_000_000_000))
+        );
+        assert_eq!(
+            props.get("time"),
+            Some(&ElementValue::String("2023-11-14T22:13:20.000Z".into()))
+        );
+        assert_eq!(props.get("count"), Some(&ElementValue::Integer(1)));
+        assert_eq!(props.get("zone"), Some(&ElementValue::String("UTC".into())));
+
+        let second = generator.tick(time(60));
+        assert!(matches!(second[0], SourceChange::Update { .. }));
+        assert_eq!(second[0].get_reference().element_id.as_ref(), "tick");
+        assert_eq!(
+            properties(&second[0]).get("count"),
+            Some(&ElementValue::Integer(2))
+        );
+    }
+
+    #[test]
+    fn test_insert_mode_with_retention() {
+        let mut generator = TickGenerator::new(
+            "clock",
+            TimerSourceConfig {
+                mode: TickMode::Insert,
+                retain: Some(2),
+                ..Default::default()
+            },
+        );
+
+        let ids = |changes: &[SourceChange]| {
+            changes
+                .iter()
+                .map(|c| c.get_reference().element_id.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(ids(&generator.tick(time(0))), ["tick:1"]);
+        assert_eq!(ids(&generator.tick(time(1))), ["tick:2"]);
+
+        let third = generator.tick(time(2));
+        assert_eq!(ids(&third), ["tick:3", "tick:1"]);
+        assert!(matches!(third[1], SourceChange::Delete { .. }));
+    }
+}
+
+mod source {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_source_emits_ticks() {
+        let source = TimerSource::builder("clock")
+            .with_interval_ms(20)
+            .build()
+            .unwrap();
+        let mut rx = source.test_subscribe();
+        source.start().await.unwrap();
+
+        let mut changes = Vec::new();
+        for _ in 0..3 {
+            let event = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+                .await
+                .expect("Timeout")
+                .expect("No event");
+            if let SourceEvent::Change(change) = &event.event {
+                changes.push(change.clone());
+            }
+        }
+        source.stop().await.unwrap();
+
+        assert!(matches!(changes[0], SourceChange::Insert { .. }));
+        assert!(matches!(changes[1], SourceChange::Update { .. }));
+        assert!(matches!(changes[2], SourceChange::Update { .. }));
+    }
+}
+
+mod descriptor {
+    use super::*;
+    use crate::descriptor::TimerSourceDescriptor;
+
+    #[tokio::test]
+    async fn test_descriptor_creates_source() {
+        let descriptor = TimerSourceDescriptor;
+        assert_eq!(descriptor.kind(), "timer");
+
+        let source = descriptor
+            .create_source(
+                "clock",
+                &json!({
+                    "schedule": {"type": "interval", "intervalMs": 60000},
+                    "label": "Minute",
+                    "elementId": "minute",
+                    "emitOnStart": false
+                }),
+                false,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(source.id(), "clock");
+        assert!(!source.auto_start());
+        let props = source.properties();
+        assert_eq!(
+            props.get("schedule"),
+            Some(&json!({"type": "interval", "intervalMs": 60000}))
+        );
+        assert_eq!(props.get("elementId"), Some(&json!("minute")));
+        assert_eq!(props.get("emitOnStart"), Some(&json!(false)));
+    }
+
+    #[tokio::test]
+    async fn test_descriptor_rejects_unknown_fields() {
+        let result = TimerSourceDescriptor
+            .create_source("clock", &json!({"label": "Tick", "bogus": 1}), true)
+            .await;
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion of fire times into tick elements.
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use drasi_core::models::{Element, ElementMetadata, ElementReference, SourceChange};
+use drasi_lib::sources::convert_json_to_element_properties;
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use crate::config::{TickMode, TimerSourceConfig};
+
+/// Builds the changes for each tick of a source.
+pub(crate) struct TickGenerator {
+    source_id: String,
+    config: TimerSourceConfig,
+    count: u64,
+    /// IDs of the retained tick nodes in `insert` mode, oldest first.
+    retained: VecDeque<String>,
+}
+
+impl TickGenerator {
+    pub(crate) fn new(source_id: impl Into<String>, config: TimerSourceConfig) -> Self {
+        Self {
+            source_id: source_id.into(),
+            config,
+            count: 0,
+            retained: VecDeque::new(),
+        }
+    }
+
+    /// Changes for a tick fired at `time`.
+    ///
+    /// Each tick node has the configured static properties plus `timestamp`
+    /// (epoch milliseconds), `time` (RFC 3339) and `count` (1 for the first
+    /// tick since the source started).
+    pub(crate) fn tick(&mut self, time: DateTime<Utc>) -> Vec<SourceChange> {
+        self.count += 1;
+        let effective_from = time.timestamp_millis() as u64;
+
+        let mut properties = self.config.properties.clone();
+        properties.insert("timestamp".to_string(), Value::from(effective_from));
+        properties.insert(
+            "time".to_string(),
+            Value::from(time.to_rfc3339_opts(SecondsFormat::Millis, true)),
+        );
+        properties.insert("count".to_string(), Value::from(self.count));
+
+        let element_id = match self.config.mode {
+            TickMode::Update => self.config.element_id.clone(),
+            TickMode::Insert => format!("{}:{}", self.config.element_id, self.count),
+        };
+        let element = Element::Node {
+            metadata: self.metadata(&element_id, effective_from),
+            properties: convert_json_to_element_properties(&properties),
+        };
+
+        match self.config.mode {
+            TickMode::Update if self.count > 1 => vec![SourceChange::Update { element }],
+            TickMode::Update => vec![SourceChange::Insert { element }],
+            TickMode::Insert => {
+                let mut changes = vec![SourceChange::Insert { element }];
+                self.retained.push_back(element_id);
+                if let Some(retain) = self.config.retain {
+                    while self.retained.len() > retain {
+                        let Some(expired) = self.retained.pop_front() else {
+                            break;
+                        };
+                        changes.push(SourceChange::Delete {
+                            metadata: self.metadata(&expired, effective_from),
+                        });
+                    }
+                }
+                changes
+            }
+        }
+    }
+
+    fn metadata(&self, element_id: &str, effective_from: u64) -> ElementMetadata {
+        ElementMetadata {
+            reference: ElementReference::new(&self.source_id, element_id),
+            labels: Arc::from(vec![Arc::from(self.config.label.as_str())]),
+            effective_from,
+        }
+    }
+}
@@ -0,0 +1,418 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Timer source implementation and builder.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use log::{debug, error, info, warn};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use drasi_core::models::SourceChange;
+use drasi_lib::channels::*;
+use drasi_lib::component_graph::ComponentStatusHandle;
+use drasi_lib::managers::{log_component_start, log_component_stop};
+use drasi_lib::sources::base::{SourceBase, SourceBaseParams};
+use drasi_lib::Source;
+use tracing::Instrument;
+
+use crate::config::{TickMode, TimerSchedule, TimerSourceConfig};
+use crate::schedule::Ticker;
+use crate::tick::TickGenerator;
+
+type Dispatchers = Arc<RwLock<Vec<Box<dyn ChangeDispatcher<SourceEventWrapper> + Send + Sync>>>>;
+
+/// Source that emits synthetic tick nodes on a schedule.
+///
+/// The source fires on a fixed interval or a cron expression and emits a
+/// node carrying the fire time, so time-driven queries and reactions can run
+/// without external infrastructure.
+pub struct TimerSource {
+    /// Base source implementation providing dispatchers, status tracking, and lifecycle management.
+    base: SourceBase,
+    /// Timer configuration.
+    config: TimerSourceConfig,
+}
+
+impl TimerSource {
+    /// Create a builder for a timer source.
+    pub fn builder(id: impl Into<String>) -> TimerSourceBuilder {
+        TimerSourceBuilder::new(id)
+    }
+
+    /// Create a new timer source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid.
+    pub fn new(id: impl Into<String>, config: TimerSourceConfig) -> Result<Self> {
+        TimerSourceBuilder::new(id).with_config(config).build()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn test_subscribe(&self) -> Box<dyn ChangeReceiver<SourceEventWrapper>> {
+        self.base.test_subscribe()
+    }
+
+    async fn dispatch(
+        source_id: &str,
+        dispatchers: &Dispatchers,
+        change: SourceChange,
+    ) -> Result<()> {
+        let mut profiling = drasi_lib::profiling::ProfilingMetadata::new();
+        profiling.source_send_ns = Some(drasi_lib::profiling::timestamp_ns());
+
+        let wrapper = SourceEventWrapper::with_profiling(
+            source_id.to_string(),
+            SourceEvent::Change(change),
+            chrono::Utc::now(),
+            profiling,
+        );
+
+        SourceBase::dispatch_from_task(dispatchers.clone(), wrapper, source_id).await
+    }
+
+    async fn emit(source_id: &str, dispatchers: &Dispatchers, changes: Vec<SourceChange>) {
+        for change in changes {
+            if let Err(e) = Self::dispatch(source_id, dispatchers, change).await {
+                warn!("[{source_id}] Failed to dispatch tick: {e}");
+            }
+        }
+    }
+
+    async fn run(
+        source_id: String,
+        config: TimerSourceConfig,
+        dispatchers: Dispatchers,
+        status_handle: ComponentStatusHandle,
+    ) {
+        let mut ticker = match Ticker::new(&config.schedule) {
+            Ok(ticker) => ticker,
+            Err(e) => {
+                error!("[{source_id}] Failed to create timer schedule: {e}");
+                status_handle
+                    .set_status(
+                        ComponentStatus::Error,
+                        Some(format!("Failed to create timer schedule: {e}")),
+                    )
+                    .await;
+                return;
+            }
+        };
+        let emit_on_start = config.emit_on_start;
+        let mut generator = TickGenerator::new(source_id.clone(), config);
+
+        status_handle
+            .set_status(
+                ComponentStatus::Running,
+                Some("Timer source running".to_string()),
+            )
+            .await;
+
+        if emit_on_start {
+            Self::emit(&source_id, &dispatchers, generator.tick(chrono::Utc::now())).await;
+        }
+        while let Some(time) = ticker.tick().await {
+            debug!("[{source_id}] Tick at {time}");
+            Self::emit(&source_id, &dispatchers, generator.tick(time)).await;
+        }
+
+        info!("[{source_id}] Timer schedule has no further fire times");
+        status_handle
+            .set_status(
+                ComponentStatus::Stopped,
+                Some("Timer schedule has no further fire times".to_string()),
+            )
+            .await;
+    }
+}
+
+#[async_trait]
+impl Source for TimerSource {
+    fn id(&self) -> &str {
+        &self.base.id
+    }
+
+    fn type_name(&self) -> &str {
+        "timer"
+    }
+
+    fn properties(&self) -> HashMap<String, serde_json::Value> {
+        use crate::descriptor::{TickModeDto, TimerScheduleDto, TimerSourceConfigDto};
+        use drasi_plugin_sdk::ConfigValue;
+
+        let schedule = match &self.config.schedule {
+            TimerSchedule::Interval { interval_ms } => TimerScheduleDto::Interval {
+                interval_ms: ConfigValue::Static(*interval_ms),
+            },
+            TimerSchedule::Cron { expression } => TimerScheduleDto::Cron {
+                expression: ConfigValue::Static(expression.clone()),
+            },
+        };
+        let dto = TimerSourceConfigDto {
+            schedule,
+            label: self.config.label.clone(),
+            element_id: self.config.element_id.clone(),
+            mode: TickModeDto::from(self.config.mode),
+            retain: self.config.retain,
+            properties: self.config.properties.clone(),
+            emit_on_start: self.config.emit_on_start,
+        };
+
+        match serde_json::to_value(&dto) {
+            Ok(serde_json::Value::Object(map)) => map.into_iter().collect(),
+            _ => HashMap::new(),
+        }
+    }
+
+    fn auto_start(&self) -> bool {
+        self.base.get_auto_start()
+    }
+
+    async fn start(&self) -> Result<()> {
+        log_component_start("Timer Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Starting,
+                Some("Starting timer source".to_string()),
+            )
+            .await;
+
+        let instance_id = self
+            .base
+            .context()
+            .await
+            .map(|c| c.instance_id)
+            .unwrap_or_default();
+
+        let span = tracing::info_span!(
+            "timer_source",
+            instance_id = %instance_id,
+            component_id = %self.base.id,
+            component_type = "source"
+        );
+        let task = tokio::spawn(
+            Self::run(
+                self.base.id.clone(),
+                self.config.clone(),
+                self.base.dispatchers.clone(),
+                self.base.status_handle(),
+            )
+            .instrument(span),
+        );
+
+        *self.base.task_handle.write().await = Some(task);
+
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        log_component_stop("Timer Source", &self.base.id);
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopping,
+                Some("Stopping timer source".to_string()),
+            )
+            .await;
+
+        if let Some(handle) = self.base.task_handle.write().await.take() {
+            handle.abort();
+            let _ = handle.await;
+        }
+
+        self.base
+            .set_status(
+                ComponentStatus::Stopped,
+                Some("Timer source stopped".to_string()),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn status(&self) -> ComponentStatus {
+        self.base.get_status().await
+    }
+
+    async fn subscribe(
+        &self,
+        settings: drasi_lib::config::SourceSubscriptionSettings,
+    ) -> Result<SubscriptionResponse> {
+        self.base.subscribe_with_bootstrap(&settings, "Timer").await
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    async fn initialize(&self, context: drasi_lib::context::SourceRuntimeContext) {
+        self.base.initialize(context).await;
+    }
+
+    async fn set_bootstrap_provider(
+        &self,
+        provider: Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>,
+    ) {
+        self.base.set_bootstrap_provider(provider).await;
+    }
+}
+
+/// Builder for [`TimerSource`] instances.
+///
+/// # Example
+///
+/// ```rust
+/// use drasi_source_timer::TimerSource;
+///
+/// let source = TimerSource::builder("clock")
+///     .with_cron("0 * * * *")
+///     .with_label("Hour")
+///     .build()
+///     .unwrap();
+/// ```
+pub struct TimerSourceBuilder {
+    id: String,
+    config: TimerSourceConfig,
+    dispatch_mode: Option<DispatchMode>,
+    dispatch_buffer_capacity: Option<usize>,
+    bootstrap_provider: Option<Box<dyn drasi_lib::bootstrap::BootstrapProvider + 'static>>,
+    auto_start: bool,
+}
+
+impl TimerSourceBuilder {
+    /// Create a new builder with the given ID and default values.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            config: TimerSourceConfig::default(),
+            dispatch_mode: None,
+            dispatch_buffer_capacity: None,
+            bootstrap_provider: None,
+            auto_start: true,
+        }
+    }
+
+    /// Fire every `interval_ms` milliseconds.
+    pub fn with_interval_ms(mut self, interval_ms: u64) -> Self {
+        self.config.schedule = TimerSchedule::Interval { interval_ms };
+        self
+    }
+
+    /// Fire on a cron expression, evaluated in UTC.
+    pub fn with_cron(mut self, expression: impl Into<String>) -> Self {
+        self.config.schedule = TimerSchedule::Cron {
+            expression: expression.into(),
+        };
+        self
+    }
+
+    /// Set the label of the tick nodes.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.config.label = label.into();
+        self
+    }
+
+    /// Set the element ID of the tick node, or the ID prefix in `insert` mode.
+    pub fn with_element_id(mut self, element_id: impl Into<String>) -> Self {
+        self.config.element_id = element_id.into();
+        self
+    }
+
+    /// Set how ticks are represented.
+    pub fn with_mode(mut self, mode: TickMode) -> Self {
+        self.config.mode = mode;
+        self
+    }
+
+    /// Set the number of tick nodes kept in `insert` mode.
+    pub fn with_retain(mut self, retain: usize) -> Self {
+        self.config.retain = Some(retain);
+        self
+    }
+
+    /// Add a static property to every tick.
+    pub fn with_property(mut self, name: impl Into<String>, value: serde_json::Value) -> Self {
+        self.config.properties.insert(name.into(), value);
+        self
+    }
+
+    /// Set whether a tick is emitted as soon as the source starts.
+    pub fn with_emit_on_start(mut self, emit_on_start: bool) -> Self {
+        self.config.emit_on_start = emit_on_start;
+        self
+    }
+
+    /// Set the dispatch mode for this source.
+    pub fn with_dispatch_mode(mut self, mode: DispatchMode) -> Self {
+        self.dispatch_mode = Some(mode);
+        self
+    }
+
+    /// Set the dispatch buffer capacity.
+    pub fn with_dispatch_buffer_capacity(mut self, capacity: usize) -> Self {
+        self.dispatch_buffer_capacity = Some(capacity);
+        self
+    }
+
+    /// Set the bootstrap provider for initial data delivery.
+    pub fn with_bootstrap_provider(
+        mut self,
+        provider: impl drasi_lib::bootstrap::BootstrapProvider + 'static,
+    ) -> Self {
+        self.bootstrap_provider = Some(Box::new(provider));
+        self
+    }
+
+    /// Set whether this source should auto-start when DrasiLib starts.
+    pub fn with_auto_start(mut self, auto_start: bool) -> Self {
+        self.auto_start = auto_start;
+        self
+    }
+
+    /// Set the full configuration at once.
+    pub fn with_config(mut self, config: TimerSourceConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Build the timer source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid or the base source
+    /// cannot be initialized.
+    pub fn build(self) -> Result<TimerSource> {
+        self.config.validate()?;
+
+        let mut params = SourceBaseParams::new(&self.id).with_auto_start(self.auto_start);
+        if let Some(mode) = self.dispatch_mode {
+            params = params.with_dispatch_mode(mode);
+        }
+        if let Some(capacity) = self.dispatch_buffer_capacity {
+            params = params.with_dispatch_buffer_capacity(capacity);
+        }
+        if let Some(provider) = self.bootstrap_provider {
+            params = params.with_bootstrap_provider(provider);
+        }
+
+        Ok(TimerSource {
+            base: SourceBase::new(params)?,
+            config: self.config,
+        })
+    }
+}
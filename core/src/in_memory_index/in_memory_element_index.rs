@@ -523,6 +523,17 @@ impl ElementIndex for InMemoryElementIndex {
         Ok(())
     }
 
+    async fn get_all_elements(&self) -> Result<ElementStream, IndexError> {
+        let elements: Vec<ElementResult> = self
+            .elements
+            .read()
+            .await
+            .values()
+            .map(|element| Ok(element.clone()))
+            .collect();
+        Ok(Box::pin(futures::stream::iter(elements)))
+    }
+
     async fn clear(&self) -> Result<(), IndexError> {
         let mut guard = self.elements.write().await;
         guard.clear();
@@ -272,6 +272,10 @@ impl ElementIndex for CachedElementIndex {
         }
     }
 
+    async fn get_all_elements(&self) -> Result<ElementStream, IndexError> {
+        self.element_index.get_all_elements().await
+    }
+
     async fn clear(&self) -> Result<(), IndexError> {
         self.element_index.clear().await?;
 
@@ -56,6 +56,15 @@ pub trait ElementIndex: Send + Sync {
     ) -> Result<ElementStream, IndexError>;
     async fn clear(&self) -> Result<(), IndexError>;
 
+    /// Stream every element currently held by the index, in no particular order.
+    ///
+    /// Used to export query state. Reads committed state and does not require
+    /// an active session. Backends that cannot enumerate their contents return
+    /// [`IndexError::NotSupported`].
+    async fn get_all_elements(&self) -> Result<ElementStream, IndexError> {
+        Err(IndexError::NotSupported)
+    }
+
     async fn set_joins(&self, match_path: &MatchPath, joins: &Vec<Arc<QueryJoin>>);
 }
 
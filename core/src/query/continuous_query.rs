@@ -20,7 +20,8 @@ use std::{
     time::Duration,
 };
 
-use drasi_query_ast::ast::Query;
+use drasi_query_ast::ast::{ProjectionClause, Query};
+use futures::StreamExt;
 use hashers::jenkins::spooky_hash::SpookyHasher;
 use tokio::{
     select,
@@ -35,8 +36,8 @@ use crate::{
         QueryPartEvaluator,
     },
     interface::{
-        ElementIndex, FutureQueue, FutureQueueConsumer, MiddlewareError, QueryClock,
-        SessionControl, SessionGuard,
+        ElementIndex, ElementStream, FutureQueue, FutureQueueConsumer, IndexError, MiddlewareError,
+        QueryClock, SessionControl, SessionGuard,
     },
     middleware::SourceMiddlewarePipelineCollection,
     models::{Element, SourceChange},
@@ -115,6 +116,34 @@ impl ContinuousQuery {
         Ok(result)
     }
 
+    /// Process changes that have already passed through source middleware.
+    ///
+    /// Used to restore elements exported from another query's element index,
+    /// which hold the post-middleware form and must not be transformed again.
+    #[tracing::instrument(skip_all, err, level = "debug")]
+    pub async fn process_restored_changes(
+        &self,
+        changes: Vec<SourceChange>,
+    ) -> Result<Vec<QueryPartEvaluationContext>, EvaluationError> {
+        let _lock = self.change_lock.lock().await;
+        let guard = SessionGuard::begin(self.session_control.clone()).await?;
+
+        let result = self.process_changes_inner(changes).await?;
+
+        guard.commit().await?;
+        Ok(result)
+    }
+
+    /// Read every element in the query's element index.
+    ///
+    /// Holds the change lock while reading so the result reflects a point
+    /// between two processed changes.
+    pub async fn get_all_elements(&self) -> Result<ElementStream, IndexError> {
+        let _lock = self.change_lock.lock().await;
+        let elements: Vec<_> = self.element_index.get_all_elements().await?.collect().await;
+        Ok(Box::pin(futures::stream::iter(elements)))
+    }
+
     /// Atomically pop a due future from the queue and process it within a single session.
     ///
     /// Returns `Ok(None)` when the queue is empty (stale peek).
@@ -705,6 +734,17 @@ impl ContinuousQuery {
     pub fn get_query(&self) -> Arc<Query> {
         self.query.clone()
     }
+
+    /// Whether every result row is derived from a single element.
+    ///
+    /// True for single-part queries that match one node without aggregation.
+    /// Changes to different elements of such a query touch disjoint result
+    /// rows, so their results may be dispatched in any relative order.
+    pub fn is_element_local(&self) -> bool {
+        self.match_path.slots.len() == 1
+            && self.query.parts.len() == 1
+            && matches!(self.query.parts[0].return_clause, ProjectionClause::Item(_))
+    }
 }
 
 impl Drop for ContinuousQuery {
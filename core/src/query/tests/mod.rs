@@ -71,3 +71,23 @@ impl FutureQueueConsumer for TestFutureConsumer {
         0
     }
 }
+
+#[tokio::test]
+async fn element_local_queries() {
+    let function_registry = Arc::new(FunctionRegistry::new());
+    let parser = Arc::new(CypherParser::new(function_registry.clone()));
+    let cases = [
+        ("MATCH (n:Person) WHERE n.age > 21 RETURN n.name", true),
+        ("MATCH (n:Person) RETURN count(n)", false),
+        ("MATCH (a:Person)-[:KNOWS]->(b:Person) RETURN a, b", false),
+        ("MATCH (n:Person) WITH n.name AS name RETURN name", false),
+    ];
+
+    for (query_str, expected) in cases {
+        let query = QueryBuilder::new(query_str, parser.clone())
+            .with_function_registry(function_registry.clone())
+            .build()
+            .await;
+        assert_eq!(query.is_element_local(), expected, "{query_str}");
+    }
+}
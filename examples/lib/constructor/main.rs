@@ -180,6 +180,9 @@ async fn main() -> Result<()> {
         dispatch_mode: None,
         storage_backend: None,
         recovery_policy: None,
+        result_processors: Vec::new(),
+        quarantine_capacity: 0,
+        priority: Default::default(),
     };
 
     // =========================================================================
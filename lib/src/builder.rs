@@ -63,6 +63,7 @@
 use std::sync::Arc;
 
 use crate::channels::DispatchMode;
+use crate::checkpoint::CheckpointStore;
 use crate::config::{
     DrasiLibConfig, QueryConfig, QueryJoinConfig, QueryLanguage, SourceSubscriptionConfig,
 };
@@ -71,9 +72,15 @@ use crate::identity::IdentityProvider;
 use crate::indexes::IndexBackendPlugin;
 use crate::indexes::StorageBackendConfig;
 use crate::lib_core::DrasiLib;
+use crate::lifecycle::DrainConfig;
+use crate::metrics::MetricsRegistry;
+use crate::queries::{EvaluationPool, QueryPriority, QueryScheduler, ResultProcessor};
 use crate::reactions::Reaction as ReactionTrait;
+use crate::registry::ComponentRegistry;
 use crate::sources::Source as SourceTrait;
 use crate::state_store::StateStoreProvider;
+use crate::supervisor::{RestartPolicies, RestartPolicy};
+use drasi_core::interface::{MiddlewareSetupError, SourceMiddleware, SourceMiddlewareFactory};
 use drasi_core::models::SourceMiddlewareConfig;
 
 // ============================================================================
@@ -133,6 +140,17 @@ pub struct DrasiLibBuilder {
     index_provider: Option<Arc<dyn IndexBackendPlugin>>,
     state_store_provider: Option<Arc<dyn StateStoreProvider>>,
     identity_provider: Option<Arc<dyn IdentityProvider>>,
+    component_registry: Option<Arc<ComponentRegistry>>,
+    checkpoint_store: Option<Arc<dyn CheckpointStore>>,
+    metrics_registry: Option<Arc<MetricsRegistry>>,
+    drain: Option<DrainConfig>,
+    restart_policies: RestartPolicies,
+    evaluation_concurrency: Option<usize>,
+    evaluation_workers: Option<usize>,
+    #[cfg(feature = "otel")]
+    otlp_exporter: Option<crate::telemetry::OtlpConfig>,
+    middleware_factories: Vec<Arc<dyn SourceMiddlewareFactory>>,
+    result_processors: Vec<(String, Arc<dyn ResultProcessor>)>,
 }
 
 impl Default for DrasiLibBuilder {
@@ -155,6 +173,17 @@ impl DrasiLibBuilder {
             index_provider: None,
             state_store_provider: None,
             identity_provider: None,
+            component_registry: None,
+            checkpoint_store: None,
+            metrics_registry: None,
+            drain: None,
+            restart_policies: RestartPolicies::default(),
+            evaluation_concurrency: None,
+            evaluation_workers: None,
+            #[cfg(feature = "otel")]
+            otlp_exporter: None,
+            middleware_factories: Vec::new(),
+            result_processors: Vec::new(),
         }
     }
 
@@ -257,6 +286,265 @@ impl DrasiLibBuilder {
         self
     }
 
+    /// Set the component registry used to create sources and reactions by kind.
+    ///
+    /// The registry backs [`DrasiLib::add_source_from_config`] and
+    /// [`DrasiLib::add_reaction_from_config`]. Without one, those calls fail
+    /// because no kinds are registered.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut registry = ComponentRegistry::new();
+    /// registry.register_source("mock", mock_factory);
+    /// let core = DrasiLib::builder()
+    ///     .with_component_registry(Arc::new(registry))
+    ///     .build()
+    ///     .await?;
+    /// ```
+    pub fn with_component_registry(mut self, registry: Arc<ComponentRegistry>) -> Self {
+        self.component_registry = Some(registry);
+        self
+    }
+
+    /// Set the checkpoint store used to persist source positions and query sequences.
+    ///
+    /// Defaults to a [`StateStoreCheckpointStore`](crate::StateStoreCheckpointStore)
+    /// over the configured state store provider, so checkpoints are only durable
+    /// when the state store is.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let core = DrasiLib::builder()
+    ///     .with_checkpoint_store(Arc::new(MyCheckpointStore::new()))
+    ///     .build()
+    ///     .await?;
+    /// ```
+    pub fn with_checkpoint_store(mut self, store: Arc<dyn CheckpointStore>) -> Self {
+        self.checkpoint_store = Some(store);
+        self
+    }
+
+    /// Set the registry that sources, queries and reactions record metrics into.
+    ///
+    /// Defaults to a new registry per instance. Share one registry across
+    /// several instances, or with the host application's own metrics, to
+    /// expose them all on a single endpoint.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let registry = Arc::new(MetricsRegistry::new());
+    /// let core = DrasiLib::builder()
+    ///     .with_metrics_registry(registry.clone())
+    ///     .build()
+    ///     .await?;
+    /// ```
+    pub fn with_metrics_registry(mut self, registry: Arc<MetricsRegistry>) -> Self {
+        self.metrics_registry = Some(registry);
+        self
+    }
+
+    /// Drain in-flight changes when the instance is stopped.
+    ///
+    /// [`DrasiLib::stop`] then stops sources first and waits, within the
+    /// per-stage timeouts of `config`, for queries and reactions to process
+    /// every change the sources already emitted before stopping them. Without
+    /// draining, changes still queued when `stop()` is called are dropped.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let core = DrasiLib::builder()
+    ///     .with_drain_on_stop(
+    ///         DrainConfig::default().with_query_drain_timeout(Duration::from_secs(60)),
+    ///     )
+    ///     .build()
+    ///     .await?;
+    /// ```
+    pub fn with_drain_on_stop(mut self, config: DrainConfig) -> Self {
+        self.drain = Some(config);
+        self
+    }
+
+    /// Limit the number of queries evaluating source changes at the same time.
+    ///
+    /// Queries then take turns by fair share: a query that has used less
+    /// evaluation time, relative to its [`QueryPriority`], goes first, so an
+    /// expensive query cannot starve cheap ones. Without a limit every query
+    /// evaluates as soon as a change arrives and priorities have no effect.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let core = DrasiLib::builder()
+    ///     .with_evaluation_concurrency(4)
+    ///     .with_query(
+    ///         Query::cypher("alerts")
+    ///             .query("MATCH (s:Sensor) WHERE s.temp > 90 RETURN s")
+    ///             .from_source("sensors")
+    ///             .with_priority(QueryPriority::High)
+    ///             .build(),
+    ///     )
+    ///     .build()
+    ///     .await?;
+    /// ```
+    pub fn with_evaluation_concurrency(mut self, concurrency: usize) -> Self {
+        self.evaluation_concurrency = Some(concurrency);
+        self
+    }
+
+    /// Evaluate query changes on a shared pool of `workers` workers.
+    ///
+    /// A query's processor then hands each change to the pool and dequeues
+    /// the next one without waiting for the previous results to be dispatched.
+    /// Different queries evaluate in parallel. Within one query, evaluation
+    /// still runs one change at a time; queries whose result rows each derive
+    /// from a single element (one node pattern, no aggregation) are partitioned
+    /// by element id, so the results of one element's change are dispatched
+    /// while changes to other elements are evaluated, and each element keeps its order.
+    /// Queries with persistent indexes keep evaluating on their own task, so
+    /// their checkpoints advance in sequence order.
+    ///
+    /// Evaluating several changes of one query in parallel is not supported:
+    /// more workers do not raise the evaluation throughput of a single query.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let core = DrasiLib::builder()
+    ///     .with_evaluation_workers(8)
+    ///     .build()
+    ///     .await?;
+    /// ```
+    pub fn with_evaluation_workers(mut self, workers: usize) -> Self {
+        self.evaluation_workers = Some(workers);
+        self
+    }
+
+    /// Restart sources and reactions that fail while the instance is running.
+    ///
+    /// `policy` applies to every source and reaction without an override from
+    /// [`with_component_restart_policy`](Self::with_component_restart_policy).
+    /// Default: [`RestartPolicy::Never`], which leaves failed components stopped.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let core = DrasiLib::builder()
+    ///     .with_restart_policy(RestartPolicy::OnFailure(
+    ///         RestartBackoff::default().with_max_restarts(5),
+    ///     ))
+    ///     .build()
+    ///     .await?;
+    /// ```
+    pub fn with_restart_policy(mut self, policy: RestartPolicy) -> Self {
+        self.restart_policies.default = policy;
+        self
+    }
+
+    /// Override the restart policy of a single source or reaction.
+    pub fn with_component_restart_policy(
+        mut self,
+        component_id: impl Into<String>,
+        policy: RestartPolicy,
+    ) -> Self {
+        self.restart_policies
+            .overrides
+            .insert(component_id.into(), policy);
+        self
+    }
+
+    /// Export pipeline traces to an OTLP collector such as Jaeger or Tempo.
+    ///
+    /// Each source change is traced from the source through query evaluation
+    /// to every reaction that receives a result from it; see
+    /// [`telemetry`](crate::telemetry). The exporter is process-wide, so the
+    /// last instance built with one serves all instances in the process.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let core = DrasiLib::builder()
+    ///     .with_otlp_exporter(OtlpConfig::new("http://localhost:4317").with_service_name("sensors"))
+    ///     .build()
+    ///     .await?;
+    /// ```
+    #[cfg(feature = "otel")]
+    pub fn with_otlp_exporter(mut self, config: crate::telemetry::OtlpConfig) -> Self {
+        self.otlp_exporter = Some(config);
+        self
+    }
+
+    /// Register a user-defined source middleware factory.
+    ///
+    /// Queries refer to the middleware by the factory's [`name`](SourceMiddlewareFactory::name)
+    /// in the `kind` of a [`SourceMiddlewareConfig`], exactly like the built-in middleware.
+    /// A factory with the same name as a built-in one replaces it.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let core = DrasiLib::builder()
+    ///     .with_middleware_factory(Arc::new(GeocodeMiddlewareFactory::new(client)))
+    ///     .with_query(
+    ///         Query::cypher("stores")
+    ///             .query("MATCH (s:Store) RETURN s.name, s.lat, s.lon")
+    ///             .from_source_with_pipeline("stores-db", vec!["geocode".into()])
+    ///             .with_middleware(SourceMiddlewareConfig {
+    ///                 kind: "geocode".into(),
+    ///                 name: "geocode".into(),
+    ///                 config: serde_json::Map::new(),
+    ///             })
+    ///             .build(),
+    ///     )
+    ///     .build()
+    ///     .await?;
+    /// ```
+    pub fn with_middleware_factory(mut self, factory: Arc<dyn SourceMiddlewareFactory>) -> Self {
+        self.middleware_factories.push(factory);
+        self
+    }
+
+    /// Register a ready-made [`SourceMiddleware`] instance under `kind`.
+    ///
+    /// Shorthand for [`with_middleware_factory`](Self::with_middleware_factory) when the
+    /// middleware needs no per-query configuration. Every query that configures
+    /// middleware of this kind shares the same instance, so it must be safe to call
+    /// concurrently; the `config` map of the query's `SourceMiddlewareConfig` is ignored.
+    pub fn with_middleware_instance(
+        self,
+        kind: impl Into<String>,
+        middleware: Arc<dyn SourceMiddleware>,
+    ) -> Self {
+        self.with_middleware_factory(Arc::new(SharedMiddlewareFactory {
+            kind: kind.into(),
+            middleware,
+        }))
+    }
+
+    /// Register a result processor under `name`.
+    ///
+    /// Queries opt in with [`Query::with_result_processor`]. Closures of the form
+    /// `|query_id: &str, results: Vec<ResultDiff>| -> Vec<ResultDiff>` can be
+    /// passed directly.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let core = DrasiLib::builder()
+    ///     .with_result_processor("round", RoundFloats::new(2))
+    ///     .with_query(
+    ///         Query::cypher("q")
+    ///             .query("MATCH (s:Sensor) RETURN s.temp AS temp")
+    ///             .from_source("iot")
+    ///             .with_result_processor("round")
+    ///             .build(),
+    ///     )
+    ///     .build()
+    ///     .await?;
+    /// ```
+    pub fn with_result_processor(
+        mut self,
+        name: impl Into<String>,
+        processor: impl ResultProcessor + 'static,
+    ) -> Self {
+        self.result_processors
+            .push((name.into(), Arc::new(processor)));
+        self
+    }
+
     /// Add a source instance, taking ownership.
     ///
     /// Source instances are created externally by plugins with their own typed configurations.
@@ -355,7 +643,23 @@ impl DrasiLibBuilder {
             self.state_store_provider,
             self.identity_provider,
         ));
-        let mut core = DrasiLib::new(runtime_config);
+        let mut core = DrasiLib::new(runtime_config, self.middleware_factories);
+        if let Some(registry) = self.component_registry {
+            core.component_registry = registry;
+        }
+        if let Some(checkpoint_store) = self.checkpoint_store {
+            core.checkpoint_store = checkpoint_store;
+        }
+        if let Some(metrics) = self.metrics_registry {
+            core.metrics = metrics;
+        }
+        core.drain = self.drain;
+        core.restart_policies = self.restart_policies;
+        #[cfg(feature = "otel")]
+        if let Some(otlp) = &self.otlp_exporter {
+            crate::telemetry::install(otlp)
+                .map_err(|e| DrasiError::invalid_config(format!("OTLP exporter: {e}")))?;
+        }
 
         // Inject state store before provisioning sources (they need it for initialization)
         let state_store = core.config.state_store_provider.clone();
@@ -363,6 +667,34 @@ impl DrasiLibBuilder {
             .inject_state_store(state_store.clone())
             .await;
         core.reaction_manager.inject_state_store(state_store).await;
+        core.source_manager
+            .inject_checkpoint_store(core.checkpoint_store.clone())
+            .await;
+        core.source_manager
+            .inject_metrics(core.metrics.clone())
+            .await;
+        core.reaction_manager
+            .inject_metrics(core.metrics.clone())
+            .await;
+
+        // The scheduler and pool must be known before queries are provisioned
+        if let Some(concurrency) = self.evaluation_concurrency {
+            core.query_manager
+                .inject_scheduler(Arc::new(QueryScheduler::new(concurrency)))
+                .await;
+        }
+        if let Some(workers) = self.evaluation_workers {
+            core.query_manager
+                .inject_evaluation_pool(Arc::new(EvaluationPool::new(workers)))
+                .await;
+        }
+
+        // Result processors must be known before queries are provisioned
+        for (name, processor) in self.result_processors {
+            core.query_manager
+                .register_result_processor(name, processor)
+                .await;
+        }
 
         // Register the component graph source BEFORE initialize (which loads query config).
         // Queries reference sources, so sources must exist in the graph first.
@@ -515,6 +847,25 @@ impl DrasiLibBuilder {
     }
 }
 
+/// Factory that hands out one shared middleware instance for every configuration.
+struct SharedMiddlewareFactory {
+    kind: String,
+    middleware: Arc<dyn SourceMiddleware>,
+}
+
+impl SourceMiddlewareFactory for SharedMiddlewareFactory {
+    fn name(&self) -> String {
+        self.kind.clone()
+    }
+
+    fn create(
+        &self,
+        _config: &SourceMiddlewareConfig,
+    ) -> std::result::Result<Arc<dyn SourceMiddleware>, MiddlewareSetupError> {
+        Ok(self.middleware.clone())
+    }
+}
+
 // ============================================================================
 // Query Builder
 // ============================================================================
@@ -549,6 +900,9 @@ pub struct Query {
     dispatch_mode: Option<DispatchMode>,
     storage_backend: Option<crate::indexes::StorageBackendRef>,
     recovery_policy: Option<crate::recovery::RecoveryPolicy>,
+    result_processors: Vec<String>,
+    quarantine_capacity: usize,
+    priority: QueryPriority,
 }
 
 impl Query {
@@ -569,6 +923,9 @@ impl Query {
             dispatch_mode: None,
             storage_backend: None,
             recovery_policy: None,
+            result_processors: Vec::new(),
+            quarantine_capacity: 0,
+            priority: QueryPriority::Normal,
         }
     }
 
@@ -589,6 +946,9 @@ impl Query {
             dispatch_mode: None,
             storage_backend: None,
             recovery_policy: None,
+            result_processors: Vec::new(),
+            quarantine_capacity: 0,
+            priority: QueryPriority::Normal,
         }
     }
 
@@ -688,6 +1048,29 @@ impl Query {
         self
     }
 
+    /// Apply a result processor, registered on DrasiLib under `name`, to this
+    /// query's results. Processors run in the order they are added.
+    /// See [`ResultProcessor`](crate::queries::ResultProcessor).
+    pub fn with_result_processor(mut self, name: impl Into<String>) -> Self {
+        self.result_processors.push(name.into());
+        self
+    }
+
+    /// Keep up to `capacity` source changes that fail evaluation for inspection.
+    /// See [`DrasiLib::quarantined_changes`](crate::DrasiLib::quarantined_changes).
+    pub fn with_quarantine(mut self, capacity: usize) -> Self {
+        self.quarantine_capacity = capacity;
+        self
+    }
+
+    /// Set the query's share of evaluation time when queries compete for it.
+    /// Takes effect with
+    /// [`DrasiLibBuilder::with_evaluation_concurrency`]; default: `Normal`.
+    pub fn with_priority(mut self, priority: QueryPriority) -> Self {
+        self.priority = priority;
+        self
+    }
+
     /// Build the query configuration.
     pub fn build(self) -> QueryConfig {
         QueryConfig {
@@ -705,6 +1088,9 @@ impl Query {
             dispatch_mode: self.dispatch_mode,
             storage_backend: self.storage_backend,
             recovery_policy: self.recovery_policy,
+            result_processors: self.result_processors,
+            quarantine_capacity: self.quarantine_capacity,
+            priority: self.priority,
         }
     }
 }
@@ -738,6 +1124,22 @@ mod tests {
         assert_eq!(config.sources[0].source_id, "source1");
     }
 
+    #[test]
+    fn test_query_builder_priority() {
+        let config = Query::cypher("test-query")
+            .query("MATCH (n) RETURN n")
+            .from_source("source1")
+            .build();
+        assert_eq!(config.priority, QueryPriority::Normal);
+
+        let config = Query::cypher("test-query")
+            .query("MATCH (n) RETURN n")
+            .from_source("source1")
+            .with_priority(QueryPriority::High)
+            .build();
+        assert_eq!(config.priority, QueryPriority::High);
+    }
+
     #[test]
     fn test_query_builder_gql() {
         let config = Query::gql("test-query")
@@ -798,6 +1200,65 @@ mod tests {
         assert_eq!(queries.len(), 1);
     }
 
+    struct TagMiddleware;
+
+    #[async_trait::async_trait]
+    impl SourceMiddleware for TagMiddleware {
+        async fn process(
+            &self,
+            source_change: drasi_core::models::SourceChange,
+            _element_index: &dyn drasi_core::interface::ElementIndex,
+        ) -> std::result::Result<
+            Vec<drasi_core::models::SourceChange>,
+            drasi_core::interface::MiddlewareError,
+        > {
+            Ok(vec![source_change])
+        }
+    }
+
+    #[tokio::test]
+    async fn test_builder_registers_custom_middleware() {
+        let core = DrasiLibBuilder::new()
+            .with_middleware_instance("tag", Arc::new(TagMiddleware))
+            .build()
+            .await
+            .unwrap();
+
+        let factory = core
+            .middleware_registry()
+            .get("tag")
+            .expect("custom middleware should be registered");
+        let config = SourceMiddlewareConfig {
+            kind: "tag".into(),
+            name: "tag-1".into(),
+            config: serde_json::Map::new(),
+        };
+        assert!(factory.create(&config).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_builder_query_with_custom_middleware_pipeline() {
+        let core = DrasiLibBuilder::new()
+            .with_middleware_instance("tag", Arc::new(TagMiddleware))
+            .with_query(
+                Query::cypher("q")
+                    .query("MATCH (n) RETURN n")
+                    .from_source_with_pipeline("s", vec!["tag-1".into()])
+                    .with_middleware(SourceMiddlewareConfig {
+                        kind: "tag".into(),
+                        name: "tag-1".into(),
+                        config: serde_json::Map::new(),
+                    })
+                    .auto_start(false)
+                    .build(),
+            )
+            .build()
+            .await
+            .unwrap();
+
+        assert_eq!(core.list_queries().await.unwrap().len(), 1);
+    }
+
     // ==========================================================================
     // DrasiLib Builder Integration Tests (from builder_tests.rs)
     // ==========================================================================
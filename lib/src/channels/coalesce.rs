@@ -0,0 +1,368 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Coalescing of rapid element updates before they reach queries.
+//!
+//! A chatty source, such as a sensor reporting several times a second, makes
+//! every subscribed query evaluate each intermediate state of an element even
+//! though only the latest one matters by the time the query gets to it.
+//!
+//! [`CoalescingChangeDispatcher`] holds the events of a source for a short
+//! window and replaces a pending update of an element with any later update
+//! of the same element, so queries evaluate the element's latest state once.
+//! Inserts and deletes are never merged: an update is only replaced by an
+//! update that follows it without an insert or delete of the element in
+//! between, so queries still see every element appear and disappear.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use drasi_core::models::{ElementReference, SourceChange};
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+use tokio::sync::Notify;
+use tokio::time::Instant;
+
+use super::dispatcher::{ChangeDispatcher, ChangeReceiver};
+use super::events::{SourceEvent, SourceEventWrapper};
+use crate::metrics::Counter;
+
+/// Events held back during the current window
+#[derive(Default)]
+struct PendingEvents {
+    events: Vec<Arc<SourceEventWrapper>>,
+    /// Position in `events` of each element's update that a later update may replace
+    updates: HashMap<ElementReference, usize>,
+    /// When the first event of the window arrived
+    started: Option<Instant>,
+}
+
+/// State shared between a coalescing dispatcher and its window flush task
+struct CoalesceState {
+    inner: Box<dyn ChangeDispatcher<SourceEventWrapper> + Send + Sync>,
+    /// Held while events are passed on, so windows leave in order
+    pending: tokio::sync::Mutex<PendingEvents>,
+    /// Signalled when an event arrives in an empty window
+    started: Notify,
+    window: Duration,
+    /// Events held before the window is passed on early
+    max_pending: usize,
+    /// Updates replaced by a later update of the same element
+    coalesced: Counter,
+}
+
+impl CoalesceState {
+    /// Pass the pending events on to the inner dispatcher. Caller holds the `pending` lock.
+    async fn flush(&self, pending: &mut PendingEvents) -> Result<()> {
+        pending.started = None;
+        pending.updates.clear();
+        if pending.events.is_empty() {
+            return Ok(());
+        }
+        let events = std::mem::take(&mut pending.events);
+        self.inner.dispatch_changes(events).await
+    }
+
+    async fn flush_on_window(self: Arc<Self>) {
+        loop {
+            self.started.notified().await;
+            loop {
+                let mut pending = self.pending.lock().await;
+                let Some(started) = pending.started else {
+                    // Passed on early because the window filled up
+                    break;
+                };
+                let deadline = started + self.window;
+                if Instant::now() >= deadline {
+                    if let Err(e) = self.flush(&mut pending).await {
+                        log::debug!("Failed to dispatch coalesced events: {e}");
+                    }
+                    break;
+                }
+                drop(pending);
+                tokio::time::sleep_until(deadline).await;
+            }
+        }
+    }
+}
+
+/// ChangeDispatcher stage that merges rapid updates of the same element.
+///
+/// Wraps the dispatcher of a subscription (or the shared broadcast dispatcher)
+/// and forwards each window of events to it at once, in arrival order, after
+/// replacing superseded updates with the latest state of their element.
+pub struct CoalescingChangeDispatcher {
+    state: Arc<CoalesceState>,
+    /// Started on the first dispatch, so the dispatcher can be created outside a runtime
+    flusher: OnceLock<tokio::task::JoinHandle<()>>,
+}
+
+impl CoalescingChangeDispatcher {
+    /// Coalesce updates arriving within `window` before passing them to `inner`.
+    ///
+    /// At most `max_pending` events are held; a full window is passed on
+    /// immediately. Replaced updates are counted in `coalesced`.
+    pub fn new(
+        inner: Box<dyn ChangeDispatcher<SourceEventWrapper> + Send + Sync>,
+        window: Duration,
+        max_pending: usize,
+        coalesced: Counter,
+    ) -> Self {
+        Self {
+            state: Arc::new(CoalesceState {
+                inner,
+                pending: tokio::sync::Mutex::new(PendingEvents::default()),
+                started: Notify::new(),
+                window,
+                max_pending: max_pending.max(1),
+                coalesced,
+            }),
+            flusher: OnceLock::new(),
+        }
+    }
+}
+
+impl Drop for CoalescingChangeDispatcher {
+    fn drop(&mut self) {
+        if let Some(flusher) = self.flusher.get() {
+            flusher.abort();
+        }
+    }
+}
+
+#[async_trait]
+impl ChangeDispatcher<SourceEventWrapper> for CoalescingChangeDispatcher {
+    async fn dispatch_change(&self, change: Arc<SourceEventWrapper>) -> Result<()> {
+        self.dispatch_changes(vec![change]).await
+    }
+
+    async fn dispatch_changes(&self, changes: Vec<Arc<SourceEventWrapper>>) -> Result<()> {
+        self.flusher
+            .get_or_init(|| tokio::spawn(self.state.clone().flush_on_window()));
+
+        let state = &self.state;
+        let mut pending = state.pending.lock().await;
+        for event in changes {
+            if pending.started.is_none() {
+                pending.started = Some(Instant::now());
+                state.started.notify_one();
+            }
+
+            let control = match &event.event {
+                SourceEvent::Change(SourceChange::Update { element }) => {
+                    let reference = element.get_reference();
+                    if let Some(&position) = pending.updates.get(reference) {
+                        pending.events[position] = event;
+                        state.coalesced.inc();
+                        continue;
+                    }
+                    let position = pending.events.len();
+                    pending.updates.insert(reference.clone(), position);
+                    false
+                }
+                // Inserts and deletes end the run of updates that may be merged
+                SourceEvent::Change(change) => {
+                    pending.updates.remove(change.get_reference());
+                    false
+                }
+                _ => true,
+            };
+            pending.events.push(event);
+
+            // Control events are not held back
+            if control || pending.events.len() >= state.max_pending {
+                state.flush(&mut pending).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn create_receiver(&self) -> Result<Box<dyn ChangeReceiver<SourceEventWrapper>>> {
+        self.state.inner.create_receiver().await
+    }
+
+    fn queue_depth(&self) -> usize {
+        let held = self
+            .state
+            .pending
+            .try_lock()
+            .map_or(0, |pending| pending.events.len());
+        self.state.inner.queue_depth() + held
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::channels::ChannelChangeDispatcher;
+    use drasi_core::models::{Element, ElementMetadata, ElementPropertyMap, ElementValue};
+
+    fn node(id: &str, value: i64) -> Element {
+        let mut properties = ElementPropertyMap::new();
+        properties.insert("value", ElementValue::Integer(value));
+        Element::Node {
+            metadata: ElementMetadata {
+                reference: ElementReference::new("sensors", id),
+                labels: Arc::from(vec![Arc::from("Sensor")]),
+                effective_from: value as u64,
+            },
+            properties,
+        }
+    }
+
+    fn event(change: SourceChange) -> Arc<SourceEventWrapper> {
+        Arc::new(SourceEventWrapper::new(
+            "sensors".to_string(),
+            SourceEvent::Change(change),
+            chrono::Utc::now(),
+        ))
+    }
+
+    fn update(id: &str, value: i64) -> Arc<SourceEventWrapper> {
+        event(SourceChange::Update {
+            element: node(id, value),
+        })
+    }
+
+    /// Operation, element id and `value` property of a received event
+    fn describe(event: &SourceEventWrapper) -> (&'static str, String, i64) {
+        let SourceEvent::Change(change) = &event.event else {
+            panic!("expected a change, got {event:?}");
+        };
+        let (operation, element) = match change {
+            SourceChange::Insert { element } => ("insert", Some(element)),
+            SourceChange::Update { element } => ("update", Some(element)),
+            SourceChange::Delete { .. } => ("delete", None),
+            SourceChange::Future { .. } => ("future", None),
+        };
+        let value = match element.and_then(|e| e.get_properties().get("value").cloned()) {
+            Some(ElementValue::Integer(v)) => v,
+            _ => -1,
+        };
+        let id = change.get_reference().element_id.to_string();
+        (operation, id, value)
+    }
+
+    async fn coalescing(
+        window: Duration,
+    ) -> (
+        CoalescingChangeDispatcher,
+        Box<dyn ChangeReceiver<SourceEventWrapper>>,
+        Counter,
+    ) {
+        let coalesced = Counter::default();
+        let dispatcher = CoalescingChangeDispatcher::new(
+            Box::new(ChannelChangeDispatcher::new(100)),
+            window,
+            100,
+            coalesced.clone(),
+        );
+        let receiver = dispatcher.create_receiver().await.unwrap();
+        (dispatcher, receiver, coalesced)
+    }
+
+    async fn recv(
+        receiver: &mut Box<dyn ChangeReceiver<SourceEventWrapper>>,
+    ) -> Arc<SourceEventWrapper> {
+        tokio::time::timeout(Duration::from_secs(1), receiver.recv())
+            .await
+            .expect("timed out waiting for coalesced event")
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn merges_updates_of_same_element_into_latest() {
+        let (dispatcher, mut receiver, coalesced) = coalescing(Duration::from_millis(20)).await;
+
+        for value in 1..=5 {
+            dispatcher
+                .dispatch_change(update("s1", value))
+                .await
+                .unwrap();
+        }
+        dispatcher.dispatch_change(update("s2", 7)).await.unwrap();
+
+        assert_eq!(
+            describe(&recv(&mut receiver).await),
+            ("update", "s1".into(), 5)
+        );
+        assert_eq!(
+            describe(&recv(&mut receiver).await),
+            ("update", "s2".into(), 7)
+        );
+        assert_eq!(coalesced.get(), 4);
+        assert_eq!(dispatcher.queue_depth(), 0);
+    }
+
+    #[tokio::test]
+    async fn keeps_insert_and_delete_boundaries() {
+        let (dispatcher, mut receiver, coalesced) = coalescing(Duration::from_millis(20)).await;
+
+        let delete = event(SourceChange::Delete {
+            metadata: match node("s1", 0) {
+                Element::Node { metadata, .. } => metadata,
+                Element::Relation { metadata, .. } => metadata,
+            },
+        });
+        let changes = vec![
+            event(SourceChange::Insert {
+                element: node("s1", 1),
+            }),
+            update("s1", 2),
+            update("s1", 3),
+            delete,
+            update("s1", 4),
+        ];
+        dispatcher.dispatch_changes(changes).await.unwrap();
+
+        let mut received = Vec::new();
+        for _ in 0..4 {
+            received.push(describe(&recv(&mut receiver).await));
+        }
+        assert_eq!(
+            received,
+            vec![
+                ("insert", "s1".into(), 1),
+                ("update", "s1".into(), 3),
+                ("delete", "s1".into(), -1),
+                ("update", "s1".into(), 4),
+            ]
+        );
+        assert_eq!(coalesced.get(), 1);
+    }
+
+    #[tokio::test]
+    async fn control_events_are_not_held_back() {
+        let (dispatcher, mut receiver, _) = coalescing(Duration::from_secs(60)).await;
+
+        dispatcher.dispatch_change(update("s1", 1)).await.unwrap();
+        dispatcher
+            .dispatch_change(Arc::new(SourceEventWrapper::new(
+                "sensors".to_string(),
+                SourceEvent::Control(crate::channels::SourceControl::FuturesDue),
+                chrono::Utc::now(),
+            )))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            describe(&recv(&mut receiver).await),
+            ("update", "s1".into(), 1)
+        );
+        assert!(matches!(
+            recv(&mut receiver).await.event,
+            SourceEvent::Control(_)
+        ));
+    }
+}
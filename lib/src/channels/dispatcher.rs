@@ -1,9 +1,12 @@
 use anyhow::Result;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 use std::fmt::Debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
-use tokio::sync::{broadcast, mpsc};
+use std::time::Duration;
+use tokio::sync::{broadcast, mpsc, Notify};
 
 /// Event routing mode for distributing changes to subscribers
 ///
@@ -51,6 +54,26 @@ use tokio::sync::{broadcast, mpsc};
 /// - Higher memory usage (one copy per subscriber)
 /// - More overhead for high-fanout scenarios
 ///
+/// ## Batched Mode
+///
+/// Like Channel mode, but collects changes and sends them to each subscriber
+/// in vectors:
+///
+/// ```text
+/// Source → [buffer → Channel 1] → Query 1  (up to max_size changes per send)
+///       → [buffer → Channel 2] → Query 2
+/// ```
+///
+/// A batch is sent once it holds `max_size` changes, or `max_latency_ms` after
+/// its first change arrived, whichever comes first.
+///
+/// **Advantages**:
+/// - One channel send, and one priority queue lock in the query, per batch
+/// - Higher throughput for sources that emit bursts of changes
+///
+/// **Disadvantages**:
+/// - Adds up to `max_latency_ms` of delay when changes arrive slowly
+///
 /// # Configuration
 ///
 /// Set in YAML configuration or via builder API:
@@ -61,6 +84,13 @@ use tokio::sync::{broadcast, mpsc};
 ///     source_type: postgres
 ///     dispatch_mode: broadcast  # or channel (default)
 ///
+///   - id: bulk_source
+///     source_type: postgres
+///     dispatch_mode:
+///       batched:
+///         max_size: 500
+///         max_latency_ms: 10
+///
 /// queries:
 ///   - id: my_query
 ///     query: "MATCH (n) RETURN n"
@@ -82,6 +112,11 @@ use tokio::sync::{broadcast, mpsc};
 /// - Isolation between subscribers is important
 /// - Memory is not constrained
 ///
+/// **Use Batched when**:
+/// - The source emits changes in bursts (bulk loads, CDC transactions)
+/// - Per-change channel overhead limits throughput
+/// - A few milliseconds of added latency are acceptable
+///
 /// # Examples
 ///
 /// ## Builder API Configuration
@@ -162,6 +197,30 @@ pub enum DispatchMode {
     /// Channel mode: dedicated channel per subscriber (1-to-1)
     #[default]
     Channel,
+    /// Batched mode: dedicated channel per subscriber carrying vectors of changes
+    Batched {
+        /// Changes per batch; a full batch is sent immediately
+        max_size: usize,
+        /// Milliseconds a partial batch waits for more changes before it is sent
+        max_latency_ms: u64,
+    },
+}
+
+impl DispatchMode {
+    /// Batched mode sending up to `max_size` changes at once, and partial
+    /// batches after `max_latency`.
+    pub fn batched(max_size: usize, max_latency: Duration) -> Self {
+        Self::Batched {
+            max_size,
+            max_latency_ms: max_latency.as_millis() as u64,
+        }
+    }
+
+    /// Whether each subscriber has a dedicated channel, so a slow subscriber
+    /// applies backpressure rather than missing changes.
+    pub fn is_dedicated(&self) -> bool {
+        !matches!(self, Self::Broadcast)
+    }
 }
 
 /// Trait for dispatching changes to subscribers
@@ -183,6 +242,11 @@ where
 
     /// Create a new receiver for this dispatcher
     async fn create_receiver(&self) -> Result<Box<dyn ChangeReceiver<T>>>;
+
+    /// Number of changes dispatched but not yet received, for metrics.
+    fn queue_depth(&self) -> usize {
+        0
+    }
 }
 
 /// Trait for receiving changes from a dispatcher
@@ -193,6 +257,14 @@ where
 {
     /// Receive the next change
     async fn recv(&mut self) -> Result<Arc<T>>;
+
+    /// Receive the next changes, waiting until at least one is available
+    ///
+    /// Receivers of [`DispatchMode::Batched`] return a whole batch at once;
+    /// others return a single change.
+    async fn recv_batch(&mut self) -> Result<Vec<Arc<T>>> {
+        Ok(vec![self.recv().await?])
+    }
 }
 
 /// Broadcast-based implementation of ChangeDispatcher
@@ -233,6 +305,10 @@ where
         let rx = self.tx.subscribe();
         Ok(Box::new(BroadcastChangeReceiver { rx }))
     }
+
+    fn queue_depth(&self) -> usize {
+        self.tx.len()
+    }
 }
 
 /// Broadcast-based implementation of ChangeReceiver
@@ -311,6 +387,10 @@ where
         })?;
         Ok(Box::new(ChannelChangeReceiver { rx }))
     }
+
+    fn queue_depth(&self) -> usize {
+        self.tx.max_capacity() - self.tx.capacity()
+    }
 }
 
 /// Channel-based (MPSC) implementation of ChangeReceiver
@@ -334,6 +414,239 @@ where
     }
 }
 
+/// Changes collected for the next batch
+struct PendingBatch<T> {
+    changes: Vec<Arc<T>>,
+    /// When the first change of the batch arrived
+    started: Option<tokio::time::Instant>,
+}
+
+/// State shared between a batching dispatcher and its latency flush task
+struct BatchState<T> {
+    tx: mpsc::Sender<Vec<Arc<T>>>,
+    /// Held while a batch is sent, so batches leave in the order they were filled
+    pending: tokio::sync::Mutex<PendingBatch<T>>,
+    /// Signalled when a change arrives in an empty batch
+    started: Notify,
+    max_size: usize,
+    max_latency: Duration,
+    /// Changes dispatched but not yet received
+    depth: Arc<AtomicUsize>,
+}
+
+impl<T> BatchState<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    /// Send the pending changes as one batch. Caller holds the `pending` lock.
+    async fn flush(&self, pending: &mut PendingBatch<T>) -> Result<()> {
+        pending.started = None;
+        if pending.changes.is_empty() {
+            return Ok(());
+        }
+        let batch = std::mem::replace(&mut pending.changes, Vec::with_capacity(self.max_size));
+        if let Err(mpsc::error::SendError(batch)) = self.tx.send(batch).await {
+            self.depth.fetch_sub(batch.len(), Ordering::Relaxed);
+            return Err(anyhow::anyhow!("Failed to send batch on channel"));
+        }
+        Ok(())
+    }
+}
+
+/// Batching (MPSC) implementation of ChangeDispatcher
+///
+/// Collects changes and sends them in vectors of up to `max_size`. A partial
+/// batch is sent by a background task `max_latency` after its first change
+/// arrived, which stops when the dispatcher is dropped.
+pub struct BatchChangeDispatcher<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    state: Arc<BatchState<T>>,
+    rx: Arc<tokio::sync::Mutex<Option<mpsc::Receiver<Vec<Arc<T>>>>>>,
+    flusher: tokio::task::JoinHandle<()>,
+}
+
+impl<T> BatchChangeDispatcher<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    /// Create a batching dispatcher buffering up to `capacity` batches
+    pub fn new(capacity: usize, max_size: usize, max_latency: Duration) -> Self {
+        let max_size = max_size.max(1);
+        let (tx, rx) = mpsc::channel(capacity.max(1));
+        let state = Arc::new(BatchState {
+            tx,
+            pending: tokio::sync::Mutex::new(PendingBatch {
+                changes: Vec::with_capacity(max_size),
+                started: None,
+            }),
+            started: Notify::new(),
+            max_size,
+            max_latency,
+            depth: Arc::new(AtomicUsize::new(0)),
+        });
+        let flusher = tokio::spawn(Self::flush_on_latency(state.clone()));
+        Self {
+            state,
+            rx: Arc::new(tokio::sync::Mutex::new(Some(rx))),
+            flusher,
+        }
+    }
+
+    async fn flush_on_latency(state: Arc<BatchState<T>>) {
+        loop {
+            state.started.notified().await;
+            loop {
+                let mut pending = state.pending.lock().await;
+                let Some(started) = pending.started else {
+                    // Sent in full before the latency ran out
+                    break;
+                };
+                let deadline = started + state.max_latency;
+                if tokio::time::Instant::now() >= deadline {
+                    if let Err(e) = state.flush(&mut pending).await {
+                        log::debug!("Dropping batch: {e}");
+                    }
+                    break;
+                }
+                drop(pending);
+                tokio::time::sleep_until(deadline).await;
+            }
+        }
+    }
+}
+
+impl<T> Drop for BatchChangeDispatcher<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    fn drop(&mut self) {
+        self.flusher.abort();
+    }
+}
+
+#[async_trait]
+impl<T> ChangeDispatcher<T> for BatchChangeDispatcher<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    async fn dispatch_change(&self, change: Arc<T>) -> Result<()> {
+        self.dispatch_changes(vec![change]).await
+    }
+
+    async fn dispatch_changes(&self, changes: Vec<Arc<T>>) -> Result<()> {
+        let mut pending = self.state.pending.lock().await;
+        for change in changes {
+            if pending.started.is_none() {
+                pending.started = Some(tokio::time::Instant::now());
+                self.state.started.notify_one();
+            }
+            pending.changes.push(change);
+            self.state.depth.fetch_add(1, Ordering::Relaxed);
+            if pending.changes.len() >= self.state.max_size {
+                self.state.flush(&mut pending).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn create_receiver(&self) -> Result<Box<dyn ChangeReceiver<T>>> {
+        // Like channel mode, there is a single receiver per dispatcher
+        let mut rx_opt = self.rx.lock().await;
+        let rx = rx_opt
+            .take()
+            .ok_or_else(|| anyhow::anyhow!("Receiver already created for this batch dispatcher"))?;
+        Ok(Box::new(BatchChangeReceiver {
+            rx,
+            buffered: VecDeque::new(),
+            depth: self.state.depth.clone(),
+        }))
+    }
+
+    fn queue_depth(&self) -> usize {
+        self.state.depth.load(Ordering::Relaxed)
+    }
+}
+
+/// Batching (MPSC) implementation of ChangeReceiver
+pub struct BatchChangeReceiver<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    rx: mpsc::Receiver<Vec<Arc<T>>>,
+    /// Rest of the last batch, for callers of `recv()`
+    buffered: VecDeque<Arc<T>>,
+    depth: Arc<AtomicUsize>,
+}
+
+impl<T> BatchChangeReceiver<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    async fn next_batch(&mut self) -> Result<Vec<Arc<T>>> {
+        let batch = self
+            .rx
+            .recv()
+            .await
+            .ok_or_else(|| anyhow::anyhow!("Channel closed"))?;
+        self.depth.fetch_sub(batch.len(), Ordering::Relaxed);
+        Ok(batch)
+    }
+}
+
+#[async_trait]
+impl<T> ChangeReceiver<T> for BatchChangeReceiver<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    async fn recv(&mut self) -> Result<Arc<T>> {
+        loop {
+            if let Some(change) = self.buffered.pop_front() {
+                return Ok(change);
+            }
+            self.buffered = self.next_batch().await?.into();
+        }
+    }
+
+    async fn recv_batch(&mut self) -> Result<Vec<Arc<T>>> {
+        if !self.buffered.is_empty() {
+            return Ok(self.buffered.drain(..).collect());
+        }
+        loop {
+            let batch = self.next_batch().await?;
+            if !batch.is_empty() {
+                return Ok(batch);
+            }
+        }
+    }
+}
+
+/// Create the dispatcher giving one subscriber a dedicated channel in `mode`.
+///
+/// Returns `None` for Broadcast mode, where subscribers share one dispatcher.
+pub fn dedicated_dispatcher<T>(
+    mode: DispatchMode,
+    capacity: usize,
+) -> Option<Box<dyn ChangeDispatcher<T> + Send + Sync>>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    match mode {
+        DispatchMode::Broadcast => None,
+        DispatchMode::Channel => Some(Box::new(ChannelChangeDispatcher::new(capacity))),
+        DispatchMode::Batched {
+            max_size,
+            max_latency_ms,
+        } => Some(Box::new(BatchChangeDispatcher::new(
+            // The channel holds batches, so it needs room for fewer entries
+            capacity.div_ceil(max_size.max(1)),
+            max_size,
+            Duration::from_millis(max_latency_ms),
+        ))),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -504,4 +817,126 @@ mod tests {
         let deserialized: DispatchMode = serde_json::from_str(&json).unwrap();
         assert_eq!(deserialized, DispatchMode::Channel);
     }
+
+    #[tokio::test]
+    async fn test_queue_depth_tracks_undelivered_changes() {
+        let channel = ChannelChangeDispatcher::<TestMessage>::new(10);
+        let mut channel_rx = channel.create_receiver().await.unwrap();
+        let broadcast = BroadcastChangeDispatcher::<TestMessage>::new(10);
+        let mut broadcast_rx = broadcast.create_receiver().await.unwrap();
+
+        for id in 0..3 {
+            let msg = Arc::new(TestMessage {
+                id,
+                content: "depth".to_string(),
+            });
+            channel.dispatch_change(msg.clone()).await.unwrap();
+            broadcast.dispatch_change(msg).await.unwrap();
+        }
+        assert_eq!(channel.queue_depth(), 3);
+        assert_eq!(broadcast.queue_depth(), 3);
+
+        channel_rx.recv().await.unwrap();
+        broadcast_rx.recv().await.unwrap();
+        assert_eq!(channel.queue_depth(), 2);
+        assert_eq!(broadcast.queue_depth(), 2);
+    }
+
+    fn test_messages(ids: std::ops::Range<u32>) -> Vec<Arc<TestMessage>> {
+        ids.map(|id| {
+            Arc::new(TestMessage {
+                id,
+                content: "batch".to_string(),
+            })
+        })
+        .collect()
+    }
+
+    #[tokio::test]
+    async fn test_batch_dispatcher_sends_full_batches() {
+        let dispatcher = BatchChangeDispatcher::<TestMessage>::new(10, 3, Duration::from_secs(60));
+        let mut receiver = dispatcher.create_receiver().await.unwrap();
+
+        dispatcher
+            .dispatch_changes(test_messages(0..7))
+            .await
+            .unwrap();
+
+        let first = receiver.recv_batch().await.unwrap();
+        let second = receiver.recv_batch().await.unwrap();
+        assert_eq!(
+            first.iter().map(|m| m.id).collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+        assert_eq!(
+            second.iter().map(|m| m.id).collect::<Vec<_>>(),
+            vec![3, 4, 5]
+        );
+        // The last change waits for more, well within the latency
+        assert_eq!(dispatcher.queue_depth(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_batch_dispatcher_flushes_partial_batch_after_latency() {
+        let dispatcher =
+            BatchChangeDispatcher::<TestMessage>::new(10, 100, Duration::from_millis(20));
+        let mut receiver = dispatcher.create_receiver().await.unwrap();
+
+        for message in test_messages(0..2) {
+            dispatcher.dispatch_change(message).await.unwrap();
+        }
+
+        let batch = tokio::time::timeout(Duration::from_secs(1), receiver.recv_batch())
+            .await
+            .expect("partial batch should be sent after the latency")
+            .unwrap();
+        assert_eq!(batch.len(), 2);
+        assert_eq!(dispatcher.queue_depth(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_batch_receiver_recv_yields_changes_in_order() {
+        let dispatcher =
+            BatchChangeDispatcher::<TestMessage>::new(10, 2, Duration::from_millis(10));
+        let mut receiver = dispatcher.create_receiver().await.unwrap();
+
+        dispatcher
+            .dispatch_changes(test_messages(0..5))
+            .await
+            .unwrap();
+
+        for expected in 0..5 {
+            let message = tokio::time::timeout(Duration::from_secs(1), receiver.recv())
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(message.id, expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dedicated_dispatcher_by_mode() {
+        assert!(dedicated_dispatcher::<TestMessage>(DispatchMode::Broadcast, 10).is_none());
+        assert!(dedicated_dispatcher::<TestMessage>(DispatchMode::Channel, 10).is_some());
+        let batched = DispatchMode::batched(50, Duration::from_millis(5));
+        assert!(dedicated_dispatcher::<TestMessage>(batched, 10).is_some());
+        assert!(batched.is_dedicated());
+        assert!(!DispatchMode::Broadcast.is_dedicated());
+    }
+
+    #[test]
+    fn test_batched_mode_serde() {
+        let mode: DispatchMode =
+            serde_json::from_str(r#"{"batched": {"max_size": 500, "max_latency_ms": 10}}"#)
+                .unwrap();
+        assert_eq!(
+            mode,
+            DispatchMode::Batched {
+                max_size: 500,
+                max_latency_ms: 10
+            }
+        );
+        let mode: DispatchMode = serde_json::from_str(r#""channel""#).unwrap();
+        assert_eq!(mode, DispatchMode::Channel);
+    }
 }
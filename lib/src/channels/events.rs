@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::ordering::OrderingKey;
 use crate::profiling::ProfilingMetadata;
 use drasi_core::models::SourceChange;
 use serde::{Deserialize, Serialize};
@@ -227,6 +228,9 @@ pub struct SourceEventWrapper {
     /// `None` for volatile sources that don't support replay.
     /// When present, must be strictly increasing per source.
     pub sequence: Option<u64>,
+    /// Ordering key set by the source's [`OrderingKeyExtractor`](super::OrderingKeyExtractor).
+    /// `None` orders the change by its element id.
+    pub ordering_key: Option<OrderingKey>,
 }
 
 impl SourceEventWrapper {
@@ -242,6 +246,7 @@ impl SourceEventWrapper {
             timestamp,
             profiling: None,
             sequence: None,
+            ordering_key: None,
         }
     }
 
@@ -258,6 +263,7 @@ impl SourceEventWrapper {
             timestamp,
             profiling: Some(profiling),
             sequence: None,
+            ordering_key: None,
         }
     }
 
@@ -275,9 +281,16 @@ impl SourceEventWrapper {
             timestamp,
             profiling,
             sequence: Some(sequence),
+            ordering_key: None,
         }
     }
 
+    /// Set the ordering key of the wrapped change
+    pub fn with_ordering_key(mut self, key: OrderingKey) -> Self {
+        self.ordering_key = Some(key);
+        self
+    }
+
     /// Consume this wrapper and return its components.
     /// This enables zero-copy extraction when the wrapper has sole ownership.
     pub fn into_parts(
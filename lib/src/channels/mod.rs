@@ -12,16 +12,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod coalesce;
 pub mod dispatcher;
 pub mod events;
+pub mod ordering;
 pub mod priority_queue;
 
 #[cfg(test)]
 mod events_test;
 
+pub use coalesce::CoalescingChangeDispatcher;
 pub use dispatcher::{
-    BroadcastChangeDispatcher, BroadcastChangeReceiver, ChangeDispatcher, ChangeReceiver,
-    ChannelChangeDispatcher, ChannelChangeReceiver, DispatchMode,
+    dedicated_dispatcher, BatchChangeDispatcher, BatchChangeReceiver, BroadcastChangeDispatcher,
+    BroadcastChangeReceiver, ChangeDispatcher, ChangeReceiver, ChannelChangeDispatcher,
+    ChannelChangeReceiver, DispatchMode,
 };
 pub use events::*;
+pub use ordering::{default_ordering_key, OrderingKey, OrderingKeyExtractor};
 pub use priority_queue::{PriorityQueue, PriorityQueueMetrics};
@@ -0,0 +1,95 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Ordering keys of source changes.
+//!
+//! Element-local queries that evaluate on an
+//! [`EvaluationPool`](crate::queries::EvaluationPool) only keep the order of
+//! changes that share an ordering key; results of changes with different keys
+//! are dispatched in parallel.
+//!
+//! By default the key of a change is the id of the element it touches, so each
+//! element's changes stay in order. A source whose upstream already orders
+//! changes by a coarser key, such as a Kafka partition or an MQTT topic, can
+//! supply an [`OrderingKeyExtractor`] through
+//! [`SourceBaseParams::with_ordering_key`](crate::sources::SourceBaseParams::with_ordering_key)
+//! so that all changes sharing that key keep their upstream order.
+
+use drasi_core::models::SourceChange;
+use std::sync::Arc;
+
+/// Key under which source changes keep their relative order
+pub type OrderingKey = Arc<str>;
+
+/// Derives the ordering key of a source change.
+///
+/// All changes of an element must map to the same key, or two changes of the
+/// element could be evaluated out of order. Deletes carry only the element's
+/// metadata, so keys are best derived from the element reference or labels
+/// rather than from properties.
+///
+/// Implemented for closures, so a source can pass
+/// `|change: &SourceChange| -> OrderingKey { .. }` directly.
+pub trait OrderingKeyExtractor: Send + Sync {
+    fn ordering_key(&self, change: &SourceChange) -> OrderingKey;
+}
+
+impl<F> OrderingKeyExtractor for F
+where
+    F: Fn(&SourceChange) -> OrderingKey + Send + Sync,
+{
+    fn ordering_key(&self, change: &SourceChange) -> OrderingKey {
+        self(change)
+    }
+}
+
+/// The ordering key of a change from a source without an extractor: its element id
+pub fn default_ordering_key(change: &SourceChange) -> OrderingKey {
+    change.get_reference().element_id.clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use drasi_core::models::{ElementMetadata, ElementReference};
+
+    fn delete(id: &str) -> SourceChange {
+        SourceChange::Delete {
+            metadata: ElementMetadata {
+                reference: ElementReference::new("sensors", id),
+                labels: Arc::from(vec![Arc::from("Sensor")]),
+                effective_from: 0,
+            },
+        }
+    }
+
+    #[test]
+    fn default_key_is_element_id() {
+        assert_eq!(&*default_ordering_key(&delete("s1")), "s1");
+    }
+
+    #[test]
+    fn closures_extract_keys() {
+        // Key by the element id prefix, as a source partitioned by site would
+        let extractor: Box<dyn OrderingKeyExtractor> = Box::new(|change: &SourceChange| {
+            let id = &change.get_reference().element_id;
+            OrderingKey::from(id.split(':').next().unwrap_or_default())
+        });
+        assert_eq!(&*extractor.ordering_key(&delete("site-a:s1")), "site-a");
+        assert_eq!(
+            extractor.ordering_key(&delete("site-a:s1")),
+            extractor.ordering_key(&delete("site-a:s2"))
+        );
+    }
+}
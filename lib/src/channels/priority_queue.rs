@@ -211,30 +211,7 @@ where
 
         // Enqueue event
         heap.push(PriorityQueueEvent::new(event));
-
-        // Update metrics using atomic operations (lock-free)
-        self.metrics
-            .total_enqueued
-            .fetch_add(1, AtomicOrdering::Relaxed);
-        let current_depth = heap.len();
-        self.metrics
-            .current_depth
-            .store(current_depth, AtomicOrdering::Relaxed);
-
-        // Update max_depth_seen if needed (using compare-exchange loop)
-        let mut max_seen = self.metrics.max_depth_seen.load(AtomicOrdering::Relaxed);
-        while current_depth > max_seen {
-            match self.metrics.max_depth_seen.compare_exchange_weak(
-                max_seen,
-                current_depth,
-                AtomicOrdering::Relaxed,
-                AtomicOrdering::Relaxed,
-            ) {
-                Ok(_) => break,
-                Err(x) => max_seen = x,
-            }
-        }
-
+        self.record_enqueued(1, heap.len());
         drop(heap);
 
         // Notify waiting dequeuers
@@ -262,30 +239,7 @@ where
             if heap.len() < self.max_capacity {
                 // Space available - enqueue the event
                 heap.push(PriorityQueueEvent::new(event));
-
-                // Update metrics using atomic operations (lock-free)
-                self.metrics
-                    .total_enqueued
-                    .fetch_add(1, AtomicOrdering::Relaxed);
-                let current_depth = heap.len();
-                self.metrics
-                    .current_depth
-                    .store(current_depth, AtomicOrdering::Relaxed);
-
-                // Update max_depth_seen if needed (using compare-exchange loop)
-                let mut max_seen = self.metrics.max_depth_seen.load(AtomicOrdering::Relaxed);
-                while current_depth > max_seen {
-                    match self.metrics.max_depth_seen.compare_exchange_weak(
-                        max_seen,
-                        current_depth,
-                        AtomicOrdering::Relaxed,
-                        AtomicOrdering::Relaxed,
-                    ) {
-                        Ok(_) => break,
-                        Err(x) => max_seen = x,
-                    }
-                }
-
+                self.record_enqueued(1, heap.len());
                 drop(heap);
 
                 // Notify waiting dequeuers
@@ -318,6 +272,70 @@ where
         }
     }
 
+    /// Enqueue a batch of events, waiting while the queue is at capacity
+    ///
+    /// Takes the queue lock once for as many events as fit, rather than once
+    /// per event. Like `enqueue_wait()`, it never drops events and must not be
+    /// used with Broadcast dispatch mode.
+    pub async fn enqueue_batch_wait(&self, events: Vec<Arc<T>>) {
+        let mut events = events.into_iter().peekable();
+        while events.peek().is_some() {
+            // Register notified future BEFORE acquiring lock to avoid race
+            let notified = self.notify.notified();
+            tokio::pin!(notified);
+
+            let mut heap = self.heap.lock().await;
+            let room = self.max_capacity.saturating_sub(heap.len());
+            if room > 0 {
+                let before = heap.len();
+                heap.extend(events.by_ref().take(room).map(PriorityQueueEvent::new));
+                let added = heap.len() - before;
+                self.record_enqueued(added, heap.len());
+                drop(heap);
+
+                // One dequeuer per event may be waiting
+                for _ in 0..added {
+                    self.notify.notify_one();
+                }
+                continue;
+            }
+
+            self.metrics
+                .blocked_enqueue_count
+                .fetch_add(1, AtomicOrdering::Relaxed);
+
+            // Drop lock and wait for dequeue to create space
+            notified.as_mut().enable();
+            drop(heap);
+            notified.await;
+        }
+    }
+
+    /// Update metrics after `added` events were enqueued, leaving `current_depth`
+    fn record_enqueued(&self, added: usize, current_depth: usize) {
+        // Update metrics using atomic operations (lock-free)
+        self.metrics
+            .total_enqueued
+            .fetch_add(added as u64, AtomicOrdering::Relaxed);
+        self.metrics
+            .current_depth
+            .store(current_depth, AtomicOrdering::Relaxed);
+
+        // Update max_depth_seen if needed (using compare-exchange loop)
+        let mut max_seen = self.metrics.max_depth_seen.load(AtomicOrdering::Relaxed);
+        while current_depth > max_seen {
+            match self.metrics.max_depth_seen.compare_exchange_weak(
+                max_seen,
+                current_depth,
+                AtomicOrdering::Relaxed,
+                AtomicOrdering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(x) => max_seen = x,
+            }
+        }
+    }
+
     /// Dequeue the oldest event from the priority queue (non-blocking)
     /// Returns None if queue is empty
     pub async fn try_dequeue(&self) -> Option<Arc<T>> {
@@ -387,6 +405,11 @@ where
         heap.is_empty()
     }
 
+    /// Shared handle to the live metrics, readable without awaiting.
+    pub fn metrics_handle(&self) -> Arc<PriorityQueueMetrics> {
+        self.metrics.clone()
+    }
+
     /// Get current metrics snapshot
     pub async fn metrics(&self) -> MetricsSnapshot {
         self.metrics.snapshot()
@@ -759,4 +782,32 @@ mod tests {
 
         assert_eq!(pq.depth().await, 2);
     }
+
+    #[tokio::test]
+    async fn test_enqueue_batch_wait_blocks_for_remainder() {
+        let pq = PriorityQueue::new(2);
+        let now = Utc::now();
+        let batch = (0..3)
+            .map(|i| create_test_event(&format!("event{i}"), now + chrono::Duration::seconds(i)))
+            .collect();
+
+        // Two of the three events fit; the third waits for a dequeue
+        let pq_clone = pq.clone();
+        let enqueue_task = tokio::spawn(async move {
+            pq_clone.enqueue_batch_wait(batch).await;
+        });
+        tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
+        assert!(!enqueue_task.is_finished());
+        assert_eq!(pq.depth().await, 2);
+
+        assert_eq!(pq.dequeue().await.id, "event0");
+        tokio::time::timeout(tokio::time::Duration::from_millis(100), enqueue_task)
+            .await
+            .expect("enqueue_batch_wait should complete")
+            .expect("Task should not panic");
+
+        assert_eq!(pq.dequeue().await.id, "event1");
+        assert_eq!(pq.dequeue().await.id, "event2");
+        assert_eq!(pq.metrics().await.total_enqueued, 3);
+    }
 }
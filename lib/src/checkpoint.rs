@@ -0,0 +1,231 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Checkpoint storage for resuming sources and queries after a restart.
+//!
+//! Two kinds of checkpoints are kept:
+//!
+//! - **Source positions**: an opaque, source-defined consumer position such as
+//!   an MQTT packet id, a Kafka offset or a Postgres LSN. Sources save and load
+//!   them through [`SourceBase::save_checkpoint`](crate::SourceBase::save_checkpoint)
+//!   and [`SourceBase::load_checkpoint`](crate::SourceBase::load_checkpoint).
+//! - **Query sequences**: for queries backed by a persistent storage backend,
+//!   the last source sequence number each query processed, per source. On
+//!   restart the query asks replay-capable sources to resume after that
+//!   sequence instead of re-bootstrapping, and drops replayed events it has
+//!   already applied.
+//!
+//! The store is pluggable via
+//! [`DrasiLibBuilder::with_checkpoint_store`](crate::DrasiLibBuilder::with_checkpoint_store).
+//! By default checkpoints are kept in the configured
+//! [`StateStoreProvider`], so a persistent state store (e.g. redb) is all that
+//! is needed for checkpoints to survive restarts.
+//!
+//! Query checkpoints are written after the query's index session commits, so
+//! delivery is at-least-once: a crash between the two writes replays the last
+//! event.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::state_store::{StateStoreError, StateStoreProvider, StateStoreResult};
+
+/// Persistent storage for source positions and query sequence checkpoints.
+#[async_trait]
+pub trait CheckpointStore: Send + Sync {
+    /// Load the last processed sequence of `query_id`, keyed by source ID.
+    async fn load_query_checkpoints(
+        &self,
+        query_id: &str,
+    ) -> StateStoreResult<HashMap<String, u64>>;
+
+    /// Record that `query_id` has processed `source_id` up to and including `sequence`.
+    async fn save_query_checkpoint(
+        &self,
+        query_id: &str,
+        source_id: &str,
+        sequence: u64,
+    ) -> StateStoreResult<()>;
+
+    /// Forget all checkpoints of `query_id` (e.g. when the query is removed or reset).
+    async fn clear_query_checkpoints(&self, query_id: &str) -> StateStoreResult<()>;
+
+    /// Load the consumer position last saved by `source_id`.
+    async fn load_source_position(&self, source_id: &str) -> StateStoreResult<Option<Vec<u8>>>;
+
+    /// Save the consumer position of `source_id`, replacing any previous one.
+    async fn save_source_position(
+        &self,
+        source_id: &str,
+        position: Vec<u8>,
+    ) -> StateStoreResult<()>;
+
+    /// Forget the consumer position of `source_id`.
+    async fn clear_source_position(&self, source_id: &str) -> StateStoreResult<()>;
+}
+
+/// Store partition holding source positions.
+const SOURCE_POSITIONS_STORE_ID: &str = "__drasi_checkpoints:sources";
+
+/// [`CheckpointStore`] backed by a [`StateStoreProvider`].
+///
+/// Source positions share one partition keyed by source ID; each query gets its
+/// own partition keyed by source ID holding the sequence as big-endian bytes.
+pub struct StateStoreCheckpointStore {
+    state_store: Arc<dyn StateStoreProvider>,
+}
+
+impl StateStoreCheckpointStore {
+    /// Create a checkpoint store on top of `state_store`.
+    pub fn new(state_store: Arc<dyn StateStoreProvider>) -> Self {
+        Self { state_store }
+    }
+
+    fn query_store_id(query_id: &str) -> String {
+        format!("__drasi_checkpoints:query:{query_id}")
+    }
+}
+
+#[async_trait]
+impl CheckpointStore for StateStoreCheckpointStore {
+    async fn load_query_checkpoints(
+        &self,
+        query_id: &str,
+    ) -> StateStoreResult<HashMap<String, u64>> {
+        let store_id = Self::query_store_id(query_id);
+        let keys = self.state_store.list_keys(&store_id).await?;
+        let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+        let values = self.state_store.get_many(&store_id, &keys).await?;
+
+        values
+            .into_iter()
+            .map(|(source_id, bytes)| {
+                let bytes: [u8; 8] = bytes.as_slice().try_into().map_err(|_| {
+                    StateStoreError::SerializationError(format!(
+                        "Invalid checkpoint for query '{query_id}', source '{source_id}': expected 8 bytes, got {}",
+                        bytes.len()
+                    ))
+                })?;
+                Ok((source_id, u64::from_be_bytes(bytes)))
+            })
+            .collect()
+    }
+
+    async fn save_query_checkpoint(
+        &self,
+        query_id: &str,
+        source_id: &str,
+        sequence: u64,
+    ) -> StateStoreResult<()> {
+        self.state_store
+            .set(
+                &Self::query_store_id(query_id),
+                source_id,
+                sequence.to_be_bytes().to_vec(),
+            )
+            .await
+    }
+
+    async fn clear_query_checkpoints(&self, query_id: &str) -> StateStoreResult<()> {
+        self.state_store
+            .clear_store(&Self::query_store_id(query_id))
+            .await
+            .map(|_| ())
+    }
+
+    async fn load_source_position(&self, source_id: &str) -> StateStoreResult<Option<Vec<u8>>> {
+        self.state_store
+            .get(SOURCE_POSITIONS_STORE_ID, source_id)
+            .await
+    }
+
+    async fn save_source_position(
+        &self,
+        source_id: &str,
+        position: Vec<u8>,
+    ) -> StateStoreResult<()> {
+        self.state_store
+            .set(SOURCE_POSITIONS_STORE_ID, source_id, position)
+            .await
+    }
+
+    async fn clear_source_position(&self, source_id: &str) -> StateStoreResult<()> {
+        self.state_store
+            .delete(SOURCE_POSITIONS_STORE_ID, source_id)
+            .await
+            .map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state_store::MemoryStateStoreProvider;
+
+    fn store() -> StateStoreCheckpointStore {
+        StateStoreCheckpointStore::new(Arc::new(MemoryStateStoreProvider::new()))
+    }
+
+    #[tokio::test]
+    async fn test_query_checkpoints_round_trip() {
+        let store = store();
+        assert!(store.load_query_checkpoints("q1").await.unwrap().is_empty());
+
+        store.save_query_checkpoint("q1", "s1", 10).await.unwrap();
+        store.save_query_checkpoint("q1", "s2", 20).await.unwrap();
+        store.save_query_checkpoint("q1", "s1", 11).await.unwrap();
+        store.save_query_checkpoint("q2", "s1", 99).await.unwrap();
+
+        let checkpoints = store.load_query_checkpoints("q1").await.unwrap();
+        assert_eq!(checkpoints.len(), 2);
+        assert_eq!(checkpoints["s1"], 11);
+        assert_eq!(checkpoints["s2"], 20);
+
+        store.clear_query_checkpoints("q1").await.unwrap();
+        assert!(store.load_query_checkpoints("q1").await.unwrap().is_empty());
+        assert_eq!(store.load_query_checkpoints("q2").await.unwrap()["s1"], 99);
+    }
+
+    #[tokio::test]
+    async fn test_source_position_round_trip() {
+        let store = store();
+        assert!(store.load_source_position("s1").await.unwrap().is_none());
+
+        store
+            .save_source_position("s1", b"0/16B6C50".to_vec())
+            .await
+            .unwrap();
+        assert_eq!(
+            store.load_source_position("s1").await.unwrap(),
+            Some(b"0/16B6C50".to_vec())
+        );
+
+        store.clear_source_position("s1").await.unwrap();
+        assert!(store.load_source_position("s1").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_corrupt_query_checkpoint_is_reported() {
+        let state_store = Arc::new(MemoryStateStoreProvider::new());
+        state_store
+            .set("__drasi_checkpoints:query:q1", "s1", vec![1, 2, 3])
+            .await
+            .unwrap();
+        let store = StateStoreCheckpointStore::new(state_store);
+
+        let err = store.load_query_checkpoints("q1").await.unwrap_err();
+        assert!(matches!(err, StateStoreError::SerializationError(_)));
+    }
+}
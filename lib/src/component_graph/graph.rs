@@ -22,7 +22,7 @@ use petgraph::Direction;
 use tokio::sync::{broadcast, mpsc, Notify};
 
 use crate::channels::{ComponentEvent, ComponentEventBroadcastReceiver, ComponentStatus};
-use crate::managers::ComponentEventHistory;
+use crate::managers::{ComponentEventHistory, EventLogEntry, EventLogFilter};
 
 use super::transaction::GraphTransaction;
 use super::{
@@ -434,20 +434,23 @@ impl ComponentGraph {
         self.index.remove(id);
         // Remove runtime instance if present (atomic with node removal)
         self.runtimes.remove(id);
-        // Remove event history for this component
-        self.event_history.remove_component(id);
         // StableGraph::remove_node automatically removes all edges connected to this node
         let removed = self
             .graph
             .remove_node(node_idx)
             .ok_or_else(|| anyhow::anyhow!("Component '{id}' already removed"))?;
 
-        self.emit_event(
+        if let Some(event) = self.emit_event(
             id,
             &kind,
             ComponentStatus::Removed,
             Some(format!("{kind} removed")),
-        );
+        ) {
+            // Keep the removal in the instance-wide event log
+            self.event_history.record_event(event);
+        }
+        // Remove event history for this component
+        self.event_history.remove_component(id);
 
         Ok(removed)
     }
@@ -1142,6 +1145,18 @@ impl ComponentGraph {
         self.event_history.record_event(event);
     }
 
+    /// Record and broadcast an event for a component without changing its status.
+    ///
+    /// Used for notices about a component, such as a scheduled restart, that
+    /// should appear in its history alongside the status changes.
+    pub fn record_notice(&mut self, id: &str, message: Option<String>) -> Option<ComponentEvent> {
+        let node = self.get_component(id)?;
+        let (kind, status) = (node.kind.clone(), node.status);
+        let event = self.emit_event(id, &kind, status, message)?;
+        self.event_history.record_event(event.clone());
+        Some(event)
+    }
+
     /// Get all lifecycle events for a specific component.
     ///
     /// Returns events in chronological order (oldest first).
@@ -1162,6 +1177,21 @@ impl ComponentGraph {
         self.event_history.get_last_error(component_id)
     }
 
+    /// Get the entries of the instance-wide event log selected by `filter`.
+    ///
+    /// Returns entries in sequence order (oldest first). Entries are kept after
+    /// their component is removed, up to 1000 most recent events in total.
+    pub fn get_event_log(&self, filter: &EventLogFilter) -> Vec<EventLogEntry> {
+        self.event_history.get_log(filter)
+    }
+
+    /// Subscribe to the instance-wide event log.
+    ///
+    /// Returns the retained log and a broadcast receiver for entries recorded after it.
+    pub fn subscribe_event_log(&self) -> (Vec<EventLogEntry>, broadcast::Receiver<EventLogEntry>) {
+        self.event_history.subscribe_log()
+    }
+
     /// Subscribe to live lifecycle events for a component.
     ///
     /// Returns the current history and a broadcast receiver for new events,
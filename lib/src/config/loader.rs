@@ -0,0 +1,420 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Declarative configuration files for DrasiLib.
+//!
+//! A configuration document describes a whole DrasiLib instance: the global
+//! settings from [`DrasiLibConfig`], plus the sources and reactions to create.
+//! Sources and reactions are plugin instances, so each entry names a `kind`
+//! that is resolved through a [`ComponentRegistry`] of factories supplied by
+//! the application.
+//!
+//! ```yaml
+//! id: my-app
+//! priority_queue_capacity: 50000
+//!
+//! sources:
+//!   - id: orders
+//!     kind: postgres
+//!     host: ${PG_HOST:-localhost}
+//!     password: ${PG_PASSWORD}
+//!
+//! queries:
+//!   - id: large-orders
+//!     query: MATCH (o:Order) WHERE o.total > 1000 RETURN o
+//!     sources:
+//!       - source_id: orders
+//!
+//! reactions:
+//!   - id: notify
+//!     kind: kafka
+//!     queries: [large-orders]
+//!     topic: ${ORDER_TOPIC}
+//! ```
+//!
+//! All keys on a source or reaction entry other than `id`, `kind`, `autoStart`
+//! (and `queries` for reactions) are passed to the factory as the component's
+//! JSON configuration.
+//!
+//! # Environment Interpolation
+//!
+//! Environment variables are interpolated after the document is parsed, and
+//! only into string values, so comments, keys and the document structure are
+//! never rewritten. The references follow the plugin SDK's `ConfigValue`
+//! rules: `${NAME}` is the value of environment variable `NAME` and
+//! `${NAME:-fallback}` falls back to `fallback` when `NAME` is unset.
+//! Referencing an unset variable without a fallback is an error. `$$`
+//! produces a literal `$`.
+//!
+//! A value that is exactly one reference, such as `port: ${PG_PORT}`, becomes
+//! a number or boolean when the variable holds one, and a string otherwise.
+//! References inside a longer string are always substituted as text, and the
+//! substituted text is never parsed again, so values with quotes, newlines or
+//! `${` are kept as they are.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use super::schema::DrasiLibConfig;
+use crate::builder::DrasiLibBuilder;
+use crate::error::{DrasiError, Result};
+use crate::lib_core::DrasiLib;
+use crate::registry::ComponentRegistry;
+
+/// Supported configuration file formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Yaml,
+    Toml,
+    Json,
+}
+
+impl ConfigFormat {
+    /// Detect the format from a file extension (`.yaml`, `.yml`, `.toml`, `.json`).
+    pub fn from_path(path: &Path) -> Result<Self> {
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(str::to_ascii_lowercase);
+        match extension.as_deref() {
+            Some("yaml") | Some("yml") => Ok(Self::Yaml),
+            Some("toml") => Ok(Self::Toml),
+            Some("json") => Ok(Self::Json),
+            _ => Err(DrasiError::invalid_config(format!(
+                "Cannot determine configuration format of '{}': expected a .yaml, .yml, .toml or .json file",
+                path.display()
+            ))),
+        }
+    }
+}
+
+/// A source entry in a configuration document.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceSpec {
+    /// Unique source id
+    pub id: String,
+    /// Registered source kind (e.g. `"postgres"`)
+    pub kind: String,
+    /// Whether to start the source with DrasiLib (default: true)
+    #[serde(default = "default_auto_start")]
+    pub auto_start: bool,
+    /// Kind-specific properties
+    #[serde(flatten)]
+    pub properties: Map<String, Value>,
+}
+
+/// A reaction entry in a configuration document.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReactionSpec {
+    /// Unique reaction id
+    pub id: String,
+    /// Registered reaction kind (e.g. `"log"`)
+    pub kind: String,
+    /// Query ids the reaction subscribes to
+    #[serde(default)]
+    pub queries: Vec<String>,
+    /// Whether to start the reaction with DrasiLib (default: true)
+    #[serde(default = "default_auto_start")]
+    pub auto_start: bool,
+    /// Kind-specific properties
+    #[serde(flatten)]
+    pub properties: Map<String, Value>,
+}
+
+fn default_auto_start() -> bool {
+    true
+}
+
+/// A complete declarative DrasiLib configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigDocument {
+    /// Global settings, queries and storage backends
+    #[serde(flatten)]
+    pub settings: DrasiLibConfig,
+    /// Source instances to create
+    #[serde(default)]
+    pub sources: Vec<SourceSpec>,
+    /// Reaction instances to create
+    #[serde(default)]
+    pub reactions: Vec<ReactionSpec>,
+}
+
+impl ConfigDocument {
+    /// Parse a document, interpolating environment variables into its string values.
+    pub fn parse(content: &str, format: ConfigFormat) -> Result<Self> {
+        Self::parse_with(content, format, |name| std::env::var(name).ok())
+    }
+
+    /// Parse a document without environment interpolation.
+    pub fn parse_raw(content: &str, format: ConfigFormat) -> Result<Self> {
+        Self::from_value(parse_value(content, format)?)
+    }
+
+    pub(crate) fn parse_with(
+        content: &str,
+        format: ConfigFormat,
+        lookup: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self> {
+        let mut value = parse_value(content, format)?;
+        interpolate_value(&mut value, &lookup)?;
+        Self::from_value(value)
+    }
+
+    fn from_value(value: Value) -> Result<Self> {
+        serde_json::from_value(value)
+            .map_err(|e| DrasiError::invalid_config(format!("Invalid configuration: {e}")))
+    }
+
+    /// Read and parse a document, detecting the format from the file extension.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let format = ConfigFormat::from_path(path)?;
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            DrasiError::invalid_config(format!(
+                "Failed to read configuration file '{}': {e}",
+                path.display()
+            ))
+        })?;
+        Self::parse(&content, format)
+    }
+
+    /// Validate the document against a registry.
+    ///
+    /// Checks the global settings, that source and reaction ids are unique,
+    /// that every kind is registered, and that reactions only subscribe to
+    /// queries defined in the document.
+    pub fn validate(&self, registry: &ComponentRegistry) -> Result<()> {
+        self.settings
+            .validate()
+            .map_err(|e| DrasiError::invalid_config(e.to_string()))?;
+
+        let mut source_ids = HashSet::new();
+        for source in &self.sources {
+            if !source_ids.insert(&source.id) {
+                return Err(DrasiError::invalid_config(format!(
+                    "Duplicate source id: '{}'",
+                    source.id
+                )));
+            }
+            if !registry.has_source_kind(&source.kind) {
+                return Err(DrasiError::invalid_config(format!(
+                    "Source '{}' has unknown kind '{}' (registered: {})",
+                    source.id,
+                    source.kind,
+                    registry.source_kinds().join(", ")
+                )));
+            }
+        }
+
+        let query_ids: HashSet<&String> = self.settings.queries.iter().map(|q| &q.id).collect();
+        let mut reaction_ids = HashSet::new();
+        for reaction in &self.reactions {
+            if !reaction_ids.insert(&reaction.id) {
+                return Err(DrasiError::invalid_config(format!(
+                    "Duplicate reaction id: '{}'",
+                    reaction.id
+                )));
+            }
+            if !registry.has_reaction_kind(&reaction.kind) {
+                return Err(DrasiError::invalid_config(format!(
+                    "Reaction '{}' has unknown kind '{}' (registered: {})",
+                    reaction.id,
+                    reaction.kind,
+                    registry.reaction_kinds().join(", ")
+                )));
+            }
+            if let Some(query_id) = reaction.queries.iter().find(|q| !query_ids.contains(q)) {
+                return Err(DrasiError::invalid_config(format!(
+                    "Reaction '{}' references unknown query '{query_id}'",
+                    reaction.id
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validate the document and create a builder with all its components.
+    ///
+    /// The returned builder can be customized further (index or state store
+    /// providers, extra components) before calling `build()`.
+    pub async fn into_builder(self, registry: &ComponentRegistry) -> Result<DrasiLibBuilder> {
+        self.validate(registry)?;
+
+        let settings = self.settings;
+        let mut builder = DrasiLib::builder().with_id(settings.id);
+        if let Some(capacity) = settings.priority_queue_capacity {
+            builder = builder.with_priority_queue_capacity(capacity);
+        }
+        if let Some(capacity) = settings.dispatch_buffer_capacity {
+            builder = builder.with_dispatch_buffer_capacity(capacity);
+        }
+        for backend in settings.storage_backends {
+            builder = builder.add_storage_backend(backend);
+        }
+        for query in settings.queries {
+            builder = builder.with_query(query);
+        }
+
+        for spec in &self.sources {
+            let source = registry
+                .create_source(
+                    &spec.kind,
+                    &spec.id,
+                    &Value::Object(spec.properties.clone()),
+                    spec.auto_start,
+                )
+                .await?;
+            builder = builder.with_source(source);
+        }
+        for spec in &self.reactions {
+            let reaction = registry
+                .create_reaction(
+                    &spec.kind,
+                    &spec.id,
+                    spec.queries.clone(),
+                    &Value::Object(spec.properties.clone()),
+                    spec.auto_start,
+                )
+                .await?;
+            builder = builder.with_reaction(reaction);
+        }
+
+        Ok(builder)
+    }
+}
+
+/// Load a configuration file and create a builder with all its components.
+///
+/// The format is detected from the file extension and environment variables
+/// are interpolated into the parsed string values.
+pub async fn builder_from_file(
+    path: impl AsRef<Path>,
+    registry: &ComponentRegistry,
+) -> Result<DrasiLibBuilder> {
+    ConfigDocument::load(path)?.into_builder(registry).await
+}
+
+/// Load a configuration file and build a DrasiLib instance from it.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let drasi = drasi_lib::config::from_file("drasi.yaml", &registry).await?;
+/// drasi.start().await?;
+/// ```
+pub async fn from_file(path: impl AsRef<Path>, registry: &ComponentRegistry) -> Result<DrasiLib> {
+    builder_from_file(path, registry).await?.build().await
+}
+
+/// Parse a document into a JSON value tree, without interpolation.
+fn parse_value(content: &str, format: ConfigFormat) -> Result<Value> {
+    match format {
+        ConfigFormat::Yaml => serde_yaml::from_str(content)
+            .map_err(|e| DrasiError::invalid_config(format!("Invalid YAML: {e}"))),
+        ConfigFormat::Toml => toml::from_str(content)
+            .map_err(|e| DrasiError::invalid_config(format!("Invalid TOML: {e}"))),
+        ConfigFormat::Json => serde_json::from_str(content)
+            .map_err(|e| DrasiError::invalid_config(format!("Invalid JSON: {e}"))),
+    }
+}
+
+/// Interpolate references into every string value of a parsed document.
+fn interpolate_value(value: &mut Value, lookup: &impl Fn(&str) -> Option<String>) -> Result<()> {
+    match value {
+        Value::String(text) => {
+            if let Some(reference) = whole_reference(text) {
+                let resolved = interpolate(reference, lookup)?;
+                *value = match serde_json::from_str::<Value>(&resolved) {
+                    Ok(typed @ (Value::Number(_) | Value::Bool(_))) => typed,
+                    _ => Value::String(resolved),
+                };
+            } else {
+                *text = interpolate(text, lookup)?;
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                interpolate_value(item, lookup)?;
+            }
+        }
+        Value::Object(map) => {
+            for item in map.values_mut() {
+                interpolate_value(item, lookup)?;
+            }
+        }
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+    Ok(())
+}
+
+/// The text, if it is exactly one `${...}` reference.
+fn whole_reference(text: &str) -> Option<&str> {
+    let inner = text.strip_prefix("${")?.strip_suffix('}')?;
+    (!inner.contains('}')).then_some(text)
+}
+
+/// Replace `${NAME}` / `${NAME:-fallback}` references in a string with environment variables.
+pub fn interpolate_env(input: &str) -> Result<String> {
+    interpolate(input, |name| std::env::var(name).ok())
+}
+pub(crate) fn interpolate(input: &str, lookup: impl Fn(&str) -> Option<String>) -> Result<String> {
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(pos) = rest.find('$') {
+        output.push_str(&rest[..pos]);
+        let after = &rest[pos + 1..];
+
+        if let Some(tail) = after.strip_prefix('$') {
+            output.push('$');
+            rest = tail;
+        } else if let Some(body) = after.strip_prefix('{') {
+            let end = body
+                .find('}')
+                .ok_or_else(|| DrasiError::invalid_config("Unterminated '${' in configuration"))?;
+            let expression = &body[..end];
+            let (name, fallback) = match expression.split_once(":-") {
+                Some((name, fallback)) => (name, Some(fallback)),
+                None => (expression, None),
+            };
+            if name.is_empty() {
+                return Err(DrasiError::invalid_config(
+                    "Empty variable name in '${}' reference",
+                ));
+            }
+            match (lookup(name), fallback) {
+                (Some(value), _) => output.push_str(&value),
+                (None, Some(fallback)) => output.push_str(fallback),
+                (None, None) => {
+                    return Err(DrasiError::invalid_config(format!(
+                        "Environment variable '{name}' is not set"
+                    )))
+                }
+            }
+            rest = &body[end + 1..];
+        } else {
+            output.push('$');
+            rest = after;
+        }
+    }
+
+    output.push_str(rest);
+    Ok(output)
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod loader;
 pub mod runtime;
 pub mod schema;
 pub mod snapshot;
@@ -19,6 +20,12 @@ pub mod snapshot;
 #[cfg(test)]
 mod tests;
 
+// Re-export loader types
+pub use loader::{
+    builder_from_file, from_file, interpolate_env, ConfigDocument, ConfigFormat, ReactionSpec,
+    SourceSpec,
+};
+
 // Re-export runtime types
 pub use runtime::{QueryRuntime, ReactionRuntime, RuntimeConfig, SourceRuntime};
 
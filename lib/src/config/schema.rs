@@ -18,6 +18,7 @@ use std::collections::HashSet;
 
 use crate::channels::DispatchMode;
 use crate::indexes::{StorageBackendConfig, StorageBackendRef};
+use crate::queries::QueryPriority;
 use crate::recovery::RecoveryPolicy;
 use drasi_core::models::SourceMiddlewareConfig;
 
@@ -434,6 +435,24 @@ pub struct QueryConfig {
         rename = "recoveryPolicy"
     )]
     pub recovery_policy: Option<RecoveryPolicy>,
+    /// Names of result processors applied, in order, to the query's result diffs
+    /// before they are dispatched. See [`ResultProcessor`](crate::queries::ResultProcessor).
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        rename = "resultProcessors"
+    )]
+    pub result_processors: Vec<String>,
+    /// Number of source changes that failed evaluation to keep for inspection
+    /// (default: 0, quarantine disabled). Failures are reported on the query's
+    /// error stream either way. See [`QuarantinedChange`](crate::QuarantinedChange).
+    #[serde(default, rename = "quarantineCapacity")]
+    pub quarantine_capacity: usize,
+    /// Share of evaluation time when queries compete for it (default: `normal`).
+    /// Only takes effect when an evaluation limit is configured.
+    /// See [`QueryPriority`](crate::QueryPriority).
+    #[serde(default)]
+    pub priority: QueryPriority,
 }
 
 /// Synthetic join configuration for queries
@@ -142,6 +142,9 @@ mod schema_tests {
             dispatch_mode: None,
             storage_backend: None,
             recovery_policy: None,
+            result_processors: Vec::new(),
+            quarantine_capacity: 0,
+            priority: Default::default(),
         });
 
         assert_eq!(config.queries.len(), 1);
@@ -181,6 +184,9 @@ mod persistence_tests {
             dispatch_mode: None,
             storage_backend: None,
             recovery_policy: None,
+            result_processors: Vec::new(),
+            quarantine_capacity: 0,
+            priority: Default::default(),
         });
 
         // Serialize to YAML
@@ -254,6 +260,9 @@ mod persistence_tests {
             dispatch_mode: None,
             storage_backend: None,
             recovery_policy: None,
+            result_processors: Vec::new(),
+            quarantine_capacity: 0,
+            priority: Default::default(),
         });
 
         // Save config
@@ -300,6 +309,9 @@ mod runtime_tests {
             dispatch_mode: None,
             storage_backend: None,
             recovery_policy: None,
+            result_processors: Vec::new(),
+            quarantine_capacity: 0,
+            priority: Default::default(),
         };
 
         let runtime = QueryRuntime::from(config.clone());
@@ -343,6 +355,9 @@ mod runtime_tests {
             dispatch_mode: None,
             storage_backend: None,
             recovery_policy: None,
+            result_processors: Vec::new(),
+            quarantine_capacity: 0,
+            priority: Default::default(),
         };
 
         let runtime = QueryRuntime::from(config.clone());
@@ -566,6 +581,9 @@ mod runtime_tests {
                 dispatch_mode: None,
                 storage_backend: None,
                 recovery_policy: None,
+                result_processors: Vec::new(),
+                quarantine_capacity: 0,
+                priority: Default::default(),
             }],
         };
 
@@ -607,6 +625,9 @@ mod runtime_tests {
                     dispatch_mode: None,
                     storage_backend: None,
                     recovery_policy: None,
+                    result_processors: Vec::new(),
+                    quarantine_capacity: 0,
+                    priority: Default::default(),
                 },
                 QueryConfig {
                     id: "q2".to_string(),
@@ -623,6 +644,9 @@ mod runtime_tests {
                     dispatch_mode: None,
                     storage_backend: None,
                     recovery_policy: None,
+                    result_processors: Vec::new(),
+                    quarantine_capacity: 0,
+                    priority: Default::default(),
                 },
             ],
         };
@@ -808,6 +832,9 @@ mod dispatch_mode_tests {
             dispatch_mode: Some(DispatchMode::Channel),
             storage_backend: None,
             recovery_policy: None,
+            result_processors: Vec::new(),
+            quarantine_capacity: 0,
+            priority: Default::default(),
         });
 
         config.queries.push(QueryConfig {
@@ -830,6 +857,9 @@ mod dispatch_mode_tests {
             dispatch_mode: Some(DispatchMode::Broadcast),
             storage_backend: None,
             recovery_policy: None,
+            result_processors: Vec::new(),
+            quarantine_capacity: 0,
+            priority: Default::default(),
         });
 
         config.queries.push(QueryConfig {
@@ -852,6 +882,9 @@ mod dispatch_mode_tests {
             dispatch_mode: None, // Default
             storage_backend: None,
             recovery_policy: None,
+            result_processors: Vec::new(),
+            quarantine_capacity: 0,
+            priority: Default::default(),
         });
 
         assert_eq!(config.queries.len(), 3);
@@ -863,3 +896,245 @@ mod dispatch_mode_tests {
         assert_eq!(config.queries[2].dispatch_mode, None);
     }
 }
+
+#[cfg(test)]
+mod loader_tests {
+    use super::super::loader::*;
+    use crate::reactions::tests::manager_tests::TestMockReaction;
+    use crate::reactions::Reaction;
+    use crate::registry::ComponentRegistry;
+    use crate::sources::tests::create_test_mock_source;
+    use crate::sources::Source;
+    use std::collections::HashMap;
+    use std::io::Write;
+
+    fn registry() -> ComponentRegistry {
+        let mut registry = ComponentRegistry::new();
+        registry
+            .register_source(
+                "mock",
+                |id: &str, _: &serde_json::Value, _: bool| -> anyhow::Result<Box<dyn Source>> {
+                    Ok(Box::new(create_test_mock_source(id.to_string())))
+                },
+            )
+            .register_reaction(
+                "mock",
+                |id: &str,
+                 queries: Vec<String>,
+                 _: &serde_json::Value,
+                 _: bool|
+                 -> anyhow::Result<Box<dyn Reaction>> {
+                    Ok(Box::new(TestMockReaction::new(id.to_string(), queries)))
+                },
+            );
+        registry
+    }
+
+    const YAML: &str = r#"
+id: file-app
+priority_queue_capacity: 500
+sources:
+  - id: orders
+    kind: mock
+    host: db.local
+    port: 5432
+queries:
+  - id: large-orders
+    query: MATCH (o:Order) RETURN o
+    sources:
+      - source_id: orders
+reactions:
+  - id: notify
+    kind: mock
+    queries: [large-orders]
+    autoStart: false
+    topic: orders-out
+"#;
+
+    #[test]
+    fn test_interpolate() {
+        let env = HashMap::from([
+            ("TOPIC".to_string(), "orders".to_string()),
+            ("EMPTY".to_string(), String::new()),
+        ]);
+        let lookup = |name: &str| env.get(name).cloned();
+
+        assert_eq!(
+            interpolate("topic: ${TOPIC}", lookup).unwrap(),
+            "topic: orders"
+        );
+        // As with the plugin SDK's ConfigValue, the fallback only replaces unset variables
+        assert_eq!(
+            interpolate("${MISSING:-fallback} [${EMPTY:-x}] [${EMPTY}]", lookup).unwrap(),
+            "fallback [] []"
+        );
+        assert_eq!(
+            interpolate("cost: $$5 $HOME", lookup).unwrap(),
+            "cost: $5 $HOME"
+        );
+        assert!(interpolate("${MISSING}", lookup)
+            .unwrap_err()
+            .to_string()
+            .contains("MISSING"));
+        assert!(interpolate("${TOPIC", lookup).is_err());
+    }
+
+    #[test]
+    fn test_commented_placeholder_is_not_interpolated() {
+        let yaml = r#"
+id: app
+# password: ${UNSET_PASSWORD}
+sources:
+  - id: orders
+    kind: mock
+    host: ${PG_HOST:-localhost} # port: ${UNSET_PORT}
+"#;
+        let document = ConfigDocument::parse_with(yaml, ConfigFormat::Yaml, |_| None).unwrap();
+        assert_eq!(document.sources[0].properties["host"], "localhost");
+
+        let toml = r#"
+id = "app"
+# topic = "${UNSET_TOPIC}"
+"#;
+        let document = ConfigDocument::parse_with(toml, ConfigFormat::Toml, |_| None).unwrap();
+        assert_eq!(document.settings.id, "app");
+    }
+
+    #[test]
+    fn test_interpolated_values_are_not_parsed_again() {
+        let secret = "pa\"ss: 'x'\n  - id: injected\n${UNSET}";
+        let env = HashMap::from([
+            ("SECRET".to_string(), secret.to_string()),
+            ("CAPACITY".to_string(), "500".to_string()),
+            ("CODE".to_string(), "00123".to_string()),
+        ]);
+        let lookup = |name: &str| env.get(name).cloned();
+
+        let yaml = r#"
+id: app
+priority_queue_capacity: ${CAPACITY}
+sources:
+  - id: orders
+    kind: mock
+    password: ${SECRET}
+    dsn: "user=drasi password=${SECRET}"
+    code: ${CODE}
+"#;
+        let document = ConfigDocument::parse_with(yaml, ConfigFormat::Yaml, lookup).unwrap();
+
+        assert_eq!(document.settings.priority_queue_capacity, Some(500));
+        assert_eq!(document.sources.len(), 1);
+        let properties = &document.sources[0].properties;
+        assert_eq!(properties["password"], secret);
+        assert_eq!(
+            properties["dsn"],
+            format!("user=drasi password={secret}").as_str()
+        );
+        // Not a JSON number, so it stays a string
+        assert_eq!(properties["code"], "00123");
+    }
+
+    #[test]
+    fn test_parse_yaml_document() {
+        let document = ConfigDocument::parse_raw(YAML, ConfigFormat::Yaml).unwrap();
+
+        assert_eq!(document.settings.id, "file-app");
+        assert_eq!(document.settings.priority_queue_capacity, Some(500));
+        assert_eq!(document.settings.queries.len(), 1);
+
+        let source = &document.sources[0];
+        assert_eq!(source.kind, "mock");
+        assert!(source.auto_start);
+        assert_eq!(source.properties["host"], "db.local");
+        assert_eq!(source.properties["port"], 5432);
+        assert!(!source.properties.contains_key("id"));
+
+        let reaction = &document.reactions[0];
+        assert_eq!(reaction.queries, vec!["large-orders"]);
+        assert!(!reaction.auto_start);
+        assert_eq!(reaction.properties["topic"], "orders-out");
+    }
+
+    #[test]
+    fn test_parse_toml_document() {
+        let toml = r#"
+id = "toml-app"
+
+[[sources]]
+id = "orders"
+kind = "mock"
+port = 5432
+
+[[queries]]
+id = "all-orders"
+query = "MATCH (o:Order) RETURN o"
+sources = [{ source_id = "orders" }]
+
+[[reactions]]
+id = "notify"
+kind = "mock"
+queries = ["all-orders"]
+"#;
+        let document = ConfigDocument::parse_raw(toml, ConfigFormat::Toml).unwrap();
+
+        assert_eq!(document.settings.id, "toml-app");
+        assert_eq!(document.sources[0].properties["port"], 5432);
+        assert_eq!(document.settings.queries[0].sources[0].source_id, "orders");
+        assert_eq!(document.reactions[0].queries, vec!["all-orders"]);
+        document.validate(&registry()).unwrap();
+    }
+
+    #[test]
+    fn test_validate_errors() {
+        let registry = registry();
+        let base = ConfigDocument::parse_raw(YAML, ConfigFormat::Yaml).unwrap();
+        base.validate(&registry).unwrap();
+
+        let mut document = base.clone();
+        document.sources[0].kind = "postgres".to_string();
+        let err = document.validate(&registry).unwrap_err().to_string();
+        assert!(err.contains("unknown kind 'postgres'"), "{err}");
+        assert!(err.contains("registered: mock"), "{err}");
+
+        let mut document = base.clone();
+        document.sources.push(document.sources[0].clone());
+        let err = document.validate(&registry).unwrap_err().to_string();
+        assert!(err.contains("Duplicate source id"), "{err}");
+
+        let mut document = base.clone();
+        document.reactions[0].queries.push("missing".to_string());
+        let err = document.validate(&registry).unwrap_err().to_string();
+        assert!(err.contains("unknown query 'missing'"), "{err}");
+    }
+
+    #[test]
+    fn test_format_from_path() {
+        use std::path::Path;
+
+        assert_eq!(
+            ConfigFormat::from_path(Path::new("drasi.YML")).unwrap(),
+            ConfigFormat::Yaml
+        );
+        assert_eq!(
+            ConfigFormat::from_path(Path::new("drasi.toml")).unwrap(),
+            ConfigFormat::Toml
+        );
+        assert!(ConfigFormat::from_path(Path::new("drasi.ini")).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_from_file_builds_components() {
+        let mut file = tempfile::Builder::new().suffix(".yaml").tempfile().unwrap();
+        file.write_all(YAML.as_bytes()).unwrap();
+
+        let drasi = from_file(file.path(), &registry()).await.unwrap();
+
+        let sources = drasi.list_sources().await.unwrap();
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0].0, "orders");
+        let queries = drasi.list_queries().await.unwrap();
+        assert_eq!(queries[0].0, "large-orders");
+        let reactions = drasi.list_reactions().await.unwrap();
+        assert_eq!(reactions[0].0, "notify");
+    }
+}
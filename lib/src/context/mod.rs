@@ -60,8 +60,11 @@
 
 use std::sync::Arc;
 
+use crate::checkpoint::{CheckpointStore, StateStoreCheckpointStore};
 use crate::component_graph::ComponentUpdateSender;
 use crate::identity::IdentityProvider;
+use crate::metrics::MetricsRegistry;
+use crate::queries::{EvaluationPool, QueryScheduler, ResultProcessor};
 use crate::state_store::StateStoreProvider;
 
 /// Context provided to Source plugins during initialization.
@@ -75,6 +78,8 @@ use crate::state_store::StateStoreProvider;
 /// - `source_id`: The unique identifier for this source instance
 /// - `state_store`: Optional persistent state storage (if configured)
 /// - `update_tx`: mpsc sender for fire-and-forget status updates to the component graph
+/// - `checkpoint_store`: Optional storage for the source's consumer position
+/// - `metrics`: Optional metrics registry of the DrasiLib instance
 ///
 /// # Clone
 ///
@@ -106,6 +111,19 @@ pub struct SourceRuntimeContext {
     /// Sources can use this to obtain authentication credentials (passwords, tokens,
     /// certificates) for connecting to external systems.
     pub identity_provider: Option<Arc<dyn IdentityProvider>>,
+
+    /// Optional checkpoint storage for the source's consumer position.
+    ///
+    /// Defaults to a [`StateStoreCheckpointStore`] over `state_store` when one is
+    /// configured. Sources usually access it through
+    /// [`SourceBase::save_checkpoint`](crate::SourceBase::save_checkpoint).
+    pub checkpoint_store: Option<Arc<dyn CheckpointStore>>,
+
+    /// Optional metrics registry of the DrasiLib instance.
+    ///
+    /// [`SourceBase`](crate::SourceBase) records dispatch and bootstrap metrics
+    /// here; sources may register their own.
+    pub metrics: Option<Arc<MetricsRegistry>>,
 }
 
 impl SourceRuntimeContext {
@@ -128,15 +146,38 @@ impl SourceRuntimeContext {
         update_tx: ComponentUpdateSender,
         identity_provider: Option<Arc<dyn IdentityProvider>>,
     ) -> Self {
+        let checkpoint_store = state_store.clone().map(|store| {
+            Arc::new(StateStoreCheckpointStore::new(store)) as Arc<dyn CheckpointStore>
+        });
         Self {
             instance_id: instance_id.into(),
             source_id: source_id.into(),
             state_store,
             update_tx,
             identity_provider,
+            checkpoint_store,
+            metrics: None,
         }
     }
 
+    /// Replace the checkpoint store derived from the state store.
+    pub fn with_checkpoint_store(mut self, checkpoint_store: Arc<dyn CheckpointStore>) -> Self {
+        self.checkpoint_store = Some(checkpoint_store);
+        self
+    }
+
+    /// Set the metrics registry the source records into.
+    pub fn with_metrics(mut self, metrics: Arc<MetricsRegistry>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Set the worker pool the query evaluates its changes on.
+    pub fn with_evaluation_pool(mut self, pool: Arc<EvaluationPool>) -> Self {
+        self.evaluation_pool = Some(pool);
+        self
+    }
+
     /// Get the DrasiLib instance ID.
     pub fn instance_id(&self) -> &str {
         &self.instance_id
@@ -154,6 +195,16 @@ impl SourceRuntimeContext {
     pub fn state_store(&self) -> Option<&Arc<dyn StateStoreProvider>> {
         self.state_store.as_ref()
     }
+
+    /// Get a reference to the checkpoint store if configured.
+    pub fn checkpoint_store(&self) -> Option<&Arc<dyn CheckpointStore>> {
+        self.checkpoint_store.as_ref()
+    }
+
+    /// Get a reference to the metrics registry if configured.
+    pub fn metrics(&self) -> Option<&Arc<MetricsRegistry>> {
+        self.metrics.as_ref()
+    }
 }
 
 impl std::fmt::Debug for SourceRuntimeContext {
@@ -173,6 +224,14 @@ impl std::fmt::Debug for SourceRuntimeContext {
                     .as_ref()
                     .map(|_| "<IdentityProvider>"),
             )
+            .field(
+                "checkpoint_store",
+                &self.checkpoint_store.as_ref().map(|_| "<CheckpointStore>"),
+            )
+            .field(
+                "metrics",
+                &self.metrics.as_ref().map(|_| "<MetricsRegistry>"),
+            )
             .finish()
     }
 }
@@ -189,6 +248,7 @@ impl std::fmt::Debug for SourceRuntimeContext {
 /// - `state_store`: Optional persistent state storage (if configured)
 /// - `update_tx`: mpsc sender for fire-and-forget status updates to the component graph
 /// - `identity_provider`: Optional identity provider for credential injection
+/// - `metrics`: Optional metrics registry of the DrasiLib instance
 ///
 /// # Clone
 ///
@@ -220,6 +280,12 @@ pub struct ReactionRuntimeContext {
     /// Reactions can use this to obtain authentication credentials (passwords, tokens,
     /// certificates) for connecting to external systems.
     pub identity_provider: Option<Arc<dyn IdentityProvider>>,
+
+    /// Optional metrics registry of the DrasiLib instance.
+    ///
+    /// [`ReactionBase`](crate::reactions::ReactionBase) records queue metrics
+    /// here; reactions may register their own.
+    pub metrics: Option<Arc<MetricsRegistry>>,
 }
 
 impl ReactionRuntimeContext {
@@ -248,9 +314,16 @@ impl ReactionRuntimeContext {
             state_store,
             update_tx,
             identity_provider,
+            metrics: None,
         }
     }
 
+    /// Set the metrics registry the reaction records into.
+    pub fn with_metrics(mut self, metrics: Arc<MetricsRegistry>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
     /// Get the DrasiLib instance ID.
     pub fn instance_id(&self) -> &str {
         &self.instance_id
@@ -265,6 +338,11 @@ impl ReactionRuntimeContext {
     pub fn state_store(&self) -> Option<&Arc<dyn StateStoreProvider>> {
         self.state_store.as_ref()
     }
+
+    /// Get a reference to the metrics registry if configured.
+    pub fn metrics(&self) -> Option<&Arc<MetricsRegistry>> {
+        self.metrics.as_ref()
+    }
 }
 
 impl std::fmt::Debug for ReactionRuntimeContext {
@@ -284,6 +362,10 @@ impl std::fmt::Debug for ReactionRuntimeContext {
                     .as_ref()
                     .map(|_| "<IdentityProvider>"),
             )
+            .field(
+                "metrics",
+                &self.metrics.as_ref().map(|_| "<MetricsRegistry>"),
+            )
             .finish()
     }
 }
@@ -313,6 +395,23 @@ pub struct QueryRuntimeContext {
     /// Status changes sent here are applied to the component graph by the
     /// graph update loop, which emits broadcast events to all subscribers.
     pub update_tx: ComponentUpdateSender,
+
+    /// Optional checkpoint storage for the query's processed source sequences.
+    ///
+    /// Only used by queries with a persistent storage backend.
+    pub checkpoint_store: Option<Arc<dyn CheckpointStore>>,
+
+    /// Result processors applied, in order, to the query's result diffs.
+    pub result_processors: Vec<Arc<dyn ResultProcessor>>,
+
+    /// Optional metrics registry the query records into.
+    pub metrics: Option<Arc<MetricsRegistry>>,
+
+    /// Optional scheduler the query acquires an evaluation slot from for each change.
+    pub scheduler: Option<Arc<QueryScheduler>>,
+
+    /// Optional worker pool the query evaluates its changes on.
+    pub evaluation_pool: Option<Arc<EvaluationPool>>,
 }
 
 impl QueryRuntimeContext {
@@ -334,9 +433,38 @@ impl QueryRuntimeContext {
             instance_id: instance_id.into(),
             query_id: query_id.into(),
             update_tx,
+            checkpoint_store: None,
+            result_processors: Vec::new(),
+            metrics: None,
+            scheduler: None,
+            evaluation_pool: None,
         }
     }
 
+    /// Set the checkpoint store used to persist processed source sequences.
+    pub fn with_checkpoint_store(mut self, checkpoint_store: Arc<dyn CheckpointStore>) -> Self {
+        self.checkpoint_store = Some(checkpoint_store);
+        self
+    }
+
+    /// Set the result processors applied to the query's results.
+    pub fn with_result_processors(mut self, processors: Vec<Arc<dyn ResultProcessor>>) -> Self {
+        self.result_processors = processors;
+        self
+    }
+
+    /// Set the metrics registry the query records into.
+    pub fn with_metrics(mut self, metrics: Arc<MetricsRegistry>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Set the scheduler that shares evaluation time between queries.
+    pub fn with_scheduler(mut self, scheduler: Arc<QueryScheduler>) -> Self {
+        self.scheduler = Some(scheduler);
+        self
+    }
+
     /// Get the DrasiLib instance ID.
     pub fn instance_id(&self) -> &str {
         &self.instance_id
@@ -354,6 +482,20 @@ impl std::fmt::Debug for QueryRuntimeContext {
             .field("instance_id", &self.instance_id)
             .field("query_id", &self.query_id)
             .field("update_tx", &"<ComponentUpdateSender>")
+            .field(
+                "checkpoint_store",
+                &self.checkpoint_store.as_ref().map(|_| "<CheckpointStore>"),
+            )
+            .field("result_processors", &self.result_processors.len())
+            .field(
+                "metrics",
+                &self.metrics.as_ref().map(|_| "<MetricsRegistry>"),
+            )
+            .field("scheduler", &self.scheduler.as_ref().map(|s| s.capacity()))
+            .field(
+                "evaluation_pool",
+                &self.evaluation_pool.as_ref().map(|p| p.workers()),
+            )
             .finish()
     }
 }
@@ -0,0 +1,297 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Structured health reporting.
+//!
+//! [`DrasiLib::health`](crate::DrasiLib::health) summarizes the state of the
+//! instance and every source, query and reaction in a [`HealthReport`]. With the
+//! `management-api` feature it is served on `GET /healthz` (liveness) and
+//! `GET /readyz` (readiness), which answer `503 Service Unavailable` when the
+//! corresponding check fails.
+//!
+//! | Overall status | When |
+//! |----------------|------|
+//! | `healthy` | The instance is running and no component is failed or in transition |
+//! | `degraded` | The instance is not running, or a component is starting, stopping or reconfiguring |
+//! | `unhealthy` | A component is in the `Error` state, or the instance has been shut down |
+//!
+//! The instance is *live* until it is shut down and *ready* while it is
+//! `healthy`. Components stopped on purpose do not affect either check.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::channels::{ComponentEvent, ComponentStatus, ComponentType};
+use crate::component_graph::ComponentKind;
+use crate::metrics::MetricsRegistry;
+
+/// Overall health of an instance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum HealthStatus {
+    Healthy,
+    Degraded,
+    Unhealthy,
+}
+
+/// Health of one source, query or reaction.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentHealth {
+    pub id: String,
+    pub component_type: ComponentType,
+    pub status: ComponentStatus,
+    /// Most recent error reported by the component, even if it has recovered since.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+    /// Seconds since the component last entered `Running`, if it is running.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uptime_secs: Option<u64>,
+    /// Events or results waiting to be processed by the component.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lag: Option<u64>,
+}
+
+impl ComponentHealth {
+    /// Build the health entry of a component from its lifecycle events.
+    pub(crate) fn new(
+        id: String,
+        component_type: ComponentType,
+        status: ComponentStatus,
+        events: &[ComponentEvent],
+        last_error: Option<String>,
+        lag: Option<u64>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let uptime_secs = (status == ComponentStatus::Running)
+            .then(|| {
+                events
+                    .iter()
+                    .rev()
+                    .find(|e| e.status == ComponentStatus::Running)
+                    .map(|e| seconds_between(e.timestamp, now))
+            })
+            .flatten();
+        Self {
+            id,
+            component_type,
+            status,
+            last_error,
+            uptime_secs,
+            lag,
+        }
+    }
+}
+
+/// Health of an instance and its components, as returned by
+/// [`DrasiLib::health`](crate::DrasiLib::health).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthReport {
+    pub status: HealthStatus,
+    /// `false` once the instance has been shut down.
+    pub live: bool,
+    /// `true` while the status is [`HealthStatus::Healthy`].
+    pub ready: bool,
+    /// Whether the instance has been started.
+    pub running: bool,
+    /// Seconds since the instance was started, if it is running.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uptime_secs: Option<u64>,
+    pub components: Vec<ComponentHealth>,
+    pub checked_at: DateTime<Utc>,
+}
+
+impl HealthReport {
+    pub(crate) fn new(
+        live: bool,
+        running: bool,
+        uptime_secs: Option<u64>,
+        components: Vec<ComponentHealth>,
+        checked_at: DateTime<Utc>,
+    ) -> Self {
+        let status = if !live
+            || components
+                .iter()
+                .any(|c| c.status == ComponentStatus::Error)
+        {
+            HealthStatus::Unhealthy
+        } else if !running
+            || components.iter().any(|c| {
+                matches!(
+                    c.status,
+                    ComponentStatus::Starting
+                        | ComponentStatus::Stopping
+                        | ComponentStatus::Reconfiguring
+                )
+            })
+        {
+            HealthStatus::Degraded
+        } else {
+            HealthStatus::Healthy
+        };
+        Self {
+            status,
+            live,
+            ready: status == HealthStatus::Healthy,
+            running,
+            uptime_secs,
+            components,
+            checked_at,
+        }
+    }
+
+    /// Components that are in the `Error` state.
+    pub fn failed_components(&self) -> impl Iterator<Item = &ComponentHealth> {
+        self.components
+            .iter()
+            .filter(|c| c.status == ComponentStatus::Error)
+    }
+}
+
+/// Events or results waiting to be processed by a component, read from the
+/// queue depth it reports to `metrics`.
+pub(crate) fn component_lag(
+    metrics: &MetricsRegistry,
+    kind: &ComponentKind,
+    id: &str,
+) -> Option<u64> {
+    let (name, label) = match kind {
+        ComponentKind::Source => ("drasi_source_dispatch_queue_depth", "source"),
+        ComponentKind::Query => ("drasi_query_queue_depth", "query"),
+        ComponentKind::Reaction => ("drasi_reaction_queue_depth", "reaction"),
+        _ => return None,
+    };
+    metrics
+        .sample(name, &[(label, id)])
+        .map(|depth| depth as u64)
+}
+
+fn seconds_between(from: DateTime<Utc>, to: DateTime<Utc>) -> u64 {
+    (to - from).num_seconds().max(0) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn component(id: &str, status: ComponentStatus) -> ComponentHealth {
+        ComponentHealth {
+            id: id.to_string(),
+            component_type: ComponentType::Query,
+            status,
+            last_error: None,
+            uptime_secs: None,
+            lag: None,
+        }
+    }
+
+    fn event(status: ComponentStatus, timestamp: DateTime<Utc>) -> ComponentEvent {
+        ComponentEvent {
+            component_id: "q1".to_string(),
+            component_type: ComponentType::Query,
+            status,
+            timestamp,
+            message: None,
+        }
+    }
+
+    #[test]
+    fn test_running_instance_with_running_components_is_ready() {
+        let report = HealthReport::new(
+            true,
+            true,
+            Some(5),
+            vec![
+                component("q1", ComponentStatus::Running),
+                component("q2", ComponentStatus::Stopped),
+            ],
+            Utc::now(),
+        );
+        assert_eq!(report.status, HealthStatus::Healthy);
+        assert!(report.live);
+        assert!(report.ready);
+    }
+
+    #[test]
+    fn test_starting_component_degrades_health() {
+        let report = HealthReport::new(
+            true,
+            true,
+            None,
+            vec![component("q1", ComponentStatus::Starting)],
+            Utc::now(),
+        );
+        assert_eq!(report.status, HealthStatus::Degraded);
+        assert!(!report.ready);
+    }
+
+    #[test]
+    fn test_failed_component_makes_instance_unhealthy() {
+        let report = HealthReport::new(
+            true,
+            true,
+            None,
+            vec![
+                component("q1", ComponentStatus::Error),
+                component("q2", ComponentStatus::Running),
+            ],
+            Utc::now(),
+        );
+        assert_eq!(report.status, HealthStatus::Unhealthy);
+        assert!(report.live);
+        let failed: Vec<_> = report.failed_components().map(|c| c.id.as_str()).collect();
+        assert_eq!(failed, vec!["q1"]);
+    }
+
+    #[test]
+    fn test_shut_down_instance_is_not_live() {
+        let report = HealthReport::new(false, false, None, vec![], Utc::now());
+        assert_eq!(report.status, HealthStatus::Unhealthy);
+        assert!(!report.live);
+    }
+
+    #[test]
+    fn test_uptime_counts_from_last_running_event() {
+        let now = Utc::now();
+        let events = vec![
+            event(ComponentStatus::Running, now - Duration::seconds(100)),
+            event(ComponentStatus::Error, now - Duration::seconds(60)),
+            event(ComponentStatus::Running, now - Duration::seconds(30)),
+        ];
+        let health = ComponentHealth::new(
+            "q1".to_string(),
+            ComponentType::Query,
+            ComponentStatus::Running,
+            &events,
+            Some("boom".to_string()),
+            Some(3),
+            now,
+        );
+        assert_eq!(health.uptime_secs, Some(30));
+        assert_eq!(health.last_error.as_deref(), Some("boom"));
+
+        let stopped = ComponentHealth::new(
+            "q1".to_string(),
+            ComponentType::Query,
+            ComponentStatus::Stopped,
+            &events,
+            None,
+            None,
+            now,
+        );
+        assert_eq!(stopped.uptime_secs, None);
+    }
+}
@@ -224,6 +224,24 @@ impl InspectionAPI {
         &self,
         id: &str,
     ) -> crate::error::Result<Vec<serde_json::Value>> {
+        Ok(self.get_query_results_snapshot(id).await?.results)
+    }
+
+    /// Get the current result set for a running query along with its sequence metadata
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use drasi_lib::DrasiLib;
+    /// # async fn example(core: &DrasiLib) -> Result<(), Box<dyn std::error::Error>> {
+    /// let snapshot = core.query_results("my-query").await?;
+    /// println!("{} items at sequence {}", snapshot.len(), snapshot.sequence);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_query_results_snapshot(
+        &self,
+        id: &str,
+    ) -> crate::error::Result<crate::queries::QueryResultSnapshot> {
         self.state_guard.require_initialized()?;
 
         // Check preconditions explicitly instead of parsing error strings.
@@ -241,7 +259,7 @@ impl InspectionAPI {
         }
 
         self.query_manager
-            .get_query_results(id)
+            .get_query_results_snapshot(id)
             .await
             .map_err(|e| DrasiError::operation_failed("query", id, "get_results", e.to_string()))
     }
@@ -702,6 +720,55 @@ impl InspectionAPI {
             .ok_or_else(|| DrasiError::component_not_found("query", id))
     }
 
+    /// Subscribe to the result diffs produced by a query.
+    pub async fn subscribe_to_query(
+        &self,
+        id: &str,
+    ) -> crate::error::Result<tokio::sync::broadcast::Receiver<crate::channels::ResultDiff>> {
+        self.state_guard.require_initialized()?;
+        self.query_manager
+            .subscribe_to_query(id)
+            .await
+            .map_err(|e| classify_component_error(e, "query", id, "subscribe"))
+    }
+
+    /// Subscribe to the evaluation errors reported by a query.
+    pub async fn subscribe_query_errors(
+        &self,
+        id: &str,
+    ) -> crate::error::Result<tokio::sync::broadcast::Receiver<crate::queries::QueryEvaluationError>>
+    {
+        self.state_guard.require_initialized()?;
+        self.query_manager
+            .subscribe_query_errors(id)
+            .await
+            .map_err(|e| classify_component_error(e, "query", id, "subscribe_errors"))
+    }
+
+    /// List the source changes a query keeps in quarantine.
+    pub async fn quarantined_changes(
+        &self,
+        id: &str,
+    ) -> crate::error::Result<Vec<crate::queries::QuarantinedChange>> {
+        self.state_guard.require_initialized()?;
+        self.query_manager
+            .quarantined_changes(id)
+            .await
+            .map_err(|e| classify_component_error(e, "query", id, "list_quarantine"))
+    }
+
+    /// Remove and return the source changes a query keeps in quarantine.
+    pub async fn drain_quarantine(
+        &self,
+        id: &str,
+    ) -> crate::error::Result<Vec<crate::queries::QuarantinedChange>> {
+        self.state_guard.require_initialized()?;
+        self.query_manager
+            .drain_quarantine(id)
+            .await
+            .map_err(|e| classify_component_error(e, "query", id, "drain_quarantine"))
+    }
+
     /// Subscribe to live events for a reaction.
     ///
     /// Returns the event history and a broadcast receiver for new events.
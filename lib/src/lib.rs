@@ -52,6 +52,31 @@ pub mod identity;
 /// Recovery policy and error types for checkpoint-based recovery
 pub mod recovery;
 
+/// Checkpoint storage for resuming sources and queries after a restart
+pub mod checkpoint;
+
+/// Factory registry for creating sources and reactions by kind
+pub mod registry;
+
+/// Metrics registry for sources, queries and reactions, rendered for Prometheus
+pub mod metrics;
+
+/// Tracing of source changes through the pipeline, with optional OTLP export
+pub mod telemetry;
+
+/// Structured health report for liveness and readiness checks
+pub mod health;
+
+/// Restart policies for sources and reactions that fail at runtime
+pub mod supervisor;
+
+/// Embedded HTTP management API (requires the `management-api` feature)
+#[cfg(feature = "management-api")]
+pub mod management;
+
+/// Shared Handlebars templating engine and helpers for reactions
+pub mod templating;
+
 // ============================================================================
 // Internal Modules (crate-private, but visible to integration tests)
 // ============================================================================
@@ -128,9 +153,42 @@ pub use error::{DrasiError, Result};
 /// Recovery policy and error types for checkpoint-based recovery
 pub use recovery::{RecoveryError, RecoveryPolicy};
 
+/// Checkpoint storage for source positions and query sequences
+pub use checkpoint::{CheckpointStore, StateStoreCheckpointStore};
+
+/// Stage timeouts for draining in-flight changes on stop
+pub use lifecycle::DrainConfig;
+
+/// Restart policies applied to failed sources and reactions
+pub use supervisor::{RestartBackoff, RestartPolicy};
+
+/// Metrics registry shared by the components of a DrasiLib instance
+pub use metrics::MetricsRegistry;
+
+/// Health report returned by `DrasiLib::health`
+pub use health::{ComponentHealth, HealthReport, HealthStatus};
+
 /// Component status type for monitoring component states
 pub use channels::ComponentStatus;
 
+/// Point-in-time copy of a query's materialized results
+pub use queries::QueryResultSnapshot;
+
+/// Evaluation failure reported by a query and the quarantined change behind it
+pub use queries::{QuarantinedChange, QueryEvaluationError};
+
+/// Query priority and the scheduler sharing evaluation time between queries
+pub use queries::{QueryPriority, QueryScheduler};
+
+/// Worker pool evaluating different queries in parallel, in order per query or element
+pub use queries::EvaluationPool;
+
+/// Portable export of a query's element index and results
+pub use queries::QueryStateSnapshot;
+
+/// Row-level change emitted by a query (see `DrasiLib::subscribe_to_query`)
+pub use channels::ResultDiff;
+
 /// Component event for tracking lifecycle changes
 pub use channels::{ComponentEvent, ComponentType};
 
@@ -143,6 +201,9 @@ pub use channels::DispatchMode;
 /// Log level and log message types for component log streaming
 pub use managers::{LogLevel, LogMessage};
 
+/// Instance-wide log of component lifecycle events
+pub use managers::{EventLogEntry, EventLogFilter};
+
 /// Tracing initialization function - call to set up component log routing
 pub use managers::get_or_init_global_registry;
 
@@ -178,6 +239,9 @@ pub use sources::SourceError;
 /// Reaction traits for implementing reaction plugins
 pub use reactions::Reaction;
 
+/// Component factory registry and factory traits for creating plugins by kind
+pub use registry::{ComponentRegistry, ReactionFactory, SourceFactory};
+
 /// Bootstrap provider trait for implementing bootstrap plugins
 pub use bootstrap::BootstrapProvider;
 /// Bootstrap provider that generates data from the component graph
@@ -209,6 +273,12 @@ pub use builder::DrasiLibBuilder;
 /// Fluent builder for query configurations
 pub use builder::Query;
 
+/// Source middleware extension points for user-defined middleware
+pub use drasi_core::interface::{
+    ElementIndex, MiddlewareError, MiddlewareSetupError, SourceMiddleware, SourceMiddlewareFactory,
+};
+pub use drasi_core::models::{SourceChange, SourceMiddlewareConfig};
+
 /// Async helper to wait for a component to reach a target status without polling.
 pub use component_graph::wait_for_status;
 /// Component graph types for dependency tracking and configuration queries
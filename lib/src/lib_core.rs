@@ -18,16 +18,21 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 
 use crate::channels::*;
+use crate::checkpoint::{CheckpointStore, StateStoreCheckpointStore};
 use crate::component_graph::{ComponentGraph, GraphSnapshot};
 use crate::config::{DrasiLibConfig, RuntimeConfig};
 use crate::error::DrasiError;
 use crate::inspection::InspectionAPI;
-use crate::lifecycle::LifecycleManager;
+use crate::lifecycle::{DrainConfig, LifecycleManager};
 use crate::managers::ComponentLogRegistry;
+use crate::metrics::MetricsRegistry;
 use crate::queries::QueryManager;
 use crate::reactions::ReactionManager;
+use crate::registry::ComponentRegistry;
 use crate::sources::SourceManager;
 use crate::state_guard::StateGuard;
+use crate::supervisor::RestartPolicies;
+use drasi_core::interface::SourceMiddlewareFactory;
 use drasi_core::middleware::MiddlewareTypeRegistry;
 
 /// Core Drasi Server for continuous query processing
@@ -160,6 +165,8 @@ pub struct DrasiLib {
     pub(crate) query_manager: Arc<QueryManager>,
     pub(crate) reaction_manager: Arc<ReactionManager>,
     pub(crate) running: Arc<RwLock<bool>>,
+    // When the server was last started, `None` while stopped
+    pub(crate) started_at: Arc<RwLock<Option<chrono::DateTime<chrono::Utc>>>>,
     pub(crate) is_shutdown: Arc<std::sync::atomic::AtomicBool>,
     pub(crate) state_guard: StateGuard,
     // Inspection API for querying server state
@@ -168,8 +175,20 @@ pub struct DrasiLib {
     pub(crate) lifecycle: Arc<LifecycleManager>,
     // Middleware registry for source middleware
     pub(crate) middleware_registry: Arc<MiddlewareTypeRegistry>,
+    // Factory registry for creating sources and reactions by kind
+    pub(crate) component_registry: Arc<ComponentRegistry>,
+    // Checkpoint store for source positions and query resume
+    pub(crate) checkpoint_store: Arc<dyn CheckpointStore>,
     // Component log registry for live log streaming
     pub(crate) log_registry: Arc<ComponentLogRegistry>,
+    // Registry that sources, queries and reactions record metrics into
+    pub(crate) metrics: Arc<MetricsRegistry>,
+    // Stage timeouts used by `stop()` to drain in-flight changes, if enabled
+    pub(crate) drain: Option<DrainConfig>,
+    // Restart policies applied by the supervisor to failed sources and reactions
+    pub(crate) restart_policies: RestartPolicies,
+    // Supervisor task, running while the server is running and a policy is set
+    pub(crate) supervisor_handle: Arc<tokio::sync::Mutex<Option<tokio::task::JoinHandle<()>>>>,
     // Broadcast sender for component events — shared with ComponentGraph.
     //
     // This is the *same* sender that the ComponentGraph uses internally to emit
@@ -193,12 +212,19 @@ impl Clone for DrasiLib {
             query_manager: Arc::clone(&self.query_manager),
             reaction_manager: Arc::clone(&self.reaction_manager),
             running: Arc::clone(&self.running),
+            started_at: Arc::clone(&self.started_at),
             is_shutdown: Arc::clone(&self.is_shutdown),
             state_guard: self.state_guard.clone(),
             inspection: self.inspection.clone(),
             lifecycle: Arc::clone(&self.lifecycle),
             middleware_registry: Arc::clone(&self.middleware_registry),
+            component_registry: Arc::clone(&self.component_registry),
+            checkpoint_store: Arc::clone(&self.checkpoint_store),
             log_registry: Arc::clone(&self.log_registry),
+            metrics: Arc::clone(&self.metrics),
+            drain: self.drain,
+            restart_policies: self.restart_policies.clone(),
+            supervisor_handle: Arc::clone(&self.supervisor_handle),
             component_event_broadcast_tx: self.component_event_broadcast_tx.clone(),
             component_graph: Arc::clone(&self.component_graph),
             graph_update_handle: Arc::clone(&self.graph_update_handle),
@@ -231,7 +257,13 @@ impl DrasiLib {
 
     /// Internal constructor - creates uninitialized server
     /// Use `builder()` instead
-    pub(crate) fn new(config: Arc<RuntimeConfig>) -> Self {
+    ///
+    /// `middleware_factories` are user-defined middleware registered after the
+    /// standard ones, so they can replace a built-in kind.
+    pub(crate) fn new(
+        config: Arc<RuntimeConfig>,
+        middleware_factories: Vec<Arc<dyn SourceMiddlewareFactory>>,
+    ) -> Self {
         // Use the shared global log registry.
         // Since tracing uses a single global subscriber, all DrasiLib instances
         // share the same log registry. This ensures logs are properly routed
@@ -286,6 +318,26 @@ impl DrasiLib {
             drasi_middleware::promote::PromoteMiddlewareFactory::new(),
         ));
 
+        #[cfg(feature = "middleware-filter")]
+        middleware_registry.register(Arc::new(
+            drasi_middleware::filter::FilterMiddlewareFactory::new(),
+        ));
+
+        #[cfg(feature = "middleware-relate")]
+        middleware_registry.register(Arc::new(
+            drasi_middleware::relate::RelateMiddlewareFactory::new(),
+        ));
+
+        #[cfg(feature = "middleware-unit-convert")]
+        middleware_registry.register(Arc::new(
+            drasi_middleware::unit_convert::UnitConvertMiddlewareFactory::new(),
+        ));
+
+        for factory in middleware_factories {
+            info!("Registering custom middleware factory '{}'", factory.name());
+            middleware_registry.register(factory);
+        }
+
         let middleware_registry = Arc::new(middleware_registry);
 
         let query_manager = Arc::new(QueryManager::new(
@@ -369,18 +421,29 @@ impl DrasiLib {
             Arc::new(tokio::sync::Mutex::new(Some(handle)))
         };
 
+        let checkpoint_store: Arc<dyn CheckpointStore> = Arc::new(StateStoreCheckpointStore::new(
+            config.state_store_provider.clone(),
+        ));
+
         Self {
             config,
             source_manager,
             query_manager,
             reaction_manager,
             running: Arc::new(RwLock::new(false)),
+            started_at: Arc::new(RwLock::new(None)),
             is_shutdown: Arc::new(std::sync::atomic::AtomicBool::new(false)),
             state_guard,
             inspection,
             lifecycle,
             middleware_registry,
+            component_registry: Arc::new(ComponentRegistry::new()),
+            checkpoint_store,
             log_registry,
+            metrics: Arc::new(MetricsRegistry::new()),
+            drain: None,
+            restart_policies: RestartPolicies::default(),
+            supervisor_handle: Arc::new(tokio::sync::Mutex::new(None)),
             component_event_broadcast_tx,
             component_graph,
             graph_update_handle,
@@ -414,6 +477,25 @@ impl DrasiLib {
             .await;
         self.reaction_manager.inject_state_store(state_store).await;
 
+        // Inject CheckpointStore so sources and persistent queries can resume
+        self.source_manager
+            .inject_checkpoint_store(self.checkpoint_store.clone())
+            .await;
+        self.query_manager
+            .inject_checkpoint_store(self.checkpoint_store.clone())
+            .await;
+
+        // Inject MetricsRegistry so components record into the instance's registry
+        self.source_manager
+            .inject_metrics(self.metrics.clone())
+            .await;
+        self.query_manager
+            .inject_metrics(self.metrics.clone())
+            .await;
+        self.reaction_manager
+            .inject_metrics(self.metrics.clone())
+            .await;
+
         // Inject IdentityProvider into SourceManager and ReactionManager (if configured)
         // This allows sources and reactions to obtain authentication credentials
         if let Some(identity_provider) = &self.config.identity_provider {
@@ -444,6 +526,10 @@ impl DrasiLib {
     ///
     /// Components are started in dependency order: Sources → Queries → Reactions
     ///
+    /// If a [`RestartPolicy`](crate::RestartPolicy) was configured with
+    /// [`with_restart_policy`](crate::DrasiLibBuilder::with_restart_policy), failed
+    /// sources and reactions are restarted until the server is stopped.
+    ///
     /// # Errors
     ///
     /// Returns an error if:
@@ -496,8 +582,21 @@ impl DrasiLib {
         // Start all configured components (no lock held during this await)
         self.lifecycle.start_components().await?;
 
+        if self.restart_policies.is_enabled() {
+            let handle = crate::supervisor::spawn(
+                self.restart_policies.clone(),
+                self.component_event_broadcast_tx.subscribe(),
+                self.component_graph.clone(),
+                self.source_manager.clone(),
+                self.reaction_manager.clone(),
+                self.metrics.clone(),
+            );
+            *self.supervisor_handle.lock().await = Some(handle);
+        }
+
         // Brief write lock to set the flag
         *self.running.write().await = true;
+        *self.started_at.write().await = Some(chrono::Utc::now());
         info!("drasi-lib started successfully");
 
         Ok(())
@@ -508,6 +607,11 @@ impl DrasiLib {
     /// This stops all currently running components (sources, queries, reactions).
     /// Components are stopped in reverse dependency order: Reactions → Queries → Sources
     ///
+    /// If the instance was built with
+    /// [`with_drain_on_stop`](crate::DrasiLibBuilder::with_drain_on_stop), sources
+    /// are stopped first and queries and reactions keep running until they have
+    /// processed the changes already emitted, or the [`DrainConfig`] timeouts expire.
+    ///
     /// On the next `start()`, only components with `auto_start=true` will be restarted.
     ///
     /// # Errors
@@ -545,6 +649,16 @@ impl DrasiLib {
 
         info!("Stopping drasi-lib");
 
+        // Stop supervising first so stopped components are not restarted
+        if let Some(handle) = self.supervisor_handle.lock().await.take() {
+            handle.abort();
+            let _ = handle.await;
+        }
+
+        if let Some(drain) = &self.drain {
+            self.lifecycle.drain(drain, &self.metrics).await;
+        }
+
         // Stop all components (no lock held during this await).
         // Capture the result but always mark as stopped — partial shutdown is
         // preferable to leaving the running flag set after a partial failure.
@@ -552,6 +666,7 @@ impl DrasiLib {
 
         // Brief write lock to clear the flag
         *self.running.write().await = false;
+        *self.started_at.write().await = None;
 
         match result {
             Ok(()) => {
@@ -611,6 +726,9 @@ impl DrasiLib {
             let _ = handle.await;
         }
 
+        #[cfg(feature = "otel")]
+        crate::telemetry::flush().await;
+
         info!("drasi-lib shut down permanently");
         Ok(())
     }
@@ -657,6 +775,15 @@ impl DrasiLib {
         Arc::clone(&self.middleware_registry)
     }
 
+    /// Get access to the component factory registry.
+    ///
+    /// Returns the registry set with
+    /// [`DrasiLibBuilder::with_component_registry`](crate::builder::DrasiLibBuilder::with_component_registry),
+    /// or an empty registry if none was provided.
+    pub fn component_registry(&self) -> Arc<ComponentRegistry> {
+        Arc::clone(&self.component_registry)
+    }
+
     /// Get access to the component log registry.
     ///
     /// The log registry captures structured log messages from components and
@@ -667,6 +794,15 @@ impl DrasiLib {
         Arc::clone(&self.log_registry)
     }
 
+    /// Get access to the metrics registry.
+    ///
+    /// Sources, queries and reactions record their metrics here; see
+    /// [`crate::metrics`] for the list. Render it with
+    /// [`MetricsRegistry::render`] to expose the metrics to Prometheus.
+    pub fn metrics(&self) -> Arc<MetricsRegistry> {
+        Arc::clone(&self.metrics)
+    }
+
     /// Get access to the component graph for event history queries.
     ///
     /// The graph centralizes all component event history. Use `graph.read().await.get_events(id)`
@@ -1006,6 +1142,21 @@ mod tests {
             registry.get("promote").is_some(),
             "Promote factory should be registered"
         );
+        #[cfg(feature = "middleware-filter")]
+        assert!(
+            registry.get("filter").is_some(),
+            "Filter factory should be registered"
+        );
+        #[cfg(feature = "middleware-relate")]
+        assert!(
+            registry.get("relate").is_some(),
+            "Relate factory should be registered"
+        );
+        #[cfg(feature = "middleware-unit-convert")]
+        assert!(
+            registry.get("unit_convert").is_some(),
+            "UnitConvert factory should be registered"
+        );
     }
 
     #[tokio::test]
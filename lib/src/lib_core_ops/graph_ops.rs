@@ -15,11 +15,15 @@
 //! Component graph operations for DrasiLib
 //!
 //! Provides public API methods for querying the component dependency graph,
-//! including graph snapshots, dependency lookups, and impact analysis.
+//! including graph snapshots, dependency lookups, and impact analysis, and
+//! for reading the instance-wide component event log.
+
+use tokio::sync::broadcast;
 
 use crate::component_graph::{ComponentNode, GraphSnapshot};
 use crate::error::Result;
 use crate::lib_core::DrasiLib;
+use crate::managers::{EventLogEntry, EventLogFilter};
 
 impl DrasiLib {
     // ============================================================================
@@ -134,11 +138,72 @@ impl DrasiLib {
             ))
         })
     }
+
+    // ============================================================================
+    // Event Log Operations
+    // ============================================================================
+
+    /// Get lifecycle events of all components from the instance-wide event log.
+    ///
+    /// Every status change of every source, query and reaction is appended to
+    /// the log with a sequence number. The log keeps the 1000 most recent
+    /// events, including those of components that have since been removed.
+    /// Pass `after` with the last sequence number seen to poll for new events.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use drasi_lib::{ComponentStatus, DrasiLib, EventLogFilter};
+    /// # async fn example(core: &DrasiLib) -> Result<(), Box<dyn std::error::Error>> {
+    /// let errors = core
+    ///     .get_event_log(&EventLogFilter {
+    ///         status: Some(ComponentStatus::Error),
+    ///         limit: Some(10),
+    ///         ..Default::default()
+    ///     })
+    ///     .await;
+    /// for entry in &errors {
+    ///     println!("#{} {}: {:?}", entry.sequence, entry.event.component_id, entry.event.message);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_event_log(&self, filter: &EventLogFilter) -> Vec<EventLogEntry> {
+        self.component_graph.read().await.get_event_log(filter)
+    }
+
+    /// Subscribe to the instance-wide event log.
+    ///
+    /// Returns the retained log and a broadcast receiver for the entries
+    /// recorded after it, so no event is missed or seen twice between the two.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use drasi_lib::DrasiLib;
+    /// # async fn example(core: &DrasiLib) -> Result<(), Box<dyn std::error::Error>> {
+    /// let (history, mut receiver) = core.subscribe_event_log().await;
+    /// for entry in history {
+    ///     println!("#{} {} {:?}", entry.sequence, entry.event.component_id, entry.event.status);
+    /// }
+    /// while let Ok(entry) = receiver.recv().await {
+    ///     println!("#{} {} {:?}", entry.sequence, entry.event.component_id, entry.event.status);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn subscribe_event_log(
+        &self,
+    ) -> (Vec<EventLogEntry>, broadcast::Receiver<EventLogEntry>) {
+        self.component_graph.read().await.subscribe_event_log()
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
+    use crate::channels::ComponentStatus;
     use crate::component_graph::ComponentKind;
+    use crate::managers::EventLogFilter;
     use crate::sources::tests::TestMockSource;
     use crate::{DrasiLib, Query};
 
@@ -353,4 +418,68 @@ mod tests {
             "Error should mention the dependent ID"
         );
     }
+
+    // ========================================================================
+    // Event log
+    // ========================================================================
+
+    #[tokio::test]
+    async fn event_log_keeps_events_of_removed_components() {
+        let core = DrasiLib::builder()
+            .with_id("event-log")
+            .build()
+            .await
+            .unwrap();
+        core.start().await.unwrap();
+        let mut event_rx = core.component_graph.read().await.subscribe();
+
+        let source = TestMockSource::with_auto_start("src1".to_string(), false).unwrap();
+        core.add_source(source).await.unwrap();
+        core.start_source("src1").await.unwrap();
+        crate::test_helpers::wait_for_component_status(
+            &mut event_rx,
+            "src1",
+            ComponentStatus::Running,
+            Duration::from_secs(5),
+        )
+        .await;
+        core.remove_source("src1", false).await.unwrap();
+
+        let filter = EventLogFilter {
+            component_id: Some("src1".to_string()),
+            ..Default::default()
+        };
+        let log = core.get_event_log(&filter).await;
+        assert!(log
+            .iter()
+            .any(|entry| entry.event.status == ComponentStatus::Running));
+        assert!(log.windows(2).all(|w| w[0].sequence < w[1].sequence));
+        assert_eq!(
+            log.last().map(|entry| entry.event.status),
+            Some(ComponentStatus::Removed)
+        );
+        assert!(core.get_graph().await.nodes.iter().all(|n| n.id != "src1"));
+    }
+
+    #[tokio::test]
+    async fn subscribe_event_log_streams_new_events() {
+        let core = DrasiLib::builder()
+            .with_id("event-log-subscribe")
+            .build()
+            .await
+            .unwrap();
+        core.start().await.unwrap();
+        let (history, mut receiver) = core.subscribe_event_log().await;
+        let last_sequence = history.last().map_or(0, |entry| entry.sequence);
+
+        let source = TestMockSource::with_auto_start("src1".to_string(), false).unwrap();
+        core.add_source(source).await.unwrap();
+
+        let entry = tokio::time::timeout(Duration::from_secs(5), receiver.recv())
+            .await
+            .expect("timed out waiting for event log entry")
+            .unwrap();
+        assert_eq!(entry.sequence, last_sequence + 1);
+        assert_eq!(entry.event.component_id, "src1");
+    }
 }
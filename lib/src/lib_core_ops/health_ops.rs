@@ -0,0 +1,170 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Health operations for DrasiLib
+//!
+//! Provides the structured health report used for liveness and readiness checks.
+
+use crate::component_graph::ComponentKind;
+use crate::health::{component_lag, ComponentHealth, HealthReport};
+use crate::lib_core::DrasiLib;
+
+impl DrasiLib {
+    // ============================================================================
+    // Health Operations
+    // ============================================================================
+
+    /// Get a structured health report for the instance and its components.
+    ///
+    /// The report lists every source, query and reaction with its status, last
+    /// error, uptime and lag (the number of events or results waiting to be
+    /// processed), and rolls them up into an overall
+    /// [`HealthStatus`](crate::HealthStatus). See [`crate::health`] for the rules.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use drasi_lib::{DrasiLib, HealthStatus};
+    /// # async fn example(core: &DrasiLib) -> Result<(), Box<dyn std::error::Error>> {
+    /// let report = core.health().await;
+    /// if report.status != HealthStatus::Healthy {
+    ///     for component in report.failed_components() {
+    ///         println!("{} failed: {:?}", component.id, component.last_error);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn health(&self) -> HealthReport {
+        let now = chrono::Utc::now();
+        let running = *self.running.read().await;
+        let uptime_secs = self
+            .started_at
+            .read()
+            .await
+            .map(|started| (now - started).num_seconds().max(0) as u64);
+        let live = !self.is_shutdown.load(std::sync::atomic::Ordering::Acquire);
+
+        let mut components = Vec::new();
+        {
+            let graph = self.component_graph.read().await;
+            for kind in [ComponentKind::Source, ComponentKind::Query, ComponentKind::Reaction] {
+                let Some(component_type) = kind.to_component_type() else {
+                    continue;
+                };
+                let mut ids = graph.list_by_kind(&kind);
+                ids.sort_by(|a, b| a.0.cmp(&b.0));
+                for (id, status) in ids {
+                    let lag = component_lag(&self.metrics, &kind, &id);
+                    let events = graph.get_events(&id);
+                    let last_error = graph.get_last_error(&id);
+                    components.push(ComponentHealth::new(
+                        id,
+                        component_type.clone(),
+                        status,
+                        &events,
+                        last_error,
+                        lag,
+                        now,
+                    ));
+                }
+            }
+        }
+
+        HealthReport::new(live, running, uptime_secs, components, now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::channels::{ComponentStatus, ComponentType};
+    use crate::component_graph::wait_for_status;
+    use crate::sources::tests::TestMockSource;
+    use crate::{DrasiLib, HealthStatus, Query};
+
+    async fn build_core() -> DrasiLib {
+        let source = TestMockSource::new("src1".to_string()).unwrap();
+        DrasiLib::builder()
+            .with_id("health-test")
+            .with_source(source)
+            .with_query(
+                Query::cypher("q1")
+                    .query("MATCH (n:Test) RETURN n")
+                    .from_source("src1")
+                    .build(),
+            )
+            .build()
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn health_before_start_is_live_but_not_ready() {
+        let core = build_core().await;
+
+        let report = core.health().await;
+
+        assert!(report.live);
+        assert!(!report.ready);
+        assert!(!report.running);
+        assert_eq!(report.status, HealthStatus::Degraded);
+        assert_eq!(report.uptime_secs, None);
+        let ids: Vec<_> = report.components.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, vec!["src1", "q1"]);
+    }
+
+    #[tokio::test]
+    async fn health_of_started_instance_reports_running_components() {
+        let core = build_core().await;
+        core.start().await.unwrap();
+        for id in ["src1", "q1"] {
+            wait_for_status(
+                &core.component_graph,
+                id,
+                &[ComponentStatus::Running],
+                Duration::from_secs(5),
+            )
+            .await
+            .unwrap();
+        }
+
+        let report = core.health().await;
+
+        assert_eq!(report.status, HealthStatus::Healthy);
+        assert!(report.ready);
+        assert!(report.running);
+        assert!(report.uptime_secs.is_some());
+        let query = report.components.iter().find(|c| c.id == "q1").unwrap();
+        assert_eq!(query.component_type, ComponentType::Query);
+        assert_eq!(query.status, ComponentStatus::Running);
+        assert!(query.uptime_secs.is_some());
+        assert_eq!(query.lag, Some(0));
+        assert_eq!(query.last_error, None);
+
+        core.stop().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn health_after_shutdown_is_not_live() {
+        let core = build_core().await;
+        core.start().await.unwrap();
+        core.shutdown().await.unwrap();
+
+        let report = core.health().await;
+
+        assert!(!report.live);
+        assert_eq!(report.status, HealthStatus::Unhealthy);
+    }
+}
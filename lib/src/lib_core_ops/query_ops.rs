@@ -19,13 +19,19 @@
 
 use anyhow::Result as AnyhowResult;
 use futures::stream::Stream;
+use serde::de::DeserializeOwned;
 use std::collections::HashMap;
+use std::sync::Arc;
 
-use crate::channels::{ComponentEvent, ComponentStatus};
+use crate::channels::{ComponentEvent, ComponentStatus, ResultDiff};
 use crate::component_ops::map_component_error;
 use crate::config::{QueryConfig, QueryRuntime};
 use crate::error::{DrasiError, Result};
 use crate::lib_core::DrasiLib;
+use crate::queries::{
+    QuarantinedChange, QueryEvaluationError, QueryResultSnapshot, QueryStateSnapshot,
+    ResultProcessor, TypedSubscription,
+};
 
 impl DrasiLib {
     /// Create a query in a running server
@@ -253,6 +259,30 @@ impl DrasiLib {
         self.inspection.get_query_results(id).await
     }
 
+    /// Get a snapshot of the current result set for a running query
+    ///
+    /// Unlike [`get_query_results`](Self::get_query_results), the snapshot also carries a
+    /// `sequence` number that increases every time the result set changes, so callers
+    /// polling for state can cheaply detect whether anything moved since their last read.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use drasi_lib::DrasiLib;
+    /// # async fn example(core: &DrasiLib) -> Result<(), Box<dyn std::error::Error>> {
+    /// let snapshot = core.query_results("my-query").await?;
+    /// println!(
+    ///     "{} items at sequence {} (last updated {:?})",
+    ///     snapshot.len(),
+    ///     snapshot.sequence,
+    ///     snapshot.last_updated
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn query_results(&self, id: &str) -> Result<QueryResultSnapshot> {
+        self.inspection.get_query_results_snapshot(id).await
+    }
+
     /// Get the full configuration for a specific query
     ///
     /// This returns the complete query configuration including all fields like auto_start and joins,
@@ -360,6 +390,233 @@ impl DrasiLib {
         self.inspection.subscribe_query_events(id).await
     }
 
+    /// Subscribe to the result diffs of a query directly from application code.
+    ///
+    /// The receiver yields every added, updated and deleted row (including rows produced
+    /// while the query bootstraps) from the moment of subscription, without having to
+    /// implement a [`Reaction`](crate::Reaction). Combine it with
+    /// [`query_results`](Self::query_results) to obtain the state prior to subscribing.
+    ///
+    /// The query does not need to be running. The receiver survives query restarts and
+    /// is closed when the query is removed. Slow consumers that fall more than the query's
+    /// `dispatch_buffer_capacity` diffs behind receive
+    /// [`RecvError::Lagged`](tokio::sync::broadcast::error::RecvError::Lagged).
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use drasi_lib::{DrasiLib, ResultDiff};
+    /// # async fn example(core: &DrasiLib) -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut rx = core.subscribe_to_query("my-query").await?;
+    /// while let Ok(diff) = rx.recv().await {
+    ///     match diff {
+    ///         ResultDiff::Add { data } => println!("added: {data}"),
+    ///         ResultDiff::Update { before, after, .. } => println!("updated: {before} -> {after}"),
+    ///         ResultDiff::Delete { data } => println!("deleted: {data}"),
+    ///         _ => {}
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn subscribe_to_query(
+        &self,
+        id: &str,
+    ) -> Result<tokio::sync::broadcast::Receiver<ResultDiff>> {
+        self.inspection.subscribe_to_query(id).await
+    }
+
+    /// Subscribe to the evaluation errors of a query.
+    ///
+    /// Every source change the query fails to evaluate, during bootstrap or
+    /// afterwards, produces a [`QueryEvaluationError`] naming the source, element
+    /// and error. The query keeps running; the change has no effect on its results.
+    /// The receiver behaves like the one returned by
+    /// [`subscribe_to_query`](Self::subscribe_to_query).
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use drasi_lib::DrasiLib;
+    /// # async fn example(core: &DrasiLib) -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut errors = core.subscribe_query_errors("my-query").await?;
+    /// while let Ok(error) = errors.recv().await {
+    ///     eprintln!(
+    ///         "{} failed on {}/{}: {}",
+    ///         error.query_id, error.source_id, error.element_id, error.error
+    ///     );
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn subscribe_query_errors(
+        &self,
+        id: &str,
+    ) -> Result<tokio::sync::broadcast::Receiver<QueryEvaluationError>> {
+        self.inspection.subscribe_query_errors(id).await
+    }
+
+    /// List the source changes a query failed to evaluate, oldest first.
+    ///
+    /// Only queries configured with a quarantine capacity keep failing changes
+    /// (see [`Query::with_quarantine`](crate::Query::with_quarantine)); once the
+    /// capacity is reached the oldest entry is evicted.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use drasi_lib::DrasiLib;
+    /// # async fn example(core: &DrasiLib) -> Result<(), Box<dyn std::error::Error>> {
+    /// for quarantined in core.quarantined_changes("my-query").await? {
+    ///     println!("#{} {:?}: {}", quarantined.id, quarantined.change, quarantined.error.error);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn quarantined_changes(&self, id: &str) -> Result<Vec<QuarantinedChange>> {
+        self.inspection.quarantined_changes(id).await
+    }
+
+    /// Remove and return every change in a query's quarantine, oldest first.
+    pub async fn drain_quarantine(&self, id: &str) -> Result<Vec<QuarantinedChange>> {
+        self.inspection.drain_quarantine(id).await
+    }
+
+    /// Get the current result set of a running query deserialized into `T`.
+    ///
+    /// Rows that do not fit `T` produce a [`DrasiError::Internal`] wrapping a
+    /// [`ResultDecodeError`](crate::queries::ResultDecodeError) that names the missing or mistyped column.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use drasi_lib::DrasiLib;
+    /// # async fn example(core: &DrasiLib) -> Result<(), Box<dyn std::error::Error>> {
+    /// #[derive(serde::Deserialize)]
+    /// struct Person {
+    ///     name: String,
+    ///     age: u32,
+    /// }
+    ///
+    /// let people: Vec<Person> = core.query_results_as("people").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn query_results_as<T: DeserializeOwned>(&self, id: &str) -> Result<Vec<T>> {
+        self.query_results(id)
+            .await?
+            .results_as()
+            .map_err(|e| DrasiError::Internal(e.into()))
+    }
+
+    /// Subscribe to the result diffs of a query with rows deserialized into `T`.
+    ///
+    /// Typed counterpart of [`subscribe_to_query`](Self::subscribe_to_query); see
+    /// [`TypedSubscription`] for how decode errors and lag are reported.
+    pub async fn subscribe_to_query_as<T: DeserializeOwned>(
+        &self,
+        id: &str,
+    ) -> Result<TypedSubscription<T>> {
+        Ok(TypedSubscription::new(self.subscribe_to_query(id).await?))
+    }
+
+    /// Export the full state of a running query: every element in its element
+    /// index plus its current result set.
+    ///
+    /// Import the result into a query on another instance with
+    /// [`import_query_state`](Self::import_query_state) to warm it up without
+    /// re-bootstrapping. Fails with [`DrasiError::OperationFailed`] if the
+    /// query's storage backend cannot enumerate its elements.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use drasi_lib::DrasiLib;
+    /// # async fn example(core: &DrasiLib) -> Result<(), Box<dyn std::error::Error>> {
+    /// let snapshot = core.export_query_state("heavy-query").await?;
+    /// snapshot.save("/backups/heavy-query.json")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn export_query_state(&self, id: &str) -> Result<QueryStateSnapshot> {
+        self.require_running_query(id).await?;
+        self.query_manager
+            .export_query_state(id)
+            .await
+            .map_err(|e| DrasiError::operation_failed("query", id, "export_state", e.to_string()))
+    }
+
+    /// Register a result processor that queries can reference by name.
+    ///
+    /// Queries added after this call can list `name` in their result processors
+    /// (see [`Query::with_result_processor`](crate::Query::with_result_processor)).
+    /// Processors for queries defined at build time should be registered with
+    /// [`DrasiLibBuilder::with_result_processor`](crate::DrasiLibBuilder::with_result_processor).
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use drasi_lib::DrasiLib;
+    /// # use drasi_lib::queries::RedactFields;
+    /// # async fn example(core: &DrasiLib) {
+    /// core.register_result_processor("redact-pii", RedactFields::new(["email", "phone"]))
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn register_result_processor(
+        &self,
+        name: impl Into<String>,
+        processor: impl ResultProcessor + 'static,
+    ) {
+        self.query_manager
+            .register_result_processor(name, Arc::new(processor))
+            .await;
+    }
+
+    /// Import a snapshot produced by [`export_query_state`](Self::export_query_state)
+    /// into a running query.
+    ///
+    /// The snapshot's elements are inserted into the query as if a source had
+    /// sent them, so the resulting diffs reach subscribed reactions. Import into
+    /// a query whose indexes are empty (e.g. with bootstrap disabled) to avoid
+    /// duplicates. Returns the number of elements applied.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use drasi_lib::{DrasiLib, QueryStateSnapshot};
+    /// # async fn example(core: &DrasiLib) -> Result<(), Box<dyn std::error::Error>> {
+    /// let snapshot = QueryStateSnapshot::load("/backups/heavy-query.json")?;
+    /// let count = core.import_query_state("heavy-query", &snapshot).await?;
+    /// println!("restored {count} elements");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn import_query_state(
+        &self,
+        id: &str,
+        snapshot: &QueryStateSnapshot,
+    ) -> Result<usize> {
+        snapshot.check_version()?;
+        self.require_running_query(id).await?;
+
+        if let Ok(config) = self.get_query_config(id).await {
+            if config.query != snapshot.query {
+                log::warn!(
+                    "Importing snapshot of query '{}' into query '{id}' with different query text",
+                    snapshot.query_id
+                );
+            }
+        }
+
+        self.query_manager
+            .import_query_state(id, snapshot)
+            .await
+            .map_err(|e| DrasiError::operation_failed("query", id, "import_state", e.to_string()))
+    }
+
+    async fn require_running_query(&self, id: &str) -> Result<()> {
+        if self.get_query_status(id).await? != ComponentStatus::Running {
+            return Err(DrasiError::invalid_state(format!(
+                "Query '{id}' is not running"
+            )));
+        }
+        Ok(())
+    }
+
     /// Internal helper for creating queries with auto-start control
     pub(crate) async fn add_query_with_options(
         &self,
@@ -620,4 +877,780 @@ mod tests {
         assert_eq!(retrieved.sources.len(), 1);
         assert_eq!(retrieved.sources[0].source_id, "test-source");
     }
+
+    // ========================================================================
+    // query_results
+    // ========================================================================
+
+    #[tokio::test]
+    async fn query_results_returns_snapshot_for_running_query() {
+        let core = build_core_with_source().await;
+
+        let config = Query::cypher("q-snapshot")
+            .query("MATCH (n:Test) RETURN n")
+            .from_source("test-source")
+            .auto_start(false)
+            .build();
+        core.add_query(config).await.unwrap();
+
+        // Not running yet
+        let err = core.query_results("q-snapshot").await.unwrap_err();
+        assert!(
+            matches!(err, DrasiError::InvalidState { .. }),
+            "expected InvalidState, got: {err:?}"
+        );
+
+        let mut event_rx = core.subscribe_all_component_events();
+        core.start_query("q-snapshot").await.unwrap();
+        crate::test_helpers::wait_for_component_status(
+            &mut event_rx,
+            "q-snapshot",
+            ComponentStatus::Running,
+            std::time::Duration::from_secs(5),
+        )
+        .await;
+
+        let snapshot = core.query_results("q-snapshot").await.unwrap();
+        assert_eq!(snapshot.query_id, "q-snapshot");
+        assert!(snapshot.is_empty());
+        assert_eq!(snapshot.sequence, 0);
+        assert!(snapshot.last_updated.is_none());
+    }
+
+    #[tokio::test]
+    async fn query_results_nonexistent_returns_error() {
+        let core = build_core_with_source().await;
+
+        let err = core.query_results("ghost-query").await.unwrap_err();
+        assert!(
+            matches!(err, DrasiError::ComponentNotFound { .. }),
+            "expected ComponentNotFound, got: {err:?}"
+        );
+    }
+
+    // ========================================================================
+    // subscribe_to_query
+    // ========================================================================
+
+    #[tokio::test]
+    async fn subscribe_to_query_closes_when_query_removed() {
+        let core = build_core_with_source().await;
+
+        let config = Query::cypher("q-subscribe")
+            .query("MATCH (n:Test) RETURN n")
+            .from_source("test-source")
+            .auto_start(false)
+            .build();
+        core.add_query(config).await.unwrap();
+
+        let mut rx = core.subscribe_to_query("q-subscribe").await.unwrap();
+        assert!(matches!(
+            rx.try_recv(),
+            Err(tokio::sync::broadcast::error::TryRecvError::Empty)
+        ));
+
+        core.remove_query("q-subscribe").await.unwrap();
+        assert!(matches!(
+            rx.recv().await,
+            Err(tokio::sync::broadcast::error::RecvError::Closed)
+        ));
+    }
+
+    #[tokio::test]
+    async fn subscribe_to_query_nonexistent_returns_error() {
+        let core = build_core_with_source().await;
+
+        let err = core.subscribe_to_query("ghost-query").await.unwrap_err();
+        assert!(
+            matches!(err, DrasiError::ComponentNotFound { .. }),
+            "expected ComponentNotFound, got: {err:?}"
+        );
+    }
+
+    // ========================================================================
+    // export_query_state / import_query_state
+    // ========================================================================
+
+    async fn start_and_wait(core: &DrasiLib, id: &str) {
+        let mut event_rx = core.subscribe_all_component_events();
+        core.start_query(id).await.unwrap();
+        crate::test_helpers::wait_for_component_status(
+            &mut event_rx,
+            id,
+            ComponentStatus::Running,
+            std::time::Duration::from_secs(5),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn export_and_import_query_state_roundtrip() {
+        use drasi_core::models::{
+            Element, ElementMetadata, ElementPropertyMap, ElementReference, ElementValue,
+            SourceChange,
+        };
+        use std::sync::Arc;
+
+        let core = build_core_with_source().await;
+        let query = "MATCH (n:Test) RETURN n.name AS name";
+        for id in ["q-export", "q-import"] {
+            let config = Query::cypher(id)
+                .query(query)
+                .from_source("test-source")
+                .auto_start(false)
+                .build();
+            core.add_query(config).await.unwrap();
+        }
+        start_and_wait(&core, "q-export").await;
+
+        let mut properties = ElementPropertyMap::new();
+        properties.insert("name", ElementValue::String(Arc::from("alpha")));
+        let element = Element::Node {
+            metadata: ElementMetadata {
+                reference: ElementReference::new("test-source", "n1"),
+                labels: Arc::from(vec![Arc::from("Test")]),
+                effective_from: 1,
+            },
+            properties,
+        };
+        let source = core
+            .source_manager
+            .get_source_instance("test-source")
+            .await
+            .unwrap();
+        source
+            .as_any()
+            .downcast_ref::<TestMockSource>()
+            .unwrap()
+            .inject_event(SourceChange::Insert { element })
+            .await
+            .unwrap();
+
+        let snapshot = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            loop {
+                let snapshot = core.export_query_state("q-export").await.unwrap();
+                if !snapshot.results.is_empty() {
+                    return snapshot;
+                }
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("timed out waiting for query results");
+        assert_eq!(snapshot.query_id, "q-export");
+        assert_eq!(snapshot.elements.len(), 1);
+
+        // q-import subscribes after the event was sent, so only the import populates it
+        start_and_wait(&core, "q-import").await;
+
+        let applied = core
+            .import_query_state("q-import", &snapshot)
+            .await
+            .unwrap();
+        assert_eq!(applied, 1);
+
+        let results = core.query_results("q-import").await.unwrap();
+        assert_eq!(results.results, vec![serde_json::json!({"name": "alpha"})]);
+    }
+
+    #[tokio::test]
+    async fn evaluation_pool_keeps_per_element_order() {
+        use drasi_core::models::{
+            Element, ElementMetadata, ElementPropertyMap, ElementReference, ElementValue,
+            SourceChange,
+        };
+        use std::sync::Arc;
+
+        let source = TestMockSource::new("test-source".to_string()).unwrap();
+        let core = DrasiLib::builder()
+            .with_id("test")
+            .with_source(source)
+            .with_evaluation_workers(4)
+            .build()
+            .await
+            .unwrap();
+        core.start().await.unwrap();
+
+        // One element-local query partitioned by element, one aggregating query
+        // evaluated in order
+        for (id, query) in [
+            (
+                "q-names",
+                "MATCH (n:Test) RETURN n.name AS name, n.version AS version",
+            ),
+            ("q-count", "MATCH (n:Test) RETURN count(n) AS total"),
+        ] {
+            let config = Query::cypher(id)
+                .query(query)
+                .from_source("test-source")
+                .auto_start(false)
+                .build();
+            core.add_query(config).await.unwrap();
+            start_and_wait(&core, id).await;
+        }
+
+        let node = |id: &str, version: i64| {
+            let mut properties = ElementPropertyMap::new();
+            properties.insert("name", ElementValue::String(Arc::from(id)));
+            properties.insert("version", ElementValue::Integer(version));
+            Element::Node {
+                metadata: ElementMetadata {
+                    reference: ElementReference::new("test-source", id),
+                    labels: Arc::from(vec![Arc::from("Test")]),
+                    effective_from: version as u64,
+                },
+                properties,
+            }
+        };
+        let source = core
+            .source_manager
+            .get_source_instance("test-source")
+            .await
+            .unwrap();
+        let source = source.as_any().downcast_ref::<TestMockSource>().unwrap();
+        for i in 0..10 {
+            let element = node(&format!("n{i}"), 0);
+            source
+                .inject_event(SourceChange::Insert { element })
+                .await
+                .unwrap();
+        }
+        for version in 1..=20 {
+            let element = node("n0", version);
+            source
+                .inject_event(SourceChange::Update { element })
+                .await
+                .unwrap();
+        }
+
+        tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            loop {
+                let names = core.query_results("q-names").await.unwrap().results;
+                let count = core.query_results("q-count").await.unwrap().results;
+                let latest = names
+                    .iter()
+                    .any(|row| row == &serde_json::json!({"name": "n0", "version": 20}));
+                if names.len() == 10 && latest && count == vec![serde_json::json!({"total": 10})] {
+                    return;
+                }
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("timed out waiting for pooled evaluation results");
+    }
+
+    #[tokio::test]
+    async fn evaluation_pool_evaluates_one_change_at_a_time_per_query() {
+        use drasi_core::interface::{ElementIndex, MiddlewareError, SourceMiddleware};
+        use drasi_core::models::{
+            Element, ElementMetadata, ElementPropertyMap, ElementReference, ElementValue,
+            SourceChange, SourceMiddlewareConfig,
+        };
+        use std::sync::Arc;
+        use std::time::Duration;
+        use tokio::sync::Notify;
+
+        /// Holds back the change to element `n1` until released
+        struct Gate {
+            entered: Notify,
+            release: Notify,
+        }
+
+        #[async_trait::async_trait]
+        impl SourceMiddleware for Gate {
+            async fn process(
+                &self,
+                source_change: SourceChange,
+                _element_index: &dyn ElementIndex,
+            ) -> std::result::Result<Vec<SourceChange>, MiddlewareError> {
+                if source_change.get_reference().element_id.as_ref() == "n1" {
+                    self.entered.notify_one();
+                    self.release.notified().await;
+                }
+                Ok(vec![source_change])
+            }
+        }
+
+        let gate = Arc::new(Gate {
+            entered: Notify::new(),
+            release: Notify::new(),
+        });
+        let source = TestMockSource::new("test-source".to_string()).unwrap();
+        let core = DrasiLib::builder()
+            .with_id("test")
+            .with_source(source)
+            .with_evaluation_workers(4)
+            .with_middleware_instance("gate", gate.clone())
+            .build()
+            .await
+            .unwrap();
+        core.start().await.unwrap();
+
+        // Element-local, so changes to n1 and n2 are handed to the pool separately
+        let config = Query::cypher("q-gated")
+            .query("MATCH (n:Test) RETURN n.name AS name")
+            .from_source_with_pipeline("test-source", vec!["gate-1".into()])
+            .with_middleware(SourceMiddlewareConfig {
+                kind: "gate".into(),
+                name: "gate-1".into(),
+                config: serde_json::Map::new(),
+            })
+            .auto_start(false)
+            .build();
+        core.add_query(config).await.unwrap();
+        start_and_wait(&core, "q-gated").await;
+
+        let source = core
+            .source_manager
+            .get_source_instance("test-source")
+            .await
+            .unwrap();
+        let source = source.as_any().downcast_ref::<TestMockSource>().unwrap();
+        for id in ["n1", "n2"] {
+            let mut properties = ElementPropertyMap::new();
+            properties.insert("name", ElementValue::String(Arc::from(id)));
+            let element = Element::Node {
+                metadata: ElementMetadata {
+                    reference: ElementReference::new("test-source", id),
+                    labels: Arc::from(vec![Arc::from("Test")]),
+                    effective_from: 1,
+                },
+                properties,
+            };
+            source
+                .inject_event(SourceChange::Insert { element })
+                .await
+                .unwrap();
+            if id == "n1" {
+                tokio::time::timeout(Duration::from_secs(5), gate.entered.notified())
+                    .await
+                    .expect("n1 should reach the middleware");
+            }
+        }
+
+        // n1 holds the query's change lock, so n2 is not evaluated either
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert!(core
+            .query_results("q-gated")
+            .await
+            .unwrap()
+            .results
+            .is_empty());
+
+        gate.release.notify_one();
+        tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                let results = core.query_results("q-gated").await.unwrap().results;
+                if results.len() == 2 {
+                    return;
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("timed out waiting for both results after release");
+    }
+
+    #[tokio::test]
+    async fn evaluation_pool_keeps_order_per_ordering_key() {
+        use crate::channels::{OrderingKey, ResultDiff};
+        use drasi_core::models::{
+            Element, ElementMetadata, ElementPropertyMap, ElementReference, ElementValue,
+            SourceChange,
+        };
+        use std::sync::Arc;
+
+        let source = TestMockSource::new("test-source".to_string()).unwrap();
+        let core = DrasiLib::builder()
+            .with_id("test")
+            .with_source(source)
+            .with_evaluation_workers(4)
+            .build()
+            .await
+            .unwrap();
+        core.start().await.unwrap();
+
+        let config = Query::cypher("q-keyed")
+            .query("MATCH (n:Test) RETURN n.name AS name")
+            .from_source("test-source")
+            .auto_start(false)
+            .build();
+        core.add_query(config).await.unwrap();
+        start_and_wait(&core, "q-keyed").await;
+        let mut results = core.subscribe_to_query("q-keyed").await.unwrap();
+
+        // Different elements under one key, as records of one Kinesis partition
+        // key or Pub/Sub ordering key arrive; without the key each element would
+        // get its own lane and their results could be dispatched in any order
+        let source = core
+            .source_manager
+            .get_source_instance("test-source")
+            .await
+            .unwrap();
+        let source = source.as_any().downcast_ref::<TestMockSource>().unwrap();
+        let names: Vec<String> = (0..50).map(|i| format!("n{i}")).collect();
+        for name in &names {
+            let mut properties = ElementPropertyMap::new();
+            properties.insert("name", ElementValue::String(Arc::from(name.as_str())));
+            let element = Element::Node {
+                metadata: ElementMetadata {
+                    reference: ElementReference::new("test-source", name),
+                    labels: Arc::from(vec![Arc::from("Test")]),
+                    effective_from: 1,
+                },
+                properties,
+            };
+            source
+                .inject_keyed_event(
+                    SourceChange::Insert { element },
+                    OrderingKey::from("partition-0"),
+                )
+                .await
+                .unwrap();
+        }
+
+        let received = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            let mut received = Vec::new();
+            while received.len() < names.len() {
+                if let ResultDiff::Add { data } = results.recv().await.unwrap() {
+                    received.push(data["name"].as_str().unwrap().to_string());
+                }
+            }
+            received
+        })
+        .await
+        .expect("timed out waiting for keyed results");
+        assert_eq!(received, names);
+    }
+
+    #[tokio::test]
+    async fn export_query_state_requires_running_query() {
+        let core = build_core_with_source().await;
+        let config = Query::cypher("q-stopped")
+            .query("MATCH (n:Test) RETURN n")
+            .from_source("test-source")
+            .auto_start(false)
+            .build();
+        core.add_query(config).await.unwrap();
+
+        let err = core.export_query_state("q-stopped").await.unwrap_err();
+        assert!(
+            matches!(err, DrasiError::InvalidState { .. }),
+            "expected InvalidState, got: {err:?}"
+        );
+
+        let err = core.export_query_state("ghost-query").await.unwrap_err();
+        assert!(
+            matches!(err, DrasiError::ComponentNotFound { .. }),
+            "expected ComponentNotFound, got: {err:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn add_query_with_unknown_result_processor_fails() {
+        let core = build_core_with_source().await;
+        let config = Query::cypher("q-processed")
+            .query("MATCH (n:Test) RETURN n")
+            .from_source("test-source")
+            .with_result_processor("missing")
+            .auto_start(false)
+            .build();
+
+        let err = core.add_query(config).await.unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("unknown result processor 'missing'"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn result_processors_apply_to_subscribers_and_result_set() {
+        use crate::queries::RedactFields;
+        use drasi_core::models::{
+            Element, ElementMetadata, ElementPropertyMap, ElementReference, ElementValue,
+            SourceChange,
+        };
+        use std::sync::Arc;
+
+        let core = build_core_with_source().await;
+        core.register_result_processor("redact", RedactFields::new(["secret"]))
+            .await;
+        let config = Query::cypher("q-redacted")
+            .query("MATCH (n:Test) RETURN n.name AS name, n.secret AS secret")
+            .from_source("test-source")
+            .with_result_processor("redact")
+            .auto_start(false)
+            .build();
+        core.add_query(config).await.unwrap();
+        start_and_wait(&core, "q-redacted").await;
+        let mut results_rx = core.subscribe_to_query("q-redacted").await.unwrap();
+
+        let mut properties = ElementPropertyMap::new();
+        properties.insert("name", ElementValue::String(Arc::from("alpha")));
+        properties.insert("secret", ElementValue::String(Arc::from("hunter2")));
+        let element = Element::Node {
+            metadata: ElementMetadata {
+                reference: ElementReference::new("test-source", "n1"),
+                labels: Arc::from(vec![Arc::from("Test")]),
+                effective_from: 1,
+            },
+            properties,
+        };
+        let source = core
+            .source_manager
+            .get_source_instance("test-source")
+            .await
+            .unwrap();
+        source
+            .as_any()
+            .downcast_ref::<TestMockSource>()
+            .unwrap()
+            .inject_event(SourceChange::Insert { element })
+            .await
+            .unwrap();
+
+        let diff = tokio::time::timeout(std::time::Duration::from_secs(5), results_rx.recv())
+            .await
+            .expect("timed out waiting for results")
+            .unwrap();
+        assert_eq!(
+            diff,
+            crate::channels::ResultDiff::Add {
+                data: serde_json::json!({"name": "alpha"})
+            }
+        );
+
+        // Snapshots are projected through the processors, so redacted fields do not leak
+        let results = core.query_results("q-redacted").await.unwrap();
+        assert_eq!(results.results, vec![serde_json::json!({"name": "alpha"})]);
+        assert!(results.results[0].get("secret").is_none());
+    }
+
+    #[tokio::test]
+    async fn suppressed_updates_do_not_leave_stale_rows() {
+        use crate::channels::ResultDiff;
+        use crate::queries::SuppressUnchangedUpdates;
+        use drasi_core::models::{
+            Element, ElementMetadata, ElementPropertyMap, ElementReference, ElementValue,
+            SourceChange,
+        };
+        use serde_json::json;
+        use std::sync::Arc;
+
+        let core = build_core_with_source().await;
+        core.register_result_processor(
+            "material",
+            SuppressUnchangedUpdates::new().ignoring(["ts"]),
+        )
+        .await;
+        let config = Query::cypher("q-material")
+            .query("MATCH (n:Test) RETURN n.name AS name, n.ts AS ts")
+            .from_source("test-source")
+            .with_result_processor("material")
+            .auto_start(false)
+            .build();
+        core.add_query(config).await.unwrap();
+        start_and_wait(&core, "q-material").await;
+        let mut results_rx = core.subscribe_to_query("q-material").await.unwrap();
+
+        let metadata = ElementMetadata {
+            reference: ElementReference::new("test-source", "n1"),
+            labels: Arc::from(vec![Arc::from("Test")]),
+            effective_from: 1,
+        };
+        let node = |name: &str, ts: i64| {
+            let mut properties = ElementPropertyMap::new();
+            properties.insert("name", ElementValue::String(Arc::from(name)));
+            properties.insert("ts", ElementValue::Integer(ts));
+            Element::Node {
+                metadata: metadata.clone(),
+                properties,
+            }
+        };
+        let source = core
+            .source_manager
+            .get_source_instance("test-source")
+            .await
+            .unwrap();
+        let source = source.as_any().downcast_ref::<TestMockSource>().unwrap();
+        async fn next_diff(rx: &mut tokio::sync::broadcast::Receiver<ResultDiff>) -> ResultDiff {
+            tokio::time::timeout(std::time::Duration::from_secs(5), rx.recv())
+                .await
+                .expect("timed out waiting for results")
+                .unwrap()
+        }
+
+        source
+            .inject_event(SourceChange::Insert {
+                element: node("alpha", 1),
+            })
+            .await
+            .unwrap();
+        assert!(matches!(
+            next_diff(&mut results_rx).await,
+            ResultDiff::Add { .. }
+        ));
+
+        // Only the ignored column changes, so subscribers never see this update
+        source
+            .inject_event(SourceChange::Update {
+                element: node("alpha", 2),
+            })
+            .await
+            .unwrap();
+        source
+            .inject_event(SourceChange::Update {
+                element: node("beta", 3),
+            })
+            .await
+            .unwrap();
+        match next_diff(&mut results_rx).await {
+            ResultDiff::Update { before, after, .. } => {
+                assert_eq!(before, json!({"name": "alpha", "ts": 2}));
+                assert_eq!(after, json!({"name": "beta", "ts": 3}));
+            }
+            other => panic!("expected the material update, got {other:?}"),
+        }
+        let results = core.query_results("q-material").await.unwrap();
+        assert_eq!(results.results, vec![json!({"name": "beta", "ts": 3})]);
+
+        source
+            .inject_event(SourceChange::Delete {
+                metadata: metadata.clone(),
+            })
+            .await
+            .unwrap();
+        assert!(matches!(
+            next_diff(&mut results_rx).await,
+            ResultDiff::Delete { .. }
+        ));
+        let results = core.query_results("q-material").await.unwrap();
+        assert!(results.is_empty(), "stale rows left: {:?}", results.results);
+    }
+
+    // ========================================================================
+    // Error channel and quarantine
+    // ========================================================================
+
+    #[tokio::test]
+    async fn failing_change_is_reported_and_quarantined() {
+        use drasi_core::models::{
+            Element, ElementMetadata, ElementPropertyMap, ElementReference, ElementValue,
+            SourceChange,
+        };
+        use std::sync::Arc;
+
+        let core = build_core_with_source().await;
+        let config = Query::cypher("q-poison")
+            .query("MATCH (n:Test) RETURN toUpper(n.name) AS name")
+            .from_source("test-source")
+            .with_quarantine(10)
+            .auto_start(false)
+            .build();
+        core.add_query(config).await.unwrap();
+        start_and_wait(&core, "q-poison").await;
+        let mut errors_rx = core.subscribe_query_errors("q-poison").await.unwrap();
+
+        let mut properties = ElementPropertyMap::new();
+        properties.insert("name", ElementValue::Integer(42));
+        let change = SourceChange::Insert {
+            element: Element::Node {
+                metadata: ElementMetadata {
+                    reference: ElementReference::new("test-source", "bad-1"),
+                    labels: Arc::from(vec![Arc::from("Test")]),
+                    effective_from: 1,
+                },
+                properties,
+            },
+        };
+        let source = core
+            .source_manager
+            .get_source_instance("test-source")
+            .await
+            .unwrap();
+        source
+            .as_any()
+            .downcast_ref::<TestMockSource>()
+            .unwrap()
+            .inject_event(change.clone())
+            .await
+            .unwrap();
+
+        let error = tokio::time::timeout(std::time::Duration::from_secs(5), errors_rx.recv())
+            .await
+            .expect("timed out waiting for error")
+            .unwrap();
+        assert_eq!(error.query_id, "q-poison");
+        assert_eq!(error.source_id, "test-source");
+        assert_eq!(error.element_id, "bad-1");
+        assert_eq!(error.operation, "insert");
+        assert_eq!(error.quarantine_id, Some(1));
+
+        let quarantined = core.quarantined_changes("q-poison").await.unwrap();
+        assert_eq!(quarantined.len(), 1);
+        assert_eq!(quarantined[0].change, change);
+        assert_eq!(quarantined[0].error, error);
+
+        assert_eq!(core.drain_quarantine("q-poison").await.unwrap().len(), 1);
+        assert!(core
+            .quarantined_changes("q-poison")
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(core.query_results("q-poison").await.unwrap().is_empty());
+
+        let metrics = core.metrics();
+        assert_eq!(
+            metrics.sample(
+                "drasi_query_events_total",
+                &[("query", "q-poison"), ("source", "test-source")]
+            ),
+            Some(1.0)
+        );
+        assert_eq!(
+            metrics.sample("drasi_query_errors_total", &[("query", "q-poison")]),
+            Some(1.0)
+        );
+        assert_eq!(
+            metrics.sample("drasi_query_results_total", &[("query", "q-poison")]),
+            Some(0.0)
+        );
+    }
+
+    #[tokio::test]
+    async fn removing_query_removes_its_metrics() {
+        let core = build_core_with_source().await;
+        let config = Query::cypher("q-metrics")
+            .query("MATCH (n:Test) RETURN n.name AS name")
+            .from_source("test-source")
+            .auto_start(false)
+            .build();
+        core.add_query(config).await.unwrap();
+        start_and_wait(&core, "q-metrics").await;
+
+        let labels = [("query", "q-metrics")];
+        assert_eq!(
+            core.metrics().sample("drasi_query_queue_depth", &labels),
+            Some(0.0)
+        );
+
+        core.remove_query("q-metrics").await.unwrap();
+        assert_eq!(
+            core.metrics().sample("drasi_query_queue_depth", &labels),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn quarantine_of_unknown_query_is_not_found() {
+        let core = build_core_with_source().await;
+        let err = core.quarantined_changes("ghost-query").await.unwrap_err();
+        assert!(
+            matches!(err, DrasiError::ComponentNotFound { .. }),
+            "expected ComponentNotFound, got: {err:?}"
+        );
+        assert!(core.subscribe_query_errors("ghost-query").await.is_err());
+    }
 }
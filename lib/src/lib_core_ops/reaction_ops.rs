@@ -51,6 +51,38 @@ impl DrasiLib {
             .await
     }
 
+    /// Create a reaction of a registered kind from JSON configuration and add it.
+    ///
+    /// The reaction is built by the factory registered for `kind` in the
+    /// [`ComponentRegistry`](crate::ComponentRegistry) and then added exactly
+    /// like [`add_reaction`](Self::add_reaction), including auto-start.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use drasi_lib::DrasiLib;
+    /// # async fn example(core: &DrasiLib) -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = serde_json::json!({ "defaultTemplate": null });
+    /// core.add_reaction_from_config("log", "audit", vec!["orders".into()], &config, true)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_reaction_from_config(
+        &self,
+        kind: &str,
+        id: &str,
+        query_ids: Vec<String>,
+        config: &serde_json::Value,
+        auto_start: bool,
+    ) -> Result<()> {
+        self.state_guard.require_initialized()?;
+        let reaction = self
+            .component_registry
+            .create_reaction(kind, id, query_ids, config, auto_start)
+            .await?;
+        self.add_reaction(reaction).await
+    }
+
     /// Add a reaction to a running server with additional metadata.
     ///
     /// Same as [`add_reaction`](Self::add_reaction) but merges `extra_metadata`
@@ -50,6 +50,36 @@ impl DrasiLib {
         self.add_source_with_metadata(source, HashMap::new()).await
     }
 
+    /// Create a source of a registered kind from JSON configuration and add it.
+    ///
+    /// The source is built by the factory registered for `kind` in the
+    /// [`ComponentRegistry`](crate::ComponentRegistry) and then added exactly
+    /// like [`add_source`](Self::add_source), including auto-start.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use drasi_lib::DrasiLib;
+    /// # async fn example(core: &DrasiLib) -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = serde_json::json!({ "host": "localhost", "topic": "orders" });
+    /// core.add_source_from_config("mqtt", "orders", &config, true).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_source_from_config(
+        &self,
+        kind: &str,
+        id: &str,
+        config: &serde_json::Value,
+        auto_start: bool,
+    ) -> Result<()> {
+        self.state_guard.require_initialized()?;
+        let source = self
+            .component_registry
+            .create_source(kind, id, config, auto_start)
+            .await?;
+        self.add_source(source).await
+    }
+
     /// Add a source to a running server with additional metadata.
     ///
     /// Same as [`add_source`](Self::add_source) but merges `extra_metadata`
@@ -13,18 +13,75 @@
 // limitations under the License.
 
 use anyhow::Result;
-use log::info;
+use log::{info, warn};
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
 
 use crate::channels::ComponentStatus;
 use crate::component_graph::{ComponentGraph, ComponentKind};
 use crate::config::RuntimeConfig;
+use crate::health::component_lag;
+use crate::metrics::MetricsRegistry;
 use crate::queries::QueryManager;
 use crate::reactions::ReactionManager;
 use crate::sources::SourceManager;
 
+/// How often pending work is sampled while draining.
+const DRAIN_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Timeouts of the stages of a draining stop.
+///
+/// With draining enabled (see [`DrasiLibBuilder::with_drain_on_stop`]),
+/// [`DrasiLib::stop`] first stops the sources, waits for queries to evaluate
+/// every change already emitted, then waits for reactions to handle every
+/// result already produced, and only then stops queries and reactions. A stage
+/// that exceeds its timeout is logged and the stop continues, so changes still
+/// in flight at that point may be dropped.
+///
+/// [`DrasiLibBuilder::with_drain_on_stop`]: crate::DrasiLibBuilder::with_drain_on_stop
+/// [`DrasiLib::stop`]: crate::DrasiLib::stop
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DrainConfig {
+    /// Time allowed for sources to stop. Default: 10 seconds.
+    pub source_stop_timeout: Duration,
+    /// Time allowed for queries to empty their queues. Default: 30 seconds.
+    pub query_drain_timeout: Duration,
+    /// Time allowed for reactions to empty their queues. Default: 30 seconds.
+    pub reaction_flush_timeout: Duration,
+}
+
+impl Default for DrainConfig {
+    fn default() -> Self {
+        Self {
+            source_stop_timeout: Duration::from_secs(10),
+            query_drain_timeout: Duration::from_secs(30),
+            reaction_flush_timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+impl DrainConfig {
+    /// Set the time allowed for sources to stop.
+    pub fn with_source_stop_timeout(mut self, timeout: Duration) -> Self {
+        self.source_stop_timeout = timeout;
+        self
+    }
+
+    /// Set the time allowed for queries to empty their queues.
+    pub fn with_query_drain_timeout(mut self, timeout: Duration) -> Self {
+        self.query_drain_timeout = timeout;
+        self
+    }
+
+    /// Set the time allowed for reactions to empty their queues.
+    pub fn with_reaction_flush_timeout(mut self, timeout: Duration) -> Self {
+        self.reaction_flush_timeout = timeout;
+        self
+    }
+}
+
 /// Manages the lifecycle orchestration for DrasiLib components
 ///
 /// This module handles:
@@ -124,6 +181,87 @@ impl LifecycleManager {
         Ok(())
     }
 
+    /// Stop sources and wait for the changes they emitted to pass through
+    /// queries and reactions.
+    ///
+    /// Leaves queries and reactions running; call
+    /// [`stop_all_components`](Self::stop_all_components) afterwards. Pending
+    /// work is read from the queue depths components report to `metrics`.
+    pub async fn drain(&self, drain: &DrainConfig, metrics: &MetricsRegistry) {
+        let sources: Vec<String> = {
+            let graph = self.graph.read().await;
+            graph
+                .list_by_kind(&ComponentKind::Source)
+                .into_iter()
+                .filter(|(_, status)| {
+                    matches!(status, ComponentStatus::Running | ComponentStatus::Starting)
+                })
+                .map(|(id, _)| id)
+                .collect()
+        };
+
+        info!("Draining: stopping {} source(s)", sources.len());
+        let stop_sources = async {
+            for id in sources {
+                if let Err(e) = self.source_manager.stop_source(id.clone()).await {
+                    warn!("Error stopping source {id} while draining: {e}");
+                }
+            }
+        };
+        if tokio::time::timeout(drain.source_stop_timeout, stop_sources)
+            .await
+            .is_err()
+        {
+            warn!(
+                "Sources did not stop within {:?}, continuing to drain",
+                drain.source_stop_timeout
+            );
+        }
+
+        info!("Draining: waiting for queries to process pending changes");
+        self.wait_until_drained("queries", drain.query_drain_timeout, |graph| {
+            pending_query_events(graph, metrics)
+        })
+        .await;
+
+        info!("Draining: waiting for reactions to process pending results");
+        self.wait_until_drained("reactions", drain.reaction_flush_timeout, |graph| {
+            pending_reaction_results(graph, metrics)
+        })
+        .await;
+    }
+
+    /// Poll `pending` until it reports no work twice in a row, or `timeout` elapses.
+    ///
+    /// A single empty sample is not enough: an event moving between a channel
+    /// and the next queue is briefly counted by neither.
+    async fn wait_until_drained(
+        &self,
+        stage: &str,
+        timeout: Duration,
+        pending: impl Fn(&ComponentGraph) -> u64,
+    ) {
+        let deadline = tokio::time::Instant::now() + timeout;
+        let mut idle_samples = 0;
+        loop {
+            let remaining = pending(&self.graph.read().await);
+            if remaining == 0 {
+                idle_samples += 1;
+                if idle_samples == 2 {
+                    info!("Draining: {stage} drained");
+                    return;
+                }
+            } else {
+                idle_samples = 0;
+            }
+            if tokio::time::Instant::now() >= deadline {
+                warn!("Draining: {stage} still have {remaining} pending item(s) after {timeout:?}, continuing");
+                return;
+            }
+            tokio::time::sleep(DRAIN_POLL_INTERVAL).await;
+        }
+    }
+
     /// Stop all running components
     ///
     /// Components are stopped in reverse dependency order using the graph's
@@ -206,6 +344,55 @@ impl LifecycleManager {
     }
 }
 
+/// Source changes waiting for, or being evaluated by, running queries.
+fn pending_query_events(graph: &ComponentGraph, metrics: &MetricsRegistry) -> u64 {
+    let mut pending = 0;
+    for (id, _) in graph.list_by_kind(&ComponentKind::Source) {
+        // Changes dispatched to stopped queries are never consumed
+        if has_running_dependent(graph, &id, &ComponentKind::Query) {
+            pending += component_lag(metrics, &ComponentKind::Source, &id).unwrap_or(0);
+        }
+    }
+    for (id, status) in graph.list_by_kind(&ComponentKind::Query) {
+        if status == ComponentStatus::Running {
+            pending += component_lag(metrics, &ComponentKind::Query, &id).unwrap_or(0);
+            pending += sample(metrics, "drasi_query_in_flight", "query", &id);
+        }
+    }
+    pending
+}
+
+/// Query results waiting for, or queued by, running reactions.
+fn pending_reaction_results(graph: &ComponentGraph, metrics: &MetricsRegistry) -> u64 {
+    let mut pending = 0;
+    for (id, status) in graph.list_by_kind(&ComponentKind::Query) {
+        if status == ComponentStatus::Running
+            && has_running_dependent(graph, &id, &ComponentKind::Reaction)
+        {
+            pending += sample(metrics, "drasi_query_dispatch_queue_depth", "query", &id);
+        }
+    }
+    for (id, status) in graph.list_by_kind(&ComponentKind::Reaction) {
+        if status == ComponentStatus::Running {
+            pending += component_lag(metrics, &ComponentKind::Reaction, &id).unwrap_or(0);
+        }
+    }
+    pending
+}
+
+fn has_running_dependent(graph: &ComponentGraph, id: &str, kind: &ComponentKind) -> bool {
+    graph
+        .get_dependents(id)
+        .iter()
+        .any(|node| &node.kind == kind && node.status == ComponentStatus::Running)
+}
+
+fn sample(metrics: &MetricsRegistry, name: &str, label: &str, id: &str) -> u64 {
+    metrics
+        .sample(name, &[(label, id)])
+        .map_or(0, |value| value as u64)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::channels::ComponentStatus;
@@ -346,4 +533,77 @@ mod tests {
         let (_, status) = queries.iter().find(|(id, _)| id == "cfg-query").unwrap();
         assert_eq!(*status, ComponentStatus::Added);
     }
+
+    // ========================================================================
+    // drain
+    // ========================================================================
+
+    #[tokio::test]
+    async fn stop_with_drain_processes_pending_changes() {
+        use crate::builder::Query;
+        use crate::lifecycle::DrainConfig;
+        use drasi_core::models::{
+            Element, ElementMetadata, ElementPropertyMap, ElementReference, SourceChange,
+        };
+        use std::sync::Arc;
+
+        let source = TestMockSource::with_auto_start("drain-src".to_string(), true).unwrap();
+        let core = DrasiLib::builder()
+            .with_id("drain-test")
+            .with_source(source)
+            .with_query(
+                Query::cypher("drain-query")
+                    .query("MATCH (n:Person) RETURN n")
+                    .from_source("drain-src")
+                    .build(),
+            )
+            .with_drain_on_stop(DrainConfig::default())
+            .build()
+            .await
+            .unwrap();
+
+        let mut event_rx = core.component_graph.read().await.subscribe();
+        core.start().await.unwrap();
+        wait_for_component_status(
+            &mut event_rx,
+            "drain-query",
+            ComponentStatus::Running,
+            Duration::from_secs(5),
+        )
+        .await;
+
+        let source = core
+            .source_manager
+            .get_source_instance("drain-src")
+            .await
+            .unwrap();
+        let source = source.as_any().downcast_ref::<TestMockSource>().unwrap();
+        for i in 0..100 {
+            let change = SourceChange::Insert {
+                element: Element::Node {
+                    metadata: ElementMetadata {
+                        reference: ElementReference::new("drain-src", &format!("p{i}")),
+                        labels: Arc::from(vec![Arc::from("Person")]),
+                        effective_from: i,
+                    },
+                    properties: ElementPropertyMap::new(),
+                },
+            };
+            source.inject_event(change).await.unwrap();
+        }
+
+        core.stop().await.unwrap();
+
+        assert_eq!(
+            core.metrics().sample(
+                "drasi_query_events_total",
+                &[("query", "drain-query"), ("source", "drain-src")]
+            ),
+            Some(100.0)
+        );
+        assert_eq!(
+            core.get_query_status("drain-query").await.unwrap(),
+            ComponentStatus::Stopped
+        );
+    }
 }
@@ -815,4 +815,69 @@ mod tests {
         assert_eq!(events2[0].component_id, "sub-src");
         assert_eq!(events1[0].status, events2[0].status);
     }
+
+    #[tokio::test]
+    async fn test_runtime_add_remove_readd_on_running_core() {
+        let core = create_test_core().await;
+        core.start().await.unwrap();
+        let mut event_rx = core.subscribe_all_component_events();
+
+        for round in 0..2 {
+            // Components added to a running core auto-start in dependency order
+            core.add_source(create_test_mock_source("rt-src".to_string()))
+                .await
+                .unwrap();
+            collect_events_until(
+                &mut event_rx,
+                "rt-src",
+                |evts| evts.iter().any(|e| e.status == ComponentStatus::Running),
+                EVENT_TIMEOUT,
+            )
+            .await;
+
+            core.add_query(
+                Query::cypher("rt-query")
+                    .query("MATCH (n) RETURN n")
+                    .from_source("rt-src")
+                    .build(),
+            )
+            .await
+            .unwrap();
+            collect_events_until(
+                &mut event_rx,
+                "rt-query",
+                |evts| evts.iter().any(|e| e.status == ComponentStatus::Running),
+                EVENT_TIMEOUT,
+            )
+            .await;
+
+            core.add_reaction(create_test_mock_reaction(
+                "rt-rxn".to_string(),
+                vec!["rt-query".to_string()],
+            ))
+            .await
+            .unwrap();
+            collect_events_until(
+                &mut event_rx,
+                "rt-rxn",
+                |evts| evts.iter().any(|e| e.status == ComponentStatus::Running),
+                EVENT_TIMEOUT,
+            )
+            .await;
+
+            // Tear down in reverse dependency order; ids become free for the next round
+            core.remove_reaction("rt-rxn", false).await.unwrap();
+            core.remove_query("rt-query").await.unwrap();
+            core.remove_source("rt-src", false).await.unwrap();
+
+            assert!(
+                core.get_reaction_status("rt-rxn").await.is_err(),
+                "reaction should be gone after round {round}"
+            );
+            assert!(core.get_query_status("rt-query").await.is_err());
+            assert!(core.get_source_status("rt-src").await.is_err());
+        }
+
+        core.stop().await.unwrap();
+    }
 }
@@ -0,0 +1,982 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Embedded HTTP management API.
+//!
+//! Enabled with the `management-api` feature. [`ManagementServer`] serves a
+//! JSON API over a running [`DrasiLib`] for listing components and their
+//! status, starting and stopping them, reading current query results and
+//! adding or removing components at runtime:
+//!
+//! | Method | Path | Description |
+//! |--------|------|-------------|
+//! | `GET` | `/health` | Liveness check |
+//! | `GET` | `/healthz` | [Health report](crate::health); `503` once the instance is shut down |
+//! | `GET` | `/readyz` | [Health report](crate::health); `503` unless the instance is healthy |
+//! | `GET` | `/metrics` | [Metrics](crate::metrics) in the Prometheus text format |
+//! | `GET` | `/api/v1/graph` | Component dependency graph |
+//! | `GET` | `/api/v1/events` | Component [event log](crate::EventLogFilter), filtered by query parameters |
+//! | `GET` `POST` | `/api/v1/sources` | List sources / add one from a [`SourceSpec`] |
+//! | `GET` `DELETE` | `/api/v1/sources/{id}` | Source details / remove (`?cleanup=true`) |
+//! | `POST` | `/api/v1/sources/{id}/start`, `/stop` | Start or stop a source |
+//! | `GET` `POST` | `/api/v1/queries` | List queries / add one from a [`QueryConfig`] |
+//! | `GET` `DELETE` | `/api/v1/queries/{id}` | Query details / remove |
+//! | `POST` | `/api/v1/queries/{id}/start`, `/stop` | Start or stop a query |
+//! | `GET` | `/api/v1/queries/{id}/results` | Current query result set |
+//! | `GET` `POST` | `/api/v1/reactions` | List reactions / add one from a [`ReactionSpec`] |
+//! | `GET` `DELETE` | `/api/v1/reactions/{id}` | Reaction details / remove (`?cleanup=true`) |
+//! | `POST` | `/api/v1/reactions/{id}/start`, `/stop` | Start or stop a reaction |
+//!
+//! Sources and reactions are created through the [`ComponentRegistry`](crate::ComponentRegistry)
+//! given to the builder, using the same entry format as configuration files:
+//!
+//! ```json
+//! { "id": "alerts", "kind": "log", "queries": ["hot-sensors"], "autoStart": true }
+//! ```
+//!
+//! Errors are returned as `{"error": "..."}` with a status code derived from
+//! the [`DrasiError`] variant.
+//!
+//! # Authentication
+//!
+//! Without an [`Authorizer`] the API is open to anyone who can reach it. Set
+//! one with [`ManagementServer::with_bearer_token`] or
+//! [`ManagementServer::with_authorizer`] and every request except the
+//! `/health`, `/healthz` and `/readyz` probes must pass it, or gets `401`.
+//! When any CORS origin is allowed (`"*"`) and no authorizer is set, every
+//! page in a browser could drive the API, so only `GET`, `HEAD` and `OPTIONS`
+//! requests are served and mutating requests get `403`.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use drasi_lib::management::ManagementServer;
+//!
+//! let handle = ManagementServer::new(core.clone())
+//!     .with_bind_address("127.0.0.1:8090".parse()?)
+//!     .with_allowed_origins(vec!["https://dashboard.example.com".to_string()])
+//!     .with_bearer_token(std::env::var("DRASI_MANAGEMENT_TOKEN")?)
+//!     .start()
+//!     .await?;
+//! // ...
+//! handle.shutdown().await;
+//! ```
+
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+
+use axum::extract::{Path, Query, Request, State};
+use axum::http::{header, HeaderMap, HeaderValue, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+use tower_http::cors::{AllowOrigin, Any, CorsLayer};
+
+use crate::channels::ComponentStatus;
+use crate::config::{QueryConfig, ReactionSpec, SourceSpec};
+use crate::error::DrasiError;
+use crate::lib_core::DrasiLib;
+use crate::managers::EventLogFilter;
+use crate::metrics::PROMETHEUS_CONTENT_TYPE;
+
+/// Default address the management server binds to.
+pub const DEFAULT_BIND_ADDRESS: SocketAddr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 8090);
+
+/// Decides whether a management API request may proceed.
+///
+/// Implemented for closures, so a server can be given
+/// `|headers: &HeaderMap| -> bool { .. }` directly, for example to check an
+/// API key header or a signed token.
+pub trait Authorizer: Send + Sync {
+    /// Whether a request with these headers is allowed.
+    fn authorize(&self, headers: &HeaderMap) -> bool;
+}
+
+impl<F> Authorizer for F
+where
+    F: Fn(&HeaderMap) -> bool + Send + Sync,
+{
+    fn authorize(&self, headers: &HeaderMap) -> bool {
+        self(headers)
+    }
+}
+
+/// Allows requests carrying `Authorization: Bearer <token>`.
+pub struct BearerToken(String);
+
+impl BearerToken {
+    pub fn new(token: impl Into<String>) -> Self {
+        Self(token.into())
+    }
+}
+
+impl Authorizer for BearerToken {
+    fn authorize(&self, headers: &HeaderMap) -> bool {
+        let Some(presented) = headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+        else {
+            return false;
+        };
+        // Compare without an early exit, so timing does not reveal the token
+        presented.len() == self.0.len()
+            && presented
+                .bytes()
+                .zip(self.0.bytes())
+                .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+                == 0
+    }
+}
+
+/// Builder and entry point for the management HTTP server.
+pub struct ManagementServer {
+    core: DrasiLib,
+    bind_address: SocketAddr,
+    allowed_origins: Vec<String>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+}
+
+impl ManagementServer {
+    /// Create a server for `core`, bound to [`DEFAULT_BIND_ADDRESS`] without
+    /// CORS or authentication.
+    pub fn new(core: DrasiLib) -> Self {
+        Self {
+            core,
+            bind_address: DEFAULT_BIND_ADDRESS,
+            allowed_origins: Vec::new(),
+            authorizer: None,
+        }
+    }
+
+    /// Set the address to listen on. Use port 0 to pick a free port.
+    pub fn with_bind_address(mut self, address: SocketAddr) -> Self {
+        self.bind_address = address;
+        self
+    }
+
+    /// Allow cross-origin requests from these origins (`"*"` allows any origin).
+    ///
+    /// With `"*"` and no authorizer, mutating requests are rejected.
+    pub fn with_allowed_origins(mut self, origins: Vec<String>) -> Self {
+        self.allowed_origins = origins;
+        self
+    }
+
+    /// Require `Authorization: Bearer <token>` on every request except the probes.
+    pub fn with_bearer_token(self, token: impl Into<String>) -> Self {
+        self.with_authorizer(BearerToken::new(token))
+    }
+
+    /// Require every request except the probes to pass `authorizer`.
+    pub fn with_authorizer(mut self, authorizer: impl Authorizer + 'static) -> Self {
+        self.authorizer = Some(Arc::new(authorizer));
+        self
+    }
+
+    /// Build the API router without binding a listener.
+    pub fn router(&self) -> anyhow::Result<Router> {
+        let any_origin = self.allowed_origins.iter().any(|o| o == "*");
+        let policy = AccessPolicy {
+            authorizer: self.authorizer.clone(),
+            read_only: any_origin && self.authorizer.is_none(),
+        };
+        if policy.read_only {
+            log::warn!(
+                "Management API allows any CORS origin without authentication; mutating requests are disabled"
+            );
+        }
+        let router = api_router(self.core.clone())
+            .layer(middleware::from_fn_with_state(policy, enforce_access));
+        if self.allowed_origins.is_empty() {
+            return Ok(router);
+        }
+
+        let allow_origin = if any_origin {
+            AllowOrigin::from(Any)
+        } else {
+            let origins = self
+                .allowed_origins
+                .iter()
+                .map(|o| {
+                    HeaderValue::from_str(o)
+                        .map_err(|e| anyhow::anyhow!("Invalid CORS origin '{o}': {e}"))
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            AllowOrigin::list(origins)
+        };
+        Ok(router.layer(
+            CorsLayer::new()
+                .allow_origin(allow_origin)
+                .allow_methods(Any)
+                .allow_headers(Any),
+        ))
+    }
+
+    /// Bind the listener and serve the API on a background task.
+    pub async fn start(self) -> anyhow::Result<ManagementServerHandle> {
+        let router = self.router()?;
+        let listener = tokio::net::TcpListener::bind(self.bind_address)
+            .await
+            .map_err(|e| {
+                anyhow::anyhow!(
+                    "Failed to bind management API to {}: {e}",
+                    self.bind_address
+                )
+            })?;
+        let local_addr = listener.local_addr()?;
+        let (shutdown_tx, shutdown_rx) = oneshot::channel::<()>();
+
+        log::info!("Management API listening on http://{local_addr}");
+        let task = tokio::spawn(async move {
+            let result = axum::serve(listener, router)
+                .with_graceful_shutdown(async {
+                    let _ = shutdown_rx.await;
+                })
+                .await;
+            if let Err(e) = result {
+                log::error!("Management API server error: {e}");
+            }
+        });
+
+        Ok(ManagementServerHandle {
+            local_addr,
+            shutdown_tx: Some(shutdown_tx),
+            task,
+        })
+    }
+}
+
+/// Handle to a running management server.
+pub struct ManagementServerHandle {
+    local_addr: SocketAddr,
+    shutdown_tx: Option<oneshot::Sender<()>>,
+    task: JoinHandle<()>,
+}
+
+impl ManagementServerHandle {
+    /// Address the server is listening on.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Stop accepting connections and wait for in-flight requests to finish.
+    pub async fn shutdown(mut self) {
+        if let Some(tx) = self.shutdown_tx.take() {
+            let _ = tx.send(());
+        }
+        let _ = self.task.await;
+    }
+}
+
+/// Build the management API router for `core`.
+///
+/// The router has no authentication or CORS handling; use
+/// [`ManagementServer::router`] for those, or add them when mounting it.
+pub fn api_router(core: DrasiLib) -> Router {
+    Router::new()
+        .route("/health", get(health))
+        .route("/healthz", get(healthz))
+        .route("/readyz", get(readyz))
+        .route("/metrics", get(metrics))
+        .route("/api/v1/graph", get(graph))
+        .route("/api/v1/events", get(events))
+        .route("/api/v1/sources", get(list_sources).post(add_source))
+        .route("/api/v1/sources/:id", get(get_source).delete(remove_source))
+        .route("/api/v1/sources/:id/start", post(start_source))
+        .route("/api/v1/sources/:id/stop", post(stop_source))
+        .route("/api/v1/queries", get(list_queries).post(add_query))
+        .route("/api/v1/queries/:id", get(get_query).delete(remove_query))
+        .route("/api/v1/queries/:id/start", post(start_query))
+        .route("/api/v1/queries/:id/stop", post(stop_query))
+        .route("/api/v1/queries/:id/results", get(query_results))
+        .route("/api/v1/reactions", get(list_reactions).post(add_reaction))
+        .route(
+            "/api/v1/reactions/:id",
+            get(get_reaction).delete(remove_reaction),
+        )
+        .route("/api/v1/reactions/:id/start", post(start_reaction))
+        .route("/api/v1/reactions/:id/stop", post(stop_reaction))
+        .with_state(core)
+}
+
+/// Authentication applied by [`ManagementServer::router`].
+#[derive(Clone)]
+struct AccessPolicy {
+    authorizer: Option<Arc<dyn Authorizer>>,
+    /// Reject requests that are not `GET`, `HEAD` or `OPTIONS`
+    read_only: bool,
+}
+
+/// Probes stay open so orchestrators can reach them without credentials
+const UNAUTHENTICATED_PATHS: [&str; 3] = ["/health", "/healthz", "/readyz"];
+
+async fn enforce_access(
+    State(policy): State<AccessPolicy>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if UNAUTHENTICATED_PATHS.contains(&request.uri().path()) {
+        return next.run(request).await;
+    }
+
+    if let Some(authorizer) = &policy.authorizer {
+        if !authorizer.authorize(request.headers()) {
+            return (
+                StatusCode::UNAUTHORIZED,
+                [(header::WWW_AUTHENTICATE, "Bearer")],
+                Json(json!({ "error": "Missing or invalid credentials" })),
+            )
+                .into_response();
+        }
+    } else if policy.read_only && !request.method().is_safe() {
+        return (
+            StatusCode::FORBIDDEN,
+            Json(json!({
+                "error": "Mutating requests are disabled while any CORS origin is allowed without authentication"
+            })),
+        )
+            .into_response();
+    }
+
+    next.run(request).await
+}
+
+/// Component id and status, as returned by the list endpoints.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComponentSummary {
+    pub id: String,
+    pub status: ComponentStatus,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct RemoveParams {
+    #[serde(default)]
+    cleanup: bool,
+}
+
+/// A [`DrasiError`] rendered as a JSON error response.
+struct ApiError(DrasiError);
+
+impl From<DrasiError> for ApiError {
+    fn from(error: DrasiError) -> Self {
+        Self(error)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = match &self.0 {
+            DrasiError::ComponentNotFound { .. } => StatusCode::NOT_FOUND,
+            DrasiError::AlreadyExists { .. } => StatusCode::CONFLICT,
+            DrasiError::InvalidConfig { .. } | DrasiError::Validation { .. } => {
+                StatusCode::BAD_REQUEST
+            }
+            DrasiError::InvalidState { .. } | DrasiError::OperationFailed { .. } => {
+                StatusCode::CONFLICT
+            }
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        (status, Json(json!({ "error": self.0.to_string() }))).into_response()
+    }
+}
+
+type ApiResult<T> = std::result::Result<T, ApiError>;
+
+fn summaries(components: Vec<(String, ComponentStatus)>) -> Json<Vec<ComponentSummary>> {
+    Json(
+        components
+            .into_iter()
+            .map(|(id, status)| ComponentSummary { id, status })
+            .collect(),
+    )
+}
+
+async fn health() -> Json<serde_json::Value> {
+    Json(json!({ "status": "ok" }))
+}
+
+async fn healthz(State(core): State<DrasiLib>) -> impl IntoResponse {
+    let report = core.health().await;
+    (probe_status(report.live), Json(report))
+}
+
+async fn readyz(State(core): State<DrasiLib>) -> impl IntoResponse {
+    let report = core.health().await;
+    (probe_status(report.ready), Json(report))
+}
+
+fn probe_status(passed: bool) -> StatusCode {
+    if passed {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    }
+}
+
+async fn metrics(State(core): State<DrasiLib>) -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, PROMETHEUS_CONTENT_TYPE)],
+        core.metrics().render(),
+    )
+}
+
+async fn graph(State(core): State<DrasiLib>) -> impl IntoResponse {
+    Json(core.get_graph().await)
+}
+
+async fn events(
+    State(core): State<DrasiLib>,
+    Query(filter): Query<EventLogFilter>,
+) -> impl IntoResponse {
+    Json(core.get_event_log(&filter).await)
+}
+
+async fn list_sources(State(core): State<DrasiLib>) -> ApiResult<impl IntoResponse> {
+    Ok(summaries(core.list_sources().await?))
+}
+
+async fn get_source(
+    State(core): State<DrasiLib>,
+    Path(id): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    Ok(Json(core.get_source_info(&id).await?))
+}
+
+async fn add_source(
+    State(core): State<DrasiLib>,
+    Json(spec): Json<SourceSpec>,
+) -> ApiResult<impl IntoResponse> {
+    let config = serde_json::Value::Object(spec.properties);
+    core.add_source_from_config(&spec.kind, &spec.id, &config, spec.auto_start)
+        .await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(core.get_source_info(&spec.id).await?),
+    ))
+}
+
+async fn remove_source(
+    State(core): State<DrasiLib>,
+    Path(id): Path<String>,
+    Query(params): Query<RemoveParams>,
+) -> ApiResult<StatusCode> {
+    core.remove_source(&id, params.cleanup).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn start_source(
+    State(core): State<DrasiLib>,
+    Path(id): Path<String>,
+) -> ApiResult<StatusCode> {
+    core.start_source(&id).await?;
+    Ok(StatusCode::ACCEPTED)
+}
+
+async fn stop_source(
+    State(core): State<DrasiLib>,
+    Path(id): Path<String>,
+) -> ApiResult<StatusCode> {
+    core.stop_source(&id).await?;
+    Ok(StatusCode::ACCEPTED)
+}
+
+async fn list_queries(State(core): State<DrasiLib>) -> ApiResult<impl IntoResponse> {
+    Ok(summaries(core.list_queries().await?))
+}
+
+async fn get_query(
+    State(core): State<DrasiLib>,
+    Path(id): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    Ok(Json(core.get_query_info(&id).await?))
+}
+
+async fn add_query(
+    State(core): State<DrasiLib>,
+    Json(config): Json<QueryConfig>,
+) -> ApiResult<impl IntoResponse> {
+    let id = config.id.clone();
+    core.add_query(config).await?;
+    Ok((StatusCode::CREATED, Json(core.get_query_info(&id).await?)))
+}
+
+async fn remove_query(
+    State(core): State<DrasiLib>,
+    Path(id): Path<String>,
+) -> ApiResult<StatusCode> {
+    core.remove_query(&id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn start_query(
+    State(core): State<DrasiLib>,
+    Path(id): Path<String>,
+) -> ApiResult<StatusCode> {
+    core.start_query(&id).await?;
+    Ok(StatusCode::ACCEPTED)
+}
+
+async fn stop_query(State(core): State<DrasiLib>, Path(id): Path<String>) -> ApiResult<StatusCode> {
+    core.stop_query(&id).await?;
+    Ok(StatusCode::ACCEPTED)
+}
+
+async fn query_results(
+    State(core): State<DrasiLib>,
+    Path(id): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    Ok(Json(core.get_query_results(&id).await?))
+}
+
+async fn list_reactions(State(core): State<DrasiLib>) -> ApiResult<impl IntoResponse> {
+    Ok(summaries(core.list_reactions().await?))
+}
+
+async fn get_reaction(
+    State(core): State<DrasiLib>,
+    Path(id): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    Ok(Json(core.get_reaction_info(&id).await?))
+}
+
+async fn add_reaction(
+    State(core): State<DrasiLib>,
+    Json(spec): Json<ReactionSpec>,
+) -> ApiResult<impl IntoResponse> {
+    let config = serde_json::Value::Object(spec.properties);
+    core.add_reaction_from_config(&spec.kind, &spec.id, spec.queries, &config, spec.auto_start)
+        .await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(core.get_reaction_info(&spec.id).await?),
+    ))
+}
+
+async fn remove_reaction(
+    State(core): State<DrasiLib>,
+    Path(id): Path<String>,
+    Query(params): Query<RemoveParams>,
+) -> ApiResult<StatusCode> {
+    core.remove_reaction(&id, params.cleanup).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn start_reaction(
+    State(core): State<DrasiLib>,
+    Path(id): Path<String>,
+) -> ApiResult<StatusCode> {
+    core.start_reaction(&id).await?;
+    Ok(StatusCode::ACCEPTED)
+}
+
+async fn stop_reaction(
+    State(core): State<DrasiLib>,
+    Path(id): Path<String>,
+) -> ApiResult<StatusCode> {
+    core.stop_reaction(&id).await?;
+    Ok(StatusCode::ACCEPTED)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reactions::tests::manager_tests::TestMockReaction;
+    use crate::reactions::Reaction;
+    use crate::registry::ComponentRegistry;
+    use crate::sources::tests::create_test_mock_source;
+    use crate::sources::Source;
+    use axum::body::Body;
+    use axum::http::{Method, Request};
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    async fn test_core() -> DrasiLib {
+        let mut registry = ComponentRegistry::new();
+        registry
+            .register_source(
+                "mock",
+                |id: &str, _: &serde_json::Value, _: bool| -> anyhow::Result<Box<dyn Source>> {
+                    Ok(Box::new(create_test_mock_source(id.to_string())))
+                },
+            )
+            .register_reaction(
+                "mock",
+                |id: &str,
+                 queries: Vec<String>,
+                 _: &serde_json::Value,
+                 _: bool|
+                 -> anyhow::Result<Box<dyn Reaction>> {
+                    Ok(Box::new(TestMockReaction::new(id.to_string(), queries)))
+                },
+            );
+        DrasiLib::builder()
+            .with_id("management-test")
+            .with_component_registry(Arc::new(registry))
+            .build()
+            .await
+            .unwrap()
+    }
+
+    async fn call(
+        router: &Router,
+        method: Method,
+        uri: &str,
+        body: Option<serde_json::Value>,
+    ) -> (StatusCode, serde_json::Value) {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(match body {
+                Some(body) => Body::from(body.to_string()),
+                None => Body::empty(),
+            })
+            .unwrap();
+        let response = router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let value = if bytes.is_empty() {
+            serde_json::Value::Null
+        } else {
+            serde_json::from_slice(&bytes).unwrap()
+        };
+        (status, value)
+    }
+
+    #[tokio::test]
+    async fn test_health_and_empty_lists() {
+        let router = api_router(test_core().await);
+
+        let (status, body) = call(&router, Method::GET, "/health", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["status"], "ok");
+
+        let (status, body) = call(&router, Method::GET, "/api/v1/reactions", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, json!([]));
+    }
+
+    #[tokio::test]
+    async fn test_liveness_and_readiness_probes() {
+        let core = test_core().await;
+        let router = api_router(core.clone());
+
+        let (status, body) = call(&router, Method::GET, "/healthz", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["live"], true);
+        let (status, body) = call(&router, Method::GET, "/readyz", None).await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["status"], "degraded");
+
+        core.start().await.unwrap();
+        let (status, body) = call(&router, Method::GET, "/readyz", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["status"], "healthy");
+
+        core.shutdown().await.unwrap();
+        let (status, body) = call(&router, Method::GET, "/healthz", None).await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["live"], false);
+    }
+
+    #[tokio::test]
+    async fn test_event_log_endpoint() {
+        let router = api_router(test_core().await);
+
+        let (status, _) = call(
+            &router,
+            Method::POST,
+            "/api/v1/sources",
+            Some(json!({"id": "s1", "kind": "mock", "autoStart": false})),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        let (status, _) = call(&router, Method::DELETE, "/api/v1/sources/s1", None).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        let (status, body) =
+            call(&router, Method::GET, "/api/v1/events?component_id=s1", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let statuses: Vec<_> = body
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|entry| entry["status"].as_str().unwrap())
+            .collect();
+        assert_eq!(statuses.first(), Some(&"Added"));
+        assert_eq!(statuses.last(), Some(&"Removed"));
+
+        let (status, body) = call(
+            &router,
+            Method::GET,
+            "/api/v1/events?status=Removed&limit=1",
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body.as_array().unwrap().len(), 1);
+        assert_eq!(body[0]["component_id"], "s1");
+    }
+
+    #[tokio::test]
+    async fn test_metrics_endpoint() {
+        let core = test_core().await;
+        core.metrics()
+            .counter("test_requests_total", "Requests", &[])
+            .inc();
+        let router = api_router(core);
+
+        let request = Request::builder()
+            .uri("/metrics")
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            PROMETHEUS_CONTENT_TYPE
+        );
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let text = String::from_utf8(bytes.to_vec()).unwrap();
+        assert!(text.contains("# TYPE test_requests_total counter"));
+        assert!(text.contains("test_requests_total 1"));
+    }
+
+    #[tokio::test]
+    async fn test_hot_add_and_remove_components() {
+        let router = api_router(test_core().await);
+
+        let (status, _) = call(
+            &router,
+            Method::POST,
+            "/api/v1/sources",
+            Some(json!({"id": "s1", "kind": "mock", "autoStart": false})),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        let (status, body) = call(
+            &router,
+            Method::POST,
+            "/api/v1/queries",
+            Some(json!({
+                "id": "q1",
+                "query": "MATCH (n) RETURN n",
+                "sources": [{"source_id": "s1"}],
+                "auto_start": false
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED, "{body}");
+
+        let (status, _) = call(
+            &router,
+            Method::POST,
+            "/api/v1/reactions",
+            Some(json!({"id": "r1", "kind": "mock", "queries": ["q1"]})),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        let (status, body) = call(&router, Method::GET, "/api/v1/reactions", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body[0]["id"], "r1");
+
+        // Query still has a dependent reaction
+        let (status, _) = call(&router, Method::DELETE, "/api/v1/queries/q1", None).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+
+        let (status, _) = call(&router, Method::DELETE, "/api/v1/reactions/r1", None).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        let (status, _) = call(&router, Method::DELETE, "/api/v1/queries/q1", None).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        let (status, _) = call(&router, Method::GET, "/api/v1/queries/q1", None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_error_status_codes() {
+        let router = api_router(test_core().await);
+
+        let (status, body) = call(
+            &router,
+            Method::POST,
+            "/api/v1/sources",
+            Some(json!({"id": "s1", "kind": "postgres"})),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body["error"]
+            .as_str()
+            .unwrap()
+            .contains("Unknown source kind"));
+
+        let (status, _) = call(&router, Method::GET, "/api/v1/sources/missing", None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_server_start_and_shutdown() {
+        let handle = ManagementServer::new(test_core().await)
+            .with_bind_address(SocketAddr::from(([127, 0, 0, 1], 0)))
+            .with_allowed_origins(vec!["http://localhost:3000".to_string()])
+            .start()
+            .await
+            .unwrap();
+
+        let mut stream = tokio::net::TcpStream::connect(handle.local_addr())
+            .await
+            .unwrap();
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        stream
+            .write_all(b"GET /health HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+
+        handle.shutdown().await;
+    }
+
+    async fn call_with_token(
+        router: &Router,
+        method: Method,
+        uri: &str,
+        token: Option<&str>,
+    ) -> StatusCode {
+        let mut request = Request::builder().method(method).uri(uri);
+        if let Some(token) = token {
+            request = request.header(header::AUTHORIZATION, format!("Bearer {token}"));
+        }
+        let request = request.body(Body::empty()).unwrap();
+        router.clone().oneshot(request).await.unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn test_bearer_token_is_required_except_for_probes() {
+        let router = ManagementServer::new(test_core().await)
+            .with_bearer_token("s3cret")
+            .router()
+            .unwrap();
+
+        for uri in ["/api/v1/sources", "/metrics"] {
+            assert_eq!(
+                call_with_token(&router, Method::GET, uri, None).await,
+                StatusCode::UNAUTHORIZED
+            );
+            assert_eq!(
+                call_with_token(&router, Method::GET, uri, Some("wrong")).await,
+                StatusCode::UNAUTHORIZED
+            );
+            assert_eq!(
+                call_with_token(&router, Method::GET, uri, Some("s3cret")).await,
+                StatusCode::OK
+            );
+        }
+        assert_eq!(
+            call_with_token(&router, Method::GET, "/health", None).await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            call_with_token(&router, Method::GET, "/healthz", None).await,
+            StatusCode::OK
+        );
+    }
+
+    #[tokio::test]
+    async fn test_custom_authorizer() {
+        let router = ManagementServer::new(test_core().await)
+            .with_authorizer(|headers: &HeaderMap| {
+                headers.get("x-api-key").is_some_and(|key| key == "k1")
+            })
+            .router()
+            .unwrap();
+
+        let request = Request::builder()
+            .uri("/api/v1/queries")
+            .header("x-api-key", "k1")
+            .body(Body::empty())
+            .unwrap();
+        let response = router.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            call_with_token(&router, Method::GET, "/api/v1/queries", Some("k1")).await,
+            StatusCode::UNAUTHORIZED
+        );
+    }
+
+    #[tokio::test]
+    async fn test_any_origin_without_auth_rejects_mutating_requests() {
+        let core = test_core().await;
+        let router = ManagementServer::new(core.clone())
+            .with_allowed_origins(vec!["*".to_string()])
+            .router()
+            .unwrap();
+
+        let (status, body) = call(
+            &router,
+            Method::POST,
+            "/api/v1/sources",
+            Some(json!({"id": "s1", "kind": "mock", "autoStart": false})),
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert!(body["error"].as_str().unwrap().contains("CORS"));
+        let (status, _) = call(&router, Method::DELETE, "/api/v1/sources/s1", None).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        let (status, _) = call(&router, Method::GET, "/api/v1/sources", None).await;
+        assert_eq!(status, StatusCode::OK);
+
+        // With a token, any origin may mutate once authenticated
+        let router = ManagementServer::new(core)
+            .with_allowed_origins(vec!["*".to_string()])
+            .with_bearer_token("s3cret")
+            .router()
+            .unwrap();
+        assert_eq!(
+            call_with_token(&router, Method::POST, "/api/v1/sources/s1/start", None).await,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            call_with_token(
+                &router,
+                Method::POST,
+                "/api/v1/sources/s1/start",
+                Some("s3cret")
+            )
+            .await,
+            StatusCode::NOT_FOUND
+        );
+    }
+
+    #[tokio::test]
+    async fn test_invalid_cors_origin() {
+        let result = ManagementServer::new(test_core().await)
+            .with_allowed_origins(vec!["bad\norigin".to_string()])
+            .router();
+        assert!(result.is_err());
+    }
+}
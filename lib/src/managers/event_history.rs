@@ -18,12 +18,19 @@
 //! allowing managers to track and query the history of status changes for
 //! sources, queries, and reactions. It also supports live streaming of events
 //! via broadcast channels.
+//!
+//! In addition to the per-component histories, every event is appended to an
+//! instance-wide event log. Entries carry a sequence number and outlive the
+//! component they describe, so the log can be used as an audit trail of
+//! lifecycle transitions, and followed without gaps with
+//! [`ComponentEventHistory::subscribe_log`].
 
 use std::collections::{HashMap, VecDeque};
 
+use serde::{Deserialize, Serialize};
 use tokio::sync::broadcast;
 
-use crate::channels::{ComponentEvent, ComponentStatus};
+use crate::channels::{ComponentEvent, ComponentStatus, ComponentType};
 
 /// Default maximum number of events to retain per component.
 pub const DEFAULT_MAX_EVENTS_PER_COMPONENT: usize = 100;
@@ -31,6 +38,49 @@ pub const DEFAULT_MAX_EVENTS_PER_COMPONENT: usize = 100;
 /// Default broadcast channel capacity for live event streaming.
 pub const DEFAULT_EVENT_CHANNEL_CAPACITY: usize = 256;
 
+/// Default maximum number of events retained in the instance-wide event log.
+pub const DEFAULT_MAX_EVENT_LOG_SIZE: usize = 1000;
+
+/// A component event in the instance-wide event log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventLogEntry {
+    /// Position in the log, starting at 1 and increasing by one per event.
+    pub sequence: u64,
+    #[serde(flatten)]
+    pub event: ComponentEvent,
+}
+
+/// Selects entries of the instance-wide event log. All criteria are optional.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EventLogFilter {
+    /// Only events of this component.
+    pub component_id: Option<String>,
+    /// Only events of this kind of component.
+    pub component_type: Option<ComponentType>,
+    /// Only events reporting this status.
+    pub status: Option<ComponentStatus>,
+    /// Only entries with a sequence number greater than this one.
+    pub after: Option<u64>,
+    /// At most this many entries, keeping the most recent.
+    pub limit: Option<usize>,
+}
+
+impl EventLogFilter {
+    /// Whether `entry` satisfies every criterion except `limit`.
+    pub fn matches(&self, entry: &EventLogEntry) -> bool {
+        self.component_id
+            .as_ref()
+            .is_none_or(|id| *id == entry.event.component_id)
+            && self
+                .component_type
+                .as_ref()
+                .is_none_or(|t| *t == entry.event.component_type)
+            && self.status.is_none_or(|s| s == entry.event.status)
+            && self.after.is_none_or(|after| entry.sequence > after)
+    }
+}
+
 /// Per-component event storage and broadcast channel.
 struct ComponentEventChannel {
     /// Recent event history
@@ -92,6 +142,14 @@ pub struct ComponentEventHistory {
     max_events_per_component: usize,
     /// Broadcast channel capacity
     channel_capacity: usize,
+    /// Instance-wide event log, kept when components are removed
+    log: VecDeque<EventLogEntry>,
+    /// Maximum entries retained in the event log
+    max_log_size: usize,
+    /// Sequence number of the most recent log entry
+    last_sequence: u64,
+    /// Broadcast sender for live log entries
+    log_sender: broadcast::Sender<EventLogEntry>,
 }
 
 impl std::fmt::Debug for ComponentEventHistory {
@@ -100,6 +158,8 @@ impl std::fmt::Debug for ComponentEventHistory {
             .field("max_events_per_component", &self.max_events_per_component)
             .field("channel_capacity", &self.channel_capacity)
             .field("component_count", &self.channels.len())
+            .field("max_log_size", &self.max_log_size)
+            .field("last_sequence", &self.last_sequence)
             .finish()
     }
 }
@@ -113,22 +173,32 @@ impl Default for ComponentEventHistory {
 impl ComponentEventHistory {
     /// Create a new event history with default capacity (100 events per component).
     pub fn new() -> Self {
-        Self {
-            channels: HashMap::new(),
-            max_events_per_component: DEFAULT_MAX_EVENTS_PER_COMPONENT,
-            channel_capacity: DEFAULT_EVENT_CHANNEL_CAPACITY,
-        }
+        Self::with_capacity(
+            DEFAULT_MAX_EVENTS_PER_COMPONENT,
+            DEFAULT_EVENT_CHANNEL_CAPACITY,
+        )
     }
 
     /// Create a new event history with custom capacity per component.
     pub fn with_capacity(max_events_per_component: usize, channel_capacity: usize) -> Self {
+        let (log_sender, _) = broadcast::channel(channel_capacity);
         Self {
             channels: HashMap::new(),
             max_events_per_component,
             channel_capacity,
+            log: VecDeque::new(),
+            max_log_size: DEFAULT_MAX_EVENT_LOG_SIZE,
+            last_sequence: 0,
+            log_sender,
         }
     }
 
+    /// Set the maximum number of entries retained in the instance-wide event log.
+    pub fn with_max_log_size(mut self, max_log_size: usize) -> Self {
+        self.max_log_size = max_log_size;
+        self
+    }
+
     /// Record a component event in the history and broadcast to subscribers.
     ///
     /// If the component has reached its maximum event count, the oldest
@@ -138,7 +208,20 @@ impl ComponentEventHistory {
         let channel = self.channels.entry(component_id).or_insert_with(|| {
             ComponentEventChannel::new(self.max_events_per_component, self.channel_capacity)
         });
-        channel.record(event);
+        channel.record(event.clone());
+
+        self.last_sequence += 1;
+        let entry = EventLogEntry {
+            sequence: self.last_sequence,
+            event,
+        };
+        if self.log.len() >= self.max_log_size {
+            self.log.pop_front();
+        }
+        if self.max_log_size > 0 {
+            self.log.push_back(entry.clone());
+        }
+        let _ = self.log_sender.send(entry);
     }
 
     /// Get all events for a specific component.
@@ -209,9 +292,37 @@ impl ComponentEventHistory {
         all_events
     }
 
+    /// Get the entries of the instance-wide event log selected by `filter`.
+    ///
+    /// Returns entries in sequence order (oldest first). Unlike the
+    /// per-component history, entries are kept after their component is removed.
+    pub fn get_log(&self, filter: &EventLogFilter) -> Vec<EventLogEntry> {
+        let matching: Vec<&EventLogEntry> = self
+            .log
+            .iter()
+            .filter(|entry| filter.matches(entry))
+            .collect();
+        let skip = filter
+            .limit
+            .map_or(0, |limit| matching.len().saturating_sub(limit));
+        matching.into_iter().skip(skip).cloned().collect()
+    }
+
+    /// Subscribe to the instance-wide event log.
+    ///
+    /// Returns the retained log and a broadcast receiver for entries recorded
+    /// after it, so that together they contain every event exactly once.
+    pub fn subscribe_log(&self) -> (Vec<EventLogEntry>, broadcast::Receiver<EventLogEntry>) {
+        (
+            self.log.iter().cloned().collect(),
+            self.log_sender.subscribe(),
+        )
+    }
+
     /// Remove all events for a specific component.
     ///
     /// This should be called when a component is deleted to clean up its history.
+    /// The component's entries in the instance-wide event log are kept.
     pub fn remove_component(&mut self, component_id: &str) {
         self.channels.remove(component_id);
     }
@@ -426,4 +537,118 @@ mod tests {
         assert_eq!(received.status, ComponentStatus::Running);
         assert_eq!(received.message, Some("live event".to_string()));
     }
+
+    #[test]
+    fn test_event_log_survives_component_removal() {
+        let mut history = ComponentEventHistory::new();
+
+        history.record_event(create_test_event("source1", ComponentStatus::Running, None));
+        history.record_event(create_test_event("source2", ComponentStatus::Running, None));
+        history.remove_component("source1");
+
+        assert!(history.get_events("source1").is_empty());
+        let log = history.get_log(&EventLogFilter::default());
+        let ids: Vec<_> = log
+            .iter()
+            .map(|entry| (entry.sequence, entry.event.component_id.as_str()))
+            .collect();
+        assert_eq!(ids, vec![(1, "source1"), (2, "source2")]);
+    }
+
+    #[test]
+    fn test_event_log_is_bounded() {
+        let mut history = ComponentEventHistory::new().with_max_log_size(3);
+
+        for i in 0..5 {
+            history.record_event(create_test_event(
+                &format!("source{i}"),
+                ComponentStatus::Running,
+                None,
+            ));
+        }
+
+        let log = history.get_log(&EventLogFilter::default());
+        let sequences: Vec<_> = log.iter().map(|entry| entry.sequence).collect();
+        assert_eq!(sequences, vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn test_event_log_filter() {
+        let mut history = ComponentEventHistory::new();
+
+        history.record_event(create_test_event(
+            "source1",
+            ComponentStatus::Starting,
+            None,
+        ));
+        history.record_event(create_test_event("source1", ComponentStatus::Running, None));
+        history.record_event(create_test_event(
+            "source2",
+            ComponentStatus::Error,
+            Some("boom"),
+        ));
+        history.record_event(create_test_event(
+            "source1",
+            ComponentStatus::Error,
+            Some("bang"),
+        ));
+
+        let errors = history.get_log(&EventLogFilter {
+            status: Some(ComponentStatus::Error),
+            ..Default::default()
+        });
+        assert_eq!(errors.len(), 2);
+
+        let source1 = history.get_log(&EventLogFilter {
+            component_id: Some("source1".to_string()),
+            after: Some(1),
+            ..Default::default()
+        });
+        let sequences: Vec<_> = source1.iter().map(|entry| entry.sequence).collect();
+        assert_eq!(sequences, vec![2, 4]);
+
+        let latest = history.get_log(&EventLogFilter {
+            limit: Some(1),
+            ..Default::default()
+        });
+        assert_eq!(latest[0].event.message, Some("bang".to_string()));
+
+        let queries = history.get_log(&EventLogFilter {
+            component_type: Some(ComponentType::Query),
+            ..Default::default()
+        });
+        assert!(queries.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_log_continues_after_history() {
+        let mut history = ComponentEventHistory::new();
+
+        history.record_event(create_test_event(
+            "source1",
+            ComponentStatus::Starting,
+            None,
+        ));
+        let (log, mut receiver) = history.subscribe_log();
+        history.record_event(create_test_event("query1", ComponentStatus::Running, None));
+
+        assert_eq!(log.len(), 1);
+        assert_eq!(log[0].sequence, 1);
+        let received = receiver.try_recv().unwrap();
+        assert_eq!(received.sequence, 2);
+        assert_eq!(received.event.component_id, "query1");
+    }
+
+    #[test]
+    fn test_event_log_entry_serializes_flat() {
+        let entry = EventLogEntry {
+            sequence: 7,
+            event: create_test_event("source1", ComponentStatus::Running, None),
+        };
+
+        let value = serde_json::to_value(&entry).unwrap();
+        assert_eq!(value["sequence"], 7);
+        assert_eq!(value["component_id"], "source1");
+        assert_eq!(value["status"], "Running");
+    }
 }
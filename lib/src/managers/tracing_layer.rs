@@ -74,6 +74,19 @@ static GLOBAL_LOG_REGISTRY: OnceLock<Arc<ComponentLogRegistry>> = OnceLock::new(
 /// Global sender for the log worker. Initialized alongside the registry.
 static GLOBAL_LOG_SENDER: OnceLock<mpsc::Sender<LogMessage>> = OnceLock::new();
 
+/// OpenTelemetry span layer, installed once an OTLP exporter is configured.
+#[cfg(feature = "otel")]
+type OtelLayer = tracing_opentelemetry::OpenTelemetryLayer<
+    tracing_subscriber::Registry,
+    opentelemetry_sdk::trace::Tracer,
+>;
+
+/// Handle for swapping the OpenTelemetry layer into the global subscriber.
+#[cfg(feature = "otel")]
+static OTEL_LAYER_HANDLE: OnceLock<
+    tracing_subscriber::reload::Handle<Option<OtelLayer>, tracing_subscriber::Registry>,
+> = OnceLock::new();
+
 /// Get or initialize the shared global log registry.
 ///
 /// This returns a shared registry that all DrasiLib instances use. The tracing
@@ -208,7 +221,17 @@ fn init_tracing_internal(log_registry: Arc<ComponentLogRegistry>) {
     // Use RUST_LOG if set, otherwise default to INFO level
     let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
 
-    let subscriber = tracing_subscriber::registry()
+    // Starts empty; an OTLP exporter configured later is swapped in
+    #[cfg(feature = "otel")]
+    let subscriber = {
+        let (otel_layer, handle) = tracing_subscriber::reload::Layer::new(None);
+        let _ = OTEL_LAYER_HANDLE.set(handle);
+        tracing_subscriber::registry().with(otel_layer)
+    };
+    #[cfg(not(feature = "otel"))]
+    let subscriber = tracing_subscriber::registry();
+
+    let subscriber = subscriber
         .with(filter)
         .with(ComponentLogLayer::new(log_registry))
         .with(fmt::layer().with_target(true).with_level(true));
@@ -218,6 +241,23 @@ fn init_tracing_internal(log_registry: Arc<ComponentLogRegistry>) {
     let _ = tracing::subscriber::set_global_default(subscriber);
 }
 
+/// Route spans to OpenTelemetry through `layer`, replacing any previous one.
+///
+/// Fails if the application installed its own global subscriber before
+/// drasi-lib could, in which case it must add an OpenTelemetry layer itself.
+#[cfg(feature = "otel")]
+pub(crate) fn set_otel_layer(layer: OtelLayer) -> anyhow::Result<()> {
+    let _ = get_or_init_global_registry();
+    let handle = OTEL_LAYER_HANDLE
+        .get()
+        .ok_or_else(|| anyhow::anyhow!("tracing subscriber is not initialized"))?;
+    handle.reload(Some(layer)).map_err(|e| {
+        anyhow::anyhow!(
+            "Cannot install the OpenTelemetry layer ({e}); another global tracing subscriber is set"
+        )
+    })
+}
+
 /// Try to initialize tracing, returning whether initialization succeeded.
 ///
 /// Unlike `init_tracing()`, this returns `false` if a subscriber is already set,
@@ -0,0 +1,606 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Metrics for sources, queries, reactions and their channels.
+//!
+//! Every [`DrasiLib`](crate::DrasiLib) owns a [`MetricsRegistry`] (see
+//! [`DrasiLib::metrics`](crate::DrasiLib::metrics)) that components record into
+//! through their runtime context. The registry renders in the Prometheus text
+//! exposition format; with the `management-api` feature it is served on
+//! `GET /metrics`.
+//!
+//! | Metric | Type | Labels | Description |
+//! |--------|------|--------|-------------|
+//! | `drasi_source_dispatch_queue_depth` | gauge | `source` | Events waiting in the source's dispatch channels |
+//! | `drasi_source_bootstrap_events_total` | counter | `source` | Events sent while bootstrapping queries |
+//! | `drasi_source_bootstrap_seconds` | histogram | `source` | Duration of bootstrap requests served |
+//! | `drasi_query_events_total` | counter | `query`, `source` | Source changes processed |
+//! | `drasi_query_evaluation_seconds` | histogram | `query` | Time spent evaluating one source change |
+//! | `drasi_query_results_total` | counter | `query` | Result diffs produced |
+//! | `drasi_query_errors_total` | counter | `query` | Source changes that failed evaluation |
+//! | `drasi_query_bootstrap_events_total` | counter | `query`, `source` | Bootstrap changes processed |
+//! | `drasi_query_bootstrap_seconds` | gauge | `query` | Duration of the last completed bootstrap |
+//! | `drasi_query_queue_depth` | gauge | `query` | Events waiting in the query's priority queue |
+//! | `drasi_query_queue_dropped_total` | counter | `query` | Events dropped because the priority queue was full |
+//! | `drasi_query_dispatch_queue_depth` | gauge | `query` | Results waiting in the query's dispatch channels |
+//! | `drasi_reaction_results_total` | counter | `reaction` | Query results received |
+//! | `drasi_reaction_queue_depth` | gauge | `reaction` | Results waiting in the reaction's priority queue |
+//! | `drasi_reaction_queue_dropped_total` | counter | `reaction` | Results dropped because the priority queue was full |
+//!
+//! Embedders can register their own metrics on the same registry:
+//!
+//! ```no_run
+//! # use drasi_lib::DrasiLib;
+//! # fn example(core: &DrasiLib) {
+//! let alerts = core.metrics().counter(
+//!     "myapp_alerts_sent_total",
+//!     "Alerts sent to the on-call channel",
+//!     &[("channel", "pager")],
+//! );
+//! alerts.inc();
+//! println!("{}", core.metrics().render());
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use log::warn;
+
+/// Histogram buckets, in seconds, used by [`MetricsRegistry::histogram`].
+pub const DEFAULT_LATENCY_BUCKETS: &[f64] = &[
+    0.0005, 0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// Content type of [`MetricsRegistry::render`] output.
+pub const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Kind of a metric family.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricKind {
+    Counter,
+    Gauge,
+    Histogram,
+}
+
+impl MetricKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            MetricKind::Counter => "counter",
+            MetricKind::Gauge => "gauge",
+            MetricKind::Histogram => "histogram",
+        }
+    }
+}
+
+/// Monotonically increasing count. Cheap to clone; clones share the value.
+#[derive(Debug, Clone, Default)]
+pub struct Counter(Arc<AtomicU64>);
+
+impl Counter {
+    pub fn inc(&self) {
+        self.inc_by(1);
+    }
+
+    pub fn inc_by(&self, n: u64) {
+        self.0.fetch_add(n, Ordering::Relaxed);
+    }
+
+    pub fn get(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Value that can go up and down. Cheap to clone; clones share the value.
+#[derive(Debug, Clone, Default)]
+pub struct Gauge(Arc<AtomicU64>);
+
+impl Gauge {
+    pub fn set(&self, value: f64) {
+        self.0.store(value.to_bits(), Ordering::Relaxed);
+    }
+
+    pub fn add(&self, delta: f64) {
+        let _ = self
+            .0
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |bits| {
+                Some((f64::from_bits(bits) + delta).to_bits())
+            });
+    }
+
+    pub fn inc(&self) {
+        self.add(1.0);
+    }
+
+    pub fn dec(&self) {
+        self.add(-1.0);
+    }
+
+    pub fn get(&self) -> f64 {
+        f64::from_bits(self.0.load(Ordering::Relaxed))
+    }
+}
+
+#[derive(Debug)]
+struct HistogramState {
+    bounds: Vec<f64>,
+    buckets: Vec<AtomicU64>,
+    sum: Gauge,
+    count: AtomicU64,
+}
+
+/// Distribution of observed values over fixed buckets. Cheap to clone; clones
+/// share the buckets.
+#[derive(Debug, Clone)]
+pub struct Histogram(Arc<HistogramState>);
+
+impl Histogram {
+    fn new(bounds: &[f64]) -> Self {
+        let mut bounds: Vec<f64> = bounds.iter().copied().filter(|b| b.is_finite()).collect();
+        bounds.sort_by(|a, b| a.total_cmp(b));
+        bounds.dedup();
+        let buckets = bounds.iter().map(|_| AtomicU64::new(0)).collect();
+        Self(Arc::new(HistogramState {
+            bounds,
+            buckets,
+            sum: Gauge::default(),
+            count: AtomicU64::new(0),
+        }))
+    }
+
+    pub fn observe(&self, value: f64) {
+        if let Some(i) = self.0.bounds.iter().position(|bound| value <= *bound) {
+            self.0.buckets[i].fetch_add(1, Ordering::Relaxed);
+        }
+        self.0.sum.add(value);
+        self.0.count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Observe a duration in seconds.
+    pub fn observe_duration(&self, duration: Duration) {
+        self.observe(duration.as_secs_f64());
+    }
+
+    /// Number of observations.
+    pub fn count(&self) -> u64 {
+        self.0.count.load(Ordering::Relaxed)
+    }
+
+    /// Sum of all observed values.
+    pub fn sum(&self) -> f64 {
+        self.0.sum.get()
+    }
+}
+
+type SampleFn = Arc<dyn Fn() -> Option<f64> + Send + Sync>;
+
+#[derive(Clone)]
+enum Series {
+    Counter(Counter),
+    Gauge(Gauge),
+    Histogram(Histogram),
+    /// Read when the registry is rendered; `None` omits the sample.
+    Callback(SampleFn),
+}
+
+type Labels = Vec<(String, String)>;
+
+struct Family {
+    help: String,
+    kind: MetricKind,
+    series: BTreeMap<Labels, Series>,
+}
+
+/// Collection of named metrics, rendered in the Prometheus text format.
+///
+/// A metric is identified by its name and label set. Asking for an existing
+/// metric returns a handle to the same value, so components can re-acquire
+/// their handles after a restart without resetting counts. Asking for a name
+/// already registered with a different [`MetricKind`] logs a warning and
+/// returns a handle that is not exported.
+#[derive(Default)]
+pub struct MetricsRegistry {
+    families: RwLock<BTreeMap<String, Family>>,
+}
+
+impl std::fmt::Debug for MetricsRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let families = self.families.read().unwrap_or_else(|e| e.into_inner());
+        f.debug_struct("MetricsRegistry")
+            .field("families", &families.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl MetricsRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get or register a counter.
+    pub fn counter(&self, name: &str, help: &str, labels: &[(&str, &str)]) -> Counter {
+        match self.get_or_insert(name, help, MetricKind::Counter, labels, || {
+            Series::Counter(Counter::default())
+        }) {
+            Some(Series::Counter(counter)) => counter,
+            _ => Counter::default(),
+        }
+    }
+
+    /// Get or register a gauge.
+    pub fn gauge(&self, name: &str, help: &str, labels: &[(&str, &str)]) -> Gauge {
+        match self.get_or_insert(name, help, MetricKind::Gauge, labels, || {
+            Series::Gauge(Gauge::default())
+        }) {
+            Some(Series::Gauge(gauge)) => gauge,
+            _ => Gauge::default(),
+        }
+    }
+
+    /// Get or register a histogram with [`DEFAULT_LATENCY_BUCKETS`].
+    pub fn histogram(&self, name: &str, help: &str, labels: &[(&str, &str)]) -> Histogram {
+        self.histogram_with_buckets(name, help, labels, DEFAULT_LATENCY_BUCKETS)
+    }
+
+    /// Get or register a histogram with custom bucket upper bounds. The bounds
+    /// of an existing histogram are kept.
+    pub fn histogram_with_buckets(
+        &self,
+        name: &str,
+        help: &str,
+        labels: &[(&str, &str)],
+        buckets: &[f64],
+    ) -> Histogram {
+        match self.get_or_insert(name, help, MetricKind::Histogram, labels, || {
+            Series::Histogram(Histogram::new(buckets))
+        }) {
+            Some(Series::Histogram(histogram)) => histogram,
+            _ => Histogram::new(buckets),
+        }
+    }
+
+    /// Register a gauge whose value is read from `sample` on every render,
+    /// replacing any gauge with the same name and labels.
+    pub fn gauge_fn(
+        &self,
+        name: &str,
+        help: &str,
+        labels: &[(&str, &str)],
+        sample: impl Fn() -> Option<f64> + Send + Sync + 'static,
+    ) {
+        self.replace(name, help, MetricKind::Gauge, labels, Arc::new(sample));
+    }
+
+    /// Register a counter whose value is read from `sample` on every render,
+    /// replacing any counter with the same name and labels.
+    pub fn counter_fn(
+        &self,
+        name: &str,
+        help: &str,
+        labels: &[(&str, &str)],
+        sample: impl Fn() -> Option<f64> + Send + Sync + 'static,
+    ) {
+        self.replace(name, help, MetricKind::Counter, labels, Arc::new(sample));
+    }
+
+    /// Current value of a counter or gauge, `None` if it is not registered.
+    /// For histograms this is the number of observations.
+    pub fn sample(&self, name: &str, labels: &[(&str, &str)]) -> Option<f64> {
+        let families = self.families.read().unwrap_or_else(|e| e.into_inner());
+        match families.get(name)?.series.get(&to_labels(labels))? {
+            Series::Counter(counter) => Some(counter.get() as f64),
+            Series::Gauge(gauge) => Some(gauge.get()),
+            Series::Histogram(histogram) => Some(histogram.count() as f64),
+            Series::Callback(sample) => sample(),
+        }
+    }
+
+    /// Remove every series carrying the label `name="value"`, e.g. all metrics
+    /// of a component that was removed.
+    pub fn remove_series(&self, name: &str, value: &str) {
+        let mut families = self.families.write().unwrap_or_else(|e| e.into_inner());
+        for family in families.values_mut() {
+            family
+                .series
+                .retain(|labels, _| !labels.iter().any(|(n, v)| n == name && v == value));
+        }
+        families.retain(|_, family| !family.series.is_empty());
+    }
+
+    /// Render all metrics in the Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        // Snapshot the series so callbacks run without holding the lock
+        let families: Vec<(String, String, MetricKind, Vec<(Labels, Series)>)> = {
+            let families = self.families.read().unwrap_or_else(|e| e.into_inner());
+            families
+                .iter()
+                .map(|(name, family)| {
+                    (
+                        name.clone(),
+                        family.help.clone(),
+                        family.kind,
+                        family
+                            .series
+                            .iter()
+                            .map(|(labels, series)| (labels.clone(), series.clone()))
+                            .collect(),
+                    )
+                })
+                .collect()
+        };
+
+        let mut out = String::new();
+        for (name, help, kind, series) in families {
+            let _ = writeln!(out, "# HELP {name} {}", escape_help(&help));
+            let _ = writeln!(out, "# TYPE {name} {}", kind.as_str());
+            for (labels, series) in series {
+                match series {
+                    Series::Counter(counter) => {
+                        write_sample(&mut out, &name, &labels, None, counter.get() as f64)
+                    }
+                    Series::Gauge(gauge) => {
+                        write_sample(&mut out, &name, &labels, None, gauge.get())
+                    }
+                    Series::Callback(sample) => {
+                        if let Some(value) = sample() {
+                            write_sample(&mut out, &name, &labels, None, value);
+                        }
+                    }
+                    Series::Histogram(histogram) => {
+                        let bucket_name = format!("{name}_bucket");
+                        let mut cumulative = 0;
+                        for (bound, bucket) in histogram.0.bounds.iter().zip(&histogram.0.buckets) {
+                            cumulative += bucket.load(Ordering::Relaxed);
+                            write_sample(
+                                &mut out,
+                                &bucket_name,
+                                &labels,
+                                Some(&format_value(*bound)),
+                                cumulative as f64,
+                            );
+                        }
+                        let count = histogram.count();
+                        write_sample(&mut out, &bucket_name, &labels, Some("+Inf"), count as f64);
+                        write_sample(
+                            &mut out,
+                            &format!("{name}_sum"),
+                            &labels,
+                            None,
+                            histogram.sum(),
+                        );
+                        write_sample(
+                            &mut out,
+                            &format!("{name}_count"),
+                            &labels,
+                            None,
+                            count as f64,
+                        );
+                    }
+                }
+            }
+        }
+        out
+    }
+
+    fn get_or_insert(
+        &self,
+        name: &str,
+        help: &str,
+        kind: MetricKind,
+        labels: &[(&str, &str)],
+        create: impl FnOnce() -> Series,
+    ) -> Option<Series> {
+        let labels = to_labels(labels);
+        let mut families = self.families.write().unwrap_or_else(|e| e.into_inner());
+        let family = families.entry(name.to_string()).or_insert_with(|| Family {
+            help: help.to_string(),
+            kind,
+            series: BTreeMap::new(),
+        });
+        if family.kind != kind {
+            warn!(
+                "Metric '{name}' is already registered as a {}, not exporting it as a {}",
+                family.kind.as_str(),
+                kind.as_str()
+            );
+            return None;
+        }
+        Some(family.series.entry(labels).or_insert_with(create).clone())
+    }
+
+    fn replace(
+        &self,
+        name: &str,
+        help: &str,
+        kind: MetricKind,
+        labels: &[(&str, &str)],
+        sample: SampleFn,
+    ) {
+        let labels = to_labels(labels);
+        let mut families = self.families.write().unwrap_or_else(|e| e.into_inner());
+        let family = families.entry(name.to_string()).or_insert_with(|| Family {
+            help: help.to_string(),
+            kind,
+            series: BTreeMap::new(),
+        });
+        if family.kind != kind {
+            warn!(
+                "Metric '{name}' is already registered as a {}, not exporting it as a {}",
+                family.kind.as_str(),
+                kind.as_str()
+            );
+            return;
+        }
+        family.series.insert(labels, Series::Callback(sample));
+    }
+}
+
+fn to_labels(labels: &[(&str, &str)]) -> Labels {
+    let mut labels: Labels = labels
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect();
+    labels.sort();
+    labels
+}
+
+fn write_sample(out: &mut String, name: &str, labels: &Labels, le: Option<&str>, value: f64) {
+    out.push_str(name);
+    if !labels.is_empty() || le.is_some() {
+        out.push('{');
+        let mut first = true;
+        for (label, label_value) in labels
+            .iter()
+            .map(|(n, v)| (n.as_str(), v.as_str()))
+            .chain(le.map(|le| ("le", le)))
+        {
+            if !first {
+                out.push(',');
+            }
+            first = false;
+            let _ = write!(out, "{label}=\"{}\"", escape_label_value(label_value));
+        }
+        out.push('}');
+    }
+    let _ = writeln!(out, " {}", format_value(value));
+}
+
+fn format_value(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value == f64::INFINITY {
+        "+Inf".to_string()
+    } else if value == f64::NEG_INFINITY {
+        "-Inf".to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+fn escape_help(help: &str) -> String {
+    help.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counter_and_gauge_render() {
+        let registry = MetricsRegistry::new();
+        let counter = registry.counter("events_total", "Events seen", &[("query", "q1")]);
+        counter.inc();
+        counter.inc_by(2);
+        registry.gauge("depth", "Queue depth", &[]).set(4.5);
+
+        assert_eq!(
+            registry.render(),
+            "# HELP depth Queue depth\n\
+             # TYPE depth gauge\n\
+             depth 4.5\n\
+             # HELP events_total Events seen\n\
+             # TYPE events_total counter\n\
+             events_total{query=\"q1\"} 3\n"
+        );
+    }
+
+    #[test]
+    fn test_same_name_and_labels_share_value() {
+        let registry = MetricsRegistry::new();
+        registry.counter("c", "", &[("b", "2"), ("a", "1")]).inc();
+        registry.counter("c", "", &[("a", "1"), ("b", "2")]).inc();
+        assert_eq!(registry.sample("c", &[("a", "1"), ("b", "2")]), Some(2.0));
+        assert_eq!(registry.sample("c", &[("a", "1")]), None);
+    }
+
+    #[test]
+    fn test_histogram_buckets_are_cumulative() {
+        let registry = MetricsRegistry::new();
+        let histogram = registry.histogram_with_buckets("latency", "Latency", &[], &[1.0, 0.25]);
+        histogram.observe(0.25);
+        histogram.observe(0.5);
+        histogram.observe(3.0);
+
+        let rendered = registry.render();
+        assert!(
+            rendered.contains("latency_bucket{le=\"0.25\"} 1\n"),
+            "{rendered}"
+        );
+        assert!(
+            rendered.contains("latency_bucket{le=\"1\"} 2\n"),
+            "{rendered}"
+        );
+        assert!(
+            rendered.contains("latency_bucket{le=\"+Inf\"} 3\n"),
+            "{rendered}"
+        );
+        assert!(rendered.contains("latency_sum 3.75\n"), "{rendered}");
+        assert!(rendered.contains("latency_count 3\n"), "{rendered}");
+    }
+
+    #[test]
+    fn test_callbacks_are_read_on_render() {
+        let registry = MetricsRegistry::new();
+        let depth = Arc::new(AtomicU64::new(7));
+        let depth_clone = depth.clone();
+        registry.gauge_fn("depth", "Depth", &[("query", "q")], move || {
+            Some(depth_clone.load(Ordering::Relaxed) as f64)
+        });
+        registry.gauge_fn("skipped", "Skipped", &[], || None);
+
+        assert_eq!(registry.sample("depth", &[("query", "q")]), Some(7.0));
+        depth.store(9, Ordering::Relaxed);
+        let rendered = registry.render();
+        assert!(rendered.contains("depth{query=\"q\"} 9\n"), "{rendered}");
+        assert!(!rendered.contains("skipped "), "{rendered}");
+    }
+
+    #[test]
+    fn test_kind_mismatch_is_not_exported() {
+        let registry = MetricsRegistry::new();
+        registry.counter("m", "", &[]).inc();
+        registry.gauge("m", "", &[]).set(10.0);
+        assert_eq!(registry.sample("m", &[]), Some(1.0));
+    }
+
+    #[test]
+    fn test_remove_series_by_label() {
+        let registry = MetricsRegistry::new();
+        registry.counter("c", "", &[("query", "q1")]).inc();
+        registry.counter("c", "", &[("query", "q2")]).inc();
+        registry.gauge("g", "", &[("query", "q1")]).set(1.0);
+
+        registry.remove_series("query", "q1");
+        assert_eq!(registry.sample("c", &[("query", "q1")]), None);
+        assert_eq!(registry.sample("c", &[("query", "q2")]), Some(1.0));
+        assert!(!registry.render().contains("# TYPE g"));
+    }
+
+    #[test]
+    fn test_label_values_are_escaped() {
+        let registry = MetricsRegistry::new();
+        registry.counter("c", "", &[("id", "a\"b\\c")]).inc();
+        assert!(registry.render().contains("c{id=\"a\\\"b\\\\c\"} 1\n"));
+    }
+}
@@ -46,6 +46,14 @@ pub struct ProfilingMetadata {
     pub reaction_receive_ns: Option<u64>,
     /// Timestamp when the reaction completed processing
     pub reaction_complete_ns: Option<u64>,
+    /// Identifier assigned to the source change when it was dispatched
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub change_id: Option<String>,
+    /// Span of the latest pipeline stage, parent of the next stage's span
+    /// (see [`crate::telemetry`]). Not serialized, so it only propagates
+    /// within the process.
+    #[serde(skip)]
+    pub span: Option<tracing::Span>,
 }
 
 impl ProfilingMetadata {
@@ -189,6 +197,12 @@ impl ProfilingMetadata {
         if self.reaction_complete_ns.is_none() {
             self.reaction_complete_ns = other.reaction_complete_ns;
         }
+        if self.change_id.is_none() {
+            self.change_id = other.change_id.clone();
+        }
+        if self.span.is_none() {
+            self.span = other.span.clone();
+        }
     }
 }
 
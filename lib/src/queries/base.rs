@@ -49,7 +49,7 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 
 use crate::channels::{
-    BroadcastChangeDispatcher, ChangeDispatcher, ChangeReceiver, ChannelChangeDispatcher,
+    dedicated_dispatcher, BroadcastChangeDispatcher, ChangeDispatcher, ChangeReceiver,
     ComponentStatus, DispatchMode, QueryResult, QuerySubscriptionResponse,
 };
 use crate::component_graph::ComponentStatusHandle;
@@ -133,28 +133,28 @@ impl QueryBase {
 
         let dispatch_mode = self.config.dispatch_mode.unwrap_or_default();
 
-        let receiver: Box<dyn ChangeReceiver<QueryResult>> = match dispatch_mode {
-            DispatchMode::Broadcast => {
-                // For broadcast mode, use the single dispatcher
-                let dispatchers = self.dispatchers.read().await;
-                if let Some(dispatcher) = dispatchers.first() {
-                    dispatcher.create_receiver().await?
-                } else {
-                    return Err(anyhow::anyhow!("No broadcast dispatcher available"));
-                }
-            }
-            DispatchMode::Channel => {
-                // For channel mode, create a new dispatcher for this subscription
-                let capacity = self.config.dispatch_buffer_capacity.unwrap_or(1000);
-                let dispatcher = ChannelChangeDispatcher::<QueryResult>::new(capacity);
-                let receiver = dispatcher.create_receiver().await?;
+        let capacity = self.config.dispatch_buffer_capacity.unwrap_or(1000);
+        let receiver: Box<dyn ChangeReceiver<QueryResult>> =
+            match dedicated_dispatcher(dispatch_mode, capacity) {
+                Some(dispatcher) => {
+                    // For channel and batched modes, each subscription has its own dispatcher
+                    let receiver = dispatcher.create_receiver().await?;
 
-                let mut dispatchers = self.dispatchers.write().await;
-                dispatchers.push(Box::new(dispatcher));
+                    let mut dispatchers = self.dispatchers.write().await;
+                    dispatchers.push(dispatcher);
 
-                receiver
-            }
-        };
+                    receiver
+                }
+                None => {
+                    // For broadcast mode, use the single dispatcher
+                    let dispatchers = self.dispatchers.read().await;
+                    if let Some(dispatcher) = dispatchers.first() {
+                        dispatcher.create_receiver().await?
+                    } else {
+                        return Err(anyhow::anyhow!("No broadcast dispatcher available"));
+                    }
+                }
+            };
 
         Ok(QuerySubscriptionResponse {
             query_id: self.config.id.clone(),
@@ -287,6 +287,9 @@ mod tests {
             dispatch_mode: mode,
             storage_backend: None,
             recovery_policy: None,
+            result_processors: Vec::new(),
+            quarantine_capacity: 0,
+            priority: Default::default(),
         }
     }
 
@@ -528,6 +531,9 @@ mod tests {
             dispatch_mode: Some(DispatchMode::Broadcast),
             storage_backend: None,
             recovery_policy: None,
+            result_processors: Vec::new(),
+            quarantine_capacity: 0,
+            priority: Default::default(),
         };
 
         let base = QueryBase::new(config).unwrap();
@@ -575,6 +581,9 @@ mod tests {
             dispatch_mode: Some(DispatchMode::Channel),
             storage_backend: None,
             recovery_policy: None,
+            result_processors: Vec::new(),
+            quarantine_capacity: 0,
+            priority: Default::default(),
         };
 
         let base = QueryBase::new(config).unwrap();
@@ -50,7 +50,8 @@ use drasi_core::models::SourceMiddlewareConfig;
 /// Fields excluded (operational tuning — changes MUST NOT wipe the index):
 ///   - `id`, `auto_start`, `enable_bootstrap`, `bootstrap_buffer_size`,
 ///     `priority_queue_capacity`, `dispatch_buffer_capacity`, `dispatch_mode`,
-///     `storage_backend`, `recovery_policy`.
+///     `storage_backend`, `recovery_policy`, `result_processors`,
+///     `quarantine_capacity`, `priority`.
 #[derive(Serialize)]
 struct QueryIdentity<'a> {
     query: &'a str,
@@ -171,6 +172,9 @@ mod tests {
             dispatch_mode: None,
             storage_backend: None,
             recovery_policy: None,
+            result_processors: Vec::new(),
+            quarantine_capacity: 0,
+            priority: Default::default(),
         }
     }
 
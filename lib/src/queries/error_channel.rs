@@ -0,0 +1,241 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-query error stream and poison-change quarantine.
+//!
+//! When a source change fails to evaluate, the query reports a
+//! [`QueryEvaluationError`] to its [`ErrorChannel`] instead of only logging
+//! it. Errors are broadcast to in-process subscribers (see
+//! [`DrasiLib::subscribe_query_errors`](crate::DrasiLib::subscribe_query_errors)),
+//! and queries configured with a quarantine capacity also keep the offending
+//! [`SourceChange`] for inspection (see
+//! [`DrasiLib::quarantined_changes`](crate::DrasiLib::quarantined_changes)).
+
+use std::collections::VecDeque;
+
+use chrono::{DateTime, Utc};
+use drasi_core::models::{ElementReference, SourceChange};
+use log::warn;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+
+use crate::queries::result_set::DEFAULT_SUBSCRIPTION_CAPACITY;
+
+/// A source change that a query failed to evaluate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryEvaluationError {
+    /// ID of the query that failed.
+    pub query_id: String,
+    /// Source the offending change came from.
+    pub source_id: String,
+    /// ID of the element the change refers to.
+    pub element_id: String,
+    /// Kind of change: `insert`, `update`, `delete` or `future`.
+    pub operation: String,
+    /// Whether the change was part of the query's bootstrap.
+    pub bootstrap: bool,
+    /// The evaluation error message.
+    pub error: String,
+    /// ID of the quarantined copy of the change, `None` when quarantine is disabled.
+    pub quarantine_id: Option<u64>,
+    /// When the failure happened.
+    pub timestamp: DateTime<Utc>,
+}
+
+/// A failing source change held in a query's quarantine.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuarantinedChange {
+    /// Identifier of this entry, unique within the query.
+    pub id: u64,
+    /// The error reported when the change failed.
+    pub error: QueryEvaluationError,
+    /// The change exactly as the query received it.
+    pub change: SourceChange,
+}
+
+/// Reference to a change that is about to be evaluated.
+///
+/// Captured before the change is handed to the continuous query, which takes
+/// ownership of it. Holds a full copy of the change only when quarantine is enabled.
+pub(crate) struct PendingChange {
+    reference: ElementReference,
+    operation: &'static str,
+    change: Option<SourceChange>,
+}
+
+/// Error stream and quarantine maintained by each query.
+#[derive(Debug)]
+pub(crate) struct ErrorChannel {
+    errors: broadcast::Sender<QueryEvaluationError>,
+    quarantine: VecDeque<QuarantinedChange>,
+    quarantine_capacity: usize,
+    next_id: u64,
+}
+
+impl Default for ErrorChannel {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl ErrorChannel {
+    /// Create an error channel keeping up to `quarantine_capacity` failing
+    /// changes. A capacity of `0` disables quarantine.
+    pub(crate) fn new(quarantine_capacity: usize) -> Self {
+        let (errors, _) = broadcast::channel(DEFAULT_SUBSCRIPTION_CAPACITY);
+        Self {
+            errors,
+            quarantine: VecDeque::new(),
+            quarantine_capacity,
+            next_id: 1,
+        }
+    }
+
+    /// Capture what is needed to report `change` should it fail.
+    pub(crate) fn track(&self, change: &SourceChange) -> PendingChange {
+        let operation = match change {
+            SourceChange::Insert { .. } => "insert",
+            SourceChange::Update { .. } => "update",
+            SourceChange::Delete { .. } => "delete",
+            SourceChange::Future { .. } => "future",
+        };
+        PendingChange {
+            reference: change.get_reference().clone(),
+            operation,
+            change: (self.quarantine_capacity > 0).then(|| change.clone()),
+        }
+    }
+
+    /// Report a failed change: broadcast the error and quarantine the change
+    /// if it was captured. When the quarantine is full the oldest entry is evicted.
+    pub(crate) fn report(
+        &mut self,
+        query_id: &str,
+        pending: PendingChange,
+        bootstrap: bool,
+        error: &dyn std::fmt::Display,
+    ) -> QueryEvaluationError {
+        let quarantine_id = pending.change.as_ref().map(|_| {
+            let id = self.next_id;
+            self.next_id += 1;
+            id
+        });
+        let report = QueryEvaluationError {
+            query_id: query_id.to_string(),
+            source_id: pending.reference.source_id.to_string(),
+            element_id: pending.reference.element_id.to_string(),
+            operation: pending.operation.to_string(),
+            bootstrap,
+            error: error.to_string(),
+            quarantine_id,
+            timestamp: Utc::now(),
+        };
+
+        if let (Some(id), Some(change)) = (quarantine_id, pending.change) {
+            if self.quarantine.len() >= self.quarantine_capacity {
+                if let Some(evicted) = self.quarantine.pop_front() {
+                    warn!(
+                        "Query '{query_id}' quarantine is full, evicting change {} for element '{}'",
+                        evicted.id, evicted.error.element_id
+                    );
+                }
+            }
+            self.quarantine.push_back(QuarantinedChange {
+                id,
+                error: report.clone(),
+                change,
+            });
+        }
+
+        // Sending only fails when nobody is subscribed
+        let _ = self.errors.send(report.clone());
+        report
+    }
+
+    /// Receive every error reported after this call.
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<QueryEvaluationError> {
+        self.errors.subscribe()
+    }
+
+    /// Quarantined changes, oldest first.
+    pub(crate) fn quarantined(&self) -> Vec<QuarantinedChange> {
+        self.quarantine.iter().cloned().collect()
+    }
+
+    /// Remove and return every quarantined change, oldest first.
+    pub(crate) fn drain_quarantine(&mut self) -> Vec<QuarantinedChange> {
+        self.quarantine.drain(..).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use drasi_core::models::{Element, ElementMetadata, ElementPropertyMap};
+    use std::sync::Arc;
+
+    fn insert(id: &str) -> SourceChange {
+        SourceChange::Insert {
+            element: Element::Node {
+                metadata: ElementMetadata {
+                    reference: ElementReference::new("src", id),
+                    labels: Arc::from(vec![Arc::from("Item")]),
+                    effective_from: 1,
+                },
+                properties: ElementPropertyMap::new(),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_report_broadcasts_error() {
+        let mut channel = ErrorChannel::default();
+        let mut rx = channel.subscribe();
+
+        let pending = channel.track(&insert("n1"));
+        channel.report("q", pending, false, &"boom");
+
+        let error = rx.recv().await.unwrap();
+        assert_eq!(error.query_id, "q");
+        assert_eq!(error.source_id, "src");
+        assert_eq!(error.element_id, "n1");
+        assert_eq!(error.operation, "insert");
+        assert_eq!(error.error, "boom");
+        assert!(!error.bootstrap);
+        assert_eq!(error.quarantine_id, None);
+        assert!(channel.quarantined().is_empty());
+    }
+
+    #[test]
+    fn test_quarantine_keeps_change_and_evicts_oldest() {
+        let mut channel = ErrorChannel::new(2);
+        for id in ["n1", "n2", "n3"] {
+            let pending = channel.track(&insert(id));
+            channel.report("q", pending, true, &"boom");
+        }
+
+        let quarantined = channel.quarantined();
+        assert_eq!(
+            quarantined.iter().map(|q| q.id).collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+        assert_eq!(quarantined[0].change, insert("n2"));
+        assert_eq!(quarantined[0].error.quarantine_id, Some(2));
+        assert!(quarantined[0].error.bootstrap);
+
+        assert_eq!(channel.drain_quarantine().len(), 2);
+        assert!(channel.quarantined().is_empty());
+    }
+}
@@ -17,6 +17,7 @@ use async_trait::async_trait;
 use log::{debug, error, info, warn};
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use tokio::sync::{Notify, RwLock};
 
@@ -26,6 +27,7 @@ use drasi_core::{
     evaluation::functions::FunctionRegistry,
     evaluation::variable_value::VariableValue,
     middleware::MiddlewareTypeRegistry,
+    models::{Element, SourceChange},
     query::{ContinuousQuery, QueryBuilder},
 };
 use drasi_functions_cypher::CypherFunctionSet;
@@ -33,8 +35,10 @@ use drasi_functions_gql::GQLFunctionSet;
 use drasi_query_ast::api::{QueryConfiguration, QueryParser};
 use drasi_query_cypher::CypherParser;
 use drasi_query_gql::GQLParser;
+use futures::StreamExt;
 
 use crate::channels::*;
+use crate::checkpoint::CheckpointStore;
 use crate::component_graph::{ComponentGraph, ComponentKind, ComponentUpdateSender};
 use crate::config::SourceSubscriptionSettings;
 use crate::config::{QueryConfig, QueryLanguage, QueryRuntime};
@@ -42,8 +46,19 @@ use crate::managers::{
     log_component_error, log_component_start, log_component_stop, ComponentLogKey,
     ComponentLogRegistry,
 };
+use crate::metrics::MetricsRegistry;
+use crate::queries::error_channel::{ErrorChannel, QuarantinedChange, QueryEvaluationError};
+use crate::queries::metrics::QueryMetrics;
+use crate::queries::pool::EvaluationPool;
+use crate::queries::result_processor::{apply_result_processors, project_rows, ResultProcessor};
+use crate::queries::result_set::DEFAULT_SUBSCRIPTION_CAPACITY;
+use crate::queries::scheduler::{QueryPriority, QueryScheduler};
+use crate::queries::state_snapshot::{
+    QueryStateSnapshot, SnapshotElement, QUERY_STATE_FORMAT_VERSION,
+};
 use crate::queries::PriorityQueue;
 use crate::queries::QueryBase;
+use crate::queries::{QueryResultSnapshot, ResultSet};
 use crate::sources::FutureQueueSource;
 use crate::sources::Source;
 use crate::sources::SourceManager;
@@ -141,19 +156,9 @@ enum BootstrapPhase {
     Completed,
 }
 
-/// Dispatch query evaluation results to the current result set and all subscribed reactions.
-///
-/// Shared between the regular event processing path and the future queue drain path.
-async fn dispatch_query_results(
-    results: &[QueryPartEvaluationContext],
-    source_id: &str,
-    query_id: &str,
-    current_results: &RwLock<Vec<serde_json::Value>>,
-    dispatchers: &RwLock<Vec<Box<dyn ChangeDispatcher<QueryResult> + Send + Sync>>>,
-    profiling: crate::profiling::ProfilingMetadata,
-) {
-    // Convert Drasi results to our QueryResult format
-    let converted_results: Vec<ResultDiff> = results
+/// Convert evaluation contexts produced by drasi-core into typed result diffs.
+fn convert_results_to_diffs(results: &[QueryPartEvaluationContext]) -> Vec<ResultDiff> {
+    results
         .iter()
         .map(|ctx| match ctx {
             QueryPartEvaluationContext::Adding { after, .. } => ResultDiff::Add {
@@ -176,43 +181,40 @@ async fn dispatch_query_results(
             }
             QueryPartEvaluationContext::Noop => ResultDiff::Noop,
         })
-        .collect();
-
-    // Update the current result set based on the changes
-    let mut result_set = current_results.write().await;
-    for result in &converted_results {
-        match result {
-            ResultDiff::Add { data } => {
-                result_set.push(data.clone());
-            }
-            ResultDiff::Delete { data } => {
-                result_set.retain(|item| item != data);
-            }
-            ResultDiff::Update { before, after, .. } => {
-                if let Some(pos) = result_set.iter().position(|item| item == before) {
-                    result_set[pos] = after.clone();
-                } else {
-                    warn!("UPDATE: Could not find exact match for before state, treating as remove+add");
-                    result_set.retain(|item| item != before);
-                    result_set.push(after.clone());
-                }
-            }
-            ResultDiff::Aggregation { before, after } => {
-                if let Some(before) = before {
-                    if let Some(pos) = result_set.iter().position(|item| item == before) {
-                        result_set[pos] = after.clone();
-                    } else {
-                        result_set.retain(|item| item != before);
-                        result_set.push(after.clone());
-                    }
-                } else {
-                    result_set.push(after.clone());
-                }
-            }
-            ResultDiff::Noop => {}
-        }
+        .collect()
+}
+
+/// Dispatch query evaluation results to the current result set and all subscribed reactions.
+///
+/// Shared between the regular event processing path and the future queue drain path.
+async fn dispatch_query_results(
+    results: &[QueryPartEvaluationContext],
+    source_id: &str,
+    query_id: &str,
+    current_results: &RwLock<ResultSet>,
+    dispatchers: &RwLock<Vec<Box<dyn ChangeDispatcher<QueryResult> + Send + Sync>>>,
+    result_processors: &[Arc<dyn ResultProcessor>],
+    profiling: crate::profiling::ProfilingMetadata,
+) {
+    // Convert Drasi results to our QueryResult format
+    let converted_results = convert_results_to_diffs(results);
+
+    // Fold the raw diffs into the current result set, so a processor that drops
+    // or rewrites an update cannot leave stale rows behind, and publish the
+    // processed diffs. Doing both under the lock keeps subscribers in step with
+    // the order diffs are applied.
+    let converted_results = {
+        let mut current_results = current_results.write().await;
+        current_results.apply_rows(&converted_results);
+        let processed = apply_result_processors(result_processors, query_id, converted_results);
+        current_results.publish(&processed);
+        processed
+    };
+    if converted_results.is_empty() {
+        debug!("Query '{query_id}' results suppressed by result processors");
+        return;
     }
-    drop(result_set);
+    let result_count = converted_results.len();
 
     let query_result = QueryResult::with_profiling(
         query_id.to_string(),
@@ -230,7 +232,7 @@ async fn dispatch_query_results(
             );
             meta.insert(
                 "result_count".to_string(),
-                serde_json::Value::Number(results.len().into()),
+                serde_json::Value::Number(result_count.into()),
             );
             meta
         },
@@ -239,8 +241,7 @@ async fn dispatch_query_results(
 
     debug!(
         "Query '{}' sending {} results to reactions",
-        query_id,
-        results.len()
+        query_id, result_count
     );
 
     // Dispatch query result to all subscribed reactions
@@ -253,12 +254,101 @@ async fn dispatch_query_results(
     }
 }
 
+/// Everything needed to evaluate a source change and dispatch its results.
+///
+/// Shared by a query's processor task and, when the query evaluates on an
+/// [`EvaluationPool`], the jobs it submits there.
+struct ChangeEvaluator {
+    continuous_query: Arc<ContinuousQuery>,
+    query_id: String,
+    instance_id: String,
+    current_results: Arc<RwLock<ResultSet>>,
+    dispatchers: Arc<RwLock<Vec<Box<dyn ChangeDispatcher<QueryResult> + Send + Sync>>>>,
+    result_processors: Vec<Arc<dyn ResultProcessor>>,
+    errors: Arc<RwLock<ErrorChannel>>,
+    metrics: std::sync::Mutex<QueryMetrics>,
+    scheduler: Option<Arc<QueryScheduler>>,
+    priority: QueryPriority,
+}
+
+impl ChangeEvaluator {
+    /// Evaluate `change` and dispatch its results. Returns whether evaluation succeeded;
+    /// failures are reported on the query's error stream.
+    async fn evaluate(
+        &self,
+        source_id: &str,
+        source_change: SourceChange,
+        profiling: Option<crate::profiling::ProfilingMetadata>,
+    ) -> bool {
+        let query_id = self.query_id.as_str();
+        let mut profiling = profiling.unwrap_or_else(crate::profiling::ProfilingMetadata::new);
+        profiling.query_receive_ns = Some(crate::profiling::timestamp_ns());
+        profiling.query_core_call_ns = Some(crate::profiling::timestamp_ns());
+        let evaluation_span = crate::telemetry::start_query_evaluation(
+            &mut profiling,
+            &self.instance_id,
+            query_id,
+            source_id,
+        );
+
+        let pending = self.errors.read().await.track(&source_change);
+        // Wait for a fair share of evaluation time; results are
+        // dispatched after the slot is released
+        let permit = match &self.scheduler {
+            Some(scheduler) => Some(scheduler.acquire(query_id, self.priority).await),
+            None => None,
+        };
+        let evaluation_started = std::time::Instant::now();
+        let outcome = self
+            .continuous_query
+            .process_source_change(source_change)
+            .instrument(evaluation_span)
+            .await;
+        drop(permit);
+        self.metrics()
+            .record_event(source_id, evaluation_started.elapsed());
+        match outcome {
+            Ok(results) => {
+                self.metrics().record_results(results.len());
+                profiling.query_core_return_ns = Some(crate::profiling::timestamp_ns());
+                if !results.is_empty() {
+                    profiling.query_send_ns = Some(crate::profiling::timestamp_ns());
+                    dispatch_query_results(
+                        &results,
+                        source_id,
+                        query_id,
+                        &self.current_results,
+                        &self.dispatchers,
+                        &self.result_processors,
+                        profiling,
+                    )
+                    .await;
+                }
+                true
+            }
+            Err(e) => {
+                error!("Query '{query_id}' failed to process source change: {e}");
+                self.metrics().record_error();
+                self.errors
+                    .write()
+                    .await
+                    .report(query_id, pending, false, &e);
+                false
+            }
+        }
+    }
+
+    fn metrics(&self) -> std::sync::MutexGuard<'_, QueryMetrics> {
+        self.metrics.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
 pub struct DrasiQuery {
     // DrasiLib instance ID for log routing isolation
     instance_id: String,
     // Use QueryBase for common functionality
     base: QueryBase,
-    current_results: Arc<RwLock<Vec<serde_json::Value>>>,
+    current_results: Arc<RwLock<ResultSet>>,
     // Priority queue for ordered event processing
     priority_queue: PriorityQueue,
     // Reference to SourceManager for direct subscription
@@ -275,8 +365,25 @@ pub struct DrasiQuery {
     middleware_registry: Arc<MiddlewareTypeRegistry>,
     // FutureQueueSource for temporal query support
     future_queue_source: Arc<RwLock<Option<Arc<FutureQueueSource>>>>,
+    // Checkpoint store for resuming persistent queries (set by initialize())
+    checkpoint_store: Arc<RwLock<Option<Arc<dyn CheckpointStore>>>>,
+    // The running ContinuousQuery, used for state export/import (set by start())
+    continuous_query: Arc<RwLock<Option<Arc<ContinuousQuery>>>>,
+    // Post-processing applied to result diffs before dispatch (set by initialize())
+    result_processors: Arc<RwLock<Vec<Arc<dyn ResultProcessor>>>>,
+    // Error stream and quarantine for changes that fail evaluation
+    errors: Arc<RwLock<ErrorChannel>>,
+    // Registry the query's metrics are recorded into (set by initialize())
+    metrics: Arc<RwLock<Option<Arc<MetricsRegistry>>>>,
+    // Scheduler sharing evaluation time with other queries (set by initialize())
+    scheduler: Arc<RwLock<Option<Arc<QueryScheduler>>>>,
+    // Worker pool the query's changes are evaluated on (set by initialize())
+    evaluation_pool: Arc<RwLock<Option<Arc<EvaluationPool>>>>,
 }
 
+/// Number of snapshot elements applied per index session during import.
+const IMPORT_BATCH_SIZE: usize = 1000;
+
 impl DrasiQuery {
     pub fn new(
         instance_id: impl Into<String>,
@@ -288,6 +395,12 @@ impl DrasiQuery {
         // Create priority queue with configured capacity (fallback to 10000 if not set)
         let priority_capacity = config.priority_queue_capacity.unwrap_or(10000);
         let priority_queue = PriorityQueue::new(priority_capacity);
+        let current_results = ResultSet::new(
+            config
+                .dispatch_buffer_capacity
+                .unwrap_or(DEFAULT_SUBSCRIPTION_CAPACITY),
+        );
+        let errors = ErrorChannel::new(config.quarantine_capacity);
 
         // Create QueryBase for common functionality
         let base = QueryBase::new(config).context("Failed to create QueryBase")?;
@@ -295,7 +408,7 @@ impl DrasiQuery {
         Ok(Self {
             instance_id: instance_id.into(),
             base,
-            current_results: Arc::new(RwLock::new(Vec::new())),
+            current_results: Arc::new(RwLock::new(current_results)),
             priority_queue,
             source_manager,
             subscription_tasks: Arc::new(RwLock::new(Vec::new())),
@@ -304,6 +417,13 @@ impl DrasiQuery {
             index_factory,
             middleware_registry,
             future_queue_source: Arc::new(RwLock::new(None)),
+            checkpoint_store: Arc::new(RwLock::new(None)),
+            continuous_query: Arc::new(RwLock::new(None)),
+            result_processors: Arc::new(RwLock::new(Vec::new())),
+            errors: Arc::new(RwLock::new(errors)),
+            metrics: Arc::new(RwLock::new(None)),
+            scheduler: Arc::new(RwLock::new(None)),
+            evaluation_pool: Arc::new(RwLock::new(None)),
         })
     }
 
@@ -312,11 +432,168 @@ impl DrasiQuery {
     /// Wires the status handle to the component graph, following the same
     /// pattern as Source and Reaction initialization.
     pub async fn initialize(&self, context: crate::context::QueryRuntimeContext) {
+        *self.checkpoint_store.write().await = context.checkpoint_store.clone();
+        *self.result_processors.write().await = context.result_processors.clone();
+        *self.metrics.write().await = context.metrics.clone();
+        *self.scheduler.write().await = context.scheduler.clone();
+        *self.evaluation_pool.write().await = context.evaluation_pool.clone();
         self.base.initialize(context).await;
     }
 
+    /// The checkpoint store to use for this run, if the query's indexes are persistent.
+    ///
+    /// Checkpoints are meaningless for volatile indexes, which start empty and
+    /// must be re-bootstrapped on every start.
+    async fn active_checkpoint_store(&self) -> Option<Arc<dyn CheckpointStore>> {
+        let backend_ref = self.base.config.storage_backend.as_ref()?;
+        if self.index_factory.is_volatile(backend_ref) {
+            return None;
+        }
+        self.checkpoint_store.read().await.clone()
+    }
+
+    /// Current rows, projected through the query's result processors.
     pub async fn get_current_results(&self) -> Vec<serde_json::Value> {
-        self.current_results.read().await.clone()
+        let processors = self.result_processors.read().await.clone();
+        project_rows(
+            &processors,
+            &self.base.config.id,
+            self.current_results.read().await.rows(),
+        )
+    }
+
+    /// Receive every result diff applied after this call.
+    pub async fn subscribe_results(&self) -> tokio::sync::broadcast::Receiver<ResultDiff> {
+        self.current_results.read().await.subscribe()
+    }
+
+    /// Receive every evaluation error reported after this call.
+    pub async fn subscribe_errors(&self) -> tokio::sync::broadcast::Receiver<QueryEvaluationError> {
+        self.errors.read().await.subscribe()
+    }
+
+    /// Source changes that failed evaluation, oldest first.
+    pub async fn quarantined_changes(&self) -> Vec<QuarantinedChange> {
+        self.errors.read().await.quarantined()
+    }
+
+    /// Remove and return every quarantined change.
+    pub async fn drain_quarantine(&self) -> Vec<QuarantinedChange> {
+        self.errors.write().await.drain_quarantine()
+    }
+
+    /// Snapshot of the current result set together with its sequence number.
+    ///
+    /// Rows are projected through the query's result processors.
+    pub async fn get_results_snapshot(&self) -> QueryResultSnapshot {
+        let processors = self.result_processors.read().await.clone();
+        let mut snapshot = self
+            .current_results
+            .read()
+            .await
+            .snapshot(&self.base.config.id);
+        snapshot.results = project_rows(&processors, &snapshot.query_id, &snapshot.results);
+        snapshot
+    }
+
+    /// Export the query's element index and current results.
+    ///
+    /// Only elements from the query's configured sources are exported; relations
+    /// synthesized by joins are rebuilt by the importing query.
+    pub async fn export_state(&self) -> Result<QueryStateSnapshot> {
+        let continuous_query = self
+            .continuous_query
+            .read()
+            .await
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("Query '{}' is not running", self.base.config.id))?;
+
+        let source_ids: HashSet<&str> = self
+            .base
+            .config
+            .sources
+            .iter()
+            .map(|s| s.source_id.as_str())
+            .collect();
+
+        let mut stream = continuous_query
+            .get_all_elements()
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to read element index: {e:?}"))?;
+        let mut elements = Vec::new();
+        while let Some(element) = stream.next().await {
+            let element =
+                element.map_err(|e| anyhow::anyhow!("Failed to read element index: {e:?}"))?;
+            if source_ids.contains(element.get_reference().source_id.as_ref()) {
+                elements.push(SnapshotElement::from(element.as_ref()));
+            }
+        }
+
+        Ok(QueryStateSnapshot {
+            format_version: QUERY_STATE_FORMAT_VERSION,
+            query_id: self.base.config.id.clone(),
+            query: self.base.config.query.clone(),
+            exported_at: chrono::Utc::now(),
+            elements,
+            results: self.get_results_snapshot().await,
+        })
+    }
+
+    /// Replay the elements of `snapshot` into this query as inserts.
+    ///
+    /// Resulting diffs are applied to the current result set and dispatched to
+    /// reactions like any other change. Returns the number of elements applied.
+    pub async fn import_state(&self, snapshot: &QueryStateSnapshot) -> Result<usize> {
+        let continuous_query = self
+            .continuous_query
+            .read()
+            .await
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("Query '{}' is not running", self.base.config.id))?;
+
+        let mut elements = snapshot
+            .elements
+            .iter()
+            .map(Element::try_from)
+            .collect::<crate::error::Result<Vec<_>>>()?;
+        // Nodes first, so relations find their endpoints already indexed
+        elements.sort_by_key(|e| matches!(e, Element::Relation { .. }));
+
+        let query_id = &self.base.config.id;
+        let result_processors = self.result_processors.read().await.clone();
+        let mut applied = 0;
+        for batch in elements.chunks(IMPORT_BATCH_SIZE) {
+            let changes = batch
+                .iter()
+                .map(|element| SourceChange::Insert {
+                    element: element.clone(),
+                })
+                .collect();
+            let results = continuous_query
+                .process_restored_changes(changes)
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to import snapshot: {e:?}"))?;
+            applied += batch.len();
+
+            if !results.is_empty() {
+                dispatch_query_results(
+                    &results,
+                    "snapshot",
+                    query_id,
+                    &self.current_results,
+                    &self.base.dispatchers,
+                    &result_processors,
+                    crate::profiling::ProfilingMetadata::new(),
+                )
+                .await;
+            }
+        }
+
+        info!(
+            "Query '{query_id}' imported {applied} elements from snapshot of query '{}'",
+            snapshot.query_id
+        );
+        Ok(applied)
     }
 }
 
@@ -497,6 +774,24 @@ impl Query for DrasiQuery {
                 }
             };
 
+        // Load checkpoints so replay-capable sources can resume where this
+        // query left off instead of re-bootstrapping into persistent indexes.
+        let checkpoint_store = self.active_checkpoint_store().await;
+        let checkpoints = match &checkpoint_store {
+            Some(store) => match store.load_query_checkpoints(&self.base.config.id).await {
+                Ok(checkpoints) => checkpoints,
+                Err(e) => {
+                    warn!(
+                        "Query '{}' failed to load checkpoints, starting without them: {}",
+                        self.base.config.id, e
+                    );
+                    HashMap::new()
+                }
+            },
+            None => HashMap::new(),
+        };
+        let mut position_handles: HashMap<String, Arc<AtomicU64>> = HashMap::new();
+
         // Set up FutureQueueSource for temporal query support.
         // This creates a virtual source that polls the future queue and emits
         // FuturesDue control signals, integrating temporal queries into the
@@ -575,7 +870,18 @@ impl Query for DrasiQuery {
             }
         }
 
-        for (source_id, source, settings) in sources_to_subscribe {
+        for (source_id, source, mut settings) in sources_to_subscribe {
+            if checkpoint_store.is_some() && source.supports_replay() {
+                settings.request_position_handle = true;
+                settings.resume_from = checkpoints.get(&source_id).copied();
+                if let Some(sequence) = settings.resume_from {
+                    info!(
+                        "Query '{}' resuming source '{}' from sequence {}",
+                        self.base.config.id, source_id, sequence
+                    );
+                }
+            }
+
             let subscription_response = match source.subscribe(settings.clone()).await {
                 Ok(response) => response,
                 Err(e) => {
@@ -605,6 +911,10 @@ impl Query for DrasiQuery {
                 self.base.config.id, source_id
             );
 
+            if let Some(handle) = subscription_response.position_handle {
+                position_handles.insert(source_id.clone(), handle);
+            }
+
             // Store bootstrap channel if provided
             // Also initialize bootstrap state only for sources that support bootstrap
             if let Some(bootstrap_rx) = subscription_response.bootstrap_receiver {
@@ -624,8 +934,7 @@ impl Query for DrasiQuery {
 
             // Get source dispatch mode to determine enqueue strategy
             let dispatch_mode = source.dispatch_mode();
-            let use_blocking_enqueue =
-                matches!(dispatch_mode, crate::channels::DispatchMode::Channel);
+            let use_blocking_enqueue = dispatch_mode.is_dedicated();
 
             let span = tracing::info_span!(
                 "query_source_forwarder",
@@ -640,20 +949,22 @@ impl Query for DrasiQuery {
                     );
 
                     loop {
-                        match receiver.recv().await {
-                            Ok(arc_event) => {
+                        match receiver.recv_batch().await {
+                            Ok(events) => {
                                 // Use appropriate enqueue method based on dispatch mode
                                 if use_blocking_enqueue {
-                                    // Channel mode: Use blocking enqueue to prevent message loss
+                                    // Channel and batched modes: Use blocking enqueue to prevent message loss
                                     // This creates backpressure when the priority queue is full
-                                    priority_queue.enqueue_wait(arc_event).await;
+                                    priority_queue.enqueue_batch_wait(events).await;
                                 } else {
                                     // Broadcast mode: Use non-blocking enqueue to prevent deadlock
                                     // Messages may be dropped when priority queue is full
-                                    if !priority_queue.enqueue(arc_event).await {
-                                        warn!(
-                                            "Query '{query_id}' priority queue at capacity, dropping event from source '{source_id_clone}' (broadcast mode)"
-                                        );
+                                    for arc_event in events {
+                                        if !priority_queue.enqueue(arc_event).await {
+                                            warn!(
+                                                "Query '{query_id}' priority queue at capacity, dropping event from source '{source_id_clone}' (broadcast mode)"
+                                            );
+                                        }
                                     }
                                 }
                             }
@@ -682,6 +993,14 @@ impl Query for DrasiQuery {
 
         // Wrap continuous_query in Arc for sharing across tasks
         let continuous_query = Arc::new(continuous_query);
+        *self.continuous_query.write().await = Some(continuous_query.clone());
+
+        let registry = self.metrics.read().await.clone().unwrap_or_default();
+        let query_metrics = QueryMetrics::new(registry, &self.base.config.id);
+        query_metrics.register_queues(
+            self.priority_queue.metrics_handle(),
+            self.base.dispatchers.clone(),
+        );
 
         // Gate that blocks the streaming event processor until bootstrap completes.
         // Events buffer safely in the priority queue during bootstrap.
@@ -727,6 +1046,9 @@ impl Query for DrasiQuery {
             let bootstrap_state = self.bootstrap_state.clone();
             let instance_id = self.instance_id.clone();
             let bootstrap_current_results = self.current_results.clone();
+            let bootstrap_result_processors = self.result_processors.read().await.clone();
+            let bootstrap_errors = self.errors.clone();
+            let bootstrap_started = std::time::Instant::now();
 
             let mut bootstrap_handles = Vec::new();
             let mut abort_handles = Vec::new();
@@ -749,7 +1071,11 @@ impl Query for DrasiQuery {
                 let base_dispatchers_clone = base_dispatchers.clone();
                 let instance_id_clone = instance_id.clone();
                 let current_results_clone = bootstrap_current_results.clone();
+                let result_processors_clone = bootstrap_result_processors.clone();
                 let bootstrap_gate_clone = bootstrap_gate.clone();
+                let errors_clone = bootstrap_errors.clone();
+                let metrics_clone = query_metrics.clone();
+                let bootstrap_events = query_metrics.bootstrap_events(&source_id);
 
                 let span = tracing::info_span!(
                     "query_bootstrap",
@@ -763,8 +1089,10 @@ impl Query for DrasiQuery {
 
                         while let Some(bootstrap_event) = bootstrap_rx.recv().await {
                             count += 1;
+                            bootstrap_events.inc();
 
                             // Process bootstrap change through ContinuousQuery
+                            let pending = errors_clone.read().await.track(&bootstrap_event.change);
                             match continuous_query_ref
                                 .process_source_change(bootstrap_event.change)
                                 .await
@@ -778,50 +1106,26 @@ impl Query for DrasiQuery {
 
                                         // Apply bootstrap results to current_results so they
                                         // are visible via the query results API.
-                                        let mut result_set = current_results_clone.write().await;
-                                        for ctx in &results {
-                                            match ctx {
-                                                QueryPartEvaluationContext::Adding { after, .. } => {
-                                                    result_set.push(convert_query_variables_to_json(after));
-                                                }
-                                                QueryPartEvaluationContext::Removing { before, .. } => {
-                                                    let data = convert_query_variables_to_json(before);
-                                                    result_set.retain(|item| item != &data);
-                                                }
-                                                QueryPartEvaluationContext::Updating { before, after, .. } => {
-                                                    let before_json = convert_query_variables_to_json(before);
-                                                    let after_json = convert_query_variables_to_json(after);
-                                                    if let Some(pos) = result_set.iter().position(|item| item == &before_json) {
-                                                        result_set[pos] = after_json;
-                                                    } else {
-                                                        result_set.retain(|item| item != &before_json);
-                                                        result_set.push(after_json);
-                                                    }
-                                                }
-                                                QueryPartEvaluationContext::Aggregation { before, after, .. } => {
-                                                    let after_json = convert_query_variables_to_json(after);
-                                                    if let Some(before) = before {
-                                                        let before_json = convert_query_variables_to_json(before);
-                                                        if let Some(pos) = result_set.iter().position(|item| item == &before_json) {
-                                                            result_set[pos] = after_json;
-                                                        } else {
-                                                            result_set.retain(|item| item != &before_json);
-                                                            result_set.push(after_json);
-                                                        }
-                                                    } else {
-                                                        result_set.push(after_json);
-                                                    }
-                                                }
-                                                QueryPartEvaluationContext::Noop => {}
-                                            }
-                                        }
-                                        drop(result_set);
+                                        let mut current_results =
+                                            current_results_clone.write().await;
+                                        let diffs = convert_results_to_diffs(&results);
+                                        current_results.apply_rows(&diffs);
+                                        current_results.publish(&apply_result_processors(
+                                            &result_processors_clone,
+                                            &query_id_clone,
+                                            diffs,
+                                        ));
                                     }
                                 }
                                 Err(e) => {
                                     error!(
                                         "[BOOTSTRAP] Query '{query_id_clone}' failed to process bootstrap event from source '{source_id_clone}': {e}"
                                     );
+                                    metrics_clone.record_error();
+                                    errors_clone
+                                        .write()
+                                        .await
+                                        .report(&query_id_clone, pending, true, &e);
                                 }
                             }
                         }
@@ -844,6 +1148,7 @@ impl Query for DrasiQuery {
                             info!(
                                 "[BOOTSTRAP] Query '{query_id_clone}' all sources completed bootstrap"
                             );
+                            metrics_clone.record_bootstrap_completed(bootstrap_started.elapsed());
 
                             // Emit bootstrapCompleted control signal
                             let mut metadata = HashMap::new();
@@ -959,11 +1264,52 @@ impl Query for DrasiQuery {
         let base_dispatchers = self.base.dispatchers.clone();
         let query_id = self.base.config.id.clone();
         let current_results = self.current_results.clone();
+        let result_processors = self.result_processors.read().await.clone();
         let task_handle_clone = self.base.task_handle.clone();
         let priority_queue = self.priority_queue.clone();
+        let scheduler = self.scheduler.read().await.clone();
+        let priority = self.base.config.priority;
         let instance_id = self.instance_id.clone();
+        let in_flight_gauge = query_metrics.clone();
+        let evaluator = Arc::new(ChangeEvaluator {
+            continuous_query: continuous_query.clone(),
+            query_id: query_id.clone(),
+            instance_id: instance_id.clone(),
+            current_results: current_results.clone(),
+            dispatchers: base_dispatchers.clone(),
+            result_processors: result_processors.clone(),
+            errors: self.errors.clone(),
+            metrics: std::sync::Mutex::new(query_metrics),
+            scheduler: scheduler.clone(),
+            priority,
+        });
+        // Checkpoints must be saved in sequence order, so checkpointed queries
+        // evaluate on their processor task. The others hand changes to the pool,
+        // keyed by ordering key where results of different elements are independent.
+        let evaluation_pool = match self.evaluation_pool.read().await.clone() {
+            Some(pool) if checkpoint_store.is_none() => Some(pool),
+            _ => None,
+        };
+        let element_local = evaluation_pool.is_some() && continuous_query.is_element_local();
+        if let Some(pool) = &evaluation_pool {
+            info!(
+                "Query '{}' evaluating on {} pool workers, partitioned by {}",
+                self.base.config.id,
+                pool.workers(),
+                if element_local {
+                    "ordering key"
+                } else {
+                    "query"
+                }
+            );
+        }
         let reporter_for_processor = self.base.status_handle();
         let fq_source_for_processor = Arc::clone(&future_queue_source);
+        // Dedup is only needed when resuming from checkpoints: replayed events at
+        // or below the checkpoint have already been applied to the indexes.
+        let mut dedup = checkpoint_store
+            .as_ref()
+            .map(|_| crate::queries::SequenceDedup::new(checkpoints));
 
         // Create shutdown channel for graceful termination
         let (shutdown_tx, mut shutdown_rx) = tokio::sync::oneshot::channel::<()>();
@@ -1028,6 +1374,11 @@ impl Query for DrasiQuery {
 
                 info!("Query '{query_id}' starting priority queue event processor");
 
+                // Completion signals of changes handed to the evaluation pool
+                let mut pooled = futures::stream::FuturesUnordered::new();
+                // Sources whose changes carried their own ordering key
+                let mut keyed_sources = HashSet::new();
+
                 loop {
                     // Check if query is still running
                     let current_status = reporter_for_processor.get_status().await;
@@ -1048,10 +1399,15 @@ impl Query for DrasiQuery {
                             break;
                         }
 
+                        // Reap finished pool evaluations
+                        Some(_) = pooled.next(), if !pooled.is_empty() => {}
+
                         // Dequeue events from priority queue (blocks until available)
                         arc_event = priority_queue.dequeue() => {
+                            let in_flight = in_flight_gauge.start_event();
+                            let ordering_key = arc_event.ordering_key.clone();
                             // Try to extract without cloning if we have sole ownership (zero-copy path).
-                            let (source_id, event, _timestamp, profiling_opt, _sequence) =
+                            let (source_id, event, _timestamp, profiling_opt, sequence) =
                                 match SourceEventWrapper::try_unwrap_arc(arc_event) {
                                     Ok(parts) => parts,
                                     Err(arc) => {
@@ -1069,9 +1425,19 @@ impl Query for DrasiQuery {
 
                             match event {
                                 SourceEvent::Control(SourceControl::FuturesDue) => {
+                                    // Due futures act on elements, so changes queued
+                                    // before them must be applied first
+                                    while pooled.next().await.is_some() {}
+
                                     // Drain all due futures atomically within sessions
                                     loop {
-                                        match continuous_query_for_processor.process_due_futures().await {
+                                        let permit = match &scheduler {
+                                            Some(scheduler) => Some(scheduler.acquire(&query_id, priority).await),
+                                            None => None,
+                                        };
+                                        let due = continuous_query_for_processor.process_due_futures().await;
+                                        drop(permit);
+                                        match due {
                                             Ok(Some(due_result)) => {
                                                 if !due_result.results.is_empty() {
                                                     let profiling = crate::profiling::ProfilingMetadata::new();
@@ -1081,6 +1447,7 @@ impl Query for DrasiQuery {
                                                         &query_id,
                                                         &current_results,
                                                         &base_dispatchers,
+                                                        &result_processors,
                                                         profiling,
                                                     )
                                                     .await;
@@ -1096,32 +1463,67 @@ impl Query for DrasiQuery {
                                     continue;
                                 }
                                 SourceEvent::Change(source_change) => {
-                                    let mut profiling =
-                                        profiling_opt.unwrap_or_else(crate::profiling::ProfilingMetadata::new);
-                                    profiling.query_receive_ns = Some(crate::profiling::timestamp_ns());
-                                    profiling.query_core_call_ns = Some(crate::profiling::timestamp_ns());
-
-                                    match continuous_query_for_processor
-                                        .process_source_change(source_change)
-                                        .await
+                                    if dedup
+                                        .as_ref()
+                                        .is_some_and(|d| d.should_skip(&source_id, sequence))
                                     {
-                                        Ok(results) => {
-                                            profiling.query_core_return_ns = Some(crate::profiling::timestamp_ns());
-                                            if !results.is_empty() {
-                                                profiling.query_send_ns = Some(crate::profiling::timestamp_ns());
-                                                dispatch_query_results(
-                                                    &results,
-                                                    &source_id,
-                                                    &query_id,
-                                                    &current_results,
-                                                    &base_dispatchers,
-                                                    profiling,
-                                                )
-                                                .await;
-                                            }
+                                        debug!(
+                                            "Query '{query_id}' skipping already-processed event from source '{source_id}' (sequence {sequence:?})"
+                                        );
+                                        continue;
+                                    }
+
+                                    if let Some(pool) = &evaluation_pool {
+                                        let reference = source_change.get_reference();
+                                        if ordering_key.is_some() {
+                                            keyed_sources.insert(reference.source_id.clone());
+                                        } else if element_local
+                                            && matches!(source_change, SourceChange::Future { .. })
+                                            && keyed_sources.contains(&reference.source_id)
+                                        {
+                                            // Futures are keyed by element id, which need not
+                                            // match the lane of the element's own changes
+                                            while pooled.next().await.is_some() {}
                                         }
-                                        Err(e) => {
-                                            error!("Query '{query_id}' failed to process source change: {e}");
+
+                                        // Changes sharing an ordering key, or of the whole
+                                        // query, share a lane and so keep their order
+                                        let key = element_local.then(|| {
+                                            let ordering_key = ordering_key.unwrap_or_else(|| {
+                                                default_ordering_key(&source_change)
+                                            });
+                                            (reference.source_id.clone(), ordering_key)
+                                        });
+                                        let evaluator = evaluator.clone();
+                                        let done = pool
+                                            .submit(&(query_id.as_str(), key), async move {
+                                                let _in_flight = in_flight;
+                                                evaluator
+                                                    .evaluate(&source_id, source_change, profiling_opt)
+                                                    .await;
+                                            })
+                                            .await;
+                                        pooled.push(done);
+                                        continue;
+                                    }
+
+                                    let _in_flight = in_flight;
+                                    let succeeded = evaluator
+                                        .evaluate(&source_id, source_change, profiling_opt)
+                                        .await;
+                                    if let (true, Some(dedup), Some(store), Some(sequence)) =
+                                        (succeeded, dedup.as_mut(), checkpoint_store.as_ref(), sequence)
+                                    {
+                                        dedup.advance(&source_id, sequence);
+                                        if let Err(e) = store
+                                            .save_query_checkpoint(&query_id, &source_id, sequence)
+                                            .await
+                                        {
+                                            warn!(
+                                                "Query '{query_id}' failed to save checkpoint for source '{source_id}': {e}"
+                                            );
+                                        } else if let Some(handle) = position_handles.get(&source_id) {
+                                            handle.store(sequence, Ordering::Release);
                                         }
                                     }
                                 }
@@ -1134,6 +1536,9 @@ impl Query for DrasiQuery {
                     }
                 }
 
+                // Let changes already handed to the pool finish before the query stops
+                while pooled.next().await.is_some() {}
+
                 fq_source_for_processor.stop().await;
 
             info!("Query '{query_id}' processing task exited");
@@ -1194,6 +1599,7 @@ impl Query for DrasiQuery {
         if let Some(fq) = self.future_queue_source.write().await.take() {
             fq.stop().await;
         }
+        *self.continuous_query.write().await = None;
 
         // Use QueryBase common stop behavior to finish shutting down the processor task
         self.base.stop_common().await?;
@@ -1239,6 +1645,15 @@ pub struct QueryManager {
     index_factory: Arc<crate::indexes::IndexFactory>,
     middleware_registry: Arc<MiddlewareTypeRegistry>,
     log_registry: Arc<ComponentLogRegistry>,
+    checkpoint_store: Arc<RwLock<Option<Arc<dyn CheckpointStore>>>>,
+    /// Result processors by name, resolved when a query is provisioned.
+    result_processors: Arc<RwLock<HashMap<String, Arc<dyn ResultProcessor>>>>,
+    /// Metrics registry passed to queries
+    metrics: Arc<RwLock<Option<Arc<MetricsRegistry>>>>,
+    /// Scheduler shared by all queries, if evaluation concurrency is limited
+    scheduler: Arc<RwLock<Option<Arc<QueryScheduler>>>>,
+    /// Worker pool shared by all queries, if evaluation runs on a pool
+    evaluation_pool: Arc<RwLock<Option<Arc<EvaluationPool>>>>,
     /// Shared component graph — the single source of truth for component metadata,
     /// state, relationships, runtime instances, AND event history.
     graph: Arc<RwLock<ComponentGraph>>,
@@ -1264,11 +1679,80 @@ impl QueryManager {
             index_factory,
             middleware_registry,
             log_registry,
+            checkpoint_store: Arc::new(RwLock::new(None)),
+            result_processors: Arc::new(RwLock::new(HashMap::new())),
+            metrics: Arc::new(RwLock::new(None)),
+            scheduler: Arc::new(RwLock::new(None)),
+            evaluation_pool: Arc::new(RwLock::new(None)),
             graph,
             update_tx,
         }
     }
 
+    /// Inject the checkpoint store (called after DrasiLib is fully constructed)
+    ///
+    /// Queries with a persistent storage backend use it to resume from their
+    /// last processed source sequences after a restart.
+    pub async fn inject_checkpoint_store(&self, checkpoint_store: Arc<dyn CheckpointStore>) {
+        *self.checkpoint_store.write().await = Some(checkpoint_store);
+    }
+
+    /// Inject the metrics registry (called after DrasiLib is fully constructed)
+    ///
+    /// Queries provisioned afterwards record their metrics into it.
+    pub async fn inject_metrics(&self, metrics: Arc<MetricsRegistry>) {
+        *self.metrics.write().await = Some(metrics);
+    }
+
+    /// Inject the evaluation scheduler (called after DrasiLib is fully constructed)
+    ///
+    /// Queries provisioned afterwards share evaluation slots through it.
+    pub async fn inject_scheduler(&self, scheduler: Arc<QueryScheduler>) {
+        *self.scheduler.write().await = Some(scheduler);
+    }
+
+    /// Inject the evaluation worker pool (called after DrasiLib is fully constructed)
+    ///
+    /// Queries provisioned afterwards evaluate their changes on its workers.
+    pub async fn inject_evaluation_pool(&self, pool: Arc<EvaluationPool>) {
+        *self.evaluation_pool.write().await = Some(pool);
+    }
+
+    /// Register a result processor that queries can reference by name.
+    ///
+    /// A processor registered under an existing name replaces it for queries
+    /// provisioned afterwards.
+    pub async fn register_result_processor(
+        &self,
+        name: impl Into<String>,
+        processor: Arc<dyn ResultProcessor>,
+    ) {
+        self.result_processors
+            .write()
+            .await
+            .insert(name.into(), processor);
+    }
+
+    /// Resolve the result processors named in a query config, in order.
+    async fn resolve_result_processors(
+        &self,
+        config: &QueryConfig,
+    ) -> Result<Vec<Arc<dyn ResultProcessor>>> {
+        let registered = self.result_processors.read().await;
+        config
+            .result_processors
+            .iter()
+            .map(|name| {
+                registered.get(name).cloned().ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Query '{}' references unknown result processor '{name}'",
+                        config.id
+                    )
+                })
+            })
+            .collect()
+    }
+
     /// Register and provision a new query from the given configuration.
     ///
     /// # Errors
@@ -1300,6 +1784,8 @@ impl QueryManager {
     /// Graph registration (node creation, dependency edges) must be done by the caller
     /// beforehand via `ComponentGraph::register_query()`.
     pub async fn provision_query(&self, config: QueryConfig) -> Result<()> {
+        let result_processors = self.resolve_result_processors(&config).await?;
+
         // Create the query instance
         let query = DrasiQuery::new(
             &self.instance_id,
@@ -1310,11 +1796,24 @@ impl QueryManager {
         )?;
 
         // Wire status handle to graph via context (same pattern as Source/Reaction)
-        let context = crate::context::QueryRuntimeContext::new(
+        let mut context = crate::context::QueryRuntimeContext::new(
             &self.instance_id,
             &config.id,
             self.update_tx.clone(),
-        );
+        )
+        .with_result_processors(result_processors);
+        if let Some(checkpoint_store) = self.checkpoint_store.read().await.clone() {
+            context = context.with_checkpoint_store(checkpoint_store);
+        }
+        if let Some(metrics) = self.metrics.read().await.clone() {
+            context = context.with_metrics(metrics);
+        }
+        if let Some(scheduler) = self.scheduler.read().await.clone() {
+            context = context.with_scheduler(scheduler);
+        }
+        if let Some(pool) = self.evaluation_pool.read().await.clone() {
+            context = context.with_evaluation_pool(pool);
+        }
         query.initialize(context).await;
 
         let query: Arc<dyn Query> = Arc::new(query);
@@ -1478,7 +1977,21 @@ impl QueryManager {
             false,
             || async {},
         )
-        .await
+        .await?;
+
+        // Stale checkpoints would make a re-added query skip events it never saw.
+        if let Some(store) = self.checkpoint_store.read().await.clone() {
+            if let Err(e) = store.clear_query_checkpoints(&id).await {
+                warn!("Failed to clear checkpoints for query '{id}': {e}");
+            }
+        }
+        if let Some(metrics) = self.metrics.read().await.as_ref() {
+            metrics.remove_series("query", &id);
+        }
+        if let Some(scheduler) = self.scheduler.read().await.as_ref() {
+            scheduler.forget(&id);
+        }
+        Ok(())
     }
 
     /// List all registered queries with their current lifecycle status.
@@ -1495,6 +2008,76 @@ impl QueryManager {
     }
 
     pub async fn get_query_results(&self, id: &str) -> Result<Vec<serde_json::Value>> {
+        Ok(self.get_query_results_snapshot(id).await?.results)
+    }
+
+    /// Subscribe to the result diffs of a query.
+    ///
+    /// Unlike [`get_query_results_snapshot`](Self::get_query_results_snapshot) the
+    /// query does not need to be running; the receiver stays open across restarts
+    /// and closes when the query is removed.
+    pub async fn subscribe_to_query(
+        &self,
+        id: &str,
+    ) -> Result<tokio::sync::broadcast::Receiver<ResultDiff>> {
+        let query = {
+            let graph = self.graph.read().await;
+            graph.get_runtime::<Arc<dyn Query>>(id).cloned()
+        };
+
+        let Some(query) = query else {
+            return Err(crate::managers::ComponentNotFoundError::new("query", id).into());
+        };
+
+        let drasi_query = query
+            .as_any()
+            .downcast_ref::<DrasiQuery>()
+            .ok_or_else(|| anyhow::anyhow!("Internal error: invalid query type"))?;
+
+        Ok(drasi_query.subscribe_results().await)
+    }
+
+    /// Subscribe to the evaluation errors of a query.
+    ///
+    /// Like [`subscribe_to_query`](Self::subscribe_to_query), the query does not
+    /// need to be running and the receiver closes when the query is removed.
+    pub async fn subscribe_query_errors(
+        &self,
+        id: &str,
+    ) -> Result<tokio::sync::broadcast::Receiver<QueryEvaluationError>> {
+        let query = self.get_query_runtime(id).await?;
+        Ok(Self::as_drasi_query(&query)?.subscribe_errors().await)
+    }
+
+    /// Source changes a query failed to evaluate and kept in quarantine.
+    pub async fn quarantined_changes(&self, id: &str) -> Result<Vec<QuarantinedChange>> {
+        let query = self.get_query_runtime(id).await?;
+        Ok(Self::as_drasi_query(&query)?.quarantined_changes().await)
+    }
+
+    /// Remove and return every change in a query's quarantine.
+    pub async fn drain_quarantine(&self, id: &str) -> Result<Vec<QuarantinedChange>> {
+        let query = self.get_query_runtime(id).await?;
+        Ok(Self::as_drasi_query(&query)?.drain_quarantine().await)
+    }
+
+    async fn get_query_runtime(&self, id: &str) -> Result<Arc<dyn Query>> {
+        let graph = self.graph.read().await;
+        graph
+            .get_runtime::<Arc<dyn Query>>(id)
+            .cloned()
+            .ok_or_else(|| crate::managers::ComponentNotFoundError::new("query", id).into())
+    }
+
+    fn as_drasi_query(query: &Arc<dyn Query>) -> Result<&DrasiQuery> {
+        query
+            .as_any()
+            .downcast_ref::<DrasiQuery>()
+            .ok_or_else(|| anyhow::anyhow!("Internal error: invalid query type"))
+    }
+
+    /// Get the current result set of a running query along with its sequence metadata.
+    pub async fn get_query_results_snapshot(&self, id: &str) -> Result<QueryResultSnapshot> {
         let query = {
             let graph = self.graph.read().await;
             graph.get_runtime::<Arc<dyn Query>>(id).cloned()
@@ -1507,19 +2090,57 @@ impl QueryManager {
                 return Err(anyhow::anyhow!("Query '{id}' is not running"));
             }
 
-            // Downcast to DrasiQuery to access get_current_results
+            // Downcast to DrasiQuery to access the materialized result set
             // Since all queries are DrasiQuery instances, this is safe
             let drasi_query = query
                 .as_any()
                 .downcast_ref::<DrasiQuery>()
                 .ok_or_else(|| anyhow::anyhow!("Internal error: invalid query type"))?;
 
-            Ok(drasi_query.get_current_results().await)
+            Ok(drasi_query.get_results_snapshot().await)
         } else {
             Err(crate::managers::ComponentNotFoundError::new("query", id).into())
         }
     }
 
+    /// Export the full state of a running query.
+    pub async fn export_query_state(&self, id: &str) -> Result<QueryStateSnapshot> {
+        let query = self.get_running_query(id).await?;
+        let drasi_query = query
+            .as_any()
+            .downcast_ref::<DrasiQuery>()
+            .ok_or_else(|| anyhow::anyhow!("Internal error: invalid query type"))?;
+        drasi_query.export_state().await
+    }
+
+    /// Import a previously exported state into a running query.
+    pub async fn import_query_state(
+        &self,
+        id: &str,
+        snapshot: &QueryStateSnapshot,
+    ) -> Result<usize> {
+        let query = self.get_running_query(id).await?;
+        let drasi_query = query
+            .as_any()
+            .downcast_ref::<DrasiQuery>()
+            .ok_or_else(|| anyhow::anyhow!("Internal error: invalid query type"))?;
+        drasi_query.import_state(snapshot).await
+    }
+
+    async fn get_running_query(&self, id: &str) -> Result<Arc<dyn Query>> {
+        let query = {
+            let graph = self.graph.read().await;
+            graph.get_runtime::<Arc<dyn Query>>(id).cloned()
+        };
+        let Some(query) = query else {
+            return Err(crate::managers::ComponentNotFoundError::new("query", id).into());
+        };
+        if query.status().await != ComponentStatus::Running {
+            return Err(anyhow::anyhow!("Query '{id}' is not running"));
+        }
+        Ok(query)
+    }
+
     /// Start all queries that are configured for auto-start.
     ///
     /// # Errors
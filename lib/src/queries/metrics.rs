@@ -0,0 +1,212 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Metric handles recorded by a running [`DrasiQuery`](super::DrasiQuery).
+
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::RwLock;
+
+use crate::channels::{ChangeDispatcher, PriorityQueueMetrics, QueryResult};
+use crate::metrics::{Counter, Gauge, Histogram, MetricsRegistry};
+
+/// Handles for the `drasi_query_*` metrics of one query.
+///
+/// Cloning is cheap and clones record into the same series.
+#[derive(Clone)]
+pub(crate) struct QueryMetrics {
+    registry: Arc<MetricsRegistry>,
+    query_id: String,
+    evaluation: Histogram,
+    results: Counter,
+    errors: Counter,
+    bootstrap_seconds: Gauge,
+    in_flight: Gauge,
+    events: HashMap<String, Counter>,
+}
+
+/// Marks an event as in flight until dropped; see [`QueryMetrics::start_event`].
+pub(crate) struct InFlightGuard(Gauge);
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.0.dec();
+    }
+}
+
+impl QueryMetrics {
+    pub(crate) fn new(registry: Arc<MetricsRegistry>, query_id: &str) -> Self {
+        let labels = [("query", query_id)];
+        Self {
+            evaluation: registry.histogram(
+                "drasi_query_evaluation_seconds",
+                "Time spent evaluating one source change",
+                &labels,
+            ),
+            results: registry.counter(
+                "drasi_query_results_total",
+                "Result diffs produced",
+                &labels,
+            ),
+            errors: registry.counter(
+                "drasi_query_errors_total",
+                "Source changes that failed evaluation",
+                &labels,
+            ),
+            bootstrap_seconds: registry.gauge(
+                "drasi_query_bootstrap_seconds",
+                "Duration of the last completed bootstrap",
+                &labels,
+            ),
+            in_flight: registry.gauge(
+                "drasi_query_in_flight",
+                "Events dequeued but not yet evaluated and dispatched",
+                &labels,
+            ),
+            events: HashMap::new(),
+            query_id: query_id.to_string(),
+            registry,
+        }
+    }
+
+    /// Export the depth of the query's priority queue and dispatch channels.
+    pub(crate) fn register_queues(
+        &self,
+        priority_queue: Arc<PriorityQueueMetrics>,
+        dispatchers: Arc<RwLock<Vec<Box<dyn ChangeDispatcher<QueryResult> + Send + Sync>>>>,
+    ) {
+        let labels = [("query", self.query_id.as_str())];
+        let depth = priority_queue.clone();
+        self.registry.gauge_fn(
+            "drasi_query_queue_depth",
+            "Events waiting in the query's priority queue",
+            &labels,
+            move || Some(depth.current_depth.load(Ordering::Relaxed) as f64),
+        );
+        self.registry.counter_fn(
+            "drasi_query_queue_dropped_total",
+            "Events dropped because the query's priority queue was full",
+            &labels,
+            move || Some(priority_queue.drops_due_to_capacity.load(Ordering::Relaxed) as f64),
+        );
+        self.registry.gauge_fn(
+            "drasi_query_dispatch_queue_depth",
+            "Results waiting in the query's dispatch channels",
+            &labels,
+            move || {
+                // Skip the sample rather than block while subscribers are being added
+                let dispatchers = dispatchers.try_read().ok()?;
+                Some(dispatchers.iter().map(|d| d.queue_depth()).sum::<usize>() as f64)
+            },
+        );
+    }
+
+    /// Count a dequeued event as in flight until the returned guard is dropped.
+    pub(crate) fn start_event(&self) -> InFlightGuard {
+        self.in_flight.inc();
+        InFlightGuard(self.in_flight.clone())
+    }
+
+    /// Record a processed source change from `source_id`.
+    pub(crate) fn record_event(&mut self, source_id: &str, evaluation: Duration) {
+        if !self.events.contains_key(source_id) {
+            let counter = self.registry.counter(
+                "drasi_query_events_total",
+                "Source changes processed",
+                &[("query", self.query_id.as_str()), ("source", source_id)],
+            );
+            self.events.insert(source_id.to_string(), counter);
+        }
+        self.events[source_id].inc();
+        self.evaluation.observe_duration(evaluation);
+    }
+
+    pub(crate) fn record_results(&self, count: usize) {
+        self.results.inc_by(count as u64);
+    }
+
+    pub(crate) fn record_error(&self) {
+        self.errors.inc();
+    }
+
+    /// Counter for the bootstrap changes received from `source_id`.
+    pub(crate) fn bootstrap_events(&self, source_id: &str) -> Counter {
+        self.registry.counter(
+            "drasi_query_bootstrap_events_total",
+            "Bootstrap changes processed",
+            &[("query", self.query_id.as_str()), ("source", source_id)],
+        )
+    }
+
+    pub(crate) fn record_bootstrap_completed(&self, duration: Duration) {
+        self.bootstrap_seconds.set(duration.as_secs_f64());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_records_per_source_events() {
+        let registry = Arc::new(MetricsRegistry::new());
+        let mut metrics = QueryMetrics::new(registry.clone(), "q1");
+
+        metrics.record_event("s1", Duration::from_millis(2));
+        metrics.record_event("s1", Duration::from_millis(3));
+        metrics.record_event("s2", Duration::from_millis(1));
+        metrics.record_results(4);
+        metrics.record_error();
+
+        let q1_s1 = [("query", "q1"), ("source", "s1")];
+        assert_eq!(
+            registry.sample("drasi_query_events_total", &q1_s1),
+            Some(2.0)
+        );
+        assert_eq!(
+            registry.sample(
+                "drasi_query_events_total",
+                &[("query", "q1"), ("source", "s2")]
+            ),
+            Some(1.0)
+        );
+        assert_eq!(
+            registry.sample("drasi_query_evaluation_seconds", &[("query", "q1")]),
+            Some(3.0)
+        );
+        assert_eq!(
+            registry.sample("drasi_query_results_total", &[("query", "q1")]),
+            Some(4.0)
+        );
+        assert_eq!(
+            registry.sample("drasi_query_errors_total", &[("query", "q1")]),
+            Some(1.0)
+        );
+    }
+
+    #[test]
+    fn test_in_flight_guard() {
+        let registry = Arc::new(MetricsRegistry::new());
+        let metrics = QueryMetrics::new(registry.clone(), "q1");
+        let labels = [("query", "q1")];
+
+        let guard = metrics.start_event();
+        assert_eq!(registry.sample("drasi_query_in_flight", &labels), Some(1.0));
+        drop(guard);
+        assert_eq!(registry.sample("drasi_query_in_flight", &labels), Some(0.0));
+    }
+}
@@ -14,11 +14,19 @@
 
 pub mod base;
 pub mod config_hash;
+pub mod error_channel;
 pub mod label_extractor;
 pub mod manager;
+mod metrics;
+pub mod pool;
 pub mod priority_queue;
+pub mod result_processor;
+pub mod result_set;
+pub mod scheduler;
 pub mod sequence_dedup;
+pub mod state_snapshot;
 pub mod subscription_builder;
+pub mod typed;
 
 #[cfg(test)]
 mod tests;
@@ -28,8 +36,21 @@ mod joins_test;
 
 pub use base::QueryBase;
 pub use config_hash::compute_config_hash;
+pub(crate) use error_channel::ErrorChannel;
+pub use error_channel::{QuarantinedChange, QueryEvaluationError};
 pub use label_extractor::*;
 pub use manager::*;
+pub use pool::EvaluationPool;
 pub use priority_queue::*;
+pub use result_processor::{RedactFields, ResultProcessor, RoundFloats, SuppressUnchangedUpdates};
+pub use result_set::QueryResultSnapshot;
+pub(crate) use result_set::ResultSet;
+pub use scheduler::{EvaluationPermit, QueryPriority, QueryScheduler};
 pub use sequence_dedup::SequenceDedup;
+pub use state_snapshot::{
+    QueryStateSnapshot, SnapshotElement, SnapshotElementRef, QUERY_STATE_FORMAT_VERSION,
+};
 pub use subscription_builder::*;
+pub use typed::{
+    decode_row, ResultDecodeError, TypedRecvError, TypedResultDiff, TypedSubscription,
+};
@@ -0,0 +1,202 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Worker pool for query evaluation.
+//!
+//! Without a pool, each query evaluates its source changes one after another
+//! on its processor task: the next change is not dequeued until the results
+//! of the previous one have been dispatched to every subscriber.
+//!
+//! [`EvaluationPool`] runs evaluations on a fixed set of workers instead. Each
+//! worker owns a lane that runs its jobs in submission order, and a job goes
+//! to the lane its key hashes to. Jobs with the same key therefore never
+//! overtake each other, while jobs with different keys run in parallel.
+//!
+//! Queries key their changes by query id, so independent queries share the
+//! workers, evaluate in parallel, and each query keeps its order. Queries whose
+//! result rows each derive from a single element (see
+//! [`ContinuousQuery::is_element_local`](drasi_core::query::ContinuousQuery::is_element_local))
+//! key by element id instead, and changes to one element stay in order.
+//!
+//! Within one query, evaluation still runs one change at a time:
+//! [`ContinuousQuery::process_source_change`](drasi_core::query::ContinuousQuery::process_source_change)
+//! holds the query's change lock while it updates the indexes. Per-element keys
+//! only let the results of one change be dispatched while the query evaluates
+//! changes to other elements. Partitioning a query's indexes so that its
+//! changes evaluate concurrently is not implemented.
+
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::{BuildHasher, Hash};
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+
+use futures::FutureExt;
+use log::error;
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::JoinHandle;
+
+type Job = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Jobs each lane buffers before [`EvaluationPool::submit`] waits for room.
+const LANE_CAPACITY: usize = 256;
+
+/// Fixed set of workers running evaluation jobs in order per key, in parallel across keys.
+///
+/// Shared by all queries of a [`QueryManager`](crate::queries::QueryManager).
+/// The workers stop when the pool is dropped.
+#[derive(Debug)]
+pub struct EvaluationPool {
+    lanes: Vec<mpsc::Sender<Job>>,
+    workers: Vec<JoinHandle<()>>,
+    hasher: RandomState,
+}
+
+impl EvaluationPool {
+    /// Spawn a pool of `workers` workers (at least one) on the current runtime.
+    pub fn new(workers: usize) -> Self {
+        let (lanes, workers) = (0..workers.max(1))
+            .map(|_| {
+                let (tx, mut rx) = mpsc::channel::<Job>(LANE_CAPACITY);
+                let worker = tokio::spawn(async move {
+                    while let Some(job) = rx.recv().await {
+                        // A panicking job must not take the lane's later jobs with it
+                        if AssertUnwindSafe(job).catch_unwind().await.is_err() {
+                            error!("Query evaluation job panicked");
+                        }
+                    }
+                });
+                (tx, worker)
+            })
+            .unzip();
+        Self {
+            lanes,
+            workers,
+            hasher: RandomState::new(),
+        }
+    }
+
+    /// Number of workers.
+    pub fn workers(&self) -> usize {
+        self.lanes.len()
+    }
+
+    /// Index of the lane jobs with `key` run on.
+    pub fn lane(&self, key: &impl Hash) -> usize {
+        (self.hasher.hash_one(key) % self.lanes.len() as u64) as usize
+    }
+
+    /// Queue `job` on the lane of `key`, waiting while that lane is full.
+    ///
+    /// The returned receiver resolves once the job has finished. It resolves
+    /// with an error instead if the job panicked or the pool was dropped first.
+    pub async fn submit(
+        &self,
+        key: &impl Hash,
+        job: impl Future<Output = ()> + Send + 'static,
+    ) -> oneshot::Receiver<()> {
+        let (done_tx, done_rx) = oneshot::channel();
+        let job: Job = Box::pin(async move {
+            job.await;
+            let _ = done_tx.send(());
+        });
+        // Workers only exit once the pool is dropped, which cannot happen
+        // while it is borrowed here
+        let _ = self.lanes[self.lane(key)].send(job).await;
+        done_rx
+    }
+}
+
+impl Drop for EvaluationPool {
+    fn drop(&mut self) {
+        for worker in &self.workers {
+            worker.abort();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn jobs_with_same_key_run_in_order() {
+        let pool = EvaluationPool::new(4);
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let mut done = Vec::new();
+        for i in 0..20u64 {
+            let order = order.clone();
+            done.push(
+                pool.submit(&"element-1", async move {
+                    // Earlier jobs take longer, so any overtaking would show
+                    tokio::time::sleep(Duration::from_millis(20 - i)).await;
+                    order.lock().unwrap().push(i);
+                })
+                .await,
+            );
+        }
+        for rx in done {
+            rx.await.unwrap();
+        }
+        assert_eq!(*order.lock().unwrap(), (0..20).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn jobs_with_different_keys_run_in_parallel() {
+        let pool = EvaluationPool::new(2);
+        let (a, b) = (0..)
+            .map(|i| format!("key-{i}"))
+            .try_fold(None, |first: Option<String>, key| match first {
+                None => Ok(Some(key)),
+                Some(first) if pool.lane(&first) != pool.lane(&key) => Err((first, key)),
+                first => Ok(first),
+            })
+            .unwrap_err();
+
+        // The first job only finishes once the second has run
+        let (tx, rx) = oneshot::channel::<()>();
+        let first = pool
+            .submit(&a, async move {
+                let _ = rx.await;
+            })
+            .await;
+        let second = pool
+            .submit(&b, async move {
+                let _ = tx.send(());
+            })
+            .await;
+
+        tokio::time::timeout(Duration::from_secs(1), async {
+            second.await.unwrap();
+            first.await.unwrap();
+        })
+        .await
+        .expect("jobs on different lanes should not block each other");
+    }
+
+    #[tokio::test]
+    async fn panicking_job_does_not_stop_lane() {
+        let pool = EvaluationPool::new(1);
+        let panicked = pool.submit(&"k", async { panic!("boom") }).await;
+        assert!(panicked.await.is_err());
+
+        let next = pool.submit(&"k", async {}).await;
+        assert!(tokio::time::timeout(Duration::from_secs(1), next)
+            .await
+            .unwrap()
+            .is_ok());
+    }
+}
@@ -0,0 +1,400 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Post-processing of query results before they reach subscribers.
+//!
+//! A [`ResultProcessor`] sees every batch of result diffs a query produces and
+//! may rewrite, enrich or drop them. Processors are registered on
+//! [`DrasiLib`](crate::DrasiLib) under a name and attached to queries by that
+//! name, and run in the order the query lists them.
+//!
+//! The query's current result set is folded from the unprocessed diffs, so a
+//! processor that drops or rewrites an update never leaves a stale row behind.
+//! Snapshots and `query_results()` instead run each stored row through the
+//! processors as an add when they are read, so rewrites such as
+//! [`RedactFields`] and [`RoundFloats`] apply to them as well.
+//!
+//! ```no_run
+//! use drasi_lib::{DrasiLib, Query};
+//! use drasi_lib::queries::{RedactFields, RoundFloats, SuppressUnchangedUpdates};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let core = DrasiLib::builder()
+//!     .with_result_processor("round", RoundFloats::new(1))
+//!     .with_result_processor("redact", RedactFields::new(["owner_email"]))
+//!     .with_result_processor("material", SuppressUnchangedUpdates::new())
+//!     .with_query(
+//!         Query::cypher("sensors")
+//!             .query("MATCH (s:Sensor) RETURN s.id AS id, s.temp AS temp, s.owner_email AS owner_email")
+//!             .from_source("iot")
+//!             .with_result_processor("round")
+//!             .with_result_processor("redact")
+//!             .with_result_processor("material")
+//!             .build(),
+//!     )
+//!     .build()
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use crate::channels::ResultDiff;
+
+/// Transforms the result diffs of a query before they are dispatched.
+///
+/// Returning an empty vector suppresses the batch entirely. Closures with the
+/// signature `Fn(&str, Vec<ResultDiff>) -> Vec<ResultDiff>` implement this trait.
+pub trait ResultProcessor: Send + Sync {
+    fn process(&self, query_id: &str, results: Vec<ResultDiff>) -> Vec<ResultDiff>;
+}
+
+impl<F> ResultProcessor for F
+where
+    F: Fn(&str, Vec<ResultDiff>) -> Vec<ResultDiff> + Send + Sync,
+{
+    fn process(&self, query_id: &str, results: Vec<ResultDiff>) -> Vec<ResultDiff> {
+        self(query_id, results)
+    }
+}
+
+/// Run `results` through each processor in turn.
+pub(crate) fn apply_result_processors(
+    processors: &[Arc<dyn ResultProcessor>],
+    query_id: &str,
+    mut results: Vec<ResultDiff>,
+) -> Vec<ResultDiff> {
+    for processor in processors {
+        if results.is_empty() {
+            break;
+        }
+        results = processor.process(query_id, results);
+    }
+    results
+}
+
+/// Project stored rows through the processors, presenting each row as an add.
+///
+/// Rows whose add is dropped by a processor are left out.
+pub(crate) fn project_rows(
+    processors: &[Arc<dyn ResultProcessor>],
+    query_id: &str,
+    rows: &[Value],
+) -> Vec<Value> {
+    if processors.is_empty() {
+        return rows.to_vec();
+    }
+    let adds = rows
+        .iter()
+        .map(|row| ResultDiff::Add { data: row.clone() })
+        .collect();
+    apply_result_processors(processors, query_id, adds)
+        .into_iter()
+        .filter_map(|diff| match diff {
+            ResultDiff::Add { data } => Some(data),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Apply `f` to every row object carried by a diff.
+fn for_each_row(diff: &mut ResultDiff, mut f: impl FnMut(&mut Value)) {
+    match diff {
+        ResultDiff::Add { data } | ResultDiff::Delete { data } => f(data),
+        ResultDiff::Update {
+            data,
+            before,
+            after,
+            ..
+        } => {
+            f(data);
+            f(before);
+            f(after);
+        }
+        ResultDiff::Aggregation { before, after } => {
+            if let Some(before) = before {
+                f(before);
+            }
+            f(after);
+        }
+        ResultDiff::Noop => {}
+    }
+}
+
+/// Rounds floating point columns to a fixed number of decimal places.
+#[derive(Debug, Clone)]
+pub struct RoundFloats {
+    decimals: i32,
+    fields: Option<HashSet<String>>,
+}
+
+impl RoundFloats {
+    /// Round every floating point column to `decimals` places.
+    pub fn new(decimals: u32) -> Self {
+        Self {
+            decimals: decimals.min(15) as i32,
+            fields: None,
+        }
+    }
+
+    /// Only round the named columns.
+    pub fn fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.fields = Some(fields.into_iter().map(Into::into).collect());
+        self
+    }
+
+    fn round_row(&self, row: &mut Value) {
+        let Value::Object(map) = row else {
+            return;
+        };
+        let factor = 10f64.powi(self.decimals);
+        for (key, value) in map.iter_mut() {
+            if self.fields.as_ref().is_some_and(|f| !f.contains(key)) {
+                continue;
+            }
+            if !value.is_f64() {
+                continue;
+            }
+            let rounded = value
+                .as_f64()
+                .and_then(|f| serde_json::Number::from_f64((f * factor).round() / factor));
+            if let Some(rounded) = rounded {
+                *value = Value::Number(rounded);
+            }
+        }
+    }
+}
+
+impl ResultProcessor for RoundFloats {
+    fn process(&self, _query_id: &str, mut results: Vec<ResultDiff>) -> Vec<ResultDiff> {
+        for diff in &mut results {
+            for_each_row(diff, |row| self.round_row(row));
+        }
+        results
+    }
+}
+
+/// Removes the named columns from every row.
+#[derive(Debug, Clone)]
+pub struct RedactFields {
+    fields: HashSet<String>,
+}
+
+impl RedactFields {
+    pub fn new<I, S>(fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            fields: fields.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl ResultProcessor for RedactFields {
+    fn process(&self, _query_id: &str, mut results: Vec<ResultDiff>) -> Vec<ResultDiff> {
+        for diff in &mut results {
+            for_each_row(diff, |row| {
+                if let Value::Object(map) = row {
+                    map.retain(|key, _| !self.fields.contains(key));
+                }
+            });
+        }
+        results
+    }
+}
+
+/// Drops updates whose before and after rows are identical.
+///
+/// Place it after processors such as [`RoundFloats`] or [`RedactFields`] so
+/// that changes they hide no longer count as changes.
+#[derive(Debug, Clone, Default)]
+pub struct SuppressUnchangedUpdates {
+    ignored: HashSet<String>,
+}
+
+impl SuppressUnchangedUpdates {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Treat rows that differ only in these columns as unchanged.
+    pub fn ignoring<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.ignored = fields.into_iter().map(Into::into).collect();
+        self
+    }
+
+    fn is_material(&self, before: &Value, after: &Value) -> bool {
+        match (before, after) {
+            (Value::Object(b), Value::Object(a)) if !self.ignored.is_empty() => {
+                let relevant = |map: &serde_json::Map<String, Value>| {
+                    map.iter()
+                        .filter(|(key, _)| !self.ignored.contains(*key))
+                        .map(|(key, value)| (key.clone(), value.clone()))
+                        .collect::<serde_json::Map<_, _>>()
+                };
+                relevant(b) != relevant(a)
+            }
+            _ => before != after,
+        }
+    }
+}
+
+impl ResultProcessor for SuppressUnchangedUpdates {
+    fn process(&self, _query_id: &str, mut results: Vec<ResultDiff>) -> Vec<ResultDiff> {
+        results.retain(|diff| match diff {
+            ResultDiff::Update { before, after, .. } => self.is_material(before, after),
+            ResultDiff::Aggregation {
+                before: Some(before),
+                after,
+            } => self.is_material(before, after),
+            _ => true,
+        });
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn update(before: Value, after: Value) -> ResultDiff {
+        ResultDiff::Update {
+            data: after.clone(),
+            before,
+            after,
+            grouping_keys: None,
+        }
+    }
+
+    #[test]
+    fn test_closure_processor() {
+        let drop_deletes = |_: &str, results: Vec<ResultDiff>| -> Vec<ResultDiff> {
+            results
+                .into_iter()
+                .filter(|d| !matches!(d, ResultDiff::Delete { .. }))
+                .collect()
+        };
+        let processors: Vec<Arc<dyn ResultProcessor>> = vec![Arc::new(drop_deletes)];
+        let results = apply_result_processors(
+            &processors,
+            "q",
+            vec![
+                ResultDiff::Add {
+                    data: json!({"id": 1}),
+                },
+                ResultDiff::Delete {
+                    data: json!({"id": 2}),
+                },
+            ],
+        );
+        assert_eq!(results.len(), 1);
+    }
+
+    #[test]
+    fn test_round_floats() {
+        let processor = RoundFloats::new(1).fields(["temp"]);
+        let results = processor.process(
+            "q",
+            vec![ResultDiff::Add {
+                data: json!({"temp": 21.4567, "humidity": 40.123, "count": 3}),
+            }],
+        );
+        match &results[0] {
+            ResultDiff::Add { data } => {
+                assert_eq!(data["temp"], json!(21.5));
+                assert_eq!(data["humidity"], json!(40.123));
+                assert_eq!(data["count"], json!(3));
+            }
+            other => panic!("unexpected diff {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_redact_fields() {
+        let processor = RedactFields::new(["email"]);
+        let results = processor.process(
+            "q",
+            vec![update(
+                json!({"id": 1, "email": "a@example.com"}),
+                json!({"id": 1, "email": "b@example.com"}),
+            )],
+        );
+        match &results[0] {
+            ResultDiff::Update {
+                data,
+                before,
+                after,
+                ..
+            } => {
+                assert_eq!(data, &json!({"id": 1}));
+                assert_eq!(before, &json!({"id": 1}));
+                assert_eq!(after, &json!({"id": 1}));
+            }
+            other => panic!("unexpected diff {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_round_then_suppress_drops_immaterial_updates() {
+        let processors: Vec<Arc<dyn ResultProcessor>> = vec![
+            Arc::new(RoundFloats::new(0)),
+            Arc::new(SuppressUnchangedUpdates::new().ignoring(["seen_at"])),
+        ];
+        let results = apply_result_processors(
+            &processors,
+            "q",
+            vec![
+                update(
+                    json!({"temp": 21.2, "seen_at": 1}),
+                    json!({"temp": 21.3, "seen_at": 2}),
+                ),
+                update(json!({"temp": 21.2}), json!({"temp": 24.0})),
+            ],
+        );
+        assert_eq!(results.len(), 1);
+    }
+
+    #[test]
+    fn test_project_rows_rewrites_and_drops_rows() {
+        let drop_hidden = |_: &str, results: Vec<ResultDiff>| -> Vec<ResultDiff> {
+            results
+                .into_iter()
+                .filter(|diff| !matches!(diff, ResultDiff::Add { data } if data["hidden"] == true))
+                .collect()
+        };
+        let processors: Vec<Arc<dyn ResultProcessor>> =
+            vec![Arc::new(RedactFields::new(["email"])), Arc::new(drop_hidden)];
+        let rows = project_rows(
+            &processors,
+            "q",
+            &[json!({"id": 1, "email": "a@example.com"}), json!({"id": 2, "hidden": true})],
+        );
+        assert_eq!(rows, vec![json!({"id": 1})]);
+    }
+}
@@ -0,0 +1,269 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Materialized result set maintained by each running query.
+//!
+//! Every batch of [`ResultDiff`]s a query emits (including bootstrap results)
+//! is folded into a [`ResultSet`]. The set carries a monotonically increasing
+//! sequence number so callers pulling a [`QueryResultSnapshot`] can tell
+//! whether the results changed between two reads, and it fans every applied
+//! diff out to in-process subscribers (see
+//! [`DrasiLib::subscribe_to_query`](crate::DrasiLib::subscribe_to_query)).
+
+use chrono::{DateTime, Utc};
+use log::warn;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+
+use crate::channels::ResultDiff;
+
+/// Point-in-time copy of a query's current result set.
+///
+/// Returned by [`DrasiLib::query_results`](crate::DrasiLib::query_results).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryResultSnapshot {
+    /// ID of the query the results belong to.
+    pub query_id: String,
+    /// Current rows, in the order they were added.
+    pub results: Vec<serde_json::Value>,
+    /// Number of result batches applied since the query started. Starts at `0`
+    /// and increases by one for every batch that changed the result set.
+    pub sequence: u64,
+    /// When the result set last changed, `None` if it never has.
+    pub last_updated: Option<DateTime<Utc>>,
+    /// When this snapshot was taken.
+    pub timestamp: DateTime<Utc>,
+}
+
+impl QueryResultSnapshot {
+    /// Number of rows in the snapshot.
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    /// Returns `true` if the snapshot holds no rows.
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+}
+
+/// Current rows of a query plus the bookkeeping needed for snapshots.
+#[derive(Debug)]
+pub(crate) struct ResultSet {
+    rows: Vec<serde_json::Value>,
+    sequence: u64,
+    last_updated: Option<DateTime<Utc>>,
+    changes: broadcast::Sender<ResultDiff>,
+}
+
+impl Default for ResultSet {
+    fn default() -> Self {
+        Self::new(DEFAULT_SUBSCRIPTION_CAPACITY)
+    }
+}
+
+/// Default number of diffs buffered per result subscriber before it starts lagging.
+pub(crate) const DEFAULT_SUBSCRIPTION_CAPACITY: usize = 1000;
+
+impl ResultSet {
+    /// Create an empty result set whose subscribers buffer up to `capacity` diffs.
+    pub(crate) fn new(capacity: usize) -> Self {
+        let (changes, _) = broadcast::channel(capacity.max(1));
+        Self {
+            rows: Vec::new(),
+            sequence: 0,
+            last_updated: None,
+            changes,
+        }
+    }
+
+    pub(crate) fn rows(&self) -> &[serde_json::Value] {
+        &self.rows
+    }
+
+    /// Fold a batch of diffs into the result set.
+    ///
+    /// Batches consisting only of no-ops leave the sequence untouched. Every
+    /// non-noop diff is also forwarded to subscribers, in application order.
+    pub(crate) fn apply(&mut self, diffs: &[ResultDiff]) {
+        self.apply_rows(diffs);
+        self.publish(diffs);
+    }
+
+    /// Forward non-noop diffs to subscribers without touching the rows.
+    ///
+    /// Used with [`apply_rows`](Self::apply_rows) when subscribers should see
+    /// post-processed diffs while the rows track the raw query output.
+    pub(crate) fn publish(&self, diffs: &[ResultDiff]) {
+        for diff in diffs {
+            if !matches!(diff, ResultDiff::Noop) {
+                // Sending only fails when nobody is subscribed
+                let _ = self.changes.send(diff.clone());
+            }
+        }
+    }
+
+    /// Fold a batch of diffs into the rows without notifying subscribers.
+    pub(crate) fn apply_rows(&mut self, diffs: &[ResultDiff]) {
+        let mut changed = false;
+        for diff in diffs {
+            match diff {
+                ResultDiff::Add { data } => {
+                    self.rows.push(data.clone());
+                }
+                ResultDiff::Delete { data } => {
+                    self.rows.retain(|item| item != data);
+                }
+                ResultDiff::Update { before, after, .. } => {
+                    if !self.replace(before, after) {
+                        warn!("UPDATE: Could not find exact match for before state, treating as remove+add");
+                    }
+                }
+                ResultDiff::Aggregation { before, after } => match before {
+                    Some(before) => {
+                        self.replace(before, after);
+                    }
+                    None => self.rows.push(after.clone()),
+                },
+                ResultDiff::Noop => continue,
+            }
+            changed = true;
+        }
+
+        if changed {
+            self.sequence += 1;
+            self.last_updated = Some(Utc::now());
+        }
+    }
+
+    /// Receive every diff applied after this call.
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<ResultDiff> {
+        self.changes.subscribe()
+    }
+
+    pub(crate) fn snapshot(&self, query_id: &str) -> QueryResultSnapshot {
+        QueryResultSnapshot {
+            query_id: query_id.to_string(),
+            results: self.rows.clone(),
+            sequence: self.sequence,
+            last_updated: self.last_updated,
+            timestamp: Utc::now(),
+        }
+    }
+
+    /// Replace `before` with `after` in place. Falls back to remove+add when
+    /// `before` is not present; returns whether an exact match was found.
+    fn replace(&mut self, before: &serde_json::Value, after: &serde_json::Value) -> bool {
+        if let Some(pos) = self.rows.iter().position(|item| item == before) {
+            self.rows[pos] = after.clone();
+            true
+        } else {
+            self.rows.retain(|item| item != before);
+            self.rows.push(after.clone());
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_apply_tracks_rows_and_sequence() {
+        let mut set = ResultSet::default();
+        assert_eq!(set.snapshot("q").sequence, 0);
+        assert!(set.snapshot("q").last_updated.is_none());
+
+        set.apply(&[
+            ResultDiff::Add {
+                data: json!({"id": 1}),
+            },
+            ResultDiff::Add {
+                data: json!({"id": 2}),
+            },
+        ]);
+        set.apply(&[ResultDiff::Update {
+            data: json!({"id": 2, "v": true}),
+            before: json!({"id": 2}),
+            after: json!({"id": 2, "v": true}),
+            grouping_keys: None,
+        }]);
+        set.apply(&[ResultDiff::Delete {
+            data: json!({"id": 1}),
+        }]);
+
+        let snapshot = set.snapshot("q");
+        assert_eq!(snapshot.query_id, "q");
+        assert_eq!(snapshot.results, vec![json!({"id": 2, "v": true})]);
+        assert_eq!(snapshot.sequence, 3);
+        assert!(snapshot.last_updated.is_some());
+    }
+
+    #[test]
+    fn test_noop_batch_does_not_bump_sequence() {
+        let mut set = ResultSet::default();
+        set.apply(&[ResultDiff::Noop]);
+        set.apply(&[]);
+        assert_eq!(set.snapshot("q").sequence, 0);
+        assert!(set.rows().is_empty());
+    }
+
+    #[test]
+    fn test_aggregation_replaces_previous_value() {
+        let mut set = ResultSet::default();
+        set.apply(&[ResultDiff::Aggregation {
+            before: None,
+            after: json!({"count": 1}),
+        }]);
+        set.apply(&[ResultDiff::Aggregation {
+            before: Some(json!({"count": 1})),
+            after: json!({"count": 2}),
+        }]);
+        assert_eq!(set.rows(), &[json!({"count": 2})]);
+        assert_eq!(set.snapshot("q").sequence, 2);
+    }
+
+    #[tokio::test]
+    async fn test_subscribers_receive_applied_diffs() {
+        let mut set = ResultSet::new(16);
+        let mut rx = set.subscribe();
+
+        set.apply(&[
+            ResultDiff::Add {
+                data: json!({"id": 1}),
+            },
+            ResultDiff::Noop,
+            ResultDiff::Delete {
+                data: json!({"id": 1}),
+            },
+        ]);
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            ResultDiff::Add {
+                data: json!({"id": 1})
+            }
+        );
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            ResultDiff::Delete {
+                data: json!({"id": 1})
+            }
+        );
+        assert!(rx.try_recv().is_err());
+    }
+}
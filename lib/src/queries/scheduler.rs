@@ -0,0 +1,355 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fair scheduling of query evaluation.
+//!
+//! Every query evaluates its source changes on its own task. Without a limit,
+//! those tasks compete freely for the runtime, so a query with an expensive
+//! pattern fed by a high-throughput source can occupy the workers that cheap,
+//! latency-sensitive queries need.
+//!
+//! [`QueryScheduler`] bounds the number of evaluations in progress and hands
+//! out free slots by weighted fair queuing: each query accumulates the time
+//! it spent evaluating, divided by the weight of its [`QueryPriority`], and
+//! the waiting query with the least accumulated time goes next. A query that
+//! evaluates cheaply therefore overtakes an expensive one, and a query with a
+//! higher priority gets a proportionally larger share of evaluation time.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::oneshot;
+
+/// Share of evaluation time a query gets when queries compete for it.
+///
+/// Each level gets twice the share of the level below it. Priorities only
+/// take effect when an evaluation limit is configured with
+/// [`DrasiLibBuilder::with_evaluation_concurrency`](crate::DrasiLibBuilder::with_evaluation_concurrency).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QueryPriority {
+    Low,
+    #[default]
+    Normal,
+    High,
+    Critical,
+}
+
+impl QueryPriority {
+    /// Relative share of evaluation time.
+    pub fn weight(&self) -> u32 {
+        match self {
+            Self::Low => 1,
+            Self::Normal => 2,
+            Self::High => 4,
+            Self::Critical => 8,
+        }
+    }
+}
+
+/// Fair-share state of one query.
+#[derive(Debug, Default)]
+struct QueryShare {
+    /// Evaluation time consumed, in seconds, divided by the query's weight
+    virtual_time: f64,
+}
+
+#[derive(Debug)]
+struct Waiter {
+    query_id: String,
+    /// Arrival order, breaks ties between equal virtual times
+    seq: u64,
+    grant: oneshot::Sender<()>,
+}
+
+#[derive(Debug)]
+struct SchedulerState {
+    /// Evaluation slots not currently held
+    available: usize,
+    shares: HashMap<String, QueryShare>,
+    waiters: Vec<Waiter>,
+    next_seq: u64,
+    /// Virtual time of the most recently granted waiter. A query that was idle
+    /// starts from here rather than from its own, older time, so it cannot
+    /// claim a burst of slots for the time it did not use.
+    clock: f64,
+}
+
+/// Bounds concurrent query evaluations and shares them fairly by priority.
+///
+/// Shared by all queries of a [`QueryManager`](crate::queries::QueryManager).
+#[derive(Debug)]
+pub struct QueryScheduler {
+    capacity: usize,
+    state: Mutex<SchedulerState>,
+}
+
+impl QueryScheduler {
+    /// Create a scheduler allowing `capacity` evaluations at a time (at least one).
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            state: Mutex::new(SchedulerState {
+                available: capacity,
+                shares: HashMap::new(),
+                waiters: Vec::new(),
+                next_seq: 0,
+                clock: 0.0,
+            }),
+        }
+    }
+
+    /// Maximum number of evaluations in progress at a time.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Wait for an evaluation slot for `query_id`.
+    ///
+    /// The slot is held until the returned permit is dropped; the time it was
+    /// held is charged to the query's fair share.
+    pub async fn acquire(
+        self: &Arc<Self>,
+        query_id: &str,
+        priority: QueryPriority,
+    ) -> EvaluationPermit {
+        let rx = {
+            let mut state = self.lock();
+            if state.available > 0 && state.waiters.is_empty() {
+                state.available -= 1;
+                None
+            } else {
+                let clock = state.clock;
+                let share = state.shares.entry(query_id.to_string()).or_default();
+                share.virtual_time = share.virtual_time.max(clock);
+                let (grant, rx) = oneshot::channel();
+                let seq = state.next_seq;
+                state.next_seq += 1;
+                state.waiters.push(Waiter {
+                    query_id: query_id.to_string(),
+                    seq,
+                    grant,
+                });
+                Some(rx)
+            }
+        };
+
+        if let Some(rx) = rx {
+            let mut waiting = Waiting {
+                scheduler: self.clone(),
+                rx: Some(rx),
+            };
+            // The sender is only dropped after a grant, or with the scheduler
+            if let Some(rx) = waiting.rx.as_mut() {
+                let _ = rx.await;
+            }
+            waiting.rx = None;
+        }
+
+        EvaluationPermit {
+            scheduler: self.clone(),
+            query_id: query_id.to_string(),
+            weight: priority.weight(),
+            started: Instant::now(),
+        }
+    }
+
+    /// Number of queries waiting for an evaluation slot.
+    pub fn waiting(&self) -> usize {
+        self.lock().waiters.len()
+    }
+
+    /// Drop the fair-share state of a removed query.
+    pub fn forget(&self, query_id: &str) {
+        self.lock().shares.remove(query_id);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, SchedulerState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Charge a finished evaluation and pass its slot on.
+    fn release(&self, charge: Option<(&str, f64)>) {
+        let mut state = self.lock();
+        if let Some((query_id, cost)) = charge {
+            state
+                .shares
+                .entry(query_id.to_string())
+                .or_default()
+                .virtual_time += cost;
+        }
+        loop {
+            let next = state
+                .waiters
+                .iter()
+                .enumerate()
+                .min_by(|(_, a), (_, b)| {
+                    let vt = |w: &Waiter| {
+                        state
+                            .shares
+                            .get(&w.query_id)
+                            .map_or(0.0, |s| s.virtual_time)
+                    };
+                    vt(a).total_cmp(&vt(b)).then(a.seq.cmp(&b.seq))
+                })
+                .map(|(index, _)| index);
+            let Some(index) = next else {
+                state.available += 1;
+                return;
+            };
+            let waiter = state.waiters.swap_remove(index);
+            if let Some(share) = state.shares.get(&waiter.query_id) {
+                state.clock = share.virtual_time;
+            }
+            // A waiter that gave up no longer needs the slot
+            if waiter.grant.send(()).is_ok() {
+                return;
+            }
+        }
+    }
+}
+
+/// A query waiting for a slot. Returns a slot granted after the wait was abandoned.
+struct Waiting {
+    scheduler: Arc<QueryScheduler>,
+    rx: Option<oneshot::Receiver<()>>,
+}
+
+impl Drop for Waiting {
+    fn drop(&mut self) {
+        if let Some(mut rx) = self.rx.take() {
+            rx.close();
+            if rx.try_recv().is_ok() {
+                self.scheduler.release(None);
+            }
+        }
+    }
+}
+
+/// An evaluation slot, released when dropped.
+pub struct EvaluationPermit {
+    scheduler: Arc<QueryScheduler>,
+    query_id: String,
+    weight: u32,
+    started: Instant,
+}
+
+impl Drop for EvaluationPermit {
+    fn drop(&mut self) {
+        let cost = self.started.elapsed().as_secs_f64() / f64::from(self.weight);
+        self.scheduler.release(Some((&self.query_id, cost)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn limits_concurrent_evaluations() {
+        let scheduler = Arc::new(QueryScheduler::new(1));
+        let held = scheduler.acquire("q1", QueryPriority::Normal).await;
+
+        let waiter = {
+            let scheduler = scheduler.clone();
+            tokio::spawn(async move { scheduler.acquire("q2", QueryPriority::Normal).await })
+        };
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert_eq!(scheduler.waiting(), 1);
+        assert!(!waiter.is_finished());
+
+        drop(held);
+        let _permit = tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(scheduler.waiting(), 0);
+    }
+
+    #[tokio::test]
+    async fn cheap_query_overtakes_expensive_one() {
+        let scheduler = Arc::new(QueryScheduler::new(1));
+        // The expensive query has already used a lot of evaluation time
+        scheduler
+            .lock()
+            .shares
+            .entry("expensive".to_string())
+            .or_default()
+            .virtual_time = 10.0;
+
+        let held = scheduler.acquire("other", QueryPriority::Normal).await;
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let mut tasks = Vec::new();
+        for id in ["expensive", "cheap"] {
+            let scheduler = scheduler.clone();
+            let order = order.clone();
+            tasks.push(tokio::spawn(async move {
+                let _permit = scheduler.acquire(id, QueryPriority::Normal).await;
+                order.lock().unwrap().push(id);
+            }));
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        drop(held);
+        for task in tasks {
+            task.await.unwrap();
+        }
+        assert_eq!(*order.lock().unwrap(), vec!["cheap", "expensive"]);
+    }
+
+    #[tokio::test]
+    async fn priority_scales_charged_time() {
+        let scheduler = Arc::new(QueryScheduler::new(1));
+        for (id, priority) in [("low", QueryPriority::Low), ("high", QueryPriority::High)] {
+            let permit = scheduler.acquire(id, priority).await;
+            tokio::time::sleep(Duration::from_millis(40)).await;
+            drop(permit);
+        }
+        let state = scheduler.lock();
+        let low = state.shares["low"].virtual_time;
+        let high = state.shares["high"].virtual_time;
+        assert!(high < low / 2.0, "high={high} low={low}");
+    }
+
+    #[tokio::test]
+    async fn abandoned_wait_does_not_leak_slot() {
+        let scheduler = Arc::new(QueryScheduler::new(1));
+        let held = scheduler.acquire("q1", QueryPriority::Normal).await;
+        let abandoned = tokio::time::timeout(
+            Duration::from_millis(20),
+            scheduler.acquire("q2", QueryPriority::Normal),
+        )
+        .await;
+        assert!(abandoned.is_err());
+
+        drop(held);
+        let permit = tokio::time::timeout(
+            Duration::from_secs(1),
+            scheduler.acquire("q3", QueryPriority::Normal),
+        )
+        .await;
+        assert!(permit.is_ok());
+    }
+
+    #[test]
+    fn priority_deserializes_lowercase() {
+        let priority: QueryPriority = serde_json::from_str("\"high\"").unwrap();
+        assert_eq!(priority, QueryPriority::High);
+        assert_eq!(QueryPriority::default(), QueryPriority::Normal);
+    }
+}
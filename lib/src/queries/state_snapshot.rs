@@ -0,0 +1,312 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Portable export of a query's full state.
+//!
+//! A [`QueryStateSnapshot`] holds every element in a running query's element
+//! index together with its current result set. Importing it into a query on
+//! another instance replays the elements through that query, rebuilding its
+//! indexes and results without re-bootstrapping from the sources. This is
+//! useful for blue/green deployments and for pre-warming heavy queries.
+//!
+//! Elements are stored in their post-middleware form and are not passed
+//! through the target query's source middleware again. Relations synthesized
+//! by joins are not exported; the target query derives them itself.
+
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use drasi_core::models::{
+    Element, ElementMetadata, ElementPropertyMap, ElementReference, ElementTimestamp,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{DrasiError, Result};
+use crate::queries::QueryResultSnapshot;
+
+/// Version of the snapshot format written by this crate.
+pub const QUERY_STATE_FORMAT_VERSION: u32 = 1;
+
+/// Full, portable state of a query.
+///
+/// Produced by [`DrasiLib::export_query_state`](crate::DrasiLib::export_query_state)
+/// and consumed by [`DrasiLib::import_query_state`](crate::DrasiLib::import_query_state).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryStateSnapshot {
+    /// Format version, see [`QUERY_STATE_FORMAT_VERSION`].
+    pub format_version: u32,
+    /// ID of the exported query.
+    pub query_id: String,
+    /// Query text of the exported query.
+    pub query: String,
+    /// When the snapshot was taken.
+    pub exported_at: DateTime<Utc>,
+    /// Every element held by the query's element index.
+    pub elements: Vec<SnapshotElement>,
+    /// The query's result set at the time of export.
+    pub results: QueryResultSnapshot,
+}
+
+impl QueryStateSnapshot {
+    /// Write the snapshot to `path` as JSON, replacing any existing file.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let file = std::fs::File::create(path).map_err(|e| {
+            anyhow::anyhow!("Failed to create snapshot file '{}': {e}", path.display())
+        })?;
+        serde_json::to_writer(BufWriter::new(file), self).map_err(|e| {
+            anyhow::anyhow!("Failed to write snapshot file '{}': {e}", path.display())
+        })?;
+        Ok(())
+    }
+
+    /// Read a snapshot previously written with [`save`](Self::save).
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let file = std::fs::File::open(path).map_err(|e| {
+            anyhow::anyhow!("Failed to open snapshot file '{}': {e}", path.display())
+        })?;
+        let snapshot: Self = serde_json::from_reader(BufReader::new(file)).map_err(|e| {
+            DrasiError::validation(format!("Invalid snapshot file '{}': {e}", path.display()))
+        })?;
+        snapshot.check_version()?;
+        Ok(snapshot)
+    }
+
+    /// Fail if the snapshot was written in a format this crate cannot read.
+    pub fn check_version(&self) -> Result<()> {
+        if self.format_version != QUERY_STATE_FORMAT_VERSION {
+            return Err(DrasiError::validation(format!(
+                "Unsupported snapshot format version {} (expected {})",
+                self.format_version, QUERY_STATE_FORMAT_VERSION
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Reference to an element by source and element ID.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotElementRef {
+    pub source_id: String,
+    pub id: String,
+}
+
+impl From<&ElementReference> for SnapshotElementRef {
+    fn from(reference: &ElementReference) -> Self {
+        Self {
+            source_id: reference.source_id.to_string(),
+            id: reference.element_id.to_string(),
+        }
+    }
+}
+
+impl From<&SnapshotElementRef> for ElementReference {
+    fn from(reference: &SnapshotElementRef) -> Self {
+        ElementReference::new(&reference.source_id, &reference.id)
+    }
+}
+
+/// A node or relation as stored in a [`QueryStateSnapshot`].
+///
+/// Relations carry both `in_node` and `out_node`; nodes carry neither.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotElement {
+    pub source_id: String,
+    pub id: String,
+    pub labels: Vec<String>,
+    /// Milliseconds since the UNIX epoch from which this version is valid.
+    pub effective_from: ElementTimestamp,
+    pub properties: serde_json::Map<String, serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub in_node: Option<SnapshotElementRef>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub out_node: Option<SnapshotElementRef>,
+}
+
+impl From<&Element> for SnapshotElement {
+    fn from(element: &Element) -> Self {
+        let (metadata, properties, endpoints) = match element {
+            Element::Node {
+                metadata,
+                properties,
+            } => (metadata, properties, None),
+            Element::Relation {
+                metadata,
+                in_node,
+                out_node,
+                properties,
+            } => (metadata, properties, Some((in_node, out_node))),
+        };
+        Self {
+            source_id: metadata.reference.source_id.to_string(),
+            id: metadata.reference.element_id.to_string(),
+            labels: metadata.labels.iter().map(|l| l.to_string()).collect(),
+            effective_from: metadata.effective_from,
+            properties: properties.into(),
+            in_node: endpoints.map(|(in_node, _)| in_node.into()),
+            out_node: endpoints.map(|(_, out_node)| out_node.into()),
+        }
+    }
+}
+
+impl TryFrom<&SnapshotElement> for Element {
+    type Error = DrasiError;
+
+    fn try_from(element: &SnapshotElement) -> Result<Self> {
+        let metadata = ElementMetadata {
+            reference: ElementReference::new(&element.source_id, &element.id),
+            labels: element
+                .labels
+                .iter()
+                .map(|l| Arc::from(l.as_str()))
+                .collect(),
+            effective_from: element.effective_from,
+        };
+        let properties = ElementPropertyMap::from(&element.properties);
+
+        match (&element.in_node, &element.out_node) {
+            (None, None) => Ok(Element::Node {
+                metadata,
+                properties,
+            }),
+            (Some(in_node), Some(out_node)) => Ok(Element::Relation {
+                metadata,
+                in_node: in_node.into(),
+                out_node: out_node.into(),
+                properties,
+            }),
+            _ => Err(DrasiError::validation(format!(
+                "Snapshot element '{}:{}' must have both inNode and outNode or neither",
+                element.source_id, element.id
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use drasi_core::models::ElementValue;
+
+    fn node() -> Element {
+        let mut properties = ElementPropertyMap::new();
+        properties.insert("name", ElementValue::String(Arc::from("Alice")));
+        properties.insert("age", ElementValue::Integer(30));
+        Element::Node {
+            metadata: ElementMetadata {
+                reference: ElementReference::new("people", "p1"),
+                labels: Arc::from(vec![Arc::from("Person")]),
+                effective_from: 1000,
+            },
+            properties,
+        }
+    }
+
+    #[test]
+    fn test_node_roundtrip() {
+        let element = node();
+        let snapshot = SnapshotElement::from(&element);
+        assert_eq!(snapshot.source_id, "people");
+        assert_eq!(snapshot.labels, vec!["Person".to_string()]);
+        assert!(snapshot.in_node.is_none());
+
+        let restored = Element::try_from(&snapshot).unwrap();
+        assert_eq!(restored, element);
+    }
+
+    #[test]
+    fn test_relation_roundtrip() {
+        let element = Element::Relation {
+            metadata: ElementMetadata {
+                reference: ElementReference::new("people", "r1"),
+                labels: Arc::from(vec![Arc::from("KNOWS")]),
+                effective_from: 2000,
+            },
+            in_node: ElementReference::new("people", "p1"),
+            out_node: ElementReference::new("people", "p2"),
+            properties: ElementPropertyMap::new(),
+        };
+        let snapshot = SnapshotElement::from(&element);
+        let json = serde_json::to_value(&snapshot).unwrap();
+        assert_eq!(json["inNode"]["id"], "p1");
+
+        let restored = Element::try_from(&snapshot).unwrap();
+        assert_eq!(restored, element);
+    }
+
+    #[test]
+    fn test_half_relation_is_rejected() {
+        let mut snapshot = SnapshotElement::from(&node());
+        snapshot.in_node = Some(SnapshotElementRef {
+            source_id: "people".to_string(),
+            id: "p2".to_string(),
+        });
+        assert!(Element::try_from(&snapshot).is_err());
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let snapshot = QueryStateSnapshot {
+            format_version: QUERY_STATE_FORMAT_VERSION,
+            query_id: "q1".to_string(),
+            query: "MATCH (p:Person) RETURN p.name".to_string(),
+            exported_at: Utc::now(),
+            elements: vec![SnapshotElement::from(&node())],
+            results: QueryResultSnapshot {
+                query_id: "q1".to_string(),
+                results: vec![serde_json::json!({"name": "Alice"})],
+                sequence: 1,
+                last_updated: None,
+                timestamp: Utc::now(),
+            },
+        };
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("q1.json");
+        snapshot.save(&path).unwrap();
+
+        assert_eq!(QueryStateSnapshot::load(&path).unwrap(), snapshot);
+    }
+
+    #[test]
+    fn test_load_rejects_unknown_version() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("bad.json");
+        let mut snapshot = serde_json::json!({
+            "formatVersion": 99,
+            "queryId": "q1",
+            "query": "",
+            "exportedAt": Utc::now(),
+            "elements": [],
+            "results": {
+                "queryId": "q1",
+                "results": [],
+                "sequence": 0,
+                "lastUpdated": null,
+                "timestamp": Utc::now()
+            }
+        });
+        std::fs::write(&path, snapshot.to_string()).unwrap();
+        assert!(QueryStateSnapshot::load(&path).is_err());
+
+        snapshot["formatVersion"] = serde_json::json!(QUERY_STATE_FORMAT_VERSION);
+        std::fs::write(&path, snapshot.to_string()).unwrap();
+        assert!(QueryStateSnapshot::load(&path).is_ok());
+    }
+}
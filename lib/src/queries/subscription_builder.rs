@@ -185,6 +185,9 @@ mod tests {
             dispatch_mode: None,
             storage_backend: None,
             recovery_policy: None,
+            result_processors: Vec::new(),
+            quarantine_capacity: 0,
+            priority: Default::default(),
         }
     }
 
@@ -57,6 +57,9 @@ mod manager_tests {
             dispatch_mode: None,
             storage_backend: None,
             recovery_policy: None,
+            result_processors: Vec::new(),
+            quarantine_capacity: 0,
+            priority: Default::default(),
         }
     }
 
@@ -85,6 +88,9 @@ mod manager_tests {
             dispatch_mode: None,
             storage_backend: None,
             recovery_policy: None,
+            result_processors: Vec::new(),
+            quarantine_capacity: 0,
+            priority: Default::default(),
         }
     }
 
@@ -246,6 +252,29 @@ mod manager_tests {
         assert_eq!(queries.len(), 0);
     }
 
+    #[tokio::test]
+    async fn test_delete_query_clears_checkpoints() {
+        use crate::checkpoint::{CheckpointStore, StateStoreCheckpointStore};
+
+        let (manager, _source_manager, graph) = create_test_manager().await;
+        let store: Arc<dyn CheckpointStore> = Arc::new(StateStoreCheckpointStore::new(Arc::new(
+            crate::state_store::MemoryStateStoreProvider::new(),
+        )));
+        manager.inject_checkpoint_store(store.clone()).await;
+
+        let config = create_test_query_config("test-query", vec![]);
+        add_query(&manager, &graph, config).await.unwrap();
+        store
+            .save_query_checkpoint("test-query", "source1", 7)
+            .await
+            .unwrap();
+
+        delete_query(&manager, &graph, "test-query").await.unwrap();
+
+        let checkpoints = store.load_query_checkpoints("test-query").await.unwrap();
+        assert!(checkpoints.is_empty());
+    }
+
     #[tokio::test]
     async fn test_start_query() {
         let (manager, source_manager, graph) = create_test_manager_with_graph().await;
@@ -904,6 +933,9 @@ mod query_core_tests {
                 dispatch_mode: None,
                 storage_backend: None,
                 recovery_policy: None,
+                result_processors: Vec::new(),
+                quarantine_capacity: 0,
+                priority: Default::default(),
             };
 
             // Just verify the config can be created
@@ -928,6 +960,9 @@ mod query_core_tests {
             dispatch_mode: None,
             storage_backend: None,
             recovery_policy: None,
+            result_processors: Vec::new(),
+            quarantine_capacity: 0,
+            priority: Default::default(),
         };
 
         // Empty queries should be caught during validation
@@ -0,0 +1,358 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Strongly-typed access to query results.
+//!
+//! Query rows are JSON objects keyed by the projected column names. The helpers
+//! in this module deserialize them into user-defined types and, when a row does
+//! not fit, report which column was missing or had the wrong type.
+//!
+//! ```no_run
+//! # use drasi_lib::DrasiLib;
+//! # async fn example(core: &DrasiLib) -> Result<(), Box<dyn std::error::Error>> {
+//! use drasi_lib::queries::TypedResultDiff;
+//!
+//! #[derive(serde::Deserialize, Debug)]
+//! struct Person {
+//!     name: String,
+//!     age: u32,
+//! }
+//!
+//! // Current state
+//! let people: Vec<Person> = core.query_results("people").await?.results_as()?;
+//!
+//! // Changes from now on
+//! let mut changes = core.subscribe_to_query_as::<Person>("people").await?;
+//! while let Some(change) = changes.recv().await {
+//!     match change? {
+//!         TypedResultDiff::Add(person) => println!("added {person:?}"),
+//!         TypedResultDiff::Delete(person) => println!("deleted {person:?}"),
+//!         TypedResultDiff::Update { before, after } => println!("{before:?} -> {after:?}"),
+//!         TypedResultDiff::Aggregation { after, .. } => println!("aggregate {after:?}"),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::marker::PhantomData;
+
+use serde::de::DeserializeOwned;
+use tokio::sync::broadcast;
+
+use crate::channels::ResultDiff;
+use crate::queries::QueryResultSnapshot;
+
+/// Error returned when a result row cannot be mapped into the requested type.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ResultDecodeError {
+    /// The row has no value for a field the target type requires.
+    #[error("missing column '{column}' (available columns: {})", available.join(", "))]
+    MissingColumn {
+        /// Path of the missing column, e.g. `name` or `address.city`.
+        column: String,
+        /// Columns present at the level where the column was expected.
+        available: Vec<String>,
+    },
+
+    /// A column is present but its value does not match the target field type.
+    #[error("column '{column}' has an unexpected value: {message}")]
+    InvalidColumn {
+        /// Path of the offending column.
+        column: String,
+        /// Description of the mismatch, as reported by serde.
+        message: String,
+    },
+
+    /// The row as a whole does not match the target type (e.g. it is not an object).
+    #[error("result row could not be decoded: {message}")]
+    InvalidRow {
+        /// Description of the mismatch, as reported by serde.
+        message: String,
+    },
+}
+
+/// Deserialize a single result row into `T`.
+pub fn decode_row<T: DeserializeOwned>(row: &serde_json::Value) -> Result<T, ResultDecodeError> {
+    serde_path_to_error::deserialize(row).map_err(|err| {
+        let path = err.path().to_string();
+        let message = err.inner().to_string();
+        let parent = (path != ".").then_some(path);
+
+        if let Some(field) = missing_field(&message) {
+            let column = match &parent {
+                Some(parent) => format!("{parent}.{field}"),
+                None => field.to_string(),
+            };
+            let available = parent
+                .as_deref()
+                .map_or(Some(row), |p| {
+                    row.pointer(&format!("/{}", p.replace('.', "/")))
+                })
+                .and_then(|v| v.as_object())
+                .map(|obj| obj.keys().cloned().collect())
+                .unwrap_or_default();
+            return ResultDecodeError::MissingColumn { column, available };
+        }
+
+        match parent {
+            Some(column) => ResultDecodeError::InvalidColumn { column, message },
+            None => ResultDecodeError::InvalidRow { message },
+        }
+    })
+}
+
+/// Extract the field name from serde's "missing field `x`" message.
+fn missing_field(message: &str) -> Option<&str> {
+    message.strip_prefix("missing field `")?.split('`').next()
+}
+
+impl QueryResultSnapshot {
+    /// Deserialize every row of the snapshot into `T`.
+    ///
+    /// Fails on the first row that does not fit `T`.
+    pub fn results_as<T: DeserializeOwned>(&self) -> Result<Vec<T>, ResultDecodeError> {
+        self.results.iter().map(decode_row).collect()
+    }
+}
+
+/// A [`ResultDiff`] whose rows have been deserialized into `T`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypedResultDiff<T> {
+    /// A row entered the result set.
+    Add(T),
+    /// A row left the result set.
+    Delete(T),
+    /// A row changed.
+    Update {
+        /// The row before the change.
+        before: T,
+        /// The row after the change.
+        after: T,
+    },
+    /// An aggregate value changed.
+    Aggregation {
+        /// The previous aggregate, if any.
+        before: Option<T>,
+        /// The new aggregate.
+        after: T,
+    },
+}
+
+impl<T: DeserializeOwned> TypedResultDiff<T> {
+    /// Decode a raw diff. Returns `Ok(None)` for [`ResultDiff::Noop`].
+    pub fn decode(diff: &ResultDiff) -> Result<Option<Self>, ResultDecodeError> {
+        Ok(Some(match diff {
+            ResultDiff::Add { data } => Self::Add(decode_row(data)?),
+            ResultDiff::Delete { data } => Self::Delete(decode_row(data)?),
+            ResultDiff::Update { before, after, .. } => Self::Update {
+                before: decode_row(before)?,
+                after: decode_row(after)?,
+            },
+            ResultDiff::Aggregation { before, after } => Self::Aggregation {
+                before: before.as_ref().map(decode_row).transpose()?,
+                after: decode_row(after)?,
+            },
+            ResultDiff::Noop => return Ok(None),
+        }))
+    }
+}
+
+/// Error yielded by [`TypedSubscription::recv`].
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum TypedRecvError {
+    /// A diff was received but its rows did not fit the target type.
+    /// The subscription remains usable.
+    #[error(transparent)]
+    Decode(#[from] ResultDecodeError),
+
+    /// The subscriber fell behind and this many diffs were dropped.
+    /// Re-sync with [`DrasiLib::query_results`](crate::DrasiLib::query_results).
+    #[error("subscription lagged behind by {0} result diffs")]
+    Lagged(u64),
+}
+
+/// Subscription to a query's result diffs, decoded into `T`.
+///
+/// Created with [`DrasiLib::subscribe_to_query_as`](crate::DrasiLib::subscribe_to_query_as).
+#[derive(Debug)]
+pub struct TypedSubscription<T> {
+    receiver: broadcast::Receiver<ResultDiff>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: DeserializeOwned> TypedSubscription<T> {
+    /// Wrap a raw receiver obtained from
+    /// [`DrasiLib::subscribe_to_query`](crate::DrasiLib::subscribe_to_query).
+    pub fn new(receiver: broadcast::Receiver<ResultDiff>) -> Self {
+        Self {
+            receiver,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Wait for the next change. Returns `None` once the query has been removed.
+    pub async fn recv(&mut self) -> Option<Result<TypedResultDiff<T>, TypedRecvError>> {
+        loop {
+            match self.receiver.recv().await {
+                Ok(diff) => match TypedResultDiff::decode(&diff) {
+                    Ok(Some(typed)) => return Some(Ok(typed)),
+                    Ok(None) => continue,
+                    Err(e) => return Some(Err(e.into())),
+                },
+                Err(broadcast::error::RecvError::Lagged(n)) => {
+                    return Some(Err(TypedRecvError::Lagged(n)))
+                }
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    }
+
+    /// Unwrap into the underlying raw receiver.
+    pub fn into_inner(self) -> broadcast::Receiver<ResultDiff> {
+        self.receiver
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Person {
+        name: String,
+        age: u32,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Address {
+        city: String,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Resident {
+        name: String,
+        address: Address,
+    }
+
+    #[test]
+    fn test_decode_row() {
+        let person: Person = decode_row(&json!({"name": "Alice", "age": 30})).unwrap();
+        assert_eq!(
+            person,
+            Person {
+                name: "Alice".to_string(),
+                age: 30
+            }
+        );
+    }
+
+    #[test]
+    fn test_missing_column_lists_available_columns() {
+        let err = decode_row::<Person>(&json!({"name": "Alice", "years": 30})).unwrap_err();
+        assert_eq!(
+            err,
+            ResultDecodeError::MissingColumn {
+                column: "age".to_string(),
+                available: vec!["name".to_string(), "years".to_string()],
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "missing column 'age' (available columns: name, years)"
+        );
+    }
+
+    #[test]
+    fn test_missing_nested_column() {
+        let err = decode_row::<Resident>(&json!({"name": "Bob", "address": {"town": "Oslo"}}))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ResultDecodeError::MissingColumn {
+                column: "address.city".to_string(),
+                available: vec!["town".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn test_mistyped_column_names_column() {
+        let err = decode_row::<Person>(&json!({"name": "Alice", "age": "thirty"})).unwrap_err();
+        match err {
+            ResultDecodeError::InvalidColumn { column, message } => {
+                assert_eq!(column, "age");
+                assert!(message.contains("invalid type"), "{message}");
+            }
+            other => panic!("expected InvalidColumn, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_non_object_row() {
+        let err = decode_row::<Person>(&json!(42)).unwrap_err();
+        assert!(matches!(err, ResultDecodeError::InvalidRow { .. }));
+    }
+
+    #[test]
+    fn test_snapshot_results_as() {
+        let snapshot = QueryResultSnapshot {
+            query_id: "q".to_string(),
+            results: vec![json!({"name": "A", "age": 1}), json!({"name": "B", "age": 2})],
+            sequence: 1,
+            last_updated: None,
+            timestamp: chrono::Utc::now(),
+        };
+        let people: Vec<Person> = snapshot.results_as().unwrap();
+        assert_eq!(people.len(), 2);
+        assert_eq!(people[1].name, "B");
+    }
+
+    #[tokio::test]
+    async fn test_typed_subscription() {
+        let (tx, rx) = broadcast::channel(8);
+        let mut sub = TypedSubscription::<Person>::new(rx);
+
+        tx.send(ResultDiff::Noop).unwrap();
+        tx.send(ResultDiff::Update {
+            data: json!({"name": "A", "age": 2}),
+            before: json!({"name": "A", "age": 1}),
+            after: json!({"name": "A", "age": 2}),
+            grouping_keys: None,
+        })
+        .unwrap();
+        tx.send(ResultDiff::Add {
+            data: json!({"name": "B"}),
+        })
+        .unwrap();
+        drop(tx);
+
+        match sub.recv().await.unwrap().unwrap() {
+            TypedResultDiff::Update { before, after } => {
+                assert_eq!(before.age, 1);
+                assert_eq!(after.age, 2);
+            }
+            other => panic!("expected Update, got {other:?}"),
+        }
+        assert!(matches!(
+            sub.recv().await.unwrap(),
+            Err(TypedRecvError::Decode(
+                ResultDecodeError::MissingColumn { .. }
+            ))
+        ));
+        assert!(sub.recv().await.is_none());
+    }
+}
@@ -31,6 +31,7 @@
 
 use anyhow::Result;
 use log::{debug, error, info, warn};
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tracing::Instrument;
@@ -170,6 +171,31 @@ impl ReactionBase {
                 *guard = Some(ip.clone());
             }
         }
+
+        if let Some(metrics) = context.metrics.as_ref() {
+            let labels = [("reaction", self.id.as_str())];
+            let queue = self.priority_queue.metrics_handle();
+            let enqueued = queue.clone();
+            metrics.counter_fn(
+                "drasi_reaction_results_total",
+                "Query results received",
+                &labels,
+                move || Some(enqueued.total_enqueued.load(Ordering::Relaxed) as f64),
+            );
+            let depth = queue.clone();
+            metrics.gauge_fn(
+                "drasi_reaction_queue_depth",
+                "Results waiting in the reaction's priority queue",
+                &labels,
+                move || Some(depth.current_depth.load(Ordering::Relaxed) as f64),
+            );
+            metrics.counter_fn(
+                "drasi_reaction_queue_dropped_total",
+                "Results dropped because the priority queue was full",
+                &labels,
+                move || Some(queue.drops_due_to_capacity.load(Ordering::Relaxed) as f64),
+            );
+        }
     }
 
     /// Get the runtime context if initialized.
@@ -370,7 +396,10 @@ impl ReactionBase {
     }
 
     /// Set the processing task handle
+    ///
+    /// If the task panics, the reaction's status is set to `Error`.
     pub async fn set_processing_task(&self, task: tokio::task::JoinHandle<()>) {
+        let task = crate::supervisor::report_panic(task, self.status_handle(), self.id.clone());
         *self.processing_task.write().await = Some(task);
     }
 }
@@ -0,0 +1,757 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Debouncing decorator for reactions.
+//!
+//! `DebouncedReaction` wraps any [`Reaction`] and coalesces rapid successive
+//! changes to the same result row. Changes are held until the row has been
+//! quiet for the configured window, then only the net change is forwarded:
+//!
+//! | Changes within the window | Forwarded |
+//! |---------------------------|-----------|
+//! | ADD, UPDATE, UPDATE | ADD with the final row |
+//! | UPDATE, UPDATE | UPDATE from the first `before` to the last `after` |
+//! | UPDATE, DELETE | DELETE of the first `before` |
+//! | ADD, DELETE | nothing |
+//! | DELETE, ADD | UPDATE from the deleted to the added row |
+//!
+//! Updates that end where they started are dropped. Rows are identified by
+//! the values of `key_fields`; changes to rows without them are forwarded
+//! immediately.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use drasi_lib::reactions::{DebounceConfig, DebouncedReaction};
+//!
+//! let reaction = DebouncedReaction::new(
+//!     http_reaction,
+//!     DebounceConfig {
+//!         window_ms: 500,
+//!         max_delay_ms: Some(5000),
+//!         key_fields: vec!["sensorId".to_string()],
+//!     },
+//! )?;
+//! drasi.add_reaction(reaction).await?;
+//! ```
+
+use anyhow::Result;
+use async_trait::async_trait;
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{oneshot, Mutex, Notify};
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+
+use crate::channels::{ComponentStatus, QueryResult, ResultDiff};
+use crate::context::ReactionRuntimeContext;
+use crate::reactions::Reaction;
+
+fn default_debounce_window_ms() -> u64 {
+    1000
+}
+
+/// Debouncing configuration
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DebounceConfig {
+    /// Time in milliseconds a row must be unchanged before its changes are forwarded
+    #[serde(default = "default_debounce_window_ms")]
+    pub window_ms: u64,
+
+    /// Maximum time in milliseconds changes of a row are held, even if it keeps
+    /// changing. Without it, a row changing faster than the window is never forwarded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_delay_ms: Option<u64>,
+
+    /// Result fields identifying a row
+    pub key_fields: Vec<String>,
+}
+
+impl Default for DebounceConfig {
+    fn default() -> Self {
+        Self {
+            window_ms: default_debounce_window_ms(),
+            max_delay_ms: None,
+            key_fields: Vec::new(),
+        }
+    }
+}
+
+/// Net change of one row within the window
+struct PendingChange {
+    /// Order of the first change, so forwarded changes keep their arrival order
+    seq: u64,
+    /// Row before the first change, `None` if it did not exist
+    initial: Option<Value>,
+    /// Row after the last change, `None` if it was deleted
+    current: Option<Value>,
+    aggregation: bool,
+    grouping_keys: Option<Vec<String>>,
+    first_seen: Instant,
+    last_seen: Instant,
+    timestamp: chrono::DateTime<chrono::Utc>,
+    metadata: HashMap<String, Value>,
+}
+
+impl PendingChange {
+    /// The change to forward, or `None` if the row ends where it started
+    fn into_diff(self) -> Option<ResultDiff> {
+        if self.aggregation {
+            return self.current.map(|after| ResultDiff::Aggregation {
+                before: self.initial,
+                after,
+            });
+        }
+        match (self.initial, self.current) {
+            (None, Some(after)) => Some(ResultDiff::Add { data: after }),
+            (Some(before), Some(after)) if before != after => Some(ResultDiff::Update {
+                data: after.clone(),
+                before,
+                after,
+                grouping_keys: self.grouping_keys,
+            }),
+            (Some(before), None) => Some(ResultDiff::Delete { data: before }),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Default)]
+struct PendingChanges {
+    changes: HashMap<(String, String), PendingChange>,
+    next_seq: u64,
+}
+
+impl PendingChanges {
+    /// Record a change of the row with the given key
+    #[allow(clippy::too_many_arguments)]
+    fn record(
+        &mut self,
+        query_result: &QueryResult,
+        key: String,
+        before: Option<&Value>,
+        after: Option<&Value>,
+        aggregation: bool,
+        grouping_keys: Option<&Vec<String>>,
+        now: Instant,
+    ) {
+        let seq = self.next_seq;
+        let change = self
+            .changes
+            .entry((query_result.query_id.clone(), key))
+            .or_insert_with(|| PendingChange {
+                seq,
+                initial: before.cloned(),
+                current: None,
+                aggregation,
+                grouping_keys: None,
+                first_seen: now,
+                last_seen: now,
+                timestamp: query_result.timestamp,
+                metadata: HashMap::new(),
+            });
+        if change.seq == seq {
+            self.next_seq += 1;
+        }
+        change.current = after.cloned();
+        change.aggregation |= aggregation;
+        if grouping_keys.is_some() {
+            change.grouping_keys = grouping_keys.cloned();
+        }
+        change.last_seen = now;
+        change.timestamp = query_result.timestamp;
+        change.metadata = query_result.metadata.clone();
+    }
+}
+
+/// State shared between the reaction and its flush task
+struct Shared<R> {
+    inner: R,
+    config: DebounceConfig,
+    pending: Mutex<PendingChanges>,
+    changed: Notify,
+}
+
+impl<R: Reaction> Shared<R> {
+    fn window(&self) -> Duration {
+        Duration::from_millis(self.config.window_ms)
+    }
+
+    fn deadline(&self, change: &PendingChange) -> Instant {
+        let deadline = change.last_seen + self.window();
+        match self.config.max_delay_ms {
+            Some(max_delay_ms) => {
+                deadline.min(change.first_seen + Duration::from_millis(max_delay_ms))
+            }
+            None => deadline,
+        }
+    }
+
+    /// Key of a row from its key fields, `None` if any is missing
+    fn key(&self, row: &Value) -> Option<String> {
+        let values = self
+            .config
+            .key_fields
+            .iter()
+            .map(|field| row.get(field).filter(|value| !value.is_null()))
+            .collect::<Option<Vec<_>>>()?;
+        serde_json::to_string(&values).ok()
+    }
+
+    /// Hold keyed changes and return the ones to forward immediately
+    async fn record(&self, query_result: &QueryResult) -> Vec<ResultDiff> {
+        let now = Instant::now();
+        let mut pending = self.pending.lock().await;
+        let mut passthrough = Vec::new();
+
+        for diff in &query_result.results {
+            match diff {
+                ResultDiff::Add { data } => match self.key(data) {
+                    Some(key) => {
+                        pending.record(query_result, key, None, Some(data), false, None, now)
+                    }
+                    None => passthrough.push(diff.clone()),
+                },
+                ResultDiff::Delete { data } => match self.key(data) {
+                    Some(key) => {
+                        pending.record(query_result, key, Some(data), None, false, None, now)
+                    }
+                    None => passthrough.push(diff.clone()),
+                },
+                ResultDiff::Update {
+                    before,
+                    after,
+                    grouping_keys,
+                    ..
+                } => match (self.key(before), self.key(after)) {
+                    (Some(old_key), Some(new_key)) if old_key == new_key => pending.record(
+                        query_result,
+                        new_key,
+                        Some(before),
+                        Some(after),
+                        false,
+                        grouping_keys.as_ref(),
+                        now,
+                    ),
+                    // A changed key removes the old row and adds the new one
+                    (Some(old_key), Some(new_key)) => {
+                        pending.record(query_result, old_key, Some(before), None, false, None, now);
+                        pending.record(query_result, new_key, None, Some(after), false, None, now);
+                    }
+                    _ => passthrough.push(diff.clone()),
+                },
+                ResultDiff::Aggregation { before, after } => match self.key(after) {
+                    Some(key) => pending.record(
+                        query_result,
+                        key,
+                        before.as_ref(),
+                        Some(after),
+                        true,
+                        None,
+                        now,
+                    ),
+                    None => passthrough.push(diff.clone()),
+                },
+                ResultDiff::Noop => {}
+            }
+        }
+
+        passthrough
+    }
+
+    /// Remove the changes due at `now`, or all with `None`, and return the
+    /// time the next change is due
+    async fn take_due(&self, now: Option<Instant>) -> (Vec<QueryResult>, Option<Instant>) {
+        let mut pending = self.pending.lock().await;
+        let due_keys: Vec<_> = pending
+            .changes
+            .iter()
+            .filter(|(_, change)| now.is_none_or(|now| self.deadline(change) <= now))
+            .map(|(key, _)| key.clone())
+            .collect();
+        let mut due: Vec<(String, PendingChange)> = due_keys
+            .into_iter()
+            .filter_map(|key| pending.changes.remove_entry(&key))
+            .map(|((query_id, _), change)| (query_id, change))
+            .collect();
+        let next_deadline = pending
+            .changes
+            .values()
+            .map(|change| self.deadline(change))
+            .min();
+        drop(pending);
+
+        due.sort_by_key(|(_, change)| change.seq);
+        let mut results: Vec<QueryResult> = Vec::new();
+        for (query_id, change) in due {
+            let timestamp = change.timestamp;
+            let metadata = change.metadata.clone();
+            let Some(diff) = change.into_diff() else {
+                continue;
+            };
+            // Consecutive changes of the same query are forwarded together
+            match results.last_mut() {
+                Some(result) if result.query_id == query_id => {
+                    result.timestamp = result.timestamp.max(timestamp);
+                    result.metadata = metadata;
+                    result.results.push(diff);
+                }
+                _ => results.push(QueryResult::new(query_id, timestamp, vec![diff], metadata)),
+            }
+        }
+        (results, next_deadline)
+    }
+
+    async fn forward(&self, results: Vec<QueryResult>) {
+        for result in results {
+            debug!(
+                "[{}] Forwarding {} debounced changes of query '{}'",
+                self.inner.id(),
+                result.results.len(),
+                result.query_id
+            );
+            if let Err(e) = self.inner.enqueue_query_result(result).await {
+                warn!(
+                    "[{}] Failed to forward debounced changes: {e}",
+                    self.inner.id()
+                );
+            }
+        }
+    }
+
+    /// Forward due changes until shutdown
+    async fn run(self: Arc<Self>, mut shutdown_rx: oneshot::Receiver<()>) {
+        loop {
+            let (results, next_deadline) = self.take_due(Some(Instant::now())).await;
+            self.forward(results).await;
+
+            let sleep = async {
+                match next_deadline {
+                    Some(deadline) => tokio::time::sleep_until(deadline).await,
+                    None => std::future::pending().await,
+                }
+            };
+            tokio::select! {
+                biased;
+                _ = &mut shutdown_rx => break,
+                _ = self.changed.notified() => {}
+                _ = sleep => {}
+            }
+        }
+    }
+}
+
+/// Reaction decorator coalescing rapid successive changes of the same row.
+///
+/// All [`Reaction`] methods delegate to the wrapped reaction, except that
+/// query results are debounced before they are enqueued. Changes still held
+/// when the reaction stops are forwarded before the wrapped reaction stops.
+pub struct DebouncedReaction<R: Reaction + 'static> {
+    shared: Arc<Shared<R>>,
+    flush_task: Mutex<Option<(JoinHandle<()>, oneshot::Sender<()>)>>,
+}
+
+impl<R: Reaction + 'static> DebouncedReaction<R> {
+    /// Wrap a reaction
+    ///
+    /// # Errors
+    ///
+    /// - Returns error if `window_ms` or `max_delay_ms` is 0
+    /// - Returns error if `key_fields` is empty or contains an empty field
+    pub fn new(inner: R, config: DebounceConfig) -> Result<Self> {
+        if config.window_ms == 0 {
+            return Err(anyhow::anyhow!(
+                "Validation error: window_ms must be greater than 0"
+            ));
+        }
+        if config.max_delay_ms == Some(0) {
+            return Err(anyhow::anyhow!(
+                "Validation error: max_delay_ms must be greater than 0"
+            ));
+        }
+        if config.key_fields.is_empty() || config.key_fields.iter().any(String::is_empty) {
+            return Err(anyhow::anyhow!(
+                "Validation error: key_fields must contain at least one non-empty field"
+            ));
+        }
+
+        Ok(Self {
+            shared: Arc::new(Shared {
+                inner,
+                config,
+                pending: Mutex::new(PendingChanges::default()),
+                changed: Notify::new(),
+            }),
+            flush_task: Mutex::new(None),
+        })
+    }
+
+    /// The wrapped reaction
+    pub fn inner(&self) -> &R {
+        &self.shared.inner
+    }
+
+    /// The debouncing configuration
+    pub fn config(&self) -> &DebounceConfig {
+        &self.shared.config
+    }
+}
+
+#[async_trait]
+impl<R: Reaction + 'static> Reaction for DebouncedReaction<R> {
+    fn id(&self) -> &str {
+        self.shared.inner.id()
+    }
+
+    fn type_name(&self) -> &str {
+        self.shared.inner.type_name()
+    }
+
+    fn properties(&self) -> HashMap<String, Value> {
+        let mut properties = self.shared.inner.properties();
+        if let Ok(debounce) = serde_json::to_value(&self.shared.config) {
+            properties.insert("debounce".to_string(), debounce);
+        }
+        properties
+    }
+
+    fn query_ids(&self) -> Vec<String> {
+        self.shared.inner.query_ids()
+    }
+
+    fn auto_start(&self) -> bool {
+        self.shared.inner.auto_start()
+    }
+
+    async fn initialize(&self, context: ReactionRuntimeContext) {
+        self.shared.inner.initialize(context).await;
+    }
+
+    async fn start(&self) -> Result<()> {
+        self.shared.inner.start().await?;
+
+        let mut flush_task = self.flush_task.lock().await;
+        if flush_task.is_none() {
+            let (shutdown_tx, shutdown_rx) = oneshot::channel();
+            let task = tokio::spawn(self.shared.clone().run(shutdown_rx));
+            *flush_task = Some((task, shutdown_tx));
+        }
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        if let Some((task, shutdown_tx)) = self.flush_task.lock().await.take() {
+            let _ = shutdown_tx.send(());
+            if let Err(e) = task.await {
+                warn!("[{}] Debounce flush task failed: {e}", self.id());
+            }
+        }
+        let (results, _) = self.shared.take_due(None).await;
+        self.shared.forward(results).await;
+
+        self.shared.inner.stop().await
+    }
+
+    async fn status(&self) -> ComponentStatus {
+        self.shared.inner.status().await
+    }
+
+    async fn enqueue_query_result(&self, result: QueryResult) -> Result<()> {
+        let passthrough = self.shared.record(&result).await;
+        self.shared.changed.notify_one();
+
+        if passthrough.is_empty() {
+            return Ok(());
+        }
+        self.shared
+            .inner
+            .enqueue_query_result(QueryResult::new(
+                result.query_id,
+                result.timestamp,
+                passthrough,
+                result.metadata,
+            ))
+            .await
+    }
+
+    async fn deprovision(&self) -> Result<()> {
+        self.shared.inner.deprovision().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::sync::Mutex as StdMutex;
+
+    /// Reaction recording the results it receives
+    #[derive(Default)]
+    struct RecordingReaction {
+        results: StdMutex<Vec<QueryResult>>,
+        stopped: StdMutex<bool>,
+    }
+
+    impl RecordingReaction {
+        fn diffs(&self) -> Vec<ResultDiff> {
+            self.results
+                .lock()
+                .unwrap()
+                .iter()
+                .flat_map(|result| result.results.clone())
+                .collect()
+        }
+    }
+
+    #[async_trait]
+    impl Reaction for RecordingReaction {
+        fn id(&self) -> &str {
+            "recording"
+        }
+
+        fn type_name(&self) -> &str {
+            "recording"
+        }
+
+        fn properties(&self) -> HashMap<String, Value> {
+            HashMap::from([("inner".to_string(), json!(true))])
+        }
+
+        fn query_ids(&self) -> Vec<String> {
+            vec!["sensors".to_string()]
+        }
+
+        async fn initialize(&self, _context: ReactionRuntimeContext) {}
+
+        async fn start(&self) -> Result<()> {
+            Ok(())
+        }
+
+        async fn stop(&self) -> Result<()> {
+            *self.stopped.lock().unwrap() = true;
+            Ok(())
+        }
+
+        async fn status(&self) -> ComponentStatus {
+            ComponentStatus::Running
+        }
+
+        async fn enqueue_query_result(&self, result: QueryResult) -> Result<()> {
+            self.results.lock().unwrap().push(result);
+            Ok(())
+        }
+    }
+
+    fn debounced(
+        window_ms: u64,
+        max_delay_ms: Option<u64>,
+    ) -> DebouncedReaction<RecordingReaction> {
+        DebouncedReaction::new(
+            RecordingReaction::default(),
+            DebounceConfig {
+                window_ms,
+                max_delay_ms,
+                key_fields: vec!["id".to_string()],
+            },
+        )
+        .unwrap()
+    }
+
+    fn result(results: Vec<ResultDiff>) -> QueryResult {
+        QueryResult::new(
+            "sensors".to_string(),
+            chrono::Utc::now(),
+            results,
+            HashMap::new(),
+        )
+    }
+
+    fn add(id: i64, value: i64) -> ResultDiff {
+        ResultDiff::Add {
+            data: json!({ "id": id, "value": value }),
+        }
+    }
+
+    fn update(id: i64, before: i64, after: i64) -> ResultDiff {
+        ResultDiff::Update {
+            data: json!({ "id": id, "value": after }),
+            before: json!({ "id": id, "value": before }),
+            after: json!({ "id": id, "value": after }),
+            grouping_keys: None,
+        }
+    }
+
+    fn delete(id: i64, value: i64) -> ResultDiff {
+        ResultDiff::Delete {
+            data: json!({ "id": id, "value": value }),
+        }
+    }
+
+    async fn wait_for_diffs(
+        reaction: &DebouncedReaction<RecordingReaction>,
+        count: usize,
+    ) -> Vec<ResultDiff> {
+        tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                let diffs = reaction.inner().diffs();
+                if diffs.len() >= count {
+                    return diffs;
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .unwrap()
+    }
+
+    #[test]
+    fn test_config_validation() {
+        let config = |window_ms, max_delay_ms, key_fields: Vec<&str>| DebounceConfig {
+            window_ms,
+            max_delay_ms,
+            key_fields: key_fields.into_iter().map(String::from).collect(),
+        };
+        let new = |config| DebouncedReaction::new(RecordingReaction::default(), config);
+
+        assert!(new(config(0, None, vec!["id"])).is_err());
+        assert!(new(config(100, Some(0), vec!["id"])).is_err());
+        assert!(new(config(100, None, vec![])).is_err());
+        assert!(new(config(100, None, vec![""])).is_err());
+        assert!(new(config(100, Some(1000), vec!["id"])).is_ok());
+    }
+
+    #[test]
+    fn test_delegates_to_inner_reaction() {
+        let reaction = debounced(250, None);
+        assert_eq!(reaction.id(), "recording");
+        assert_eq!(reaction.type_name(), "recording");
+        assert_eq!(reaction.query_ids(), vec!["sensors"]);
+        let properties = reaction.properties();
+        assert_eq!(properties.get("inner"), Some(&json!(true)));
+        assert_eq!(
+            properties.get("debounce"),
+            Some(&json!({ "window_ms": 250, "key_fields": ["id"] }))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_coalesces_changes_per_key() {
+        let reaction = debounced(100, None);
+        reaction.start().await.unwrap();
+
+        reaction
+            .enqueue_query_result(result(vec![add(1, 10), update(2, 20, 21)]))
+            .await
+            .unwrap();
+        reaction
+            .enqueue_query_result(result(vec![update(1, 10, 11), update(2, 21, 22)]))
+            .await
+            .unwrap();
+        reaction
+            .enqueue_query_result(result(vec![update(1, 11, 12), add(3, 30), delete(3, 30)]))
+            .await
+            .unwrap();
+        assert!(reaction.inner().diffs().is_empty());
+
+        // Rows become due independently, so their order is not fixed
+        let diffs = wait_for_diffs(&reaction, 2).await;
+        assert!(diffs.contains(&add(1, 12)));
+        assert!(diffs.contains(&update(2, 20, 22)));
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert_eq!(reaction.inner().diffs().len(), 2);
+        reaction.stop().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_net_changes() {
+        let reaction = debounced(50, None);
+        reaction.start().await.unwrap();
+
+        reaction
+            .enqueue_query_result(result(vec![
+                update(1, 10, 11),
+                delete(1, 11),
+                delete(2, 20),
+                add(2, 21),
+                update(3, 30, 31),
+                update(3, 31, 30),
+            ]))
+            .await
+            .unwrap();
+
+        let diffs = wait_for_diffs(&reaction, 2).await;
+        assert_eq!(diffs, vec![delete(1, 10), update(2, 20, 21)]);
+        reaction.stop().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_max_delay_forwards_busy_rows() {
+        let reaction = debounced(200, Some(300));
+        reaction.start().await.unwrap();
+
+        let started = Instant::now();
+        for value in 0..20 {
+            reaction
+                .enqueue_query_result(result(vec![update(1, value, value + 1)]))
+                .await
+                .unwrap();
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            if !reaction.inner().diffs().is_empty() {
+                break;
+            }
+        }
+
+        let diffs = reaction.inner().diffs();
+        assert_eq!(diffs.len(), 1);
+        assert!(started.elapsed() < Duration::from_millis(900));
+        reaction.stop().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rows_without_key_pass_through() {
+        let reaction = debounced(10_000, None);
+        reaction.start().await.unwrap();
+
+        let unkeyed = ResultDiff::Add {
+            data: json!({ "value": 1 }),
+        };
+        reaction
+            .enqueue_query_result(result(vec![add(1, 10), unkeyed.clone(), ResultDiff::Noop]))
+            .await
+            .unwrap();
+
+        assert_eq!(reaction.inner().diffs(), vec![unkeyed]);
+        reaction.stop().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_stop_flushes_pending_changes() {
+        let reaction = debounced(10_000, None);
+        reaction.start().await.unwrap();
+
+        reaction
+            .enqueue_query_result(result(vec![add(1, 10), update(1, 10, 11)]))
+            .await
+            .unwrap();
+        reaction.stop().await.unwrap();
+
+        assert_eq!(reaction.inner().diffs(), vec![add(1, 11)]);
+        assert!(*reaction.inner().stopped.lock().unwrap());
+    }
+}
@@ -16,8 +16,10 @@
 
 pub mod base;
 pub mod config;
+pub mod debounce;
 pub mod templates;
 
 pub use base::ReactionBase;
 pub use config::AdaptiveBatchConfig;
+pub use debounce::{DebounceConfig, DebouncedReaction};
 pub use templates::{OperationType, QueryConfig, TemplateRouting, TemplateSpec};
@@ -25,6 +25,7 @@ use crate::config::ReactionRuntime;
 use crate::context::ReactionRuntimeContext;
 use crate::identity::IdentityProvider;
 use crate::managers::{log_component_error, ComponentLogKey, ComponentLogRegistry};
+use crate::metrics::MetricsRegistry;
 use crate::queries::Query;
 use crate::reactions::{QueryProvider, Reaction};
 use crate::state_store::StateStoreProvider;
@@ -37,6 +38,8 @@ pub struct ReactionManager {
     state_store: Arc<RwLock<Option<Arc<dyn StateStoreProvider>>>>,
     /// Identity provider for credential injection
     identity_provider: Arc<RwLock<Option<Arc<dyn IdentityProvider>>>>,
+    /// Metrics registry passed to reactions
+    metrics: Arc<RwLock<Option<Arc<MetricsRegistry>>>>,
     /// Log registry for component log streaming
     log_registry: Arc<ComponentLogRegistry>,
     /// Handles to subscription forwarder tasks per reaction
@@ -68,6 +71,7 @@ impl ReactionManager {
             query_provider: Arc::new(RwLock::new(None)),
             state_store: Arc::new(RwLock::new(None)),
             identity_provider: Arc::new(RwLock::new(None)),
+            metrics: Arc::new(RwLock::new(None)),
             log_registry,
             subscription_tasks: Arc::new(RwLock::new(HashMap::new())),
             graph,
@@ -96,6 +100,13 @@ impl ReactionManager {
         *self.identity_provider.write().await = Some(identity_provider);
     }
 
+    /// Inject the metrics registry (called after DrasiLib is fully constructed)
+    ///
+    /// Reactions added afterwards record their queue metrics into it.
+    pub async fn inject_metrics(&self, metrics: Arc<MetricsRegistry>) {
+        *self.metrics.write().await = Some(metrics);
+    }
+
     /// Add a reaction instance, taking ownership and wrapping it in an Arc internally.
     ///
     /// This method handles runtime-only operations: creating the runtime context,
@@ -122,6 +133,9 @@ impl ReactionManager {
             None,
         );
         context.identity_provider = self.identity_provider.read().await.clone();
+        if let Some(metrics) = self.metrics.read().await.clone() {
+            context = context.with_metrics(metrics);
+        }
 
         // Initialize the reaction with its runtime context
         reaction.initialize(context).await;
@@ -176,6 +190,14 @@ impl ReactionManager {
         Ok(())
     }
 
+    /// Start a failed reaction again, replacing its subscription forwarder tasks.
+    ///
+    /// Used by the supervisor, which has already moved the reaction to `Starting`.
+    pub(crate) async fn restart_reaction(&self, id: String) -> Result<()> {
+        self.abort_subscription_tasks(&id).await;
+        self.start_reaction(id).await
+    }
+
     /// Stop a running reaction and abort its subscription forwarder tasks.
     ///
     /// # Errors
@@ -261,6 +283,9 @@ impl ReactionManager {
 
         // Also abort any remaining subscription tasks after teardown
         self.abort_subscription_tasks(&id).await;
+        if let Some(metrics) = self.metrics.read().await.as_ref() {
+            metrics.remove_series("reaction", &id);
+        }
         Ok(())
     }
 
@@ -293,6 +318,7 @@ impl ReactionManager {
             let graph = &self.graph;
             let instance_id = &self.instance_id;
             let state_store = &self.state_store;
+            let metrics = &self.metrics;
             let update_tx = &self.update_tx;
 
             crate::managers::lifecycle_helpers::reconfigure_component::<Arc<dyn Reaction>, _, _, _>(
@@ -303,13 +329,16 @@ impl ReactionManager {
                 || self.abort_subscription_tasks(&id),
                 || async {
                     let new_reaction: Arc<dyn Reaction> = Arc::new(new_reaction);
-                    let context = ReactionRuntimeContext::new(
+                    let mut context = ReactionRuntimeContext::new(
                         instance_id,
                         &id,
                         state_store.read().await.clone(),
                         update_tx.clone(),
                         None,
                     );
+                    if let Some(metrics) = metrics.read().await.clone() {
+                        context = context.with_metrics(metrics);
+                    }
                     new_reaction.initialize(context).await;
 
                     let mut g = graph.write().await;
@@ -477,6 +506,7 @@ impl ReactionManager {
             let reaction = reaction.clone();
             let query_id_clone = query_id.clone();
             let reaction_id_owned = reaction_id.to_string();
+            let instance_id_owned = instance_id.clone();
 
             let query_config = query.get_config();
             let dispatch_mode = query_config
@@ -502,8 +532,13 @@ impl ReactionManager {
                         match receiver.recv().await {
                             Ok(query_result) => {
                                 // Unwrap Arc or clone if shared
-                                let result = Arc::try_unwrap(query_result)
+                                let mut result = Arc::try_unwrap(query_result)
                                     .unwrap_or_else(|arc| (*arc).clone());
+                                crate::telemetry::start_reaction(
+                                    &mut result,
+                                    &instance_id_owned,
+                                    &reaction_id_owned,
+                                );
                                 if let Err(e) = reaction.enqueue_query_result(result).await {
                                     log::error!(
                                         "[{reaction_id_owned}] Failed to enqueue result from query '{query_id_clone}': {e}"
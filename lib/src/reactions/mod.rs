@@ -24,4 +24,5 @@ pub(crate) use traits::QueryProvider;
 pub use traits::Reaction;
 
 pub use common::base::{ReactionBase, ReactionBaseParams};
+pub use common::debounce::{DebounceConfig, DebouncedReaction};
 pub use manager::ReactionManager;
@@ -0,0 +1,314 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Factory registry for creating sources and reactions by kind.
+//!
+//! drasi-lib only knows the [`Source`] and [`Reaction`] traits. A
+//! [`ComponentRegistry`] maps a kind name (e.g. `"postgres"`, `"log"`) to a
+//! factory that builds an instance from its JSON configuration, so components
+//! can be created from serialized config at runtime — by the configuration
+//! file loader, by [`DrasiLib::add_source_from_config`](crate::DrasiLib::add_source_from_config)
+//! or by a management API.
+//!
+//! Plain closures are factories:
+//!
+//! ```rust,ignore
+//! use drasi_lib::ComponentRegistry;
+//!
+//! let mut registry = ComponentRegistry::new();
+//! registry.register_source(
+//!     "postgres",
+//!     |id: &str, config: &serde_json::Value, _auto_start: bool| -> anyhow::Result<Box<dyn Source>> {
+//!         let config: PostgresSourceConfig = serde_json::from_value(config.clone())?;
+//!         Ok(Box::new(PostgresReplicationSource::new(id, config)?))
+//!     },
+//! );
+//! ```
+//!
+//! Plugin crates expose their descriptors, which `drasi-plugin-sdk` adapts
+//! into factories (see `ComponentRegistryExt` there).
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::error::{DrasiError, Result};
+use crate::reactions::Reaction;
+use crate::sources::Source;
+
+/// Creates source instances of one kind from JSON configuration.
+#[async_trait]
+pub trait SourceFactory: Send + Sync {
+    /// Create a source with the given id and kind-specific configuration.
+    async fn create_source(
+        &self,
+        id: &str,
+        config: &Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn Source>>;
+}
+
+/// Creates reaction instances of one kind from JSON configuration.
+#[async_trait]
+pub trait ReactionFactory: Send + Sync {
+    /// Create a reaction with the given id, subscribed queries and kind-specific configuration.
+    async fn create_reaction(
+        &self,
+        id: &str,
+        query_ids: Vec<String>,
+        config: &Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn Reaction>>;
+}
+
+#[async_trait]
+impl<F> SourceFactory for F
+where
+    F: Fn(&str, &Value, bool) -> anyhow::Result<Box<dyn Source>> + Send + Sync,
+{
+    async fn create_source(
+        &self,
+        id: &str,
+        config: &Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn Source>> {
+        self(id, config, auto_start)
+    }
+}
+
+#[async_trait]
+impl<F> ReactionFactory for F
+where
+    F: Fn(&str, Vec<String>, &Value, bool) -> anyhow::Result<Box<dyn Reaction>> + Send + Sync,
+{
+    async fn create_reaction(
+        &self,
+        id: &str,
+        query_ids: Vec<String>,
+        config: &Value,
+        auto_start: bool,
+    ) -> anyhow::Result<Box<dyn Reaction>> {
+        self(id, query_ids, config, auto_start)
+    }
+}
+
+/// Maps source and reaction kinds to the factories that create them.
+#[derive(Default, Clone)]
+pub struct ComponentRegistry {
+    sources: HashMap<String, Arc<dyn SourceFactory>>,
+    reactions: HashMap<String, Arc<dyn ReactionFactory>>,
+}
+
+impl std::fmt::Debug for ComponentRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ComponentRegistry")
+            .field("sources", &self.source_kinds())
+            .field("reactions", &self.reaction_kinds())
+            .finish()
+    }
+}
+
+impl ComponentRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a source factory for `kind`, replacing any existing one.
+    pub fn register_source(
+        &mut self,
+        kind: impl Into<String>,
+        factory: impl SourceFactory + 'static,
+    ) -> &mut Self {
+        self.sources.insert(kind.into(), Arc::new(factory));
+        self
+    }
+
+    /// Register a reaction factory for `kind`, replacing any existing one.
+    pub fn register_reaction(
+        &mut self,
+        kind: impl Into<String>,
+        factory: impl ReactionFactory + 'static,
+    ) -> &mut Self {
+        self.reactions.insert(kind.into(), Arc::new(factory));
+        self
+    }
+
+    /// Whether a source factory is registered for `kind`.
+    pub fn has_source_kind(&self, kind: &str) -> bool {
+        self.sources.contains_key(kind)
+    }
+
+    /// Whether a reaction factory is registered for `kind`.
+    pub fn has_reaction_kind(&self, kind: &str) -> bool {
+        self.reactions.contains_key(kind)
+    }
+
+    /// Registered source kinds, sorted.
+    pub fn source_kinds(&self) -> Vec<&str> {
+        let mut kinds: Vec<&str> = self.sources.keys().map(String::as_str).collect();
+        kinds.sort_unstable();
+        kinds
+    }
+
+    /// Registered reaction kinds, sorted.
+    pub fn reaction_kinds(&self) -> Vec<&str> {
+        let mut kinds: Vec<&str> = self.reactions.keys().map(String::as_str).collect();
+        kinds.sort_unstable();
+        kinds
+    }
+
+    /// Create a source of the given kind.
+    pub async fn create_source(
+        &self,
+        kind: &str,
+        id: &str,
+        config: &Value,
+        auto_start: bool,
+    ) -> Result<Box<dyn Source>> {
+        let factory = self.sources.get(kind).ok_or_else(|| {
+            DrasiError::invalid_config(format!(
+                "Unknown source kind '{kind}' (registered: {})",
+                self.source_kinds().join(", ")
+            ))
+        })?;
+        factory
+            .create_source(id, config, auto_start)
+            .await
+            .map_err(|e| DrasiError::invalid_config(format!("Failed to create source '{id}': {e}")))
+    }
+
+    /// Create a reaction of the given kind.
+    pub async fn create_reaction(
+        &self,
+        kind: &str,
+        id: &str,
+        query_ids: Vec<String>,
+        config: &Value,
+        auto_start: bool,
+    ) -> Result<Box<dyn Reaction>> {
+        let factory = self.reactions.get(kind).ok_or_else(|| {
+            DrasiError::invalid_config(format!(
+                "Unknown reaction kind '{kind}' (registered: {})",
+                self.reaction_kinds().join(", ")
+            ))
+        })?;
+        factory
+            .create_reaction(id, query_ids, config, auto_start)
+            .await
+            .map_err(|e| {
+                DrasiError::invalid_config(format!("Failed to create reaction '{id}': {e}"))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reactions::tests::manager_tests::TestMockReaction;
+    use crate::sources::tests::create_test_mock_source;
+    use serde_json::json;
+
+    fn registry() -> ComponentRegistry {
+        let mut registry = ComponentRegistry::new();
+        registry
+            .register_source(
+                "mock",
+                |id: &str, _: &Value, _: bool| -> anyhow::Result<Box<dyn Source>> {
+                    Ok(Box::new(create_test_mock_source(id.to_string())))
+                },
+            )
+            .register_reaction(
+                "mock",
+                |id: &str,
+                 queries: Vec<String>,
+                 config: &Value,
+                 _: bool|
+                 -> anyhow::Result<Box<dyn Reaction>> {
+                    if config.get("fail").is_some() {
+                        anyhow::bail!("bad config");
+                    }
+                    Ok(Box::new(TestMockReaction::new(id.to_string(), queries)))
+                },
+            );
+        registry
+    }
+
+    #[tokio::test]
+    async fn test_create_registered_kinds() {
+        let registry = registry();
+        assert!(registry.has_source_kind("mock"));
+        assert_eq!(registry.reaction_kinds(), vec!["mock"]);
+
+        let source = registry
+            .create_source("mock", "s1", &json!({}), true)
+            .await
+            .unwrap();
+        assert_eq!(source.id(), "s1");
+
+        let reaction = registry
+            .create_reaction("mock", "r1", vec!["q1".to_string()], &json!({}), true)
+            .await
+            .unwrap();
+        assert_eq!(reaction.query_ids(), vec!["q1"]);
+    }
+
+    #[tokio::test]
+    async fn test_create_errors() {
+        let registry = registry();
+
+        let err = registry
+            .create_source("postgres", "s1", &json!({}), true)
+            .await
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("Unknown source kind 'postgres'"), "{err}");
+        assert!(err.contains("registered: mock"), "{err}");
+
+        let err = registry
+            .create_reaction("mock", "r1", vec![], &json!({"fail": true}), true)
+            .await
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(
+            err.contains("Failed to create reaction 'r1': bad config"),
+            "{err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_drasi_lib_adds_components_from_config() {
+        let core = crate::DrasiLib::builder()
+            .with_component_registry(Arc::new(registry()))
+            .build()
+            .await
+            .unwrap();
+
+        core.add_source_from_config("mock", "s1", &json!({}), false)
+            .await
+            .unwrap();
+        assert!(core.get_source_status("s1").await.is_ok());
+
+        let err = core
+            .add_source_from_config("postgres", "s2", &json!({}), false)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Unknown source kind"), "{err}");
+        assert!(core.get_source_status("s2").await.is_err());
+    }
+}
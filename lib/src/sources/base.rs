@@ -34,14 +34,17 @@ use log::{debug, error, info, warn};
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
 use tracing::Instrument;
 
 use crate::bootstrap::{BootstrapContext, BootstrapProvider, BootstrapRequest};
 use crate::channels::*;
+use crate::checkpoint::CheckpointStore;
 use crate::component_graph::ComponentStatusHandle;
 use crate::context::SourceRuntimeContext;
 use crate::identity::IdentityProvider;
+use crate::metrics::Counter;
 use crate::profiling;
 use crate::state_store::StateStoreProvider;
 use drasi_core::models::SourceChange;
@@ -74,6 +77,10 @@ pub struct SourceBaseParams {
     pub bootstrap_provider: Option<Box<dyn BootstrapProvider + 'static>>,
     /// Whether this source should auto-start - defaults to true
     pub auto_start: bool,
+    /// Window in which updates of the same element are merged - defaults to none
+    pub coalesce_window: Option<Duration>,
+    /// Derives the ordering key of each change - defaults to the element id
+    pub ordering_key: Option<Arc<dyn OrderingKeyExtractor>>,
 }
 
 impl std::fmt::Debug for SourceBaseParams {
@@ -87,6 +94,11 @@ impl std::fmt::Debug for SourceBaseParams {
                 &self.bootstrap_provider.as_ref().map(|_| "<provider>"),
             )
             .field("auto_start", &self.auto_start)
+            .field("coalesce_window", &self.coalesce_window)
+            .field(
+                "ordering_key",
+                &self.ordering_key.as_ref().map(|_| "<extractor>"),
+            )
             .finish()
     }
 }
@@ -100,6 +112,8 @@ impl SourceBaseParams {
             dispatch_buffer_capacity: None,
             bootstrap_provider: None,
             auto_start: true,
+            coalesce_window: None,
+            ordering_key: None,
         }
     }
 
@@ -132,6 +146,28 @@ impl SourceBaseParams {
         self.auto_start = auto_start;
         self
     }
+
+    /// Merge updates of the same element that arrive within `window`
+    ///
+    /// Queries then evaluate only the latest state of an element that changed
+    /// several times in quick succession. Inserts and deletes are always
+    /// delivered, and every change is delayed by up to `window`.
+    pub fn with_coalescing(mut self, window: Duration) -> Self {
+        self.coalesce_window = Some(window);
+        self
+    }
+
+    /// Set how the ordering key of each change is derived
+    ///
+    /// Queries evaluating on a worker pool keep the order of changes that
+    /// share a key and dispatch the results of the others in parallel. Without an extractor
+    /// each element's changes are ordered on their own. Sources whose upstream
+    /// orders changes by partition, topic or similar can key by it so those
+    /// changes keep their upstream order across elements.
+    pub fn with_ordering_key(mut self, extractor: impl OrderingKeyExtractor + 'static) -> Self {
+        self.ordering_key = Some(Arc::new(extractor));
+        self
+    }
 }
 
 /// Base implementation for common source functionality
@@ -142,6 +178,12 @@ pub struct SourceBase {
     dispatch_mode: DispatchMode,
     /// Dispatch buffer capacity
     dispatch_buffer_capacity: usize,
+    /// Window in which updates of the same element are merged, if enabled
+    coalesce_window: Option<Duration>,
+    /// Updates dropped because a later update of the same element replaced them
+    coalesced: Counter,
+    /// Derives the ordering key of dispatched changes, if the source sets one
+    ordering_key: Option<Arc<dyn OrderingKeyExtractor>>,
     /// Whether this source should auto-start
     pub auto_start: bool,
     /// Component status handle — always available, wired to graph during initialize().
@@ -194,11 +236,17 @@ impl SourceBase {
         let mut dispatchers: Vec<Box<dyn ChangeDispatcher<SourceEventWrapper> + Send + Sync>> =
             Vec::new();
 
+        let coalesced = Counter::default();
         if dispatch_mode == DispatchMode::Broadcast {
             // For broadcast mode, create a single broadcast dispatcher
             let dispatcher =
                 BroadcastChangeDispatcher::<SourceEventWrapper>::new(dispatch_buffer_capacity);
-            dispatchers.push(Box::new(dispatcher));
+            dispatchers.push(Self::coalesce(
+                Box::new(dispatcher),
+                params.coalesce_window,
+                dispatch_buffer_capacity,
+                &coalesced,
+            ));
         }
         // For channel mode, dispatchers will be created on-demand when subscribing
 
@@ -211,6 +259,9 @@ impl SourceBase {
             id: params.id.clone(),
             dispatch_mode,
             dispatch_buffer_capacity,
+            coalesce_window: params.coalesce_window,
+            coalesced,
+            ordering_key: params.ordering_key,
             auto_start: params.auto_start,
             status_handle: ComponentStatusHandle::new(&params.id),
             dispatchers: Arc::new(RwLock::new(dispatchers)),
@@ -256,6 +307,47 @@ impl SourceBase {
                 *guard = Some(ip.clone());
             }
         }
+
+        if let Some(metrics) = context.metrics.as_ref() {
+            let dispatchers = self.dispatchers.clone();
+            metrics.gauge_fn(
+                "drasi_source_dispatch_queue_depth",
+                "Events waiting in the source's dispatch channels",
+                &[("source", &self.id)],
+                move || {
+                    // Skip the sample rather than block while queries subscribe
+                    let dispatchers = dispatchers.try_read().ok()?;
+                    Some(dispatchers.iter().map(|d| d.queue_depth()).sum::<usize>() as f64)
+                },
+            );
+            if self.coalesce_window.is_some() {
+                let coalesced = self.coalesced.clone();
+                metrics.counter_fn(
+                    "drasi_source_coalesced_changes_total",
+                    "Updates replaced by a later update of the same element",
+                    &[("source", &self.id)],
+                    move || Some(coalesced.get() as f64),
+                );
+            }
+        }
+    }
+
+    /// Put the coalescing stage in front of `dispatcher` if a window is configured
+    fn coalesce(
+        dispatcher: Box<dyn ChangeDispatcher<SourceEventWrapper> + Send + Sync>,
+        window: Option<Duration>,
+        max_pending: usize,
+        coalesced: &Counter,
+    ) -> Box<dyn ChangeDispatcher<SourceEventWrapper> + Send + Sync> {
+        match window {
+            Some(window) if !window.is_zero() => Box::new(CoalescingChangeDispatcher::new(
+                dispatcher,
+                window,
+                max_pending,
+                coalesced.clone(),
+            )),
+            _ => dispatcher,
+        }
     }
 
     /// Get the runtime context if initialized.
@@ -290,6 +382,56 @@ impl SourceBase {
         *self.identity_provider.write().await = Some(provider);
     }
 
+    /// Persist the source's consumer position (e.g. an offset or LSN).
+    ///
+    /// The position is serialized as JSON and stored in the context's
+    /// checkpoint store under this source's ID, replacing any previous value.
+    /// Does nothing if no checkpoint store is configured.
+    pub async fn save_checkpoint<T: serde::Serialize>(&self, position: &T) -> Result<()> {
+        let Some(store) = self.checkpoint_store().await else {
+            return Ok(());
+        };
+        let bytes = serde_json::to_vec(position)?;
+        store
+            .save_source_position(&self.id, bytes)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to save checkpoint for source '{}': {e}", self.id))
+    }
+
+    /// Load the consumer position last saved with [`save_checkpoint`](Self::save_checkpoint).
+    ///
+    /// Returns `None` if no checkpoint store is configured or nothing has been saved.
+    pub async fn load_checkpoint<T: serde::de::DeserializeOwned>(&self) -> Result<Option<T>> {
+        let Some(store) = self.checkpoint_store().await else {
+            return Ok(None);
+        };
+        let bytes = store.load_source_position(&self.id).await.map_err(|e| {
+            anyhow::anyhow!("Failed to load checkpoint for source '{}': {e}", self.id)
+        })?;
+        match bytes {
+            Some(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Forget the saved consumer position, so the next start begins from scratch.
+    pub async fn clear_checkpoint(&self) -> Result<()> {
+        let Some(store) = self.checkpoint_store().await else {
+            return Ok(());
+        };
+        store.clear_source_position(&self.id).await.map_err(|e| {
+            anyhow::anyhow!("Failed to clear checkpoint for source '{}': {e}", self.id)
+        })
+    }
+
+    async fn checkpoint_store(&self) -> Option<Arc<dyn CheckpointStore>> {
+        self.context
+            .read()
+            .await
+            .as_ref()
+            .and_then(|context| context.checkpoint_store.clone())
+    }
+
     /// Create and register a position handle for `query_id`, initialized to `u64::MAX`.
     ///
     /// Returns the shared handle; the same `Arc` is placed in
@@ -375,6 +517,9 @@ impl SourceBase {
             id: self.id.clone(),
             dispatch_mode: self.dispatch_mode,
             dispatch_buffer_capacity: self.dispatch_buffer_capacity,
+            coalesce_window: self.coalesce_window,
+            coalesced: self.coalesced.clone(),
+            ordering_key: self.ordering_key.clone(),
             auto_start: self.auto_start,
             status_handle: self.status_handle.clone(),
             dispatchers: self.dispatchers.clone(),
@@ -411,36 +556,40 @@ impl SourceBase {
     ///
     /// This creates the appropriate receiver based on the configured dispatch mode:
     /// - Broadcast mode: Returns a receiver from the shared broadcast dispatcher
-    /// - Channel mode: Creates a new dedicated dispatcher and returns its receiver
+    /// - Channel and Batched modes: Creates a new dedicated dispatcher and returns its receiver
     ///
     /// This is a helper method that can be used by sources with custom subscribe logic.
     pub async fn create_streaming_receiver(
         &self,
     ) -> Result<Box<dyn ChangeReceiver<SourceEventWrapper>>> {
-        let receiver: Box<dyn ChangeReceiver<SourceEventWrapper>> = match self.dispatch_mode {
-            DispatchMode::Broadcast => {
-                // For broadcast mode, use the single dispatcher
-                let dispatchers = self.dispatchers.read().await;
-                if let Some(dispatcher) = dispatchers.first() {
-                    dispatcher.create_receiver().await?
-                } else {
-                    return Err(anyhow::anyhow!("No broadcast dispatcher available"));
-                }
-            }
-            DispatchMode::Channel => {
-                // For channel mode, create a new dispatcher for this subscription
-                let dispatcher = ChannelChangeDispatcher::<SourceEventWrapper>::new(
-                    self.dispatch_buffer_capacity,
-                );
-                let receiver = dispatcher.create_receiver().await?;
+        let receiver: Box<dyn ChangeReceiver<SourceEventWrapper>> =
+            match dedicated_dispatcher(self.dispatch_mode, self.dispatch_buffer_capacity) {
+                Some(dispatcher) => {
+                    // For channel and batched modes, each subscription has its own dispatcher
+                    let dispatcher = Self::coalesce(
+                        dispatcher,
+                        self.coalesce_window,
+                        self.dispatch_buffer_capacity,
+                        &self.coalesced,
+                    );
+                    let receiver = dispatcher.create_receiver().await?;
 
-                // Add the new dispatcher to our list
-                let mut dispatchers = self.dispatchers.write().await;
-                dispatchers.push(Box::new(dispatcher));
+                    // Add the new dispatcher to our list
+                    let mut dispatchers = self.dispatchers.write().await;
+                    dispatchers.push(dispatcher);
 
-                receiver
-            }
-        };
+                    receiver
+                }
+                None => {
+                    // For broadcast mode, use the single dispatcher
+                    let dispatchers = self.dispatchers.read().await;
+                    if let Some(dispatcher) = dispatchers.first() {
+                        dispatcher.create_receiver().await?
+                    } else {
+                        return Err(anyhow::anyhow!("No broadcast dispatcher available"));
+                    }
+                }
+            };
 
         Ok(receiver)
     }
@@ -547,11 +696,12 @@ impl SourceBase {
             let source_id = self.id.clone();
 
             // Get instance_id from context for log routing isolation
-            let instance_id = self
-                .context()
-                .await
+            let runtime_context = self.context().await;
+            let instance_id = runtime_context
+                .as_ref()
                 .map(|c| c.instance_id.clone())
                 .unwrap_or_default();
+            let metrics = runtime_context.and_then(|c| c.metrics);
 
             // Spawn bootstrap task with tracing span for proper log routing
             let span = tracing::info_span!(
@@ -562,6 +712,7 @@ impl SourceBase {
             );
             tokio::spawn(
                 async move {
+                    let started = std::time::Instant::now();
                     match provider
                         .bootstrap(request, &context, bootstrap_tx, Some(&settings_clone))
                         .await
@@ -571,6 +722,23 @@ impl SourceBase {
                                 "Bootstrap completed successfully for query '{}', sent {} events",
                                 settings_clone.query_id, result.event_count
                             );
+                            if let Some(metrics) = metrics {
+                                let labels = [("source", source_id.as_str())];
+                                metrics
+                                    .counter(
+                                        "drasi_source_bootstrap_events_total",
+                                        "Events sent while bootstrapping queries",
+                                        &labels,
+                                    )
+                                    .inc_by(result.event_count as u64);
+                                metrics
+                                    .histogram(
+                                        "drasi_source_bootstrap_seconds",
+                                        "Duration of bootstrap requests served",
+                                        &labels,
+                                    )
+                                    .observe_duration(started.elapsed());
+                            }
                             // `result.last_sequence` / `result.sequences_aligned`
                             // are intentionally unused at this call site — a
                             // future query-processor integration issue will
@@ -621,28 +789,92 @@ impl SourceBase {
         self.dispatch_event(wrapper).await
     }
 
+    /// Dispatch several SourceChange events with profiling metadata
+    ///
+    /// Like [`dispatch_source_change`](Self::dispatch_source_change), but hands
+    /// all changes to each dispatcher at once. In Batched dispatch mode they
+    /// enter the subscriber's batch under a single lock, so sources that read
+    /// changes in bulk should prefer this method.
+    pub async fn dispatch_source_changes(&self, changes: Vec<SourceChange>) -> Result<()> {
+        if changes.is_empty() {
+            return Ok(());
+        }
+
+        let wrappers: Vec<_> = changes
+            .into_iter()
+            .map(|change| {
+                let mut profiling = profiling::ProfilingMetadata::new();
+                profiling.source_send_ns = Some(profiling::timestamp_ns());
+                let mut wrapper = SourceEventWrapper::with_profiling(
+                    self.id.clone(),
+                    SourceEvent::Change(change),
+                    chrono::Utc::now(),
+                    profiling,
+                );
+                self.stamp_ordering_key(&mut wrapper);
+                crate::telemetry::start_change(&mut wrapper);
+                Arc::new(wrapper)
+            })
+            .collect();
+        debug!("[{}] Dispatching {} events", self.id, wrappers.len());
+
+        // Send to all dispatchers
+        let dispatchers = self.dispatchers.read().await;
+        for dispatcher in dispatchers.iter() {
+            if let Err(e) = dispatcher.dispatch_changes(wrappers.clone()).await {
+                debug!("[{}] Failed to dispatch events: {}", self.id, e);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Dispatch a SourceEventWrapper to all subscribers
     ///
     /// This is a generic method for dispatching any SourceEvent.
     /// It handles Arc-wrapping for zero-copy sharing and logs
     /// when there are no subscribers.
-    pub async fn dispatch_event(&self, wrapper: SourceEventWrapper) -> Result<()> {
+    pub async fn dispatch_event(&self, mut wrapper: SourceEventWrapper) -> Result<()> {
+        self.stamp_ordering_key(&mut wrapper);
+        crate::telemetry::start_change(&mut wrapper);
+        let span = crate::telemetry::dispatch_span(&wrapper);
         debug!("[{}] Dispatching event: {:?}", self.id, &wrapper);
 
         // Arc-wrap for zero-copy sharing across dispatchers
         let arc_wrapper = Arc::new(wrapper);
 
         // Send to all dispatchers
-        let dispatchers = self.dispatchers.read().await;
-        for dispatcher in dispatchers.iter() {
-            if let Err(e) = dispatcher.dispatch_change(arc_wrapper.clone()).await {
-                debug!("[{}] Failed to dispatch event: {}", self.id, e);
+        async {
+            let dispatchers = self.dispatchers.read().await;
+            for dispatcher in dispatchers.iter() {
+                if let Err(e) = dispatcher.dispatch_change(arc_wrapper.clone()).await {
+                    debug!("[{}] Failed to dispatch event: {}", self.id, e);
+                }
             }
         }
+        .instrument(span)
+        .await;
 
         Ok(())
     }
 
+    /// The extractor set with [`SourceBaseParams::with_ordering_key`], if any
+    ///
+    /// Events dispatched through [`dispatch_from_task`](Self::dispatch_from_task)
+    /// are not keyed automatically; tasks can key them with this extractor.
+    pub fn ordering_key_extractor(&self) -> Option<Arc<dyn OrderingKeyExtractor>> {
+        self.ordering_key.clone()
+    }
+
+    /// Key a change with the source's extractor, unless the source keyed it already
+    fn stamp_ordering_key(&self, wrapper: &mut SourceEventWrapper) {
+        if let (Some(extractor), SourceEvent::Change(change), None) =
+            (&self.ordering_key, &wrapper.event, &wrapper.ordering_key)
+        {
+            wrapper.ordering_key = Some(extractor.ordering_key(change));
+        }
+    }
+
     /// Broadcast SourceControl events
     pub async fn broadcast_control(&self, control: SourceControl) -> Result<()> {
         let wrapper = SourceEventWrapper::new(
@@ -691,6 +923,8 @@ impl SourceBase {
     /// have access to `self`. It manually iterates through dispatchers and sends the event.
     ///
     /// For code that has access to `&self`, prefer using `dispatch_event()` instead.
+    /// Unlike `dispatch_event()`, this does not set the wrapper's ordering key;
+    /// see [`ordering_key_extractor`](Self::ordering_key_extractor).
     ///
     /// # Arguments
     /// * `dispatchers` - Arc to the dispatchers list (from `self.base.dispatchers.clone()`)
@@ -698,9 +932,11 @@ impl SourceBase {
     /// * `source_id` - Source ID for logging
     pub async fn dispatch_from_task(
         dispatchers: Arc<RwLock<Vec<Box<dyn ChangeDispatcher<SourceEventWrapper> + Send + Sync>>>>,
-        wrapper: SourceEventWrapper,
+        mut wrapper: SourceEventWrapper,
         source_id: &str,
     ) -> Result<()> {
+        crate::telemetry::start_change(&mut wrapper);
+        let span = crate::telemetry::dispatch_span(&wrapper);
         debug!(
             "[{}] Dispatching event from task: {:?}",
             source_id, &wrapper
@@ -710,12 +946,16 @@ impl SourceBase {
         let arc_wrapper = Arc::new(wrapper);
 
         // Send to all dispatchers
-        let dispatchers_guard = dispatchers.read().await;
-        for dispatcher in dispatchers_guard.iter() {
-            if let Err(e) = dispatcher.dispatch_change(arc_wrapper.clone()).await {
-                debug!("[{source_id}] Failed to dispatch event from task: {e}");
+        async {
+            let dispatchers_guard = dispatchers.read().await;
+            for dispatcher in dispatchers_guard.iter() {
+                if let Err(e) = dispatcher.dispatch_change(arc_wrapper.clone()).await {
+                    debug!("[{source_id}] Failed to dispatch event from task: {e}");
+                }
             }
         }
+        .instrument(span)
+        .await;
 
         Ok(())
     }
@@ -777,6 +1017,7 @@ impl SourceBase {
                 count, self.id
             );
         }
+        self.clear_checkpoint().await?;
         Ok(())
     }
 
@@ -793,7 +1034,10 @@ impl SourceBase {
     }
 
     /// Set the task handle
+    ///
+    /// If the task panics, the source's status is set to `Error`.
     pub async fn set_task_handle(&self, handle: tokio::task::JoinHandle<()>) {
+        let handle = crate::supervisor::report_panic(handle, self.status_handle(), self.id.clone());
         *self.task_handle.write().await = Some(handle);
     }
 
@@ -1143,4 +1387,105 @@ mod tests {
         assert!(response.bootstrap_receiver.is_none());
         assert!(response.position_handle.is_none());
     }
+
+    #[tokio::test]
+    async fn test_checkpoint_roundtrip_through_state_store() {
+        let base = SourceBase::new(SourceBaseParams::new("cp-source")).unwrap();
+        let (update_tx, _update_rx) = tokio::sync::mpsc::channel(16);
+        let store: Arc<dyn StateStoreProvider> =
+            Arc::new(crate::state_store::MemoryStateStoreProvider::new());
+        base.initialize(SourceRuntimeContext::new(
+            "test-instance",
+            "cp-source",
+            Some(store),
+            update_tx,
+            None,
+        ))
+        .await;
+
+        assert_eq!(base.load_checkpoint::<u64>().await.unwrap(), None);
+        base.save_checkpoint(&42u64).await.unwrap();
+        assert_eq!(base.load_checkpoint::<u64>().await.unwrap(), Some(42));
+
+        base.clear_checkpoint().await.unwrap();
+        assert_eq!(base.load_checkpoint::<u64>().await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_checkpoint_without_store_is_noop() {
+        let base = SourceBase::new(SourceBaseParams::new("no-store")).unwrap();
+        base.save_checkpoint(&1u64).await.unwrap();
+        assert_eq!(base.load_checkpoint::<u64>().await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_coalescing_delivers_latest_update_per_element() {
+        use drasi_core::models::{
+            Element, ElementMetadata, ElementPropertyMap, ElementReference, ElementValue,
+        };
+
+        let params = SourceBaseParams::new("sensors").with_coalescing(Duration::from_millis(20));
+        assert_eq!(params.coalesce_window, Some(Duration::from_millis(20)));
+        let base = SourceBase::new(params).unwrap();
+        let mut receiver = base.create_streaming_receiver().await.unwrap();
+
+        let reading = |value: i64| {
+            let mut properties = ElementPropertyMap::new();
+            properties.insert("value", ElementValue::Integer(value));
+            SourceChange::Update {
+                element: Element::Node {
+                    metadata: ElementMetadata {
+                        reference: ElementReference::new("sensors", "s1"),
+                        labels: Arc::from(vec![Arc::from("Sensor")]),
+                        effective_from: value as u64,
+                    },
+                    properties,
+                },
+            }
+        };
+        base.dispatch_source_changes((1..=10).map(reading).collect())
+            .await
+            .unwrap();
+
+        let event = tokio::time::timeout(Duration::from_secs(1), receiver.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        let SourceEvent::Change(SourceChange::Update { element }) = &event.event else {
+            panic!("expected an update, got {event:?}");
+        };
+        assert_eq!(
+            element.get_properties().get("value"),
+            Some(&ElementValue::Integer(10))
+        );
+        assert_eq!(base.coalesced.get(), 9);
+    }
+
+    #[tokio::test]
+    async fn test_ordering_key_is_set_on_dispatched_changes() {
+        use drasi_core::models::{ElementMetadata, ElementReference};
+
+        let params = SourceBaseParams::new("sensors")
+            .with_ordering_key(|_: &SourceChange| OrderingKey::from("partition-0"));
+        let base = SourceBase::new(params).unwrap();
+        let mut receiver = base.create_streaming_receiver().await.unwrap();
+
+        base.dispatch_source_change(SourceChange::Delete {
+            metadata: ElementMetadata {
+                reference: ElementReference::new("sensors", "s1"),
+                labels: Arc::from(vec![Arc::from("Sensor")]),
+                effective_from: 0,
+            },
+        })
+        .await
+        .unwrap();
+        base.broadcast_control(SourceControl::FuturesDue)
+            .await
+            .unwrap();
+
+        let change = receiver.recv().await.unwrap();
+        assert_eq!(change.ordering_key.as_deref(), Some("partition-0"));
+        let control = receiver.recv().await.unwrap();
+        assert!(control.ordering_key.is_none());
+    }
 }
@@ -24,11 +24,13 @@ use serde_json::Value;
 use std::collections::BTreeMap;
 
 use crate::channels::*;
+use crate::checkpoint::CheckpointStore;
 use crate::component_graph::{ComponentGraph, ComponentKind, ComponentUpdateSender};
 use crate::config::SourceRuntime;
 use crate::context::SourceRuntimeContext;
 use crate::identity::IdentityProvider;
 use crate::managers::{ComponentLogKey, ComponentLogRegistry};
+use crate::metrics::MetricsRegistry;
 use crate::sources::Source;
 use crate::state_store::StateStoreProvider;
 
@@ -74,6 +76,8 @@ pub struct SourceManager {
     instance_id: String,
     state_store: Arc<RwLock<Option<Arc<dyn StateStoreProvider>>>>,
     identity_provider: Arc<RwLock<Option<Arc<dyn IdentityProvider>>>>,
+    checkpoint_store: Arc<RwLock<Option<Arc<dyn CheckpointStore>>>>,
+    metrics: Arc<RwLock<Option<Arc<MetricsRegistry>>>>,
     log_registry: Arc<ComponentLogRegistry>,
     /// Shared component graph — the single source of truth for component metadata,
     /// state, relationships, runtime instances, AND event history.
@@ -101,6 +105,8 @@ impl SourceManager {
             instance_id: instance_id.into(),
             state_store: Arc::new(RwLock::new(None)),
             identity_provider: Arc::new(RwLock::new(None)),
+            checkpoint_store: Arc::new(RwLock::new(None)),
+            metrics: Arc::new(RwLock::new(None)),
             log_registry,
             graph,
             update_tx,
@@ -121,6 +127,20 @@ impl SourceManager {
         *self.identity_provider.write().await = Some(identity_provider);
     }
 
+    /// Inject the checkpoint store (called after DrasiLib is fully constructed)
+    ///
+    /// This allows sources to persist their consumer position across restarts.
+    pub async fn inject_checkpoint_store(&self, checkpoint_store: Arc<dyn CheckpointStore>) {
+        *self.checkpoint_store.write().await = Some(checkpoint_store);
+    }
+
+    /// Inject the metrics registry (called after DrasiLib is fully constructed)
+    ///
+    /// Sources added afterwards record their dispatch and bootstrap metrics into it.
+    pub async fn inject_metrics(&self, metrics: Arc<MetricsRegistry>) {
+        *self.metrics.write().await = Some(metrics);
+    }
+
     pub async fn get_source_instance(&self, id: &str) -> Option<Arc<dyn Source>> {
         let graph = self.graph.read().await;
         graph.get_runtime::<Arc<dyn Source>>(id).cloned()
@@ -152,6 +172,12 @@ impl SourceManager {
             None,
         );
         context.identity_provider = self.identity_provider.read().await.clone();
+        if let Some(checkpoint_store) = self.checkpoint_store.read().await.clone() {
+            context = context.with_checkpoint_store(checkpoint_store);
+        }
+        if let Some(metrics) = self.metrics.read().await.clone() {
+            context = context.with_metrics(metrics);
+        }
 
         // Initialize the source with its runtime context
         source.initialize(context).await;
@@ -262,7 +288,11 @@ impl SourceManager {
             cleanup,
             || async {},
         )
-        .await
+        .await?;
+        if let Some(metrics) = self.metrics.read().await.as_ref() {
+            metrics.remove_series("source", &id);
+        }
+        Ok(())
     }
 
     /// Update a source by replacing it with a new instance.
@@ -290,6 +320,8 @@ impl SourceManager {
             let graph = &self.graph;
             let instance_id = &self.instance_id;
             let state_store = &self.state_store;
+            let checkpoint_store = &self.checkpoint_store;
+            let metrics = &self.metrics;
             let update_tx = &self.update_tx;
 
             crate::managers::lifecycle_helpers::reconfigure_component::<Arc<dyn Source>, _, _, _>(
@@ -300,13 +332,19 @@ impl SourceManager {
                 || async {},
                 || async {
                     let new_source: Arc<dyn Source> = Arc::new(new_source);
-                    let context = SourceRuntimeContext::new(
+                    let mut context = SourceRuntimeContext::new(
                         instance_id,
                         &id,
                         state_store.read().await.clone(),
                         update_tx.clone(),
                         None,
                     );
+                    if let Some(store) = checkpoint_store.read().await.clone() {
+                        context = context.with_checkpoint_store(store);
+                    }
+                    if let Some(metrics) = metrics.read().await.clone() {
+                        context = context.with_metrics(metrics);
+                    }
                     new_source.initialize(context).await;
 
                     let mut g = graph.write().await;
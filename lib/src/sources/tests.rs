@@ -66,18 +66,44 @@ impl TestMockSource {
 
     /// Inject an event into all subscribed queries.
     pub async fn inject_event(&self, change: SourceChange) -> Result<()> {
-        let dispatchers = self.dispatchers.read().await;
         let wrapper = SourceEventWrapper::new(
             self.id.clone(),
             SourceEvent::Change(change),
             chrono::Utc::now(),
         );
+        self.inject_wrapper(wrapper).await
+    }
+
+    /// Inject an event keyed by `key`, as a partitioned source would.
+    pub async fn inject_keyed_event(
+        &self,
+        change: SourceChange,
+        key: crate::channels::OrderingKey,
+    ) -> Result<()> {
+        let wrapper = SourceEventWrapper::new(
+            self.id.clone(),
+            SourceEvent::Change(change),
+            chrono::Utc::now(),
+        )
+        .with_ordering_key(key);
+        self.inject_wrapper(wrapper).await
+    }
+
+    async fn inject_wrapper(&self, wrapper: SourceEventWrapper) -> Result<()> {
+        let dispatchers = self.dispatchers.read().await;
         let arc_wrapper = Arc::new(wrapper);
         for dispatcher in dispatchers.iter() {
             dispatcher.dispatch_change(arc_wrapper.clone()).await?;
         }
         Ok(())
     }
+
+    /// Report a failure, as a source does when it loses its upstream connection.
+    pub async fn report_error(&self, message: &str) {
+        self.status_handle
+            .set_status(ComponentStatus::Error, Some(message.to_string()))
+            .await;
+    }
 }
 
 #[async_trait]
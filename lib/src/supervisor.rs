@@ -0,0 +1,533 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Automatic restart of failed sources and reactions.
+//!
+//! While a [`DrasiLib`](crate::DrasiLib) is running, the supervisor follows the
+//! component event stream. When a source or reaction reports
+//! [`ComponentStatus::Error`] — including when its task panics — or, under
+//! [`RestartPolicy::Always`], stops without being asked to, the supervisor
+//! restarts it after a backoff delay. Each restart attempt and the decision to
+//! give up are recorded as component events, so they appear in the component
+//! history and the instance event log.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use log::{error, info, warn};
+use tokio::sync::{broadcast, RwLock};
+use tokio::task::{JoinHandle, JoinSet};
+use tokio::time::Instant;
+
+use crate::channels::{
+    ComponentEvent, ComponentEventBroadcastReceiver, ComponentStatus, ComponentType,
+};
+use crate::component_graph::{ComponentGraph, ComponentKind, ComponentStatusHandle};
+use crate::metrics::MetricsRegistry;
+use crate::reactions::ReactionManager;
+use crate::sources::SourceManager;
+
+/// Delays between restarts of a failing component.
+///
+/// The first restart waits `initial_delay`; each consecutive restart waits
+/// twice as long as the previous one, up to `max_delay`. A component that
+/// stays running for `reset_after` is considered healthy again, and its next
+/// failure starts over from `initial_delay`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RestartBackoff {
+    /// Delay before the first restart. Default: 1 second.
+    pub initial_delay: Duration,
+    /// Upper bound of the delay between restarts. Default: 60 seconds.
+    pub max_delay: Duration,
+    /// Consecutive restarts after which the component is left failed.
+    /// `None` restarts indefinitely. Default: `None`.
+    pub max_restarts: Option<u32>,
+    /// Running time after which the restart count is reset. Default: 60 seconds.
+    pub reset_after: Duration,
+}
+
+impl Default for RestartBackoff {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+            max_restarts: None,
+            reset_after: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RestartBackoff {
+    /// Set the delay before the first restart.
+    pub fn with_initial_delay(mut self, delay: Duration) -> Self {
+        self.initial_delay = delay;
+        self
+    }
+
+    /// Set the upper bound of the delay between restarts.
+    pub fn with_max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Give up after this many consecutive restarts.
+    pub fn with_max_restarts(mut self, max_restarts: u32) -> Self {
+        self.max_restarts = Some(max_restarts);
+        self
+    }
+
+    /// Set the running time after which the restart count is reset.
+    pub fn with_reset_after(mut self, reset_after: Duration) -> Self {
+        self.reset_after = reset_after;
+        self
+    }
+
+    /// Delay before the given restart attempt (1-based).
+    fn delay(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_delay
+            .saturating_mul(factor)
+            .min(self.max_delay)
+    }
+}
+
+/// What the supervisor does when a source or reaction fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RestartPolicy {
+    /// Leave the component failed. This is the default.
+    #[default]
+    Never,
+    /// Restart the component when it reports [`ComponentStatus::Error`].
+    OnFailure(RestartBackoff),
+    /// Restart the component when it reports [`ComponentStatus::Error`] or
+    /// stops without a stop being requested.
+    Always(RestartBackoff),
+}
+
+impl RestartPolicy {
+    /// Restart on failure with the default [`RestartBackoff`].
+    pub fn on_failure() -> Self {
+        Self::OnFailure(RestartBackoff::default())
+    }
+
+    /// Restart on failure or unexpected stop with the default [`RestartBackoff`].
+    pub fn always() -> Self {
+        Self::Always(RestartBackoff::default())
+    }
+
+    fn backoff(&self) -> Option<&RestartBackoff> {
+        match self {
+            Self::Never => None,
+            Self::OnFailure(backoff) | Self::Always(backoff) => Some(backoff),
+        }
+    }
+}
+
+/// Restart policies of the sources and reactions of an instance.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RestartPolicies {
+    /// Policy of components without an override
+    pub default: RestartPolicy,
+    /// Per-component overrides, keyed by component ID
+    pub overrides: HashMap<String, RestartPolicy>,
+}
+
+impl RestartPolicies {
+    pub fn policy(&self, id: &str) -> RestartPolicy {
+        self.overrides.get(id).copied().unwrap_or(self.default)
+    }
+
+    /// Whether any component can be restarted, i.e. whether a supervisor is needed.
+    pub fn is_enabled(&self) -> bool {
+        self.default != RestartPolicy::Never
+            || self
+                .overrides
+                .values()
+                .any(|policy| *policy != RestartPolicy::Never)
+    }
+}
+
+/// Restart state of one supervised component.
+#[derive(Default)]
+struct Supervised {
+    /// Last status reported for the component
+    status: Option<ComponentStatus>,
+    /// When the component last reached Running
+    running_since: Option<Instant>,
+    /// Consecutive restarts since the component was last healthy
+    restarts: u32,
+}
+
+/// Spawn the supervisor loop for a running instance.
+///
+/// The returned task runs until aborted; aborting it also cancels pending restarts.
+pub(crate) fn spawn(
+    policies: RestartPolicies,
+    events: ComponentEventBroadcastReceiver,
+    graph: Arc<RwLock<ComponentGraph>>,
+    source_manager: Arc<SourceManager>,
+    reaction_manager: Arc<ReactionManager>,
+    metrics: Arc<MetricsRegistry>,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut events = events;
+        let mut components: HashMap<String, Supervised> = HashMap::new();
+        let mut restarts = JoinSet::new();
+
+        // Components that are already running have been healthy since startup
+        {
+            let graph = graph.read().await;
+            for kind in [ComponentKind::Source, ComponentKind::Reaction] {
+                for (id, status) in graph.list_by_kind(&kind) {
+                    components.insert(
+                        id,
+                        Supervised {
+                            status: Some(status),
+                            running_since: (status == ComponentStatus::Running).then(Instant::now),
+                            restarts: 0,
+                        },
+                    );
+                }
+            }
+        }
+
+        loop {
+            let event = tokio::select! {
+                event = events.recv() => event,
+                // Reap finished restart tasks so the set does not grow unbounded
+                Some(_) = restarts.join_next(), if !restarts.is_empty() => continue,
+            };
+            let event = match event {
+                Ok(event) => event,
+                Err(broadcast::error::RecvError::Lagged(n)) => {
+                    warn!("Supervisor missed {n} component event(s)");
+                    continue;
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            };
+            if !matches!(
+                event.component_type,
+                ComponentType::Source | ComponentType::Reaction
+            ) {
+                continue;
+            }
+            if event.status == ComponentStatus::Removed {
+                components.remove(&event.component_id);
+                continue;
+            }
+
+            let state = components.entry(event.component_id.clone()).or_default();
+            let previous = state.status.replace(event.status);
+            // Events that do not change the status are supervisor notices
+            if previous == Some(event.status) {
+                continue;
+            }
+
+            let policy = policies.policy(&event.component_id);
+            let failed = match event.status {
+                ComponentStatus::Running => {
+                    state.running_since = Some(Instant::now());
+                    continue;
+                }
+                ComponentStatus::Error => true,
+                // A requested stop passes through Stopping first
+                ComponentStatus::Stopped if previous == Some(ComponentStatus::Running) => false,
+                _ => continue,
+            };
+            let Some(backoff) = policy.backoff().copied() else {
+                continue;
+            };
+            if !failed && !matches!(policy, RestartPolicy::Always(_)) {
+                continue;
+            }
+
+            if state
+                .running_since
+                .take()
+                .is_some_and(|since| since.elapsed() >= backoff.reset_after)
+            {
+                state.restarts = 0;
+            }
+            if backoff
+                .max_restarts
+                .is_some_and(|max| state.restarts >= max)
+            {
+                let message = format!(
+                    "Restart limit reached after {} attempt(s), leaving component stopped",
+                    state.restarts
+                );
+                error!(
+                    "{:?} '{}': {message}",
+                    event.component_type, event.component_id
+                );
+                graph
+                    .write()
+                    .await
+                    .record_notice(&event.component_id, Some(message));
+                continue;
+            }
+            state.restarts += 1;
+
+            let attempt = state.restarts;
+            let delay = backoff.delay(attempt);
+            let reason = if failed {
+                event
+                    .message
+                    .clone()
+                    .unwrap_or_else(|| "failed".to_string())
+            } else {
+                "stopped unexpectedly".to_string()
+            };
+            let message = format!("Restarting in {delay:?} (attempt {attempt}): {reason}");
+            warn!(
+                "{:?} '{}': {message}",
+                event.component_type, event.component_id
+            );
+            graph
+                .write()
+                .await
+                .record_notice(&event.component_id, Some(message));
+            let label = match event.component_type {
+                ComponentType::Source => "source",
+                _ => "reaction",
+            };
+            metrics
+                .counter(
+                    "drasi_component_restarts_total",
+                    "Restarts of failed components by the supervisor",
+                    &[(label, &event.component_id)],
+                )
+                .inc();
+
+            restarts.spawn(restart(
+                event,
+                delay,
+                attempt,
+                graph.clone(),
+                source_manager.clone(),
+                reaction_manager.clone(),
+            ));
+        }
+    })
+}
+
+/// Restart a component after `delay`, unless it was started or removed meanwhile.
+async fn restart(
+    event: ComponentEvent,
+    delay: Duration,
+    attempt: u32,
+    graph: Arc<RwLock<ComponentGraph>>,
+    source_manager: Arc<SourceManager>,
+    reaction_manager: Arc<ReactionManager>,
+) {
+    tokio::time::sleep(delay).await;
+    let id = event.component_id;
+
+    {
+        let mut graph = graph.write().await;
+        match graph.get_component(&id).map(|node| node.status) {
+            Some(ComponentStatus::Error | ComponentStatus::Stopped) => {}
+            _ => return,
+        }
+        // Claim the restart so a concurrent manual start is rejected
+        if let Err(e) = graph.validate_and_transition(
+            &id,
+            ComponentStatus::Starting,
+            Some(format!("Restarting (attempt {attempt})")),
+        ) {
+            warn!("Cannot restart '{id}': {e}");
+            return;
+        }
+    }
+
+    info!(
+        "Restarting {:?} '{id}' (attempt {attempt})",
+        event.component_type
+    );
+    let result = match event.component_type {
+        ComponentType::Source => source_manager.start_source(id.clone()).await,
+        ComponentType::Reaction => reaction_manager.restart_reaction(id.clone()).await,
+        _ => return,
+    };
+    // A failed start moves the component to Error, which schedules the next attempt
+    if let Err(e) = result {
+        warn!("Restart of '{id}' failed: {e}");
+    }
+}
+
+/// Wrap a component task so that a panic is reported as [`ComponentStatus::Error`].
+///
+/// Aborting the returned handle aborts `task` as well.
+pub(crate) fn report_panic(
+    task: JoinHandle<()>,
+    status: ComponentStatusHandle,
+    component_id: String,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut task = AbortOnDrop(task);
+        if let Err(e) = (&mut task.0).await {
+            if e.is_panic() {
+                error!("Task of component '{component_id}' panicked: {e}");
+                status
+                    .set_status(ComponentStatus::Error, Some(format!("Task panicked: {e}")))
+                    .await;
+            }
+        }
+    })
+}
+
+struct AbortOnDrop(JoinHandle<()>);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lib_core::DrasiLib;
+    use crate::sources::tests::TestMockSource;
+    use crate::test_helpers::wait_for_component_status;
+
+    #[test]
+    fn backoff_doubles_up_to_max_delay() {
+        let backoff = RestartBackoff::default()
+            .with_initial_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_millis(500));
+        assert_eq!(backoff.delay(1), Duration::from_millis(100));
+        assert_eq!(backoff.delay(2), Duration::from_millis(200));
+        assert_eq!(backoff.delay(3), Duration::from_millis(400));
+        assert_eq!(backoff.delay(4), Duration::from_millis(500));
+        assert_eq!(backoff.delay(40), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn overrides_take_precedence() {
+        let mut policies = RestartPolicies::default();
+        assert!(!policies.is_enabled());
+        policies
+            .overrides
+            .insert("flaky".to_string(), RestartPolicy::on_failure());
+        assert!(policies.is_enabled());
+        assert_eq!(policies.policy("flaky"), RestartPolicy::on_failure());
+        assert_eq!(policies.policy("other"), RestartPolicy::Never);
+    }
+
+    async fn build(policy: RestartPolicy) -> DrasiLib {
+        let source = TestMockSource::with_auto_start("sup-src".to_string(), true).unwrap();
+        DrasiLib::builder()
+            .with_id("supervisor-test")
+            .with_source(source)
+            .with_restart_policy(policy)
+            .build()
+            .await
+            .unwrap()
+    }
+
+    async fn fail_source(core: &DrasiLib) {
+        let source = core
+            .source_manager
+            .get_source_instance("sup-src")
+            .await
+            .unwrap();
+        let source = source.as_any().downcast_ref::<TestMockSource>().unwrap();
+        source.report_error("connection lost").await;
+    }
+
+    #[tokio::test]
+    async fn failed_source_is_restarted() {
+        let core = build(RestartPolicy::OnFailure(
+            RestartBackoff::default().with_initial_delay(Duration::from_millis(10)),
+        ))
+        .await;
+        let mut event_rx = core.component_graph.read().await.subscribe();
+        core.start().await.unwrap();
+        wait_for_component_status(
+            &mut event_rx,
+            "sup-src",
+            ComponentStatus::Running,
+            Duration::from_secs(5),
+        )
+        .await;
+
+        fail_source(&core).await;
+        wait_for_component_status(
+            &mut event_rx,
+            "sup-src",
+            ComponentStatus::Error,
+            Duration::from_secs(5),
+        )
+        .await;
+        wait_for_component_status(
+            &mut event_rx,
+            "sup-src",
+            ComponentStatus::Running,
+            Duration::from_secs(5),
+        )
+        .await;
+
+        let events = core.source_manager.get_source_events("sup-src").await;
+        assert!(events.iter().any(|e| e
+            .message
+            .as_deref()
+            .is_some_and(|m| m.starts_with("Restarting in"))));
+        assert_eq!(
+            core.metrics()
+                .sample("drasi_component_restarts_total", &[("source", "sup-src")]),
+            Some(1.0)
+        );
+        core.stop().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn failed_source_stays_failed_without_policy() {
+        let core = build(RestartPolicy::Never).await;
+        let mut event_rx = core.component_graph.read().await.subscribe();
+        core.start().await.unwrap();
+        wait_for_component_status(
+            &mut event_rx,
+            "sup-src",
+            ComponentStatus::Running,
+            Duration::from_secs(5),
+        )
+        .await;
+
+        fail_source(&core).await;
+        wait_for_component_status(
+            &mut event_rx,
+            "sup-src",
+            ComponentStatus::Error,
+            Duration::from_secs(5),
+        )
+        .await;
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(
+            core.get_source_status("sup-src").await.unwrap(),
+            ComponentStatus::Error
+        );
+    }
+
+    #[tokio::test]
+    async fn panicking_task_reports_error() {
+        let status = ComponentStatusHandle::new("panicky");
+        let task = tokio::spawn(async { panic!("boom") });
+        report_panic(task, status.clone(), "panicky".to_string())
+            .await
+            .unwrap();
+        assert_eq!(status.get_status().await, ComponentStatus::Error);
+    }
+}
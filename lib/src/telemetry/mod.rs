@@ -0,0 +1,231 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tracing of source changes through the pipeline.
+//!
+//! Every source change gets a `change_id` and a span tree that follows it from
+//! the source to each reaction that receives a result derived from it:
+//!
+//! ```text
+//! drasi.change            source_id, change_id, operation, element_id
+//! ├── drasi.dispatch      source_id, change_id
+//! └── drasi.query         query_id, source_id, change_id
+//!     └── drasi.reaction  reaction_id, query_id, change_id
+//! ```
+//!
+//! The span and change id travel with the event in its
+//! [`ProfilingMetadata`]. A span closes once the event and every result
+//! derived from it have been dropped, so the duration of `drasi.change` is the
+//! end-to-end latency up to the last reaction that handled it. Spans use the
+//! [`TRACE_TARGET`] target at `INFO` level.
+//!
+//! With the `otel` feature, [`OtlpConfig`] exports the spans to an OTLP
+//! collector such as Jaeger or Grafana Tempo; see
+//! [`DrasiLibBuilder::with_otlp_exporter`](crate::builder::DrasiLibBuilder::with_otlp_exporter).
+//!
+//! Reactions that want their own logs and spans attached to the trace enter
+//! [`result_span`] while handling a result:
+//!
+//! ```ignore
+//! let span = drasi_lib::telemetry::result_span(&query_result);
+//! let _entered = span.enter();
+//! ```
+
+#[cfg(feature = "otel")]
+mod otlp;
+
+#[cfg(feature = "otel")]
+pub(crate) use otlp::{flush, install};
+#[cfg(feature = "otel")]
+pub use otlp::{OtlpConfig, OtlpProtocol};
+
+use drasi_core::models::SourceChange;
+use tracing::Span;
+
+use crate::channels::{QueryResult, SourceEvent, SourceEventWrapper};
+use crate::profiling::ProfilingMetadata;
+
+/// Target of the pipeline spans, for use in `RUST_LOG` style filters.
+pub const TRACE_TARGET: &str = "drasi::pipeline";
+
+/// The span a reaction should enter while handling `result`.
+///
+/// Returns a disabled span if the result was not traced.
+pub fn result_span(result: &QueryResult) -> Span {
+    result
+        .profiling
+        .as_ref()
+        .and_then(|p| p.span.clone())
+        .unwrap_or_else(Span::none)
+}
+
+/// Assign a change id and open the `drasi.change` span for a source change
+/// about to be dispatched. Control events and already traced changes are left
+/// untouched.
+pub(crate) fn start_change(wrapper: &mut SourceEventWrapper) {
+    let SourceEvent::Change(change) = &wrapper.event else {
+        return;
+    };
+    let profiling = wrapper.profiling.get_or_insert_with(ProfilingMetadata::new);
+    if profiling.span.is_some() {
+        return;
+    }
+    let change_id = profiling
+        .change_id
+        .get_or_insert_with(|| uuid::Uuid::new_v4().to_string());
+    let span = tracing::info_span!(
+        target: TRACE_TARGET,
+        parent: None,
+        "drasi.change",
+        source_id = %wrapper.source_id,
+        change_id = %change_id,
+        operation = operation(change),
+        element_id = %change.get_reference().element_id,
+    );
+    if !span.is_disabled() {
+        profiling.span = Some(span);
+    }
+}
+
+/// Span covering the hand-off of a traced change to the source's subscribers.
+pub(crate) fn dispatch_span(wrapper: &SourceEventWrapper) -> Span {
+    match wrapper.profiling.as_ref() {
+        Some(ProfilingMetadata {
+            span: Some(parent),
+            change_id,
+            ..
+        }) => tracing::info_span!(
+            target: TRACE_TARGET,
+            parent: parent,
+            "drasi.dispatch",
+            source_id = %wrapper.source_id,
+            change_id = change_id.as_deref().unwrap_or_default(),
+        ),
+        _ => Span::none(),
+    }
+}
+
+/// Open the `drasi.query` span for evaluating a change and make it the parent
+/// of the spans of the results it produces.
+pub(crate) fn start_query_evaluation(
+    profiling: &mut ProfilingMetadata,
+    instance_id: &str,
+    query_id: &str,
+    source_id: &str,
+) -> Span {
+    let parent = profiling.span.as_ref().and_then(Span::id);
+    let span = tracing::info_span!(
+        target: TRACE_TARGET,
+        parent: parent,
+        "drasi.query",
+        instance_id,
+        component_id = query_id,
+        component_type = "query",
+        query_id,
+        source_id,
+        change_id = profiling.change_id.as_deref().unwrap_or_default(),
+    );
+    if !span.is_disabled() {
+        profiling.span = Some(span.clone());
+    }
+    span
+}
+
+/// Open the `drasi.reaction` span for delivering `result` to a reaction.
+pub(crate) fn start_reaction(result: &mut QueryResult, instance_id: &str, reaction_id: &str) {
+    let Some(profiling) = result.profiling.as_mut() else {
+        return;
+    };
+    let Some(parent) = profiling.span.as_ref() else {
+        return;
+    };
+    let span = tracing::info_span!(
+        target: TRACE_TARGET,
+        parent: parent,
+        "drasi.reaction",
+        instance_id,
+        component_id = reaction_id,
+        component_type = "reaction",
+        reaction_id,
+        query_id = %result.query_id,
+        change_id = profiling.change_id.as_deref().unwrap_or_default(),
+    );
+    profiling.span = Some(span);
+}
+
+fn operation(change: &SourceChange) -> &'static str {
+    match change {
+        SourceChange::Insert { .. } => "insert",
+        SourceChange::Update { .. } => "update",
+        SourceChange::Delete { .. } => "delete",
+        SourceChange::Future { .. } => "future",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use drasi_core::models::{Element, ElementMetadata, ElementPropertyMap, ElementReference};
+    use std::sync::Arc;
+
+    fn insert(id: &str) -> SourceEventWrapper {
+        SourceEventWrapper::new(
+            "s1".to_string(),
+            SourceEvent::Change(SourceChange::Insert {
+                element: Element::Node {
+                    metadata: ElementMetadata {
+                        reference: ElementReference::new("s1", id),
+                        labels: Arc::from(vec![Arc::from("Test")]),
+                        effective_from: 0,
+                    },
+                    properties: ElementPropertyMap::new(),
+                },
+            }),
+            chrono::Utc::now(),
+        )
+    }
+
+    #[test]
+    fn test_start_change_assigns_change_id_once() {
+        let mut wrapper = insert("n1");
+        start_change(&mut wrapper);
+        let change_id = wrapper.profiling.as_ref().unwrap().change_id.clone();
+        assert!(change_id.is_some());
+
+        start_change(&mut wrapper);
+        assert_eq!(wrapper.profiling.unwrap().change_id, change_id);
+    }
+
+    #[test]
+    fn test_control_events_are_not_traced() {
+        let mut wrapper = SourceEventWrapper::new(
+            "s1".to_string(),
+            SourceEvent::Control(crate::channels::SourceControl::FuturesDue),
+            chrono::Utc::now(),
+        );
+        start_change(&mut wrapper);
+        assert!(wrapper.profiling.is_none());
+    }
+
+    #[test]
+    fn test_untraced_result_has_disabled_span() {
+        let result = QueryResult::new(
+            "q1".to_string(),
+            chrono::Utc::now(),
+            vec![],
+            Default::default(),
+        );
+        assert!(result_span(&result).is_disabled());
+    }
+}
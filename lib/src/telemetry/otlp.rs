@@ -0,0 +1,200 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! OTLP export of pipeline spans (`otel` feature).
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use anyhow::Result;
+use log::{info, warn};
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::trace::{Sampler, TracerProvider};
+use opentelemetry_sdk::{runtime, Resource};
+
+/// Provider of the installed exporter, kept so it can be flushed on shutdown.
+static PROVIDER: Mutex<Option<TracerProvider>> = Mutex::new(None);
+
+/// Wire protocol used to reach the OTLP collector.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OtlpProtocol {
+    /// gRPC, usually on port 4317.
+    #[default]
+    Grpc,
+    /// Protobuf over HTTP, usually on port 4318.
+    HttpProtobuf,
+}
+
+/// Where and how to export pipeline spans.
+///
+/// # Example
+///
+/// ```ignore
+/// let otlp = OtlpConfig::new("http://tempo:4317")
+///     .with_service_name("sensor-pipeline")
+///     .with_sample_ratio(0.1);
+/// let core = DrasiLib::builder().with_otlp_exporter(otlp).build().await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct OtlpConfig {
+    /// Collector endpoint. For [`OtlpProtocol::HttpProtobuf`] this is the full
+    /// traces URL, e.g. `http://localhost:4318/v1/traces`.
+    pub endpoint: String,
+    pub protocol: OtlpProtocol,
+    /// Reported as the `service.name` resource attribute.
+    pub service_name: String,
+    /// Fraction of changes traced, between 0.0 and 1.0.
+    pub sample_ratio: f64,
+    /// Timeout of each export request.
+    pub timeout: Duration,
+}
+
+impl Default for OtlpConfig {
+    fn default() -> Self {
+        Self {
+            endpoint: "http://localhost:4317".to_string(),
+            protocol: OtlpProtocol::Grpc,
+            service_name: "drasi".to_string(),
+            sample_ratio: 1.0,
+            timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+impl OtlpConfig {
+    /// Export over gRPC to `endpoint`.
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_protocol(mut self, protocol: OtlpProtocol) -> Self {
+        self.protocol = protocol;
+        self
+    }
+
+    pub fn with_service_name(mut self, service_name: impl Into<String>) -> Self {
+        self.service_name = service_name.into();
+        self
+    }
+
+    pub fn with_sample_ratio(mut self, ratio: f64) -> Self {
+        self.sample_ratio = ratio;
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+/// Start exporting spans as configured, replacing any previous exporter.
+///
+/// The tracing subscriber is process-wide, so the last exporter installed
+/// serves every `DrasiLib` instance in the process.
+pub(crate) fn install(config: &OtlpConfig) -> Result<()> {
+    if !(0.0..=1.0).contains(&config.sample_ratio) {
+        anyhow::bail!(
+            "OTLP sample ratio must be between 0.0 and 1.0, got {}",
+            config.sample_ratio
+        );
+    }
+
+    let exporter = match config.protocol {
+        OtlpProtocol::Grpc => SpanExporter::builder()
+            .with_tonic()
+            .with_endpoint(config.endpoint.clone())
+            .with_timeout(config.timeout)
+            .build()?,
+        OtlpProtocol::HttpProtobuf => SpanExporter::builder()
+            .with_http()
+            .with_endpoint(config.endpoint.clone())
+            .with_timeout(config.timeout)
+            .build()?,
+    };
+
+    let provider = TracerProvider::builder()
+        .with_batch_exporter(exporter, runtime::Tokio)
+        .with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+            config.sample_ratio,
+        ))))
+        .with_resource(Resource::new([KeyValue::new(
+            "service.name",
+            config.service_name.clone(),
+        )]))
+        .build();
+    let layer = tracing_opentelemetry::layer().with_tracer(provider.tracer("drasi-lib"));
+    crate::managers::set_otel_layer(layer)?;
+
+    let previous = PROVIDER
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .replace(provider);
+    if let Some(previous) = previous {
+        // Shutting down blocks until pending spans are exported
+        tokio::task::spawn_blocking(move || {
+            if let Err(e) = previous.shutdown() {
+                warn!("Failed to shut down previous OTLP exporter: {e}");
+            }
+        });
+    }
+
+    info!(
+        "Exporting traces to {} ({:?}) as service '{}'",
+        config.endpoint, config.protocol, config.service_name
+    );
+    Ok(())
+}
+
+/// Export all finished spans that are still buffered.
+pub(crate) async fn flush() {
+    let provider = PROVIDER.lock().unwrap_or_else(|e| e.into_inner()).clone();
+    let Some(provider) = provider else {
+        return;
+    };
+    // force_flush blocks on the batch processor, which runs on this runtime
+    let results = tokio::task::spawn_blocking(move || provider.force_flush())
+        .await
+        .unwrap_or_default();
+    for result in results {
+        if let Err(e) = result {
+            warn!("Failed to flush OTLP spans: {e}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_defaults() {
+        let config = OtlpConfig::new("http://collector:4317").with_service_name("svc");
+        assert_eq!(config.endpoint, "http://collector:4317");
+        assert_eq!(config.protocol, OtlpProtocol::Grpc);
+        assert_eq!(config.service_name, "svc");
+        assert_eq!(config.sample_ratio, 1.0);
+    }
+
+    #[test]
+    fn test_invalid_sample_ratio_is_rejected() {
+        let config = OtlpConfig::new("http://collector:4317").with_sample_ratio(1.5);
+        assert!(install(&config).is_err());
+    }
+}
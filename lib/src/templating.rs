@@ -0,0 +1,518 @@
+// Copyright 2025 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Shared Handlebars templating for reactions.
+//!
+//! Reactions that format query results with user-supplied templates should go
+//! through [`TemplateEngine`] so every reaction exposes the same helpers and
+//! the same template variables.
+//!
+//! # Template Variables
+//!
+//! [`change_context`] builds the context for a single result diff:
+//! - `query_name` - The id of the query that produced the result
+//! - `operation` - `"ADD"`, `"UPDATE"`, `"DELETE"` or `"AGGREGATION"`
+//! - `after` - The row after the change (ADD, UPDATE, AGGREGATION)
+//! - `before` - The row before the change (UPDATE, DELETE, AGGREGATION)
+//! - `data` - The raw data field (UPDATE)
+//! - `timestamp` - The query result timestamp (RFC 3339)
+//! - `metadata` - The query result metadata map
+//!
+//! [`batch_context`] builds the context for a whole [`QueryResult`], exposing
+//! `query_name`, `timestamp`, `metadata`, `count` and a `results` array whose
+//! entries carry the per-change `operation`, `before`, `after` and `data`
+//! fields, so templates can iterate with `{{#each results}}`.
+//!
+//! # Helpers
+//!
+//! On top of the Handlebars built-ins (`if`, `unless`, `each`, `with`,
+//! `lookup`, `eq`, `ne`, `gt`, `gte`, `lt`, `lte`, `and`, `or`, `not`, `len`):
+//! - `json value` - Serialize a value as JSON
+//! - `format_number value [decimals]` - Fixed-point formatting, 2 decimals by default
+//! - `format_date value [format]` - Format an RFC 3339 string or epoch milliseconds
+//!   with a chrono format string (RFC 3339 by default)
+//! - `upper value` / `lower value` - Change string case
+//! - `default value fallback` - Use `fallback` when `value` is null or empty
+//! - `truncate value length` - Limit a string to `length` characters
+//! - `join array [separator]` - Join array items, `", "` by default
+//!
+//! # Example
+//!
+//! ```rust
+//! use drasi_lib::templating::TemplateEngine;
+//! use serde_json::json;
+//!
+//! let engine = TemplateEngine::new();
+//! let rendered = engine
+//!     .render(
+//!         "{{#if (gt after.temp 30)}}HOT {{format_number after.temp 1}}{{/if}}",
+//!         &json!({ "after": { "temp": 31.26 } }),
+//!     )
+//!     .unwrap();
+//! assert_eq!(rendered, "HOT 31.3");
+//! ```
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, SecondsFormat, TimeZone, Utc};
+use handlebars::{
+    Context, Handlebars, Helper, HelperResult, Output, RenderContext, RenderErrorReason,
+};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::fmt::Write;
+
+use crate::channels::{QueryResult, ResultDiff};
+
+/// Handlebars engine with the shared reaction helpers registered.
+#[derive(Debug, Clone)]
+pub struct TemplateEngine {
+    handlebars: Handlebars<'static>,
+}
+
+impl TemplateEngine {
+    /// Create an engine with all shared helpers registered.
+    pub fn new() -> Self {
+        let mut handlebars = Handlebars::new();
+        register_helpers(&mut handlebars);
+        Self { handlebars }
+    }
+
+    /// Create an engine that does not HTML-escape `{{expression}}` output,
+    /// for templates that render plain text rather than HTML.
+    pub fn without_escaping() -> Self {
+        let mut engine = Self::new();
+        engine.handlebars.register_escape_fn(handlebars::no_escape);
+        engine
+    }
+
+    /// Check that a template compiles.
+    pub fn validate(template: &str) -> Result<()> {
+        handlebars::Template::compile(template)
+            .map(|_| ())
+            .map_err(|e| anyhow!("Invalid template: {e}"))
+    }
+
+    /// Render a template string against a serializable context.
+    pub fn render<T: Serialize>(&self, template: &str, context: &T) -> Result<String> {
+        self.handlebars
+            .render_template(template, context)
+            .map_err(|e| anyhow!("Template render error: {e}"))
+    }
+
+    /// Access the underlying Handlebars registry.
+    pub fn handlebars(&self) -> &Handlebars<'static> {
+        &self.handlebars
+    }
+}
+
+impl Default for TemplateEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Build the template context for a single result diff.
+///
+/// Returns `None` for [`ResultDiff::Noop`], which has nothing to render.
+pub fn change_context(query_result: &QueryResult, diff: &ResultDiff) -> Option<Value> {
+    let mut context = query_fields(query_result);
+    context.extend(diff_fields(diff)?);
+    Some(Value::Object(context))
+}
+
+/// Build the template context for a whole query result batch.
+pub fn batch_context(query_result: &QueryResult) -> Value {
+    let results: Vec<Value> = query_result
+        .results
+        .iter()
+        .filter_map(diff_fields)
+        .map(Value::Object)
+        .collect();
+
+    let mut context = query_fields(query_result);
+    context.insert("count".to_string(), json!(results.len()));
+    context.insert("results".to_string(), Value::Array(results));
+    Value::Object(context)
+}
+
+fn query_fields(query_result: &QueryResult) -> Map<String, Value> {
+    let mut fields = Map::new();
+    fields.insert(
+        "query_name".to_string(),
+        Value::String(query_result.query_id.clone()),
+    );
+    fields.insert(
+        "timestamp".to_string(),
+        Value::String(
+            query_result
+                .timestamp
+                .to_rfc3339_opts(SecondsFormat::Millis, true),
+        ),
+    );
+    fields.insert(
+        "metadata".to_string(),
+        Value::Object(
+            query_result
+                .metadata
+                .iter()
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect(),
+        ),
+    );
+    fields
+}
+
+fn diff_fields(diff: &ResultDiff) -> Option<Map<String, Value>> {
+    let mut fields = Map::new();
+    match diff {
+        ResultDiff::Add { data } => {
+            fields.insert("operation".to_string(), Value::String("ADD".into()));
+            fields.insert("after".to_string(), data.clone());
+        }
+        ResultDiff::Delete { data } => {
+            fields.insert("operation".to_string(), Value::String("DELETE".into()));
+            fields.insert("before".to_string(), data.clone());
+        }
+        ResultDiff::Update {
+            data,
+            before,
+            after,
+            ..
+        } => {
+            fields.insert("operation".to_string(), Value::String("UPDATE".into()));
+            fields.insert("before".to_string(), before.clone());
+            fields.insert("after".to_string(), after.clone());
+            fields.insert("data".to_string(), data.clone());
+        }
+        ResultDiff::Aggregation { before, after } => {
+            fields.insert("operation".to_string(), Value::String("AGGREGATION".into()));
+            if let Some(before) = before {
+                fields.insert("before".to_string(), before.clone());
+            }
+            fields.insert("after".to_string(), after.clone());
+        }
+        ResultDiff::Noop => return None,
+    }
+    Some(fields)
+}
+
+/// Escape text for inclusion in HTML, as `{{expression}}` output is escaped.
+pub fn html_escape(text: &str) -> String {
+    handlebars::html_escape(text)
+}
+
+/// Register the shared reaction helpers on an existing Handlebars registry.
+pub fn register_helpers(handlebars: &mut Handlebars) {
+    handlebars.register_helper("json", Box::new(json_helper));
+    handlebars.register_helper("format_number", Box::new(format_number_helper));
+    handlebars.register_helper("format_date", Box::new(format_date_helper));
+    handlebars.register_helper("upper", Box::new(upper_helper));
+    handlebars.register_helper("lower", Box::new(lower_helper));
+    handlebars.register_helper("default", Box::new(default_helper));
+    handlebars.register_helper("truncate", Box::new(truncate_helper));
+    handlebars.register_helper("join", Box::new(join_helper));
+}
+
+fn param<'a>(
+    h: &'a Helper,
+    name: &'static str,
+    index: usize,
+) -> Result<&'a Value, RenderErrorReason> {
+    h.param(index)
+        .map(|p| p.value())
+        .ok_or(RenderErrorReason::ParamNotFoundForIndex(name, index))
+}
+
+fn display(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+fn as_f64(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+fn json_helper(
+    h: &Helper,
+    _: &Handlebars,
+    _: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> HelperResult {
+    let value = param(h, "json", 0)?;
+    let json_str = serde_json::to_string(value).unwrap_or_else(|_| "null".to_string());
+    out.write(&json_str)?;
+    Ok(())
+}
+
+fn format_number_helper(
+    h: &Helper,
+    _: &Handlebars,
+    _: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> HelperResult {
+    let value = param(h, "format_number", 0)?;
+    let decimals = h.param(1).and_then(|p| p.value().as_u64()).unwrap_or(2) as usize;
+    match as_f64(value) {
+        Some(n) => out.write(&format!("{n:.decimals$}"))?,
+        None => out.write(&display(value))?,
+    }
+    Ok(())
+}
+
+fn format_date_helper(
+    h: &Helper,
+    _: &Handlebars,
+    _: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> HelperResult {
+    let value = param(h, "format_date", 0)?;
+    let format = h.param(1).and_then(|p| p.value().as_str());
+
+    let parsed: Option<DateTime<Utc>> = match value {
+        Value::Number(n) => n
+            .as_i64()
+            .and_then(|ms| Utc.timestamp_millis_opt(ms).single()),
+        Value::String(s) => DateTime::parse_from_rfc3339(s)
+            .ok()
+            .map(|dt| dt.with_timezone(&Utc)),
+        _ => None,
+    };
+
+    match (parsed, format) {
+        (Some(dt), Some(format)) => {
+            let mut formatted = String::new();
+            write!(formatted, "{}", dt.format(format))
+                .map_err(|_| RenderErrorReason::Other(format!("Invalid date format: {format}")))?;
+            out.write(&formatted)?;
+        }
+        (Some(dt), None) => out.write(&dt.to_rfc3339_opts(SecondsFormat::Millis, true))?,
+        (None, _) => out.write(&display(value))?,
+    }
+    Ok(())
+}
+
+fn upper_helper(
+    h: &Helper,
+    _: &Handlebars,
+    _: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> HelperResult {
+    let value = param(h, "upper", 0)?;
+    out.write(&display(value).to_uppercase())?;
+    Ok(())
+}
+
+fn lower_helper(
+    h: &Helper,
+    _: &Handlebars,
+    _: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> HelperResult {
+    let value = param(h, "lower", 0)?;
+    out.write(&display(value).to_lowercase())?;
+    Ok(())
+}
+
+fn default_helper(
+    h: &Helper,
+    _: &Handlebars,
+    _: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> HelperResult {
+    let value = h.param(0).map(|p| p.value()).unwrap_or(&Value::Null);
+    let fallback = param(h, "default", 1)?;
+    let output = match value {
+        Value::Null => fallback,
+        Value::String(s) if s.is_empty() => fallback,
+        other => other,
+    };
+    out.write(&display(output))?;
+    Ok(())
+}
+
+fn truncate_helper(
+    h: &Helper,
+    _: &Handlebars,
+    _: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> HelperResult {
+    let value = display(param(h, "truncate", 0)?);
+    let length = param(h, "truncate", 1)?
+        .as_u64()
+        .ok_or(RenderErrorReason::InvalidParamType(
+            "truncate length must be a number",
+        ))? as usize;
+    out.write(&value.chars().take(length).collect::<String>())?;
+    Ok(())
+}
+
+fn join_helper(
+    h: &Helper,
+    _: &Handlebars,
+    _: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> HelperResult {
+    let value = param(h, "join", 0)?;
+    let separator = h.param(1).and_then(|p| p.value().as_str()).unwrap_or(", ");
+    match value {
+        Value::Array(items) => {
+            let joined = items
+                .iter()
+                .map(display)
+                .collect::<Vec<_>>()
+                .join(separator);
+            out.write(&joined)?;
+        }
+        other => out.write(&display(other))?,
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn query_result(results: Vec<ResultDiff>) -> QueryResult {
+        let mut metadata = HashMap::new();
+        metadata.insert("tenant".to_string(), json!("acme"));
+        QueryResult::new(
+            "sensors".to_string(),
+            Utc.timestamp_millis_opt(1_700_000_000_000).unwrap(),
+            results,
+            metadata,
+        )
+    }
+
+    #[test]
+    fn test_change_context_fields() {
+        let qr = query_result(vec![]);
+        let diff = ResultDiff::Update {
+            data: json!({"id": 1}),
+            before: json!({"id": 1, "temp": 20}),
+            after: json!({"id": 1, "temp": 25}),
+            grouping_keys: None,
+        };
+        let context = change_context(&qr, &diff).unwrap();
+        assert_eq!(context["query_name"], "sensors");
+        assert_eq!(context["operation"], "UPDATE");
+        assert_eq!(context["before"]["temp"], 20);
+        assert_eq!(context["after"]["temp"], 25);
+        assert_eq!(context["metadata"]["tenant"], "acme");
+        assert_eq!(context["timestamp"], "2023-11-14T22:13:20.000Z");
+
+        assert!(change_context(&qr, &ResultDiff::Noop).is_none());
+    }
+
+    #[test]
+    fn test_batch_loop_and_conditionals() {
+        let qr = query_result(vec![
+            ResultDiff::Add {
+                data: json!({"id": "a"}),
+            },
+            ResultDiff::Noop,
+            ResultDiff::Delete {
+                data: json!({"id": "b"}),
+            },
+        ]);
+        let engine = TemplateEngine::new();
+        let rendered = engine
+            .render(
+                "{{query_name}} ({{count}}):{{#each results}} {{#if (eq operation \"ADD\")}}+{{after.id}}{{else}}-{{before.id}}{{/if}}{{/each}}",
+                &batch_context(&qr),
+            )
+            .unwrap();
+        assert_eq!(rendered, "sensors (2): +a -b");
+    }
+
+    #[test]
+    fn test_format_helpers() {
+        let engine = TemplateEngine::new();
+        let context = json!({
+            "price": 3.14159,
+            "text": "42",
+            "ts": 1_700_000_000_000_i64,
+            "iso": "2023-11-14T22:13:20Z",
+        });
+        let render = |t: &str| engine.render(t, &context).unwrap();
+
+        assert_eq!(render("{{format_number price}}"), "3.14");
+        assert_eq!(render("{{format_number price 0}}"), "3");
+        assert_eq!(render("{{format_number text 1}}"), "42.0");
+        assert_eq!(render("{{format_date ts \"%Y-%m-%d\"}}"), "2023-11-14");
+        assert_eq!(render("{{format_date iso \"%H:%M\"}}"), "22:13");
+        assert_eq!(render("{{format_date ts}}"), "2023-11-14T22:13:20.000Z");
+        assert!(engine
+            .render("{{format_date ts \"%Q\"}}", &context)
+            .is_err());
+    }
+
+    #[test]
+    fn test_string_helpers() {
+        let engine = TemplateEngine::new();
+        let context = json!({
+            "name": "Drasi",
+            "empty": "",
+            "tags": ["a", "b", 3],
+            "obj": {"k": 1},
+        });
+        let render = |t: &str| engine.render(t, &context).unwrap();
+
+        assert_eq!(render("{{upper name}} {{lower name}}"), "DRASI drasi");
+        assert_eq!(render("{{default empty \"n/a\"}}"), "n/a");
+        assert_eq!(render("{{default missing \"n/a\"}}"), "n/a");
+        assert_eq!(render("{{default name \"n/a\"}}"), "Drasi");
+        assert_eq!(render("{{truncate name 2}}"), "Dr");
+        assert_eq!(render("{{join tags}}"), "a, b, 3");
+        assert_eq!(render("{{join tags \"|\"}}"), "a|b|3");
+        assert_eq!(render("{{{json obj}}}"), r#"{"k":1}"#);
+    }
+
+    #[test]
+    fn test_escaping() {
+        let context = json!({"text": "<b>&</b>"});
+        assert_eq!(
+            TemplateEngine::new().render("{{text}}", &context).unwrap(),
+            "&lt;b&gt;&amp;&lt;/b&gt;"
+        );
+        assert_eq!(
+            TemplateEngine::without_escaping()
+                .render("{{text}}", &context)
+                .unwrap(),
+            "<b>&</b>"
+        );
+        assert_eq!(html_escape("<b>"), "&lt;b&gt;");
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(TemplateEngine::validate("{{#each results}}{{after.id}}{{/each}}").is_ok());
+        assert!(TemplateEngine::validate("{{#if x}}unclosed").is_err());
+    }
+}
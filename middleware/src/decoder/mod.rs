@@ -24,6 +24,7 @@ use drasi_core::{
 };
 use serde::Deserialize;
 use serde_json::Value;
+use std::io::Read;
 use std::sync::Arc;
 
 #[cfg(test)]
@@ -40,6 +41,17 @@ pub enum EncodingType {
     JsonEscape,
 }
 
+/// Compression applied to the payload underneath the text encoding.
+///
+/// Only valid with the binary-to-text encodings (`base64`, `base64url`, `hex`).
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Compression {
+    Gzip,
+    /// Requires the `zstd` feature.
+    Zstd,
+}
+
 /// Mapping of encoding types to their string representations for error messages
 const ENCODING_TYPE_NAMES: &[(&str, EncodingType)] = &[
     ("base64", EncodingType::Base64),
@@ -69,12 +81,22 @@ pub struct DecoderConfig {
     /// Maximum allowed size for encoded strings to prevent excessive memory usage.
     #[serde(default = "default_max_size")]
     pub max_size_bytes: usize,
+    /// Optional compression to undo after decoding the text encoding.
+    #[serde(default)]
+    pub compression: Option<Compression>,
+    /// Maximum allowed size of the decompressed payload, guarding against compression bombs.
+    #[serde(default = "default_max_decompressed_size")]
+    pub max_decompressed_bytes: usize,
 }
 
 fn default_max_size() -> usize {
     1024 * 1024 // 1MB default
 }
 
+fn default_max_decompressed_size() -> usize {
+    16 * 1024 * 1024 // 16MB default
+}
+
 /// Middleware that decodes a string property using a specified encoding.
 pub struct Decoder {
     name: String,
@@ -166,13 +188,22 @@ impl Decoder {
                 }
                 .to_string();
 
-                // Step 2: Decode the string based on encoding type
-                let decoded_result = match self.config.encoding_type {
-                    EncodingType::Base64 => self.decode_base64(&processed_str),
-                    EncodingType::Base64url => self.decode_base64url(&processed_str),
-                    EncodingType::Hex => self.decode_hex(&processed_str),
-                    EncodingType::Url => self.decode_url(&processed_str),
-                    EncodingType::JsonEscape => self.decode_json_escape(&processed_str),
+                // Step 2: Decode the string based on encoding type, decompressing if configured
+                let decoded_result = match self.config.compression {
+                    Some(compression) => self
+                        .decode_bytes(&processed_str)
+                        .and_then(|bytes| self.decompress(compression, &bytes))
+                        .and_then(|bytes| {
+                            String::from_utf8(bytes)
+                                .map_err(|e| format!("Decompressed bytes are not valid UTF-8: {e}"))
+                        }),
+                    None => match self.config.encoding_type {
+                        EncodingType::Base64 => self.decode_base64(&processed_str),
+                        EncodingType::Base64url => self.decode_base64url(&processed_str),
+                        EncodingType::Hex => self.decode_hex(&processed_str),
+                        EncodingType::Url => self.decode_url(&processed_str),
+                        EncodingType::JsonEscape => self.decode_json_escape(&processed_str),
+                    },
                 };
 
                 match decoded_result {
@@ -246,6 +277,50 @@ impl Decoder {
         }
     }
 
+    /// Decodes a binary-to-text encoded string into raw bytes.
+    fn decode_bytes(&self, encoded: &str) -> Result<Vec<u8>, String> {
+        match self.config.encoding_type {
+            EncodingType::Base64 => base64::engine::general_purpose::STANDARD
+                .decode(encoded.as_bytes())
+                .map_err(|e| format!("Invalid base64 encoding: {e}")),
+            EncodingType::Base64url => base64::engine::general_purpose::URL_SAFE_NO_PAD
+                .decode(encoded.as_bytes())
+                .map_err(|e| format!("Invalid base64url encoding: {e}")),
+            EncodingType::Hex => {
+                hex::decode(encoded).map_err(|e| format!("Invalid hex encoding: {e}"))
+            }
+            EncodingType::Url | EncodingType::JsonEscape => Err(format!(
+                "{} encoding does not carry binary data",
+                self.get_encoding_type_name()
+            )),
+        }
+    }
+
+    /// Decompresses `bytes`, failing if the output exceeds `max_decompressed_bytes`.
+    fn decompress(&self, compression: Compression, bytes: &[u8]) -> Result<Vec<u8>, String> {
+        let limit = self.config.max_decompressed_bytes as u64;
+        let mut output = Vec::new();
+        let read = match compression {
+            Compression::Gzip => flate2::read::GzDecoder::new(bytes)
+                .take(limit + 1)
+                .read_to_end(&mut output),
+            #[cfg(feature = "zstd")]
+            Compression::Zstd => zstd::stream::read::Decoder::new(bytes)
+                .and_then(|decoder| decoder.take(limit + 1).read_to_end(&mut output)),
+            #[cfg(not(feature = "zstd"))]
+            Compression::Zstd => {
+                return Err("zstd support is not enabled (requires the 'zstd' feature)".into())
+            }
+        };
+        read.map_err(|e| format!("Invalid {compression:?} data: {e}"))?;
+        if output.len() as u64 > limit {
+            return Err(format!(
+                "Decompressed payload exceeds size limit ({limit} bytes)"
+            ));
+        }
+        Ok(output)
+    }
+
     /// Decodes a base64 encoded string.
     pub fn decode_base64(&self, encoded: &str) -> Result<String, String> {
         base64::engine::general_purpose::STANDARD
@@ -345,6 +420,30 @@ impl SourceMiddlewareFactory for DecoderFactory {
             }
         }
 
+        if let Some(compression) = decoder_config.compression {
+            if matches!(
+                decoder_config.encoding_type,
+                EncodingType::Url | EncodingType::JsonEscape
+            ) {
+                return Err(MiddlewareSetupError::InvalidConfiguration(format!(
+                    "[{}] 'compression' requires a binary encoding (base64, base64url or hex)",
+                    config.name
+                )));
+            }
+            if compression == Compression::Zstd && !cfg!(feature = "zstd") {
+                return Err(MiddlewareSetupError::InvalidConfiguration(format!(
+                    "[{}] zstd compression requires the 'zstd' feature",
+                    config.name
+                )));
+            }
+            if decoder_config.max_decompressed_bytes == 0 {
+                return Err(MiddlewareSetupError::InvalidConfiguration(format!(
+                    "[{}] 'max_decompressed_bytes' must be greater than zero",
+                    config.name
+                )));
+            }
+        }
+
         // Validate max_size_bytes is reasonable
         if decoder_config.max_size_bytes == 0 {
             return Err(MiddlewareSetupError::InvalidConfiguration(format!(
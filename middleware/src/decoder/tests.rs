@@ -111,6 +111,14 @@ fn get_props_from_change(change: &SourceChange) -> &ElementPropertyMap {
     }
 }
 
+fn gzip_base64(payload: &str) -> String {
+    use base64::Engine;
+    use std::io::Write;
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(payload.as_bytes()).unwrap();
+    base64::engine::general_purpose::STANDARD.encode(encoder.finish().unwrap())
+}
+
 // --- Test Modules ---
 
 mod process {
@@ -1010,6 +1018,123 @@ mod process {
     }
 }
 
+mod compression {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_gzip_base64_decode_success() {
+        let factory = DecoderFactory::new();
+        let config = json!({
+            "encoding_type": "base64",
+            "compression": "gzip",
+            "target_property": "payload",
+            "output_property": "decoded"
+        });
+        let subject = factory.create(&create_mw_config(config)).unwrap();
+        let element_index = Arc::new(InMemoryElementIndex::new());
+
+        let source_change = create_node_insert_change(json!({
+            "payload": gzip_base64("{\"temp\":21.5}")
+        }));
+
+        let result = subject
+            .process(source_change, element_index.as_ref())
+            .await
+            .unwrap();
+        let props = get_props_from_change(&result[0]);
+        assert_eq!(
+            props.get("decoded"),
+            Some(&ElementValue::String("{\"temp\":21.5}".into()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_gzip_invalid_data_fail() {
+        let factory = DecoderFactory::new();
+        let config = json!({
+            "encoding_type": "base64",
+            "compression": "gzip",
+            "target_property": "payload"
+        });
+        let subject = factory.create(&create_mw_config(config)).unwrap();
+        let element_index = Arc::new(InMemoryElementIndex::new());
+
+        // Valid base64 but not gzip
+        let source_change = create_node_insert_change(json!({ "payload": "SGVsbG8gV29ybGQh" }));
+
+        let result = subject.process(source_change, element_index.as_ref()).await;
+        assert!(matches!(result, Err(MiddlewareError::SourceChangeError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_gzip_decompressed_size_limit() {
+        let factory = DecoderFactory::new();
+        let config = json!({
+            "encoding_type": "base64",
+            "compression": "gzip",
+            "target_property": "payload",
+            "max_decompressed_bytes": 16
+        });
+        let subject = factory.create(&create_mw_config(config)).unwrap();
+        let element_index = Arc::new(InMemoryElementIndex::new());
+
+        let source_change = create_node_insert_change(json!({
+            "payload": gzip_base64(&"a".repeat(1000))
+        }));
+
+        let result = subject.process(source_change, element_index.as_ref()).await;
+        match result {
+            Err(MiddlewareError::SourceChangeError(msg)) => {
+                assert!(msg.contains("exceeds size limit"))
+            }
+            _ => panic!("Expected SourceChangeError"),
+        }
+    }
+
+    #[cfg(feature = "zstd")]
+    #[tokio::test]
+    async fn test_zstd_hex_decode_success() {
+        let factory = DecoderFactory::new();
+        let config = json!({
+            "encoding_type": "hex",
+            "compression": "zstd",
+            "target_property": "payload"
+        });
+        let subject = factory.create(&create_mw_config(config)).unwrap();
+        let element_index = Arc::new(InMemoryElementIndex::new());
+
+        let compressed = zstd::encode_all("device-42".as_bytes(), 0).unwrap();
+        let source_change =
+            create_node_insert_change(json!({ "payload": hex::encode(compressed) }));
+
+        let result = subject
+            .process(source_change, element_index.as_ref())
+            .await
+            .unwrap();
+        let props = get_props_from_change(&result[0]);
+        assert_eq!(
+            props.get("payload"),
+            Some(&ElementValue::String("device-42".into()))
+        );
+    }
+
+    #[test]
+    fn fail_compression_with_text_encoding() {
+        let factory = DecoderFactory::new();
+        let config = json!({
+            "encoding_type": "url",
+            "compression": "gzip",
+            "target_property": "payload"
+        });
+        match factory.create(&create_mw_config(config)) {
+            Err(MiddlewareSetupError::InvalidConfiguration(msg)) => {
+                assert!(msg.contains("requires a binary encoding"))
+            }
+            _ => panic!("Expected InvalidConfiguration error"),
+        }
+    }
+}
+
 mod factory {
     use super::*;
 
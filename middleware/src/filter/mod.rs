@@ -0,0 +1,285 @@
+// Copyright 2024 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use drasi_core::{
+    interface::{
+        ElementIndex, MiddlewareError, MiddlewareSetupError, SourceMiddleware,
+        SourceMiddlewareFactory,
+    },
+    models::{Element, SourceChange, SourceMiddlewareConfig},
+};
+use serde::Deserialize;
+use serde_json::Value;
+
+#[cfg(test)]
+mod tests;
+
+/// Comparison applied by a [`FilterCondition`].
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterOperator {
+    Eq,
+    Ne,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+    /// The property is present and not null.
+    Exists,
+    /// The property is absent or null.
+    NotExists,
+    /// The property equals one of the values in the `value` array.
+    In,
+    /// The string property contains `value`, or the list property contains an element equal to `value`.
+    Contains,
+}
+
+/// How multiple conditions are combined.
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum FilterMode {
+    /// Every condition must hold.
+    #[default]
+    All,
+    /// At least one condition must hold.
+    Any,
+}
+
+/// A single predicate on an element property.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FilterCondition {
+    /// Property to test. Nested object fields are addressed with dots, e.g. `location.city`.
+    pub property: String,
+    pub operator: FilterOperator,
+    /// Operand; not needed for `exists` / `not_exists`.
+    #[serde(default)]
+    pub value: Value,
+}
+
+/// Configuration for the filter middleware
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FilterMiddlewareConfig {
+    pub conditions: Vec<FilterCondition>,
+    #[serde(default)]
+    pub mode: FilterMode,
+    /// Only filter elements carrying at least one of these labels. Other
+    /// elements pass through unchanged. Empty means all elements are filtered.
+    #[serde(default)]
+    pub labels: Vec<String>,
+}
+
+/// Drops source changes whose element does not satisfy the configured predicate.
+///
+/// An update to an element that no longer matches is turned into a delete, so
+/// elements that drift out of the filter are removed from the query rather
+/// than left stale.
+pub struct FilterMiddleware {
+    name: String,
+    config: FilterMiddlewareConfig,
+}
+
+impl FilterMiddleware {
+    pub fn new(name: String, config: FilterMiddlewareConfig) -> Self {
+        FilterMiddleware { name, config }
+    }
+
+    fn applies_to(&self, element: &Element) -> bool {
+        self.config.labels.is_empty()
+            || element
+                .get_metadata()
+                .labels
+                .iter()
+                .any(|label| self.config.labels.iter().any(|l| l == label.as_ref()))
+    }
+
+    fn matches(&self, element: &Element) -> bool {
+        if !self.applies_to(element) {
+            return true;
+        }
+        let mut results = self
+            .config
+            .conditions
+            .iter()
+            .map(|condition| evaluate(condition, element));
+        match self.config.mode {
+            FilterMode::All => results.all(|r| r),
+            FilterMode::Any => results.any(|r| r),
+        }
+    }
+}
+
+/// Look up a possibly dotted property path on an element.
+fn lookup(element: &Element, path: &str) -> Option<Value> {
+    let mut segments = path.split('.');
+    let first = segments.next()?;
+    let mut current: Value = element.get_properties().get(first)?.into();
+    for segment in segments {
+        current = current.get(segment)?.clone();
+    }
+    Some(current)
+}
+
+fn evaluate(condition: &FilterCondition, element: &Element) -> bool {
+    let actual = lookup(element, &condition.property).filter(|v| !v.is_null());
+    let expected = &condition.value;
+
+    match (condition.operator, actual) {
+        (FilterOperator::Exists, actual) => actual.is_some(),
+        (FilterOperator::NotExists, actual) => actual.is_none(),
+        // Comparisons never hold for a missing property
+        (_, None) => false,
+        (FilterOperator::Eq, Some(actual)) => values_equal(&actual, expected),
+        (FilterOperator::Ne, Some(actual)) => !values_equal(&actual, expected),
+        (FilterOperator::Gt, Some(actual)) => compare(&actual, expected).is_some_and(|o| o.is_gt()),
+        (FilterOperator::Gte, Some(actual)) => {
+            compare(&actual, expected).is_some_and(|o| o.is_ge())
+        }
+        (FilterOperator::Lt, Some(actual)) => compare(&actual, expected).is_some_and(|o| o.is_lt()),
+        (FilterOperator::Lte, Some(actual)) => {
+            compare(&actual, expected).is_some_and(|o| o.is_le())
+        }
+        (FilterOperator::In, Some(actual)) => expected
+            .as_array()
+            .is_some_and(|options| options.iter().any(|o| values_equal(&actual, o))),
+        (FilterOperator::Contains, Some(actual)) => match (&actual, expected) {
+            (Value::String(s), Value::String(needle)) => s.contains(needle.as_str()),
+            (Value::Array(items), _) => items.iter().any(|i| values_equal(i, expected)),
+            _ => false,
+        },
+    }
+}
+
+/// Equality that treats `1` and `1.0` as equal.
+fn values_equal(a: &Value, b: &Value) -> bool {
+    match (a.as_f64(), b.as_f64()) {
+        (Some(x), Some(y)) => x == y,
+        _ => a == b,
+    }
+}
+
+/// Order numbers numerically and strings lexically; other combinations are incomparable.
+fn compare(a: &Value, b: &Value) -> Option<std::cmp::Ordering> {
+    match (a, b) {
+        (Value::String(x), Value::String(y)) => Some(x.cmp(y)),
+        _ => a.as_f64()?.partial_cmp(&b.as_f64()?),
+    }
+}
+
+#[async_trait]
+impl SourceMiddleware for FilterMiddleware {
+    async fn process(
+        &self,
+        source_change: SourceChange,
+        _element_index: &dyn ElementIndex,
+    ) -> Result<Vec<SourceChange>, MiddlewareError> {
+        match source_change {
+            SourceChange::Insert { ref element } => {
+                if self.matches(element) {
+                    Ok(vec![source_change])
+                } else {
+                    log::debug!(
+                        "[{}] Dropping insert of {} that does not match the filter",
+                        self.name,
+                        element.get_reference()
+                    );
+                    Ok(vec![])
+                }
+            }
+            SourceChange::Update { element } => {
+                if self.matches(&element) {
+                    Ok(vec![SourceChange::Update { element }])
+                } else {
+                    Ok(vec![SourceChange::Delete {
+                        metadata: element.get_metadata().clone(),
+                    }])
+                }
+            }
+            SourceChange::Delete { .. } | SourceChange::Future { .. } => Ok(vec![source_change]),
+        }
+    }
+}
+
+pub struct FilterMiddlewareFactory {}
+
+impl FilterMiddlewareFactory {
+    pub fn new() -> Self {
+        FilterMiddlewareFactory {}
+    }
+}
+
+impl Default for FilterMiddlewareFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SourceMiddlewareFactory for FilterMiddlewareFactory {
+    fn name(&self) -> String {
+        "filter".to_string()
+    }
+
+    fn create(
+        &self,
+        config: &SourceMiddlewareConfig,
+    ) -> Result<Arc<dyn SourceMiddleware>, MiddlewareSetupError> {
+        let filter_config: FilterMiddlewareConfig =
+            match serde_json::from_value(Value::Object(config.config.clone())) {
+                Ok(cfg) => cfg,
+                Err(e) => {
+                    return Err(MiddlewareSetupError::InvalidConfiguration(format!(
+                        "[{}] Invalid filter configuration: {}",
+                        config.name, e
+                    )))
+                }
+            };
+
+        if filter_config.conditions.is_empty() {
+            return Err(MiddlewareSetupError::InvalidConfiguration(format!(
+                "[{}] At least one condition must be specified",
+                config.name
+            )));
+        }
+
+        for condition in &filter_config.conditions {
+            if condition.property.is_empty() {
+                return Err(MiddlewareSetupError::InvalidConfiguration(format!(
+                    "[{}] Condition 'property' cannot be empty",
+                    config.name
+                )));
+            }
+            if condition.operator == FilterOperator::In && !condition.value.is_array() {
+                return Err(MiddlewareSetupError::InvalidConfiguration(format!(
+                    "[{}] The 'in' operator on '{}' requires an array value",
+                    config.name, condition.property
+                )));
+            }
+        }
+
+        log::info!(
+            "[{}] Creating Filter middleware with {} conditions",
+            config.name,
+            filter_config.conditions.len()
+        );
+
+        Ok(Arc::new(FilterMiddleware::new(
+            config.name.to_string(),
+            filter_config,
+        )))
+    }
+}
@@ -0,0 +1,228 @@
+// Copyright 2024 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Filter Middleware Tests
+
+use crate::filter::FilterMiddlewareFactory;
+use drasi_core::{
+    in_memory_index::in_memory_element_index::InMemoryElementIndex,
+    interface::{MiddlewareSetupError, SourceMiddleware, SourceMiddlewareFactory},
+    models::{Element, ElementMetadata, ElementReference, SourceChange, SourceMiddlewareConfig},
+};
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+fn create_mw_config(config_json: Value) -> SourceMiddlewareConfig {
+    SourceMiddlewareConfig {
+        name: "test_filter".into(),
+        kind: "filter".into(),
+        config: config_json
+            .as_object()
+            .expect("Config JSON must be an object")
+            .clone(),
+    }
+}
+
+fn node(label: &str, props: Value) -> Element {
+    Element::Node {
+        metadata: ElementMetadata {
+            reference: ElementReference::new("test_source", "node1"),
+            labels: vec![label.into()].into(),
+            effective_from: 0,
+        },
+        properties: props.into(),
+    }
+}
+
+fn create(config: Value) -> Arc<dyn SourceMiddleware> {
+    FilterMiddlewareFactory::new()
+        .create(&create_mw_config(config))
+        .expect("Failed to create middleware")
+}
+
+async fn run(mw: &Arc<dyn SourceMiddleware>, change: SourceChange) -> Vec<SourceChange> {
+    let element_index = InMemoryElementIndex::new();
+    mw.process(change, &element_index)
+        .await
+        .expect("process failed")
+}
+
+#[tokio::test]
+async fn test_insert_matching_passes() {
+    let mw = create(json!({
+        "conditions": [{ "property": "status", "operator": "eq", "value": "active" }]
+    }));
+    let result = run(
+        &mw,
+        SourceChange::Insert {
+            element: node("Sensor", json!({ "status": "active" })),
+        },
+    )
+    .await;
+    assert_eq!(result.len(), 1);
+    assert!(matches!(result[0], SourceChange::Insert { .. }));
+}
+
+#[tokio::test]
+async fn test_insert_not_matching_dropped() {
+    let mw = create(json!({
+        "conditions": [{ "property": "status", "operator": "eq", "value": "active" }]
+    }));
+    let result = run(
+        &mw,
+        SourceChange::Insert {
+            element: node("Sensor", json!({ "status": "retired" })),
+        },
+    )
+    .await;
+    assert!(result.is_empty());
+}
+
+#[tokio::test]
+async fn test_update_not_matching_becomes_delete() {
+    let mw = create(json!({
+        "conditions": [{ "property": "reading", "operator": "gt", "value": 10 }]
+    }));
+    let result = run(
+        &mw,
+        SourceChange::Update {
+            element: node("Sensor", json!({ "reading": 3.5 })),
+        },
+    )
+    .await;
+    assert_eq!(result.len(), 1);
+    match &result[0] {
+        SourceChange::Delete { metadata } => {
+            assert_eq!(metadata.reference.element_id.as_ref(), "node1")
+        }
+        other => panic!("Expected delete, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_mode_any_and_nested_paths() {
+    let mw = create(json!({
+        "mode": "any",
+        "conditions": [
+            { "property": "site.region", "operator": "in", "value": ["eu", "us"] },
+            { "property": "tags", "operator": "contains", "value": "critical" }
+        ]
+    }));
+
+    let by_region = run(
+        &mw,
+        SourceChange::Insert {
+            element: node("Sensor", json!({ "site": { "region": "eu" } })),
+        },
+    )
+    .await;
+    assert_eq!(by_region.len(), 1);
+
+    let by_tag = run(
+        &mw,
+        SourceChange::Insert {
+            element: node("Sensor", json!({ "tags": ["critical", "rooftop"] })),
+        },
+    )
+    .await;
+    assert_eq!(by_tag.len(), 1);
+
+    let neither = run(
+        &mw,
+        SourceChange::Insert {
+            element: node("Sensor", json!({ "site": { "region": "apac" } })),
+        },
+    )
+    .await;
+    assert!(neither.is_empty());
+}
+
+#[tokio::test]
+async fn test_exists_and_numeric_equality() {
+    let mw = create(json!({
+        "conditions": [
+            { "property": "owner", "operator": "exists" },
+            { "property": "level", "operator": "eq", "value": 1 }
+        ]
+    }));
+    let ok = run(
+        &mw,
+        SourceChange::Insert {
+            element: node("Sensor", json!({ "owner": "a", "level": 1.0 })),
+        },
+    )
+    .await;
+    assert_eq!(ok.len(), 1);
+
+    let null_owner = run(
+        &mw,
+        SourceChange::Insert {
+            element: node("Sensor", json!({ "owner": null, "level": 1 })),
+        },
+    )
+    .await;
+    assert!(null_owner.is_empty());
+}
+
+#[tokio::test]
+async fn test_labels_restrict_filtering() {
+    let mw = create(json!({
+        "labels": ["Sensor"],
+        "conditions": [{ "property": "status", "operator": "eq", "value": "active" }]
+    }));
+    let other_label = run(
+        &mw,
+        SourceChange::Insert {
+            element: node("Building", json!({ "status": "closed" })),
+        },
+    )
+    .await;
+    assert_eq!(other_label.len(), 1);
+}
+
+#[tokio::test]
+async fn test_delete_passes_through() {
+    let mw = create(json!({
+        "conditions": [{ "property": "status", "operator": "eq", "value": "active" }]
+    }));
+    let result = run(
+        &mw,
+        SourceChange::Delete {
+            metadata: ElementMetadata {
+                reference: ElementReference::new("test_source", "node1"),
+                labels: vec!["Sensor".into()].into(),
+                effective_from: 0,
+            },
+        },
+    )
+    .await;
+    assert_eq!(result.len(), 1);
+}
+
+#[test]
+fn test_invalid_configurations() {
+    let factory = FilterMiddlewareFactory::new();
+    for config in [
+        json!({ "conditions": [] }),
+        json!({ "conditions": [{ "property": "", "operator": "exists" }] }),
+        json!({ "conditions": [{ "property": "x", "operator": "in", "value": 1 }] }),
+        json!({ "conditions": [{ "property": "x", "operator": "between" }] }),
+        json!({ "conditions": [{ "property": "x", "operator": "exists" }], "extra": 1 }),
+    ] {
+        assert!(matches!(
+            factory.create(&create_mw_config(config)),
+            Err(MiddlewareSetupError::InvalidConfiguration(_))
+        ));
+    }
+}
@@ -17,6 +17,9 @@ pub mod common;
 #[cfg(feature = "decoder")]
 pub mod decoder;
 
+#[cfg(feature = "filter")]
+pub mod filter;
+
 #[cfg(feature = "jq")]
 pub mod jq;
 
@@ -32,5 +35,11 @@ pub mod promote;
 #[cfg(feature = "relabel")]
 pub mod relabel;
 
+#[cfg(feature = "relate")]
+pub mod relate;
+
+#[cfg(feature = "unit_convert")]
+pub mod unit_convert;
+
 #[cfg(feature = "unwind")]
 pub mod unwind;
@@ -43,6 +43,70 @@ pub enum ConflictStrategy {
     Fail,
 }
 
+/// Target type a promoted value is coerced to
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum CoerceType {
+    String,
+    Integer,
+    Float,
+    Boolean,
+}
+
+impl CoerceType {
+    /// Convert `value` to this type. Null values are left as null.
+    fn coerce(self, value: Value) -> Result<Value, String> {
+        let fail = |value: &Value| format!("cannot coerce {value} to {self:?}");
+        match (self, value) {
+            (_, Value::Null) => Ok(Value::Null),
+
+            (CoerceType::String, Value::String(s)) => Ok(Value::String(s)),
+            (CoerceType::String, Value::Number(n)) => Ok(Value::String(n.to_string())),
+            (CoerceType::String, Value::Bool(b)) => Ok(Value::String(b.to_string())),
+            (CoerceType::String, other) => Ok(Value::String(other.to_string())),
+
+            (CoerceType::Integer, Value::Number(n)) => match n.as_i64() {
+                Some(i) => Ok(Value::from(i)),
+                None => n
+                    .as_f64()
+                    .filter(|f| f.fract() == 0.0 && f.is_finite())
+                    .map(|f| Value::from(f as i64))
+                    .ok_or_else(|| fail(&Value::Number(n))),
+            },
+            (CoerceType::Integer, Value::String(s)) => match s.trim().parse::<i64>() {
+                Ok(i) => Ok(Value::from(i)),
+                Err(_) => Err(fail(&Value::String(s))),
+            },
+            (CoerceType::Integer, Value::Bool(b)) => Ok(Value::from(b as i64)),
+
+            (CoerceType::Float, Value::Number(n)) => n
+                .as_f64()
+                .map(Value::from)
+                .ok_or_else(|| fail(&Value::Number(n))),
+            (CoerceType::Float, Value::String(s)) => match s.trim().parse::<f64>() {
+                Ok(f) if f.is_finite() => Ok(Value::from(f)),
+                _ => Err(fail(&Value::String(s))),
+            },
+            (CoerceType::Float, Value::Bool(b)) => Ok(Value::from(if b { 1.0 } else { 0.0 })),
+
+            (CoerceType::Boolean, Value::Bool(b)) => Ok(Value::Bool(b)),
+            (CoerceType::Boolean, Value::Number(n)) => match n.as_f64() {
+                Some(f) => Ok(Value::Bool(f != 0.0)),
+                None => Err(fail(&Value::Number(n))),
+            },
+            (CoerceType::Boolean, Value::String(s)) => {
+                match s.trim().to_ascii_lowercase().as_str() {
+                    "true" | "1" | "yes" => Ok(Value::Bool(true)),
+                    "false" | "0" | "no" => Ok(Value::Bool(false)),
+                    _ => Err(fail(&Value::String(s))),
+                }
+            }
+
+            (_, other) => Err(fail(&other)),
+        }
+    }
+}
+
 /// JSONPath expression wrapper for selecting values to promote
 #[derive(Debug, Clone)]
 pub struct JsonPathExpression {
@@ -85,6 +149,9 @@ pub struct PromoteMapping {
     pub path: JsonPathExpression,
     /// Name for the promoted value
     pub target_name: String,
+    /// Optional type to coerce the selected value to
+    #[serde(default, rename = "type")]
+    pub coerce: Option<CoerceType>,
 }
 
 fn deserialize_jsonpath<'de, D>(deserializer: D) -> Result<JsonPathExpression, D::Error>
@@ -194,6 +261,29 @@ impl PromoteMiddleware {
                 None => continue, // Skip this mapping
             };
 
+            // Coerce to the requested type, if any
+            let value = match mapping.coerce {
+                Some(target_type) => match target_type.coerce(value) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        let msg = format!(
+                            "[{}] Value selected by '{}' for '{}': {}",
+                            self.name, mapping.path.expression, mapping.target_name, e
+                        );
+                        match self.on_error {
+                            ErrorHandling::Skip => {
+                                debug!("{msg}");
+                                continue;
+                            }
+                            ErrorHandling::Fail => {
+                                return Err(MiddlewareError::SourceChangeError(msg))
+                            }
+                        }
+                    }
+                },
+                None => value,
+            };
+
             // Check if the property already exists using the mutable properties reference
             if let Some(existing) = properties.get(&mapping.target_name) {
                 if *existing != ElementValue::Null {
@@ -833,6 +833,77 @@ mod process {
         assert!(props.get("id").is_some());
         assert!(props.get("values").is_some());
     }
+
+    #[tokio::test]
+    async fn test_promote_with_type_coercion() {
+        let factory = PromoteMiddlewareFactory::new();
+        let config = json!({
+            "mappings": [
+                { "path": "$.payload.telemetry.temp", "target_name": "temp", "type": "float" },
+                { "path": "$.payload.seq", "target_name": "seq", "type": "integer" },
+                { "path": "$.payload.online", "target_name": "online", "type": "boolean" },
+                { "path": "$.payload.id", "target_name": "device_id", "type": "string" }
+            ]
+        });
+        let subject = factory.create(&create_mw_config(config)).unwrap();
+        let element_index = Arc::new(InMemoryElementIndex::new());
+
+        let source_change = create_node_insert_change(json!({
+            "payload": {
+                "telemetry": { "temp": "21.5" },
+                "seq": 42.0,
+                "online": "true",
+                "id": 1001
+            }
+        }));
+
+        let result = subject
+            .process(source_change, element_index.as_ref())
+            .await
+            .unwrap();
+        let props = get_props_from_change(&result[0]);
+
+        assert_eq!(
+            props.get("temp"),
+            Some(&ElementValue::Float(ordered_float::OrderedFloat(21.5)))
+        );
+        assert_eq!(props.get("seq"), Some(&ElementValue::Integer(42)));
+        assert_eq!(props.get("online"), Some(&ElementValue::Bool(true)));
+        assert_eq!(
+            props.get("device_id"),
+            Some(&ElementValue::String("1001".into()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_promote_type_coercion_failure() {
+        let factory = PromoteMiddlewareFactory::new();
+        let element_index = Arc::new(InMemoryElementIndex::new());
+        let payload = json!({ "payload": { "temp": "warm" } });
+
+        let fail_config = json!({
+            "mappings": [{ "path": "$.payload.temp", "target_name": "temp", "type": "float" }]
+        });
+        let subject = factory.create(&create_mw_config(fail_config)).unwrap();
+        let result = subject
+            .process(
+                create_node_insert_change(payload.clone()),
+                element_index.as_ref(),
+            )
+            .await;
+        assert!(matches!(result, Err(MiddlewareError::SourceChangeError(_))));
+
+        let skip_config = json!({
+            "mappings": [{ "path": "$.payload.temp", "target_name": "temp", "type": "float" }],
+            "on_error": "skip"
+        });
+        let subject = factory.create(&create_mw_config(skip_config)).unwrap();
+        let result = subject
+            .process(create_node_insert_change(payload), element_index.as_ref())
+            .await
+            .unwrap();
+        assert!(get_props_from_change(&result[0]).get("temp").is_none());
+    }
 }
 
 mod factory {
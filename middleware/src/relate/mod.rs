@@ -0,0 +1,329 @@
+// Copyright 2024 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use drasi_core::{
+    interface::{
+        ElementIndex, MiddlewareError, MiddlewareSetupError, SourceMiddleware,
+        SourceMiddlewareFactory,
+    },
+    models::{
+        Element, ElementMetadata, ElementPropertyMap, ElementReference, ElementValue, SourceChange,
+        SourceMiddlewareConfig,
+    },
+};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::common::ErrorHandling;
+
+#[cfg(test)]
+mod tests;
+
+const VALUE_PLACEHOLDER: &str = "{value}";
+
+/// Direction of a synthesized relation, relative to the node carrying the key property.
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RelationDirection {
+    /// `(node)-[relation]->(target)`
+    #[default]
+    Outgoing,
+    /// `(target)-[relation]->(node)`
+    Incoming,
+}
+
+/// Declares one relation to synthesize from a foreign-key-style property.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RelationRule {
+    /// Label of the nodes this rule applies to.
+    pub label: String,
+    /// Property holding the key of the related node.
+    pub property: String,
+    /// Label of the synthesized relation.
+    pub relation_label: String,
+    /// Element id of the related node. `{value}` is replaced with the key.
+    #[serde(default = "default_target_id")]
+    pub target_id: String,
+    /// Source id of the related node. Defaults to the source of the change.
+    #[serde(default)]
+    pub target_source: Option<String>,
+    #[serde(default)]
+    pub direction: RelationDirection,
+}
+
+fn default_target_id() -> String {
+    VALUE_PLACEHOLDER.to_string()
+}
+
+/// Configuration for the relate middleware
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RelateMiddlewareConfig {
+    pub rules: Vec<RelationRule>,
+    #[serde(default)]
+    pub on_error: ErrorHandling,
+}
+
+/// Synthesizes relations from key properties on nodes.
+///
+/// Each rule gives a node at most one relation, identified by the rule and the
+/// node id, so updates that change the key move the relation rather than
+/// adding a second one.
+pub struct RelateMiddleware {
+    name: String,
+    rules: Vec<RelationRule>,
+    on_error: ErrorHandling,
+}
+
+/// What a rule wants done to its relation for a given node change.
+enum RelationChange {
+    Upsert(Element),
+    Remove(ElementMetadata),
+    Unchanged,
+}
+
+impl RelateMiddleware {
+    fn rules_for<'a>(
+        &'a self,
+        metadata: &'a ElementMetadata,
+    ) -> impl Iterator<Item = &'a RelationRule> {
+        self.rules.iter().filter(move |rule| {
+            metadata
+                .labels
+                .iter()
+                .any(|label| label.as_ref() == rule.label)
+        })
+    }
+
+    fn relation_metadata(rule: &RelationRule, node: &ElementMetadata) -> ElementMetadata {
+        ElementMetadata {
+            reference: ElementReference::new(
+                &node.reference.source_id,
+                &format_relation_id(rule, &node.reference.element_id),
+            ),
+            labels: Arc::new([Arc::from(rule.relation_label.as_str())]),
+            effective_from: node.effective_from,
+        }
+    }
+
+    fn relation_for(
+        &self,
+        rule: &RelationRule,
+        metadata: &ElementMetadata,
+        properties: &ElementPropertyMap,
+    ) -> Result<RelationChange, MiddlewareError> {
+        let key = match properties.get(&rule.property) {
+            None => return Ok(RelationChange::Unchanged),
+            Some(ElementValue::Null) => {
+                return Ok(RelationChange::Remove(Self::relation_metadata(
+                    rule, metadata,
+                )))
+            }
+            Some(ElementValue::String(s)) => s.to_string(),
+            Some(ElementValue::Integer(i)) => i.to_string(),
+            Some(other) => {
+                let msg = format!(
+                    "[{}] Property '{}' on {} cannot be used as a key: {:?}",
+                    self.name, rule.property, metadata.reference, other
+                );
+                return match self.on_error {
+                    ErrorHandling::Fail => Err(MiddlewareError::SourceChangeError(msg)),
+                    ErrorHandling::Skip => {
+                        log::warn!("{msg}");
+                        Ok(RelationChange::Unchanged)
+                    }
+                };
+            }
+        };
+
+        let target = ElementReference::new(
+            rule.target_source
+                .as_deref()
+                .unwrap_or(&metadata.reference.source_id),
+            &rule.target_id.replace(VALUE_PLACEHOLDER, &key),
+        );
+        let (in_node, out_node) = match rule.direction {
+            RelationDirection::Outgoing => (metadata.reference.clone(), target),
+            RelationDirection::Incoming => (target, metadata.reference.clone()),
+        };
+
+        Ok(RelationChange::Upsert(Element::Relation {
+            metadata: Self::relation_metadata(rule, metadata),
+            properties: ElementPropertyMap::new(),
+            in_node,
+            out_node,
+        }))
+    }
+}
+
+/// Relation ids include the whole rule, so rules for different node labels
+/// that share a relation label never overwrite each other's relations.
+fn format_relation_id(rule: &RelationRule, node_id: &str) -> String {
+    format!(
+        "$relate-{}-{}-{}-{node_id}",
+        rule.label, rule.property, rule.relation_label
+    )
+}
+
+#[async_trait]
+impl SourceMiddleware for RelateMiddleware {
+    async fn process(
+        &self,
+        source_change: SourceChange,
+        _element_index: &dyn ElementIndex,
+    ) -> Result<Vec<SourceChange>, MiddlewareError> {
+        match source_change {
+            SourceChange::Insert {
+                element:
+                    Element::Node {
+                        ref metadata,
+                        ref properties,
+                    },
+            } => {
+                let mut relations = Vec::new();
+                for rule in self.rules_for(metadata) {
+                    if let RelationChange::Upsert(element) =
+                        self.relation_for(rule, metadata, properties)?
+                    {
+                        relations.push(SourceChange::Insert { element });
+                    }
+                }
+                let mut result = vec![source_change];
+                result.append(&mut relations);
+                Ok(result)
+            }
+            SourceChange::Update {
+                element:
+                    Element::Node {
+                        ref metadata,
+                        ref properties,
+                    },
+            } => {
+                let mut relations = Vec::new();
+                for rule in self.rules_for(metadata) {
+                    match self.relation_for(rule, metadata, properties)? {
+                        RelationChange::Upsert(element) => {
+                            relations.push(SourceChange::Update { element })
+                        }
+                        RelationChange::Remove(metadata) => {
+                            relations.push(SourceChange::Delete { metadata })
+                        }
+                        RelationChange::Unchanged => {}
+                    }
+                }
+                let mut result = vec![source_change];
+                result.append(&mut relations);
+                Ok(result)
+            }
+            SourceChange::Delete { ref metadata } => {
+                // Remove synthesized relations before the node itself. Deletes
+                // often carry no or partial labels, so every rule's relation is
+                // removed; deleting one the node never had is a no-op.
+                let mut result: Vec<SourceChange> = self
+                    .rules
+                    .iter()
+                    .map(|rule| SourceChange::Delete {
+                        metadata: Self::relation_metadata(rule, metadata),
+                    })
+                    .collect();
+                result.push(source_change);
+                Ok(result)
+            }
+            _ => Ok(vec![source_change]),
+        }
+    }
+}
+
+pub struct RelateMiddlewareFactory {}
+
+impl RelateMiddlewareFactory {
+    pub fn new() -> Self {
+        RelateMiddlewareFactory {}
+    }
+}
+
+impl Default for RelateMiddlewareFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SourceMiddlewareFactory for RelateMiddlewareFactory {
+    fn name(&self) -> String {
+        "relate".to_string()
+    }
+
+    fn create(
+        &self,
+        config: &SourceMiddlewareConfig,
+    ) -> Result<Arc<dyn SourceMiddleware>, MiddlewareSetupError> {
+        let relate_config: RelateMiddlewareConfig =
+            match serde_json::from_value(Value::Object(config.config.clone())) {
+                Ok(cfg) => cfg,
+                Err(e) => {
+                    return Err(MiddlewareSetupError::InvalidConfiguration(format!(
+                        "[{}] Invalid relate configuration: {}",
+                        config.name, e
+                    )))
+                }
+            };
+
+        if relate_config.rules.is_empty() {
+            return Err(MiddlewareSetupError::InvalidConfiguration(format!(
+                "[{}] At least one rule must be specified",
+                config.name
+            )));
+        }
+
+        for (i, rule) in relate_config.rules.iter().enumerate() {
+            if rule.label.is_empty() || rule.property.is_empty() || rule.relation_label.is_empty() {
+                return Err(MiddlewareSetupError::InvalidConfiguration(format!(
+                    "[{}] Rule at index {} must set 'label', 'property' and 'relation_label'",
+                    config.name, i
+                )));
+            }
+            if relate_config.rules[..i]
+                .iter()
+                .any(|r| r.label == rule.label && r.relation_label == rule.relation_label)
+            {
+                return Err(MiddlewareSetupError::InvalidConfiguration(format!(
+                    "[{}] Duplicate rule for label '{}' and relation '{}'",
+                    config.name, rule.label, rule.relation_label
+                )));
+            }
+            if !rule.target_id.contains(VALUE_PLACEHOLDER) {
+                return Err(MiddlewareSetupError::InvalidConfiguration(format!(
+                    "[{}] 'target_id' of rule at index {} must contain '{}'",
+                    config.name, i, VALUE_PLACEHOLDER
+                )));
+            }
+        }
+
+        log::info!(
+            "[{}] Creating Relate middleware with {} rules",
+            config.name,
+            relate_config.rules.len()
+        );
+
+        Ok(Arc::new(RelateMiddleware {
+            name: config.name.to_string(),
+            rules: relate_config.rules,
+            on_error: relate_config.on_error,
+        }))
+    }
+}
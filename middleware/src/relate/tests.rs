@@ -0,0 +1,316 @@
+// Copyright 2024 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Relate Middleware Tests
+
+use crate::relate::RelateMiddlewareFactory;
+use drasi_core::{
+    in_memory_index::in_memory_element_index::InMemoryElementIndex,
+    interface::{MiddlewareError, MiddlewareSetupError, SourceMiddleware, SourceMiddlewareFactory},
+    models::{Element, ElementMetadata, ElementReference, SourceChange, SourceMiddlewareConfig},
+};
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+fn create_mw_config(config_json: Value) -> SourceMiddlewareConfig {
+    SourceMiddlewareConfig {
+        name: "test_relate".into(),
+        kind: "relate".into(),
+        config: config_json
+            .as_object()
+            .expect("Config JSON must be an object")
+            .clone(),
+    }
+}
+
+fn reading_metadata() -> ElementMetadata {
+    ElementMetadata {
+        reference: ElementReference::new("iot", "reading1"),
+        labels: vec!["Reading".into()].into(),
+        effective_from: 7,
+    }
+}
+
+fn reading(props: Value) -> Element {
+    Element::Node {
+        metadata: reading_metadata(),
+        properties: props.into(),
+    }
+}
+
+fn create(config: Value) -> Arc<dyn SourceMiddleware> {
+    RelateMiddlewareFactory::new()
+        .create(&create_mw_config(config))
+        .expect("Failed to create middleware")
+}
+
+fn default_config() -> Value {
+    json!({
+        "rules": [{
+            "label": "Reading",
+            "property": "device_id",
+            "relation_label": "REPORTED_BY",
+            "target_id": "device-{value}"
+        }]
+    })
+}
+
+async fn run(mw: &Arc<dyn SourceMiddleware>, change: SourceChange) -> Vec<SourceChange> {
+    let element_index = InMemoryElementIndex::new();
+    mw.process(change, &element_index)
+        .await
+        .expect("process failed")
+}
+
+#[tokio::test]
+async fn test_insert_creates_relation() {
+    let mw = create(default_config());
+    let result = run(
+        &mw,
+        SourceChange::Insert {
+            element: reading(json!({ "device_id": "d1", "value": 3 })),
+        },
+    )
+    .await;
+
+    assert_eq!(result.len(), 2);
+    assert!(matches!(
+        result[0],
+        SourceChange::Insert {
+            element: Element::Node { .. }
+        }
+    ));
+    match &result[1] {
+        SourceChange::Insert {
+            element:
+                Element::Relation {
+                    metadata,
+                    in_node,
+                    out_node,
+                    ..
+                },
+        } => {
+            assert_eq!(metadata.labels[0].as_ref(), "REPORTED_BY");
+            assert_eq!(metadata.effective_from, 7);
+            assert_eq!(in_node, &ElementReference::new("iot", "reading1"));
+            assert_eq!(out_node, &ElementReference::new("iot", "device-d1"));
+        }
+        other => panic!("Expected relation insert, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_incoming_direction_and_target_source() {
+    let mw = create(json!({
+        "rules": [{
+            "label": "Reading",
+            "property": "device_id",
+            "relation_label": "HAS_READING",
+            "target_source": "registry",
+            "direction": "incoming"
+        }]
+    }));
+    let result = run(
+        &mw,
+        SourceChange::Insert {
+            element: reading(json!({ "device_id": 17 })),
+        },
+    )
+    .await;
+
+    match &result[1] {
+        SourceChange::Insert {
+            element: Element::Relation {
+                in_node, out_node, ..
+            },
+        } => {
+            assert_eq!(in_node, &ElementReference::new("registry", "17"));
+            assert_eq!(out_node, &ElementReference::new("iot", "reading1"));
+        }
+        other => panic!("Expected relation insert, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_update_moves_or_removes_relation() {
+    let mw = create(default_config());
+
+    let moved = run(
+        &mw,
+        SourceChange::Update {
+            element: reading(json!({ "device_id": "d2" })),
+        },
+    )
+    .await;
+    assert_eq!(moved.len(), 2);
+    assert!(matches!(
+        moved[1],
+        SourceChange::Update {
+            element: Element::Relation { .. }
+        }
+    ));
+
+    let cleared = run(
+        &mw,
+        SourceChange::Update {
+            element: reading(json!({ "device_id": null })),
+        },
+    )
+    .await;
+    assert_eq!(cleared.len(), 2);
+    assert!(matches!(cleared[1], SourceChange::Delete { .. }));
+
+    // A partial update without the key leaves the relation alone
+    let partial = run(
+        &mw,
+        SourceChange::Update {
+            element: reading(json!({ "value": 5 })),
+        },
+    )
+    .await;
+    assert_eq!(partial.len(), 1);
+}
+
+#[tokio::test]
+async fn test_delete_removes_relation_first() {
+    let mw = create(default_config());
+    let result = run(
+        &mw,
+        SourceChange::Delete {
+            metadata: reading_metadata(),
+        },
+    )
+    .await;
+
+    assert_eq!(result.len(), 2);
+    match (&result[0], &result[1]) {
+        (SourceChange::Delete { metadata: rel }, SourceChange::Delete { metadata: node }) => {
+            assert_eq!(
+                rel.reference.element_id.as_ref(),
+                "$relate-Reading-device_id-REPORTED_BY-reading1"
+            );
+            assert_eq!(node.reference.element_id.as_ref(), "reading1");
+        }
+        other => panic!("Unexpected changes {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_delete_without_labels_removes_relations() {
+    let mw = create(default_config());
+    let result = run(
+        &mw,
+        SourceChange::Delete {
+            metadata: ElementMetadata {
+                labels: Vec::new().into(),
+                ..reading_metadata()
+            },
+        },
+    )
+    .await;
+
+    assert_eq!(result.len(), 2);
+    assert_eq!(
+        result[0].get_reference().element_id.as_ref(),
+        "$relate-Reading-device_id-REPORTED_BY-reading1"
+    );
+    assert_eq!(result[1].get_reference().element_id.as_ref(), "reading1");
+}
+
+#[tokio::test]
+async fn test_other_labels_pass_through() {
+    let mw = create(default_config());
+    let result = run(
+        &mw,
+        SourceChange::Insert {
+            element: Element::Node {
+                metadata: ElementMetadata {
+                    reference: ElementReference::new("iot", "device-d1"),
+                    labels: vec!["Device".into()].into(),
+                    effective_from: 0,
+                },
+                properties: json!({ "device_id": "d1" }).into(),
+            },
+        },
+    )
+    .await;
+    assert_eq!(result.len(), 1);
+}
+
+#[tokio::test]
+async fn test_invalid_key_fail() {
+    let mw = create(default_config());
+    let element_index = InMemoryElementIndex::new();
+    let result = mw
+        .process(
+            SourceChange::Insert {
+                element: reading(json!({ "device_id": { "nested": true } })),
+            },
+            &element_index,
+        )
+        .await;
+    assert!(matches!(result, Err(MiddlewareError::SourceChangeError(_))));
+}
+
+#[tokio::test]
+async fn test_rules_sharing_relation_label_keep_separate_relations() {
+    let mw = create(json!({
+        "rules": [
+            { "label": "Reading", "property": "device_id", "relation_label": "LOCATED_AT" },
+            { "label": "Alarm", "property": "site_id", "relation_label": "LOCATED_AT" }
+        ]
+    }));
+    let result = run(
+        &mw,
+        SourceChange::Insert {
+            element: Element::Node {
+                metadata: ElementMetadata {
+                    labels: vec!["Reading".into(), "Alarm".into()].into(),
+                    ..reading_metadata()
+                },
+                properties: json!({ "device_id": "d1", "site_id": "s1" }).into(),
+            },
+        },
+    )
+    .await;
+
+    let ids: Vec<_> = result[1..]
+        .iter()
+        .map(|change| change.get_reference().element_id.to_string())
+        .collect();
+    assert_eq!(
+        ids,
+        vec![
+            "$relate-Reading-device_id-LOCATED_AT-reading1",
+            "$relate-Alarm-site_id-LOCATED_AT-reading1",
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_configurations() {
+    let factory = RelateMiddlewareFactory::new();
+    let rule = json!({ "label": "Reading", "property": "device_id", "relation_label": "R" });
+    for config in [
+        json!({ "rules": [] }),
+        json!({ "rules": [{ "label": "", "property": "device_id", "relation_label": "R" }] }),
+        json!({ "rules": [{ "label": "Reading", "property": "device_id", "relation_label": "R", "target_id": "device" }] }),
+        json!({ "rules": [rule.clone(), rule] }),
+    ] {
+        assert!(matches!(
+            factory.create(&create_mw_config(config)),
+            Err(MiddlewareSetupError::InvalidConfiguration(_))
+        ));
+    }
+}
@@ -0,0 +1,328 @@
+// Copyright 2024 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use drasi_core::{
+    interface::{
+        ElementIndex, MiddlewareError, MiddlewareSetupError, SourceMiddleware,
+        SourceMiddlewareFactory,
+    },
+    models::{Element, ElementValue, SourceChange, SourceMiddlewareConfig},
+};
+use ordered_float::OrderedFloat;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::common::ErrorHandling;
+
+#[cfg(test)]
+mod tests;
+
+/// Physical dimension of a unit. Conversions are only allowed within a dimension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dimension {
+    Temperature,
+    Length,
+    Mass,
+    Speed,
+    Pressure,
+    Time,
+    Volume,
+}
+
+/// A known unit: `si = value * factor + offset`.
+#[derive(Debug, Clone, Copy)]
+struct Unit {
+    dimension: Dimension,
+    factor: f64,
+    offset: f64,
+}
+
+const fn unit(dimension: Dimension, factor: f64) -> Unit {
+    Unit {
+        dimension,
+        factor,
+        offset: 0.0,
+    }
+}
+
+fn lookup_unit(name: &str) -> Option<Unit> {
+    use Dimension::*;
+    let unit = match name {
+        "C" | "celsius" => Unit {
+            dimension: Temperature,
+            factor: 1.0,
+            offset: 273.15,
+        },
+        "F" | "fahrenheit" => Unit {
+            dimension: Temperature,
+            factor: 5.0 / 9.0,
+            offset: 273.15 - 32.0 * 5.0 / 9.0,
+        },
+        "K" | "kelvin" => unit(Temperature, 1.0),
+
+        "mm" => unit(Length, 0.001),
+        "cm" => unit(Length, 0.01),
+        "m" => unit(Length, 1.0),
+        "km" => unit(Length, 1000.0),
+        "in" => unit(Length, 0.0254),
+        "ft" => unit(Length, 0.3048),
+        "yd" => unit(Length, 0.9144),
+        "mi" => unit(Length, 1609.344),
+
+        "mg" => unit(Mass, 1e-6),
+        "g" => unit(Mass, 0.001),
+        "kg" => unit(Mass, 1.0),
+        "t" => unit(Mass, 1000.0),
+        "oz" => unit(Mass, 0.028_349_523_125),
+        "lb" => unit(Mass, 0.453_592_37),
+
+        "m/s" => unit(Speed, 1.0),
+        "km/h" => unit(Speed, 1000.0 / 3600.0),
+        "mph" => unit(Speed, 0.447_04),
+        "kn" => unit(Speed, 1852.0 / 3600.0),
+
+        "Pa" => unit(Pressure, 1.0),
+        "kPa" => unit(Pressure, 1000.0),
+        "bar" => unit(Pressure, 100_000.0),
+        "psi" => unit(Pressure, 6_894.757_293_168),
+        "atm" => unit(Pressure, 101_325.0),
+
+        "ms" => unit(Time, 0.001),
+        "s" => unit(Time, 1.0),
+        "min" => unit(Time, 60.0),
+        "h" => unit(Time, 3600.0),
+        "d" => unit(Time, 86_400.0),
+
+        "ml" => unit(Volume, 0.001),
+        "l" => unit(Volume, 1.0),
+        "m3" => unit(Volume, 1000.0),
+        "gal" => unit(Volume, 3.785_411_784),
+        _ => return None,
+    };
+    Some(unit)
+}
+
+/// A single property conversion.
+///
+/// Either `from`/`to` name units from the built-in table, or `scale`/`offset`
+/// give a linear transform `value * scale + offset`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConversionSpec {
+    /// Numeric property to convert.
+    pub property: String,
+    #[serde(default)]
+    pub from: Option<String>,
+    #[serde(default)]
+    pub to: Option<String>,
+    #[serde(default)]
+    pub scale: Option<f64>,
+    #[serde(default)]
+    pub offset: Option<f64>,
+    /// Property to write the result to. Defaults to overwriting `property`.
+    #[serde(default)]
+    pub output_property: Option<String>,
+}
+
+/// Configuration for the unit conversion middleware
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UnitConvertMiddlewareConfig {
+    pub conversions: Vec<ConversionSpec>,
+    #[serde(default)]
+    pub on_error: ErrorHandling,
+}
+
+/// A validated conversion reduced to `output = value * scale + offset`.
+#[derive(Debug, Clone)]
+struct Conversion {
+    property: String,
+    output_property: String,
+    scale: f64,
+    offset: f64,
+}
+
+impl Conversion {
+    fn from_spec(spec: ConversionSpec) -> Result<Self, String> {
+        if spec.property.is_empty() {
+            return Err("Conversion 'property' cannot be empty".to_string());
+        }
+        let (scale, offset) = match (&spec.from, &spec.to, spec.scale, spec.offset) {
+            (Some(from), Some(to), None, None) => {
+                let from_unit =
+                    lookup_unit(from).ok_or_else(|| format!("Unknown unit '{from}'"))?;
+                let to_unit = lookup_unit(to).ok_or_else(|| format!("Unknown unit '{to}'"))?;
+                if from_unit.dimension != to_unit.dimension {
+                    return Err(format!(
+                        "Cannot convert '{}' from '{from}' ({:?}) to '{to}' ({:?})",
+                        spec.property, from_unit.dimension, to_unit.dimension
+                    ));
+                }
+                // out = (v * f_from + o_from - o_to) / f_to
+                (
+                    from_unit.factor / to_unit.factor,
+                    (from_unit.offset - to_unit.offset) / to_unit.factor,
+                )
+            }
+            (None, None, scale, offset) if scale.is_some() || offset.is_some() => {
+                (scale.unwrap_or(1.0), offset.unwrap_or(0.0))
+            }
+            _ => {
+                return Err(format!(
+                    "Conversion for '{}' must specify either 'from' and 'to', or 'scale'/'offset'",
+                    spec.property
+                ))
+            }
+        };
+        Ok(Conversion {
+            output_property: spec
+                .output_property
+                .unwrap_or_else(|| spec.property.clone()),
+            property: spec.property,
+            scale,
+            offset,
+        })
+    }
+}
+
+/// Converts numeric properties between units on insert and update.
+pub struct UnitConvertMiddleware {
+    name: String,
+    conversions: Vec<Conversion>,
+    on_error: ErrorHandling,
+}
+
+impl UnitConvertMiddleware {
+    fn convert_element(&self, element: &mut Element) -> Result<(), MiddlewareError> {
+        let properties = match element {
+            Element::Node { properties, .. } | Element::Relation { properties, .. } => properties,
+        };
+        for conversion in &self.conversions {
+            let value = match properties.get(&conversion.property) {
+                None | Some(ElementValue::Null) => continue,
+                Some(ElementValue::Integer(i)) => *i as f64,
+                Some(ElementValue::Float(f)) => f.into_inner(),
+                Some(other) => {
+                    let message = format!(
+                        "[{}] Property '{}' is not numeric: {:?}",
+                        self.name, conversion.property, other
+                    );
+                    match self.on_error {
+                        ErrorHandling::Fail => {
+                            return Err(MiddlewareError::SourceChangeError(message))
+                        }
+                        ErrorHandling::Skip => {
+                            log::warn!("{message}");
+                            continue;
+                        }
+                    }
+                }
+            };
+            let converted = value * conversion.scale + conversion.offset;
+            properties.insert(
+                &conversion.output_property,
+                ElementValue::Float(OrderedFloat(converted)),
+            );
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SourceMiddleware for UnitConvertMiddleware {
+    async fn process(
+        &self,
+        source_change: SourceChange,
+        _element_index: &dyn ElementIndex,
+    ) -> Result<Vec<SourceChange>, MiddlewareError> {
+        match source_change {
+            SourceChange::Insert { mut element } => {
+                self.convert_element(&mut element)?;
+                Ok(vec![SourceChange::Insert { element }])
+            }
+            SourceChange::Update { mut element } => {
+                self.convert_element(&mut element)?;
+                Ok(vec![SourceChange::Update { element }])
+            }
+            SourceChange::Delete { .. } | SourceChange::Future { .. } => Ok(vec![source_change]),
+        }
+    }
+}
+
+pub struct UnitConvertMiddlewareFactory {}
+
+impl UnitConvertMiddlewareFactory {
+    pub fn new() -> Self {
+        UnitConvertMiddlewareFactory {}
+    }
+}
+
+impl Default for UnitConvertMiddlewareFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SourceMiddlewareFactory for UnitConvertMiddlewareFactory {
+    fn name(&self) -> String {
+        "unit_convert".to_string()
+    }
+
+    fn create(
+        &self,
+        config: &SourceMiddlewareConfig,
+    ) -> Result<Arc<dyn SourceMiddleware>, MiddlewareSetupError> {
+        let convert_config: UnitConvertMiddlewareConfig =
+            match serde_json::from_value(Value::Object(config.config.clone())) {
+                Ok(cfg) => cfg,
+                Err(e) => {
+                    return Err(MiddlewareSetupError::InvalidConfiguration(format!(
+                        "[{}] Invalid unit_convert configuration: {}",
+                        config.name, e
+                    )))
+                }
+            };
+
+        if convert_config.conversions.is_empty() {
+            return Err(MiddlewareSetupError::InvalidConfiguration(format!(
+                "[{}] At least one conversion must be specified",
+                config.name
+            )));
+        }
+
+        let conversions = convert_config
+            .conversions
+            .into_iter()
+            .map(Conversion::from_spec)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| {
+                MiddlewareSetupError::InvalidConfiguration(format!("[{}] {}", config.name, e))
+            })?;
+
+        log::info!(
+            "[{}] Creating UnitConvert middleware with {} conversions",
+            config.name,
+            conversions.len()
+        );
+
+        Ok(Arc::new(UnitConvertMiddleware {
+            name: config.name.to_string(),
+            conversions,
+            on_error: convert_config.on_error,
+        }))
+    }
+}
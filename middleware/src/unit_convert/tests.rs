@@ -0,0 +1,182 @@
+// Copyright 2024 The Drasi Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Unit Convert Middleware Tests
+
+use crate::unit_convert::UnitConvertMiddlewareFactory;
+use drasi_core::{
+    in_memory_index::in_memory_element_index::InMemoryElementIndex,
+    interface::{MiddlewareError, MiddlewareSetupError, SourceMiddleware, SourceMiddlewareFactory},
+    models::{
+        Element, ElementMetadata, ElementReference, ElementValue, SourceChange,
+        SourceMiddlewareConfig,
+    },
+};
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+fn create_mw_config(config_json: Value) -> SourceMiddlewareConfig {
+    SourceMiddlewareConfig {
+        name: "test_unit_convert".into(),
+        kind: "unit_convert".into(),
+        config: config_json
+            .as_object()
+            .expect("Config JSON must be an object")
+            .clone(),
+    }
+}
+
+fn create_node_insert_change(props: Value) -> SourceChange {
+    SourceChange::Insert {
+        element: Element::Node {
+            metadata: ElementMetadata {
+                reference: ElementReference::new("test_source", "node1"),
+                labels: vec!["Sensor".into()].into(),
+                effective_from: 0,
+            },
+            properties: props.into(),
+        },
+    }
+}
+
+fn create(config: Value) -> Arc<dyn SourceMiddleware> {
+    UnitConvertMiddlewareFactory::new()
+        .create(&create_mw_config(config))
+        .expect("Failed to create middleware")
+}
+
+fn float_prop(change: &SourceChange, name: &str) -> f64 {
+    match change {
+        SourceChange::Insert { element } | SourceChange::Update { element } => {
+            match element.get_properties().get(name) {
+                Some(ElementValue::Float(f)) => f.into_inner(),
+                other => panic!("Expected float for {name}, got {other:?}"),
+            }
+        }
+        other => panic!("Unexpected change {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_temperature_conversion() {
+    let mw = create(json!({
+        "conversions": [{ "property": "temp", "from": "F", "to": "C" }]
+    }));
+    let element_index = InMemoryElementIndex::new();
+    let result = mw
+        .process(
+            create_node_insert_change(json!({ "temp": 212 })),
+            &element_index,
+        )
+        .await
+        .expect("process failed");
+    assert!((float_prop(&result[0], "temp") - 100.0).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn test_output_property_and_length() {
+    let mw = create(json!({
+        "conversions": [
+            { "property": "distance", "from": "mi", "to": "km", "output_property": "distance_km" }
+        ]
+    }));
+    let element_index = InMemoryElementIndex::new();
+    let result = mw
+        .process(
+            create_node_insert_change(json!({ "distance": 10.0 })),
+            &element_index,
+        )
+        .await
+        .expect("process failed");
+    assert!((float_prop(&result[0], "distance_km") - 16.09344).abs() < 1e-9);
+    assert!((float_prop(&result[0], "distance") - 10.0).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn test_custom_scale_and_offset() {
+    let mw = create(json!({
+        "conversions": [{ "property": "raw", "scale": 0.5, "offset": -1 }]
+    }));
+    let element_index = InMemoryElementIndex::new();
+    let result = mw
+        .process(
+            create_node_insert_change(json!({ "raw": 10 })),
+            &element_index,
+        )
+        .await
+        .expect("process failed");
+    assert!((float_prop(&result[0], "raw") - 4.0).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn test_missing_property_is_ignored() {
+    let mw = create(json!({
+        "conversions": [{ "property": "temp", "from": "C", "to": "K" }]
+    }));
+    let element_index = InMemoryElementIndex::new();
+    let result = mw
+        .process(
+            create_node_insert_change(json!({ "other": 1 })),
+            &element_index,
+        )
+        .await
+        .expect("process failed");
+    assert_eq!(result.len(), 1);
+}
+
+#[tokio::test]
+async fn test_non_numeric_fail_and_skip() {
+    let element_index = InMemoryElementIndex::new();
+    let fail = create(json!({
+        "conversions": [{ "property": "temp", "from": "C", "to": "K" }]
+    }));
+    let result = fail
+        .process(
+            create_node_insert_change(json!({ "temp": "hot" })),
+            &element_index,
+        )
+        .await;
+    assert!(matches!(result, Err(MiddlewareError::SourceChangeError(_))));
+
+    let skip = create(json!({
+        "conversions": [{ "property": "temp", "from": "C", "to": "K" }],
+        "on_error": "skip"
+    }));
+    let result = skip
+        .process(
+            create_node_insert_change(json!({ "temp": "hot" })),
+            &element_index,
+        )
+        .await
+        .expect("process failed");
+    assert_eq!(result.len(), 1);
+}
+
+#[test]
+fn test_invalid_configurations() {
+    let factory = UnitConvertMiddlewareFactory::new();
+    for config in [
+        json!({ "conversions": [] }),
+        json!({ "conversions": [{ "property": "x", "from": "kg", "to": "m" }] }),
+        json!({ "conversions": [{ "property": "x", "from": "parsec", "to": "m" }] }),
+        json!({ "conversions": [{ "property": "x", "from": "kg" }] }),
+        json!({ "conversions": [{ "property": "x", "from": "kg", "to": "g", "scale": 2 }] }),
+        json!({ "conversions": [{ "property": "", "scale": 2 }] }),
+    ] {
+        assert!(matches!(
+            factory.create(&create_mw_config(config)),
+            Err(MiddlewareSetupError::InvalidConfiguration(_))
+        ));
+    }
+}